@@ -8,10 +8,12 @@
 // | Create              | < 1ms     | Single issue creation            |
 // | List (1k)           | < 10ms    | List 1000 issues                 |
 // | List (10k)          | < 100ms   | List 10000 issues                |
+// | List + counts (10k) | < 150ms   | 10k issues, 20k deps, one query  |
 // | Ready (1k/2k)       | < 5ms     | Ready query: 1k issues, 2k deps  |
 // | Ready (10k/20k)     | < 50ms    | Ready query: 10k issues, 20k deps|
 // | Export (10k)        | < 500ms   | Export 10k issues to JSONL       |
 // | Import (10k)        | < 1s      | Import 10k issues from JSONL     |
+// | Import (50k)        | < 2s      | Import 50k issues (bulk path)    |
 
 #![allow(
     clippy::significant_drop_tightening,
@@ -23,6 +25,7 @@ use beads_rust::model::{Issue, IssueType, Priority, Status};
 use beads_rust::storage::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, SqliteStorage};
 use chrono::Utc;
 use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::collections::BTreeMap;
 use std::hint::black_box;
 use std::io::Cursor;
 use std::sync::Once;
@@ -81,6 +84,10 @@ fn create_test_issue(i: usize) -> Issue {
         labels: vec![format!("label-{}", i % 5)],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
@@ -289,6 +296,7 @@ fn bench_update_issue(c: &mut Criterion) {
                 deleted_at: None,
                 deleted_by: None,
                 delete_reason: None,
+                pinned: None,
                 skip_cache_rebuild: false,
                 expect_unassigned: false,
                 claim_exclusive: false,
@@ -445,6 +453,48 @@ fn bench_list_issues_filtered(c: &mut Criterion) {
     log_group_end(group_name);
 }
 
+/// Benchmark listing with dependency counts: one joined query against
+/// listing first and counting in two batch queries.
+fn bench_list_with_counts(c: &mut Criterion) {
+    init_bench_logging();
+    let group_name = "storage/list_with_counts";
+    log_group_start(group_name);
+    let mut group = c.benchmark_group(group_name);
+    configure_group(&mut group);
+
+    let (_dir, storage) = setup_db_with_deps(10_000, 20_000);
+    let filters = ListFilters::default();
+    group.throughput(Throughput::Elements(10_000));
+
+    group.bench_function("joined", |b| {
+        let bench_name = "storage/list_with_counts/joined";
+        let bench_start = log_bench_start(bench_name);
+        b.iter(|| {
+            let rows = storage
+                .list_issues_with_counts(black_box(&filters))
+                .unwrap();
+            black_box(rows)
+        });
+        log_bench_end(bench_name, bench_start);
+    });
+
+    group.bench_function("batched", |b| {
+        let bench_name = "storage/list_with_counts/batched";
+        let bench_start = log_bench_start(bench_name);
+        b.iter(|| {
+            let issues = storage.list_issues(black_box(&filters)).unwrap();
+            let ids: Vec<String> = issues.iter().map(|issue| issue.id.clone()).collect();
+            let dependencies = storage.count_dependencies_for_issues(&ids).unwrap();
+            let dependents = storage.count_dependents_for_issues(&ids).unwrap();
+            black_box((issues, dependencies, dependents))
+        });
+        log_bench_end(bench_name, bench_start);
+    });
+
+    group.finish();
+    log_group_end(group_name);
+}
+
 /// Benchmark ready query with dependencies.
 fn bench_ready_query(c: &mut Criterion) {
     init_bench_logging();
@@ -550,7 +600,7 @@ fn bench_import(c: &mut Criterion) {
     let mut group = c.benchmark_group(group_name);
     configure_group(&mut group);
 
-    for size in [100, 500, 1000, 2000, 5000] {
+    for size in [100, 500, 1000, 2000, 5000, 50_000] {
         // Create source data
         let (_src_dir, src_storage) = setup_db_with_issues(size);
         let mut buffer = Cursor::new(Vec::new());
@@ -1006,6 +1056,7 @@ criterion_group!(
     bench_delete_issue,
     bench_list_issues,
     bench_list_issues_filtered,
+    bench_list_with_counts,
     bench_ready_query,
     bench_blocked_query,
     bench_add_dependency,
@@ -0,0 +1,111 @@
+// SharedStore read-heavy workload benchmarks.
+//
+// Run with: cargo bench -p beads-lib --bench shared_store
+//
+// Each iteration performs 4,000 point reads (`get_issue` + `is_blocked`) on a
+// 10k-issue tree, split across reader threads. `with_writer` adds a thread
+// that updates an issue every 100 reads, so readers contend with writes.
+//
+// | Operation                 | Target  | Description                         |
+// |---------------------------|---------|-------------------------------------|
+// | direct (1 thread)         | < 2ms   | `&InMemoryStore`, no lock           |
+// | shared/readers=1          | < 3ms   | Same reads through `SharedStore`    |
+// | shared/readers=4          | < 2ms   | Reads spread over 4 threads         |
+// | shared_with_writer/4      | < 4ms   | 4 readers plus one writer           |
+
+use beads_lib::model::{DependencyType, Issue};
+use beads_lib::{InMemoryStore, IssueUpdate, SharedStore};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+use std::thread;
+
+const SIZE: usize = 10_000;
+const READS: usize = 4_000;
+
+fn issue_id(i: usize) -> String {
+    format!("bench-{i:06}")
+}
+
+/// Binary tree of blocking edges, as in `store_deps`.
+fn setup_tree() -> InMemoryStore {
+    let mut store = InMemoryStore::new();
+    for i in 0..SIZE {
+        let issue = Issue {
+            id: issue_id(i),
+            title: format!("Benchmark issue {i}"),
+            ..Default::default()
+        };
+        store.create_issue(&issue, "benchmark").unwrap();
+    }
+    for i in 1..SIZE {
+        store
+            .add_dependency(
+                &issue_id(i),
+                &issue_id((i - 1) / 2),
+                DependencyType::Blocks,
+                "benchmark",
+                None,
+            )
+            .unwrap();
+    }
+    store
+}
+
+fn read_ids() -> Vec<String> {
+    (0..READS).map(|i| issue_id((i * 7_919) % SIZE)).collect()
+}
+
+fn shared_reads(shared: &SharedStore, ids: &[String], readers: usize, writer: bool) {
+    thread::scope(|scope| {
+        for chunk in ids.chunks(ids.len() / readers) {
+            scope.spawn(move || {
+                for id in chunk {
+                    black_box(shared.get_issue(id).unwrap());
+                    black_box(shared.is_blocked(id));
+                }
+            });
+        }
+        if writer {
+            scope.spawn(|| {
+                let update = IssueUpdate {
+                    notes: Some(Some("touched".to_string())),
+                    ..Default::default()
+                };
+                for id in ids.iter().step_by(100) {
+                    shared.update_issue(id, &update, "benchmark").unwrap();
+                }
+            });
+        }
+    });
+}
+
+fn bench_read_heavy(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shared_store/read_heavy");
+    group.throughput(Throughput::Elements(READS as u64));
+    let ids = read_ids();
+
+    let store = setup_tree();
+    group.bench_function("direct", |b| {
+        b.iter(|| {
+            for id in &ids {
+                black_box(store.get_issue(id).unwrap());
+                black_box(store.is_blocked(id));
+            }
+        });
+    });
+
+    let shared = SharedStore::new(store);
+    for readers in [1, 4] {
+        group.bench_with_input(BenchmarkId::new("shared", readers), &readers, |b, &n| {
+            b.iter(|| shared_reads(&shared, &ids, n, false));
+        });
+    }
+    group.bench_with_input(BenchmarkId::new("shared_with_writer", 4), &4, |b, &n| {
+        b.iter(|| shared_reads(&shared, &ids, n, true));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_read_heavy);
+criterion_main!(benches);
@@ -0,0 +1,118 @@
+// InMemoryStore dependency query benchmarks.
+//
+// Run with: cargo bench -p beads-lib
+//
+// Dependency queries use forward/reverse adjacency maps, so per-issue calls
+// cost O(out-degree) or O(in-degree) and should stay flat as the graph grows.
+//
+// | Operation           | Target    | Description                         |
+// |---------------------|-----------|-------------------------------------|
+// | is_blocked          | < 1µs     | Single issue, any graph size        |
+// | get_dependents      | < 2µs     | Single issue, any graph size        |
+// | would_create_cycle  | < 20µs    | Tree of depth log2(n)               |
+// | blocked list (10k)  | < 5ms     | `get_blocked_issues`, 10k issues    |
+// | list w/ counts (10k)| < 10ms    | `list_issues_with_counts`, 10k      |
+
+use beads_lib::model::{DependencyType, Issue};
+use beads_lib::{InMemoryStore, ListFilters};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+
+fn issue_id(i: usize) -> String {
+    format!("bench-{i:06}")
+}
+
+/// Build a store where issue `i` is blocked by its parent `(i - 1) / 2`,
+/// giving a binary tree with `size - 1` blocking edges.
+fn setup_tree(size: usize) -> InMemoryStore {
+    let mut store = InMemoryStore::new();
+    for i in 0..size {
+        let issue = Issue {
+            id: issue_id(i),
+            title: format!("Benchmark issue {i}"),
+            ..Default::default()
+        };
+        store.create_issue(&issue, "benchmark").unwrap();
+    }
+    for i in 1..size {
+        store
+            .add_dependency(
+                &issue_id(i),
+                &issue_id((i - 1) / 2),
+                DependencyType::Blocks,
+                "benchmark",
+                None,
+            )
+            .unwrap();
+    }
+    store
+}
+
+fn bench_dependency_queries(c: &mut Criterion) {
+    let mut group = c.benchmark_group("store/dependencies");
+
+    for size in [1_000, 10_000, 50_000] {
+        let store = setup_tree(size);
+        let root = issue_id(0);
+        let leaf = issue_id(size - 1);
+        let mid = issue_id(size / 4);
+
+        group.bench_with_input(BenchmarkId::new("is_blocked", size), &store, |b, store| {
+            b.iter(|| black_box(store.is_blocked(black_box(&leaf))));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("get_dependents", size),
+            &store,
+            |b, store| {
+                b.iter(|| black_box(store.get_dependents(black_box(&mid)).len()));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("would_create_cycle", size),
+            &store,
+            |b, store| {
+                // Root -> leaf closes a loop through every ancestor of the leaf
+                b.iter(|| black_box(store.would_create_cycle(black_box(&root), &leaf)));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_blocked_list(c: &mut Criterion) {
+    let mut group = c.benchmark_group("store/blocked_list");
+
+    for size in [1_000, 10_000] {
+        let store = setup_tree(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &store, |b, store| {
+            b.iter(|| black_box(store.get_blocked_issues().len()));
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_list_with_counts(c: &mut Criterion) {
+    let mut group = c.benchmark_group("store/list_with_counts");
+
+    for size in [1_000, 10_000] {
+        let store = setup_tree(size);
+        let filters = ListFilters::default();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &store, |b, store| {
+            b.iter(|| black_box(store.list_issues_with_counts(black_box(&filters)).len()));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_dependency_queries,
+    bench_blocked_list,
+    bench_list_with_counts
+);
+criterion_main!(benches);
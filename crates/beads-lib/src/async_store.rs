@@ -0,0 +1,258 @@
+//! Async facade over [`SharedStore`] for tokio runtimes.
+//!
+//! Loading and saving run on tokio's blocking pool (`spawn_blocking`), so a
+//! large JSONL file never stalls the executor. Store operations run there
+//! too: they are quick, but a write can wait for the lock while a save or a
+//! long query holds it. Enabled by the `async` feature.
+
+use std::path::{Path, PathBuf};
+
+use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+use crate::persistence::Persistence;
+use crate::query::{IssueUpdate, ListFilters, LoadFilters, ReadyFilters, ReadySortPolicy};
+use crate::shared::SharedStore;
+use crate::store::InMemoryStore;
+
+/// Cloneable async handle to one [`InMemoryStore`].
+#[derive(Clone)]
+pub struct AsyncStore {
+    shared: SharedStore,
+}
+
+/// Run `f` on the blocking pool. A panic in `f` is resumed in the caller.
+async fn blocking<R: Send + 'static>(f: impl FnOnce() -> R + Send + 'static) -> Result<R> {
+    match tokio::task::spawn_blocking(f).await {
+        Ok(value) => Ok(value),
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(err) => Err(BeadsError::Storage(format!("Blocking task failed: {err}"))),
+    }
+}
+
+impl AsyncStore {
+    #[must_use]
+    pub fn new(store: InMemoryStore) -> Self {
+        Self {
+            shared: SharedStore::new(store),
+        }
+    }
+
+    /// Load a JSONL file; see [`InMemoryStore::open`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        blocking(move || InMemoryStore::open(path))
+            .await?
+            .map(Self::new)
+    }
+
+    /// Load the issues `filters` keeps; see [`InMemoryStore::open_streaming`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub async fn open_streaming(path: impl AsRef<Path>, filters: LoadFilters) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        blocking(move || InMemoryStore::open_streaming(path, &filters))
+            .await?
+            .map(Self::new)
+    }
+
+    /// Load from a custom backend; see [`InMemoryStore::with_persistence`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the backend's error if loading fails.
+    pub async fn with_persistence(persistence: Box<dyn Persistence>) -> Result<Self> {
+        blocking(move || InMemoryStore::with_persistence(persistence))
+            .await?
+            .map(Self::new)
+    }
+
+    /// The synchronous handle, for callers already off the executor.
+    #[must_use]
+    pub const fn shared(&self) -> &SharedStore {
+        &self.shared
+    }
+
+    /// Run `f` with shared access to the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Storage` if the blocking task was cancelled.
+    pub async fn read<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&InMemoryStore) -> R + Send + 'static,
+    ) -> Result<R> {
+        let shared = self.shared.clone();
+        blocking(move || shared.read(f)).await
+    }
+
+    /// Run `f` with exclusive access to the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Storage` if the blocking task was cancelled.
+    pub async fn write<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut InMemoryStore) -> R + Send + 'static,
+    ) -> Result<R> {
+        let shared = self.shared.clone();
+        blocking(move || shared.write(f)).await
+    }
+
+    /// Get a copy of one issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IssueNotFound` if the ID doesn't exist.
+    pub async fn get_issue(&self, id: impl Into<String>) -> Result<Issue> {
+        let id = id.into();
+        self.read(move |store| store.get_issue(&id).cloned())
+            .await?
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Storage` if the blocking task was cancelled.
+    pub async fn list_issues(&self, filters: ListFilters) -> Result<Vec<Issue>> {
+        self.read(move |store| store.list_issues(&filters).into_iter().cloned().collect())
+            .await
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Storage` if the blocking task was cancelled.
+    pub async fn get_ready_issues(
+        &self,
+        filters: ReadyFilters,
+        sort_policy: ReadySortPolicy,
+    ) -> Result<Vec<Issue>> {
+        self.read(move |store| {
+            store
+                .get_ready_issues(&filters, sort_policy)
+                .into_iter()
+                .cloned()
+                .collect()
+        })
+        .await
+    }
+
+    /// See [`InMemoryStore::create_issue`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `IdCollision` or `Validation` as the store does.
+    pub async fn create_issue(&self, issue: Issue, actor: impl Into<String>) -> Result<Issue> {
+        let actor = actor.into();
+        self.write(move |store| store.create_issue(&issue, &actor))
+            .await?
+    }
+
+    /// See [`InMemoryStore::update_issue`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `IssueNotFound` or `Validation` as the store does.
+    pub async fn update_issue(
+        &self,
+        id: impl Into<String>,
+        update: IssueUpdate,
+        actor: impl Into<String>,
+    ) -> Result<Issue> {
+        let (id, actor) = (id.into(), actor.into());
+        self.write(move |store| store.update_issue(&id, &update, &actor))
+            .await?
+    }
+
+    /// Save through the store's persistence, off the executor.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`InMemoryStore::save`].
+    pub async fn save(&self) -> Result<()> {
+        let shared = self.shared.clone();
+        blocking(move || shared.save()).await?
+    }
+
+    /// Save to a specific JSONL file, off the executor.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Io` on write failure.
+    pub async fn save_to(&self, path: impl Into<PathBuf>) -> Result<()> {
+        let path = path.into();
+        self.read(move |store| store.save_to(path)).await?
+    }
+}
+
+impl From<InMemoryStore> for AsyncStore {
+    fn from(store: InMemoryStore) -> Self {
+        Self::new(store)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn test_async_open_mutate_save() {
+        block_on(open_mutate_save());
+    }
+
+    async fn open_mutate_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("issues.jsonl");
+        InMemoryStore::new().save_to(&path).unwrap();
+
+        let store = AsyncStore::open(&path).await.unwrap();
+        let created = store
+            .create_issue(
+                Issue {
+                    title: "Async".to_string(),
+                    ..Issue::default()
+                },
+                "agent",
+            )
+            .await
+            .unwrap();
+        let update = IssueUpdate {
+            notes: Some(Some("from a task".to_string())),
+            ..IssueUpdate::default()
+        };
+        store
+            .update_issue(created.id.clone(), update, "agent")
+            .await
+            .unwrap();
+        store.save().await.unwrap();
+
+        let reloaded = AsyncStore::open(&path).await.unwrap();
+        let issue = reloaded.get_issue(created.id.clone()).await.unwrap();
+        assert_eq!(issue.notes.as_deref(), Some("from a task"));
+        let ready = reloaded
+            .get_ready_issues(ReadyFilters::default(), ReadySortPolicy::default())
+            .await
+            .unwrap();
+        assert_eq!(ready.len(), 1);
+        assert!(matches!(
+            reloaded.get_issue("bd-missing").await,
+            Err(BeadsError::IssueNotFound { .. })
+        ));
+        assert!(
+            AsyncStore::open(dir.path().join("absent.jsonl"))
+                .await
+                .is_err()
+        );
+    }
+}
@@ -2,9 +2,17 @@
 //!
 //! Each line in the JSONL file is a complete Issue with embedded
 //! labels, dependencies, and comments.
+//!
+//! File functions need the `fs` feature (on by default). [`parse_str`],
+//! [`parse_reader`] and [`records_to_string`] work on in-memory data, e.g.
+//! a blob fetched by a browser.
 
+#[cfg(feature = "fs")]
 use std::fs;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
+#[cfg(feature = "fs")]
+use std::io::BufReader;
+#[cfg(feature = "fs")]
 use std::path::Path;
 
 use crate::error::{BeadsError, Result};
@@ -18,16 +26,66 @@ pub struct LoadedData {
     pub comments: Vec<(String, Vec<Comment>)>,
 }
 
-/// Load issues from a JSONL file.
+/// Iterator over the issues of JSONL data, parsed one line at a time.
 ///
-/// Each line is parsed as a complete `Issue`. Embedded labels,
-/// dependencies, and comments are extracted into separate collections
-/// while the bare issue keeps empty relation vectors.
+/// Each item is a complete `Issue` with its embedded relations. Blank lines
+/// are skipped. Created by [`load_iter`], [`parse_reader`] or [`parse_str`].
+pub struct IssueIter<R> {
+    lines: std::io::Lines<R>,
+    line_num: usize,
+}
+
+impl<R: BufRead> Iterator for IssueIter<R> {
+    type Item = Result<Issue>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.line_num += 1;
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            return Some(
+                serde_json::from_str(trimmed).map_err(|e| BeadsError::JsonlParse {
+                    line: self.line_num,
+                    reason: e.to_string(),
+                }),
+            );
+        }
+    }
+}
+
+/// Parse JSONL from any buffered reader, one line at a time.
+///
+/// Parse errors are reported per item as `JsonlParse`.
+pub fn parse_reader<R: BufRead>(reader: R) -> IssueIter<R> {
+    IssueIter {
+        lines: reader.lines(),
+        line_num: 0,
+    }
+}
+
+/// Parse JSONL held in memory.
+#[must_use]
+pub fn parse_str(data: &str) -> IssueIter<&[u8]> {
+    parse_reader(data.as_bytes())
+}
+
+/// Open a JSONL file for streaming.
+///
+/// Unlike [`load`], only one line is held in memory at a time, so callers can
+/// filter large archives while reading them.
 ///
 /// # Errors
 ///
-/// Returns `Io` if the file cannot be read, or `JsonlParse` if any line is invalid.
-pub fn load(path: &Path) -> Result<LoadedData> {
+/// Returns `FileNotFound` or `Io` if the file cannot be opened. Parse errors
+/// are reported per item as `JsonlParse`.
+#[cfg(feature = "fs")]
+pub fn load_iter(path: &Path) -> Result<IssueIter<BufReader<fs::File>>> {
     let file = fs::File::open(path).map_err(|e| {
         if e.kind() == std::io::ErrorKind::NotFound {
             BeadsError::FileNotFound(path.to_path_buf())
@@ -35,25 +93,27 @@ pub fn load(path: &Path) -> Result<LoadedData> {
             BeadsError::Io(e)
         }
     })?;
-    let reader = BufReader::new(file);
+    Ok(parse_reader(BufReader::new(file)))
+}
 
+/// Load issues from a JSONL file.
+///
+/// Each line is parsed as a complete `Issue`. Embedded labels,
+/// dependencies, and comments are extracted into separate collections
+/// while the bare issue keeps empty relation vectors.
+///
+/// # Errors
+///
+/// Returns `Io` if the file cannot be read, or `JsonlParse` if any line is invalid.
+#[cfg(feature = "fs")]
+pub fn load(path: &Path) -> Result<LoadedData> {
     let mut issues = Vec::new();
     let mut all_labels = Vec::new();
     let mut all_dependencies = Vec::new();
     let mut all_comments = Vec::new();
 
-    for (line_num, line) in reader.lines().enumerate() {
-        let line = line?;
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
-
-        let mut issue: Issue =
-            serde_json::from_str(trimmed).map_err(|e| BeadsError::JsonlParse {
-                line: line_num + 1,
-                reason: e.to_string(),
-            })?;
+    for issue in load_iter(path)? {
+        let mut issue = issue?;
 
         // Extract embedded relations
         if !issue.labels.is_empty() {
@@ -94,18 +154,42 @@ fn check_issue_json_roundtrip(json: &str, line: usize) -> Result<()> {
     Ok(())
 }
 
+/// Put an issue's embedded relations in canonical order.
+///
+/// Labels are sorted and deduplicated, dependencies are ordered by
+/// `(issue_id, depends_on_id, type)` and comments by `(created_at, id)`.
+/// Field order comes from the `Issue` struct and timestamps always
+/// serialize as UTC RFC 3339 (`...Z`), so the same logical issue always
+/// produces the same line.
+fn canonicalize(issue: &mut Issue) {
+    issue.labels.sort();
+    issue.labels.dedup();
+    issue.dependencies.sort_by(|a, b| {
+        (&a.issue_id, &a.depends_on_id, a.dep_type.as_str()).cmp(&(
+            &b.issue_id,
+            &b.depends_on_id,
+            b.dep_type.as_str(),
+        ))
+    });
+    issue.comments.sort_by_key(|c| (c.created_at, c.id));
+}
+
 /// Save issues to a JSONL file with atomic write.
 ///
 /// Each issue is serialized with its labels, dependencies, and comments
-/// re-embedded. Each line is checked by parsing back to an `Issue` (round-trip
-/// parse only; no domain validation). If the round-trip check fails, no file is
-/// written. Uses write-to-temp + rename for atomicity.
+/// re-embedded. Output is canonical: lines are sorted by issue ID and
+/// relations are put in a stable order, so identical logical state always
+/// produces a byte-identical file. Each line is checked by parsing back to
+/// an `Issue` (round-trip parse only; no domain validation). If the
+/// round-trip check fails, no file is written. Uses write-to-temp + rename
+/// for atomicity.
 ///
 /// # Errors
 ///
 /// * [`BeadsError::Json`] if serialization fails
 /// * [`BeadsError::JsonlWriteRoundtripFailed`] if a serialized line does not parse back as `Issue`
 /// * [`BeadsError::Io`] if the file cannot be written
+#[cfg(feature = "fs")]
 pub fn save(
     path: &Path,
     issues: &[Issue],
@@ -113,6 +197,58 @@ pub fn save(
     dependencies: &[Dependency],
     comments: &[(String, Vec<Comment>)],
 ) -> Result<()> {
+    save_records(path, &records(issues, labels, dependencies, comments))
+}
+
+/// Write complete issue records (relations already embedded), one per line,
+/// in the given order. Same checks and atomic write as [`save`].
+///
+/// # Errors
+///
+/// * [`BeadsError::Json`] if serialization fails
+/// * [`BeadsError::JsonlWriteRoundtripFailed`] if a serialized line does not parse back as `Issue`
+/// * [`BeadsError::Io`] if the file cannot be written
+#[cfg(feature = "fs")]
+pub fn save_records(path: &Path, records: &[Issue]) -> Result<()> {
+    validate_then_write(path, &serialize(records)?)
+}
+
+/// Complete issue records as JSONL text, one line each, with the same
+/// round-trip check as [`save`].
+///
+/// # Errors
+///
+/// * [`BeadsError::Json`] if serialization fails
+/// * [`BeadsError::JsonlWriteRoundtripFailed`] if a serialized line does not parse back as `Issue`
+pub fn records_to_string(records: &[Issue]) -> Result<String> {
+    let lines = serialize(records)?;
+    check_lines(&lines)?;
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&line);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn serialize(records: &[Issue]) -> Result<Vec<String>> {
+    let mut lines = Vec::with_capacity(records.len());
+    for record in records {
+        lines.push(serde_json::to_string(record)?);
+    }
+    Ok(lines)
+}
+
+/// Re-embed labels, dependencies, and comments into their issues.
+///
+/// Records are sorted by issue ID and canonicalized, as written by [`save`].
+#[must_use]
+pub fn records(
+    issues: &[Issue],
+    labels: &[(String, Vec<String>)],
+    dependencies: &[Dependency],
+    comments: &[(String, Vec<Comment>)],
+) -> Vec<Issue> {
     use std::collections::HashMap;
 
     // Build lookup maps for reassembly
@@ -134,9 +270,11 @@ pub fn save(
         }
     }
 
-    // Serialize and round-trip check every line before touching the filesystem.
-    let mut lines = Vec::with_capacity(issues.len());
-    for issue in issues {
+    let mut ordered: Vec<&Issue> = issues.iter().collect();
+    ordered.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut records = Vec::with_capacity(issues.len());
+    for issue in ordered {
         let mut full_issue = issue.clone();
         if let Some(labels) = label_map.get(issue.id.as_str()) {
             full_issue.labels.clone_from(labels);
@@ -147,23 +285,28 @@ pub fn save(
         if let Some(comms) = comment_map.get(issue.id.as_str()) {
             full_issue.comments.clone_from(comms);
         }
-
-        let json = serde_json::to_string(&full_issue)?;
-        lines.push(json);
+        canonicalize(&mut full_issue);
+        records.push(full_issue);
     }
-
-    validate_then_write(path, &lines)
+    records
 }
 
 /// Round-trip parse check on every line, then writes to path. No file I/O until all pass.
+#[cfg(feature = "fs")]
 fn validate_then_write(path: &Path, lines: &[String]) -> Result<()> {
+    check_lines(lines)?;
+    write_validated_lines(path, lines)
+}
+
+fn check_lines(lines: &[String]) -> Result<()> {
     for (idx, json) in lines.iter().enumerate() {
         check_issue_json_roundtrip(json, idx + 1)?;
     }
-    write_validated_lines(path, lines)
+    Ok(())
 }
 
 /// Writes pre-checked JSONL lines to path (temp file + atomic rename).
+#[cfg(feature = "fs")]
 fn write_validated_lines(path: &Path, lines: &[String]) -> Result<()> {
     use std::io::Write;
 
@@ -181,6 +324,7 @@ fn write_validated_lines(path: &Path, lines: &[String]) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "fs")]
     use crate::model::{Comment, Dependency, DependencyType, Priority, Status};
     use chrono::Utc;
 
@@ -197,6 +341,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fs")]
     fn test_roundtrip() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("issues.jsonl");
@@ -226,12 +371,14 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fs")]
     fn test_load_missing_file() {
         let result = load(Path::new("/nonexistent/issues.jsonl"));
         assert!(result.is_err());
     }
 
     #[test]
+    #[cfg(feature = "fs")]
     fn test_load_empty_file() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("empty.jsonl");
@@ -242,6 +389,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fs")]
     fn test_load_skips_blank_lines() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("blanks.jsonl");
@@ -260,6 +408,24 @@ mod tests {
         assert_eq!(loaded.issues.len(), 1);
     }
 
+    #[test]
+    #[cfg(feature = "fs")]
+    fn test_load_iter_yields_issues_then_reports_bad_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stream.jsonl");
+        let json = minimal_issue_json();
+        fs::write(&path, format!("{json}\n\n{{not json\n{json}\n")).unwrap();
+
+        let mut iter = load_iter(&path).unwrap();
+        assert_eq!(iter.next().unwrap().unwrap().id, "bd-abc");
+        match iter.next().unwrap() {
+            Err(BeadsError::JsonlParse { line, .. }) => assert_eq!(line, 3),
+            other => panic!("expected parse error, got {other:?}"),
+        }
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().is_none());
+    }
+
     // --- check_issue_json_roundtrip (round-trip parse) tests ---
 
     #[test]
@@ -326,9 +492,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_str_and_records_to_string_roundtrip() {
+        let data = format!("{}\n\n{}\n", minimal_issue_json(), minimal_issue_json());
+        let issues: Vec<Issue> = parse_str(&data).collect::<Result<_>>().unwrap();
+        assert_eq!(issues.len(), 2);
+
+        let text = records_to_string(&issues[..1]).unwrap();
+        assert_eq!(text.lines().count(), 1);
+        let reparsed: Vec<Issue> = parse_str(&text).collect::<Result<_>>().unwrap();
+        assert_eq!(reparsed[0].id, "bd-abc");
+
+        let err = parse_str("{not json\n").next().unwrap().unwrap_err();
+        assert!(matches!(err, BeadsError::JsonlParse { line: 1, .. }));
+    }
+
     // --- validate_then_write: no file when validation fails ---
 
     #[test]
+    #[cfg(feature = "fs")]
     fn test_validate_then_write_rejects_invalid_line_and_does_not_create_file() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("out.jsonl");
@@ -347,6 +529,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fs")]
     fn test_validate_then_write_with_valid_lines_creates_file() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("out.jsonl");
@@ -361,6 +544,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fs")]
     fn test_validate_then_write_first_line_invalid_no_file() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("out.jsonl");
@@ -375,6 +559,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fs")]
     fn test_save_multiple_issues_validates_all_lines() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("multi.jsonl");
@@ -415,6 +600,80 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fs")]
+    fn test_save_is_canonical_and_roundtrip_stable() {
+        use chrono::TimeZone;
+
+        let dir = tempfile::tempdir().unwrap();
+        let t0 = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+        let issue = |id: &str| Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            created_at: t0,
+            updated_at: t0,
+            ..Default::default()
+        };
+        let dep = |from: &str, to: &str| Dependency {
+            issue_id: from.to_string(),
+            depends_on_id: to.to_string(),
+            dep_type: DependencyType::Blocks,
+            created_at: t0,
+            created_by: None,
+            metadata: None,
+            thread_id: None,
+        };
+        let comment = |id: i64, minutes: i64| Comment {
+            id,
+            issue_id: "bd-a".to_string(),
+            author: "user".to_string(),
+            body: format!("comment {id}"),
+            created_at: t0 + chrono::Duration::minutes(minutes),
+            updated_at: None,
+            deleted: false,
+        };
+
+        let first = dir.path().join("first.jsonl");
+        save(
+            &first,
+            &[issue("bd-a"), issue("bd-b"), issue("bd-c")],
+            &[("bd-a".to_string(), vec!["z".to_string(), "a".to_string()])],
+            &[dep("bd-a", "bd-c"), dep("bd-a", "bd-b")],
+            &[("bd-a".to_string(), vec![comment(2, 5), comment(1, 1)])],
+        )
+        .unwrap();
+
+        // Same logical state, supplied in a different order
+        let second = dir.path().join("second.jsonl");
+        save(
+            &second,
+            &[issue("bd-c"), issue("bd-a"), issue("bd-b")],
+            &[(
+                "bd-a".to_string(),
+                vec!["a".to_string(), "z".to_string(), "a".to_string()],
+            )],
+            &[dep("bd-a", "bd-b"), dep("bd-a", "bd-c")],
+            &[("bd-a".to_string(), vec![comment(1, 1), comment(2, 5)])],
+        )
+        .unwrap();
+        let bytes = fs::read(&first).unwrap();
+        assert_eq!(bytes, fs::read(&second).unwrap());
+
+        // Load and save again without changes: byte-identical
+        let loaded = load(&first).unwrap();
+        let third = dir.path().join("third.jsonl");
+        save(
+            &third,
+            &loaded.issues,
+            &loaded.labels,
+            &loaded.dependencies,
+            &loaded.comments,
+        )
+        .unwrap();
+        assert_eq!(bytes, fs::read(&third).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "fs")]
     fn test_roundtrip_preserves_target_dependencies() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("deps.jsonl");
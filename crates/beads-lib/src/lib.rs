@@ -3,17 +3,26 @@
 //! Provides a standalone, SQLite-free API for managing beads issues.
 //! Data is stored in memory and persisted via JSONL files.
 //!
+//! # Features
+//!
+//! * `fs` (default): file IO (`InMemoryStore::open`, `save_to`, `JsonlFile`).
+//!   Without it the crate compiles to `wasm32-unknown-unknown`; load a blob
+//!   with `InMemoryStore::from_jsonl_str` and export with `to_jsonl_string`.
+//! * `async`: `AsyncStore` for tokio.
+//!
 //! # Quick Start
 //!
 //! ```no_run
 //! use beads_lib::{InMemoryStore, IssueUpdate, Status};
 //! use beads_lib::model::Issue;
 //!
+//! # #[cfg(feature = "fs")] {
 //! // Load existing file
 //! let mut store = InMemoryStore::open("path/to/.beads/issues.jsonl").unwrap();
 //!
 //! // Query
 //! let ready = store.get_ready_issues(&Default::default(), Default::default());
+//! let bugs = store.query().status(Status::Open).label("bug").sort_priority().limit(10).run();
 //!
 //! // Create
 //! store.create_issue(&Issue { title: "New task".into(), ..Default::default() }, "agent").unwrap();
@@ -23,16 +32,32 @@
 //!
 //! // Save back
 //! store.save().unwrap();
+//! # }
 //! ```
 
+#[cfg(feature = "async")]
+pub mod async_store;
 pub mod error;
 pub mod jsonl;
 pub mod model;
+pub mod persistence;
 pub mod query;
+pub mod shared;
+pub mod snapshot;
 pub mod store;
 pub mod util;
 
+#[cfg(feature = "async")]
+pub use async_store::AsyncStore;
 pub use error::{BeadsError, Result};
-pub use model::{Comment, Dependency, Event, Issue, Status};
-pub use query::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy};
-pub use store::InMemoryStore;
+pub use model::{CodeRef, Comment, Dependency, Event, Issue, Reaction, Status};
+#[cfg(feature = "fs")]
+pub use persistence::JsonlFile;
+pub use persistence::{AutosavePolicy, MemoryOnly, Persistence};
+pub use query::{
+    IssueQuery, IssueUpdate, ListFilters, LoadFilters, ReadyFilters, ReadySortPolicy, SearchField,
+    SearchQuery, TextMatcher,
+};
+pub use shared::SharedStore;
+pub use snapshot::Snapshot;
+pub use store::{InMemoryStore, SaveOnDrop};
@@ -4,6 +4,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::str::FromStr;
 
@@ -258,6 +259,119 @@ impl FromStr for DependencyType {
     }
 }
 
+/// When a `conditional-blocks` dependency blocks, evaluated on the blocker.
+///
+/// Stored under the `condition` key of the dependency's metadata, e.g.
+/// `{"condition":"label:release-blocker"}`. A conditional dependency without
+/// a condition blocks like `blocks`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyCondition {
+    /// `label:<name>`: the blocker has this label
+    Label(String),
+    /// `type:<type>`: the blocker has this issue type
+    Type(String),
+    /// `priority:<n>`: the blocker's priority is `n` or more urgent
+    Priority(i32),
+}
+
+impl DependencyCondition {
+    const METADATA_KEY: &'static str = "condition";
+
+    /// The condition in a dependency's metadata, if it has a valid one.
+    #[must_use]
+    pub fn from_metadata(metadata: Option<&str>) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(metadata?).ok()?;
+        value.get(Self::METADATA_KEY)?.as_str()?.parse().ok()
+    }
+
+    /// Dependency metadata holding this condition.
+    #[must_use]
+    pub fn to_metadata(&self) -> String {
+        serde_json::json!({ Self::METADATA_KEY: self.to_string() }).to_string()
+    }
+
+    /// Whether the condition holds for a blocker with these properties.
+    #[must_use]
+    pub fn matches(&self, issue_type: &str, priority: i32, labels: &[String]) -> bool {
+        match self {
+            Self::Label(label) => labels.iter().any(|l| l == label),
+            Self::Type(wanted) => issue_type.eq_ignore_ascii_case(wanted),
+            Self::Priority(max) => priority <= *max,
+        }
+    }
+}
+
+impl fmt::Display for DependencyCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Label(label) => write!(f, "label:{label}"),
+            Self::Type(issue_type) => write!(f, "type:{issue_type}"),
+            Self::Priority(priority) => write!(f, "priority:{priority}"),
+        }
+    }
+}
+
+impl FromStr for DependencyCondition {
+    type Err = crate::error::BeadsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            crate::error::BeadsError::validation(
+                "condition",
+                format!("expected label:<name>, type:<type> or priority:<0-4>, got '{s}'"),
+            )
+        };
+        let (kind, value) = s.split_once(':').ok_or_else(invalid)?;
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(invalid());
+        }
+        match kind.trim().to_lowercase().as_str() {
+            "label" => Ok(Self::Label(value.to_string())),
+            "type" => Ok(Self::Type(value.to_lowercase())),
+            "priority" => value
+                .parse::<Priority>()
+                .map(|priority| Self::Priority(priority.0))
+                .map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Wake time of a `waits-for` dependency.
+///
+/// Stored under the `until` key of the dependency's metadata, e.g.
+/// `{"until":"2025-07-01T09:00:00Z"}`. Once the time has passed the edge no
+/// longer blocks, even if the issue it waits for is still open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WaitUntil(pub DateTime<Utc>);
+
+impl WaitUntil {
+    const METADATA_KEY: &'static str = "until";
+
+    /// The wake time in a dependency's metadata, if it has a valid one.
+    #[must_use]
+    pub fn from_metadata(metadata: Option<&str>) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(metadata?).ok()?;
+        let until = value.get(Self::METADATA_KEY)?.as_str()?;
+        DateTime::parse_from_rfc3339(until)
+            .ok()
+            .map(|until| Self(until.with_timezone(&Utc)))
+    }
+
+    /// Dependency metadata holding this wake time.
+    #[must_use]
+    pub fn to_metadata(self) -> String {
+        serde_json::json!({ Self::METADATA_KEY: self.0.to_rfc3339() }).to_string()
+    }
+
+    /// Whether the wake time is at or before `now`.
+    #[must_use]
+    pub fn has_passed(self, now: DateTime<Utc>) -> bool {
+        self.0 <= now
+    }
+}
+
 /// Audit event type.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EventType {
@@ -474,6 +588,15 @@ pub struct Issue {
     pub dependencies: Vec<Dependency>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub comments: Vec<Comment>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub code_refs: Vec<CodeRef>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub watchers: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub reactions: Vec<Reaction>,
+    /// Custom field values by name
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub fields: BTreeMap<String, String>,
 }
 
 impl Default for Issue {
@@ -518,6 +641,10 @@ impl Default for Issue {
             labels: Vec::new(),
             dependencies: Vec::new(),
             comments: Vec::new(),
+            code_refs: Vec::new(),
+            watchers: Vec::new(),
+            reactions: Vec::new(),
+            fields: BTreeMap::new(),
         }
     }
 }
@@ -559,6 +686,18 @@ pub struct Dependency {
     pub thread_id: Option<String>,
 }
 
+/// A source location an issue refers to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CodeRef {
+    pub path: String,
+    /// 1-based line number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// Commit (or other git revision) the reference is pinned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+}
+
 /// A comment on an issue.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Comment {
@@ -568,6 +707,24 @@ pub struct Comment {
     #[serde(rename = "text")]
     pub body: String,
     pub created_at: DateTime<Utc>,
+    /// When the comment was last edited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Soft-deleted comments are kept as tombstones so deletions sync.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub deleted: bool,
+}
+
+/// A reaction (emoji or short tag) left on an issue or one of its comments.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Reaction {
+    pub issue_id: String,
+    /// Comment the reaction is attached to; `None` for the issue itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<i64>,
+    pub reaction: String,
+    pub actor: String,
+    pub created_at: DateTime<Utc>,
 }
 
 /// An event in the issue's history (audit log).
@@ -653,6 +810,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dependency_condition_parse_and_metadata() {
+        let condition = DependencyCondition::from_str("label:release-blocker").unwrap();
+        assert!(condition.matches("task", 2, &["release-blocker".to_string()]));
+        assert!(!condition.matches("task", 2, &[]));
+        assert_eq!(
+            DependencyCondition::from_metadata(Some(&condition.to_metadata())),
+            Some(condition)
+        );
+        assert!(DependencyCondition::from_str("priority:9").is_err());
+        assert_eq!(DependencyCondition::from_metadata(Some("{}")), None);
+    }
+
     #[test]
     fn comment_json_round_trip() {
         let t = fixed_ts();
@@ -662,6 +832,8 @@ mod tests {
             author: "alice".to_string(),
             body: "hello".to_string(),
             created_at: t,
+            updated_at: Some(t),
+            deleted: true,
         };
         let json = serde_json::to_string(&original).unwrap();
         let back: Comment = serde_json::from_str(&json).unwrap();
@@ -705,6 +877,27 @@ mod tests {
         assert_eq!(back.updated_at, original.updated_at);
         assert_eq!(back.status, Status::Open);
         assert!(back.content_hash.is_none());
+        assert!(!json.contains("code_refs"));
+        assert!(!json.contains("watchers"));
+        assert!(!json.contains("fields"));
+    }
+
+    #[test]
+    fn issue_code_refs_round_trip() {
+        let original = Issue {
+            id: "bd-abc".to_string(),
+            title: "Title".to_string(),
+            code_refs: vec![CodeRef {
+                path: "src/lib.rs".to_string(),
+                line: Some(7),
+                commit: None,
+            }],
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&original).unwrap();
+        assert!(json.contains(r#""code_refs":[{"path":"src/lib.rs","line":7}]"#));
+        let back: Issue = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.code_refs, original.code_refs);
     }
 
     #[test]
@@ -0,0 +1,94 @@
+//! Pluggable persistence for [`InMemoryStore`](crate::InMemoryStore).
+//!
+//! A backend loads and saves complete issue records: each `Issue` carries
+//! its labels, dependencies, and comments embedded, exactly like one JSONL
+//! line. The store keeps doing all querying and graph work in memory, so a
+//! backend only has to move those records to and from its own storage.
+
+#[cfg(feature = "fs")]
+use std::path::{Path, PathBuf};
+
+use crate::error::Result;
+#[cfg(feature = "fs")]
+use crate::jsonl;
+use crate::model::Issue;
+
+/// Where an [`InMemoryStore`](crate::InMemoryStore) loads from and saves to.
+///
+/// Backends are `Send + Sync` so the store can be shared across threads.
+pub trait Persistence: Send + Sync {
+    /// Load every issue record.
+    ///
+    /// A dependency may be embedded in both of its issues' records; the
+    /// store keeps one copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backing storage cannot be read.
+    fn load(&self) -> Result<Vec<Issue>>;
+
+    /// Replace the stored records with `records`, sorted by issue ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backing storage cannot be written.
+    fn save(&self, records: &[Issue]) -> Result<()>;
+}
+
+/// A JSONL file, one issue per line (what `InMemoryStore::open` uses).
+#[cfg(feature = "fs")]
+#[derive(Debug, Clone)]
+pub struct JsonlFile {
+    path: PathBuf,
+}
+
+#[cfg(feature = "fs")]
+impl JsonlFile {
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The file's path.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(feature = "fs")]
+impl Persistence for JsonlFile {
+    fn load(&self) -> Result<Vec<Issue>> {
+        jsonl::load_iter(&self.path)?.collect()
+    }
+
+    fn save(&self, records: &[Issue]) -> Result<()> {
+        jsonl::save_records(&self.path, records)
+    }
+}
+
+/// When an [`InMemoryStore`](crate::InMemoryStore) saves on its own.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AutosavePolicy {
+    /// Only when `save()` is called.
+    #[default]
+    Manual,
+    /// After every mutation.
+    OnDirty,
+    /// After every `n` mutations (`0` counts as `1`).
+    EveryNMutations(usize),
+}
+
+/// No persistence: starts empty, and saving is a no-op.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MemoryOnly;
+
+impl Persistence for MemoryOnly {
+    fn load(&self) -> Result<Vec<Issue>> {
+        Ok(Vec::new())
+    }
+
+    fn save(&self, _records: &[Issue]) -> Result<()> {
+        Ok(())
+    }
+}
@@ -1,8 +1,14 @@
 //! Query and filter types for issue operations.
 
+use std::fmt;
+use std::str::FromStr;
+
 use chrono::{DateTime, Utc};
+use regex::{Regex, RegexBuilder};
 
-use crate::model::{IssueType, Priority, Status};
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, IssueType, Priority, Status};
+use crate::store::InMemoryStore;
 
 /// Fields to update on an issue.
 #[derive(Debug, Clone, Default)]
@@ -83,6 +89,181 @@ pub struct ListFilters {
     pub updated_after: Option<DateTime<Utc>>,
 }
 
+/// Fluent builder over [`ListFilters`], started with [`InMemoryStore::query`].
+///
+/// Repeated `status`, `issue_type`, and `priority` calls widen the match
+/// (any of them); repeated `label` calls narrow it (all of them).
+///
+/// ```
+/// use beads_lib::{InMemoryStore, Status};
+///
+/// let store = InMemoryStore::new();
+/// let bugs = store
+///     .query()
+///     .status(Status::Open)
+///     .label("bug")
+///     .sort_priority()
+///     .limit(10)
+///     .run();
+/// assert!(bugs.is_empty());
+/// ```
+#[derive(Clone)]
+#[must_use = "a query does nothing until `run` or `count` is called"]
+pub struct IssueQuery<'a> {
+    store: &'a InMemoryStore,
+    filters: ListFilters,
+}
+
+impl<'a> IssueQuery<'a> {
+    pub(crate) fn new(store: &'a InMemoryStore) -> Self {
+        Self {
+            store,
+            filters: ListFilters::default(),
+        }
+    }
+
+    /// Match issues with this status. Replaces the default of hiding closed
+    /// and deferred issues.
+    pub fn status(mut self, status: Status) -> Self {
+        self.filters
+            .statuses
+            .get_or_insert_with(Vec::new)
+            .push(status);
+        self
+    }
+
+    pub fn issue_type(mut self, issue_type: IssueType) -> Self {
+        self.filters
+            .types
+            .get_or_insert_with(Vec::new)
+            .push(issue_type);
+        self
+    }
+
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.filters
+            .priorities
+            .get_or_insert_with(Vec::new)
+            .push(priority);
+        self
+    }
+
+    pub fn assignee(mut self, assignee: impl Into<String>) -> Self {
+        self.filters.assignee = Some(assignee.into());
+        self
+    }
+
+    pub fn unassigned(mut self) -> Self {
+        self.filters.unassigned = true;
+        self
+    }
+
+    /// Require this label (every `label` must be present).
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.filters
+            .labels
+            .get_or_insert_with(Vec::new)
+            .push(label.into());
+        self
+    }
+
+    /// Require at least one of the `any_label` labels.
+    pub fn any_label(mut self, label: impl Into<String>) -> Self {
+        self.filters
+            .labels_or
+            .get_or_insert_with(Vec::new)
+            .push(label.into());
+        self
+    }
+
+    /// Case-insensitive title substring.
+    pub fn title_contains(mut self, text: impl Into<String>) -> Self {
+        self.filters.title_contains = Some(text.into());
+        self
+    }
+
+    pub fn updated_before(mut self, at: DateTime<Utc>) -> Self {
+        self.filters.updated_before = Some(at);
+        self
+    }
+
+    pub fn updated_after(mut self, at: DateTime<Utc>) -> Self {
+        self.filters.updated_after = Some(at);
+        self
+    }
+
+    pub fn include_closed(mut self) -> Self {
+        self.filters.include_closed = true;
+        self
+    }
+
+    pub fn include_deferred(mut self) -> Self {
+        self.filters.include_deferred = true;
+        self
+    }
+
+    pub fn include_templates(mut self) -> Self {
+        self.filters.include_templates = true;
+        self
+    }
+
+    pub fn sort_priority(self) -> Self {
+        self.sort("priority")
+    }
+
+    pub fn sort_created(self) -> Self {
+        self.sort("created_at")
+    }
+
+    pub fn sort_updated(self) -> Self {
+        self.sort("updated_at")
+    }
+
+    pub fn sort_title(self) -> Self {
+        self.sort("title")
+    }
+
+    fn sort(mut self, field: &str) -> Self {
+        self.filters.sort = Some(field.to_string());
+        self
+    }
+
+    /// Reverse the sort order.
+    pub fn reverse(mut self) -> Self {
+        self.filters.reverse = true;
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.filters.limit = Some(limit);
+        self
+    }
+
+    /// The filters built so far.
+    #[must_use]
+    pub const fn filters(&self) -> &ListFilters {
+        &self.filters
+    }
+
+    /// Take the filters, e.g. to pass to a `SharedStore`.
+    #[must_use]
+    pub fn into_filters(self) -> ListFilters {
+        self.filters
+    }
+
+    /// Run the query; see [`InMemoryStore::list_issues`].
+    #[must_use]
+    pub fn run(&self) -> Vec<&'a Issue> {
+        self.store.list_issues(&self.filters)
+    }
+
+    /// Count matches, ignoring `limit`; see [`InMemoryStore::count_issues`].
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.store.count_issues(&self.filters)
+    }
+}
+
 /// Filter options for ready issues.
 #[derive(Debug, Clone, Default)]
 pub struct ReadyFilters {
@@ -100,6 +281,211 @@ pub struct ReadyFilters {
     pub recursive: bool,
 }
 
+/// Filter options applied while streaming a JSONL file into a store.
+#[derive(Debug, Clone, Default)]
+pub struct LoadFilters {
+    /// Skip tombstoned (deleted) issues.
+    pub skip_tombstones: bool,
+    /// Skip closed issues whose `closed_at` is more than this many days ago.
+    pub closed_within_days: Option<i64>,
+}
+
+impl LoadFilters {
+    /// Whether an issue read at `now` should be loaded.
+    #[must_use]
+    pub fn keeps(&self, issue: &Issue, now: DateTime<Utc>) -> bool {
+        if self.skip_tombstones && issue.status == Status::Tombstone {
+            return false;
+        }
+        match (self.closed_within_days, &issue.status, issue.closed_at) {
+            (Some(days), Status::Closed, Some(closed_at)) => {
+                now.signed_duration_since(closed_at).num_days() <= days
+            }
+            _ => true,
+        }
+    }
+}
+
+/// An issue field a search can be scoped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchField {
+    Id,
+    Title,
+    Description,
+    Design,
+    AcceptanceCriteria,
+    Notes,
+    Comments,
+}
+
+impl SearchField {
+    /// Fields searched when none are given.
+    pub const DEFAULT: [Self; 3] = [Self::Title, Self::Description, Self::Id];
+
+    /// Every searchable field.
+    pub const ALL: [Self; 7] = [
+        Self::Id,
+        Self::Title,
+        Self::Description,
+        Self::Design,
+        Self::AcceptanceCriteria,
+        Self::Notes,
+        Self::Comments,
+    ];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Title => "title",
+            Self::Description => "description",
+            Self::Design => "design",
+            Self::AcceptanceCriteria => "acceptance_criteria",
+            Self::Notes => "notes",
+            Self::Comments => "comments",
+        }
+    }
+
+    /// The field's text on `issue` (`None` for comments, which are stored
+    /// separately, and for unset fields).
+    #[must_use]
+    pub fn text(self, issue: &Issue) -> Option<&str> {
+        match self {
+            Self::Id => Some(&issue.id),
+            Self::Title => Some(&issue.title),
+            Self::Description => issue.description.as_deref(),
+            Self::Design => issue.design.as_deref(),
+            Self::AcceptanceCriteria => issue.acceptance_criteria.as_deref(),
+            Self::Notes => issue.notes.as_deref(),
+            Self::Comments => None,
+        }
+    }
+}
+
+impl fmt::Display for SearchField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SearchField {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().replace('-', "_").as_str() {
+            "id" => Ok(Self::Id),
+            "title" => Ok(Self::Title),
+            "description" | "desc" => Ok(Self::Description),
+            "design" => Ok(Self::Design),
+            "acceptance_criteria" | "acceptance" => Ok(Self::AcceptanceCriteria),
+            "notes" => Ok(Self::Notes),
+            "comments" | "comment" => Ok(Self::Comments),
+            other => Err(BeadsError::validation(
+                "in",
+                format!(
+                    "unknown search field '{other}' (expected one of: {})",
+                    Self::ALL.map(Self::as_str).join(", ")
+                ),
+            )),
+        }
+    }
+}
+
+/// What to search for, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub text: String,
+    /// Fields to search; an issue matches if any of them does.
+    pub fields: Vec<SearchField>,
+    /// Treat `text` as a regular expression.
+    pub regex: bool,
+    pub case_sensitive: bool,
+}
+
+impl SearchQuery {
+    /// Case-insensitive substring search over the default fields.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            fields: SearchField::DEFAULT.to_vec(),
+            regex: false,
+            case_sensitive: false,
+        }
+    }
+
+    /// Compile the query into a matcher.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a regex query does not compile.
+    pub fn matcher(&self) -> Result<TextMatcher> {
+        if self.regex {
+            let regex = RegexBuilder::new(&self.text)
+                .case_insensitive(!self.case_sensitive)
+                .build()
+                .map_err(|err| BeadsError::validation("query", format!("invalid regex: {err}")))?;
+            Ok(TextMatcher::Regex(regex))
+        } else if self.case_sensitive {
+            Ok(TextMatcher::Exact(self.text.clone()))
+        } else {
+            Ok(TextMatcher::Folded(self.text.to_lowercase()))
+        }
+    }
+}
+
+/// A compiled [`SearchQuery`].
+#[derive(Debug, Clone)]
+pub enum TextMatcher {
+    Regex(Regex),
+    /// Case-sensitive substring.
+    Exact(String),
+    /// Case-insensitive substring (the needle is already lowercased).
+    Folded(String),
+}
+
+impl TextMatcher {
+    /// Byte range of the first match in `haystack`.
+    #[must_use]
+    pub fn find(&self, haystack: &str) -> Option<(usize, usize)> {
+        match self {
+            Self::Regex(regex) => regex.find(haystack).map(|m| (m.start(), m.end())),
+            Self::Exact(needle) => haystack
+                .find(needle.as_str())
+                .map(|start| (start, start + needle.len())),
+            Self::Folded(needle) => find_folded(haystack, needle),
+        }
+    }
+
+    #[must_use]
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.find(haystack).is_some()
+    }
+}
+
+/// Case-insensitive substring search returning byte offsets into `haystack`.
+fn find_folded(haystack: &str, needle: &str) -> Option<(usize, usize)> {
+    if needle.is_empty() {
+        return Some((0, 0));
+    }
+    for (start, _) in haystack.char_indices() {
+        let mut folded = String::new();
+        for (offset, ch) in haystack[start..].char_indices() {
+            folded.extend(ch.to_lowercase());
+            if folded.len() >= needle.len() {
+                if folded == needle {
+                    return Some((start, start + offset + ch.len_utf8()));
+                }
+                break;
+            }
+            if !needle.starts_with(folded.as_str()) {
+                break;
+            }
+        }
+    }
+    None
+}
+
 /// Sort policy for ready issues.
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
 pub enum ReadySortPolicy {
@@ -161,6 +547,52 @@ mod tests {
         assert_eq!(filters.labels_and[0], "backend");
     }
 
+    #[test]
+    fn load_filters_skip_tombstones_and_old_closed() {
+        let now = Utc::now();
+        let filters = LoadFilters {
+            skip_tombstones: true,
+            closed_within_days: Some(30),
+        };
+        let issue = |status, closed_days_ago: Option<i64>| Issue {
+            status,
+            closed_at: closed_days_ago.map(|days| now - chrono::Duration::days(days)),
+            ..Default::default()
+        };
+
+        assert!(filters.keeps(&issue(Status::Open, None), now));
+        assert!(!filters.keeps(&issue(Status::Tombstone, None), now));
+        assert!(filters.keeps(&issue(Status::Closed, Some(3)), now));
+        assert!(!filters.keeps(&issue(Status::Closed, Some(90)), now));
+        // Without a close time there is nothing to age out
+        assert!(filters.keeps(&issue(Status::Closed, None), now));
+        assert!(LoadFilters::default().keeps(&issue(Status::Tombstone, None), now));
+    }
+
+    #[test]
+    fn search_query_matchers() {
+        assert_eq!(
+            "acceptance-criteria".parse::<SearchField>().unwrap(),
+            SearchField::AcceptanceCriteria
+        );
+        assert!("labels".parse::<SearchField>().is_err());
+
+        let mut query = SearchQuery::new("Auth");
+        assert_eq!(
+            query.matcher().unwrap().find("fix oauth flow"),
+            Some((5, 9))
+        );
+        query.case_sensitive = true;
+        assert!(!query.matcher().unwrap().is_match("fix oauth flow"));
+
+        query.regex = true;
+        query.text = "^Fix (auth|login)".to_string();
+        assert!(query.matcher().unwrap().is_match("Fix login page"));
+        assert!(!query.matcher().unwrap().is_match("fix login page"));
+        query.text = "(".to_string();
+        assert!(query.matcher().is_err());
+    }
+
     #[test]
     fn ready_sort_policy_default_is_hybrid() {
         assert_eq!(ReadySortPolicy::default(), ReadySortPolicy::Hybrid);
@@ -0,0 +1,283 @@
+//! Thread-safe handle to an [`InMemoryStore`].
+//!
+//! `SharedStore` wraps the store in `Arc<RwLock<..>>`: any number of threads
+//! can read at once, while writes take the lock exclusively. The read
+//! methods return owned data so the lock is held only for the lookup itself;
+//! [`SharedStore::read`] and [`SharedStore::write`] give direct access for
+//! anything not covered here.
+
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::error::Result;
+use crate::model::{Comment, Dependency, Issue};
+use crate::query::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy};
+use crate::store::InMemoryStore;
+
+/// Cloneable, thread-safe handle to one [`InMemoryStore`].
+///
+/// A panic while holding the lock does not make the store unusable: store
+/// mutations validate before they change anything, so later calls keep
+/// using the data as it was left.
+#[derive(Clone)]
+pub struct SharedStore {
+    inner: Arc<RwLock<InMemoryStore>>,
+}
+
+impl SharedStore {
+    #[must_use]
+    pub fn new(store: InMemoryStore) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(store)),
+        }
+    }
+
+    fn read_guard(&self) -> RwLockReadGuard<'_, InMemoryStore> {
+        self.inner.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write_guard(&self) -> RwLockWriteGuard<'_, InMemoryStore> {
+        self.inner.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Run `f` with shared access to the store.
+    pub fn read<R>(&self, f: impl FnOnce(&InMemoryStore) -> R) -> R {
+        f(&self.read_guard())
+    }
+
+    /// Run `f` with exclusive access to the store.
+    pub fn write<R>(&self, f: impl FnOnce(&mut InMemoryStore) -> R) -> R {
+        f(&mut self.write_guard())
+    }
+
+    /// Take the store back if this is the last handle.
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` unchanged while other handles exist.
+    pub fn into_inner(self) -> std::result::Result<InMemoryStore, Self> {
+        Arc::try_unwrap(self.inner)
+            .map(|lock| lock.into_inner().unwrap_or_else(PoisonError::into_inner))
+            .map_err(|inner| Self { inner })
+    }
+
+    // ========================================================================
+    // Reads
+    // ========================================================================
+
+    /// Get a copy of one issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IssueNotFound` if the ID doesn't exist.
+    pub fn get_issue(&self, id: &str) -> Result<Issue> {
+        self.read_guard().get_issue(id).cloned()
+    }
+
+    /// Resolve a full or partial ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IssueNotFound` or `AmbiguousId`.
+    pub fn resolve_id(&self, input: &str) -> Result<String> {
+        self.read_guard().resolve_id(input)
+    }
+
+    #[must_use]
+    pub fn list_issues(&self, filters: &ListFilters) -> Vec<Issue> {
+        cloned(self.read_guard().list_issues(filters))
+    }
+
+    #[must_use]
+    pub fn count_issues(&self, filters: &ListFilters) -> usize {
+        self.read_guard().count_issues(filters)
+    }
+
+    #[must_use]
+    pub fn search_issues(&self, query: &str) -> Vec<Issue> {
+        cloned(self.read_guard().search_issues(query))
+    }
+
+    #[must_use]
+    pub fn get_ready_issues(
+        &self,
+        filters: &ReadyFilters,
+        sort_policy: ReadySortPolicy,
+    ) -> Vec<Issue> {
+        cloned(self.read_guard().get_ready_issues(filters, sort_policy))
+    }
+
+    #[must_use]
+    pub fn get_blocked_issues(&self) -> Vec<Issue> {
+        cloned(self.read_guard().get_blocked_issues())
+    }
+
+    #[must_use]
+    pub fn is_blocked(&self, issue_id: &str) -> bool {
+        self.read_guard().is_blocked(issue_id)
+    }
+
+    #[must_use]
+    pub fn get_dependencies(&self, issue_id: &str) -> Vec<Dependency> {
+        cloned(self.read_guard().get_dependencies(issue_id))
+    }
+
+    #[must_use]
+    pub fn get_dependents(&self, issue_id: &str) -> Vec<Dependency> {
+        cloned(self.read_guard().get_dependents(issue_id))
+    }
+
+    #[must_use]
+    pub fn get_labels(&self, issue_id: &str) -> Vec<String> {
+        self.read_guard()
+            .get_labels(issue_id)
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[must_use]
+    pub fn get_comments(&self, issue_id: &str) -> Vec<Comment> {
+        cloned(self.read_guard().get_comments(issue_id))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.read_guard().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.read_guard().is_empty()
+    }
+
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.read_guard().is_dirty()
+    }
+
+    // ========================================================================
+    // Writes
+    // ========================================================================
+
+    /// See [`InMemoryStore::create_issue`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `IdCollision` or `Validation` as the store does.
+    pub fn create_issue(&self, issue: &Issue, actor: &str) -> Result<Issue> {
+        self.write_guard().create_issue(issue, actor)
+    }
+
+    /// See [`InMemoryStore::update_issue`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `IssueNotFound` or `Validation` as the store does.
+    pub fn update_issue(&self, id: &str, update: &IssueUpdate, actor: &str) -> Result<Issue> {
+        self.write_guard().update_issue(id, update, actor)
+    }
+
+    /// See [`InMemoryStore::add_label`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `IssueNotFound` if the issue doesn't exist.
+    pub fn add_label(&self, issue_id: &str, label: &str, actor: &str) -> Result<()> {
+        self.write_guard().add_label(issue_id, label, actor)
+    }
+
+    /// See [`InMemoryStore::add_comment`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `IssueNotFound` if the issue doesn't exist.
+    pub fn add_comment(&self, issue_id: &str, author: &str, body: &str) -> Result<Comment> {
+        self.write_guard().add_comment(issue_id, author, body)
+    }
+
+    /// Save through the store's persistence. Holds only a read lock, so
+    /// readers continue while the file is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`InMemoryStore::save`].
+    pub fn save(&self) -> Result<()> {
+        self.read_guard().save()
+    }
+}
+
+impl From<InMemoryStore> for SharedStore {
+    fn from(store: InMemoryStore) -> Self {
+        Self::new(store)
+    }
+}
+
+fn cloned<T: Clone>(items: Vec<&T>) -> Vec<T> {
+    items.into_iter().cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::DependencyType;
+    use std::thread;
+
+    fn issue(id: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn test_shared_store_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedStore>();
+        assert_send_sync::<InMemoryStore>();
+    }
+
+    #[test]
+    fn test_concurrent_reads_and_writes() {
+        let shared = SharedStore::new(InMemoryStore::new());
+        shared.create_issue(&issue("bd-root"), "user").unwrap();
+
+        let writers: Vec<_> = (0..4)
+            .map(|t| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    for i in 0..25 {
+                        let id = format!("bd-{t}-{i}");
+                        shared.create_issue(&issue(&id), "writer").unwrap();
+                        shared.write(|store| {
+                            store
+                                .add_dependency(&id, "bd-root", DependencyType::Blocks, "w", None)
+                                .unwrap();
+                        });
+                    }
+                })
+            })
+            .collect();
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        let issue = shared.get_issue("bd-root").unwrap();
+                        assert_eq!(issue.title, "Issue bd-root");
+                        assert!(!shared.is_empty());
+                    }
+                })
+            })
+            .collect();
+        for handle in writers.into_iter().chain(readers) {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(shared.len(), 101);
+        assert_eq!(shared.get_dependents("bd-root").len(), 100);
+        assert!(shared.is_blocked("bd-0-0"));
+        let store = shared.into_inner().ok().expect("last handle");
+        assert_eq!(store.len(), 101);
+    }
+}
@@ -0,0 +1,35 @@
+//! Complete store state as one serializable value.
+//!
+//! Unlike JSONL, which only carries issues and their relations, a
+//! [`Snapshot`] also keeps the event log, config, and ID prefix, so
+//! [`InMemoryStore::from_snapshot`](crate::InMemoryStore::from_snapshot)
+//! rebuilds exactly the store that produced it.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{Comment, Dependency, Event, Issue};
+
+/// Format version written by [`InMemoryStore::to_snapshot`](crate::InMemoryStore::to_snapshot).
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Everything an [`InMemoryStore`](crate::InMemoryStore) holds.
+///
+/// Issues are stored bare; their labels, dependencies, and comments live in
+/// the separate fields. Maps are ordered so the same state always
+/// serializes to the same document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub version: u32,
+    pub prefix: String,
+    /// Sorted by ID.
+    pub issues: Vec<Issue>,
+    pub labels: BTreeMap<String, Vec<String>>,
+    /// Sorted by `(issue_id, depends_on_id)`.
+    pub dependencies: Vec<Dependency>,
+    pub comments: BTreeMap<String, Vec<Comment>>,
+    /// In the order they were recorded.
+    pub events: Vec<Event>,
+    pub config: BTreeMap<String, String>,
+}
@@ -4,31 +4,58 @@
 //! comments, and events without any database dependency.
 
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::path::{Path, PathBuf};
+#[cfg(feature = "fs")]
+use std::path::Path;
 
 use chrono::Utc;
 
 use crate::error::{BeadsError, Result};
 use crate::jsonl;
-use crate::model::{Comment, Dependency, DependencyType, Event, EventType, Issue, Status};
-use crate::query::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy};
+use crate::model::{
+    Comment, Dependency, DependencyCondition, DependencyType, Event, EventType, Issue, Status,
+    WaitUntil,
+};
+#[cfg(feature = "fs")]
+use crate::persistence::JsonlFile;
+use crate::persistence::{AutosavePolicy, Persistence};
+use crate::query::{
+    IssueQuery, IssueUpdate, ListFilters, LoadFilters, ReadyFilters, ReadySortPolicy, SearchField,
+    SearchQuery, TextMatcher,
+};
+use crate::snapshot::{SNAPSHOT_VERSION, Snapshot};
+
+/// Callback registered with [`InMemoryStore::on_change`].
+///
+/// `Send + Sync` so the store can be shared across threads (see
+/// [`SharedStore`](crate::SharedStore)).
+pub type ChangeObserver = Box<dyn Fn(&Event) + Send + Sync>;
 
 /// In-memory beads issue store.
 ///
-/// All data lives in memory. Use `open()` to load from a JSONL file
-/// and `save()` to persist back.
+/// All data lives in memory. Use `open()` to load from a JSONL file, or
+/// `with_persistence()` for another backend, and `save()` to persist back.
 pub struct InMemoryStore {
     issues: HashMap<String, Issue>,
     labels: HashMap<String, Vec<String>>,
-    dependencies: Vec<Dependency>,
+    /// Outgoing edges keyed by `issue_id`.
+    dependencies: HashMap<String, Vec<Dependency>>,
+    /// Reverse adjacency: `depends_on_id` -> IDs of the issues depending on it.
+    dependents: HashMap<String, Vec<String>>,
     comments: HashMap<String, Vec<Comment>>,
     events: Vec<Event>,
     dirty_ids: HashSet<String>,
     config: HashMap<String, String>,
-    jsonl_path: Option<PathBuf>,
+    persistence: Option<Box<dyn Persistence>>,
     next_event_id: i64,
     next_comment_id: i64,
     prefix: String,
+    /// Callbacks registered with `on_change`, called for each new event.
+    observers: Vec<ChangeObserver>,
+    autosave: AutosavePolicy,
+    /// Mutations since the last autosave or `clear_dirty()`.
+    unsaved_mutations: usize,
+    /// Why the most recent autosave failed, until taken.
+    autosave_error: Option<BeadsError>,
 }
 
 impl InMemoryStore {
@@ -42,15 +69,20 @@ impl InMemoryStore {
         Self {
             issues: HashMap::new(),
             labels: HashMap::new(),
-            dependencies: Vec::new(),
+            dependencies: HashMap::new(),
+            dependents: HashMap::new(),
             comments: HashMap::new(),
             events: Vec::new(),
             dirty_ids: HashSet::new(),
             config: HashMap::new(),
-            jsonl_path: None,
+            persistence: None,
             next_event_id: 1,
             next_comment_id: 1,
             prefix: "bd".to_string(),
+            observers: Vec::new(),
+            autosave: AutosavePolicy::Manual,
+            unsaved_mutations: 0,
+            autosave_error: None,
         }
     }
 
@@ -59,47 +91,121 @@ impl InMemoryStore {
     /// # Errors
     ///
     /// Returns an error if the file cannot be read or parsed.
+    #[cfg(feature = "fs")]
     pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::open_streaming(path, &LoadFilters::default())
+    }
+
+    /// Open a JSONL file, loading only the issues `filters` keeps.
+    ///
+    /// The file is read one line at a time, so skipped issues never stay in
+    /// memory. Dependencies on skipped issues are dropped as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    #[cfg(feature = "fs")]
+    pub fn open_streaming(path: impl AsRef<Path>, filters: &LoadFilters) -> Result<Self> {
         let path = path.as_ref();
-        let loaded = jsonl::load(path)?;
+        let mut store = Self::load_filtered(jsonl::load_iter(path)?, filters)?;
+        store.persistence = Some(Box::new(JsonlFile::new(path)));
+        Ok(store)
+    }
+
+    /// Load JSONL held in memory, such as a blob fetched by a browser.
+    ///
+    /// The store has no persistence; `to_jsonl_string()` writes it back out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonlParse` if a line is invalid.
+    pub fn from_jsonl_str(data: &str) -> Result<Self> {
+        Self::load_filtered(jsonl::parse_str(data), &LoadFilters::default())
+    }
 
+    fn load_filtered(
+        records: impl Iterator<Item = Result<Issue>>,
+        filters: &LoadFilters,
+    ) -> Result<Self> {
+        let now = Utc::now();
         let mut store = Self::new();
-        store.jsonl_path = Some(path.to_path_buf());
-
-        for issue in loaded.issues {
-            if store.issues.contains_key(&issue.id) {
-                tracing::warn!(
-                    id = %issue.id,
-                    "Duplicate issue ID in JSONL file; later entry overwrites earlier one"
-                );
+        let mut skipped = HashSet::new();
+
+        for issue in records {
+            let issue = issue?;
+            if filters.keeps(&issue, now) {
+                store.insert_record(issue);
+            } else {
+                skipped.insert(issue.id);
             }
-            store.issues.insert(issue.id.clone(), issue);
         }
 
-        for (issue_id, issue_labels) in loaded.labels {
-            store.labels.insert(issue_id, issue_labels);
+        for id in &skipped {
+            // An ID can be skipped on one line and kept on a later one
+            if !store.issues.contains_key(id) {
+                store.remove_dependency_edges(id);
+            }
         }
+        store.infer_prefix();
+
+        Ok(store)
+    }
 
-        store.dependencies = loaded.dependencies;
+    /// Load from `persistence`, and save back to it with `save()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the backend's error if loading fails.
+    pub fn with_persistence(persistence: Box<dyn Persistence>) -> Result<Self> {
+        let mut store = Self::new();
+        for issue in persistence.load()? {
+            store.insert_record(issue);
+        }
+        store.infer_prefix();
+        store.persistence = Some(persistence);
+        Ok(store)
+    }
 
-        for (issue_id, issue_comments) in loaded.comments {
+    /// Add a loaded issue record, extracting its embedded relations.
+    fn insert_record(&mut self, mut issue: Issue) {
+        if self.issues.contains_key(&issue.id) {
+            tracing::warn!(
+                id = %issue.id,
+                "Duplicate issue ID in loaded records; later entry overwrites earlier one"
+            );
+        }
+
+        let issue_labels = std::mem::take(&mut issue.labels);
+        if !issue_labels.is_empty() {
+            self.labels.insert(issue.id.clone(), issue_labels);
+        }
+        // The same dep may be embedded in both the source and target record
+        for dep in std::mem::take(&mut issue.dependencies) {
+            if !self.dependency_exists(&dep.issue_id, &dep.depends_on_id) {
+                self.insert_dependency(dep);
+            }
+        }
+        let issue_comments = std::mem::take(&mut issue.comments);
+        if !issue_comments.is_empty() {
             // Track max comment ID
             for c in &issue_comments {
-                if c.id >= store.next_comment_id {
-                    store.next_comment_id = c.id + 1;
+                if c.id >= self.next_comment_id {
+                    self.next_comment_id = c.id + 1;
                 }
             }
-            store.comments.insert(issue_id, issue_comments);
+            self.comments.insert(issue.id.clone(), issue_comments);
         }
 
-        // Infer prefix from first issue ID
-        if let Some(id) = store.issues.keys().next() {
+        self.issues.insert(issue.id.clone(), issue);
+    }
+
+    /// Infer the ID prefix from the first loaded issue ID.
+    fn infer_prefix(&mut self) {
+        if let Some(id) = self.issues.keys().next() {
             if let Some(dash) = id.rfind('-') {
-                store.prefix = id[..dash].to_string();
+                self.prefix = id[..dash].to_string();
             }
         }
-
-        Ok(store)
     }
 
     /// Set the ID prefix for new issues.
@@ -113,17 +219,18 @@ impl InMemoryStore {
         &self.prefix
     }
 
-    /// Save to the file that was opened.
+    /// Save to the file that was opened, or the `with_persistence()` backend.
     ///
     /// # Errors
     ///
-    /// Returns `Storage` if no file path is set, or `Io` on write failure.
+    /// Returns `Storage` if the store has no persistence, or the backend's
+    /// error (`Io` for files) on write failure.
     pub fn save(&self) -> Result<()> {
-        let path = self
-            .jsonl_path
+        let persistence = self
+            .persistence
             .as_ref()
-            .ok_or_else(|| BeadsError::Storage("No file path set; use save_to()".to_string()))?;
-        self.save_to(path.clone())
+            .ok_or_else(|| BeadsError::Storage("No persistence set; use save_to()".to_string()))?;
+        persistence.save(&self.export_records())
     }
 
     /// Save to a specific file path.
@@ -131,13 +238,58 @@ impl InMemoryStore {
     /// # Errors
     ///
     /// Returns `Io` on write failure.
+    #[cfg(feature = "fs")]
     pub fn save_to(&self, path: impl AsRef<Path>) -> Result<()> {
+        jsonl::save_records(path.as_ref(), &self.export_records())
+    }
+
+    /// The store as JSONL text, in the same canonical form `save_to()`
+    /// writes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Json` or `JsonlWriteRoundtripFailed` if an issue can't be
+    /// serialized.
+    pub fn to_jsonl_string(&self) -> Result<String> {
+        jsonl::records_to_string(&self.export_records())
+    }
+
+    /// Save automatically after mutations, as `policy` says.
+    ///
+    /// Autosave writes through `save()`, so the store needs persistence
+    /// (`open()` or `with_persistence()`). A failed autosave doesn't undo or
+    /// fail the mutation: it is logged, kept for `take_autosave_error()`,
+    /// and retried after the next mutation.
+    pub fn set_autosave(&mut self, policy: AutosavePolicy) {
+        self.autosave = policy;
+    }
+
+    /// The current autosave policy.
+    #[must_use]
+    pub const fn autosave(&self) -> AutosavePolicy {
+        self.autosave
+    }
+
+    /// Take the error from the most recent failed autosave, if any.
+    pub const fn take_autosave_error(&mut self) -> Option<BeadsError> {
+        self.autosave_error.take()
+    }
+
+    /// Wrap the store so it is saved when dropped, including while
+    /// unwinding from a panic.
+    #[must_use]
+    pub fn save_on_drop(self) -> SaveOnDrop {
+        SaveOnDrop { store: self }
+    }
+
+    /// Every issue with its relations embedded, sorted by ID.
+    fn export_records(&self) -> Vec<Issue> {
         let issues: Vec<Issue> = self.get_all_issues_for_export();
         let labels: Vec<(String, Vec<String>)> = self.get_all_labels();
         let deps = self.get_all_dependency_records();
         let comments: Vec<(String, Vec<Comment>)> = self.get_all_comments();
 
-        jsonl::save(path.as_ref(), &issues, &labels, &deps, &comments)
+        jsonl::records(&issues, &labels, &deps, &comments)
     }
 
     // ========================================================================
@@ -203,13 +355,15 @@ impl InMemoryStore {
         if !issue_labels.is_empty() {
             self.labels.insert(id.clone(), issue_labels);
         }
-        self.dependencies.extend(issue_deps);
+        for dep in issue_deps {
+            self.insert_dependency(dep);
+        }
         if !issue_comments.is_empty() {
             self.comments.insert(id.clone(), issue_comments);
         }
 
         self.record_event(&id, EventType::Created, actor, None, None);
-        self.dirty_ids.insert(id);
+        self.mark_dirty(id);
 
         Ok(new_issue)
     }
@@ -334,44 +488,108 @@ impl InMemoryStore {
             );
         }
         self.record_event(id, EventType::Updated, actor, None, None);
-        self.dirty_ids.insert(id.to_string());
+        self.mark_dirty(id);
 
         Ok(updated)
     }
 
-    /// Delete an issue from the store.
+    /// Delete an issue by turning it into a tombstone.
+    ///
+    /// The tombstone keeps its ID, labels and comments and is saved with the
+    /// rest of the store, so the deletion reaches other clones; default
+    /// queries skip it. Dependencies to and from it are removed. Use
+    /// [`Self::purge_issue`] to remove an issue outright.
     ///
     /// # Errors
     ///
-    /// Returns `IssueNotFound` if the issue doesn't exist,
-    /// or `HasDependents` if other issues depend on it.
-    pub fn delete_issue(&mut self, id: &str, actor: &str, force: bool) -> Result<()> {
-        if !self.issues.contains_key(id) {
+    /// Returns `IssueNotFound` if the issue doesn't exist or is already a
+    /// tombstone, or `HasDependents` if other issues depend on it and `force`
+    /// is false.
+    pub fn delete_issue(
+        &mut self,
+        id: &str,
+        actor: &str,
+        reason: &str,
+        force: bool,
+    ) -> Result<Issue> {
+        if self
+            .issues
+            .get(id)
+            .is_none_or(|issue| issue.status == Status::Tombstone)
+        {
             return Err(BeadsError::IssueNotFound { id: id.to_string() });
         }
+        self.check_dependents(id, force)?;
+        self.detach(id);
 
-        if !force {
-            let dependents = self.get_dependents(id);
-            if !dependents.is_empty() {
-                return Err(BeadsError::HasDependents {
-                    id: id.to_string(),
-                    count: dependents.len(),
-                });
-            }
+        let now = Utc::now();
+        let issue = self
+            .issues
+            .get_mut(id)
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+        issue.original_type = Some(issue.issue_type.as_str().to_string());
+        issue.status = Status::Tombstone;
+        issue.deleted_at = Some(now);
+        issue.deleted_by = Some(actor.to_string());
+        issue.delete_reason = Some(reason.to_string());
+        issue.updated_at = now;
+        issue.content_hash = Some(issue.compute_content_hash());
+        let deleted = issue.clone();
+
+        self.record_event(id, EventType::Deleted, actor, None, Some(reason));
+        self.mark_dirty(id);
+
+        Ok(deleted)
+    }
+
+    /// Remove an issue from the store with its labels, comments and
+    /// dependencies, leaving no tombstone. Works on tombstones too.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IssueNotFound` if the issue doesn't exist, or `HasDependents`
+    /// if other issues depend on it and `force` is false.
+    pub fn purge_issue(&mut self, id: &str, actor: &str, force: bool) -> Result<()> {
+        if !self.issues.contains_key(id) {
+            return Err(BeadsError::IssueNotFound { id: id.to_string() });
         }
+        self.check_dependents(id, force)?;
+        self.detach(id);
 
         self.issues.remove(id);
         self.labels.remove(id);
         self.comments.remove(id);
-        self.dependencies
-            .retain(|d| d.issue_id != id && d.depends_on_id != id);
 
         self.record_event(id, EventType::Deleted, actor, None, None);
-        self.dirty_ids.insert(id.to_string());
+        self.mark_dirty(id);
 
         Ok(())
     }
 
+    /// `HasDependents` if other issues depend on `id`, unless `force`.
+    fn check_dependents(&self, id: &str, force: bool) -> Result<()> {
+        let count = self.get_dependents(id).len();
+        if force || count == 0 {
+            return Ok(());
+        }
+        Err(BeadsError::HasDependents {
+            id: id.to_string(),
+            count,
+        })
+    }
+
+    /// Drop every dependency to and from `id`; dependents whose lists
+    /// change are marked dirty.
+    fn detach(&mut self, id: &str) {
+        let dependents: Vec<String> = self
+            .get_dependents(id)
+            .iter()
+            .map(|dep| dep.issue_id.clone())
+            .collect();
+        self.remove_dependency_edges(id);
+        self.dirty_ids.extend(dependents);
+    }
+
     /// Get a single issue by ID.
     ///
     /// # Errors
@@ -393,6 +611,11 @@ impl InMemoryStore {
     // Queries
     // ========================================================================
 
+    /// Start a fluent query; see [`IssueQuery`].
+    pub fn query(&self) -> IssueQuery<'_> {
+        IssueQuery::new(self)
+    }
+
     /// List issues with filters.
     #[must_use]
     pub fn list_issues(&self, filters: &ListFilters) -> Vec<&Issue> {
@@ -411,12 +634,29 @@ impl InMemoryStore {
         results
     }
 
+    /// List issues with filters, each as `(issue, dependency_count,
+    /// dependent_count)`.
+    ///
+    /// Counts are read from the adjacency maps, so this costs two lookups
+    /// per issue on top of [`Self::list_issues`].
+    #[must_use]
+    pub fn list_issues_with_counts(&self, filters: &ListFilters) -> Vec<(&Issue, usize, usize)> {
+        self.list_issues(filters)
+            .into_iter()
+            .map(|issue| {
+                let dependents = self.dependents.get(&issue.id).map_or(0, Vec::len);
+                (issue, self.outgoing(&issue.id).len(), dependents)
+            })
+            .collect()
+    }
+
     /// Search issues by title substring.
     #[must_use]
     pub fn search_issues(&self, query: &str) -> Vec<&Issue> {
         let query_lower = query.to_lowercase();
         self.issues
             .values()
+            .filter(|issue| issue.status != Status::Tombstone)
             .filter(|issue| {
                 issue.title.to_lowercase().contains(&query_lower)
                     || issue
@@ -427,6 +667,47 @@ impl InMemoryStore {
             .collect()
     }
 
+    /// Search issues matching `filters` for `query` in the query's fields.
+    ///
+    /// Results are sorted like [`Self::list_issues`] and the limit applies
+    /// after matching.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a regex query does not compile.
+    pub fn search(&self, query: &SearchQuery, filters: &ListFilters) -> Result<Vec<&Issue>> {
+        let matcher = query.matcher()?;
+        let fields: &[SearchField] = if query.fields.is_empty() {
+            &SearchField::DEFAULT
+        } else {
+            &query.fields
+        };
+        let mut results: Vec<&Issue> = self
+            .issues
+            .values()
+            .filter(|issue| self.matches_list_filters(issue, filters))
+            .filter(|issue| self.matches_search(issue, fields, &matcher))
+            .collect();
+
+        Self::sort_issues(&mut results, filters.sort.as_deref(), filters.reverse);
+
+        if let Some(limit) = filters.limit {
+            results.truncate(limit);
+        }
+
+        Ok(results)
+    }
+
+    fn matches_search(&self, issue: &Issue, fields: &[SearchField], matcher: &TextMatcher) -> bool {
+        fields.iter().any(|field| match field {
+            SearchField::Comments => self
+                .get_comments(&issue.id)
+                .iter()
+                .any(|comment| matcher.is_match(&comment.body)),
+            other => other.text(issue).is_some_and(|text| matcher.is_match(text)),
+        })
+    }
+
     /// Get issues that are ready to work on (not blocked).
     #[must_use]
     pub fn get_ready_issues(
@@ -539,7 +820,7 @@ impl InMemoryStore {
             });
         }
 
-        self.dependencies.push(Dependency {
+        self.insert_dependency(Dependency {
             issue_id: issue_id.to_string(),
             depends_on_id: depends_on_id.to_string(),
             dep_type,
@@ -556,7 +837,7 @@ impl InMemoryStore {
             None,
             Some(depends_on_id),
         );
-        self.dirty_ids.insert(issue_id.to_string());
+        self.mark_dirty(issue_id);
 
         Ok(())
     }
@@ -572,11 +853,15 @@ impl InMemoryStore {
         depends_on_id: &str,
         actor: &str,
     ) -> Result<()> {
-        let before = self.dependencies.len();
-        self.dependencies
-            .retain(|d| !(d.issue_id == issue_id && d.depends_on_id == depends_on_id));
+        let removed = self.dependencies.get_mut(issue_id).is_some_and(|deps| {
+            let before = deps.len();
+            deps.retain(|d| d.depends_on_id != depends_on_id);
+            deps.len() != before
+        });
 
-        if self.dependencies.len() == before {
+        if removed {
+            self.unlink_dependent(depends_on_id, issue_id);
+        } else {
             return Err(BeadsError::NothingToDo {
                 reason: format!("No dependency from {issue_id} to {depends_on_id}"),
             });
@@ -589,7 +874,7 @@ impl InMemoryStore {
             Some(depends_on_id),
             None,
         );
-        self.dirty_ids.insert(issue_id.to_string());
+        self.mark_dirty(issue_id);
 
         Ok(())
     }
@@ -597,17 +882,17 @@ impl InMemoryStore {
     /// Get all dependencies for an issue (things this issue depends on).
     #[must_use]
     pub fn get_dependencies(&self, issue_id: &str) -> Vec<&Dependency> {
-        self.dependencies
-            .iter()
-            .filter(|d| d.issue_id == issue_id)
-            .collect()
+        self.outgoing(issue_id).iter().collect()
     }
 
     /// Get all issues that depend on the given issue.
     #[must_use]
     pub fn get_dependents(&self, issue_id: &str) -> Vec<&Dependency> {
-        self.dependencies
-            .iter()
+        self.dependents
+            .get(issue_id)
+            .into_iter()
+            .flatten()
+            .flat_map(|dependent| self.outgoing(dependent))
             .filter(|d| d.depends_on_id == issue_id)
             .collect()
     }
@@ -615,36 +900,52 @@ impl InMemoryStore {
     /// Check if an issue is blocked by any dependency.
     #[must_use]
     pub fn is_blocked(&self, issue_id: &str) -> bool {
-        self.dependencies.iter().any(|d| {
-            d.issue_id == issue_id
-                && d.dep_type.is_blocking()
-                && self
-                    .issues
-                    .get(&d.depends_on_id)
-                    .is_some_and(|i| !i.status.is_terminal())
-        })
+        self.outgoing(issue_id)
+            .iter()
+            .any(|d| self.active_blocker(d).is_some())
     }
 
     /// Get the issues blocking a given issue.
     #[must_use]
     pub fn get_blockers(&self, issue_id: &str) -> Vec<&Issue> {
-        self.dependencies
+        self.outgoing(issue_id)
             .iter()
-            .filter(|d| d.issue_id == issue_id && d.dep_type.is_blocking())
-            .filter_map(|d| {
-                self.issues
-                    .get(&d.depends_on_id)
-                    .filter(|i| !i.status.is_terminal())
-            })
+            .filter_map(|d| self.active_blocker(d))
             .collect()
     }
 
+    /// The target of `dep` if it currently blocks: a blocking dependency on
+    /// an open issue, whose condition (for `conditional-blocks`) holds.
+    fn active_blocker(&self, dep: &Dependency) -> Option<&Issue> {
+        if !dep.dep_type.is_blocking() {
+            return None;
+        }
+        let blocker = self
+            .issues
+            .get(&dep.depends_on_id)
+            .filter(|i| !i.status.is_terminal())?;
+        if dep.dep_type == DependencyType::WaitsFor {
+            return WaitUntil::from_metadata(dep.metadata.as_deref())
+                .is_none_or(|until| !until.has_passed(Utc::now()))
+                .then_some(blocker);
+        }
+        if dep.dep_type != DependencyType::ConditionalBlocks {
+            return Some(blocker);
+        }
+        DependencyCondition::from_metadata(dep.metadata.as_deref())
+            .is_none_or(|condition| {
+                let labels = self.labels.get(&blocker.id).map_or(&[][..], Vec::as_slice);
+                condition.matches(blocker.issue_type.as_str(), blocker.priority.0, labels)
+            })
+            .then_some(blocker)
+    }
+
     /// Check if a dependency edge already exists.
     #[must_use]
     pub fn dependency_exists(&self, issue_id: &str, depends_on_id: &str) -> bool {
-        self.dependencies
+        self.outgoing(issue_id)
             .iter()
-            .any(|d| d.issue_id == issue_id && d.depends_on_id == depends_on_id)
+            .any(|d| d.depends_on_id == depends_on_id)
     }
 
     /// Check if adding a dependency would create a cycle.
@@ -664,10 +965,8 @@ impl InMemoryStore {
             if !visited.insert(current.clone()) {
                 continue;
             }
-            for dep in &self.dependencies {
-                if dep.issue_id == current {
-                    queue.push_back(dep.depends_on_id.clone());
-                }
+            for dep in self.outgoing(&current) {
+                queue.push_back(dep.depends_on_id.clone());
             }
         }
 
@@ -694,7 +993,7 @@ impl InMemoryStore {
         if !labels.contains(&label.to_string()) {
             labels.push(label.to_string());
             self.record_event(issue_id, EventType::LabelAdded, actor, None, Some(label));
-            self.dirty_ids.insert(issue_id.to_string());
+            self.mark_dirty(issue_id);
         }
 
         Ok(())
@@ -716,7 +1015,7 @@ impl InMemoryStore {
             if let Some(pos) = labels.iter().position(|l| l == label) {
                 labels.remove(pos);
                 self.record_event(issue_id, EventType::LabelRemoved, actor, Some(label), None);
-                self.dirty_ids.insert(issue_id.to_string());
+                self.mark_dirty(issue_id);
             }
         }
 
@@ -745,15 +1044,24 @@ impl InMemoryStore {
         }
 
         self.labels.insert(issue_id.to_string(), labels);
-        self.dirty_ids.insert(issue_id.to_string());
+        self.mark_dirty(issue_id);
         Ok(())
     }
 
-    /// Get all unique labels with their usage counts.
+    /// Get all unique labels with their usage counts, skipping tombstones.
     #[must_use]
     pub fn get_unique_labels_with_counts(&self) -> Vec<(String, usize)> {
         let mut counts: HashMap<&str, usize> = HashMap::new();
-        for labels in self.labels.values() {
+        let live = self
+            .labels
+            .iter()
+            .filter(|(id, _)| {
+                self.issues
+                    .get(*id)
+                    .is_some_and(|issue| issue.status != Status::Tombstone)
+            })
+            .map(|(_, labels)| labels);
+        for labels in live {
             for label in labels {
                 *counts.entry(label.as_str()).or_insert(0) += 1;
             }
@@ -788,6 +1096,8 @@ impl InMemoryStore {
             author: author.to_string(),
             body: body.to_string(),
             created_at: Utc::now(),
+            updated_at: None,
+            deleted: false,
         };
         self.next_comment_id += 1;
 
@@ -797,26 +1107,47 @@ impl InMemoryStore {
             .push(comment.clone());
 
         self.record_event(issue_id, EventType::Commented, author, None, Some(body));
-        self.dirty_ids.insert(issue_id.to_string());
+        self.mark_dirty(issue_id);
 
         Ok(comment)
     }
 
-    /// Get comments for an issue.
+    /// Get comments for an issue, excluding soft-deleted ones.
     #[must_use]
     pub fn get_comments(&self, issue_id: &str) -> Vec<&Comment> {
         self.comments
             .get(issue_id)
-            .map(|c| c.iter().collect())
+            .map(|c| c.iter().filter(|c| !c.deleted).collect())
             .unwrap_or_default()
     }
 
-    /// Update the body of an existing comment.
+    /// Find a live (not deleted) comment for modification.
+    fn live_comment_mut(&mut self, issue_id: &str, comment_id: i64) -> Result<&mut Comment> {
+        if !self.issues.contains_key(issue_id) {
+            return Err(BeadsError::IssueNotFound {
+                id: issue_id.to_string(),
+            });
+        }
+
+        self.comments
+            .get_mut(issue_id)
+            .and_then(|comments| {
+                comments
+                    .iter_mut()
+                    .find(|c| c.id == comment_id && !c.deleted)
+            })
+            .ok_or_else(|| BeadsError::CommentNotFound {
+                issue_id: issue_id.to_string(),
+                comment_id,
+            })
+    }
+
+    /// Update the body of an existing comment and stamp its `updated_at`.
     ///
     /// # Errors
     ///
     /// Returns `IssueNotFound` if the issue doesn't exist, or `CommentNotFound` if
-    /// no comment with the given `comment_id` exists on that issue.
+    /// no live comment with the given `comment_id` exists on that issue.
     pub fn update_comment(
         &mut self,
         issue_id: &str,
@@ -824,76 +1155,41 @@ impl InMemoryStore {
         body: &str,
         actor: &str,
     ) -> Result<()> {
-        if !self.issues.contains_key(issue_id) {
-            return Err(BeadsError::IssueNotFound {
-                id: issue_id.to_string(),
-            });
-        }
-
-        let comments =
-            self.comments
-                .get_mut(issue_id)
-                .ok_or_else(|| BeadsError::CommentNotFound {
-                    issue_id: issue_id.to_string(),
-                    comment_id,
-                })?;
-        let comment = comments
-            .iter_mut()
-            .find(|c| c.id == comment_id)
-            .ok_or_else(|| BeadsError::CommentNotFound {
-                issue_id: issue_id.to_string(),
-                comment_id,
-            })?;
+        let comment = self.live_comment_mut(issue_id, comment_id)?;
         comment.body = body.to_string();
+        comment.updated_at = Some(Utc::now());
 
         self.record_event(
             issue_id,
-            EventType::Custom(format!("comment_updated:{comment_id}")),
+            EventType::Updated,
             actor,
             None,
-            Some(body),
+            Some(&format!("Edited comment {comment_id}")),
         );
-        self.dirty_ids.insert(issue_id.to_string());
+        self.mark_dirty(issue_id);
         Ok(())
     }
 
-    /// Remove a comment from an issue.
+    /// Soft-delete a comment, keeping it as a tombstone so the deletion is
+    /// persisted to JSONL.
     ///
     /// # Errors
     ///
     /// Returns `IssueNotFound` if the issue doesn't exist, or `CommentNotFound` if
-    /// no comment with the given `comment_id` exists on that issue.
+    /// no live comment with the given `comment_id` exists on that issue.
     pub fn delete_comment(&mut self, issue_id: &str, comment_id: i64, actor: &str) -> Result<()> {
-        if !self.issues.contains_key(issue_id) {
-            return Err(BeadsError::IssueNotFound {
-                id: issue_id.to_string(),
-            });
-        }
-
-        let comments =
-            self.comments
-                .get_mut(issue_id)
-                .ok_or_else(|| BeadsError::CommentNotFound {
-                    issue_id: issue_id.to_string(),
-                    comment_id,
-                })?;
-        let pos = comments
-            .iter()
-            .position(|c| c.id == comment_id)
-            .ok_or_else(|| BeadsError::CommentNotFound {
-                issue_id: issue_id.to_string(),
-                comment_id,
-            })?;
-        comments.remove(pos);
+        let comment = self.live_comment_mut(issue_id, comment_id)?;
+        comment.deleted = true;
+        comment.updated_at = Some(Utc::now());
 
         self.record_event(
             issue_id,
-            EventType::Custom(format!("comment_deleted:{comment_id}")),
+            EventType::Updated,
             actor,
             None,
-            None,
+            Some(&format!("Deleted comment {comment_id}")),
         );
-        self.dirty_ids.insert(issue_id.to_string());
+        self.mark_dirty(issue_id);
         Ok(())
     }
 
@@ -916,6 +1212,16 @@ impl InMemoryStore {
         &self.events
     }
 
+    /// Call `observer` with every event recorded from now on (creates,
+    /// updates, closes, labels, comments, dependencies, deletes).
+    ///
+    /// Observers run synchronously, in registration order, right after the
+    /// event is recorded. Loading a file records no events, so it does not
+    /// notify them.
+    pub fn on_change(&mut self, observer: ChangeObserver) {
+        self.observers.push(observer);
+    }
+
     // ========================================================================
     // ID Resolution
     // ========================================================================
@@ -1015,10 +1321,12 @@ impl InMemoryStore {
         issues
     }
 
-    /// Get all dependency records.
+    /// Get all dependency records (sorted by `issue_id`, then `depends_on_id`).
     #[must_use]
     pub fn get_all_dependency_records(&self) -> Vec<Dependency> {
-        self.dependencies.clone()
+        let mut deps: Vec<Dependency> = self.dependencies.values().flatten().cloned().collect();
+        deps.sort_by(|a, b| (&a.issue_id, &a.depends_on_id).cmp(&(&b.issue_id, &b.depends_on_id)));
+        deps
     }
 
     /// Get all comments grouped by issue ID.
@@ -1039,6 +1347,65 @@ impl InMemoryStore {
             .collect()
     }
 
+    /// Capture the complete state: issues, relations, events, and config.
+    #[must_use]
+    pub fn to_snapshot(&self) -> Snapshot {
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            prefix: self.prefix.clone(),
+            issues: self.get_all_issues_for_export(),
+            labels: self.labels.clone().into_iter().collect(),
+            dependencies: self.get_all_dependency_records(),
+            comments: self.comments.clone().into_iter().collect(),
+            events: self.events.clone(),
+            config: self.config.clone().into_iter().collect(),
+        }
+    }
+
+    /// Rebuild a store from `to_snapshot()` output.
+    ///
+    /// The store starts clean, with no persistence, autosave, or observers;
+    /// new events and comments continue the snapshot's IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Validation` if the snapshot has an unsupported version.
+    pub fn from_snapshot(snapshot: Snapshot) -> Result<Self> {
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(BeadsError::validation(
+                "version",
+                format!(
+                    "unsupported snapshot version {} (expected {SNAPSHOT_VERSION})",
+                    snapshot.version
+                ),
+            ));
+        }
+
+        let mut store = Self::new();
+        store.prefix = snapshot.prefix;
+        for issue in snapshot.issues {
+            store.insert_record(issue);
+        }
+        store.labels.extend(snapshot.labels);
+        for dep in snapshot.dependencies {
+            if !store.dependency_exists(&dep.issue_id, &dep.depends_on_id) {
+                store.insert_dependency(dep);
+            }
+        }
+        for (issue_id, comments) in snapshot.comments {
+            if let Some(max) = comments.iter().map(|c| c.id).max() {
+                store.next_comment_id = store.next_comment_id.max(max + 1);
+            }
+            store.comments.insert(issue_id, comments);
+        }
+        if let Some(max) = snapshot.events.iter().map(|e| e.id).max() {
+            store.next_event_id = max + 1;
+        }
+        store.events = snapshot.events;
+        store.config.extend(snapshot.config);
+        Ok(store)
+    }
+
     // ========================================================================
     // Config
     // ========================================================================
@@ -1073,6 +1440,31 @@ impl InMemoryStore {
     /// Clear dirty tracking flags.
     pub fn clear_dirty(&mut self) {
         self.dirty_ids.clear();
+        self.unsaved_mutations = 0;
+    }
+
+    /// Mark `id` modified at the end of a mutation, then autosave if the
+    /// policy is due.
+    fn mark_dirty(&mut self, id: impl Into<String>) {
+        self.dirty_ids.insert(id.into());
+        self.unsaved_mutations += 1;
+        let due = match self.autosave {
+            AutosavePolicy::Manual => false,
+            AutosavePolicy::OnDirty => true,
+            AutosavePolicy::EveryNMutations(n) => self.unsaved_mutations >= n.max(1),
+        };
+        if due {
+            match self.save() {
+                Ok(()) => {
+                    self.clear_dirty();
+                    self.autosave_error = None;
+                }
+                Err(err) => {
+                    tracing::warn!(error = %err, "Autosave failed; changes are not saved");
+                    self.autosave_error = Some(err);
+                }
+            }
+        }
     }
 
     /// Get the total number of issues.
@@ -1091,6 +1483,48 @@ impl InMemoryStore {
     // Internal Helpers
     // ========================================================================
 
+    /// Outgoing edges of an issue.
+    fn outgoing(&self, issue_id: &str) -> &[Dependency] {
+        self.dependencies.get(issue_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Add an edge to both adjacency maps.
+    fn insert_dependency(&mut self, dep: Dependency) {
+        let dependents = self
+            .dependents
+            .entry(dep.depends_on_id.clone())
+            .or_default();
+        if !dependents.contains(&dep.issue_id) {
+            dependents.push(dep.issue_id.clone());
+        }
+        self.dependencies
+            .entry(dep.issue_id.clone())
+            .or_default()
+            .push(dep);
+    }
+
+    /// Remove every edge into or out of an issue.
+    fn remove_dependency_edges(&mut self, id: &str) {
+        for dep in self.dependencies.remove(id).unwrap_or_default() {
+            self.unlink_dependent(&dep.depends_on_id, id);
+        }
+        for dependent in self.dependents.remove(id).unwrap_or_default() {
+            if let Some(deps) = self.dependencies.get_mut(&dependent) {
+                deps.retain(|d| d.depends_on_id != id);
+            }
+        }
+    }
+
+    /// Drop `dependent` from the reverse adjacency of `depends_on_id`.
+    fn unlink_dependent(&mut self, depends_on_id: &str, dependent: &str) {
+        if let Some(ids) = self.dependents.get_mut(depends_on_id) {
+            ids.retain(|id| id != dependent);
+            if ids.is_empty() {
+                self.dependents.remove(depends_on_id);
+            }
+        }
+    }
+
     fn record_event(
         &mut self,
         issue_id: &str,
@@ -1110,6 +1544,9 @@ impl InMemoryStore {
             created_at: Utc::now(),
         };
         self.next_event_id += 1;
+        for observer in &self.observers {
+            observer(&event);
+        }
         self.events.push(event);
     }
 
@@ -1322,6 +1759,46 @@ impl Default for InMemoryStore {
     }
 }
 
+/// An [`InMemoryStore`] that saves unsaved changes when dropped.
+///
+/// Created by [`InMemoryStore::save_on_drop`]; derefs to the store. Errors
+/// from the final save can't be returned, so they are logged.
+pub struct SaveOnDrop {
+    store: InMemoryStore,
+}
+
+impl SaveOnDrop {
+    /// Unwrap the store without saving it.
+    #[must_use]
+    pub fn into_inner(mut self) -> InMemoryStore {
+        std::mem::take(&mut self.store)
+    }
+}
+
+impl std::ops::Deref for SaveOnDrop {
+    type Target = InMemoryStore;
+
+    fn deref(&self) -> &InMemoryStore {
+        &self.store
+    }
+}
+
+impl std::ops::DerefMut for SaveOnDrop {
+    fn deref_mut(&mut self) -> &mut InMemoryStore {
+        &mut self.store
+    }
+}
+
+impl Drop for SaveOnDrop {
+    fn drop(&mut self) {
+        if self.store.is_dirty() {
+            if let Err(err) = self.store.save() {
+                tracing::warn!(error = %err, "Save on drop failed; changes are lost");
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1409,8 +1886,27 @@ mod tests {
         let issue = make_issue("bd-del1", "Delete me");
         store.create_issue(&issue, "user").unwrap();
 
-        store.delete_issue("bd-del1", "user", false).unwrap();
+        let deleted = store
+            .delete_issue("bd-del1", "user", "duplicate", false)
+            .unwrap();
+        assert_eq!(deleted.status, Status::Tombstone);
+        assert_eq!(deleted.delete_reason.as_deref(), Some("duplicate"));
+        assert_eq!(deleted.original_type.as_deref(), Some("task"));
+
+        // The tombstone is kept for export but skipped by default queries
+        assert!(store.get_issue("bd-del1").is_ok());
+        assert!(store.list_issues(&ListFilters::default()).is_empty());
+        assert!(store.search_issues("Delete").is_empty());
+        assert_eq!(store.get_all_issues_for_export().len(), 1);
+        assert!(
+            store
+                .delete_issue("bd-del1", "user", "again", false)
+                .is_err()
+        );
+
+        store.purge_issue("bd-del1", "user", false).unwrap();
         assert!(store.get_issue("bd-del1").is_err());
+        assert!(store.get_all_issues_for_export().is_empty());
     }
 
     #[test]
@@ -1426,12 +1922,17 @@ mod tests {
             .add_dependency("bd-b1", "bd-a1", DependencyType::Blocks, "user", None)
             .unwrap();
 
-        let result = store.delete_issue("bd-a1", "user", false);
+        let result = store.delete_issue("bd-a1", "user", "delete", false);
+        assert!(matches!(result, Err(BeadsError::HasDependents { .. })));
+        let result = store.purge_issue("bd-a1", "user", false);
         assert!(matches!(result, Err(BeadsError::HasDependents { .. })));
 
-        // Force delete works
-        store.delete_issue("bd-a1", "user", true).unwrap();
-        assert!(store.get_issue("bd-a1").is_err());
+        // Force delete works and orphans the dependent
+        store.clear_dirty();
+        store.delete_issue("bd-a1", "user", "delete", true).unwrap();
+        assert_eq!(store.get_issue("bd-a1").unwrap().status, Status::Tombstone);
+        assert!(store.get_dependencies("bd-b1").is_empty());
+        assert_eq!(store.dirty_count(), 2);
     }
 
     #[test]
@@ -1470,6 +1971,58 @@ mod tests {
         assert_eq!(results[0].title, "Fix login bug");
     }
 
+    #[test]
+    fn test_search_fields_regex_and_filters() {
+        let mut store = InMemoryStore::new();
+        let mut cache = make_issue("bd-q1", "Cache layer");
+        cache.notes = Some("Seen a Timeout in prod".to_string());
+        store.create_issue(&cache, "user").unwrap();
+        store
+            .create_issue(&make_issue("bd-q2", "Timeout on login"), "user")
+            .unwrap();
+        store
+            .add_comment("bd-q2", "user", "retry after timeout")
+            .unwrap();
+        store.add_label("bd-q2", "backend", "user").unwrap();
+
+        let ids = |query: &SearchQuery, filters: &ListFilters| {
+            let mut ids: Vec<String> = store
+                .search(query, filters)
+                .unwrap()
+                .into_iter()
+                .map(|issue| issue.id.clone())
+                .collect();
+            ids.sort();
+            ids
+        };
+        let all = ListFilters::default();
+
+        let notes = SearchQuery {
+            fields: vec![SearchField::Notes],
+            ..SearchQuery::new("timeout")
+        };
+        assert_eq!(ids(&notes, &all), vec!["bd-q1"]);
+
+        let comments = SearchQuery {
+            fields: vec![SearchField::Comments],
+            ..SearchQuery::new("retry")
+        };
+        assert_eq!(ids(&comments, &all), vec!["bd-q2"]);
+
+        let regex = SearchQuery {
+            fields: vec![SearchField::Title, SearchField::Notes],
+            regex: true,
+            case_sensitive: true,
+            ..SearchQuery::new("Time?out")
+        };
+        assert_eq!(ids(&regex, &all), vec!["bd-q1", "bd-q2"]);
+        let backend = ListFilters {
+            labels: Some(vec!["backend".to_string()]),
+            ..ListFilters::default()
+        };
+        assert_eq!(ids(&regex, &backend), vec!["bd-q2"]);
+    }
+
     #[test]
     fn test_dependencies_cycle_detection() {
         let mut store = InMemoryStore::new();
@@ -1495,6 +2048,37 @@ mod tests {
         assert!(matches!(result, Err(BeadsError::DependencyCycle { .. })));
     }
 
+    #[test]
+    fn test_dependency_indexes_follow_remove_and_delete() {
+        let mut store = InMemoryStore::new();
+        for id in ["bd-i1", "bd-i2", "bd-i3"] {
+            store.create_issue(&make_issue(id, id), "user").unwrap();
+        }
+        store
+            .add_dependency("bd-i1", "bd-i3", DependencyType::Blocks, "user", None)
+            .unwrap();
+        store
+            .add_dependency("bd-i2", "bd-i3", DependencyType::Blocks, "user", None)
+            .unwrap();
+
+        let dependents: Vec<&str> = store
+            .get_dependents("bd-i3")
+            .iter()
+            .map(|d| d.issue_id.as_str())
+            .collect();
+        assert_eq!(dependents, vec!["bd-i1", "bd-i2"]);
+
+        store.remove_dependency("bd-i1", "bd-i3", "user").unwrap();
+        assert!(!store.is_blocked("bd-i1"));
+        assert_eq!(store.get_dependents("bd-i3").len(), 1);
+
+        // Deleting the target drops the edges pointing at it
+        store.delete_issue("bd-i3", "user", "delete", true).unwrap();
+        assert!(store.get_dependencies("bd-i2").is_empty());
+        assert!(!store.would_create_cycle("bd-i3", "bd-i2"));
+        assert!(store.get_all_dependency_records().is_empty());
+    }
+
     #[test]
     fn test_self_dependency_rejected() {
         let mut store = InMemoryStore::new();
@@ -1561,6 +2145,77 @@ mod tests {
         assert!(!store.is_blocked("bd-bc2"));
     }
 
+    #[test]
+    fn test_conditional_blocks_follows_condition() {
+        let mut store = InMemoryStore::new();
+        store
+            .create_issue(&make_issue("bd-cb1", "Blocker"), "user")
+            .unwrap();
+        store
+            .create_issue(&make_issue("bd-cb2", "Release"), "user")
+            .unwrap();
+        store
+            .create_issue(&make_issue("bd-cb3", "Always waits"), "user")
+            .unwrap();
+        let condition = DependencyCondition::Label("release-blocker".to_string());
+        store
+            .add_dependency(
+                "bd-cb2",
+                "bd-cb1",
+                DependencyType::ConditionalBlocks,
+                "user",
+                Some(condition.to_metadata()),
+            )
+            .unwrap();
+        // Without a condition it blocks like `blocks`
+        store
+            .add_dependency(
+                "bd-cb3",
+                "bd-cb1",
+                DependencyType::ConditionalBlocks,
+                "user",
+                None,
+            )
+            .unwrap();
+
+        assert!(!store.is_blocked("bd-cb2"));
+        assert!(store.is_blocked("bd-cb3"));
+
+        store
+            .add_label("bd-cb1", "release-blocker", "user")
+            .unwrap();
+        assert!(store.is_blocked("bd-cb2"));
+        assert_eq!(store.get_blockers("bd-cb2")[0].id, "bd-cb1");
+    }
+
+    #[test]
+    fn test_waits_for_until_stops_blocking() {
+        let mut store = InMemoryStore::new();
+        for (id, title) in [
+            ("bd-wf1", "Vendor release"),
+            ("bd-wf2", "Woke up"),
+            ("bd-wf3", "Still waiting"),
+        ] {
+            store.create_issue(&make_issue(id, title), "user").unwrap();
+        }
+        let passed = WaitUntil(Utc::now() - chrono::Duration::hours(1));
+        let pending = WaitUntil(Utc::now() + chrono::Duration::days(3));
+        for (id, until) in [("bd-wf2", passed), ("bd-wf3", pending)] {
+            store
+                .add_dependency(
+                    id,
+                    "bd-wf1",
+                    DependencyType::WaitsFor,
+                    "user",
+                    Some(until.to_metadata()),
+                )
+                .unwrap();
+        }
+
+        assert!(!store.is_blocked("bd-wf2"));
+        assert!(store.is_blocked("bd-wf3"));
+    }
+
     #[test]
     fn test_labels() {
         let mut store = InMemoryStore::new();
@@ -1665,6 +2320,13 @@ mod tests {
         store.delete_comment("bd-dc1", c2.id, "user").unwrap();
         assert!(store.get_comments("bd-dc1").is_empty());
 
+        // Tombstones are kept for persistence and cannot be deleted twice
+        let all = store.get_all_comments();
+        assert_eq!(all[0].1.len(), 2);
+        assert!(all[0].1.iter().all(|c| c.deleted && c.updated_at.is_some()));
+        let err = store.delete_comment("bd-dc1", c1.id, "user").unwrap_err();
+        assert!(matches!(err, BeadsError::CommentNotFound { .. }));
+
         // Comment not found
         let err = store.delete_comment("bd-dc1", 999, "user").unwrap_err();
         assert!(matches!(err, BeadsError::CommentNotFound { .. }));
@@ -1743,6 +2405,53 @@ mod tests {
     }
 
     #[test]
+    fn test_on_change_observers_see_new_events() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::{Arc, Mutex};
+
+        let mut store = InMemoryStore::new();
+        store
+            .create_issue(&make_issue("bd-ob1", "Before"), "user")
+            .unwrap();
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        store.on_change(Box::new(move |event| {
+            sink.lock()
+                .unwrap()
+                .push((event.issue_id.clone(), event.event_type.clone()));
+        }));
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&count);
+        store.on_change(Box::new(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }));
+
+        store
+            .create_issue(&make_issue("bd-ob2", "After"), "user")
+            .unwrap();
+        store
+            .update_issue(
+                "bd-ob2",
+                &IssueUpdate {
+                    status: Some(Status::Closed),
+                    ..IssueUpdate::default()
+                },
+                "user",
+            )
+            .unwrap();
+
+        let seen = seen.lock().unwrap().clone();
+        assert_eq!(seen[0], ("bd-ob2".to_string(), EventType::Created));
+        assert!(seen.contains(&("bd-ob2".to_string(), EventType::Closed)));
+        assert!(seen.iter().all(|(id, _)| id == "bd-ob2"));
+        assert_eq!(count.load(Ordering::Relaxed), seen.len());
+        let recorded = store.get_all_events();
+        assert_eq!(recorded.len() - seen.len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "fs")]
     fn test_roundtrip_save_load() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("issues.jsonl");
@@ -1762,6 +2471,246 @@ mod tests {
         assert_eq!(loaded.get_comments("bd-rt1").len(), 1);
     }
 
+    #[test]
+    #[cfg(feature = "fs")]
+    fn test_autosave_policies_and_save_on_drop() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("issues.jsonl");
+        InMemoryStore::new().save_to(&path).unwrap();
+        let saved_ids = || -> Vec<String> {
+            InMemoryStore::open(&path)
+                .unwrap()
+                .get_all_issues_for_export()
+                .into_iter()
+                .map(|i| i.id)
+                .collect()
+        };
+
+        let mut store = InMemoryStore::open(&path).unwrap();
+        store.set_autosave(AutosavePolicy::EveryNMutations(2));
+        store
+            .create_issue(&make_issue("bd-as1", "One"), "user")
+            .unwrap();
+        assert!(saved_ids().is_empty());
+        store.add_label("bd-as1", "x", "user").unwrap();
+        assert_eq!(saved_ids(), vec!["bd-as1"]);
+        assert!(!store.is_dirty());
+
+        store.set_autosave(AutosavePolicy::OnDirty);
+        store
+            .create_issue(&make_issue("bd-as2", "Two"), "user")
+            .unwrap();
+        assert_eq!(saved_ids().len(), 2);
+
+        store.set_autosave(AutosavePolicy::Manual);
+        let mut guarded = store.save_on_drop();
+        guarded
+            .create_issue(&make_issue("bd-as3", "Three"), "user")
+            .unwrap();
+        assert_eq!(saved_ids().len(), 2);
+        drop(guarded);
+        assert_eq!(saved_ids().len(), 3);
+
+        let mut unsaved = InMemoryStore::new();
+        unsaved.set_autosave(AutosavePolicy::OnDirty);
+        unsaved
+            .create_issue(&make_issue("bd-as4", "Four"), "user")
+            .unwrap();
+        assert!(unsaved.is_dirty());
+        assert!(matches!(
+            unsaved.take_autosave_error(),
+            Some(BeadsError::Storage(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_jsonl_str_and_back() {
+        let mut store = InMemoryStore::new();
+        store
+            .create_issue(&make_issue("web-1", "Blocker"), "user")
+            .unwrap();
+        store
+            .create_issue(&make_issue("web-2", "Blocked"), "user")
+            .unwrap();
+        store
+            .add_dependency("web-2", "web-1", DependencyType::Blocks, "user", None)
+            .unwrap();
+        let blob = store.to_jsonl_string().unwrap();
+
+        let loaded = InMemoryStore::from_jsonl_str(&blob).unwrap();
+        assert_eq!(loaded.prefix(), "web");
+        assert!(loaded.is_blocked("web-2"));
+        let ready: Vec<&str> = loaded
+            .get_ready_issues(&ReadyFilters::default(), ReadySortPolicy::default())
+            .iter()
+            .map(|i| i.id.as_str())
+            .collect();
+        assert_eq!(ready, vec!["web-1"]);
+        assert_eq!(loaded.to_jsonl_string().unwrap(), blob);
+        assert!(loaded.save().is_err());
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip_through_json() {
+        let mut store = InMemoryStore::new();
+        store.set_prefix("snap");
+        store.set_config("owner", "team-a");
+        store
+            .create_issue(&make_issue("snap-1", "First"), "user")
+            .unwrap();
+        store
+            .create_issue(&make_issue("snap-2", "Second"), "user")
+            .unwrap();
+        store
+            .add_dependency("snap-2", "snap-1", DependencyType::Blocks, "user", None)
+            .unwrap();
+        store.add_label("snap-1", "backend", "user").unwrap();
+        store.add_comment("snap-1", "user", "Hello").unwrap();
+
+        // Compare serialized forms: derived fields like `content_hash` are skipped
+        let snapshot = store.to_snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let parsed: Snapshot = serde_json::from_str(&json).unwrap();
+        let mut restored = InMemoryStore::from_snapshot(parsed).unwrap();
+        assert_eq!(
+            serde_json::to_string(&restored.to_snapshot()).unwrap(),
+            json
+        );
+        assert!(!restored.is_dirty());
+        assert_eq!(restored.get_config("owner"), Some("team-a"));
+        assert!(restored.is_blocked("snap-2"));
+        assert_eq!(
+            restored.get_all_events().len(),
+            store.get_all_events().len()
+        );
+
+        let comment = restored.add_comment("snap-2", "user", "Next").unwrap();
+        assert_eq!(comment.id, 2);
+        let last_event = restored.get_all_events().last().unwrap().id;
+        assert_eq!(last_event, store.get_all_events().last().unwrap().id + 1);
+        let created = restored
+            .create_issue(&make_issue("", "Generated"), "user")
+            .unwrap();
+        assert!(created.id.starts_with("snap-"));
+
+        let mut future = snapshot;
+        future.version = SNAPSHOT_VERSION + 1;
+        assert!(matches!(
+            InMemoryStore::from_snapshot(future),
+            Err(BeadsError::Validation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_custom_persistence_roundtrip() {
+        use crate::persistence::MemoryOnly;
+        use std::sync::{Arc, Mutex};
+
+        /// Records kept in a shared vector, standing in for an app database.
+        struct Shared(Arc<Mutex<Vec<Issue>>>);
+
+        impl Persistence for Shared {
+            fn load(&self) -> Result<Vec<Issue>> {
+                Ok(self.0.lock().unwrap().clone())
+            }
+
+            fn save(&self, records: &[Issue]) -> Result<()> {
+                *self.0.lock().unwrap() = records.to_vec();
+                Ok(())
+            }
+        }
+
+        let backing = Arc::new(Mutex::new(Vec::new()));
+        let mut store =
+            InMemoryStore::with_persistence(Box::new(Shared(Arc::clone(&backing)))).unwrap();
+        store
+            .create_issue(&make_issue("app-1", "First"), "user")
+            .unwrap();
+        store
+            .create_issue(&make_issue("app-2", "Second"), "user")
+            .unwrap();
+        store
+            .add_dependency("app-2", "app-1", DependencyType::Blocks, "user", None)
+            .unwrap();
+        store.add_label("app-1", "backend", "user").unwrap();
+        store.save().unwrap();
+
+        let saved: Vec<String> = backing
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|i| i.id.clone())
+            .collect();
+        assert_eq!(saved, vec!["app-1", "app-2"]);
+        assert_eq!(backing.lock().unwrap()[0].labels, vec!["backend"]);
+
+        let reloaded = InMemoryStore::with_persistence(Box::new(Shared(backing))).unwrap();
+        assert_eq!(reloaded.prefix(), "app");
+        assert_eq!(reloaded.get_labels("app-1"), vec!["backend"]);
+        assert_eq!(reloaded.get_dependencies("app-2").len(), 1);
+        assert_eq!(reloaded.get_dependents("app-1").len(), 1);
+
+        let memory = InMemoryStore::with_persistence(Box::new(MemoryOnly)).unwrap();
+        assert!(memory.save().is_ok());
+        assert!(InMemoryStore::new().save().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "fs")]
+    fn test_open_streaming_skips_filtered_issues_and_their_edges() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("issues.jsonl");
+
+        let mut store = InMemoryStore::new();
+        for id in ["bd-st1", "bd-st2", "bd-st3"] {
+            store.create_issue(&make_issue(id, id), "user").unwrap();
+        }
+        store
+            .add_dependency("bd-st1", "bd-st2", DependencyType::Related, "user", None)
+            .unwrap();
+        let long_ago = Utc::now() - chrono::Duration::days(400);
+        store
+            .update_issue(
+                "bd-st2",
+                &IssueUpdate {
+                    status: Some(Status::Closed),
+                    closed_at: Some(Some(long_ago)),
+                    ..Default::default()
+                },
+                "user",
+            )
+            .unwrap();
+        store
+            .update_issue(
+                "bd-st3",
+                &IssueUpdate {
+                    status: Some(Status::Tombstone),
+                    ..Default::default()
+                },
+                "user",
+            )
+            .unwrap();
+        store.save_to(&path).unwrap();
+
+        let full = InMemoryStore::open(&path).unwrap();
+        assert!(full.get_issue("bd-st3").is_ok());
+        assert_eq!(full.get_dependencies("bd-st1").len(), 1);
+
+        let recent = InMemoryStore::open_streaming(
+            &path,
+            &LoadFilters {
+                skip_tombstones: true,
+                closed_within_days: Some(90),
+            },
+        )
+        .unwrap();
+        assert!(recent.get_issue("bd-st1").is_ok());
+        assert!(recent.get_issue("bd-st2").is_err());
+        assert!(recent.get_issue("bd-st3").is_err());
+        assert!(recent.get_dependencies("bd-st1").is_empty());
+        assert!(recent.get_all_dependency_records().is_empty());
+    }
+
     #[test]
     fn test_list_with_label_filter() {
         let mut store = InMemoryStore::new();
@@ -1781,6 +2730,78 @@ mod tests {
         assert_eq!(filtered[0].id, "bd-lf1");
     }
 
+    #[test]
+    fn test_list_issues_with_counts() {
+        let mut store = InMemoryStore::new();
+        for id in ["bd-wc1", "bd-wc2", "bd-wc3"] {
+            store.create_issue(&make_issue(id, id), "user").unwrap();
+        }
+        store
+            .add_dependency("bd-wc2", "bd-wc1", DependencyType::Blocks, "user", None)
+            .unwrap();
+        store
+            .add_dependency("bd-wc3", "bd-wc1", DependencyType::Related, "user", None)
+            .unwrap();
+        store
+            .add_dependency("bd-wc3", "bd-wc2", DependencyType::Blocks, "user", None)
+            .unwrap();
+        store.remove_dependency("bd-wc3", "bd-wc2", "user").unwrap();
+
+        let mut counts: Vec<(&str, usize, usize)> = store
+            .list_issues_with_counts(&ListFilters::default())
+            .into_iter()
+            .map(|(issue, deps, dependents)| (issue.id.as_str(), deps, dependents))
+            .collect();
+        counts.sort_unstable();
+        assert_eq!(
+            counts,
+            vec![("bd-wc1", 0, 2), ("bd-wc2", 1, 0), ("bd-wc3", 1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_query_builder() {
+        let mut store = InMemoryStore::new();
+        let mut urgent = make_issue("bd-qb1", "Crash on save");
+        urgent.priority = Priority::CRITICAL;
+        store.create_issue(&urgent, "user").unwrap();
+        store
+            .create_issue(&make_issue("bd-qb2", "Slow search"), "user")
+            .unwrap();
+        let mut closed = make_issue("bd-qb3", "Old crash");
+        closed.status = Status::Closed;
+        store.create_issue(&closed, "user").unwrap();
+        for id in ["bd-qb1", "bd-qb2", "bd-qb3"] {
+            store.add_label(id, "bug", "user").unwrap();
+        }
+        store.add_label("bd-qb1", "ui", "user").unwrap();
+
+        let ids = |issues: Vec<&Issue>| -> Vec<String> {
+            issues.into_iter().map(|i| i.id.clone()).collect()
+        };
+        let query = store
+            .query()
+            .status(Status::Open)
+            .label("bug")
+            .sort_priority()
+            .limit(10);
+        assert_eq!(ids(query.run()), ["bd-qb1", "bd-qb2"]);
+        assert_eq!(query.filters().statuses, Some(vec![Status::Open]));
+
+        assert_eq!(
+            ids(store.query().label("bug").label("ui").run()),
+            ["bd-qb1"]
+        );
+        let with_closed = store.query().title_contains("crash").include_closed();
+        assert_eq!(with_closed.count(), 2);
+        let reversed = with_closed.sort_title().reverse().limit(1);
+        assert_eq!(ids(reversed.run()), ["bd-qb3"]);
+        assert_eq!(reversed.count(), 2);
+
+        let filters = store.query().status(Status::Closed).into_filters();
+        assert_eq!(ids(store.list_issues(&filters)), ["bd-qb3"]);
+    }
+
     #[test]
     fn test_get_unique_labels_with_counts() {
         let mut store = InMemoryStore::new();
@@ -1936,6 +2957,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fs")]
     #[allow(clippy::too_many_lines)]
     fn test_load_project_jsonl() {
         // Load the project's own .beads/issues.jsonl if it exists
@@ -57,10 +57,8 @@ where
                     }
                     let entropy = format!(
                         "{}|{}|{}",
-                        std::process::id(),
-                        std::time::SystemTime::now()
-                            .duration_since(std::time::UNIX_EPOCH)
-                            .map_or(0, |d| d.as_nanos()),
+                        process_id(),
+                        Utc::now().timestamp_nanos_opt().unwrap_or_default(),
                         nonce,
                     );
                     return format!("{prefix}-{}", compute_id_hash(&entropy, 12));
@@ -70,6 +68,18 @@ where
     }
 }
 
+/// This process's ID; `0` on wasm32, which has no processes.
+fn process_id() -> u32 {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::process::id()
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        0
+    }
+}
+
 /// Compute the optimal hash length for a given issue count.
 #[must_use]
 #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
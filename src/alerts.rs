@@ -0,0 +1,339 @@
+//! Alert hooks for humans watching a workspace.
+//!
+//! When `alerts.command` or `alerts.desktop` is set, `br ready` and `br sync`
+//! compare the workspace with what they saw last time and raise an alert for
+//! each issue that has become unblocked (was blocked, is now ready) or
+//! overdue. The previous state lives in `alerts-seen.json` in the cache
+//! directory (`BEADS_CACHE_DIR`, or `.beads/`); the first run only records
+//! it, so enabling alerts does not replay the whole backlog.
+//!
+//! The `alerts.*` keys are read only from user config and the environment.
+//! `.beads/config.yaml` is committed with the repository, so setting them
+//! there is an error rather than a way to run commands on every clone.
+//!
+//! `alerts.command` runs through the shell once per alert with `BR_ALERT`
+//! (`unblocked` or `overdue`), `BR_ISSUE_ID`, `BR_ISSUE_TITLE` and
+//! `BR_ALERT_MESSAGE` set. `alerts.desktop` uses `notify-send` (or
+//! `osascript` on macOS). Each hook may run for `alerts.timeout_ms` (default
+//! 5s) before it is killed, so a hung hook cannot stall the command. A
+//! failing hook is logged and never fails the command.
+
+use crate::config::{self, AlertConfig, CliOverrides};
+use crate::error::Result;
+use crate::model::Issue;
+use crate::storage::{ListFilters, ReadyFilters, ReadySortPolicy, Storage};
+use crate::util::resolve_cache_dir;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// How often a running hook is checked for exit.
+const HOOK_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// What was blocked and overdue at the last check, in the cache directory.
+pub const ALERTS_STATE_FILE: &str = "alerts-seen.json";
+
+/// Why an alert was raised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    Unblocked,
+    Overdue,
+}
+
+impl AlertKind {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unblocked => "unblocked",
+            Self::Overdue => "overdue",
+        }
+    }
+}
+
+/// One state change worth telling a human about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub id: String,
+    pub title: String,
+}
+
+impl Alert {
+    /// One-line description for notifications.
+    #[must_use]
+    pub fn message(&self) -> String {
+        match self.kind {
+            AlertKind::Unblocked => format!("{} is unblocked: {}", self.id, self.title),
+            AlertKind::Overdue => format!("{} is overdue: {}", self.id, self.title),
+        }
+    }
+}
+
+/// Issue IDs seen at the last check.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct SeenState {
+    blocked: BTreeSet<String>,
+    overdue: BTreeSet<String>,
+}
+
+/// Raise alerts for changes since the last check, if alerts are set up.
+///
+/// Does nothing in read-only mode, since it records what it has seen.
+///
+/// # Errors
+///
+/// Returns an error if the alert settings are invalid, the database cannot
+/// be read, or the state file cannot be written. Hook failures are only
+/// logged.
+pub fn check(beads_dir: &Path, storage: &dyn Storage, cli: &CliOverrides) -> Result<Vec<Alert>> {
+    if cli.read_only.unwrap_or(false) || storage.is_read_only() {
+        return Ok(Vec::new());
+    }
+    let layer = config::load_user_startup_config(beads_dir)?;
+    let alert_config = config::alerts_from_layer(&layer)?;
+    if !alert_config.is_enabled() {
+        return Ok(Vec::new());
+    }
+
+    let path = state_path(beads_dir);
+    let previous = fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<SeenState>(&content).ok());
+    let (alerts, current) = detect(storage, previous.as_ref(), Utc::now())?;
+    fs::write(&path, serde_json::to_string(&current)?)?;
+
+    for alert in &alerts {
+        raise(&alert_config, alert);
+    }
+    Ok(alerts)
+}
+
+/// [`check`], logging errors instead of returning them, for commands that
+/// must not fail because of an alert hook.
+pub fn check_or_warn(beads_dir: &Path, storage: &dyn Storage, cli: &CliOverrides) {
+    if let Err(e) = check(beads_dir, storage, cli) {
+        warn!(error = %e, "Alert hooks skipped");
+        eprintln!("Warning: alerts skipped: {e}");
+    }
+}
+
+fn state_path(beads_dir: &Path) -> PathBuf {
+    resolve_cache_dir(beads_dir).join(ALERTS_STATE_FILE)
+}
+
+/// Compare the workspace with `previous`; with no previous state nothing is
+/// reported.
+fn detect(
+    storage: &dyn Storage,
+    previous: Option<&SeenState>,
+    now: DateTime<Utc>,
+) -> Result<(Vec<Alert>, SeenState)> {
+    let blocked = storage.get_blocked_ids()?;
+    let overdue: Vec<Issue> = storage
+        .list_issues(&ListFilters::default())?
+        .into_iter()
+        .filter(|issue| issue.due_at.is_some_and(|due| due < now) && !issue.status.is_terminal())
+        .collect();
+    let current = SeenState {
+        blocked: blocked.into_iter().collect(),
+        overdue: overdue.iter().map(|issue| issue.id.clone()).collect(),
+    };
+    let Some(previous) = previous else {
+        return Ok((Vec::new(), current));
+    };
+
+    let mut alerts: Vec<Alert> = storage
+        .get_ready_issues(&ReadyFilters::default(), ReadySortPolicy::default())?
+        .into_iter()
+        .filter(|issue| previous.blocked.contains(&issue.id))
+        .map(|issue| Alert {
+            kind: AlertKind::Unblocked,
+            id: issue.id,
+            title: issue.title,
+        })
+        .collect();
+    alerts.extend(
+        overdue
+            .into_iter()
+            .filter(|issue| !previous.overdue.contains(&issue.id))
+            .map(|issue| Alert {
+                kind: AlertKind::Overdue,
+                id: issue.id,
+                title: issue.title,
+            }),
+    );
+    Ok((alerts, current))
+}
+
+/// Run the configured hooks for one alert.
+fn raise(alert_config: &AlertConfig, alert: &Alert) {
+    debug!(kind = alert.kind.as_str(), id = %alert.id, "Raising alert");
+    let message = alert.message();
+    if let Some(command) = &alert_config.command {
+        let mut shell = shell_command(command);
+        shell
+            .env("BR_ALERT", alert.kind.as_str())
+            .env("BR_ISSUE_ID", &alert.id)
+            .env("BR_ISSUE_TITLE", &alert.title)
+            .env("BR_ALERT_MESSAGE", &message);
+        run_hook(shell, "alerts.command", alert_config.timeout);
+    }
+    if alert_config.desktop {
+        run_hook(
+            desktop_command(&message),
+            "alerts.desktop",
+            alert_config.timeout,
+        );
+    }
+}
+
+/// Run a hook, killing it if it is still running after `timeout`.
+fn run_hook(mut command: Command, source: &str, timeout: Duration) {
+    let mut child = match command.stdin(Stdio::null()).stdout(Stdio::null()).spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            warn!(source, error = %e, "Alert hook could not run");
+            return;
+        }
+    };
+    let deadline = Instant::now() + timeout;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) if status.success() => return,
+            Ok(Some(status)) => {
+                warn!(source, %status, "Alert hook failed");
+                return;
+            }
+            Ok(None) if Instant::now() < deadline => thread::sleep(HOOK_POLL_INTERVAL),
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                warn!(source, ?timeout, "Alert hook timed out and was killed");
+                return;
+            }
+            Err(e) => {
+                warn!(source, error = %e, "Alert hook could not be waited on");
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+fn shell_command(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.args(["/C", command]);
+    shell
+}
+
+#[cfg(not(windows))]
+fn shell_command(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.args(["-c", command]);
+    shell
+}
+
+#[cfg(target_os = "macos")]
+fn desktop_command(message: &str) -> Command {
+    let mut osascript = Command::new("osascript");
+    osascript
+        .arg("-e")
+        .arg("on run argv\ndisplay notification (item 1 of argv) with title \"beads\"\nend run")
+        .arg(message);
+    osascript
+}
+
+#[cfg(not(target_os = "macos"))]
+fn desktop_command(message: &str) -> Command {
+    let mut notify_send = Command::new("notify-send");
+    notify_send.args(["beads", message]);
+    notify_send
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Status;
+    use crate::storage::SqliteStorage;
+    use chrono::Duration;
+
+    fn make_issue(id: &str, title: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            ..Issue::default()
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_hook_kills_hook_past_timeout() {
+        let started = Instant::now();
+        run_hook(
+            shell_command("sleep 10"),
+            "test",
+            std::time::Duration::from_millis(100),
+        );
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_detect_reports_unblocked_and_overdue_changes() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let now = Utc::now();
+        storage
+            .create_issue(&make_issue("bd-1", "Blocker"), "tester")
+            .unwrap();
+        storage
+            .create_issue(&make_issue("bd-2", "Waiting"), "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-2", "bd-1", "blocks", "tester")
+            .unwrap();
+
+        // The first check only records state
+        let (alerts, seen) = detect(&storage, None, now).unwrap();
+        assert!(alerts.is_empty());
+        assert!(seen.blocked.contains("bd-2"));
+
+        storage
+            .update_issue(
+                "bd-1",
+                &crate::storage::IssueUpdate {
+                    status: Some(Status::Closed),
+                    due_at: Some(Some(now - Duration::days(1))),
+                    ..Default::default()
+                },
+                "tester",
+            )
+            .unwrap();
+        storage
+            .update_issue(
+                "bd-2",
+                &crate::storage::IssueUpdate {
+                    due_at: Some(Some(now - Duration::hours(1))),
+                    ..Default::default()
+                },
+                "tester",
+            )
+            .unwrap();
+        let (alerts, seen) = detect(&storage, Some(&seen), now).unwrap();
+        let kinds: Vec<_> = alerts.iter().map(|a| (a.kind, a.id.as_str())).collect();
+        // Closed issues are never overdue
+        assert_eq!(
+            kinds,
+            vec![(AlertKind::Unblocked, "bd-2"), (AlertKind::Overdue, "bd-2")]
+        );
+
+        // Nothing changed since, so nothing new
+        let (alerts, _) = detect(&storage, Some(&seen), now).unwrap();
+        assert!(alerts.is_empty());
+    }
+}
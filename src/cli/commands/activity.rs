@@ -0,0 +1,371 @@
+//! Activity command implementation.
+//!
+//! `br activity` merges the event history and comments of every issue into
+//! one feed, newest first, so a supervisor can review what agents did.
+//! Comments recorded without a `commented` event (e.g. imported ones) are
+//! included from the comments table. `--follow` prints the feed oldest first
+//! and then polls for new events, like `tail -f`. `--actor` filters the feed
+//! to one actor.
+
+use crate::cli::ActivityArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::truncate_title;
+use crate::model::{Comment, Event, EventType};
+use crate::output::OutputContext;
+use crate::storage::{ListFilters, Storage};
+use crate::util::time::parse_since;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::thread;
+use std::time::Duration;
+
+/// How often `--follow` checks for new events.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Comment text shown in text output before it is cut off.
+const COMMENT_PREVIEW_WIDTH: usize = 60;
+
+/// One entry in the feed.
+#[derive(Debug, Clone, Serialize)]
+struct ActivityItem {
+    at: DateTime<Utc>,
+    issue_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(rename = "type")]
+    event_type: String,
+    actor: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_value: Option<String>,
+    /// Comment text, or the reason given for a close or reopen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_id: Option<i64>,
+}
+
+/// Filters from the command line.
+#[derive(Debug)]
+struct Filters {
+    since: Option<DateTime<Utc>>,
+    actor: Option<String>,
+    types: HashSet<String>,
+    session: Option<String>,
+}
+
+impl Filters {
+    fn matches(&self, item: &ActivityItem) -> bool {
+        self.since.is_none_or(|since| item.at >= since)
+            && self.actor.as_ref().is_none_or(|actor| &item.actor == actor)
+            && (self.types.is_empty() || self.types.contains(&item.event_type))
+            && self
+                .session
+                .as_ref()
+                .is_none_or(|session| item.session_id.as_ref() == Some(session))
+    }
+}
+
+/// Execute the activity command.
+///
+/// # Errors
+///
+/// Returns an error if a filter is invalid or database operations fail.
+pub fn execute(args: &ActivityArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &*storage_ctx.storage;
+
+    let events = storage.get_all_events(0)?;
+    let filters = Filters {
+        since: args
+            .since
+            .as_deref()
+            .map(|since| parse_since(since, "since"))
+            .transpose()?,
+        actor: args.actor.clone(),
+        types: parse_types(&args.types)?,
+        session: match args.session.as_deref() {
+            // Events come newest first
+            Some("last") => Some(
+                events
+                    .iter()
+                    .find_map(|event| event.session_id.clone())
+                    .ok_or_else(|| {
+                        BeadsError::validation("session", "no events have a session ID")
+                    })?,
+            ),
+            session => session.map(str::to_string),
+        },
+    };
+
+    let titles: HashMap<String, String> = storage
+        .list_issues(&ListFilters {
+            include_closed: true,
+            include_deferred: true,
+            include_templates: true,
+            ..ListFilters::default()
+        })?
+        .into_iter()
+        .map(|issue| (issue.id, issue.title))
+        .collect();
+    let last_event_id = events.first().map_or(0, |event| event.id);
+
+    let mut items = merge_feed(events, storage.get_all_comments()?, &titles);
+    items.retain(|item| filters.matches(item));
+    if args.limit > 0 {
+        items.truncate(args.limit);
+    }
+
+    if !args.follow {
+        if ctx.is_json() {
+            ctx.json_pretty(&items);
+        } else if !ctx.is_quiet() {
+            if items.is_empty() {
+                println!("No activity.");
+            }
+            for item in &items {
+                print_item(item);
+            }
+        }
+        return Ok(());
+    }
+
+    for item in items.iter().rev() {
+        emit(item, ctx);
+    }
+    follow(storage, last_event_id, &filters, ctx)
+}
+
+/// Validate `--type` names.
+fn parse_types(types: &[String]) -> Result<HashSet<String>> {
+    types
+        .iter()
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            match serde_json::from_value::<EventType>(serde_json::Value::String(name.to_string())) {
+                Ok(EventType::Custom(_)) | Err(_) => Err(BeadsError::validation(
+                    "type",
+                    format!("unknown event type '{name}'"),
+                )),
+                Ok(event_type) => Ok(event_type.as_str().to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Events and comments, newest first. A comment that also has a `commented`
+/// event with the same author and text is only listed once.
+fn merge_feed(
+    events: Vec<Event>,
+    comments: HashMap<String, Vec<Comment>>,
+    titles: &HashMap<String, String>,
+) -> Vec<ActivityItem> {
+    let commented: HashSet<(String, String, String)> = events
+        .iter()
+        .filter(|event| event.event_type == EventType::Commented)
+        .map(|event| {
+            (
+                event.issue_id.clone(),
+                event.actor.clone(),
+                event.comment.clone().unwrap_or_default(),
+            )
+        })
+        .collect();
+    let mut items: Vec<ActivityItem> = events
+        .into_iter()
+        .map(|event| item_from_event(event, titles))
+        .collect();
+    items.extend(
+        comments
+            .into_values()
+            .flatten()
+            .filter(|comment| !comment.deleted)
+            .filter(|comment| {
+                !commented.contains(&(
+                    comment.issue_id.clone(),
+                    comment.author.clone(),
+                    comment.body.clone(),
+                ))
+            })
+            .map(|comment| ActivityItem {
+                at: comment.created_at,
+                title: titles.get(&comment.issue_id).cloned(),
+                issue_id: comment.issue_id,
+                event_type: EventType::Commented.as_str().to_string(),
+                actor: comment.author,
+                old_value: None,
+                new_value: None,
+                comment: Some(comment.body),
+                session_id: None,
+                event_id: None,
+            }),
+    );
+    items.sort_by(|a, b| b.at.cmp(&a.at).then(b.event_id.cmp(&a.event_id)));
+    items
+}
+
+fn item_from_event(event: Event, titles: &HashMap<String, String>) -> ActivityItem {
+    ActivityItem {
+        at: event.created_at,
+        title: titles.get(&event.issue_id).cloned(),
+        issue_id: event.issue_id,
+        event_type: event.event_type.as_str().to_string(),
+        actor: event.actor,
+        old_value: event.old_value,
+        new_value: event.new_value,
+        comment: event.comment,
+        session_id: event.session_id,
+        event_id: Some(event.id),
+    }
+}
+
+/// Poll for events newer than `after_id` and print those that match, until
+/// the process is interrupted.
+fn follow(
+    storage: &dyn Storage,
+    mut after_id: i64,
+    filters: &Filters,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let mut titles: HashMap<String, String> = HashMap::new();
+    loop {
+        thread::sleep(FOLLOW_POLL_INTERVAL);
+        for event in storage.get_events_after(after_id)? {
+            after_id = event.id;
+            if !titles.contains_key(&event.issue_id) {
+                if let Some(issue) = storage.get_issue(&event.issue_id)? {
+                    titles.insert(issue.id, issue.title);
+                }
+            }
+            let item = item_from_event(event, &titles);
+            if filters.matches(&item) {
+                emit(&item, ctx);
+            }
+        }
+    }
+}
+
+/// Print one item while following: a JSON line, or a text line.
+fn emit(item: &ActivityItem, ctx: &OutputContext) {
+    if ctx.is_json() {
+        if let Ok(line) = serde_json::to_string(item) {
+            println!("{line}");
+        }
+    } else if !ctx.is_quiet() {
+        print_item(item);
+    }
+}
+
+fn print_item(item: &ActivityItem) {
+    println!(
+        "{}  {:<12} {:<10} {}",
+        item.at.format("%Y-%m-%d %H:%M"),
+        item.actor,
+        item.issue_id,
+        describe(item)
+    );
+}
+
+/// What happened, in words.
+fn describe(item: &ActivityItem) -> String {
+    let title = item.title.as_deref().map_or_else(String::new, |title| {
+        format!(" \"{}\"", truncate_title(title, 40))
+    });
+    let detail = match (item.event_type.as_str(), &item.old_value, &item.new_value) {
+        ("commented", _, _) => {
+            let text = item.comment.as_deref().unwrap_or("");
+            let first_line = text.lines().next().unwrap_or("");
+            return format!(
+                "commented{title}: {}",
+                truncate_title(first_line, COMMENT_PREVIEW_WIDTH)
+            );
+        }
+        (_, Some(old), Some(new)) => format!(" ({old} -> {new})"),
+        (_, None, Some(new)) => format!(" ({new})"),
+        _ => String::new(),
+    };
+    let reason = match (item.event_type.as_str(), &item.comment) {
+        ("closed" | "reopened", Some(reason)) if !reason.is_empty() => format!(": {reason}"),
+        _ => String::new(),
+    };
+    format!(
+        "{}{title}{detail}{reason}",
+        item.event_type.replace('_', " ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn event(id: i64, issue_id: &str, event_type: EventType, minute: u32) -> Event {
+        Event {
+            id,
+            issue_id: issue_id.to_string(),
+            event_type,
+            actor: "agent".to_string(),
+            old_value: None,
+            new_value: None,
+            comment: None,
+            created_at: Utc.with_ymd_and_hms(2026, 5, 1, 12, minute, 0).unwrap(),
+            session_id: None,
+        }
+    }
+
+    fn comment(issue_id: &str, body: &str, minute: u32) -> Comment {
+        Comment {
+            id: 1,
+            issue_id: issue_id.to_string(),
+            author: "agent".to_string(),
+            body: body.to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 5, 1, 12, minute, 0).unwrap(),
+            updated_at: None,
+            deleted: false,
+        }
+    }
+
+    #[test]
+    fn test_merge_feed_orders_newest_first_without_duplicates() {
+        let mut noted = event(2, "bd-1", EventType::Commented, 5);
+        noted.comment = Some("Looks good".to_string());
+        let events = vec![noted, event(1, "bd-1", EventType::Created, 0)];
+        let comments = HashMap::from([(
+            "bd-1".to_string(),
+            vec![
+                comment("bd-1", "Looks good", 5),
+                comment("bd-1", "Imported", 9),
+            ],
+        )]);
+        let titles = HashMap::from([("bd-1".to_string(), "First".to_string())]);
+
+        let items = merge_feed(events, comments, &titles);
+        let summary: Vec<(&str, Option<&str>)> = items
+            .iter()
+            .map(|item| (item.event_type.as_str(), item.comment.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("commented", Some("Imported")),
+                ("commented", Some("Looks good")),
+                ("created", None),
+            ]
+        );
+        assert_eq!(items[2].title.as_deref(), Some("First"));
+    }
+
+    #[test]
+    fn test_parse_types_rejects_unknown_names() {
+        let types = parse_types(&["closed".to_string(), " status_changed".to_string()]).unwrap();
+        assert!(types.contains("status_changed"));
+        assert!(parse_types(&["exploded".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+//! Apply command implementation, and `--plan` recording.
+//!
+//! `br --plan <command>` runs a mutating command against a snapshot of the
+//! database and prints the resulting [`ChangePlan`] as JSON instead of
+//! writing anything. `br apply <file>` replays a plan after checking that the
+//! issues it touches have not changed since it was recorded.
+
+use crate::cli::ApplyArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::storage::{ChangePlan, SqliteStorage};
+use crate::util::BEADS_CACHE_DIR_ENV;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process::{self, Command};
+use tracing::debug;
+
+/// Global flags that `--plan` drops before re-running the command.
+const STRIPPED_FLAGS: [&str; 2] = ["--plan", "--read-only"];
+
+/// Execute the apply command.
+///
+/// # Errors
+///
+/// Returns an error if the plan cannot be read or parsed, an issue changed
+/// since the plan was recorded (without `--force`), or a change fails.
+pub fn execute(args: &ApplyArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let content = if args.file.as_os_str() == "-" {
+        let mut buffer = String::new();
+        io::stdin().read_to_string(&mut buffer)?;
+        buffer
+    } else {
+        fs::read_to_string(&args.file)?
+    };
+    let plan: ChangePlan = serde_json::from_str(&content)
+        .map_err(|e| BeadsError::validation("plan", format!("not a change plan: {e}")))?;
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&layer);
+
+    let report = plan.apply(storage_ctx.storage.sqlite_mut("apply")?, &actor, args.force)?;
+    storage_ctx.flush_no_db_if_dirty()?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&report);
+    } else if !ctx.is_quiet() {
+        println!(
+            "Applied plan: {} created, {} updated",
+            report.created.len(),
+            report.updated.len()
+        );
+    }
+    Ok(())
+}
+
+/// Record the plan for the current command line and print it.
+///
+/// The command runs in a child `br` against a snapshot of the workspace in a
+/// private temporary directory, so the real database is only read. Commands
+/// that write other workspace files are refused before this is called. If the
+/// command fails, its output and exit code are passed through unchanged.
+///
+/// # Errors
+///
+/// Returns an error if the workspace uses `--no-db`, the snapshot cannot be
+/// taken, or the child cannot be started.
+pub fn execute_plan(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    if cli.no_db.unwrap_or(false) {
+        return Err(BeadsError::validation(
+            "plan",
+            "--plan needs a database; it cannot be used with --no-db",
+        ));
+    }
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut read_cli = cli.clone();
+    read_cli.read_only = Some(true);
+    let original = config::open_storage_with_cli(&beads_dir, &read_cli)?.storage;
+
+    let work_dir = tempfile::Builder::new().prefix("br-plan-").tempdir()?;
+    record_plan(original.sqlite("--plan")?, &beads_dir, work_dir.path(), ctx)
+}
+
+/// Workspace files copied next to the snapshot so the child sees the same
+/// configuration as the real workspace.
+const SNAPSHOT_FILES: [&str; 2] = ["config.yaml", "metadata.json"];
+
+fn record_plan(
+    original: &SqliteStorage,
+    beads_dir: &Path,
+    work_dir: &Path,
+    ctx: &OutputContext,
+) -> Result<()> {
+    // The child derives its workspace from `--db`, which must sit in `.beads/`.
+    let copy_beads_dir = work_dir.join(".beads");
+    fs::create_dir(&copy_beads_dir)?;
+    for name in SNAPSHOT_FILES {
+        let source = beads_dir.join(name);
+        if source.is_file() {
+            fs::copy(&source, copy_beads_dir.join(name))?;
+        }
+    }
+    let copy_path = copy_beads_dir.join("beads.db");
+    original.snapshot_to(&copy_path)?;
+
+    let args = child_args(std::env::args_os().skip(1), &copy_path);
+    debug!(?args, "Recording plan");
+    let output = Command::new(std::env::current_exe()?)
+        .args(&args)
+        .env_remove("BEADS_READONLY")
+        .env(crate::notify::DISABLE_ENV, "1")
+        .env(BEADS_CACHE_DIR_ENV, work_dir)
+        .output()?;
+    if !output.status.success() {
+        let _ = io::stdout().write_all(&output.stdout);
+        let _ = io::stderr().write_all(&output.stderr);
+        let _ = fs::remove_dir_all(work_dir);
+        process::exit(output.status.code().unwrap_or(1));
+    }
+
+    let command = std::env::args()
+        .skip(1)
+        .filter(|arg| arg != "--plan")
+        .collect::<Vec<_>>()
+        .join(" ");
+    let after = SqliteStorage::open(&copy_path)?;
+    let plan = ChangePlan::diff(original, &after, &command)?;
+    ctx.json_pretty(&plan);
+    Ok(())
+}
+
+/// The original arguments with `--plan`, `--read-only` and `--db` removed,
+/// pointed at the snapshot with auto-import and auto-flush off.
+fn child_args(mut args: impl Iterator<Item = OsString>, copy_path: &Path) -> Vec<OsString> {
+    let mut child = Vec::new();
+    while let Some(arg) = args.next() {
+        let text = arg.to_string_lossy();
+        if STRIPPED_FLAGS.contains(&text.as_ref()) || text.starts_with("--db=") {
+            continue;
+        }
+        if text == "--db" {
+            args.next();
+            continue;
+        }
+        child.push(arg);
+    }
+    child.extend([
+        OsString::from("--db"),
+        copy_path.as_os_str().to_owned(),
+        OsString::from("--no-auto-flush"),
+        OsString::from("--no-auto-import"),
+    ]);
+    child
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_child_args_point_at_snapshot() {
+        let args = [
+            "--plan",
+            "--db=/x.db",
+            "update",
+            "bd-1",
+            "--db",
+            "/y.db",
+            "-s",
+            "closed",
+        ]
+        .into_iter()
+        .map(OsString::from);
+        let child = child_args(args, Path::new("/tmp/copy.db"));
+        assert_eq!(
+            child,
+            [
+                "update",
+                "bd-1",
+                "-s",
+                "closed",
+                "--db",
+                "/tmp/copy.db",
+                "--no-auto-flush",
+                "--no-auto-import"
+            ]
+            .map(OsString::from)
+        );
+    }
+}
@@ -127,7 +127,7 @@ pub fn execute(
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let layer = config::load_config(&beads_dir, None, cli)?;
-    let actor = config::resolve_actor(&layer);
+    let actor = config::actor(&layer);
 
     match command {
         AuditCommands::Record(args) => record_entry(args, &beads_dir, &actor, ctx),
@@ -2,15 +2,18 @@
 //!
 //! Lists blocked issues from the `blocked_issues_cache`.
 
-use crate::cli::{BlockedArgs, OutputFormat, resolve_output_format_basic};
+use crate::cli::commands::list::attach_labels;
+use crate::cli::{BlockedArgs, OutputFormat, resolve_output_format};
 use crate::config::{
-    CliOverrides, discover_beads_dir, external_project_db_paths, load_config,
-    open_storage_with_cli, should_use_color,
+    CliOverrides, ConfigLayer, discover_beads_dir, external_project_db_paths, load_config,
+    open_storage_with_cli, should_use_color, table_max_width_from_layer,
 };
 use crate::error::Result;
-use crate::format::{BlockedIssue, BlockedIssueOutput};
-use crate::model::{IssueType, Priority};
+use crate::format::{BlockedIssue, BlockedIssueOutput, BlockerSummary, table, tabular};
+use crate::model::{Issue, IssueType, Priority};
 use crate::output::{OutputContext, OutputMode};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 use std::str::FromStr;
 
 /// Execute the blocked command.
@@ -32,14 +35,15 @@ pub fn execute(
 
     let beads_dir = discover_beads_dir(None)?;
     let storage_ctx = open_storage_with_cli(&beads_dir, overrides)?;
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
 
     let config_layer = load_config(&beads_dir, Some(storage), overrides)?;
     let external_db_paths = external_project_db_paths(&config_layer, &beads_dir);
     let use_color = should_use_color(&config_layer);
-    let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), args.robot);
+    let output_format =
+        resolve_output_format(args.format.map(Into::into), outer_ctx.is_json(), args.robot);
     let quiet = overrides.quiet.unwrap_or(false);
-    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+    let ctx = outer_ctx.with_output_format(output_format, quiet, use_color);
 
     // Get blocked issues from cache
     let blocked_raw = storage.get_blocked_issues()?;
@@ -65,7 +69,7 @@ pub fn execute(
     let external_blockers = storage.external_blockers(&external_statuses)?;
 
     if !external_blockers.is_empty() {
-        let mut by_id: std::collections::HashMap<String, usize> = blocked_issues
+        let mut by_id: HashMap<String, usize> = blocked_issues
             .iter()
             .enumerate()
             .map(|(idx, bi)| (bi.issue.id.clone(), idx))
@@ -99,6 +103,11 @@ pub fn execute(
     // Apply filters
     filter_by_type(&mut blocked_issues, &args.type_)?;
     filter_by_priority(&mut blocked_issues, &args.priority)?;
+    filter_by_assignee(
+        &mut blocked_issues,
+        args.assignee.as_deref(),
+        args.unassigned,
+    );
 
     // Filter by labels (AND logic) - need to fetch labels from storage
     if !args.label.is_empty() {
@@ -128,59 +137,42 @@ pub fn execute(
         return Ok(());
     }
 
+    let wake_times = storage.get_waits_for_wake_times()?;
     match output_format {
         OutputFormat::Json => {
-            let output: Vec<BlockedIssueOutput> = blocked_issues
-                .iter()
-                .map(|bi| BlockedIssueOutput {
-                    blocked_by: bi
-                        .blocked_by
-                        .iter()
-                        .map(|blocker_ref| blocker_id_from_ref(blocker_ref).to_string())
-                        .collect(),
-                    blocked_by_count: bi.blocked_by_count,
-                    created_at: bi.issue.created_at,
-                    created_by: bi.issue.created_by.clone(),
-                    description: bi.issue.description.clone(),
-                    id: bi.issue.id.clone(),
-                    issue_type: bi.issue.issue_type.clone(),
-                    priority: bi.issue.priority,
-                    status: bi.issue.status.clone(),
-                    title: bi.issue.title.clone(),
-                    updated_at: bi.issue.updated_at,
-                })
-                .collect();
+            let output = build_output(&blocked_issues, storage, &wake_times)?;
             ctx.json_pretty(&output);
         }
         OutputFormat::Toon => {
-            let output: Vec<BlockedIssueOutput> = blocked_issues
-                .iter()
-                .map(|bi| BlockedIssueOutput {
-                    blocked_by: bi
-                        .blocked_by
-                        .iter()
-                        .map(|blocker_ref| blocker_id_from_ref(blocker_ref).to_string())
-                        .collect(),
-                    blocked_by_count: bi.blocked_by_count,
-                    created_at: bi.issue.created_at,
-                    created_by: bi.issue.created_by.clone(),
-                    description: bi.issue.description.clone(),
-                    id: bi.issue.id.clone(),
-                    issue_type: bi.issue.issue_type.clone(),
-                    priority: bi.issue.priority,
-                    status: bi.issue.status.clone(),
-                    title: bi.issue.title.clone(),
-                    updated_at: bi.issue.updated_at,
-                })
-                .collect();
+            let output = build_output(&blocked_issues, storage, &wake_times)?;
             ctx.toon_with_stats(&output, args.stats);
         }
+        OutputFormat::Table => {
+            print_table(
+                &blocked_issues,
+                args.columns.as_deref(),
+                storage,
+                &config_layer,
+            )?;
+        }
         OutputFormat::Text | OutputFormat::Csv => {
             let max_width = if args.wrap { ctx.width() } else { 0 };
             if matches!(ctx.mode(), OutputMode::Rich) {
-                render_blocked_rich(&blocked_issues, args.detailed, storage, max_width);
+                render_blocked_rich(
+                    &blocked_issues,
+                    args.detailed,
+                    storage,
+                    &wake_times,
+                    max_width,
+                );
             } else {
-                print_text_output(&blocked_issues, args.detailed, storage, max_width);
+                print_text_output(
+                    &blocked_issues,
+                    args.detailed,
+                    storage,
+                    &wake_times,
+                    max_width,
+                );
             }
         }
     }
@@ -188,6 +180,44 @@ pub fn execute(
     Ok(())
 }
 
+/// Print `--format table` output; `blocked_by` lists the blocker IDs.
+fn print_table(
+    blocked_issues: &[BlockedIssue],
+    columns: Option<&str>,
+    storage: &dyn crate::storage::Storage,
+    config_layer: &ConfigLayer,
+) -> Result<()> {
+    let columns = table::parse_columns(columns, &["blocked_by"])?;
+    let max_width = table::max_width(table_max_width_from_layer(config_layer));
+    let mut issues: Vec<Issue> = blocked_issues.iter().map(|bi| bi.issue.clone()).collect();
+    if tabular::needs_labels(&columns) {
+        attach_labels(storage, &mut issues)?;
+    }
+    let blocked_by: HashMap<&str, String> = blocked_issues
+        .iter()
+        .map(|bi| {
+            let ids: Vec<&str> = bi
+                .blocked_by
+                .iter()
+                .map(|r| blocker_id_from_ref(r))
+                .collect();
+            (bi.issue.id.as_str(), ids.join(", "))
+        })
+        .collect();
+    print!(
+        "{}",
+        table::render_issues(&issues, &columns, max_width, |issue, column| {
+            (column == "blocked_by").then(|| {
+                blocked_by
+                    .get(issue.id.as_str())
+                    .cloned()
+                    .unwrap_or_default()
+            })
+        })
+    );
+    Ok(())
+}
+
 /// Sort blocked issues by priority (ascending), then by blocker count (descending).
 fn sort_blocked_issues(issues: &mut [BlockedIssue]) {
     issues.sort_by(|a, b| {
@@ -228,9 +258,18 @@ fn filter_by_priority(issues: &mut Vec<BlockedIssue>, priorities: &[String]) ->
     Ok(())
 }
 
+/// Filter blocked issues by assignee (exact match), or to unassigned ones.
+fn filter_by_assignee(issues: &mut Vec<BlockedIssue>, assignee: Option<&str>, unassigned: bool) {
+    if unassigned {
+        issues.retain(|bi| bi.issue.assignee.as_deref().is_none_or(str::is_empty));
+    } else if let Some(assignee) = assignee {
+        issues.retain(|bi| bi.issue.assignee.as_deref() == Some(assignee));
+    }
+}
+
 fn filter_by_labels(
     issues: &mut Vec<BlockedIssue>,
-    storage: &crate::storage::SqliteStorage,
+    storage: &dyn crate::storage::Storage,
     labels: &[String],
 ) -> Result<()> {
     let mut filtered = Vec::with_capacity(issues.len());
@@ -249,10 +288,45 @@ fn filter_by_labels(
     Ok(())
 }
 
+/// `(issue_id, depends_on_id)` -> wake time of a `waits-for` dependency.
+type WakeTimes = HashMap<(String, String), DateTime<Utc>>;
+
+fn wake_time(wake_times: &WakeTimes, issue_id: &str, blocker_id: &str) -> Option<DateTime<Utc>> {
+    wake_times
+        .get(&(issue_id.to_string(), blocker_id.to_string()))
+        .copied()
+}
+
+/// `until 2025-07-01 09:00 UTC (in 3d 4h)`.
+fn describe_wake(until: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    format!(
+        "until {} ({})",
+        until.format("%Y-%m-%d %H:%M UTC"),
+        format_countdown(until, now)
+    )
+}
+
+/// Time left until `until`, in its two largest units (`in 3d 4h`).
+fn format_countdown(until: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = until.signed_duration_since(now).num_minutes();
+    if minutes < 1 {
+        return "in <1m".to_string();
+    }
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    if days > 0 {
+        format!("in {days}d {hours}h")
+    } else if hours > 0 {
+        format!("in {hours}h {minutes}m")
+    } else {
+        format!("in {minutes}m")
+    }
+}
+
 fn print_text_output(
     blocked_issues: &[BlockedIssue],
     verbose: bool,
-    storage: &crate::storage::SqliteStorage,
+    storage: &dyn crate::storage::Storage,
+    wake_times: &WakeTimes,
     max_width: usize,
 ) {
     use crate::format::truncate_title;
@@ -292,9 +366,12 @@ fn print_text_output(
                     } else {
                         blocker.title.clone()
                     };
+                    let wake = wake_time(wake_times, &bi.issue.id, blocker_id)
+                        .map(|until| format!(" waits {}", describe_wake(until, Utc::now())))
+                        .unwrap_or_default();
                     println!(
-                        "    • {}: {} [P{}] [{}]",
-                        blocker_id, blocker_title, blocker.priority.0, blocker.status
+                        "    • {}: {} [P{}] [{}]{}",
+                        blocker_id, blocker_title, blocker.priority.0, blocker.status, wake
                     );
                 } else {
                     println!("    • {blocker_ref} (not found)");
@@ -315,10 +392,93 @@ fn print_text_output(
                 count,
                 ids.join(", ")
             );
+            for id in ids {
+                if let Some(until) = wake_time(wake_times, &bi.issue.id, id) {
+                    println!("  Waits for {id} {}", describe_wake(until, Utc::now()));
+                }
+            }
         }
     }
 }
 
+/// Build JSON/TOON output, looking up each blocker's title and priority.
+fn build_output(
+    blocked_issues: &[BlockedIssue],
+    storage: &dyn crate::storage::Storage,
+    wake_times: &WakeTimes,
+) -> Result<Vec<BlockedIssueOutput>> {
+    let mut blocker_ids: Vec<String> = blocked_issues
+        .iter()
+        .flat_map(|bi| bi.blocked_by.iter())
+        .map(|blocker_ref| blocker_id_from_ref(blocker_ref).to_string())
+        .collect();
+    blocker_ids.sort();
+    blocker_ids.dedup();
+    let blockers: HashMap<String, Issue> = storage
+        .get_issues_by_ids(&blocker_ids)?
+        .into_iter()
+        .map(|issue| (issue.id.clone(), issue))
+        .collect();
+
+    Ok(blocked_issues
+        .iter()
+        .map(|bi| BlockedIssueOutput {
+            blocked_by: bi
+                .blocked_by
+                .iter()
+                .map(|blocker_ref| blocker_id_from_ref(blocker_ref).to_string())
+                .collect(),
+            blocked_by_count: bi.blocked_by_count,
+            blockers: bi
+                .blocked_by
+                .iter()
+                .map(|blocker_ref| BlockerSummary {
+                    waits_until: wake_time(
+                        wake_times,
+                        &bi.issue.id,
+                        blocker_id_from_ref(blocker_ref),
+                    ),
+                    ..blocker_summary(blocker_ref, &blockers)
+                })
+                .collect(),
+            created_at: bi.issue.created_at,
+            created_by: bi.issue.created_by.clone(),
+            description: bi.issue.description.clone(),
+            id: bi.issue.id.clone(),
+            issue_type: bi.issue.issue_type.clone(),
+            priority: bi.issue.priority,
+            status: bi.issue.status.clone(),
+            title: bi.issue.title.clone(),
+            updated_at: bi.issue.updated_at,
+        })
+        .collect())
+}
+
+/// Describe one blocker ref (`id:status`), preferring the stored issue's
+/// current status over the one recorded in the ref.
+fn blocker_summary(blocker_ref: &str, issues: &HashMap<String, Issue>) -> BlockerSummary {
+    let id = blocker_id_from_ref(blocker_ref);
+    issues.get(id).map_or_else(
+        || BlockerSummary {
+            id: id.to_string(),
+            priority: None,
+            status: blocker_ref
+                .rsplit_once(':')
+                .map_or("unknown", |(_, status)| status)
+                .to_string(),
+            title: None,
+            waits_until: None,
+        },
+        |issue| BlockerSummary {
+            id: issue.id.clone(),
+            priority: Some(issue.priority),
+            status: issue.status.as_str().to_string(),
+            title: Some(issue.title.clone()),
+            waits_until: None,
+        },
+    )
+}
+
 fn blocker_id_from_ref(blocker_ref: &str) -> &str {
     // Split from the right to preserve external IDs containing ':'
     blocker_ref
@@ -326,10 +486,12 @@ fn blocker_id_from_ref(blocker_ref: &str) -> &str {
         .map_or(blocker_ref, |(prefix, _)| prefix)
 }
 
+#[allow(clippy::too_many_lines)]
 fn render_blocked_rich(
     blocked_issues: &[BlockedIssue],
     verbose: bool,
-    storage: &crate::storage::SqliteStorage,
+    storage: &dyn crate::storage::Storage,
+    wake_times: &WakeTimes,
     max_width: usize,
 ) {
     use crate::format::truncate_title;
@@ -416,6 +578,12 @@ fn render_blocked_rich(
                         .append_styled(&format!(" [P{}]", blocker.priority.0), Style::new().dim());
                     blocker_line
                         .append_styled(&format!(" [{}]", blocker.status), Style::new().dim());
+                    if let Some(until) = wake_time(wake_times, &bi.issue.id, blocker_id) {
+                        blocker_line.append_styled(
+                            &format!(" waits {}", describe_wake(until, Utc::now())),
+                            Style::new().color(color("blue")),
+                        );
+                    }
                 } else {
                     blocker_line.append_styled(" (not found)", Style::new().dim());
                 }
@@ -434,6 +602,17 @@ fn render_blocked_rich(
                 Style::new().color(color("yellow")),
             );
             console.print_renderable(&detail);
+            for id in ids {
+                if let Some(until) = wake_time(wake_times, &bi.issue.id, id) {
+                    let mut wake = Text::new("");
+                    wake.append_styled(&format!("  Waits for {id} "), Style::new().dim());
+                    wake.append_styled(
+                        &describe_wake(until, Utc::now()),
+                        Style::new().color(color("blue")),
+                    );
+                    console.print_renderable(&wake);
+                }
+            }
         }
     }
 }
@@ -445,6 +624,7 @@ mod tests {
     use crate::logging::init_test_logging;
     use crate::model::{Issue, IssueType, Priority, Status};
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
     use tracing::info;
 
     fn make_issue(id: &str, title: &str, priority: i32, issue_type: IssueType) -> Issue {
@@ -488,6 +668,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -694,4 +878,66 @@ mod tests {
         assert!(ids.contains(&"c"));
         info!("test_filter_by_priority_multiple: assertions passed");
     }
+
+    #[test]
+    fn test_filter_by_assignee() {
+        init_test_logging();
+        info!("test_filter_by_assignee: starting");
+        let mut issues = vec![
+            make_blocked_issue("a", "Alice's", 2, 1),
+            make_blocked_issue("b", "Bob's", 2, 1),
+            make_blocked_issue("c", "Nobody's", 2, 1),
+        ];
+        issues[0].issue.assignee = Some("alice".to_string());
+        issues[1].issue.assignee = Some("bob".to_string());
+
+        let mut assigned = issues.clone();
+        filter_by_assignee(&mut assigned, Some("alice"), false);
+        let ids: Vec<_> = assigned.iter().map(|i| i.issue.id.as_str()).collect();
+        assert_eq!(ids, vec!["a"]);
+
+        filter_by_assignee(&mut issues, None, true);
+        let ids: Vec<_> = issues.iter().map(|i| i.issue.id.as_str()).collect();
+        assert_eq!(ids, vec!["c"]);
+        info!("test_filter_by_assignee: assertions passed");
+    }
+
+    #[test]
+    fn test_blocker_summary_uses_stored_issue() {
+        init_test_logging();
+        info!("test_blocker_summary_uses_stored_issue: starting");
+        let mut blocker = make_issue("bd-1", "Fix the build", 1, IssueType::Bug);
+        blocker.status = Status::InProgress;
+        let issues = HashMap::from([(blocker.id.clone(), blocker)]);
+
+        let known = blocker_summary("bd-1:open", &issues);
+        assert_eq!(known.id, "bd-1");
+        assert_eq!(known.status, "in_progress");
+        assert_eq!(known.title.as_deref(), Some("Fix the build"));
+        assert_eq!(known.priority, Some(Priority(1)));
+
+        let external = blocker_summary("external:other:auth:blocked", &issues);
+        assert_eq!(external.id, "external:other:auth");
+        assert_eq!(external.status, "blocked");
+        assert!(external.title.is_none());
+        info!("test_blocker_summary_uses_stored_issue: assertions passed");
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        let now = Utc.with_ymd_and_hms(2025, 7, 1, 9, 0, 0).unwrap();
+        let at = |days, hours, minutes| {
+            now + chrono::Duration::days(days)
+                + chrono::Duration::hours(hours)
+                + chrono::Duration::minutes(minutes)
+        };
+        assert_eq!(format_countdown(at(3, 4, 30), now), "in 3d 4h");
+        assert_eq!(format_countdown(at(0, 2, 5), now), "in 2h 5m");
+        assert_eq!(format_countdown(at(0, 0, 12), now), "in 12m");
+        assert_eq!(format_countdown(now, now), "in <1m");
+        assert_eq!(
+            describe_wake(at(1, 0, 0), now),
+            "until 2025-07-02 09:00 UTC (in 1d 0h)"
+        );
+    }
 }
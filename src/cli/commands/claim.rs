@@ -0,0 +1,104 @@
+//! Claim command implementation.
+//!
+//! Atomically picks the top ready issue(s), assigns them to the current actor
+//! and moves them to `in_progress`, so concurrent agents never grab the same task.
+
+use crate::cli::ClaimArgs;
+use crate::cli::commands::ready::{parse_priorities, parse_types, ready_sort_policy};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+use crate::output::OutputContext;
+use crate::storage::ReadyFilters;
+use serde::Serialize;
+use std::collections::HashSet;
+use tracing::{debug, info};
+
+/// JSON output for a claimed issue.
+#[derive(Serialize)]
+struct ClaimedIssueOutput {
+    id: String,
+    title: String,
+    status: String,
+    priority: i32,
+    assignee: Option<String>,
+}
+
+impl From<&Issue> for ClaimedIssueOutput {
+    fn from(issue: &Issue) -> Self {
+        Self {
+            id: issue.id.clone(),
+            title: issue.title.clone(),
+            status: issue.status.as_str().to_string(),
+            priority: issue.priority.0,
+            assignee: issue.assignee.clone(),
+        }
+    }
+}
+
+/// Execute the claim command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, filters are invalid,
+/// or (in text mode) there is no ready issue to claim.
+pub fn execute(args: &ClaimArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    if args.count == 0 {
+        return Err(BeadsError::validation("count", "must be at least 1"));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
+
+    let filters = ReadyFilters {
+        labels_and: args.label.clone(),
+        types: parse_types(&args.type_)?,
+        priorities: parse_priorities(&args.priority)?,
+        parent: args.parent.clone(),
+        ..ReadyFilters::default()
+    };
+    let sort_policy = ready_sort_policy(args.sort);
+
+    // Issues waiting on unresolved external dependencies are not claimable
+    let storage = &mut *storage_ctx.storage;
+    let external_statuses =
+        storage.resolve_external_dependency_statuses(&external_db_paths, true)?;
+    let excluded: HashSet<String> = storage
+        .external_blockers(&external_statuses)?
+        .into_keys()
+        .collect();
+
+    debug!(filters = ?filters, sort = ?sort_policy, count = args.count, "Claiming ready issues");
+    let claimed =
+        storage.claim_ready_issues(&filters, sort_policy, args.count, &excluded, &actor)?;
+    info!(count = claimed.len(), actor = %actor, "Claimed issues");
+
+    if let Some(last) = claimed.last() {
+        crate::util::set_last_touched_id(&beads_dir, &last.id);
+    }
+
+    if ctx.is_json() {
+        let output: Vec<ClaimedIssueOutput> =
+            claimed.iter().map(ClaimedIssueOutput::from).collect();
+        ctx.json_pretty(&output);
+    } else if !ctx.is_quiet() {
+        for issue in &claimed {
+            println!("{}", issue.id);
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+
+    // In `--json` mode an empty array already tells agents nothing was claimed.
+    if !ctx.is_json() && claimed.is_empty() {
+        return Err(BeadsError::NothingToDo {
+            reason: "no ready issues to claim".to_string(),
+        });
+    }
+
+    Ok(())
+}
@@ -1,12 +1,14 @@
 //! Close command implementation.
 
 use crate::cli::CloseArgs as CliCloseArgs;
-use crate::config;
-use crate::error::{BeadsError, Result};
+use crate::cli::selection::resolve_selection;
+use crate::config::{self, CloseChildren};
+use crate::error::{BeadsError, Result, ValidationError};
 use crate::model::Status;
 use crate::output::OutputContext;
-use crate::storage::IssueUpdate;
-use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::storage::{IssueUpdate, Storage};
+use crate::util::id::{IdResolver, ResolverConfig};
+use crate::validation::{PolicyRule, PolicyValidator};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
@@ -23,6 +25,8 @@ pub struct CloseArgs {
     pub session: Option<String>,
     /// Return newly unblocked issues (single ID only)
     pub suggest_next: bool,
+    /// Also close open descendants
+    pub cascade: bool,
 }
 
 impl From<&CliCloseArgs> for CloseArgs {
@@ -33,6 +37,7 @@ impl From<&CliCloseArgs> for CloseArgs {
             force: cli.force,
             session: cli.session.clone(),
             suggest_next: cli.suggest_next,
+            cascade: cli.cascade,
         }
     }
 }
@@ -110,6 +115,7 @@ pub fn execute(
         force: false,
         session: None,
         suggest_next: false,
+        cascade: false,
     };
 
     execute_with_args(&args, json, cli, ctx)
@@ -132,12 +138,20 @@ pub fn execute_with_args(
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
-    let actor = config::resolve_actor(&config_layer);
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
     let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
-    let all_ids = storage_ctx.storage.get_all_ids()?;
-    let storage = &mut storage_ctx.storage;
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?)
+            .with_last_touched(crate::util::get_last_touched_id(&beads_dir)),
+    );
+    let storage = &mut *storage_ctx.storage;
+    // `--session` wins over the ambient session (BD_SESSION / BEADS_SESSION / config)
+    let session = args
+        .session
+        .clone()
+        .or_else(|| storage.session_id().map(str::to_string));
 
     // Get IDs - use last touched if none provided
     let mut ids = args.ids.clone();
@@ -160,12 +174,19 @@ pub fn execute_with_args(
         ));
     }
 
-    // Resolve all IDs
-    let resolved_ids = resolver.resolve_all(
-        &ids,
-        |id| all_ids.iter().any(|existing| existing == id),
-        |hash| find_matching_ids(&all_ids, hash),
-    )?;
+    // Resolve all IDs, expanding ranges, globs and filters
+    let resolved_ids = resolve_selection(storage, &resolver, &ids)?;
+
+    let children_policy = config::close_children_from_layer(&config_layer)?;
+    let cascade = args.cascade || children_policy == CloseChildren::Cascade;
+    let targets = close_targets(storage, &resolved_ids, cascade)?;
+
+    // Check required-field policies before closing anything (unless --force)
+    let policy_rules = config::policy_rules_from_layer(&config_layer)?;
+    if !args.force && !policy_rules.is_empty() {
+        let ids: Vec<&str> = targets.iter().map(|(id, _)| id.as_str()).collect();
+        check_close_policies(storage, &ids, &policy_rules)?;
+    }
 
     // Track blocked issues before closing (for suggest-next)
     let blocked_before: Vec<String> = if args.suggest_next {
@@ -180,9 +201,9 @@ pub fn execute_with_args(
 
     let mut closed_issues: Vec<ClosedIssue> = Vec::new();
     let mut skipped_issues: Vec<SkippedIssue> = Vec::new();
+    let mut left_open: Vec<String> = Vec::new();
 
-    for resolved in &resolved_ids {
-        let id = &resolved.id;
+    for (id, cascaded_from) in &targets {
         tracing::info!(id = %id, "Closing issue");
 
         // Get current issue
@@ -203,38 +224,37 @@ pub fn execute_with_args(
             continue;
         }
 
-        // Check if blocked (unless --force)
-        if !args.force && storage.is_blocked(id)? {
-            let mut blocker_ids = storage
-                .get_blocked_issues()?
-                .into_iter()
-                .find(|(issue, _)| issue.id == *id)
-                .map(|(_, blockers)| blockers)
-                .unwrap_or_default();
-            if blocker_ids.is_empty() {
-                blocker_ids = storage.get_dependencies(id)?;
+        // Blockers and `close.children: block` (unless --force)
+        if !args.force {
+            if let Some(reason) = close_refusal(storage, id, children_policy)? {
+                skipped_issues.push(SkippedIssue {
+                    id: id.clone(),
+                    reason,
+                });
+                continue;
             }
-            tracing::debug!(blocked_by = ?blocker_ids, "Issue is blocked");
-            let reason = if blocker_ids.is_empty() {
-                "blocked by dependencies".to_string()
-            } else {
-                format!("blocked by: {}", blocker_ids.join(", "))
-            };
-            skipped_issues.push(SkippedIssue {
-                id: id.clone(),
-                reason,
-            });
-            continue;
+        }
+
+        // Children still open at this point were not cascaded (or were skipped)
+        let open_children = storage.get_open_descendants(id)?;
+        if !open_children.is_empty() {
+            left_open.push(format!(
+                "{id} closed with open children: {}",
+                open_children.join(", ")
+            ));
         }
 
         // Build update
         let now = Utc::now();
-        let close_reason = args.reason.clone().unwrap_or_else(|| "done".to_string());
+        let close_reason = match cascaded_from {
+            Some(parent) => cascaded_close_reason(args.reason.as_deref(), parent),
+            None => args.reason.clone().unwrap_or_else(|| "done".to_string()),
+        };
         let update = IssueUpdate {
             status: Some(Status::Closed),
             closed_at: Some(Some(now)),
             close_reason: Some(Some(close_reason.clone())),
-            closed_by_session: args.session.clone().map(Some),
+            closed_by_session: session.clone().map(Some),
             ..Default::default()
         };
 
@@ -323,6 +343,9 @@ pub fn execute_with_args(
             for skipped in &skipped_issues {
                 ctx.warning(&format!("Skipped {}: {}", skipped.id, skipped.reason));
             }
+            for warning in &left_open {
+                ctx.warning(warning);
+            }
             if !unblocked_issues.is_empty() {
                 ctx.newline();
                 ctx.info(&format!("Unblocked {} issue(s):", unblocked_issues.len()));
@@ -346,6 +369,114 @@ pub fn execute_with_args(
     Ok(())
 }
 
+/// The issues closing `ids` acts on, in order. With `cascade` each issue is
+/// preceded by its open descendants, deepest first, so children close before
+/// parents; a descendant not in `ids` is paired with the issue it closes with.
+///
+/// # Errors
+///
+/// Returns an error if the descendants cannot be read.
+pub(crate) fn close_targets(
+    storage: &dyn Storage,
+    ids: &[String],
+    cascade: bool,
+) -> Result<Vec<(String, Option<String>)>> {
+    let mut targets: Vec<(String, Option<String>)> = Vec::new();
+    for id in ids {
+        if cascade {
+            for child in storage.get_open_descendants(id)? {
+                if !targets.iter().any(|(target, _)| *target == child) {
+                    let parent = (!ids.contains(&child)).then(|| id.clone());
+                    targets.push((child, parent));
+                }
+            }
+        }
+        if !targets.iter().any(|(target, _)| target == id) {
+            targets.push((id.clone(), None));
+        }
+    }
+    Ok(targets)
+}
+
+/// Why `id` cannot be closed now: open blockers, or open children under
+/// `close.children: block`. `None` if nothing stands in the way.
+///
+/// # Errors
+///
+/// Returns an error if the dependencies cannot be read.
+pub(crate) fn close_refusal(
+    storage: &dyn Storage,
+    id: &str,
+    children_policy: CloseChildren,
+) -> Result<Option<String>> {
+    if storage.is_blocked(id)? {
+        let mut blocker_ids = storage
+            .get_blocked_issues()?
+            .into_iter()
+            .find(|(issue, _)| issue.id == id)
+            .map(|(_, blockers)| blockers)
+            .unwrap_or_default();
+        if blocker_ids.is_empty() {
+            blocker_ids = storage.get_dependencies(id)?;
+        }
+        tracing::debug!(blocked_by = ?blocker_ids, "Issue is blocked");
+        return Ok(Some(if blocker_ids.is_empty() {
+            "blocked by dependencies".to_string()
+        } else {
+            format!("blocked by: {}", blocker_ids.join(", "))
+        }));
+    }
+
+    if children_policy == CloseChildren::Block {
+        let open_children = storage.get_open_descendants(id)?;
+        if !open_children.is_empty() {
+            return Ok(Some(format!(
+                "has open children: {}",
+                open_children.join(", ")
+            )));
+        }
+    }
+    Ok(None)
+}
+
+/// Close reason for a descendant closed along with `parent`.
+pub(crate) fn cascaded_close_reason(reason: Option<&str>, parent: &str) -> String {
+    reason.map_or_else(
+        || format!("closed with {parent}"),
+        |reason| format!("{reason} (closed with {parent})"),
+    )
+}
+
+/// Reject the close if any open issue in `ids` is missing a field that a
+/// policy requires.
+pub(crate) fn check_close_policies(
+    storage: &dyn Storage,
+    ids: &[&str],
+    rules: &[PolicyRule],
+) -> Result<()> {
+    let mut errors = Vec::new();
+    for id in ids {
+        let Some(mut issue) = storage.get_issue(id)? else {
+            continue;
+        };
+        if issue.status.is_terminal() {
+            continue;
+        }
+        issue.labels = storage.get_labels(id)?;
+        if let Err(violations) = PolicyValidator::validate(&issue, rules, true) {
+            errors.extend(violations.into_iter().map(|violation| {
+                ValidationError::new(violation.field, format!("{} ({id})", violation.message))
+            }));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(BeadsError::from_validation_errors(errors))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -362,6 +493,7 @@ mod tests {
         assert!(!args.force);
         assert!(args.session.is_none());
         assert!(!args.suggest_next);
+        assert!(!args.cascade);
     }
 
     #[test]
@@ -372,6 +504,7 @@ mod tests {
             force: true,
             session: Some("session-456".to_string()),
             suggest_next: true,
+            cascade: true,
         };
         assert_eq!(args.ids.len(), 2);
         assert_eq!(args.ids[0], "bd-abc");
@@ -379,6 +512,7 @@ mod tests {
         assert!(args.force);
         assert_eq!(args.session.as_deref(), Some("session-456"));
         assert!(args.suggest_next);
+        assert!(args.cascade);
     }
 
     // =========================================================================
@@ -659,6 +793,7 @@ mod tests {
             force: true,
             session: Some("sess".to_string()),
             suggest_next: true,
+            cascade: false,
         };
         let cloned = args.clone();
         assert_eq!(cloned.ids, args.ids);
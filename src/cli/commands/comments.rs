@@ -1,17 +1,20 @@
 //! Comments command implementation.
 
-use crate::cli::{CommentAddArgs, CommentCommands, CommentListArgs, CommentsArgs};
+use crate::cli::{
+    CommentAddArgs, CommentCommands, CommentDeleteArgs, CommentEditArgs, CommentListArgs,
+    CommentsArgs,
+};
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::model::Comment;
 use crate::output::{OutputContext, OutputMode};
-use crate::storage::SqliteStorage;
+use crate::storage::Storage;
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
-use chrono::{DateTime, Utc};
+use chrono::Utc;
 use rich_rust::prelude::*;
 use std::fs;
 use std::io::Read;
-use std::process::Command;
+use std::path::Path;
 
 /// Execute the comments command.
 ///
@@ -27,23 +30,21 @@ pub fn execute(
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
     let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?)
+            .with_last_touched(crate::util::get_last_touched_id(&beads_dir)),
+    );
     let all_ids = storage_ctx.storage.get_all_ids()?;
-    let actor = config::actor_from_layer(&config_layer);
-    let storage = &mut storage_ctx.storage;
+    let actor = config::actor(&config_layer);
+    let storage = &mut *storage_ctx.storage;
 
     match &args.command {
-        Some(CommentCommands::Add(add_args)) => add_comment(
-            add_args,
-            storage,
-            &resolver,
-            &all_ids,
-            actor.as_deref(),
-            json,
-            ctx,
-        ),
+        Some(CommentCommands::Add(add_args)) => {
+            add_comment(add_args, storage, &resolver, &all_ids, &actor, json, ctx)
+        }
         Some(CommentCommands::List(list_args)) => list_comments(
             list_args,
             storage,
@@ -53,6 +54,12 @@ pub fn execute(
             ctx,
             list_args.wrap,
         ),
+        Some(CommentCommands::Edit(edit_args)) => {
+            edit_comment(edit_args, storage, &resolver, &all_ids, &actor, ctx)
+        }
+        Some(CommentCommands::Delete(delete_args)) => {
+            delete_comment(delete_args, storage, &resolver, &all_ids, &actor, ctx)
+        }
         None => {
             let id = args
                 .id
@@ -68,10 +75,10 @@ pub fn execute(
 
 fn add_comment(
     args: &CommentAddArgs,
-    storage: &mut SqliteStorage,
+    storage: &mut dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
-    actor: Option<&str>,
+    actor: &str,
     _json: bool,
     ctx: &OutputContext,
 ) -> Result<()> {
@@ -98,9 +105,88 @@ fn add_comment(
     Ok(())
 }
 
+fn edit_comment(
+    args: &CommentEditArgs,
+    storage: &mut dyn Storage,
+    resolver: &IdResolver,
+    all_ids: &[String],
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let issue_id = resolve_issue_id(storage, resolver, all_ids, &args.id)?;
+    let text = read_text(args.file.as_deref(), args.message.as_deref(), &args.text)?;
+    if text.trim().is_empty() {
+        return Err(BeadsError::validation(
+            "text",
+            "comment text cannot be empty",
+        ));
+    }
+    check_comment_owner(storage, &issue_id, args.comment_id, actor, args.force)?;
+
+    let comment = storage.edit_comment(args.comment_id, &text, actor)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&comment);
+    } else {
+        ctx.success(&format!("Edited comment {} on {issue_id}", comment.id));
+    }
+
+    Ok(())
+}
+
+fn delete_comment(
+    args: &CommentDeleteArgs,
+    storage: &mut dyn Storage,
+    resolver: &IdResolver,
+    all_ids: &[String],
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let issue_id = resolve_issue_id(storage, resolver, all_ids, &args.id)?;
+    check_comment_owner(storage, &issue_id, args.comment_id, actor, args.force)?;
+
+    let comment = storage.delete_comment(args.comment_id, actor)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&comment);
+    } else {
+        ctx.success(&format!("Deleted comment {} on {issue_id}", comment.id));
+    }
+
+    Ok(())
+}
+
+/// Ensure the comment belongs to `issue_id` and was written by `actor`
+/// (unless `force`).
+fn check_comment_owner(
+    storage: &dyn Storage,
+    issue_id: &str,
+    comment_id: i64,
+    actor: &str,
+    force: bool,
+) -> Result<()> {
+    let comment = storage
+        .get_comment(comment_id)?
+        .filter(|comment| comment.issue_id == issue_id && !comment.deleted)
+        .ok_or_else(|| BeadsError::CommentNotFound {
+            issue_id: issue_id.to_string(),
+            comment_id,
+        })?;
+    if comment.author != actor && !force {
+        return Err(BeadsError::validation(
+            "comment",
+            format!(
+                "comment {comment_id} was written by {}; use --force to modify it",
+                comment.author
+            ),
+        ));
+    }
+    Ok(())
+}
+
 fn list_comments(
     args: &CommentListArgs,
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     json: bool,
@@ -112,7 +198,7 @@ fn list_comments(
 
 fn list_comments_by_id(
     id: &str,
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     _json: bool,
@@ -120,7 +206,11 @@ fn list_comments_by_id(
     wrap: bool,
 ) -> Result<()> {
     let issue_id = resolve_issue_id(storage, resolver, all_ids, id)?;
-    let comments = storage.get_comments(&issue_id)?;
+    let comments: Vec<Comment> = storage
+        .get_comments(&issue_id)?
+        .into_iter()
+        .filter(|comment| !comment.deleted)
+        .collect();
 
     if ctx.is_json() {
         ctx.json_pretty(&comments);
@@ -140,7 +230,15 @@ fn list_comments_by_id(
     println!("Comments for {issue_id}:");
     for comment in comments {
         let timestamp = comment.created_at.format("%Y-%m-%d %H:%M UTC");
-        println!("[{}] at {}", comment.author, timestamp);
+        let edited = if comment.updated_at.is_some() {
+            " (edited)"
+        } else {
+            ""
+        };
+        println!(
+            "[{}] at {} #{}{edited}",
+            comment.author, timestamp, comment.id
+        );
         println!("{}", comment.body.trim_end_matches('\n'));
         println!();
     }
@@ -187,9 +285,13 @@ fn render_comments_list_rich(
         content.append_styled(&format!("@{}", comment.author), theme.username.clone());
         content.append_styled(" \u{2022} ", theme.dimmed.clone());
         content.append_styled(
-            &format_relative_time(comment.created_at, now),
+            &ctx.time_format().datetime(comment.created_at, now),
             theme.timestamp.clone(),
         );
+        content.append_styled(&format!(" #{}", comment.id), theme.dimmed.clone());
+        if comment.updated_at.is_some() {
+            content.append_styled(" (edited)", theme.dimmed.clone());
+        }
         content.append("\n");
 
         // Comment body
@@ -245,45 +347,8 @@ fn render_comment_added_rich(issue_id: &str, comment: &Comment, ctx: &OutputCont
     console.print_renderable(&comment_text);
 }
 
-/// Format a timestamp as relative time (e.g., "2 days ago", "3 hours ago").
-fn format_relative_time(timestamp: DateTime<Utc>, now: DateTime<Utc>) -> String {
-    let duration = now.signed_duration_since(timestamp);
-    let seconds = duration.num_seconds();
-
-    if seconds < 60 {
-        return "just now".to_string();
-    }
-
-    let minutes = duration.num_minutes();
-    if minutes < 60 {
-        return format!(
-            "{} minute{} ago",
-            minutes,
-            if minutes == 1 { "" } else { "s" }
-        );
-    }
-
-    let hours = duration.num_hours();
-    if hours < 24 {
-        return format!("{} hour{} ago", hours, if hours == 1 { "" } else { "s" });
-    }
-
-    let days = duration.num_days();
-    if days < 30 {
-        return format!("{} day{} ago", days, if days == 1 { "" } else { "s" });
-    }
-
-    let months = days / 30;
-    if months < 12 {
-        return format!("{} month{} ago", months, if months == 1 { "" } else { "s" });
-    }
-
-    let years = months / 12;
-    format!("{} year{} ago", years, if years == 1 { "" } else { "s" })
-}
-
 fn resolve_issue_id(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     input: &str,
@@ -298,7 +363,13 @@ fn resolve_issue_id(
 }
 
 fn read_comment_text(args: &CommentAddArgs) -> Result<String> {
-    if let Some(path) = &args.file {
+    read_text(args.file.as_deref(), args.message.as_deref(), &args.text)
+}
+
+/// Comment text from `--file` (or `-` for stdin), then `--message`, then
+/// positional words.
+fn read_text(file: Option<&Path>, message: Option<&str>, text: &[String]) -> Result<String> {
+    if let Some(path) = file {
         if path.as_os_str() == "-" {
             let mut buffer = String::new();
             std::io::stdin().read_to_string(&mut buffer)?;
@@ -306,60 +377,21 @@ fn read_comment_text(args: &CommentAddArgs) -> Result<String> {
         }
         return Ok(fs::read_to_string(path)?);
     }
-    if let Some(message) = &args.message {
-        return Ok(message.clone());
+    if let Some(message) = message {
+        return Ok(message.to_string());
     }
-    if !args.text.is_empty() {
-        return Ok(args.text.join(" "));
+    if !text.is_empty() {
+        return Ok(text.join(" "));
     }
     Err(BeadsError::validation("text", "comment text required"))
 }
 
-fn resolve_author(author_override: Option<&str>, actor: Option<&str>) -> String {
-    if let Some(author) = author_override {
-        if !author.trim().is_empty() {
-            return author.to_string();
-        }
-    }
-    if let Some(actor) = actor {
-        if !actor.trim().is_empty() {
-            return actor.to_string();
-        }
-    }
-    if let Ok(value) = std::env::var("BD_ACTOR") {
-        if !value.trim().is_empty() {
-            return value;
-        }
-    }
-    if let Ok(value) = std::env::var("BEADS_ACTOR") {
-        if !value.trim().is_empty() {
-            return value;
-        }
-    }
-    if let Some(name) = git_user_name() {
-        return name;
-    }
-    if let Ok(value) = std::env::var("USER") {
-        if !value.trim().is_empty() {
-            return value;
-        }
-    }
-
-    "unknown".to_string()
-}
-
-fn git_user_name() -> Option<String> {
-    let output = Command::new("git")
-        .args(["config", "--get", "user.name"])
-        .output()
-        .ok()?;
-
-    if !output.status.success() {
-        return None;
-    }
-
-    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    if name.is_empty() { None } else { Some(name) }
+/// Comment author: `--author` when non-blank, otherwise the resolved actor.
+fn resolve_author(author_override: Option<&str>, actor: &str) -> String {
+    author_override
+        .filter(|author| !author.trim().is_empty())
+        .unwrap_or(actor)
+        .to_string()
 }
 
 #[cfg(test)]
@@ -375,7 +407,7 @@ mod tests {
         init_test_logging();
         info!("test_resolve_author_with_override: starting");
         // When author override is provided, it should be used
-        let result = resolve_author(Some("custom_author"), Some("actor_name"));
+        let result = resolve_author(Some("custom_author"), "actor_name");
         assert_eq!(result, "custom_author");
         info!("test_resolve_author_with_override: assertions passed");
     }
@@ -385,7 +417,7 @@ mod tests {
         init_test_logging();
         info!("test_resolve_author_empty_override_uses_actor: starting");
         // Empty override should fall through to actor
-        let result = resolve_author(Some(""), Some("actor_name"));
+        let result = resolve_author(Some(""), "actor_name");
         assert_eq!(result, "actor_name");
         info!("test_resolve_author_empty_override_uses_actor: assertions passed");
     }
@@ -395,7 +427,7 @@ mod tests {
         init_test_logging();
         info!("test_resolve_author_whitespace_override_uses_actor: starting");
         // Whitespace-only override should fall through to actor
-        let result = resolve_author(Some("   "), Some("actor_name"));
+        let result = resolve_author(Some("   "), "actor_name");
         assert_eq!(result, "actor_name");
         info!("test_resolve_author_whitespace_override_uses_actor: assertions passed");
     }
@@ -405,23 +437,11 @@ mod tests {
         init_test_logging();
         info!("test_resolve_author_no_override_uses_actor: starting");
         // No override should use actor
-        let result = resolve_author(None, Some("actor_name"));
+        let result = resolve_author(None, "actor_name");
         assert_eq!(result, "actor_name");
         info!("test_resolve_author_no_override_uses_actor: assertions passed");
     }
 
-    #[test]
-    fn test_resolve_author_empty_actor_falls_through() {
-        init_test_logging();
-        info!("test_resolve_author_empty_actor_falls_through: starting");
-        // Empty actor should fall through to env/git/USER/unknown
-        // Since we can't easily control env, just test that it doesn't panic
-        // and returns something non-empty
-        let result = resolve_author(None, Some(""));
-        assert!(!result.is_empty());
-        info!("test_resolve_author_empty_actor_falls_through: assertions passed");
-    }
-
     #[test]
     fn test_read_comment_text_from_message_flag() {
         init_test_logging();
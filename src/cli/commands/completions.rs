@@ -15,7 +15,7 @@
 //! br completions fish > ~/.config/fish/completions/br.fish
 //! ```
 
-use crate::cli::{Cli, CompletionsArgs, ShellType};
+use crate::cli::{Cli, CompleteIdsArgs, CompletionsArgs, ShellType, completion_candidates};
 use crate::error::Result;
 use crate::output::OutputContext;
 use clap::CommandFactory;
@@ -51,6 +51,20 @@ pub fn execute(args: &CompletionsArgs, _ctx: &OutputContext) -> Result<()> {
     Ok(())
 }
 
+/// Execute the hidden `__complete-ids` helper.
+///
+/// Prints one candidate per line so hand-written bash/zsh/fish scripts can
+/// complete issue IDs, labels and statuses from the current workspace. Outside
+/// a workspace it prints nothing rather than failing mid-completion.
+pub fn execute_complete_ids(args: &CompleteIdsArgs) {
+    for (value, help) in completion_candidates(args.kind, &args.partial) {
+        match help {
+            Some(help) if args.describe => println!("{value}\t{help}"),
+            _ => println!("{value}"),
+        }
+    }
+}
+
 fn write_dynamic_completions(
     shell: ShellType,
     cmd: &clap::Command,
@@ -12,11 +12,12 @@
 use crate::cli::ConfigCommands;
 use crate::config::{
     self, CliOverrides, ConfigLayer, ConfigPaths, default_config_layer, discover_beads_dir,
-    id_config_from_layer, load_legacy_user_config, load_project_config, load_user_config,
-    resolve_actor,
+    id_config_from_layer, is_user_only_key, load_legacy_user_config, load_project_config,
+    load_user_config,
 };
-use crate::error::Result;
+use crate::error::{Result, exit_code};
 use crate::output::OutputContext;
+use crate::storage::Storage;
 use rich_rust::prelude::*;
 use serde_json::json;
 use std::collections::BTreeMap;
@@ -61,6 +62,20 @@ impl ConfigSource {
             Self::Cli => "CLI",
         }
     }
+
+    /// The file that supplied a value, or the label for non-file sources.
+    fn origin(self, beads_dir: Option<&PathBuf>) -> String {
+        let path = match self {
+            Self::Project => beads_dir.map(|dir| dir.join("config.yaml")),
+            Self::User => get_user_config_path(),
+            Self::LegacyUser => get_legacy_user_config_path(),
+            _ => None,
+        };
+        path.map_or_else(
+            || self.label().to_string(),
+            |path| path.display().to_string(),
+        )
+    }
 }
 
 struct ConfigEntry {
@@ -112,21 +127,28 @@ fn build_layers(
     beads_dir: Option<&PathBuf>,
     overrides: &CliOverrides,
 ) -> Result<Vec<LayerWithSource>> {
-    let defaults = default_config_layer();
-
     let db_layer = if let Some(dir) = beads_dir {
         let storage = config::open_storage_with_cli(dir, overrides)
             .ok()
             .map(|ctx| ctx.storage);
         if let Some(storage) = storage {
-            ConfigLayer::from_db(&storage)?
+            ConfigLayer::from_db(&*storage)?
         } else {
             ConfigLayer::default()
         }
     } else {
         ConfigLayer::default()
     };
+    stack_layers(db_layer, beads_dir, overrides)
+}
 
+/// All config layers, lowest precedence first, around an already-loaded DB layer.
+fn stack_layers(
+    db_layer: ConfigLayer,
+    beads_dir: Option<&PathBuf>,
+    overrides: &CliOverrides,
+) -> Result<Vec<LayerWithSource>> {
+    let defaults = default_config_layer();
     let legacy_user = load_legacy_user_config()?;
     let user = load_user_config()?;
     let project = if let Some(dir) = beads_dir {
@@ -177,6 +199,33 @@ fn merge_layers(layers: &[LayerWithSource]) -> ConfigLayer {
     merged
 }
 
+/// Where each explicitly set config key comes from: the config file that
+/// supplied it, or `db`, `environment` or `cli`. Keys left at their defaults
+/// are omitted.
+///
+/// # Errors
+///
+/// Returns an error if a config file or the DB config table cannot be read.
+pub(crate) fn config_origins(
+    storage: &dyn Storage,
+    beads_dir: &Path,
+    overrides: &CliOverrides,
+) -> Result<BTreeMap<String, String>> {
+    let beads_dir = beads_dir.to_path_buf();
+    let layers = stack_layers(ConfigLayer::from_db(storage)?, Some(&beads_dir), overrides)?;
+    let mut origins = BTreeMap::new();
+    for layer in &layers {
+        if matches!(layer.source, ConfigSource::Default) {
+            continue;
+        }
+        for key in layer.layer.startup.keys().chain(layer.layer.runtime.keys()) {
+            // Later layers take precedence
+            origins.insert(key.clone(), layer.source.origin(Some(&beads_dir)));
+        }
+    }
+    Ok(origins)
+}
+
 fn resolve_source(key: &str, layers: &[LayerWithSource]) -> ConfigSource {
     for layer in layers.iter().rev() {
         if layer.layer.runtime.contains_key(key) || layer.layer.startup.contains_key(key) {
@@ -390,13 +439,16 @@ fn get_config_value(
         }
     } else {
         eprintln!("Config key not found: {key}");
-        std::process::exit(1);
+        std::process::exit(exit_code::CONFIG);
     }
 
     Ok(())
 }
 
 /// Set a config value in project config (if available) or user config.
+///
+/// Keys that only user config may set (`alerts.*`, `webhooks.*`) always go
+/// to user config.
 fn set_config_value(args: &[String], _json_mode: bool, ctx: &OutputContext) -> Result<()> {
     let (key, value) = match args.len() {
         1 => args[0]
@@ -414,8 +466,11 @@ fn set_config_value(args: &[String], _json_mode: bool, ctx: &OutputContext) -> R
         }
     };
 
-    // Determine target config file
-    let (config_path, is_project) = if let Ok(beads_dir) = discover_beads_dir(None) {
+    // Determine target config file; user-only keys never go in the shared project file
+    let project_dir = discover_beads_dir(None)
+        .ok()
+        .filter(|_| !is_user_only_key(key));
+    let (config_path, is_project) = if let Some(beads_dir) = project_dir {
         (beads_dir.join("config.yaml"), true)
     } else {
         let path = get_user_config_path().ok_or_else(|| {
@@ -755,7 +810,7 @@ fn show_config(
 
     // Compute derived values
     let id_config = id_config_from_layer(&layer);
-    let actor = resolve_actor(&layer);
+    let actor = config::actor(&layer);
 
     if ctx.is_json() {
         let mut all_keys: BTreeMap<String, serde_json::Value> = BTreeMap::new();
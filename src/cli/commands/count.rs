@@ -1,9 +1,9 @@
-use crate::cli::{CountArgs, CountBy};
+use crate::cli::commands::list;
+use crate::cli::{CountArgs, CountBy, ListArgs};
 use crate::config;
 use crate::error::Result;
-use crate::model::{IssueType, Priority, Status};
 use crate::output::{OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::storage::{ListFilters, Storage};
 use rich_rust::prelude::*;
 use serde::Serialize;
 use std::collections::BTreeMap;
@@ -38,31 +38,10 @@ pub fn execute(
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
 
-    let mut filters = ListFilters::default();
-    let statuses = parse_statuses(&args.status)?;
-    let types = parse_types(&args.types)?;
-    let priorities = parse_priorities(&args.priority)?;
-
-    if !statuses.is_empty() {
-        if statuses.iter().any(Status::is_terminal) {
-            filters.include_closed = true;
-        }
-        filters.statuses = Some(statuses);
-    }
-    if !types.is_empty() {
-        filters.types = Some(types);
-    }
-    if !priorities.is_empty() {
-        filters.priorities = Some(priorities);
-    }
-
-    filters.assignee.clone_from(&args.assignee);
-    filters.unassigned = args.unassigned;
-    filters.include_closed = filters.include_closed || args.include_closed;
+    let mut filters = build_filters(args)?;
     filters.include_templates = args.include_templates;
-    filters.title_contains.clone_from(&args.title_contains);
 
     let issues = storage.list_issues(&filters)?;
     let total = issues.len();
@@ -173,29 +152,25 @@ fn render_count_grouped_rich(
     console.print_renderable(&panel);
 }
 
-fn parse_statuses(values: &[String]) -> Result<Vec<Status>> {
-    values
-        .iter()
-        .map(|value| value.parse())
-        .collect::<Result<Vec<Status>>>()
-}
-
-fn parse_types(values: &[String]) -> Result<Vec<IssueType>> {
-    values
-        .iter()
-        .map(|value| value.parse())
-        .collect::<Result<Vec<IssueType>>>()
-}
-
-fn parse_priorities(values: &[String]) -> Result<Vec<Priority>> {
-    values
-        .iter()
-        .map(|value| value.parse())
-        .collect::<Result<Vec<Priority>>>()
+/// Build storage filters through `list`'s parser so counts match `br list`.
+fn build_filters(args: &CountArgs) -> Result<ListFilters> {
+    let list_args = ListArgs {
+        status: args.status.clone(),
+        type_: args.types.clone(),
+        priority: args.priority.clone(),
+        assignee: args.assignee.clone(),
+        unassigned: args.unassigned,
+        label: args.label.clone(),
+        label_any: args.label_any.clone(),
+        title_contains: args.title_contains.clone(),
+        all: args.include_closed,
+        ..ListArgs::default()
+    };
+    list::build_filters(&list_args)
 }
 
 fn group_counts(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     issues: &[crate::model::Issue],
     by: CountBy,
 ) -> Result<Vec<CountGroup>> {
@@ -256,6 +231,7 @@ fn group_counts(
 mod tests {
     use super::*;
     use crate::model::{Issue, IssueType, Priority, Status};
+    use crate::storage::SqliteStorage;
     use chrono::Utc;
     use tracing::info;
 
@@ -303,6 +279,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
             content_hash: None,
         }
     }
@@ -365,4 +345,46 @@ mod tests {
         assert_eq!(map.get("(no labels)"), Some(&1));
         info!("test_group_counts_label_includes_unlabeled: assertions passed");
     }
+
+    #[test]
+    fn test_count_filters_match_list_filters() {
+        init_logging();
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let open = make_issue("bd-1", Status::Open, Priority::MEDIUM, IssueType::Task);
+        let deferred = make_issue("bd-2", Status::Deferred, Priority::MEDIUM, IssueType::Task);
+        let mut closed = make_issue("bd-3", Status::Closed, Priority::MEDIUM, IssueType::Task);
+        closed.closed_at = Some(Utc::now());
+        for issue in [&open, &deferred, &closed] {
+            storage.create_issue(issue, "tester").unwrap();
+        }
+        storage.add_label("bd-2", "backend", "tester").unwrap();
+
+        let count = |args: CountArgs| {
+            let filters = build_filters(&args).unwrap();
+            storage.list_issues(&filters).unwrap().len()
+        };
+
+        assert_eq!(count(CountArgs::default()), 2);
+        assert_eq!(
+            count(CountArgs {
+                status: vec!["deferred".to_string()],
+                ..CountArgs::default()
+            }),
+            1
+        );
+        assert_eq!(
+            count(CountArgs {
+                label: vec!["backend".to_string()],
+                ..CountArgs::default()
+            }),
+            1
+        );
+        assert_eq!(
+            count(CountArgs {
+                include_closed: true,
+                ..CountArgs::default()
+            }),
+            3
+        );
+    }
 }
@@ -1,14 +1,20 @@
 use crate::cli::CreateArgs;
+use crate::cli::commands::label;
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::model::{Dependency, DependencyType, Issue, IssueType, Priority, Status};
 use crate::output::OutputContext;
-use crate::storage::SqliteStorage;
+use crate::storage::{IssueUpdate, ListFilters, Storage};
+use crate::util::duplicates::{self, DuplicateCandidate};
 use crate::util::id::{IdGenerator, child_id};
-use crate::util::markdown_import::{parse_dependency, parse_markdown_file};
+use crate::util::markdown_import::{
+    ParsedIssue, parse_dependency, parse_markdown_file, parse_placeholder,
+};
 use crate::util::time::parse_flexible_timestamp;
 use crate::validation::{IssueValidator, LabelValidator};
 use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Read};
 use std::path::Path;
 use std::str::FromStr;
 
@@ -17,7 +23,30 @@ pub struct CreateConfig {
     pub id_config: crate::util::id::IdConfig,
     pub default_priority: Priority,
     pub default_issue_type: IssueType,
+    /// Per-type field defaults, keyed by issue type (see `--no-defaults`).
+    pub type_defaults: HashMap<String, config::TypeDefaults>,
     pub actor: String,
+    /// What to do when the issue looks like an existing open issue.
+    pub duplicate_check: config::DuplicateCheck,
+}
+
+impl CreateConfig {
+    /// Creation settings from the merged config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a default priority or type, a type default, or
+    /// `create.duplicates` is invalid.
+    pub fn from_layer(layer: &config::ConfigLayer) -> Result<Self> {
+        Ok(Self {
+            id_config: config::id_config_from_layer(layer),
+            default_priority: config::default_priority_from_layer(layer)?,
+            default_issue_type: config::default_issue_type_from_layer(layer)?,
+            type_defaults: config::type_defaults_from_layer(layer)?,
+            actor: config::actor(layer),
+            duplicate_check: config::duplicate_check_from_layer(layer)?,
+        })
+    }
 }
 
 /// Execute the create command.
@@ -43,29 +72,42 @@ pub fn execute(args: &CreateArgs, cli: &config::CliOverrides, ctx: &OutputContex
         }
         return execute_import(file_path, args, cli, ctx);
     }
+    if args.title.as_deref() == Some("-") {
+        if args.dry_run {
+            return Err(BeadsError::validation(
+                "dry_run",
+                "--dry-run is not supported when reading issues from stdin",
+            ));
+        }
+        return execute_stdin_json(cli, ctx);
+    }
 
     // 1. Open storage (unless dry run without DB)
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
 
     // We open storage even for dry-run to check ID collisions.
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
-
-    let config = CreateConfig {
-        id_config: config::id_config_from_layer(&layer),
-        default_priority: config::default_priority_from_layer(&layer)?,
-        default_issue_type: config::default_issue_type_from_layer(&layer)?,
-        actor: config::resolve_actor(&layer),
-    };
+    let layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let config = CreateConfig::from_layer(&layer)?;
 
-    let issue = create_issue_impl(&mut storage_ctx.storage, args, &config)?;
+    let issue = create_issue_impl(&mut *storage_ctx.storage, args, &config)?;
+    if !args.dry_run {
+        crate::util::set_last_touched_id(&beads_dir, &issue.id);
+    }
+    // In block mode create_issue_impl has already refused any duplicates
+    let duplicates =
+        if config.duplicate_check == config::DuplicateCheck::Warn && !args.allow_duplicate {
+            find_open_duplicates(&*storage_ctx.storage, &issue)?
+        } else {
+            Vec::new()
+        };
 
     // Output
     if args.silent {
         println!("{}", issue.id);
     } else if ctx.is_json() {
-        if args.dry_run {
-            ctx.json_pretty(&issue);
+        let mut output = if args.dry_run {
+            serde_json::to_value(&issue)?
         } else {
             let full_issue = storage_ctx
                 .storage
@@ -73,8 +115,12 @@ pub fn execute(args: &CreateArgs, cli: &config::CliOverrides, ctx: &OutputContex
                 .ok_or_else(|| BeadsError::IssueNotFound {
                     id: issue.id.clone(),
                 })?;
-            ctx.json_pretty(&full_issue);
+            serde_json::to_value(&full_issue)?
+        };
+        if !duplicates.is_empty() {
+            output["possible_duplicates"] = serde_json::to_value(&duplicates)?;
         }
+        ctx.json_pretty(&output);
     } else if args.dry_run {
         ctx.info(&format!("Dry run: would create issue {}", issue.id));
         ctx.print(&format!("Title: {}", issue.title));
@@ -92,6 +138,19 @@ pub fn execute(args: &CreateArgs, cli: &config::CliOverrides, ctx: &OutputContex
     } else {
         ctx.success(&format!("Created {}: {}", issue.id, issue.title));
     }
+    if !args.silent {
+        for candidate in &duplicates {
+            ctx.warning(&format!(
+                "Possible duplicate of {}: {} ({:.0}% similar{})",
+                candidate.id,
+                candidate.title,
+                candidate.similarity * 100.0,
+                if candidate.exact { ", identical" } else { "" }
+            ));
+        }
+        let labels: Vec<&str> = issue.labels.iter().map(String::as_str).collect();
+        label::warn_undeclared_labels(&beads_dir, &layer, &labels, ctx);
+    }
 
     storage_ctx.flush_no_db_if_dirty()?;
     Ok(())
@@ -108,10 +167,12 @@ pub fn execute(args: &CreateArgs, cli: &config::CliOverrides, ctx: &OutputContex
 /// - Title is empty
 /// - ID generation fails
 /// - Validation fails
+/// - The issue looks like an existing open issue and `duplicate_check` is
+///   `Block` (unless `--allow-duplicate`)
 /// - Storage write fails
 #[allow(clippy::too_many_lines)]
 pub fn create_issue_impl(
-    storage: &mut SqliteStorage,
+    storage: &mut dyn Storage,
     args: &CreateArgs,
     config: &CreateConfig,
 ) -> Result<Issue> {
@@ -178,18 +239,28 @@ pub fn create_issue_impl(
     };
 
     // 3. Parse fields
-    let priority = if let Some(p) = &args.priority {
-        Priority::from_str(p)?
-    } else {
-        config.default_priority
-    };
-
     let issue_type = if let Some(t) = &args.type_ {
         IssueType::from_str(t)?
     } else {
         config.default_issue_type.clone()
     };
 
+    let no_type_defaults = config::TypeDefaults::default();
+    let type_defaults = if args.no_defaults {
+        &no_type_defaults
+    } else {
+        config
+            .type_defaults
+            .get(issue_type.as_str())
+            .unwrap_or(&no_type_defaults)
+    };
+
+    let priority = if let Some(p) = &args.priority {
+        Priority::from_str(p)?
+    } else {
+        type_defaults.priority.unwrap_or(config.default_priority)
+    };
+
     let due_at = parse_optional_date(args.due.as_deref())?;
     let defer_until = parse_optional_date(args.defer.as_deref())?;
 
@@ -211,13 +282,19 @@ pub fn create_issue_impl(
     let mut issue = Issue {
         id: id.clone(),
         title: title.clone(),
-        description: args.description.clone(),
+        description: args
+            .description
+            .clone()
+            .or_else(|| type_defaults.description.clone()),
         status,
         priority,
         issue_type,
         created_at: now,
         updated_at: now,
-        assignee: args.assignee.clone(),
+        assignee: args
+            .assignee
+            .clone()
+            .or_else(|| type_defaults.assignee.clone()),
         owner: args.owner.clone(),
         estimated_minutes: args.estimate,
         due_at,
@@ -226,9 +303,9 @@ pub fn create_issue_impl(
         ephemeral: args.ephemeral,
         // Defaults
         content_hash: None,
-        design: None,
-        acceptance_criteria: None,
-        notes: None,
+        design: type_defaults.design.clone(),
+        acceptance_criteria: type_defaults.acceptance_criteria.clone(),
+        notes: type_defaults.notes.clone(),
         created_by: Some(config.actor.clone()),
         closed_at,
         close_reason: None,
@@ -249,6 +326,10 @@ pub fn create_issue_impl(
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     };
 
     // Compute content hash
@@ -260,8 +341,24 @@ pub fn create_issue_impl(
     // 5b. Validate Relations (fail fast before DB writes)
     validate_relations(args, &id)?;
 
+    // 5c. Refuse likely duplicates of open issues
+    if config.duplicate_check == config::DuplicateCheck::Block && !args.allow_duplicate {
+        let candidates = find_open_duplicates(storage, &issue)?;
+        if !candidates.is_empty() {
+            return Err(BeadsError::PossibleDuplicate {
+                title: issue.title,
+                candidates,
+            });
+        }
+    }
+
     // 6. Populate Relations (labels & dependencies)
     populate_relations(&mut issue, args, &config.actor, now);
+    for label in &type_defaults.labels {
+        if !issue.labels.contains(label) {
+            issue.labels.push(label.clone());
+        }
+    }
 
     // 7. Dry Run check - return early
     if args.dry_run {
@@ -274,6 +371,26 @@ pub fn create_issue_impl(
     Ok(issue)
 }
 
+/// Open issues that `issue` likely duplicates, most similar first.
+///
+/// # Errors
+///
+/// Returns an error if the open issues cannot be loaded.
+pub fn find_open_duplicates(
+    storage: &dyn Storage,
+    issue: &Issue,
+) -> Result<Vec<DuplicateCandidate>> {
+    let open_issues = storage.list_issues(&ListFilters {
+        include_deferred: true,
+        ..ListFilters::default()
+    })?;
+    Ok(duplicates::find_duplicates(
+        issue,
+        &open_issues,
+        duplicates::DEFAULT_SIMILARITY_THRESHOLD,
+    ))
+}
+
 fn validate_relations(args: &CreateArgs, id: &str) -> Result<()> {
     // Validate Labels
     for label in &args.labels {
@@ -402,12 +519,12 @@ fn execute_import(
 
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
 
     let id_config = config::id_config_from_layer(&layer);
     let default_priority = config::default_priority_from_layer(&layer)?;
     let default_issue_type = config::default_issue_type_from_layer(&layer)?;
-    let actor = config::resolve_actor(&layer);
+    let actor = config::actor(&layer);
     let now = Utc::now();
     let _json_mode = cli.json.unwrap_or(false);
     let due_at = parse_optional_date(args.due.as_deref())?;
@@ -420,27 +537,39 @@ fn execute_import(
         Status::Open
     };
 
-    // Set closed_at if status is Closed or Tombstone
-    let import_closed_at = if matches!(import_status, Status::Closed | Status::Tombstone) {
-        Some(now)
-    } else {
-        None
-    };
-
-    let storage = &mut storage_ctx.storage;
+    let storage = &mut *storage_ctx.storage;
     let id_gen = IdGenerator::new(id_config);
 
-    // Track created IDs for output
+    // Track created and updated IDs for output
     let mut created_ids = Vec::new();
-    let mut created_issues = Vec::new();
+    let mut updated_ids = Vec::new();
+    // Created IDs by position in the file, and `#N` dependencies to add once
+    // every issue exists: (issue ID, dependency type, target position)
+    let mut ids_by_index: HashMap<usize, String> = HashMap::new();
+    let mut placeholder_deps: Vec<(String, String, usize)> = Vec::new();
 
-    for parsed in parsed_issues {
+    for (index, parsed) in parsed_issues.into_iter().enumerate() {
         let title = parsed.title.trim().to_string();
         if title.is_empty() {
             eprintln!("✗ Failed to create issue: title cannot be empty");
             continue;
         }
 
+        if args.update {
+            if let Some(existing) = find_existing(storage, &parsed)? {
+                let id = existing.id.clone();
+                match update_from_markdown(storage, &existing, &parsed, index, &actor) {
+                    Ok(pending_deps) => {
+                        placeholder_deps.extend(pending_deps);
+                        ids_by_index.insert(index, id.clone());
+                        updated_ids.push((id, title));
+                    }
+                    Err(err) => eprintln!("✗ Failed to update {id}: {err}"),
+                }
+                continue;
+            }
+        }
+
         let count = storage.count_issues()?;
         let id = id_gen.generate(
             &title,
@@ -475,11 +604,21 @@ fn execute_import(
             default_issue_type.clone()
         };
 
+        let status = match parsed.status.as_deref().map(Status::from_str).transpose() {
+            Ok(status) => status.unwrap_or_else(|| import_status.clone()),
+            Err(err) => {
+                eprintln!("✗ Failed to create {title}: {err}");
+                continue;
+            }
+        };
+        // Set closed_at if status is Closed or Tombstone
+        let closed_at = matches!(status, Status::Closed | Status::Tombstone).then_some(now);
+
         let mut issue = Issue {
             id: id.clone(),
             title: title.clone(),
             description: parsed.description,
-            status: import_status.clone(),
+            status,
             priority,
             issue_type,
             created_at: now,
@@ -489,14 +628,14 @@ fn execute_import(
             estimated_minutes: args.estimate,
             due_at,
             defer_until,
-            external_ref: args.external_ref.clone(),
+            external_ref: parsed.external_ref.or_else(|| args.external_ref.clone()),
             ephemeral: args.ephemeral,
             design: parsed.design,
             acceptance_criteria: parsed.acceptance_criteria,
             content_hash: None,
-            notes: None,
+            notes: parsed.notes,
             created_by: None,
-            closed_at: import_closed_at,
+            closed_at,
             close_reason: None,
             closed_by_session: None,
             source_system: None,
@@ -515,6 +654,10 @@ fn execute_import(
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         };
 
         issue.content_hash = Some(issue.compute_content_hash());
@@ -546,6 +689,7 @@ fn execute_import(
         // Populate Dependencies (with validation)
         let mut deps = parsed.dependencies;
         deps.extend(args.deps.clone());
+        let mut pending_deps = Vec::new();
         for dep_str in deps {
             let (mut type_str, dep_id, valid) = parse_dependency(&dep_str);
             if !valid {
@@ -555,6 +699,14 @@ fn execute_import(
             if type_str.eq_ignore_ascii_case("blocked-by") {
                 type_str = "blocks".to_string();
             }
+            if let Some(target) = parse_placeholder(&dep_id) {
+                if target == index {
+                    eprintln!("warning: skipping self-dependency for issue {id}");
+                } else {
+                    pending_deps.push((id.clone(), type_str, target));
+                }
+                continue;
+            }
             if dep_id == id {
                 eprintln!("warning: skipping self-dependency for issue {id}");
                 continue;
@@ -580,27 +732,204 @@ fn execute_import(
             continue;
         }
 
-        if ctx.is_json() {
-            if let Some(full_issue) = storage.get_issue_for_export(&id)? {
+        placeholder_deps.extend(pending_deps);
+        ids_by_index.insert(index, id.clone());
+        created_ids.push((id, title));
+    }
+
+    // Second pass: resolve `#N` references now that every issue has an ID
+    for (id, dep_type, target) in placeholder_deps {
+        let Some(target_id) = ids_by_index.get(&target) else {
+            eprintln!(
+                "warning: skipping dependency #{} for issue {id}: no such issue was created",
+                target + 1
+            );
+            continue;
+        };
+        if let Err(err) = storage.add_dependency(&id, target_id, &dep_type, &actor) {
+            eprintln!("warning: skipping dependency {dep_type}:{target_id} for issue {id}: {err}");
+        }
+    }
+
+    if ctx.is_json() {
+        let mut created_issues = Vec::new();
+        for (id, _) in created_ids.iter().chain(&updated_ids) {
+            if let Some(full_issue) = storage.get_issue_for_export(id)? {
                 created_issues.push(full_issue);
             } else {
                 eprintln!("warning: could not load created issue {id} for JSON output");
             }
         }
+        ctx.json_pretty(&created_issues);
+    } else {
+        for (verb, ids) in [("Created", created_ids), ("Updated", updated_ids)] {
+            if ids.is_empty() {
+                continue;
+            }
+            ctx.success(&format!(
+                "{verb} {} issues from {}:",
+                ids.len(),
+                path.display()
+            ));
+            for (id, title) in ids {
+                ctx.print(&format!("  {id}: {title}"));
+            }
+        }
+    }
 
-        created_ids.push((id, title));
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Find the existing issue a parsed markdown issue refers to, by its
+/// `<!-- br:id ... -->` comment or its external ref.
+fn find_existing(storage: &dyn Storage, parsed: &ParsedIssue) -> Result<Option<Issue>> {
+    if let Some(id) = &parsed.id {
+        if let Some(issue) = storage.get_issue(id)? {
+            return Ok(Some(issue));
+        }
+    }
+    match &parsed.external_ref {
+        Some(external_ref) => storage.find_by_external_ref(external_ref),
+        None => Ok(None),
+    }
+}
+
+/// Apply a parsed markdown issue to an existing one (`--update`).
+///
+/// Only sections present in the file change the issue. A Labels section
+/// replaces the labels, and dependencies not already present are added.
+/// Returns the `#N` dependencies to add once every issue in the file exists.
+fn update_from_markdown(
+    storage: &mut dyn Storage,
+    existing: &Issue,
+    parsed: &ParsedIssue,
+    index: usize,
+    actor: &str,
+) -> Result<Vec<(String, String, usize)>> {
+    let id = &existing.id;
+    let mut update = IssueUpdate::default();
+    let title = parsed.title.trim();
+    if title != existing.title {
+        update.title = Some(title.to_string());
+    }
+    if let Some(status) = &parsed.status {
+        let status = Status::from_str(status)?;
+        if status != existing.status {
+            update.closed_at =
+                Some(matches!(status, Status::Closed | Status::Tombstone).then(Utc::now));
+            update.status = Some(status);
+        }
+    }
+    if let Some(priority) = &parsed.priority {
+        let priority = Priority::from_str(priority)?;
+        if priority != existing.priority {
+            update.priority = Some(priority);
+        }
+    }
+    if let Some(issue_type) = &parsed.issue_type {
+        let issue_type = IssueType::from_str(issue_type)?;
+        if issue_type != existing.issue_type {
+            update.issue_type = Some(issue_type);
+        }
+    }
+    update.description = changed_text(
+        existing.description.as_deref(),
+        parsed.description.as_deref(),
+    );
+    update.design = changed_text(existing.design.as_deref(), parsed.design.as_deref());
+    update.acceptance_criteria = changed_text(
+        existing.acceptance_criteria.as_deref(),
+        parsed.acceptance_criteria.as_deref(),
+    );
+    update.notes = changed_text(existing.notes.as_deref(), parsed.notes.as_deref());
+    update.assignee = changed_text(existing.assignee.as_deref(), parsed.assignee.as_deref());
+    update.external_ref = changed_text(
+        existing.external_ref.as_deref(),
+        parsed.external_ref.as_deref(),
+    );
+    if !update.is_empty() {
+        storage.update_issue(id, &update, actor)?;
+    }
+
+    if !parsed.labels.is_empty() {
+        let mut labels = Vec::new();
+        for label in &parsed.labels {
+            let label = label.trim();
+            if label.is_empty() {
+                continue;
+            }
+            LabelValidator::validate(label)
+                .map_err(|err| BeadsError::from_validation_errors(vec![err]))?;
+            labels.push(label.to_string());
+        }
+        labels.sort();
+        labels.dedup();
+        let mut current = storage.get_labels(id)?;
+        current.sort();
+        if labels != current {
+            storage.set_labels(id, &labels, actor)?;
+        }
+    }
+
+    let current_deps: Vec<String> = storage
+        .get_dependencies_full(id)?
+        .into_iter()
+        .map(|dep| dep.depends_on_id)
+        .collect();
+    let mut pending_deps = Vec::new();
+    for dep_str in &parsed.dependencies {
+        let (mut type_str, dep_id, valid) = parse_dependency(dep_str);
+        if !valid {
+            eprintln!("warning: skipping invalid dependency type '{type_str}' for issue {id}");
+            continue;
+        }
+        if type_str.eq_ignore_ascii_case("blocked-by") {
+            type_str = "blocks".to_string();
+        }
+        if let Some(target) = parse_placeholder(&dep_id) {
+            if target != index {
+                pending_deps.push((id.clone(), type_str, target));
+            }
+            continue;
+        }
+        if dep_id == *id || current_deps.contains(&dep_id) {
+            continue;
+        }
+        storage.add_dependency(id, &dep_id, &type_str, actor)?;
+    }
+    Ok(pending_deps)
+}
+
+/// The update for a text field given in the markdown, or None if the section
+/// is absent or unchanged.
+fn changed_text(current: Option<&str>, parsed: Option<&str>) -> Option<Option<String>> {
+    let parsed = parsed?.trim();
+    (current.map(str::trim) != Some(parsed)).then(|| Some(parsed.to_string()))
+}
+
+/// Create issues from a JSON issue (or array of issues) on stdin
+/// (`br create -`) and print the created IDs.
+fn execute_stdin_json(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let config = CreateConfig::from_layer(&layer)?;
+
+    let ids = create_issues_from_json(&mut *storage_ctx.storage, &input, &config)?;
+    if let Some(last) = ids.last() {
+        crate::util::set_last_touched_id(&beads_dir, last);
     }
 
     if ctx.is_json() {
-        ctx.json_pretty(&created_issues);
-    } else if !created_ids.is_empty() {
-        ctx.success(&format!(
-            "Created {} issues from {}:",
-            created_ids.len(),
-            path.display()
-        ));
-        for (id, title) in created_ids {
-            ctx.print(&format!("  {id}: {title}"));
+        ctx.json_pretty(&ids);
+    } else {
+        ctx.success(&format!("Created {} issues from stdin", ids.len()));
+        for id in &ids {
+            ctx.print(&format!("  {id}"));
         }
     }
 
@@ -608,6 +937,151 @@ fn execute_import(
     Ok(())
 }
 
+/// Create issues from a JSON issue object or array of them.
+///
+/// `id`, `created_at` and `updated_at` may be omitted; missing IDs are
+/// generated, and missing priority, type and text fields use the configured
+/// defaults (including per-type defaults). Every issue is validated before
+/// any is created.
+///
+/// # Errors
+///
+/// Returns an error if the JSON is malformed, an issue fails validation, a
+/// given ID already exists, an issue looks like an open issue and
+/// `duplicate_check` is `Block`, or the database write fails.
+pub fn create_issues_from_json(
+    storage: &mut dyn Storage,
+    input: &str,
+    config: &CreateConfig,
+) -> Result<Vec<String>> {
+    let value: serde_json::Value = serde_json::from_str(input)?;
+    let items = match value {
+        serde_json::Value::Array(items) => items,
+        item @ serde_json::Value::Object(_) => vec![item],
+        _ => {
+            return Err(BeadsError::validation(
+                "stdin",
+                "expected an issue object or an array of issues",
+            ));
+        }
+    };
+
+    let now = Utc::now();
+    let id_gen = IdGenerator::new(config.id_config.clone());
+    let mut issues: Vec<Issue> = Vec::with_capacity(items.len());
+
+    for (index, mut item) in items.into_iter().enumerate() {
+        let Some(object) = item.as_object_mut() else {
+            return Err(BeadsError::validation(
+                "stdin",
+                format!("item {index} is not an issue object"),
+            ));
+        };
+        let has_priority = object.contains_key("priority");
+        let has_type = object.contains_key("issue_type");
+        let now_value = serde_json::Value::String(now.to_rfc3339());
+        object.entry("id").or_insert_with(|| "".into());
+        object
+            .entry("created_at")
+            .or_insert_with(|| now_value.clone());
+        object
+            .entry("updated_at")
+            .or_insert_with(|| now_value.clone());
+        if let Some(deps) = object
+            .get_mut("dependencies")
+            .and_then(serde_json::Value::as_array_mut)
+        {
+            for dep in deps.iter_mut().filter_map(serde_json::Value::as_object_mut) {
+                dep.entry("issue_id").or_insert_with(|| "".into());
+                dep.entry("type").or_insert_with(|| "blocks".into());
+                dep.entry("created_at").or_insert_with(|| now_value.clone());
+            }
+        }
+
+        let mut issue: Issue = serde_json::from_value(item)
+            .map_err(|e| BeadsError::validation("stdin", format!("item {index}: {e}")))?;
+        if !has_type {
+            issue.issue_type = config.default_issue_type.clone();
+        }
+        let type_defaults = config
+            .type_defaults
+            .get(issue.issue_type.as_str())
+            .cloned()
+            .unwrap_or_default();
+        if !has_priority {
+            issue.priority = type_defaults.priority.unwrap_or(config.default_priority);
+        }
+        if issue.description.is_none() {
+            issue.description = type_defaults.description;
+        }
+        if issue.design.is_none() {
+            issue.design = type_defaults.design;
+        }
+        if issue.acceptance_criteria.is_none() {
+            issue.acceptance_criteria = type_defaults.acceptance_criteria;
+        }
+        if issue.notes.is_none() {
+            issue.notes = type_defaults.notes;
+        }
+        if issue.assignee.is_none() {
+            issue.assignee = type_defaults.assignee;
+        }
+        for label in type_defaults.labels {
+            if !issue.labels.contains(&label) {
+                issue.labels.push(label);
+            }
+        }
+
+        if issue.id.is_empty() {
+            let count = storage.count_issues()? + issues.len();
+            issue.id = id_gen.generate(
+                &issue.title,
+                issue.description.as_deref(),
+                None,
+                now,
+                count,
+                |id| storage.id_exists(id).unwrap_or(false) || issues.iter().any(|i| i.id == id),
+            );
+        } else if storage.id_exists(&issue.id)? || issues.iter().any(|i| i.id == issue.id) {
+            return Err(BeadsError::IdCollision { id: issue.id });
+        }
+
+        if issue.created_by.is_none() {
+            issue.created_by = Some(config.actor.clone());
+        }
+        if issue.closed_at.is_none() && matches!(issue.status, Status::Closed | Status::Tombstone) {
+            issue.closed_at = Some(now);
+        }
+        for dep in &mut issue.dependencies {
+            dep.issue_id.clone_from(&issue.id);
+        }
+        issue.content_hash = Some(issue.compute_content_hash());
+
+        IssueValidator::validate(&issue).map_err(BeadsError::from_validation_errors)?;
+        for label in &issue.labels {
+            LabelValidator::validate(label)
+                .map_err(|e| BeadsError::validation("label", e.message))?;
+        }
+        if config.duplicate_check == config::DuplicateCheck::Block {
+            let candidates = find_open_duplicates(storage, &issue)?;
+            if !candidates.is_empty() {
+                return Err(BeadsError::PossibleDuplicate {
+                    title: issue.title,
+                    candidates,
+                });
+            }
+        }
+        issues.push(issue);
+    }
+
+    let mut ids = Vec::with_capacity(issues.len());
+    for issue in issues {
+        storage.create_issue(&issue, &config.actor)?;
+        ids.push(issue.id);
+    }
+    Ok(ids)
+}
+
 fn parse_optional_date(s: Option<&str>) -> Result<Option<DateTime<Utc>>> {
     match s {
         Some(s) if !s.trim().is_empty() => parse_flexible_timestamp(s, "date").map(Some),
@@ -619,6 +1093,7 @@ fn parse_optional_date(s: Option<&str>) -> Result<Option<DateTime<Utc>>> {
 mod tests {
     use super::*;
     use crate::logging::init_test_logging;
+    use crate::storage::SqliteStorage;
     use crate::util::id::IdConfig;
     use chrono::Datelike;
     use tracing::info;
@@ -645,6 +1120,9 @@ mod tests {
             dry_run: false,
             silent: false,
             file: None,
+            update: false,
+            no_defaults: false,
+            allow_duplicate: false,
         }
     }
 
@@ -655,10 +1133,13 @@ mod tests {
                 min_hash_length: 3,
                 max_hash_length: 8,
                 max_collision_prob: 0.25,
+                salt: None,
             },
             default_priority: Priority::MEDIUM,
             default_issue_type: IssueType::Task,
+            type_defaults: HashMap::new(),
             actor: "test_user".to_string(),
+            duplicate_check: config::DuplicateCheck::Off,
         }
     }
 
@@ -688,6 +1169,143 @@ mod tests {
         info!("test_create_issue_basic_success: assertions passed");
     }
 
+    #[test]
+    fn test_create_issue_rejects_likely_duplicate() {
+        init_test_logging();
+        let mut storage = setup_memory_storage();
+        let config = CreateConfig {
+            duplicate_check: config::DuplicateCheck::Block,
+            ..default_config()
+        };
+        let first = create_issue_impl(&mut storage, &default_args(), &config).expect("create");
+
+        let err = create_issue_impl(&mut storage, &default_args(), &config).unwrap_err();
+        match err {
+            BeadsError::PossibleDuplicate { candidates, .. } => {
+                assert_eq!(candidates.len(), 1);
+                assert_eq!(candidates[0].id, first.id);
+                assert!(candidates[0].exact);
+            }
+            other => panic!("expected PossibleDuplicate, got {other:?}"),
+        }
+
+        let args = CreateArgs {
+            title: Some("Unrelated work".to_string()),
+            ..default_args()
+        };
+        create_issue_impl(&mut storage, &args, &config).expect("distinct title");
+
+        let args = CreateArgs {
+            allow_duplicate: true,
+            ..default_args()
+        };
+        create_issue_impl(&mut storage, &args, &config).expect("allowed duplicate");
+    }
+
+    #[test]
+    fn test_create_issue_applies_type_defaults() {
+        init_test_logging();
+        let mut storage = setup_memory_storage();
+        let mut config = default_config();
+        config.type_defaults.insert(
+            "bug".to_string(),
+            config::TypeDefaults {
+                priority: Some(Priority::HIGH),
+                labels: vec!["bug".to_string()],
+                acceptance_criteria: Some("Repro no longer fails".to_string()),
+                ..config::TypeDefaults::default()
+            },
+        );
+        let args = CreateArgs {
+            type_: Some("bug".to_string()),
+            labels: vec!["ui".to_string()],
+            ..default_args()
+        };
+
+        let issue = create_issue_impl(&mut storage, &args, &config).expect("create failed");
+        assert_eq!(issue.priority, Priority::HIGH);
+        assert_eq!(issue.labels, vec!["ui".to_string(), "bug".to_string()]);
+        assert_eq!(
+            issue.acceptance_criteria.as_deref(),
+            Some("Repro no longer fails")
+        );
+
+        let args = CreateArgs {
+            priority: Some("3".to_string()),
+            no_defaults: true,
+            ..args
+        };
+        let issue = create_issue_impl(&mut storage, &args, &config).expect("create failed");
+        assert_eq!(issue.priority, Priority(3));
+        assert_eq!(issue.labels, vec!["ui".to_string()]);
+        assert!(issue.acceptance_criteria.is_none());
+    }
+
+    #[test]
+    fn test_create_issues_from_json_generates_ids_and_defaults() {
+        init_test_logging();
+        let mut storage = setup_memory_storage();
+        let config = default_config();
+        let input = r#"[
+            {"title": "First", "labels": ["backend"]},
+            {"id": "bd-given", "title": "Second", "priority": 0, "issue_type": "bug"}
+        ]"#;
+
+        let ids = create_issues_from_json(&mut storage, input, &config).expect("create");
+        assert_eq!(ids.len(), 2);
+        assert!(ids[0].starts_with("bd-"));
+        assert_eq!(ids[1], "bd-given");
+
+        let first = storage.get_issue(&ids[0]).unwrap().unwrap();
+        assert_eq!(first.priority, Priority::MEDIUM);
+        assert_eq!(storage.get_labels(&ids[0]).unwrap(), vec!["backend"]);
+        let second = storage.get_issue("bd-given").unwrap().unwrap();
+        assert_eq!(second.issue_type, IssueType::Bug);
+
+        // Existing IDs and invalid issues are rejected before anything is written
+        let err = create_issues_from_json(
+            &mut storage,
+            r#"[{"title": "Third"}, {"id": "bd-given", "title": "Dup"}]"#,
+            &config,
+        );
+        assert!(err.is_err());
+        assert!(create_issues_from_json(&mut storage, r#"{"title": ""}"#, &config).is_err());
+        assert_eq!(storage.count_issues().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_create_issues_from_json_uses_type_defaults_and_duplicate_check() {
+        init_test_logging();
+        let mut storage = setup_memory_storage();
+        let mut config = CreateConfig {
+            duplicate_check: config::DuplicateCheck::Block,
+            ..default_config()
+        };
+        config.type_defaults.insert(
+            "bug".to_string(),
+            config::TypeDefaults {
+                priority: Some(Priority(1)),
+                labels: vec!["triage".to_string()],
+                notes: Some("Repro steps?".to_string()),
+                ..config::TypeDefaults::default()
+            },
+        );
+
+        let input = r#"{"title": "Login button broken", "issue_type": "bug"}"#;
+        let ids = create_issues_from_json(&mut storage, input, &config).expect("create");
+        let bug = storage.get_issue(&ids[0]).unwrap().unwrap();
+        assert_eq!(bug.priority, Priority(1));
+        assert_eq!(bug.notes.as_deref(), Some("Repro steps?"));
+        assert_eq!(storage.get_labels(&ids[0]).unwrap(), vec!["triage"]);
+
+        let err = create_issues_from_json(&mut storage, input, &config).unwrap_err();
+        assert!(
+            matches!(err, BeadsError::PossibleDuplicate { .. }),
+            "{err:?}"
+        );
+        assert_eq!(storage.count_issues().unwrap(), 1);
+    }
+
     #[test]
     fn test_create_issue_validation_empty_title() {
         init_test_logging();
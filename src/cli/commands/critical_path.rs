@@ -0,0 +1,275 @@
+//! Critical path command implementation.
+//!
+//! `br critical-path` finds the longest chain of open blocking dependencies,
+//! weighted by `estimated_minutes` (issues without an estimate count as
+//! zero, and chain length breaks ties). With `--to <id>` the chain must end
+//! at that issue, which shows the sequence of work standing in front of it.
+//!
+//! Parent-child edges describe hierarchy rather than ordering and are not
+//! followed.
+
+use crate::cli::CriticalPathArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::format_estimate;
+use crate::model::{DependencyType, Issue};
+use crate::output::OutputContext;
+use crate::storage::ListFilters;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// One issue on the critical path.
+#[derive(Debug, Serialize)]
+struct PathStep {
+    id: String,
+    title: String,
+    status: String,
+    priority: i32,
+    estimated_minutes: Option<i32>,
+}
+
+/// JSON output for the critical-path command.
+#[derive(Debug, Serialize)]
+struct CriticalPathOutput {
+    /// Issues in the order they have to be done (prerequisites first)
+    path: Vec<PathStep>,
+    total_minutes: i64,
+    length: usize,
+}
+
+/// Execute the critical-path command.
+///
+/// # Errors
+///
+/// Returns an error if `--to` cannot be resolved or is closed, or database
+/// operations fail.
+#[allow(clippy::too_many_lines)]
+pub fn execute(
+    args: &CriticalPathArgs,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &*storage_ctx.storage;
+
+    let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix).with_aliases(storage.get_id_aliases()?),
+    );
+    let all_ids = storage.get_all_ids()?;
+    let target = args
+        .to
+        .as_deref()
+        .map(|input| {
+            resolver
+                .resolve(
+                    input,
+                    |id| storage.id_exists(id).unwrap_or(false),
+                    |hash| find_matching_ids(&all_ids, hash),
+                )
+                .map(|resolved| resolved.id)
+        })
+        .transpose()?;
+
+    let issues: HashMap<String, Issue> = storage
+        .list_issues(&ListFilters {
+            include_deferred: true,
+            ..ListFilters::default()
+        })?
+        .into_iter()
+        .map(|issue| (issue.id.clone(), issue))
+        .collect();
+    if let Some(target) = &target {
+        if !issues.contains_key(target) {
+            return Err(BeadsError::validation(
+                "to",
+                format!("{target} is not open"),
+            ));
+        }
+    }
+
+    let mut edges: HashMap<String, Vec<String>> = HashMap::new();
+    for (issue_id, deps) in storage.get_all_dependency_records()? {
+        if !issues.contains_key(&issue_id) {
+            continue;
+        }
+        let open_blockers: Vec<String> = deps
+            .into_iter()
+            .filter(|dep| dep.dep_type.is_blocking() && dep.dep_type != DependencyType::ParentChild)
+            .map(|dep| dep.depends_on_id)
+            .filter(|id| issues.contains_key(id))
+            .collect();
+        if !open_blockers.is_empty() {
+            edges.insert(issue_id, open_blockers);
+        }
+    }
+    let weights: HashMap<String, i64> = issues
+        .iter()
+        .map(|(id, issue)| {
+            let minutes = issue.estimated_minutes.map_or(0, |m| i64::from(m.max(0)));
+            (id.clone(), minutes)
+        })
+        .collect();
+
+    let chain = longest_chain(&weights, &edges, target.as_deref());
+    let total_minutes = chain.iter().map(|id| weights[id]).sum();
+    let output = CriticalPathOutput {
+        length: chain.len(),
+        path: chain
+            .iter()
+            .map(|id| {
+                let issue = &issues[id];
+                PathStep {
+                    id: issue.id.clone(),
+                    title: issue.title.clone(),
+                    status: issue.status.as_str().to_string(),
+                    priority: issue.priority.0,
+                    estimated_minutes: issue.estimated_minutes,
+                }
+            })
+            .collect(),
+        total_minutes,
+    };
+
+    if ctx.is_json() {
+        ctx.json_pretty(&output);
+        return Ok(());
+    }
+    if output.path.is_empty() {
+        ctx.info("No open issues");
+        return Ok(());
+    }
+    ctx.info(&format!(
+        "Critical path: {} issue(s), {} estimated",
+        output.length,
+        format_estimate(output.total_minutes)
+    ));
+    for (i, step) in output.path.iter().enumerate() {
+        let estimate = step
+            .estimated_minutes
+            .map_or_else(|| "-".to_string(), |m| format_estimate(i64::from(m)));
+        ctx.print(&format!(
+            "  {}. {}: {} [P{}] [{}] {estimate}",
+            i + 1,
+            step.id,
+            step.title,
+            step.priority,
+            step.status
+        ));
+    }
+    Ok(())
+}
+
+/// The heaviest chain through `edges` (issue -> its blockers), ordered with
+/// prerequisites first. With `target`, only chains ending at it count.
+/// Edges that close a cycle are ignored.
+fn longest_chain(
+    weights: &HashMap<String, i64>,
+    edges: &HashMap<String, Vec<String>>,
+    target: Option<&str>,
+) -> Vec<String> {
+    let mut memo: HashMap<String, (i64, Vec<String>)> = HashMap::new();
+    let mut visiting = HashSet::new();
+    if let Some(target) = target {
+        let (_, mut chain) = heaviest_from(target, weights, edges, &mut memo, &mut visiting);
+        chain.reverse();
+        return chain;
+    }
+
+    let mut ids: Vec<&String> = weights.keys().collect();
+    ids.sort();
+    let mut best: (i64, Vec<String>) = (0, Vec::new());
+    for id in ids {
+        let candidate = heaviest_from(id, weights, edges, &mut memo, &mut visiting);
+        if (candidate.0, candidate.1.len()) > (best.0, best.1.len()) {
+            best = candidate;
+        }
+    }
+    best.1.reverse();
+    best.1
+}
+
+/// Weight and chain (starting at `id`, then its blockers) of the heaviest
+/// chain from `id`.
+fn heaviest_from(
+    id: &str,
+    weights: &HashMap<String, i64>,
+    edges: &HashMap<String, Vec<String>>,
+    memo: &mut HashMap<String, (i64, Vec<String>)>,
+    visiting: &mut HashSet<String>,
+) -> (i64, Vec<String>) {
+    if let Some(found) = memo.get(id) {
+        return found.clone();
+    }
+    visiting.insert(id.to_string());
+    let mut best: (i64, Vec<String>) = (0, Vec::new());
+    let mut blockers: Vec<&String> = edges
+        .get(id)
+        .map(|b| b.iter().collect())
+        .unwrap_or_default();
+    blockers.sort();
+    for blocker in blockers {
+        if visiting.contains(blocker) {
+            continue;
+        }
+        let candidate = heaviest_from(blocker, weights, edges, memo, visiting);
+        if (candidate.0, candidate.1.len()) > (best.0, best.1.len()) {
+            best = candidate;
+        }
+    }
+    visiting.remove(id);
+
+    let mut chain = vec![id.to_string()];
+    chain.extend(best.1);
+    let result = (weights.get(id).copied().unwrap_or(0) + best.0, chain);
+    memo.insert(id.to_string(), result.clone());
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(
+        nodes: &[(&str, i64)],
+        deps: &[(&str, &str)],
+    ) -> (HashMap<String, i64>, HashMap<String, Vec<String>>) {
+        let weights = nodes
+            .iter()
+            .map(|(id, w)| ((*id).to_string(), *w))
+            .collect();
+        let mut edges: HashMap<String, Vec<String>> = HashMap::new();
+        for (from, to) in deps {
+            edges
+                .entry((*from).to_string())
+                .or_default()
+                .push((*to).to_string());
+        }
+        (weights, edges)
+    }
+
+    #[test]
+    fn test_longest_chain_prefers_estimated_work() {
+        // d depends on b and c; b depends on a. a->b->d is 3 issues but
+        // c->d carries more estimated work.
+        let (weights, edges) = graph(
+            &[("a", 10), ("b", 10), ("c", 120), ("d", 5)],
+            &[("d", "b"), ("d", "c"), ("b", "a")],
+        );
+        assert_eq!(longest_chain(&weights, &edges, None), vec!["c", "d"]);
+        assert_eq!(longest_chain(&weights, &edges, Some("b")), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_longest_chain_uses_length_without_estimates() {
+        let (weights, edges) = graph(
+            &[("a", 0), ("b", 0), ("c", 0)],
+            &[("c", "b"), ("b", "a"), ("a", "c")],
+        );
+        // The cycle edge is skipped rather than looping forever
+        assert_eq!(longest_chain(&weights, &edges, None).len(), 3);
+    }
+}
@@ -34,6 +34,7 @@ pub struct SkippedIssue {
 /// # Errors
 ///
 /// Returns an error if database operations fail or IDs cannot be resolved.
+#[allow(clippy::too_many_lines)]
 pub fn execute_defer(
     args: &DeferArgs,
     _json: bool,
@@ -52,12 +53,15 @@ pub fn execute_defer(
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
-    let actor = config::resolve_actor(&config_layer);
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
     let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
     let all_ids = storage_ctx.storage.get_all_ids()?;
-    let storage = &mut storage_ctx.storage;
+    let storage = &mut *storage_ctx.storage;
 
     // Parse defer_until if provided
     let defer_until = args
@@ -190,12 +194,15 @@ pub fn execute_undefer(
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
-    let actor = config::resolve_actor(&config_layer);
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
     let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
     let all_ids = storage_ctx.storage.get_all_ids()?;
-    let storage = &mut storage_ctx.storage;
+    let storage = &mut *storage_ctx.storage;
 
     // Resolve all IDs
     let resolved_ids = resolver.resolve_all(
@@ -414,6 +421,7 @@ mod tests {
     use crate::model::{Issue, IssueType, Priority, Status};
     use crate::storage::SqliteStorage;
     use chrono::{Datelike, Duration, Local, Utc};
+    use std::collections::BTreeMap;
     use std::env;
     use std::path::PathBuf;
     use std::sync::Mutex;
@@ -481,6 +489,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
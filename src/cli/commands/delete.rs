@@ -1,16 +1,18 @@
 //! Delete command implementation.
 //!
-//! Creates tombstones for issues, handles dependencies, and supports
-//! cascade/force/dry-run modes.
+//! Creates tombstones for issues (or, with `--hard`, removes them
+//! outright), handles dependencies, and supports cascade/force/dry-run modes.
 
 use crate::cli::DeleteArgs;
+use crate::cli::selection::resolve_selection;
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::output::OutputContext;
-use crate::storage::SqliteStorage;
+use crate::storage::Storage;
+use crate::util::id::{IdResolver, ResolverConfig};
 use rich_rust::prelude::*;
 use serde::Serialize;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::fs;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
@@ -25,6 +27,33 @@ pub struct DeleteResult {
     pub events_removed: usize,
     pub references_updated: usize,
     pub orphaned_issues: Vec<String>,
+    /// Issues were removed outright instead of tombstoned (`--hard`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub hard: bool,
+}
+
+/// What a delete would touch, for the preview and `--dry-run`.
+#[derive(Debug, Serialize)]
+pub struct DeletePlan {
+    /// Always true: nothing was changed
+    pub preview: bool,
+    /// Issues named on the command line
+    pub issues: Vec<String>,
+    /// Issues directly depending on them (orphaned by `--force`)
+    pub dependents: Vec<String>,
+    /// Every issue `--cascade` would delete as well (the whole subtree)
+    pub cascade: Vec<String>,
+    /// Dependency links the delete removes
+    pub edges: Vec<AffectedEdge>,
+}
+
+/// A dependency link removed by a delete.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct AffectedEdge {
+    pub issue_id: String,
+    pub depends_on_id: String,
+    #[serde(rename = "type")]
+    pub dep_type: String,
 }
 
 impl DeleteResult {
@@ -37,6 +66,7 @@ impl DeleteResult {
             events_removed: 0,
             references_updated: 0,
             orphaned_issues: Vec::new(),
+            hard: false,
         }
     }
 }
@@ -69,51 +99,41 @@ pub fn execute(
         return Err(BeadsError::validation("ids", "no issue IDs provided"));
     }
 
-    // Deduplicate
-    let ids: Vec<String> = ids
-        .into_iter()
-        .collect::<HashSet<_>>()
-        .into_iter()
-        .collect();
-
     // 2. Open storage
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
-    let storage = &mut storage_ctx.storage;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let storage = &mut *storage_ctx.storage;
 
-    // 3. Validate all IDs exist
-    for id in &ids {
-        if storage.get_issue(id)?.is_none() {
-            return Err(BeadsError::IssueNotFound { id: id.clone() });
-        }
-    }
+    // 3. Resolve IDs (expanding ranges, globs and filters); each must exist
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(config::id_config_from_layer(&config_layer).prefix)
+            .with_aliases(storage.get_id_aliases()?),
+    );
+    let ids = resolve_selection(storage, &resolver, &ids)?;
 
-    // 4. Check for dependents (if not --force and not --cascade)
-    let delete_set: HashSet<String> = ids.iter().cloned().collect();
-    let mut all_dependents: Vec<String> = Vec::new();
+    // 4. Work out everything the delete touches
+    let plan = build_plan(storage, &ids, args.cascade)?;
 
-    for id in &ids {
-        let dependents = storage.get_dependents(id)?;
-        for dep_id in dependents {
-            if !delete_set.contains(&dep_id) {
-                all_dependents.push(dep_id);
-            }
-        }
-    }
-    all_dependents.sort();
-    all_dependents.dedup();
-
-    if !all_dependents.is_empty() && !args.force && !args.cascade {
+    if !plan.dependents.is_empty() && !args.force && !args.cascade {
         // Preview mode: show what would happen
-        if ctx.is_rich() {
-            render_dependents_warning_rich(&all_dependents, storage, ctx);
+        if ctx.is_json() {
+            ctx.json_pretty(&plan);
+        } else if ctx.is_rich() {
+            render_dependents_warning_rich(&plan, storage, ctx);
         } else {
             println!("The following issues depend on issues being deleted:");
-            for dep in &all_dependents {
+            for dep in &plan.dependents {
                 println!("  - {dep}");
             }
             println!();
+            println!(
+                "--cascade would also delete {} issue(s):",
+                plan.cascade.len()
+            );
+            print_issue_lines(storage, &plan.cascade);
+            print_edge_lines(&plan.edges);
+            println!();
             println!(
                 "Use --force to orphan these dependents, or --cascade to delete them recursively."
             );
@@ -124,77 +144,67 @@ pub fn execute(
 
     // 5. Dry-run mode
     if args.dry_run {
-        if ctx.is_rich() {
-            let cascade_ids: Vec<String> = if args.cascade {
-                all_dependents.clone()
-            } else {
-                vec![]
-            };
-            let orphan_ids: Vec<String> = if args.force && !args.cascade {
-                all_dependents.clone()
+        if ctx.is_json() {
+            ctx.json_pretty(&plan);
+        } else if ctx.is_rich() {
+            let cascade_ids: &[String] = if args.cascade { &plan.cascade } else { &[] };
+            let orphan_ids: &[String] = if args.force && !args.cascade {
+                &plan.dependents
             } else {
-                vec![]
+                &[]
             };
-            render_dry_run_rich(&ids, &cascade_ids, &orphan_ids, storage, ctx);
+            render_dry_run_rich(&plan, cascade_ids, orphan_ids, storage, ctx);
         } else {
             println!("Dry-run: Would delete {} issue(s):", ids.len());
-            for id in &ids {
-                let issue = storage
-                    .get_issue(id)?
-                    .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
-                println!("  - {}: {}", id, issue.title);
-            }
-            if args.cascade && !all_dependents.is_empty() {
+            print_issue_lines(storage, &ids);
+            if args.cascade && !plan.cascade.is_empty() {
                 println!(
                     "Would also cascade delete {} dependent(s):",
-                    all_dependents.len()
+                    plan.cascade.len()
                 );
-                for dep in &all_dependents {
-                    println!("  - {dep}");
-                }
+                print_issue_lines(storage, &plan.cascade);
             }
-            if args.force && !all_dependents.is_empty() {
-                println!("Would orphan {} dependent(s):", all_dependents.len());
-                for dep in &all_dependents {
+            if args.force && !plan.dependents.is_empty() {
+                println!("Would orphan {} dependent(s):", plan.dependents.len());
+                for dep in &plan.dependents {
                     println!("  - {dep}");
                 }
             }
+            print_edge_lines(&plan.edges);
         }
         return Ok(());
     }
 
     // 6. Build final delete set
-    let mut final_delete_set: HashSet<String> = delete_set;
+    let mut final_ids = ids;
     if args.cascade {
-        // Recursively collect all dependents
-        let cascade_ids = collect_cascade_dependents(storage, &ids)?;
-        final_delete_set.extend(cascade_ids);
+        final_ids.extend(plan.cascade.iter().cloned());
     }
+    final_ids.sort();
+    final_ids.dedup();
 
     // 7. Get actor
-    let actor = config::resolve_actor(&config_layer);
+    let actor = config::actor(&config_layer);
 
-    // 8. Perform deletion
+    // 8. Perform deletion: links and issues go in one transaction, so a
+    // failing cascade leaves the subtree as it was
     let mut result = DeleteResult::new();
-
-    // First, remove all dependency links for issues being deleted
-    for id in &final_delete_set {
-        let deps_removed = storage.remove_all_dependencies(id, &actor)?;
-        result.dependencies_removed += deps_removed;
-    }
+    result.dependencies_removed =
+        storage.delete_issues(&final_ids, &actor, &args.reason, args.hard)?;
 
     // Track orphaned issues (only relevant for --force mode)
     if args.force && !args.cascade {
-        result.orphaned_issues.clone_from(&all_dependents);
+        result.orphaned_issues.clone_from(&plan.dependents);
     }
 
-    // Delete each issue (create tombstone)
-    let final_ids: Vec<String> = final_delete_set.into_iter().collect();
-    for id in &final_ids {
-        storage.delete_issue(id, &actor, &args.reason, None)?;
-        result.deleted.push(id.clone());
-    }
+    result.deleted.clone_from(&final_ids);
     result.deleted_count = result.deleted.len();
+    result.hard = args.hard;
+
+    // A purged issue must leave the JSONL now, or later exports refuse
+    if args.hard {
+        storage_ctx.export_after_purge(&final_ids)?;
+    }
 
     // 9. Output
     if ctx.is_json() {
@@ -206,9 +216,10 @@ pub fn execute(
     result.deleted.sort();
 
     if ctx.is_rich() {
-        render_delete_result_rich(&result, storage, ctx);
+        render_delete_result_rich(&result, &*storage_ctx.storage, ctx);
     } else {
-        println!("Deleted {} issue(s):", result.deleted_count);
+        let verb = if result.hard { "Purged" } else { "Deleted" };
+        println!("{verb} {} issue(s):", result.deleted_count);
         for id in &result.deleted {
             println!("  - {id}");
         }
@@ -252,9 +263,81 @@ fn read_ids_from_file(path: &Path) -> Result<Vec<String>> {
     Ok(ids)
 }
 
+/// Work out what deleting `ids` touches: their direct dependents, the
+/// subtree `--cascade` would take along, and the dependency links removed
+/// (those of `ids` alone, or of the whole subtree with `cascade`).
+fn build_plan(storage: &dyn Storage, ids: &[String], cascade: bool) -> Result<DeletePlan> {
+    let requested: HashSet<&str> = ids.iter().map(String::as_str).collect();
+    let mut dependents = BTreeSet::new();
+    for id in ids {
+        for dep_id in storage.get_dependents(id)? {
+            if !requested.contains(dep_id.as_str()) {
+                dependents.insert(dep_id);
+            }
+        }
+    }
+    let subtree: BTreeSet<String> = collect_cascade_dependents(storage, ids)?
+        .into_iter()
+        .collect();
+
+    let mut removed: Vec<&String> = ids.iter().collect();
+    if cascade {
+        removed.extend(&subtree);
+    }
+    let mut edges = BTreeSet::new();
+    for id in removed {
+        for dep in storage.get_dependencies_with_metadata(id)? {
+            edges.insert(AffectedEdge {
+                issue_id: id.clone(),
+                depends_on_id: dep.id,
+                dep_type: dep.dep_type,
+            });
+        }
+        for dep in storage.get_dependents_with_metadata(id)? {
+            edges.insert(AffectedEdge {
+                issue_id: dep.id,
+                depends_on_id: id.clone(),
+                dep_type: dep.dep_type,
+            });
+        }
+    }
+
+    Ok(DeletePlan {
+        preview: true,
+        issues: ids.to_vec(),
+        dependents: dependents.into_iter().collect(),
+        cascade: subtree.into_iter().collect(),
+        edges: edges.into_iter().collect(),
+    })
+}
+
+/// Print `  - id: title` for each issue.
+fn print_issue_lines(storage: &dyn Storage, ids: &[String]) {
+    for id in ids {
+        match storage.get_issue(id) {
+            Ok(Some(issue)) => println!("  - {id}: {}", issue.title),
+            _ => println!("  - {id}"),
+        }
+    }
+}
+
+/// Print the dependency links a delete removes.
+fn print_edge_lines(edges: &[AffectedEdge]) {
+    if edges.is_empty() {
+        return;
+    }
+    println!("Would remove {} dependency link(s):", edges.len());
+    for edge in edges {
+        println!(
+            "  - {} depends on {} ({})",
+            edge.issue_id, edge.depends_on_id, edge.dep_type
+        );
+    }
+}
+
 /// Recursively collect all dependents for cascade deletion.
 fn collect_cascade_dependents(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     initial_ids: &[String],
 ) -> Result<HashSet<String>> {
     let mut all_ids: HashSet<String> = initial_ids.iter().cloned().collect();
@@ -279,11 +362,7 @@ fn collect_cascade_dependents(
 }
 
 /// Render the dependents warning panel in rich format.
-fn render_dependents_warning_rich(
-    dependents: &[String],
-    storage: &SqliteStorage,
-    ctx: &OutputContext,
-) {
+fn render_dependents_warning_rich(plan: &DeletePlan, storage: &dyn Storage, ctx: &OutputContext) {
     let console = Console::default();
     let theme = ctx.theme();
     let width = ctx.width();
@@ -295,7 +374,7 @@ fn render_dependents_warning_rich(
         theme.warning.clone(),
     );
 
-    for dep_id in dependents {
+    for dep_id in &plan.dependents {
         content.append_styled("  \u{2022} ", theme.dimmed.clone());
         content.append_styled(dep_id, theme.issue_id.clone());
         // Try to get title
@@ -306,6 +385,21 @@ fn render_dependents_warning_rich(
         content.append("\n");
     }
 
+    content.append("\n");
+    content.append_styled("--cascade would also delete ", theme.dimmed.clone());
+    content.append_styled(&format!("{}", plan.cascade.len()), theme.emphasis.clone());
+    content.append_styled(" issue(s):\n\n", theme.dimmed.clone());
+    for id in &plan.cascade {
+        content.append_styled("  \u{21b3} ", theme.warning.clone());
+        content.append_styled(id, theme.issue_id.clone());
+        if let Ok(Some(issue)) = storage.get_issue(id) {
+            content.append_styled(": ", theme.dimmed.clone());
+            content.append(&issue.title);
+        }
+        content.append("\n");
+    }
+    append_edges_rich(&mut content, &plan.edges, theme);
+
     content.append("\n");
     content.append_styled(
         "Use --force to orphan these dependents, or --cascade to delete them recursively.\n",
@@ -325,10 +419,10 @@ fn render_dependents_warning_rich(
 
 /// Render the dry-run preview in rich format.
 fn render_dry_run_rich(
-    ids: &[String],
+    plan: &DeletePlan,
     cascade_ids: &[String],
     orphan_ids: &[String],
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     ctx: &OutputContext,
 ) {
     let console = Console::default();
@@ -339,10 +433,10 @@ fn render_dry_run_rich(
 
     // Main issues to delete
     content.append_styled("Would delete ", theme.dimmed.clone());
-    content.append_styled(&format!("{}", ids.len()), theme.emphasis.clone());
+    content.append_styled(&format!("{}", plan.issues.len()), theme.emphasis.clone());
     content.append_styled(" issue(s):\n\n", theme.dimmed.clone());
 
-    for id in ids {
+    for id in &plan.issues {
         content.append_styled("  \u{2717} ", theme.error.clone());
         content.append_styled(id, theme.issue_id.clone());
         if let Ok(Some(issue)) = storage.get_issue(id) {
@@ -388,6 +482,8 @@ fn render_dry_run_rich(
         }
     }
 
+    append_edges_rich(&mut content, &plan.edges, theme);
+
     let panel = Panel::from_rich_text(&content, width)
         .title(Text::styled(
             "\u{1f4cb} Dry Run Preview",
@@ -398,8 +494,26 @@ fn render_dry_run_rich(
     console.print_renderable(&panel);
 }
 
+/// Append the dependency links a delete removes to a rich panel.
+fn append_edges_rich(content: &mut Text, edges: &[AffectedEdge], theme: &crate::output::Theme) {
+    if edges.is_empty() {
+        return;
+    }
+    content.append("\n");
+    content.append_styled("Would remove ", theme.dimmed.clone());
+    content.append_styled(&format!("{}", edges.len()), theme.emphasis.clone());
+    content.append_styled(" dependency link(s):\n\n", theme.dimmed.clone());
+    for edge in edges {
+        content.append_styled("  \u{2702} ", theme.dimmed.clone());
+        content.append_styled(&edge.issue_id, theme.issue_id.clone());
+        content.append_styled(" depends on ", theme.dimmed.clone());
+        content.append_styled(&edge.depends_on_id, theme.issue_id.clone());
+        content.append_styled(&format!(" ({})\n", edge.dep_type), theme.muted.clone());
+    }
+}
+
 /// Render the delete result in rich format.
-fn render_delete_result_rich(result: &DeleteResult, storage: &SqliteStorage, ctx: &OutputContext) {
+fn render_delete_result_rich(result: &DeleteResult, storage: &dyn Storage, ctx: &OutputContext) {
     let console = Console::default();
     let theme = ctx.theme();
     let width = ctx.width();
@@ -407,7 +521,8 @@ fn render_delete_result_rich(result: &DeleteResult, storage: &SqliteStorage, ctx
     let mut content = Text::new("");
 
     // Deleted items
-    content.append_styled("Deleted ", theme.success.clone());
+    let verb = if result.hard { "Purged " } else { "Deleted " };
+    content.append_styled(verb, theme.success.clone());
     content.append_styled(&format!("{}", result.deleted_count), theme.emphasis.clone());
     content.append_styled(" issue(s):\n\n", theme.success.clone());
 
@@ -467,7 +582,9 @@ fn render_delete_result_rich(result: &DeleteResult, storage: &SqliteStorage, ctx
 mod tests {
     use super::*;
     use crate::model::{Issue, IssueType, Priority, Status};
+    use crate::storage::SqliteStorage;
     use chrono::Utc;
+    use std::collections::BTreeMap;
     use std::io::Write;
     use tempfile::NamedTempFile;
     use tracing::info;
@@ -517,6 +634,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -1,19 +1,22 @@
 //! Dependency command implementation.
 
 use crate::cli::{
-    DepAddArgs, DepCommands, DepCyclesArgs, DepDirection, DepListArgs, DepRemoveArgs, DepTreeArgs,
-    OutputFormat, resolve_output_format_basic,
+    DepAddArgs, DepApplyArgs, DepCommands, DepCyclesArgs, DepDirection, DepListArgs, DepRemoveArgs,
+    DepTreeArgs, OutputFormat, resolve_output_format_basic,
 };
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::format::truncate_title;
-use crate::model::DependencyType;
+use crate::model::{DependencyCondition, DependencyType, WaitUntil};
 use crate::output::{OutputContext, OutputMode};
-use crate::storage::SqliteStorage;
+use crate::storage::{DependencyChange, DependencyCycle, Storage};
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::time::parse_flexible_timestamp;
 use rich_rust::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read, Write};
 use std::path::PathBuf;
 
 /// Execute the dep command.
@@ -30,33 +33,48 @@ pub fn execute(
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
     let quiet = cli.quiet.unwrap_or(false);
     let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?)
+            .with_last_touched(crate::util::get_last_touched_id(&beads_dir)),
+    );
     let all_ids = storage_ctx.storage.get_all_ids()?;
-    let storage = &mut storage_ctx.storage;
+    let storage = &mut *storage_ctx.storage;
 
-    let actor = config::resolve_actor(&config_layer);
+    let actor = config::actor(&config_layer);
 
     let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
 
     match command {
-        DepCommands::Add(args) => dep_add(args, storage, &resolver, &all_ids, &actor, json, ctx),
-        DepCommands::Remove(args) => {
-            dep_remove(args, storage, &resolver, &all_ids, &actor, json, ctx)
-        }
-        DepCommands::List(args) => dep_list(
+        DepCommands::Add(args) => dep_add(
             args,
             storage,
             &resolver,
             &all_ids,
-            &external_db_paths,
-            json,
-            quiet,
-            !use_color,
+            &actor,
+            config::auto_close_duplicates_from_layer(&config_layer),
+            ctx,
         ),
+        DepCommands::Remove(args) => {
+            dep_remove(args, storage, &resolver, &all_ids, &actor, json, ctx)
+        }
+        DepCommands::List(args) => {
+            let output_format = resolve_output_format_basic(args.format, json, false);
+            let list_ctx = ctx.with_output_format(output_format, quiet, use_color);
+            dep_list(
+                args,
+                storage,
+                &resolver,
+                &all_ids,
+                &external_db_paths,
+                output_format,
+                &list_ctx,
+            )
+        }
         DepCommands::Tree(args) => dep_tree(
             args,
             storage,
@@ -66,7 +84,8 @@ pub fn execute(
             json,
             ctx,
         ),
-        DepCommands::Cycles(args) => dep_cycles(args, storage, json, ctx),
+        DepCommands::Cycles(args) => dep_cycles(args, storage, &actor, json, ctx),
+        DepCommands::Apply(args) => dep_apply(args, storage, &resolver, &all_ids, &actor, ctx),
     }?;
 
     storage_ctx.flush_no_db_if_dirty()?;
@@ -82,6 +101,9 @@ struct DepActionResult {
     #[serde(rename = "type")]
     dep_type: String,
     action: String,
+    /// Set when a `duplicates` dependency closed the issue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    close_reason: Option<String>,
 }
 
 /// JSON output for dep list
@@ -94,6 +116,9 @@ struct DepListItem {
     title: String,
     status: String,
     priority: i32,
+    /// Condition of a `conditional-blocks` dependency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    condition: Option<String>,
 }
 
 /// JSON output for dep tree
@@ -111,17 +136,53 @@ struct TreeNode {
 /// JSON output for dep cycles
 #[derive(Serialize)]
 struct CyclesResult {
-    cycles: Vec<Vec<String>>,
+    cycles: Vec<DependencyCycle>,
     count: usize,
 }
 
+/// Metadata for a new dependency from `--condition`, `--until` or `--metadata`.
+pub(crate) fn dep_metadata(
+    condition: Option<&str>,
+    until: Option<&str>,
+    metadata: Option<&str>,
+    dep_type: &DependencyType,
+) -> Result<Option<String>> {
+    Ok(match (condition, until, metadata) {
+        (Some(condition), _, _) => {
+            if *dep_type != DependencyType::ConditionalBlocks {
+                return Err(BeadsError::validation(
+                    "condition",
+                    "--condition needs --type conditional-blocks",
+                ));
+            }
+            Some(condition.parse::<DependencyCondition>()?.to_metadata())
+        }
+        (None, Some(until), _) => {
+            if *dep_type != DependencyType::WaitsFor {
+                return Err(BeadsError::validation(
+                    "until",
+                    "--until needs --type waits-for",
+                ));
+            }
+            Some(WaitUntil(parse_flexible_timestamp(until, "until")?).to_metadata())
+        }
+        (None, None, Some(metadata)) => {
+            serde_json::from_str::<serde_json::Value>(metadata).map_err(|err| {
+                BeadsError::validation("metadata", format!("not valid JSON: {err}"))
+            })?;
+            Some(metadata.to_string())
+        }
+        (None, None, None) => None,
+    })
+}
+
 fn dep_add(
     args: &DepAddArgs,
-    storage: &mut SqliteStorage,
+    storage: &mut dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     actor: &str,
-    _json: bool,
+    auto_close_duplicates: bool,
     ctx: &OutputContext,
 ) -> Result<()> {
     let issue_id = resolve_issue_id(storage, resolver, all_ids, &args.issue)?;
@@ -133,45 +194,23 @@ fn dep_add(
         resolve_issue_id(storage, resolver, all_ids, &args.depends_on)?
     };
 
-    // Parse and validate dependency type
-    let dep_type_str = &args.dep_type;
-    let dep_type: DependencyType = dep_type_str.parse().map_err(|_| BeadsError::Validation {
-        field: "type".to_string(),
-        reason: format!("Invalid dependency type: {dep_type_str}"),
-    })?;
-
-    // Disallow accidental custom types from typos
-    if let DependencyType::Custom(_) = dep_type {
-        // We enforce standard types for reliability unless it looks like a deliberate custom type
-        // For now, let's strictly enforce known types to prevent typos like "parent_child"
-        // which would otherwise be accepted as a non-blocking custom type.
-        return Err(BeadsError::Validation {
-            field: "type".to_string(),
-            reason: format!(
-                "Unknown dependency type: '{dep_type_str}'. \
-                 Allowed types: blocks, parent-child, conditional-blocks, waits-for, \
-                 related, discovered-from, replies-to, relates-to, duplicates, \
-                 supersedes, caused-by"
-            ),
-        });
-    }
-
-    // Self-dependency check
-    if issue_id == depends_on_id {
-        return Err(BeadsError::SelfDependency { id: issue_id });
-    }
-
-    // Cycle check for blocking types only
-    if dep_type.is_blocking()
-        && !depends_on_id.starts_with("external:")
-        && storage.would_create_cycle(&issue_id, &depends_on_id, true)?
-    {
-        return Err(BeadsError::DependencyCycle {
-            path: format!("{issue_id} -> {depends_on_id}"),
-        });
-    }
-
-    let added = storage.add_dependency(&issue_id, &depends_on_id, dep_type.as_str(), actor)?;
+    let dep_type = parse_dep_type(&args.dep_type)?;
+    let metadata = dep_metadata(
+        args.condition.as_deref(),
+        args.until.as_deref(),
+        args.metadata.as_deref(),
+        &dep_type,
+    )?;
+    let (added, closed) = add_dependency_checked(
+        storage,
+        &issue_id,
+        &depends_on_id,
+        &dep_type,
+        metadata.as_deref(),
+        actor,
+        auto_close_duplicates,
+    )?;
+    let close_reason = closed.then(|| format!("duplicate of {depends_on_id}"));
 
     if ctx.is_json() || ctx.is_toon() {
         let result = DepActionResult {
@@ -180,6 +219,7 @@ fn dep_add(
             depends_on_id: depends_on_id.clone(),
             dep_type: dep_type.as_str().to_string(),
             action: if added { "added" } else { "already_exists" }.to_string(),
+            close_reason: close_reason.clone(),
         };
         if ctx.is_toon() {
             ctx.toon(&result);
@@ -217,13 +257,65 @@ fn dep_add(
             "Dependency already exists: {issue_id} → {depends_on_id}"
         ));
     }
+    if let (Some(reason), false) = (&close_reason, ctx.is_json() || ctx.is_toon()) {
+        ctx.success(&format!("Closed {issue_id} ({reason})"));
+    }
 
     Ok(())
 }
 
+/// Add `issue_id -> depends_on_id` as `br dep add` does, after the self
+/// dependency and cycle checks. With `auto_close_duplicates` a `duplicates`
+/// edge also closes `issue_id`.
+///
+/// Returns whether the edge was added and whether `issue_id` was closed.
+///
+/// # Errors
+///
+/// Returns an error if the edge would depend on itself or close a cycle, or
+/// if the write fails.
+pub(crate) fn add_dependency_checked(
+    storage: &mut dyn Storage,
+    issue_id: &str,
+    depends_on_id: &str,
+    dep_type: &DependencyType,
+    metadata: Option<&str>,
+    actor: &str,
+    auto_close_duplicates: bool,
+) -> Result<(bool, bool)> {
+    if issue_id == depends_on_id {
+        return Err(BeadsError::SelfDependency {
+            id: issue_id.to_string(),
+        });
+    }
+
+    // Cycle check for blocking types only
+    if dep_type.is_blocking()
+        && !depends_on_id.starts_with("external:")
+        && storage.would_create_cycle(issue_id, depends_on_id, true)?
+    {
+        return Err(BeadsError::DependencyCycle {
+            path: format!("{issue_id} -> {depends_on_id}"),
+        });
+    }
+
+    // With `auto_close_duplicates`, the edge and the close land together
+    if auto_close_duplicates && *dep_type == DependencyType::Duplicates {
+        return storage.add_duplicate_and_close(issue_id, depends_on_id, actor);
+    }
+    let added = storage.add_dependency_with_metadata(
+        issue_id,
+        depends_on_id,
+        dep_type.as_str(),
+        metadata,
+        actor,
+    )?;
+    Ok((added, false))
+}
+
 fn dep_remove(
     args: &DepRemoveArgs,
-    storage: &mut SqliteStorage,
+    storage: &mut dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     actor: &str,
@@ -248,6 +340,7 @@ fn dep_remove(
             depends_on_id: depends_on_id.clone(),
             dep_type: "unknown".to_string(),
             action: if removed { "removed" } else { "not_found" }.to_string(),
+            close_reason: None,
         };
         if ctx.is_toon() {
             ctx.toon(&result);
@@ -278,19 +371,16 @@ fn dep_remove(
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_lines)]
 fn dep_list(
     args: &DepListArgs,
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     external_db_paths: &HashMap<String, PathBuf>,
-    json: bool,
-    quiet: bool,
-    no_color: bool,
+    output_format: OutputFormat,
+    ctx: &OutputContext,
 ) -> Result<()> {
-    let output_format = resolve_output_format_basic(args.format, json, false);
-    let ctx = OutputContext::from_output_format(output_format, quiet, no_color);
     let issue_id = resolve_issue_id(storage, resolver, all_ids, &args.issue)?;
 
     let mut items = Vec::new();
@@ -311,6 +401,7 @@ fn dep_list(
                 title: dep.title.clone(),
                 status: dep.status.as_str().to_string(),
                 priority: dep.priority.0,
+                condition: dep.condition.clone(),
             });
         }
     }
@@ -331,6 +422,7 @@ fn dep_list(
                 title: dep.title.clone(),
                 status: dep.status.as_str().to_string(),
                 priority: dep.priority.0,
+                condition: dep.condition.clone(),
             });
         }
     }
@@ -358,7 +450,7 @@ fn dep_list(
             ctx.toon_with_stats(&items, args.stats);
             return Ok(());
         }
-        OutputFormat::Text | OutputFormat::Csv => {}
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Table => {}
     }
 
     if items.is_empty() {
@@ -373,7 +465,7 @@ fn dep_list(
 
     if ctx.is_rich() {
         // Rich mode: Use panel with tree-like display
-        render_dep_list_rich(&ctx, &issue_id, &items, args.direction);
+        render_dep_list_rich(ctx, &issue_id, &items, args.direction);
     } else {
         // Plain mode: Simple text output
         let header = match args.direction {
@@ -388,10 +480,11 @@ fn dep_list(
         ctx.info(&header);
 
         for item in &items {
+            let dep_type = dep_type_label(item);
             let arrow = if item.issue_id == issue_id {
-                format!("  -> {} ({})", item.depends_on_id, item.dep_type)
+                format!("  -> {} ({dep_type})", item.depends_on_id)
             } else {
-                format!("  <- {} ({})", item.issue_id, item.dep_type)
+                format!("  <- {} ({dep_type})", item.issue_id)
             };
             ctx.print(&format!(
                 "{}: {} [P{}] [{}]",
@@ -429,8 +522,12 @@ fn render_dep_list_rich(
             };
             let status_indicator = format_status_indicator(&item.status);
             content.push_str(&format!(
-                "{} {} {} {}\n",
-                prefix, item.depends_on_id, status_indicator, item.title
+                "{} {} {} {}{}\n",
+                prefix,
+                item.depends_on_id,
+                status_indicator,
+                item.title,
+                rich_condition(item)
             ));
         }
     }
@@ -454,8 +551,12 @@ fn render_dep_list_rich(
             };
             let status_indicator = format_status_indicator(&item.status);
             content.push_str(&format!(
-                "{} {} {} {}\n",
-                prefix, item.issue_id, status_indicator, item.title
+                "{} {} {} {}{}\n",
+                prefix,
+                item.issue_id,
+                status_indicator,
+                item.title,
+                rich_condition(item)
             ));
         }
     }
@@ -467,6 +568,24 @@ fn render_dep_list_rich(
     ctx.render(&panel);
 }
 
+/// Dependency type for plain output, with the condition of a conditional
+/// dependency (`conditional-blocks if label:release-blocker`).
+fn dep_type_label(item: &DepListItem) -> String {
+    item.condition.as_ref().map_or_else(
+        || item.dep_type.clone(),
+        |condition| format!("{} if {condition}", item.dep_type),
+    )
+}
+
+/// Dimmed condition suffix for rich output.
+fn rich_condition(item: &DepListItem) -> String {
+    item.condition
+        .as_ref()
+        .map_or_else(String::new, |condition| {
+            format!(" [dim](if {condition})[/]")
+        })
+}
+
 /// Format status indicator with appropriate styling hints
 fn format_status_indicator(status: &str) -> String {
     match status {
@@ -515,7 +634,7 @@ fn apply_external_dep_list_metadata(
 #[allow(clippy::too_many_lines)]
 fn dep_tree(
     args: &DepTreeArgs,
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     external_db_paths: &HashMap<String, PathBuf>,
@@ -779,12 +898,20 @@ fn parse_external_dep_id(dep_id: &str) -> Option<(String, String)> {
 }
 
 fn dep_cycles(
-    _args: &DepCyclesArgs,
-    storage: &SqliteStorage,
+    args: &DepCyclesArgs,
+    storage: &mut dyn Storage,
+    actor: &str,
     _json: bool,
     ctx: &OutputContext,
 ) -> Result<()> {
-    let cycles = storage.detect_all_cycles()?;
+    if args.fix && (ctx.is_json() || ctx.is_toon()) {
+        return Err(BeadsError::validation(
+            "fix",
+            "--fix is interactive and cannot be combined with structured output",
+        ));
+    }
+
+    let cycles = storage.find_dependency_cycles(args.blocking_only)?;
     let count = cycles.len();
 
     if ctx.is_json() || ctx.is_toon() {
@@ -799,22 +926,120 @@ fn dep_cycles(
 
     if count == 0 {
         ctx.success("No dependency cycles detected.");
-    } else if ctx.is_rich() {
+        return Ok(());
+    }
+    if ctx.is_rich() {
         // Rich mode: Show cycles with red highlighting in a panel
         render_cycles_rich(ctx, &cycles, count);
     } else {
         // Plain mode: Simple text output
         ctx.warning(&format!("Found {count} dependency cycle(s):"));
         for (i, cycle) in cycles.iter().enumerate() {
-            ctx.print(&format!("  {}. {}", i + 1, cycle.join(" -> ")));
+            ctx.print(&format!("  {}. {}", i + 1, format_cycle_path(cycle)));
+            if has_other_members(cycle) {
+                ctx.print(&format!("     members: {}", cycle.members.join(", ")));
+            }
+        }
+    }
+
+    if args.fix {
+        fix_cycles(storage, &cycles, args.blocking_only, actor, ctx)?;
+    }
+    Ok(())
+}
+
+/// Ask which dependency to drop from each cycle and remove it.
+fn fix_cycles(
+    storage: &mut dyn Storage,
+    cycles: &[DependencyCycle],
+    blocking_only: bool,
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    for (i, cycle) in cycles.iter().enumerate() {
+        ctx.newline();
+        ctx.print(&format!("Cycle {}: {}", i + 1, cycle.members.join(", ")));
+        for (n, edge) in cycle.edges.iter().enumerate() {
+            ctx.print(&format!(
+                "  [{}] {} -> {} ({})",
+                n + 1,
+                edge.issue_id,
+                edge.depends_on_id,
+                edge.dep_type
+            ));
+        }
+        print!(
+            "Drop which dependency? [1-{}, Enter to skip] ",
+            cycle.edges.len()
+        );
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input)? == 0 {
+            // EOF: nothing more to answer
+            break;
+        }
+        let choice = input.trim();
+        if choice.is_empty() {
+            ctx.info("  Skipped");
+            continue;
+        }
+        let Some(edge) = choice
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|n| cycle.edges.get(n))
+        else {
+            ctx.warning(&format!("  Invalid choice '{choice}', skipped"));
+            continue;
+        };
+        if storage.remove_dependency(&edge.issue_id, &edge.depends_on_id, actor)? {
+            ctx.success(&format!(
+                "  Removed dependency: {} → {} ({})",
+                edge.issue_id, edge.depends_on_id, edge.dep_type
+            ));
         }
     }
 
+    // A component with several loops can survive a single removal
+    let remaining = storage.find_dependency_cycles(blocking_only)?.len();
+    ctx.newline();
+    if remaining == 0 {
+        ctx.success("No dependency cycles remain.");
+    } else {
+        ctx.warning(&format!(
+            "{remaining} dependency cycle(s) remain; run `br dep cycles --fix` again"
+        ));
+    }
     Ok(())
 }
 
+/// True if the cycle has members that its shortest loop does not pass through.
+fn has_other_members(cycle: &DependencyCycle) -> bool {
+    cycle.members.len() + 1 > cycle.path.len()
+}
+
+/// Format a cycle's loop with the type of each edge, e.g.
+/// `bd-1 -[blocks]-> bd-2 -[related]-> bd-1`.
+fn format_cycle_path(cycle: &DependencyCycle) -> String {
+    let mut out = String::new();
+    for (i, id) in cycle.path.iter().enumerate() {
+        if i > 0 {
+            let from = &cycle.path[i - 1];
+            let dep_type = cycle
+                .edges
+                .iter()
+                .find(|edge| &edge.issue_id == from && &edge.depends_on_id == id)
+                .map_or("?", |edge| edge.dep_type.as_str());
+            out.push_str(&format!(" -[{dep_type}]-> "));
+        }
+        out.push_str(id);
+    }
+    out
+}
+
 /// Render cycles in rich mode with red highlighting
-fn render_cycles_rich(ctx: &OutputContext, cycles: &[Vec<String>], count: usize) {
+fn render_cycles_rich(ctx: &OutputContext, cycles: &[DependencyCycle], count: usize) {
     let theme = ctx.theme();
 
     let mut content = String::new();
@@ -824,21 +1049,27 @@ fn render_cycles_rich(ctx: &OutputContext, cycles: &[Vec<String>], count: usize)
     ));
 
     for (i, cycle) in cycles.iter().enumerate() {
-        // Format cycle path with arrows
-        let cycle_path = cycle.join(" [red]→[/] ");
+        // Format cycle path with arrows, dimming the edge types
+        let cycle_path = format_cycle_path(cycle)
+            .replace(" -[", " [red]→[/] [dim]")
+            .replace("]-> ", "[/] [red]→[/] ");
         content.push_str(&format!("[bold]Cycle {}:[/]\n", i + 1));
-        content.push_str(&format!("  [red]{}[/]\n", cycle_path));
-
-        // Add underline visual
-        let path_len = cycle.iter().map(|s| s.len() + 4).sum::<usize>();
-        content.push_str(&format!("  [red]{}[/]\n", "^".repeat(path_len.min(60))));
+        content.push_str(&format!("  {}\n", cycle_path));
+        if has_other_members(cycle) {
+            content.push_str(&format!(
+                "  [dim]members: {}[/]\n",
+                cycle.members.join(", ")
+            ));
+        }
 
         if i < cycles.len() - 1 {
             content.push('\n');
         }
     }
 
-    content.push_str("\n[dim]Suggestion: Remove one dependency from each cycle to break it.[/]");
+    content.push_str(
+        "\n[dim]Suggestion: Remove one dependency from each cycle to break it (br dep cycles --fix).[/]",
+    );
 
     let panel = Panel::from_text(&content)
         .title(Text::new("Dependency Cycles"))
@@ -847,8 +1078,336 @@ fn render_cycles_rich(ctx: &OutputContext, cycles: &[Vec<String>], count: usize)
     ctx.render(&panel);
 }
 
+/// Parse a dependency type, rejecting unknown (custom) types.
+pub(crate) fn parse_dep_type(dep_type_str: &str) -> Result<DependencyType> {
+    let dep_type: DependencyType = dep_type_str.parse().map_err(|_| BeadsError::Validation {
+        field: "type".to_string(),
+        reason: format!("Invalid dependency type: {dep_type_str}"),
+    })?;
+
+    // Disallow accidental custom types from typos
+    if let DependencyType::Custom(_) = dep_type {
+        // We enforce standard types for reliability unless it looks like a deliberate custom type
+        // For now, let's strictly enforce known types to prevent typos like "parent_child"
+        // which would otherwise be accepted as a non-blocking custom type.
+        return Err(BeadsError::Validation {
+            field: "type".to_string(),
+            reason: format!(
+                "Unknown dependency type: '{dep_type_str}'. \
+                 Allowed types: blocks, parent-child, conditional-blocks, waits-for, \
+                 related, discovered-from, replies-to, relates-to, duplicates, \
+                 supersedes, caused-by"
+            ),
+        });
+    }
+    Ok(dep_type)
+}
+
+/// One edge read from a `dep apply` file, before ID resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct EdgeSpec {
+    remove: bool,
+    issue: String,
+    depends_on: String,
+    dep_type: String,
+}
+
+/// JSON form of an edge in a `dep apply` file.
+#[derive(Deserialize)]
+struct JsonEdge {
+    #[serde(alias = "issue")]
+    issue_id: String,
+    #[serde(alias = "depends_on")]
+    depends_on_id: String,
+    #[serde(rename = "type", alias = "dep_type", default)]
+    dep_type: Option<String>,
+    /// `add` (default) or `remove`
+    #[serde(default)]
+    action: Option<String>,
+}
+
+/// Per-edge result of `dep apply`.
+#[derive(Serialize)]
+struct DepApplyItem {
+    line: usize,
+    action: &'static str,
+    issue_id: String,
+    depends_on_id: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    dep_type: Option<String>,
+    /// added, exists, removed, not_found or invalid
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// JSON output for dep apply
+#[derive(Serialize)]
+struct DepApplyResult {
+    applied: bool,
+    added: usize,
+    removed: usize,
+    unchanged: usize,
+    invalid: usize,
+    results: Vec<DepApplyItem>,
+}
+
+/// Parse an edge list: either JSON (an array of edges, or one edge object
+/// per line) or text lines of `<issue> <type> <depends-on>`, where `-` in
+/// front removes the edge and `#` starts a comment. The type may be left out
+/// (`blocks`). Lines that cannot be parsed are returned as errors.
+fn parse_edge_list(content: &str) -> Vec<(usize, std::result::Result<EdgeSpec, String>)> {
+    let trimmed = content.trim_start();
+    if trimmed.starts_with('[') {
+        return match serde_json::from_str::<Vec<JsonEdge>>(trimmed) {
+            Ok(edges) => edges
+                .into_iter()
+                .enumerate()
+                .map(|(i, edge)| (i + 1, edge_from_json(edge)))
+                .collect(),
+            Err(e) => vec![(0, Err(format!("invalid JSON edge list: {e}")))],
+        };
+    }
+
+    let mut specs = Vec::new();
+    for (i, raw) in content.lines().enumerate() {
+        let line = i + 1;
+        let text = raw.split('#').next().unwrap_or("").trim();
+        if text.is_empty() {
+            continue;
+        }
+        if text.starts_with('{') {
+            let spec = serde_json::from_str::<JsonEdge>(text)
+                .map_err(|e| format!("invalid JSON edge: {e}"))
+                .and_then(edge_from_json);
+            specs.push((line, spec));
+            continue;
+        }
+
+        let mut tokens: Vec<&str> = text.split_whitespace().collect();
+        let remove = match tokens.first() {
+            Some(&("-" | "remove")) => {
+                tokens.remove(0);
+                true
+            }
+            Some(&("+" | "add")) => {
+                tokens.remove(0);
+                false
+            }
+            _ => false,
+        };
+        let spec = match tokens.as_slice() {
+            [issue, dep_type, depends_on] => Ok(EdgeSpec {
+                remove,
+                issue: (*issue).to_string(),
+                depends_on: (*depends_on).to_string(),
+                dep_type: (*dep_type).to_string(),
+            }),
+            [issue, depends_on] => Ok(EdgeSpec {
+                remove,
+                issue: (*issue).to_string(),
+                depends_on: (*depends_on).to_string(),
+                dep_type: DependencyType::Blocks.as_str().to_string(),
+            }),
+            _ => Err(format!(
+                "expected `<issue> <type> <depends-on>`, got `{text}`"
+            )),
+        };
+        specs.push((line, spec));
+    }
+    specs
+}
+
+fn edge_from_json(edge: JsonEdge) -> std::result::Result<EdgeSpec, String> {
+    let remove = match edge.action.as_deref() {
+        None | Some("add") => false,
+        Some("remove") => true,
+        Some(other) => return Err(format!("unknown action '{other}' (use add or remove)")),
+    };
+    Ok(EdgeSpec {
+        remove,
+        issue: edge.issue_id,
+        depends_on: edge.depends_on_id,
+        dep_type: edge
+            .dep_type
+            .unwrap_or_else(|| DependencyType::Blocks.as_str().to_string()),
+    })
+}
+
+/// Resolve and validate one edge the way `dep add`/`dep remove` would.
+fn validate_edge(
+    spec: &EdgeSpec,
+    storage: &dyn Storage,
+    resolver: &IdResolver,
+    all_ids: &[String],
+) -> Result<DependencyChange> {
+    let issue_id = resolve_issue_id(storage, resolver, all_ids, &spec.issue)?;
+    let depends_on_id = if spec.depends_on.starts_with("external:") {
+        spec.depends_on.clone()
+    } else {
+        resolve_issue_id(storage, resolver, all_ids, &spec.depends_on)?
+    };
+    if spec.remove {
+        return Ok(DependencyChange::Remove {
+            issue_id,
+            depends_on_id,
+        });
+    }
+
+    let dep_type = parse_dep_type(&spec.dep_type)?;
+    if issue_id == depends_on_id {
+        return Err(BeadsError::SelfDependency { id: issue_id });
+    }
+    Ok(DependencyChange::Add {
+        issue_id,
+        depends_on_id,
+        dep_type: dep_type.as_str().to_string(),
+    })
+}
+
+fn dep_apply(
+    args: &DepApplyArgs,
+    storage: &mut dyn Storage,
+    resolver: &IdResolver,
+    all_ids: &[String],
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let content = if args.file.as_os_str() == "-" {
+        let mut buffer = String::new();
+        io::stdin().read_to_string(&mut buffer)?;
+        buffer
+    } else {
+        fs::read_to_string(&args.file)?
+    };
+
+    let mut results = Vec::new();
+    let mut changes = Vec::new();
+    for (line, spec) in parse_edge_list(&content) {
+        let spec = match spec {
+            Ok(spec) => spec,
+            Err(error) => {
+                results.push(DepApplyItem {
+                    line,
+                    action: "add",
+                    issue_id: String::new(),
+                    depends_on_id: String::new(),
+                    dep_type: None,
+                    status: "invalid",
+                    error: Some(error),
+                });
+                continue;
+            }
+        };
+        let action = if spec.remove { "remove" } else { "add" };
+        match validate_edge(&spec, storage, resolver, all_ids) {
+            Ok(change) => {
+                let (issue_id, depends_on_id, dep_type) = match &change {
+                    DependencyChange::Add {
+                        issue_id,
+                        depends_on_id,
+                        dep_type,
+                    } => (issue_id, depends_on_id, Some(dep_type.clone())),
+                    DependencyChange::Remove {
+                        issue_id,
+                        depends_on_id,
+                    } => (issue_id, depends_on_id, None),
+                };
+                results.push(DepApplyItem {
+                    line,
+                    action,
+                    issue_id: issue_id.clone(),
+                    depends_on_id: depends_on_id.clone(),
+                    dep_type,
+                    status: "pending",
+                    error: None,
+                });
+                changes.push(change);
+            }
+            Err(e) => results.push(DepApplyItem {
+                line,
+                action,
+                issue_id: spec.issue,
+                depends_on_id: spec.depends_on,
+                dep_type: (!spec.remove).then_some(spec.dep_type),
+                status: "invalid",
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+
+    let invalid = results.iter().filter(|r| r.status == "invalid").count();
+    let applied = invalid == 0 && !changes.is_empty();
+    if applied {
+        let outcomes = storage.apply_dependency_changes(&changes, actor)?;
+        for (item, changed) in results.iter_mut().zip(outcomes) {
+            item.status = match (item.action, changed) {
+                ("add", true) => "added",
+                ("add", false) => "exists",
+                (_, true) => "removed",
+                (_, false) => "not_found",
+            };
+        }
+    }
+
+    let count = |status: &str| results.iter().filter(|r| r.status == status).count();
+    let result = DepApplyResult {
+        applied,
+        added: count("added"),
+        removed: count("removed"),
+        unchanged: count("exists") + count("not_found"),
+        invalid,
+        results,
+    };
+
+    print_apply_result(&result, ctx);
+
+    if result.invalid > 0 {
+        return Err(BeadsError::validation(
+            "file",
+            format!("{} invalid edge(s); no changes applied", result.invalid),
+        ));
+    }
+    Ok(())
+}
+
+fn print_apply_result(result: &DepApplyResult, ctx: &OutputContext) {
+    if ctx.is_json() || ctx.is_toon() {
+        if ctx.is_toon() {
+            ctx.toon(result);
+        } else {
+            ctx.json_pretty(result);
+        }
+    } else {
+        for item in &result.results {
+            let sign = if item.action == "add" { '+' } else { '-' };
+            let edge = match &item.dep_type {
+                Some(dep_type) => {
+                    format!("{} -> {} ({dep_type})", item.issue_id, item.depends_on_id)
+                }
+                None => format!("{} -> {}", item.issue_id, item.depends_on_id),
+            };
+            match &item.error {
+                Some(error) => ctx.print(&format!("  line {}: {sign} {edge}: {error}", item.line)),
+                None => ctx.print(&format!(
+                    "  line {}: {sign} {edge}: {}",
+                    item.line, item.status
+                )),
+            }
+        }
+        if result.applied {
+            ctx.success(&format!(
+                "Applied dependency changes: {} added, {} removed, {} unchanged",
+                result.added, result.removed, result.unchanged
+            ));
+        } else if result.invalid == 0 {
+            ctx.info("No dependency changes found");
+        }
+    }
+}
+
 fn resolve_issue_id(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     input: &str,
@@ -867,8 +1426,9 @@ mod tests {
     use super::*;
     use crate::logging::init_test_logging;
     use crate::model::{Issue, IssueType, Priority, Status};
+    use crate::storage::{CycleEdge, SqliteStorage};
     use chrono::{TimeZone, Utc};
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
     use tracing::info;
 
     fn make_test_issue(id: &str, title: &str) -> Issue {
@@ -912,6 +1472,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -1153,10 +1717,12 @@ mod tests {
             depends_on_id: "bd-002".to_string(),
             dep_type: "blocks".to_string(),
             action: "added".to_string(),
+            close_reason: None,
         };
 
         let json = serde_json::to_string(&result).unwrap();
         assert!(json.contains("\"status\":\"ok\""));
+        assert!(!json.contains("close_reason"));
         assert!(json.contains("\"issue_id\":\"bd-001\""));
         assert!(json.contains("\"type\":\"blocks\"")); // Note: renamed field
         info!("test_dep_action_result_json: assertions passed");
@@ -1173,6 +1739,7 @@ mod tests {
             title: "Test Issue".to_string(),
             status: "open".to_string(),
             priority: 2,
+            condition: None,
         };
 
         let json = serde_json::to_string(&item).unwrap();
@@ -1186,23 +1753,76 @@ mod tests {
         init_test_logging();
         info!("test_cycles_result_json: starting");
         let result = CyclesResult {
-            cycles: vec![
-                vec!["bd-001".to_string(), "bd-002".to_string()],
-                vec![
-                    "bd-003".to_string(),
-                    "bd-004".to_string(),
-                    "bd-005".to_string(),
-                ],
-            ],
-            count: 2,
+            cycles: vec![two_issue_cycle()],
+            count: 1,
         };
 
         let json = serde_json::to_string(&result).unwrap();
-        assert!(json.contains("\"count\":2"));
-        assert!(json.contains("bd-001"));
+        assert!(json.contains("\"count\":1"));
+        assert!(json.contains("\"members\":[\"bd-001\",\"bd-002\"]"));
+        assert!(json.contains("\"type\":\"related\""));
         info!("test_cycles_result_json: assertions passed");
     }
 
+    fn two_issue_cycle() -> DependencyCycle {
+        let edge = |from: &str, to: &str, dep_type: &str| CycleEdge {
+            issue_id: from.to_string(),
+            depends_on_id: to.to_string(),
+            dep_type: dep_type.to_string(),
+        };
+        DependencyCycle {
+            members: vec!["bd-001".to_string(), "bd-002".to_string()],
+            path: vec![
+                "bd-001".to_string(),
+                "bd-002".to_string(),
+                "bd-001".to_string(),
+            ],
+            edges: vec![
+                edge("bd-001", "bd-002", "blocks"),
+                edge("bd-002", "bd-001", "related"),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_parse_edge_list_text_and_json() {
+        let specs = parse_edge_list(
+            "# plan\n\
+             bd-a1 blocks bd-b2\n\
+             bd-a1 bd-c3  # defaults to blocks\n\
+             - bd-d4 related bd-e5\n\
+             {\"issue_id\": \"bd-f6\", \"depends_on_id\": \"bd-g7\", \"type\": \"waits-for\"}\n\
+             bd-h8\n",
+        );
+        let lines: Vec<usize> = specs.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![2, 3, 4, 5, 6]);
+        let spec = |remove: bool, issue: &str, dep_type: &str, depends_on: &str| EdgeSpec {
+            remove,
+            issue: issue.to_string(),
+            depends_on: depends_on.to_string(),
+            dep_type: dep_type.to_string(),
+        };
+        assert_eq!(specs[0].1, Ok(spec(false, "bd-a1", "blocks", "bd-b2")));
+        assert_eq!(specs[1].1, Ok(spec(false, "bd-a1", "blocks", "bd-c3")));
+        assert_eq!(specs[2].1, Ok(spec(true, "bd-d4", "related", "bd-e5")));
+        assert_eq!(specs[3].1, Ok(spec(false, "bd-f6", "waits-for", "bd-g7")));
+        assert!(specs[4].1.is_err());
+
+        let specs =
+            parse_edge_list(r#"[{"issue": "bd-a1", "depends_on": "bd-b2", "action": "remove"}]"#);
+        assert_eq!(specs, vec![(1, Ok(spec(true, "bd-a1", "blocks", "bd-b2")))]);
+    }
+
+    #[test]
+    fn test_format_cycle_path_includes_edge_types() {
+        let cycle = two_issue_cycle();
+        assert_eq!(
+            format_cycle_path(&cycle),
+            "bd-001 -[blocks]-> bd-002 -[related]-> bd-001"
+        );
+        assert!(!has_other_members(&cycle));
+    }
+
     #[test]
     fn test_external_dependency_prefix_check() {
         init_test_logging();
@@ -1236,6 +1856,7 @@ mod tests {
                 title: String::new(),
                 status: "open".to_string(),
                 priority: 2,
+                condition: None,
             },
             DepListItem {
                 issue_id: "bd-002".to_string(),
@@ -1244,6 +1865,7 @@ mod tests {
                 title: String::new(),
                 status: "open".to_string(),
                 priority: 2,
+                condition: None,
             },
         ];
 
@@ -1271,6 +1893,7 @@ mod tests {
             title: "Already set".to_string(),
             status: "open".to_string(),
             priority: 2,
+            condition: None,
         }];
 
         let mut statuses = HashMap::new();
@@ -1294,6 +1917,7 @@ mod tests {
             title: String::new(),
             status: "open".to_string(),
             priority: 2,
+            condition: None,
         }];
 
         let mut statuses = HashMap::new();
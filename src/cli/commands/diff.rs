@@ -0,0 +1,149 @@
+//! Diff command implementation.
+//!
+//! `br diff [A] [B]` compares two sets of issues by ID and reports which were
+//! created, closed, modified or deleted, with the changed fields of each.
+//! Either side defaults to something useful: `A` to the last export (the
+//! configured JSONL) and `B` to what the working database would export now,
+//! so a bare `br diff` shows what the next flush will write.
+
+use crate::cli::DiffArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+use crate::output::OutputContext;
+use crate::sync::diff::{FieldChange, IssueDelta, SnapshotDiff, diff_snapshots};
+use crate::sync::{issues_for_export, read_issues_from_jsonl, shard};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+/// Longest value shown in text output before it is truncated.
+const MAX_VALUE_CHARS: usize = 60;
+
+/// JSON output for the diff command.
+#[derive(Serialize)]
+struct DiffOutput<'a> {
+    from: String,
+    to: String,
+    identical: bool,
+    #[serde(flatten)]
+    diff: &'a SnapshotDiff,
+}
+
+/// Execute the diff command.
+///
+/// # Errors
+///
+/// Returns an error if a JSONL file is missing or invalid, or the database
+/// cannot be read.
+pub fn execute(
+    args: &DiffArgs,
+    json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let jsonl_path = config::resolve_paths(&beads_dir, cli.db.as_ref())?.jsonl_path;
+
+    let from_path = args.from.as_deref().unwrap_or(jsonl_path.as_path());
+    let before = read_side(from_path)?;
+    let (to_label, after) = if let Some(to) = &args.to {
+        (to.display().to_string(), read_side(to)?)
+    } else {
+        let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+        (
+            "database".to_string(),
+            issues_for_export(storage_ctx.storage.sqlite("diff")?)?,
+        )
+    };
+    let diff = diff_snapshots(&before, &after);
+    let from_label = from_path.display().to_string();
+
+    if json {
+        let output = DiffOutput {
+            from: from_label,
+            to: to_label,
+            identical: diff.is_empty(),
+            diff: &diff,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+
+    println!("Diff: {from_label} → {to_label}");
+    if diff.is_empty() {
+        println!("No differences ({} issues unchanged).", diff.unchanged);
+        return Ok(());
+    }
+    let sections = [
+        ("Created", "+", &diff.created),
+        ("Closed", "x", &diff.closed),
+        ("Modified", "~", &diff.modified),
+        ("Deleted", "-", &diff.deleted),
+    ];
+    for (label, marker, deltas) in sections {
+        if deltas.is_empty() {
+            continue;
+        }
+        println!();
+        println!("{label} ({}):", deltas.len());
+        for delta in deltas {
+            print_delta(marker, delta);
+        }
+    }
+    println!();
+    println!(
+        "{} created, {} closed, {} modified, {} deleted, {} unchanged",
+        diff.created.len(),
+        diff.closed.len(),
+        diff.modified.len(),
+        diff.deleted.len(),
+        diff.unchanged
+    );
+    Ok(())
+}
+
+/// Read one side of the diff from a JSONL file (or its shards).
+fn read_side(path: &Path) -> Result<Vec<Issue>> {
+    if !shard::jsonl_exists(path) {
+        return Err(BeadsError::Config(format!(
+            "JSONL file not found: {}",
+            path.display()
+        )));
+    }
+    read_issues_from_jsonl(path)
+}
+
+fn print_delta(marker: &str, delta: &IssueDelta) {
+    println!("  {marker} {}  {}", delta.id, delta.title);
+    for change in &delta.changes {
+        print_change(change);
+    }
+}
+
+fn print_change(change: &FieldChange) {
+    println!(
+        "      {}: {} → {}",
+        change.field,
+        format_value(&change.old),
+        format_value(&change.new)
+    );
+}
+
+/// Compact one-line rendering of a field value.
+fn format_value(value: &Value) -> String {
+    let text = match value {
+        Value::Null => "(none)".to_string(),
+        Value::String(s) => format!("{s:?}"),
+        other => other.to_string(),
+    };
+    if text.chars().count() > MAX_VALUE_CHARS {
+        let truncated: String = text.chars().take(MAX_VALUE_CHARS - 1).collect();
+        format!("{truncated}…")
+    } else {
+        text
+    }
+}
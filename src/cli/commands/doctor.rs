@@ -4,13 +4,18 @@
 
 use crate::config;
 use crate::error::Result;
+use crate::model::{IssueType, Priority, Status};
 use crate::output::OutputContext;
+use crate::sync::shard::{self, ShardProblem};
 use crate::sync::{
-    PathValidation, scan_conflict_markers, validate_no_git_path, validate_sync_path,
+    PathValidation, read_issues_from_jsonl, scan_conflict_markers, validate_no_git_path,
+    validate_sync_path,
 };
+use crate::util::{content_hash, content_hash_from_parts};
 use rich_rust::prelude::*;
 use rusqlite::{Connection, OpenFlags};
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
@@ -397,6 +402,55 @@ fn check_jsonl(path: &Path, checks: &mut Vec<CheckResult>) -> Result<usize> {
     Ok(total)
 }
 
+/// Check that a sharded export is consistent: no leftover unsharded file,
+/// no issue in two files, and every issue in the shard for its status.
+fn check_jsonl_shards(path: &Path, checks: &mut Vec<CheckResult>) {
+    let check_name = "jsonl.shards";
+    match shard::check_consistency(path) {
+        Ok(problems) if problems.is_empty() => {
+            let files = shard::jsonl_sources(path);
+            let message = if files.len() > 1 {
+                format!("{} shard files consistent", files.len())
+            } else {
+                "Single-file JSONL layout".to_string()
+            };
+            push_check(checks, check_name, CheckStatus::Ok, Some(message), None);
+        }
+        Ok(problems) => {
+            let details: Vec<serde_json::Value> = problems
+                .iter()
+                .map(|problem| {
+                    let kind = match problem {
+                        ShardProblem::MixedLayout { .. } => "mixed_layout",
+                        ShardProblem::DuplicateId { .. } => "duplicate_id",
+                        ShardProblem::Misplaced { .. } => "misplaced",
+                    };
+                    serde_json::json!({ "kind": kind, "message": problem.to_string() })
+                })
+                .collect();
+            push_check(
+                checks,
+                check_name,
+                CheckStatus::Error,
+                Some(format!("Found {} JSONL shard problem(s)", problems.len())),
+                Some(serde_json::json!({
+                    "problems": details,
+                    "remediation": "Run 'br sync --flush-only --force' to rewrite the JSONL layout"
+                })),
+            );
+        }
+        Err(e) => {
+            push_check(
+                checks,
+                check_name,
+                CheckStatus::Warn,
+                Some(format!("Could not check JSONL shards: {e}")),
+                Some(serde_json::json!({ "path": path.display().to_string() })),
+            );
+        }
+    }
+}
+
 fn check_db_count(
     conn: &Connection,
     jsonl_count: Option<usize>,
@@ -444,6 +498,401 @@ fn check_db_count(
     Ok(())
 }
 
+// ============================================================================
+// DATA CONSISTENCY CHECKS
+// ============================================================================
+
+/// Most per-issue entries listed in a check's JSON details.
+const MAX_DETAIL_ROWS: usize = 100;
+
+/// Relation rows whose issue is missing, as `(row details, owned by issue)`.
+///
+/// Owned rows (a dependency's `issue_id`, labels, comments) should have been
+/// removed with their issue. Dependency targets have no foreign key, so a
+/// missing target is reported as a warning unless it is an `external:` ref.
+fn dangling_relation_rows(conn: &Connection) -> Result<Vec<(serde_json::Value, bool)>> {
+    // (query, table, second column's name, owned by issue_id)
+    let queries = [
+        (
+            "SELECT issue_id, depends_on_id FROM dependencies
+             WHERE issue_id NOT IN (SELECT id FROM issues) ORDER BY issue_id, depends_on_id",
+            "dependencies",
+            "depends_on_id",
+            true,
+        ),
+        (
+            "SELECT issue_id, depends_on_id FROM dependencies
+             WHERE depends_on_id NOT IN (SELECT id FROM issues)
+               AND depends_on_id NOT LIKE 'external:%'
+             ORDER BY issue_id, depends_on_id",
+            "dependencies",
+            "depends_on_id",
+            false,
+        ),
+        (
+            "SELECT issue_id, label FROM labels
+             WHERE issue_id NOT IN (SELECT id FROM issues) ORDER BY issue_id, label",
+            "labels",
+            "label",
+            true,
+        ),
+        (
+            "SELECT issue_id, CAST(id AS TEXT) FROM comments
+             WHERE issue_id NOT IN (SELECT id FROM issues) ORDER BY issue_id, id",
+            "comments",
+            "comment_id",
+            true,
+        ),
+    ];
+
+    let mut rows = Vec::new();
+    for (sql, table, other_key, owned) in queries {
+        let mut stmt = conn.prepare(sql)?;
+        let found = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+        for row in found {
+            let (issue_id, other) = row?;
+            let missing = if owned { &issue_id } else { &other };
+            rows.push((
+                serde_json::json!({
+                    "table": table,
+                    "missing": missing,
+                    "issue_id": issue_id,
+                    other_key: other,
+                }),
+                owned,
+            ));
+        }
+    }
+    Ok(rows)
+}
+
+/// Check that every dependency, label and comment row references an issue.
+fn check_dangling_relations(conn: &Connection, checks: &mut Vec<CheckResult>) {
+    let check_name = "db.dangling_relations";
+    let rows = match dangling_relation_rows(conn) {
+        Ok(rows) => rows,
+        Err(err) => {
+            push_check(
+                checks,
+                check_name,
+                CheckStatus::Warn,
+                Some(format!("Could not check relation rows: {err}")),
+                None,
+            );
+            return;
+        }
+    };
+    if rows.is_empty() {
+        push_check(checks, check_name, CheckStatus::Ok, None, None);
+        return;
+    }
+
+    let status = if rows.iter().any(|(_, owned)| *owned) {
+        CheckStatus::Error
+    } else {
+        CheckStatus::Warn
+    };
+    let total = rows.len();
+    let listed: Vec<serde_json::Value> = rows
+        .into_iter()
+        .take(MAX_DETAIL_ROWS)
+        .map(|(row, _)| row)
+        .collect();
+    push_check(
+        checks,
+        check_name,
+        status,
+        Some(format!("{total} relation row(s) reference a missing issue")),
+        Some(serde_json::json!({
+            "count": total,
+            "rows": listed,
+            "truncated": total > MAX_DETAIL_ROWS,
+        })),
+    );
+}
+
+/// Issues whose cached dependency counts differ from the dependencies table.
+fn stale_dependency_count_rows(conn: &Connection) -> Result<Vec<serde_json::Value>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, dependency_count, dependent_count, actual_dependencies, actual_dependents
+         FROM (
+             SELECT id, dependency_count, dependent_count,
+                    (SELECT COUNT(*) FROM dependencies d WHERE d.issue_id = issues.id)
+                        AS actual_dependencies,
+                    (SELECT COUNT(*) FROM dependencies d WHERE d.depends_on_id = issues.id)
+                        AS actual_dependents
+             FROM issues
+         )
+         WHERE dependency_count != actual_dependencies OR dependent_count != actual_dependents
+         ORDER BY id",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok(serde_json::json!({
+            "id": row.get::<_, String>(0)?,
+            "dependency_count": row.get::<_, i64>(1)?,
+            "dependent_count": row.get::<_, i64>(2)?,
+            "actual_dependency_count": row.get::<_, i64>(3)?,
+            "actual_dependent_count": row.get::<_, i64>(4)?,
+        }))
+    })?;
+    let mut stale = Vec::new();
+    for row in rows {
+        stale.push(row?);
+    }
+    Ok(stale)
+}
+
+/// Check that the trigger-maintained dependency counts on `issues` match the
+/// dependencies table.
+fn check_dependency_counts(conn: &Connection, checks: &mut Vec<CheckResult>) {
+    let check_name = "db.dependency_counts";
+    let has_columns = collect_table_columns(conn, "issues")
+        .map(|columns| columns.iter().any(|column| column == "dependency_count"));
+    if matches!(has_columns, Ok(false)) {
+        push_check(
+            checks,
+            check_name,
+            CheckStatus::Warn,
+            Some("Dependency count columns are missing (run `br migrate`)".to_string()),
+            None,
+        );
+        return;
+    }
+    let rows = match has_columns.and_then(|_| stale_dependency_count_rows(conn)) {
+        Ok(rows) => rows,
+        Err(err) => {
+            push_check(
+                checks,
+                check_name,
+                CheckStatus::Warn,
+                Some(format!("Could not check dependency counts: {err}")),
+                None,
+            );
+            return;
+        }
+    };
+    if rows.is_empty() {
+        push_check(checks, check_name, CheckStatus::Ok, None, None);
+        return;
+    }
+
+    let total = rows.len();
+    let listed: Vec<serde_json::Value> = rows.into_iter().take(MAX_DETAIL_ROWS).collect();
+    push_check(
+        checks,
+        check_name,
+        CheckStatus::Warn,
+        Some(format!(
+            "{total} issue(s) have stale dependency counts (run `br migrate --rebuild-counts`)"
+        )),
+        Some(serde_json::json!({
+            "count": total,
+            "issues": listed,
+            "truncated": total > MAX_DETAIL_ROWS,
+        })),
+    );
+}
+
+/// Stored and recomputed content hash of one database issue.
+struct DbIssueHash {
+    id: String,
+    stored: Option<String>,
+    computed: String,
+    ephemeral: bool,
+}
+
+fn db_issue_hashes(conn: &Connection) -> Result<Vec<DbIssueHash>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
+                status, priority, issue_type, assignee, owner, created_by,
+                external_ref, source_system, pinned, is_template, ephemeral
+         FROM issues ORDER BY id",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        let status: Option<String> = row.get(7)?;
+        let status = status.map_or_else(Status::default, |value| {
+            value.parse().unwrap_or_else(|_| Status::Custom(value))
+        });
+        let issue_type: Option<String> = row.get(9)?;
+        let issue_type = issue_type
+            .and_then(|value| value.parse::<IssueType>().ok())
+            .unwrap_or_default();
+        let priority = Priority(row.get::<_, Option<i32>>(8)?.unwrap_or(2));
+        let computed = content_hash_from_parts(
+            &row.get::<_, String>(2)?,
+            row.get::<_, Option<String>>(3)?.as_deref(),
+            row.get::<_, Option<String>>(4)?.as_deref(),
+            row.get::<_, Option<String>>(5)?.as_deref(),
+            row.get::<_, Option<String>>(6)?.as_deref(),
+            &status,
+            &priority,
+            &issue_type,
+            row.get::<_, Option<String>>(10)?.as_deref(),
+            row.get::<_, Option<String>>(11)?.as_deref(),
+            row.get::<_, Option<String>>(12)?.as_deref(),
+            row.get::<_, Option<String>>(13)?.as_deref(),
+            row.get::<_, Option<String>>(14)?.as_deref(),
+            row.get::<_, Option<i64>>(15)?.unwrap_or(0) != 0,
+            row.get::<_, Option<i64>>(16)?.unwrap_or(0) != 0,
+        );
+        let id: String = row.get(0)?;
+        Ok(DbIssueHash {
+            ephemeral: row.get::<_, Option<i64>>(17)?.unwrap_or(0) != 0 || id.contains("-wisp-"),
+            id,
+            stored: row.get(1)?,
+            computed,
+        })
+    })?;
+    Ok(rows.collect::<std::result::Result<_, _>>()?)
+}
+
+/// Check that every issue's stored `content_hash` matches its fields.
+///
+/// A stale hash breaks content-hash matching on import, so a drifted issue
+/// can be duplicated instead of updated.
+fn check_content_hashes(hashes: &[DbIssueHash], checks: &mut Vec<CheckResult>) {
+    let check_name = "db.content_hash";
+    let drifted: Vec<&DbIssueHash> = hashes
+        .iter()
+        .filter(|issue| issue.stored.as_deref() != Some(issue.computed.as_str()))
+        .collect();
+    if drifted.is_empty() {
+        push_check(
+            checks,
+            check_name,
+            CheckStatus::Ok,
+            Some(format!("{} issue hashes verified", hashes.len())),
+            None,
+        );
+        return;
+    }
+
+    let listed: Vec<serde_json::Value> = drifted
+        .iter()
+        .take(MAX_DETAIL_ROWS)
+        .map(|issue| {
+            serde_json::json!({
+                "id": issue.id,
+                "stored": issue.stored,
+                "computed": issue.computed,
+            })
+        })
+        .collect();
+    push_check(
+        checks,
+        check_name,
+        CheckStatus::Warn,
+        Some(format!(
+            "{} issue(s) have a stale content_hash",
+            drifted.len()
+        )),
+        Some(serde_json::json!({
+            "count": drifted.len(),
+            "issues": listed,
+            "truncated": drifted.len() > MAX_DETAIL_ROWS,
+        })),
+    );
+}
+
+/// Compare per-issue content hashes between the JSONL and the database.
+///
+/// Issues with unexported local changes (`dirty_issues`) are expected to
+/// differ and are only counted.
+fn check_jsonl_issue_hashes(
+    conn: &Connection,
+    hashes: &[DbIssueHash],
+    jsonl_path: &Path,
+    checks: &mut Vec<CheckResult>,
+) -> Result<()> {
+    let check_name = "sync.issue_hashes";
+    let jsonl_issues = match read_issues_from_jsonl(jsonl_path) {
+        Ok(issues) => issues,
+        Err(err) => {
+            push_check(
+                checks,
+                check_name,
+                CheckStatus::Warn,
+                Some(format!("Could not read JSONL issues: {err}")),
+                Some(serde_json::json!({ "path": jsonl_path.display().to_string() })),
+            );
+            return Ok(());
+        }
+    };
+
+    let dirty: HashSet<String> = conn
+        .prepare("SELECT issue_id FROM dirty_issues")?
+        .query_map([], |row| row.get(0))?
+        .collect::<std::result::Result<_, _>>()?;
+
+    let mut jsonl: HashMap<String, String> = jsonl_issues
+        .iter()
+        .filter(|issue| !issue.ephemeral)
+        .map(|issue| (issue.id.clone(), content_hash(issue)))
+        .collect();
+
+    let mut differing = Vec::new();
+    let mut only_in_db = Vec::new();
+    let mut pending_export = 0usize;
+    let mut matching = 0usize;
+    for issue in hashes.iter().filter(|issue| !issue.ephemeral) {
+        let jsonl_hash = jsonl.remove(&issue.id);
+        if dirty.contains(&issue.id) {
+            pending_export += 1;
+            continue;
+        }
+        match jsonl_hash {
+            Some(hash) if hash == issue.computed => matching += 1,
+            Some(hash) => differing.push(serde_json::json!({
+                "id": issue.id,
+                "db": issue.computed,
+                "jsonl": hash,
+            })),
+            None => only_in_db.push(issue.id.clone()),
+        }
+    }
+    let mut only_in_jsonl: Vec<String> = jsonl.into_keys().collect();
+    only_in_jsonl.sort();
+
+    let problems = differing.len() + only_in_db.len() + only_in_jsonl.len();
+    let status = if problems == 0 {
+        CheckStatus::Ok
+    } else {
+        CheckStatus::Warn
+    };
+    let message = if problems == 0 {
+        format!("{matching} issues match the JSONL")
+    } else {
+        format!(
+            "{} differ, {} only in DB, {} only in JSONL",
+            differing.len(),
+            only_in_db.len(),
+            only_in_jsonl.len()
+        )
+    };
+    let truncated = differing.len() > MAX_DETAIL_ROWS
+        || only_in_db.len() > MAX_DETAIL_ROWS
+        || only_in_jsonl.len() > MAX_DETAIL_ROWS;
+    differing.truncate(MAX_DETAIL_ROWS);
+    only_in_db.truncate(MAX_DETAIL_ROWS);
+    only_in_jsonl.truncate(MAX_DETAIL_ROWS);
+    push_check(
+        checks,
+        check_name,
+        status,
+        Some(message),
+        Some(serde_json::json!({
+            "differing": differing,
+            "only_in_db": only_in_db,
+            "only_in_jsonl": only_in_jsonl,
+            "pending_export": pending_export,
+            "truncated": truncated,
+        })),
+    );
+    Ok(())
+}
+
 // ============================================================================
 // SYNC SAFETY CHECKS (beads_rust-0v1.2.6)
 // ============================================================================
@@ -595,7 +1044,7 @@ fn check_sync_jsonl_path(jsonl_path: &Path, beads_dir: &Path, checks: &mut Vec<C
 fn check_sync_conflict_markers(jsonl_path: &Path, checks: &mut Vec<CheckResult>) {
     let check_name = "sync_conflict_markers";
 
-    if !jsonl_path.exists() {
+    if !shard::jsonl_exists(jsonl_path) {
         return;
     }
 
@@ -616,6 +1065,7 @@ fn check_sync_conflict_markers(jsonl_path: &Path, checks: &mut Vec<CheckResult>)
                     .take(5)
                     .map(|m| {
                         serde_json::json!({
+                            "path": m.path.display().to_string(),
                             "line": m.line,
                             "type": format!("{:?}", m.marker_type),
                             "branch": m.branch.as_deref().unwrap_or("")
@@ -710,8 +1160,11 @@ fn check_sync_metadata(
 
     // Determine staleness
     let (jsonl_newer, db_newer) = if let Some(p) = jsonl_path {
-        if p.exists() {
-            let jsonl_mtime = fs::metadata(p).and_then(|m| m.modified()).ok();
+        if shard::jsonl_exists(p) {
+            let jsonl_mtime = shard::jsonl_sources(p)
+                .iter()
+                .filter_map(|source| fs::metadata(source).and_then(|m| m.modified()).ok())
+                .max();
 
             // JSONL is newer if it was modified after last import
             let j_newer = last_import.as_ref().is_none_or(|import_time| {
@@ -830,7 +1283,7 @@ pub fn execute(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
 
     check_merge_artifacts(&beads_dir, &mut checks)?;
 
-    let jsonl_path = if paths.jsonl_path.exists() {
+    let jsonl_path = if shard::jsonl_exists(&paths.jsonl_path) {
         Some(paths.jsonl_path.clone())
     } else {
         discover_jsonl(&beads_dir)
@@ -843,19 +1296,25 @@ pub fn execute(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
         // Check for merge conflict markers
         check_sync_conflict_markers(path, &mut checks);
 
-        match check_jsonl(path, &mut checks) {
-            Ok(count) => Some(count),
-            Err(err) => {
-                push_check(
-                    &mut checks,
-                    "jsonl.parse",
-                    CheckStatus::Error,
-                    Some(format!("Failed to read JSONL: {err}")),
-                    Some(serde_json::json!({ "path": path.display().to_string() })),
-                );
-                None
+        // Sharded exports are parsed file by file
+        let mut total = Some(0);
+        for source in shard::jsonl_sources(path) {
+            match check_jsonl(&source, &mut checks) {
+                Ok(count) => total = total.map(|total| total + count),
+                Err(err) => {
+                    push_check(
+                        &mut checks,
+                        "jsonl.parse",
+                        CheckStatus::Error,
+                        Some(format!("Failed to read JSONL: {err}")),
+                        Some(serde_json::json!({ "path": source.display().to_string() })),
+                    );
+                    total = None;
+                }
             }
         }
+        check_jsonl_shards(path, &mut checks);
+        total
     } else {
         push_check(
             &mut checks,
@@ -874,6 +1333,23 @@ pub fn execute(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
                 required_schema_checks(&conn, &mut checks)?;
                 check_integrity(&conn, &mut checks)?;
                 check_db_count(&conn, jsonl_count, &mut checks)?;
+                check_dangling_relations(&conn, &mut checks);
+                check_dependency_counts(&conn, &mut checks);
+                match db_issue_hashes(&conn) {
+                    Ok(hashes) => {
+                        check_content_hashes(&hashes, &mut checks);
+                        if let Some(path) = jsonl_path.as_ref() {
+                            check_jsonl_issue_hashes(&conn, &hashes, path, &mut checks)?;
+                        }
+                    }
+                    Err(err) => push_check(
+                        &mut checks,
+                        "db.content_hash",
+                        CheckStatus::Warn,
+                        Some(format!("Could not read issue hashes: {err}")),
+                        None,
+                    ),
+                }
 
                 // SYNC SAFETY CHECK: metadata consistency (beads_rust-0v1.2.6)
                 check_sync_metadata(&conn, Some(&paths.jsonl_path), &mut checks);
@@ -937,6 +1413,104 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_check_jsonl_shards_flags_misplaced_issue() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let base = temp.path().join("issues.jsonl");
+        fs::write(
+            shard::Shard::Open.path(&base),
+            "{\"id\":\"bd-1\",\"status\":\"closed\"}\n",
+        )
+        .unwrap();
+
+        let mut checks = Vec::new();
+        check_jsonl_shards(&base, &mut checks);
+        let check = find_check(&checks, "jsonl.shards").expect("check present");
+        assert!(matches!(check.status, CheckStatus::Error));
+
+        fs::write(
+            shard::Shard::Open.path(&base),
+            "{\"id\":\"bd-1\",\"status\":\"open\"}\n",
+        )
+        .unwrap();
+        let mut checks = Vec::new();
+        check_jsonl_shards(&base, &mut checks);
+        let check = find_check(&checks, "jsonl.shards").expect("check present");
+        assert!(matches!(check.status, CheckStatus::Ok));
+    }
+
+    #[test]
+    fn test_consistency_checks_report_per_issue_details() {
+        use crate::model::Issue;
+        use crate::storage::SqliteStorage;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = temp.path().join("beads.db");
+        let jsonl_path = temp.path().join("issues.jsonl");
+        let mut storage = SqliteStorage::open(&db_path).unwrap();
+        for (id, title) in [("bd-1", "One"), ("bd-2", "Two")] {
+            let issue = Issue {
+                id: id.to_string(),
+                title: title.to_string(),
+                ..Issue::default()
+            };
+            storage.create_issue(&issue, "test").unwrap();
+        }
+        drop(storage);
+
+        let mut exported = Issue {
+            id: "bd-1".to_string(),
+            title: "One (edited elsewhere)".to_string(),
+            ..Issue::default()
+        };
+        exported.content_hash = Some(content_hash(&exported));
+        fs::write(
+            &jsonl_path,
+            format!("{}\n", serde_json::to_string(&exported).unwrap()),
+        )
+        .unwrap();
+
+        let conn = Connection::open(&db_path).unwrap();
+        conn.execute_batch(
+            "PRAGMA foreign_keys = OFF;
+             DELETE FROM dirty_issues;
+             INSERT INTO labels (issue_id, label) VALUES ('bd-gone', 'stale');
+             UPDATE issues SET content_hash = 'bogus' WHERE id = 'bd-2';
+             UPDATE issues SET dependent_count = 3 WHERE id = 'bd-1';",
+        )
+        .unwrap();
+
+        let mut checks = Vec::new();
+        check_dangling_relations(&conn, &mut checks);
+        check_dependency_counts(&conn, &mut checks);
+        let hashes = db_issue_hashes(&conn).unwrap();
+        check_content_hashes(&hashes, &mut checks);
+        check_jsonl_issue_hashes(&conn, &hashes, &jsonl_path, &mut checks).unwrap();
+
+        let dangling = find_check(&checks, "db.dangling_relations").expect("check present");
+        assert!(matches!(dangling.status, CheckStatus::Error));
+        let rows = &dangling.details.as_ref().unwrap()["rows"];
+        assert_eq!(rows[0]["missing"], "bd-gone");
+        assert_eq!(rows[0]["label"], "stale");
+
+        let counts = find_check(&checks, "db.dependency_counts").expect("check present");
+        assert!(matches!(counts.status, CheckStatus::Warn));
+        let stale = &counts.details.as_ref().unwrap()["issues"];
+        assert_eq!(stale[0]["id"], "bd-1");
+        assert_eq!(stale[0]["dependent_count"], 3);
+        assert_eq!(stale[0]["actual_dependent_count"], 0);
+
+        let drift = find_check(&checks, "db.content_hash").expect("check present");
+        assert!(matches!(drift.status, CheckStatus::Warn));
+        assert_eq!(drift.details.as_ref().unwrap()["issues"][0]["id"], "bd-2");
+
+        let sync = find_check(&checks, "sync.issue_hashes").expect("check present");
+        assert!(matches!(sync.status, CheckStatus::Warn));
+        let details = sync.details.as_ref().unwrap();
+        assert_eq!(details["differing"][0]["id"], "bd-1");
+        assert_eq!(details["only_in_db"][0], "bd-2");
+    }
+
     #[test]
     fn test_required_schema_checks_missing_tables() {
         let conn = Connection::open_in_memory().unwrap();
@@ -0,0 +1,151 @@
+//! Duplicate and Supersede command implementations.
+//!
+//! `br duplicate <id> --of <other>` closes an issue as a duplicate of another
+//! and records a `duplicates` edge from it; `br supersede <old> --by <new>`
+//! closes the old issue and records a `supersedes` edge from the new one.
+//! Both links show up in `br show` on either side.
+
+use crate::cli::{DuplicateArgs, SupersedeArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{DependencyType, Status};
+use crate::output::OutputContext;
+use crate::storage::IssueUpdate;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use chrono::Utc;
+use serde::Serialize;
+
+/// JSON output for the duplicate and supersede commands.
+#[derive(Debug, Serialize)]
+struct ReplacedResult {
+    /// The issue that was closed
+    id: String,
+    /// The issue that replaces it
+    replaced_by: String,
+    dependency_type: String,
+    /// False if the issue was already closed
+    closed: bool,
+    close_reason: Option<String>,
+}
+
+/// Execute the duplicate command.
+///
+/// # Errors
+///
+/// Returns an error if an ID cannot be resolved, both IDs are the same
+/// issue, or database operations fail.
+pub fn execute_duplicate(
+    args: &DuplicateArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    replace_issue(&args.id, &args.of, &DependencyType::Duplicates, cli, ctx)
+}
+
+/// Execute the supersede command.
+///
+/// # Errors
+///
+/// Returns an error if an ID cannot be resolved, both IDs are the same
+/// issue, or database operations fail.
+pub fn execute_supersede(
+    args: &SupersedeArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    replace_issue(&args.id, &args.by, &DependencyType::Supersedes, cli, ctx)
+}
+
+/// Close `old_input` in favor of `new_input` and link the two.
+fn replace_issue(
+    old_input: &str,
+    new_input: &str,
+    dep_type: &DependencyType,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+    let storage = &mut *storage_ctx.storage;
+
+    let resolve = |input: &str| {
+        resolver
+            .resolve(
+                input,
+                |id| all_ids.binary_search_by(|p| p.as_str().cmp(id)).is_ok(),
+                |hash| find_matching_ids(&all_ids, hash),
+            )
+            .map(|resolved| resolved.id)
+    };
+    let old_id = resolve(old_input)?;
+    let new_id = resolve(new_input)?;
+    let (field, close_reason) = if *dep_type == DependencyType::Duplicates {
+        ("of", format!("duplicate of {new_id}"))
+    } else {
+        ("by", format!("superseded by {new_id}"))
+    };
+    if old_id == new_id {
+        return Err(BeadsError::validation(
+            field,
+            format!("{old_id} cannot replace itself"),
+        ));
+    }
+
+    let issue = storage
+        .get_issue(&old_id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: old_id.clone() })?;
+
+    // `old duplicates new`, but `new supersedes old`
+    let (from, to) = if *dep_type == DependencyType::Duplicates {
+        (&old_id, &new_id)
+    } else {
+        (&new_id, &old_id)
+    };
+    storage.add_dependency(from, to, dep_type.as_str(), &actor)?;
+
+    let closed = !issue.status.is_terminal();
+    if closed {
+        let update = IssueUpdate {
+            status: Some(Status::Closed),
+            closed_at: Some(Some(Utc::now())),
+            close_reason: Some(Some(close_reason.clone())),
+            closed_by_session: storage.session_id().map(|s| Some(s.to_string())),
+            ..Default::default()
+        };
+        storage.update_issue(&old_id, &update, &actor)?;
+    }
+    tracing::info!(id = %old_id, replaced_by = %new_id, dep_type = %dep_type, "Replaced issue");
+    crate::util::set_last_touched_id(&beads_dir, &old_id);
+
+    if ctx.is_json() {
+        ctx.json_pretty(&ReplacedResult {
+            id: old_id,
+            replaced_by: new_id,
+            dependency_type: dep_type.as_str().to_string(),
+            closed,
+            close_reason: closed.then_some(close_reason),
+        });
+    } else if closed {
+        ctx.success(&format!(
+            "Closed {old_id}: {} ({close_reason})",
+            issue.title
+        ));
+    } else {
+        ctx.success(&format!("Linked {from} {dep_type} {to}"));
+        ctx.info(&format!("{old_id} was already {}", issue.status.as_str()));
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
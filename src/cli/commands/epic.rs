@@ -5,7 +5,7 @@ use crate::config;
 use crate::error::Result;
 use crate::model::{EpicStatus, IssueType, Status};
 use crate::output::{OutputContext, OutputMode};
-use crate::storage::{IssueUpdate, ListFilters, SqliteStorage};
+use crate::storage::{IssueUpdate, ListFilters, Storage};
 use chrono::Utc;
 use crossterm::style::Stylize;
 use rich_rust::prelude::*;
@@ -37,7 +37,7 @@ fn execute_status(
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
 
@@ -85,10 +85,10 @@ fn execute_close_eligible(
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
-    let actor = config::resolve_actor(&config_layer);
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
 
-    let storage = &mut storage_ctx.storage;
+    let storage = &mut *storage_ctx.storage;
     let mut epics = load_epic_statuses(storage)?;
     epics.retain(|e| e.eligible_for_close);
 
@@ -156,7 +156,7 @@ fn execute_close_eligible(
     Ok(())
 }
 
-fn load_epic_statuses(storage: &SqliteStorage) -> Result<Vec<EpicStatus>> {
+fn load_epic_statuses(storage: &dyn Storage) -> Result<Vec<EpicStatus>> {
     let filters = ListFilters {
         types: Some(vec![IssueType::Epic]),
         include_closed: false,
@@ -435,7 +435,9 @@ fn render_close_result_rich(closed_ids: &[String], ctx: &OutputContext) {
 mod tests {
     use super::*;
     use crate::model::{Issue, Priority};
+    use crate::storage::SqliteStorage;
     use chrono::TimeZone;
+    use std::collections::BTreeMap;
 
     fn base_issue(id: &str, title: &str, issue_type: IssueType, status: Status) -> Issue {
         Issue {
@@ -478,6 +480,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -0,0 +1,215 @@
+//! Export command implementation.
+//!
+//! Dumps selected columns of filtered issues as CSV or TSV for spreadsheet
+//! triage and BI ingestion, due/defer dates as ICS calendar events, or whole
+//! issues as markdown that `br create --file --update` reads back, or a
+//! static HTML dashboard. Rows are streamed from the database in batches,
+//! except for the dashboard, which needs every issue at once.
+
+use crate::cli::commands::list::STREAM_BATCH_SIZE;
+use crate::cli::commands::ready::{parse_priorities, parse_types};
+use crate::cli::{ExportArgs, ExportFormat};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::tabular::{self, Delimiter};
+use crate::format::{html, ics};
+use crate::model::{DependencyType, Status};
+use crate::output::OutputContext;
+use crate::storage::{ListFilters, ReadyFilters, ReadySortPolicy, Storage};
+use crate::util::markdown_export;
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use tracing::info;
+
+/// JSON summary when exporting to a file.
+#[derive(Serialize)]
+struct ExportSummary {
+    path: String,
+    format: &'static str,
+    columns: Vec<&'static str>,
+    count: usize,
+}
+
+/// Execute the export command.
+///
+/// # Errors
+///
+/// Returns an error if filters or columns are invalid, the database query
+/// fails, or the output cannot be written.
+pub fn execute(
+    args: &ExportArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    // None means ICS, markdown or HTML, which have a fixed layout
+    let (delimiter, format_name) = match args.format {
+        ExportFormat::Csv => (Some(Delimiter::Comma), "csv"),
+        ExportFormat::Tsv => (Some(Delimiter::Tab), "tsv"),
+        ExportFormat::Ics => (None, "ics"),
+        ExportFormat::Md => (None, "md"),
+        ExportFormat::Html => (None, "html"),
+    };
+    let ics = args.format == ExportFormat::Ics;
+    if ics && args.columns.is_some() {
+        return Err(BeadsError::validation(
+            "columns",
+            "--columns cannot be used with --format ics",
+        ));
+    }
+    let columns = if delimiter.is_some() {
+        tabular::parse_columns(args.columns.as_deref())?
+    } else {
+        Vec::new()
+    };
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &*storage_ctx.storage;
+
+    let statuses = args
+        .status
+        .iter()
+        .map(|s| s.parse())
+        .collect::<Result<Vec<Status>>>()?;
+    let filters = ListFilters {
+        include_closed: args.all || statuses.iter().any(Status::is_terminal),
+        include_deferred: true,
+        statuses: (!statuses.is_empty()).then_some(statuses),
+        types: parse_types(&args.types)?,
+        priorities: parse_priorities(&args.priority)?,
+        assignee: args.assignee.clone(),
+        labels: (!args.label.is_empty()).then(|| args.label.clone()),
+        ..ListFilters::default()
+    };
+
+    let mut writer: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+    match delimiter {
+        Some(delimiter) if !args.no_header => {
+            tabular::write_header(&mut writer, &columns, delimiter)?;
+        }
+        Some(_) => {}
+        None if ics => ics::write_header(&mut writer)?,
+        None => {}
+    }
+
+    let markdown = args.format == ExportFormat::Md;
+    let with_labels = markdown || tabular::needs_labels(&columns);
+    let mut count = 0;
+    if args.format == ExportFormat::Html {
+        count = write_html(storage, &beads_dir, &filters, &mut writer)?;
+    } else {
+        storage.stream_issues(&filters, STREAM_BATCH_SIZE, &mut |mut batch| {
+            if with_labels {
+                let ids: Vec<String> = batch.iter().map(|i| i.id.clone()).collect();
+                let mut labels = storage.get_labels_for_issues(&ids)?;
+                for issue in &mut batch {
+                    issue.labels = labels.remove(&issue.id).unwrap_or_default();
+                }
+            }
+            if let Some(delimiter) = delimiter {
+                tabular::write_rows(&mut writer, &batch, &columns, delimiter)?;
+                count += batch.len();
+            } else if markdown {
+                for issue in &mut batch {
+                    issue.dependencies = storage.get_dependencies_full(&issue.id)?;
+                }
+                markdown_export::write_issues(&mut writer, &batch)?;
+                count += batch.len();
+            } else {
+                count += ics::write_events(&mut writer, &batch)?;
+            }
+            Ok(true)
+        })?;
+    }
+    if ics {
+        ics::write_footer(&mut writer)?;
+    }
+    writer.flush()?;
+    drop(writer);
+
+    info!(count, format = format_name, "Exported issues");
+
+    // Summaries only make sense when the table did not go to stdout.
+    if let Some(path) = &args.output {
+        if ctx.is_json() {
+            ctx.json_pretty(&ExportSummary {
+                path: path.display().to_string(),
+                format: format_name,
+                columns,
+                count,
+            });
+        } else if !ctx.is_quiet() {
+            let noun = if ics { "event(s)" } else { "issue(s)" };
+            println!(
+                "\u{2713} Exported {count} {noun} to {} ({format_name})",
+                path.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Write the HTML dashboard for the issues matching `filters`, returning how
+/// many were included.
+fn write_html<W: Write>(
+    storage: &dyn Storage,
+    beads_dir: &Path,
+    filters: &ListFilters,
+    writer: &mut W,
+) -> Result<usize> {
+    let mut issues = storage.list_issues(filters)?;
+    let ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+    let mut labels = storage.get_labels_for_issues(&ids)?;
+    for issue in &mut issues {
+        issue.labels = labels.remove(&issue.id).unwrap_or_default();
+    }
+    let included: HashSet<String> = ids.into_iter().collect();
+
+    let ready: HashSet<String> = storage
+        .get_ready_issues(&ReadyFilters::default(), ReadySortPolicy::default())?
+        .into_iter()
+        .map(|issue| issue.id)
+        .filter(|id| included.contains(id))
+        .collect();
+    let blocked: HashMap<String, Vec<String>> = storage
+        .get_blocked_issues()?
+        .into_iter()
+        .map(|(issue, blockers)| (issue.id, blockers))
+        .filter(|(id, _)| included.contains(id))
+        .collect();
+    // Parent-child links are structure, not blockers, so the graph leaves them out
+    let edges: Vec<(String, String)> = storage
+        .get_all_dependency_records()?
+        .into_values()
+        .flatten()
+        .filter(|dep| dep.dep_type.is_blocking() && dep.dep_type != DependencyType::ParentChild)
+        .filter(|dep| included.contains(&dep.issue_id) && included.contains(&dep.depends_on_id))
+        .map(|dep| (dep.issue_id, dep.depends_on_id))
+        .collect();
+
+    let project = beads_dir.parent().and_then(Path::file_name).map_or_else(
+        || "beads".to_string(),
+        |name| name.to_string_lossy().to_string(),
+    );
+    html::write_dashboard(
+        writer,
+        &html::Dashboard {
+            title: &project,
+            generated_at: Utc::now(),
+            issues: &issues,
+            ready: &ready,
+            blocked: &blocked,
+            edges: &edges,
+        },
+    )?;
+    Ok(issues.len())
+}
@@ -0,0 +1,347 @@
+//! Forecast command implementation.
+//!
+//! `br forecast [EPIC]` estimates when the open work (an epic's descendants,
+//! or the whole backlog) will be done. Daily close counts over a history
+//! window are read from the event history, counting only issue types that
+//! are still open, and a Monte Carlo simulation draws days from them until
+//! the remaining work is used up. The spread of simulated finish days gives
+//! the confidence bands.
+
+use crate::cli::ForecastArgs;
+use crate::cli::commands::reparent::descendants;
+use crate::cli::commands::resolve_issue_id;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Event, EventType, Issue, IssueType};
+use crate::output::OutputContext;
+use crate::storage::ListFilters;
+use chrono::{Duration, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{BuildHasher, Hasher};
+
+/// Confidence levels reported, in percent.
+const PERCENTILES: [u32; 3] = [50, 85, 95];
+
+/// Simulated futures stop here; anything longer is reported as this.
+const MAX_SIMULATED_DAYS: u32 = 3650;
+
+/// JSON output for the forecast command.
+#[derive(Debug, Serialize)]
+struct ForecastOutput {
+    /// Epic ID, or `backlog`
+    scope: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    /// Open issues still to close
+    remaining: usize,
+    history_days: u32,
+    /// Matching issues closed during the history window
+    closed_in_history: usize,
+    /// Mean matching closes per day over the window
+    daily_throughput: f64,
+    runs: u32,
+    /// Finish dates by confidence; empty when nothing matching was closed
+    confidence: Vec<ForecastPoint>,
+    /// Close rates by type and priority for the open work
+    rates: Vec<CloseRate>,
+}
+
+/// Finish date at one confidence level.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct ForecastPoint {
+    percentile: u32,
+    days: u32,
+    date: NaiveDate,
+}
+
+/// Historical close rate for one type and priority.
+#[derive(Debug, Serialize)]
+struct CloseRate {
+    issue_type: String,
+    priority: i32,
+    remaining: usize,
+    closed: usize,
+    per_week: f64,
+}
+
+/// Execute the forecast command.
+///
+/// # Errors
+///
+/// Returns an error if the epic cannot be resolved, the options are out of
+/// range, or database operations fail.
+#[allow(clippy::cast_precision_loss)]
+pub fn execute(args: &ForecastArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    if args.history_days == 0 {
+        return Err(BeadsError::validation("history-days", "must be at least 1"));
+    }
+    if args.runs == 0 {
+        return Err(BeadsError::validation("runs", "must be at least 1"));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &*storage_ctx.storage;
+
+    let all_issues = storage.list_issues(&ListFilters {
+        include_closed: true,
+        include_deferred: true,
+        ..ListFilters::default()
+    })?;
+
+    // The epic and its descendants, or everything
+    let (scope, title, in_scope) = match &args.epic {
+        Some(input) => {
+            let id = resolve_issue_id(input, &beads_dir, storage, cli)?;
+            let title = storage.get_issue(&id)?.map(|issue| issue.title);
+            let ids = descendants(storage, &id)?;
+            (id, title, Some(ids))
+        }
+        None => ("backlog".to_string(), None, None),
+    };
+    let scoped = |issue: &&Issue| in_scope.as_ref().is_none_or(|ids| ids.contains(&issue.id));
+
+    let open: Vec<&Issue> = all_issues
+        .iter()
+        .filter(scoped)
+        .filter(|issue| !issue.status.is_terminal() && issue.issue_type != IssueType::Epic)
+        .collect();
+    let open_types: HashSet<&IssueType> = open.iter().map(|issue| &issue.issue_type).collect();
+
+    // Close history for the open issue types
+    let today = Utc::now().date_naive();
+    let window_start = today - Duration::days(i64::from(args.history_days) - 1);
+    let closes = close_dates(storage.get_events_after(0)?, &all_issues);
+    let issues_by_id: HashMap<&str, &Issue> = all_issues
+        .iter()
+        .map(|issue| (issue.id.as_str(), issue))
+        .collect();
+    let mut daily = vec![0u32; args.history_days as usize];
+    let mut closed_by_class: BTreeMap<(String, i32), usize> = BTreeMap::new();
+    for (id, date) in &closes {
+        let Some(issue) = issues_by_id.get(id.as_str()) else {
+            continue;
+        };
+        if *date < window_start || *date > today || !open_types.contains(&issue.issue_type) {
+            continue;
+        }
+        let offset = usize::try_from((*date - window_start).num_days()).unwrap_or(0);
+        daily[offset] += 1;
+        *closed_by_class
+            .entry((issue.issue_type.as_str().to_string(), issue.priority.0))
+            .or_default() += 1;
+    }
+    let closed_in_history: usize = daily.iter().map(|&count| count as usize).sum();
+
+    let rates = close_rates(&open, &closed_by_class, args.history_days);
+
+    let remaining = open.len();
+    let confidence = if closed_in_history == 0 && remaining > 0 {
+        Vec::new()
+    } else {
+        let seed = args.seed.unwrap_or_else(random_seed);
+        let finish_days = simulate(&daily, remaining, args.runs, seed);
+        PERCENTILES
+            .iter()
+            .map(|&percentile| {
+                let days = percentile_of(&finish_days, percentile);
+                ForecastPoint {
+                    percentile,
+                    days,
+                    date: today + Duration::days(i64::from(days)),
+                }
+            })
+            .collect()
+    };
+
+    let output = ForecastOutput {
+        scope,
+        title,
+        remaining,
+        history_days: args.history_days,
+        closed_in_history,
+        daily_throughput: round_tenths(closed_in_history as f64 / f64::from(args.history_days)),
+        runs: args.runs,
+        confidence,
+        rates,
+    };
+
+    if ctx.is_json() {
+        ctx.json_pretty(&output);
+    } else if !ctx.is_quiet() {
+        print_text(&output);
+    }
+    Ok(())
+}
+
+/// Close rates per type and priority of the open issues.
+#[allow(clippy::cast_precision_loss)]
+fn close_rates(
+    open: &[&Issue],
+    closed_by_class: &BTreeMap<(String, i32), usize>,
+    history_days: u32,
+) -> Vec<CloseRate> {
+    let mut remaining_by_class: BTreeMap<(String, i32), usize> = BTreeMap::new();
+    for issue in open {
+        *remaining_by_class
+            .entry((issue.issue_type.as_str().to_string(), issue.priority.0))
+            .or_default() += 1;
+    }
+    let weeks = f64::from(history_days) / 7.0;
+    remaining_by_class
+        .into_iter()
+        .map(|((issue_type, priority), remaining)| {
+            let closed = closed_by_class
+                .get(&(issue_type.clone(), priority))
+                .copied()
+                .unwrap_or(0);
+            CloseRate {
+                issue_type,
+                priority,
+                remaining,
+                closed,
+                per_week: round_tenths(closed as f64 / weeks),
+            }
+        })
+        .collect()
+}
+
+/// The day each issue was closed: from `closed` events, or `closed_at` for
+/// issues closed without one (e.g. imported). Reopened and closed again
+/// counts each close.
+fn close_dates(events: Vec<Event>, issues: &[Issue]) -> Vec<(String, NaiveDate)> {
+    let mut closes: Vec<(String, NaiveDate)> = events
+        .into_iter()
+        .filter(|event| event.event_type == EventType::Closed)
+        .map(|event| (event.issue_id, event.created_at.date_naive()))
+        .collect();
+    let with_events: HashSet<String> = closes.iter().map(|(id, _)| id.clone()).collect();
+    closes.extend(
+        issues
+            .iter()
+            .filter(|issue| !with_events.contains(&issue.id))
+            .filter_map(|issue| {
+                issue
+                    .closed_at
+                    .map(|closed_at| (issue.id.clone(), closed_at.date_naive()))
+            }),
+    );
+    closes
+}
+
+/// Simulate `runs` futures, each drawing past days at random until
+/// `remaining` closes are reached; returns the finish day of each, sorted.
+#[allow(clippy::cast_possible_truncation)]
+fn simulate(daily: &[u32], remaining: usize, runs: u32, seed: u64) -> Vec<u32> {
+    let mut rng = SplitMix64(seed);
+    let mut finish_days: Vec<u32> = (0..runs)
+        .map(|_| {
+            let mut closed = 0usize;
+            let mut days = 0;
+            while closed < remaining && days < MAX_SIMULATED_DAYS {
+                closed += daily[(rng.next() % daily.len() as u64) as usize] as usize;
+                days += 1;
+            }
+            days
+        })
+        .collect();
+    finish_days.sort_unstable();
+    finish_days
+}
+
+/// The value at `percentile` of sorted `values`.
+fn percentile_of(values: &[u32], percentile: u32) -> u32 {
+    let rank = (values.len() * percentile as usize).div_ceil(100);
+    values[rank.saturating_sub(1).min(values.len() - 1)]
+}
+
+fn round_tenths(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// Small deterministic PRNG, so `--seed` reproduces a forecast.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    const fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+fn print_text(output: &ForecastOutput) {
+    match &output.title {
+        Some(title) => println!("Forecast for {}: {title}", output.scope),
+        None => println!("Forecast for the open backlog"),
+    }
+    println!("Remaining: {} open issue(s)", output.remaining);
+    println!(
+        "Throughput: {} closes/day over the last {} days ({} closed)",
+        output.daily_throughput, output.history_days, output.closed_in_history
+    );
+    if output.remaining == 0 {
+        println!("Nothing left to do.");
+    } else if output.confidence.is_empty() {
+        println!(
+            "No matching issues were closed in the last {} days, so there is nothing to \
+             forecast from. Try a longer --history-days.",
+            output.history_days
+        );
+    } else {
+        println!();
+        for point in &output.confidence {
+            println!(
+                "  {:>3}%  by {} ({} days)",
+                point.percentile, point.date, point.days
+            );
+        }
+    }
+    if !output.rates.is_empty() {
+        println!();
+        println!("Close rates:");
+        for rate in &output.rates {
+            println!(
+                "  {:<10} P{}  {:>4} open  {:>4} closed  {}/week",
+                rate.issue_type, rate.priority, rate.remaining, rate.closed, rate.per_week
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulate_is_reproducible_and_bounded() {
+        let daily = [0, 1, 2, 1, 0, 3, 1];
+        let first = simulate(&daily, 10, 500, 42);
+        assert_eq!(first, simulate(&daily, 10, 500, 42));
+        assert!(first.windows(2).all(|pair| pair[0] <= pair[1]));
+        // At most 3 closes a day, so never faster than 4 days
+        assert!(first[0] >= 4);
+        assert!(percentile_of(&first, 50) <= percentile_of(&first, 95));
+
+        // No throughput at all never finishes
+        assert_eq!(simulate(&[0, 0], 1, 3, 1), vec![MAX_SIMULATED_DAYS; 3]);
+        assert_eq!(simulate(&[0, 0], 0, 2, 1), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_percentile_of() {
+        let values: Vec<u32> = (1..=10).collect();
+        assert_eq!(percentile_of(&values, 50), 5);
+        assert_eq!(percentile_of(&values, 85), 9);
+        assert_eq!(percentile_of(&values, 95), 10);
+        assert_eq!(percentile_of(&[7], 50), 7);
+    }
+}
@@ -0,0 +1,244 @@
+//! Gc command implementation.
+//!
+//! Tombstones ephemeral issues once they are older than the configured TTL
+//! (`gc.ephemeral_ttl_days`, default 7 days). With `--aggressive`, closed
+//! issues that were closed more than `--closed-days` ago are removed as well.
+//! Pinned issues and templates are never collected.
+
+use crate::cli::GcArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, Status};
+use crate::output::OutputContext;
+use crate::storage::ListFilters;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+/// Issue selected for garbage collection.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+struct GcCandidate {
+    id: String,
+    title: String,
+    /// `ephemeral` or `closed`
+    reason: &'static str,
+    /// Days since creation (ephemeral) or closing (closed)
+    age_days: i64,
+}
+
+/// JSON output for the gc command.
+#[derive(Debug, Serialize)]
+struct GcResult {
+    dry_run: bool,
+    ttl_days: i64,
+    closed_days: Option<i64>,
+    removed: Vec<GcCandidate>,
+    dependencies_removed: usize,
+}
+
+/// Execute the gc command.
+///
+/// # Errors
+///
+/// Returns an error if the TTL is invalid or database operations fail.
+pub fn execute(
+    args: &GcArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    if args.closed_days < 0 {
+        return Err(BeadsError::validation("closed_days", "must be >= 0"));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+
+    let ttl_days = match args.ttl_days {
+        Some(days) if days < 0 => {
+            return Err(BeadsError::validation("ttl_days", "must be >= 0"));
+        }
+        Some(days) => days,
+        None => config::gc_ephemeral_ttl_days_from_layer(&config_layer)?,
+    };
+    let closed_days = args.aggressive.then_some(args.closed_days);
+
+    let storage = &mut *storage_ctx.storage;
+    let filters = ListFilters {
+        include_closed: true,
+        include_deferred: true,
+        ..ListFilters::default()
+    };
+    let issues = storage.list_issues(&filters)?;
+    let candidates = select_candidates(&issues, Utc::now(), ttl_days, closed_days);
+
+    let mut dependencies_removed = 0;
+    if !args.dry_run {
+        for candidate in &candidates {
+            dependencies_removed += storage.remove_all_dependencies(&candidate.id, &actor)?;
+            let days = if candidate.reason == "closed" {
+                args.closed_days
+            } else {
+                ttl_days
+            };
+            let reason = format!("gc: {} issue older than {days} days", candidate.reason);
+            storage.delete_issue(&candidate.id, &actor, &reason, false)?;
+            tracing::info!(id = %candidate.id, reason = candidate.reason, "Garbage collected issue");
+        }
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&GcResult {
+            dry_run: args.dry_run,
+            ttl_days,
+            closed_days,
+            removed: candidates,
+            dependencies_removed,
+        });
+    } else if candidates.is_empty() {
+        ctx.info("Nothing to collect");
+    } else {
+        if args.dry_run {
+            ctx.info(&format!("Would remove {} issue(s):", candidates.len()));
+        } else {
+            ctx.success(&format!("Removed {} issue(s):", candidates.len()));
+        }
+        for candidate in &candidates {
+            println!(
+                "  - {} [{}, {}d] {}",
+                candidate.id, candidate.reason, candidate.age_days, candidate.title
+            );
+        }
+        if dependencies_removed > 0 {
+            println!("Removed {dependencies_removed} dependency link(s)");
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Pick the issues to collect, oldest first.
+///
+/// Ephemeral issues qualify once `created_at` is at least `ttl_days` old;
+/// when `closed_days` is set, closed issues qualify once `closed_at` is at
+/// least that old. Pinned issues, templates, and tombstones are skipped.
+fn select_candidates(
+    issues: &[Issue],
+    now: DateTime<Utc>,
+    ttl_days: i64,
+    closed_days: Option<i64>,
+) -> Vec<GcCandidate> {
+    let ephemeral_cutoff = now - Duration::days(ttl_days);
+    let closed_cutoff = closed_days.map(|days| now - Duration::days(days));
+
+    let mut selected: Vec<(DateTime<Utc>, GcCandidate)> = Vec::new();
+    for issue in issues {
+        if issue.pinned || issue.is_template || issue.status == Status::Tombstone {
+            continue;
+        }
+
+        let matched = if issue.ephemeral && issue.created_at <= ephemeral_cutoff {
+            Some(("ephemeral", issue.created_at))
+        } else if issue.status == Status::Closed {
+            let closed_at = issue.closed_at.unwrap_or(issue.updated_at);
+            closed_cutoff
+                .filter(|cutoff| closed_at <= *cutoff)
+                .map(|_| ("closed", closed_at))
+        } else {
+            None
+        };
+
+        if let Some((reason, since)) = matched {
+            selected.push((
+                since,
+                GcCandidate {
+                    id: issue.id.clone(),
+                    title: issue.title.clone(),
+                    reason,
+                    age_days: (now - since).num_days().max(0),
+                },
+            ));
+        }
+    }
+
+    selected.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.id.cmp(&b.1.id)));
+    selected
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn issue(id: &str, created_at: DateTime<Utc>) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            created_at,
+            updated_at: created_at,
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn selects_expired_ephemerals_and_old_closed_with_aggressive() {
+        let now = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+
+        let old_wisp = Issue {
+            ephemeral: true,
+            ..issue("bd-1", now - Duration::days(10))
+        };
+        let fresh_wisp = Issue {
+            ephemeral: true,
+            ..issue("bd-2", now - Duration::days(2))
+        };
+        let pinned_wisp = Issue {
+            ephemeral: true,
+            pinned: true,
+            ..issue("bd-3", now - Duration::days(30))
+        };
+        let old_closed = Issue {
+            status: Status::Closed,
+            closed_at: Some(now - Duration::days(120)),
+            ..issue("bd-4", now - Duration::days(200))
+        };
+        let recent_closed = Issue {
+            status: Status::Closed,
+            closed_at: Some(now - Duration::days(5)),
+            ..issue("bd-5", now - Duration::days(200))
+        };
+        let open = issue("bd-6", now - Duration::days(365));
+        let issues = vec![
+            old_wisp,
+            fresh_wisp,
+            pinned_wisp,
+            old_closed,
+            recent_closed,
+            open,
+        ];
+
+        let ids = |candidates: Vec<GcCandidate>| {
+            candidates
+                .into_iter()
+                .map(|c| (c.id, c.reason))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(select_candidates(&issues, now, 7, None)),
+            vec![("bd-1".to_string(), "ephemeral")]
+        );
+        assert_eq!(
+            ids(select_candidates(&issues, now, 7, Some(90))),
+            vec![
+                ("bd-4".to_string(), "closed"),
+                ("bd-1".to_string(), "ephemeral")
+            ]
+        );
+    }
+}
@@ -10,7 +10,7 @@ use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::model::{DependencyType, Issue, Status};
 use crate::output::{OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::storage::{ListFilters, Storage};
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
 use rich_rust::prelude::*;
 use serde::Serialize;
@@ -61,26 +61,29 @@ pub fn execute(args: &GraphArgs, cli: &config::CliOverrides, ctx: &OutputContext
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
     let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
     let all_ids = storage_ctx.storage.get_all_ids()?;
 
     if args.all {
-        graph_all(&storage_ctx.storage, args.compact, ctx)
+        graph_all(&*storage_ctx.storage, args.compact, ctx)
     } else {
         let issue_id = args.issue.as_ref().ok_or_else(|| {
             BeadsError::validation("issue", "Issue ID required unless --all is specified")
         })?;
 
-        let resolved_id = resolve_issue_id(&storage_ctx.storage, &resolver, &all_ids, issue_id)?;
-        graph_single(&storage_ctx.storage, &resolved_id, args.compact, ctx)
+        let resolved_id = resolve_issue_id(&*storage_ctx.storage, &resolver, &all_ids, issue_id)?;
+        graph_single(&*storage_ctx.storage, &resolved_id, args.compact, ctx)
     }
 }
 
 /// Show graph for a single issue (traverse dependents only).
 fn graph_single(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     root_id: &str,
     compact: bool,
     ctx: &OutputContext,
@@ -199,7 +202,7 @@ fn graph_single(
 
 /// Show graph for all `open`/`in_progress`/`blocked` issues.
 #[allow(clippy::too_many_lines)]
-fn graph_all(storage: &SqliteStorage, compact: bool, ctx: &OutputContext) -> Result<()> {
+fn graph_all(storage: &dyn Storage, compact: bool, ctx: &OutputContext) -> Result<()> {
     // Get all open/in_progress/blocked issues
     let filters = ListFilters {
         statuses: Some(vec![Status::Open, Status::InProgress, Status::Blocked]),
@@ -478,7 +481,7 @@ fn calculate_depths(
 }
 
 fn resolve_issue_id(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     input: &str,
@@ -714,6 +717,7 @@ fn status_style(status: &str, theme: &crate::output::Theme) -> Style {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storage::SqliteStorage;
 
     #[test]
     fn test_graph_node_serialization() {
@@ -4,12 +4,15 @@ use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::output::OutputContext;
 use crate::sync::history;
+use crate::sync::shard;
+use crate::sync::{
+    ImportConfig, METADATA_JSONL_CONTENT_HASH, compute_jsonl_hash, import_from_jsonl,
+    read_issues_from_jsonl,
+};
 use rich_rust::prelude::*;
 use serde_json::json;
-use std::path::Path;
-
-/// Result type for diff status: (status_string, diff_available, optional_size_tuple).
-type DiffStatusResult = (&'static str, bool, Option<(u64, u64)>);
+use std::fs;
+use std::path::{Path, PathBuf};
 
 /// Execute the history command.
 ///
@@ -21,10 +24,23 @@ pub fn execute(args: HistoryArgs, cli: &config::CliOverrides, ctx: &OutputContex
     let history_dir = beads_dir.join(".br_history");
 
     match args.command {
-        Some(HistoryCommands::Diff { file }) => diff_backup(&beads_dir, &history_dir, &file, ctx),
-        Some(HistoryCommands::Restore { file, force }) => {
-            restore_backup(&beads_dir, &history_dir, &file, force, ctx)
+        Some(HistoryCommands::Show { snapshot }) => show_snapshot(&history_dir, &snapshot, ctx),
+        Some(HistoryCommands::Diff { from, to }) => {
+            let jsonl_path = config::resolve_paths(&beads_dir, cli.db.as_ref())?.jsonl_path;
+            diff_snapshots(&history_dir, &jsonl_path, &from, to.as_deref(), ctx)
         }
+        Some(HistoryCommands::Restore {
+            snapshot,
+            force,
+            dry_run,
+        }) => restore_snapshot(
+            &beads_dir,
+            &history_dir,
+            &snapshot,
+            RestoreOptions { force, dry_run },
+            cli,
+            ctx,
+        ),
         Some(HistoryCommands::Prune { keep, older_than }) => {
             prune_backups(&history_dir, keep, older_than, ctx)
         }
@@ -127,38 +143,28 @@ fn list_backups(history_dir: &Path, ctx: &OutputContext) -> Result<()> {
     Ok(())
 }
 
-/// Show diff between current state and a backup.
-fn diff_backup(
-    beads_dir: &Path,
-    history_dir: &Path,
-    filename: &str,
-    ctx: &OutputContext,
-) -> Result<()> {
-    let backup_path = history_dir.join(filename);
-    if !backup_path.exists() {
-        return Err(BeadsError::Config(format!(
-            "Backup file not found: {filename}"
-        )));
-    }
-
-    let current_path = beads_dir.join("issues.jsonl");
-    if !current_path.exists() {
-        return Err(BeadsError::Config(
-            "Current issues.jsonl not found".to_string(),
-        ));
-    }
+/// Show the issues in a snapshot.
+fn show_snapshot(history_dir: &Path, spec: &str, ctx: &OutputContext) -> Result<()> {
+    let entries = history::find_snapshot(history_dir, spec)?;
+    let issues = history::read_snapshot(&entries)?;
 
     if ctx.is_json() {
-        let (status_label, diff_available, size_fallback) =
-            diff_status_for_json(&current_path, &backup_path)?;
+        let items: Vec<_> = issues
+            .iter()
+            .map(|issue| {
+                json!({
+                    "id": issue.id,
+                    "title": issue.title,
+                    "status": issue.status.as_str(),
+                    "priority": issue.priority,
+                })
+            })
+            .collect();
         let output = json!({
-            "action": "diff",
-            "backup": filename,
-            "current": current_path.display().to_string(),
-            "status": status_label,
-            "diff_available": diff_available,
-            "current_size_bytes": size_fallback.map(|sizes| sizes.0),
-            "backup_size_bytes": size_fallback.map(|sizes| sizes.1),
+            "snapshot": spec,
+            "files": file_names(&entries),
+            "count": issues.len(),
+            "issues": items,
         });
         ctx.json_pretty(&output);
         return Ok(());
@@ -170,97 +176,214 @@ fn diff_backup(
 
     if ctx.is_rich() {
         let theme = ctx.theme();
-        let header = format!("Current: issues.jsonl\nBackup: {filename}");
-        let panel = Panel::from_text(&header)
-            .title(Text::styled("History Diff", theme.panel_title.clone()))
+        let mut table = Table::new()
             .box_style(theme.box_style)
-            .border_style(theme.panel_border.clone());
-        ctx.render(&panel);
+            .border_style(theme.panel_border.clone())
+            .title(Text::styled(
+                format!("Snapshot {spec} ({} issues)", issues.len()),
+                theme.panel_title.clone(),
+            ));
+        table = table
+            .with_column(Column::new("ID").min_width(8).max_width(24))
+            .with_column(Column::new("Status").min_width(6).max_width(12))
+            .with_column(Column::new("Title").min_width(20));
+        for issue in &issues {
+            table.add_row(Row::new(vec![
+                Cell::new(Text::styled(issue.id.clone(), theme.issue_id.clone())),
+                Cell::new(Text::new(issue.status.as_str())),
+                Cell::new(Text::styled(issue.title.clone(), theme.issue_title.clone())),
+            ]));
+        }
+        ctx.render(&table);
     } else {
-        println!("Diffing current issues.jsonl vs {filename}...");
+        println!(
+            "Snapshot {spec} ({} issues from {}):",
+            issues.len(),
+            file_names(&entries).join(", ")
+        );
+        for issue in &issues {
+            println!(
+                "  {:<16} {:<12} {}",
+                issue.id,
+                issue.status.as_str(),
+                issue.title
+            );
+        }
     }
 
-    // Let's shell out to `diff -u` for now as it's standard on linux/mac.
-    // Avoid GNU-only flags (like --color) to keep this portable.
-    let status = std::process::Command::new("diff")
-        .arg("-u")
-        .arg(&current_path)
-        .arg(&backup_path)
-        .status();
-
-    match status {
-        Ok(s) => {
-            if s.success() {
-                if ctx.is_rich() {
-                    ctx.success("Files are identical.");
-                } else {
-                    println!("Files are identical.");
-                }
-            }
-            // diff returns 1 if differences found, which is fine/expected.
-        }
-        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-            let current_size = std::fs::metadata(&current_path)?.len();
-            let backup_size = std::fs::metadata(&backup_path)?.len();
-            let current_human = format_size(current_size);
-            let backup_human = format_size(backup_size);
-            if ctx.is_rich() {
-                let theme = ctx.theme();
-                let body = format!(
-                    "Diff tool not available; comparing sizes.\nCurrent: {current_human} ({current_size} bytes)\nBackup:  {backup_human} ({backup_size} bytes)"
-                );
-                let panel = Panel::from_text(&body)
-                    .title(Text::styled("History Diff", theme.panel_title.clone()))
-                    .box_style(theme.box_style)
-                    .border_style(theme.panel_border.clone());
-                ctx.render(&panel);
-            } else {
-                println!("'diff' command not found. Comparing sizes:");
-                println!("Current: {current_size} bytes");
-                println!("Backup:  {backup_size} bytes");
-            }
-        }
-        Err(err) => {
-            return Err(BeadsError::Config(format!("Failed to run diff: {err}")));
+    Ok(())
+}
+
+/// Issue-level diff between a snapshot and another snapshot or the current JSONL.
+fn diff_snapshots(
+    history_dir: &Path,
+    jsonl_path: &Path,
+    from: &str,
+    to: Option<&str>,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let before = history::read_snapshot(&history::find_snapshot(history_dir, from)?)?;
+    let (to_label, after) = if let Some(to) = to {
+        let entries = history::find_snapshot(history_dir, to)?;
+        (to.to_string(), history::read_snapshot(&entries)?)
+    } else {
+        if !shard::jsonl_exists(jsonl_path) {
+            return Err(BeadsError::Config(format!(
+                "Current JSONL not found at {}",
+                jsonl_path.display()
+            )));
         }
+        (
+            jsonl_path.display().to_string(),
+            read_issues_from_jsonl(jsonl_path)?,
+        )
+    };
+    let diff = history::diff_issues(&before, &after);
+
+    if ctx.is_json() {
+        let output = json!({
+            "action": "diff",
+            "from": from,
+            "to": to_label,
+            "identical": diff.is_empty(),
+            "added": diff.added,
+            "removed": diff.removed,
+            "changed": diff.changed,
+        });
+        ctx.json_pretty(&output);
+        return Ok(());
+    }
+
+    if ctx.is_quiet() {
+        return Ok(());
     }
 
+    let title = format!("History Diff: {from} → {to_label}");
+    print_diff(&title, &diff, ctx);
     Ok(())
 }
 
-/// Restore a backup.
-fn restore_backup(
+/// Restore a snapshot to the JSONL and re-import it.
+///
+/// The import runs with `--force` semantics, so the snapshot's version of each
+/// issue wins over the database. Issues that are not in the snapshot are left
+/// as they are.
+#[allow(clippy::too_many_lines)]
+fn restore_snapshot(
     beads_dir: &Path,
     history_dir: &Path,
-    filename: &str,
-    force: bool,
+    spec: &str,
+    options: RestoreOptions,
+    cli: &config::CliOverrides,
     ctx: &OutputContext,
 ) -> Result<()> {
-    let backup_path = history_dir.join(filename);
-    if !backup_path.exists() {
-        return Err(BeadsError::Config(format!(
-            "Backup file not found: {filename}"
-        )));
+    let entries = history::find_snapshot(history_dir, spec)?;
+    // Validate before touching anything: a corrupt snapshot can't be imported.
+    let snapshot = history::read_snapshot(&entries)
+        .map_err(|err| BeadsError::Config(format!("Snapshot {spec} is not valid JSONL: {err}")))?;
+
+    let jsonl_path = config::resolve_paths(beads_dir, cli.db.as_ref())?.jsonl_path;
+    let copies: Vec<(PathBuf, PathBuf)> = entries
+        .iter()
+        .map(|entry| {
+            (
+                entry.path.clone(),
+                jsonl_path.with_file_name(format!("{}.jsonl", entry.stem)),
+            )
+        })
+        .collect();
+    // Current issues, and files of the current layout the snapshot does not have
+    let (current, superseded): (Vec<_>, Vec<PathBuf>) = if shard::jsonl_exists(&jsonl_path) {
+        let current = read_issues_from_jsonl(&jsonl_path).unwrap_or_else(|err| {
+            tracing::warn!(error = %err, "Current JSONL unreadable; diffing against empty");
+            Vec::new()
+        });
+        let superseded = shard::jsonl_sources(&jsonl_path)
+            .into_iter()
+            .filter(|path| path.exists() && !copies.iter().any(|(_, target)| target == path))
+            .collect();
+        (current, superseded)
+    } else {
+        (Vec::new(), Vec::new())
+    };
+    let diff = history::diff_issues(&current, &snapshot);
+
+    if options.dry_run {
+        if ctx.is_json() {
+            let output = json!({
+                "action": "restore",
+                "backup": spec,
+                "files": file_names(&entries),
+                "target": jsonl_path.display().to_string(),
+                "dry_run": true,
+                "restored": false,
+                "added": diff.added,
+                "removed": diff.removed,
+                "changed": diff.changed,
+            });
+            ctx.json_pretty(&output);
+        } else if !ctx.is_quiet() {
+            let title = format!("Dry run: restore {spec}");
+            print_diff(&title, &diff, ctx);
+            println!("Issues not in the snapshot are kept in the database. Nothing was written.");
+        }
+        return Ok(());
     }
 
-    let target_path = beads_dir.join("issues.jsonl");
+    if !options.force {
+        let existing = copies
+            .iter()
+            .map(|(_, target)| target)
+            .chain(&superseded)
+            .find(|path| path.exists());
+        if let Some(path) = existing {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            return Err(BeadsError::Config(format!(
+                "Current {name} exists. Use --force to overwrite."
+            )));
+        }
+    }
 
-    if target_path.exists() && !force {
-        return Err(BeadsError::Config(
-            "Current issues.jsonl exists. Use --force to overwrite.".to_string(),
-        ));
+    for (backup, target) in &copies {
+        fs::copy(backup, target)?;
+    }
+    for path in &superseded {
+        fs::remove_file(path)?;
     }
 
-    // Copy backup to issues.jsonl
-    std::fs::copy(&backup_path, &target_path)?;
+    let mut storage_ctx = config::open_storage_with_cli(beads_dir, cli)?;
+    let imported = if storage_ctx.no_db {
+        None
+    } else {
+        let storage = storage_ctx.storage.sqlite_mut("history restore")?;
+        let prefix = storage
+            .get_config("issue_prefix")?
+            .unwrap_or_else(|| "bd".to_string());
+        let import_config = ImportConfig {
+            // The snapshot is the state being restored, so it wins
+            force_upsert: true,
+            ..ImportConfig::default()
+        };
+        let result = import_from_jsonl(storage, &jsonl_path, &import_config, Some(&prefix))?;
+        storage.set_metadata(
+            METADATA_JSONL_CONTENT_HASH,
+            &compute_jsonl_hash(&jsonl_path)?,
+        )?;
+        Some(result.imported_count)
+    };
 
     if ctx.is_json() {
         let output = json!({
             "action": "restore",
-            "backup": filename,
-            "target": target_path.display().to_string(),
+            "backup": spec,
+            "files": file_names(&entries),
+            "target": jsonl_path.display().to_string(),
+            "dry_run": false,
             "restored": true,
-            "next_step": "br sync --import-only --force",
+            "imported": imported,
+            "added": diff.added,
+            "removed": diff.removed,
+            "changed": diff.changed,
         });
         ctx.json_pretty(&output);
         return Ok(());
@@ -270,23 +393,130 @@ fn restore_backup(
         return Ok(());
     }
 
+    let target_name = jsonl_path.file_name().unwrap_or_default().to_string_lossy();
+    let imported_line = imported.map_or_else(
+        || "No database (--no-db); JSONL restored only.".to_string(),
+        |count| format!("Re-imported {count} issue(s) into the database."),
+    );
     if ctx.is_rich() {
         let theme = ctx.theme();
-        let body =
-            format!("Restored {filename} to issues.jsonl.\nNext: br sync --import-only --force");
+        let body = format!(
+            "Restored {spec} to {target_name}.\n{imported_line}\n\n{} added, {} changed, {} no longer in JSONL",
+            diff.added.len(),
+            diff.changed.len(),
+            diff.removed.len()
+        );
         let panel = Panel::from_text(&body)
             .title(Text::styled("History Restore", theme.panel_title.clone()))
             .box_style(theme.box_style)
             .border_style(theme.panel_border.clone());
         ctx.render(&panel);
     } else {
-        println!("Restored {filename} to issues.jsonl");
-        println!("Run 'br sync --import-only --force' to import this state into the database.");
+        println!("Restored {spec} to {target_name}");
+        println!("{imported_line}");
+        println!(
+            "{} added, {} changed, {} no longer in JSONL",
+            diff.added.len(),
+            diff.changed.len(),
+            diff.removed.len()
+        );
     }
 
     Ok(())
 }
 
+#[derive(Debug, Clone, Copy)]
+struct RestoreOptions {
+    force: bool,
+    dry_run: bool,
+}
+
+fn file_names(entries: &[history::BackupEntry]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|entry| {
+            entry
+                .path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect()
+}
+
+/// Print an issue-level diff in plain or rich form.
+fn print_diff(title: &str, diff: &history::IssueDiff, ctx: &OutputContext) {
+    let sections = [
+        ("Added", "+", &diff.added),
+        ("Changed", "~", &diff.changed),
+        ("Removed", "-", &diff.removed),
+    ];
+
+    if ctx.is_rich() {
+        let theme = ctx.theme();
+        let mut text = Text::new("");
+        if diff.is_empty() {
+            text.append_styled("No issue-level differences.", theme.muted.clone());
+        }
+        for (label, marker, changes) in sections {
+            if changes.is_empty() {
+                continue;
+            }
+            let style = match marker {
+                "+" => theme.success.clone(),
+                "-" => theme.error.clone(),
+                _ => theme.warning.clone(),
+            };
+            text.append_styled(
+                &format!("{label} ({})\n", changes.len()),
+                theme.section.clone(),
+            );
+            for change in changes {
+                text.append_styled(&format!("  {marker} "), style.clone());
+                text.append_styled(&change.id, theme.issue_id.clone());
+                text.append(&format!("  {}", change.title));
+                if !change.fields.is_empty() {
+                    text.append_styled(
+                        &format!("  ({})", change.fields.join(", ")),
+                        theme.dimmed.clone(),
+                    );
+                }
+                text.append("\n");
+            }
+        }
+        let panel = Panel::from_rich_text(&text, ctx.width())
+            .title(Text::styled(title, theme.panel_title.clone()))
+            .box_style(theme.box_style)
+            .border_style(theme.panel_border.clone());
+        ctx.render(&panel);
+        return;
+    }
+
+    println!("{title}");
+    if diff.is_empty() {
+        println!("No issue-level differences.");
+    }
+    for (label, marker, changes) in sections {
+        if changes.is_empty() {
+            continue;
+        }
+        println!("{label} ({}):", changes.len());
+        for change in changes {
+            if change.fields.is_empty() {
+                println!("  {marker} {}  {}", change.id, change.title);
+            } else {
+                println!(
+                    "  {marker} {}  {}  ({})",
+                    change.id,
+                    change.title,
+                    change.fields.join(", ")
+                );
+            }
+        }
+    }
+}
+
 /// Prune old backups.
 fn prune_backups(
     history_dir: &Path,
@@ -332,32 +562,6 @@ fn prune_backups(
     Ok(())
 }
 
-fn diff_status_for_json(current_path: &Path, backup_path: &Path) -> Result<DiffStatusResult> {
-    let output = std::process::Command::new("diff")
-        .arg("-u")
-        .arg(current_path)
-        .arg(backup_path)
-        .output();
-
-    match output {
-        Ok(out) => {
-            if out.status.success() {
-                Ok(("identical", true, None))
-            } else if out.status.code() == Some(1) {
-                Ok(("different", true, None))
-            } else {
-                Ok(("error", true, None))
-            }
-        }
-        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-            let current_size = std::fs::metadata(current_path)?.len();
-            let backup_size = std::fs::metadata(backup_path)?.len();
-            Ok(("diff_unavailable", false, Some((current_size, backup_size))))
-        }
-        Err(_) => Ok(("error", false, None)),
-    }
-}
-
 #[allow(clippy::cast_precision_loss)]
 fn format_size(bytes: u64) -> String {
     const KB: u64 = 1024;
@@ -0,0 +1,142 @@
+//! Impact command implementation.
+//!
+//! `br impact <id>` lists the open issues transitively blocked by an issue,
+//! with their count, total estimate and the highest priority among them, to
+//! help decide what to finish first.
+
+use crate::cli::ImpactArgs;
+use crate::config;
+use crate::error::Result;
+use crate::format::format_estimate;
+use crate::output::OutputContext;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// One issue blocked by the target.
+#[derive(Debug, Serialize)]
+struct ImpactedIssue {
+    id: String,
+    title: String,
+    status: String,
+    priority: i32,
+    estimated_minutes: Option<i32>,
+    /// Dependency hops from the target
+    depth: usize,
+}
+
+/// JSON output for the impact command.
+#[derive(Debug, Serialize)]
+struct ImpactOutput {
+    id: String,
+    title: String,
+    count: usize,
+    total_estimated_minutes: i64,
+    /// Impacted issues without an estimate
+    unestimated: usize,
+    /// Most urgent priority among impacted issues (lowest number)
+    highest_priority: Option<i32>,
+    issues: Vec<ImpactedIssue>,
+}
+
+/// Execute the impact command.
+///
+/// # Errors
+///
+/// Returns an error if the ID cannot be resolved or database operations fail.
+pub fn execute(args: &ImpactArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &*storage_ctx.storage;
+
+    let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix).with_aliases(storage.get_id_aliases()?),
+    );
+    let all_ids = storage.get_all_ids()?;
+    let id = resolver
+        .resolve(
+            &args.id,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?
+        .id;
+    let title = storage
+        .get_issue(&id)?
+        .map(|issue| issue.title)
+        .unwrap_or_default();
+
+    let impacted = storage.get_transitive_dependents(&id, args.depth)?;
+    let ids: Vec<String> = impacted.iter().map(|(id, _)| id.clone()).collect();
+    let mut issues_by_id: HashMap<String, _> = storage
+        .get_issues_by_ids(&ids)?
+        .into_iter()
+        .map(|issue| (issue.id.clone(), issue))
+        .collect();
+    let issues: Vec<ImpactedIssue> = impacted
+        .into_iter()
+        .filter_map(|(id, depth)| {
+            issues_by_id.remove(&id).map(|issue| ImpactedIssue {
+                id: issue.id,
+                title: issue.title,
+                status: issue.status.as_str().to_string(),
+                priority: issue.priority.0,
+                estimated_minutes: issue.estimated_minutes,
+                depth,
+            })
+        })
+        .collect();
+
+    let output = ImpactOutput {
+        id,
+        title,
+        count: issues.len(),
+        total_estimated_minutes: issues
+            .iter()
+            .filter_map(|issue| issue.estimated_minutes)
+            .map(i64::from)
+            .sum(),
+        unestimated: issues
+            .iter()
+            .filter(|issue| issue.estimated_minutes.is_none())
+            .count(),
+        highest_priority: issues.iter().map(|issue| issue.priority).min(),
+        issues,
+    };
+
+    if ctx.is_json() {
+        ctx.json_pretty(&output);
+        return Ok(());
+    }
+    if output.issues.is_empty() {
+        ctx.info(&format!("{} does not block any open issues", output.id));
+        return Ok(());
+    }
+
+    let mut summary = format!(
+        "{} blocks {} open issue(s), {} estimated",
+        output.id,
+        output.count,
+        format_estimate(output.total_estimated_minutes)
+    );
+    if output.unestimated > 0 {
+        summary.push_str(&format!(" ({} without estimate)", output.unestimated));
+    }
+    if let Some(priority) = output.highest_priority {
+        summary.push_str(&format!(", highest priority P{priority}"));
+    }
+    ctx.info(&summary);
+    for issue in &output.issues {
+        let indent = "  ".repeat(issue.depth);
+        let estimate = issue
+            .estimated_minutes
+            .map(|m| format!(" {}", format_estimate(i64::from(m))))
+            .unwrap_or_default();
+        ctx.print(&format!(
+            "{indent}{}: {} [P{}] [{}]{estimate}",
+            issue.id, issue.title, issue.priority, issue.status
+        ));
+    }
+    Ok(())
+}
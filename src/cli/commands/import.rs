@@ -0,0 +1,796 @@
+//! Import command implementation.
+//!
+//! Imports issues from Jira, GitHub, or Linear CSV exports. Each source has a
+//! built-in mapping (column names, status names, priority scale, issue types)
+//! that a `--mapping` YAML file can override. Imported issues record the source
+//! key in `external_ref` and the source name in `source_system`, so importing a
+//! newer export updates the matching issues instead of duplicating them.
+
+use crate::cli::{ImportArgs, ImportSource};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::tabular::parse_csv;
+use crate::model::{Issue, IssueType, Priority, Status};
+use crate::output::OutputContext;
+use crate::storage::{Backend, IssueUpdate, Storage};
+use crate::util::id::IdGenerator;
+use crate::validation::{IssueValidator, LabelValidator};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::str::FromStr;
+use tracing::{debug, info};
+
+/// Source column names for each beads field.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ColumnMapping {
+    /// Stable source identifier, stored as `external_ref`
+    key: Option<String>,
+    title: Option<String>,
+    description: Option<String>,
+    status: Option<String>,
+    priority: Option<String>,
+    issue_type: Option<String>,
+    assignee: Option<String>,
+    /// Comma-separated within a cell; repeated columns are combined
+    labels: Option<String>,
+}
+
+/// Field mapping for one import source.
+///
+/// Value maps are matched case-insensitively. Values missing from the status
+/// and priority maps fall back to parsing the raw value as a beads status or
+/// priority, then to the defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ImportMapping {
+    columns: ColumnMapping,
+    status: BTreeMap<String, String>,
+    priority: BTreeMap<String, i32>,
+    types: BTreeMap<String, String>,
+}
+
+impl ImportMapping {
+    /// Built-in mapping for a source.
+    #[allow(clippy::too_many_lines)]
+    fn builtin(source: ImportSource) -> Self {
+        let columns = |key: &str,
+                       title: &str,
+                       description: &str,
+                       status: &str,
+                       priority: Option<&str>,
+                       issue_type: Option<&str>,
+                       assignee: &str,
+                       labels: &str| ColumnMapping {
+            key: Some(key.to_string()),
+            title: Some(title.to_string()),
+            description: Some(description.to_string()),
+            status: Some(status.to_string()),
+            priority: priority.map(str::to_string),
+            issue_type: issue_type.map(str::to_string),
+            assignee: Some(assignee.to_string()),
+            labels: Some(labels.to_string()),
+        };
+        let map = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(from, to)| (from.to_lowercase(), (*to).to_string()))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let scale = |pairs: &[(&str, i32)]| {
+            pairs
+                .iter()
+                .map(|(from, to)| (from.to_lowercase(), *to))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        match source {
+            ImportSource::JiraCsv => Self {
+                columns: columns(
+                    "Issue key",
+                    "Summary",
+                    "Description",
+                    "Status",
+                    Some("Priority"),
+                    Some("Issue Type"),
+                    "Assignee",
+                    "Labels",
+                ),
+                status: map(&[
+                    ("To Do", "open"),
+                    ("Open", "open"),
+                    ("Backlog", "open"),
+                    ("Selected for Development", "open"),
+                    ("Reopened", "open"),
+                    ("In Progress", "in_progress"),
+                    ("In Review", "in_progress"),
+                    ("Blocked", "blocked"),
+                    ("Done", "closed"),
+                    ("Closed", "closed"),
+                    ("Resolved", "closed"),
+                ]),
+                priority: scale(&[
+                    ("Highest", 0),
+                    ("Blocker", 0),
+                    ("High", 1),
+                    ("Critical", 1),
+                    ("Medium", 2),
+                    ("Major", 2),
+                    ("Low", 3),
+                    ("Minor", 3),
+                    ("Lowest", 4),
+                    ("Trivial", 4),
+                ]),
+                types: map(&[
+                    ("Bug", "bug"),
+                    ("Story", "feature"),
+                    ("New Feature", "feature"),
+                    ("Improvement", "feature"),
+                    ("Task", "task"),
+                    ("Sub-task", "task"),
+                    ("Subtask", "task"),
+                    ("Epic", "epic"),
+                ]),
+            },
+            ImportSource::GithubCsv => Self {
+                columns: columns(
+                    "URL",
+                    "Title",
+                    "Body",
+                    "State",
+                    None,
+                    None,
+                    "Assignees",
+                    "Labels",
+                ),
+                status: map(&[("Open", "open"), ("Closed", "closed")]),
+                priority: BTreeMap::new(),
+                types: BTreeMap::new(),
+            },
+            ImportSource::LinearCsv => Self {
+                columns: columns(
+                    "ID",
+                    "Title",
+                    "Description",
+                    "Status",
+                    Some("Priority"),
+                    None,
+                    "Assignee",
+                    "Labels",
+                ),
+                status: map(&[
+                    ("Triage", "open"),
+                    ("Backlog", "open"),
+                    ("Todo", "open"),
+                    ("In Progress", "in_progress"),
+                    ("In Review", "in_progress"),
+                    ("Done", "closed"),
+                    ("Canceled", "closed"),
+                    ("Cancelled", "closed"),
+                    ("Duplicate", "closed"),
+                ]),
+                priority: scale(&[("Urgent", 0), ("High", 1), ("Medium", 2), ("Low", 3)]),
+                types: BTreeMap::new(),
+            },
+        }
+    }
+
+    /// Apply overrides from a user mapping on top of this one.
+    fn merge(&mut self, other: Self) {
+        fn overlay(base: &mut Option<String>, value: Option<String>) {
+            if value.is_some() {
+                *base = value;
+            }
+        }
+        let (base, columns) = (&mut self.columns, other.columns);
+        overlay(&mut base.key, columns.key);
+        overlay(&mut base.title, columns.title);
+        overlay(&mut base.description, columns.description);
+        overlay(&mut base.status, columns.status);
+        overlay(&mut base.priority, columns.priority);
+        overlay(&mut base.issue_type, columns.issue_type);
+        overlay(&mut base.assignee, columns.assignee);
+        overlay(&mut base.labels, columns.labels);
+        self.status.extend(lowercase_keys(other.status));
+        self.priority.extend(lowercase_keys(other.priority));
+        self.types.extend(lowercase_keys(other.types));
+    }
+
+    /// Check that mapped values are valid beads values.
+    fn validate(&self) -> Result<()> {
+        for value in self.status.values() {
+            Status::from_str(value)?;
+        }
+        for value in self.priority.values() {
+            if !(0..=4).contains(value) {
+                return Err(BeadsError::InvalidPriority { priority: *value });
+            }
+        }
+        if self.columns.key.is_none() || self.columns.title.is_none() {
+            return Err(BeadsError::validation(
+                "mapping",
+                "columns.key and columns.title are required",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Value maps are keyed by lowercased source value for case-insensitive lookup.
+fn lowercase_keys<V>(map: BTreeMap<String, V>) -> BTreeMap<String, V> {
+    map.into_iter()
+        .map(|(from, to)| (from.to_lowercase(), to))
+        .collect()
+}
+
+fn lookup<'a, V>(map: &'a BTreeMap<String, V>, value: &str) -> Option<&'a V> {
+    map.get(&value.to_lowercase())
+}
+
+/// One CSV record converted to beads fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ImportedRow {
+    key: String,
+    title: String,
+    description: Option<String>,
+    status: Status,
+    priority: Priority,
+    issue_type: IssueType,
+    assignee: Option<String>,
+    labels: Vec<String>,
+}
+
+/// Resolves CSV records against a mapping and header row.
+struct RowMapper<'a> {
+    mapping: &'a ImportMapping,
+    header: &'a [String],
+    default_priority: Priority,
+    default_issue_type: IssueType,
+}
+
+impl RowMapper<'_> {
+    /// Value of the first column named `name`, trimmed; empty becomes `None`.
+    fn cell<'r>(&self, record: &'r [String], name: Option<&String>) -> Option<&'r str> {
+        let name = name?;
+        let index = self.header.iter().position(|h| h.trim() == name)?;
+        record
+            .get(index)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+
+    fn map(&self, record: &[String], warnings: &mut Vec<String>) -> Result<ImportedRow> {
+        let columns = &self.mapping.columns;
+        let key = self
+            .cell(record, columns.key.as_ref())
+            .ok_or_else(|| BeadsError::validation("key", "missing source key"))?
+            .to_string();
+        let title = self
+            .cell(record, columns.title.as_ref())
+            .ok_or_else(|| BeadsError::validation("title", "missing title"))?
+            .to_string();
+
+        let status = match self.cell(record, columns.status.as_ref()) {
+            None => Status::Open,
+            Some(raw) => match lookup(&self.mapping.status, raw) {
+                Some(mapped) => Status::from_str(mapped)?,
+                None => Status::from_str(&raw.replace(' ', "_")).unwrap_or_else(|_| {
+                    warnings.push(format!("{key}: unmapped status '{raw}', using open"));
+                    Status::Open
+                }),
+            },
+        };
+
+        let priority = match self.cell(record, columns.priority.as_ref()) {
+            None => self.default_priority,
+            Some(raw) => match lookup(&self.mapping.priority, raw) {
+                Some(mapped) => Priority(*mapped),
+                None => Priority::from_str(raw).unwrap_or_else(|_| {
+                    warnings.push(format!(
+                        "{key}: unmapped priority '{raw}', using {}",
+                        self.default_priority
+                    ));
+                    self.default_priority
+                }),
+            },
+        };
+
+        let issue_type = match self.cell(record, columns.issue_type.as_ref()) {
+            None => self.default_issue_type.clone(),
+            Some(raw) => match lookup(&self.mapping.types, raw) {
+                Some(mapped) => IssueType::from_str(mapped)?,
+                None => match IssueType::from_str(raw)? {
+                    IssueType::Custom(_) => {
+                        warnings.push(format!(
+                            "{key}: unmapped type '{raw}', using {}",
+                            self.default_issue_type.as_str()
+                        ));
+                        self.default_issue_type.clone()
+                    }
+                    standard => standard,
+                },
+            },
+        };
+
+        // Multi-assignee sources (GitHub) keep the first assignee
+        let assignee = self
+            .cell(record, columns.assignee.as_ref())
+            .and_then(|raw| raw.split(',').map(str::trim).find(|a| !a.is_empty()))
+            .map(str::to_string);
+
+        // Jira repeats the Labels column once per label
+        let mut labels: Vec<String> = Vec::new();
+        if let Some(name) = &columns.labels {
+            for (index, _) in self
+                .header
+                .iter()
+                .enumerate()
+                .filter(|(_, h)| h.trim() == name)
+            {
+                let cell = record.get(index).map_or("", String::as_str);
+                for label in cell.split(',').map(str::trim).filter(|l| !l.is_empty()) {
+                    if let Err(err) = LabelValidator::validate(label) {
+                        warnings.push(format!(
+                            "{key}: skipping invalid label '{label}': {}",
+                            err.message
+                        ));
+                    } else if !labels.iter().any(|l| l == label) {
+                        labels.push(label.to_string());
+                    }
+                }
+            }
+        }
+
+        Ok(ImportedRow {
+            key,
+            title,
+            description: self
+                .cell(record, columns.description.as_ref())
+                .map(str::to_string),
+            status,
+            priority,
+            issue_type,
+            assignee,
+            labels,
+        })
+    }
+}
+
+/// Per-row outcome reported by the import command.
+#[derive(Debug, Serialize)]
+struct ImportedIssue {
+    id: String,
+    external_ref: String,
+    title: String,
+}
+
+/// A row that could not be imported.
+#[derive(Debug, Serialize)]
+struct SkippedRow {
+    /// 1-based record number, counting the header as record 1
+    row: usize,
+    reason: String,
+}
+
+/// JSON output for the import command.
+#[derive(Debug, Serialize)]
+struct ImportSummary {
+    source: &'static str,
+    file: String,
+    dry_run: bool,
+    created: Vec<ImportedIssue>,
+    updated: Vec<ImportedIssue>,
+    unchanged: usize,
+    skipped: Vec<SkippedRow>,
+    warnings: Vec<String>,
+}
+
+/// Execute the import command.
+///
+/// # Errors
+///
+/// Returns an error if the file or mapping cannot be read or parsed, the
+/// mapping is invalid, or the database cannot be opened.
+#[allow(clippy::too_many_lines)]
+pub fn execute(
+    args: &ImportArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let source_system = args.from.source_system();
+    let mut mapping = ImportMapping::builtin(args.from);
+    if let Some(path) = &args.mapping {
+        let overrides: ImportMapping = serde_yaml::from_str(&fs::read_to_string(path)?)?;
+        mapping.merge(overrides);
+    }
+    mapping.validate()?;
+
+    let records = parse_csv(&fs::read_to_string(&args.file)?)?;
+    let Some((header, records)) = records.split_first() else {
+        return Err(BeadsError::validation("file", "CSV file is empty"));
+    };
+    for (field, column) in [
+        ("key", &mapping.columns.key),
+        ("title", &mapping.columns.title),
+    ] {
+        if let Some(column) = column
+            .as_ref()
+            .filter(|c| !header.iter().any(|h| h.trim() == *c))
+        {
+            return Err(BeadsError::validation(
+                field,
+                format!("column '{column}' not found in CSV header"),
+            ));
+        }
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let id_gen = IdGenerator::new(config::id_config_from_layer(&layer));
+    let actor = config::actor(&layer);
+    let mapper = RowMapper {
+        mapping: &mapping,
+        header,
+        default_priority: config::default_priority_from_layer(&layer)?,
+        default_issue_type: config::default_issue_type_from_layer(&layer)?,
+    };
+    let now = Utc::now();
+
+    let mut summary = ImportSummary {
+        source: source_system,
+        file: args.file.display().to_string(),
+        dry_run: args.dry_run,
+        created: Vec::new(),
+        updated: Vec::new(),
+        unchanged: 0,
+        skipped: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    // Issues a dry run would have written, by key, so a key repeated later
+    // in the file is reported the way a real run would handle it.
+    let mut planned: HashMap<String, Issue> = HashMap::new();
+
+    let mut run = |storage: &mut dyn Storage| -> Result<()> {
+        for (index, record) in records.iter().enumerate() {
+            let row_number = index + 2;
+            let row = match mapper.map(record, &mut summary.warnings) {
+                Ok(row) => row,
+                Err(err) => {
+                    summary.skipped.push(SkippedRow {
+                        row: row_number,
+                        reason: err.to_string(),
+                    });
+                    continue;
+                }
+            };
+            let outcome = ImportedIssue {
+                id: String::new(),
+                external_ref: row.key.clone(),
+                title: row.title.clone(),
+            };
+
+            let existing = match planned.get(&row.key) {
+                Some(issue) => Some(issue.clone()),
+                None => match storage.find_by_external_ref(&row.key)? {
+                    Some(mut issue) => {
+                        issue.labels = storage.get_labels(&issue.id)?;
+                        Some(issue)
+                    }
+                    None => None,
+                },
+            };
+
+            if let Some(existing) = existing {
+                // external_ref is unique, so an issue from another source
+                // with the same key cannot be created alongside it
+                if existing.source_system.as_deref() != Some(source_system) {
+                    summary.skipped.push(SkippedRow {
+                        row: row_number,
+                        reason: format!(
+                            "external_ref '{}' already belongs to {} (source: {})",
+                            row.key,
+                            existing.id,
+                            existing.source_system.as_deref().unwrap_or("none")
+                        ),
+                    });
+                    continue;
+                }
+                let new_labels: Vec<&String> = row
+                    .labels
+                    .iter()
+                    .filter(|label| !existing.labels.contains(label))
+                    .collect();
+                let update = update_for(&existing, &row, now);
+                if update.is_empty() && new_labels.is_empty() {
+                    summary.unchanged += 1;
+                    continue;
+                }
+                if args.dry_run {
+                    let mut next = existing.clone();
+                    apply_row(&mut next, &row);
+                    planned.insert(row.key.clone(), next);
+                } else {
+                    if !update.is_empty() {
+                        storage.update_issue(&existing.id, &update, &actor)?;
+                    }
+                    for label in new_labels {
+                        storage.add_label(&existing.id, label, &actor)?;
+                    }
+                }
+                debug!(id = %existing.id, key = %row.key, "Updated imported issue");
+                summary.updated.push(ImportedIssue {
+                    id: existing.id,
+                    ..outcome
+                });
+                continue;
+            }
+
+            let id = id_gen.generate(
+                &row.title,
+                row.description.as_deref(),
+                None,
+                now,
+                storage.count_issues()?,
+                |id| {
+                    storage.id_exists(id).unwrap_or(false)
+                        || planned.values().any(|issue| issue.id == id)
+                },
+            );
+            let mut issue = Issue {
+                id: id.clone(),
+                title: row.title,
+                description: row.description,
+                closed_at: row.status.is_terminal().then_some(now),
+                status: row.status,
+                priority: row.priority,
+                issue_type: row.issue_type,
+                assignee: row.assignee,
+                created_at: now,
+                updated_at: now,
+                created_by: Some(actor.clone()),
+                external_ref: Some(row.key),
+                source_system: Some(source_system.to_string()),
+                labels: row.labels,
+                ..Issue::default()
+            };
+            issue.content_hash = Some(issue.compute_content_hash());
+            if let Err(err) =
+                IssueValidator::validate(&issue).map_err(BeadsError::from_validation_errors)
+            {
+                summary.skipped.push(SkippedRow {
+                    row: row_number,
+                    reason: err.to_string(),
+                });
+                continue;
+            }
+            debug!(id = %id, key = ?issue.external_ref, "Created imported issue");
+            if args.dry_run {
+                planned.insert(outcome.external_ref.clone(), issue);
+            } else {
+                storage.create_issue(&issue, &actor)?;
+            }
+            summary.created.push(ImportedIssue { id, ..outcome });
+        }
+        Ok(())
+    };
+    // One transaction, so a failed write leaves none of the file imported;
+    // with --no-db nothing is saved unless the command succeeds
+    match &mut storage_ctx.storage {
+        Backend::Sqlite(storage) if !args.dry_run => {
+            storage.transaction(|storage| run(storage))?;
+        }
+        storage => run(&mut **storage)?,
+    }
+
+    info!(
+        source = source_system,
+        created = summary.created.len(),
+        updated = summary.updated.len(),
+        unchanged = summary.unchanged,
+        skipped = summary.skipped.len(),
+        "Imported issues"
+    );
+
+    if ctx.is_json() {
+        ctx.json_pretty(&summary);
+        return Ok(());
+    }
+    for warning in &summary.warnings {
+        eprintln!("warning: {warning}");
+    }
+    for skipped in &summary.skipped {
+        eprintln!("✗ Skipped row {}: {}", skipped.row, skipped.reason);
+    }
+    if !ctx.is_quiet() {
+        let verb = if args.dry_run {
+            "Would import"
+        } else {
+            "Imported"
+        };
+        println!(
+            "\u{2713} {verb} from {} ({source_system}): {} created, {} updated, {} unchanged, {} skipped",
+            summary.file,
+            summary.created.len(),
+            summary.updated.len(),
+            summary.unchanged,
+            summary.skipped.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Bring an in-memory issue in line with an imported row, as
+/// [`update_for`] and the label additions would in the database.
+fn apply_row(issue: &mut Issue, row: &ImportedRow) {
+    issue.title.clone_from(&row.title);
+    issue.description.clone_from(&row.description);
+    issue.status = row.status.clone();
+    issue.priority = row.priority;
+    issue.issue_type = row.issue_type.clone();
+    issue.assignee.clone_from(&row.assignee);
+    for label in &row.labels {
+        if !issue.labels.contains(label) {
+            issue.labels.push(label.clone());
+        }
+    }
+}
+
+/// Build the update that brings `existing` in line with an imported row.
+fn update_for(existing: &Issue, row: &ImportedRow, now: DateTime<Utc>) -> IssueUpdate {
+    let mut update = IssueUpdate::default();
+    if existing.title != row.title {
+        update.title = Some(row.title.clone());
+    }
+    if existing.description != row.description {
+        update.description = Some(row.description.clone());
+    }
+    if existing.status != row.status {
+        update.status = Some(row.status.clone());
+        if row.status.is_terminal() && !existing.status.is_terminal() {
+            update.closed_at = Some(Some(now));
+        } else if !row.status.is_terminal() && existing.status.is_terminal() {
+            update.closed_at = Some(None);
+        }
+    }
+    if existing.priority != row.priority {
+        update.priority = Some(row.priority);
+    }
+    if existing.issue_type != row.issue_type {
+        update.issue_type = Some(row.issue_type.clone());
+    }
+    if existing.assignee != row.assignee {
+        update.assignee = Some(row.assignee.clone());
+    }
+    update
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    fn map_row(
+        mapping: &ImportMapping,
+        header: &[&str],
+        record: &[&str],
+    ) -> (Result<ImportedRow>, Vec<String>) {
+        let header = strings(header);
+        let mapper = RowMapper {
+            mapping,
+            header: &header,
+            default_priority: Priority::MEDIUM,
+            default_issue_type: IssueType::Task,
+        };
+        let mut warnings = Vec::new();
+        let row = mapper.map(&strings(record), &mut warnings);
+        (row, warnings)
+    }
+
+    #[test]
+    fn jira_row_maps_status_priority_type_and_repeated_labels() {
+        let mapping = ImportMapping::builtin(ImportSource::JiraCsv);
+        let (row, warnings) = map_row(
+            &mapping,
+            &[
+                "Summary",
+                "Issue key",
+                "Issue Type",
+                "Status",
+                "Priority",
+                "Labels",
+                "Labels",
+            ],
+            &[
+                "Fix login",
+                "PROJ-7",
+                "Story",
+                "in progress",
+                "Highest",
+                "auth",
+                "ui",
+            ],
+        );
+        let row = row.unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(row.key, "PROJ-7");
+        assert_eq!(row.status, Status::InProgress);
+        assert_eq!(row.priority, Priority(0));
+        assert_eq!(row.issue_type, IssueType::Feature);
+        assert_eq!(row.labels, vec!["auth", "ui"]);
+    }
+
+    #[test]
+    fn unmapped_values_fall_back_with_warnings() {
+        let mapping = ImportMapping::builtin(ImportSource::LinearCsv);
+        let (row, warnings) = map_row(
+            &mapping,
+            &["ID", "Title", "Status", "Priority"],
+            &["ENG-1", "Thing", "Someday", "No priority"],
+        );
+        let row = row.unwrap();
+        assert_eq!(row.status, Status::Open);
+        assert_eq!(row.priority, Priority::MEDIUM);
+        assert_eq!(warnings.len(), 2);
+    }
+
+    #[test]
+    fn missing_key_is_an_error() {
+        let mapping = ImportMapping::builtin(ImportSource::GithubCsv);
+        let (row, _) = map_row(&mapping, &["URL", "Title"], &["", "No key"]);
+        assert!(row.is_err());
+    }
+
+    #[test]
+    fn yaml_overrides_merge_onto_builtin() {
+        let mut mapping = ImportMapping::builtin(ImportSource::JiraCsv);
+        let overrides: ImportMapping = serde_yaml::from_str(
+            "columns:\n  title: Headline\nstatus:\n  QA: in_progress\npriority:\n  P1: 1\n",
+        )
+        .unwrap();
+        mapping.merge(overrides);
+        mapping.validate().unwrap();
+        assert_eq!(mapping.columns.title.as_deref(), Some("Headline"));
+        assert_eq!(mapping.columns.key.as_deref(), Some("Issue key"));
+        assert_eq!(
+            lookup(&mapping.status, "qa").map(String::as_str),
+            Some("in_progress")
+        );
+        assert_eq!(
+            lookup(&mapping.status, "done").map(String::as_str),
+            Some("closed")
+        );
+    }
+
+    #[test]
+    fn update_only_touches_changed_fields() {
+        let existing = Issue {
+            id: "bd-1".to_string(),
+            title: "Same".to_string(),
+            status: Status::Open,
+            ..Issue::default()
+        };
+        let row = ImportedRow {
+            key: "PROJ-1".to_string(),
+            title: "Same".to_string(),
+            description: None,
+            status: Status::Closed,
+            priority: existing.priority,
+            issue_type: existing.issue_type.clone(),
+            assignee: None,
+            labels: Vec::new(),
+        };
+        let update = update_for(&existing, &row, Utc::now());
+        assert!(update.title.is_none());
+        assert_eq!(update.status, Some(Status::Closed));
+        assert!(matches!(update.closed_at, Some(Some(_))));
+    }
+}
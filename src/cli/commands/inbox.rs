@@ -0,0 +1,416 @@
+//! Inbox command implementation.
+//!
+//! `br inbox` lists the issues where an actor (the global `--actor`, with
+//! `me` meaning the usual actor) was `@mentioned` in a comment or assigned by
+//! someone else, and what others did on issues the actor watches (`br
+//! watch`) since they started watching. Items newer than the actor's read mark are
+//! unread; listing them moves the mark forward (unless `--peek`), so under
+//! `--read-only` only `--peek` runs. The mark is kept per actor in the
+//! database metadata, so it stays local to this clone.
+
+use crate::cli::InboxArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::truncate_title;
+use crate::model::{Comment, Event, EventType, Issue};
+use crate::output::OutputContext;
+use crate::storage::ListFilters;
+use crate::util::parse_mentions;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// Metadata key prefix for the per-actor read mark.
+const READ_MARK_PREFIX: &str = "inbox_read_at:";
+
+/// Comment text shown in text output before it is cut off.
+const COMMENT_PREVIEW_WIDTH: usize = 60;
+
+/// Why an issue is in the inbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum InboxKind {
+    Mention,
+    Assigned,
+    Watched,
+}
+
+/// One inbox entry.
+#[derive(Debug, Clone, Serialize)]
+struct InboxItem {
+    at: DateTime<Utc>,
+    kind: InboxKind,
+    issue_id: String,
+    title: String,
+    /// Who mentioned or assigned the actor, or acted on a watched issue
+    by: String,
+    /// The event type, for watched issues
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event: Option<String>,
+    /// The comment, for mentions; what changed, for watched issues
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    read: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct InboxOutput {
+    actor: String,
+    unread: usize,
+    items: Vec<InboxItem>,
+}
+
+/// Execute the inbox command.
+///
+/// # Errors
+///
+/// Returns an error if database operations fail.
+pub fn execute(args: &InboxArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    if !args.peek && storage_ctx.storage.is_read_only() {
+        return Err(BeadsError::ReadOnly {
+            operation: "inbox read mark".to_string(),
+        });
+    }
+    // `--actor` picks whose inbox to show; `me` means the usual actor
+    let actor = match cli.actor.as_deref().map(str::trim) {
+        Some(name) if !name.is_empty() && name != "me" => name.to_string(),
+        _ => {
+            let own = config::CliOverrides {
+                actor: None,
+                ..cli.clone()
+            };
+            config::actor(&config::load_config(
+                &beads_dir,
+                Some(&*storage_ctx.storage),
+                &own,
+            )?)
+        }
+    };
+    let storage = &mut *storage_ctx.storage;
+
+    let read_key = format!("{READ_MARK_PREFIX}{}", actor.to_lowercase());
+    let read_mark = storage
+        .get_metadata(&read_key)?
+        .and_then(|value| DateTime::parse_from_rfc3339(&value).ok())
+        .map(|at| at.with_timezone(&Utc));
+
+    let issues = storage.list_issues(&ListFilters {
+        include_closed: true,
+        include_deferred: true,
+        ..ListFilters::default()
+    })?;
+    let mut items = collect_items(
+        &actor,
+        &issues,
+        &storage.get_comments_mentioning(&actor)?,
+        &storage.get_all_events(0)?,
+        &storage.get_watched_issues(&actor)?,
+        read_mark,
+    );
+    let unread = items.iter().filter(|item| !item.read).count();
+    let newest = items.iter().map(|item| item.at).max();
+    if !args.all {
+        items.retain(|item| !item.read);
+    }
+
+    if !args.peek && unread > 0 {
+        if let Some(newest) = newest {
+            storage.set_metadata(&read_key, &newest.to_rfc3339())?;
+        }
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&InboxOutput {
+            actor,
+            unread,
+            items,
+        });
+    } else if !ctx.is_quiet() {
+        print_inbox(&actor, unread, &items);
+    }
+    Ok(())
+}
+
+/// Mentions by others, assignments by others and others' events on watched
+/// issues, newest first.
+#[allow(clippy::too_many_lines)]
+fn collect_items(
+    actor: &str,
+    issues: &[Issue],
+    mentions: &[Comment],
+    events: &[Event],
+    watched: &[(String, DateTime<Utc>)],
+    read_mark: Option<DateTime<Utc>>,
+) -> Vec<InboxItem> {
+    let titles: HashMap<&str, &str> = issues
+        .iter()
+        .map(|issue| (issue.id.as_str(), issue.title.as_str()))
+        .collect();
+    let is_actor = |name: &str| name.eq_ignore_ascii_case(actor);
+    let item = |at: DateTime<Utc>, kind, issue_id: &str, by: &str, text: Option<&str>| InboxItem {
+        at,
+        kind,
+        issue_id: issue_id.to_string(),
+        title: titles
+            .get(issue_id)
+            .copied()
+            .unwrap_or_default()
+            .to_string(),
+        by: by.to_string(),
+        event: None,
+        text: text.map(str::to_string),
+        read: read_mark.is_some_and(|mark| at <= mark),
+    };
+
+    let mut items: Vec<InboxItem> = mentions
+        .iter()
+        .filter(|comment| !is_actor(&comment.author))
+        .map(|comment| {
+            item(
+                comment.created_at,
+                InboxKind::Mention,
+                &comment.issue_id,
+                &comment.author,
+                Some(&comment.body),
+            )
+        })
+        .collect();
+
+    let assignment_events: Vec<&Event> = events
+        .iter()
+        .filter(|event| event.event_type == EventType::AssigneeChanged)
+        .collect();
+    items.extend(
+        assignment_events
+            .iter()
+            .filter(|event| {
+                event.new_value.as_deref().is_some_and(is_actor) && !is_actor(&event.actor)
+            })
+            .map(|event| {
+                item(
+                    event.created_at,
+                    InboxKind::Assigned,
+                    &event.issue_id,
+                    &event.actor,
+                    None,
+                )
+            }),
+    );
+    // Issues created already assigned have no assignee_changed event
+    let reassigned: HashSet<&str> = assignment_events
+        .iter()
+        .map(|event| event.issue_id.as_str())
+        .collect();
+    items.extend(
+        issues
+            .iter()
+            .filter(|issue| issue.assignee.as_deref().is_some_and(is_actor))
+            .filter(|issue| !reassigned.contains(issue.id.as_str()))
+            .filter_map(|issue| {
+                let creator = issue.created_by.as_deref()?;
+                (!is_actor(creator)).then(|| {
+                    item(
+                        issue.created_at,
+                        InboxKind::Assigned,
+                        &issue.id,
+                        creator,
+                        None,
+                    )
+                })
+            }),
+    );
+
+    // Events on watched issues that are not already listed as a mention or
+    // an assignment
+    let watched_since: HashMap<&str, DateTime<Utc>> = watched
+        .iter()
+        .map(|(issue_id, since)| (issue_id.as_str(), *since))
+        .collect();
+    let actor_lower = actor.to_lowercase();
+    items.extend(
+        events
+            .iter()
+            .filter(|event| {
+                watched_since
+                    .get(event.issue_id.as_str())
+                    .is_some_and(|since| event.created_at >= *since)
+            })
+            .filter(|event| !is_actor(&event.actor))
+            .filter(|event| match event.event_type {
+                EventType::AssigneeChanged => !event.new_value.as_deref().is_some_and(is_actor),
+                EventType::Commented => !event
+                    .comment
+                    .as_deref()
+                    .is_some_and(|text| parse_mentions(text).contains(&actor_lower)),
+                _ => true,
+            })
+            .map(|event| {
+                let detail = match (&event.old_value, &event.new_value, &event.comment) {
+                    (Some(old), Some(new), _) => Some(format!("{old} -> {new}")),
+                    (None, Some(new), _) => Some(new.clone()),
+                    (_, _, comment) => comment.clone().filter(|text| !text.is_empty()),
+                };
+                InboxItem {
+                    event: Some(event.event_type.as_str().to_string()),
+                    ..item(
+                        event.created_at,
+                        InboxKind::Watched,
+                        &event.issue_id,
+                        &event.actor,
+                        detail.as_deref(),
+                    )
+                }
+            }),
+    );
+
+    items.sort_by(|a, b| b.at.cmp(&a.at).then_with(|| a.issue_id.cmp(&b.issue_id)));
+    items
+}
+
+fn print_inbox(actor: &str, unread: usize, items: &[InboxItem]) {
+    println!("Inbox for {actor}: {unread} unread");
+    for item in items {
+        let marker = if item.read { ' ' } else { '*' };
+        let what = match item.kind {
+            InboxKind::Mention => {
+                let text = item.text.as_deref().unwrap_or("");
+                format!(
+                    "mentioned by {}: {}",
+                    item.by,
+                    truncate_title(text.lines().next().unwrap_or(""), COMMENT_PREVIEW_WIDTH)
+                )
+            }
+            InboxKind::Assigned => format!("assigned by {}", item.by),
+            InboxKind::Watched => {
+                let event = item.event.as_deref().unwrap_or("updated").replace('_', " ");
+                match item.text.as_deref().and_then(|text| text.lines().next()) {
+                    Some(detail) => format!(
+                        "{event} by {}: {}",
+                        item.by,
+                        truncate_title(detail, COMMENT_PREVIEW_WIDTH)
+                    ),
+                    None => format!("{event} by {}", item.by),
+                }
+            }
+        };
+        println!(
+            "{marker} {}  {:<10} {}  \"{}\"",
+            item.at.format("%Y-%m-%d %H:%M"),
+            item.issue_id,
+            what,
+            truncate_title(&item.title, 40)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 5, 1, 12, minute, 0).unwrap()
+    }
+
+    fn issue(id: &str, assignee: Option<&str>, created_by: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            assignee: assignee.map(str::to_string),
+            created_by: Some(created_by.to_string()),
+            created_at: at(0),
+            ..Issue::default()
+        }
+    }
+
+    fn comment(issue_id: &str, author: &str, minute: u32) -> Comment {
+        Comment {
+            id: i64::from(minute),
+            issue_id: issue_id.to_string(),
+            author: author.to_string(),
+            body: "@alice please look".to_string(),
+            created_at: at(minute),
+            updated_at: None,
+            deleted: false,
+        }
+    }
+
+    fn assigned(issue_id: &str, by: &str, to: &str, minute: u32) -> Event {
+        Event {
+            id: i64::from(minute),
+            issue_id: issue_id.to_string(),
+            event_type: EventType::AssigneeChanged,
+            actor: by.to_string(),
+            old_value: None,
+            new_value: Some(to.to_string()),
+            comment: None,
+            created_at: at(minute),
+            session_id: None,
+        }
+    }
+
+    #[test]
+    fn test_collect_items_skips_own_actions_and_marks_read() {
+        let issues = vec![
+            issue("bd-1", Some("alice"), "bob"),
+            issue("bd-2", Some("Alice"), "carol"),
+            issue("bd-3", Some("alice"), "alice"),
+        ];
+        let mentions = vec![comment("bd-1", "bob", 5), comment("bd-1", "alice", 6)];
+        let events = vec![
+            assigned("bd-2", "carol", "alice", 9),
+            assigned("bd-3", "alice", "alice", 10),
+        ];
+
+        let items = collect_items("alice", &issues, &mentions, &events, &[], Some(at(5)));
+        let summary: Vec<(InboxKind, &str, bool)> = items
+            .iter()
+            .map(|item| (item.kind, item.issue_id.as_str(), item.read))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (InboxKind::Assigned, "bd-2", false),
+                (InboxKind::Mention, "bd-1", true),
+                (InboxKind::Assigned, "bd-1", true),
+            ]
+        );
+        assert_eq!(items[1].by, "bob");
+    }
+
+    #[test]
+    fn test_collect_items_reports_others_events_on_watched_issues() {
+        let issues = vec![issue("bd-1", None, "bob")];
+        let mut status = assigned("bd-1", "bob", "", 20);
+        status.event_type = EventType::StatusChanged;
+        status.old_value = Some("open".to_string());
+        status.new_value = Some("in_progress".to_string());
+        let mut mention = assigned("bd-1", "bob", "", 21);
+        mention.event_type = EventType::Commented;
+        mention.new_value = None;
+        mention.comment = Some("@alice please look".to_string());
+        let events = vec![
+            assigned("bd-1", "carol", "dave", 5),
+            status,
+            mention,
+            assigned("bd-1", "carol", "alice", 22),
+            assigned("bd-1", "alice", "bob", 23),
+        ];
+        let watched = vec![("bd-1".to_string(), at(10))];
+
+        let items = collect_items("alice", &issues, &[], &events, &watched, None);
+        let watched: Vec<(Option<&str>, Option<&str>)> = items
+            .iter()
+            .filter(|item| item.kind == InboxKind::Watched)
+            .map(|item| (item.event.as_deref(), item.text.as_deref()))
+            .collect();
+        // Before watching, own events, the mention and the assignment are left out
+        assert_eq!(
+            watched,
+            vec![(Some("status_changed"), Some("open -> in_progress"))]
+        );
+        assert!(items.iter().any(|item| item.kind == InboxKind::Assigned));
+    }
+}
@@ -1,15 +1,25 @@
 //! Info command implementation.
+//!
+//! Reports where the workspace lives and what state it is in: database and
+//! JSONL paths and sizes, when the JSONL was last written, issue counts by
+//! status, the effective prefix, the schema version, the last export/import
+//! times, and which config file (or the DB, environment or CLI) supplied each
+//! explicitly set config key.
 
 use crate::cli::InfoArgs;
+use crate::cli::commands::config::config_origins;
 use crate::config;
 use crate::error::Result;
+use crate::format::time::relative;
 use crate::output::OutputContext;
-use crate::storage::SqliteStorage;
+use crate::storage::Storage;
 use crate::storage::schema::CURRENT_SCHEMA_VERSION;
+use crate::sync::{METADATA_LAST_EXPORT_TIME, METADATA_LAST_IMPORT_TIME};
 use crate::util::parse_id;
+use chrono::{DateTime, Utc};
 use rich_rust::prelude::*;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
 const SCHEMA_TABLES: &[&str] = &[
@@ -60,6 +70,23 @@ struct InfoOutput {
     jsonl_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     jsonl_size: Option<u64>,
+    /// When the JSONL file was last written (absent if it does not exist yet)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jsonl_modified_at: Option<DateTime<Utc>>,
+    /// Issue counts per status
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    issue_counts: BTreeMap<String, usize>,
+    /// Effective issue ID prefix
+    prefix: String,
+    schema_version: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_export_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_import_time: Option<String>,
+    /// Explicitly set config keys and the file (or `db`, `environment`,
+    /// `cli`) that supplied each
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    config_origins: BTreeMap<String, String>,
 }
 
 /// Execute the info command.
@@ -81,16 +108,14 @@ pub fn execute(args: &InfoArgs, cli: &config::CliOverrides, ctx: &OutputContext)
 
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &*storage_ctx.storage;
     let db_path = canonicalize_lossy(&storage_ctx.paths.db_path);
+    let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
 
-    let issue_count = storage_ctx.storage.count_issues().ok();
-    let config_map = storage_ctx
-        .storage
-        .get_all_config()
-        .ok()
-        .filter(|map| !map.is_empty());
+    let issue_count = storage.count_issues().ok();
+    let config_map = storage.get_all_config().ok().filter(|map| !map.is_empty());
     let schema = if args.schema {
-        Some(build_schema_info(&storage_ctx.storage, config_map.as_ref()))
+        Some(build_schema_info(storage, config_map.as_ref()))
     } else {
         None
     };
@@ -99,9 +124,11 @@ pub fn execute(args: &InfoArgs, cli: &config::CliOverrides, ctx: &OutputContext)
     let db_size = std::fs::metadata(&storage_ctx.paths.db_path)
         .map(|m| m.len())
         .ok();
-    let jsonl_size = std::fs::metadata(&storage_ctx.paths.jsonl_path)
-        .map(|m| m.len())
-        .ok();
+    let jsonl_metadata = std::fs::metadata(&storage_ctx.paths.jsonl_path).ok();
+    let jsonl_size = jsonl_metadata.as_ref().map(std::fs::Metadata::len);
+    let jsonl_modified_at = jsonl_metadata
+        .and_then(|m| m.modified().ok())
+        .map(DateTime::<Utc>::from);
 
     let output = InfoOutput {
         database_path: db_path.display().to_string(),
@@ -120,6 +147,17 @@ pub fn execute(args: &InfoArgs, cli: &config::CliOverrides, ctx: &OutputContext)
                 .to_string(),
         ),
         jsonl_size,
+        jsonl_modified_at,
+        issue_counts: storage.count_issues_by_status()?,
+        prefix: config::id_config_from_layer(&config_layer).prefix,
+        // The JSONL-only backend has no schema of its own to lag behind
+        schema_version: match storage_ctx.storage.as_sqlite() {
+            Some(db) => db.schema_version()?,
+            None => CURRENT_SCHEMA_VERSION,
+        },
+        last_export_time: storage.get_metadata(METADATA_LAST_EXPORT_TIME)?,
+        last_import_time: storage.get_metadata(METADATA_LAST_IMPORT_TIME)?,
+        config_origins: config_origins(storage, &beads_dir, cli)?,
     };
 
     if ctx.is_json() {
@@ -137,7 +175,7 @@ pub fn execute(args: &InfoArgs, cli: &config::CliOverrides, ctx: &OutputContext)
 }
 
 fn build_schema_info(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     config_map: Option<&HashMap<String, String>>,
 ) -> SchemaInfo {
     let mut ids = storage.get_all_ids().unwrap_or_default();
@@ -164,7 +202,14 @@ fn build_schema_info(
 
 fn print_human(info: &InfoOutput) {
     println!("Beads Database Information");
-    println!("Database: {}", info.database_path);
+    println!("Workspace: {}", info.beads_dir);
+    match info.db_size {
+        Some(size) => println!("Database: {} ({})", info.database_path, format_bytes(size)),
+        None => println!("Database: {}", info.database_path),
+    }
+    if let Some(jsonl_path) = &info.jsonl_path {
+        println!("JSONL: {jsonl_path} ({})", jsonl_summary(info));
+    }
     println!("Mode: {}", info.mode);
 
     if info.daemon_connected {
@@ -177,12 +222,28 @@ fn print_human(info: &InfoOutput) {
     }
 
     if let Some(count) = info.issue_count {
-        println!("Issue count: {count}");
+        if info.issue_counts.is_empty() {
+            println!("Issue count: {count}");
+        } else {
+            println!("Issue count: {count} ({})", status_breakdown(info));
+        }
     }
-
-    if let Some(config_map) = &info.config {
-        if let Some(prefix) = config_map.get("issue_prefix") {
-            println!("Issue prefix: {prefix}");
+    println!("Issue prefix: {}", info.prefix);
+    println!("Schema version: {}", info.schema_version);
+    println!(
+        "Last export: {}",
+        info.last_export_time.as_deref().unwrap_or("never")
+    );
+    println!(
+        "Last import: {}",
+        info.last_import_time.as_deref().unwrap_or("never")
+    );
+
+    if !info.config_origins.is_empty() {
+        println!();
+        println!("Config origins:");
+        for (key, origin) in &info.config_origins {
+            println!("  {key}: {origin}");
         }
     }
 
@@ -200,6 +261,28 @@ fn print_human(info: &InfoOutput) {
     }
 }
 
+/// "1.2 KB, written 3 minutes ago" or "not written yet".
+fn jsonl_summary(info: &InfoOutput) -> String {
+    match (info.jsonl_size, info.jsonl_modified_at) {
+        (Some(size), Some(modified)) => format!(
+            "{}, written {}",
+            format_bytes(size),
+            relative(modified, Utc::now())
+        ),
+        (Some(size), None) => format_bytes(size),
+        _ => "not written yet".to_string(),
+    }
+}
+
+/// "open 3, closed 2" in status order of the counts map.
+fn status_breakdown(info: &InfoOutput) -> String {
+    info.issue_counts
+        .iter()
+        .map(|(status, count)| format!("{status} {count}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 #[allow(clippy::unnecessary_wraps)]
 fn print_message(ctx: &OutputContext, message: &str, key: &str) -> Result<()> {
     if ctx.is_json() {
@@ -217,6 +300,7 @@ fn print_message(ctx: &OutputContext, message: &str, key: &str) -> Result<()> {
 }
 
 /// Render project info as a rich panel.
+#[allow(clippy::too_many_lines)]
 fn render_info_rich(info: &InfoOutput, ctx: &OutputContext) {
     let console = Console::default();
     let theme = ctx.theme();
@@ -229,14 +313,12 @@ fn render_info_rich(info: &InfoOutput, ctx: &OutputContext) {
     content.append_styled(&info.beads_dir, theme.accent.clone());
     content.append("\n");
 
-    // Prefix (if available)
-    if let Some(config_map) = &info.config {
-        if let Some(prefix) = config_map.get("issue_prefix") {
-            content.append_styled("Prefix      ", theme.dimmed.clone());
-            content.append_styled(prefix, theme.issue_id.clone());
-            content.append("\n");
-        }
-    }
+    content.append_styled("Prefix      ", theme.dimmed.clone());
+    content.append_styled(&info.prefix, theme.issue_id.clone());
+    content.append("\n");
+    content.append_styled("Schema      ", theme.dimmed.clone());
+    content.append(&format!("v{}", info.schema_version));
+    content.append("\n");
 
     content.append("\n");
 
@@ -255,7 +337,14 @@ fn render_info_rich(info: &InfoOutput, ctx: &OutputContext) {
     if let Some(count) = info.issue_count {
         content.append_styled("  Issues    ", theme.dimmed.clone());
         content.append_styled(&format!("{count}"), theme.emphasis.clone());
-        content.append_styled(" total\n", theme.dimmed.clone());
+        content.append_styled(" total", theme.dimmed.clone());
+        if !info.issue_counts.is_empty() {
+            content.append_styled(
+                &format!(" ({})", status_breakdown(info)),
+                theme.muted.clone(),
+            );
+        }
+        content.append("\n");
     }
 
     // JSONL section
@@ -271,8 +360,24 @@ fn render_info_rich(info: &InfoOutput, ctx: &OutputContext) {
             content.append(&format_bytes(size));
             content.append("\n");
         }
+        content.append_styled("  Written   ", theme.dimmed.clone());
+        content.append(&info.jsonl_modified_at.map_or_else(
+            || "not yet".to_string(),
+            |modified| relative(modified, Utc::now()),
+        ));
+        content.append("\n");
     }
 
+    // Sync section
+    content.append("\n");
+    content.append_styled("Sync\n", theme.section.clone());
+    content.append_styled("  Exported  ", theme.dimmed.clone());
+    content.append(info.last_export_time.as_deref().unwrap_or("never"));
+    content.append("\n");
+    content.append_styled("  Imported  ", theme.dimmed.clone());
+    content.append(info.last_import_time.as_deref().unwrap_or("never"));
+    content.append("\n");
+
     // Mode section
     content.append("\n");
     content.append_styled("Mode        ", theme.dimmed.clone());
@@ -307,6 +412,15 @@ fn render_info_rich(info: &InfoOutput, ctx: &OutputContext) {
         }
     }
 
+    if !info.config_origins.is_empty() {
+        content.append("\n");
+        content.append_styled("Config\n", theme.section.clone());
+        for (key, origin) in &info.config_origins {
+            content.append_styled(&format!("  {key}"), theme.emphasis.clone());
+            content.append_styled(&format!("  {origin}\n"), theme.dimmed.clone());
+        }
+    }
+
     let panel = Panel::from_rich_text(&content, width)
         .title(Text::styled(
             "Project Information",
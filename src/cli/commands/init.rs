@@ -1,6 +1,7 @@
 use crate::error::{BeadsError, Result};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::SqliteStorage;
+use crate::sync::shard;
 use crate::util::db_path;
 use rich_rust::prelude::*;
 use std::fs;
@@ -92,14 +93,24 @@ pub fn execute(
 # Temporary
 last-touched
 *.tmp
+
+# Undelivered webhooks
+webhooks-dead.jsonl
+
+# Alert hook state
+alerts-seen.json
+
+# Session started with `br session start`
+current-session
 ";
         fs::write(gitignore_path, gitignore)?;
     }
 
     // Write empty issues.jsonl for compatibility with bv (beads_viewer)
     // bv expects this file to exist even if there are no issues yet
+    // (a sharded export already provides it)
     let jsonl_path = beads_dir.join("issues.jsonl");
-    let jsonl_existed = jsonl_path.exists();
+    let jsonl_existed = shard::jsonl_exists(&jsonl_path);
     if !jsonl_existed {
         fs::write(&jsonl_path, "")?;
     }
@@ -1,15 +1,22 @@
 //! Label command implementation.
 //!
-//! Provides label management: add, remove, list, list-all, and rename.
-
-use crate::cli::{LabelAddArgs, LabelCommands, LabelListArgs, LabelRemoveArgs, LabelRenameArgs};
+//! Provides label management: add, remove, list, list-all, rename, merge, and
+//! describe (label colors and descriptions, see [`crate::config::label_defs`]).
+
+use crate::cli::selection::resolve_selection;
+use crate::cli::{
+    LabelAddArgs, LabelCommands, LabelDescribeArgs, LabelListArgs, LabelMergeArgs, LabelRemoveArgs,
+    LabelRenameArgs,
+};
 use crate::config;
+use crate::config::label_defs::{self, LabelDef};
 use crate::error::{BeadsError, Result};
-use crate::output::{OutputContext, OutputMode};
-use crate::storage::SqliteStorage;
+use crate::output::{OutputContext, OutputMode, Theme};
+use crate::storage::Storage;
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
 use rich_rust::prelude::*;
 use serde::Serialize;
+use std::path::Path;
 use tracing::{debug, info};
 
 /// Execute the label command.
@@ -26,23 +33,30 @@ pub fn execute(
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
     let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?)
+            .with_last_touched(crate::util::get_last_touched_id(&beads_dir)),
+    );
     let all_ids = storage_ctx.storage.get_all_ids()?;
-    let actor = config::resolve_actor(&config_layer);
-    let storage = &mut storage_ctx.storage;
+    let actor = config::actor(&config_layer);
+    let storage = &mut *storage_ctx.storage;
 
     match command {
         LabelCommands::Add(args) => {
-            label_add(args, storage, &resolver, &all_ids, &actor, json, ctx)
-        }
-        LabelCommands::Remove(args) => {
-            label_remove(args, storage, &resolver, &all_ids, &actor, json, ctx)
+            label_add(args, storage, &resolver, &actor, json, ctx)?;
+            let (_, label) = parse_issues_and_label(&args.issues, args.label.as_ref())?;
+            warn_undeclared_labels(&beads_dir, &config_layer, &[label.as_str()], ctx);
+            Ok(())
         }
+        LabelCommands::Remove(args) => label_remove(args, storage, &resolver, &actor, json, ctx),
         LabelCommands::List(args) => label_list(args, storage, &resolver, &all_ids, json, ctx),
         LabelCommands::ListAll => label_list_all(storage, json, ctx),
         LabelCommands::Rename(args) => label_rename(args, storage, &actor, json, ctx),
+        LabelCommands::Merge(args) => label_merge(args, storage, &actor, ctx),
+        LabelCommands::Describe(args) => label_describe(args, &beads_dir, ctx),
     }?;
 
     storage_ctx.flush_no_db_if_dirty()?;
@@ -72,6 +86,14 @@ struct RenameResult {
     affected_issues: usize,
 }
 
+/// JSON output for merge.
+#[derive(Serialize)]
+struct MergeResult {
+    sources: Vec<String>,
+    into: String,
+    affected_issues: usize,
+}
+
 /// Validate a label name.
 ///
 /// Labels must be alphanumeric with dashes and underscores allowed.
@@ -129,9 +151,8 @@ fn parse_issues_and_label(
 
 fn label_add(
     args: &LabelAddArgs,
-    storage: &mut SqliteStorage,
+    storage: &mut dyn Storage,
     resolver: &IdResolver,
-    all_ids: &[String],
     actor: &str,
     _json: bool,
     ctx: &OutputContext,
@@ -142,9 +163,7 @@ fn label_add(
 
     let mut results = Vec::new();
 
-    for input in &issue_inputs {
-        let issue_id = resolve_issue_id(storage, resolver, all_ids, input)?;
-
+    for issue_id in resolve_selection(storage, resolver, &issue_inputs)? {
         info!(issue_id = %issue_id, label = %label, "Adding label");
 
         let added = storage.add_label(&issue_id, &label, actor)?;
@@ -157,7 +176,7 @@ fn label_add(
 
         results.push(LabelActionResult {
             status: if added { "added" } else { "exists" }.to_string(),
-            issue_id: issue_id.clone(),
+            issue_id,
             label: label.clone(),
         });
     }
@@ -187,9 +206,8 @@ fn label_add(
 
 fn label_remove(
     args: &LabelRemoveArgs,
-    storage: &mut SqliteStorage,
+    storage: &mut dyn Storage,
     resolver: &IdResolver,
-    all_ids: &[String],
     actor: &str,
     _json: bool,
     ctx: &OutputContext,
@@ -198,16 +216,14 @@ fn label_remove(
 
     let mut results = Vec::new();
 
-    for input in &issue_inputs {
-        let issue_id = resolve_issue_id(storage, resolver, all_ids, input)?;
-
+    for issue_id in resolve_selection(storage, resolver, &issue_inputs)? {
         info!(issue_id = %issue_id, label = %label, "Removing label");
 
         let removed = storage.remove_label(&issue_id, &label, actor)?;
 
         results.push(LabelActionResult {
             status: if removed { "removed" } else { "not_found" }.to_string(),
-            issue_id: issue_id.clone(),
+            issue_id,
             label: label.clone(),
         });
     }
@@ -237,7 +253,7 @@ fn label_remove(
 
 fn label_list(
     args: &LabelListArgs,
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     _json: bool,
@@ -282,7 +298,7 @@ fn label_list(
     Ok(())
 }
 
-fn label_list_all(storage: &SqliteStorage, _json: bool, ctx: &OutputContext) -> Result<()> {
+fn label_list_all(storage: &dyn Storage, _json: bool, ctx: &OutputContext) -> Result<()> {
     let labels_with_counts = storage.get_unique_labels_with_counts()?;
 
     let label_counts: Vec<LabelCount> = labels_with_counts
@@ -316,7 +332,7 @@ fn label_list_all(storage: &SqliteStorage, _json: bool, ctx: &OutputContext) ->
 
 fn label_rename(
     args: &LabelRenameArgs,
-    storage: &mut SqliteStorage,
+    storage: &mut dyn Storage,
     actor: &str,
     _json: bool,
     ctx: &OutputContext,
@@ -369,8 +385,167 @@ fn label_rename(
     Ok(())
 }
 
+fn label_merge(
+    args: &LabelMergeArgs,
+    storage: &mut dyn Storage,
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    validate_label(&args.into)?;
+
+    info!(sources = ?args.sources, into = %args.into, "Merging labels");
+
+    let sources: Vec<&str> = args.sources.iter().map(String::as_str).collect();
+    let count = storage.merge_labels(&sources, &args.into, actor)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&MergeResult {
+            sources: args.sources.clone(),
+            into: args.into.clone(),
+            affected_issues: count,
+        });
+    } else if count == 0 {
+        println!(
+            "Labels {} not found on any issues.",
+            args.sources.join(", ")
+        );
+    } else {
+        println!(
+            "\u{2713} Merged {} into '{}' on {} issue{}",
+            args.sources.join(", "),
+            args.into,
+            count,
+            if count == 1 { "" } else { "s" }
+        );
+    }
+
+    Ok(())
+}
+
+fn label_describe(args: &LabelDescribeArgs, beads_dir: &Path, ctx: &OutputContext) -> Result<()> {
+    let mut defs = label_defs::load_label_defs(beads_dir)?;
+
+    let Some(label) = &args.label else {
+        if ctx.is_json() {
+            ctx.json_pretty(&defs);
+        } else if defs.is_empty() {
+            println!("No label definitions.");
+        } else {
+            for def in &defs {
+                print_label_def(def);
+            }
+        }
+        return Ok(());
+    };
+
+    if args.clear {
+        let before = defs.len();
+        defs.retain(|def| &def.label != label);
+        if defs.len() != before {
+            label_defs::save_label_defs(beads_dir, &defs)?;
+        }
+        if ctx.is_json() {
+            ctx.json_pretty(&serde_json::json!({
+                "label": label,
+                "cleared": defs.len() != before,
+            }));
+        } else if defs.len() != before {
+            println!("\u{2713} Cleared definition for label '{label}'");
+        } else {
+            println!("Label '{label}' has no definition.");
+        }
+        return Ok(());
+    }
+
+    if args.color.is_none() && args.description.is_none() {
+        let def = label_defs::find_label_def(&defs, label);
+        if ctx.is_json() {
+            ctx.json_pretty(&def);
+        } else if let Some(def) = def {
+            print_label_def(def);
+        } else {
+            println!("Label '{label}' has no definition.");
+        }
+        return Ok(());
+    }
+
+    validate_label(label)?;
+    if let Some(color) = &args.color {
+        if Color::parse(color).is_err() {
+            return Err(BeadsError::validation(
+                "color",
+                format!("unknown color '{color}' (use a name like 'red' or hex like '#ff8800')"),
+            ));
+        }
+    }
+
+    let index = if let Some(index) = defs.iter().position(|def| &def.label == label) {
+        index
+    } else {
+        defs.push(LabelDef {
+            label: label.clone(),
+            ..LabelDef::default()
+        });
+        defs.len() - 1
+    };
+    let def = &mut defs[index];
+    if let Some(color) = &args.color {
+        def.color = Some(color.clone());
+    }
+    if let Some(description) = &args.description {
+        def.description = Some(description.clone()).filter(|d| !d.trim().is_empty());
+    }
+    let def = def.clone();
+    defs.sort_by(|a, b| a.label.cmp(&b.label));
+    label_defs::save_label_defs(beads_dir, &defs)?;
+
+    info!(label = %def.label, "Updated label definition");
+    if ctx.is_json() {
+        ctx.json_pretty(&def);
+    } else {
+        println!("\u{2713} Updated label '{}'", def.label);
+        print_label_def(&def);
+    }
+    Ok(())
+}
+
+fn print_label_def(def: &LabelDef) {
+    let mut line = def.label.clone();
+    if let Some(color) = &def.color {
+        line.push_str(&format!(" [{color}]"));
+    }
+    if let Some(description) = &def.description {
+        line.push_str(&format!(" - {description}"));
+    }
+    println!("{line}");
+}
+
+/// Warn about labels without a definition when `strict_labels` is enabled.
+pub(crate) fn warn_undeclared_labels(
+    beads_dir: &Path,
+    layer: &config::ConfigLayer,
+    labels: &[&str],
+    ctx: &OutputContext,
+) {
+    if labels.is_empty() || !config::strict_labels_from_layer(layer) {
+        return;
+    }
+    let defs = match label_defs::load_label_defs(beads_dir) {
+        Ok(defs) => defs,
+        Err(e) => {
+            ctx.warning(&format!("Could not read label definitions: {e}"));
+            return;
+        }
+    };
+    for label in label_defs::undeclared_labels(&defs, labels) {
+        ctx.warning(&format!(
+            "Label '{label}' is not declared (define it with `br label describe {label}`)"
+        ));
+    }
+}
+
 fn resolve_issue_id(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     resolver: &IdResolver,
     all_ids: &[String],
     input: &str,
@@ -409,6 +584,15 @@ fn label_color(label: &str) -> Color {
     Color::parse(color_name).unwrap_or_default()
 }
 
+/// Style for a label: its defined color, or a stable color from its name.
+fn label_style(theme: &Theme, label: &str) -> Style {
+    theme
+        .label_colors
+        .get(label)
+        .cloned()
+        .unwrap_or_else(|| Style::new().color(label_color(label)))
+}
+
 /// Render label add/remove action results in rich mode.
 fn render_label_action_results_rich(
     results: &[LabelActionResult],
@@ -437,10 +621,7 @@ fn render_label_action_results_rich(
         };
 
         text.append_styled(&format!("{icon} {verb} label "), style);
-        text.append_styled(
-            &result.label,
-            Style::new().color(label_color(&result.label)),
-        );
+        text.append_styled(&result.label, label_style(theme, &result.label));
         text.append(if action == "add" { " on " } else { " from " });
         text.append_styled(&result.issue_id, theme.issue_id.clone());
 
@@ -473,7 +654,7 @@ fn render_labels_for_issue_rich(issue_id: &str, labels: &[String], ctx: &OutputC
         if i > 0 {
             label_line.append("  ");
         }
-        label_line.append_styled(label, Style::new().color(label_color(label)));
+        label_line.append_styled(label, label_style(theme, label));
     }
     console.print_renderable(&label_line);
 }
@@ -500,7 +681,7 @@ fn render_unique_labels_rich(labels: &[String], ctx: &OutputContext) {
         if i > 0 {
             label_line.append("  ");
         }
-        label_line.append_styled(label, Style::new().color(label_color(label)));
+        label_line.append_styled(label, label_style(theme, label));
     }
     console.print_renderable(&label_line);
 }
@@ -525,10 +706,7 @@ fn render_label_counts_rich(label_counts: &[LabelCount], ctx: &OutputContext) {
         if i > 0 {
             content.append("\n");
         }
-        content.append_styled(
-            &format!("{:<20}", lc.label),
-            Style::new().color(label_color(&lc.label)),
-        );
+        content.append_styled(&format!("{:<20}", lc.label), label_style(theme, &lc.label));
         content.append_styled(
             &format!(
                 "{:>4} issue{}",
@@ -566,7 +744,7 @@ fn render_rename_not_found_rich(old_name: &str, ctx: &OutputContext) {
     let mut text = Text::new("");
     text.append_styled("\u{26a0} ", theme.warning.clone());
     text.append("Label ");
-    text.append_styled(old_name, Style::new().color(label_color(old_name)));
+    text.append_styled(old_name, label_style(theme, old_name));
     text.append_styled(" not found on any issues.", theme.dimmed.clone());
 
     console.print_renderable(&text);
@@ -580,9 +758,9 @@ fn render_rename_result_rich(old_name: &str, new_name: &str, count: usize, ctx:
     let mut text = Text::new("");
     text.append_styled("\u{2713} ", theme.success.clone());
     text.append("Renamed ");
-    text.append_styled(old_name, Style::new().color(label_color(old_name)).dim());
+    text.append_styled(old_name, label_style(theme, old_name).dim());
     text.append(" \u{2192} ");
-    text.append_styled(new_name, Style::new().color(label_color(new_name)).bold());
+    text.append_styled(new_name, label_style(theme, new_name).bold());
     text.append_styled(
         &format!(" on {} issue{}", count, if count == 1 { "" } else { "s" }),
         theme.dimmed.clone(),
@@ -0,0 +1,253 @@
+//! Link command implementation.
+//!
+//! Attaches source code locations (`path`, `path:line`, `path@commit`) to an
+//! issue. New references are checked against the working tree, or against git
+//! when pinned to a commit, so typos are caught before they are synced.
+//! Linked issues can be found again with `br list --touches <path>`.
+
+use crate::cli::LinkArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::CodeRef;
+use crate::output::OutputContext;
+use crate::util::id::{IdResolver, ResolverConfig};
+use serde::Serialize;
+use std::fs;
+use std::path::{Component, Path};
+use std::process::Command;
+
+/// JSON output for the link command.
+#[derive(Debug, Serialize)]
+struct LinkResult {
+    issue_id: String,
+    /// `linked` or `unlinked`
+    action: &'static str,
+    /// References that were actually added or removed.
+    changed: Vec<CodeRef>,
+    /// All references on the issue after the change.
+    code_refs: Vec<CodeRef>,
+}
+
+/// Execute the link command.
+///
+/// # Errors
+///
+/// Returns an error if a reference cannot be parsed or does not exist in the
+/// working tree (unless `--no-verify`), the issue cannot be found, or the
+/// database update fails.
+pub fn execute(
+    args: &LinkArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let root = beads_dir.parent().unwrap_or(&beads_dir).to_path_buf();
+
+    let mut code_refs = Vec::with_capacity(args.refs.len());
+    for raw in &args.refs {
+        let code_ref = parse_code_ref(raw, &root)?;
+        if !args.remove && !args.no_verify {
+            verify_code_ref(&code_ref, &root)?;
+        }
+        code_refs.push(code_ref);
+    }
+
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
+    let storage = &mut *storage_ctx.storage;
+
+    let issue_id = resolver
+        .resolve(
+            &args.id,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+        )?
+        .id;
+
+    let mut changed = Vec::new();
+    for code_ref in code_refs {
+        let did_change = if args.remove {
+            storage.remove_code_ref(&issue_id, &code_ref, &actor)?
+        } else {
+            storage.add_code_ref(&issue_id, &code_ref, &actor)?
+        };
+        if did_change {
+            changed.push(code_ref);
+        } else if !ctx.is_json() {
+            let state = if args.remove {
+                "not linked to"
+            } else {
+                "already linked to"
+            };
+            ctx.info(&format!("{code_ref} {state} {issue_id}"));
+        }
+    }
+
+    let action = if args.remove { "unlinked" } else { "linked" };
+    if ctx.is_json() {
+        let code_refs = storage.get_code_refs(&issue_id)?;
+        ctx.json_pretty(&LinkResult {
+            issue_id,
+            action,
+            changed,
+            code_refs,
+        });
+    } else {
+        let direction = if args.remove { "from" } else { "to" };
+        for code_ref in &changed {
+            let verb = if args.remove { "Unlinked" } else { "Linked" };
+            ctx.success(&format!("{verb} {code_ref} {direction} {issue_id}"));
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Parse a reference and make its path relative to the workspace root.
+///
+/// Absolute paths are accepted when they lie inside `root`; `..` components
+/// are rejected so references cannot point outside the workspace.
+fn parse_code_ref(raw: &str, root: &Path) -> Result<CodeRef> {
+    let mut code_ref: CodeRef = raw.parse()?;
+
+    let path = Path::new(&code_ref.path);
+    if path.is_absolute() {
+        let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let relative = path.strip_prefix(&root).map_err(|_| {
+            BeadsError::validation(
+                "code_ref",
+                format!("'{raw}' is outside the workspace {}", root.display()),
+            )
+        })?;
+        code_ref.path = relative.to_string_lossy().replace('\\', "/");
+    }
+
+    if Path::new(&code_ref.path)
+        .components()
+        .any(|component| matches!(component, Component::ParentDir))
+    {
+        return Err(BeadsError::validation(
+            "code_ref",
+            format!("'{raw}' must not contain '..'"),
+        ));
+    }
+    if code_ref.path.is_empty() {
+        return Err(BeadsError::validation(
+            "code_ref",
+            format!("'{raw}' is the workspace root"),
+        ));
+    }
+    Ok(code_ref)
+}
+
+/// Check that the referenced file (and line) exists, in the working tree or
+/// at the pinned commit.
+fn verify_code_ref(code_ref: &CodeRef, root: &Path) -> Result<()> {
+    let contents = if let Some(commit) = &code_ref.commit {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(root)
+            .args(["show", &format!("{commit}:{}", code_ref.path)])
+            .output()
+            .map_err(|e| BeadsError::Config(format!("Failed to run git: {e}")))?;
+        if !output.status.success() {
+            return Err(BeadsError::validation(
+                "code_ref",
+                format!("{} does not exist at {commit}", code_ref.path),
+            ));
+        }
+        output.stdout
+    } else {
+        let full = root.join(&code_ref.path);
+        if !full.exists() {
+            return Err(BeadsError::validation(
+                "code_ref",
+                format!("{} does not exist in the working tree", code_ref.path),
+            ));
+        }
+        if code_ref.line.is_none() {
+            return Ok(());
+        }
+        if !full.is_file() {
+            return Err(BeadsError::validation(
+                "code_ref",
+                format!("{} is not a file", code_ref.path),
+            ));
+        }
+        fs::read(&full)?
+    };
+
+    if let Some(line) = code_ref.line {
+        let line_count = count_lines(&contents);
+        if line as usize > line_count {
+            return Err(BeadsError::validation(
+                "code_ref",
+                format!(
+                    "{} has {line_count} line(s), cannot link line {line}",
+                    code_ref.path
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Number of lines, counting a final line without a trailing newline.
+fn count_lines(contents: &[u8]) -> usize {
+    let newlines = contents.iter().filter(|b| **b == b'\n').count();
+    if contents.last().is_some_and(|b| *b != b'\n') {
+        newlines + 1
+    } else {
+        newlines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn count_lines_handles_missing_trailing_newline() {
+        assert_eq!(count_lines(b""), 0);
+        assert_eq!(count_lines(b"a\nb\n"), 2);
+        assert_eq!(count_lines(b"a\nb"), 2);
+    }
+
+    #[test]
+    fn verify_checks_file_and_line_in_working_tree() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/foo.rs"), "fn a() {}\nfn b() {}\n").unwrap();
+
+        let ok = parse_code_ref("src/foo.rs:2", dir.path()).unwrap();
+        assert!(verify_code_ref(&ok, dir.path()).is_ok());
+        let directory = parse_code_ref("src/", dir.path()).unwrap();
+        assert!(verify_code_ref(&directory, dir.path()).is_ok());
+
+        let past_end = parse_code_ref("src/foo.rs:3", dir.path()).unwrap();
+        assert!(verify_code_ref(&past_end, dir.path()).is_err());
+        let missing = parse_code_ref("src/bar.rs", dir.path()).unwrap();
+        assert!(verify_code_ref(&missing, dir.path()).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_paths_outside_workspace() {
+        let dir = TempDir::new().unwrap();
+        assert!(parse_code_ref("../secret.rs", dir.path()).is_err());
+        assert!(parse_code_ref("/definitely/elsewhere.rs:1", dir.path()).is_err());
+
+        let inside = dir.path().canonicalize().unwrap().join("src/foo.rs:4");
+        let code_ref = parse_code_ref(&inside.to_string_lossy(), dir.path()).unwrap();
+        assert_eq!(code_ref.path, "src/foo.rs");
+        assert_eq!(code_ref.line, Some(4));
+    }
+}
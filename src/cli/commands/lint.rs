@@ -1,14 +1,17 @@
 //! Lint command implementation.
 //!
 //! Checks issues for missing recommended template sections based on issue type.
+//! With `--policy`, checks them against the required-field policies from
+//! config instead.
 
 use crate::cli::LintArgs;
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::model::{Issue, IssueType, Status};
 use crate::output::OutputContext;
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::storage::{ListFilters, Storage};
 use crate::util::id::{IdResolver, ResolverConfig};
+use crate::validation::{PolicyRule, PolicyValidator};
 use rich_rust::prelude::*;
 use serde::Serialize;
 use std::collections::BTreeMap;
@@ -37,12 +40,22 @@ struct LintSummary {
     checked: usize,
     warnings: usize,
     results: Vec<LintResult>,
+    /// Results are policy violations rather than missing template sections
+    policy: bool,
 }
 
 impl LintSummary {
     const fn exit_code(&self, json: bool) -> i32 {
         if json || self.warnings == 0 { 0 } else { 1 }
     }
+
+    const fn noun(&self) -> &'static str {
+        if self.policy {
+            "policy violations"
+        } else {
+            "template warnings"
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -86,16 +99,30 @@ pub fn execute(
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
 
-    let issues = if args.ids.is_empty() {
+    let mut issues = if args.ids.is_empty() {
         let filters = build_filters(args)?;
         storage.list_issues(&filters)?
     } else {
         resolve_issues(storage, &beads_dir, args, cli)?
     };
 
-    let summary = lint_issues(&issues);
+    let summary = if args.policy {
+        let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+        let rules = config::policy_rules_from_layer(&config_layer)?;
+        if rules.is_empty() && !ctx.is_json() {
+            ctx.warning("No policies configured (set `policies` in .beads/config.yaml)");
+        }
+        let ids: Vec<String> = issues.iter().map(|issue| issue.id.clone()).collect();
+        let mut labels = storage.get_labels_for_issues(&ids)?;
+        for issue in &mut issues {
+            issue.labels = labels.remove(&issue.id).unwrap_or_default();
+        }
+        lint_policies(&issues, &rules)
+    } else {
+        lint_issues(&issues)
+    };
 
     if ctx.is_json() {
         let output = LintOutput {
@@ -119,14 +146,20 @@ pub fn execute(
     } else {
         if summary.results.is_empty() {
             println!(
-                "✓ No template warnings found ({} issues checked)",
+                "✓ No {} found ({} issues checked)",
+                summary.noun(),
                 summary.checked
             );
             return Ok(());
         }
 
+        let heading = if summary.policy {
+            "Policy violations"
+        } else {
+            "Template warnings"
+        };
         println!(
-            "Template warnings ({} issues, {} warnings):\n",
+            "{heading} ({} issues, {} warnings):\n",
             summary.results.len(),
             summary.warnings
         );
@@ -146,7 +179,12 @@ fn render_lint_rich(summary: &LintSummary, ctx: &OutputContext) {
     let theme = ctx.theme();
     let mut content = Text::new("");
 
-    content.append_styled("Template Lint\n", theme.emphasis.clone());
+    let heading = if summary.policy {
+        "Policy Lint\n"
+    } else {
+        "Template Lint\n"
+    };
+    content.append_styled(heading, theme.emphasis.clone());
     content.append("\n");
 
     content.append_styled("Checked: ", theme.dimmed.clone());
@@ -162,7 +200,8 @@ fn render_lint_rich(summary: &LintSummary, ctx: &OutputContext) {
     if summary.results.is_empty() {
         content.append_styled(
             &format!(
-                "✓ No template warnings found ({} issues checked)",
+                "✓ No {} found ({} issues checked)",
+                summary.noun(),
                 summary.checked
             ),
             theme.success.clone(),
@@ -201,10 +240,12 @@ fn render_lint_rich(summary: &LintSummary, ctx: &OutputContext) {
             content.append("\n");
         }
 
-        content.append_styled(
-            "Tip: Add the missing sections to issue descriptions to clear warnings.\n",
-            theme.dimmed.clone(),
-        );
+        let tip = if summary.policy {
+            "Tip: Fill in the required fields (br update) to clear violations.\n"
+        } else {
+            "Tip: Add the missing sections to issue descriptions to clear warnings.\n"
+        };
+        content.append_styled(tip, theme.dimmed.clone());
     }
 
     let panel = Panel::from_rich_text(&content, ctx.width())
@@ -245,7 +286,8 @@ fn build_filters(args: &LintArgs) -> Result<ListFilters> {
         filters.types = Some(vec![issue_type]);
     }
 
-    let status_filter = args.status.as_deref().unwrap_or("open").trim();
+    let default_status = if args.policy { "all" } else { "open" };
+    let status_filter = args.status.as_deref().unwrap_or(default_status).trim();
     if !status_filter.is_empty() && !status_filter.eq_ignore_ascii_case("all") {
         let status: Status = status_filter.parse()?;
         if status.is_terminal() {
@@ -260,14 +302,16 @@ fn build_filters(args: &LintArgs) -> Result<ListFilters> {
 }
 
 fn resolve_issues(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     beads_dir: &Path,
     args: &LintArgs,
     cli: &config::CliOverrides,
 ) -> Result<Vec<Issue>> {
     let config_layer = config::load_config(beads_dir, Some(storage), cli)?;
     let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix).with_aliases(storage.get_id_aliases()?),
+    );
 
     let mut issues = Vec::new();
     for id_input in &args.ids {
@@ -301,6 +345,35 @@ fn lint_issues(issues: &[Issue]) -> LintSummary {
         checked: issues.len(),
         warnings,
         results,
+        policy: false,
+    }
+}
+
+/// Check issues against policy rules. Closed issues are also held to the
+/// on-close rules.
+fn lint_policies(issues: &[Issue], rules: &[PolicyRule]) -> LintSummary {
+    let mut warnings = 0;
+    let mut results = Vec::new();
+
+    for issue in issues {
+        let closed = issue.status == Status::Closed;
+        if let Err(violations) = PolicyValidator::validate(issue, rules, closed) {
+            warnings += violations.len();
+            results.push(LintResult {
+                id: issue.id.clone(),
+                title: issue.title.clone(),
+                issue_type: issue.issue_type.as_str().to_string(),
+                warnings: violations.len(),
+                missing: violations.iter().map(ToString::to_string).collect(),
+            });
+        }
+    }
+
+    LintSummary {
+        checked: issues.len(),
+        warnings,
+        results,
+        policy: true,
     }
 }
 
@@ -403,6 +476,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -437,4 +514,26 @@ mod tests {
         assert_eq!(summary.exit_code(true), 0);
         assert_eq!(summary.exit_code(false), 1);
     }
+
+    #[test]
+    fn test_lint_policies_applies_on_close_rules_to_closed_issues() {
+        let rules = vec![PolicyRule {
+            trigger: crate::validation::PolicyTrigger::OnClose,
+            selector: "bug".to_string(),
+            fields: vec!["acceptance_criteria".to_string()],
+        }];
+        let open_bug = make_issue(IssueType::Bug, None);
+        let mut closed_bug = make_issue(IssueType::Bug, None);
+        closed_bug.id = "bd-456".to_string();
+        closed_bug.status = Status::Closed;
+
+        let summary = lint_policies(&[open_bug, closed_bug], &rules);
+        assert_eq!(summary.checked, 2);
+        assert_eq!(summary.results.len(), 1);
+        assert_eq!(summary.results[0].id, "bd-456");
+        assert_eq!(
+            summary.results[0].missing,
+            vec!["acceptance_criteria: required before closing bug issues".to_string()]
+        );
+    }
 }
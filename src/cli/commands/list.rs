@@ -1,19 +1,55 @@
 //! List command implementation.
 //!
 //! Primary discovery interface with classic filter semantics and
-//! `IssueWithCounts` JSON output. Supports text, JSON, and CSV formats.
+//! `IssueWithCounts` JSON output. Supports text, JSON, CSV, TOON and table
+//! formats; `--group-by` splits text and JSON/TOON output into sections.
 
-use crate::cli::{ListArgs, OutputFormat, resolve_output_format};
+use crate::cli::commands::milestone::check_milestone;
+use crate::cli::commands::resolve_issue_id;
+use crate::cli::{GroupBy, ListArgs, OutputFormat, resolve_output_format};
 use crate::config;
+use crate::config::remotes::{self, RemoteStorage};
 use crate::error::{BeadsError, Result};
-use crate::format::csv;
 use crate::format::{IssueWithCounts, TextFormatOptions, format_issue_line_with, terminal_width};
-use crate::model::{IssueType, Priority, Status};
+use crate::format::{csv, table, tabular};
+use crate::model::{DependencyType, Issue, IssueType, MILESTONE_DEP_TYPE, Priority, Status};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::storage::{FieldFilter, ListCursor, ListFilters, Storage};
 use chrono::Utc;
-use std::collections::HashSet;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
 use std::io::IsTerminal;
+use std::path::Path;
+
+/// Rows fetched per batch in `--stream` mode.
+pub(crate) const STREAM_BATCH_SIZE: usize = 1000;
+
+/// Page size used by `--paginate`/`--after` when `--limit` is not given.
+pub(crate) const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// JSON output for a paginated listing.
+#[derive(Serialize)]
+pub(crate) struct ListPage<T: Serialize> {
+    pub issues: Vec<T>,
+    /// Pass to `--after` to fetch the next page; `null` on the last page.
+    pub next_cursor: Option<String>,
+}
+
+/// JSON output for `--group-by`: sections in key order, each with its issues.
+#[derive(Serialize)]
+pub(crate) struct GroupedIssues<T: Serialize> {
+    /// Distinct issues listed (an issue can appear in several label groups).
+    pub total: usize,
+    pub groups: Vec<IssueGroup<T>>,
+}
+
+/// One `--group-by` section.
+#[derive(Serialize)]
+pub(crate) struct IssueGroup<T: Serialize> {
+    pub group: String,
+    pub count: usize,
+    pub issues: Vec<T>,
+}
 
 /// Execute the list command.
 ///
@@ -30,7 +66,7 @@ pub fn execute(
     // Open storage (--db flag allows working from any directory)
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
     let max_width = if std::io::stdout().is_terminal() {
@@ -44,114 +80,170 @@ pub fn execute(
         wrap: args.wrap,
     };
 
-    // Build filter from args
-    let mut filters = build_filters(args)?;
-    let client_filters = needs_client_filters(args);
-    let limit = if client_filters {
-        filters.limit.take()
+    // Validate sort key before query
+    validate_sort_key(args.sort.as_deref())?;
+
+    // Check --where against the declared custom fields
+    let checked_args;
+    let args = if args.where_.is_empty() {
+        args
     } else {
-        None
+        checked_args = ListArgs {
+            where_: config::fields::normalize_filters(&args.where_, &config_layer)?,
+            ..args.clone()
+        };
+        &checked_args
     };
 
-    // Validate sort key before query
-    validate_sort_key(args.sort.as_deref())?;
+    // --depends-on, --blocks and --milestone may name partial IDs
+    let resolved_args;
+    let args = if args.depends_on.is_some() || args.blocks.is_some() || args.milestone.is_some() {
+        let mut resolved = args.clone();
+        resolve_filter_ids(&mut resolved, &beads_dir, storage, cli)?;
+        resolved_args = resolved;
+        &resolved_args
+    } else {
+        args
+    };
 
-    // Query issues
-    let issues = storage.list_issues(&filters)?;
-    let mut issues = if client_filters {
-        apply_client_filters(storage, issues, args)?
+    let paginate = is_paginated(args);
+    if paginate && args.remotes {
+        return Err(BeadsError::validation(
+            "after",
+            "pagination is not supported with --remotes",
+        ));
+    }
+    let paged_args;
+    let args = if paginate && args.limit.is_none() {
+        paged_args = ListArgs {
+            limit: Some(DEFAULT_PAGE_SIZE),
+            ..args.clone()
+        };
+        &paged_args
     } else {
-        issues
+        args
     };
 
-    if let Some(limit) = limit {
-        if limit > 0 && issues.len() > limit {
-            issues.truncate(limit);
+    if args.stream {
+        if cli.quiet.unwrap_or(false) {
+            return Ok(());
         }
+        return stream_list(storage, args, &beads_dir, cli.lock_timeout);
     }
 
+    // Query issues (with dependency counts, in one statement)
+    let rows = query_issues(storage, args)?;
+    let next_cursor = if paginate {
+        next_cursor(rows.len(), rows.last().map(|row| &row.issue), args)
+    } else {
+        None
+    };
+
+    // Federated listing: query each registered remote with the same filters.
+    // Local issues come first, then remotes in name order.
+    let mut remote_groups = Vec::new();
+    if args.remotes {
+        for remote in remotes::open_remote_storages(&beads_dir, cli.lock_timeout)? {
+            let remote_rows = query_issues(&remote.storage, args)?;
+            remote_groups.push((remote, remote_rows));
+        }
+    }
+    let limit = args.limit.unwrap_or(0);
+
     // Determine output format: --json flag overrides --format
     let output_format = resolve_output_format(args.format, outer_ctx.is_json(), false);
     let quiet = cli.quiet.unwrap_or(false);
-    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+    let ctx = outer_ctx.with_output_format(output_format, quiet, use_color);
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
+    if args.group_by.is_some() && matches!(output_format, OutputFormat::Csv | OutputFormat::Table) {
+        return Err(BeadsError::validation(
+            "group_by",
+            "--group-by is not supported with --format csv or table",
+        ));
+    }
 
     // Output
     match output_format {
         OutputFormat::Json | OutputFormat::Toon => {
-            // Fetch relations for all issues
-            let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
-            let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
-
-            // Use batch counting
-            let dependency_counts = storage.count_dependencies_for_issues(&issue_ids)?;
-            let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
-
-            // Convert to IssueWithCounts
-            let issues_with_counts: Vec<IssueWithCounts> = issues
-                .into_iter()
-                .map(|mut issue| {
-                    if let Some(labels) = labels_map.remove(&issue.id) {
-                        issue.labels = labels;
-                    }
-
-                    let dependency_count = *dependency_counts.get(&issue.id).unwrap_or(&0);
-                    let dependent_count = *dependent_counts.get(&issue.id).unwrap_or(&0);
-
-                    IssueWithCounts {
-                        issue,
-                        dependency_count,
-                        dependent_count,
-                    }
-                })
-                .collect();
-
-            if matches!(output_format, OutputFormat::Toon) {
+            let mut issues_with_counts = rows;
+            attach_row_labels(storage, &mut issues_with_counts)?;
+            for (remote, mut rows) in remote_groups {
+                attach_row_labels(&remote.storage, &mut rows)?;
+                for row in &mut rows {
+                    remotes::qualify_issue(&mut row.issue, &remote.name);
+                }
+                issues_with_counts.extend(rows);
+            }
+            if limit > 0 {
+                issues_with_counts.truncate(limit);
+            }
+
+            if let Some(by) = args.group_by {
+                let grouped = group_issues(issues_with_counts, by, |row| &row.issue);
+                if matches!(output_format, OutputFormat::Toon) {
+                    ctx.toon_with_stats(&grouped, args.stats);
+                } else {
+                    ctx.json_pretty(&grouped);
+                }
+            } else if paginate {
+                let page = ListPage {
+                    issues: issues_with_counts,
+                    next_cursor,
+                };
+                if matches!(output_format, OutputFormat::Toon) {
+                    ctx.toon_with_stats(&page, args.stats);
+                } else {
+                    ctx.json_pretty(&page);
+                }
+            } else if matches!(output_format, OutputFormat::Toon) {
                 ctx.toon_with_stats(&issues_with_counts, args.stats);
             } else {
                 ctx.json_pretty(&issues_with_counts);
             }
         }
         OutputFormat::Csv => {
+            let (issues, remote_groups) = without_counts(rows, remote_groups);
+            let issues = merge_remote_issues(issues, remote_groups, limit);
             let fields = csv::parse_fields(args.fields.as_deref());
             let csv_output = csv::format_csv(&issues, &fields);
             print!("{csv_output}");
         }
+        OutputFormat::Table => {
+            let columns = table::parse_columns(args.columns.as_deref(), &[])?;
+            let (mut issues, mut remote_groups) = without_counts(rows, remote_groups);
+            if tabular::needs_labels(&columns) {
+                attach_labels(storage, &mut issues)?;
+                for (remote, remote_issues) in &mut remote_groups {
+                    attach_labels(&remote.storage, remote_issues)?;
+                }
+            }
+            let issues = merge_remote_issues(issues, remote_groups, limit);
+            let max_width = table::max_width(config::table_max_width_from_layer(&config_layer));
+            print!(
+                "{}",
+                table::render_issues(&issues, &columns, max_width, |_, _| None)
+            );
+            if let Some(cursor) = next_cursor {
+                println!("More results: --after {cursor}");
+            }
+        }
         OutputFormat::Text => {
-            if matches!(ctx.mode(), OutputMode::Rich) {
-                let columns = if args.long {
-                    IssueTableColumns {
-                        id: true,
-                        priority: true,
-                        status: true,
-                        issue_type: true,
-                        title: true,
-                        assignee: true,
-                        created: true,
-                        updated: true,
-                        ..Default::default()
-                    }
-                } else {
-                    IssueTableColumns {
-                        id: true,
-                        priority: true,
-                        status: true,
-                        issue_type: true,
-                        title: true,
-                        ..Default::default()
-                    }
-                };
-                let mut table = IssueTable::new(&issues, ctx.theme())
-                    .columns(columns)
-                    .title(format!("Issues ({})", issues.len()))
-                    .wrap(args.wrap);
-                if args.wrap {
-                    table = table.width(Some(ctx.width()));
+            let (mut issues, mut remote_groups) = without_counts(rows, remote_groups);
+            if args.group_by == Some(GroupBy::Label) {
+                attach_labels(storage, &mut issues)?;
+                for (remote, remote_issues) in &mut remote_groups {
+                    attach_labels(&remote.storage, remote_issues)?;
                 }
-                let table = table.build();
-                ctx.render(&table);
+            }
+            let issues = merge_remote_issues(issues, remote_groups, limit);
+            if let Some(by) = args.group_by {
+                let grouped = group_issues(issues, by, |issue| issue);
+                print_groups_text(&grouped, args, &ctx, format_options);
+            } else if matches!(ctx.mode(), OutputMode::Rich) {
+                let title = format!("Issues ({})", issues.len());
+                render_issue_table(&issues, args, &ctx, title);
             } else {
                 // Note: bd outputs nothing when no issues found, matching that for conformance
                 for issue in &issues {
@@ -159,14 +251,322 @@ pub fn execute(
                     println!("{line}");
                 }
             }
+            if let Some(cursor) = next_cursor {
+                println!("More results: --after {cursor}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Render `issues` as a rich table (more columns with `--long`).
+fn render_issue_table(issues: &[Issue], args: &ListArgs, ctx: &OutputContext, title: String) {
+    let columns = if args.long {
+        IssueTableColumns {
+            id: true,
+            priority: true,
+            status: true,
+            issue_type: true,
+            title: true,
+            assignee: true,
+            created: true,
+            updated: true,
+            ..Default::default()
+        }
+    } else {
+        IssueTableColumns {
+            id: true,
+            priority: true,
+            status: true,
+            issue_type: true,
+            title: true,
+            ..Default::default()
+        }
+    };
+    let mut table = IssueTable::new(issues, ctx.theme())
+        .columns(columns)
+        .time_format(ctx.time_format().clone())
+        .title(title)
+        .wrap(args.wrap);
+    if args.wrap {
+        table = table.width(Some(ctx.width()));
+    }
+    let table = table.build();
+    ctx.render(&table);
+}
+
+/// Split `items` into `--group-by` sections, keeping list order within each.
+///
+/// Groups are sorted by key. Issues without an assignee go under
+/// `(unassigned)`, and issues without labels under `(no labels)`.
+pub(crate) fn group_issues<T, F>(items: Vec<T>, by: GroupBy, issue: F) -> GroupedIssues<T>
+where
+    T: Clone + Serialize,
+    F: Fn(&T) -> &Issue,
+{
+    let total = items.len();
+    let mut groups: BTreeMap<String, Vec<T>> = BTreeMap::new();
+    for item in items {
+        for key in group_keys(issue(&item), by) {
+            groups.entry(key).or_default().push(item.clone());
+        }
+    }
+    GroupedIssues {
+        total,
+        groups: groups
+            .into_iter()
+            .map(|(group, issues)| IssueGroup {
+                group,
+                count: issues.len(),
+                issues,
+            })
+            .collect(),
+    }
+}
+
+fn group_keys(issue: &Issue, by: GroupBy) -> Vec<String> {
+    match by {
+        GroupBy::Status => vec![issue.status.as_str().to_string()],
+        GroupBy::Type => vec![issue.issue_type.as_str().to_string()],
+        GroupBy::Assignee => vec![
+            issue
+                .assignee
+                .clone()
+                .unwrap_or_else(|| "(unassigned)".to_string()),
+        ],
+        GroupBy::Label if issue.labels.is_empty() => vec!["(no labels)".to_string()],
+        GroupBy::Label => issue.labels.clone(),
+    }
+}
+
+/// Print `--group-by` sections: a table per group in rich mode, otherwise a
+/// `group (count)` header over the group's indented issue lines.
+fn print_groups_text(
+    grouped: &GroupedIssues<Issue>,
+    args: &ListArgs,
+    ctx: &OutputContext,
+    format_options: TextFormatOptions,
+) {
+    let format_options = TextFormatOptions {
+        max_width: format_options
+            .max_width
+            .map(|width| width.saturating_sub(2)),
+        ..format_options
+    };
+    for (i, group) in grouped.groups.iter().enumerate() {
+        let title = format!("{} ({})", group.group, group.count);
+        if matches!(ctx.mode(), OutputMode::Rich) {
+            render_issue_table(&group.issues, args, ctx, title);
+            continue;
+        }
+        if i > 0 {
+            println!();
+        }
+        println!("{title}");
+        for issue in &group.issues {
+            println!("  {}", format_issue_line_with(issue, format_options));
+        }
+    }
+}
+
+/// Whether the listing is paginated (`--paginate` or `--after`).
+pub(crate) const fn is_paginated(args: &ListArgs) -> bool {
+    args.paginate || args.after.is_some()
+}
+
+/// Cursor for the page following a page of `count` issues ending in `last`,
+/// or `None` if this was the last page.
+pub(crate) fn next_cursor(count: usize, last: Option<&Issue>, args: &ListArgs) -> Option<String> {
+    let limit = args.limit.filter(|&limit| limit > 0)?;
+    if count < limit {
+        return None;
+    }
+    last.map(|issue| ListCursor::after_issue(issue, args.sort.as_deref(), args.reverse).encode())
+}
+
+/// Stream matching issues as JSON Lines: local issues first, then each remote.
+fn stream_list(
+    storage: &dyn Storage,
+    args: &ListArgs,
+    beads_dir: &Path,
+    lock_timeout: Option<u64>,
+) -> Result<()> {
+    let ctx = OutputContext::from_output_format(OutputFormat::Json, false, false);
+    let mut remaining = args.limit.filter(|&limit| limit > 0);
+
+    stream_from(storage, args, None, &ctx, &mut remaining)?;
+    if args.remotes {
+        for remote in remotes::open_remote_storages(beads_dir, lock_timeout)? {
+            if remaining == Some(0) {
+                break;
+            }
+            stream_from(
+                &remote.storage,
+                args,
+                Some(&remote.name),
+                &ctx,
+                &mut remaining,
+            )?;
         }
     }
 
     Ok(())
 }
 
+/// Stream one storage's matches in batches, qualifying IDs for remotes.
+///
+/// `remaining` tracks the overall `--limit` across storages.
+fn stream_from(
+    storage: &dyn Storage,
+    args: &ListArgs,
+    remote: Option<&str>,
+    ctx: &OutputContext,
+    remaining: &mut Option<usize>,
+) -> Result<()> {
+    let mut filters = build_filters(args)?;
+    let client_filters = needs_client_filters(args);
+    if client_filters {
+        filters.limit = None;
+    }
+
+    storage.stream_issues(&filters, STREAM_BATCH_SIZE, &mut |batch| {
+        let batch = if client_filters {
+            apply_client_filters(storage, batch, args, |issue| issue)?
+        } else {
+            batch
+        };
+        let mut rows = with_counts(storage, batch)?;
+        if let Some(remaining) = remaining.as_mut() {
+            rows.truncate(*remaining);
+            *remaining -= rows.len();
+        }
+        if let Some(name) = remote {
+            for row in &mut rows {
+                remotes::qualify_issue(&mut row.issue, name);
+            }
+        }
+        ctx.json_lines(&rows);
+        Ok(remaining.is_none_or(|remaining| remaining > 0))
+    })
+}
+
+/// Run the list query (SQL filters plus client-side filters) against one
+/// storage, with each issue's dependency counts.
+fn query_issues(storage: &dyn Storage, args: &ListArgs) -> Result<Vec<IssueWithCounts>> {
+    let mut filters = build_filters(args)?;
+    let client_filters = needs_client_filters(args);
+    let limit = if client_filters {
+        filters.limit.take()
+    } else {
+        None
+    };
+
+    let rows = storage.list_issues_with_counts(&filters)?;
+    let mut rows = if client_filters {
+        apply_client_filters(storage, rows, args, |row| &row.issue)?
+    } else {
+        rows
+    };
+
+    if let Some(limit) = limit {
+        if limit > 0 && rows.len() > limit {
+            rows.truncate(limit);
+        }
+    }
+
+    Ok(rows)
+}
+
+/// Drop the dependency counts from local and remote rows (text, CSV and
+/// table output don't show them).
+fn without_counts(
+    rows: Vec<IssueWithCounts>,
+    remote_groups: Vec<(RemoteStorage, Vec<IssueWithCounts>)>,
+) -> (Vec<Issue>, Vec<(RemoteStorage, Vec<Issue>)>) {
+    let issues = |rows: Vec<IssueWithCounts>| -> Vec<Issue> {
+        rows.into_iter().map(|row| row.issue).collect()
+    };
+    let remote_groups = remote_groups
+        .into_iter()
+        .map(|(remote, rows)| (remote, issues(rows)))
+        .collect();
+    (issues(rows), remote_groups)
+}
+
+/// Load labels onto `rows` from `storage` in one query.
+fn attach_row_labels(storage: &dyn Storage, rows: &mut [IssueWithCounts]) -> Result<()> {
+    let issue_ids: Vec<String> = rows.iter().map(|row| row.issue.id.clone()).collect();
+    let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
+    for row in rows {
+        if let Some(labels) = labels_map.remove(&row.issue.id) {
+            row.issue.labels = labels;
+        }
+    }
+    Ok(())
+}
+
+/// Attach labels and dependency counts using batch queries against `storage`.
+///
+/// Used for `--stream` batches; other listings get their counts from
+/// [`Storage::list_issues_with_counts`].
+fn with_counts(storage: &dyn Storage, issues: Vec<Issue>) -> Result<Vec<IssueWithCounts>> {
+    let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+    let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
+    let dependency_counts = storage.count_dependencies_for_issues(&issue_ids)?;
+    let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
+
+    Ok(issues
+        .into_iter()
+        .map(|mut issue| {
+            if let Some(labels) = labels_map.remove(&issue.id) {
+                issue.labels = labels;
+            }
+
+            let dependency_count = *dependency_counts.get(&issue.id).unwrap_or(&0);
+            let dependent_count = *dependent_counts.get(&issue.id).unwrap_or(&0);
+
+            IssueWithCounts {
+                issue,
+                dependency_count,
+                dependent_count,
+            }
+        })
+        .collect())
+}
+
+/// Load labels onto `issues` from `storage` in one query.
+pub(crate) fn attach_labels(storage: &dyn Storage, issues: &mut [Issue]) -> Result<()> {
+    let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+    let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
+    for issue in issues {
+        issue.labels = labels_map.remove(&issue.id).unwrap_or_default();
+    }
+    Ok(())
+}
+
+/// Append remote issues (with repo-qualified IDs) and re-apply the overall limit.
+fn merge_remote_issues(
+    mut issues: Vec<Issue>,
+    remote_groups: Vec<(RemoteStorage, Vec<Issue>)>,
+    limit: usize,
+) -> Vec<Issue> {
+    for (remote, remote_issues) in remote_groups {
+        issues.extend(remote_issues.into_iter().map(|mut issue| {
+            remotes::qualify_issue(&mut issue, &remote.name);
+            issue
+        }));
+    }
+    if limit > 0 {
+        issues.truncate(limit);
+    }
+    issues
+}
+
 /// Convert CLI args to storage filter.
-fn build_filters(args: &ListArgs) -> Result<ListFilters> {
+///
+/// Also used by `count`, so both commands select the same issues.
+pub(crate) fn build_filters(args: &ListArgs) -> Result<ListFilters> {
     // Parse status strings to Status enums
     let statuses = if args.status.is_empty() {
         None
@@ -240,11 +640,55 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         } else {
             Some(args.label_any.clone())
         },
+        touches: args.touches.clone(),
+        depends_on: args.milestone.clone().or_else(|| args.depends_on.clone()),
+        blocks: args.blocks.clone(),
+        dep_type: if args.milestone.is_some() {
+            Some(MILESTONE_DEP_TYPE.to_string())
+        } else {
+            parse_dep_type_filter(args.dep_type.as_deref())?
+        },
         updated_before: None,
         updated_after: None,
+        after: args.after.as_deref().map(ListCursor::decode).transpose()?,
+        paginate: is_paginated(args),
+        field_filters: args
+            .where_
+            .iter()
+            .map(|expr| FieldFilter::parse(expr))
+            .collect::<Result<_>>()?,
     })
 }
 
+/// Resolve the (possibly partial) issue IDs given to `--depends-on`,
+/// `--blocks` and `--milestone` to full IDs, checking that `--milestone`
+/// names a milestone.
+pub(crate) fn resolve_filter_ids(
+    args: &mut ListArgs,
+    beads_dir: &Path,
+    storage: &dyn Storage,
+    cli: &config::CliOverrides,
+) -> Result<()> {
+    for id in [&mut args.depends_on, &mut args.blocks]
+        .into_iter()
+        .flatten()
+    {
+        *id = resolve_issue_id(id, beads_dir, storage, cli)?;
+    }
+    if let Some(milestone) = &mut args.milestone {
+        *milestone = resolve_issue_id(milestone, beads_dir, storage, cli)?;
+        check_milestone(storage, milestone)?;
+    }
+    Ok(())
+}
+
+/// Normalize a `--dep-type` filter to the stored spelling.
+pub(crate) fn parse_dep_type_filter(dep_type: Option<&str>) -> Result<Option<String>> {
+    dep_type
+        .map(|t| t.parse::<DependencyType>().map(|t| t.as_str().to_string()))
+        .transpose()
+}
+
 fn needs_client_filters(args: &ListArgs) -> bool {
     !args.id.is_empty()
         || !args.label.is_empty()
@@ -257,11 +701,12 @@ fn needs_client_filters(args: &ListArgs) -> bool {
         || args.overdue
 }
 
-fn apply_client_filters(
-    storage: &SqliteStorage,
-    issues: Vec<crate::model::Issue>,
+fn apply_client_filters<T>(
+    storage: &dyn Storage,
+    items: Vec<T>,
     args: &ListArgs,
-) -> Result<Vec<crate::model::Issue>> {
+    issue_of: impl Fn(&T) -> &Issue,
+) -> Result<Vec<T>> {
     let id_filter: Option<HashSet<&str>> = if args.id.is_empty() {
         None
     } else {
@@ -272,7 +717,7 @@ fn apply_client_filters(
 
     // Pre-fetch labels if needed to avoid N+1
     let labels_map = if label_filters {
-        let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+        let issue_ids: Vec<String> = items.iter().map(|item| issue_of(item).id.clone()).collect();
         storage.get_labels_for_issues(&issue_ids)?
     } else {
         std::collections::HashMap::new()
@@ -303,7 +748,8 @@ fn apply_client_filters(
         }
     }
 
-    for issue in issues {
+    for item in items {
+        let issue = issue_of(&item);
         if let Some(ids) = &id_filter {
             if !ids.contains(issue.id.as_str()) {
                 continue;
@@ -359,7 +805,7 @@ fn apply_client_filters(
             }
         }
 
-        filtered.push(issue);
+        filtered.push(item);
     }
 
     Ok(filtered)
@@ -446,4 +892,51 @@ mod tests {
         assert!(needs_client_filters(&args));
         info!("test_needs_client_filters_detects_fields: assertions passed");
     }
+
+    #[test]
+    fn test_group_issues_by_label_and_assignee() {
+        init_logging();
+        info!("test_group_issues_by_label_and_assignee: starting");
+        let issue = |id: &str, assignee: Option<&str>, labels: &[&str]| Issue {
+            id: id.to_string(),
+            assignee: assignee.map(str::to_string),
+            labels: labels.iter().map(|l| (*l).to_string()).collect(),
+            ..Issue::default()
+        };
+        let issues = vec![
+            issue("bd-1", Some("bob"), &["api", "ui"]),
+            issue("bd-2", None, &[]),
+            issue("bd-3", Some("bob"), &["api"]),
+        ];
+
+        let grouped = group_issues(issues.clone(), GroupBy::Label, |i| i);
+        assert_eq!(grouped.total, 3);
+        let summary: Vec<(&str, Vec<&str>)> = grouped
+            .groups
+            .iter()
+            .map(|g| {
+                (
+                    g.group.as_str(),
+                    g.issues.iter().map(|i| i.id.as_str()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("(no labels)", vec!["bd-2"]),
+                ("api", vec!["bd-1", "bd-3"]),
+                ("ui", vec!["bd-1"]),
+            ]
+        );
+
+        let grouped = group_issues(issues, GroupBy::Assignee, |i| i);
+        let counts: Vec<(&str, usize)> = grouped
+            .groups
+            .iter()
+            .map(|g| (g.group.as_str(), g.count))
+            .collect();
+        assert_eq!(counts, vec![("(unassigned)", 1), ("bob", 2)]);
+        info!("test_group_issues_by_label_and_assignee: assertions passed");
+    }
 }
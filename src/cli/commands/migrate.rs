@@ -0,0 +1,157 @@
+//! Migrate command implementation.
+//!
+//! Opening a database already migrates it, so `br migrate` mostly exists to
+//! do that explicitly and report what changed. `br migrate --status` only
+//! reads the database: its schema version, the migrations recorded in
+//! `schema_migrations`, and the ones still pending. `br migrate
+//! --rebuild-counts` also recomputes the cached dependency counts that
+//! `br doctor` reports as stale.
+
+use crate::cli::MigrateArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::storage::schema::{CURRENT_SCHEMA_VERSION, SchemaState};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct MigrationEntry {
+    version: i32,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applied_at: Option<String>,
+}
+
+/// JSON output for the migrate command.
+#[derive(Serialize)]
+struct MigrateOutput {
+    database_path: String,
+    /// Schema version before this command ran.
+    from_version: i32,
+    /// Schema version now (equal to `from_version` with `--status`).
+    database_version: i32,
+    supported_version: i32,
+    applied: Vec<MigrationEntry>,
+    pending: Vec<MigrationEntry>,
+    /// Issues whose dependency counts were corrected (`--rebuild-counts`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rebuilt_counts: Option<usize>,
+}
+
+/// Execute the migrate command.
+///
+/// # Errors
+///
+/// Returns an error if the database does not exist, cannot be migrated, or
+/// (without `--status`) was written by a newer `br`.
+pub fn execute(args: &MigrateArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let db_path = config::resolve_paths(&beads_dir, cli.db.as_ref())?.db_path;
+
+    let before = SqliteStorage::inspect_schema(&db_path)?;
+    let mut rebuilt_counts = None;
+    let after = if args.status {
+        before.clone()
+    } else {
+        if before.is_newer() {
+            return Err(BeadsError::SchemaMismatch {
+                expected: CURRENT_SCHEMA_VERSION,
+                found: before.version,
+            });
+        }
+        let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+        if args.rebuild_counts {
+            rebuilt_counts = Some(
+                storage_ctx
+                    .storage
+                    .sqlite_mut("migrate")?
+                    .rebuild_dependency_counts()?,
+            );
+        }
+        storage_ctx.storage.sqlite("migrate")?.schema_state()?
+    };
+
+    let output = MigrateOutput {
+        database_path: db_path.display().to_string(),
+        from_version: before.version,
+        database_version: after.version,
+        supported_version: CURRENT_SCHEMA_VERSION,
+        applied: after
+            .applied
+            .iter()
+            .map(|migration| MigrationEntry {
+                version: migration.version,
+                name: migration.name.clone(),
+                applied_at: Some(migration.applied_at.clone()),
+            })
+            .collect(),
+        pending: after
+            .pending()
+            .into_iter()
+            .map(|migration| MigrationEntry {
+                version: migration.version,
+                name: migration.name.to_string(),
+                applied_at: None,
+            })
+            .collect(),
+        rebuilt_counts,
+    };
+
+    if ctx.is_json() {
+        ctx.json_pretty(&output);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+
+    if args.status {
+        print_status(&output, &after);
+    } else if before.version == after.version {
+        println!("Database schema is up to date (v{}).", after.version);
+    } else {
+        println!(
+            "Migrated database from v{} to v{}.",
+            before.version, after.version
+        );
+    }
+    if let Some(count) = rebuilt_counts {
+        println!("Rebuilt dependency counts: {count} issue(s) corrected.");
+    }
+    Ok(())
+}
+
+fn print_status(output: &MigrateOutput, state: &SchemaState) {
+    println!("Database: {}", output.database_path);
+    println!(
+        "Schema version: v{} (this br supports v{})",
+        output.database_version, output.supported_version
+    );
+    if state.is_newer() {
+        println!("This database was written by a newer br and is read-only here; run: br upgrade");
+    }
+
+    println!();
+    if output.applied.is_empty() {
+        println!("Applied: none recorded");
+    } else {
+        println!("Applied:");
+        for migration in &output.applied {
+            println!(
+                "  v{:<3} {:<36} {}",
+                migration.version,
+                migration.name,
+                migration.applied_at.as_deref().unwrap_or_default()
+            );
+        }
+    }
+    if output.pending.is_empty() {
+        println!("Pending: none");
+    } else {
+        println!("Pending (applied on next open or by `br migrate`):");
+        for migration in &output.pending {
+            println!("  v{:<3} {}", migration.version, migration.name);
+        }
+    }
+}
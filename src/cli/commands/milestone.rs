@@ -0,0 +1,399 @@
+//! Milestone command implementation.
+//!
+//! A milestone (a sprint, a release) is an issue of type `milestone` with an
+//! optional due date. Issues join a milestone through a `milestone`
+//! dependency on it, so both milestones and assignments live in the database
+//! and in JSONL like any other issue and dependency. An issue belongs to at
+//! most one milestone; milestones are never ready work themselves.
+
+use crate::cli::{
+    MilestoneAddArgs, MilestoneCommands, MilestoneCreateArgs, MilestoneListArgs,
+    MilestoneRemoveArgs,
+};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::{MilestoneProgress, truncate_title};
+use crate::model::{Issue, IssueType, MILESTONE_DEP_TYPE, MILESTONE_TYPE, Priority, Status};
+use crate::output::OutputContext;
+use crate::storage::{ListFilters, Storage};
+use crate::util::id::{IdGenerator, IdResolver, ResolverConfig};
+use crate::util::time::parse_flexible_timestamp;
+use chrono::Utc;
+use serde::Serialize;
+
+/// JSON output for one issue assigned to or removed from a milestone.
+#[derive(Debug, Serialize)]
+struct AssignResult {
+    issue_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    milestone: Option<String>,
+    /// Milestone the issue was in before, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous: Option<String>,
+}
+
+/// Execute a milestone subcommand.
+///
+/// # Errors
+///
+/// Returns an error if an issue cannot be found, the target is not a
+/// milestone, or database operations fail.
+pub fn execute(
+    command: &MilestoneCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(config::id_config_from_layer(&layer).prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
+    let storage = &mut *storage_ctx.storage;
+
+    match command {
+        MilestoneCommands::Create(args) => create(args, &layer, &actor, storage, ctx)?,
+        MilestoneCommands::Add(args) => add(args, &resolver, &actor, storage, ctx)?,
+        MilestoneCommands::Remove(args) => remove(args, &resolver, &actor, storage, ctx)?,
+        MilestoneCommands::List(args) => list(args, storage, ctx)?,
+    }
+    storage_ctx.flush_no_db_if_dirty()
+}
+
+fn create(
+    args: &MilestoneCreateArgs,
+    layer: &config::ConfigLayer,
+    actor: &str,
+    storage: &mut dyn Storage,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let milestone = create_milestone(
+        storage,
+        layer,
+        actor,
+        &args.title,
+        args.due.as_deref(),
+        args.description.as_deref(),
+    )?;
+    if ctx.is_json() {
+        ctx.json_pretty(&milestone);
+    } else if !ctx.is_quiet() {
+        let due = milestone.due_at.map_or_else(String::new, |due| {
+            format!(" (due {})", due.format("%Y-%m-%d"))
+        });
+        ctx.success(&format!(
+            "Created milestone {}: {}{due}",
+            milestone.id, milestone.title
+        ));
+    }
+    Ok(())
+}
+
+fn create_milestone(
+    storage: &mut dyn Storage,
+    layer: &config::ConfigLayer,
+    actor: &str,
+    title: &str,
+    due: Option<&str>,
+    description: Option<&str>,
+) -> Result<Issue> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(BeadsError::validation("title", "cannot be empty"));
+    }
+    let due_at = due
+        .filter(|due| !due.trim().is_empty())
+        .map(|due| parse_flexible_timestamp(due, "due"))
+        .transpose()?;
+
+    let now = Utc::now();
+    let id_gen = IdGenerator::new(config::id_config_from_layer(layer));
+    let count = storage.count_issues()?;
+    let id = id_gen.generate(title, description, Some(actor), now, count, |candidate| {
+        storage.id_exists(candidate).unwrap_or(false)
+    });
+    let mut milestone = Issue {
+        id,
+        title: title.to_string(),
+        description: description.map(str::to_string),
+        status: Status::Open,
+        priority: Priority::MEDIUM,
+        issue_type: IssueType::Custom(MILESTONE_TYPE.to_string()),
+        created_by: Some(actor.to_string()),
+        due_at,
+        created_at: now,
+        updated_at: now,
+        ..Issue::default()
+    };
+    milestone.content_hash = Some(milestone.compute_content_hash());
+    storage.create_issue(&milestone, actor)?;
+    Ok(milestone)
+}
+
+/// Check that `id` names a milestone.
+pub(crate) fn check_milestone(storage: &dyn Storage, id: &str) -> Result<()> {
+    let target = storage
+        .get_issue(id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+    if target.issue_type.as_str() != MILESTONE_TYPE {
+        return Err(BeadsError::validation(
+            "milestone",
+            format!("{id} is a {}, not a milestone", target.issue_type),
+        ));
+    }
+    Ok(())
+}
+
+fn add(
+    args: &MilestoneAddArgs,
+    resolver: &IdResolver,
+    actor: &str,
+    storage: &mut dyn Storage,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let milestone = resolve(resolver, storage, &args.milestone)?;
+    check_milestone(storage, &milestone)?;
+
+    let mut results = Vec::with_capacity(args.ids.len());
+    for input in &args.ids {
+        let issue_id = resolve(resolver, storage, input)?;
+        let previous = assign(storage, &issue_id, &milestone, actor)?;
+        if !ctx.is_json() {
+            match &previous {
+                Some(previous) if *previous == milestone => {
+                    ctx.info(&format!("{issue_id} is already in {milestone}"));
+                }
+                Some(previous) => {
+                    ctx.success(&format!("Moved {issue_id} from {previous} to {milestone}"));
+                }
+                None => ctx.success(&format!("Added {issue_id} to {milestone}")),
+            }
+        }
+        results.push(AssignResult {
+            issue_id,
+            milestone: Some(milestone.clone()),
+            previous,
+        });
+    }
+    if ctx.is_json() {
+        ctx.json_pretty(&results);
+    }
+    Ok(())
+}
+
+fn remove(
+    args: &MilestoneRemoveArgs,
+    resolver: &IdResolver,
+    actor: &str,
+    storage: &mut dyn Storage,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let mut results = Vec::with_capacity(args.ids.len());
+    for input in &args.ids {
+        let issue_id = resolve(resolver, storage, input)?;
+        let previous = milestone_of(storage, &issue_id)?;
+        if let Some(previous) = &previous {
+            storage.remove_dependency(&issue_id, previous, actor)?;
+        }
+        if !ctx.is_json() {
+            match &previous {
+                Some(previous) => ctx.success(&format!("Removed {issue_id} from {previous}")),
+                None => ctx.info(&format!("{issue_id} is not in a milestone")),
+            }
+        }
+        results.push(AssignResult {
+            issue_id,
+            milestone: None,
+            previous,
+        });
+    }
+    if ctx.is_json() {
+        ctx.json_pretty(&results);
+    }
+    Ok(())
+}
+
+/// Put an issue in a milestone, taking it out of its current one.
+///
+/// Returns the milestone the issue was in before.
+fn assign(
+    storage: &mut dyn Storage,
+    issue_id: &str,
+    milestone: &str,
+    actor: &str,
+) -> Result<Option<String>> {
+    if issue_id == milestone {
+        return Err(BeadsError::validation(
+            "ids",
+            format!("{issue_id} cannot be in itself"),
+        ));
+    }
+    let previous = milestone_of(storage, issue_id)?;
+    match previous.as_deref() {
+        Some(current) if current == milestone => {}
+        Some(current) => {
+            storage.remove_dependency(issue_id, current, actor)?;
+            storage.add_dependency(issue_id, milestone, MILESTONE_DEP_TYPE, actor)?;
+        }
+        None => {
+            storage.add_dependency(issue_id, milestone, MILESTONE_DEP_TYPE, actor)?;
+        }
+    }
+    Ok(previous)
+}
+
+/// The milestone an issue is in, if any.
+fn milestone_of(storage: &dyn Storage, issue_id: &str) -> Result<Option<String>> {
+    Ok(storage
+        .get_dependencies_full(issue_id)?
+        .into_iter()
+        .find(|dep| dep.dep_type.as_str() == MILESTONE_DEP_TYPE)
+        .map(|dep| dep.depends_on_id))
+}
+
+/// Progress of every milestone, soonest due first.
+///
+/// # Errors
+///
+/// Returns an error if database queries fail.
+pub fn milestone_progress(
+    storage: &dyn Storage,
+    include_closed: bool,
+) -> Result<Vec<MilestoneProgress>> {
+    let mut milestones = storage.list_issues(&ListFilters {
+        types: Some(vec![IssueType::Custom(MILESTONE_TYPE.to_string())]),
+        include_closed,
+        include_deferred: true,
+        ..ListFilters::default()
+    })?;
+    // Milestones with a due date first, by date; then the rest by creation
+    milestones.sort_by(|a, b| {
+        a.due_at
+            .is_none()
+            .cmp(&b.due_at.is_none())
+            .then_with(|| a.due_at.cmp(&b.due_at))
+            .then_with(|| a.created_at.cmp(&b.created_at))
+    });
+
+    let mut progress = Vec::with_capacity(milestones.len());
+    for milestone in milestones {
+        let members: Vec<_> = storage
+            .get_dependents_with_metadata(&milestone.id)?
+            .into_iter()
+            .filter(|dep| dep.dep_type == MILESTONE_DEP_TYPE && dep.status != Status::Tombstone)
+            .collect();
+        progress.push(MilestoneProgress {
+            id: milestone.id,
+            title: milestone.title,
+            status: milestone.status,
+            due_at: milestone.due_at,
+            total: members.len(),
+            closed: members
+                .iter()
+                .filter(|dep| dep.status == Status::Closed)
+                .count(),
+        });
+    }
+    Ok(progress)
+}
+
+fn list(args: &MilestoneListArgs, storage: &dyn Storage, ctx: &OutputContext) -> Result<()> {
+    let progress = milestone_progress(storage, args.all)?;
+    if ctx.is_json() {
+        ctx.json_pretty(&progress);
+    } else if !ctx.is_quiet() {
+        if progress.is_empty() {
+            println!("No milestones.");
+        }
+        for milestone in &progress {
+            println!("{}", format_progress(milestone));
+        }
+    }
+    Ok(())
+}
+
+/// One line of milestone progress: ID, title, closed/total, percent, due date.
+#[must_use]
+pub fn format_progress(milestone: &MilestoneProgress) -> String {
+    let due = milestone.due_at.map_or_else(String::new, |due| {
+        format!("  due {}", due.format("%Y-%m-%d"))
+    });
+    let state = if milestone.status == Status::Closed {
+        "  [closed]"
+    } else {
+        ""
+    };
+    format!(
+        "{:<10} {:<40} {:>3}/{:<3} {:>3.0}%{due}{state}",
+        milestone.id,
+        truncate_title(&milestone.title, 40),
+        milestone.closed,
+        milestone.total,
+        milestone.percent_done()
+    )
+}
+
+fn resolve(resolver: &IdResolver, storage: &dyn Storage, input: &str) -> Result<String> {
+    Ok(resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+        )?
+        .id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::SqliteStorage;
+
+    fn task(storage: &mut dyn Storage, id: &str, status: Status) {
+        let issue = Issue {
+            id: id.to_string(),
+            title: id.to_string(),
+            closed_at: (status == Status::Closed).then(Utc::now),
+            status,
+            ..Issue::default()
+        };
+        storage.create_issue(&issue, "tester").unwrap();
+    }
+
+    #[test]
+    fn test_assign_moves_between_milestones_and_counts_progress() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let layer = config::default_config_layer();
+        let sprint = create_milestone(
+            &mut storage,
+            &layer,
+            "tester",
+            "Sprint 12",
+            Some("2025-07-01"),
+            None,
+        )
+        .unwrap();
+        let release =
+            create_milestone(&mut storage, &layer, "tester", "Release", None, None).unwrap();
+        task(&mut storage, "bd-1", Status::Open);
+        task(&mut storage, "bd-2", Status::Closed);
+
+        assert_eq!(
+            assign(&mut storage, "bd-1", &release.id, "tester").unwrap(),
+            None
+        );
+        assert_eq!(
+            assign(&mut storage, "bd-1", &sprint.id, "tester").unwrap(),
+            Some(release.id.clone())
+        );
+        assign(&mut storage, "bd-2", &sprint.id, "tester").unwrap();
+        assert!(assign(&mut storage, &sprint.id, &sprint.id, "tester").is_err());
+
+        let progress = milestone_progress(&storage, false).unwrap();
+        assert_eq!(progress[0].id, sprint.id, "dated milestone sorts first");
+        assert_eq!((progress[0].closed, progress[0].total), (1, 2));
+        assert!((progress[0].percent_done() - 50.0).abs() < f64::EPSILON);
+        assert_eq!((progress[1].closed, progress[1].total), (0, 0));
+    }
+}
@@ -1,39 +1,101 @@
+pub mod activity;
 pub mod agents;
+pub mod apply;
 pub mod audit;
 pub mod blocked;
 pub mod changelog;
+pub mod claim;
 pub mod close;
 pub mod comments;
 pub mod completions;
 pub mod config;
 pub mod count;
 pub mod create;
+pub mod critical_path;
 pub mod defer;
 pub mod delete;
 pub mod dep;
+pub mod diff;
 pub mod doctor;
+pub mod duplicate;
 pub mod epic;
+pub mod export;
+pub mod forecast;
+pub mod gc;
 pub mod graph;
 pub mod history;
+pub mod impact;
+pub mod import;
+pub mod inbox;
 pub mod info;
 pub mod init;
 pub mod label;
+pub mod link;
 pub mod lint;
 pub mod list;
+pub mod migrate;
+pub mod milestone;
+pub mod msg;
+pub mod next;
 pub mod orphans;
+pub mod pin;
 pub mod q;
 pub mod query;
+pub mod react;
 pub mod ready;
+pub mod remote;
+pub mod rename;
 pub mod reopen;
+pub mod reparent;
+pub mod report;
+pub mod restore;
 pub mod schema;
 pub mod search;
+pub mod session;
 pub mod show;
 pub mod stale;
 pub mod stats;
 pub mod sync;
 pub mod update;
 pub mod version;
+pub mod watch;
 pub mod r#where;
+pub mod why_blocked;
 
+#[cfg(feature = "serve")]
+pub mod serve;
 #[cfg(feature = "self_update")]
 pub mod upgrade;
+
+use crate::error::Result;
+use crate::storage::Storage;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use std::path::Path;
+
+/// Resolve a possibly partial issue ID or alias given on the command line
+/// to the full ID, using the workspace's prefix.
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be loaded or no single issue
+/// matches `input`.
+pub(crate) fn resolve_issue_id(
+    input: &str,
+    beads_dir: &Path,
+    storage: &dyn Storage,
+    cli: &crate::config::CliOverrides,
+) -> Result<String> {
+    let config_layer = crate::config::load_config(beads_dir, Some(storage), cli)?;
+    let id_config = crate::config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix).with_aliases(storage.get_id_aliases()?),
+    );
+    let all_ids = storage.get_all_ids()?;
+    Ok(resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?
+        .id)
+}
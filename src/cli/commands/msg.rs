@@ -0,0 +1,326 @@
+//! Msg command implementation.
+//!
+//! Messages between agents are ephemeral issues of type `message`: the
+//! sender is stored in `sender`, the recipient in `assignee`, and the text in
+//! the description (its first line is the title). Being ephemeral, they stay
+//! in the local database and are never exported to JSONL. A reply links to
+//! the message it answers with a `replies-to` dependency whose `thread_id` is
+//! the first message of the conversation. Close a message once handled.
+
+use crate::cli::commands::resolve_issue_id;
+use crate::cli::{MsgCommands, MsgInboxArgs, MsgReplyArgs, MsgSendArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::truncate_title;
+use crate::model::{Dependency, DependencyType, Issue, IssueType, Priority, Status};
+use crate::output::OutputContext;
+use crate::storage::{ListFilters, Storage};
+use crate::util::id::IdGenerator;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::Path;
+
+/// Issue type of messages.
+pub const MESSAGE_TYPE: &str = "message";
+
+/// Longest title taken from the first line of a message.
+const MAX_SUBJECT_LEN: usize = 120;
+
+/// A message as shown by `br msg inbox`.
+#[derive(Debug, Clone, Serialize)]
+struct MessageView {
+    id: String,
+    from: String,
+    subject: String,
+    text: String,
+    created_at: DateTime<Utc>,
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    in_reply_to: Option<String>,
+    thread_id: String,
+}
+
+/// Execute a msg subcommand.
+///
+/// # Errors
+///
+/// Returns an error if the message is empty, the replied-to issue is not a
+/// message, or database operations fail.
+pub fn execute(
+    command: &MsgCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&layer);
+    let storage = &mut *storage_ctx.storage;
+
+    match command {
+        MsgCommands::Send(args) => send(args, &layer, &actor, storage, ctx)?,
+        MsgCommands::Reply(args) => reply(args, &beads_dir, &layer, &actor, storage, cli, ctx)?,
+        MsgCommands::Inbox(args) => inbox(args, &actor, storage, ctx)?,
+    }
+    storage_ctx.flush_no_db_if_dirty()
+}
+
+fn send(
+    args: &MsgSendArgs,
+    layer: &config::ConfigLayer,
+    actor: &str,
+    storage: &mut dyn Storage,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let to = args.to.trim();
+    if to.is_empty() {
+        return Err(BeadsError::validation("to", "cannot be empty"));
+    }
+    let message = create_message(storage, layer, actor, to, &args.text, None)?;
+    print_sent(&message, to, &message.id, ctx);
+    Ok(())
+}
+
+fn reply(
+    args: &MsgReplyArgs,
+    beads_dir: &Path,
+    layer: &config::ConfigLayer,
+    actor: &str,
+    storage: &mut dyn Storage,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let id = resolve_issue_id(&args.id, beads_dir, storage, cli)?;
+    let original = storage
+        .get_issue(&id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+    if original.issue_type.as_str() != MESSAGE_TYPE {
+        return Err(BeadsError::validation(
+            "id",
+            format!("{id} is a {}, not a message", original.issue_type),
+        ));
+    }
+    let to = original
+        .sender
+        .clone()
+        .or_else(|| original.created_by.clone())
+        .ok_or_else(|| BeadsError::validation("id", format!("{id} has no sender to reply to")))?;
+    let thread_id = thread_of(storage, &id)?.unwrap_or_else(|| id.clone());
+
+    let message = create_message(
+        storage,
+        layer,
+        actor,
+        &to,
+        &args.text,
+        Some((id.as_str(), thread_id.as_str())),
+    )?;
+    print_sent(&message, &to, &thread_id, ctx);
+    Ok(())
+}
+
+/// Create a message issue, optionally as a reply to `(message, thread)`.
+fn create_message(
+    storage: &mut dyn Storage,
+    layer: &config::ConfigLayer,
+    actor: &str,
+    to: &str,
+    text: &str,
+    reply_to: Option<(&str, &str)>,
+) -> Result<Issue> {
+    let text = text.trim();
+    let subject = text.lines().next().unwrap_or("").trim();
+    if subject.is_empty() {
+        return Err(BeadsError::validation("text", "cannot be empty"));
+    }
+    let subject = truncate_title(subject, MAX_SUBJECT_LEN);
+
+    let now = Utc::now();
+    let id_gen = IdGenerator::new(config::id_config_from_layer(layer));
+    let count = storage.count_issues()?;
+    let id = id_gen.generate(&subject, Some(text), Some(actor), now, count, |candidate| {
+        storage.id_exists(candidate).unwrap_or(false)
+    });
+    let mut message = Issue {
+        id,
+        title: subject,
+        description: Some(text.to_string()),
+        status: Status::Open,
+        priority: Priority::MEDIUM,
+        issue_type: IssueType::Custom(MESSAGE_TYPE.to_string()),
+        assignee: Some(to.to_string()),
+        created_by: Some(actor.to_string()),
+        sender: Some(actor.to_string()),
+        ephemeral: true,
+        created_at: now,
+        updated_at: now,
+        ..Issue::default()
+    };
+    if let Some((original, thread_id)) = reply_to {
+        message.dependencies.push(Dependency {
+            issue_id: message.id.clone(),
+            depends_on_id: original.to_string(),
+            dep_type: DependencyType::RepliesTo,
+            created_at: now,
+            created_by: Some(actor.to_string()),
+            metadata: None,
+            thread_id: Some(thread_id.to_string()),
+        });
+    }
+    message.content_hash = Some(message.compute_content_hash());
+    storage.create_issue(&message, actor)?;
+    Ok(message)
+}
+
+fn print_sent(message: &Issue, to: &str, thread_id: &str, ctx: &OutputContext) {
+    if ctx.is_json() {
+        ctx.json_pretty(&serde_json::json!({
+            "id": message.id,
+            "to": to,
+            "thread_id": thread_id,
+        }));
+    } else if !ctx.is_quiet() {
+        println!("Sent {} to {to}", message.id);
+    }
+}
+
+/// The `replies-to` link of a message: `(replied-to message, thread ID)`.
+fn reply_link(storage: &dyn Storage, id: &str) -> Result<Option<(String, String)>> {
+    Ok(storage
+        .get_dependencies_full(id)?
+        .into_iter()
+        .find(|dep| dep.dep_type == DependencyType::RepliesTo)
+        .map(|dep| {
+            let thread_id = dep
+                .thread_id
+                .filter(|thread| !thread.is_empty())
+                .unwrap_or_else(|| dep.depends_on_id.clone());
+            (dep.depends_on_id, thread_id)
+        }))
+}
+
+fn thread_of(storage: &dyn Storage, id: &str) -> Result<Option<String>> {
+    Ok(reply_link(storage, id)?.map(|(_, thread_id)| thread_id))
+}
+
+fn inbox(
+    args: &MsgInboxArgs,
+    actor: &str,
+    storage: &dyn Storage,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let recipient = args
+        .recipient
+        .as_deref()
+        .map_or(actor, str::trim)
+        .to_string();
+    let mut messages = storage.list_issues(&ListFilters {
+        types: Some(vec![IssueType::Custom(MESSAGE_TYPE.to_string())]),
+        assignee: Some(recipient.clone()),
+        include_closed: args.all,
+        include_deferred: true,
+        ..ListFilters::default()
+    })?;
+    messages.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let mut views = Vec::with_capacity(messages.len());
+    for message in messages {
+        let link = reply_link(storage, &message.id)?;
+        views.push(MessageView {
+            from: message.sender.or(message.created_by).unwrap_or_default(),
+            subject: message.title,
+            text: message.description.unwrap_or_default(),
+            created_at: message.created_at,
+            status: message.status.as_str().to_string(),
+            thread_id: link
+                .as_ref()
+                .map_or_else(|| message.id.clone(), |(_, thread_id)| thread_id.clone()),
+            in_reply_to: link.map(|(original, _)| original),
+            id: message.id,
+        });
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&views);
+    } else if !ctx.is_quiet() {
+        if views.is_empty() {
+            println!("No messages for {recipient}.");
+        }
+        for view in &views {
+            let reply = view
+                .in_reply_to
+                .as_deref()
+                .map_or_else(String::new, |original| format!(" (re {original})"));
+            let state = if view.status == Status::Closed.as_str() {
+                "  [closed]"
+            } else {
+                ""
+            };
+            println!(
+                "{}  {:<10} from {}{reply}: {}{state}",
+                view.created_at.format("%Y-%m-%d %H:%M"),
+                view.id,
+                view.from,
+                view.subject
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::SqliteStorage;
+
+    fn layer() -> config::ConfigLayer {
+        config::default_config_layer()
+    }
+
+    #[test]
+    fn test_reply_threads_to_first_message() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let first = create_message(
+            &mut storage,
+            &layer(),
+            "lead",
+            "worker",
+            "Start task\nDetails",
+            None,
+        )
+        .unwrap();
+        assert!(first.ephemeral);
+        assert_eq!(first.title, "Start task");
+
+        let answer = create_message(
+            &mut storage,
+            &layer(),
+            "worker",
+            "lead",
+            "On it",
+            Some((first.id.as_str(), first.id.as_str())),
+        )
+        .unwrap();
+        let thread = thread_of(&storage, &answer.id).unwrap().unwrap();
+        assert_eq!(thread, first.id);
+
+        let follow_up = create_message(
+            &mut storage,
+            &layer(),
+            "lead",
+            "worker",
+            "Thanks",
+            Some((answer.id.as_str(), thread.as_str())),
+        )
+        .unwrap();
+        assert_eq!(
+            reply_link(&storage, &follow_up.id).unwrap(),
+            Some((answer.id.clone(), first.id.clone()))
+        );
+        assert!(create_message(&mut storage, &layer(), "lead", "worker", "  \n", None).is_err());
+    }
+}
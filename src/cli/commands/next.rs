@@ -0,0 +1,103 @@
+//! Next command implementation.
+//!
+//! Picks the single top ready issue, optionally claiming it. This is the one
+//! call an agent needs per iteration of its work loop.
+
+use crate::cli::NextArgs;
+use crate::cli::commands::ready::{parse_priorities, parse_types, ready_sort_policy};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::ReadyIssue;
+use crate::output::OutputContext;
+use crate::storage::ReadyFilters;
+use serde::Serialize;
+use std::collections::HashSet;
+use tracing::{debug, info};
+
+/// JSON output for the picked issue.
+#[derive(Serialize)]
+struct NextOutput {
+    #[serde(flatten)]
+    issue: ReadyIssue,
+    claimed: bool,
+}
+
+/// Execute the next command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, filters are invalid,
+/// or (in text mode) no issue is ready.
+pub fn execute(
+    args: &NextArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
+
+    // Only unassigned work is offered, so `next` shows what `next --claim` takes
+    let filters = ReadyFilters {
+        labels_and: args.label.clone(),
+        types: parse_types(&args.type_)?,
+        priorities: parse_priorities(&args.priority)?,
+        parent: args.parent.clone(),
+        unassigned: true,
+        ..ReadyFilters::default()
+    };
+    let sort_policy = ready_sort_policy(args.sort);
+
+    // Issues waiting on unresolved external dependencies are not ready
+    let storage = &mut *storage_ctx.storage;
+    let external_statuses =
+        storage.resolve_external_dependency_statuses(&external_db_paths, true)?;
+    let excluded: HashSet<String> = storage
+        .external_blockers(&external_statuses)?
+        .into_keys()
+        .collect();
+
+    debug!(filters = ?filters, sort = ?sort_policy, claim = args.claim, "Picking next issue");
+    let next = if args.claim {
+        storage
+            .claim_ready_issues(&filters, sort_policy, 1, &excluded, &actor)?
+            .pop()
+    } else {
+        storage.next_ready_issue(&filters, sort_policy, &excluded)?
+    };
+    info!(id = ?next.as_ref().map(|issue| &issue.id), claimed = args.claim, "Next issue");
+
+    if let Some(issue) = &next {
+        crate::util::set_last_touched_id(&beads_dir, &issue.id);
+    }
+
+    if ctx.is_json() {
+        let output = next.as_ref().map(|issue| NextOutput {
+            issue: ReadyIssue::from(issue),
+            claimed: args.claim,
+        });
+        ctx.json_pretty(&output);
+    } else if !ctx.is_quiet() {
+        if let Some(issue) = &next {
+            println!(
+                "{} [P{}] [{}] {}",
+                issue.id, issue.priority.0, issue.issue_type, issue.title
+            );
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+
+    // In `--json` mode `null` already tells agents there is nothing to do.
+    if !ctx.is_json() && next.is_none() {
+        return Err(BeadsError::NothingToDo {
+            reason: "no ready issues".to_string(),
+        });
+    }
+
+    Ok(())
+}
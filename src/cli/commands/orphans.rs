@@ -56,7 +56,7 @@ pub fn execute(
         output_empty(ctx.is_json() || args.robot, ctx);
         return Ok(());
     };
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
 
     // Get issue prefix from config
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
@@ -214,6 +214,7 @@ pub fn execute(
                         force: false,
                         session: None,
                         suggest_next: false,
+                        cascade: false,
                     };
 
                     if let Err(e) = close::execute_with_args(&close_args, false, cli, ctx) {
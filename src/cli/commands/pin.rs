@@ -0,0 +1,149 @@
+//! Pin and Unpin command implementations.
+//!
+//! Pinned issues sort first in `list` and `ready` and are marked with 📌 in
+//! text output. Pinning sets the `pinned` flag and leaves the status alone.
+
+use crate::cli::PinArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::storage::IssueUpdate;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use serde::Serialize;
+
+/// Issue whose pin state changed.
+#[derive(Debug, Serialize)]
+struct PinnedIssue {
+    id: String,
+    title: String,
+    pinned: bool,
+}
+
+/// Issue that was skipped because it was already in the requested state.
+#[derive(Debug, Serialize)]
+struct SkippedIssue {
+    id: String,
+    reason: String,
+}
+
+/// JSON output for the pin and unpin commands.
+#[derive(Debug, Serialize)]
+struct PinResult {
+    updated: Vec<PinnedIssue>,
+    skipped: Vec<SkippedIssue>,
+}
+
+/// Execute the pin command.
+///
+/// # Errors
+///
+/// Returns an error if database operations fail or IDs cannot be resolved.
+pub fn execute_pin(
+    args: &PinArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    set_pinned(args, true, cli, ctx)
+}
+
+/// Execute the unpin command.
+///
+/// # Errors
+///
+/// Returns an error if database operations fail or IDs cannot be resolved.
+pub fn execute_unpin(
+    args: &PinArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    set_pinned(args, false, cli, ctx)
+}
+
+fn set_pinned(
+    args: &PinArgs,
+    pinned: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    if args.ids.is_empty() {
+        return Err(BeadsError::validation(
+            "ids",
+            "at least one issue ID is required",
+        ));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+    let storage = &mut *storage_ctx.storage;
+
+    let resolved_ids = resolver.resolve_all(
+        &args.ids,
+        |id| all_ids.iter().any(|existing| existing == id),
+        |hash| find_matching_ids(&all_ids, hash),
+    )?;
+
+    let mut updated = Vec::new();
+    let mut skipped = Vec::new();
+    for resolved in &resolved_ids {
+        let id = &resolved.id;
+        let Some(issue) = storage.get_issue(id)? else {
+            skipped.push(SkippedIssue {
+                id: id.clone(),
+                reason: "issue not found".to_string(),
+            });
+            continue;
+        };
+        if issue.pinned == pinned {
+            skipped.push(SkippedIssue {
+                id: id.clone(),
+                reason: if pinned {
+                    "already pinned"
+                } else {
+                    "not pinned"
+                }
+                .to_string(),
+            });
+            continue;
+        }
+
+        let update = IssueUpdate {
+            pinned: Some(pinned),
+            ..Default::default()
+        };
+        storage.update_issue(id, &update, &actor)?;
+        tracing::info!(id = %id, pinned, "Updated pin state");
+        crate::util::set_last_touched_id(&beads_dir, id);
+
+        updated.push(PinnedIssue {
+            id: id.clone(),
+            title: issue.title,
+            pinned,
+        });
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&PinResult { updated, skipped });
+    } else {
+        let verb = if pinned { "Pinned" } else { "Unpinned" };
+        for issue in &updated {
+            ctx.success(&format!("{verb} {}: {}", issue.id, issue.title));
+        }
+        for skipped in &skipped {
+            ctx.info(&format!("Skipped {}: {}", skipped.id, skipped.reason));
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
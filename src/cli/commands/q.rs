@@ -7,6 +7,7 @@ use crate::util::id::IdGenerator;
 use crate::validation::LabelValidator;
 use chrono::Utc;
 use rich_rust::prelude::*;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 fn split_labels(values: &[String]) -> Vec<String> {
@@ -27,6 +28,7 @@ fn split_labels(values: &[String]) -> Vec<String> {
 /// # Errors
 ///
 /// Returns an error if validation fails, the database cannot be opened, or creation fails.
+#[allow(clippy::too_many_lines)]
 pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
     let title = args.title.join(" ").trim().to_string();
     if title.is_empty() {
@@ -35,17 +37,12 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
 
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
     let id_config = config::id_config_from_layer(&layer);
     let default_priority = config::default_priority_from_layer(&layer)?;
     let default_issue_type = config::default_issue_type_from_layer(&layer)?;
-    let storage = &mut storage_ctx.storage;
-
-    let priority = if let Some(p) = args.priority {
-        Priority::from_str(&p)?
-    } else {
-        default_priority
-    };
+    let mut type_defaults = config::type_defaults_from_layer(&layer)?;
+    let storage = &mut *storage_ctx.storage;
 
     let issue_type = if let Some(t) = args.type_ {
         IssueType::from_str(&t)?
@@ -53,6 +50,20 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
         default_issue_type
     };
 
+    let defaults = if args.no_defaults {
+        config::TypeDefaults::default()
+    } else {
+        type_defaults
+            .remove(issue_type.as_str())
+            .unwrap_or_default()
+    };
+
+    let priority = if let Some(p) = args.priority {
+        Priority::from_str(&p)?
+    } else {
+        defaults.priority.unwrap_or(default_priority)
+    };
+
     let id_gen = IdGenerator::new(id_config);
     let now = Utc::now();
     let count = storage.count_issues()?;
@@ -64,17 +75,17 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
     let mut issue = Issue {
         id,
         title,
-        description: None,
+        description: defaults.description,
         status: Status::Open,
         priority,
         issue_type,
         created_at: now,
         updated_at: now,
         content_hash: None,
-        design: None,
-        acceptance_criteria: None,
-        notes: None,
-        assignee: None,
+        design: defaults.design,
+        acceptance_criteria: defaults.acceptance_criteria,
+        notes: defaults.notes,
+        assignee: defaults.assignee,
         owner: None,
         estimated_minutes: None,
         created_by: None,
@@ -101,18 +112,28 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     };
 
     // Resolve actor and set created_by
-    let actor = config::resolve_actor(&layer);
+    let actor = config::actor(&layer);
     issue.created_by = Some(actor.clone());
 
     // Compute content hash
     issue.content_hash = Some(issue.compute_content_hash());
 
     storage.create_issue(&issue, &actor)?;
+    crate::util::set_last_touched_id(&beads_dir, &issue.id);
 
-    let labels = split_labels(&args.labels);
+    let mut labels = split_labels(&args.labels);
+    for label in defaults.labels {
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
     for label in labels {
         if let Err(err) = LabelValidator::validate(&label) {
             eprintln!("Warning: invalid label '{label}': {}", err.message);
@@ -60,6 +60,18 @@ pub struct SavedFilters {
     pub desc_contains: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub notes_contains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub touches: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dep_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub where_: Vec<String>,
     #[serde(default, skip_serializing_if = "is_false")]
     pub all: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -96,6 +108,12 @@ impl From<&ListArgs> for SavedFilters {
             title_contains: args.title_contains.clone(),
             desc_contains: args.desc_contains.clone(),
             notes_contains: args.notes_contains.clone(),
+            touches: args.touches.clone(),
+            depends_on: args.depends_on.clone(),
+            blocks: args.blocks.clone(),
+            dep_type: args.dep_type.clone(),
+            milestone: args.milestone.clone(),
+            where_: args.where_.clone(),
             all: args.all,
             limit: args.limit,
             sort: args.sort.clone(),
@@ -124,6 +142,12 @@ impl SavedFilters {
             title_contains: self.title_contains.clone(),
             desc_contains: self.desc_contains.clone(),
             notes_contains: self.notes_contains.clone(),
+            touches: self.touches.clone(),
+            depends_on: self.depends_on.clone(),
+            blocks: self.blocks.clone(),
+            dep_type: self.dep_type.clone(),
+            milestone: self.milestone.clone(),
+            where_: self.where_.clone(),
             all: self.all,
             limit: self.limit,
             sort: self.sort.clone(),
@@ -134,9 +158,15 @@ impl SavedFilters {
             long: false,
             pretty: false,
             wrap: false,
+            group_by: None,
             format: None,
             stats: false,
+            stream: false,
+            paginate: false,
+            after: None,
+            remotes: false,
             fields: None,
+            columns: None,
         }
     }
 
@@ -176,6 +206,11 @@ impl SavedFilters {
             } else {
                 cli.priority.clone()
             },
+            where_: if cli.where_.is_empty() {
+                base.where_
+            } else {
+                cli.where_.clone()
+            },
             // Option fields: CLI overrides if Some
             assignee: cli.assignee.clone().or(base.assignee),
             priority_min: cli.priority_min.or(base.priority_min),
@@ -183,6 +218,11 @@ impl SavedFilters {
             title_contains: cli.title_contains.clone().or(base.title_contains),
             desc_contains: cli.desc_contains.clone().or(base.desc_contains),
             notes_contains: cli.notes_contains.clone().or(base.notes_contains),
+            touches: cli.touches.clone().or(base.touches),
+            depends_on: cli.depends_on.clone().or(base.depends_on),
+            blocks: cli.blocks.clone().or(base.blocks),
+            dep_type: cli.dep_type.clone().or(base.dep_type),
+            milestone: cli.milestone.clone().or(base.milestone),
             limit: cli.limit.or(base.limit),
             sort: cli.sort.clone().or(base.sort),
             // Bool fields: CLI true overrides saved
@@ -195,9 +235,15 @@ impl SavedFilters {
             long: cli.long,
             pretty: cli.pretty,
             wrap: cli.wrap,
+            group_by: cli.group_by,
             format: cli.format,
             stats: cli.stats,
+            stream: cli.stream,
+            paginate: cli.paginate,
+            after: cli.after.clone(),
+            remotes: cli.remotes,
             fields: cli.fields.clone(),
+            columns: cli.columns.clone(),
         }
     }
 }
@@ -241,16 +287,16 @@ pub fn execute(
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
     match command {
-        QueryCommands::Save(args) => query_save(args, &mut storage_ctx.storage, ctx),
-        QueryCommands::Run(args) => query_run(args, &storage_ctx.storage, cli, &beads_dir, ctx),
-        QueryCommands::List => query_list(&storage_ctx.storage, ctx),
-        QueryCommands::Delete(args) => query_delete(args, &mut storage_ctx.storage, ctx),
+        QueryCommands::Save(args) => query_save(args, &mut *storage_ctx.storage, ctx),
+        QueryCommands::Run(args) => query_run(args, &*storage_ctx.storage, cli, &beads_dir, ctx),
+        QueryCommands::List => query_list(&*storage_ctx.storage, ctx),
+        QueryCommands::Delete(args) => query_delete(args, &mut *storage_ctx.storage, ctx),
     }
 }
 
 fn query_save(
     args: &QuerySaveArgs,
-    storage: &mut crate::storage::SqliteStorage,
+    storage: &mut dyn crate::storage::Storage,
     ctx: &OutputContext,
 ) -> Result<()> {
     let name = args.name.trim();
@@ -306,7 +352,7 @@ fn query_save(
 
 fn query_run(
     args: &QueryRunArgs,
-    storage: &crate::storage::SqliteStorage,
+    storage: &dyn crate::storage::Storage,
     cli: &config::CliOverrides,
     _beads_dir: &Path,
     ctx: &OutputContext,
@@ -334,7 +380,7 @@ fn query_run(
     super::list::execute(&merged_args, ctx.is_json(), cli, ctx)
 }
 
-fn query_list(storage: &crate::storage::SqliteStorage, ctx: &OutputContext) -> Result<()> {
+fn query_list(storage: &dyn crate::storage::Storage, ctx: &OutputContext) -> Result<()> {
     let all_config = storage.get_all_config()?;
 
     let mut queries: Vec<QueryListItem> = Vec::new();
@@ -388,7 +434,7 @@ fn query_list(storage: &crate::storage::SqliteStorage, ctx: &OutputContext) -> R
 
 fn query_delete(
     args: &QueryDeleteArgs,
-    storage: &mut crate::storage::SqliteStorage,
+    storage: &mut dyn crate::storage::Storage,
     ctx: &OutputContext,
 ) -> Result<()> {
     let name = args.name.trim();
@@ -839,6 +885,12 @@ mod tests {
             title_contains: Some("search term".to_string()),
             desc_contains: Some("description search".to_string()),
             notes_contains: Some("notes search".to_string()),
+            touches: Some("src/lib.rs".to_string()),
+            depends_on: Some("bd-1".to_string()),
+            blocks: Some("bd-2".to_string()),
+            dep_type: Some("related".to_string()),
+            milestone: Some("bd-3".to_string()),
+            where_: vec!["team=core".to_string()],
             all: true,
             limit: Some(25),
             sort: Some("created".to_string()),
@@ -863,6 +915,12 @@ mod tests {
         assert_eq!(parsed.title_contains, filters.title_contains);
         assert_eq!(parsed.desc_contains, filters.desc_contains);
         assert_eq!(parsed.notes_contains, filters.notes_contains);
+        assert_eq!(parsed.touches, filters.touches);
+        assert_eq!(parsed.depends_on, filters.depends_on);
+        assert_eq!(parsed.blocks, filters.blocks);
+        assert_eq!(parsed.dep_type, filters.dep_type);
+        assert_eq!(parsed.milestone, filters.milestone);
+        assert_eq!(parsed.where_, filters.where_);
         assert_eq!(parsed.all, filters.all);
         assert_eq!(parsed.limit, filters.limit);
         assert_eq!(parsed.sort, filters.sort);
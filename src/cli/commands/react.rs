@@ -0,0 +1,141 @@
+//! React command implementation.
+//!
+//! Reactions are short emoji or tags (`:+1:`, `👀`, `approved`) left on an
+//! issue or comment. They let agents and people acknowledge work without
+//! adding comments, and are shown by `br show`.
+
+use crate::cli::ReactArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::util::id::{IdResolver, ResolverConfig};
+use serde::Serialize;
+
+/// Longest accepted reaction, in characters.
+const MAX_REACTION_CHARS: usize = 32;
+
+/// JSON output for the react command.
+#[derive(Debug, Serialize)]
+struct ReactResult {
+    issue_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment_id: Option<i64>,
+    reaction: String,
+    actor: String,
+    /// `added`, `removed`, or `unchanged`
+    action: &'static str,
+}
+
+/// Execute the react command.
+///
+/// # Errors
+///
+/// Returns an error if the reaction is invalid, the issue or comment cannot
+/// be found, or the database update fails.
+pub fn execute(
+    args: &ReactArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let reaction = validate_reaction(&args.reaction)?;
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
+    let storage = &mut *storage_ctx.storage;
+
+    let issue_id = resolver
+        .resolve(
+            &args.id,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+        )?
+        .id;
+
+    let changed = if args.remove {
+        storage.remove_reaction(&issue_id, args.comment, reaction, &actor)?
+    } else {
+        storage.add_reaction(&issue_id, args.comment, reaction, &actor)?
+    };
+    storage_ctx.flush_no_db_if_dirty()?;
+    let action = match (changed, args.remove) {
+        (false, _) => "unchanged",
+        (true, false) => "added",
+        (true, true) => "removed",
+    };
+
+    let target = args.comment.map_or_else(
+        || issue_id.clone(),
+        |comment_id| format!("comment {comment_id} on {issue_id}"),
+    );
+    if ctx.is_json() {
+        ctx.json_pretty(&ReactResult {
+            issue_id,
+            comment_id: args.comment,
+            reaction: reaction.to_string(),
+            actor,
+            action,
+        });
+    } else if !changed {
+        let state = if args.remove {
+            "not present"
+        } else {
+            "already present"
+        };
+        ctx.info(&format!("Reaction {reaction} {state} on {target}"));
+    } else if args.remove {
+        ctx.success(&format!("Removed {reaction} from {target}"));
+    } else {
+        ctx.success(&format!("Reacted {reaction} to {target}"));
+    }
+
+    Ok(())
+}
+
+/// Trim and check a reaction: non-empty, no whitespace, at most
+/// [`MAX_REACTION_CHARS`] characters.
+fn validate_reaction(raw: &str) -> Result<&str> {
+    let reaction = raw.trim();
+    if reaction.is_empty() {
+        return Err(BeadsError::validation("reaction", "cannot be empty"));
+    }
+    if reaction.chars().any(char::is_whitespace) {
+        return Err(BeadsError::validation(
+            "reaction",
+            "cannot contain whitespace",
+        ));
+    }
+    if reaction.chars().count() > MAX_REACTION_CHARS {
+        return Err(BeadsError::validation(
+            "reaction",
+            format!("must be at most {MAX_REACTION_CHARS} characters"),
+        ));
+    }
+    Ok(reaction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_reaction_accepts_emoji_shortcodes_and_tags() {
+        assert_eq!(validate_reaction(" :+1: ").unwrap(), ":+1:");
+        assert_eq!(validate_reaction("👀").unwrap(), "👀");
+        assert_eq!(validate_reaction("approved").unwrap(), "approved");
+    }
+
+    #[test]
+    fn validate_reaction_rejects_blank_spaced_and_long() {
+        assert!(validate_reaction("  ").is_err());
+        assert!(validate_reaction("looks good").is_err());
+        assert!(validate_reaction(&"x".repeat(MAX_REACTION_CHARS + 1)).is_err());
+    }
+}
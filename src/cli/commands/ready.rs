@@ -1,15 +1,28 @@
 //! Ready command implementation.
 //!
-//! Shows issues ready to work on: unblocked, not deferred, not pinned, not ephemeral.
+//! Shows issues ready to work on: unblocked, not deferred, not ephemeral.
+//! Pinned issues are included and listed first under every sort policy.
 
-use crate::cli::{OutputFormat, ReadyArgs, SortPolicy, resolve_output_format_basic};
+use crate::alerts;
+use crate::cli::commands::list::{attach_labels, parse_dep_type_filter};
+use crate::cli::commands::resolve_issue_id;
+use crate::cli::{OutputFormat, ReadyArgs, SortPolicy, resolve_output_format};
 use crate::config;
+use crate::config::{AgingConfig, remotes};
 use crate::error::Result;
-use crate::format::{ReadyIssue, format_priority_badge, terminal_width, truncate_title};
-use crate::model::{IssueType, Priority};
+use crate::format::{
+    ReadyIssue, format_priority_badge, format_status_icon, table, tabular, terminal_width,
+    truncate_title,
+};
+use crate::model::{Issue, IssueType, Priority, Status};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ReadyFilters, ReadySortPolicy};
+use crate::storage::{ListFilters, ReadyFilters, ReadySortPolicy, Storage};
+use crate::util::time::parse_duration_minutes;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::io::IsTerminal;
+use std::path::Path;
 use std::str::FromStr;
 use tracing::{debug, info, trace};
 use unicode_width::UnicodeWidthStr;
@@ -28,7 +41,7 @@ pub fn execute(
     // Open storage
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
 
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
@@ -38,33 +51,26 @@ pub fn execute(
     } else {
         None
     };
-    let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), args.robot);
+    let output_format =
+        resolve_output_format(args.format.map(Into::into), outer_ctx.is_json(), args.robot);
     let quiet = cli.quiet.unwrap_or(false);
-    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+    let ctx = outer_ctx.with_output_format(output_format, quiet, use_color);
 
-    let filters = ReadyFilters {
-        assignee: args.assignee.clone(),
-        unassigned: args.unassigned,
-        labels_and: args.label.clone(),
-        labels_or: args.label_any.clone(),
-        types: parse_types(&args.type_)?,
-        priorities: parse_priorities(&args.priority)?,
-        include_deferred: args.include_deferred,
-        // Fetch all candidates to allow post-filtering of external blockers
-        limit: None,
-        parent: args.parent.clone(),
-        recursive: args.recursive,
-    };
-
-    let sort_policy = match args.sort {
-        SortPolicy::Hybrid => ReadySortPolicy::Hybrid,
-        SortPolicy::Priority => ReadySortPolicy::Priority,
-        SortPolicy::Oldest => ReadySortPolicy::Oldest,
-    };
+    let mut filters = ready_filters(args)?;
+    // --depends-on and --blocks may name partial IDs
+    for id in [&mut filters.depends_on, &mut filters.blocks]
+        .into_iter()
+        .flatten()
+    {
+        *id = resolve_issue_id(id, &beads_dir, storage, cli)?;
+    }
+    let sort_policy = ready_sort_policy(args.sort);
 
     info!("Fetching ready issues");
     debug!(filters = ?filters, sort = ?sort_policy, "Applied ready filters");
 
+    alerts::check_or_warn(&beads_dir, storage, cli);
+
     // Get ready issues from storage (blocked cache only)
     let mut ready_issues = storage.get_ready_issues(&filters, sort_policy)?;
 
@@ -75,6 +81,23 @@ pub fn execute(
         ready_issues.retain(|issue| !external_blockers.contains_key(&issue.id));
     }
 
+    if args.remotes {
+        merge_remote_ready(&mut ready_issues, &beads_dir, cli, &filters, sort_policy)?;
+    }
+
+    // Aging settings are only read when used, so a bad value can't break plain `ready`
+    let aging = if sort_policy == ReadySortPolicy::Aging || args.explain {
+        Some(config::aging_from_layer(&config_layer)?)
+    } else {
+        None
+    };
+    let now = Utc::now();
+    match (sort_policy, &aging, filters.max_minutes) {
+        (ReadySortPolicy::Fit, _, Some(budget)) => fit_to_budget(&mut ready_issues, budget),
+        (ReadySortPolicy::Aging, Some(aging), _) => rank_by_aging(&mut ready_issues, aging, now),
+        _ => {}
+    }
+
     // Apply limit after external filtering
     if args.limit > 0 && ready_issues.len() > args.limit {
         ready_issues.truncate(args.limit);
@@ -89,71 +112,436 @@ pub fn execute(
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
+    let explain = match aging.filter(|_| args.explain) {
+        Some(aging) => Some(build_explain_output(
+            storage,
+            &ready_issues,
+            &external_blockers,
+            sort_policy,
+            &aging,
+            now,
+        )?),
+        None => None,
+    };
     match output_format {
         OutputFormat::Json => {
-            let ready_output: Vec<ReadyIssue> = ready_issues.iter().map(ReadyIssue::from).collect();
-            ctx.json_pretty(&ready_output);
+            if let Some(explain) = &explain {
+                ctx.json_pretty(explain);
+            } else {
+                let ready_output: Vec<ReadyIssue> =
+                    ready_issues.iter().map(ReadyIssue::from).collect();
+                ctx.json_pretty(&ready_output);
+            }
         }
         OutputFormat::Toon => {
-            let ready_output: Vec<ReadyIssue> = ready_issues.iter().map(ReadyIssue::from).collect();
-            ctx.toon_with_stats(&ready_output, args.stats);
-        }
-        OutputFormat::Text | OutputFormat::Csv => {
-            if ready_issues.is_empty() {
-                // Match bd empty output format
-                println!("✨ No open issues");
-            } else if matches!(ctx.mode(), OutputMode::Rich) {
-                let columns = IssueTableColumns {
-                    id: true,
-                    priority: true,
-                    status: true,
-                    issue_type: true,
-                    title: true,
-                    ..Default::default()
-                };
-                let mut table = IssueTable::new(&ready_issues, ctx.theme())
-                    .columns(columns)
-                    .title(format!(
-                        "Ready work ({} issue{} with no blockers)",
-                        ready_issues.len(),
-                        if ready_issues.len() == 1 { "" } else { "s" }
-                    ))
-                    .wrap(args.wrap);
-                if args.wrap {
-                    table = table.width(Some(ctx.width()));
-                }
-                let table = table.build();
-                ctx.render(&table);
+            if let Some(explain) = &explain {
+                ctx.toon_with_stats(explain, args.stats);
             } else {
-                // Match bd header format: 📋 Ready work (N issues with no blockers):
-                println!(
-                    "📋 Ready work ({} issue{} with no blockers):\n",
-                    ready_issues.len(),
-                    if ready_issues.len() == 1 { "" } else { "s" }
-                );
-                for (i, issue) in ready_issues.iter().enumerate() {
-                    let line = format_ready_line(i + 1, issue, use_color, max_width, args.wrap);
-                    println!("{line}");
-                }
+                let ready_output: Vec<ReadyIssue> =
+                    ready_issues.iter().map(ReadyIssue::from).collect();
+                ctx.toon_with_stats(&ready_output, args.stats);
+            }
+        }
+        OutputFormat::Table => print_ready_table(args, storage, &config_layer, &mut ready_issues)?,
+        OutputFormat::Text | OutputFormat::Csv => print_ready_text(
+            args,
+            &ctx,
+            &ready_issues,
+            explain.as_ref(),
+            use_color,
+            max_width,
+        ),
+    }
+
+    Ok(())
+}
+
+/// Build storage filters from `br ready` arguments.
+fn ready_filters(args: &ReadyArgs) -> Result<ReadyFilters> {
+    Ok(ReadyFilters {
+        assignee: args.assignee.clone(),
+        unassigned: args.unassigned,
+        labels_and: args.label.clone(),
+        labels_or: args.label_any.clone(),
+        types: parse_types(&args.type_)?,
+        priorities: parse_priorities(&args.priority)?,
+        include_deferred: args.include_deferred,
+        // Fetch all candidates to allow post-filtering of external blockers
+        limit: None,
+        parent: args.parent.clone(),
+        recursive: args.recursive,
+        depends_on: args.depends_on.clone(),
+        blocks: args.blocks.clone(),
+        dep_type: parse_dep_type_filter(args.dep_type.as_deref())?,
+        max_minutes: args
+            .within
+            .as_deref()
+            .map(|within| parse_duration_minutes(within, "within"))
+            .transpose()?,
+    })
+}
+
+/// Map the CLI sort policy onto the storage one.
+pub(crate) const fn ready_sort_policy(policy: SortPolicy) -> ReadySortPolicy {
+    match policy {
+        SortPolicy::Hybrid => ReadySortPolicy::Hybrid,
+        SortPolicy::Priority => ReadySortPolicy::Priority,
+        SortPolicy::Oldest => ReadySortPolicy::Oldest,
+        SortPolicy::Fit => ReadySortPolicy::Fit,
+        SortPolicy::Aging => ReadySortPolicy::Aging,
+    }
+}
+
+/// Print `--format table` output.
+fn print_ready_table(
+    args: &ReadyArgs,
+    storage: &dyn Storage,
+    config_layer: &config::ConfigLayer,
+    issues: &mut [Issue],
+) -> Result<()> {
+    let columns = table::parse_columns(args.columns.as_deref(), &[])?;
+    let max_width = table::max_width(config::table_max_width_from_layer(config_layer));
+    if tabular::needs_labels(&columns) {
+        attach_labels(storage, issues)?;
+    }
+    print!(
+        "{}",
+        table::render_issues(issues.iter(), &columns, max_width, |_, _| None)
+    );
+    Ok(())
+}
+
+/// Print ready issues as text (a table in rich mode, unless explaining).
+///
+/// With `--explain`, each issue is followed by its ranking reasons, and the
+/// issues that were skipped are listed at the end.
+fn print_ready_text(
+    args: &ReadyArgs,
+    ctx: &OutputContext,
+    ready_issues: &[Issue],
+    explain: Option<&ReadyExplainOutput>,
+    use_color: bool,
+    max_width: Option<usize>,
+) {
+    if ready_issues.is_empty() {
+        // Match bd empty output format
+        println!("✨ No open issues");
+    } else if matches!(ctx.mode(), OutputMode::Rich) && explain.is_none() {
+        let columns = IssueTableColumns {
+            id: true,
+            priority: true,
+            status: true,
+            issue_type: true,
+            title: true,
+            ..Default::default()
+        };
+        let mut table = IssueTable::new(ready_issues, ctx.theme())
+            .columns(columns)
+            .title(format!(
+                "Ready work ({} issue{} with no blockers)",
+                ready_issues.len(),
+                if ready_issues.len() == 1 { "" } else { "s" }
+            ))
+            .wrap(args.wrap);
+        if args.wrap {
+            table = table.width(Some(ctx.width()));
+        }
+        let table = table.build();
+        ctx.render(&table);
+    } else {
+        // Match bd header format: 📋 Ready work (N issues with no blockers):
+        println!(
+            "📋 Ready work ({} issue{} with no blockers):\n",
+            ready_issues.len(),
+            if ready_issues.len() == 1 { "" } else { "s" }
+        );
+        for (i, issue) in ready_issues.iter().enumerate() {
+            let line = format_ready_line(i + 1, issue, use_color, max_width, args.wrap);
+            println!("{line}");
+            if let Some(explained) = explain.map(|e| &e.issues[i]) {
+                println!("     {}", explained.explain.reasons.join("; "));
             }
         }
     }
 
+    if let Some(explain) = explain.filter(|e| !e.excluded.is_empty()) {
+        println!("\nSkipped ({}):", explain.excluded.len());
+        for excluded in &explain.excluded {
+            println!(
+                "  [P{}] {}: {} ({})",
+                excluded.priority, excluded.id, excluded.title, excluded.reason
+            );
+        }
+    }
+}
+
+/// Merge ready work from registered remotes (IDs become `remote:id`).
+fn merge_remote_ready(
+    ready_issues: &mut Vec<Issue>,
+    beads_dir: &Path,
+    cli: &config::CliOverrides,
+    filters: &ReadyFilters,
+    sort_policy: ReadySortPolicy,
+) -> Result<()> {
+    let remote_storages = remotes::open_remote_storages(beads_dir, cli.lock_timeout)?;
+    if remote_storages.is_empty() {
+        return Ok(());
+    }
+    for remote in &remote_storages {
+        let mut remote_ready = remote.storage.get_ready_issues(filters, sort_policy)?;
+        debug!(remote = %remote.name, count = remote_ready.len(), "Fetched remote ready issues");
+        for issue in &mut remote_ready {
+            remotes::qualify_issue(issue, &remote.name);
+        }
+        ready_issues.extend(remote_ready);
+    }
+    sort_ready_issues(ready_issues, sort_policy);
     Ok(())
 }
 
+/// Re-apply the storage sort policy to a merged (local + remote) ready list.
+fn sort_ready_issues(issues: &mut [Issue], policy: ReadySortPolicy) {
+    match policy {
+        ReadySortPolicy::Hybrid | ReadySortPolicy::Fit => {
+            issues.sort_by_key(|issue| (!issue.pinned, issue.priority.0 > 1, issue.created_at));
+        }
+        ReadySortPolicy::Priority => {
+            issues.sort_by_key(|issue| (!issue.pinned, issue.priority.0, issue.created_at));
+        }
+        ReadySortPolicy::Oldest => issues.sort_by_key(|issue| (!issue.pinned, issue.created_at)),
+        ReadySortPolicy::Aging => {
+            issues.sort_by_key(|issue| (!issue.pinned, issue.priority.0, issue.updated_at));
+        }
+    }
+}
+
+/// Ranking details shown by `--explain`.
+#[derive(Debug, Serialize)]
+struct ReadyExplanation {
+    /// Position in the ready list (1-based)
+    rank: usize,
+    /// Effective priority the issue was ranked by (lower ranks first)
+    score: f64,
+    /// Days since the issue was last updated
+    age_days: f64,
+    /// Priority levels subtracted for age (only with `--sort aging`)
+    aging_boost: f64,
+    pinned: bool,
+    /// Days until the due date (negative when overdue)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_in_days: Option<f64>,
+    /// Human-readable reasons for the position
+    reasons: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ExplainedReadyIssue {
+    #[serde(flatten)]
+    issue: ReadyIssue,
+    explain: ReadyExplanation,
+}
+
+/// An open issue left out of the ready list, and why.
+#[derive(Debug, Serialize)]
+struct ExcludedIssue {
+    id: String,
+    title: String,
+    priority: i32,
+    reason: String,
+}
+
+/// `--explain` output: ranked issues plus the ones that were skipped.
+#[derive(Serialize)]
+struct ReadyExplainOutput {
+    issues: Vec<ExplainedReadyIssue>,
+    excluded: Vec<ExcludedIssue>,
+}
+
+/// Explain the ranking of `ready_issues` and list open issues that were not
+/// ready (blocked, deferred, or waiting on an external project).
+fn build_explain_output(
+    storage: &dyn Storage,
+    ready_issues: &[Issue],
+    external_blockers: &HashMap<String, Vec<String>>,
+    policy: ReadySortPolicy,
+    aging: &AgingConfig,
+    now: DateTime<Utc>,
+) -> Result<ReadyExplainOutput> {
+    let issues = ready_issues
+        .iter()
+        .enumerate()
+        .map(|(i, issue)| ExplainedReadyIssue {
+            issue: ReadyIssue::from(issue),
+            explain: explain_issue(issue, i + 1, policy, aging, now),
+        })
+        .collect();
+
+    let mut excluded = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    for (issue, blockers) in storage.get_blocked_issues()? {
+        let ids: Vec<&str> = blockers
+            .iter()
+            .map(|blocker| {
+                blocker
+                    .rsplit_once(':')
+                    .map_or(blocker.as_str(), |(id, _)| id)
+            })
+            .collect();
+        seen.insert(issue.id.clone());
+        excluded.push(excluded_issue(
+            &issue,
+            format!("blocked by {}", ids.join(", ")),
+        ));
+    }
+
+    let candidates = storage.list_issues(&ListFilters {
+        statuses: Some(vec![Status::Open, Status::InProgress, Status::Deferred]),
+        include_deferred: true,
+        ..ListFilters::default()
+    })?;
+    for issue in candidates {
+        if seen.contains(&issue.id) {
+            continue;
+        }
+        let reason = if let Some(blockers) = external_blockers.get(&issue.id) {
+            format!("waiting on {}", blockers.join(", "))
+        } else if let Some(until) = issue.defer_until.filter(|until| *until > now) {
+            format!("deferred until {}", until.format("%Y-%m-%d %H:%M UTC"))
+        } else if issue.status == Status::Deferred {
+            "status is deferred".to_string()
+        } else {
+            continue;
+        };
+        excluded.push(excluded_issue(&issue, reason));
+    }
+
+    excluded.sort_by(|a, b| a.priority.cmp(&b.priority).then_with(|| a.id.cmp(&b.id)));
+    Ok(ReadyExplainOutput { issues, excluded })
+}
+
+fn excluded_issue(issue: &Issue, reason: String) -> ExcludedIssue {
+    ExcludedIssue {
+        id: issue.id.clone(),
+        title: issue.title.clone(),
+        priority: issue.priority.0,
+        reason,
+    }
+}
+
+/// Days since `issue` was last updated, never negative.
+fn age_days(issue: &Issue, now: DateTime<Utc>) -> f64 {
+    (now - issue.updated_at).num_seconds().max(0) as f64 / 86_400.0
+}
+
+/// Effective priority under the aging policy: the issue's priority minus
+/// its age boost, so long-untouched work gradually moves up.
+fn aging_score(issue: &Issue, aging: &AgingConfig, now: DateTime<Utc>) -> f64 {
+    f64::from(issue.priority.0) - aging.boost(age_days(issue, now))
+}
+
+/// Sort by aging score (pinned first, then oldest first among equal scores).
+fn rank_by_aging(issues: &mut [Issue], aging: &AgingConfig, now: DateTime<Utc>) {
+    issues.sort_by(|a, b| {
+        b.pinned
+            .cmp(&a.pinned)
+            .then_with(|| aging_score(a, aging, now).total_cmp(&aging_score(b, aging, now)))
+            .then_with(|| a.created_at.cmp(&b.created_at))
+    });
+}
+
+fn explain_issue(
+    issue: &Issue,
+    rank: usize,
+    policy: ReadySortPolicy,
+    aging: &AgingConfig,
+    now: DateTime<Utc>,
+) -> ReadyExplanation {
+    let round = |value: f64| (value * 100.0).round() / 100.0;
+    let priority = issue.priority.0;
+    let age = age_days(issue, now);
+    let aging_boost = if policy == ReadySortPolicy::Aging {
+        aging.boost(age)
+    } else {
+        0.0
+    };
+    let due_in_days = issue
+        .due_at
+        .map(|due| round((due - now).num_seconds() as f64 / 86_400.0));
+
+    let mut reasons = Vec::new();
+    if issue.pinned {
+        reasons.push("pinned (listed first)".to_string());
+    }
+    reasons.push(match policy {
+        ReadySortPolicy::Hybrid | ReadySortPolicy::Fit if priority <= 1 => {
+            format!("P{priority}: high-priority tier, oldest first")
+        }
+        ReadySortPolicy::Hybrid | ReadySortPolicy::Fit => {
+            format!("P{priority}: normal tier, oldest first")
+        }
+        ReadySortPolicy::Priority => format!("P{priority}, then oldest first"),
+        ReadySortPolicy::Oldest => "oldest first (priority ignored)".to_string(),
+        ReadySortPolicy::Aging => {
+            format!("P{priority} boosted {aging_boost:.2} levels for age")
+        }
+    });
+    reasons.push(format!("updated {age:.1} days ago"));
+    if let Some(days) = due_in_days {
+        reasons.push(if days < 0.0 {
+            format!("overdue by {:.1} days", -days)
+        } else {
+            format!("due in {days:.1} days")
+        });
+    }
+
+    ReadyExplanation {
+        rank,
+        score: round(f64::from(priority) - aging_boost),
+        age_days: round(age),
+        aging_boost: round(aging_boost),
+        pinned: issue.pinned,
+        due_in_days,
+        reasons,
+    }
+}
+
+/// Greedily fill a time budget: walking the list in order, each issue whose
+/// estimate fits the minutes still left is taken and moved to the front.
+/// The remaining issues keep their order after the ones taken.
+fn fit_to_budget(issues: &mut Vec<Issue>, budget: i32) {
+    let mut remaining = budget;
+    let (fits, rest): (Vec<Issue>, Vec<Issue>) =
+        std::mem::take(issues)
+            .into_iter()
+            .partition(|issue| match issue.estimated_minutes {
+                Some(minutes) if minutes <= remaining => {
+                    remaining -= minutes;
+                    true
+                }
+                _ => false,
+            });
+    issues.extend(fits);
+    issues.extend(rest);
+}
+
 fn format_ready_line(
     index: usize,
-    issue: &crate::model::Issue,
+    issue: &Issue,
     use_color: bool,
     max_width: Option<usize>,
     wrap: bool,
 ) -> String {
     // Match bd format: {index}. [● P{n}] [{type}] {id}: {title}
+    // Pinned issues get a leading pushpin: {index}. 📌 [● P{n}] ...
+    let pin = if issue.pinned {
+        format!("{} ", format_status_icon(&Status::Pinned))
+    } else {
+        String::new()
+    };
     let priority_badge_plain = format!("[● {}]", crate::format::format_priority(&issue.priority));
     let type_badge_plain = format!("[{}]", issue.issue_type.as_str());
     let prefix_plain = format!(
-        "{index}. {priority_badge_plain} {type_badge_plain} {}: ",
+        "{index}. {pin}{priority_badge_plain} {type_badge_plain} {}: ",
         issue.id
     );
     let title = if wrap {
@@ -171,13 +559,13 @@ fn format_ready_line(
     let priority_badge = format_priority_badge(&issue.priority, use_color);
     let type_badge = crate::format::format_type_badge_colored(&issue.issue_type, use_color);
     format!(
-        "{index}. {priority_badge} {type_badge} {}: {title}",
+        "{index}. {pin}{priority_badge} {type_badge} {}: {title}",
         issue.id
     )
 }
 
 /// Parse type filter strings to `IssueType` enums.
-fn parse_types(types: &[String]) -> Result<Option<Vec<IssueType>>> {
+pub(crate) fn parse_types(types: &[String]) -> Result<Option<Vec<IssueType>>> {
     if types.is_empty() {
         return Ok(None);
     }
@@ -191,7 +579,7 @@ fn parse_types(types: &[String]) -> Result<Option<Vec<IssueType>>> {
 }
 
 /// Parse priority filter strings to Priority values.
-fn parse_priorities(priorities: &[String]) -> Result<Option<Vec<Priority>>> {
+pub(crate) fn parse_priorities(priorities: &[String]) -> Result<Option<Vec<Priority>>> {
     if priorities.is_empty() {
         return Ok(None);
     }
@@ -207,6 +595,7 @@ fn parse_priorities(priorities: &[String]) -> Result<Option<Vec<Priority>>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storage::SqliteStorage;
     use tracing::info;
 
     fn init_logging() {
@@ -237,4 +626,125 @@ mod tests {
         assert_eq!(p[2].0, 2);
         info!("test_parse_priorities: assertions passed");
     }
+
+    #[test]
+    fn test_fit_to_budget_fills_time_box_in_order() {
+        init_logging();
+        info!("test_fit_to_budget_fills_time_box_in_order: starting");
+        let issue = |id: &str, minutes: Option<i32>| Issue {
+            id: id.to_string(),
+            estimated_minutes: minutes,
+            ..Issue::default()
+        };
+        let mut issues = vec![
+            issue("a", Some(40)),
+            issue("b", Some(30)),
+            issue("c", None),
+            issue("d", Some(20)),
+            issue("e", Some(5)),
+        ];
+
+        fit_to_budget(&mut issues, 60);
+
+        // a (40) + d (20) fill the hour; b no longer fits and keeps its place
+        let ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "d", "b", "c", "e"]);
+        info!("test_fit_to_budget_fills_time_box_in_order: assertions passed");
+    }
+
+    #[test]
+    fn test_rank_by_aging_lifts_long_untouched_issues() {
+        init_logging();
+        info!("test_rank_by_aging_lifts_long_untouched_issues: starting");
+        let now = Utc::now();
+        let fresh = Issue {
+            id: "fresh".to_string(),
+            priority: Priority(1),
+            updated_at: now,
+            ..Issue::default()
+        };
+        let stale = Issue {
+            id: "stale".to_string(),
+            priority: Priority(3),
+            updated_at: now - chrono::Duration::days(60),
+            ..Issue::default()
+        };
+
+        // 60 days at 14 days/level is capped at 3 levels: P3 -> 0.0 beats P1
+        let mut issues = vec![fresh.clone(), stale.clone()];
+        rank_by_aging(&mut issues, &AgingConfig::default(), now);
+        assert_eq!(issues[0].id, "stale");
+
+        let capped = AgingConfig {
+            max_boost: 1.0,
+            ..AgingConfig::default()
+        };
+        let mut issues = vec![stale, fresh];
+        rank_by_aging(&mut issues, &capped, now);
+        assert_eq!(issues[0].id, "fresh");
+
+        let explain = explain_issue(&issues[1], 2, ReadySortPolicy::Aging, &capped, now);
+        assert!((explain.score - 2.0).abs() < f64::EPSILON);
+        assert!((explain.age_days - 60.0).abs() < 0.01);
+        info!("test_rank_by_aging_lifts_long_untouched_issues: assertions passed");
+    }
+
+    #[test]
+    fn test_explain_lists_reasons_and_skipped_issues() {
+        init_logging();
+        info!("test_explain_lists_reasons_and_skipped_issues: starting");
+        let now = Utc::now();
+        let mut storage = SqliteStorage::open_memory().expect("open storage");
+        let issue = |id: &str, title: &str| Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            created_at: now,
+            updated_at: now,
+            ..Issue::default()
+        };
+        let mut ready = issue("bd-1", "Ready and pinned");
+        ready.pinned = true;
+        ready.due_at = Some(now + chrono::Duration::days(2));
+        let blocked = issue("bd-2", "Blocked");
+        let mut deferred = issue("bd-3", "Deferred");
+        deferred.defer_until = Some(now + chrono::Duration::days(7));
+        for issue in [&ready, &blocked, &deferred] {
+            storage.create_issue(issue, "tester").expect("create issue");
+        }
+        storage
+            .add_dependency("bd-2", "bd-1", "blocks", "tester")
+            .expect("add dependency");
+
+        let output = build_explain_output(
+            &storage,
+            std::slice::from_ref(&ready),
+            &HashMap::new(),
+            ReadySortPolicy::Hybrid,
+            &AgingConfig::default(),
+            now,
+        )
+        .expect("explain");
+
+        let explain = &output.issues[0].explain;
+        assert_eq!(explain.rank, 1);
+        assert!(explain.pinned);
+        assert_eq!(explain.reasons[0], "pinned (listed first)");
+        assert!(
+            explain
+                .reasons
+                .iter()
+                .any(|r| r.starts_with("due in 2.0 days"))
+        );
+
+        let skipped: Vec<(&str, &str)> = output
+            .excluded
+            .iter()
+            .map(|e| (e.id.as_str(), e.reason.as_str()))
+            .collect();
+        assert_eq!(skipped.len(), 2);
+        assert_eq!(skipped[0], ("bd-2", "blocked by bd-1"));
+        assert_eq!(skipped[1].0, "bd-3");
+        assert!(skipped[1].1.starts_with("deferred until "));
+        info!("test_explain_lists_reasons_and_skipped_issues: assertions passed");
+    }
 }
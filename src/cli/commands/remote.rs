@@ -0,0 +1,161 @@
+//! Remote command implementation.
+//!
+//! Registers other beads workspaces so `list`, `ready`, and `show` can
+//! aggregate across them using repo-qualified IDs (`other:bd-abc12`).
+
+use crate::cli::{RemoteAddArgs, RemoteCommands, RemoteRemoveArgs};
+use crate::config;
+use crate::config::remotes::{
+    RemoteEntry, find_remote, load_remotes, save_remotes, validate_remote_name,
+};
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use serde::Serialize;
+use std::path::Path;
+use tracing::info;
+
+/// JSON output for a registered remote.
+#[derive(Serialize)]
+struct RemoteInfo {
+    name: String,
+    path: String,
+    beads_dir: Option<String>,
+    available: bool,
+}
+
+impl RemoteInfo {
+    fn from_entry(entry: &RemoteEntry, local_beads_dir: &Path) -> Self {
+        let resolved = entry.resolve_beads_dir(local_beads_dir).ok();
+        Self {
+            name: entry.name.clone(),
+            path: entry.path.clone(),
+            available: resolved.is_some(),
+            beads_dir: resolved.map(|dir| dir.display().to_string()),
+        }
+    }
+}
+
+/// JSON output for remote add/remove.
+#[derive(Serialize)]
+struct RemoteActionResult {
+    status: String,
+    name: String,
+    path: String,
+}
+
+/// Execute the remote command.
+///
+/// # Errors
+///
+/// Returns an error if the registry cannot be read or written, or inputs are invalid.
+pub fn execute(
+    command: &RemoteCommands,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+
+    match command {
+        RemoteCommands::Add(args) => remote_add(args, &beads_dir, ctx),
+        RemoteCommands::Remove(args) => remote_remove(args, &beads_dir, ctx),
+        RemoteCommands::List => remote_list(&beads_dir, ctx),
+    }
+}
+
+fn remote_add(args: &RemoteAddArgs, beads_dir: &Path, ctx: &OutputContext) -> Result<()> {
+    validate_remote_name(&args.name)?;
+
+    let mut remotes = load_remotes(beads_dir)?;
+    if find_remote(&remotes, &args.name).is_some() {
+        return Err(BeadsError::validation(
+            "name",
+            format!("remote '{}' already exists", args.name),
+        ));
+    }
+
+    let entry = RemoteEntry {
+        name: args.name.clone(),
+        path: args.path.clone(),
+    };
+    let remote_dir = entry.resolve_beads_dir(beads_dir)?;
+    if dunce::canonicalize(&remote_dir).ok() == dunce::canonicalize(beads_dir).ok() {
+        return Err(BeadsError::validation(
+            "path",
+            "remote points at the current workspace",
+        ));
+    }
+
+    info!(name = %entry.name, path = %entry.path, "Registering remote");
+    remotes.push(entry);
+    save_remotes(beads_dir, &remotes)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&RemoteActionResult {
+            status: "added".to_string(),
+            name: args.name.clone(),
+            path: args.path.clone(),
+        });
+    } else if !ctx.is_quiet() {
+        println!(
+            "\u{2713} Added remote '{}' -> {}",
+            args.name,
+            remote_dir.display()
+        );
+    }
+
+    Ok(())
+}
+
+fn remote_remove(args: &RemoteRemoveArgs, beads_dir: &Path, ctx: &OutputContext) -> Result<()> {
+    let mut remotes = load_remotes(beads_dir)?;
+    let Some(entry) = find_remote(&remotes, &args.name).cloned() else {
+        return Err(BeadsError::validation(
+            "name",
+            format!("no remote named '{}'", args.name),
+        ));
+    };
+
+    info!(name = %entry.name, "Removing remote");
+    remotes.retain(|r| r.name != entry.name);
+    save_remotes(beads_dir, &remotes)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&RemoteActionResult {
+            status: "removed".to_string(),
+            name: entry.name,
+            path: entry.path,
+        });
+    } else if !ctx.is_quiet() {
+        println!("\u{2713} Removed remote '{}'", entry.name);
+    }
+
+    Ok(())
+}
+
+fn remote_list(beads_dir: &Path, ctx: &OutputContext) -> Result<()> {
+    let infos: Vec<RemoteInfo> = load_remotes(beads_dir)?
+        .iter()
+        .map(|entry| RemoteInfo::from_entry(entry, beads_dir))
+        .collect();
+
+    if ctx.is_json() {
+        ctx.json_pretty(&infos);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+
+    if infos.is_empty() {
+        println!("No remotes registered.");
+        return Ok(());
+    }
+
+    for info in &infos {
+        let suffix = if info.available { "" } else { " (unavailable)" };
+        println!("{}\t{}{}", info.name, info.path, suffix);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+//! Rename command implementation.
+//!
+//! `br rename <old-id> <new-id>` changes an issue's ID (prefix, hash, or
+//! both). IDs nested under it (`old.1`, `old.1.2`) are renamed along with
+//! it, and every old ID is kept as an alias so references in commit
+//! messages and other tools keep resolving.
+
+use crate::cli::RenameArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::util::id::{
+    IdResolver, ResolverConfig, find_matching_ids, nested_id_renames, normalize_id, parse_id,
+};
+use serde::Serialize;
+
+/// An ID that was rewritten.
+#[derive(Debug, Serialize)]
+struct RenamedId {
+    old_id: String,
+    new_id: String,
+}
+
+/// JSON output for the rename command.
+#[derive(Debug, Serialize)]
+struct RenameResult {
+    id: String,
+    renamed: Vec<RenamedId>,
+}
+
+/// Execute the rename command.
+///
+/// # Errors
+///
+/// Returns an error if the old ID cannot be resolved, the new ID is invalid
+/// or already taken, or database operations fail.
+pub fn execute(
+    args: &RenameArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let new_id = normalize_id(args.new_id.trim());
+    let new_prefix = parse_id(&new_id)?.prefix;
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix.clone())
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+    let storage = &mut *storage_ctx.storage;
+
+    let old_id = resolver
+        .resolve(
+            &args.old_id,
+            |id| all_ids.binary_search_by(|p| p.as_str().cmp(id)).is_ok(),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?
+        .id;
+    if old_id == new_id {
+        return Err(BeadsError::validation(
+            "new_id",
+            format!("{old_id} already has that ID"),
+        ));
+    }
+    if new_id.starts_with(&format!("{old_id}.")) {
+        return Err(BeadsError::validation(
+            "new_id",
+            format!("cannot rename {old_id} to an ID nested under itself"),
+        ));
+    }
+
+    let renames = nested_id_renames(&all_ids, &old_id, &new_id);
+    storage.rename_issue_ids(&renames, &actor, &format!("Renamed {old_id} to {new_id}"))?;
+    tracing::info!(old_id = %old_id, new_id = %new_id, count = renames.len(), "Renamed issue");
+    crate::util::set_last_touched_id(&beads_dir, &new_id);
+
+    if new_prefix != id_config.prefix {
+        ctx.warning(&format!(
+            "{new_id} does not use the workspace prefix '{}'",
+            id_config.prefix
+        ));
+    }
+
+    let renamed: Vec<RenamedId> = renames
+        .into_iter()
+        .map(|(old_id, new_id)| RenamedId { old_id, new_id })
+        .collect();
+    if ctx.is_json() {
+        ctx.json_pretty(&RenameResult {
+            id: new_id,
+            renamed,
+        });
+    } else {
+        ctx.success(&format!("Renamed {old_id} to {new_id}"));
+        for rename in renamed.iter().skip(1) {
+            println!("  {} -> {}", rename.old_id, rename.new_id);
+        }
+        ctx.info(&format!("{old_id} still resolves as an alias"));
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
@@ -1,14 +1,18 @@
 //! Reopen command implementation.
+//!
+//! Reopening sets a closed (or deleted) issue back to `open` and records a
+//! `reopened` event carrying the `--reason`. Issues that depend on it are
+//! blocked again once the blocked cache is rebuilt.
 
 use crate::cli::ReopenArgs;
 use crate::config;
 use crate::error::{BeadsError, Result};
-use crate::model::Status;
 use crate::output::{OutputContext, OutputMode};
-use crate::storage::IssueUpdate;
+use crate::storage::Storage;
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
 use rich_rust::prelude::*;
 use serde::Serialize;
+use std::path::Path;
 
 /// Result of reopening a single issue.
 #[derive(Debug, Serialize)]
@@ -40,31 +44,45 @@ pub struct ReopenResult {
 /// # Errors
 ///
 /// Returns an error if database operations fail or IDs cannot be resolved.
-#[allow(clippy::too_many_lines)]
 pub fn execute(
     args: &ReopenArgs,
     json: bool,
     cli: &config::CliOverrides,
     ctx: &OutputContext,
 ) -> Result<()> {
-    let use_json = json || args.robot;
-
     tracing::info!("Executing reopen command");
 
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+
+    let result = reopen_issues(args, &mut *storage_ctx.storage, &beads_dir, &config_layer)?;
+    print_result(args, json || args.robot, &result, ctx);
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
 
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
-    let actor = config::resolve_actor(&config_layer);
-    let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
-    let all_ids = storage_ctx.storage.get_all_ids()?;
-    let storage = &mut storage_ctx.storage;
+/// Reopen the requested issues (or the last-touched one).
+fn reopen_issues(
+    args: &ReopenArgs,
+    storage: &mut dyn Storage,
+    beads_dir: &Path,
+    config_layer: &config::ConfigLayer,
+) -> Result<ReopenResult> {
+    let actor = config::actor(config_layer);
+    let id_config = config::id_config_from_layer(config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage.get_id_aliases()?)
+            .with_last_touched(crate::util::get_last_touched_id(beads_dir)),
+    );
+    let all_ids = storage.get_all_ids()?;
 
     // Get IDs - use last touched if none provided
     let mut ids = args.ids.clone();
     if ids.is_empty() {
-        let last_touched = crate::util::get_last_touched_id(&beads_dir);
+        let last_touched = crate::util::get_last_touched_id(beads_dir);
         if last_touched.is_empty() {
             return Err(BeadsError::validation(
                 "ids",
@@ -109,20 +127,9 @@ pub fn execute(
 
         tracing::debug!(previous_status = ?issue.status, "Issue was previously {:?}", issue.status);
 
-        // Build update: set status=open, clear closed_at, clear tombstone fields
-        let update = IssueUpdate {
-            status: Some(Status::Open),
-            closed_at: Some(None),         // Clear closed_at
-            close_reason: Some(None),      // Clear close_reason
-            closed_by_session: Some(None), // Clear closed_by_session
-            deleted_at: Some(None),        // Clear deleted_at
-            deleted_by: Some(None),        // Clear deleted_by
-            delete_reason: Some(None),     // Clear delete_reason
-            ..Default::default()
-        };
-
-        // Apply update
-        storage.update_issue(id, &update, &actor)?;
+        // Clears the close and tombstone fields, records the Reopened event
+        // (with the reason) and re-blocks dependents
+        storage.reopen_issue(id, args.reason.as_deref(), &actor)?;
         tracing::info!(id = %id, reason = ?args.reason, "Issue reopened");
 
         // Add comment if reason provided
@@ -133,7 +140,7 @@ pub fn execute(
         }
 
         // Update last touched
-        crate::util::set_last_touched_id(&beads_dir, id);
+        crate::util::set_last_touched_id(beads_dir, id);
 
         reopened_issues.push(ReopenedIssue {
             id: id.clone(),
@@ -143,27 +150,30 @@ pub fn execute(
         });
     }
 
-    // Output
+    Ok(ReopenResult {
+        reopened: reopened_issues,
+        skipped: skipped_issues,
+    })
+}
+
+/// Print the outcome in the requested format.
+fn print_result(args: &ReopenArgs, use_json: bool, result: &ReopenResult, ctx: &OutputContext) {
     if use_json {
-        let result = ReopenResult {
-            reopened: reopened_issues,
-            skipped: skipped_issues,
-        };
         if ctx.is_json() {
-            ctx.json_pretty(&result);
+            ctx.json_pretty(result);
         } else {
             let json_ctx = OutputContext::from_flags(true, false, true);
-            json_ctx.json_pretty(&result);
+            json_ctx.json_pretty(result);
         }
     } else if matches!(ctx.mode(), OutputMode::Rich) {
         render_reopen_rich(
-            &reopened_issues,
-            &skipped_issues,
+            &result.reopened,
+            &result.skipped,
             args.reason.as_deref(),
             ctx,
         );
     } else {
-        for reopened in &reopened_issues {
+        for reopened in &result.reopened {
             print!("\u{2713} Reopened {}: {}", reopened.id, reopened.title);
             if let Some(ref reason) = args.reason {
                 println!(" ({reason})");
@@ -171,16 +181,13 @@ pub fn execute(
                 println!();
             }
         }
-        for skipped in &skipped_issues {
+        for skipped in &result.skipped {
             println!("\u{2298} Skipped {}: {}", skipped.id, skipped.reason);
         }
-        if reopened_issues.is_empty() && skipped_issues.is_empty() {
+        if result.reopened.is_empty() && result.skipped.is_empty() {
             println!("No issues to reopen.");
         }
     }
-
-    storage_ctx.flush_no_db_if_dirty()?;
-    Ok(())
 }
 
 /// Render reopen results with rich formatting.
@@ -0,0 +1,223 @@
+//! Reparent and Promote command implementations.
+//!
+//! `br reparent <id> --to <parent>` moves an issue under a new parent and
+//! `br promote <id>` moves it to the top level. Hierarchical IDs
+//! (`bd-abc.1`) are rewritten to match the new position, along with the IDs
+//! of every descendant; issues with plain IDs only get their parent-child
+//! edge replaced. Old IDs are kept as aliases so they still resolve.
+
+use crate::cli::{PromoteArgs, ReparentArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::DependencyType;
+use crate::output::OutputContext;
+use crate::storage::Storage;
+use crate::util::id::{
+    IdConfig, IdGenerator, IdResolver, ResolverConfig, child_id, find_matching_ids, is_child_id,
+    nested_id_renames,
+};
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// An ID that was rewritten.
+#[derive(Debug, Serialize)]
+struct RenamedId {
+    old_id: String,
+    new_id: String,
+}
+
+/// JSON output for the reparent and promote commands.
+#[derive(Debug, Serialize)]
+struct MoveResult {
+    id: String,
+    old_parent: Option<String>,
+    new_parent: Option<String>,
+    /// IDs rewritten for the issue and its descendants (empty for plain IDs)
+    renamed: Vec<RenamedId>,
+}
+
+/// Execute the reparent command.
+///
+/// # Errors
+///
+/// Returns an error if an ID cannot be resolved, the move would create a
+/// cycle, or database operations fail.
+pub fn execute_reparent(
+    args: &ReparentArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    move_issue(&args.id, Some(&args.to), cli, ctx)
+}
+
+/// Execute the promote command.
+///
+/// # Errors
+///
+/// Returns an error if the ID cannot be resolved or database operations fail.
+pub fn execute_promote(
+    args: &PromoteArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    move_issue(&args.id, None, cli, ctx)
+}
+
+fn move_issue(
+    id_input: &str,
+    parent_input: Option<&str>,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix.clone())
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+    let storage = &mut *storage_ctx.storage;
+
+    let resolve = |input: &str| {
+        resolver
+            .resolve(
+                input,
+                |id| all_ids.binary_search_by(|p| p.as_str().cmp(id)).is_ok(),
+                |hash| find_matching_ids(&all_ids, hash),
+            )
+            .map(|resolved| resolved.id)
+    };
+    let id = resolve(id_input)?;
+    let new_parent = parent_input.map(resolve).transpose()?;
+    let old_parent = storage.get_parent_id(&id)?;
+
+    if let Some(parent) = &new_parent {
+        if *parent == id {
+            return Err(BeadsError::validation(
+                "to",
+                "issue cannot be its own parent",
+            ));
+        }
+        if descendants(storage, &id)?.contains(parent) {
+            return Err(BeadsError::validation(
+                "to",
+                format!("cannot move {id} under its own descendant {parent}"),
+            ));
+        }
+    }
+    if old_parent == new_parent && (new_parent.is_some() || !is_child_id(&id)) {
+        return Err(BeadsError::validation(
+            "id",
+            new_parent.as_ref().map_or_else(
+                || format!("{id} is already top-level"),
+                |parent| format!("{id} is already under {parent}"),
+            ),
+        ));
+    }
+
+    let summary = new_parent.as_ref().map_or_else(
+        || format!("Promoted {id} to top level"),
+        |parent| format!("Moved {id} under {parent}"),
+    );
+    let renamed: Vec<RenamedId> = if is_child_id(&id) {
+        let new_id = next_id(storage, &id, new_parent.as_deref(), &id_config)?;
+        let renames = nested_id_renames(&all_ids, &id, &new_id);
+        storage.rename_issue_ids(&renames, &actor, &summary)?;
+        renames
+            .into_iter()
+            .map(|(old_id, new_id)| RenamedId { old_id, new_id })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let current_id = renamed
+        .first()
+        .map_or_else(|| id.clone(), |rename| rename.new_id.clone());
+
+    storage.remove_parent(&current_id, &actor)?;
+    if let Some(parent) = &new_parent {
+        storage.add_dependency(
+            &current_id,
+            parent,
+            DependencyType::ParentChild.as_str(),
+            &actor,
+        )?;
+    }
+    tracing::info!(id = %id, new_id = %current_id, parent = ?new_parent, "Moved issue");
+    crate::util::set_last_touched_id(&beads_dir, &current_id);
+
+    let result = MoveResult {
+        id: current_id,
+        old_parent,
+        new_parent,
+        renamed,
+    };
+    if ctx.is_json() {
+        ctx.json_pretty(&result);
+    } else {
+        ctx.success(&summary);
+        for rename in &result.renamed {
+            println!("  {} -> {}", rename.old_id, rename.new_id);
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// All issues below `id` through parent-child edges.
+pub(crate) fn descendants(storage: &dyn Storage, id: &str) -> Result<HashSet<String>> {
+    let mut found = HashSet::new();
+    let mut stack = vec![id.to_string()];
+    while let Some(current) = stack.pop() {
+        for dependent in storage.get_dependents_with_metadata(&current)? {
+            if dependent.dep_type == DependencyType::ParentChild.as_str()
+                && found.insert(dependent.id.clone())
+            {
+                stack.push(dependent.id);
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// Pick the issue's new ID: the next free child slot of the new parent, or a
+/// fresh top-level ID when promoting.
+fn next_id(
+    storage: &dyn Storage,
+    id: &str,
+    new_parent: Option<&str>,
+    id_config: &IdConfig,
+) -> Result<String> {
+    if let Some(parent) = new_parent {
+        let mut num = storage.next_child_number(parent)?;
+        loop {
+            let candidate = child_id(parent, num);
+            if !storage.id_exists(&candidate)? {
+                return Ok(candidate);
+            }
+            num = num.saturating_add(1);
+        }
+    }
+
+    let title = storage
+        .get_issue(id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?
+        .title;
+    let id_gen = IdGenerator::new(id_config.clone());
+    Ok(id_gen.generate(
+        &title,
+        None,
+        None,
+        Utc::now(),
+        storage.count_issues()?,
+        |candidate| storage.id_exists(candidate).unwrap_or(false),
+    ))
+}
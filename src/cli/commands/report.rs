@@ -0,0 +1,322 @@
+//! Report command implementation.
+//!
+//! Renders a Markdown status report (open work by priority, recently closed,
+//! blocked with blockers, stale) suitable for PR descriptions or standup notes.
+//! A custom `--template` can rearrange the sections via `{{placeholder}}`s.
+
+use crate::cli::ReportArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, Status};
+use crate::output::OutputContext;
+use crate::storage::{ListFilters, Storage};
+use crate::util::template::render_template;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use tracing::debug;
+
+/// Template used when `--template` is not given.
+const DEFAULT_TEMPLATE: &str = "# Status Report ({{date}})
+
+**{{open_count}}** open · **{{in_progress_count}}** in progress · **{{blocked_count}}** blocked · **{{closed_count}}** closed in the last {{closed_days}} days
+
+## Open by Priority
+
+{{open_by_priority}}
+## Recently Closed
+
+{{recently_closed}}
+## Blocked
+
+{{blocked}}
+## Stale (no update in {{stale_days}}+ days)
+
+{{stale}}";
+
+/// JSON output for the report command.
+#[derive(Serialize)]
+struct ReportOutput {
+    generated_at: String,
+    open_count: usize,
+    in_progress_count: usize,
+    blocked_count: usize,
+    closed_count: usize,
+    stale_count: usize,
+    markdown: String,
+}
+
+/// Execute the report command.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails, the template cannot be read
+/// or references an unknown placeholder, or the output cannot be written.
+pub fn execute(
+    args: &ReportArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    if args.closed_days < 0 {
+        return Err(BeadsError::validation("closed_days", "must be >= 0"));
+    }
+    if args.stale_days < 0 {
+        return Err(BeadsError::validation("stale_days", "must be >= 0"));
+    }
+
+    let template = match &args.template {
+        Some(path) => fs::read_to_string(path)?,
+        None => DEFAULT_TEMPLATE.to_string(),
+    };
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &*storage_ctx.storage;
+
+    let now = Utc::now();
+    let data = ReportData::collect(storage, now, args)?;
+    debug!(
+        open = data.open.len(),
+        closed = data.closed.len(),
+        blocked = data.blocked.len(),
+        stale = data.stale.len(),
+        "Collected report data"
+    );
+
+    let in_progress_count = data
+        .open
+        .iter()
+        .filter(|issue| issue.status == Status::InProgress)
+        .count();
+    let mut vars: BTreeMap<&str, String> = BTreeMap::new();
+    vars.insert("date", now.format("%Y-%m-%d").to_string());
+    vars.insert("open_count", data.open.len().to_string());
+    vars.insert("in_progress_count", in_progress_count.to_string());
+    vars.insert("blocked_count", data.blocked.len().to_string());
+    vars.insert("closed_count", data.closed.len().to_string());
+    vars.insert("stale_count", data.stale.len().to_string());
+    vars.insert("closed_days", args.closed_days.to_string());
+    vars.insert("stale_days", args.stale_days.to_string());
+    vars.insert("open_by_priority", open_by_priority_section(&data.open));
+    vars.insert("recently_closed", recently_closed_section(&data.closed));
+    vars.insert("blocked", blocked_section(&data.blocked));
+    vars.insert("stale", stale_section(&data.stale, now));
+
+    let markdown = render_template(&template, &vars)?;
+
+    if let Some(path) = &args.output {
+        fs::write(path, &markdown)?;
+        if !ctx.is_json() && !ctx.is_quiet() {
+            println!("\u{2713} Wrote report to {}", path.display());
+        }
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&ReportOutput {
+            generated_at: now.to_rfc3339(),
+            open_count: data.open.len(),
+            in_progress_count,
+            blocked_count: data.blocked.len(),
+            closed_count: data.closed.len(),
+            stale_count: data.stale.len(),
+            markdown,
+        });
+    } else if args.output.is_none() && !ctx.is_quiet() {
+        print!("{markdown}");
+        if !markdown.ends_with('\n') {
+            println!();
+        }
+    }
+
+    Ok(())
+}
+
+/// Issues feeding each report section.
+struct ReportData {
+    open: Vec<Issue>,
+    closed: Vec<Issue>,
+    blocked: Vec<(Issue, Vec<String>)>,
+    stale: Vec<Issue>,
+}
+
+impl ReportData {
+    fn collect(storage: &dyn Storage, now: DateTime<Utc>, args: &ReportArgs) -> Result<Self> {
+        let active = Some(vec![Status::Open, Status::InProgress]);
+
+        let open = storage.list_issues(&ListFilters {
+            statuses: active.clone(),
+            ..ListFilters::default()
+        })?;
+
+        let closed_since = now - Duration::days(args.closed_days);
+        let mut closed: Vec<Issue> = storage
+            .list_issues(&ListFilters {
+                statuses: Some(vec![Status::Closed]),
+                include_closed: true,
+                ..ListFilters::default()
+            })?
+            .into_iter()
+            .filter(|issue| issue.closed_at.is_some_and(|at| at >= closed_since))
+            .collect();
+        closed.sort_by(|a, b| b.closed_at.cmp(&a.closed_at));
+
+        let blocked = storage.get_blocked_issues()?;
+
+        // Oldest update first: updated_at sorts DESC by default, reverse gives ASC
+        let stale = storage.list_issues(&ListFilters {
+            statuses: active,
+            updated_before: Some(now - Duration::days(args.stale_days)),
+            sort: Some("updated_at".to_string()),
+            reverse: true,
+            ..ListFilters::default()
+        })?;
+
+        Ok(Self {
+            open,
+            closed,
+            blocked,
+            stale,
+        })
+    }
+}
+
+fn issue_bullet(issue: &Issue) -> String {
+    let mut line = format!("- `{}` {}", issue.id, issue.title);
+    if let Some(assignee) = issue.assignee.as_deref() {
+        let _ = write!(line, " (@{assignee})");
+    }
+    line
+}
+
+fn none_line() -> String {
+    "_None._\n".to_string()
+}
+
+fn open_by_priority_section(open: &[Issue]) -> String {
+    if open.is_empty() {
+        return none_line();
+    }
+    let mut by_priority: BTreeMap<i32, Vec<&Issue>> = BTreeMap::new();
+    for issue in open {
+        by_priority.entry(issue.priority.0).or_default().push(issue);
+    }
+
+    let mut out = String::new();
+    for (priority, issues) in by_priority {
+        let _ = writeln!(out, "### P{priority} ({})\n", issues.len());
+        for issue in issues {
+            let marker = if issue.status == Status::InProgress {
+                " _(in progress)_"
+            } else {
+                ""
+            };
+            let _ = writeln!(out, "{}{marker}", issue_bullet(issue));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn recently_closed_section(closed: &[Issue]) -> String {
+    if closed.is_empty() {
+        return none_line();
+    }
+    let mut out = String::new();
+    for issue in closed {
+        let date = issue
+            .closed_at
+            .map(|at| at.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+        let _ = writeln!(out, "{} — closed {date}", issue_bullet(issue));
+    }
+    out
+}
+
+fn blocked_section(blocked: &[(Issue, Vec<String>)]) -> String {
+    if blocked.is_empty() {
+        return none_line();
+    }
+    let mut out = String::new();
+    for (issue, blockers) in blocked {
+        let blockers = blockers
+            .iter()
+            .map(|id| format!("`{id}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let _ = writeln!(out, "{} — blocked by {blockers}", issue_bullet(issue));
+    }
+    out
+}
+
+fn stale_section(stale: &[Issue], now: DateTime<Utc>) -> String {
+    if stale.is_empty() {
+        return none_line();
+    }
+    let mut out = String::new();
+    for issue in stale {
+        let days = (now - issue.updated_at).num_days().max(0);
+        let _ = writeln!(out, "{} — {days}d since update", issue_bullet(issue));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Priority;
+
+    fn issue(id: &str, priority: i32, status: Status) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: format!("Title {id}"),
+            priority: Priority(priority),
+            status,
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn open_section_groups_by_priority() {
+        let open = vec![
+            issue("bd-2", 2, Status::Open),
+            issue("bd-0", 0, Status::InProgress),
+            issue("bd-3", 2, Status::Open),
+        ];
+        let section = open_by_priority_section(&open);
+        assert_eq!(
+            section,
+            "### P0 (1)\n\n- `bd-0` Title bd-0 _(in progress)_\n\n\
+             ### P2 (2)\n\n- `bd-2` Title bd-2\n- `bd-3` Title bd-3\n\n"
+        );
+    }
+
+    #[test]
+    fn empty_sections_render_none() {
+        assert_eq!(open_by_priority_section(&[]), "_None._\n");
+        assert_eq!(blocked_section(&[]), "_None._\n");
+    }
+
+    #[test]
+    fn default_template_uses_only_known_placeholders() {
+        let names = [
+            "date",
+            "open_count",
+            "in_progress_count",
+            "blocked_count",
+            "closed_count",
+            "stale_count",
+            "closed_days",
+            "stale_days",
+            "open_by_priority",
+            "recently_closed",
+            "blocked",
+            "stale",
+        ];
+        let vars: BTreeMap<&str, String> =
+            names.iter().map(|name| (*name, String::new())).collect();
+        assert!(render_template(DEFAULT_TEMPLATE, &vars).is_ok());
+    }
+}
@@ -0,0 +1,173 @@
+//! Restore command implementation.
+//!
+//! Undoes `br delete`: clears the tombstone fields, records a `restored`
+//! event, and re-checks the restored issue's dependencies so links to
+//! issues that are missing or still deleted are reported.
+
+use crate::cli::RestoreArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::Status;
+use crate::output::OutputContext;
+use crate::storage::Storage;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use serde::Serialize;
+
+/// Issue that was restored.
+#[derive(Debug, Serialize)]
+struct RestoredIssue {
+    id: String,
+    title: String,
+    status: String,
+    /// Dependency problems found after restoring
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
+}
+
+/// Issue that was skipped.
+#[derive(Debug, Serialize)]
+struct SkippedIssue {
+    id: String,
+    reason: String,
+}
+
+/// JSON output for the restore command.
+#[derive(Debug, Serialize)]
+struct RestoreResult {
+    restored: Vec<RestoredIssue>,
+    skipped: Vec<SkippedIssue>,
+}
+
+/// Execute the restore command.
+///
+/// # Errors
+///
+/// Returns an error if database operations fail or IDs cannot be resolved.
+pub fn execute(
+    args: &RestoreArgs,
+    _json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    if args.ids.is_empty() {
+        return Err(BeadsError::validation(
+            "ids",
+            "at least one issue ID is required",
+        ));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+    let storage = &mut *storage_ctx.storage;
+
+    let resolved_ids = resolver.resolve_all(
+        &args.ids,
+        |id| all_ids.binary_search_by(|p| p.as_str().cmp(id)).is_ok(),
+        |hash| find_matching_ids(&all_ids, hash),
+    )?;
+
+    let mut restored = Vec::new();
+    let mut skipped = Vec::new();
+    for resolved in &resolved_ids {
+        let id = &resolved.id;
+        if !storage.is_tombstone(id)? {
+            skipped.push(SkippedIssue {
+                id: id.clone(),
+                reason: "not deleted".to_string(),
+            });
+            continue;
+        }
+
+        let issue = storage.restore_issue(id, &actor, args.reason.as_deref())?;
+        tracing::info!(id = %id, status = %issue.status.as_str(), "Restored issue");
+        crate::util::set_last_touched_id(&beads_dir, id);
+
+        restored.push(RestoredIssue {
+            id: id.clone(),
+            title: issue.title,
+            status: issue.status.as_str().to_string(),
+            warnings: dependency_warnings(storage, id)?,
+        });
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&RestoreResult { restored, skipped });
+    } else {
+        for issue in &restored {
+            ctx.success(&format!(
+                "Restored {}: {} ({})",
+                issue.id, issue.title, issue.status
+            ));
+            for warning in &issue.warnings {
+                ctx.warning(&format!("  {warning}"));
+            }
+        }
+        for skipped in &skipped {
+            ctx.info(&format!("Skipped {}: {}", skipped.id, skipped.reason));
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Report dependencies of `id` whose target is missing or still deleted.
+///
+/// External references (`external:...`) are not checked.
+fn dependency_warnings(storage: &dyn Storage, id: &str) -> Result<Vec<String>> {
+    let mut warnings = Vec::new();
+    for target in storage.get_dependencies(id)? {
+        if target.starts_with("external:") {
+            continue;
+        }
+        match storage.get_issue(&target)? {
+            None => warnings.push(format!("depends on missing issue {target}")),
+            Some(issue) if issue.status == Status::Tombstone => {
+                warnings.push(format!("depends on deleted issue {target}"));
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Issue;
+    use crate::storage::SqliteStorage;
+
+    #[test]
+    fn dependency_warnings_flag_deleted_targets() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        for id in ["bd-1", "bd-2", "bd-3"] {
+            let issue = Issue {
+                id: id.to_string(),
+                title: format!("Issue {id}"),
+                ..Issue::default()
+            };
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-1", "bd-2", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-1", "bd-3", "blocks", "tester")
+            .unwrap();
+        storage
+            .delete_issue("bd-3", "tester", "delete", None)
+            .unwrap();
+
+        let warnings = dependency_warnings(&storage, "bd-1").unwrap();
+        assert_eq!(warnings, vec!["depends on deleted issue bd-3".to_string()]);
+    }
+}
@@ -7,7 +7,8 @@
 use crate::cli::{OutputFormat, SchemaArgs, SchemaTarget, resolve_output_format_basic};
 use crate::error::Result;
 use crate::format::{
-    BlockedIssue, IssueDetails, IssueWithCounts, ReadyIssue, StaleIssue, Statistics, TreeNode,
+    BlockedIssue, IssueDetails, IssueWithCounts, ReadyIssue, SearchResult, StaleIssue, Statistics,
+    TreeNode,
 };
 use crate::model::Issue;
 use crate::output::{OutputContext, OutputMode};
@@ -27,6 +28,9 @@ struct ErrorEnvelope {
 struct ErrorBody {
     /// Machine-readable error code (SCREAMING_SNAKE_CASE)
     code: String,
+    /// Issue ID the error is about (only present for issue-specific errors)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
     /// Human-readable message
     message: String,
     /// Optional hint for remediation
@@ -59,7 +63,7 @@ pub fn execute(
     let quiet = cli.quiet.unwrap_or(false);
 
     // Schema output is always machine-readable; for text mode we print pretty JSON.
-    let ctx = output::OutputContext::from_output_format(output_format, quiet, true);
+    let ctx = output::OutputContext::from_output_format(output_format, quiet, false);
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
@@ -78,7 +82,7 @@ pub fn execute(
         OutputFormat::Json => {
             ctx.json_pretty(&payload);
         }
-        OutputFormat::Text | OutputFormat::Csv => {
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Table => {
             // Text mode: still emit JSON Schema; don't require callers to pass --json.
             let json = serde_json::to_string_pretty(&payload).expect("schema payload is JSON");
             println!("{json}");
@@ -95,6 +99,7 @@ fn build_schemas(target: SchemaTarget) -> BTreeMap<&'static str, RootSchema> {
         SchemaTarget::All => {
             schemas.insert("Issue", schema_for!(Issue));
             schemas.insert("IssueWithCounts", schema_for!(IssueWithCounts));
+            schemas.insert("SearchResult", schema_for!(SearchResult));
             schemas.insert("IssueDetails", schema_for!(IssueDetails));
             schemas.insert("ReadyIssue", schema_for!(ReadyIssue));
             schemas.insert("StaleIssue", schema_for!(StaleIssue));
@@ -109,6 +114,9 @@ fn build_schemas(target: SchemaTarget) -> BTreeMap<&'static str, RootSchema> {
         SchemaTarget::IssueWithCounts => {
             schemas.insert("IssueWithCounts", schema_for!(IssueWithCounts));
         }
+        SchemaTarget::SearchResult => {
+            schemas.insert("SearchResult", schema_for!(SearchResult));
+        }
         SchemaTarget::IssueDetails => {
             schemas.insert("IssueDetails", schema_for!(IssueDetails));
         }
@@ -1,22 +1,45 @@
 //! Search command implementation.
 //!
 //! Classic bd-style LIKE search across title/description/id with list-like filters.
-
+//! `--in` scopes the search to other fields (including comments), `--regex`
+//! treats the query as a regular expression and `--case-sensitive` turns off
+//! case folding. Each result carries the field the query matched in and a
+//! snippet of the matched line (`match_field`/`snippet` in JSON).
+
+use crate::cli::commands::list::{
+    DEFAULT_PAGE_SIZE, ListPage, STREAM_BATCH_SIZE, attach_labels, is_paginated, next_cursor,
+    parse_dep_type_filter, resolve_filter_ids,
+};
 use crate::cli::{ListArgs, OutputFormat, SearchArgs, resolve_output_format};
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::format::{
-    IssueWithCounts, TextFormatOptions, csv, format_issue_line_with, terminal_width,
+    IssueWithCounts, SearchResult, TextFormatOptions, csv, format_issue_line_with, table, tabular,
+    terminal_width,
 };
-use crate::model::{IssueType, Priority, Status};
+use crate::model::{Issue, IssueType, MILESTONE_DEP_TYPE, Priority, Status};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::storage::{
+    FieldFilter, ListCursor, ListFilters, SearchField, SearchQuery, Storage, TextMatcher,
+};
 use chrono::Utc;
+use crossterm::style::Stylize;
 use regex::{Regex, RegexBuilder};
 use std::collections::{HashMap, HashSet};
 use std::io::IsTerminal;
 use std::str::FromStr;
 
+/// Characters of context kept on each side of a match in a snippet.
+const SNIPPET_RADIUS: usize = 32;
+
+/// Where the query matched an issue.
+struct SearchMatch {
+    field: SearchField,
+    snippet: String,
+    /// Byte range of the match within `snippet`.
+    highlight: (usize, usize),
+}
+
 /// Execute the search command.
 ///
 /// # Errors
@@ -37,10 +60,20 @@ pub fn execute(
             reason: "search query cannot be empty".to_string(),
         });
     }
+    if args.filters.group_by.is_some() {
+        return Err(BeadsError::validation(
+            "group_by",
+            "--group-by is only supported by list",
+        ));
+    }
+
+    let search_query = build_search_query(args, query)?;
+    // Fail on a bad regex before touching the database
+    let matcher = search_query.matcher()?;
 
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
     let max_width = if std::io::stdout().is_terminal() {
@@ -54,20 +87,85 @@ pub fn execute(
         wrap: args.filters.wrap,
     };
 
-    let mut filters = build_filters(&args.filters)?;
-    let client_filters = needs_client_filters(&args.filters);
+    // Check --where against the declared custom fields
+    let checked_filters;
+    let filter_args = if args.filters.where_.is_empty() {
+        &args.filters
+    } else {
+        checked_filters = ListArgs {
+            where_: config::fields::normalize_filters(&args.filters.where_, &config_layer)?,
+            ..args.filters.clone()
+        };
+        &checked_filters
+    };
+
+    // --depends-on, --blocks and --milestone may name partial IDs
+    let resolved_filters;
+    let filter_args = if filter_args.depends_on.is_some()
+        || filter_args.blocks.is_some()
+        || filter_args.milestone.is_some()
+    {
+        let mut resolved = filter_args.clone();
+        resolve_filter_ids(&mut resolved, &beads_dir, storage, cli)?;
+        resolved_filters = resolved;
+        &resolved_filters
+    } else {
+        filter_args
+    };
+
+    if args.filters.stream {
+        if args.filters.sort.is_some() || args.filters.reverse {
+            return Err(BeadsError::validation(
+                "stream",
+                "--stream emits results in relevance order and cannot be combined with --sort or --reverse",
+            ));
+        }
+        if cli.quiet.unwrap_or(false) {
+            return Ok(());
+        }
+        return stream_search(storage, &search_query, &matcher, filter_args);
+    }
+
+    let paginate = is_paginated(&args.filters);
+    if paginate && (args.filters.sort.is_some() || args.filters.reverse) {
+        return Err(BeadsError::validation(
+            "after",
+            "paginated search uses relevance order and cannot be combined with --sort or --reverse",
+        ));
+    }
+    let paged_filters;
+    let list_args = if paginate && filter_args.limit.is_none() {
+        paged_filters = ListArgs {
+            limit: Some(DEFAULT_PAGE_SIZE),
+            ..filter_args.clone()
+        };
+        &paged_filters
+    } else {
+        filter_args
+    };
+
+    let mut filters = build_filters(list_args)?;
+    let client_filters = needs_client_filters(list_args);
     let limit = if client_filters {
         filters.limit.take()
     } else {
         None
     };
 
-    let issues = storage.search_issues(query, &filters)?;
-    let issues = if client_filters {
+    let issues = storage.search_issues_with(&search_query, &filters)?;
+    let mut issues = if client_filters {
         apply_client_filters(storage, issues, &args.filters)?
     } else {
         issues
     };
+    let next_cursor = if paginate {
+        if let Some(limit) = limit.filter(|&limit| limit > 0) {
+            issues.truncate(limit);
+        }
+        next_cursor(issues.len(), issues.last(), list_args)
+    } else {
+        None
+    };
 
     let output_format = resolve_output_format(args.filters.format, outer_ctx.is_json(), false);
     let needs_counts = matches!(output_format, OutputFormat::Json | OutputFormat::Toon);
@@ -107,19 +205,45 @@ pub fn execute(
     }
 
     let quiet = cli.quiet.unwrap_or(false);
-    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+    let ctx = outer_ctx.with_output_format(output_format, quiet, use_color);
 
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
 
+    let mut matches = if matches!(output_format, OutputFormat::Csv | OutputFormat::Table) {
+        HashMap::new()
+    } else {
+        find_matches(
+            storage,
+            issues_with_counts.iter().map(|iwc| &iwc.issue),
+            &search_query,
+            &matcher,
+        )?
+    };
+
     match output_format {
+        OutputFormat::Json | OutputFormat::Toon if paginate => {
+            let page = ListPage {
+                issues: into_results(issues_with_counts, &mut matches),
+                next_cursor,
+            };
+            if matches!(output_format, OutputFormat::Toon) {
+                ctx.toon_with_stats(&page, args.filters.stats);
+            } else {
+                ctx.json_pretty(&page);
+            }
+            return Ok(());
+        }
         OutputFormat::Json => {
-            ctx.json_pretty(&issues_with_counts);
+            ctx.json_pretty(&into_results(issues_with_counts, &mut matches));
             return Ok(());
         }
         OutputFormat::Toon => {
-            ctx.toon_with_stats(&issues_with_counts, args.filters.stats);
+            ctx.toon_with_stats(
+                &into_results(issues_with_counts, &mut matches),
+                args.filters.stats,
+            );
             return Ok(());
         }
         OutputFormat::Csv => {
@@ -132,6 +256,25 @@ pub fn execute(
             print!("{csv_output}");
             return Ok(());
         }
+        OutputFormat::Table => {
+            let columns = table::parse_columns(args.filters.columns.as_deref(), &[])?;
+            let mut issues: Vec<_> = issues_with_counts
+                .into_iter()
+                .map(|iwc| iwc.issue)
+                .collect();
+            if tabular::needs_labels(&columns) {
+                attach_labels(storage, &mut issues)?;
+            }
+            let max_width = table::max_width(config::table_max_width_from_layer(&config_layer));
+            print!(
+                "{}",
+                table::render_issues(&issues, &columns, max_width, |_, _| None)
+            );
+            if let Some(cursor) = next_cursor {
+                println!("More results: --after {cursor}");
+            }
+            return Ok(());
+        }
         OutputFormat::Text => {}
     }
 
@@ -140,7 +283,7 @@ pub fn execute(
             .iter()
             .map(|iwc| iwc.issue.clone())
             .collect();
-        let context_snippets = build_context_snippets(&issues, query);
+        let context_snippets = context_snippets(&matches);
         let show_context = !context_snippets.is_empty();
         let columns = IssueTableColumns {
             id: true,
@@ -160,8 +303,10 @@ pub fn execute(
                 issues.len(),
                 if issues.len() == 1 { "" } else { "s" }
             ))
-            .highlight_query(query)
             .wrap(args.filters.wrap);
+        if let Some(regex) = build_highlight_regex(&search_query) {
+            table = table.highlight_regex(regex);
+        }
         if args.filters.wrap {
             table = table.width(Some(ctx.width()));
         }
@@ -180,83 +325,208 @@ pub fn execute(
     for iwc in &issues_with_counts {
         let line = format_issue_line_with(&iwc.issue, format_options);
         ctx.print(&line);
+        if let Some(line) = matches
+            .get(&iwc.issue.id)
+            .and_then(|found| format_match_line(found, use_color))
+        {
+            ctx.print(&line);
+        }
+    }
+    if let Some(cursor) = next_cursor {
+        ctx.print(&format!("More results: --after {cursor}"));
     }
 
     Ok(())
 }
 
-fn build_context_snippets(issues: &[crate::model::Issue], query: &str) -> HashMap<String, String> {
-    let Some(regex) = build_highlight_regex(query) else {
-        return HashMap::new();
-    };
-
-    let mut snippets = HashMap::new();
-    for issue in issues {
-        if let Some(description) = issue.description.as_deref() {
-            if let Some(mat) = regex.find(description) {
-                let snippet = snippet_around_match(description, mat.start(), mat.end(), 32);
-                if !snippet.is_empty() {
-                    snippets.insert(issue.id.clone(), snippet);
-                    continue;
-                }
-            }
-        }
-
-        if regex.is_match(&issue.id) && !regex.is_match(&issue.title) {
-            snippets.insert(issue.id.clone(), "ID match".to_string());
+/// Build the search query from the text and `--in`/`--regex`/`--case-sensitive`.
+fn build_search_query(args: &SearchArgs, query: &str) -> Result<SearchQuery> {
+    let mut fields = Vec::new();
+    for name in &args.in_fields {
+        let field = SearchField::from_str(name)?;
+        if !fields.contains(&field) {
+            fields.push(field);
         }
     }
-
-    snippets
-}
-
-fn build_highlight_regex(query: &str) -> Option<Regex> {
-    let trimmed = query.trim();
-    if trimmed.is_empty() {
-        return None;
+    if fields.is_empty() {
+        fields = SearchField::DEFAULT.to_vec();
     }
-    let pattern = regex::escape(trimmed);
-    RegexBuilder::new(&pattern)
-        .case_insensitive(true)
-        .build()
-        .ok()
+    Ok(SearchQuery {
+        text: query.to_string(),
+        fields,
+        regex: args.regex,
+        case_sensitive: args.case_sensitive,
+    })
 }
 
-fn snippet_around_match(text: &str, start: usize, end: usize, radius: usize) -> String {
-    if text.is_empty() {
-        return String::new();
+/// Stream search results as JSON Lines in batches.
+fn stream_search(
+    storage: &dyn Storage,
+    query: &SearchQuery,
+    matcher: &TextMatcher,
+    args: &ListArgs,
+) -> Result<()> {
+    let ctx = OutputContext::from_output_format(OutputFormat::Json, false, false);
+    let mut filters = build_filters(args)?;
+    let client_filters = needs_client_filters(args);
+    if client_filters {
+        filters.limit = None;
     }
+    let mut remaining = args.limit.filter(|&limit| limit > 0);
+
+    storage.stream_search_issues(query, &filters, STREAM_BATCH_SIZE, &mut |batch| {
+        let mut issues = if client_filters {
+            apply_client_filters(storage, batch, args)?
+        } else {
+            batch
+        };
+        if let Some(remaining) = remaining.as_mut() {
+            issues.truncate(*remaining);
+            *remaining -= issues.len();
+        }
 
-    let mut char_starts: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
-    char_starts.push(text.len());
+        let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+        let dep_counts = storage.count_dependencies_for_issues(&issue_ids)?;
+        let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
+        let mut matches = find_matches(storage, &issues, query, matcher)?;
+        let rows: Vec<IssueWithCounts> = issues
+            .into_iter()
+            .map(|issue| IssueWithCounts {
+                dependency_count: *dep_counts.get(&issue.id).unwrap_or(&0),
+                dependent_count: *dependent_counts.get(&issue.id).unwrap_or(&0),
+                issue,
+            })
+            .collect();
+        ctx.json_lines(&into_results(rows, &mut matches));
+        Ok(remaining.is_none_or(|remaining| remaining > 0))
+    })
+}
 
-    let total_chars = char_starts.len().saturating_sub(1);
-    let start_char = char_starts.partition_point(|&idx| idx < start);
-    let end_char = char_starts.partition_point(|&idx| idx < end);
+/// Locate the query in each issue, keyed by issue ID.
+fn find_matches<'a>(
+    storage: &dyn Storage,
+    issues: impl IntoIterator<Item = &'a Issue>,
+    query: &SearchQuery,
+    matcher: &TextMatcher,
+) -> Result<HashMap<String, SearchMatch>> {
+    let mut matches = HashMap::new();
+    for issue in issues {
+        let Some((field, text)) = storage.find_search_match(issue, &query.fields, matcher)? else {
+            continue;
+        };
+        if let Some((snippet, highlight)) = match_snippet(&text, matcher) {
+            matches.insert(
+                issue.id.clone(),
+                SearchMatch {
+                    field,
+                    snippet,
+                    highlight,
+                },
+            );
+        }
+    }
+    Ok(matches)
+}
 
-    let snippet_start_char = start_char.saturating_sub(radius);
-    let snippet_end_char = (end_char + radius).min(total_chars);
+/// Attach `match_field`/`snippet` to each row for JSON and TOON output.
+fn into_results(
+    rows: Vec<IssueWithCounts>,
+    matches: &mut HashMap<String, SearchMatch>,
+) -> Vec<SearchResult> {
+    rows.into_iter()
+        .map(|row| {
+            let found = matches.remove(&row.issue.id);
+            SearchResult {
+                match_field: found.as_ref().map(|found| found.field.to_string()),
+                snippet: found.map(|found| found.snippet),
+                issue: row,
+            }
+        })
+        .collect()
+}
 
-    let snippet_start_byte = char_starts[snippet_start_char];
-    let snippet_end_byte = char_starts[snippet_end_char];
+/// Context column for the rich table; title matches are already visible.
+fn context_snippets(matches: &HashMap<String, SearchMatch>) -> HashMap<String, String> {
+    matches
+        .iter()
+        .filter_map(|(id, found)| match found.field {
+            SearchField::Title => None,
+            SearchField::Id => Some((id.clone(), "ID match".to_string())),
+            field => Some((id.clone(), format!("{field}: {}", found.snippet))),
+        })
+        .collect()
+}
 
-    let mut snippet = text[snippet_start_byte..snippet_end_byte]
-        .trim()
-        .to_string();
-    snippet = normalize_whitespace(&snippet);
+/// Indented snippet line for plain text output, with the match in bold (or
+/// between `**` without color). Title and ID matches are already on the issue
+/// line.
+fn format_match_line(found: &SearchMatch, use_color: bool) -> Option<String> {
+    if matches!(found.field, SearchField::Title | SearchField::Id) {
+        return None;
+    }
+    let (start, end) = found.highlight;
+    let matched = &found.snippet[start..end];
+    let matched = if use_color {
+        matched.bold().yellow().to_string()
+    } else {
+        format!("**{matched}**")
+    };
+    Some(format!(
+        "    {}: {}{matched}{}",
+        found.field,
+        &found.snippet[..start],
+        &found.snippet[end..]
+    ))
+}
 
-    if snippet_start_char > 0 {
-        snippet.insert_str(0, "...");
+/// The line of `text` holding the first match, clipped to [`SNIPPET_RADIUS`]
+/// characters either side, with the byte range of the match in the snippet.
+fn match_snippet(text: &str, matcher: &TextMatcher) -> Option<(String, (usize, usize))> {
+    let (start, end) = matcher.find(text)?;
+    let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = text[end..].find('\n').map_or(text.len(), |i| end + i);
+    let clip_start = text[line_start..start]
+        .char_indices()
+        .rev()
+        .nth(SNIPPET_RADIUS - 1)
+        .map_or(line_start, |(i, _)| line_start + i);
+    let clip_end = text[end..line_end]
+        .char_indices()
+        .nth(SNIPPET_RADIUS)
+        .map_or(line_end, |(i, _)| end + i);
+    // A regex may match across lines; keep the snippet on one line
+    let flatten = |part: &str| part.replace(['\n', '\r', '\t'], " ");
+
+    let mut snippet = String::new();
+    if clip_start > line_start {
+        snippet.push_str("...");
     }
-    if snippet_end_char < total_chars {
+    snippet.push_str(flatten(&text[clip_start..start]).trim_start());
+    let highlight_start = snippet.len();
+    snippet.push_str(&flatten(&text[start..end]));
+    let highlight = (highlight_start, snippet.len());
+    snippet.push_str(flatten(&text[end..clip_end]).trim_end());
+    if clip_end < line_end {
         snippet.push_str("...");
     }
-
-    snippet
+    Some((snippet, highlight))
 }
 
-fn normalize_whitespace(input: &str) -> String {
-    input.split_whitespace().collect::<Vec<_>>().join(" ")
+/// Regex highlighting what the query matches in the rich table.
+fn build_highlight_regex(query: &SearchQuery) -> Option<Regex> {
+    let text = query.text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    let pattern = if query.regex {
+        text.to_string()
+    } else {
+        regex::escape(text)
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!query.case_sensitive)
+        .build()
+        .ok()
 }
 
 fn build_filters(args: &ListArgs) -> Result<ListFilters> {
@@ -324,8 +594,23 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
             Some(args.label.clone())
         },
         labels_or: None,
+        touches: args.touches.clone(),
+        depends_on: args.milestone.clone().or_else(|| args.depends_on.clone()),
+        blocks: args.blocks.clone(),
+        dep_type: if args.milestone.is_some() {
+            Some(MILESTONE_DEP_TYPE.to_string())
+        } else {
+            parse_dep_type_filter(args.dep_type.as_deref())?
+        },
         updated_before: None,
         updated_after: None,
+        after: args.after.as_deref().map(ListCursor::decode).transpose()?,
+        paginate: is_paginated(args),
+        field_filters: args
+            .where_
+            .iter()
+            .map(|expr| FieldFilter::parse(expr))
+            .collect::<Result<_>>()?,
     })
 }
 
@@ -342,7 +627,7 @@ fn needs_client_filters(args: &ListArgs) -> bool {
 }
 
 fn apply_client_filters(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     issues: Vec<crate::model::Issue>,
     args: &ListArgs,
 ) -> Result<Vec<crate::model::Issue>> {
@@ -474,7 +759,9 @@ fn apply_sort(issues: &mut [IssueWithCounts], sort: Option<&str>) -> Result<()>
 mod tests {
     use super::*;
     use crate::model::{Issue, IssueType, Priority, Status};
+    use crate::storage::SqliteStorage;
     use chrono::{DateTime, TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     fn make_issue(
         id: &str,
@@ -522,6 +809,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -552,6 +843,40 @@ mod tests {
         assert_eq!(results[0].id, "bd-xyz");
     }
 
+    #[test]
+    fn test_match_snippet_clips_to_matched_line() {
+        let matcher = SearchQuery::new("timeout").matcher().unwrap();
+        let text =
+            "First line\nThe worker hit a Timeout while syncing a very long batch of records\nLast";
+        let (snippet, (start, end)) = match_snippet(text, &matcher).unwrap();
+        assert_eq!(&snippet[start..end], "Timeout");
+        assert_eq!(
+            snippet,
+            "The worker hit a Timeout while syncing a very long batch..."
+        );
+
+        let found = SearchMatch {
+            field: SearchField::Notes,
+            snippet,
+            highlight: (start, end),
+        };
+        assert_eq!(
+            format_match_line(&found, false).unwrap(),
+            "    notes: The worker hit a **Timeout** while syncing a very long batch..."
+        );
+        let title = SearchMatch {
+            field: SearchField::Title,
+            ..found
+        };
+        assert!(format_match_line(&title, false).is_none());
+
+        let long_prefix = format!("{}needle", "x".repeat(40));
+        let matcher = SearchQuery::new("needle").matcher().unwrap();
+        let (snippet, (start, _)) = match_snippet(&long_prefix, &matcher).unwrap();
+        assert_eq!(snippet, format!("...{}needle", "x".repeat(32)));
+        assert_eq!(start, 35);
+    }
+
     #[test]
     fn test_sort_by_title_and_reverse() {
         let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
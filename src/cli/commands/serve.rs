@@ -0,0 +1,65 @@
+//! Serve command implementation.
+//!
+//! Runs the HTTP API from [`crate::serve`] until interrupted.
+
+use crate::cli::ServeArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::serve::Server;
+use std::fmt::Write;
+
+/// Execute the serve command.
+///
+/// # Errors
+///
+/// Returns an error if no workspace is found, the token is empty, no random
+/// token can be generated, or the port cannot be bound.
+pub fn execute(args: &ServeArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let token = match &args.token {
+        Some(token) if token.trim().is_empty() => {
+            return Err(BeadsError::validation("token", "cannot be empty"));
+        }
+        Some(token) => token.trim().to_string(),
+        None => generate_token()?,
+    };
+    let server = Server::bind(args.port, token.clone(), beads_dir, cli.clone())?;
+    let url = format!("http://{}", server.local_addr()?);
+
+    if ctx.is_json() {
+        ctx.json(&serde_json::json!({ "url": url, "token": token }));
+    } else {
+        println!("Serving the beads API at {url}");
+        if args.token.is_none() {
+            println!("Token: {token}");
+        }
+        println!("Send 'Authorization: Bearer <token>' with every request; Ctrl-C to stop.");
+    }
+    server.run()
+}
+
+/// 128 bits from the OS random source, hex-encoded.
+fn generate_token() -> Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes)
+        .map_err(|e| std::io::Error::other(format!("no OS randomness for a token: {e}")))?;
+    let mut token = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(token, "{byte:02x}");
+    }
+    Ok(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_token_is_random_hex() {
+        let token = generate_token().unwrap();
+        assert_eq!(token.len(), 32);
+        assert!(token.bytes().all(|b| b.is_ascii_hexdigit()));
+        assert_ne!(token, generate_token().unwrap());
+    }
+}
@@ -0,0 +1,435 @@
+//! Session command implementation.
+//!
+//! A session groups the changes made during one stretch of work, such as an
+//! agent run. Its ID is stamped onto every event and onto `closed_by_session`
+//! when an issue is closed. `br session start` records the ID in the
+//! `current-session` file next to the database; `BD_SESSION`/`BEADS_SESSION`
+//! and the `session` config key take precedence over it.
+
+use crate::cli::{SessionCommands, SessionShowArgs, SessionStartArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::format::truncate_title;
+use crate::model::{Event, EventType};
+use crate::output::OutputContext;
+use crate::storage::{ListFilters, Storage};
+use crate::util::{clear_current_session, get_current_session, set_current_session};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+/// An issue touched in a session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct SessionIssue {
+    id: String,
+    title: String,
+}
+
+/// Everything that changed in one session.
+#[derive(Debug, Clone, Serialize)]
+struct SessionSummary {
+    session_id: String,
+    active: bool,
+    started_at: Option<DateTime<Utc>>,
+    ended_at: Option<DateTime<Utc>>,
+    actors: Vec<String>,
+    event_count: usize,
+    /// Events per type
+    counts: BTreeMap<String, usize>,
+    created: Vec<SessionIssue>,
+    closed: Vec<SessionIssue>,
+    /// Touched but neither created nor closed in the session
+    updated: Vec<SessionIssue>,
+}
+
+/// One row of `br session list`.
+#[derive(Debug, Clone, Serialize)]
+struct SessionListEntry {
+    session_id: String,
+    active: bool,
+    started_at: DateTime<Utc>,
+    ended_at: DateTime<Utc>,
+    event_count: usize,
+    issue_count: usize,
+    actors: Vec<String>,
+}
+
+/// Execute a session subcommand.
+///
+/// # Errors
+///
+/// Returns an error if the session ID is invalid, a session is already
+/// active, or database operations fail.
+pub fn execute(
+    command: &SessionCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    match command {
+        SessionCommands::Start(args) => start(&beads_dir, args, ctx),
+        SessionCommands::End => end(&beads_dir, ctx),
+        SessionCommands::Show(args) => show(&beads_dir, args, cli, ctx),
+        SessionCommands::List => list(&beads_dir, cli, ctx),
+    }
+}
+
+fn start(beads_dir: &Path, args: &SessionStartArgs, ctx: &OutputContext) -> Result<()> {
+    if let Some(current) = get_current_session(beads_dir) {
+        return Err(BeadsError::validation(
+            "session",
+            format!("session '{current}' is already active; run `br session end` first"),
+        ));
+    }
+    let id = match args.id.as_deref().map(str::trim) {
+        Some(id) => {
+            validate_session_id(id)?;
+            id.to_string()
+        }
+        None => Utc::now().format("s-%Y%m%d-%H%M%S").to_string(),
+    };
+    set_current_session(beads_dir, &id)?;
+
+    let layer = config::load_startup_config(beads_dir)?;
+    let overridden = config::session_id_from_layer(&layer).filter(|ambient| *ambient != id);
+
+    if ctx.is_json() {
+        ctx.json_pretty(&serde_json::json!({ "session_id": id, "overridden_by": overridden }));
+    } else if !ctx.is_quiet() {
+        println!("Started session {id}");
+        if let Some(ambient) = overridden {
+            eprintln!(
+                "Warning: BD_SESSION/BEADS_SESSION or the session config key sets '{ambient}', which takes precedence"
+            );
+        }
+    }
+    Ok(())
+}
+
+fn end(beads_dir: &Path, ctx: &OutputContext) -> Result<()> {
+    let current = get_current_session(beads_dir);
+    let ended = current.is_some() && clear_current_session(beads_dir);
+    if ctx.is_json() {
+        ctx.json_pretty(&serde_json::json!({ "ended": ended, "session_id": current }));
+    } else if !ctx.is_quiet() {
+        match current {
+            Some(id) if ended => println!("Ended session {id}"),
+            _ => println!("No active session."),
+        }
+    }
+    Ok(())
+}
+
+fn show(
+    beads_dir: &Path,
+    args: &SessionShowArgs,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let storage_ctx = config::open_storage_with_cli(beads_dir, cli)?;
+    let storage = &*storage_ctx.storage;
+    let active = storage.session_id().map(str::to_string);
+
+    let session_id = match args.id.as_deref().map(str::trim) {
+        Some(id) if !id.is_empty() && id != "last" => id.to_string(),
+        _ => match active.clone() {
+            Some(id) => id,
+            None => storage
+                .get_all_events(0)?
+                .into_iter()
+                .find_map(|event| event.session_id)
+                .ok_or_else(|| BeadsError::validation("session", "no sessions recorded"))?,
+        },
+    };
+
+    let summary = summarize(
+        storage,
+        &session_id,
+        active.as_deref() == Some(session_id.as_str()),
+    )?;
+    if summary.event_count == 0 && summary.closed.is_empty() {
+        return Err(BeadsError::validation(
+            "session",
+            format!("no changes recorded in session '{session_id}'"),
+        ));
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&summary);
+    } else if !ctx.is_quiet() {
+        print_summary(&summary);
+    }
+    Ok(())
+}
+
+fn summarize(storage: &dyn Storage, session_id: &str, active: bool) -> Result<SessionSummary> {
+    let events = storage.get_session_events(session_id)?;
+    let issues = storage.list_issues(&ListFilters {
+        include_closed: true,
+        include_deferred: true,
+        include_templates: true,
+        ..ListFilters::default()
+    })?;
+    let titles: HashMap<&str, &str> = issues
+        .iter()
+        .map(|issue| (issue.id.as_str(), issue.title.as_str()))
+        .collect();
+    // Closes synced from another clone carry the session but no events
+    let closed_elsewhere: Vec<&str> = issues
+        .iter()
+        .filter(|issue| issue.closed_by_session.as_deref() == Some(session_id))
+        .map(|issue| issue.id.as_str())
+        .collect();
+    Ok(build_summary(
+        session_id,
+        active,
+        &events,
+        &closed_elsewhere,
+        &titles,
+    ))
+}
+
+fn build_summary(
+    session_id: &str,
+    active: bool,
+    events: &[Event],
+    closed_by_session: &[&str],
+    titles: &HashMap<&str, &str>,
+) -> SessionSummary {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    let mut created = BTreeSet::new();
+    let mut closed: BTreeSet<&str> = closed_by_session.iter().copied().collect();
+    let mut touched = BTreeSet::new();
+    for event in events {
+        *counts
+            .entry(event.event_type.as_str().to_string())
+            .or_default() += 1;
+        match event.event_type {
+            EventType::Created => {
+                created.insert(event.issue_id.as_str());
+            }
+            EventType::Closed => {
+                closed.insert(event.issue_id.as_str());
+            }
+            _ => {}
+        }
+        touched.insert(event.issue_id.as_str());
+    }
+
+    let issue = |id: &str| SessionIssue {
+        id: id.to_string(),
+        title: titles.get(id).copied().unwrap_or_default().to_string(),
+    };
+    SessionSummary {
+        session_id: session_id.to_string(),
+        active,
+        started_at: events.first().map(|event| event.created_at),
+        ended_at: events.last().map(|event| event.created_at),
+        actors: events
+            .iter()
+            .map(|event| event.actor.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect(),
+        event_count: events.len(),
+        counts,
+        created: created.iter().map(|id| issue(id)).collect(),
+        closed: closed.iter().map(|id| issue(id)).collect(),
+        updated: touched
+            .iter()
+            .filter(|id| !created.contains(*id) && !closed.contains(*id))
+            .map(|id| issue(id))
+            .collect(),
+    }
+}
+
+fn print_summary(summary: &SessionSummary) {
+    let state = if summary.active { " (active)" } else { "" };
+    println!("Session {}{state}", summary.session_id);
+    if let (Some(start), Some(end)) = (summary.started_at, summary.ended_at) {
+        println!(
+            "  {} -> {}",
+            start.format("%Y-%m-%d %H:%M"),
+            end.format("%Y-%m-%d %H:%M")
+        );
+    }
+    if !summary.actors.is_empty() {
+        println!("  Actors: {}", summary.actors.join(", "));
+    }
+    let counts: Vec<String> = summary
+        .counts
+        .iter()
+        .map(|(event_type, count)| format!("{count} {}", event_type.replace('_', " ")))
+        .collect();
+    println!("  {} events: {}", summary.event_count, counts.join(", "));
+
+    for (heading, issues) in [
+        ("Created", &summary.created),
+        ("Closed", &summary.closed),
+        ("Updated", &summary.updated),
+    ] {
+        if issues.is_empty() {
+            continue;
+        }
+        println!("\n{heading} ({}):", issues.len());
+        for issue in issues {
+            println!("  {:<12} {}", issue.id, truncate_title(&issue.title, 60));
+        }
+    }
+}
+
+fn list(beads_dir: &Path, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let storage_ctx = config::open_storage_with_cli(beads_dir, cli)?;
+    let storage = &*storage_ctx.storage;
+    let entries = list_sessions(&storage.get_all_events(0)?, storage.session_id());
+
+    if ctx.is_json() {
+        ctx.json_pretty(&entries);
+    } else if !ctx.is_quiet() {
+        if entries.is_empty() {
+            println!("No sessions recorded.");
+        }
+        for entry in &entries {
+            println!(
+                "{:<24} {}  {:>4} events  {:>3} issues  {}{}",
+                entry.session_id,
+                entry.started_at.format("%Y-%m-%d %H:%M"),
+                entry.event_count,
+                entry.issue_count,
+                entry.actors.join(", "),
+                if entry.active { "  (active)" } else { "" }
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Group events by session, most recently active first.
+fn list_sessions(events: &[Event], active: Option<&str>) -> Vec<SessionListEntry> {
+    let mut by_session: HashMap<&str, Vec<&Event>> = HashMap::new();
+    for event in events {
+        if let Some(session_id) = event.session_id.as_deref() {
+            by_session.entry(session_id).or_default().push(event);
+        }
+    }
+    let mut entries: Vec<SessionListEntry> = by_session
+        .into_iter()
+        .map(|(session_id, events)| SessionListEntry {
+            session_id: session_id.to_string(),
+            active: active == Some(session_id),
+            started_at: events
+                .iter()
+                .map(|event| event.created_at)
+                .min()
+                .unwrap_or_default(),
+            ended_at: events
+                .iter()
+                .map(|event| event.created_at)
+                .max()
+                .unwrap_or_default(),
+            event_count: events.len(),
+            issue_count: events
+                .iter()
+                .map(|event| event.issue_id.as_str())
+                .collect::<BTreeSet<_>>()
+                .len(),
+            actors: events
+                .iter()
+                .map(|event| event.actor.clone())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+        })
+        .collect();
+    entries.sort_by(|a, b| {
+        b.ended_at
+            .cmp(&a.ended_at)
+            .then_with(|| a.session_id.cmp(&b.session_id))
+    });
+    entries
+}
+
+fn validate_session_id(id: &str) -> Result<()> {
+    if id.is_empty() {
+        return Err(BeadsError::validation("session", "cannot be empty"));
+    }
+    if id == "last" {
+        return Err(BeadsError::validation(
+            "session",
+            "'last' is reserved for the most recent session",
+        ));
+    }
+    if id.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(BeadsError::validation(
+            "session",
+            "cannot contain whitespace",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn event(issue_id: &str, event_type: EventType, minute: u32, session: &str) -> Event {
+        Event {
+            id: i64::from(minute),
+            issue_id: issue_id.to_string(),
+            event_type,
+            actor: "agent".to_string(),
+            old_value: None,
+            new_value: None,
+            comment: None,
+            created_at: Utc.with_ymd_and_hms(2026, 5, 1, 12, minute, 0).unwrap(),
+            session_id: Some(session.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_build_summary_groups_issues() {
+        let events = vec![
+            event("bd-1", EventType::Created, 0, "run-1"),
+            event("bd-2", EventType::StatusChanged, 5, "run-1"),
+            event("bd-1", EventType::Closed, 9, "run-1"),
+        ];
+        let titles = HashMap::from([("bd-1", "New"), ("bd-2", "Old"), ("bd-3", "Remote")]);
+        let summary = build_summary("run-1", true, &events, &["bd-3"], &titles);
+
+        assert_eq!(summary.event_count, 3);
+        assert_eq!(summary.counts.get("created"), Some(&1));
+        let ids = |issues: &[SessionIssue]| issues.iter().map(|i| i.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&summary.created), vec!["bd-1"]);
+        assert_eq!(ids(&summary.closed), vec!["bd-1", "bd-3"]);
+        assert_eq!(ids(&summary.updated), vec!["bd-2"]);
+        assert_eq!(summary.actors, vec!["agent"]);
+    }
+
+    #[test]
+    fn test_list_sessions_newest_first() {
+        let mut unsessioned = event("bd-9", EventType::Created, 1, "x");
+        unsessioned.session_id = None;
+        let events = vec![
+            event("bd-1", EventType::Created, 0, "early"),
+            event("bd-2", EventType::Created, 7, "late"),
+            event("bd-1", EventType::Closed, 3, "early"),
+            unsessioned,
+        ];
+        let entries = list_sessions(&events, Some("early"));
+        let ids: Vec<&str> = entries.iter().map(|e| e.session_id.as_str()).collect();
+        assert_eq!(ids, vec!["late", "early"]);
+        assert_eq!(entries[1].event_count, 2);
+        assert_eq!(entries[1].issue_count, 1);
+        assert!(entries[1].active);
+    }
+
+    #[test]
+    fn test_validate_session_id() {
+        assert!(validate_session_id("agent-run-7").is_ok());
+        assert!(validate_session_id("").is_err());
+        assert!(validate_session_id("last").is_err());
+        assert!(validate_session_id("two words").is_err());
+    }
+}
@@ -1,12 +1,22 @@
 //! Show command implementation.
 
-use crate::cli::{ShowArgs, resolve_output_format_basic};
+use crate::cli::{OutputFormat, ShowArgs, ShowFormat, resolve_output_format_basic};
 use crate::config;
+use crate::config::remotes;
 use crate::error::{BeadsError, Result};
-use crate::format::{format_priority_label, format_status_icon_colored};
+use crate::format::time::TimeFormat;
+use crate::format::{
+    IssueDetails, format_estimate, format_priority_label, format_status_icon_colored, icon_status,
+};
 use crate::output::{IssuePanel, OutputContext, OutputMode};
 use crate::util::id::{IdResolver, ResolverConfig};
+use crate::util::markdown_export;
+use chrono::Utc;
 use std::fmt::Write as FmtWrite;
+use std::io;
+
+/// Most recent events shown with `--events`.
+const EVENT_LIMIT: usize = 10;
 
 /// Execute the show command.
 ///
@@ -21,55 +31,98 @@ pub fn execute(
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
 
+    let last_touched = crate::util::get_last_touched_id(&beads_dir);
     let mut target_ids = args.ids.clone();
     if target_ids.is_empty() {
-        let last_touched = crate::util::get_last_touched_id(&beads_dir);
         if last_touched.is_empty() {
             return Err(BeadsError::validation(
                 "ids",
                 "no issue IDs provided and no last-touched issue",
             ));
         }
-        target_ids.push(last_touched);
+        target_ids.push(last_touched.clone());
     }
 
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let id_config = config::id_config_from_layer(&config_layer);
-    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage.get_id_aliases()?)
+            .with_last_touched(last_touched),
+    );
     let use_color = config::should_use_color(&config_layer);
-    let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), false);
+    let actor = config::actor(&config_layer);
+    let markdown = args.format == Some(ShowFormat::Md);
+    let output_format = resolve_output_format_basic(
+        args.format.and_then(ShowFormat::basic),
+        outer_ctx.is_json(),
+        false,
+    );
     let quiet = cli.quiet.unwrap_or(false);
-    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+    let ctx = outer_ctx.with_output_format(output_format, quiet, use_color);
+
+    let registered_remotes = remotes::load_remotes(&beads_dir)?;
 
     let mut details_list = Vec::new();
     for id_input in target_ids {
+        // Repo-qualified IDs (`other:bd-abc12`) are looked up in the named remote
+        if let Some((name, local_id)) = remotes::split_qualified_id(&id_input) {
+            if let Some(entry) = remotes::find_remote(&registered_remotes, name) {
+                let details = show_remote_issue(entry, local_id, args.events, &beads_dir, cli)?;
+                details_list.push(details);
+                continue;
+            }
+        }
+
         let resolution = resolver.resolve(
             &id_input,
             |id| storage.id_exists(id).unwrap_or(false),
             |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
         )?;
 
-        // Fetch full details including comments and events
-        if let Some(details) = storage.get_issue_details(&resolution.id, true, false, 10)? {
+        // Fetch full details including comments (and events on request)
+        if let Some(details) =
+            storage.get_issue_details(&resolution.id, true, args.events, EVENT_LIMIT)?
+        {
             details_list.push(details);
         } else {
             return Err(BeadsError::IssueNotFound { id: resolution.id });
         }
     }
 
+    // Remember the last local issue shown so `-` and bare commands can chain on it
+    if let Some(last) = details_list
+        .iter()
+        .rev()
+        .find(|details| remotes::split_qualified_id(&details.issue.id).is_none())
+    {
+        crate::util::set_last_touched_id(&beads_dir, &last.issue.id);
+    }
+
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
+    if markdown {
+        let mut issues = Vec::with_capacity(details_list.len());
+        for details in &details_list {
+            let mut issue = details.issue.clone();
+            issue.labels.clone_from(&details.labels);
+            issue.dependencies = storage.get_dependencies_full(&issue.id)?;
+            issues.push(issue);
+        }
+        markdown_export::write_issues(&mut io::stdout().lock(), &issues)?;
+        return Ok(());
+    }
     match output_format {
-        crate::cli::OutputFormat::Json => {
+        OutputFormat::Json => {
             ctx.json_pretty(&details_list);
         }
-        crate::cli::OutputFormat::Toon => {
+        OutputFormat::Toon => {
             ctx.toon_with_stats(&details_list, args.stats);
         }
-        crate::cli::OutputFormat::Text | crate::cli::OutputFormat::Csv => {
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Table => {
             for (i, details) in details_list.iter().enumerate() {
                 if i > 0 {
                     println!(); // Separate multiple issues
@@ -78,7 +131,7 @@ pub fn execute(
                     let panel = IssuePanel::from_details(details, ctx.theme());
                     panel.print(&ctx, args.wrap);
                 } else {
-                    print_issue_details(details, use_color);
+                    print_issue_details(details, use_color, &actor, ctx.time_format());
                 }
             }
         }
@@ -87,16 +140,69 @@ pub fn execute(
     Ok(())
 }
 
-fn print_issue_details(details: &crate::format::IssueDetails, use_color: bool) {
-    let output = format_issue_details(details, use_color);
+/// Fetch issue details from a registered remote, qualifying every ID it references.
+fn show_remote_issue(
+    entry: &remotes::RemoteEntry,
+    local_id: &str,
+    include_events: bool,
+    beads_dir: &std::path::Path,
+    cli: &config::CliOverrides,
+) -> Result<IssueDetails> {
+    let remote = remotes::open_remote(entry, beads_dir, cli.lock_timeout)?;
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(remote.id_prefix()?));
+    let resolution = resolver.resolve(
+        local_id,
+        |id| remote.storage.id_exists(id).unwrap_or(false),
+        |hash| remote.storage.find_ids_by_hash(hash).unwrap_or_default(),
+    )?;
+
+    let Some(mut details) =
+        remote
+            .storage
+            .get_issue_details(&resolution.id, true, include_events, EVENT_LIMIT)?
+    else {
+        return Err(BeadsError::IssueNotFound {
+            id: remotes::qualify_id(&remote.name, &resolution.id),
+        });
+    };
+
+    remotes::qualify_issue(&mut details.issue, &remote.name);
+    for related in details
+        .dependencies
+        .iter_mut()
+        .chain(details.dependents.iter_mut())
+    {
+        if !related.id.starts_with("external:") {
+            related.id = remotes::qualify_id(&remote.name, &related.id);
+        }
+    }
+    for backlink in &mut details.referenced_by {
+        backlink.id = remotes::qualify_id(&remote.name, &backlink.id);
+    }
+    if let Some(parent) = details.parent.as_mut() {
+        *parent = remotes::qualify_id(&remote.name, parent);
+    }
+
+    Ok(details)
+}
+
+fn print_issue_details(details: &IssueDetails, use_color: bool, actor: &str, time: &TimeFormat) {
+    let output = format_issue_details(details, use_color, actor, time);
     print!("{output}");
 }
 
+/// Render the bd-style text view. Issues without an owner show `actor` (bd parity).
 #[allow(clippy::too_many_lines)]
-fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool) -> String {
+fn format_issue_details(
+    details: &IssueDetails,
+    use_color: bool,
+    actor: &str,
+    time: &TimeFormat,
+) -> String {
     let mut output = String::new();
+    let now = Utc::now();
     let issue = &details.issue;
-    let status_icon = format_status_icon_colored(&issue.status, use_color);
+    let status_icon = format_status_icon_colored(icon_status(issue), use_color);
     let priority_label = format_priority_label(&issue.priority, use_color);
     let status_upper = issue.status.as_str().to_uppercase();
 
@@ -108,11 +214,7 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
     );
 
     // Owner/Type line: Owner: {owner} · Type: {type}
-    let owner = issue
-        .owner
-        .clone()
-        .or_else(|| std::env::var("USER").ok())
-        .unwrap_or_else(|| "unknown".to_string());
+    let owner = issue.owner.as_deref().unwrap_or(actor);
     let _ = writeln!(
         output,
         "Owner: {} · Type: {}",
@@ -124,18 +226,39 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
     let _ = writeln!(
         output,
         "Created: {} · Updated: {}",
-        issue.created_at.format("%Y-%m-%d"),
-        issue.updated_at.format("%Y-%m-%d")
+        time.date(issue.created_at, now),
+        time.date(issue.updated_at, now)
     );
 
     if let Some(assignee) = &issue.assignee {
         let _ = writeln!(output, "Assignee: {assignee}");
     }
 
+    if let Some(parent) = &details.parent {
+        let _ = writeln!(output, "Parent: {parent}");
+    }
+
     if !details.labels.is_empty() {
         let _ = writeln!(output, "Labels: {}", details.labels.join(", "));
     }
 
+    if let Some(reactions) = details.reaction_summary(None) {
+        let _ = writeln!(output, "Reactions: {reactions}");
+    }
+
+    if !issue.watchers.is_empty() {
+        let _ = writeln!(output, "Watchers: {}", issue.watchers.join(", "));
+    }
+
+    if !issue.fields.is_empty() {
+        let fields: Vec<String> = issue
+            .fields
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect();
+        let _ = writeln!(output, "Fields: {}", fields.join(", "));
+    }
+
     if let Some(ext_ref) = &issue.external_ref {
         if !ext_ref.is_empty() {
             let _ = writeln!(output, "Ref: {ext_ref}");
@@ -143,24 +266,16 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
     }
 
     if let Some(due) = &issue.due_at {
-        let _ = writeln!(output, "Due: {}", due.format("%Y-%m-%d"));
+        let _ = writeln!(output, "Due: {}", time.date(*due, now));
     }
 
     if let Some(defer) = &issue.defer_until {
-        let _ = writeln!(output, "Deferred until: {}", defer.format("%Y-%m-%d"));
+        let _ = writeln!(output, "Deferred until: {}", time.date(*defer, now));
     }
 
     if let Some(minutes) = issue.estimated_minutes {
         if minutes > 0 {
-            let hours = minutes / 60;
-            let remaining = minutes % 60;
-            if hours > 0 && remaining > 0 {
-                let _ = writeln!(output, "Estimate: {hours}h {remaining}m");
-            } else if hours > 0 {
-                let _ = writeln!(output, "Estimate: {hours}h");
-            } else {
-                let _ = writeln!(output, "Estimate: {remaining}m");
-            }
+            let _ = writeln!(output, "Estimate: {}", format_estimate(i64::from(minutes)));
         }
     }
 
@@ -169,11 +284,15 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
         let _ = writeln!(
             output,
             "Closed: {} ({})",
-            closed.format("%Y-%m-%d"),
+            time.date(*closed, now),
             reason_str
         );
     }
 
+    for (label, id) in details.replacement_links() {
+        let _ = writeln!(output, "{label}: {id}");
+    }
+
     if let Some(desc) = &issue.description {
         output.push('\n');
         let _ = writeln!(output, "{desc}");
@@ -203,6 +322,14 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
         }
     }
 
+    if !issue.code_refs.is_empty() {
+        output.push('\n');
+        let _ = writeln!(output, "Code:");
+        for code_ref in &issue.code_refs {
+            let _ = writeln!(output, "  {code_ref}");
+        }
+    }
+
     if !details.dependencies.is_empty() {
         output.push('\n');
         let _ = writeln!(output, "Dependencies:");
@@ -219,6 +346,20 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
         }
     }
 
+    if !details.referenced_by.is_empty() {
+        output.push('\n');
+        let _ = writeln!(output, "Referenced by:");
+        for backlink in &details.referenced_by {
+            let _ = writeln!(
+                output,
+                "  <~ {} [{}] - {}",
+                backlink.id,
+                backlink.status.as_str(),
+                backlink.title
+            );
+        }
+    }
+
     if !details.comments.is_empty() {
         output.push('\n');
         let _ = writeln!(output, "Comments:");
@@ -226,10 +367,37 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
             let _ = writeln!(
                 output,
                 "  [{}] {}: {}",
-                comment.created_at.format("%Y-%m-%d %H:%M UTC"),
+                time.datetime(comment.created_at, now),
                 comment.author,
                 comment.body
             );
+            if let Some(reactions) = details.reaction_summary(Some(comment.id)) {
+                let _ = writeln!(output, "    {reactions}");
+            }
+        }
+    }
+
+    if !details.events.is_empty() {
+        output.push('\n');
+        let _ = writeln!(output, "History:");
+        for event in &details.events {
+            let _ = write!(
+                output,
+                "  [{}] {} {}",
+                time.datetime(event.created_at, now),
+                event.actor,
+                event.event_type.as_str()
+            );
+            match (&event.old_value, &event.new_value) {
+                (Some(old), Some(new)) => {
+                    let _ = write!(output, ": {old} -> {new}");
+                }
+                (None, Some(new)) => {
+                    let _ = write!(output, ": {new}");
+                }
+                _ => {}
+            }
+            output.push('\n');
         }
     }
 
@@ -239,11 +407,13 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
 #[cfg(test)]
 mod tests {
     use super::format_issue_details;
-    use crate::format::{IssueDetails, IssueWithDependencyMetadata};
+    use crate::format::time::TimeFormat;
+    use crate::format::{IssueBacklink, IssueDetails, IssueWithDependencyMetadata};
     use crate::model::{Comment, Issue, IssueType, Priority, Status};
     use crate::storage::SqliteStorage;
     use crate::util::id::{IdResolver, ResolverConfig};
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
     use tracing::info;
 
     fn init_logging() {
@@ -291,6 +461,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -442,9 +616,12 @@ mod tests {
                 status: Status::Open,
                 priority: Priority::MEDIUM,
                 dep_type: "blocks".to_string(),
+                condition: None,
             }],
             dependents: Vec::new(),
+            referenced_by: Vec::new(),
             comments: Vec::new(),
+            reactions: Vec::new(),
             events: Vec::new(),
             parent: None,
         };
@@ -472,23 +649,96 @@ mod tests {
                 status: Status::Open,
                 priority: Priority::MEDIUM,
                 dep_type: "blocks".to_string(),
+                condition: None,
             }],
             dependents: Vec::new(),
+            referenced_by: Vec::new(),
             comments: vec![Comment {
                 id: 1,
                 issue_id: "bd-001".to_string(),
                 author: "alice".to_string(),
                 body: "Looks good".to_string(),
                 created_at: Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 0).unwrap(),
+                updated_at: None,
+                deleted: false,
             }],
+            reactions: Vec::new(),
             events: Vec::new(),
             parent: None,
         };
-        let output = format_issue_details(&details, false);
+        let output = format_issue_details(&details, false, "tester", &TimeFormat::absolute());
         assert!(output.contains("Dependencies:"));
         assert!(output.contains("-> bd-002 (blocks) - Dep"));
         assert!(output.contains("Comments:"));
         assert!(output.contains("alice: Looks good"));
         info!("test_show_text_includes_dependencies_and_comments: assertions passed");
     }
+
+    #[test]
+    fn test_show_text_includes_replacement_links() {
+        init_logging();
+        let link = |id: &str, dep_type: &str| IssueWithDependencyMetadata {
+            id: id.to_string(),
+            title: "Other".to_string(),
+            status: Status::Open,
+            priority: Priority::MEDIUM,
+            dep_type: dep_type.to_string(),
+            condition: None,
+        };
+        let details = IssueDetails {
+            issue: make_test_issue("bd-001", "Test Issue"),
+            labels: Vec::new(),
+            dependencies: vec![link("bd-002", "duplicates"), link("bd-003", "blocks")],
+            dependents: vec![link("bd-004", "supersedes")],
+            referenced_by: vec![IssueBacklink {
+                id: "bd-005".to_string(),
+                title: "Related".to_string(),
+                status: Status::Closed,
+            }],
+            comments: Vec::new(),
+            reactions: Vec::new(),
+            events: Vec::new(),
+            parent: None,
+        };
+        let output = format_issue_details(&details, false, "tester", &TimeFormat::absolute());
+        assert!(output.contains("Duplicate of: bd-002"));
+        assert!(output.contains("Superseded by: bd-004"));
+        assert!(!output.contains("of: bd-003"));
+        assert!(output.contains("Referenced by:\n  <~ bd-005 [closed] - Related"));
+    }
+
+    #[test]
+    fn test_show_details_derive_parent_and_events() {
+        init_logging();
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        storage
+            .create_issue(&make_test_issue("bd-abc", "Parent"), "tester")
+            .unwrap();
+        storage
+            .create_issue(&make_test_issue("bd-abc.1", "Child"), "tester")
+            .unwrap();
+        storage
+            .create_issue(&make_test_issue("bd-xyz.1", "Orphan"), "tester")
+            .unwrap();
+
+        // No parent-child edge: the parent comes from the hierarchical ID
+        let details = storage
+            .get_issue_details("bd-abc.1", true, true, 10)
+            .unwrap()
+            .unwrap();
+        assert_eq!(details.parent.as_deref(), Some("bd-abc"));
+        assert!(!details.events.is_empty());
+        let output = format_issue_details(&details, false, "tester", &TimeFormat::absolute());
+        assert!(output.contains("Parent: bd-abc"));
+        assert!(output.contains("History:"));
+        assert!(output.contains("tester created"));
+
+        // A hierarchical ID whose parent does not exist has no parent
+        let orphan = storage
+            .get_issue_details("bd-xyz.1", true, false, 10)
+            .unwrap()
+            .unwrap();
+        assert!(orphan.parent.is_none());
+        assert!(orphan.events.is_empty());
+    }
 }
@@ -19,7 +19,7 @@ pub fn execute(args: &StaleArgs, cli: &config::CliOverrides, ctx: &OutputContext
 
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
 
     let statuses = if args.status.is_empty() {
         vec![Status::Open, Status::InProgress]
@@ -56,23 +56,23 @@ pub fn execute(args: &StaleArgs, cli: &config::CliOverrides, ctx: &OutputContext
             args.days
         );
         for (idx, issue) in stale.iter().enumerate() {
-            let days_stale = (now - issue.updated_at).num_days().max(0);
+            let age = ctx.time_format().age(issue.updated_at, now);
             let status = issue.status.as_str();
             if let Some(assignee) = issue.assignee.as_deref() {
                 println!(
-                    "{}. [{}] {}d {} {} ({assignee})",
+                    "{}. [{}] {} {} {} ({assignee})",
                     idx + 1,
                     status,
-                    days_stale,
+                    age,
                     issue.id,
                     issue.title
                 );
             } else {
                 println!(
-                    "{}. [{}] {}d {} {}",
+                    "{}. [{}] {} {} {}",
                     idx + 1,
                     status,
-                    days_stale,
+                    age,
                     issue.id,
                     issue.title
                 );
@@ -145,8 +145,9 @@ fn render_stale_rich(
 
         let mut line = Text::new("");
 
-        // Days stale badge
-        line.append_styled(&format!("{:>3}d ", days_stale), staleness_style);
+        // Age badge
+        let age = ctx.time_format().age(issue.updated_at, now);
+        line.append_styled(&format!("{age:>4} "), staleness_style);
 
         // Status badge
         line.append_styled(&format!("[{}] ", issue.status.as_str()), status_style);
@@ -171,6 +172,7 @@ fn render_stale_rich(
 mod tests {
     use super::*;
     use crate::model::{IssueType, Priority};
+    use std::collections::BTreeMap;
     use tracing::info;
 
     fn init_logging() {
@@ -217,6 +219,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
             content_hash: None,
         }
     }
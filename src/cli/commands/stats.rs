@@ -1,20 +1,27 @@
 //! Stats command implementation.
 //!
 //! Shows project statistics including issue counts by status, type, priority,
-//! assignee, and label. Also supports recent activity tracking via git.
+//! assignee, and label. Also supports recent activity tracking via git, and
+//! with `--burndown` daily burndown, throughput and cumulative-flow series
+//! replayed from the event history.
 
+use crate::cli::commands::milestone::{format_progress, milestone_progress};
 use crate::cli::{OutputFormat, StatsArgs, resolve_output_format_basic};
 use crate::config;
-use crate::error::Result;
+use crate::error::{BeadsError, Result};
 use crate::format::{
-    Breakdown, BreakdownEntry, RecentActivity, Statistics, StatsSummary, truncate_title,
+    Breakdown, BreakdownEntry, MilestoneProgress, RecentActivity, Statistics, StatsSummary,
+    truncate_title,
 };
-use crate::model::{IssueType, Status};
+use crate::model::{Event, EventType, Issue, IssueType, MILESTONE_TYPE, Status};
 use crate::output::{OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
-use chrono::Utc;
+use crate::storage::{ListFilters, Storage};
+use crate::sync::shard;
+use crate::util::time::parse_since;
+use chrono::{DateTime, NaiveDate, Utc};
 use rich_rust::prelude::*;
-use std::collections::BTreeMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 use std::process::{Command, Stdio};
@@ -33,12 +40,16 @@ pub fn execute(
 ) -> Result<()> {
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
-    let storage = &storage_ctx.storage;
+    let storage = &*storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
     let use_color = config::should_use_color(&config_layer);
     let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), args.robot);
     let quiet = cli.quiet.unwrap_or(false);
-    let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+    let ctx = outer_ctx.with_output_format(output_format, quiet, use_color);
+
+    if args.burndown {
+        return execute_burndown(args, storage, output_format, &ctx);
+    }
 
     info!("Computing project statistics");
 
@@ -83,6 +94,7 @@ pub fn execute(
         summary,
         breakdowns,
         recent_activity,
+        milestones: milestone_progress(storage, false)?,
     };
 
     // Output based on mode
@@ -97,7 +109,7 @@ pub fn execute(
         OutputFormat::Toon => {
             ctx.toon_with_stats(&output, args.stats);
         }
-        OutputFormat::Text | OutputFormat::Csv => {
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Table => {
             if matches!(ctx.mode(), OutputMode::Rich) {
                 render_stats_rich(&output, &ctx);
             } else {
@@ -111,10 +123,7 @@ pub fn execute(
 
 /// Compute summary statistics.
 #[allow(clippy::cast_precision_loss)]
-fn compute_summary(
-    storage: &SqliteStorage,
-    issues: &[crate::model::Issue],
-) -> Result<StatsSummary> {
+fn compute_summary(storage: &dyn Storage, issues: &[crate::model::Issue]) -> Result<StatsSummary> {
     let mut open = 0;
     let mut in_progress = 0;
     let mut closed = 0;
@@ -170,6 +179,7 @@ fn compute_summary(
                 && !all_blocked_ids.contains(&i.id)
                 && !i.ephemeral
                 && !i.pinned
+                && i.issue_type.as_str() != MILESTONE_TYPE
                 && i.defer_until.is_none_or(|d| d <= now)
         })
         .count();
@@ -213,7 +223,7 @@ fn compute_summary(
 }
 
 /// Count epics that have all children closed.
-fn count_epics_eligible_for_closure(storage: &SqliteStorage, epic_ids: &[String]) -> Result<usize> {
+fn count_epics_eligible_for_closure(storage: &dyn Storage, epic_ids: &[String]) -> Result<usize> {
     let mut eligible = 0;
 
     for epic_id in epic_ids {
@@ -313,7 +323,7 @@ fn compute_assignee_breakdown(issues: &[crate::model::Issue]) -> Breakdown {
 
 /// Compute breakdown by label.
 fn compute_label_breakdown(
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
     issues: &[crate::model::Issue],
 ) -> Result<Breakdown> {
     let mut counts: BTreeMap<String, usize> = BTreeMap::new();
@@ -353,10 +363,16 @@ fn compute_label_breakdown(
 /// Compute recent activity from git log on issues.jsonl.
 fn compute_recent_activity(beads_dir: &Path, hours: u32) -> Option<RecentActivity> {
     let jsonl_path = beads_dir.join("issues.jsonl");
-    if !jsonl_path.exists() {
+    if !shard::jsonl_exists(&jsonl_path) {
         debug!("No issues.jsonl found for activity tracking");
         return None;
     }
+    // Track every file of a sharded export
+    let pathspecs: Vec<String> = shard::jsonl_sources(&jsonl_path)
+        .iter()
+        .filter_map(|path| path.file_name())
+        .map(|name| format!(".beads/{}", name.to_string_lossy()))
+        .collect();
 
     let since = format!("{hours} hours ago");
 
@@ -365,14 +381,8 @@ fn compute_recent_activity(beads_dir: &Path, hours: u32) -> Option<RecentActivit
 
     // Get commit count using relative path from repo root
     let mut child = Command::new("git")
-        .args([
-            "log",
-            "--oneline",
-            "--since",
-            &since,
-            "--",
-            ".beads/issues.jsonl",
-        ])
+        .args(["log", "--oneline", "--since", &since, "--"])
+        .args(&pathspecs)
         .current_dir(repo_root)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -467,6 +477,13 @@ fn print_text_output(output: &Statistics) {
         }
     }
 
+    if !output.milestones.is_empty() {
+        println!("\nMilestones:");
+        for milestone in &output.milestones {
+            println!("  {}", format_progress(milestone));
+        }
+    }
+
     if let Some(activity) = &output.recent_activity {
         println!("\nRecent Activity (last {} hours):", activity.hours_tracked);
         println!("  Commits:                {}", activity.commit_count);
@@ -526,6 +543,13 @@ fn render_stats_rich(output: &Statistics, ctx: &OutputContext) {
         content.append("\n");
     }
 
+    // === Milestones ===
+    if !output.milestones.is_empty() {
+        content.append_styled("\u{1f3c1} Milestones\n", theme.section.clone());
+        render_milestone_bars(&mut content, &output.milestones, theme);
+        content.append("\n");
+    }
+
     // === Recent Activity ===
     if let Some(activity) = &output.recent_activity {
         content.append_styled(
@@ -621,6 +645,44 @@ fn render_status_bars(content: &mut Text, summary: &StatsSummary, theme: &crate:
     }
 }
 
+/// Render milestone progress as bars of closed issues.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn render_milestone_bars(
+    content: &mut Text,
+    milestones: &[MilestoneProgress],
+    theme: &crate::output::Theme,
+) {
+    let bar_width: usize = 24;
+
+    for milestone in milestones {
+        let pct = milestone.percent_done();
+        let filled = ((pct / 100.0) * bar_width as f64).round() as usize;
+        let empty = bar_width.saturating_sub(filled);
+
+        content.append_styled(
+            &format!("   {:<12}", truncate_title(&milestone.title, 12)),
+            theme.accent.clone(),
+        );
+        content.append_styled(&"\u{2588}".repeat(filled), theme.success.clone());
+        content.append_styled(&"\u{2591}".repeat(empty), theme.dimmed.clone());
+        content.append_styled(
+            &format!(" {}/{} ({:.0}%)", milestone.closed, milestone.total, pct),
+            theme.dimmed.clone(),
+        );
+        if let Some(due) = milestone.due_at {
+            content.append_styled(
+                &format!("  due {}", due.format("%Y-%m-%d")),
+                theme.dimmed.clone(),
+            );
+        }
+        content.append("\n");
+    }
+}
+
 /// Render a breakdown as progress bars.
 #[allow(
     clippy::cast_precision_loss,
@@ -678,6 +740,250 @@ fn render_breakdown_bars(
 }
 
 /// Capitalize the first letter of a string.
+/// Window used by `--burndown` without `--since`.
+const DEFAULT_BURNDOWN_WINDOW: &str = "30d";
+
+/// Sparkline glyphs, lowest first.
+const SPARKS: [char; 8] = [
+    '\u{2581}', '\u{2582}', '\u{2583}', '\u{2584}', '\u{2585}', '\u{2586}', '\u{2587}', '\u{2588}',
+];
+
+/// Daily series for `stats --burndown`.
+#[derive(Debug, Serialize)]
+struct Burndown {
+    since: NaiveDate,
+    until: NaiveDate,
+    total_created: usize,
+    total_closed: usize,
+    days: Vec<BurndownDay>,
+}
+
+/// One day of burndown, throughput and cumulative-flow data.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+struct BurndownDay {
+    date: NaiveDate,
+    /// Issues created that day
+    created: usize,
+    /// Issues closed that day
+    closed: usize,
+    /// Issues not yet started at the end of the day
+    open: usize,
+    /// Issues in progress at the end of the day
+    in_progress: usize,
+    /// Closed issues at the end of the day
+    done: usize,
+    /// `open` + `in_progress`
+    remaining: usize,
+}
+
+/// A status change replayed from the event history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Change {
+    Created,
+    Status(Status),
+    Removed,
+}
+
+fn execute_burndown(
+    args: &StatsArgs,
+    storage: &dyn Storage,
+    output_format: OutputFormat,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let now = Utc::now();
+    let since = burndown_start(args.since.as_deref(), now)?;
+    let until = now.date_naive();
+    info!(%since, %until, "Computing burndown");
+
+    let issues = storage.list_issues(&ListFilters {
+        include_closed: true,
+        include_templates: true,
+        ..Default::default()
+    })?;
+    let events = storage.get_events_after(0)?;
+    let days = compute_burndown(&issues, &events, since, until);
+    let burndown = Burndown {
+        since,
+        until,
+        total_created: days.iter().map(|day| day.created).sum(),
+        total_closed: days.iter().map(|day| day.closed).sum(),
+        days,
+    };
+
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return Ok(());
+    }
+    match output_format {
+        OutputFormat::Json => ctx.json_pretty(&burndown),
+        OutputFormat::Toon => ctx.toon_with_stats(&burndown, args.stats),
+        OutputFormat::Text | OutputFormat::Csv | OutputFormat::Table => {
+            print_burndown_text(&burndown)
+        }
+    }
+    Ok(())
+}
+
+/// First day of the burndown window: a bare span such as `30d` or `4w`
+/// counts back from now; anything else is a date or relative time.
+fn burndown_start(since: Option<&str>, now: DateTime<Utc>) -> Result<NaiveDate> {
+    let since = since.unwrap_or(DEFAULT_BURNDOWN_WINDOW).trim();
+    if let Ok(date) = NaiveDate::parse_from_str(since, "%Y-%m-%d") {
+        if date > now.date_naive() {
+            return Err(BeadsError::validation("since", "must be in the past"));
+        }
+        return Ok(date);
+    }
+    let start = parse_since(since, "since")?;
+    if start > now {
+        return Err(BeadsError::validation("since", "must be in the past"));
+    }
+    Ok(start.date_naive())
+}
+
+/// Replay status changes and count them per day from `since` to `until`.
+///
+/// Issues with no `created` event (e.g. imported ones) are treated as created
+/// at `created_at` and, if closed, closed at `closed_at`.
+fn compute_burndown(
+    issues: &[Issue],
+    events: &[Event],
+    since: NaiveDate,
+    until: NaiveDate,
+) -> Vec<BurndownDay> {
+    let mut changes: Vec<(DateTime<Utc>, &str, Change)> = Vec::new();
+    let mut has_events: HashSet<&str> = HashSet::new();
+    for event in events {
+        let change = match &event.event_type {
+            EventType::Created => Change::Created,
+            EventType::StatusChanged => {
+                match event.new_value.as_deref().map(str::parse::<Status>) {
+                    Some(Ok(Status::Tombstone)) => Change::Removed,
+                    Some(Ok(status)) => Change::Status(status),
+                    _ => continue,
+                }
+            }
+            EventType::Closed => Change::Status(Status::Closed),
+            EventType::Reopened | EventType::Restored => Change::Status(Status::Open),
+            EventType::Deleted => Change::Removed,
+            _ => continue,
+        };
+        if change == Change::Created {
+            has_events.insert(event.issue_id.as_str());
+        }
+        changes.push((event.created_at, event.issue_id.as_str(), change));
+    }
+    for issue in issues
+        .iter()
+        .filter(|i| !has_events.contains(i.id.as_str()))
+    {
+        changes.push((issue.created_at, issue.id.as_str(), Change::Created));
+        if issue.status == Status::Tombstone {
+            let deleted_at = issue.deleted_at.unwrap_or(issue.updated_at);
+            changes.push((deleted_at, issue.id.as_str(), Change::Removed));
+        } else if issue.status.is_terminal() {
+            let closed_at = issue.closed_at.unwrap_or(issue.updated_at);
+            changes.push((closed_at, issue.id.as_str(), Change::Status(Status::Closed)));
+        }
+    }
+    changes.sort_by_key(|(at, _, _)| *at);
+
+    let mut statuses: HashMap<&str, Status> = HashMap::new();
+    let mut pending = changes.into_iter().peekable();
+    let mut days = Vec::new();
+    for date in since.iter_days().take_while(|date| *date <= until) {
+        let mut day = BurndownDay {
+            date,
+            ..BurndownDay::default()
+        };
+        while let Some((at, id, change)) = pending.next_if(|(at, _, _)| at.date_naive() <= date) {
+            // Changes before the window only set up the starting state
+            let counted = at.date_naive() >= since;
+            let was_done = statuses.get(id).is_some_and(Status::is_terminal);
+            match change {
+                Change::Created => {
+                    statuses.entry(id).or_insert(Status::Open);
+                    day.created += usize::from(counted);
+                }
+                Change::Status(status) => {
+                    day.closed += usize::from(counted && !was_done && status.is_terminal());
+                    statuses.insert(id, status);
+                }
+                Change::Removed => {
+                    statuses.remove(id);
+                }
+            }
+        }
+        for status in statuses.values() {
+            match status {
+                Status::InProgress => day.in_progress += 1,
+                status if status.is_terminal() => day.done += 1,
+                _ => day.open += 1,
+            }
+        }
+        day.remaining = day.open + day.in_progress;
+        days.push(day);
+    }
+    days
+}
+
+fn print_burndown_text(burndown: &Burndown) {
+    println!(
+        "Burndown {} to {} ({} days)",
+        burndown.since,
+        burndown.until,
+        burndown.days.len()
+    );
+    let series = |pick: fn(&BurndownDay) -> usize| -> Vec<usize> {
+        burndown.days.iter().map(pick).collect()
+    };
+    let remaining = series(|day| day.remaining);
+    let in_progress = series(|day| day.in_progress);
+    let created = series(|day| day.created);
+    let closed = series(|day| day.closed);
+    let span = |values: &[usize]| {
+        format!(
+            "{} -> {}",
+            values.first().copied().unwrap_or(0),
+            values.last().copied().unwrap_or(0)
+        )
+    };
+    println!(
+        "  Remaining    {}  {}",
+        sparkline(&remaining),
+        span(&remaining)
+    );
+    println!(
+        "  In progress  {}  {}",
+        sparkline(&in_progress),
+        span(&in_progress)
+    );
+    println!(
+        "  Created/day  {}  {} total",
+        sparkline(&created),
+        burndown.total_created
+    );
+    println!(
+        "  Closed/day   {}  {} total",
+        sparkline(&closed),
+        burndown.total_closed
+    );
+}
+
+/// Render values as a one-line bar chart scaled to the largest value.
+fn sparkline(values: &[usize]) -> String {
+    let max = values.iter().copied().max().unwrap_or(0);
+    values
+        .iter()
+        .map(|&value| {
+            if max == 0 {
+                SPARKS[0]
+            } else {
+                SPARKS[value * (SPARKS.len() - 1) / max]
+            }
+        })
+        .collect()
+}
+
 fn capitalize(s: &str) -> String {
     let mut chars = s.chars();
     chars.next().map_or_else(String::new, |first| {
@@ -690,7 +996,7 @@ mod tests {
     use super::*;
     use crate::model::{Issue, IssueType, Priority, Status};
     use crate::storage::SqliteStorage;
-    use chrono::Utc;
+    use chrono::{TimeZone, Utc};
 
     fn make_issue(id: &str, status: Status, issue_type: IssueType) -> Issue {
         Issue {
@@ -732,6 +1038,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
             content_hash: None,
         }
     }
@@ -926,4 +1236,70 @@ mod tests {
         assert_eq!(capitalize(""), "");
         assert_eq!(capitalize("ALREADY"), "ALREADY");
     }
+
+    fn make_event(
+        issue_id: &str,
+        event_type: EventType,
+        new_value: Option<&str>,
+        day: u32,
+    ) -> Event {
+        Event {
+            id: 0,
+            issue_id: issue_id.to_string(),
+            event_type,
+            actor: "tester".to_string(),
+            old_value: None,
+            new_value: new_value.map(str::to_string),
+            comment: None,
+            created_at: Utc.with_ymd_and_hms(2026, 3, day, 12, 0, 0).unwrap(),
+            session_id: None,
+        }
+    }
+
+    #[test]
+    fn test_compute_burndown_replays_events_per_day() {
+        let events = vec![
+            make_event("t-1", EventType::Created, None, 1),
+            make_event("t-2", EventType::Created, None, 2),
+            make_event("t-1", EventType::StatusChanged, Some("in_progress"), 3),
+            make_event("t-1", EventType::StatusChanged, Some("closed"), 4),
+            make_event("t-1", EventType::Closed, None, 4),
+            make_event("t-2", EventType::Deleted, None, 5),
+        ];
+        // Imported without events: created before the window, closed on day 3
+        let mut imported = make_issue("t-3", Status::Closed, IssueType::Task);
+        imported.created_at = Utc.with_ymd_and_hms(2026, 2, 20, 0, 0, 0).unwrap();
+        imported.closed_at = Some(Utc.with_ymd_and_hms(2026, 3, 3, 9, 0, 0).unwrap());
+
+        let day = |d| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        let days = compute_burndown(&[imported], &events, day(2), day(5));
+        let summary: Vec<_> = days
+            .iter()
+            .map(|d| (d.created, d.closed, d.open, d.in_progress, d.done))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, 0, 3, 0, 0), // t-1 from before the window, t-2, t-3
+                (0, 1, 1, 1, 1),
+                (0, 1, 1, 0, 2), // the duplicate closed event is not counted twice
+                (0, 0, 0, 0, 2),
+            ]
+        );
+        assert_eq!(days[1].remaining, 2);
+    }
+
+    #[test]
+    fn test_burndown_start_and_sparkline() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 31, 12, 0, 0).unwrap();
+        assert_eq!(
+            burndown_start(Some("2026-03-01"), now).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()
+        );
+        assert!(burndown_start(Some("2999-01-01"), now).is_err());
+        assert!(burndown_start(Some("30x"), now).is_err());
+
+        assert_eq!(sparkline(&[0, 1, 2, 4]), "\u{2581}\u{2582}\u{2584}\u{2588}");
+        assert_eq!(sparkline(&[0, 0]), "\u{2581}\u{2581}");
+    }
 }
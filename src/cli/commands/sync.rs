@@ -3,18 +3,20 @@
 //! Provides explicit JSONL sync actions without git operations.
 //! Supports `--flush-only` (export) and `--import-only` (import).
 
+use crate::alerts;
 use crate::cli::SyncArgs;
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::output::OutputContext;
 use crate::sync::history::HistoryConfig;
+use crate::sync::shard::{self, ShardStrategy};
 use crate::sync::{
-    ConflictResolution, ExportConfig, ExportEntityType, ExportError, ExportErrorPolicy,
+    ConflictResolution, ExportConfig, ExportEntityType, ExportError, ExportErrorPolicy, IdRemap,
     ImportConfig, METADATA_JSONL_CONTENT_HASH, METADATA_LAST_EXPORT_TIME,
     METADATA_LAST_IMPORT_TIME, MergeContext, OrphanMode, compute_jsonl_hash, count_issues_in_jsonl,
-    export_to_jsonl_with_policy, finalize_export, get_issue_ids_from_jsonl, import_from_jsonl,
-    load_base_snapshot, read_issues_from_jsonl, require_safe_sync_overwrite_path,
-    save_base_snapshot, three_way_merge,
+    dirty_issues_needing_export, export_to_jsonl_with_policy, finalize_export,
+    get_issue_ids_from_jsonl, import_from_jsonl, load_base_snapshot, read_issues_from_jsonl,
+    require_safe_sync_overwrite_path, save_base_snapshot, three_way_merge,
 };
 use rich_rust::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -48,6 +50,8 @@ pub struct ImportResultOutput {
     pub skipped: usize,
     pub tombstone_skipped: usize,
     pub blocked_cache_rebuilt: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub id_remaps: Vec<IdRemap>,
 }
 
 /// Sync status information.
@@ -73,6 +77,7 @@ struct SyncPathPolicy {
     manifest_path: PathBuf,
     beads_dir: PathBuf,
     is_external: bool,
+    shard: ShardStrategy,
 }
 
 /// Execute the sync command.
@@ -89,15 +94,20 @@ pub fn execute(
     // Open storage
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let config::OpenStorageResult {
-        mut storage, paths, ..
+        mut storage,
+        paths,
+        shard,
+        ..
     } = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = storage.sqlite_mut("sync")?;
 
     let jsonl_path = paths.jsonl_path;
     let retention_days = paths.metadata.deletions_retention_days;
     let use_json = ctx.is_json() || args.robot;
     let quiet = cli.quiet.unwrap_or(false);
     let show_progress = should_show_progress(use_json, quiet);
-    let path_policy = validate_sync_paths(&beads_dir, &jsonl_path, args.allow_external_jsonl)?;
+    let path_policy =
+        validate_sync_paths(&beads_dir, &jsonl_path, args.allow_external_jsonl, shard)?;
     debug!(
         jsonl_path = %path_policy.jsonl_path.display(),
         manifest_path = %path_policy.manifest_path.display(),
@@ -107,7 +117,7 @@ pub fn execute(
 
     // Handle --status flag
     if args.status {
-        return execute_status(&storage, &path_policy, use_json, ctx);
+        return execute_status(storage, &path_policy, use_json, ctx);
     }
 
     // Validate mutually exclusive modes
@@ -120,9 +130,9 @@ pub fn execute(
         });
     }
 
-    if args.flush_only {
+    let result = if args.flush_only {
         execute_flush(
-            &mut storage,
+            storage,
             &beads_dir,
             &path_policy,
             args,
@@ -133,7 +143,7 @@ pub fn execute(
         )
     } else if args.merge {
         execute_merge(
-            &mut storage,
+            storage,
             &path_policy,
             args,
             json,
@@ -145,26 +155,25 @@ pub fn execute(
     } else {
         // Default to import-only if no flag is specified (consistent with existing behavior)
         // or explicitly import-only
-        execute_import(
-            &mut storage,
-            &path_policy,
-            args,
-            use_json,
-            show_progress,
-            ctx,
-        )
+        execute_import(storage, &path_policy, args, use_json, show_progress, ctx)
+    };
+    if result.is_ok() {
+        alerts::check_or_warn(&beads_dir, &*storage, cli);
     }
+    result
 }
 
 fn validate_sync_paths(
     beads_dir: &Path,
     jsonl_path: &Path,
     allow_external_jsonl: bool,
+    shard: ShardStrategy,
 ) -> Result<SyncPathPolicy> {
     debug!(
         beads_dir = %beads_dir.display(),
         jsonl_path = %jsonl_path.display(),
         allow_external_jsonl,
+        %shard,
         "Validating sync paths"
     );
     let canonical_beads = dunce::canonicalize(beads_dir).map_err(|e| {
@@ -251,6 +260,7 @@ fn validate_sync_paths(
         manifest_path,
         beads_dir: canonical_beads,
         is_external,
+        shard,
     })
 }
 
@@ -275,7 +285,7 @@ fn execute_status(
     let jsonl_content_hash = storage.get_metadata(METADATA_JSONL_CONTENT_HASH)?;
 
     let jsonl_path = &path_policy.jsonl_path;
-    let jsonl_exists = jsonl_path.exists();
+    let jsonl_exists = shard::jsonl_exists(jsonl_path);
     debug!(
         jsonl_path = %jsonl_path.display(),
         jsonl_exists,
@@ -287,8 +297,12 @@ fn execute_status(
     let (jsonl_newer, db_newer) = if jsonl_exists {
         // Use symlink_metadata (Lstat) instead of metadata (stat) to get the mtime
         // of the symlink itself, not the target. This is important for detecting
-        // when the JSONL file has been updated via a symlink.
-        let jsonl_mtime = fs::symlink_metadata(jsonl_path)?.modified()?;
+        // when the JSONL file has been updated via a symlink. For a sharded
+        // export the newest shard wins.
+        let mut jsonl_mtime = std::time::SystemTime::UNIX_EPOCH;
+        for source in shard::jsonl_sources(jsonl_path) {
+            jsonl_mtime = jsonl_mtime.max(fs::symlink_metadata(source)?.modified()?);
+        }
 
         // JSONL is newer if it was modified after last import
         let mtime_newer = last_import_time.as_ref().is_none_or(|import_time| {
@@ -460,6 +474,24 @@ fn render_status_rich(status: &SyncStatus, ctx: &OutputContext) {
     console.print_renderable(&panel);
 }
 
+/// Whether the JSONL on disk is in the configured layout and hashes to the
+/// `jsonl_content_hash` recorded by the last export.
+fn jsonl_matches_last_export(
+    storage: &crate::storage::SqliteStorage,
+    path_policy: &SyncPathPolicy,
+) -> Result<bool> {
+    let jsonl_path = &path_policy.jsonl_path;
+    if !shard::jsonl_exists(jsonl_path)
+        || !shard::superseded_paths(jsonl_path, path_policy.shard).is_empty()
+    {
+        return Ok(false);
+    }
+    let Some(stored_hash) = storage.get_metadata(METADATA_JSONL_CONTENT_HASH)? else {
+        return Ok(false);
+    };
+    Ok(compute_jsonl_hash(jsonl_path)? == stored_hash)
+}
+
 /// Execute the --flush-only (export) operation.
 #[allow(clippy::too_many_lines, clippy::too_many_arguments)]
 fn execute_flush(
@@ -509,7 +541,9 @@ fn execute_flush(
 
             let jsonl_ids = get_issue_ids_from_jsonl(jsonl_path)?;
             if !jsonl_ids.is_empty() {
-                let db_ids: HashSet<String> = issues.iter().map(|i| i.id.clone()).collect();
+                let mut db_ids: HashSet<String> = issues.iter().map(|i| i.id.clone()).collect();
+                // Old IDs of renamed issues are accounted for by the new ID.
+                db_ids.extend(storage.get_id_aliases()?.into_keys());
                 let missing: Vec<_> = jsonl_ids.difference(&db_ids).collect();
 
                 if !missing.is_empty() {
@@ -568,6 +602,39 @@ fn execute_flush(
         return Ok(());
     }
 
+    // Issues can be dirty without their exported record changing (e.g. edited
+    // and edited back). Skip the rewrite if none changed and the JSONL on disk
+    // is still exactly what the last export wrote.
+    if !args.force && jsonl_matches_last_export(storage, path_policy)? {
+        let changed = dirty_issues_needing_export(storage, &dirty_ids)?;
+        if changed.is_empty() {
+            storage.clear_dirty_issues(&dirty_ids)?;
+            debug!(
+                cleared = dirty_ids.len(),
+                "Dirty issues unchanged since last export"
+            );
+            if use_json {
+                let result = FlushResult {
+                    exported_issues: 0,
+                    exported_dependencies: 0,
+                    exported_labels: 0,
+                    exported_comments: 0,
+                    content_hash: String::new(),
+                    cleared_dirty: dirty_ids.len(),
+                    policy: export_policy,
+                    success_rate: 1.0,
+                    errors: Vec::new(),
+                    manifest_path: None,
+                };
+                ctx.json_pretty(&result);
+            } else {
+                println!("Nothing to export (no changes since last export)");
+            }
+            return Ok(());
+        }
+        debug!(changed = changed.len(), "Issues changed since last export");
+    }
+
     // Configure export
     let export_config = ExportConfig {
         force: args.force,
@@ -578,6 +645,8 @@ fn execute_flush(
         allow_external_jsonl: args.allow_external_jsonl,
         show_progress,
         history: HistoryConfig::default(),
+        shard: path_policy.shard,
+        purged_ids: Vec::new(),
     };
 
     // Execute export
@@ -856,8 +925,8 @@ fn execute_import(
         "Import configuration resolved"
     );
 
-    // Check if JSONL exists
-    if !jsonl_path.exists() {
+    // Check if JSONL exists (either layout)
+    if !shard::jsonl_exists(jsonl_path) {
         warn!(path = %jsonl_path.display(), "JSONL path missing, skipping import");
         if use_json {
             let result = ImportResultOutput {
@@ -866,6 +935,7 @@ fn execute_import(
                 skipped: 0,
                 tombstone_skipped: 0,
                 blocked_cache_rebuilt: false,
+                id_remaps: Vec::new(),
             };
             ctx.json_pretty(&result);
         } else {
@@ -896,6 +966,7 @@ fn execute_import(
                         skipped: 0,
                         tombstone_skipped: 0,
                         blocked_cache_rebuilt: false,
+                        id_remaps: Vec::new(),
                     };
                     ctx.json_pretty(&result);
                 } else {
@@ -974,6 +1045,7 @@ fn execute_import(
         skipped: import_result.skipped_count,
         tombstone_skipped: import_result.tombstone_skipped,
         blocked_cache_rebuilt: true,
+        id_remaps: import_result.id_remaps,
     };
 
     if use_json {
@@ -989,6 +1061,12 @@ fn execute_import(
         if result.tombstone_skipped > 0 {
             println!("  Tombstone protected: {} issues", result.tombstone_skipped);
         }
+        if !result.id_remaps.is_empty() {
+            println!("  Remapped colliding IDs:");
+            for remap in &result.id_remaps {
+                println!("    {} -> {}  {}", remap.old_id, remap.new_id, remap.title);
+            }
+        }
         println!("  Rebuilt blocked cache");
     }
 
@@ -1033,6 +1111,21 @@ fn render_import_result_rich(result: &ImportResultOutput, ctx: &OutputContext) {
         text.append("\n");
     }
 
+    // Issues moved off a colliding ID
+    if !result.id_remaps.is_empty() {
+        text.append_styled("Remapped IDs       ", theme.dimmed.clone());
+        text.append(&result.id_remaps.len().to_string());
+        text.append("\n");
+        for remap in &result.id_remaps {
+            text.append_styled("  ", theme.dimmed.clone());
+            text.append_styled(&remap.old_id, theme.muted.clone());
+            text.append_styled(" → ", theme.dimmed.clone());
+            text.append_styled(&remap.new_id, theme.accent.clone());
+            text.append(&format!("  {}", remap.title));
+            text.append("\n");
+        }
+    }
+
     // Cache rebuilt
     text.append("\n");
     text.append_styled("✓ ", theme.success.clone());
@@ -1117,6 +1210,10 @@ fn execute_merge(
     let all_deps = storage.get_all_dependency_records()?;
     let all_labels = storage.get_all_labels()?;
     let all_comments = storage.get_all_comments()?;
+    let all_code_refs = storage.get_all_code_refs()?;
+    let all_watchers = storage.get_all_watchers()?;
+    let all_reactions = storage.get_all_reactions()?;
+    let all_fields = storage.get_all_fields()?;
 
     for issue in &mut left_issues {
         if let Some(deps) = all_deps.get(&issue.id) {
@@ -1128,6 +1225,18 @@ fn execute_merge(
         if let Some(comments) = all_comments.get(&issue.id) {
             issue.comments = comments.clone();
         }
+        if let Some(code_refs) = all_code_refs.get(&issue.id) {
+            issue.code_refs = code_refs.clone();
+        }
+        if let Some(watchers) = all_watchers.get(&issue.id) {
+            issue.watchers = watchers.clone();
+        }
+        if let Some(reactions) = all_reactions.get(&issue.id) {
+            issue.reactions = reactions.clone();
+        }
+        if let Some(fields) = all_fields.get(&issue.id) {
+            issue.fields = fields.clone();
+        }
     }
 
     let mut left = HashMap::new();
@@ -1138,7 +1247,7 @@ fn execute_merge(
 
     // 3. Load Right State (external JSONL)
     let mut right = HashMap::new();
-    if jsonl_path.exists() {
+    if shard::jsonl_exists(jsonl_path) {
         for issue in read_issues_from_jsonl(jsonl_path)? {
             right.insert(issue.id.clone(), issue);
         }
@@ -1189,6 +1298,10 @@ fn execute_merge(
         storage.sync_labels_for_import(&issue.id, &issue.labels)?;
         storage.sync_dependencies_for_import(&issue.id, &issue.dependencies)?;
         storage.sync_comments_for_import(&issue.id, &issue.comments)?;
+        storage.sync_code_refs_for_import(&issue.id, &issue.code_refs)?;
+        storage.sync_watchers_for_import(&issue.id, &issue.watchers)?;
+        storage.sync_reactions_for_import(&issue.id, &issue.reactions)?;
+        storage.sync_fields_for_import(&issue.id, &issue.fields)?;
     }
 
     // Rebuild cache
@@ -1213,6 +1326,8 @@ fn execute_merge(
         allow_external_jsonl: args.allow_external_jsonl,
         show_progress,
         history: HistoryConfig::default(),
+        shard: path_policy.shard,
+        purged_ids: Vec::new(),
     };
 
     let (export_result, _) = export_to_jsonl_with_policy(storage, jsonl_path, &export_config)?;
@@ -1351,6 +1466,7 @@ mod tests {
     use crate::model::{Issue, IssueType, Priority, Status};
     use crate::storage::SqliteStorage;
     use chrono::Utc;
+    use std::collections::BTreeMap;
     use tempfile::TempDir;
 
     fn make_test_issue(id: &str, title: &str) -> Issue {
@@ -1394,6 +1510,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -1,16 +1,25 @@
 //! Update command implementation.
+//!
+//! Fields are set with flags (an empty string clears optional fields), or
+//! with `--edit`, which opens a single issue as YAML in `$EDITOR` and applies
+//! whatever was changed.
 
 use crate::cli::UpdateArgs;
+use crate::cli::commands::label;
+use crate::cli::selection::resolve_selection;
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::model::{DependencyType, Issue, Status};
 use crate::output::OutputContext;
-use crate::storage::{IssueUpdate, SqliteStorage};
+use crate::storage::{IssueUpdate, Storage};
 use crate::util::id::{IdResolver, ResolverConfig};
 use crate::util::time::parse_flexible_timestamp;
-use crate::validation::LabelValidator;
+use crate::validation::{LabelValidator, PolicyRule, PolicyValidator};
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::process::Command;
 
 /// JSON output structure for updated issues.
 #[derive(Serialize)]
@@ -44,22 +53,45 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
 
-    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
-    let actor = config::resolve_actor(&config_layer);
-    let resolver = build_resolver(&config_layer, &storage_ctx.storage);
-    let resolved_ids = resolve_target_ids(args, &beads_dir, &resolver, &storage_ctx.storage)?;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let resolver = build_resolver(&config_layer, &*storage_ctx.storage, &beads_dir)?;
+    let resolved_ids = resolve_target_ids(args, &beads_dir, &resolver, &*storage_ctx.storage)?;
 
     let claim_exclusive = config::claim_exclusive_from_layer(&config_layer);
-    let update = build_update(args, &actor, claim_exclusive)?;
-    let has_updates = !update.is_empty()
+    let flag_update = build_update(args, &actor, claim_exclusive)?;
+    let field_updates = parse_field_updates(args, &config_layer)?;
+    let has_flag_updates = !flag_update.is_empty()
+        || !field_updates.is_empty()
         || !args.add_label.is_empty()
         || !args.remove_label.is_empty()
         || !args.set_labels.is_empty()
         || args.parent.is_some();
 
+    let (update, edited_labels) = if args.edit {
+        if resolved_ids.len() != 1 {
+            return Err(BeadsError::validation(
+                "edit",
+                "--edit works on a single issue",
+            ));
+        }
+        if has_flag_updates {
+            return Err(BeadsError::validation(
+                "edit",
+                "--edit cannot be combined with field flags",
+            ));
+        }
+        edit_issue(&*storage_ctx.storage, &resolved_ids[0])?
+    } else {
+        (flag_update, None)
+    };
+    let has_updates = has_flag_updates || !update.is_empty() || edited_labels.is_some();
+
+    let policy_rules = config::policy_rules_from_layer(&config_layer)?;
+
     let mut updated_issues: Vec<UpdatedIssueOutput> = Vec::new();
 
-    let storage = &mut storage_ctx.storage;
+    let storage = &mut *storage_ctx.storage;
 
     for id in &resolved_ids {
         // Get issue before update for change tracking
@@ -70,23 +102,21 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
 
         // Check if transitioning to in_progress (via --claim or --status in_progress)
         // and if so, validate that the issue is not blocked
-        let transitioning_to_in_progress = args.claim
-            || args
-                .status
-                .as_ref()
-                .is_some_and(|s| s.eq_ignore_ascii_case("in_progress"));
-
-        if transitioning_to_in_progress && !args.force && storage.is_blocked(id)? {
-            let blockers = storage.get_blockers(id)?;
-            let blocker_list = if blockers.is_empty() {
-                "blocking dependencies".to_string()
-            } else {
-                blockers.join(", ")
+        let transitioning_to_in_progress = update.status == Some(Status::InProgress);
+        if transitioning_to_in_progress && !args.force {
+            ensure_not_blocked(storage, id)?;
+        }
+
+        // Check required-field policies against the updated issue (unless --force)
+        if let Some(before) = issue_before
+            .as_ref()
+            .filter(|_| has_updates && !args.force && !policy_rules.is_empty())
+        {
+            let labels = match &edited_labels {
+                Some(labels) => labels.clone(),
+                None => labels_after_update(args, storage.get_labels(id)?),
             };
-            return Err(BeadsError::validation(
-                "claim",
-                format!("cannot claim blocked issue: {blocker_list}"),
-            ));
+            check_update_policies(before, &update, labels, &policy_rules)?;
         }
 
         // Apply basic field updates
@@ -94,28 +124,11 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
             storage.update_issue(id, &update, &actor)?;
         }
 
-        // Apply labels
-        for label in &args.add_label {
-            LabelValidator::validate(label)
-                .map_err(|e| BeadsError::validation("label", e.message))?;
-            storage.add_label(id, label, &actor)?;
-        }
-        for label in &args.remove_label {
-            storage.remove_label(id, label, &actor)?;
-        }
-        if !args.set_labels.is_empty() {
-            // Remove all then add new
-            storage.remove_all_labels(id, &actor)?;
-            // Join all flag values, then split by comma (handles both --set-labels a,b and --set-labels a --set-labels b)
-            let combined = args.set_labels.join(",");
-            for label in combined.split(',') {
-                let label = label.trim();
-                if !label.is_empty() {
-                    LabelValidator::validate(label)
-                        .map_err(|e| BeadsError::validation("label", e.message))?;
-                    storage.add_label(id, label, &actor)?;
-                }
-            }
+        apply_labels(storage, id, args, edited_labels.as_deref(), &actor)?;
+
+        // Apply custom fields
+        for (name, value) in &field_updates {
+            storage.set_field(id, name, value.as_deref(), &actor)?;
         }
 
         // Apply parent
@@ -142,10 +155,96 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
         ctx.json_pretty(&updated_issues);
     }
 
+    let added_labels: Vec<&str> = args
+        .add_label
+        .iter()
+        .map(String::as_str)
+        .chain(args.set_labels.iter().flat_map(|labels| labels.split(',')))
+        .chain(edited_labels.iter().flatten().map(String::as_str))
+        .map(str::trim)
+        .filter(|label| !label.is_empty())
+        .collect();
+    label::warn_undeclared_labels(&beads_dir, &config_layer, &added_labels, ctx);
+
     storage_ctx.flush_no_db_if_dirty()?;
     Ok(())
 }
 
+/// Refuse to start work on an issue that is blocked.
+fn ensure_not_blocked(storage: &dyn Storage, id: &str) -> Result<()> {
+    if !storage.is_blocked(id)? {
+        return Ok(());
+    }
+    let blockers = storage.get_blockers(id)?;
+    let blocker_list = if blockers.is_empty() {
+        "blocking dependencies".to_string()
+    } else {
+        blockers.join(", ")
+    };
+    Err(BeadsError::validation(
+        "claim",
+        format!("cannot claim blocked issue: {blocker_list}"),
+    ))
+}
+
+/// Apply `--add-label`, `--remove-label` and `--set-labels`, or the labels
+/// from `--edit`.
+fn apply_labels(
+    storage: &mut dyn Storage,
+    id: &str,
+    args: &UpdateArgs,
+    edited_labels: Option<&[String]>,
+    actor: &str,
+) -> Result<()> {
+    for label in &args.add_label {
+        LabelValidator::validate(label).map_err(|e| BeadsError::validation("label", e.message))?;
+        storage.add_label(id, label, actor)?;
+    }
+    for label in &args.remove_label {
+        storage.remove_label(id, label, actor)?;
+    }
+    if !args.set_labels.is_empty() {
+        // Remove all then add new
+        storage.remove_all_labels(id, actor)?;
+        // Join all flag values, then split by comma (handles both --set-labels a,b and --set-labels a --set-labels b)
+        let combined = args.set_labels.join(",");
+        for label in combined.split(',') {
+            let label = label.trim();
+            if !label.is_empty() {
+                LabelValidator::validate(label)
+                    .map_err(|e| BeadsError::validation("label", e.message))?;
+                storage.add_label(id, label, actor)?;
+            }
+        }
+    }
+
+    if let Some(labels) = edited_labels {
+        storage.remove_all_labels(id, actor)?;
+        for label in labels {
+            LabelValidator::validate(label)
+                .map_err(|e| BeadsError::validation("label", e.message))?;
+            storage.add_label(id, label, actor)?;
+        }
+    }
+    Ok(())
+}
+
+/// Validate `--field NAME=VALUE` assignments against the fields declared in
+/// config. A `None` value clears the field.
+fn parse_field_updates(
+    args: &UpdateArgs,
+    config_layer: &config::ConfigLayer,
+) -> Result<Vec<(String, Option<String>)>> {
+    if args.field.is_empty() {
+        return Ok(Vec::new());
+    }
+    let fields = config::fields::custom_fields_from_layer(config_layer)?;
+    args.field
+        .iter()
+        .map(|expr| config::fields::parse_assignment(expr, &fields))
+        .collect()
+}
+
 /// Print a summary of what changed for the issue.
 fn print_update_summary(id: &str, title: &str, before: Option<&Issue>, after: &Issue) {
     println!("Updated {id}: {title}");
@@ -186,16 +285,98 @@ fn print_update_summary(id: &str, title: &str, before: Option<&Issue>, after: &I
     }
 }
 
-fn build_resolver(config_layer: &config::ConfigLayer, _storage: &SqliteStorage) -> IdResolver {
+/// Reject `update` if the issue it leaves behind is missing a field that a
+/// policy requires (closing policies apply when it closes the issue).
+pub(crate) fn check_update_policies(
+    before: &Issue,
+    update: &IssueUpdate,
+    labels: Vec<String>,
+    rules: &[PolicyRule],
+) -> Result<()> {
+    let after = preview_update(before, update, labels);
+    let closing = after.status == Status::Closed && !before.status.is_terminal();
+    PolicyValidator::validate(&after, rules, closing).map_err(BeadsError::from_validation_errors)
+}
+
+/// The issue as it will look once `update` is applied, for policy checks.
+fn preview_update(before: &Issue, update: &IssueUpdate, labels: Vec<String>) -> Issue {
+    let mut issue = before.clone();
+    if let Some(description) = &update.description {
+        issue.description.clone_from(description);
+    }
+    if let Some(design) = &update.design {
+        issue.design.clone_from(design);
+    }
+    if let Some(acceptance_criteria) = &update.acceptance_criteria {
+        issue.acceptance_criteria.clone_from(acceptance_criteria);
+    }
+    if let Some(notes) = &update.notes {
+        issue.notes.clone_from(notes);
+    }
+    if let Some(status) = &update.status {
+        issue.status = status.clone();
+    }
+    if let Some(priority) = update.priority {
+        issue.priority = priority;
+    }
+    if let Some(issue_type) = &update.issue_type {
+        issue.issue_type = issue_type.clone();
+    }
+    if let Some(assignee) = &update.assignee {
+        issue.assignee.clone_from(assignee);
+    }
+    if let Some(owner) = &update.owner {
+        issue.owner.clone_from(owner);
+    }
+    if let Some(estimated_minutes) = update.estimated_minutes {
+        issue.estimated_minutes = estimated_minutes;
+    }
+    if let Some(due_at) = update.due_at {
+        issue.due_at = due_at;
+    }
+    issue.labels = labels;
+    issue
+}
+
+/// Labels after `--add-label`, `--remove-label` and `--set-labels` are applied.
+fn labels_after_update(args: &UpdateArgs, mut labels: Vec<String>) -> Vec<String> {
+    for label in &args.add_label {
+        if !labels.contains(label) {
+            labels.push(label.clone());
+        }
+    }
+    labels.retain(|label| !args.remove_label.contains(label));
+    if !args.set_labels.is_empty() {
+        labels = args
+            .set_labels
+            .iter()
+            .flat_map(|labels| labels.split(','))
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+    labels
+}
+
+fn build_resolver(
+    config_layer: &config::ConfigLayer,
+    storage: &dyn Storage,
+    beads_dir: &std::path::Path,
+) -> Result<IdResolver> {
     let id_config = config::id_config_from_layer(config_layer);
-    IdResolver::new(ResolverConfig::with_prefix(id_config.prefix))
+    Ok(IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage.get_id_aliases()?)
+            .with_last_touched(crate::util::get_last_touched_id(beads_dir)),
+    ))
 }
 
 fn resolve_target_ids(
     args: &UpdateArgs,
     beads_dir: &std::path::Path,
     resolver: &IdResolver,
-    storage: &SqliteStorage,
+    storage: &dyn Storage,
 ) -> Result<Vec<String>> {
     let mut ids = args.ids.clone();
     if ids.is_empty() {
@@ -209,13 +390,7 @@ fn resolve_target_ids(
         ids.push(last_touched);
     }
 
-    let resolved_ids = resolver.resolve_all(
-        &ids,
-        |id| storage.id_exists(id).unwrap_or(false),
-        |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
-    )?;
-
-    Ok(resolved_ids.into_iter().map(|r| r.id).collect())
+    resolve_selection(storage, resolver, &ids)
 }
 
 fn build_update(args: &UpdateArgs, actor: &str, claim_exclusive: bool) -> Result<IssueUpdate> {
@@ -239,19 +414,15 @@ fn build_update(args: &UpdateArgs, actor: &str, claim_exclusive: bool) -> Result
     let due_at = optional_date_field(args.due.as_deref())?;
     let defer_until = optional_date_field(args.defer.as_deref())?;
 
-    let closed_at = match &status {
-        Some(Status::Closed | Status::Tombstone) => Some(Some(Utc::now())),
-        Some(Status::Open | Status::InProgress) => Some(None),
-        _ => None,
-    };
+    let closed_at = closed_at_for(status.as_ref());
 
     // Build update struct
     Ok(IssueUpdate {
         title: args.title.clone(),
-        description: args.description.clone().map(Some),
-        design: args.design.clone().map(Some),
-        acceptance_criteria: args.acceptance_criteria.clone().map(Some),
-        notes: args.notes.clone().map(Some),
+        description: optional_string_field(args.description.as_deref()),
+        design: optional_string_field(args.design.as_deref()),
+        acceptance_criteria: optional_string_field(args.acceptance_criteria.as_deref()),
+        notes: optional_string_field(args.notes.as_deref()),
         status,
         priority,
         issue_type,
@@ -262,11 +433,12 @@ fn build_update(args: &UpdateArgs, actor: &str, claim_exclusive: bool) -> Result
         defer_until,
         external_ref: optional_string_field(args.external_ref.as_deref()),
         closed_at,
-        close_reason: None,
+        close_reason: optional_string_field(args.close_reason.as_deref()),
         closed_by_session: args.session.clone().map(Some),
         deleted_at: None,
         deleted_by: None,
         delete_reason: None,
+        pinned: None,
         skip_cache_rebuild: false,
         expect_unassigned: args.claim,
         claim_exclusive: args.claim && claim_exclusive,
@@ -278,6 +450,153 @@ fn build_update(args: &UpdateArgs, actor: &str, claim_exclusive: bool) -> Result
     })
 }
 
+/// `closed_at` change implied by a status change.
+#[allow(clippy::option_option)]
+pub(crate) fn closed_at_for(status: Option<&Status>) -> Option<Option<DateTime<Utc>>> {
+    match status {
+        Some(Status::Closed | Status::Tombstone) => Some(Some(Utc::now())),
+        Some(Status::Open | Status::InProgress) => Some(None),
+        _ => None,
+    }
+}
+
+/// An issue as presented in the editor by `--edit`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+struct EditableIssue {
+    title: String,
+    status: String,
+    priority: i32,
+    #[serde(rename = "type")]
+    issue_type: String,
+    assignee: Option<String>,
+    owner: Option<String>,
+    labels: Vec<String>,
+    estimated_minutes: Option<i32>,
+    due_at: Option<String>,
+    defer_until: Option<String>,
+    external_ref: Option<String>,
+    description: Option<String>,
+    design: Option<String>,
+    acceptance_criteria: Option<String>,
+    notes: Option<String>,
+}
+
+impl EditableIssue {
+    fn from_issue(issue: &Issue, labels: Vec<String>) -> Self {
+        Self {
+            title: issue.title.clone(),
+            status: issue.status.as_str().to_string(),
+            priority: issue.priority.0,
+            issue_type: issue.issue_type.as_str().to_string(),
+            assignee: issue.assignee.clone(),
+            owner: issue.owner.clone(),
+            labels,
+            estimated_minutes: issue.estimated_minutes,
+            due_at: issue.due_at.map(|d| d.to_rfc3339()),
+            defer_until: issue.defer_until.map(|d| d.to_rfc3339()),
+            external_ref: issue.external_ref.clone(),
+            description: issue.description.clone(),
+            design: issue.design.clone(),
+            acceptance_criteria: issue.acceptance_criteria.clone(),
+            notes: issue.notes.clone(),
+        }
+    }
+}
+
+/// Open the issue as YAML in `$EDITOR` and turn the edits into an update,
+/// plus the new label set if labels changed.
+fn edit_issue(storage: &dyn Storage, id: &str) -> Result<(IssueUpdate, Option<Vec<String>>)> {
+    let issue = storage
+        .get_issue(id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+    let original = EditableIssue::from_issue(&issue, storage.get_labels(id)?);
+
+    let path = env::temp_dir().join(format!("br-edit-{}-{id}.yaml", std::process::id()));
+    fs::write(&path, serde_yaml::to_string(&original)?)?;
+
+    let editor = env::var("EDITOR")
+        .or_else(|_| env::var("VISUAL"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let status = Command::new(&editor).arg(&path).status();
+    let content = fs::read_to_string(&path);
+    let _ = fs::remove_file(&path);
+
+    if !status?.success() {
+        return Err(BeadsError::validation(
+            "edit",
+            format!("{editor} exited with an error; no changes applied"),
+        ));
+    }
+    let edited: EditableIssue = serde_yaml::from_str(&content?)
+        .map_err(|e| BeadsError::validation("edit", format!("invalid YAML: {e}")))?;
+
+    edit_to_update(&original, &edited)
+}
+
+/// Build an update from the fields that differ between the original and the
+/// edited issue.
+fn edit_to_update(
+    original: &EditableIssue,
+    edited: &EditableIssue,
+) -> Result<(IssueUpdate, Option<Vec<String>>)> {
+    let mut update = IssueUpdate::default();
+
+    if edited.title != original.title {
+        update.title = Some(edited.title.clone());
+    }
+    if edited.status != original.status {
+        let status: Status = edited.status.parse()?;
+        update.closed_at = closed_at_for(Some(&status));
+        update.status = Some(status);
+    }
+    if edited.priority != original.priority {
+        update.priority = Some(edited.priority.to_string().parse()?);
+    }
+    if edited.issue_type != original.issue_type {
+        update.issue_type = Some(edited.issue_type.parse()?);
+    }
+    if edited.estimated_minutes != original.estimated_minutes {
+        update.estimated_minutes = Some(edited.estimated_minutes);
+    }
+    if edited.due_at != original.due_at {
+        update.due_at = optional_date_field(Some(edited.due_at.as_deref().unwrap_or("")))?;
+    }
+    if edited.defer_until != original.defer_until {
+        update.defer_until =
+            optional_date_field(Some(edited.defer_until.as_deref().unwrap_or("")))?;
+    }
+    update.assignee = changed_text(original.assignee.as_deref(), edited.assignee.as_deref());
+    update.owner = changed_text(original.owner.as_deref(), edited.owner.as_deref());
+    update.external_ref = changed_text(
+        original.external_ref.as_deref(),
+        edited.external_ref.as_deref(),
+    );
+    update.description = changed_text(
+        original.description.as_deref(),
+        edited.description.as_deref(),
+    );
+    update.design = changed_text(original.design.as_deref(), edited.design.as_deref());
+    update.acceptance_criteria = changed_text(
+        original.acceptance_criteria.as_deref(),
+        edited.acceptance_criteria.as_deref(),
+    );
+    update.notes = changed_text(original.notes.as_deref(), edited.notes.as_deref());
+
+    let labels = (edited.labels != original.labels).then(|| edited.labels.clone());
+    Ok((update, labels))
+}
+
+/// The new value of an edited text field, or `None` if it didn't change.
+/// Blank values clear the field.
+#[allow(clippy::option_option)]
+fn changed_text(original: Option<&str>, edited: Option<&str>) -> Option<Option<String>> {
+    if edited == original {
+        return None;
+    }
+    Some(edited.filter(|v| !v.trim().is_empty()).map(str::to_string))
+}
+
 #[allow(clippy::option_option, clippy::single_option_map)]
 fn optional_string_field(value: Option<&str>) -> Option<Option<String>> {
     value.map(|v| {
@@ -302,7 +621,7 @@ fn optional_date_field(value: Option<&str>) -> Result<Option<Option<DateTime<Utc
         .transpose()
 }
 
-fn resolve_issue_id(resolver: &IdResolver, storage: &SqliteStorage, input: &str) -> Result<String> {
+fn resolve_issue_id(resolver: &IdResolver, storage: &dyn Storage, input: &str) -> Result<String> {
     resolver
         .resolve(
             input,
@@ -313,7 +632,7 @@ fn resolve_issue_id(resolver: &IdResolver, storage: &SqliteStorage, input: &str)
 }
 
 fn apply_parent_update(
-    storage: &mut SqliteStorage,
+    storage: &mut dyn Storage,
     issue_id: &str,
     parent: Option<&str>,
     resolver: &IdResolver,
@@ -515,6 +834,54 @@ mod tests {
         info!("test_build_update_with_priority: assertions passed");
     }
 
+    #[test]
+    fn test_build_update_empty_text_clears_field() {
+        let args = UpdateArgs {
+            description: Some(String::new()),
+            notes: Some("Keep".to_string()),
+            ..Default::default()
+        };
+        let update = build_update(&args, "test_actor", false).unwrap();
+        assert_eq!(update.description, Some(None));
+        assert_eq!(update.notes, Some(Some("Keep".to_string())));
+        assert_eq!(update.design, None);
+    }
+
+    #[test]
+    fn test_edit_to_update_only_changed_fields() {
+        let original = EditableIssue {
+            title: "Old".to_string(),
+            status: "open".to_string(),
+            priority: 2,
+            issue_type: "task".to_string(),
+            description: Some("Body".to_string()),
+            labels: vec!["backend".to_string()],
+            ..Default::default()
+        };
+        let mut edited = original.clone();
+        let (update, labels) = edit_to_update(&original, &edited).unwrap();
+        assert!(update.is_empty());
+        assert!(labels.is_none());
+
+        edited.status = "closed".to_string();
+        edited.priority = 1;
+        edited.description = Some("  ".to_string());
+        edited.labels.push("urgent".to_string());
+        let (update, labels) = edit_to_update(&original, &edited).unwrap();
+        assert_eq!(update.status, Some(Status::Closed));
+        assert!(update.closed_at.is_some());
+        assert_eq!(update.priority, Some(Priority(1)));
+        assert_eq!(update.description, Some(None));
+        assert_eq!(update.title, None);
+        assert_eq!(
+            labels,
+            Some(vec!["backend".to_string(), "urgent".to_string()])
+        );
+
+        edited.priority = 9;
+        assert!(edit_to_update(&original, &edited).is_err());
+    }
+
     #[test]
     fn test_build_update_empty() {
         init_test_logging();
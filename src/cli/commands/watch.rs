@@ -0,0 +1,117 @@
+//! Watch command implementation.
+//!
+//! `br watch <id>` registers the actor (the global `--actor` or the usual
+//! one) as a watcher of an issue. Events others record on watched issues
+//! show up in `br inbox`, and webhook payloads list an issue's watchers.
+//! Watchers are exported with the issue, so they follow it across clones.
+
+use crate::cli::WatchArgs;
+use crate::config;
+use crate::error::Result;
+use crate::format::truncate_title;
+use crate::output::OutputContext;
+use crate::util::id::{IdResolver, ResolverConfig};
+use serde::Serialize;
+
+/// JSON output for one watched or unwatched issue.
+#[derive(Debug, Serialize)]
+struct WatchResult {
+    issue_id: String,
+    actor: String,
+    /// `added`, `removed`, or `unchanged`
+    action: &'static str,
+}
+
+/// JSON output for one issue in the watch list.
+#[derive(Debug, Serialize)]
+struct WatchedIssue {
+    id: String,
+    title: String,
+    status: String,
+}
+
+/// Execute the watch command.
+///
+/// # Errors
+///
+/// Returns an error if an issue cannot be found or the database update fails.
+pub fn execute(args: &WatchArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&*storage_ctx.storage), cli)?;
+    let actor = config::actor(&config_layer);
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix)
+            .with_aliases(storage_ctx.storage.get_id_aliases()?),
+    );
+    let storage = &mut *storage_ctx.storage;
+
+    if args.ids.is_empty() {
+        let mut watched = Vec::new();
+        for (id, _) in storage.get_watched_issues(&actor)? {
+            if let Some(issue) = storage.get_issue(&id)? {
+                watched.push(WatchedIssue {
+                    id: issue.id,
+                    title: issue.title,
+                    status: issue.status.as_str().to_string(),
+                });
+            }
+        }
+        if ctx.is_json() {
+            ctx.json_pretty(&watched);
+        } else if !ctx.is_quiet() {
+            if watched.is_empty() {
+                println!("{actor} is not watching any issues.");
+            }
+            for issue in &watched {
+                println!(
+                    "{:<10} [{}] {}",
+                    issue.id,
+                    issue.status,
+                    truncate_title(&issue.title, 60)
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    let mut results = Vec::with_capacity(args.ids.len());
+    for input in &args.ids {
+        let issue_id = resolver
+            .resolve(
+                input,
+                |id| storage.id_exists(id).unwrap_or(false),
+                |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+            )?
+            .id;
+        let changed = if args.remove {
+            storage.remove_watcher(&issue_id, &actor, &actor)?
+        } else {
+            storage.add_watcher(&issue_id, &actor, &actor)?
+        };
+        let action = match (changed, args.remove) {
+            (false, _) => "unchanged",
+            (true, false) => "added",
+            (true, true) => "removed",
+        };
+        if !ctx.is_json() {
+            match action {
+                "added" => ctx.success(&format!("{actor} is watching {issue_id}")),
+                "removed" => ctx.success(&format!("{actor} stopped watching {issue_id}")),
+                _ if args.remove => ctx.info(&format!("{actor} was not watching {issue_id}")),
+                _ => ctx.info(&format!("{actor} is already watching {issue_id}")),
+            }
+        }
+        results.push(WatchResult {
+            issue_id,
+            actor: actor.clone(),
+            action,
+        });
+    }
+    if ctx.is_json() {
+        ctx.json_pretty(&results);
+    }
+
+    storage_ctx.flush_no_db_if_dirty()
+}
@@ -2,7 +2,7 @@
 
 use crate::config;
 use crate::config::routing::follow_redirects;
-use crate::error::Result;
+use crate::error::{Result, exit_code};
 use crate::output::OutputContext;
 use crate::util::parse_id;
 use rich_rust::prelude::*;
@@ -149,7 +149,7 @@ fn handle_missing_beads(ctx: &OutputContext) -> Result<()> {
         eprintln!("No beads directory found.");
         eprintln!("Run `br init` to create one.");
     }
-    std::process::exit(1);
+    std::process::exit(exit_code::NOT_INITIALIZED);
 }
 
 /// Render location info as a rich panel.
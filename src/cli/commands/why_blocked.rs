@@ -0,0 +1,222 @@
+//! Why-blocked command implementation.
+//!
+//! `br why-blocked <id>` walks the blocked cache from an issue to everything
+//! transitively blocking it and marks the blockers that can be worked on
+//! now (open or in progress and not blocked themselves). Those are the
+//! actionable roots: finishing them is what eventually unblocks the target.
+
+use crate::cli::WhyBlockedArgs;
+use crate::config;
+use crate::error::Result;
+use crate::model::Status;
+use crate::output::OutputContext;
+use crate::storage::Storage;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use serde::Serialize;
+
+/// One issue in the blocking tree.
+#[derive(Debug, Serialize)]
+struct BlockerNode {
+    id: String,
+    title: String,
+    status: String,
+    priority: i32,
+    /// Not blocked itself and workable now
+    actionable: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    blocked_by: Vec<BlockerNode>,
+}
+
+/// JSON output for the why-blocked command.
+#[derive(Debug, Serialize)]
+struct WhyBlockedOutput {
+    id: String,
+    blocked: bool,
+    tree: BlockerNode,
+    /// Chains from the issue down to each actionable blocker
+    paths: Vec<Vec<String>>,
+    /// Distinct actionable blockers, in first-seen order
+    actionable: Vec<String>,
+}
+
+/// Execute the why-blocked command.
+///
+/// # Errors
+///
+/// Returns an error if the ID cannot be resolved or database operations fail.
+pub fn execute(
+    args: &WhyBlockedArgs,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &*storage_ctx.storage;
+
+    let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(
+        ResolverConfig::with_prefix(id_config.prefix).with_aliases(storage.get_id_aliases()?),
+    );
+    let all_ids = storage.get_all_ids()?;
+    let id = resolver
+        .resolve(
+            &args.id,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?
+        .id;
+
+    let tree = build_node(storage, &id, &mut Vec::new())?;
+    let mut paths = Vec::new();
+    collect_paths(&tree, &mut Vec::new(), &mut paths);
+    let mut actionable: Vec<String> = Vec::new();
+    for path in &paths {
+        if let Some(last) = path.last() {
+            if !actionable.contains(last) {
+                actionable.push(last.clone());
+            }
+        }
+    }
+    let output = WhyBlockedOutput {
+        id,
+        blocked: !tree.blocked_by.is_empty(),
+        tree,
+        paths,
+        actionable,
+    };
+
+    if ctx.is_json() {
+        ctx.json_pretty(&output);
+        return Ok(());
+    }
+    if !output.blocked {
+        ctx.info(&format!("{} is not blocked", output.id));
+        return Ok(());
+    }
+
+    let mut lines = Vec::new();
+    render_tree(&output.tree, 0, &mut lines);
+    for line in lines {
+        ctx.print(&line);
+    }
+    ctx.newline();
+    if output.actionable.is_empty() {
+        ctx.warning("No blocker can be worked on now (deferred, manually blocked, or cyclic)");
+    } else {
+        ctx.info(&format!(
+            "Work on {} to unblock {}",
+            output.actionable.join(", "),
+            output.id
+        ));
+    }
+    Ok(())
+}
+
+/// Build the blocking tree below `id`. Edges back into `path` (cycles) are
+/// skipped.
+fn build_node(storage: &dyn Storage, id: &str, path: &mut Vec<String>) -> Result<BlockerNode> {
+    let issue = storage.get_issue(id)?;
+    let blocked = storage.is_blocked(id)?;
+
+    let mut blocked_by = Vec::new();
+    if blocked {
+        path.push(id.to_string());
+        let mut blockers = storage.get_blockers(id)?;
+        blockers.sort();
+        blockers.dedup();
+        for blocker in blockers {
+            if !path.contains(&blocker) {
+                blocked_by.push(build_node(storage, &blocker, path)?);
+            }
+        }
+        path.pop();
+    }
+
+    Ok(match issue {
+        Some(issue) => BlockerNode {
+            actionable: !blocked && matches!(issue.status, Status::Open | Status::InProgress),
+            id: issue.id,
+            title: issue.title,
+            status: issue.status.as_str().to_string(),
+            priority: issue.priority.0,
+            blocked_by,
+        },
+        // External or missing blocker
+        None => BlockerNode {
+            id: id.to_string(),
+            title: id.to_string(),
+            status: "unknown".to_string(),
+            priority: 2,
+            actionable: false,
+            blocked_by,
+        },
+    })
+}
+
+/// Collect the ID chains from the root to each actionable blocker.
+fn collect_paths(node: &BlockerNode, prefix: &mut Vec<String>, paths: &mut Vec<Vec<String>>) {
+    prefix.push(node.id.clone());
+    if node.actionable && prefix.len() > 1 {
+        paths.push(prefix.clone());
+    }
+    for child in &node.blocked_by {
+        collect_paths(child, prefix, paths);
+    }
+    prefix.pop();
+}
+
+fn render_tree(node: &BlockerNode, depth: usize, lines: &mut Vec<String>) {
+    let indent = "  ".repeat(depth);
+    let prefix = if depth == 0 { "" } else { "├── " };
+    let marker = if node.actionable { "  <- ready" } else { "" };
+    lines.push(format!(
+        "{indent}{prefix}{}: {} [P{}] [{}]{marker}",
+        node.id, node.title, node.priority, node.status
+    ));
+    for child in &node.blocked_by {
+        render_tree(child, depth + 1, lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{DependencyType, Issue};
+    use crate::storage::SqliteStorage;
+
+    fn add_issue(storage: &mut dyn Storage, id: &str, status: Status) {
+        let issue = Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            status,
+            ..Issue::default()
+        };
+        storage.create_issue(&issue, "tester").unwrap();
+    }
+
+    #[test]
+    fn test_blocking_tree_marks_actionable_roots() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        add_issue(&mut storage, "bd-target", Status::Open);
+        add_issue(&mut storage, "bd-mid", Status::Open);
+        add_issue(&mut storage, "bd-root", Status::InProgress);
+        add_issue(&mut storage, "bd-later", Status::Deferred);
+        let blocks = DependencyType::Blocks.as_str();
+        storage
+            .add_dependency("bd-target", "bd-mid", blocks, "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-target", "bd-later", blocks, "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-mid", "bd-root", blocks, "tester")
+            .unwrap();
+
+        let tree = build_node(&storage, "bd-target", &mut Vec::new()).unwrap();
+        assert_eq!(tree.blocked_by.len(), 2);
+        let mut paths = Vec::new();
+        collect_paths(&tree, &mut Vec::new(), &mut paths);
+        assert_eq!(paths, vec![vec!["bd-target", "bd-mid", "bd-root"]]);
+    }
+}
@@ -16,6 +16,7 @@ use crate::format::truncate_title;
 use crate::model::{IssueType, Status};
 
 pub mod commands;
+pub mod selection;
 
 #[derive(Clone, Copy)]
 enum IssueCompletionFilter {
@@ -287,7 +288,7 @@ fn build_config_index() -> CompletionConfigIndex {
         if let Ok(storage_ctx) =
             config::open_storage_with_cli(&beads_dir, &config::CliOverrides::default())
         {
-            if let Ok(db_layer) = config::ConfigLayer::from_db(&storage_ctx.storage) {
+            if let Ok(db_layer) = config::ConfigLayer::from_db(&*storage_ctx.storage) {
                 add_layer_keys(&mut keys, &db_layer);
             }
             if let Ok(map) = storage_ctx.storage.get_all_config() {
@@ -691,6 +692,14 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_db: bool,
 
+    /// Open storage read-only; mutating commands only validate (also `BEADS_READONLY=1`)
+    #[arg(long, global = true)]
+    pub read_only: bool,
+
+    /// Print the changes a mutating command would make as a JSON plan, without making them
+    #[arg(long, global = true)]
+    pub plan: bool,
+
     /// Increase logging verbosity (-v, -vv)
     #[arg(short, long, action = clap::ArgAction::Count, global = true)]
     pub verbose: u8,
@@ -699,9 +708,33 @@ pub struct Cli {
     #[arg(short, long, global = true)]
     pub quiet: bool,
 
-    /// Disable colored output
+    /// Disable colored output (same as `--color never`)
     #[arg(long, global = true)]
     pub no_color: bool,
+
+    /// When to color output: auto (terminal, no `NO_COLOR`), always, never
+    #[arg(long, global = true, value_enum, value_name = "WHEN", default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
+
+    /// Show dates and times instead of relative times ("3d ago") in text output
+    #[arg(long, global = true)]
+    pub absolute: bool,
+}
+
+impl Cli {
+    /// Color forced on or off by `--color`/`--no-color`, or `None` to
+    /// defer to `display.color` and the terminal.
+    #[must_use]
+    pub const fn color_override(&self) -> Option<bool> {
+        if self.no_color {
+            return Some(false);
+        }
+        match self.color {
+            ColorChoice::Auto => None,
+            ColorChoice::Always => Some(true),
+            ColorChoice::Never => Some(false),
+        }
+    }
 }
 
 #[derive(Subcommand, Debug)]
@@ -736,6 +769,21 @@ pub enum Commands {
     /// Update an issue
     Update(UpdateArgs),
 
+    /// Move an issue under a new parent (rewrites hierarchical IDs)
+    Reparent(ReparentArgs),
+
+    /// Move a child issue to the top level (rewrites hierarchical IDs)
+    Promote(PromoteArgs),
+
+    /// Change an issue's ID, keeping the old ID as an alias
+    Rename(RenameArgs),
+
+    /// Close an issue as a duplicate of another
+    Duplicate(DuplicateArgs),
+
+    /// Close an issue as superseded by a newer one
+    Supersede(SupersedeArgs),
+
     /// Close an issue
     Close(CloseArgs),
 
@@ -745,9 +793,18 @@ pub enum Commands {
     /// Delete an issue (creates tombstone)
     Delete(DeleteArgs),
 
+    /// Restore deleted (tombstoned) issues
+    Restore(RestoreArgs),
+
     /// List ready issues (unblocked, not deferred)
     Ready(ReadyArgs),
 
+    /// Claim the top ready issue(s): assign to you and mark in progress
+    Claim(ClaimArgs),
+
+    /// Show the single next ready issue to work on (optionally claiming it)
+    Next(NextArgs),
+
     /// List blocked issues
     Blocked(BlockedArgs),
 
@@ -772,10 +829,25 @@ pub enum Commands {
         command: EpicCommands,
     },
 
+    /// Group issues into milestones (sprints, releases) and track progress
+    Milestone {
+        #[command(subcommand)]
+        command: MilestoneCommands,
+    },
+
     /// Manage comments
     #[command(alias = "comment")]
     Comments(CommentsArgs),
 
+    /// React to an issue or comment (e.g. :+1:)
+    React(ReactArgs),
+
+    /// Link source code locations to an issue (path, path:line, path@commit)
+    Link(LinkArgs),
+
+    /// Watch issues: their events show up in your inbox and in webhooks
+    Watch(WatchArgs),
+
     /// Show project statistics
     Stats(StatsArgs),
 
@@ -797,6 +869,15 @@ pub enum Commands {
     /// Undefer issues (make ready again)
     Undefer(UndeferArgs),
 
+    /// Pin issues so they sort first in list and ready
+    Pin(PinArgs),
+
+    /// Unpin issues
+    Unpin(PinArgs),
+
+    /// Tombstone expired ephemeral issues (and old closed issues with --aggressive)
+    Gc(GcArgs),
+
     /// Configuration management
     Config {
         #[command(subcommand)]
@@ -850,6 +931,9 @@ EXAMPLES:
     /// Emit JSON Schemas for br output types (for agent/tooling integration)
     Schema(SchemaArgs),
 
+    /// Migrate the database schema, or show its migration status
+    Migrate(MigrateArgs),
+
     /// Show the active .beads directory
     Where,
 
@@ -860,10 +944,18 @@ EXAMPLES:
     #[cfg(feature = "self_update")]
     Upgrade(UpgradeArgs),
 
+    /// Serve a JSON API for editors and dashboards on localhost
+    #[cfg(feature = "serve")]
+    Serve(ServeArgs),
+
     /// Generate shell completions
     #[command(alias = "completion")]
     Completions(CompletionsArgs),
 
+    /// Print completion candidates one per line (for custom completion scripts)
+    #[command(name = "__complete-ids", hide = true)]
+    CompleteIds(CompleteIdsArgs),
+
     /// Record and label agent interactions (append-only JSONL)
     Audit {
         #[command(subcommand)]
@@ -872,10 +964,20 @@ EXAMPLES:
 
     /// Manage local history backups
     History(HistoryArgs),
+    /// Show created/closed/modified issues between two JSONL files or the database
+    Diff(DiffArgs),
     /// List orphan issues (referenced in commits but open)
     Orphans(OrphansArgs),
     /// Generate changelog from closed issues
     Changelog(ChangelogArgs),
+    /// Export issues as CSV/TSV for spreadsheets or ICS for calendars
+    Export(ExportArgs),
+    /// Generate a Markdown status report
+    Report(ReportArgs),
+    /// Import issues from Jira, GitHub, or Linear CSV exports
+    Import(ImportArgs),
+    /// Apply a change plan recorded with `--plan`
+    Apply(ApplyArgs),
 
     /// Manage saved queries
     Query {
@@ -886,8 +988,45 @@ EXAMPLES:
     /// Visualize dependency graph
     Graph(GraphArgs),
 
+    /// Explain what is transitively blocking an issue
+    WhyBlocked(WhyBlockedArgs),
+
+    /// Show the longest chain of open blocking work, weighted by estimates
+    CriticalPath(CriticalPathArgs),
+
+    /// Show everything transitively blocked by an issue
+    Impact(ImpactArgs),
+
+    /// Forecast when an epic or the open backlog will be done, from past
+    /// throughput
+    Forecast(ForecastArgs),
+
+    /// Show recent events and comments across all issues, newest first
+    Activity(ActivityArgs),
+
+    /// List mentions, assignments and watched-issue activity since you last checked
+    Inbox(InboxArgs),
+
+    /// Send and read messages between agents
+    Msg {
+        #[command(subcommand)]
+        command: MsgCommands,
+    },
+
+    /// Group changes into named sessions and summarize them
+    Session {
+        #[command(subcommand)]
+        command: SessionCommands,
+    },
+
     /// Manage AGENTS.md workflow instructions
     Agents(AgentsArgs),
+
+    /// Manage remote beads workspaces for federated queries
+    Remote {
+        #[command(subcommand)]
+        command: RemoteCommands,
+    },
 }
 
 /// Arguments for the completions command.
@@ -902,6 +1041,59 @@ pub struct CompletionsArgs {
     pub output: Option<std::path::PathBuf>,
 }
 
+/// Arguments for the hidden `__complete-ids` helper.
+#[derive(Args, Debug, Clone, Default)]
+pub struct CompleteIdsArgs {
+    /// Prefix typed so far
+    #[arg(default_value = "")]
+    pub partial: String,
+
+    /// What to complete
+    #[arg(long, value_enum, default_value_t = CompletionKind::Ids)]
+    pub kind: CompletionKind,
+
+    /// Append a tab and a description to each candidate
+    #[arg(long)]
+    pub describe: bool,
+}
+
+/// Candidate sets offered by `__complete-ids`.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum CompletionKind {
+    /// Every issue ID
+    #[default]
+    Ids,
+    /// IDs of issues that are not closed
+    OpenIds,
+    /// Labels in use
+    Labels,
+    /// Issue statuses
+    Statuses,
+}
+
+/// Completion candidates for `kind` starting with `partial`, as
+/// `(value, description)` pairs.
+///
+/// Uses the same workspace index as the dynamic `COMPLETE=<shell>` completion.
+#[must_use]
+pub fn completion_candidates(kind: CompletionKind, partial: &str) -> Vec<(String, Option<String>)> {
+    let candidates = match kind {
+        CompletionKind::Ids => issue_id_candidates(partial, IssueCompletionFilter::Any),
+        CompletionKind::OpenIds => issue_id_candidates(partial, IssueCompletionFilter::Open),
+        CompletionKind::Labels => dynamic_candidates(partial, &completion_index().labels),
+        CompletionKind::Statuses => static_candidates(partial, STATUS_CANDIDATES),
+    };
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            (
+                candidate.get_value().to_string_lossy().into_owned(),
+                candidate.get_help().map(ToString::to_string),
+            )
+        })
+        .collect()
+}
+
 /// Supported shells for completion generation.
 #[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
 pub enum ShellType {
@@ -921,7 +1113,7 @@ pub enum ShellType {
 
 #[derive(Args, Debug, Default)]
 pub struct CreateArgs {
-    /// Issue title
+    /// Issue title ('-' reads a JSON issue or array of issues from stdin)
     pub title: Option<String>,
 
     /// Issue title (alternative to positional argument)
@@ -995,6 +1187,19 @@ pub struct CreateArgs {
     /// Create issues from a markdown file (bulk import)
     #[arg(long, short = 'f')]
     pub file: Option<std::path::PathBuf>,
+
+    /// With --file, update issues matched by their `<!-- br:id -->` comment
+    /// or external ref instead of creating duplicates
+    #[arg(long, requires = "file")]
+    pub update: bool,
+
+    /// Don't apply the per-type field defaults from `type_defaults` config
+    #[arg(long)]
+    pub no_defaults: bool,
+
+    /// Create even if the issue looks like an existing open issue
+    #[arg(long)]
+    pub allow_duplicate: bool,
 }
 
 #[derive(Args, Debug)]
@@ -1013,12 +1218,16 @@ pub struct QuickArgs {
     /// Labels to apply (repeatable, comma-separated allowed)
     #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
     pub labels: Vec<String>,
+
+    /// Don't apply the per-type field defaults from `type_defaults` config
+    #[arg(long)]
+    pub no_defaults: bool,
 }
 
 #[derive(Args, Debug, Default)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct UpdateArgs {
-    /// Issue IDs to update
+    /// Issue IDs or selectors (`a..b`, `id.*`, `@filter:"..."`) to update
     #[arg(add = ArgValueCompleter::new(issue_id_completer))]
     pub ids: Vec<String>,
 
@@ -1026,19 +1235,19 @@ pub struct UpdateArgs {
     #[arg(long)]
     pub title: Option<String>,
 
-    /// Update description
+    /// Update description (empty string clears)
     #[arg(long, visible_alias = "body")]
     pub description: Option<String>,
 
-    /// Update design notes
+    /// Update design notes (empty string clears)
     #[arg(long)]
     pub design: Option<String>,
 
-    /// Update acceptance criteria
+    /// Update acceptance criteria (empty string clears)
     #[arg(long, visible_alias = "acceptance")]
     pub acceptance_criteria: Option<String>,
 
-    /// Update additional notes
+    /// Update additional notes (empty string clears)
     #[arg(long)]
     pub notes: Option<String>,
 
@@ -1066,7 +1275,7 @@ pub struct UpdateArgs {
     #[arg(long)]
     pub claim: bool,
 
-    /// Force update even if issue is blocked
+    /// Force update even if issue is blocked or violates a configured policy
     #[arg(long)]
     pub force: bool,
 
@@ -1094,23 +1303,35 @@ pub struct UpdateArgs {
     #[arg(long, add = ArgValueCompleter::new(label_completer_delimited))]
     pub set_labels: Vec<String>,
 
+    /// Set a custom field declared in config (empty value clears; repeatable)
+    #[arg(long, value_name = "NAME=VALUE")]
+    pub field: Vec<String>,
+
     /// Reparent to new parent (empty string removes parent)
     #[arg(long, add = ArgValueCompleter::new(issue_id_completer))]
     pub parent: Option<String>,
 
-    /// Set external reference
+    /// Set external reference (empty string clears)
     #[arg(long)]
     pub external_ref: Option<String>,
 
+    /// Set close reason (empty string clears)
+    #[arg(long)]
+    pub close_reason: Option<String>,
+
     /// Set `closed_by_session` when closing
     #[arg(long)]
     pub session: Option<String>,
+
+    /// Open the issue as YAML in $EDITOR (single ID)
+    #[arg(long)]
+    pub edit: bool,
 }
 
 #[derive(Args, Debug)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct DeleteArgs {
-    /// Issue IDs to delete
+    /// Issue IDs or selectors (`a..b`, `id.*`, `@filter:"..."`) to delete
     #[arg(add = ArgValueCompleter::new(issue_id_completer))]
     pub ids: Vec<String>,
 
@@ -1130,7 +1351,8 @@ pub struct DeleteArgs {
     #[arg(long, conflicts_with = "cascade")]
     pub force: bool,
 
-    /// Prune tombstones from JSONL immediately
+    /// Remove the issues outright instead of leaving tombstones, and
+    /// rewrite the JSONL without them right away
     #[arg(long)]
     pub hard: bool,
 
@@ -1139,6 +1361,73 @@ pub struct DeleteArgs {
     pub dry_run: bool,
 }
 
+/// Arguments for the restore command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct RestoreArgs {
+    /// Issue IDs to restore
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub ids: Vec<String>,
+
+    /// Reason for restoring (recorded on the restore event)
+    #[arg(long, short = 'r')]
+    pub reason: Option<String>,
+}
+
+/// Arguments for the reparent command.
+#[derive(Args, Debug, Default, Clone)]
+pub struct ReparentArgs {
+    /// Issue ID to move
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// New parent issue ID
+    #[arg(long, value_name = "PARENT", add = ArgValueCompleter::new(issue_id_completer))]
+    pub to: String,
+}
+
+/// Arguments for the promote command.
+#[derive(Args, Debug, Default, Clone)]
+pub struct PromoteArgs {
+    /// Issue ID to move to the top level
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+}
+
+/// Arguments for the duplicate command.
+#[derive(Args, Debug, Default, Clone)]
+pub struct DuplicateArgs {
+    /// Issue ID to close as a duplicate
+    #[arg(add = ArgValueCompleter::new(open_issue_id_completer))]
+    pub id: String,
+
+    /// The issue it duplicates
+    #[arg(long, value_name = "ID", add = ArgValueCompleter::new(issue_id_completer))]
+    pub of: String,
+}
+
+/// Arguments for the supersede command.
+#[derive(Args, Debug, Default, Clone)]
+pub struct SupersedeArgs {
+    /// Issue ID to close as superseded
+    #[arg(add = ArgValueCompleter::new(open_issue_id_completer))]
+    pub id: String,
+
+    /// The issue that replaces it
+    #[arg(long, value_name = "ID", add = ArgValueCompleter::new(issue_id_completer))]
+    pub by: String,
+}
+
+/// Arguments for the rename command.
+#[derive(Args, Debug, Default, Clone)]
+pub struct RenameArgs {
+    /// Current issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub old_id: String,
+
+    /// New issue ID (any prefix and hash)
+    pub new_id: String,
+}
+
 /// Arguments for the info command.
 #[derive(Args, Debug, Default, Clone)]
 pub struct InfoArgs {
@@ -1155,6 +1444,18 @@ pub struct InfoArgs {
     pub thanks: bool,
 }
 
+/// Arguments for the migrate command.
+#[derive(Args, Debug, Default, Clone)]
+pub struct MigrateArgs {
+    /// Show applied and pending migrations without migrating
+    #[arg(long)]
+    pub status: bool,
+
+    /// Recompute the cached dependency counts on every issue
+    #[arg(long, conflicts_with = "status")]
+    pub rebuild_counts: bool,
+}
+
 /// Arguments for the schema command.
 #[derive(Args, Debug, Default, Clone)]
 pub struct SchemaArgs {
@@ -1179,8 +1480,10 @@ pub enum SchemaTarget {
     All,
     /// Core Issue object (used by many commands)
     Issue,
-    /// List/search row: Issue + dependency/dependent counts
+    /// List row: Issue + dependency/dependent counts
     IssueWithCounts,
+    /// Search row: IssueWithCounts + match_field/snippet
+    SearchResult,
     /// Show view: Issue + relations/comments/events
     IssueDetails,
     /// Ready list row
@@ -1209,6 +1512,8 @@ pub enum OutputFormat {
     Csv,
     /// TOON format (token-optimized object notation)
     Toon,
+    /// Aligned table with selectable columns
+    Table,
 }
 
 impl OutputFormat {
@@ -1263,6 +1568,43 @@ impl From<OutputFormatBasic> for OutputFormat {
     }
 }
 
+/// Output format for `br ready` and `br blocked`.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum ReportFormat {
+    /// Human-readable text (default)
+    #[default]
+    Text,
+    /// JSON output
+    Json,
+    /// TOON format (token-optimized object notation)
+    Toon,
+    /// Aligned table with selectable columns
+    Table,
+}
+
+impl From<ReportFormat> for OutputFormat {
+    fn from(format: ReportFormat) -> Self {
+        match format {
+            ReportFormat::Text => Self::Text,
+            ReportFormat::Json => Self::Json,
+            ReportFormat::Toon => Self::Toon,
+            ReportFormat::Table => Self::Table,
+        }
+    }
+}
+
+/// When to use ANSI color (`--color`).
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and `NO_COLOR` is unset
+    #[default]
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
 /// Resolve effective output format with CLI/env precedence.
 #[must_use]
 pub fn resolve_output_format(
@@ -1279,6 +1621,32 @@ pub fn resolve_output_format(
     }
 }
 
+/// Output format for `br show`.
+#[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ShowFormat {
+    /// Human-readable text (default)
+    Text,
+    /// JSON output
+    Json,
+    /// TOON format (token-optimized object notation)
+    Toon,
+    /// Markdown in the `br create --file` dialect
+    Md,
+}
+
+impl ShowFormat {
+    /// The equivalent basic format, or None for markdown.
+    #[must_use]
+    pub const fn basic(self) -> Option<OutputFormatBasic> {
+        match self {
+            Self::Text => Some(OutputFormatBasic::Text),
+            Self::Json => Some(OutputFormatBasic::Json),
+            Self::Toon => Some(OutputFormatBasic::Toon),
+            Self::Md => None,
+        }
+    }
+}
+
 /// Resolve effective output format for commands without CSV support.
 #[must_use]
 pub fn resolve_output_format_basic(
@@ -1288,7 +1656,7 @@ pub fn resolve_output_format_basic(
 ) -> OutputFormat {
     let resolved = resolve_output_format(requested.map(Into::into), json, robot);
     match resolved {
-        OutputFormat::Csv => OutputFormat::Text,
+        OutputFormat::Csv | OutputFormat::Table => OutputFormat::Text,
         other => other,
     }
 }
@@ -1349,6 +1717,31 @@ pub struct ListArgs {
     #[arg(long)]
     pub notes_contains: Option<String>,
 
+    /// Only issues with a code ref in this file or directory (see `br link`)
+    #[arg(long, value_name = "PATH")]
+    pub touches: Option<String>,
+
+    /// Only issues that depend on this issue
+    #[arg(long, value_name = "ID", add = ArgValueCompleter::new(issue_id_completer))]
+    pub depends_on: Option<String>,
+
+    /// Only issues that block this issue
+    #[arg(long, value_name = "ID", add = ArgValueCompleter::new(issue_id_completer))]
+    pub blocks: Option<String>,
+
+    /// Only issues with a dependency of this type (narrows --depends-on/--blocks)
+    #[arg(long, add = ArgValueCompleter::new(dep_type_completer))]
+    pub dep_type: Option<String>,
+
+    /// Only issues in this milestone (see `br milestone`)
+    #[arg(long, value_name = "ID", conflicts_with_all = ["depends_on", "dep_type"], add = ArgValueCompleter::new(issue_id_completer))]
+    pub milestone: Option<String>,
+
+    /// Only issues whose custom field matches (`=`, `!=`, `<`, `<=`, `>`, `>=`;
+    /// `FIELD=` means unset; repeatable, all must match)
+    #[arg(long = "where", value_name = "FIELD=VALUE")]
+    pub where_: Vec<String>,
+
     /// Include closed issues (default excludes closed)
     #[arg(long, short = 'a')]
     pub all: bool,
@@ -1385,14 +1778,40 @@ pub struct ListArgs {
     #[arg(long)]
     pub wrap: bool,
 
-    /// Output format (text, json, csv, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
+    /// Show issues in sections by status, assignee, label or type
+    #[arg(long, value_enum, conflicts_with_all = ["stream", "paginate", "after"])]
+    pub group_by: Option<GroupBy>,
+
+    /// Output format (text, json, csv, toon, table). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
     pub format: Option<OutputFormat>,
 
+    /// Table columns (comma-separated) for `--format table`
+    ///
+    /// Default: id, status, priority, assignee, age, title
+    #[arg(long, value_name = "COLS")]
+    pub columns: Option<String>,
+
     /// Show token savings stats when using TOON output
     #[arg(long)]
     pub stats: bool,
 
+    /// Stream results as JSON Lines (one object per line) without buffering the full result set
+    #[arg(long, conflicts_with_all = ["format", "stats", "fields"])]
+    pub stream: bool,
+
+    /// Page through results: JSON output becomes `{issues, next_cursor}`
+    #[arg(long, conflicts_with = "stream")]
+    pub paginate: bool,
+
+    /// Resume after a `next_cursor` from a previous page (implies --paginate)
+    #[arg(long, value_name = "CURSOR", conflicts_with = "stream")]
+    pub after: Option<String>,
+
+    /// Include issues from registered remotes (IDs shown as `remote:id`)
+    #[arg(long)]
+    pub remotes: bool,
+
     /// CSV fields to include (comma-separated)
     ///
     /// Available: id, title, description, status, priority, `issue_type`,
@@ -1410,6 +1829,19 @@ pub struct SearchArgs {
     /// Search query
     pub query: String,
 
+    /// Fields to search, comma-separated: id, title, description, design,
+    /// acceptance_criteria, notes, comments (default: title,description,id)
+    #[arg(long = "in", value_delimiter = ',')]
+    pub in_fields: Vec<String>,
+
+    /// Treat the query as a regular expression
+    #[arg(long)]
+    pub regex: bool,
+
+    /// Match case exactly (default: case-insensitive)
+    #[arg(long)]
+    pub case_sensitive: bool,
+
     #[command(flatten)]
     pub filters: ListArgs,
 }
@@ -1421,9 +1853,9 @@ pub struct ShowArgs {
     #[arg(add = ArgValueCompleter::new(issue_id_completer))]
     pub ids: Vec<String>,
 
-    /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
+    /// Output format (text, json, toon, md). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
-    pub format: Option<OutputFormatBasic>,
+    pub format: Option<ShowFormat>,
 
     /// Wrap long lines instead of truncating in text output
     #[arg(long)]
@@ -1432,6 +1864,10 @@ pub struct ShowArgs {
     /// Show token savings stats when using TOON output
     #[arg(long)]
     pub stats: bool,
+
+    /// Include the most recent history events (up to 10)
+    #[arg(long)]
+    pub events: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -1447,6 +1883,8 @@ pub enum DepCommands {
     Tree(DepTreeArgs),
     /// Detect and report dependency cycles
     Cycles(DepCyclesArgs),
+    /// Add and remove dependencies listed in a file, all or nothing
+    Apply(DepApplyArgs),
 }
 
 /// Subcommands for the epic command.
@@ -1475,6 +1913,62 @@ pub struct EpicCloseEligibleArgs {
     pub dry_run: bool,
 }
 
+/// Subcommands for the milestone command.
+#[derive(Subcommand, Debug)]
+pub enum MilestoneCommands {
+    /// Create a milestone
+    Create(MilestoneCreateArgs),
+    /// Assign issues to a milestone (replacing their current one)
+    Add(MilestoneAddArgs),
+    /// Take issues out of their milestone
+    Remove(MilestoneRemoveArgs),
+    /// List milestones with progress
+    List(MilestoneListArgs),
+}
+
+/// Arguments for the milestone create command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct MilestoneCreateArgs {
+    /// Milestone title, e.g. "Sprint 12"
+    pub title: String,
+
+    /// Due date
+    #[arg(long)]
+    pub due: Option<String>,
+
+    /// Description
+    #[arg(long, short = 'd')]
+    pub description: Option<String>,
+}
+
+/// Arguments for the milestone add command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct MilestoneAddArgs {
+    /// Milestone ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub milestone: String,
+
+    /// Issues to assign
+    #[arg(required = true, add = ArgValueCompleter::new(issue_id_completer))]
+    pub ids: Vec<String>,
+}
+
+/// Arguments for the milestone remove command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct MilestoneRemoveArgs {
+    /// Issues to take out of their milestone
+    #[arg(required = true, add = ArgValueCompleter::new(issue_id_completer))]
+    pub ids: Vec<String>,
+}
+
+/// Arguments for the milestone list command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct MilestoneListArgs {
+    /// Include closed milestones
+    #[arg(long, short = 'a')]
+    pub all: bool,
+}
+
 #[derive(Args, Debug, Default)]
 pub struct DepAddArgs {
     /// Issue ID (the one that will depend on something)
@@ -1492,6 +1986,16 @@ pub struct DepAddArgs {
     /// Optional JSON metadata
     #[arg(long)]
     pub metadata: Option<String>,
+
+    /// When a conditional-blocks dependency blocks: `label:<name>`,
+    /// `type:<type>` or `priority:<0-4>`, checked on the target
+    #[arg(long, conflicts_with = "metadata")]
+    pub condition: Option<String>,
+
+    /// When a waits-for dependency stops blocking, even if the target is
+    /// still open (RFC3339, YYYY-MM-DD, +2d, tomorrow)
+    #[arg(long, conflicts_with_all = ["metadata", "condition"])]
+    pub until: Option<String>,
 }
 
 #[derive(Args, Debug)]
@@ -1559,6 +2063,17 @@ pub struct DepCyclesArgs {
     /// Only check blocking dependency types
     #[arg(long)]
     pub blocking_only: bool,
+
+    /// Interactively choose a dependency to remove from each cycle
+    #[arg(long)]
+    pub fix: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct DepApplyArgs {
+    /// Edge list file (`-` for stdin): lines of `<issue> <type> <depends-on>`,
+    /// prefixed with `-` to remove, or JSON edges
+    pub file: PathBuf,
 }
 
 #[derive(Subcommand, Debug)]
@@ -1574,11 +2089,15 @@ pub enum LabelCommands {
     ListAll,
     /// Rename a label across all issues
     Rename(LabelRenameArgs),
+    /// Merge labels into one across all issues
+    Merge(LabelMergeArgs),
+    /// Show or set a label's color and description
+    Describe(LabelDescribeArgs),
 }
 
 #[derive(Args, Debug)]
 pub struct LabelAddArgs {
-    /// Issue ID(s) to add label to
+    /// Issue IDs or selectors (`a..b`, `id.*`, `@filter:"..."`) to add label to
     #[arg(add = ArgValueCompleter::new(issue_id_completer))]
     pub issues: Vec<String>,
 
@@ -1589,7 +2108,7 @@ pub struct LabelAddArgs {
 
 #[derive(Args, Debug)]
 pub struct LabelRemoveArgs {
-    /// Issue ID(s) to remove label from
+    /// Issue IDs or selectors (`a..b`, `id.*`, `@filter:"..."`) to remove label from
     #[arg(add = ArgValueCompleter::new(issue_id_completer))]
     pub issues: Vec<String>,
 
@@ -1615,6 +2134,36 @@ pub struct LabelRenameArgs {
     pub new_name: String,
 }
 
+#[derive(Args, Debug)]
+pub struct LabelDescribeArgs {
+    /// Label to show or define (omit to list all definitions)
+    #[arg(add = ArgValueCompleter::new(label_completer))]
+    pub label: Option<String>,
+
+    /// Color for rich output (name like `red` or hex like `#ff8800`)
+    #[arg(long = "label-color", id = "label_color", requires = "label")]
+    pub color: Option<String>,
+
+    /// Description of what the label means
+    #[arg(long, short = 'd', requires = "label")]
+    pub description: Option<String>,
+
+    /// Remove the label's definition
+    #[arg(long, requires = "label", conflicts_with_all = ["label_color", "description"])]
+    pub clear: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct LabelMergeArgs {
+    /// Labels to merge
+    #[arg(required = true, add = ArgValueCompleter::new(label_completer))]
+    pub sources: Vec<String>,
+
+    /// Label that replaces the merged labels (may be one of them)
+    #[arg(long, add = ArgValueCompleter::new(label_completer))]
+    pub into: String,
+}
+
 #[derive(Args, Debug)]
 pub struct CommentsArgs {
     #[command(subcommand)]
@@ -1633,6 +2182,10 @@ pub struct CommentsArgs {
 pub enum CommentCommands {
     Add(CommentAddArgs),
     List(CommentListArgs),
+    /// Replace the text of a comment
+    Edit(CommentEditArgs),
+    /// Delete a comment (kept as a tombstone so the deletion syncs)
+    Delete(CommentDeleteArgs),
 }
 
 #[derive(Args, Debug)]
@@ -1657,6 +2210,45 @@ pub struct CommentAddArgs {
     pub message: Option<String>,
 }
 
+#[derive(Args, Debug)]
+pub struct CommentEditArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Comment ID (shown by `br comments list`)
+    pub comment_id: i64,
+
+    /// New comment text
+    pub text: Vec<String>,
+
+    /// Read new comment text from file
+    #[arg(short = 'f', long = "file")]
+    pub file: Option<PathBuf>,
+
+    /// New comment text (alternative flag)
+    #[arg(long = "message")]
+    pub message: Option<String>,
+
+    /// Edit a comment written by someone else
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CommentDeleteArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Comment ID (shown by `br comments list`)
+    pub comment_id: i64,
+
+    /// Delete a comment written by someone else
+    #[arg(long)]
+    pub force: bool,
+}
+
 #[derive(Args, Debug)]
 pub struct CommentListArgs {
     /// Issue ID
@@ -1668,8 +2260,59 @@ pub struct CommentListArgs {
     pub wrap: bool,
 }
 
-#[derive(Subcommand, Debug)]
-pub enum AuditCommands {
+/// Arguments for the react command.
+#[derive(Args, Debug, Clone)]
+pub struct ReactArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Emoji, shortcode, or short tag (e.g. :+1:, 👀, approved)
+    pub reaction: String,
+
+    /// React to this comment instead of the issue
+    #[arg(long, value_name = "COMMENT_ID")]
+    pub comment: Option<i64>,
+
+    /// Remove your reaction instead of adding it
+    #[arg(long)]
+    pub remove: bool,
+}
+
+/// Arguments for the watch command.
+#[derive(Args, Debug, Clone)]
+pub struct WatchArgs {
+    /// Issue IDs (omit to list the issues you watch)
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub ids: Vec<String>,
+
+    /// Stop watching instead
+    #[arg(long, requires = "ids")]
+    pub remove: bool,
+}
+
+/// Arguments for the link command.
+#[derive(Args, Debug, Clone)]
+pub struct LinkArgs {
+    /// Issue ID
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Code references: `path`, `path:line`, `path@commit`, or `path:line@commit`
+    #[arg(required = true, value_name = "REF")]
+    pub refs: Vec<String>,
+
+    /// Unlink the references instead of linking them
+    #[arg(long)]
+    pub remove: bool,
+
+    /// Skip checking that the file and line exist
+    #[arg(long)]
+    pub no_verify: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AuditCommands {
     /// Append an audit interaction entry
     Record(AuditRecordArgs),
     /// Append a label entry referencing an existing interaction
@@ -1748,7 +2391,7 @@ pub struct AuditSummaryArgs {
     pub days: u32,
 }
 
-#[derive(Args, Debug, Clone)]
+#[derive(Args, Debug, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CountArgs {
     /// Group counts by field
@@ -1795,6 +2438,14 @@ pub struct CountArgs {
     #[arg(long)]
     pub unassigned: bool,
 
+    /// Filter by label (AND: must have ALL specified labels)
+    #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
+    pub label: Vec<String>,
+
+    /// Filter by label (OR: must have AT LEAST ONE of these labels)
+    #[arg(long, add = ArgValueCompleter::new(label_completer))]
+    pub label_any: Vec<String>,
+
     /// Include closed and tombstone issues
     #[arg(long)]
     pub include_closed: bool,
@@ -1817,6 +2468,16 @@ pub enum CountBy {
     Label,
 }
 
+/// Sections for `br list --group-by`.
+#[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GroupBy {
+    Status,
+    Assignee,
+    /// One section per label; issues with several labels appear in each
+    Label,
+    Type,
+}
+
 #[derive(Args, Debug, Clone)]
 pub struct StaleArgs {
     /// Minimum days since last update
@@ -1838,9 +2499,14 @@ pub struct LintArgs {
     #[arg(long, short = 't', add = ArgValueCompleter::new(issue_type_standard_completer))]
     pub type_: Option<String>,
 
-    /// Filter by status (default: open, use 'all' for all)
+    /// Filter by status (default: open, or all with --policy; use 'all' for all)
     #[arg(long, short = 's', add = ArgValueCompleter::new(status_or_all_completer))]
     pub status: Option<String>,
+
+    /// Report violations of configured required-field policies instead of
+    /// missing template sections
+    #[arg(long)]
+    pub policy: bool,
 }
 
 /// Arguments for the defer command.
@@ -1871,6 +2537,39 @@ pub struct UndeferArgs {
     pub robot: bool,
 }
 
+/// Arguments for the pin and unpin commands.
+#[derive(Args, Debug, Clone, Default)]
+pub struct PinArgs {
+    /// Issue IDs to pin or unpin
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub ids: Vec<String>,
+}
+
+/// Arguments for the gc command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct GcArgs {
+    /// Remove ephemeral issues older than this many days (default: `gc.ephemeral_ttl_days` or 7)
+    #[arg(long, value_name = "DAYS")]
+    pub ttl_days: Option<i64>,
+
+    /// Also remove closed issues that were closed more than --closed-days ago
+    #[arg(long)]
+    pub aggressive: bool,
+
+    /// Minimum days since closing for --aggressive
+    #[arg(
+        long,
+        value_name = "DAYS",
+        default_value_t = 90,
+        requires = "aggressive"
+    )]
+    pub closed_days: i64,
+
+    /// Show what would be removed without changing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 /// Arguments for the ready command.
 #[derive(Args, Debug, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)]
@@ -1903,10 +2602,18 @@ pub struct ReadyArgs {
     #[arg(long, short = 'p', add = ArgValueCompleter::new(priority_completer))]
     pub priority: Vec<String>,
 
-    /// Sort policy: hybrid (default), priority, oldest
+    /// Sort policy: hybrid (default), priority, oldest, fit, aging
     #[arg(long, default_value = "hybrid", value_enum)]
     pub sort: SortPolicy,
 
+    /// Show why each issue ranked where it did, and which issues were skipped
+    #[arg(long)]
+    pub explain: bool,
+
+    /// Only issues estimated to fit in this time (e.g. 90, 45m, 2h, 1h30m)
+    #[arg(long, value_name = "DURATION")]
+    pub within: Option<String>,
+
     /// Include deferred issues
     #[arg(long)]
     pub include_deferred: bool,
@@ -1919,13 +2626,35 @@ pub struct ReadyArgs {
     #[arg(long, short = 'r')]
     pub recursive: bool,
 
+    /// Only issues that depend on this issue
+    #[arg(long, value_name = "ID", add = ArgValueCompleter::new(issue_id_completer))]
+    pub depends_on: Option<String>,
+
+    /// Only issues that block this issue
+    #[arg(long, value_name = "ID", add = ArgValueCompleter::new(issue_id_completer))]
+    pub blocks: Option<String>,
+
+    /// Only issues with a dependency of this type (narrows --depends-on/--blocks)
+    #[arg(long, add = ArgValueCompleter::new(dep_type_completer))]
+    pub dep_type: Option<String>,
+
+    /// Include ready issues from registered remotes (IDs shown as `remote:id`)
+    #[arg(long)]
+    pub remotes: bool,
+
     /// Wrap long lines instead of truncating in text output
     #[arg(long)]
     pub wrap: bool,
 
-    /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
+    /// Output format (text, json, toon, table). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
-    pub format: Option<OutputFormatBasic>,
+    pub format: Option<ReportFormat>,
+
+    /// Table columns (comma-separated) for `--format table`
+    ///
+    /// Default: id, status, priority, assignee, age, title
+    #[arg(long, value_name = "COLS")]
+    pub columns: Option<String>,
 
     /// Show token savings stats when using TOON output
     #[arg(long)]
@@ -1936,6 +2665,62 @@ pub struct ReadyArgs {
     pub robot: bool,
 }
 
+/// Arguments for the claim command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct ClaimArgs {
+    /// Number of ready issues to claim
+    #[arg(long, short = 'n', default_value_t = 1)]
+    pub count: usize,
+
+    /// Only claim issues with this label (AND logic, can be repeated)
+    #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
+    pub label: Vec<String>,
+
+    /// Only claim issues of this type (can be repeated)
+    #[arg(long = "type", short = 't', add = ArgValueCompleter::new(issue_type_completer))]
+    pub type_: Vec<String>,
+
+    /// Only claim issues with this priority (can be repeated, 0-4 or P0-P4)
+    #[arg(long, short = 'p', add = ArgValueCompleter::new(priority_completer))]
+    pub priority: Vec<String>,
+
+    /// Only claim children of this parent issue ID
+    #[arg(long, add = ArgValueCompleter::new(issue_id_completer))]
+    pub parent: Option<String>,
+
+    /// Sort policy used to pick the top issue(s): hybrid (default), priority, oldest
+    #[arg(long, default_value = "hybrid", value_enum)]
+    pub sort: SortPolicy,
+}
+
+/// Arguments for the next command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct NextArgs {
+    /// Only pick issues with this label (AND logic, can be repeated)
+    #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
+    pub label: Vec<String>,
+
+    /// Only pick issues of this type (can be repeated)
+    #[arg(long = "type", short = 't', add = ArgValueCompleter::new(issue_type_completer))]
+    pub type_: Vec<String>,
+
+    /// Only pick issues with this priority (can be repeated, 0-4 or P0-P4)
+    #[arg(long, short = 'p', add = ArgValueCompleter::new(priority_completer))]
+    pub priority: Vec<String>,
+
+    /// Only pick children of this parent issue ID
+    #[arg(long, add = ArgValueCompleter::new(issue_id_completer))]
+    pub parent: Option<String>,
+
+    /// Sort policy used to pick the issue: hybrid (default), priority, oldest
+    #[arg(long, default_value = "hybrid", value_enum)]
+    pub sort: SortPolicy,
+
+    /// Claim the issue: assign to you and mark in progress
+    #[arg(long)]
+    pub claim: bool,
+}
+
 /// Arguments for the blocked command.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug, Clone, Default)]
@@ -1964,9 +2749,23 @@ pub struct BlockedArgs {
     #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
     pub label: Vec<String>,
 
-    /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
+    /// Filter by assignee
+    #[arg(long, add = ArgValueCompleter::new(assignee_completer))]
+    pub assignee: Option<String>,
+
+    /// Show only unassigned issues
+    #[arg(long, conflicts_with = "assignee")]
+    pub unassigned: bool,
+
+    /// Output format (text, json, toon, table). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
-    pub format: Option<OutputFormatBasic>,
+    pub format: Option<ReportFormat>,
+
+    /// Table columns (comma-separated) for `--format table`
+    ///
+    /// Default: id, status, priority, assignee, age, title
+    #[arg(long, value_name = "COLS")]
+    pub columns: Option<String>,
 
     /// Show token savings stats when using TOON output
     #[arg(long)]
@@ -1980,7 +2779,7 @@ pub struct BlockedArgs {
 /// Arguments for the close command.
 #[derive(Args, Debug, Clone, Default)]
 pub struct CloseArgs {
-    /// Issue IDs to close (uses last-touched if empty)
+    /// Issue IDs or selectors (`a..b`, `id.*`, `@filter:"..."`) to close (uses last-touched if empty)
     #[arg(add = ArgValueCompleter::new(open_issue_id_completer))]
     pub ids: Vec<String>,
 
@@ -1988,7 +2787,7 @@ pub struct CloseArgs {
     #[arg(long, short = 'r')]
     pub reason: Option<String>,
 
-    /// Close even if blocked by open dependencies
+    /// Close even if blocked by open dependencies or missing policy-required fields
     #[arg(long, short = 'f')]
     pub force: bool,
 
@@ -1996,6 +2795,10 @@ pub struct CloseArgs {
     #[arg(long)]
     pub suggest_next: bool,
 
+    /// Also close all open descendants, each with a reason naming the parent
+    #[arg(long)]
+    pub cascade: bool,
+
     /// Session ID for tracking
     #[arg(long)]
     pub session: Option<String>,
@@ -2012,7 +2815,7 @@ pub struct ReopenArgs {
     #[arg(add = ArgValueCompleter::new(closed_issue_id_completer))]
     pub ids: Vec<String>,
 
-    /// Reason for reopening (stored as a comment)
+    /// Reason for reopening (recorded on the reopened event and as a comment)
     #[arg(long, short = 'r')]
     pub reason: Option<String>,
 
@@ -2031,6 +2834,10 @@ pub enum SortPolicy {
     Priority,
     /// Sort by `created_at` ASC only
     Oldest,
+    /// Put issues that together fit the `--within` budget first (hybrid order otherwise)
+    Fit,
+    /// Boost issues by time since last update (`ready.aging.*` config) so old work isn't starved
+    Aging,
 }
 
 /// Arguments for the sync command.
@@ -2182,6 +2989,16 @@ pub struct StatsArgs {
     #[arg(long, default_value_t = 24)]
     pub activity_hours: u32,
 
+    /// Show daily burndown, throughput and cumulative flow instead of the
+    /// summary, from the event history
+    #[arg(long)]
+    pub burndown: bool,
+
+    /// Burndown window: a span back from now (30d, 4w) or a start date
+    /// (default: 30d)
+    #[arg(long, value_name = "WHEN", requires = "burndown")]
+    pub since: Option<String>,
+
     /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
     pub format: Option<OutputFormatBasic>,
@@ -2205,18 +3022,28 @@ pub struct HistoryArgs {
 pub enum HistoryCommands {
     /// List history backups
     List,
-    /// Diff backup against current JSONL
+    /// Show the issues in a snapshot
+    Show {
+        /// Snapshot timestamp (e.g. 20250101_120000) or backup filename
+        snapshot: String,
+    },
+    /// Issue-level diff between two snapshots (added/changed/removed)
     Diff {
-        /// Backup filename (e.g. issues.2025-01-01T12-00-00.jsonl)
-        file: String,
+        /// Older snapshot timestamp or backup filename
+        from: String,
+        /// Newer snapshot (default: current JSONL)
+        to: Option<String>,
     },
-    /// Restore from backup
+    /// Restore a snapshot to the JSONL and re-import it
     Restore {
-        /// Backup filename
-        file: String,
+        /// Snapshot timestamp or backup filename
+        snapshot: String,
         /// Force overwrite
         #[arg(long, short = 'f')]
         force: bool,
+        /// Show what the restore would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Prune old backups
     Prune {
@@ -2262,6 +3089,19 @@ pub struct UpgradeArgs {
     pub dry_run: bool,
 }
 
+/// Arguments for the serve command.
+#[cfg(feature = "serve")]
+#[derive(Args, Debug, Clone, Default)]
+pub struct ServeArgs {
+    /// Port on 127.0.0.1 (0 picks a free port)
+    #[arg(long, default_value_t = 7420)]
+    pub port: u16,
+
+    /// Bearer token clients must send (generated and printed if unset)
+    #[arg(long, env = "BEADS_SERVE_TOKEN", hide_env_values = true)]
+    pub token: Option<String>,
+}
+
 /// Arguments for the orphans command.
 #[derive(Args, Debug, Clone, Default)]
 pub struct OrphansArgs {
@@ -2278,6 +3118,23 @@ pub struct OrphansArgs {
     pub robot: bool,
 }
 
+/// Arguments for the diff command.
+///
+/// With no arguments the last export is compared with the working database.
+/// With one file, that file is compared with the database.
+#[derive(Args, Debug, Clone, Default)]
+pub struct DiffArgs {
+    /// Old side: a JSONL file (default: the last export)
+    pub from: Option<PathBuf>,
+
+    /// New side: a JSONL file (default: the working database)
+    pub to: Option<PathBuf>,
+
+    /// Machine-readable output (alias for --json)
+    #[arg(long)]
+    pub robot: bool,
+}
+
 /// Arguments for the changelog command.
 #[derive(Args, Debug, Clone, Default)]
 pub struct ChangelogArgs {
@@ -2298,6 +3155,148 @@ pub struct ChangelogArgs {
     pub robot: bool,
 }
 
+/// Arguments for the report command.
+#[derive(Args, Debug, Clone)]
+pub struct ReportArgs {
+    /// Include issues closed within this many days
+    #[arg(long, default_value_t = 7)]
+    pub closed_days: i64,
+
+    /// Treat open issues not updated in this many days as stale
+    #[arg(long, default_value_t = 14)]
+    pub stale_days: i64,
+
+    /// Markdown template with `{{placeholder}}` substitution (see docs)
+    #[arg(long, value_name = "PATH")]
+    pub template: Option<PathBuf>,
+
+    /// Write the report to a file instead of stdout
+    #[arg(short = 'o', long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+/// Arguments for the export command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct ExportArgs {
+    /// Output format
+    #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+    pub format: ExportFormat,
+
+    /// Columns to include (comma-separated)
+    ///
+    /// Available: id, title, description, status, priority, `issue_type`,
+    /// assignee, owner, `created_at`, `updated_at`, `closed_at`, `due_at`,
+    /// `defer_until`, notes, `external_ref`, labels, `created_by`,
+    /// `close_reason`, `estimated_minutes`
+    ///
+    /// Default: id, title, status, priority, `issue_type`, assignee, labels,
+    /// `created_at`, `updated_at`
+    #[arg(long, value_name = "COLUMNS")]
+    pub columns: Option<String>,
+
+    /// Write to a file instead of stdout
+    #[arg(short = 'o', long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    /// Omit the header row (CSV/TSV only)
+    #[arg(long)]
+    pub no_header: bool,
+
+    /// Filter by status (repeatable or comma-separated)
+    #[arg(long, value_delimiter = ',', add = ArgValueCompleter::new(status_completer_delimited))]
+    pub status: Vec<String>,
+
+    /// Filter by issue type (repeatable or comma-separated)
+    #[arg(long = "type", value_delimiter = ',', add = ArgValueCompleter::new(issue_type_completer_delimited))]
+    pub types: Vec<String>,
+
+    /// Filter by priority (0-4 or P0-P4; repeatable or comma-separated)
+    #[arg(long, value_delimiter = ',', add = ArgValueCompleter::new(priority_completer_delimited))]
+    pub priority: Vec<String>,
+
+    /// Filter by assignee
+    #[arg(long, add = ArgValueCompleter::new(assignee_completer))]
+    pub assignee: Option<String>,
+
+    /// Filter by label (AND logic, can repeat)
+    #[arg(long, short = 'l', add = ArgValueCompleter::new(label_completer))]
+    pub label: Vec<String>,
+
+    /// Include closed issues
+    #[arg(long, short = 'a')]
+    pub all: bool,
+}
+
+/// Tabular export formats.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values (RFC 4180 quoting)
+    #[default]
+    Csv,
+    /// Tab-separated values (backslash-escaped)
+    Tsv,
+    /// iCalendar events for issues with due or defer dates
+    Ics,
+    /// Markdown in the `br create --file` dialect, for bulk editing
+    Md,
+    /// Self-contained HTML dashboard with stats, a dependency graph and
+    /// sortable tables
+    Html,
+}
+
+/// Arguments for the import command.
+#[derive(Args, Debug, Clone)]
+pub struct ImportArgs {
+    /// Export format of the source tracker
+    #[arg(long, value_enum)]
+    pub from: ImportSource,
+
+    /// CSV file exported from the source tracker
+    pub file: PathBuf,
+
+    /// YAML file overriding the built-in column, status, priority, and type mapping
+    #[arg(long, value_name = "PATH")]
+    pub mapping: Option<PathBuf>,
+
+    /// Show what would be created or updated without writing
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Arguments for the apply command.
+#[derive(Args, Debug, Clone)]
+pub struct ApplyArgs {
+    /// Plan file written by `br --plan ...` (`-` for stdin)
+    pub file: PathBuf,
+
+    /// Apply even if issues changed since the plan was recorded
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Trackers whose CSV exports `br import` understands.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    /// Jira issue search CSV export
+    JiraCsv,
+    /// GitHub issues or project CSV export
+    GithubCsv,
+    /// Linear issues CSV export
+    LinearCsv,
+}
+
+impl ImportSource {
+    /// Value stored in `source_system` for issues imported from this source.
+    #[must_use]
+    pub const fn source_system(self) -> &'static str {
+        match self {
+            Self::JiraCsv => "jira",
+            Self::GithubCsv => "github",
+            Self::LinearCsv => "linear",
+        }
+    }
+}
+
 /// Subcommands for the query command.
 #[derive(Subcommand, Debug)]
 pub enum QueryCommands {
@@ -2362,6 +3361,168 @@ pub struct GraphArgs {
     pub compact: bool,
 }
 
+/// Arguments for the why-blocked command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct WhyBlockedArgs {
+    /// Issue ID to explain
+    #[arg(add = ArgValueCompleter::new(open_issue_id_completer))]
+    pub id: String,
+}
+
+/// Arguments for the critical-path command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct CriticalPathArgs {
+    /// Only consider chains ending at this issue
+    #[arg(long, add = ArgValueCompleter::new(open_issue_id_completer))]
+    pub to: Option<String>,
+}
+
+/// Arguments for the impact command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct ImpactArgs {
+    /// Issue ID to analyze
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Maximum number of dependency hops to follow (default: 50)
+    #[arg(long)]
+    pub depth: Option<usize>,
+}
+
+/// Arguments for the forecast command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct ForecastArgs {
+    /// Epic to forecast (default: the whole open backlog)
+    #[arg(add = ArgValueCompleter::new(open_issue_id_completer))]
+    pub epic: Option<String>,
+
+    /// Days of close history to sample throughput from
+    #[arg(long, default_value_t = 90)]
+    pub history_days: u32,
+
+    /// Number of simulated futures
+    #[arg(long, default_value_t = 1000)]
+    pub runs: u32,
+
+    /// Seed for a reproducible simulation
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+/// Arguments for the activity command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct ActivityArgs {
+    /// Only activity since this time: a span back from now (24h, 7d) or a
+    /// date
+    #[arg(long, value_name = "WHEN")]
+    pub since: Option<String>,
+
+    /// Only these event types, e.g. created, closed, commented (repeatable or
+    /// comma-separated)
+    #[arg(long = "type", value_delimiter = ',')]
+    pub types: Vec<String>,
+
+    /// Only events from this session ID, or `last` for the most recent one
+    #[arg(long)]
+    pub session: Option<String>,
+
+    /// Only events and comments by this actor
+    #[arg(long, value_name = "NAME")]
+    pub actor: Option<String>,
+
+    /// Maximum entries to show (0 = no limit)
+    #[arg(short = 'n', long, default_value_t = 50)]
+    pub limit: usize,
+
+    /// Keep running and print new events as they are recorded
+    #[arg(short = 'f', long)]
+    pub follow: bool,
+}
+
+/// Arguments for the inbox command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct InboxArgs {
+    /// Include items already marked read
+    #[arg(long)]
+    pub all: bool,
+
+    /// Show unread items without marking them read
+    #[arg(long)]
+    pub peek: bool,
+}
+
+/// Subcommands for the msg command.
+#[derive(Subcommand, Debug)]
+pub enum MsgCommands {
+    /// Send a message to an agent
+    Send(MsgSendArgs),
+    /// List messages sent to an agent, newest first
+    Inbox(MsgInboxArgs),
+    /// Reply to a message, in the same thread
+    Reply(MsgReplyArgs),
+}
+
+/// Arguments for the msg send command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct MsgSendArgs {
+    /// Recipient
+    #[arg(long, add = ArgValueCompleter::new(assignee_completer))]
+    pub to: String,
+
+    /// Message text; the first line becomes the title
+    pub text: String,
+}
+
+/// Arguments for the msg inbox command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct MsgInboxArgs {
+    /// Recipient (default: the current actor)
+    #[arg(long = "for", value_name = "AGENT", add = ArgValueCompleter::new(assignee_completer))]
+    pub recipient: Option<String>,
+
+    /// Include closed (handled) messages
+    #[arg(long)]
+    pub all: bool,
+}
+
+/// Arguments for the msg reply command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct MsgReplyArgs {
+    /// Message to reply to
+    #[arg(add = ArgValueCompleter::new(issue_id_completer))]
+    pub id: String,
+
+    /// Reply text; the first line becomes the title
+    pub text: String,
+}
+
+/// Subcommands for the session command.
+#[derive(Subcommand, Debug)]
+pub enum SessionCommands {
+    /// Start a session; later changes are stamped with its ID
+    Start(SessionStartArgs),
+    /// End the current session
+    End,
+    /// Summarize the changes made in a session
+    Show(SessionShowArgs),
+    /// List recorded sessions, newest first
+    List,
+}
+
+/// Arguments for the session start command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct SessionStartArgs {
+    /// Session ID (default: generated from the current time)
+    pub id: Option<String>,
+}
+
+/// Arguments for the session show command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct SessionShowArgs {
+    /// Session ID (default: the current session, else the most recent one)
+    pub id: Option<String>,
+}
+
 /// Arguments for the agents command.
 #[derive(Args, Debug, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)]
@@ -2390,3 +3551,32 @@ pub struct AgentsArgs {
     #[arg(long, short = 'f')]
     pub force: bool,
 }
+
+/// Subcommands for the remote command.
+#[derive(Subcommand, Debug)]
+pub enum RemoteCommands {
+    /// Register another beads workspace
+    Add(RemoteAddArgs),
+    /// Unregister a remote workspace
+    #[command(visible_alias = "rm")]
+    Remove(RemoteRemoveArgs),
+    /// List registered remotes
+    List,
+}
+
+/// Arguments for the remote add command.
+#[derive(Args, Debug, Clone)]
+pub struct RemoteAddArgs {
+    /// Remote name used to qualify IDs (e.g. `other` for `other:bd-abc12`)
+    pub name: String,
+
+    /// Path to the remote project root or its .beads directory
+    pub path: String,
+}
+
+/// Arguments for the remote remove command.
+#[derive(Args, Debug, Clone)]
+pub struct RemoteRemoveArgs {
+    /// Remote name
+    pub name: String,
+}
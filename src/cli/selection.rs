@@ -0,0 +1,260 @@
+//! Bulk issue selection for mutating commands.
+//!
+//! `close`, `update`, `label add/remove` and `delete` accept selectors
+//! alongside plain (possibly partial) IDs:
+//!
+//! | Selector | Selects |
+//! |----------|---------|
+//! | `bd-a1..bd-a9` | Every issue whose ID sorts between the two endpoints, inclusive |
+//! | `bd-abc12.*` | Every hierarchical descendant of `bd-abc12` |
+//! | `@filter:"status=open label=tmp"` | Every issue matching the filter |
+//!
+//! Range endpoints and glob parents are resolved like any other ID, so partial
+//! IDs work there too. IDs sort by prefix, hash, then child path numerically
+//! (`bd-a.2` before `bd-a.10`). Tombstones are never selected by a range,
+//! glob or filter.
+
+use crate::error::{BeadsError, Result};
+use crate::model::{IssueType, Priority, Status};
+use crate::storage::{ListFilters, Storage};
+use crate::util::id::{IdResolver, parse_id};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// Separator between the two endpoints of a range selector.
+const RANGE_SEPARATOR: &str = "..";
+/// Suffix that selects every descendant of an issue.
+const CHILDREN_SUFFIX: &str = ".*";
+/// Prefix of a filter selector.
+const FILTER_PREFIX: &str = "@filter:";
+
+/// One parsed command-line selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selector<'a> {
+    Id(&'a str),
+    Range(&'a str, &'a str),
+    Children(&'a str),
+    Filter(&'a str),
+}
+
+impl<'a> Selector<'a> {
+    fn parse(input: &'a str) -> Self {
+        let input = input.trim();
+        if let Some(filter) = input.strip_prefix(FILTER_PREFIX) {
+            return Self::Filter(filter.trim().trim_matches(|c| c == '"' || c == '\''));
+        }
+        if let Some(parent) = input.strip_suffix(CHILDREN_SUFFIX) {
+            return Self::Children(parent);
+        }
+        if let Some((start, end)) = input.split_once(RANGE_SEPARATOR) {
+            return Self::Range(start, end);
+        }
+        Self::Id(input)
+    }
+}
+
+/// Resolve command-line inputs to issue IDs, expanding selectors.
+///
+/// The result keeps the order of `inputs` (selector matches in ID order) and
+/// lists each issue once.
+///
+/// # Errors
+///
+/// Returns an error if an ID or endpoint cannot be resolved, a filter is
+/// invalid, or a selector matches no issues.
+pub fn resolve_selection(
+    storage: &dyn Storage,
+    resolver: &IdResolver,
+    inputs: &[String],
+) -> Result<Vec<String>> {
+    let resolve = |input: &str| {
+        resolver
+            .resolve(
+                input,
+                |id| storage.id_exists(id).unwrap_or(false),
+                |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+            )
+            .map(|resolved| resolved.id)
+    };
+    let mut live_ids: Option<Vec<String>> = None;
+    let mut live = || -> Result<Vec<String>> {
+        if live_ids.is_none() {
+            live_ids = Some(live_issue_ids(storage)?);
+        }
+        Ok(live_ids.clone().unwrap_or_default())
+    };
+
+    let mut seen = HashSet::new();
+    let mut selected = Vec::new();
+    for input in inputs {
+        let matches = match Selector::parse(input) {
+            Selector::Id(id) => vec![resolve(id)?],
+            Selector::Range(start, end) => {
+                let (start, end) = (resolve(start)?, resolve(end)?);
+                live()?
+                    .into_iter()
+                    .filter(|id| {
+                        compare_ids(id, &start) != Ordering::Less
+                            && compare_ids(id, &end) != Ordering::Greater
+                    })
+                    .collect()
+            }
+            Selector::Children(parent) => {
+                let prefix = format!("{}.", resolve(parent)?);
+                live()?
+                    .into_iter()
+                    .filter(|id| id.starts_with(&prefix))
+                    .collect()
+            }
+            Selector::Filter(spec) => {
+                let mut ids: Vec<String> = storage
+                    .list_issues(&parse_filter(spec)?)?
+                    .into_iter()
+                    .map(|issue| issue.id)
+                    .collect();
+                ids.sort_by(|a, b| compare_ids(a, b));
+                ids
+            }
+        };
+        if matches.is_empty() {
+            return Err(BeadsError::validation(
+                "ids",
+                format!("'{input}' matched no issues"),
+            ));
+        }
+        selected.extend(matches.into_iter().filter(|id| seen.insert(id.clone())));
+    }
+    Ok(selected)
+}
+
+/// IDs of every issue that is not a tombstone, in [`compare_ids`] order.
+fn live_issue_ids(storage: &dyn Storage) -> Result<Vec<String>> {
+    let filters = ListFilters {
+        include_closed: true,
+        include_deferred: true,
+        include_templates: true,
+        ..ListFilters::default()
+    };
+    let mut ids: Vec<String> = storage
+        .list_issues(&filters)?
+        .into_iter()
+        .filter(|issue| issue.status != Status::Tombstone)
+        .map(|issue| issue.id)
+        .collect();
+    ids.sort_by(|a, b| compare_ids(a, b));
+    Ok(ids)
+}
+
+/// Order IDs by prefix, hash, then child path compared numerically.
+fn compare_ids(a: &str, b: &str) -> Ordering {
+    match (parse_id(a), parse_id(b)) {
+        (Ok(a), Ok(b)) => (a.prefix, a.hash, a.child_path).cmp(&(b.prefix, b.hash, b.child_path)),
+        _ => a.cmp(b),
+    }
+}
+
+/// Parse `key=value` pairs separated by whitespace into list filters.
+///
+/// Keys: `status`, `type`, `priority` and `label` take comma-separated values
+/// (an issue must carry every listed label); `assignee` and `title`
+/// (substring) take one. Without `status`, closed and deferred issues are
+/// excluded as in `br list`.
+pub(crate) fn parse_filter(spec: &str) -> Result<ListFilters> {
+    let mut filters = ListFilters::default();
+    if spec.trim().is_empty() {
+        return Err(BeadsError::validation("filter", "filter is empty"));
+    }
+    for term in spec.split_whitespace() {
+        let Some((key, value)) = term.split_once('=') else {
+            return Err(BeadsError::validation(
+                "filter",
+                format!("expected key=value, got '{term}'"),
+            ));
+        };
+        let values = || value.split(',').map(str::trim).filter(|v| !v.is_empty());
+        match key {
+            "status" => {
+                filters.statuses = Some(values().map(Status::from_str).collect::<Result<_>>()?);
+                filters.include_closed = true;
+                filters.include_deferred = true;
+            }
+            "type" => {
+                filters.types = Some(values().map(IssueType::from_str).collect::<Result<_>>()?);
+            }
+            "priority" => {
+                filters.priorities = Some(values().map(Priority::from_str).collect::<Result<_>>()?);
+            }
+            "label" => {
+                filters.labels = Some(values().map(str::to_string).collect());
+            }
+            "assignee" => filters.assignee = Some(value.to_string()),
+            "title" => filters.title_contains = Some(value.to_string()),
+            other => {
+                return Err(BeadsError::validation(
+                    "filter",
+                    format!(
+                        "unknown key '{other}' (expected status, type, priority, label, assignee or title)"
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(filters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Issue;
+    use crate::storage::SqliteStorage;
+    use crate::util::id::ResolverConfig;
+
+    fn storage_with(ids: &[&str]) -> SqliteStorage {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        for id in ids {
+            let issue = Issue {
+                id: (*id).to_string(),
+                title: format!("Issue {id}"),
+                ..Issue::default()
+            };
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+    }
+
+    #[test]
+    fn test_selection_expands_ranges_globs_and_filters() {
+        let mut storage = storage_with(&[
+            "bd-a1", "bd-a2", "bd-a9", "bd-b1", "bd-a1.1", "bd-a1.2", "bd-a1.10",
+        ]);
+        storage.add_label("bd-b1", "tmp", "tester").unwrap();
+        let resolver = IdResolver::new(ResolverConfig::with_prefix("bd"));
+        let select = |inputs: &[&str]| {
+            let inputs: Vec<String> = inputs.iter().map(|s| (*s).to_string()).collect();
+            resolve_selection(&storage, &resolver, &inputs)
+        };
+
+        assert_eq!(
+            select(&["bd-a1.*"]).unwrap(),
+            vec!["bd-a1.1", "bd-a1.2", "bd-a1.10"]
+        );
+        assert_eq!(
+            select(&["bd-a1.2..bd-a2"]).unwrap(),
+            vec!["bd-a1.2", "bd-a1.10", "bd-a2"]
+        );
+        assert_eq!(
+            select(&["@filter:\"label=tmp status=open\""]).unwrap(),
+            vec!["bd-b1"]
+        );
+        // Plain IDs keep their place and duplicates collapse
+        assert_eq!(
+            select(&["bd-b1", "bd-a9..bd-b1"]).unwrap(),
+            vec!["bd-b1", "bd-a9"]
+        );
+
+        assert!(select(&["bd-a9.*"]).is_err());
+        assert!(select(&["@filter:color=red"]).is_err());
+        assert!(select(&["@filter:status"]).is_err());
+    }
+}
@@ -0,0 +1,340 @@
+//! Custom fields: typed, config-declared values on issues.
+//!
+//! Fields are declared under `fields:` in `.beads/config.yaml`, each with a
+//! type:
+//!
+//! ```yaml
+//! fields:
+//!   sprint: int
+//!   review_on: date
+//!   team: enum(core, web, infra)
+//!   owner_note: string
+//!   release:
+//!     type: enum
+//!     values: [1.0, 2.0]
+//! ```
+//!
+//! Values are stored as text in their canonical form (`12`, `2026-05-01`,
+//! the enum value as declared), so they compare the same way everywhere:
+//! integers numerically and dates as text, which sorts chronologically.
+
+use super::ConfigLayer;
+use crate::error::{BeadsError, Result};
+use crate::storage::{FieldFilter, FieldOp};
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Config key prefix of field declarations.
+const FIELDS_PREFIX: &str = "fields.";
+
+/// The type of a custom field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldType {
+    String,
+    Int,
+    Date,
+    Enum(Vec<String>),
+}
+
+impl FieldType {
+    /// Parse a type name: `string`, `int`, `date`, or `enum(a, b, ...)`.
+    fn parse(spec: &str, values: Option<&str>) -> Option<Self> {
+        let spec = spec.trim();
+        let list = |values: &str| -> Vec<String> {
+            values
+                .split(',')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+        match spec.to_lowercase().as_str() {
+            "string" | "text" => Some(Self::String),
+            "int" | "integer" => Some(Self::Int),
+            "date" => Some(Self::Date),
+            "enum" => Some(Self::Enum(list(values?))).filter(Self::has_values),
+            lower if lower.starts_with("enum(") => spec[5..]
+                .strip_suffix(')')
+                .map(|inner| Self::Enum(list(inner)))
+                .filter(Self::has_values),
+            _ => None,
+        }
+    }
+
+    fn has_values(&self) -> bool {
+        !matches!(self, Self::Enum(values) if values.is_empty())
+    }
+
+    /// Whether `<`, `<=`, `>` and `>=` are meaningful for this type.
+    #[must_use]
+    pub const fn is_ordered(&self) -> bool {
+        matches!(self, Self::Int | Self::Date)
+    }
+
+    /// Check a value against the type and return its canonical form.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of what was expected if the value does not fit.
+    pub fn normalize(&self, value: &str) -> std::result::Result<String, String> {
+        let value = value.trim();
+        match self {
+            Self::String => Ok(value.to_string()),
+            Self::Int => value
+                .parse::<i64>()
+                .map(|number| number.to_string())
+                .map_err(|_| "expected an integer".to_string()),
+            Self::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .map_err(|_| "expected a date (YYYY-MM-DD)".to_string()),
+            Self::Enum(values) => values
+                .iter()
+                .find(|allowed| allowed.eq_ignore_ascii_case(value))
+                .cloned()
+                .ok_or_else(|| format!("expected one of: {}", values.join(", "))),
+        }
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String => write!(f, "string"),
+            Self::Int => write!(f, "int"),
+            Self::Date => write!(f, "date"),
+            Self::Enum(values) => write!(f, "enum({})", values.join(", ")),
+        }
+    }
+}
+
+/// Declared custom fields from the `fields.*` keys, by name.
+///
+/// # Errors
+///
+/// Returns an error if a field name or type is invalid.
+pub fn custom_fields_from_layer(layer: &ConfigLayer) -> Result<BTreeMap<String, FieldType>> {
+    // `fields.<name>: <type>`, or `fields.<name>.type` plus `fields.<name>.values`
+    let mut specs: BTreeMap<&str, (Option<&str>, Option<&str>)> = BTreeMap::new();
+    for (key, value) in &layer.startup {
+        let Some(rest) = key.trim().strip_prefix(FIELDS_PREFIX) else {
+            continue;
+        };
+        let (name, part) = rest.split_once('.').unwrap_or((rest, "type"));
+        let spec = specs.entry(name).or_default();
+        match part {
+            "type" => spec.0 = Some(value),
+            "values" => spec.1 = Some(value),
+            _ => {
+                return Err(BeadsError::Config(format!(
+                    "fields.{name}: unknown setting '{part}' (expected type or values)"
+                )));
+            }
+        }
+    }
+
+    let mut fields = BTreeMap::new();
+    for (name, (spec, values)) in specs {
+        validate_field_name(name).map_err(BeadsError::Config)?;
+        let field_type = spec
+            .and_then(|spec| FieldType::parse(spec, values))
+            .ok_or_else(|| {
+                BeadsError::Config(format!(
+                    "fields.{name} must be string, int, date or enum(a, b, ...), got '{}'",
+                    spec.unwrap_or_default()
+                ))
+            })?;
+        fields.insert(name.to_string(), field_type);
+    }
+    Ok(fields)
+}
+
+fn validate_field_name(name: &str) -> std::result::Result<(), String> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+    {
+        Ok(())
+    } else {
+        Err(format!(
+            "field name '{name}' may only contain letters, digits, '_' and '-'"
+        ))
+    }
+}
+
+/// Look up a declared field, with a hint when it is not declared.
+fn declared<'a>(
+    fields: &'a BTreeMap<String, FieldType>,
+    name: &str,
+    flag: &str,
+) -> Result<&'a FieldType> {
+    fields.get(name).ok_or_else(|| {
+        let known = if fields.is_empty() {
+            "no fields are declared".to_string()
+        } else {
+            format!(
+                "declared: {}",
+                fields.keys().cloned().collect::<Vec<_>>().join(", ")
+            )
+        };
+        BeadsError::validation(
+            flag,
+            format!(
+                "unknown field '{name}' ({known}; declare fields under `fields:` in config.yaml)"
+            ),
+        )
+    })
+}
+
+/// Parse `name=value` for `br update --field`; an empty value clears the field.
+///
+/// # Errors
+///
+/// Returns an error if the field is not declared or the value does not fit
+/// its type.
+pub fn parse_assignment(
+    expr: &str,
+    fields: &BTreeMap<String, FieldType>,
+) -> Result<(String, Option<String>)> {
+    let (name, value) = expr.split_once('=').ok_or_else(|| {
+        BeadsError::validation("field", format!("expected NAME=VALUE, got '{expr}'"))
+    })?;
+    let name = name.trim();
+    let field_type = declared(fields, name, "field")?;
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok((name.to_string(), None));
+    }
+    let value = field_type.normalize(value).map_err(|reason| {
+        BeadsError::validation("field", format!("{name} ({field_type}): {reason}"))
+    })?;
+    Ok((name.to_string(), Some(value)))
+}
+
+/// Check a `--where` filter against the declared fields and return it with
+/// its value in canonical form.
+///
+/// # Errors
+///
+/// Returns an error if the filter does not parse, the field is not declared,
+/// the value does not fit the type, or the comparison needs an ordered type.
+pub fn normalize_filter(expr: &str, fields: &BTreeMap<String, FieldType>) -> Result<String> {
+    let mut filter = FieldFilter::parse(expr)?;
+    let field_type = declared(fields, &filter.name, "where")?;
+    if filter.op.is_ordered() && !field_type.is_ordered() {
+        return Err(BeadsError::validation(
+            "where",
+            format!(
+                "{} is a {field_type} field; only int and date fields support {}",
+                filter.name,
+                filter.op.as_str()
+            ),
+        ));
+    }
+    if !(filter.value.is_empty() && matches!(filter.op, FieldOp::Eq | FieldOp::Ne)) {
+        filter.value = field_type.normalize(&filter.value).map_err(|reason| {
+            BeadsError::validation("where", format!("{} ({field_type}): {reason}", filter.name))
+        })?;
+    }
+    Ok(filter.to_string())
+}
+
+/// Normalize `--where` filters against the fields declared in `layer`.
+///
+/// # Errors
+///
+/// Returns an error if the field declarations or any filter are invalid.
+pub fn normalize_filters(exprs: &[String], layer: &ConfigLayer) -> Result<Vec<String>> {
+    if exprs.is_empty() {
+        return Ok(Vec::new());
+    }
+    let fields = custom_fields_from_layer(layer)?;
+    exprs
+        .iter()
+        .map(|expr| normalize_filter(expr, &fields))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(pairs: &[(&str, &str)]) -> ConfigLayer {
+        let mut layer = ConfigLayer::default();
+        for (key, value) in pairs {
+            layer
+                .startup
+                .insert((*key).to_string(), (*value).to_string());
+        }
+        layer
+    }
+
+    #[test]
+    fn test_custom_fields_from_layer() {
+        let fields = custom_fields_from_layer(&layer(&[
+            ("fields.sprint", "int"),
+            ("fields.review_on", "date"),
+            ("fields.team", "Enum(core, Web)"),
+            ("fields.release.type", "enum"),
+            ("fields.release.values", "1.0,2.0"),
+        ]))
+        .unwrap();
+        assert_eq!(fields["sprint"], FieldType::Int);
+        assert_eq!(fields["review_on"], FieldType::Date);
+        assert_eq!(
+            fields["team"],
+            FieldType::Enum(vec!["core".to_string(), "Web".to_string()])
+        );
+        assert_eq!(fields["release"].to_string(), "enum(1.0, 2.0)");
+
+        assert!(custom_fields_from_layer(&layer(&[("fields.sprint", "number")])).is_err());
+        assert!(custom_fields_from_layer(&layer(&[("fields.team", "enum()")])).is_err());
+        assert!(custom_fields_from_layer(&layer(&[("fields.a b", "int")])).is_err());
+    }
+
+    #[test]
+    fn test_parse_assignment_validates_type() {
+        let fields = custom_fields_from_layer(&layer(&[
+            ("fields.sprint", "int"),
+            ("fields.team", "enum(core, web)"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            parse_assignment("sprint= 012", &fields).unwrap(),
+            ("sprint".to_string(), Some("12".to_string()))
+        );
+        assert_eq!(
+            parse_assignment("team=Core", &fields).unwrap().1.as_deref(),
+            Some("core")
+        );
+        assert_eq!(parse_assignment("team=", &fields).unwrap().1, None);
+        assert!(parse_assignment("sprint=twelve", &fields).is_err());
+        assert!(parse_assignment("team=mobile", &fields).is_err());
+        assert!(parse_assignment("points=3", &fields).is_err());
+        assert!(parse_assignment("sprint", &fields).is_err());
+    }
+
+    #[test]
+    fn test_normalize_filter() {
+        let fields = custom_fields_from_layer(&layer(&[
+            ("fields.sprint", "int"),
+            ("fields.due", "date"),
+            ("fields.team", "enum(core, web)"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            normalize_filter("sprint >= 07", &fields).unwrap(),
+            "sprint>=7"
+        );
+        assert_eq!(
+            normalize_filter("due<2026-05-01", &fields).unwrap(),
+            "due<2026-05-01"
+        );
+        assert_eq!(normalize_filter("team!=WEB", &fields).unwrap(), "team!=web");
+        assert_eq!(normalize_filter("team=", &fields).unwrap(), "team=");
+        assert!(normalize_filter("team>core", &fields).is_err());
+        assert!(normalize_filter("due>soon", &fields).is_err());
+        assert!(normalize_filter("unknown=1", &fields).is_err());
+    }
+}
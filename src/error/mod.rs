@@ -15,7 +15,7 @@ mod context;
 mod structured;
 
 pub use context::{OptionExt, ResultExt};
-pub use structured::{ErrorCode, StructuredError};
+pub use structured::{ErrorCode, StructuredError, exit_code};
 
 use std::path::PathBuf;
 use thiserror::Error;
@@ -35,6 +35,18 @@ pub enum BeadsError {
     #[error("Database is locked: {path}")]
     DatabaseLocked { path: PathBuf },
 
+    /// Another `br` run holds the workspace or JSONL lock.
+    #[error("Locked by another br process{}: {path}", describe_lock_holder(.pid.as_ref(), .actor.as_deref()))]
+    Locked {
+        path: PathBuf,
+        pid: Option<u32>,
+        actor: Option<String>,
+    },
+
+    /// A write was attempted in read-only mode (`--read-only`).
+    #[error("Read-only mode: {operation} was not written")]
+    ReadOnly { operation: String },
+
     /// Database schema version doesn't match expected.
     #[error("Schema version mismatch: expected {expected}, found {found}")]
     SchemaMismatch { expected: i32, found: i32 },
@@ -48,6 +60,10 @@ pub enum BeadsError {
     #[error("Issue not found: {id}")]
     IssueNotFound { id: String },
 
+    /// Comment with the specified ID was not found on the issue.
+    #[error("Comment {comment_id} not found on {issue_id}")]
+    CommentNotFound { issue_id: String, comment_id: i64 },
+
     /// Attempted to create an issue with an ID that already exists.
     #[error("Issue ID collision: {id}")]
     IdCollision { id: String },
@@ -63,6 +79,16 @@ pub enum BeadsError {
     #[error("Invalid issue ID format: {id}")]
     InvalidId { id: String },
 
+    /// A new issue looks like one or more existing open issues.
+    #[error(
+        "Possible duplicate of {}",
+        .candidates.iter().map(|c| c.id.as_str()).collect::<Vec<_>>().join(", ")
+    )]
+    PossibleDuplicate {
+        title: String,
+        candidates: Vec<crate::util::duplicates::DuplicateCandidate>,
+    },
+
     // === Validation Errors ===
     /// Field validation failed.
     #[error("Validation failed: {field}: {reason}")]
@@ -163,6 +189,16 @@ pub enum BeadsError {
     Other(#[from] anyhow::Error),
 }
 
+/// ` (pid 123, actor alice)` for a lock holder, empty when unknown.
+fn describe_lock_holder(pid: Option<&u32>, actor: Option<&str>) -> String {
+    match (pid, actor) {
+        (Some(pid), Some(actor)) => format!(" (pid {pid}, actor {actor})"),
+        (Some(pid), None) => format!(" (pid {pid})"),
+        (None, Some(actor)) => format!(" (actor {actor})"),
+        (None, None) => String::new(),
+    }
+}
+
 /// A single field validation error.
 #[derive(Debug, Clone)]
 pub struct ValidationError {
@@ -200,6 +236,7 @@ impl BeadsError {
             Self::DatabaseNotFound { .. }
                 | Self::NotInitialized
                 | Self::IssueNotFound { .. }
+                | Self::CommentNotFound { .. }
                 | Self::Validation { .. }
                 | Self::InvalidStatus { .. }
                 | Self::InvalidType { .. }
@@ -226,7 +263,15 @@ impl BeadsError {
         match self {
             Self::NotInitialized => Some("Run: br init"),
             Self::DatabaseNotFound { .. } => Some("Check path or run: br init"),
+            Self::SchemaMismatch { .. } => {
+                Some("The database was written by a newer br; run: br upgrade")
+            }
+            Self::ReadOnly { .. } => Some("Run without --read-only (or BEADS_READONLY) to write"),
+            Self::Locked { .. } => Some("Retry, or raise --lock-timeout"),
             Self::AmbiguousId { .. } => Some("Provide more characters of the ID"),
+            Self::PossibleDuplicate { .. } => {
+                Some("Update the existing issue, or use --allow-duplicate to create anyway")
+            }
             Self::HasDependents { .. } => Some("Use --force or --cascade to delete anyway"),
             Self::ImportCollision { .. } => Some("Use --force to overwrite or resolve manually"),
             Self::DependencyCycle { .. } => Some("Remove one dependency to break the cycle"),
@@ -243,16 +288,13 @@ impl BeadsError {
         }
     }
 
-    /// Get the exit code for this error.
+    /// Get the process exit code for this error.
     ///
-    /// Legacy bd typically uses exit code 1 for most errors.
-    /// `NothingToDo` uses exit code 3 (issue errors category).
+    /// The code is the category of the error's [`ErrorCode`]; see
+    /// [`exit_code`] for the contract.
     #[must_use]
-    pub const fn exit_code(&self) -> i32 {
-        match self {
-            Self::NothingToDo { .. } => 3,
-            _ => 1,
-        }
+    pub fn exit_code(&self) -> i32 {
+        ErrorCode::from_error(self).exit_code()
     }
 
     /// Create a validation error for a specific field.
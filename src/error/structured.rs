@@ -24,19 +24,50 @@ use serde_json::{Value, json};
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
+/// Process exit codes, one per error category.
+///
+/// Scripts and agents branch on these instead of parsing messages, so a value
+/// never changes meaning. Every [`ErrorCode`] maps to exactly one of them via
+/// [`ErrorCode::exit_code`]; `docs/agent/ERRORS.md` lists the full table.
+pub mod exit_code {
+    /// Success.
+    pub const SUCCESS: i32 = 0;
+    /// Internal or unexpected error.
+    pub const INTERNAL: i32 = 1;
+    /// Invalid input: bad field values or command-line usage errors.
+    pub const VALIDATION: i32 = 2;
+    /// Issue or dependency target not found; nothing to do.
+    pub const NOT_FOUND: i32 = 3;
+    /// Conflict with existing state: ambiguous or colliding IDs, cycles,
+    /// duplicates, dependents.
+    pub const CONFLICT: i32 = 4;
+    /// Workspace not initialized.
+    pub const NOT_INITIALIZED: i32 = 5;
+    /// Database errors (locked, schema mismatch, read-only).
+    pub const DATABASE: i32 = 6;
+    /// Sync and JSONL errors.
+    pub const SYNC: i32 = 7;
+    /// Configuration errors.
+    pub const CONFIG: i32 = 8;
+    /// I/O and serialization errors.
+    pub const IO: i32 = 9;
+}
+
 /// Machine-readable error codes.
 ///
 /// These codes are stable and can be used for programmatic error handling.
 /// Format: `SCREAMING_SNAKE_CASE` for easy parsing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ErrorCode {
-    // === Database Errors (exit code 2) ===
+    // === Database Errors (exit code 6; not initialized: 5; already initialized: 4) ===
     /// Database file not found
     DatabaseNotFound,
     /// Database is locked by another process
     DatabaseLocked,
     /// Database schema version mismatch
     SchemaMismatch,
+    /// Write attempted in read-only mode
+    ReadOnly,
     /// Database operation failed
     DatabaseError,
     /// Beads workspace not initialized
@@ -44,17 +75,21 @@ pub enum ErrorCode {
     /// Already initialized
     AlreadyInitialized,
 
-    // === Issue Errors (exit code 3) ===
+    // === Issue Errors (not found: 3; conflict: 4; invalid: 2) ===
     /// Issue with specified ID not found
     IssueNotFound,
+    /// Comment ID not found on the issue
+    CommentNotFound,
     /// Partial ID matches multiple issues
     AmbiguousId,
     /// Issue ID collision on create
     IdCollision,
     /// Invalid issue ID format
     InvalidId,
+    /// New issue looks like an existing one
+    PossibleDuplicate,
 
-    // === Validation Errors (exit code 4) ===
+    // === Validation Errors (exit code 2) ===
     /// Field validation failed
     ValidationFailed,
     /// Invalid status value
@@ -66,7 +101,7 @@ pub enum ErrorCode {
     /// Required field missing
     RequiredField,
 
-    // === Dependency Errors (exit code 5) ===
+    // === Dependency Errors (not found: 3; invalid: 2; conflict: 4) ===
     /// Dependency cycle detected
     CycleDetected,
     /// Dependency target not found
@@ -78,7 +113,7 @@ pub enum ErrorCode {
     /// Duplicate dependency
     DuplicateDependency,
 
-    // === Sync/JSONL Errors (exit code 6) ===
+    // === Sync/JSONL Errors (exit code 7) ===
     /// JSONL parse error
     JsonlParseError,
     /// Prefix mismatch during import
@@ -90,7 +125,7 @@ pub enum ErrorCode {
     /// Path traversal attempt blocked
     PathTraversal,
 
-    // === Config Errors (exit code 7) ===
+    // === Config Errors (exit code 8) ===
     /// Configuration error
     ConfigError,
     /// Config file not found
@@ -98,7 +133,7 @@ pub enum ErrorCode {
     /// Config parse error
     ConfigParseError,
 
-    // === I/O Errors (exit code 8) ===
+    // === I/O Errors (exit code 9) ===
     /// File I/O error
     IoError,
     /// JSON serialization error
@@ -124,14 +159,17 @@ impl ErrorCode {
             Self::DatabaseNotFound => "DATABASE_NOT_FOUND",
             Self::DatabaseLocked => "DATABASE_LOCKED",
             Self::SchemaMismatch => "SCHEMA_MISMATCH",
+            Self::ReadOnly => "READ_ONLY",
             Self::DatabaseError => "DATABASE_ERROR",
             Self::NotInitialized => "NOT_INITIALIZED",
             Self::AlreadyInitialized => "ALREADY_INITIALIZED",
             // Issue
             Self::IssueNotFound => "ISSUE_NOT_FOUND",
+            Self::CommentNotFound => "COMMENT_NOT_FOUND",
             Self::AmbiguousId => "AMBIGUOUS_ID",
             Self::IdCollision => "ID_COLLISION",
             Self::InvalidId => "INVALID_ID",
+            Self::PossibleDuplicate => "POSSIBLE_DUPLICATE",
             // Validation
             Self::ValidationFailed => "VALIDATION_FAILED",
             Self::InvalidStatus => "INVALID_STATUS",
@@ -184,59 +222,48 @@ impl ErrorCode {
         )
     }
 
-    /// Get the exit code for this error category.
-    ///
-    /// Exit codes are grouped by error category:
-    /// - 1: Internal/unknown errors
-    /// - 2: Database errors
-    /// - 3: Issue errors
-    /// - 4: Validation errors
-    /// - 5: Dependency errors
-    /// - 6: Sync/JSONL errors
-    /// - 7: Config errors
-    /// - 8: I/O errors
+    /// Get the exit code for this error category (see [`exit_code`]).
     #[must_use]
     pub const fn exit_code(&self) -> i32 {
         match self {
-            // Database (2)
-            Self::DatabaseNotFound
-            | Self::DatabaseLocked
-            | Self::SchemaMismatch
-            | Self::DatabaseError
-            | Self::NotInitialized
-            | Self::AlreadyInitialized => 2,
-            // Issue / Operational (3)
-            Self::IssueNotFound
-            | Self::AmbiguousId
-            | Self::IdCollision
-            | Self::InvalidId
-            | Self::NothingToDo => 3,
-            // Validation (4)
             Self::ValidationFailed
             | Self::InvalidStatus
             | Self::InvalidType
             | Self::InvalidPriority
-            | Self::RequiredField => 4,
-            // Dependency (5)
-            Self::CycleDetected
+            | Self::RequiredField
+            | Self::InvalidId
+            | Self::SelfDependency => exit_code::VALIDATION,
+            Self::IssueNotFound
+            | Self::CommentNotFound
             | Self::DependencyNotFound
+            | Self::NothingToDo => exit_code::NOT_FOUND,
+            Self::AmbiguousId
+            | Self::IdCollision
+            | Self::PossibleDuplicate
+            | Self::AlreadyInitialized
+            | Self::CycleDetected
             | Self::HasDependents
-            | Self::SelfDependency
-            | Self::DuplicateDependency => 5,
-            // Sync (6)
+            | Self::DuplicateDependency => exit_code::CONFLICT,
+            Self::NotInitialized | Self::DatabaseNotFound => exit_code::NOT_INITIALIZED,
+            Self::DatabaseLocked | Self::SchemaMismatch | Self::ReadOnly | Self::DatabaseError => {
+                exit_code::DATABASE
+            }
             Self::JsonlParseError
             | Self::PrefixMismatch
             | Self::ImportCollision
             | Self::ConflictMarkers
-            | Self::PathTraversal => 6,
-            // Config (7)
-            Self::ConfigError | Self::ConfigNotFound | Self::ConfigParseError => 7,
-            // I/O (8)
-            Self::IoError | Self::JsonError | Self::YamlError => 8,
-            // Internal (1)
-            Self::InternalError => 1,
+            | Self::PathTraversal => exit_code::SYNC,
+            Self::ConfigError | Self::ConfigNotFound | Self::ConfigParseError => exit_code::CONFIG,
+            Self::IoError | Self::JsonError | Self::YamlError => exit_code::IO,
+            Self::InternalError => exit_code::INTERNAL,
         }
     }
+
+    /// The code for a `BeadsError`.
+    #[must_use]
+    pub fn from_error(err: &BeadsError) -> Self {
+        StructuredError::extract_code_and_context(err).0
+    }
 }
 
 /// Structured error for machine-parseable output.
@@ -445,18 +472,31 @@ impl StructuredError {
         }
     }
 
+    /// The issue ID this error is about, if any.
+    ///
+    /// Taken from the context so agents can read `error.id` without knowing
+    /// which context key each code uses.
+    #[must_use]
+    pub fn issue_id(&self) -> Option<&str> {
+        let context = self.context.as_ref()?;
+        ["searched_id", "id", "partial_id"]
+            .iter()
+            .find_map(|key| context.get(*key).and_then(Value::as_str))
+    }
+
     /// Serialize to JSON value.
     #[must_use]
     pub fn to_json(&self) -> Value {
-        json!({
-            "error": {
-                "code": self.code.as_str(),
-                "message": self.message,
-                "hint": self.hint,
-                "retryable": self.retryable,
-                "context": self.context,
-            }
-        })
+        let mut body = serde_json::Map::new();
+        body.insert("code".to_string(), json!(self.code.as_str()));
+        if let Some(id) = self.issue_id() {
+            body.insert("id".to_string(), json!(id));
+        }
+        body.insert("message".to_string(), json!(self.message));
+        body.insert("hint".to_string(), json!(self.hint));
+        body.insert("retryable".to_string(), json!(self.retryable));
+        body.insert("context".to_string(), json!(self.context));
+        json!({ "error": body })
     }
 
     /// Format for human-readable output.
@@ -499,6 +539,13 @@ impl StructuredError {
                 ErrorCode::DatabaseLocked,
                 Some(json!({"path": path.display().to_string()})),
             ),
+            BeadsError::Locked { path, pid, actor } => (
+                ErrorCode::DatabaseLocked,
+                Some(json!({"path": path.display().to_string(), "pid": pid, "actor": actor})),
+            ),
+            BeadsError::ReadOnly { operation } => {
+                (ErrorCode::ReadOnly, Some(json!({"operation": operation})))
+            }
             BeadsError::SchemaMismatch { expected, found } => (
                 ErrorCode::SchemaMismatch,
                 Some(json!({"expected": expected, "found": found})),
@@ -512,12 +559,23 @@ impl StructuredError {
             BeadsError::IssueNotFound { id } => {
                 (ErrorCode::IssueNotFound, Some(json!({"searched_id": id})))
             }
+            BeadsError::CommentNotFound {
+                issue_id,
+                comment_id,
+            } => (
+                ErrorCode::CommentNotFound,
+                Some(json!({"id": issue_id, "comment_id": comment_id})),
+            ),
             BeadsError::AmbiguousId { partial, matches } => (
                 ErrorCode::AmbiguousId,
                 Some(json!({"partial_id": partial, "matches": matches})),
             ),
             BeadsError::IdCollision { id } => (ErrorCode::IdCollision, Some(json!({"id": id}))),
             BeadsError::InvalidId { id } => (ErrorCode::InvalidId, Some(json!({"id": id}))),
+            BeadsError::PossibleDuplicate { title, candidates } => (
+                ErrorCode::PossibleDuplicate,
+                Some(json!({"title": title, "duplicates": candidates})),
+            ),
             BeadsError::Validation { field, reason } => (
                 ErrorCode::ValidationFailed,
                 Some(json!({"field": field, "reason": reason})),
@@ -604,8 +662,19 @@ impl StructuredError {
             BeadsError::Io(_) => (ErrorCode::IoError, None),
             BeadsError::Json(_) => (ErrorCode::JsonError, None),
             BeadsError::Yaml(_) => (ErrorCode::YamlError, None),
-            BeadsError::WithContext { context, .. } => {
-                (ErrorCode::InternalError, Some(json!({"context": context})))
+            BeadsError::WithContext { context, source } => {
+                // Keep the code of the wrapped error so context never hides it
+                let (code, inner) = source
+                    .downcast_ref::<BeadsError>()
+                    .map_or((ErrorCode::InternalError, None), |inner| {
+                        Self::extract_code_and_context(inner)
+                    });
+                let mut merged = match inner {
+                    Some(Value::Object(fields)) => fields,
+                    _ => serde_json::Map::new(),
+                };
+                merged.insert("context".to_string(), json!(context));
+                (code, Some(Value::Object(merged)))
             }
             BeadsError::Other(_) => (ErrorCode::InternalError, None),
         }
@@ -623,6 +692,9 @@ impl StructuredError {
             BeadsError::IssueNotFound { .. } => {
                 Some("Run 'br list' to see available issues.".to_string())
             }
+            BeadsError::CommentNotFound { issue_id, .. } => Some(format!(
+                "Run 'br comments list {issue_id}' to see its comments."
+            )),
             BeadsError::InvalidPriority { priority } => {
                 if let Some(detected) = detect_priority_intent(&priority.to_string()) {
                     Some(format!("Did you mean --priority {detected}?"))
@@ -938,14 +1010,20 @@ mod tests {
 
     #[test]
     fn test_error_code_exit_codes() {
-        assert_eq!(ErrorCode::NotInitialized.exit_code(), 2);
-        assert_eq!(ErrorCode::IssueNotFound.exit_code(), 3);
-        assert_eq!(ErrorCode::ValidationFailed.exit_code(), 4);
-        assert_eq!(ErrorCode::CycleDetected.exit_code(), 5);
-        assert_eq!(ErrorCode::JsonlParseError.exit_code(), 6);
-        assert_eq!(ErrorCode::ConfigError.exit_code(), 7);
-        assert_eq!(ErrorCode::IoError.exit_code(), 8);
         assert_eq!(ErrorCode::InternalError.exit_code(), 1);
+        assert_eq!(ErrorCode::ValidationFailed.exit_code(), 2);
+        assert_eq!(ErrorCode::InvalidPriority.exit_code(), 2);
+        assert_eq!(ErrorCode::IssueNotFound.exit_code(), 3);
+        assert_eq!(ErrorCode::CommentNotFound.exit_code(), 3);
+        assert_eq!(ErrorCode::DependencyNotFound.exit_code(), 3);
+        assert_eq!(ErrorCode::AmbiguousId.exit_code(), 4);
+        assert_eq!(ErrorCode::IdCollision.exit_code(), 4);
+        assert_eq!(ErrorCode::CycleDetected.exit_code(), 4);
+        assert_eq!(ErrorCode::NotInitialized.exit_code(), 5);
+        assert_eq!(ErrorCode::DatabaseLocked.exit_code(), 6);
+        assert_eq!(ErrorCode::JsonlParseError.exit_code(), 7);
+        assert_eq!(ErrorCode::ConfigError.exit_code(), 8);
+        assert_eq!(ErrorCode::IoError.exit_code(), 9);
     }
 
     #[test]
@@ -959,8 +1037,27 @@ mod tests {
         };
         let json = err.to_json();
         assert_eq!(json["error"]["code"], "ISSUE_NOT_FOUND");
+        assert_eq!(json["error"]["id"], "bd-abc");
         assert_eq!(json["error"]["hint"], "Did you mean 'bd-abd'?");
         assert!(!json["error"]["retryable"].as_bool().unwrap());
+
+        let json = StructuredError::not_initialized().to_json();
+        assert!(json["error"].get("id").is_none());
+    }
+
+    #[test]
+    fn test_with_context_keeps_wrapped_code() {
+        let err = BeadsError::WithContext {
+            context: "closing issue".to_string(),
+            source: Box::new(BeadsError::IssueNotFound {
+                id: "bd-xyz".to_string(),
+            }),
+        };
+        let structured = StructuredError::from_error(&err);
+        assert_eq!(structured.code, ErrorCode::IssueNotFound);
+        assert_eq!(structured.code.exit_code(), 3);
+        assert_eq!(structured.issue_id(), Some("bd-xyz"));
+        assert_eq!(structured.context.unwrap()["context"], "closing issue");
     }
 
     #[test]
@@ -148,6 +148,7 @@ mod tests {
     use super::*;
     use crate::model::{IssueType, Priority, Status};
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     fn make_test_issue(id: &str, title: &str) -> Issue {
         Issue {
@@ -190,6 +191,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -0,0 +1,441 @@
+//! Static HTML dashboard for `br export --format html`.
+//!
+//! Writes one self-contained page (inline CSS, SVG and a few lines of
+//! script, no external assets) with summary stats, bar charts by status,
+//! priority and type, a dependency graph, and sortable tables of ready,
+//! blocked and all issues. It is meant to be generated in CI and published
+//! as-is, e.g. on GitHub Pages.
+
+use crate::model::{Issue, Status};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::{self, Write};
+
+/// Graphs with more nodes than this are left out; they would be unreadable.
+pub const MAX_GRAPH_NODES: usize = 300;
+
+const NODE_WIDTH: usize = 160;
+const NODE_HEIGHT: usize = 28;
+const COLUMN_GAP: usize = 60;
+const ROW_GAP: usize = 12;
+const CHART_WIDTH: usize = 320;
+const BAR_HEIGHT: usize = 18;
+
+/// Everything the dashboard shows.
+#[derive(Debug)]
+pub struct Dashboard<'a> {
+    /// Page heading, usually the project name
+    pub title: &'a str,
+    pub generated_at: DateTime<Utc>,
+    /// Exported issues, with labels loaded
+    pub issues: &'a [Issue],
+    /// IDs of issues that are ready to work on
+    pub ready: &'a HashSet<String>,
+    /// Blocked issue IDs and what blocks them
+    pub blocked: &'a HashMap<String, Vec<String>>,
+    /// Blocking edges as (issue, depends on), both among `issues`
+    pub edges: &'a [(String, String)],
+}
+
+/// Write the dashboard page.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+pub fn write_dashboard<W: Write>(writer: &mut W, dashboard: &Dashboard<'_>) -> io::Result<()> {
+    let title = escape_html(dashboard.title);
+    writeln!(writer, "<!DOCTYPE html>")?;
+    writeln!(
+        writer,
+        "<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">"
+    )?;
+    writeln!(
+        writer,
+        "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">"
+    )?;
+    writeln!(
+        writer,
+        "<title>{title} issues</title>\n<style>{STYLE}</style>"
+    )?;
+    writeln!(writer, "</head>\n<body>")?;
+    writeln!(writer, "<h1>{title} issues</h1>")?;
+    writeln!(
+        writer,
+        "<p class=\"muted\">Generated {} by br {}</p>",
+        dashboard.generated_at.format("%Y-%m-%d %H:%M UTC"),
+        env!("CARGO_PKG_VERSION")
+    )?;
+
+    write_summary(writer, dashboard)?;
+    write_charts(writer, dashboard.issues)?;
+
+    writeln!(writer, "<h2>Dependency graph</h2>")?;
+    write_graph(writer, dashboard)?;
+
+    let ready: Vec<&Issue> = dashboard
+        .issues
+        .iter()
+        .filter(|issue| dashboard.ready.contains(&issue.id))
+        .collect();
+    let blocked: Vec<&Issue> = dashboard
+        .issues
+        .iter()
+        .filter(|issue| dashboard.blocked.contains_key(&issue.id))
+        .collect();
+    let all: Vec<&Issue> = dashboard.issues.iter().collect();
+    writeln!(writer, "<h2 id=\"ready\">Ready ({})</h2>", ready.len())?;
+    write_table(writer, &ready, None)?;
+    writeln!(
+        writer,
+        "<h2 id=\"blocked\">Blocked ({})</h2>",
+        blocked.len()
+    )?;
+    write_table(writer, &blocked, Some(dashboard.blocked))?;
+    writeln!(writer, "<h2 id=\"all\">All issues ({})</h2>", all.len())?;
+    write_table(writer, &all, None)?;
+
+    writeln!(writer, "<script>{SCRIPT}</script>\n</body>\n</html>")
+}
+
+fn write_summary<W: Write>(writer: &mut W, dashboard: &Dashboard<'_>) -> io::Result<()> {
+    let count = |status: &Status| {
+        dashboard
+            .issues
+            .iter()
+            .filter(|issue| &issue.status == status)
+            .count()
+    };
+    let cards = [
+        ("Issues", dashboard.issues.len()),
+        ("Open", count(&Status::Open)),
+        ("In progress", count(&Status::InProgress)),
+        ("Ready", dashboard.ready.len()),
+        ("Blocked", dashboard.blocked.len()),
+        ("Closed", count(&Status::Closed)),
+    ];
+    writeln!(writer, "<div class=\"cards\">")?;
+    for (label, value) in cards {
+        writeln!(
+            writer,
+            "<div class=\"card\"><div class=\"value\">{value}</div><div>{label}</div></div>"
+        )?;
+    }
+    writeln!(writer, "</div>")
+}
+
+fn write_charts<W: Write>(writer: &mut W, issues: &[Issue]) -> io::Result<()> {
+    let mut by_status: BTreeMap<String, usize> = BTreeMap::new();
+    let mut by_priority: BTreeMap<String, usize> = BTreeMap::new();
+    let mut by_type: BTreeMap<String, usize> = BTreeMap::new();
+    for issue in issues {
+        *by_status
+            .entry(issue.status.as_str().to_string())
+            .or_default() += 1;
+        *by_priority.entry(issue.priority.to_string()).or_default() += 1;
+        *by_type
+            .entry(issue.issue_type.as_str().to_string())
+            .or_default() += 1;
+    }
+    writeln!(writer, "<div class=\"charts\">")?;
+    for (heading, counts) in [
+        ("By status", &by_status),
+        ("By priority", &by_priority),
+        ("By type", &by_type),
+    ] {
+        writeln!(writer, "<figure><figcaption>{heading}</figcaption>")?;
+        write_bar_chart(writer, counts)?;
+        writeln!(writer, "</figure>")?;
+    }
+    writeln!(writer, "</div>")
+}
+
+/// A horizontal bar chart as inline SVG.
+fn write_bar_chart<W: Write>(writer: &mut W, counts: &BTreeMap<String, usize>) -> io::Result<()> {
+    const LABEL_WIDTH: usize = 100;
+    let max = counts.values().copied().max().unwrap_or(0).max(1);
+    let height = counts.len().max(1) * (BAR_HEIGHT + 4);
+    writeln!(
+        writer,
+        "<svg width=\"{CHART_WIDTH}\" height=\"{height}\" role=\"img\">"
+    )?;
+    for (row, (label, count)) in counts.iter().enumerate() {
+        let y = row * (BAR_HEIGHT + 4);
+        let width = (CHART_WIDTH - LABEL_WIDTH - 40) * count / max;
+        writeln!(
+            writer,
+            "<text x=\"0\" y=\"{}\">{}</text>\
+             <rect class=\"bar\" x=\"{LABEL_WIDTH}\" y=\"{y}\" width=\"{width}\" height=\"{BAR_HEIGHT}\"/>\
+             <text x=\"{}\" y=\"{}\">{count}</text>",
+            y + BAR_HEIGHT - 5,
+            escape_html(label),
+            LABEL_WIDTH + width + 4,
+            y + BAR_HEIGHT - 5,
+        )?;
+    }
+    writeln!(writer, "</svg>")
+}
+
+/// The blocking dependencies as a layered SVG graph, blockers on the left.
+fn write_graph<W: Write>(writer: &mut W, dashboard: &Dashboard<'_>) -> io::Result<()> {
+    let layers = graph_layers(dashboard.edges);
+    if layers.is_empty() {
+        return writeln!(writer, "<p class=\"muted\">No dependencies.</p>");
+    }
+    let node_count: usize = layers.iter().map(Vec::len).sum();
+    if node_count > MAX_GRAPH_NODES {
+        return writeln!(
+            writer,
+            "<p class=\"muted\">{node_count} issues have dependencies; the graph is \
+             only drawn for up to {MAX_GRAPH_NODES}.</p>"
+        );
+    }
+
+    let issues: HashMap<&str, &Issue> = dashboard
+        .issues
+        .iter()
+        .map(|issue| (issue.id.as_str(), issue))
+        .collect();
+    let mut positions: HashMap<&str, (usize, usize)> = HashMap::new();
+    for (column, layer) in layers.iter().enumerate() {
+        for (row, id) in layer.iter().enumerate() {
+            positions.insert(
+                id.as_str(),
+                (
+                    column * (NODE_WIDTH + COLUMN_GAP),
+                    row * (NODE_HEIGHT + ROW_GAP),
+                ),
+            );
+        }
+    }
+    let width = layers.len() * (NODE_WIDTH + COLUMN_GAP) - COLUMN_GAP + 2;
+    let height = layers.iter().map(Vec::len).max().unwrap_or(0) * (NODE_HEIGHT + ROW_GAP);
+
+    writeln!(
+        writer,
+        "<div class=\"graph\"><svg width=\"{width}\" height=\"{height}\" role=\"img\">\
+         <defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" \
+         markerWidth=\"6\" markerHeight=\"6\" orient=\"auto\">\
+         <path d=\"M0,0 L10,5 L0,10 z\"/></marker></defs>"
+    )?;
+    for (issue, depends_on) in dashboard.edges {
+        let (Some(&(x1, y1)), Some(&(x2, y2))) = (
+            positions.get(depends_on.as_str()),
+            positions.get(issue.as_str()),
+        ) else {
+            continue;
+        };
+        writeln!(
+            writer,
+            "<line class=\"edge\" x1=\"{}\" y1=\"{}\" x2=\"{x2}\" y2=\"{}\" marker-end=\"url(#arrow)\"/>",
+            x1 + NODE_WIDTH,
+            y1 + NODE_HEIGHT / 2,
+            y2 + NODE_HEIGHT / 2,
+        )?;
+    }
+    for layer in &layers {
+        for id in layer {
+            let (x, y) = positions[id.as_str()];
+            let issue = issues.get(id.as_str());
+            let class = if issue.is_some_and(|issue| issue.status.is_terminal()) {
+                "closed"
+            } else if dashboard.blocked.contains_key(id) {
+                "blocked"
+            } else if dashboard.ready.contains(id) {
+                "ready"
+            } else {
+                "open"
+            };
+            let tooltip = issue.map_or_else(String::new, |issue| escape_html(&issue.title));
+            writeln!(
+                writer,
+                "<g class=\"node {class}\"><title>{tooltip}</title>\
+                 <rect x=\"{}\" y=\"{y}\" width=\"{NODE_WIDTH}\" height=\"{NODE_HEIGHT}\" rx=\"4\"/>\
+                 <text x=\"{}\" y=\"{}\">{}</text></g>",
+                x + 1,
+                x + 8,
+                y + NODE_HEIGHT / 2 + 5,
+                escape_html(id),
+            )?;
+        }
+    }
+    writeln!(writer, "</svg></div>")
+}
+
+/// Group the issues in `edges` into columns: an issue sits one column right
+/// of the furthest issue it depends on. Cycles are cut off after as many
+/// passes as there are issues.
+fn graph_layers(edges: &[(String, String)]) -> Vec<Vec<String>> {
+    let mut depth: BTreeMap<&str, usize> = BTreeMap::new();
+    for (issue, depends_on) in edges {
+        depth.insert(issue.as_str(), 0);
+        depth.insert(depends_on.as_str(), 0);
+    }
+    for _ in 0..depth.len() {
+        let mut changed = false;
+        for (issue, depends_on) in edges {
+            let wanted = depth[depends_on.as_str()] + 1;
+            if depth[issue.as_str()] < wanted && wanted < depth.len() {
+                depth.insert(issue.as_str(), wanted);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let mut layers: Vec<Vec<String>> = Vec::new();
+    for (id, column) in depth {
+        if layers.len() <= column {
+            layers.resize_with(column + 1, Vec::new);
+        }
+        layers[column].push(id.to_string());
+    }
+    layers.retain(|layer| !layer.is_empty());
+    layers
+}
+
+fn write_table<W: Write>(
+    writer: &mut W,
+    issues: &[&Issue],
+    blocked_by: Option<&HashMap<String, Vec<String>>>,
+) -> io::Result<()> {
+    if issues.is_empty() {
+        return writeln!(writer, "<p class=\"muted\">None.</p>");
+    }
+    write!(
+        writer,
+        "<table class=\"sortable\"><thead><tr><th>ID</th><th>Title</th><th>Status</th>\
+         <th>Priority</th><th>Type</th><th>Assignee</th><th>Labels</th><th>Updated</th>"
+    )?;
+    if blocked_by.is_some() {
+        write!(writer, "<th>Blocked by</th>")?;
+    }
+    writeln!(writer, "</tr></thead><tbody>")?;
+    for issue in issues {
+        write!(
+            writer,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td data-sort=\"{}\">{}</td><td>{}</td>\
+             <td>{}</td><td>{}</td><td data-sort=\"{}\">{}</td>",
+            escape_html(&issue.id),
+            escape_html(&issue.title),
+            escape_html(issue.status.as_str()),
+            issue.priority.0,
+            issue.priority,
+            escape_html(issue.issue_type.as_str()),
+            escape_html(issue.assignee.as_deref().unwrap_or("")),
+            escape_html(&issue.labels.join(", ")),
+            issue.updated_at.to_rfc3339(),
+            issue.updated_at.format("%Y-%m-%d"),
+        )?;
+        if let Some(blocked_by) = blocked_by {
+            let blockers = blocked_by
+                .get(&issue.id)
+                .map_or_else(String::new, |ids| ids.join(", "));
+            write!(writer, "<td>{}</td>", escape_html(&blockers))?;
+        }
+        writeln!(writer, "</tr>")?;
+    }
+    writeln!(writer, "</tbody></table>")
+}
+
+/// Escape text for HTML element content and quoted attributes.
+#[must_use]
+pub fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+const STYLE: &str = "\
+body{font-family:system-ui,sans-serif;margin:2rem;color:#1f2328}\
+h2{margin-top:2rem;border-bottom:1px solid #d0d7de}\
+.muted{color:#656d76}\
+.cards{display:flex;flex-wrap:wrap;gap:1rem}\
+.card{border:1px solid #d0d7de;border-radius:6px;padding:.75rem 1.25rem;min-width:7rem}\
+.card .value{font-size:1.75rem;font-weight:600}\
+.charts{display:flex;flex-wrap:wrap;gap:2rem;margin-top:1.5rem}\
+figure{margin:0}figcaption{font-weight:600;margin-bottom:.5rem}\
+svg text{font-size:12px;fill:#1f2328}\
+.bar{fill:#0969da}\
+.graph{overflow-x:auto}\
+.edge{stroke:#8c959f;stroke-width:1.5}\
+.node rect{stroke:#57606a;fill:#ddf4ff}\
+.node.ready rect{fill:#dafbe1}.node.blocked rect{fill:#ffebe9}\
+.node.closed rect{fill:#f6f8fa}.node.closed text{fill:#8c959f}\
+table{border-collapse:collapse;width:100%;font-size:14px}\
+th,td{border-bottom:1px solid #d0d7de;padding:.35rem .5rem;text-align:left}\
+th{cursor:pointer;user-select:none;background:#f6f8fa}\
+th[data-dir=asc]::after{content:' \\25B2'}th[data-dir=desc]::after{content:' \\25BC'}";
+
+/// Click a column header to sort by it; click again to reverse.
+const SCRIPT: &str = "\
+document.querySelectorAll('table.sortable th').forEach(function(th){\
+th.addEventListener('click',function(){\
+var table=th.closest('table'),body=table.tBodies[0],index=th.cellIndex,\
+asc=th.dataset.dir!=='asc';\
+table.querySelectorAll('th').forEach(function(h){delete h.dataset.dir;});\
+th.dataset.dir=asc?'asc':'desc';\
+var key=function(row){var cell=row.cells[index];\
+var value=cell.dataset.sort!==undefined?cell.dataset.sort:cell.textContent;\
+return isNaN(value)||value===''?value.toLowerCase():Number(value);};\
+Array.from(body.rows).sort(function(a,b){var x=key(a),y=key(b);\
+return (x<y?-1:x>y?1:0)*(asc?1:-1);}).forEach(function(row){body.appendChild(row);});\
+});});";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(issue: &str, depends_on: &str) -> (String, String) {
+        (issue.to_string(), depends_on.to_string())
+    }
+
+    #[test]
+    fn test_graph_layers_orders_blockers_first() {
+        let layers = graph_layers(&[
+            edge("bd-c", "bd-b"),
+            edge("bd-b", "bd-a"),
+            edge("bd-c", "bd-a"),
+        ]);
+        assert_eq!(layers, vec![vec!["bd-a"], vec!["bd-b"], vec!["bd-c"]]);
+
+        // A cycle still terminates
+        let layers = graph_layers(&[edge("bd-a", "bd-b"), edge("bd-b", "bd-a")]);
+        assert_eq!(layers.iter().map(Vec::len).sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn test_dashboard_escapes_and_lists_sections() {
+        let issue = Issue {
+            id: "bd-1".to_string(),
+            title: "<script>alert(1)</script>".to_string(),
+            ..Issue::default()
+        };
+        let issues = [issue];
+        let ready: HashSet<String> = ["bd-1".to_string()].into();
+        let dashboard = Dashboard {
+            title: "demo",
+            generated_at: Utc::now(),
+            issues: &issues,
+            ready: &ready,
+            blocked: &HashMap::new(),
+            edges: &[],
+        };
+        let mut out = Vec::new();
+        write_dashboard(&mut out, &dashboard).unwrap();
+        let html = String::from_utf8(out).unwrap();
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(!html.contains("<script>alert"));
+        assert!(html.contains("<h2 id=\"ready\">Ready (1)</h2>"));
+        assert!(html.contains("No dependencies."));
+    }
+}
@@ -0,0 +1,201 @@
+//! iCalendar (RFC 5545) output for `br export --format ics`.
+//!
+//! Each issue with a `due_at` or `defer_until` becomes a `VEVENT`, so deadlines
+//! and deferral dates show up in calendar apps. Events use stable UIDs
+//! (`<id>-due@beads`, `<id>-defer@beads`) so re-importing a newer export
+//! updates existing entries instead of duplicating them.
+
+use crate::model::Issue;
+use chrono::{DateTime, Utc};
+use std::io::{self, Write};
+
+/// Product identifier written to the calendar header.
+const PRODID: &str = "-//beads_rust//br export//EN";
+
+/// Maximum content line length in octets before folding (RFC 5545 §3.1).
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Write the `BEGIN:VCALENDAR` preamble.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+pub fn write_header<W: Write>(writer: &mut W) -> io::Result<()> {
+    write_line(writer, "BEGIN:VCALENDAR")?;
+    write_line(writer, "VERSION:2.0")?;
+    write_line(writer, &format!("PRODID:{PRODID}"))?;
+    write_line(writer, "CALSCALE:GREGORIAN")
+}
+
+/// Write the closing `END:VCALENDAR` line.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+pub fn write_footer<W: Write>(writer: &mut W) -> io::Result<()> {
+    write_line(writer, "END:VCALENDAR")
+}
+
+/// Write events for the dated issues in `issues`, returning how many were written.
+///
+/// Issues without `due_at` or `defer_until` are skipped.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+pub fn write_events<W: Write>(writer: &mut W, issues: &[Issue]) -> io::Result<usize> {
+    let mut written = 0;
+    for issue in issues {
+        if let Some(due) = issue.due_at {
+            write_event(writer, issue, "due", "Due", due)?;
+            written += 1;
+        }
+        if let Some(defer) = issue.defer_until {
+            write_event(writer, issue, "defer", "Deferred until", defer)?;
+            written += 1;
+        }
+    }
+    Ok(written)
+}
+
+fn write_event<W: Write>(
+    writer: &mut W,
+    issue: &Issue,
+    kind: &str,
+    label: &str,
+    at: DateTime<Utc>,
+) -> io::Result<()> {
+    let url = issue_url(issue);
+    let mut description = format!("Issue: {}", issue.id);
+    if let Some(url) = url {
+        description.push_str("\nURL: ");
+        description.push_str(url);
+    }
+
+    write_line(writer, "BEGIN:VEVENT")?;
+    write_line(writer, &format!("UID:{}-{kind}@beads", issue.id))?;
+    write_line(
+        writer,
+        &format!("DTSTAMP:{}", format_timestamp(issue.updated_at)),
+    )?;
+    write_line(writer, &format!("DTSTART:{}", format_timestamp(at)))?;
+    write_line(
+        writer,
+        &format!(
+            "SUMMARY:{}",
+            escape_text(&format!(
+                "[{}] {label}: {} ({})",
+                issue.status.as_str(),
+                issue.title,
+                issue.id
+            ))
+        ),
+    )?;
+    write_line(
+        writer,
+        &format!("DESCRIPTION:{}", escape_text(&description)),
+    )?;
+    if let Some(url) = url {
+        write_line(writer, &format!("URL:{url}"))?;
+    }
+    write_line(writer, "END:VEVENT")
+}
+
+/// The issue's external reference, if it is a web URL.
+fn issue_url(issue: &Issue) -> Option<&str> {
+    issue
+        .external_ref
+        .as_deref()
+        .filter(|r| r.starts_with("http://") || r.starts_with("https://"))
+}
+
+/// Format a UTC timestamp as an iCalendar DATE-TIME (`20250115T120000Z`).
+fn format_timestamp(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value (RFC 5545 §3.3.11).
+#[must_use]
+pub fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Write a content line, folding at 75 octets without splitting UTF-8 sequences.
+fn write_line<W: Write>(writer: &mut W, line: &str) -> io::Result<()> {
+    let mut remaining = line;
+    let mut limit = MAX_LINE_OCTETS;
+    loop {
+        if remaining.len() <= limit {
+            writer.write_all(remaining.as_bytes())?;
+            return writer.write_all(b"\r\n");
+        }
+        let mut split = limit;
+        while !remaining.is_char_boundary(split) {
+            split -= 1;
+        }
+        writer.write_all(remaining[..split].as_bytes())?;
+        writer.write_all(b"\r\n ")?;
+        remaining = &remaining[split..];
+        // Continuation lines start with a space, which counts toward the limit
+        limit = MAX_LINE_OCTETS - 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn escape_text_handles_special_characters() {
+        assert_eq!(escape_text("a, b; c\\d\ne"), "a\\, b\\; c\\\\d\\ne");
+    }
+
+    #[test]
+    fn events_only_for_dated_issues() {
+        let due = Utc.with_ymd_and_hms(2025, 3, 1, 17, 0, 0).unwrap();
+        let dated = Issue {
+            id: "bd-1".to_string(),
+            title: "Ship it".to_string(),
+            due_at: Some(due),
+            external_ref: Some("https://example.com/1".to_string()),
+            ..Issue::default()
+        };
+        let undated = Issue {
+            id: "bd-2".to_string(),
+            ..Issue::default()
+        };
+
+        let mut out = Vec::new();
+        let count = write_events(&mut out, &[dated, undated]).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(count, 1);
+        assert!(text.contains("UID:bd-1-due@beads\r\n"));
+        assert!(text.contains("DTSTART:20250301T170000Z\r\n"));
+        assert!(text.contains("SUMMARY:[open] Due: Ship it (bd-1)\r\n"));
+        assert!(text.contains("URL:https://example.com/1\r\n"));
+        assert!(!text.contains("bd-2"));
+    }
+
+    #[test]
+    fn long_lines_are_folded() {
+        let mut out = Vec::new();
+        write_line(&mut out, &"x".repeat(200)).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        for line in text.split("\r\n").filter(|l| !l.is_empty()) {
+            assert!(line.len() <= MAX_LINE_OCTETS);
+        }
+        assert_eq!(text.replace("\r\n ", "").trim_end(), "x".repeat(200));
+    }
+}
@@ -6,7 +6,8 @@
 //! # Output Types
 //!
 //! These types match the classic bd JSON schemas for CLI compatibility:
-//! - [`IssueWithCounts`] - Issue with dependency/dependent counts (list/search)
+//! - [`IssueWithCounts`] - Issue with dependency/dependent counts (list)
+//! - [`SearchResult`] - Issue with counts and match context (search)
 //! - [`IssueDetails`] - Issue with full relations (show)
 //! - [`BlockedIssue`] - Issue with blocking info (blocked)
 //! - [`TreeNode`] - Issue in dependency tree (dep tree)
@@ -18,6 +19,17 @@
 //! - Configurable field selection via `--fields`
 //! - Proper escaping of commas, quotes, and newlines
 //!
+//! The [`table`] module lays out `--format table` for `list`, `search`,
+//! `ready` and `blocked`, with `--columns` selection and width-aware
+//! truncation.
+//!
+//! The [`time`] module renders timestamps for text output: relative
+//! ("3d ago", "in 2h") by default, or fixed UTC formats with `--absolute`.
+//!
+//! The [`tabular`] module backs `br export` with CSV/TSV output and
+//! multi-valued columns such as labels; [`ics`] writes due and defer dates
+//! as iCalendar events, and [`html`] a static dashboard page.
+//!
 //! # Rich Output
 //!
 //! The [`rich`] module provides enhanced terminal output using `rich_rust`:
@@ -34,22 +46,27 @@
 
 pub mod context;
 pub mod csv;
+pub mod html;
+pub mod ics;
 pub mod markdown;
 mod output;
 pub mod rich;
 pub mod syntax;
+pub mod table;
+pub mod tabular;
 mod text;
 pub mod theme;
+pub mod time;
 
 pub use output::{
-    BlockedIssue, BlockedIssueOutput, Breakdown, BreakdownEntry, IssueDetails, IssueWithCounts,
-    IssueWithDependencyMetadata, ReadyIssue, RecentActivity, StaleIssue, Statistics, StatsSummary,
-    TreeNode,
+    BlockedIssue, BlockedIssueOutput, BlockerSummary, Breakdown, BreakdownEntry, IssueBacklink,
+    IssueDetails, IssueWithCounts, IssueWithDependencyMetadata, MilestoneProgress, ReadyIssue,
+    RecentActivity, SearchResult, StaleIssue, Statistics, StatsSummary, TreeNode,
 };
 pub use text::{
-    TextFormatOptions, format_issue_line, format_issue_line_with, format_priority,
+    TextFormatOptions, format_estimate, format_issue_line, format_issue_line_with, format_priority,
     format_priority_badge, format_priority_label, format_status_icon, format_status_icon_colored,
-    format_status_label, format_type_badge, format_type_badge_colored, terminal_width,
+    format_status_label, format_type_badge, format_type_badge_colored, icon_status, terminal_width,
     truncate_title,
 };
 
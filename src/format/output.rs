@@ -1,4 +1,4 @@
-use crate::model::{Comment, Event, Issue, IssueType, Priority, Status};
+use crate::model::{Comment, Event, Issue, IssueType, Priority, Reaction, Status};
 use chrono::{DateTime, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -74,6 +74,8 @@ impl From<&Issue> for ReadyIssue {
 pub struct BlockedIssueOutput {
     pub blocked_by: Vec<String>,
     pub blocked_by_count: usize,
+    /// Details of each entry in `blocked_by`, in the same order.
+    pub blockers: Vec<BlockerSummary>,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<String>,
@@ -87,6 +89,23 @@ pub struct BlockedIssueOutput {
     pub updated_at: DateTime<Utc>,
 }
 
+/// A single blocker of a blocked issue.
+///
+/// `title` and `priority` are absent for external blockers, which live in
+/// another project's database.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BlockerSummary {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Wake time of a `waits-for` dependency on this blocker.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waits_until: Option<DateTime<Utc>>,
+}
+
 impl From<&Issue> for StaleIssue {
     fn from(issue: &Issue) -> Self {
         Self {
@@ -110,6 +129,19 @@ pub struct IssueWithCounts {
     pub dependent_count: usize,
 }
 
+/// Search result row: issue with counts plus where the query matched.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchResult {
+    #[serde(flatten)]
+    pub issue: IssueWithCounts,
+    /// Field the query matched in (e.g. `title`, `notes`, `comments`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_field: Option<String>,
+    /// The matched line, clipped to the match and some context
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
 /// Issue details with full relations for show view.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct IssueDetails {
@@ -121,14 +153,74 @@ pub struct IssueDetails {
     pub dependencies: Vec<IssueWithDependencyMetadata>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub dependents: Vec<IssueWithDependencyMetadata>,
+    /// Issues that mention this one ("see also" backlinks)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub referenced_by: Vec<IssueBacklink>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub comments: Vec<Comment>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<Reaction>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub events: Vec<Event>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent: Option<String>,
 }
 
+impl IssueDetails {
+    /// One-line summary of the reactions on the issue (`comment_id` = `None`)
+    /// or on one comment, e.g. `:+1: ×2 (alice, bob) · :eyes: (carol)`.
+    #[must_use]
+    pub fn reaction_summary(&self, comment_id: Option<i64>) -> Option<String> {
+        let mut grouped: Vec<(&str, Vec<&str>)> = Vec::new();
+        for reaction in self.reactions.iter().filter(|r| r.comment_id == comment_id) {
+            match grouped
+                .iter_mut()
+                .find(|(name, _)| *name == reaction.reaction)
+            {
+                Some((_, actors)) => actors.push(&reaction.actor),
+                None => grouped.push((&reaction.reaction, vec![&reaction.actor])),
+            }
+        }
+        if grouped.is_empty() {
+            return None;
+        }
+        let parts: Vec<String> = grouped
+            .iter()
+            .map(|(name, actors)| {
+                if actors.len() > 1 {
+                    format!("{name} ×{} ({})", actors.len(), actors.join(", "))
+                } else {
+                    format!("{name} ({})", actors.join(", "))
+                }
+            })
+            .collect();
+        Some(parts.join(" · "))
+    }
+
+    /// Duplicate and supersede links, as `(label, id)` pairs such as
+    /// `("Duplicate of", "bd-abc")` or `("Superseded by", "bd-def")`.
+    #[must_use]
+    pub fn replacement_links(&self) -> Vec<(&'static str, &str)> {
+        let outgoing = self.dependencies.iter().filter_map(|dep| {
+            match dep.dep_type.as_str() {
+                "duplicates" => Some("Duplicate of"),
+                "supersedes" => Some("Supersedes"),
+                _ => None,
+            }
+            .map(|label| (label, dep.id.as_str()))
+        });
+        let incoming = self.dependents.iter().filter_map(|dep| {
+            match dep.dep_type.as_str() {
+                "duplicates" => Some("Duplicated by"),
+                "supersedes" => Some("Superseded by"),
+                _ => None,
+            }
+            .map(|label| (label, dep.id.as_str()))
+        });
+        outgoing.chain(incoming).collect()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct IssueWithDependencyMetadata {
     pub id: String,
@@ -137,6 +229,17 @@ pub struct IssueWithDependencyMetadata {
     pub priority: Priority,
     #[serde(rename = "dependency_type")]
     pub dep_type: String,
+    /// Condition of a `conditional-blocks` dependency (`label:<name>`, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+}
+
+/// An issue whose text or comments mention another issue's ID.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueBacklink {
+    pub id: String,
+    pub title: String,
+    pub status: Status,
 }
 
 /// Blocked issue for blocked view.
@@ -201,6 +304,31 @@ pub struct RecentActivity {
     pub total_changes: usize,
 }
 
+/// Progress of a milestone: how many of its issues are closed.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MilestoneProgress {
+    pub id: String,
+    pub title: String,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_at: Option<DateTime<Utc>>,
+    pub total: usize,
+    pub closed: usize,
+}
+
+impl MilestoneProgress {
+    /// Share of the milestone's issues that are closed, in percent.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn percent_done(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.closed as f64 / self.total as f64 * 100.0
+        }
+    }
+}
+
 /// Aggregate statistics output.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Statistics {
@@ -209,12 +337,15 @@ pub struct Statistics {
     pub breakdowns: Vec<Breakdown>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recent_activity: Option<RecentActivity>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub milestones: Vec<MilestoneProgress>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     fn base_issue(id: &str, title: &str) -> Issue {
         Issue {
@@ -257,6 +388,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -283,7 +418,9 @@ mod tests {
             labels: vec!["backend".to_string()],
             dependencies: vec![],
             dependents: vec![],
+            referenced_by: vec![],
             comments: vec![],
+            reactions: vec![],
             events: vec![],
             parent: Some("bd-parent".to_string()),
         };
@@ -293,6 +430,43 @@ mod tests {
         assert!(json.contains("\"labels\":[\"backend\"]"));
     }
 
+    #[test]
+    fn reaction_summary_groups_by_reaction() {
+        let reaction = |comment_id: Option<i64>, name: &str, actor: &str| Reaction {
+            issue_id: "bd-2".to_string(),
+            comment_id,
+            reaction: name.to_string(),
+            actor: actor.to_string(),
+            created_at: Utc::now(),
+        };
+        let details = IssueDetails {
+            issue: base_issue("bd-2", "Details"),
+            labels: vec![],
+            dependencies: vec![],
+            dependents: vec![],
+            referenced_by: vec![],
+            comments: vec![],
+            reactions: vec![
+                reaction(None, ":+1:", "alice"),
+                reaction(None, ":eyes:", "carol"),
+                reaction(None, ":+1:", "bob"),
+                reaction(Some(7), ":+1:", "dave"),
+            ],
+            events: vec![],
+            parent: None,
+        };
+
+        assert_eq!(
+            details.reaction_summary(None).as_deref(),
+            Some(":+1: ×2 (alice, bob) · :eyes: (carol)")
+        );
+        assert_eq!(
+            details.reaction_summary(Some(7)).as_deref(),
+            Some(":+1: (dave)")
+        );
+        assert!(details.reaction_summary(Some(8)).is_none());
+    }
+
     #[test]
     fn blocked_issue_serializes_blockers() {
         let issue = base_issue("bd-3", "Blocked");
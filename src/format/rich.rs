@@ -35,7 +35,7 @@
 //! }
 //! ```
 
-use crate::format::text::{format_priority, format_status_icon, truncate_title};
+use crate::format::text::{format_priority, format_status_icon, icon_status, truncate_title};
 use crate::model::{Issue, Status};
 use crate::output::Theme;
 use rich_rust::prelude::*;
@@ -91,7 +91,7 @@ impl<'a> RichIssueTable<'a> {
         table = table.with_column(Column::new("Title"));
 
         for issue in self.issues {
-            let status_icon = format_status_icon(&issue.status);
+            let status_icon = format_status_icon(icon_status(issue));
             let priority = format_priority(&issue.priority);
             let title = self
                 .max_title_width
@@ -158,7 +158,7 @@ impl<'a> RichIssuePanel<'a> {
         let mut content = String::new();
 
         // Header line: status icon + title
-        let status_icon = format_status_icon(&self.issue.status);
+        let status_icon = format_status_icon(icon_status(self.issue));
         content.push_str(&format!("{} {}\n", status_icon, self.issue.title));
 
         // Metadata line
@@ -323,6 +323,7 @@ mod tests {
     use super::*;
     use crate::model::{IssueType, Priority};
     use chrono::Utc;
+    use std::collections::BTreeMap;
 
     fn make_test_issue(id: &str, title: &str) -> Issue {
         Issue {
@@ -365,6 +366,10 @@ mod tests {
             is_template: false,
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -0,0 +1,272 @@
+//! Aligned, width-aware text tables for `--format table`.
+//!
+//! Shared by `list`, `search`, `ready` and `blocked`: the command picks the
+//! columns with [`parse_columns`], [`render_issues`] turns issues into cells,
+//! and [`Table`] lays them out. When a table is wider than its maximum width
+//! the widest columns give up space first, and cells that no longer fit are
+//! truncated with an ellipsis.
+
+use crate::error::{BeadsError, Result};
+use crate::format::time::compact_duration;
+use crate::format::{tabular, truncate_title};
+use crate::model::Issue;
+use chrono::{DateTime, Utc};
+use std::io::IsTerminal;
+use unicode_width::UnicodeWidthStr;
+
+/// Columns shown when `--columns` is not given.
+pub const DEFAULT_COLUMNS: &[&str] = &["id", "status", "priority", "assignee", "age", "title"];
+
+/// Columns only tables offer, on top of [`tabular::available_columns`].
+const TABLE_COLUMNS: &[&str] = &["type", "age"];
+
+/// Space between columns.
+const GAP: &str = "  ";
+
+/// Narrowest a column is squeezed to when fitting the maximum width.
+const MIN_COLUMN_WIDTH: usize = 4;
+
+/// Parse a comma-separated `--columns` list. `extra` names the columns the
+/// command fills in itself (e.g. `blocked_by`).
+///
+/// Returns [`DEFAULT_COLUMNS`] when the input is empty.
+///
+/// # Errors
+///
+/// Returns a validation error naming the first unknown column.
+pub fn parse_columns(arg: Option<&str>, extra: &[&'static str]) -> Result<Vec<&'static str>> {
+    let Some(arg) = arg.map(str::trim).filter(|arg| !arg.is_empty()) else {
+        return Ok(DEFAULT_COLUMNS.to_vec());
+    };
+
+    let mut available = tabular::available_columns();
+    available.extend(TABLE_COLUMNS);
+    available.extend(extra);
+    arg.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            available
+                .iter()
+                .find(|&&column| column == name)
+                .copied()
+                .ok_or_else(|| {
+                    BeadsError::validation(
+                        "columns",
+                        format!(
+                            "unknown column '{name}' (available: {})",
+                            available.join(", ")
+                        ),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// The widest a table may be: `configured` (`table.max_width`, 0 for no
+/// limit), otherwise the terminal width when stdout is a terminal.
+#[must_use]
+pub fn max_width(configured: Option<usize>) -> Option<usize> {
+    match configured {
+        Some(0) => None,
+        Some(width) => Some(width),
+        None if std::io::stdout().is_terminal() => Some(crate::format::terminal_width()),
+        None => None,
+    }
+}
+
+/// The text of `column` for `issue` as shown in a table.
+#[must_use]
+pub fn cell(issue: &Issue, column: &str, now: DateTime<Utc>) -> String {
+    let value = match column {
+        "age" => compact_duration(now.signed_duration_since(issue.created_at)),
+        "type" => issue.issue_type.as_str().to_string(),
+        "priority" => format!("P{}", issue.priority.0),
+        "labels" => issue.labels.join(", "),
+        "created_at" => short_date(Some(issue.created_at)),
+        "updated_at" => short_date(Some(issue.updated_at)),
+        "closed_at" => short_date(issue.closed_at),
+        "due_at" => short_date(issue.due_at),
+        "defer_until" => short_date(issue.defer_until),
+        _ => tabular::cell_value(issue, column),
+    };
+    // One line per row: fold line breaks and tabs into spaces
+    if value.contains(['\n', '\r', '\t']) {
+        value.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else {
+        value
+    }
+}
+
+/// Lay out `issues` as a table of `columns`. `extra` supplies the
+/// command's own columns and returns `None` for the shared ones.
+#[must_use]
+pub fn render_issues<'a, I, F>(
+    issues: I,
+    columns: &[&str],
+    max_width: Option<usize>,
+    extra: F,
+) -> String
+where
+    I: IntoIterator<Item = &'a Issue>,
+    F: Fn(&Issue, &str) -> Option<String>,
+{
+    let now = Utc::now();
+    let mut table = Table::new(columns).max_width(max_width);
+    for issue in issues {
+        table.push_row(
+            columns
+                .iter()
+                .map(|column| extra(issue, column).unwrap_or_else(|| cell(issue, column, now)))
+                .collect(),
+        );
+    }
+    table.render()
+}
+
+fn short_date(timestamp: Option<DateTime<Utc>>) -> String {
+    timestamp.map_or_else(String::new, |ts| ts.format("%Y-%m-%d").to_string())
+}
+
+/// A text table: a header row and rows of cells in aligned columns.
+#[derive(Debug, Clone)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    max_width: Option<usize>,
+}
+
+impl Table {
+    /// A table with the given column names, shown upper-cased as headers.
+    #[must_use]
+    pub fn new(columns: &[&str]) -> Self {
+        Self {
+            headers: columns.iter().map(|c| c.to_uppercase()).collect(),
+            rows: Vec::new(),
+            max_width: None,
+        }
+    }
+
+    /// Limit the rendered width (`None` for no limit).
+    #[must_use]
+    pub const fn max_width(mut self, max_width: Option<usize>) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Add a row; missing cells are left blank and extra ones ignored.
+    pub fn push_row(&mut self, mut cells: Vec<String>) {
+        cells.resize(self.headers.len(), String::new());
+        self.rows.push(cells);
+    }
+
+    /// Render the header and rows, one line each, without trailing spaces.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.width()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.width());
+            }
+        }
+        if let Some(max_width) = self.max_width {
+            fit(&mut widths, max_width);
+        }
+
+        let mut out = String::new();
+        for row in std::iter::once(&self.headers).chain(&self.rows) {
+            let mut line = String::new();
+            for (i, (cell, &width)) in row.iter().zip(&widths).enumerate() {
+                if i > 0 {
+                    line.push_str(GAP);
+                }
+                let cell = truncate_title(cell, width);
+                let pad = width.saturating_sub(cell.width());
+                line.push_str(&cell);
+                line.extend(std::iter::repeat_n(' ', pad));
+            }
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Narrow the widest columns one step at a time until the table fits
+/// `max_width` or every column is down to [`MIN_COLUMN_WIDTH`].
+fn fit(widths: &mut [usize], max_width: usize) {
+    let gaps = GAP.len() * widths.len().saturating_sub(1);
+    let mut total = widths.iter().sum::<usize>() + gaps;
+    while total > max_width {
+        let Some((widest, width)) = widths
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by_key(|&(_, width)| width)
+        else {
+            return;
+        };
+        if width <= MIN_COLUMN_WIDTH {
+            return;
+        }
+        widths[widest] -= 1;
+        total -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Priority;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_columns() {
+        assert_eq!(parse_columns(None, &[]).unwrap(), DEFAULT_COLUMNS);
+        assert_eq!(
+            parse_columns(Some("id, age,blocked_by"), &["blocked_by"]).unwrap(),
+            vec!["id", "age", "blocked_by"]
+        );
+        assert!(parse_columns(Some("id,blocked_by"), &[]).is_err());
+    }
+
+    #[test]
+    fn test_table_aligns_columns() {
+        let mut table = Table::new(&["id", "title"]);
+        table.push_row(vec!["bd-1".into(), "Short".into()]);
+        table.push_row(vec!["bd-12345".into(), "Longer title".into()]);
+        assert_eq!(
+            table.render(),
+            "ID        TITLE\nbd-1      Short\nbd-12345  Longer title\n"
+        );
+    }
+
+    #[test]
+    fn test_table_truncates_widest_column_to_fit() {
+        let mut table = Table::new(&["id", "title"]).max_width(Some(20));
+        table.push_row(vec!["bd-1".into(), "A rather long issue title".into()]);
+        let rendered = table.render();
+        for line in rendered.lines() {
+            assert!(line.width() <= 20, "{line:?}");
+        }
+        assert!(rendered.contains("bd-1  A rather lo..."), "{rendered}");
+    }
+
+    #[test]
+    fn test_cell_formats_for_tables() {
+        let created = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 1, 4, 0, 0, 0).unwrap();
+        let mut issue = Issue {
+            title: "Line one\nline two".to_string(),
+            created_at: created,
+            priority: Priority::MEDIUM,
+            ..Issue::default()
+        };
+        issue.labels = vec!["a".into(), "b".into()];
+        assert_eq!(cell(&issue, "age", now), "3d");
+        assert_eq!(cell(&issue, "priority", now), "P2");
+        assert_eq!(cell(&issue, "labels", now), "a, b");
+        assert_eq!(cell(&issue, "created_at", now), "2025-01-01");
+        assert_eq!(cell(&issue, "title", now), "Line one line two");
+    }
+}
@@ -0,0 +1,305 @@
+//! Delimited (CSV/TSV) table output for `br export`, and the CSV reader used
+//! by `br import`.
+//!
+//! Unlike [`crate::format::csv`], which backs `list --format csv`, this writer
+//! supports both comma- and tab-separated output, rejects unknown columns, and
+//! can include multi-valued columns such as `labels`.
+//!
+//! Quoting rules:
+//! - CSV follows RFC 4180: fields containing the delimiter, quotes, or line
+//!   breaks are wrapped in double quotes with embedded quotes doubled.
+//! - TSV never quotes; tabs, line breaks, and backslashes are escaped as
+//!   `\t`, `\n`, `\r`, and `\\` so every record stays on one line.
+
+use crate::error::{BeadsError, Result};
+use crate::format::csv;
+use crate::model::Issue;
+use std::io::{self, Write};
+
+/// Separator used when joining multi-valued columns (labels).
+pub const LIST_SEPARATOR: &str = ";";
+
+/// Columns exported when `--columns` is not given.
+pub const DEFAULT_COLUMNS: &[&str] = &[
+    "id",
+    "title",
+    "status",
+    "priority",
+    "issue_type",
+    "assignee",
+    "labels",
+    "created_at",
+    "updated_at",
+];
+
+/// Columns available only in tabular export (in addition to [`csv::ALL_FIELDS`]).
+const EXTRA_COLUMNS: &[&str] = &["labels", "created_by", "close_reason", "estimated_minutes"];
+
+/// Field delimiter for tabular output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    Comma,
+    Tab,
+}
+
+impl Delimiter {
+    const fn as_char(self) -> char {
+        match self {
+            Self::Comma => ',',
+            Self::Tab => '\t',
+        }
+    }
+}
+
+/// All column names accepted by [`parse_columns`].
+#[must_use]
+pub fn available_columns() -> Vec<&'static str> {
+    csv::ALL_FIELDS
+        .iter()
+        .chain(EXTRA_COLUMNS)
+        .copied()
+        .collect()
+}
+
+/// Parse a comma-separated column list.
+///
+/// Returns [`DEFAULT_COLUMNS`] when the input is empty.
+///
+/// # Errors
+///
+/// Returns a validation error naming the first unknown column.
+pub fn parse_columns(arg: Option<&str>) -> Result<Vec<&'static str>> {
+    let Some(arg) = arg.map(str::trim).filter(|arg| !arg.is_empty()) else {
+        return Ok(DEFAULT_COLUMNS.to_vec());
+    };
+
+    let available = available_columns();
+    arg.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            available
+                .iter()
+                .find(|&&column| column == name)
+                .copied()
+                .ok_or_else(|| {
+                    BeadsError::validation(
+                        "columns",
+                        format!(
+                            "unknown column '{name}' (available: {})",
+                            available.join(", ")
+                        ),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Whether any of `columns` needs labels loaded on the issues.
+#[must_use]
+pub fn needs_labels(columns: &[&str]) -> bool {
+    columns.contains(&"labels")
+}
+
+/// Get a column value from an issue.
+#[must_use]
+pub fn cell_value(issue: &Issue, column: &str) -> String {
+    match column {
+        "labels" => issue.labels.join(LIST_SEPARATOR),
+        "created_by" => issue.created_by.clone().unwrap_or_default(),
+        "close_reason" => issue.close_reason.clone().unwrap_or_default(),
+        "estimated_minutes" => issue
+            .estimated_minutes
+            .map_or_else(String::new, |minutes| minutes.to_string()),
+        _ => csv::get_field_value(issue, column),
+    }
+}
+
+/// Escape a single cell for the given delimiter.
+#[must_use]
+pub fn escape_cell(value: &str, delimiter: Delimiter) -> String {
+    match delimiter {
+        Delimiter::Comma => csv::escape_field(value),
+        Delimiter::Tab => {
+            let mut escaped = String::with_capacity(value.len());
+            for c in value.chars() {
+                match c {
+                    '\\' => escaped.push_str("\\\\"),
+                    '\t' => escaped.push_str("\\t"),
+                    '\n' => escaped.push_str("\\n"),
+                    '\r' => escaped.push_str("\\r"),
+                    _ => escaped.push(c),
+                }
+            }
+            escaped
+        }
+    }
+}
+
+/// Write the header row.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+pub fn write_header<W: Write>(
+    writer: &mut W,
+    columns: &[&str],
+    delimiter: Delimiter,
+) -> io::Result<()> {
+    let mut sep = [0u8; 4];
+    writeln!(
+        writer,
+        "{}",
+        columns.join(delimiter.as_char().encode_utf8(&mut sep))
+    )
+}
+
+/// Write one row per issue.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+pub fn write_rows<W: Write>(
+    writer: &mut W,
+    issues: &[Issue],
+    columns: &[&str],
+    delimiter: Delimiter,
+) -> io::Result<()> {
+    let mut sep = [0u8; 4];
+    let sep = delimiter.as_char().encode_utf8(&mut sep);
+    for issue in issues {
+        let row = columns
+            .iter()
+            .map(|column| escape_cell(&cell_value(issue, column), delimiter))
+            .collect::<Vec<_>>()
+            .join(sep);
+        writeln!(writer, "{row}")?;
+    }
+    Ok(())
+}
+
+/// Parse RFC 4180 CSV into records of fields.
+///
+/// Handles quoted fields with embedded delimiters, doubled quotes, and line
+/// breaks; accepts LF or CRLF record endings and strips a leading UTF-8 BOM.
+/// Blank lines are skipped.
+///
+/// # Errors
+///
+/// Returns a validation error if a quoted field is never closed.
+pub fn parse_csv(input: &str) -> Result<Vec<Vec<String>>> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if !(record.len() == 1 && record[0].is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(BeadsError::validation(
+            "csv",
+            format!("unterminated quoted field in record {}", records.len() + 1),
+        ));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_with_labels(id: &str, title: &str, labels: &[&str]) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            labels: labels.iter().map(ToString::to_string).collect(),
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn parse_columns_defaults_and_rejects_unknown() {
+        assert_eq!(parse_columns(None).unwrap(), DEFAULT_COLUMNS);
+        assert_eq!(parse_columns(Some("  ")).unwrap(), DEFAULT_COLUMNS);
+        assert_eq!(
+            parse_columns(Some("id, title,labels")).unwrap(),
+            vec!["id", "title", "labels"]
+        );
+        assert!(parse_columns(Some("id,bogus")).is_err());
+    }
+
+    #[test]
+    fn csv_rows_quote_and_join_labels() {
+        let issue = issue_with_labels("bd-1", "Fix, then \"ship\"", &["backend", "urgent"]);
+        let mut out = Vec::new();
+        write_header(&mut out, &["id", "title", "labels"], Delimiter::Comma).unwrap();
+        write_rows(
+            &mut out,
+            &[issue],
+            &["id", "title", "labels"],
+            Delimiter::Comma,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "id,title,labels\nbd-1,\"Fix, then \"\"ship\"\"\",backend;urgent\n"
+        );
+    }
+
+    #[test]
+    fn tsv_rows_escape_control_characters() {
+        let issue = issue_with_labels("bd-2", "tab\there\nnewline \\ slash", &[]);
+        let mut out = Vec::new();
+        write_rows(&mut out, &[issue], &["id", "title"], Delimiter::Tab).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "bd-2\ttab\\there\\nnewline \\\\ slash\n"
+        );
+    }
+
+    #[test]
+    fn parse_csv_handles_quotes_crlf_and_bom() {
+        let input =
+            "\u{feff}key,summary\r\nA-1,\"Fix, then \"\"ship\"\"\"\r\n\r\nA-2,\"multi\nline\"";
+        assert_eq!(
+            parse_csv(input).unwrap(),
+            vec![
+                vec!["key", "summary"],
+                vec!["A-1", "Fix, then \"ship\""],
+                vec!["A-2", "multi\nline"],
+            ]
+        );
+        assert!(parse_csv("a,\"open").is_err());
+    }
+}
@@ -64,6 +64,19 @@ pub const fn format_status_icon(status: &Status) -> &'static str {
     }
 }
 
+/// Status whose icon represents `issue`.
+///
+/// Issues with the `pinned` flag show 📌 like `Status::Pinned` until they are
+/// closed or deleted.
+#[must_use]
+pub const fn icon_status(issue: &Issue) -> &Status {
+    if issue.pinned && !issue.status.is_terminal() {
+        &Status::Pinned
+    } else {
+        &issue.status
+    }
+}
+
 /// Format priority as "P0", "P1", etc.
 #[must_use]
 pub fn format_priority(priority: &Priority) -> String {
@@ -159,6 +172,20 @@ pub fn format_type_badge_colored(issue_type: &IssueType, use_color: bool) -> Str
     format!("[{colored}]")
 }
 
+/// Format an estimate in minutes as `2h 30m`, `2h`, or `45m`.
+#[must_use]
+pub fn format_estimate(minutes: i64) -> String {
+    let hours = minutes / 60;
+    let remaining = minutes % 60;
+    if hours > 0 && remaining > 0 {
+        format!("{hours}h {remaining}m")
+    } else if hours > 0 {
+        format!("{hours}h")
+    } else {
+        format!("{remaining}m")
+    }
+}
+
 /// Determine terminal width from environment (falls back to 80).
 ///
 /// Checks in order:
@@ -239,7 +266,7 @@ fn visible_len(text: &str) -> usize {
 /// (matches bd text output format)
 #[must_use]
 pub fn format_issue_line_with(issue: &Issue, options: TextFormatOptions) -> String {
-    let status_icon_plain = format_status_icon(&issue.status);
+    let status_icon_plain = format_status_icon(icon_status(issue));
     // Account for the bullet in priority badge: [● P2]
     let priority_badge_plain = format!("[● {}]", format_priority(&issue.priority));
     let type_badge_plain = format_type_badge(&issue.issue_type);
@@ -263,7 +290,7 @@ pub fn format_issue_line_with(issue: &Issue, options: TextFormatOptions) -> Stri
         )
     };
 
-    let status_icon = format_status_icon_colored(&issue.status, options.use_color);
+    let status_icon = format_status_icon_colored(icon_status(issue), options.use_color);
     let priority_badge = format_priority_badge(&issue.priority, options.use_color);
     let type_badge = format_type_badge_colored(&issue.issue_type, options.use_color);
 
@@ -285,6 +312,7 @@ pub fn format_issue_line(issue: &Issue) -> String {
 mod tests {
     use super::*;
     use chrono::Utc;
+    use std::collections::BTreeMap;
 
     fn make_test_issue() -> Issue {
         Issue {
@@ -327,6 +355,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -345,6 +377,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pinned_flag_shows_pin_icon_until_closed() {
+        let mut issue = make_test_issue();
+        issue.pinned = true;
+        assert!(format_issue_line(&issue).starts_with("📌 bd-test"));
+
+        issue.status = Status::Closed;
+        assert!(format_issue_line(&issue).starts_with("✓ bd-test"));
+    }
+
     #[test]
     fn test_format_priority() {
         assert_eq!(format_priority(&Priority::CRITICAL), "P0");
@@ -0,0 +1,181 @@
+//! Human-friendly timestamps for text output.
+//!
+//! Text output shows times relative to now ("3d ago", "in 2h") unless
+//! `--absolute` or `display.time: absolute` asks for fixed dates. Absolute
+//! times are always UTC and use chrono's built-in English names, so the
+//! output doesn't depend on the machine's locale or timezone.
+
+use crate::error::{BeadsError, Result};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Duration, Utc};
+
+/// Default `display.date_format`.
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Default `display.datetime_format`.
+pub const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M UTC";
+
+/// Whether text output shows relative or absolute times.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeStyle {
+    /// "3d ago", "in 2h"
+    #[default]
+    Relative,
+    /// Dates and times in the configured formats
+    Absolute,
+}
+
+/// How text output renders timestamps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeFormat {
+    pub style: TimeStyle,
+    /// strftime format for dates (due dates, created/updated)
+    pub date_format: String,
+    /// strftime format for points in time (comments, events)
+    pub datetime_format: String,
+}
+
+impl Default for TimeFormat {
+    fn default() -> Self {
+        Self {
+            style: TimeStyle::Relative,
+            date_format: DEFAULT_DATE_FORMAT.to_string(),
+            datetime_format: DEFAULT_DATETIME_FORMAT.to_string(),
+        }
+    }
+}
+
+impl TimeFormat {
+    /// Absolute times in the default formats.
+    #[must_use]
+    pub fn absolute() -> Self {
+        Self {
+            style: TimeStyle::Absolute,
+            ..Self::default()
+        }
+    }
+
+    /// A date: relative, or in `date_format`.
+    #[must_use]
+    pub fn date(&self, timestamp: DateTime<Utc>, now: DateTime<Utc>) -> String {
+        match self.style {
+            TimeStyle::Relative => relative(timestamp, now),
+            TimeStyle::Absolute => timestamp.format(&self.date_format).to_string(),
+        }
+    }
+
+    /// A point in time: relative, or in `datetime_format`.
+    #[must_use]
+    pub fn datetime(&self, timestamp: DateTime<Utc>, now: DateTime<Utc>) -> String {
+        match self.style {
+            TimeStyle::Relative => relative(timestamp, now),
+            TimeStyle::Absolute => timestamp.format(&self.datetime_format).to_string(),
+        }
+    }
+
+    /// Time elapsed since `timestamp` as a short duration ("4w"), or the
+    /// date in `date_format`. For narrow columns such as `stale`'s age.
+    #[must_use]
+    pub fn age(&self, timestamp: DateTime<Utc>, now: DateTime<Utc>) -> String {
+        match self.style {
+            TimeStyle::Relative => compact_duration(now.signed_duration_since(timestamp)),
+            TimeStyle::Absolute => timestamp.format(&self.date_format).to_string(),
+        }
+    }
+}
+
+/// `timestamp` relative to `now`: "just now", "5m ago", "in 2h".
+#[must_use]
+pub fn relative(timestamp: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let delta = timestamp.signed_duration_since(now);
+    if delta.num_seconds().abs() < 60 {
+        "just now".to_string()
+    } else if delta > Duration::zero() {
+        format!("in {}", compact_duration(delta))
+    } else {
+        format!("{} ago", compact_duration(-delta))
+    }
+}
+
+/// Short duration, rounded down: `45m`, `5h`, `3d`, `4w`, `5mo`, `2y`.
+/// Negative durations count as zero.
+#[must_use]
+pub fn compact_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes().max(0);
+    let days = minutes / (60 * 24);
+    match minutes {
+        0..60 => format!("{minutes}m"),
+        60..1440 => format!("{}h", minutes / 60),
+        _ if days < 14 => format!("{days}d"),
+        _ if days < 56 => format!("{}w", days / 7),
+        _ if days < 365 => format!("{}mo", days / 30),
+        _ => format!("{}y", days / 365),
+    }
+}
+
+/// Check that `format` is a valid strftime format; `key` names the setting.
+///
+/// # Errors
+///
+/// Returns a config error for unknown `%` specifiers.
+pub fn validate_format(key: &str, format: &str) -> Result<()> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(BeadsError::Config(format!(
+            "{key} is not a valid strftime format: '{format}'"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 15, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_relative() {
+        let now = now();
+        assert_eq!(relative(now - Duration::seconds(30), now), "just now");
+        assert_eq!(relative(now - Duration::minutes(5), now), "5m ago");
+        assert_eq!(relative(now - Duration::days(3), now), "3d ago");
+        assert_eq!(relative(now + Duration::hours(2), now), "in 2h");
+        assert_eq!(relative(now - Duration::days(30), now), "4w ago");
+    }
+
+    #[test]
+    fn test_compact_duration() {
+        assert_eq!(compact_duration(Duration::minutes(-5)), "0m");
+        assert_eq!(compact_duration(Duration::minutes(59)), "59m");
+        assert_eq!(compact_duration(Duration::hours(5)), "5h");
+        assert_eq!(compact_duration(Duration::days(3)), "3d");
+        assert_eq!(compact_duration(Duration::days(45)), "6w");
+        assert_eq!(compact_duration(Duration::days(150)), "5mo");
+        assert_eq!(compact_duration(Duration::days(800)), "2y");
+    }
+
+    #[test]
+    fn test_absolute_formats() {
+        let now = now();
+        let format = TimeFormat {
+            date_format: "%d %b %Y".to_string(),
+            ..TimeFormat::absolute()
+        };
+        assert_eq!(format.date(now, now), "15 Jun 2025");
+        assert_eq!(format.datetime(now, now), "2025-06-15 12:00 UTC");
+        assert_eq!(format.age(now, now), "15 Jun 2025");
+        assert_eq!(
+            TimeFormat::default().age(now - Duration::days(3), now),
+            "3d"
+        );
+    }
+
+    #[test]
+    fn test_validate_format() {
+        assert!(validate_format("display.date_format", "%Y-%m-%d").is_ok());
+        assert!(validate_format("display.date_format", "%Q").is_err());
+    }
+}
@@ -12,21 +12,28 @@
 //! - [`storage`] - `SQLite` database layer
 //! - [`sync`] - JSONL import/export operations
 //! - [`config`] - Configuration management
+//! - [`notify`] - Webhooks for mutations
+//! - [`alerts`] - Alert hooks for unblocked and overdue issues
 //! - [`error`] - Error types and handling
 //! - [`format`] - Output formatting (text, JSON)
 //! - [`util`] - Utility functions (hashing, time, paths)
+//! - `serve` - HTTP API server for `br serve` (feature `serve`)
 
 #![forbid(unsafe_code)]
 // Lint configuration is in Cargo.toml [lints.clippy] section
 #![allow(clippy::module_name_repetitions)]
 
+pub mod alerts;
 pub mod cli;
 pub mod config;
 pub mod error;
 pub mod format;
 pub mod logging;
 pub mod model;
+pub mod notify;
 pub mod output;
+#[cfg(feature = "serve")]
+pub mod serve;
 pub mod storage;
 pub mod sync;
 pub mod util;
@@ -1,9 +1,12 @@
 use beads_rust::cli::commands;
 use beads_rust::cli::{Cli, Commands};
 use beads_rust::config;
+use beads_rust::error::exit_code;
 use beads_rust::logging::init_logging;
-use beads_rust::output::OutputContext;
-use beads_rust::sync::{auto_flush, auto_import_if_stale};
+use beads_rust::notify;
+use beads_rust::output::{OutputContext, Theme};
+use beads_rust::sync::lock::LockFile;
+use beads_rust::sync::{auto_flush_with_shard, auto_import_if_stale};
 use beads_rust::{BeadsError, Result, StructuredError};
 use clap::{CommandFactory, Parser};
 use clap_complete::CompleteEnv;
@@ -15,8 +18,8 @@ use tracing::{debug, error, warn};
 fn main() {
     CompleteEnv::with_factory(Cli::command).complete();
 
-    let cli = Cli::parse();
-    let output_ctx = OutputContext::from_args(&cli);
+    let cli = Cli::try_parse().unwrap_or_else(|err| exit_on_usage_error(&err));
+    let mut output_ctx = OutputContext::from_args(&cli);
 
     // Initialize logging
     if let Err(e) = init_logging(cli.verbose, cli.quiet, None) {
@@ -28,8 +31,49 @@ fn main() {
 
     // Track if this command potentially mutates data (for auto-flush)
     let is_mutating = is_mutating_command(&cli.command);
+    let json_errors = cli.json || robot_requested(&cli.command);
+    let read_only = overrides.read_only.unwrap_or(false);
 
-    if should_auto_import(&cli.command) && !cli.no_db {
+    if read_only {
+        if let Some(operation) = writes_workspace_files(&cli.command) {
+            let err = BeadsError::ReadOnly {
+                operation: operation.to_string(),
+            };
+            handle_error(&err, json_errors);
+        }
+    }
+
+    // A plan runs the command against a snapshot in a child process; this
+    // run only reads the database. Commands that write other workspace files
+    // would escape the snapshot, so they cannot be planned
+    if cli.plan {
+        if let Some(operation) = writes_workspace_files(&cli.command) {
+            let err = BeadsError::validation(
+                "plan",
+                format!("{operation} writes files outside the database and cannot be planned"),
+            );
+            handle_error(&err, json_errors);
+        }
+        if !is_mutating {
+            let err = BeadsError::validation("plan", "--plan only applies to commands that write");
+            handle_error(&err, json_errors);
+        }
+        if let Err(e) = commands::apply::execute_plan(&overrides, &output_ctx) {
+            handle_error(&e, json_errors);
+        }
+        return;
+    }
+
+    // Serialize with other br runs until this one has flushed
+    let workspace_lock = if read_only {
+        None
+    } else if takes_workspace_lock(&cli.command) {
+        lock_workspace(&overrides).unwrap_or_else(|e| handle_error(&e, json_errors))
+    } else {
+        None
+    };
+
+    if should_auto_import(&cli.command) && !cli.no_db && !read_only {
         if let Err(e) = run_auto_import(&overrides, cli.allow_stale, cli.no_auto_import) {
             error!(
                 error = %e,
@@ -42,6 +86,19 @@ fn main() {
         }
     }
 
+    // Mark where this command's events begin, for webhooks sent at exit
+    let notifier = if is_mutating && !cli.no_db && !read_only {
+        config::discover_beads_dir_with_cli(&overrides)
+            .ok()
+            .and_then(|beads_dir| notify::start_notifier(&beads_dir, &overrides))
+    } else {
+        None
+    };
+
+    if output_ctx.is_rich() || output_ctx.is_plain() {
+        load_display_settings(&overrides, &mut output_ctx);
+    }
+
     let result = match cli.command {
         Commands::Init {
             prefix,
@@ -57,6 +114,9 @@ fn main() {
         Commands::Comments(args) => {
             commands::comments::execute(&args, cli.json, &overrides, &output_ctx)
         }
+        Commands::React(args) => commands::react::execute(&args, cli.json, &overrides, &output_ctx),
+        Commands::Link(args) => commands::link::execute(&args, cli.json, &overrides, &output_ctx),
+        Commands::Watch(args) => commands::watch::execute(&args, &overrides, &output_ctx),
         Commands::Search(args) => {
             commands::search::execute(&args, cli.json, &overrides, &output_ctx)
         }
@@ -67,6 +127,24 @@ fn main() {
         Commands::Reopen(args) => {
             commands::reopen::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
+        Commands::Restore(args) => {
+            commands::restore::execute(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Reparent(args) => {
+            commands::reparent::execute_reparent(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Promote(args) => {
+            commands::reparent::execute_promote(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Rename(args) => {
+            commands::rename::execute(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Duplicate(args) => {
+            commands::duplicate::execute_duplicate(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Supersede(args) => {
+            commands::duplicate::execute_supersede(&args, cli.json, &overrides, &output_ctx)
+        }
         Commands::Q(args) => commands::q::execute(args, &overrides, &output_ctx),
         Commands::Dep { command } => {
             commands::dep::execute(&command, cli.json, &overrides, &output_ctx)
@@ -74,6 +152,9 @@ fn main() {
         Commands::Epic { command } => {
             commands::epic::execute(&command, cli.json, &overrides, &output_ctx)
         }
+        Commands::Milestone { command } => {
+            commands::milestone::execute(&command, &overrides, &output_ctx)
+        }
         Commands::Label { command } => {
             commands::label::execute(&command, cli.json, &overrides, &output_ctx)
         }
@@ -81,6 +162,8 @@ fn main() {
         Commands::Stale(args) => commands::stale::execute(&args, &overrides, &output_ctx),
         Commands::Lint(args) => commands::lint::execute(&args, cli.json, &overrides, &output_ctx),
         Commands::Ready(args) => commands::ready::execute(&args, cli.json, &overrides, &output_ctx),
+        Commands::Claim(args) => commands::claim::execute(&args, &overrides, &output_ctx),
+        Commands::Next(args) => commands::next::execute(&args, cli.json, &overrides, &output_ctx),
         Commands::Blocked(args) => {
             commands::blocked::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
@@ -88,12 +171,19 @@ fn main() {
         Commands::Doctor => commands::doctor::execute(&overrides, &output_ctx),
         Commands::Info(args) => commands::info::execute(&args, &overrides, &output_ctx),
         Commands::Schema(args) => commands::schema::execute(&args, &overrides, &output_ctx),
+        Commands::Migrate(args) => commands::migrate::execute(&args, &overrides, &output_ctx),
         Commands::Where => commands::r#where::execute(&overrides, &output_ctx),
         Commands::Version(args) => commands::version::execute(&args, &output_ctx),
 
         #[cfg(feature = "self_update")]
         Commands::Upgrade(args) => commands::upgrade::execute(&args, &output_ctx),
+        #[cfg(feature = "serve")]
+        Commands::Serve(args) => commands::serve::execute(&args, &overrides, &output_ctx),
         Commands::Completions(args) => commands::completions::execute(&args, &output_ctx),
+        Commands::CompleteIds(args) => {
+            commands::completions::execute_complete_ids(&args);
+            Ok(())
+        }
         Commands::Audit { command } => {
             commands::audit::execute(&command, cli.json, &overrides, &output_ctx)
         }
@@ -104,20 +194,52 @@ fn main() {
             commands::config::execute(&command, cli.json, &overrides, &output_ctx)
         }
         Commands::History(args) => commands::history::execute(args, &overrides, &output_ctx),
+        Commands::Diff(args) => {
+            commands::diff::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
+        }
         Commands::Defer(args) => {
             commands::defer::execute_defer(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
         Commands::Undefer(args) => {
             commands::defer::execute_undefer(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
+        Commands::Pin(args) => commands::pin::execute_pin(&args, cli.json, &overrides, &output_ctx),
+        Commands::Unpin(args) => {
+            commands::pin::execute_unpin(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Gc(args) => commands::gc::execute(&args, cli.json, &overrides, &output_ctx),
         Commands::Orphans(args) => {
             commands::orphans::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
         Commands::Changelog(args) => {
             commands::changelog::execute(&args, cli.json || args.robot, &overrides, &output_ctx)
         }
+        Commands::Export(args) => {
+            commands::export::execute(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Report(args) => {
+            commands::report::execute(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Import(args) => {
+            commands::import::execute(&args, cli.json, &overrides, &output_ctx)
+        }
+        Commands::Apply(args) => commands::apply::execute(&args, &overrides, &output_ctx),
         Commands::Query { command } => commands::query::execute(&command, &overrides, &output_ctx),
         Commands::Graph(args) => commands::graph::execute(&args, &overrides, &output_ctx),
+        Commands::WhyBlocked(args) => {
+            commands::why_blocked::execute(&args, &overrides, &output_ctx)
+        }
+        Commands::CriticalPath(args) => {
+            commands::critical_path::execute(&args, &overrides, &output_ctx)
+        }
+        Commands::Impact(args) => commands::impact::execute(&args, &overrides, &output_ctx),
+        Commands::Forecast(args) => commands::forecast::execute(&args, &overrides, &output_ctx),
+        Commands::Activity(args) => commands::activity::execute(&args, &overrides, &output_ctx),
+        Commands::Inbox(args) => commands::inbox::execute(&args, &overrides, &output_ctx),
+        Commands::Msg { command } => commands::msg::execute(&command, &overrides, &output_ctx),
+        Commands::Session { command } => {
+            commands::session::execute(&command, &overrides, &output_ctx)
+        }
         Commands::Agents(args) => {
             let agents_args = commands::agents::AgentsArgs {
                 add: args.add,
@@ -129,17 +251,141 @@ fn main() {
             };
             commands::agents::execute(&agents_args, &output_ctx)
         }
+        Commands::Remote { command } => {
+            commands::remote::execute(&command, cli.json, &overrides, &output_ctx)
+        }
     };
 
+    // In read-only mode a mutating command stops at its first write, after
+    // validating its input; that is the dry run it was turned into
+    if let Err(BeadsError::ReadOnly { operation }) = &result {
+        if is_mutating {
+            report_read_only_dry_run(operation, &output_ctx);
+            return;
+        }
+    }
+
     // Handle command result
     if let Err(e) = result {
-        handle_error(&e, cli.json);
+        // Exiting skips destructors, so release the lock first
+        drop(workspace_lock);
+        handle_error(&e, json_errors);
     }
 
     // Auto-flush after successful mutating commands (unless --no-auto-flush)
-    if is_mutating && !cli.no_auto_flush && !cli.no_db {
+    if is_mutating && !cli.no_auto_flush && !cli.no_db && !read_only {
         run_auto_flush(&overrides);
     }
+
+    // Queue webhooks while the lock keeps other runs' events out, then send
+    // them without holding up those runs
+    let webhooks = notifier.and_then(|notifier| notify::queue_webhooks(notifier, &overrides));
+    drop(workspace_lock);
+    if let Some(queue) = webhooks {
+        notify::send_webhooks(queue);
+    }
+}
+
+/// Commands that write files outside storage (JSONL, snapshots, label
+/// definitions, sessions, remotes, config), so read-only mode cannot turn
+/// them into a dry run; they are refused instead.
+const fn writes_workspace_files(cmd: &Commands) -> Option<&'static str> {
+    use beads_rust::cli::{
+        ConfigCommands, HistoryCommands, LabelCommands, RemoteCommands, SessionCommands,
+    };
+    match cmd {
+        Commands::Init { .. } => Some("init"),
+        Commands::Sync(args) if !args.status => Some("sync"),
+        Commands::Migrate(args) if !args.status => Some("migrate"),
+        Commands::History(args) => match &args.command {
+            Some(HistoryCommands::Restore { dry_run: false, .. }) => Some("history restore"),
+            Some(HistoryCommands::Prune { .. }) => Some("history prune"),
+            _ => None,
+        },
+        Commands::Label {
+            command: LabelCommands::Describe(args),
+        } if args.clear || args.color.is_some() || args.description.is_some() => {
+            Some("label describe")
+        }
+        Commands::Session { command } => match command {
+            SessionCommands::Start(_) => Some("session start"),
+            SessionCommands::End => Some("session end"),
+            _ => None,
+        },
+        Commands::Remote { command } => match command {
+            RemoteCommands::Add(_) => Some("remote add"),
+            RemoteCommands::Remove(_) => Some("remote remove"),
+            RemoteCommands::List => None,
+        },
+        Commands::Config { command } => match command {
+            ConfigCommands::Set { .. } => Some("config set"),
+            ConfigCommands::Delete { .. } => Some("config delete"),
+            ConfigCommands::Edit => Some("config edit"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Report a mutating command that read-only mode stopped before it wrote.
+fn report_read_only_dry_run(operation: &str, output_ctx: &OutputContext) {
+    if output_ctx.is_json() {
+        output_ctx.json_pretty(&serde_json::json!({
+            "dry_run": true,
+            "read_only": true,
+            "operation": operation,
+        }));
+    } else if !output_ctx.is_quiet() {
+        println!("Read-only mode: input is valid; {operation} was not written (dry run).");
+    }
+}
+
+/// Take the workspace lock, or `None` outside a workspace (e.g. before `br init`).
+fn lock_workspace(overrides: &config::CliOverrides) -> Result<Option<LockFile>> {
+    match config::discover_beads_dir_with_cli(overrides) {
+        Ok(beads_dir) => config::lock_workspace_with_cli(&beads_dir, overrides).map(Some),
+        Err(BeadsError::NotInitialized) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Apply the workspace's display settings, if a workspace is found:
+/// `display.color`, `display.time` and its formats, `theme.*` style
+/// overrides and the label colors defined in `labels.jsonl`.
+fn load_display_settings(overrides: &config::CliOverrides, output_ctx: &mut OutputContext) {
+    let Ok(beads_dir) = config::discover_beads_dir_with_cli(overrides) else {
+        return;
+    };
+    let mut theme = Theme::default();
+    match config::load_config(&beads_dir, None, overrides) {
+        Ok(layer) => {
+            if let Some(use_color) = config::display_color_from_layer(&layer) {
+                output_ctx.use_display_color(use_color);
+            }
+            theme = theme.with_overrides(&config::theme_overrides_from_layer(&layer));
+            match config::time_format_from_layer(&layer) {
+                Ok(time_format) => output_ctx.use_time_format(time_format),
+                Err(e) => warn!(error = %e, "Ignoring invalid time display settings"),
+            }
+        }
+        Err(e) => warn!(error = %e, "Ignoring unreadable display settings"),
+    }
+    match config::label_defs::load_label_defs(&beads_dir) {
+        Ok(defs) => theme = theme.with_label_defs(&defs),
+        Err(e) => warn!(error = %e, "Ignoring unreadable label definitions"),
+    }
+    output_ctx.use_theme(theme);
+}
+
+/// Whether a command holds the workspace lock for its whole run: every
+/// mutating command, sync and migrations, and the subcommands that rewrite
+/// workspace files.
+const fn takes_workspace_lock(cmd: &Commands) -> bool {
+    match cmd {
+        Commands::Init { .. } => false,
+        Commands::Sync(_) => true,
+        _ => is_mutating_command(cmd) || writes_workspace_files(cmd).is_some(),
+    }
 }
 
 /// Determine if a command potentially mutates data.
@@ -147,19 +393,43 @@ const fn is_mutating_command(cmd: &Commands) -> bool {
     match cmd {
         Commands::Create(_)
         | Commands::Update(_)
+        | Commands::Claim(_)
         | Commands::Delete(_)
+        | Commands::Restore(_)
+        | Commands::Reparent(_)
+        | Commands::Promote(_)
+        | Commands::Rename(_)
+        | Commands::Duplicate(_)
+        | Commands::Supersede(_)
         | Commands::Close(_)
         | Commands::Reopen(_)
         | Commands::Q(_)
         | Commands::Dep { .. }
         | Commands::Label { .. }
         | Commands::Comments(_)
+        | Commands::React(_)
         | Commands::Defer(_)
-        | Commands::Undefer(_) => true,
+        | Commands::Undefer(_)
+        | Commands::Pin(_)
+        | Commands::Unpin(_)
+        | Commands::Import(_)
+        | Commands::Apply(_)
+        | Commands::Link(_) => true,
+        Commands::Next(args) => args.claim,
+        Commands::Inbox(args) => !args.peek,
+        Commands::Watch(args) => !args.ids.is_empty(),
+        Commands::Gc(args) => !args.dry_run,
+        Commands::Msg { command } => matches!(
+            command,
+            beads_rust::cli::MsgCommands::Send(_) | beads_rust::cli::MsgCommands::Reply(_)
+        ),
         Commands::Epic { command } => matches!(
             command,
             beads_rust::cli::EpicCommands::CloseEligible(args) if !args.dry_run
         ),
+        Commands::Milestone { command } => {
+            !matches!(command, beads_rust::cli::MilestoneCommands::List(_))
+        }
         _ => false,
     }
 }
@@ -182,19 +452,46 @@ const fn should_auto_import(cmd: &Commands) -> bool {
         | Commands::Status(_)
         | Commands::Orphans(_)
         | Commands::Changelog(_)
+        | Commands::Export(_)
+        | Commands::Report(_)
         | Commands::Graph(_)
+        | Commands::WhyBlocked(_)
+        | Commands::CriticalPath(_)
+        | Commands::Impact(_)
+        | Commands::Forecast(_)
+        | Commands::Activity(_)
+        | Commands::Inbox(_)
+        | Commands::Msg { .. }
+        | Commands::Session { .. }
         | Commands::Create(_)
         | Commands::Update(_)
+        | Commands::Claim(_)
+        | Commands::Next(_)
         | Commands::Delete(_)
+        | Commands::Restore(_)
+        | Commands::Reparent(_)
+        | Commands::Promote(_)
+        | Commands::Rename(_)
+        | Commands::Duplicate(_)
+        | Commands::Supersede(_)
         | Commands::Close(_)
         | Commands::Reopen(_)
         | Commands::Q(_)
         | Commands::Defer(_)
         | Commands::Undefer(_)
+        | Commands::Pin(_)
+        | Commands::Unpin(_)
+        | Commands::Gc(_)
+        | Commands::Import(_)
+        | Commands::Apply(_)
         | Commands::Comments(_)
+        | Commands::React(_)
+        | Commands::Link(_)
+        | Commands::Watch(_)
         | Commands::Dep { .. }
         | Commands::Label { .. }
         | Commands::Epic { .. }
+        | Commands::Milestone { .. }
         | Commands::Query { .. } => true,
 
         // Explicitly excluded: init, sync, diagnostic, and config commands
@@ -203,16 +500,23 @@ const fn should_auto_import(cmd: &Commands) -> bool {
         | Commands::Doctor
         | Commands::Info(_)
         | Commands::Schema(_)
+        | Commands::Migrate(_)
         | Commands::Where
         | Commands::Version(_)
         | Commands::Completions(_)
+        | Commands::CompleteIds(_)
         | Commands::Audit { .. }
         | Commands::Config { .. }
         | Commands::History(_)
-        | Commands::Agents(_) => false,
+        | Commands::Diff(_)
+        | Commands::Agents(_)
+        | Commands::Remote { .. } => false,
 
         #[cfg(feature = "self_update")]
         Commands::Upgrade(_) => false,
+        // Start from fresh data; the server does not import while running
+        #[cfg(feature = "serve")]
+        Commands::Serve(_) => true,
     }
 }
 
@@ -233,6 +537,7 @@ fn run_auto_import(
         mut storage,
         paths,
         no_db,
+        ..
     } = config::open_storage_with_cli(&beads_dir, overrides)?;
 
     if no_db {
@@ -241,7 +546,7 @@ fn run_auto_import(
 
     let expected_prefix = storage.get_config("issue_prefix")?;
     let outcome = auto_import_if_stale(
-        &mut storage,
+        storage.sqlite_mut("auto-import")?,
         &paths.beads_dir,
         &paths.jsonl_path,
         expected_prefix.as_deref(),
@@ -289,6 +594,12 @@ fn run_auto_flush(overrides: &config::CliOverrides) {
         }
     };
 
+    // `no-db` in config.yaml saves the JSONL itself, like `--no-db`
+    if config::no_db_with_cli(&beads_dir, overrides).unwrap_or(false) {
+        debug!("Auto-flush skipped: no-db mode");
+        return;
+    }
+
     // Open storage with fresh connection
     let (mut storage, _paths) =
         match config::open_storage(&beads_dir, overrides.db.as_ref(), overrides.lock_timeout) {
@@ -305,8 +616,20 @@ fn run_auto_flush(overrides: &config::CliOverrides) {
             }
         };
 
+    let shard = match config::load_startup_config(&beads_dir)
+        .and_then(|layer| config::shard_strategy_from_layer(&layer))
+    {
+        Ok(shard) => shard,
+        Err(e) => {
+            error!(error = %e, "Auto-flush failed: invalid sync.shard setting");
+            eprintln!("Warning: auto-flush failed: {e}");
+            eprintln!("Your changes are saved locally but NOT exported to JSONL.");
+            return;
+        }
+    };
+
     // Run auto-flush
-    match auto_flush(&mut storage, &beads_dir) {
+    match auto_flush_with_shard(&mut storage, &beads_dir, shard) {
         Ok(result) => {
             if result.flushed {
                 debug!(
@@ -328,18 +651,57 @@ fn run_auto_flush(overrides: &config::CliOverrides) {
     }
 }
 
+/// Print a clap error and exit.
+///
+/// Help and version requests exit 0. Usage errors exit with the validation
+/// code, like any other invalid input.
+fn exit_on_usage_error(err: &clap::Error) -> ! {
+    let _ = err.print();
+    let code = if err.use_stderr() {
+        exit_code::VALIDATION
+    } else {
+        exit_code::SUCCESS
+    };
+    std::process::exit(code);
+}
+
+/// Whether the command asked for machine-readable output with its own
+/// `--robot` flag.
+const fn robot_requested(cmd: &Commands) -> bool {
+    match cmd {
+        Commands::Blocked(args) => args.robot,
+        Commands::Changelog(args) => args.robot,
+        Commands::Close(args) => args.robot,
+        Commands::Defer(args) => args.robot,
+        Commands::Undefer(args) => args.robot,
+        Commands::Diff(args) => args.robot,
+        Commands::Orphans(args) => args.robot,
+        Commands::Ready(args) => args.robot,
+        Commands::Reopen(args) => args.robot,
+        Commands::Stats(args) | Commands::Status(args) => args.robot,
+        Commands::Sync(args) => args.robot,
+        _ => false,
+    }
+}
+
 /// Handle errors with structured output support.
 ///
-/// When --json is set or stdout is not a TTY, outputs structured JSON to stderr.
-/// Otherwise, outputs human-readable error with optional color.
+/// With --json or --robot the structured JSON envelope goes to stdout, where
+/// the command's output would have been, and the plain message to stderr.
+/// When stdout is only not a TTY, the envelope goes to stderr. Otherwise,
+/// outputs human-readable error with optional color.
 fn handle_error(err: &BeadsError, json_mode: bool) -> ! {
     let structured = StructuredError::from_error(err);
     let exit_code = structured.code.exit_code();
 
-    // Determine output mode: JSON if --json flag or stdout is not a terminal
-    let use_json = json_mode || !io::stdout().is_terminal();
-
-    if use_json {
+    if json_mode {
+        let json = structured.to_json();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json).unwrap_or_else(|_| json.to_string())
+        );
+        eprintln!("{}", structured.to_human(false));
+    } else if !io::stdout().is_terminal() {
         // Output structured JSON to stderr
         let json = structured.to_json();
         eprintln!(
@@ -361,13 +723,15 @@ fn build_cli_overrides(cli: &Cli) -> config::CliOverrides {
         actor: cli.actor.clone(),
         identity: None,
         json: Some(cli.json),
-        display_color: if cli.no_color { Some(false) } else { None },
+        display_color: cli.color_override(),
+        absolute_time: Some(cli.absolute),
         quiet: Some(cli.quiet),
         no_db: Some(cli.no_db),
         no_daemon: Some(cli.no_daemon),
         no_auto_flush: Some(cli.no_auto_flush),
         no_auto_import: Some(cli.no_auto_import),
         lock_timeout: cli.lock_timeout,
+        read_only: Some(cli.read_only || config::read_only_from_env()),
     }
 }
 
@@ -397,6 +761,9 @@ mod tests {
             dry_run: false,
             silent: false,
             file: None,
+            update: false,
+            no_defaults: false,
+            allow_duplicate: false,
         }
     }
 
@@ -461,4 +828,34 @@ mod tests {
         assert!(is_mutating_command(&create_cmd));
         assert!(!is_mutating_command(&list_cmd));
     }
+
+    #[test]
+    fn takes_workspace_lock_covers_file_writes() {
+        for args in [
+            &["br", "create", "Locked"][..],
+            &["br", "sync", "--flush-only"],
+            &["br", "migrate", "--rebuild-counts"],
+            &["br", "history", "restore", "20250101_120000"],
+            &["br", "history", "prune"],
+            &["br", "session", "start"],
+            &["br", "remote", "add", "other", "../other"],
+            &["br", "config", "set", "actor=alice"],
+            &["br", "inbox"],
+        ] {
+            let cli = Cli::parse_from(args);
+            assert!(takes_workspace_lock(&cli.command), "{args:?}");
+        }
+        for args in [
+            &["br", "list"][..],
+            &["br", "migrate", "--status"],
+            &["br", "history", "list"],
+            &["br", "session", "list"],
+            &["br", "remote", "list"],
+            &["br", "config", "get", "actor"],
+            &["br", "inbox", "--peek"],
+        ] {
+            let cli = Cli::parse_from(args);
+            assert!(!takes_workspace_lock(&cli.command), "{args:?}");
+        }
+    }
 }
@@ -6,11 +6,14 @@
 //! - `IssueType` - Categories of issues
 //! - `Dependency` - Relationships between issues
 //! - `Comment` - Issue comments
+//! - `Reaction` - Lightweight acknowledgements on issues and comments
+//! - `CodeRef` - Source code locations linked to issues
 //! - `Event` - Audit log entries
 
 use chrono::{DateTime, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::str::FromStr;
 
@@ -133,6 +136,12 @@ impl FromStr for Priority {
     }
 }
 
+/// Issue type of milestones, which group issues into sprints or releases.
+pub const MILESTONE_TYPE: &str = "milestone";
+
+/// Dependency type linking an issue to its milestone.
+pub const MILESTONE_DEP_TYPE: &str = "milestone";
+
 /// Issue type category.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -277,6 +286,119 @@ impl FromStr for DependencyType {
     }
 }
 
+/// When a `conditional-blocks` dependency blocks, evaluated on the blocker.
+///
+/// Stored under the `condition` key of the dependency's metadata, e.g.
+/// `{"condition":"label:release-blocker"}`. A conditional dependency without
+/// a condition blocks like `blocks`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyCondition {
+    /// `label:<name>`: the blocker has this label
+    Label(String),
+    /// `type:<type>`: the blocker has this issue type
+    Type(String),
+    /// `priority:<n>`: the blocker's priority is `n` or more urgent
+    Priority(i32),
+}
+
+impl DependencyCondition {
+    const METADATA_KEY: &'static str = "condition";
+
+    /// The condition in a dependency's metadata, if it has a valid one.
+    #[must_use]
+    pub fn from_metadata(metadata: Option<&str>) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(metadata?).ok()?;
+        value.get(Self::METADATA_KEY)?.as_str()?.parse().ok()
+    }
+
+    /// Dependency metadata holding this condition.
+    #[must_use]
+    pub fn to_metadata(&self) -> String {
+        serde_json::json!({ Self::METADATA_KEY: self.to_string() }).to_string()
+    }
+
+    /// Whether the condition holds for a blocker with these properties.
+    #[must_use]
+    pub fn matches(&self, issue_type: &str, priority: i32, labels: &[String]) -> bool {
+        match self {
+            Self::Label(label) => labels.iter().any(|l| l == label),
+            Self::Type(wanted) => issue_type.eq_ignore_ascii_case(wanted),
+            Self::Priority(max) => priority <= *max,
+        }
+    }
+}
+
+impl fmt::Display for DependencyCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Label(label) => write!(f, "label:{label}"),
+            Self::Type(issue_type) => write!(f, "type:{issue_type}"),
+            Self::Priority(priority) => write!(f, "priority:{priority}"),
+        }
+    }
+}
+
+impl FromStr for DependencyCondition {
+    type Err = crate::error::BeadsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            crate::error::BeadsError::validation(
+                "condition",
+                format!("expected label:<name>, type:<type> or priority:<0-4>, got '{s}'"),
+            )
+        };
+        let (kind, value) = s.split_once(':').ok_or_else(invalid)?;
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(invalid());
+        }
+        match kind.trim().to_lowercase().as_str() {
+            "label" => Ok(Self::Label(value.to_string())),
+            "type" => Ok(Self::Type(value.to_lowercase())),
+            "priority" => value
+                .parse::<Priority>()
+                .map(|priority| Self::Priority(priority.0))
+                .map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Wake time of a `waits-for` dependency.
+///
+/// Stored under the `until` key of the dependency's metadata, e.g.
+/// `{"until":"2025-07-01T09:00:00Z"}`. Once the time has passed the edge no
+/// longer blocks, even if the issue it waits for is still open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WaitUntil(pub DateTime<Utc>);
+
+impl WaitUntil {
+    const METADATA_KEY: &'static str = "until";
+
+    /// The wake time in a dependency's metadata, if it has a valid one.
+    #[must_use]
+    pub fn from_metadata(metadata: Option<&str>) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(metadata?).ok()?;
+        let until = value.get(Self::METADATA_KEY)?.as_str()?;
+        DateTime::parse_from_rfc3339(until)
+            .ok()
+            .map(|until| Self(until.with_timezone(&Utc)))
+    }
+
+    /// Dependency metadata holding this wake time.
+    #[must_use]
+    pub fn to_metadata(self) -> String {
+        serde_json::json!({ Self::METADATA_KEY: self.0.to_rfc3339() }).to_string()
+    }
+
+    /// Whether the wake time is at or before `now`.
+    #[must_use]
+    pub fn has_passed(self, now: DateTime<Utc>) -> bool {
+        self.0 <= now
+    }
+}
+
 /// Audit event type.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EventType {
@@ -500,6 +622,15 @@ pub struct Issue {
     pub dependencies: Vec<Dependency>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub comments: Vec<Comment>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub code_refs: Vec<CodeRef>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub watchers: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub reactions: Vec<Reaction>,
+    /// Custom field values by name, as declared under `fields:` in config
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub fields: BTreeMap<String, String>,
 }
 
 impl Default for Issue {
@@ -544,6 +675,10 @@ impl Default for Issue {
             labels: Vec::new(),
             dependencies: Vec::new(),
             comments: Vec::new(),
+            code_refs: Vec::new(),
+            watchers: Vec::new(),
+            reactions: Vec::new(),
+            fields: BTreeMap::new(),
         }
     }
 }
@@ -633,6 +768,97 @@ pub struct Comment {
     #[serde(rename = "text")]
     pub body: String,
     pub created_at: DateTime<Utc>,
+    /// When the comment was last edited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Soft-deleted comments are kept as tombstones so deletions sync.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub deleted: bool,
+}
+
+/// A reaction (emoji or short tag) left on an issue or one of its comments.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct Reaction {
+    pub issue_id: String,
+    /// Comment the reaction is attached to; `None` for the issue itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<i64>,
+    pub reaction: String,
+    pub actor: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A source code location linked to an issue (`path`, `path:line`, or `path@commit`).
+///
+/// Paths are relative to the workspace root and always use `/` separators.
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, JsonSchema,
+)]
+pub struct CodeRef {
+    pub path: String,
+    /// 1-based line number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// Commit (or other git revision) the reference is pinned to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+}
+
+impl fmt::Display for CodeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.path)?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+        }
+        if let Some(commit) = &self.commit {
+            write!(f, "@{commit}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for CodeRef {
+    type Err = crate::error::BeadsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            |msg: &str| crate::error::BeadsError::validation("code_ref", format!("'{s}': {msg}"));
+
+        let s = s.trim();
+        let (rest, commit) = match s.rsplit_once('@') {
+            Some((rest, commit)) => {
+                if commit.is_empty() || commit.chars().any(char::is_whitespace) {
+                    return Err(invalid("commit must be a non-empty revision"));
+                }
+                (rest, Some(commit.to_string()))
+            }
+            None => (s, None),
+        };
+
+        let (path, line) = match rest.rsplit_once(':') {
+            Some((path, line)) if !line.is_empty() && line.bytes().all(|b| b.is_ascii_digit()) => {
+                let line = line
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|line| *line > 0)
+                    .ok_or_else(|| invalid("line must be a positive number"))?;
+                (path, Some(line))
+            }
+            _ => (rest, None),
+        };
+
+        let path = path.replace('\\', "/");
+        let path = path.trim_start_matches("./").trim_end_matches('/');
+        if path.is_empty() {
+            return Err(invalid("path is empty"));
+        }
+
+        Ok(Self {
+            path: path.to_string(),
+            line,
+            commit,
+        })
+    }
 }
 
 /// An event in the issue's history (audit log).
@@ -649,6 +875,9 @@ pub struct Event {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
     pub created_at: DateTime<Utc>,
+    /// Session that produced the event (agent run, CI job, ...), if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
 }
 
 #[cfg(test)]
@@ -731,6 +960,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         };
 
         let json = serde_json::to_string(&issue).unwrap();
@@ -1078,6 +1311,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dependency_condition_parse_and_match() {
+        let condition: DependencyCondition = "label:release-blocker".parse().unwrap();
+        assert_eq!(
+            condition,
+            DependencyCondition::Label("release-blocker".to_string())
+        );
+        assert!(condition.matches("task", 2, &["release-blocker".to_string()]));
+        assert!(!condition.matches("task", 2, &[]));
+
+        let condition: DependencyCondition = "priority:P1".parse().unwrap();
+        assert!(condition.matches("bug", 0, &[]));
+        assert!(!condition.matches("bug", 2, &[]));
+        assert!(
+            "Type:Bug"
+                .parse::<DependencyCondition>()
+                .unwrap()
+                .matches("bug", 2, &[])
+        );
+
+        for bad in ["label", "label:", "priority:7", "owner:alice"] {
+            assert!(bad.parse::<DependencyCondition>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_dependency_condition_metadata_roundtrip() {
+        let condition = DependencyCondition::Type("epic".to_string());
+        let metadata = condition.to_metadata();
+        assert_eq!(metadata, r#"{"condition":"type:epic"}"#);
+        assert_eq!(
+            DependencyCondition::from_metadata(Some(&metadata)),
+            Some(condition)
+        );
+        assert_eq!(DependencyCondition::from_metadata(Some("{}")), None);
+        assert_eq!(DependencyCondition::from_metadata(Some("not json")), None);
+        assert_eq!(DependencyCondition::from_metadata(None), None);
+    }
+
+    #[test]
+    fn test_wait_until_metadata_roundtrip() {
+        let until = WaitUntil(
+            DateTime::parse_from_rfc3339("2025-07-01T09:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        );
+        let metadata = until.to_metadata();
+        assert_eq!(metadata, r#"{"until":"2025-07-01T09:00:00+00:00"}"#);
+        assert_eq!(WaitUntil::from_metadata(Some(&metadata)), Some(until));
+        assert_eq!(WaitUntil::from_metadata(Some(r#"{"until":"soon"}"#)), None);
+        assert_eq!(WaitUntil::from_metadata(None), None);
+        assert!(until.has_passed(until.0));
+        assert!(!until.has_passed(until.0 - chrono::Duration::seconds(1)));
+    }
+
     #[test]
     fn test_dependency_type_from_str_custom() {
         let result = DependencyType::from_str("my-custom-dep").unwrap();
@@ -1186,6 +1474,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -1406,6 +1698,8 @@ mod tests {
             author: "testuser".to_string(),
             body: "This is a comment".to_string(),
             created_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            updated_at: None,
+            deleted: false,
         };
 
         let json = serde_json::to_string(&comment).unwrap();
@@ -1421,6 +1715,27 @@ mod tests {
         assert_eq!(comment.body, "comment body");
     }
 
+    // ========================================================================
+    // CODE REF TESTS
+    // ========================================================================
+
+    #[test]
+    fn test_code_ref_parse_forms() {
+        let plain: CodeRef = "./src/foo.rs".parse().unwrap();
+        assert_eq!(plain.path, "src/foo.rs");
+        assert_eq!(plain.line, None);
+        assert_eq!(plain.commit, None);
+
+        let full: CodeRef = "src/foo.rs:42@abc123".parse().unwrap();
+        assert_eq!(full.line, Some(42));
+        assert_eq!(full.commit.as_deref(), Some("abc123"));
+        assert_eq!(full.to_string(), "src/foo.rs:42@abc123");
+
+        assert!("src/foo.rs:0".parse::<CodeRef>().is_err());
+        assert!(":12".parse::<CodeRef>().is_err());
+        assert!("src/foo.rs@".parse::<CodeRef>().is_err());
+    }
+
     // ========================================================================
     // DEPENDENCY TESTS
     // ========================================================================
@@ -1465,6 +1780,7 @@ mod tests {
             new_value: Some("closed".to_string()),
             comment: None,
             created_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            session_id: Some("sess-1".to_string()),
         };
 
         let json = serde_json::to_string(&event).unwrap();
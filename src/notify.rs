@@ -0,0 +1,508 @@
+//! Webhook notifications for mutations.
+//!
+//! When `webhooks.urls` is set, a mutating command notes the newest event ID
+//! before it runs ([`Notifier::start`]). When it is done,
+//! [`Notifier::finish`] queues the events the command recorded whose types
+//! are in `webhooks.events`, while the workspace lock still keeps other runs'
+//! events out; after the lock is released, [`WebhookQueue::flush`] POSTs each
+//! as JSON to every URL, with one thread per URL. Failed attempts are retried
+//! with backoff, but the whole flush is bounded by `webhooks.deadline_ms`
+//! (default 2s), so a dead endpoint costs a command at most that long. A
+//! delivery that fails, or is still pending at the deadline, is appended to
+//! `.beads/webhooks-dead.jsonl` with its payload, so nothing is silently lost.
+//!
+//! Webhook settings are read only from user config and the environment:
+//! payloads carry full issues, and a cloned repository must not be able to
+//! send them to a host of its choosing. There is no TLS client, so only
+//! plain `http://` URLs to a loopback host (`localhost`, `127.0.0.0/8`,
+//! `::1`) are accepted: payloads never cross the network unencrypted, and
+//! other endpoints are reached through a local relay.
+
+use crate::config::{self, CliOverrides, WebhookConfig};
+use crate::error::Result;
+use chrono::Utc;
+use serde_json::{Value, json};
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{IpAddr, TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// Undelivered webhooks, one JSON object per line, inside `.beads/`.
+pub const DEAD_LETTER_FILE: &str = "webhooks-dead.jsonl";
+
+/// Set to any value to skip webhooks, e.g. in `--plan` dry runs.
+pub const DISABLE_ENV: &str = "BEADS_NO_WEBHOOKS";
+
+/// Delay before the first retry; doubled for each later one.
+const RETRY_BACKOFF: Duration = Duration::from_millis(250);
+
+/// How long past the deadline to wait for results that are already on their
+/// way back from the delivery threads.
+const DEADLINE_GRACE: Duration = Duration::from_millis(50);
+
+/// Why a delivery still pending at the deadline was dead-lettered.
+const DEADLINE_ERROR: &str = "webhooks.deadline_ms exceeded";
+
+/// Watches one command's events and sends them when it finishes.
+#[derive(Debug)]
+pub struct Notifier {
+    config: WebhookConfig,
+    beads_dir: PathBuf,
+    /// Newest event ID before the command ran
+    after_event_id: i64,
+}
+
+/// Webhook payloads waiting to be sent, with their event types.
+#[derive(Debug)]
+pub struct WebhookQueue {
+    config: WebhookConfig,
+    beads_dir: PathBuf,
+    payloads: Vec<(String, Value)>,
+}
+
+/// What [`WebhookQueue::flush`] sent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FlushReport {
+    pub delivered: usize,
+    pub dead_lettered: usize,
+}
+
+/// One POST to one URL.
+#[derive(Debug, Clone)]
+struct Delivery<'a> {
+    url: &'a str,
+    event_type: &'a str,
+    payload: &'a Value,
+}
+
+impl Notifier {
+    /// Start watching, or `None` if no webhooks are configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the webhook settings are invalid or the database
+    /// cannot be read.
+    pub fn start(beads_dir: &Path, cli: &CliOverrides) -> Result<Option<Self>> {
+        if std::env::var_os(DISABLE_ENV).is_some() {
+            return Ok(None);
+        }
+        let layer = config::load_user_startup_config(beads_dir)?;
+        let webhooks = config::webhooks_from_layer(&layer)?;
+        if webhooks.urls.is_empty() {
+            return Ok(None);
+        }
+        let storage = config::open_storage_with_cli(beads_dir, cli)?.storage;
+        Ok(Some(Self {
+            config: webhooks,
+            beads_dir: beads_dir.to_path_buf(),
+            after_event_id: storage.max_event_id()?,
+        }))
+    }
+
+    /// Queue the events recorded since [`Notifier::start`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the events cannot be read.
+    pub fn finish(self, cli: &CliOverrides) -> Result<WebhookQueue> {
+        let storage = config::open_storage_with_cli(&self.beads_dir, cli)?.storage;
+        let mut payloads = Vec::new();
+        for event in storage.get_events_after(self.after_event_id)? {
+            if !self
+                .config
+                .events
+                .iter()
+                .any(|wanted| wanted == event.event_type.as_str())
+            {
+                continue;
+            }
+            // The event, the issue as it is now, and who watches it
+            let issue = storage.get_issue(&event.issue_id)?;
+            let watchers = storage.get_watchers(&event.issue_id)?;
+            let payload = json!({ "event": event, "issue": issue, "watchers": watchers });
+            payloads.push((event.event_type.as_str().to_string(), payload));
+        }
+        Ok(WebhookQueue {
+            config: self.config,
+            beads_dir: self.beads_dir,
+            payloads,
+        })
+    }
+}
+
+impl WebhookQueue {
+    /// Send every queued payload to every URL, waiting no longer than
+    /// `webhooks.deadline_ms`.
+    ///
+    /// Delivery threads are detached: one stuck past the deadline (say, in a
+    /// DNS lookup) is abandoned with its deliveries dead-lettered, and a late
+    /// success may then be delivered and dead-lettered both.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dead-letter file cannot be written; failed
+    /// deliveries themselves are not errors.
+    pub fn flush(self) -> Result<FlushReport> {
+        if self.payloads.is_empty() {
+            return Ok(FlushReport::default());
+        }
+        debug!(events = self.payloads.len(), "Sending webhooks");
+
+        let deadline = Instant::now() + self.config.deadline;
+        let payloads = Arc::new(self.payloads);
+        let config = Arc::new(self.config);
+        let (sender, receiver) = mpsc::channel();
+        for (url_index, url) in config.urls.iter().enumerate() {
+            let (sender, payloads, config, url) = (
+                sender.clone(),
+                Arc::clone(&payloads),
+                Arc::clone(&config),
+                url.clone(),
+            );
+            thread::spawn(move || {
+                for (payload_index, (event_type, payload)) in payloads.iter().enumerate() {
+                    let delivery = Delivery {
+                        url: &url,
+                        event_type,
+                        payload,
+                    };
+                    let result = deliver(&delivery, &config, deadline);
+                    if sender.send((url_index, payload_index, result)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Everything starts out failed; finished deliveries replace that
+        let mut outcomes: Vec<Vec<std::result::Result<(), String>>> =
+            vec![vec![Err(DEADLINE_ERROR.to_string()); payloads.len()]; config.urls.len()];
+        let mut pending = payloads.len() * config.urls.len();
+        while pending > 0 {
+            let wait = (deadline + DEADLINE_GRACE).saturating_duration_since(Instant::now());
+            let Ok((url_index, payload_index, result)) = receiver.recv_timeout(wait) else {
+                break;
+            };
+            outcomes[url_index][payload_index] = result;
+            pending -= 1;
+        }
+
+        let failures: Vec<(&str, &Value, &str)> = config
+            .urls
+            .iter()
+            .zip(&outcomes)
+            .flat_map(|(url, results)| {
+                results
+                    .iter()
+                    .zip(payloads.iter())
+                    .filter_map(move |(result, (_, payload))| {
+                        result
+                            .as_ref()
+                            .err()
+                            .map(|error| (url.as_str(), payload, error.as_str()))
+                    })
+            })
+            .collect();
+        let report = FlushReport {
+            delivered: payloads.len() * config.urls.len() - failures.len(),
+            dead_lettered: failures.len(),
+        };
+        if !failures.is_empty() {
+            let path = self.beads_dir.join(DEAD_LETTER_FILE);
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            for (url, payload, error) in failures {
+                warn!(url, error, "Webhook delivery failed");
+                let entry = json!({
+                    "url": url,
+                    "error": error,
+                    "attempts": config.retries + 1,
+                    "failed_at": Utc::now(),
+                    "payload": payload,
+                });
+                writeln!(file, "{entry}")?;
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// POST a delivery, retrying with backoff until `deadline`.
+fn deliver(
+    delivery: &Delivery<'_>,
+    config: &WebhookConfig,
+    deadline: Instant,
+) -> std::result::Result<(), String> {
+    let body = delivery.payload.to_string();
+    let mut backoff = RETRY_BACKOFF;
+    let mut attempt = 0;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(DEADLINE_ERROR.to_string());
+        }
+        let timeout = config.timeout.min(remaining);
+        match post(delivery.url, delivery.event_type, &body, timeout) {
+            Ok(()) => return Ok(()),
+            Err(error) if attempt >= config.retries || Instant::now() + backoff >= deadline => {
+                return Err(error);
+            }
+            Err(error) => {
+                debug!(url = delivery.url, %error, attempt, "Retrying webhook");
+                thread::sleep(backoff);
+                backoff *= 2;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Send one POST and require a 2xx status.
+fn post(
+    url: &str,
+    event_type: &str,
+    body: &str,
+    timeout: Duration,
+) -> std::result::Result<(), String> {
+    let (host, port, path) = parse_url(url)?;
+    let addr = (host, port)
+        .to_socket_addrs()
+        .map_err(|e| format!("cannot resolve {host}: {e}"))?
+        .next()
+        .ok_or_else(|| format!("cannot resolve {host}"))?;
+    let authority = if host.contains(':') {
+        format!("[{host}]:{port}")
+    } else {
+        format!("{host}:{port}")
+    };
+    let mut stream =
+        TcpStream::connect_timeout(&addr, timeout).map_err(|e| format!("connect failed: {e}"))?;
+    let io_error = |e: std::io::Error| format!("request failed: {e}");
+    stream.set_read_timeout(Some(timeout)).map_err(io_error)?;
+    stream.set_write_timeout(Some(timeout)).map_err(io_error)?;
+    // One write, so a receiver that reads once sees the whole request
+    let request = format!(
+        "POST {path} HTTP/1.1\r\nHost: {authority}\r\nUser-Agent: br/{}\r\n\
+         Content-Type: application/json\r\nX-Beads-Event: {event_type}\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        env!("CARGO_PKG_VERSION"),
+        body.len()
+    );
+    stream.write_all(request.as_bytes()).map_err(io_error)?;
+
+    let mut status_line = String::new();
+    BufReader::new(&stream)
+        .read_line(&mut status_line)
+        .map_err(io_error)?;
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| format!("invalid response: {}", status_line.trim()))?;
+    if (200..300).contains(&status) {
+        Ok(())
+    } else {
+        Err(format!("HTTP {status}"))
+    }
+}
+
+/// Start watching for webhook events in `beads_dir`. Webhook problems never
+/// fail the write they report on, so they are only warned about.
+#[must_use]
+pub fn start_notifier(beads_dir: &Path, cli: &CliOverrides) -> Option<Notifier> {
+    Notifier::start(beads_dir, cli).unwrap_or_else(|e| {
+        warn!(error = %e, "Webhooks disabled for this write");
+        eprintln!("Warning: webhooks disabled: {e}");
+        None
+    })
+}
+
+/// Collect the write's events for the configured webhooks.
+#[must_use]
+pub fn queue_webhooks(notifier: Notifier, cli: &CliOverrides) -> Option<WebhookQueue> {
+    notifier
+        .finish(cli)
+        .map_err(|e| {
+            warn!(error = %e, "Could not read events for webhooks");
+            eprintln!("Warning: webhooks not sent: {e}");
+        })
+        .ok()
+}
+
+/// Send queued webhooks; failed deliveries are dead-lettered and reported.
+pub fn send_webhooks(queue: WebhookQueue) {
+    match queue.flush() {
+        Ok(report) if report.dead_lettered > 0 => {
+            warn!(failed = report.dead_lettered, "Webhook deliveries failed");
+            eprintln!(
+                "Warning: {} webhook deliveries failed; see .beads/{DEAD_LETTER_FILE}",
+                report.dead_lettered
+            );
+        }
+        Ok(report) => debug!(delivered = report.delivered, "Webhooks sent"),
+        Err(e) => {
+            warn!(error = %e, "Webhook delivery failed");
+            eprintln!("Warning: webhooks not sent: {e}");
+        }
+    }
+}
+
+/// Split an `http://host[:port][/path]` URL, requiring a loopback host.
+///
+/// # Errors
+///
+/// Returns a message if the URL is not `http://`, is malformed, or names a
+/// host other than `localhost`, `127.0.0.0/8` or `::1`.
+pub fn parse_url(url: &str) -> std::result::Result<(&str, u16, &str), String> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("not an http:// URL: {url}"))?;
+    let (authority, path) = rest
+        .find('/')
+        .map_or((rest, "/"), |slash| (&rest[..slash], &rest[slash..]));
+    // An IPv6 literal is bracketed, and its colons are not the port's
+    let (host, port) = match authority.strip_prefix('[') {
+        Some(bracketed) => {
+            let (host, after) = bracketed
+                .split_once(']')
+                .ok_or_else(|| format!("invalid host in {url}"))?;
+            (host, after.strip_prefix(':'))
+        }
+        None => match authority.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        },
+    };
+    let port = match port {
+        Some(port) => port.parse().map_err(|_| format!("invalid port in {url}"))?,
+        None => 80,
+    };
+    if host.is_empty() {
+        return Err(format!("missing host in {url}"));
+    }
+    if !is_loopback(host) {
+        return Err(format!(
+            "{host} is not a loopback host; plain http is only sent to localhost: {url}"
+        ));
+    }
+    Ok((host, port, path))
+}
+
+/// Whether `host` is `localhost` or a loopback IP address.
+fn is_loopback(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost")
+        || host.parse::<IpAddr>().is_ok_and(|addr| addr.is_loopback())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(
+            parse_url("http://localhost:9000/hooks/beads"),
+            Ok(("localhost", 9000, "/hooks/beads"))
+        );
+        assert_eq!(parse_url("http://127.0.0.1"), Ok(("127.0.0.1", 80, "/")));
+        assert_eq!(
+            parse_url("http://[::1]:9000/hook"),
+            Ok(("::1", 9000, "/hook"))
+        );
+        assert!(parse_url("https://localhost").is_err());
+        assert!(parse_url("http://:80/").is_err());
+        assert!(parse_url("http://[::1/").is_err());
+    }
+
+    #[test]
+    fn test_parse_url_refuses_remote_hosts() {
+        assert!(parse_url("http://example.com/hook").is_err());
+        assert!(parse_url("http://10.0.0.1:9000/").is_err());
+        assert!(parse_url("http://[2001:db8::1]/").is_err());
+        assert!(parse_url("http://localhost.example.com/").is_err());
+    }
+
+    #[test]
+    fn test_deliver_retries_until_success() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut bodies = Vec::new();
+            for status in ["500 Internal Server Error", "204 No Content"] {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = vec![0; 4096];
+                let n = stream.read(&mut request).unwrap();
+                bodies.push(String::from_utf8_lossy(&request[..n]).to_string());
+                write!(stream, "HTTP/1.1 {status}\r\nContent-Length: 0\r\n\r\n").unwrap();
+            }
+            bodies
+        });
+
+        let payload = json!({ "event": { "event_type": "created" } });
+        let config = WebhookConfig {
+            urls: vec![url.clone()],
+            retries: 2,
+            ..WebhookConfig::default()
+        };
+        let delivery = Delivery {
+            url: &url,
+            event_type: "created",
+            payload: &payload,
+        };
+        let later = Instant::now() + Duration::from_secs(30);
+        assert_eq!(deliver(&delivery, &config, later), Ok(()));
+        let requests = server.join().unwrap();
+        assert!(requests[1].starts_with("POST /hook HTTP/1.1"));
+        assert!(requests[1].contains("X-Beads-Event: created"));
+
+        let config = WebhookConfig {
+            retries: 0,
+            ..config
+        };
+        assert!(deliver(&delivery, &config, later).is_err());
+    }
+
+    #[test]
+    fn test_flush_stops_at_deadline() {
+        // Accepts connections but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let streams: Vec<_> = listener.incoming().collect();
+            drop(streams);
+        });
+
+        let beads_dir = tempfile::TempDir::new().unwrap();
+        let queue = WebhookQueue {
+            config: WebhookConfig {
+                urls: vec![url],
+                timeout: Duration::from_secs(10),
+                deadline: Duration::from_millis(200),
+                ..WebhookConfig::default()
+            },
+            beads_dir: beads_dir.path().to_path_buf(),
+            payloads: (0..3)
+                .map(|n| ("created".to_string(), json!({ "n": n })))
+                .collect(),
+        };
+
+        let started = Instant::now();
+        let report = queue.flush().unwrap();
+        assert!(started.elapsed() < Duration::from_secs(2), "flush overran");
+        assert_eq!(
+            report,
+            FlushReport {
+                delivered: 0,
+                dead_lettered: 3,
+            }
+        );
+        let dead = std::fs::read_to_string(beads_dir.path().join(DEAD_LETTER_FILE)).unwrap();
+        assert_eq!(dead.lines().count(), 3);
+    }
+}
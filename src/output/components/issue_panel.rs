@@ -1,6 +1,7 @@
-use crate::format::{IssueDetails, IssueWithDependencyMetadata};
+use crate::format::{IssueBacklink, IssueDetails, IssueWithDependencyMetadata};
 use crate::model::{Comment, Dependency, Issue};
 use crate::output::{OutputContext, Theme};
+use chrono::Utc;
 use rich_rust::prelude::*;
 
 /// Renders a single issue with full details in a styled panel.
@@ -56,6 +57,7 @@ impl<'a> IssuePanel<'a> {
         self
     }
 
+    #[allow(clippy::too_many_lines)]
     pub fn print(&self, ctx: &OutputContext, wrap: bool) {
         let mut content = Text::new("");
 
@@ -97,6 +99,12 @@ impl<'a> IssuePanel<'a> {
             content.append_styled(&format!("{}\n", assignee), self.theme.username.clone());
         }
 
+        // Parent
+        if let Some(parent) = self.details.and_then(|d| d.parent.as_ref()) {
+            content.append_styled("Parent:   ", self.theme.dimmed.clone());
+            content.append_styled(&format!("{parent}\n"), self.theme.issue_id.clone());
+        }
+
         // Labels
         let labels = self
             .details
@@ -107,24 +115,74 @@ impl<'a> IssuePanel<'a> {
                 if i > 0 {
                     content.append(", ");
                 }
-                content.append_styled(label, self.theme.label.clone());
+                content.append_styled(label, self.theme.label_style(label));
             }
             content.append("\n");
         }
 
+        // Reactions
+        if let Some(reactions) = self.details.and_then(|d| d.reaction_summary(None)) {
+            content.append_styled("Reactions: ", self.theme.dimmed.clone());
+            content.append(&format!("{reactions}\n"));
+        }
+
+        // Watchers
+        if !self.issue.watchers.is_empty() {
+            content.append_styled("Watchers: ", self.theme.dimmed.clone());
+            content.append(&format!("{}\n", self.issue.watchers.join(", ")));
+        }
+
+        // Custom fields
+        if !self.issue.fields.is_empty() {
+            let fields: Vec<String> = self
+                .issue
+                .fields
+                .iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect();
+            content.append_styled("Fields: ", self.theme.dimmed.clone());
+            content.append(&format!("{}\n", fields.join(", ")));
+        }
+
         // Timestamps
+        let time = ctx.time_format();
+        let now = Utc::now();
         content.append_styled("Created:  ", self.theme.dimmed.clone());
         content.append_styled(
-            &format!("{}\n", self.issue.created_at.format("%Y-%m-%d %H:%M")),
+            &format!("{}\n", time.datetime(self.issue.created_at, now)),
             self.theme.timestamp.clone(),
         );
 
         content.append_styled("Updated:  ", self.theme.dimmed.clone());
         content.append_styled(
-            &format!("{}\n", self.issue.updated_at.format("%Y-%m-%d %H:%M")),
+            &format!("{}\n", time.datetime(self.issue.updated_at, now)),
             self.theme.timestamp.clone(),
         );
 
+        if let Some(due) = self.issue.due_at {
+            content.append_styled("Due:      ", self.theme.dimmed.clone());
+            content.append_styled(
+                &format!("{}\n", time.date(due, now)),
+                self.theme.timestamp.clone(),
+            );
+        }
+
+        // Duplicate / supersede links
+        if let Some(details) = self.details {
+            for (label, id) in details.replacement_links() {
+                content.append_styled(&format!("{label}: "), self.theme.dimmed.clone());
+                content.append_styled(&format!("{id}\n"), self.theme.issue_id.clone());
+            }
+        }
+
+        // Code refs
+        if !self.issue.code_refs.is_empty() {
+            content.append_styled("\nCode:\n", self.theme.emphasis.clone());
+            for code_ref in &self.issue.code_refs {
+                content.append(&format!("  {code_ref}\n"));
+            }
+        }
+
         // Dependencies / Dependents
         if self.show_dependencies {
             if let Some(details) = self.details {
@@ -142,6 +200,7 @@ impl<'a> IssuePanel<'a> {
         if self.show_dependents {
             if let Some(details) = self.details {
                 render_dependency_list("Dependents", &details.dependents, &mut content, self.theme);
+                render_backlinks(&details.referenced_by, &mut content, self.theme);
             }
         }
 
@@ -154,7 +213,7 @@ impl<'a> IssuePanel<'a> {
             for comment in comments {
                 content.append("  ");
                 content.append_styled(
-                    &comment.created_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+                    &time.datetime(comment.created_at, now),
                     self.theme.timestamp.clone(),
                 );
                 content.append(" ");
@@ -162,6 +221,35 @@ impl<'a> IssuePanel<'a> {
                 content.append_styled(": ", self.theme.dimmed.clone());
                 content.append_styled(&comment.body, self.theme.comment.clone());
                 content.append("\n");
+                if let Some(reactions) = self
+                    .details
+                    .and_then(|d| d.reaction_summary(Some(comment.id)))
+                {
+                    content.append_styled(&format!("    {reactions}\n"), self.theme.dimmed.clone());
+                }
+            }
+        }
+
+        // Recent history (only fetched with `show --events`)
+        if let Some(details) = self.details.filter(|d| !d.events.is_empty()) {
+            content.append_styled("\nHistory:\n", self.theme.emphasis.clone());
+            for event in &details.events {
+                content.append("  ");
+                content.append_styled(
+                    &time.datetime(event.created_at, now),
+                    self.theme.timestamp.clone(),
+                );
+                content.append(" ");
+                content.append_styled(&event.actor, self.theme.username.clone());
+                content.append(&format!(" {}", event.event_type.as_str()));
+                if let Some(new) = &event.new_value {
+                    let change = event
+                        .old_value
+                        .as_ref()
+                        .map_or_else(|| format!(": {new}"), |old| format!(": {old} → {new}"));
+                    content.append_styled(&change, self.theme.dimmed.clone());
+                }
+                content.append("\n");
             }
         }
 
@@ -225,6 +313,30 @@ fn render_dependency_list(
     }
 }
 
+fn render_backlinks(backlinks: &[IssueBacklink], content: &mut Text, theme: &Theme) {
+    if backlinks.is_empty() {
+        return;
+    }
+
+    content.append_styled(
+        "\n───────────────────────────────────\n",
+        theme.dimmed.clone(),
+    );
+    content.append_styled("Referenced by:\n", theme.emphasis.clone());
+    for backlink in backlinks {
+        content.append_styled("  ← ", theme.dimmed.clone());
+        content.append_styled(&backlink.id, theme.issue_id.clone());
+        content.append(" ");
+        content.append_styled(
+            &format!("[{}]", backlink.status.as_str()),
+            theme.status_style(&backlink.status),
+        );
+        content.append(" ");
+        content.append_styled(&backlink.title, theme.issue_title.clone());
+        content.append("\n");
+    }
+}
+
 fn render_dependency_refs(deps: &[Dependency], content: &mut Text, theme: &Theme) {
     if deps.is_empty() {
         return;
@@ -1,6 +1,8 @@
+use crate::format::time::TimeFormat;
 use crate::format::truncate_title;
 use crate::model::Issue;
 use crate::output::Theme;
+use chrono::Utc;
 use regex::{Regex, RegexBuilder};
 use rich_rust::prelude::*;
 use rich_rust::renderables::Cell;
@@ -12,10 +14,11 @@ pub struct IssueTable<'a> {
     theme: &'a Theme,
     columns: IssueTableColumns,
     title: Option<String>,
-    highlight_query: Option<String>,
+    highlight: Option<Regex>,
     context_snippets: Option<HashMap<String, String>>,
     width: Option<usize>,
     wrap: bool,
+    time_format: TimeFormat,
 }
 
 #[derive(Default, Clone)]
@@ -83,10 +86,11 @@ impl<'a> IssueTable<'a> {
             theme,
             columns: IssueTableColumns::standard(),
             title: None,
-            highlight_query: None,
+            highlight: None,
             context_snippets: None,
             width: None,
             wrap: false,
+            time_format: TimeFormat::default(),
         }
     }
 
@@ -102,6 +106,13 @@ impl<'a> IssueTable<'a> {
         self
     }
 
+    /// How the created/updated columns render dates.
+    #[must_use]
+    pub fn time_format(mut self, time_format: TimeFormat) -> Self {
+        self.time_format = time_format;
+        self
+    }
+
     #[must_use]
     pub fn columns(mut self, columns: IssueTableColumns) -> Self {
         self.columns = columns;
@@ -114,15 +125,23 @@ impl<'a> IssueTable<'a> {
         self
     }
 
+    /// Highlight case-insensitive occurrences of `query`.
     #[must_use]
     pub fn highlight_query(mut self, query: impl Into<String>) -> Self {
         let query = query.into();
-        if !query.trim().is_empty() {
-            self.highlight_query = Some(query);
+        if let Some(regex) = build_highlight_regex(&query) {
+            self.highlight = Some(regex);
         }
         self
     }
 
+    /// Highlight matches of `regex` (for regex or case-sensitive searches).
+    #[must_use]
+    pub fn highlight_regex(mut self, regex: Regex) -> Self {
+        self.highlight = Some(regex);
+        self
+    }
+
     #[must_use]
     pub fn context_snippets(mut self, snippets: HashMap<String, String>) -> Self {
         if !snippets.is_empty() {
@@ -134,10 +153,7 @@ impl<'a> IssueTable<'a> {
     #[must_use]
     #[allow(clippy::too_many_lines)]
     pub fn build(&self) -> Table {
-        let highlight_regex = self
-            .highlight_query
-            .as_deref()
-            .and_then(build_highlight_regex);
+        let highlight_regex = self.highlight.as_ref();
 
         // Reserve ~100 chars for other columns (conservative) or min 60.
         let title_max_width = self.width.map_or(60, |w| w.saturating_sub(100).max(60));
@@ -178,16 +194,17 @@ impl<'a> IssueTable<'a> {
             table = table.with_column(Column::new("Labels").max_width(30));
         }
         if self.columns.created {
-            table = table.with_column(Column::new("Created").width(10));
+            table = table.with_column(Column::new("Created").min_width(10));
         }
         if self.columns.updated {
-            table = table.with_column(Column::new("Updated").width(10));
+            table = table.with_column(Column::new("Updated").min_width(10));
         }
         if self.columns.context {
             table = table.with_column(Column::new("Context").min_width(20).max_width(60));
         }
 
         // Add rows
+        let now = Utc::now();
         for issue in self.issues {
             let mut cells: Vec<Cell> = vec![];
 
@@ -218,7 +235,7 @@ impl<'a> IssueTable<'a> {
                 } else {
                     truncate_title(&issue.title, title_max_width)
                 };
-                let title_text = highlight_text(&title, highlight_regex.as_ref(), self.theme);
+                let title_text = highlight_text(&title, highlight_regex, self.theme);
                 cells.push(Cell::new(title_text).style(self.theme.issue_title.clone()));
             }
             if self.columns.assignee {
@@ -228,19 +245,24 @@ impl<'a> IssueTable<'a> {
                 );
             }
             if self.columns.labels {
-                cells.push(
-                    Cell::new(Text::new(issue.labels.join(", "))).style(self.theme.label.clone()),
-                );
+                let mut labels = Text::new("");
+                for (i, label) in issue.labels.iter().enumerate() {
+                    if i > 0 {
+                        labels.append(", ");
+                    }
+                    labels.append_styled(label, self.theme.label_style(label));
+                }
+                cells.push(Cell::new(labels));
             }
             if self.columns.created {
                 cells.push(
-                    Cell::new(Text::new(issue.created_at.format("%Y-%m-%d").to_string()))
+                    Cell::new(Text::new(self.time_format.date(issue.created_at, now)))
                         .style(self.theme.timestamp.clone()),
                 );
             }
             if self.columns.updated {
                 cells.push(
-                    Cell::new(Text::new(issue.updated_at.format("%Y-%m-%d").to_string()))
+                    Cell::new(Text::new(self.time_format.date(issue.updated_at, now)))
                         .style(self.theme.timestamp.clone()),
                 );
             }
@@ -250,7 +272,7 @@ impl<'a> IssueTable<'a> {
                     .as_ref()
                     .and_then(|snippets| snippets.get(&issue.id))
                     .map_or("", String::as_str);
-                let snippet_text = highlight_text(snippet, highlight_regex.as_ref(), self.theme);
+                let snippet_text = highlight_text(snippet, highlight_regex, self.theme);
                 cells.push(Cell::new(snippet_text).style(self.theme.muted.clone()));
             }
 
@@ -1,5 +1,7 @@
 use super::Theme;
 use crate::cli::{Cli, OutputFormat};
+use crate::config;
+use crate::format::time::TimeFormat;
 use rich_rust::prelude::*;
 use rich_rust::renderables::Renderable;
 use std::io::{self, IsTerminal, Write};
@@ -20,6 +22,11 @@ pub struct OutputContext {
     console: OnceLock<Console>,
     /// Theme for consistent styling (lazy)
     theme: OnceLock<Theme>,
+    /// Color was forced on (`--color always`), so keep ANSI codes even
+    /// when stdout is not a terminal
+    force_color: bool,
+    /// How text output renders timestamps (`--absolute`, `display.time`)
+    time_format: TimeFormat,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -48,6 +55,12 @@ impl OutputContext {
             width: OnceLock::new(),
             console: OnceLock::new(),
             theme: OnceLock::new(),
+            force_color: args.color_override() == Some(true),
+            time_format: if args.absolute {
+                TimeFormat::absolute()
+            } else {
+                TimeFormat::default()
+            },
         }
     }
 
@@ -61,8 +74,7 @@ impl OutputContext {
             OutputMode::Json
         } else if quiet {
             OutputMode::Quiet
-        } else if no_color || std::env::var("NO_COLOR").is_ok() || !std::io::stdout().is_terminal()
-        {
+        } else if no_color || !config::auto_color() {
             OutputMode::Plain
         } else {
             OutputMode::Rich
@@ -73,25 +85,30 @@ impl OutputContext {
             width: OnceLock::new(),
             console: OnceLock::new(),
             theme: OnceLock::new(),
+            force_color: false,
+            time_format: TimeFormat::default(),
         }
     }
 
     /// Create from an explicit output format.
+    ///
+    /// `use_color` is the resolved preference from
+    /// [`config::should_use_color`], so `--color`, `display.color` and
+    /// `NO_COLOR` apply the same way as for the global context.
     #[must_use]
-    pub fn from_output_format(format: OutputFormat, quiet: bool, no_color: bool) -> Self {
+    pub fn from_output_format(format: OutputFormat, quiet: bool, use_color: bool) -> Self {
         let mode = match format {
             OutputFormat::Json => OutputMode::Json,
             OutputFormat::Toon => OutputMode::Toon,
+            OutputFormat::Table if quiet => OutputMode::Quiet,
+            OutputFormat::Table => OutputMode::Plain,
             OutputFormat::Text | OutputFormat::Csv => {
                 if quiet {
                     OutputMode::Quiet
-                } else if no_color
-                    || std::env::var("NO_COLOR").is_ok()
-                    || !std::io::stdout().is_terminal()
-                {
-                    OutputMode::Plain
-                } else {
+                } else if use_color {
                     OutputMode::Rich
+                } else {
+                    OutputMode::Plain
                 }
             }
         };
@@ -101,7 +118,21 @@ impl OutputContext {
             width: OnceLock::new(),
             console: OnceLock::new(),
             theme: OnceLock::new(),
+            force_color: use_color && !std::io::stdout().is_terminal(),
+            time_format: TimeFormat::default(),
+        }
+    }
+
+    /// Like [`Self::from_output_format`], keeping this context's theme and
+    /// time format.
+    #[must_use]
+    pub fn with_output_format(&self, format: OutputFormat, quiet: bool, use_color: bool) -> Self {
+        let mut ctx = Self::from_output_format(format, quiet, use_color);
+        ctx.time_format.clone_from(&self.time_format);
+        if let Some(theme) = self.theme.get() {
+            ctx.use_theme(theme.clone());
         }
+        ctx
     }
 
     fn detect_mode(args: &Cli) -> OutputMode {
@@ -111,18 +142,41 @@ impl OutputContext {
         if args.quiet {
             return OutputMode::Quiet;
         }
-        if args.no_color || std::env::var("NO_COLOR").is_ok() {
-            return OutputMode::Plain;
+        match args.color_override() {
+            Some(true) => OutputMode::Rich,
+            Some(false) => OutputMode::Plain,
+            None if config::auto_color() => OutputMode::Rich,
+            None => OutputMode::Plain,
         }
-        if !std::io::stdout().is_terminal() {
-            return OutputMode::Plain;
+    }
+
+    /// Apply `display.color` from config when no `--color`/`--no-color`
+    /// decided it. Only switches between rich and plain output.
+    pub fn use_display_color(&mut self, use_color: bool) {
+        if matches!(self.mode, OutputMode::Rich | OutputMode::Plain) {
+            self.mode = if use_color {
+                OutputMode::Rich
+            } else {
+                OutputMode::Plain
+            };
+            self.force_color = use_color && !std::io::stdout().is_terminal();
         }
-        OutputMode::Rich
+    }
+
+    /// Render timestamps with `time_format` (`display.time` and its formats).
+    pub fn use_time_format(&mut self, time_format: TimeFormat) {
+        self.time_format = time_format;
+    }
+
+    /// How text output renders timestamps.
+    pub const fn time_format(&self) -> &TimeFormat {
+        &self.time_format
     }
 
     /// Lazily create console based on mode.
     fn console(&self) -> &Console {
         self.console.get_or_init(|| match self.mode {
+            OutputMode::Rich if self.force_color => Console::builder().force_terminal(true).build(),
             OutputMode::Rich => Console::new(),
             OutputMode::Plain | OutputMode::Quiet | OutputMode::Json | OutputMode::Toon => {
                 Console::builder().no_color().force_terminal(false).build()
@@ -165,6 +219,13 @@ impl OutputContext {
         self.theme.get_or_init(Theme::default)
     }
 
+    /// Style with `theme` (config overrides and label colors applied).
+    ///
+    /// Has no effect once the theme has been initialized.
+    pub fn use_theme(&self, theme: Theme) {
+        let _ = self.theme.set(theme);
+    }
+
     // ─────────────────────────────────────────────────────────────
     // Output Methods
     // ─────────────────────────────────────────────────────────────
@@ -202,6 +263,33 @@ impl OutputContext {
         }
     }
 
+    /// Output values as JSON Lines (one compact object per line).
+    ///
+    /// Used by `--stream` so callers can emit results batch by batch without
+    /// materializing the full result set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if serialization fails (e.g., non-string map keys, recursive structures).
+    pub fn json_lines<T: serde::Serialize>(&self, values: &[T]) {
+        if self.is_json() {
+            let stdout = io::stdout();
+            let mut out = io::BufWriter::new(stdout.lock());
+            for value in values {
+                if let Err(err) = serde_json::to_writer(&mut out, value) {
+                    assert!(
+                        err.is_io(),
+                        "JSON serialization failed - value is not serializable"
+                    );
+                    return;
+                }
+                if out.write_all(b"\n").is_err() {
+                    return;
+                }
+            }
+        }
+    }
+
     /// # Panics
     ///
     /// Panics if serialization fails (e.g., non-string map keys, recursive structures).
@@ -9,9 +9,13 @@
 //!
 //! 1. `--json` or `--robot` flags → **JSON mode** (machine-readable)
 //! 2. `--quiet` flag → **Quiet mode** (minimal output)
-//! 3. `NO_COLOR` env or `--no-color` → **Plain mode** (no ANSI codes)
-//! 4. Non-TTY stdout → **Plain mode** (piped output)
-//! 5. Otherwise → **Rich mode** (colors, tables, panels)
+//! 3. `--color always|never` (or `--no-color`) → **Rich** / **Plain mode**
+//! 4. `display.color` in config → **Rich** / **Plain mode**
+//! 5. Non-empty `NO_COLOR`, `TERM=dumb` or non-TTY stdout → **Plain mode**
+//! 6. Otherwise → **Rich mode** (colors, tables, panels)
+//!
+//! The theme starts from [`Theme::default`], with `theme.<role>` overrides
+//! from config and label colors from `labels.jsonl` applied at startup.
 //!
 //! ## Usage
 //!
@@ -43,7 +47,7 @@
 //! ## Design Principles
 //!
 //! - **Zero overhead in JSON/Quiet modes**: Console and theme are lazy-initialized
-//! - **Automatic mode detection**: No manual configuration needed
+//! - **Automatic mode detection**: No configuration needed, `--color` to override
 //! - **Graceful degradation**: Rich → Plain → JSON → Quiet fallback chain
 //! - **Consistent styling**: Theme provides unified look across commands
 
@@ -1,8 +1,11 @@
 //! Theme and color definitions for rich output.
 
+use crate::config::label_defs::LabelDef;
 use crate::model::{IssueType, Priority, Status};
 use rich_rust::r#box::ROUNDED;
 use rich_rust::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use tracing::warn;
 
 fn color(name: &str) -> Color {
     Color::parse(name).unwrap_or_else(|_| {
@@ -11,6 +14,21 @@ fn color(name: &str) -> Color {
     })
 }
 
+/// Parse a style like `bold red` or `dim #808080`: any of `bold`, `dim`
+/// and `italic` plus at most one color. `None` if a word isn't understood.
+fn parse_style(spec: &str) -> Option<Style> {
+    let mut style = Style::new();
+    for word in spec.split_whitespace() {
+        style = match word.to_lowercase().as_str() {
+            "bold" => style.bold(),
+            "dim" => style.dim(),
+            "italic" => style.italic(),
+            name => style.color(Color::parse(name).ok()?),
+        };
+    }
+    Some(style)
+}
+
 #[derive(Debug, Clone)]
 pub struct Theme {
     pub success: Style,
@@ -56,6 +74,8 @@ pub struct Theme {
     pub timestamp: Style,
     pub username: Style,
     pub comment: Style,
+    /// Per-label colors from `labels.jsonl`, overriding `label`
+    pub label_colors: HashMap<String, Style>,
 
     pub box_style: &'static BoxChars,
 }
@@ -106,6 +126,7 @@ impl Default for Theme {
             timestamp: Style::new().color(color("bright_black")),
             username: Style::new().color(color("green")),
             comment: Style::new().italic(),
+            label_colors: HashMap::new(),
 
             box_style: &ROUNDED,
         }
@@ -150,4 +171,117 @@ impl Theme {
             IssueType::Custom(_) => self.muted.clone(),
         }
     }
+
+    /// Style for a label: its defined color if any, otherwise `label`.
+    #[must_use]
+    pub fn label_style(&self, label: &str) -> Style {
+        self.label_colors
+            .get(label)
+            .cloned()
+            .unwrap_or_else(|| self.label.clone())
+    }
+
+    /// Apply `theme.<role>` style overrides from config, e.g.
+    /// `issue_id: "bold magenta"`. Unknown roles and unparseable styles are
+    /// skipped with a warning.
+    #[must_use]
+    pub fn with_overrides(mut self, overrides: &BTreeMap<String, String>) -> Self {
+        for (role, spec) in overrides {
+            let Some(style) = parse_style(spec) else {
+                warn!(role, style = %spec, "Ignoring unparseable theme style");
+                continue;
+            };
+            match self.role_mut(role) {
+                Some(slot) => *slot = style,
+                None => warn!(role, "Ignoring unknown theme role"),
+            }
+        }
+        self
+    }
+
+    fn role_mut(&mut self, role: &str) -> Option<&mut Style> {
+        let slot = match role {
+            "success" => &mut self.success,
+            "error" => &mut self.error,
+            "warning" => &mut self.warning,
+            "info" => &mut self.info,
+            "dimmed" => &mut self.dimmed,
+            "accent" => &mut self.accent,
+            "highlight" => &mut self.highlight,
+            "muted" => &mut self.muted,
+            "emphasis" => &mut self.emphasis,
+            "issue_id" => &mut self.issue_id,
+            "issue_title" => &mut self.issue_title,
+            "issue_description" => &mut self.issue_description,
+            "status_open" => &mut self.status_open,
+            "status_in_progress" => &mut self.status_in_progress,
+            "status_blocked" => &mut self.status_blocked,
+            "status_deferred" => &mut self.status_deferred,
+            "status_closed" => &mut self.status_closed,
+            "priority_critical" => &mut self.priority_critical,
+            "priority_high" => &mut self.priority_high,
+            "priority_medium" => &mut self.priority_medium,
+            "priority_low" => &mut self.priority_low,
+            "priority_backlog" => &mut self.priority_backlog,
+            "type_task" => &mut self.type_task,
+            "type_bug" => &mut self.type_bug,
+            "type_feature" => &mut self.type_feature,
+            "type_epic" => &mut self.type_epic,
+            "type_chore" => &mut self.type_chore,
+            "type_docs" => &mut self.type_docs,
+            "type_question" => &mut self.type_question,
+            "table_header" => &mut self.table_header,
+            "table_border" => &mut self.table_border,
+            "panel_title" => &mut self.panel_title,
+            "panel_border" => &mut self.panel_border,
+            "section" => &mut self.section,
+            "label" => &mut self.label,
+            "timestamp" => &mut self.timestamp,
+            "username" => &mut self.username,
+            "comment" => &mut self.comment,
+            _ => return None,
+        };
+        Some(slot)
+    }
+
+    /// Apply colors from label definitions. Unparseable colors are ignored.
+    #[must_use]
+    pub fn with_label_defs(mut self, defs: &[LabelDef]) -> Self {
+        for def in defs {
+            if let Some(parsed) = def.color.as_deref().and_then(|c| Color::parse(c).ok()) {
+                self.label_colors
+                    .insert(def.label.clone(), Style::new().color(parsed));
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_style() {
+        let parsed = parse_style("bold  magenta").unwrap();
+        let expected = Style::new().bold().color(color("magenta"));
+        assert_eq!(format!("{parsed:?}"), format!("{expected:?}"));
+        assert!(parse_style("bold sparkly").is_none());
+    }
+
+    #[test]
+    fn test_with_overrides_skips_bad_entries() {
+        let overrides = BTreeMap::from([
+            ("issue_id".to_string(), "italic green".to_string()),
+            ("status_open".to_string(), "not-a-color".to_string()),
+            ("no_such_role".to_string(), "red".to_string()),
+        ]);
+        let theme = Theme::default().with_overrides(&overrides);
+        let expected = Style::new().italic().color(color("green"));
+        assert_eq!(format!("{:?}", theme.issue_id), format!("{expected:?}"));
+        assert_eq!(
+            format!("{:?}", theme.status_open),
+            format!("{:?}", Theme::default().status_open)
+        );
+    }
 }
@@ -0,0 +1,225 @@
+//! Minimal HTTP/1.1 request parsing and response writing.
+//!
+//! Only what the API needs: one request per connection, bodies sized by
+//! `Content-Length`, and JSON responses closed after writing.
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+/// Largest accepted request body.
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+/// Largest accepted request line or header line.
+const MAX_LINE_BYTES: usize = 8 * 1024;
+
+/// A parsed request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    /// Path without the query string, percent-decoded.
+    pub path: String,
+    /// Query parameters, percent-decoded; later duplicates win.
+    pub query: BTreeMap<String, String>,
+    /// Header names are lowercased.
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+impl Request {
+    /// Read one request from `stream`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidData` if the request is malformed or too large, or any
+    /// I/O error from the stream.
+    pub fn read_from(stream: impl Read) -> io::Result<Self> {
+        let mut reader = BufReader::new(stream);
+        let request_line = read_line(&mut reader)?;
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+            return Err(invalid("malformed request line"));
+        };
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+        let mut headers = BTreeMap::new();
+        loop {
+            let line = read_line(&mut reader)?;
+            if line.is_empty() {
+                break;
+            }
+            let Some((name, value)) = line.split_once(':') else {
+                return Err(invalid("malformed header"));
+            };
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+
+        let length = match headers.get("content-length") {
+            Some(value) => value
+                .parse::<usize>()
+                .map_err(|_| invalid("invalid Content-Length"))?,
+            None => 0,
+        };
+        if length > MAX_BODY_BYTES {
+            return Err(invalid("request body too large"));
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+
+        Ok(Self {
+            method: method.to_ascii_uppercase(),
+            path: percent_decode(path),
+            query: parse_query(query),
+            headers,
+            body: String::from_utf8(body).map_err(|_| invalid("body is not UTF-8"))?,
+        })
+    }
+
+    /// The token from an `Authorization: Bearer <token>` header.
+    #[must_use]
+    pub fn bearer_token(&self) -> Option<&str> {
+        self.headers
+            .get("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+    }
+
+    /// Path segments, without empty ones (`/issues/bd-1/` → `["issues", "bd-1"]`).
+    #[must_use]
+    pub fn segments(&self) -> Vec<&str> {
+        self.path.split('/').filter(|s| !s.is_empty()).collect()
+    }
+}
+
+/// A JSON response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    /// A response with `value` serialized as the body.
+    #[must_use]
+    pub fn json(status: u16, value: &impl serde::Serialize) -> Self {
+        Self {
+            status,
+            body: serde_json::to_string_pretty(value).unwrap_or_else(|_| "null".to_string()),
+        }
+    }
+
+    /// An error response in the CLI's structured error shape.
+    #[must_use]
+    pub fn error(status: u16, code: &str, message: &str) -> Self {
+        Self::json(
+            status,
+            &serde_json::json!({ "error": { "code": code, "message": message } }),
+        )
+    }
+
+    /// Write the response and its headers to `stream`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from the stream.
+    pub fn write_to(&self, mut stream: impl Write) -> io::Result<()> {
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason_phrase(self.status),
+            self.body.len(),
+            self.body
+        )?;
+        stream.flush()
+    }
+}
+
+fn read_line(reader: &mut impl BufRead) -> io::Result<String> {
+    let mut line = Vec::new();
+    reader
+        .take(MAX_LINE_BYTES as u64 + 1)
+        .read_until(b'\n', &mut line)?;
+    if line.len() > MAX_LINE_BYTES {
+        return Err(invalid("header line too long"));
+    }
+    if !line.ends_with(b"\n") {
+        return Err(invalid("connection closed mid-request"));
+    }
+    let line = String::from_utf8(line).map_err(|_| invalid("header is not UTF-8"))?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn parse_query(query: &str) -> BTreeMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect()
+}
+
+/// Decode `%XX` escapes and `+` (as a space); invalid escapes are kept as is.
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], escaped) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+            (b'+', _) => decoded.push(b' '),
+            (byte, _) => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+const fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        409 => "Conflict",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_parses_query_headers_and_body() {
+        let raw = "PATCH /issues/bd-1?status=in_progress&title=a%20b+c HTTP/1.1\r\n\
+                   Host: localhost\r\nAuthorization: Bearer s3cret\r\nContent-Length: 2\r\n\r\n{}";
+        let request = Request::read_from(raw.as_bytes()).unwrap();
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(request.segments(), vec!["issues", "bd-1"]);
+        assert_eq!(request.query["status"], "in_progress");
+        assert_eq!(request.query["title"], "a b c");
+        assert_eq!(request.bearer_token(), Some("s3cret"));
+        assert_eq!(request.body, "{}");
+
+        assert!(Request::read_from("GET\r\n\r\n".as_bytes()).is_err());
+        assert!(
+            Request::read_from("GET / HTTP/1.1\r\nContent-Length: 9\r\n\r\n{}".as_bytes()).is_err()
+        );
+        assert_eq!(percent_decode("100%"), "100%");
+    }
+}
@@ -0,0 +1,615 @@
+//! HTTP API server (`br serve`).
+//!
+//! Serves a small JSON API on `127.0.0.1` so editors and dashboards can read
+//! and change issues without starting a `br` process per request:
+//!
+//! | Method | Path | Does |
+//! |--------|------|------|
+//! | `GET` | `/issues` | List issues; query keys as in `@filter:` plus `limit` |
+//! | `POST` | `/issues` | Create an issue from a JSON object (as `br create -`) |
+//! | `GET` | `/ready` | Ready issues; optional `limit` |
+//! | `GET` | `/issues/{id}` | Issue details with labels, dependencies and comments |
+//! | `PATCH` | `/issues/{id}` | Update fields |
+//! | `POST` | `/issues/{id}/dependencies` | Add `{"depends_on": ..., "type": ...}` |
+//! | `DELETE` | `/issues/{id}/dependencies/{other}` | Remove a dependency |
+//! | `GET`/`POST` | `/issues/{id}/comments` | List comments / add `{"text": ...}` |
+//!
+//! Every request must carry `Authorization: Bearer <token>`. Each connection
+//! is handled on its own thread with a fresh storage connection, so a slow
+//! client or webhook endpoint never holds up the others. Writes take the
+//! workspace lock (and so still run one at a time) and go through the same
+//! config and checks as the CLI: type defaults, duplicate checks, policies,
+//! blockers, `close.children` and `auto_close_duplicates`. They flush to
+//! JSONL before responding and send webhooks after responding, like a CLI
+//! run. Errors use the CLI's structured error body; a close that `br close`
+//! would skip is refused with 409.
+
+pub mod http;
+
+use crate::cli::commands::close::{
+    cascaded_close_reason, check_close_policies, close_refusal, close_targets,
+};
+use crate::cli::commands::create::{CreateConfig, create_issues_from_json, find_open_duplicates};
+use crate::cli::commands::dep::{add_dependency_checked, dep_metadata, parse_dep_type};
+use crate::cli::commands::update::{check_update_policies, closed_at_for};
+use crate::cli::selection::parse_filter;
+use crate::config::{self, CliOverrides, CloseChildren, DuplicateCheck, OpenStorageResult};
+use crate::error::{BeadsError, ErrorCode, Result, StructuredError};
+use crate::model::{IssueType, Priority, Status};
+use crate::notify::{WebhookQueue, queue_webhooks, send_webhooks, start_notifier};
+use crate::storage::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, Storage};
+use crate::util::id::{IdResolver, ResolverConfig};
+use crate::validation::PolicyRule;
+use chrono::Utc;
+use http::{Request, Response};
+use serde::Deserialize;
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A bound API server.
+#[derive(Debug)]
+pub struct Server {
+    listener: TcpListener,
+    token: String,
+    beads_dir: PathBuf,
+    cli: CliOverrides,
+}
+
+impl Server {
+    /// Bind to `127.0.0.1:port` (0 picks a free port).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the port cannot be bound.
+    pub fn bind(port: u16, token: String, beads_dir: PathBuf, cli: CliOverrides) -> Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        Ok(Self {
+            listener,
+            token,
+            beads_dir,
+            cli,
+        })
+    }
+
+    /// The bound address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket cannot report its address.
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Serve requests until the process is stopped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if the listener fails; failed connections are
+    /// logged and skipped.
+    pub fn run(&self) -> Result<()> {
+        thread::scope(|scope| {
+            for stream in self.listener.incoming() {
+                match stream {
+                    Ok(stream) => {
+                        scope.spawn(move || {
+                            if let Err(e) = self.handle_connection(&stream) {
+                                warn!(error = %e, "Dropped API connection");
+                            }
+                        });
+                    }
+                    Err(e) => warn!(error = %e, "Failed to accept API connection"),
+                }
+            }
+        });
+        Ok(())
+    }
+
+    fn handle_connection(&self, stream: &TcpStream) -> std::io::Result<()> {
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        let (response, webhooks) = match Request::read_from(stream) {
+            Ok(request) => self.respond_queued(&request),
+            Err(e) => (Response::error(400, "BAD_REQUEST", &e.to_string()), None),
+        };
+        let written = response.write_to(stream);
+        // The client has its answer; a slow endpoint only holds this worker
+        if let Some(queue) = webhooks {
+            send_webhooks(queue);
+        }
+        written
+    }
+
+    /// Authenticate and route one request, sending its webhooks.
+    #[must_use]
+    pub fn respond(&self, request: &Request) -> Response {
+        let (response, webhooks) = self.respond_queued(request);
+        if let Some(queue) = webhooks {
+            send_webhooks(queue);
+        }
+        response
+    }
+
+    /// Authenticate and route one request; webhooks for its writes are
+    /// returned unsent.
+    fn respond_queued(&self, request: &Request) -> (Response, Option<WebhookQueue>) {
+        debug!(method = %request.method, path = %request.path, "API request");
+        if !request
+            .bearer_token()
+            .is_some_and(|token| tokens_match(token, &self.token))
+        {
+            let response = Response::error(401, "UNAUTHORIZED", "missing or invalid bearer token");
+            return (response, None);
+        }
+        match self.route(request) {
+            Ok(routed) => routed,
+            Err(e) => (error_response(&e), None),
+        }
+    }
+
+    fn route(&self, request: &Request) -> Result<(Response, Option<WebhookQueue>)> {
+        let segments = request.segments();
+        let Some(route) = Route::parse(&request.method, &segments) else {
+            let response = Response::error(
+                404,
+                "ROUTE_NOT_FOUND",
+                &format!("no route for {} {}", request.method, request.path),
+            );
+            return Ok((response, None));
+        };
+
+        let writes = route.writes() && !self.cli.read_only.unwrap_or(false);
+        let lock = if writes {
+            Some(config::lock_workspace_with_cli(&self.beads_dir, &self.cli)?)
+        } else {
+            None
+        };
+        let mut ctx = config::open_storage_with_cli(&self.beads_dir, &self.cli)?;
+        // Mark where this request's events begin, as a CLI write does
+        let notifier = if writes && !ctx.no_db {
+            start_notifier(&self.beads_dir, &self.cli)
+        } else {
+            None
+        };
+        let api = Api::new(&self.beads_dir, &ctx, &self.cli)?;
+        let storage = &mut *ctx.storage;
+        let response = match route {
+            Route::List => list_issues(storage, request),
+            Route::Create => api.create(storage, request),
+            Route::Ready => ready_issues(storage, request),
+            Route::Show(id) => api.show(storage, id),
+            Route::Update(id) => api.update(storage, id, request),
+            Route::AddDependency(id) => api.add_dependency(storage, id, request),
+            Route::RemoveDependency(id, other) => api.remove_dependency(storage, id, other),
+            Route::Comments(id) => api.comments(storage, id),
+            Route::AddComment(id) => api.add_comment(storage, id, request),
+        }?;
+        if route.writes() {
+            flush(&mut ctx, &self.beads_dir)?;
+        }
+        // Queue while the lock keeps other writers' events out
+        let webhooks = notifier.and_then(|notifier| queue_webhooks(notifier, &self.cli));
+        drop(lock);
+        Ok((response, webhooks))
+    }
+}
+
+/// An API endpoint, with the IDs from its path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Route<'a> {
+    List,
+    Create,
+    Ready,
+    Show(&'a str),
+    Update(&'a str),
+    AddDependency(&'a str),
+    RemoveDependency(&'a str, &'a str),
+    Comments(&'a str),
+    AddComment(&'a str),
+}
+
+impl<'a> Route<'a> {
+    fn parse(method: &str, segments: &[&'a str]) -> Option<Self> {
+        Some(match (method, segments) {
+            ("GET", ["issues"]) => Self::List,
+            ("POST", ["issues"]) => Self::Create,
+            ("GET", ["ready"]) => Self::Ready,
+            ("GET", ["issues", id]) => Self::Show(*id),
+            ("PATCH", ["issues", id]) => Self::Update(*id),
+            ("POST", ["issues", id, "dependencies"]) => Self::AddDependency(*id),
+            ("DELETE", ["issues", id, "dependencies", other]) => {
+                Self::RemoveDependency(*id, *other)
+            }
+            ("GET", ["issues", id, "comments"]) => Self::Comments(*id),
+            ("POST", ["issues", id, "comments"]) => Self::AddComment(*id),
+            _ => return None,
+        })
+    }
+
+    const fn writes(self) -> bool {
+        !matches!(
+            self,
+            Self::List | Self::Ready | Self::Show(_) | Self::Comments(_)
+        )
+    }
+}
+
+/// Request handlers, with the workspace settings they share.
+struct Api {
+    resolver: IdResolver,
+    actor: String,
+    create_config: CreateConfig,
+    policy_rules: Vec<PolicyRule>,
+    close_children: CloseChildren,
+    auto_close_duplicates: bool,
+}
+
+/// Body of `PATCH /issues/{id}`; absent fields are left alone, `null` clears.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct UpdateBody {
+    title: Option<String>,
+    #[serde(deserialize_with = "present")]
+    description: Option<Option<String>>,
+    #[serde(deserialize_with = "present")]
+    design: Option<Option<String>>,
+    #[serde(deserialize_with = "present")]
+    acceptance_criteria: Option<Option<String>>,
+    #[serde(deserialize_with = "present")]
+    notes: Option<Option<String>>,
+    status: Option<Status>,
+    priority: Option<Priority>,
+    issue_type: Option<IssueType>,
+    #[serde(deserialize_with = "present")]
+    assignee: Option<Option<String>>,
+    #[serde(deserialize_with = "present")]
+    close_reason: Option<Option<String>>,
+}
+
+/// Body of `POST /issues/{id}/dependencies`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DependencyBody {
+    depends_on: String,
+    #[serde(default = "default_dep_type", rename = "type")]
+    dep_type: String,
+    /// As `br dep add --condition` (`conditional-blocks` only)
+    condition: Option<String>,
+    /// As `br dep add --until` (`waits-for` only)
+    until: Option<String>,
+}
+
+/// Body of `POST /issues/{id}/comments`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CommentBody {
+    text: String,
+    author: Option<String>,
+}
+
+fn default_dep_type() -> String {
+    "blocks".to_string()
+}
+
+/// Deserialize a present field, so `null` becomes `Some(None)`.
+#[allow(clippy::option_option)]
+fn present<'de, D, T>(deserializer: D) -> std::result::Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+impl Api {
+    fn new(beads_dir: &Path, ctx: &OpenStorageResult, cli: &CliOverrides) -> Result<Self> {
+        let layer = config::load_config(beads_dir, Some(&*ctx.storage), cli)?;
+        let create_config = CreateConfig::from_layer(&layer)?;
+        let resolver = IdResolver::new(
+            ResolverConfig::with_prefix(create_config.id_config.prefix.clone())
+                .with_aliases(ctx.storage.get_id_aliases()?),
+        );
+        Ok(Self {
+            resolver,
+            actor: create_config.actor.clone(),
+            create_config,
+            policy_rules: config::policy_rules_from_layer(&layer)?,
+            close_children: config::close_children_from_layer(&layer)?,
+            auto_close_duplicates: config::auto_close_duplicates_from_layer(&layer),
+        })
+    }
+
+    fn resolve(&self, storage: &dyn Storage, input: &str) -> Result<String> {
+        self.resolver
+            .resolve(
+                input,
+                |id| storage.id_exists(id).unwrap_or(false),
+                |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+            )
+            .map(|resolved| resolved.id)
+    }
+
+    fn show(&self, storage: &dyn Storage, id: &str) -> Result<Response> {
+        let id = self.resolve(storage, id)?;
+        let details = storage
+            .get_issue_details(&id, true, false, 0)?
+            .ok_or(BeadsError::IssueNotFound { id })?;
+        Ok(Response::json(200, &details))
+    }
+
+    fn create(&self, storage: &mut dyn Storage, request: &Request) -> Result<Response> {
+        if !request.body.trim_start().starts_with('{') {
+            return Err(BeadsError::validation("body", "expected an issue object"));
+        }
+        let ids = create_issues_from_json(storage, &request.body, &self.create_config)?;
+        let id = ids.into_iter().next().unwrap_or_default();
+        let details = storage
+            .get_issue_details(&id, true, false, 0)?
+            .ok_or(BeadsError::IssueNotFound { id })?;
+        let mut body = serde_json::to_value(&details)?;
+        // In block mode the create has already refused any duplicates
+        if self.create_config.duplicate_check == DuplicateCheck::Warn {
+            let duplicates = find_open_duplicates(storage, &details.issue)?;
+            if !duplicates.is_empty() {
+                body["possible_duplicates"] = serde_json::to_value(&duplicates)?;
+            }
+        }
+        Ok(Response::json(201, &body))
+    }
+
+    fn update(&self, storage: &mut dyn Storage, id: &str, request: &Request) -> Result<Response> {
+        let body: UpdateBody = parse_body(request)?;
+        if body.title.as_deref().is_some_and(|t| t.trim().is_empty()) {
+            return Err(BeadsError::validation("title", "cannot be empty"));
+        }
+        if let Some(priority) = body.priority {
+            if !(0..=4).contains(&priority.0) {
+                return Err(BeadsError::validation("priority", "must be 0-4"));
+            }
+        }
+        let mut update = IssueUpdate {
+            title: body.title,
+            description: body.description,
+            design: body.design,
+            acceptance_criteria: body.acceptance_criteria,
+            notes: body.notes,
+            closed_at: closed_at_for(body.status.as_ref()),
+            status: body.status,
+            priority: body.priority,
+            issue_type: body.issue_type,
+            assignee: body.assignee,
+            close_reason: body.close_reason,
+            ..IssueUpdate::default()
+        };
+        if update.is_empty() {
+            return Err(BeadsError::validation("body", "no fields to update"));
+        }
+        let id = self.resolve(storage, id)?;
+        let before = storage
+            .get_issue(&id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+
+        // Closing follows `br close`: blockers and `close.children: block`
+        // refuse it, `close.children: cascade` closes open descendants first,
+        // and the reason defaults to "done"
+        let closing = update.status == Some(Status::Closed) && !before.status.is_terminal();
+        let given_reason = update.close_reason.clone().flatten();
+        let mut cascaded = Vec::new();
+        if closing {
+            if let Some(reason) = close_refusal(storage, &id, self.close_children)? {
+                return Err(BeadsError::NothingToDo {
+                    reason: format!("{id} not closed: {reason}"),
+                });
+            }
+            let cascade = self.close_children == CloseChildren::Cascade;
+            cascaded = close_targets(storage, std::slice::from_ref(&id), cascade)?
+                .into_iter()
+                .filter_map(|(child, parent)| parent.map(|_| child))
+                .collect();
+            if given_reason.is_none() {
+                update.close_reason = Some(Some("done".to_string()));
+            }
+        }
+
+        if !self.policy_rules.is_empty() {
+            let labels = storage.get_labels(&id)?;
+            check_update_policies(&before, &update, labels, &self.policy_rules)?;
+            let children: Vec<&str> = cascaded.iter().map(String::as_str).collect();
+            check_close_policies(storage, &children, &self.policy_rules)?;
+        }
+
+        for child in &cascaded {
+            // As `br close --cascade`, a child that cannot close stays open
+            if close_refusal(storage, child, self.close_children)?.is_some() {
+                continue;
+            }
+            let close = IssueUpdate {
+                status: Some(Status::Closed),
+                closed_at: Some(Some(Utc::now())),
+                close_reason: Some(Some(cascaded_close_reason(given_reason.as_deref(), &id))),
+                ..IssueUpdate::default()
+            };
+            storage.update_issue(child, &close, &self.actor)?;
+        }
+        let issue = storage.update_issue(&id, &update, &self.actor)?;
+        Ok(Response::json(200, &issue))
+    }
+
+    fn add_dependency(
+        &self,
+        storage: &mut dyn Storage,
+        id: &str,
+        request: &Request,
+    ) -> Result<Response> {
+        let body: DependencyBody = parse_body(request)?;
+        let dep_type = parse_dep_type(&body.dep_type)?;
+        let metadata = dep_metadata(
+            body.condition.as_deref(),
+            body.until.as_deref(),
+            None,
+            &dep_type,
+        )?;
+        let id = self.resolve(storage, id)?;
+        // External dependencies don't need resolution
+        let depends_on = if body.depends_on.starts_with("external:") {
+            body.depends_on
+        } else {
+            self.resolve(storage, &body.depends_on)?
+        };
+        let (added, closed) = add_dependency_checked(
+            storage,
+            &id,
+            &depends_on,
+            &dep_type,
+            metadata.as_deref(),
+            &self.actor,
+            self.auto_close_duplicates,
+        )?;
+        Ok(Response::json(
+            if added { 201 } else { 200 },
+            &serde_json::json!({
+                "issue_id": id,
+                "depends_on_id": depends_on,
+                "type": dep_type.as_str(),
+                "added": added,
+                "closed": closed,
+            }),
+        ))
+    }
+
+    fn remove_dependency(
+        &self,
+        storage: &mut dyn Storage,
+        id: &str,
+        other: &str,
+    ) -> Result<Response> {
+        let id = self.resolve(storage, id)?;
+        let depends_on = self.resolve(storage, other)?;
+        let removed = storage.remove_dependency(&id, &depends_on, &self.actor)?;
+        Ok(Response::json(
+            200,
+            &serde_json::json!({
+                "issue_id": id,
+                "depends_on_id": depends_on,
+                "removed": removed,
+            }),
+        ))
+    }
+
+    fn comments(&self, storage: &dyn Storage, id: &str) -> Result<Response> {
+        let id = self.resolve(storage, id)?;
+        let comments = storage.get_comments(&id)?;
+        Ok(Response::json(200, &comments))
+    }
+
+    fn add_comment(
+        &self,
+        storage: &mut dyn Storage,
+        id: &str,
+        request: &Request,
+    ) -> Result<Response> {
+        let body: CommentBody = parse_body(request)?;
+        if body.text.trim().is_empty() {
+            return Err(BeadsError::validation("text", "cannot be empty"));
+        }
+        let id = self.resolve(storage, id)?;
+        let author = body.author.unwrap_or_else(|| self.actor.clone());
+        let comment = storage.add_comment(&id, &author, &body.text)?;
+        Ok(Response::json(201, &comment))
+    }
+}
+
+fn list_issues(storage: &dyn Storage, request: &Request) -> Result<Response> {
+    let spec = request
+        .query
+        .iter()
+        .filter(|(key, _)| key.as_str() != "limit")
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut filters = if spec.is_empty() {
+        ListFilters::default()
+    } else {
+        parse_filter(&spec)?
+    };
+    filters.limit = limit(request)?;
+    Ok(Response::json(200, &storage.list_issues(&filters)?))
+}
+
+fn ready_issues(storage: &dyn Storage, request: &Request) -> Result<Response> {
+    let filters = ReadyFilters {
+        limit: limit(request)?,
+        ..ReadyFilters::default()
+    };
+    let issues = storage.get_ready_issues(&filters, ReadySortPolicy::default())?;
+    Ok(Response::json(200, &issues))
+}
+
+fn parse_body<T: serde::de::DeserializeOwned>(request: &Request) -> Result<T> {
+    serde_json::from_str(&request.body)
+        .map_err(|e| BeadsError::validation("body", format!("invalid JSON body: {e}")))
+}
+
+fn limit(request: &Request) -> Result<Option<usize>> {
+    request
+        .query
+        .get("limit")
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| BeadsError::validation("limit", "must be a number"))
+        })
+        .transpose()
+}
+
+/// Export the request's writes to JSONL, as auto-flush does after a CLI run.
+fn flush(ctx: &mut OpenStorageResult, beads_dir: &Path) -> Result<()> {
+    if ctx.no_db {
+        return ctx.flush_no_db_if_dirty();
+    }
+    if !ctx.storage.is_read_only() {
+        crate::sync::auto_flush_with_shard(ctx.storage.sqlite_mut("flush")?, beads_dir, ctx.shard)?;
+    }
+    Ok(())
+}
+
+fn error_response(err: &BeadsError) -> Response {
+    let structured = StructuredError::from_error(err);
+    let status = match structured.code {
+        ErrorCode::IssueNotFound | ErrorCode::CommentNotFound | ErrorCode::DependencyNotFound => {
+            404
+        }
+        ErrorCode::ValidationFailed
+        | ErrorCode::InvalidStatus
+        | ErrorCode::InvalidType
+        | ErrorCode::InvalidPriority
+        | ErrorCode::RequiredField
+        | ErrorCode::InvalidId
+        | ErrorCode::AmbiguousId
+        | ErrorCode::JsonError => 400,
+        ErrorCode::ReadOnly => 403,
+        ErrorCode::IdCollision
+        | ErrorCode::PossibleDuplicate
+        | ErrorCode::CycleDetected
+        | ErrorCode::SelfDependency
+        | ErrorCode::DuplicateDependency
+        | ErrorCode::NothingToDo
+        | ErrorCode::DatabaseLocked => 409,
+        _ => 500,
+    };
+    Response::json(status, &structured.to_json())
+}
+
+/// Compare tokens without stopping at the first differing byte.
+fn tokens_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
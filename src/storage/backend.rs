@@ -0,0 +1,1977 @@
+//! The storage backend trait.
+//!
+//! [`Storage`] covers everything the commands do with issues and their
+//! relations. There are two backends: [`SqliteStorage`], and
+//! [`MemoryStorage`](crate::storage::MemoryStorage) over
+//! `beads_lib::InMemoryStore`, which `--no-db` loads from the JSONL file.
+//!
+//! Operations that can be written in terms of others have default bodies
+//! here; `SqliteStorage` overrides them with single queries. Database
+//! upkeep (dirty tracking, export bookkeeping, schema, import) stays on
+//! `SqliteStorage` and is reached through [`Backend::sqlite`]; see
+//! `docs/ARCHITECTURE.md`.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+
+use crate::error::{BeadsError, Result};
+use crate::format::{IssueDetails, IssueWithCounts, IssueWithDependencyMetadata};
+use crate::model::{
+    CodeRef, Comment, Dependency, DependencyCondition, DependencyType, Event, Issue, Priority,
+    Status, WaitUntil,
+};
+use crate::storage::memory::MemoryStorage;
+use crate::storage::sqlite::{dependency_cycles, external_blockers_from};
+use crate::storage::{
+    DependencyChange, DependencyCycle, IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy,
+    SearchField, SearchQuery, SqliteStorage, TextMatcher,
+};
+use crate::util::mentions::parse_mentions;
+
+/// Operations every storage backend provides.
+#[allow(clippy::missing_errors_doc)]
+pub trait Storage {
+    // ========================================================================
+    // Handle state
+    // ========================================================================
+
+    /// Whether mutations are refused (`--read-only`).
+    fn is_read_only(&self) -> bool;
+
+    /// Session recorded on events written through this handle.
+    fn session_id(&self) -> Option<&str>;
+
+    // ========================================================================
+    // Issues
+    // ========================================================================
+
+    /// Create an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID already exists or the issue is invalid.
+    fn create_issue(&mut self, issue: &Issue, actor: &str) -> Result<()>;
+
+    /// Apply `update` to an issue and return the updated issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist or the update is invalid.
+    fn update_issue(&mut self, id: &str, update: &IssueUpdate, actor: &str) -> Result<Issue>;
+
+    /// Get an issue by exact ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_issue(&self, id: &str) -> Result<Option<Issue>>;
+
+    /// Get the issues with these IDs, skipping missing ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_issues_by_ids(&self, ids: &[String]) -> Result<Vec<Issue>> {
+        let mut issues = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(issue) = self.get_issue(id)? {
+                issues.push(issue);
+            }
+        }
+        Ok(issues)
+    }
+
+    /// Get an issue with its labels, dependencies and comments filled in.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_issue_for_export(&self, id: &str) -> Result<Option<Issue>> {
+        let Some(mut issue) = self.get_issue(id)? else {
+            return Ok(None);
+        };
+        issue.labels = self.get_labels(id)?;
+        issue.dependencies = self.get_dependencies_full(id)?;
+        issue.comments = self.get_comments(id)?;
+        Ok(Some(issue))
+    }
+
+    /// Get an issue with its labels, related issues, comments and, with
+    /// `include_events`, up to `event_limit` recent events (0 = all).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_issue_details(
+        &self,
+        id: &str,
+        include_comments: bool,
+        include_events: bool,
+        event_limit: usize,
+    ) -> Result<Option<IssueDetails>>;
+
+    /// The issue whose `external_ref` is `external_ref`, in any status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn find_by_external_ref(&self, external_ref: &str) -> Result<Option<Issue>>;
+
+    /// Set a closed or deleted issue back to `open`, clearing its close and
+    /// tombstone fields, and return it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist or is not closed.
+    fn reopen_issue(&mut self, id: &str, reason: Option<&str>, actor: &str) -> Result<Issue>;
+
+    /// Bring a tombstone back to the status it was deleted from (closed if it
+    /// had been closed, open otherwise) and return it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist or is not a tombstone.
+    fn restore_issue(&mut self, id: &str, actor: &str, reason: Option<&str>) -> Result<Issue>;
+
+    /// Whether `id` exists and is a tombstone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn is_tombstone(&self, id: &str) -> Result<bool> {
+        Ok(self
+            .get_issue(id)?
+            .is_some_and(|issue| issue.status == Status::Tombstone))
+    }
+
+    /// Turn an issue into a tombstone and return it, dropping its
+    /// dependencies. The tombstone is kept (and exported) so the deletion
+    /// syncs, but default queries skip it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HasDependents` if other issues depend on it and `force` is
+    /// false, or an error if the issue does not exist.
+    fn delete_issue(&mut self, id: &str, actor: &str, reason: &str, force: bool) -> Result<Issue>;
+
+    /// Remove an issue outright, leaving no tombstone.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HasDependents` if other issues depend on it and `force` is
+    /// false, or an error if the issue does not exist.
+    fn purge_issue(&mut self, id: &str, actor: &str, force: bool) -> Result<()>;
+
+    /// Delete (or, with `hard`, purge) every issue in `ids` together with
+    /// all dependencies to and from them; returns the number of dependencies
+    /// removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IssueNotFound`, before changing anything, if an ID is missing.
+    fn delete_issues(
+        &mut self,
+        ids: &[String],
+        actor: &str,
+        reason: &str,
+        hard: bool,
+    ) -> Result<usize> {
+        for id in ids {
+            if !self.id_exists(id)? {
+                return Err(BeadsError::IssueNotFound { id: id.clone() });
+            }
+        }
+        let mut removed = 0;
+        for id in ids {
+            removed += self.remove_all_dependencies(id, actor)?;
+        }
+        for id in ids {
+            if hard {
+                self.purge_issue(id, actor, true)?;
+            } else {
+                self.delete_issue(id, actor, reason, true)?;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Rename issues (`(old, new)` pairs), rewriting every reference to them
+    /// and recording `comment` on each.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an old ID is missing or a new ID is taken.
+    fn rename_issue_ids(
+        &mut self,
+        renames: &[(String, String)],
+        actor: &str,
+        comment: &str,
+    ) -> Result<()>;
+
+    /// List issues matching `filters`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read or does not support a
+    /// filter.
+    fn list_issues(&self, filters: &ListFilters) -> Result<Vec<Issue>>;
+
+    /// List issues matching `filters` with their dependency and dependent
+    /// counts, in the same order as [`Self::list_issues`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read or does not support a
+    /// filter.
+    fn list_issues_with_counts(&self, filters: &ListFilters) -> Result<Vec<IssueWithCounts>>;
+
+    /// Pass the issues matching `filters` to `f` in batches of up to
+    /// `batch_size`, until `f` returns `false`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read or `f` fails.
+    fn stream_issues(
+        &self,
+        filters: &ListFilters,
+        batch_size: usize,
+        f: &mut dyn FnMut(Vec<Issue>) -> Result<bool>,
+    ) -> Result<()> {
+        let issues = self.list_issues(filters)?;
+        for batch in issues.chunks(batch_size.max(1)) {
+            if !f(batch.to_vec())? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Issues matching `query` and `filters`; an empty query matches none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the regex is invalid or the backend cannot be read.
+    fn search_issues_with(&self, query: &SearchQuery, filters: &ListFilters) -> Result<Vec<Issue>> {
+        if query.text.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        let matcher = query.matcher()?;
+        let candidates = ListFilters {
+            limit: None,
+            ..filters.clone()
+        };
+        let mut issues = Vec::new();
+        for issue in self.list_issues(&candidates)? {
+            if self
+                .find_search_match(&issue, &query.fields, &matcher)?
+                .is_some()
+            {
+                issues.push(issue);
+            }
+        }
+        if let Some(limit) = filters.limit.filter(|&limit| limit > 0) {
+            issues.truncate(limit);
+        }
+        Ok(issues)
+    }
+
+    /// [`Self::search_issues_with`] in batches, as [`Self::stream_issues`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the regex is invalid, the backend cannot be read
+    /// or `f` fails.
+    fn stream_search_issues(
+        &self,
+        query: &SearchQuery,
+        filters: &ListFilters,
+        batch_size: usize,
+        f: &mut dyn FnMut(Vec<Issue>) -> Result<bool>,
+    ) -> Result<()> {
+        let issues = self.search_issues_with(query, filters)?;
+        for batch in issues.chunks(batch_size.max(1)) {
+            if !f(batch.to_vec())? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// The first of `fields` on `issue` that `matcher` matches, with the text
+    /// it matched in (the comment body for [`SearchField::Comments`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if comments cannot be read.
+    fn find_search_match(
+        &self,
+        issue: &Issue,
+        fields: &[SearchField],
+        matcher: &TextMatcher,
+    ) -> Result<Option<(SearchField, String)>> {
+        for &field in fields {
+            if field == SearchField::Comments {
+                let comment = self
+                    .get_comments(&issue.id)?
+                    .into_iter()
+                    .find(|comment| !comment.deleted && matcher.is_match(&comment.body));
+                if let Some(comment) = comment {
+                    return Ok(Some((field, comment.body)));
+                }
+            } else if let Some(text) = field.text(issue).filter(|text| matcher.is_match(text)) {
+                return Ok(Some((field, text.to_string())));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Every issue ID, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_all_ids(&self) -> Result<Vec<String>>;
+
+    /// Number of issues, tombstones included (sizes generated IDs).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn count_issues(&self) -> Result<usize>;
+
+    /// Number of issues in each status, tombstones included.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn count_issues_by_status(&self) -> Result<BTreeMap<String, usize>> {
+        let mut counts = BTreeMap::new();
+        for id in self.get_all_ids()? {
+            if let Some(issue) = self.get_issue(&id)? {
+                *counts.entry(issue.status.as_str().to_string()).or_insert(0) += 1;
+            }
+        }
+        Ok(counts)
+    }
+
+    /// ID aliases left behind by renames, mapped to the current ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_id_aliases(&self) -> Result<HashMap<String, String>>;
+
+    /// Whether an issue with exactly this ID exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn id_exists(&self, id: &str) -> Result<bool>;
+
+    /// IDs whose hash part contains `hash_suffix` (for partial ID resolution).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn find_ids_by_hash(&self, hash_suffix: &str) -> Result<Vec<String>>;
+
+    /// The number for the next `{parent_id}.N` child ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn next_child_number(&self, parent_id: &str) -> Result<u32> {
+        let prefix_with_dot = format!("{parent_id}.");
+        let max_child = self
+            .get_all_ids()?
+            .iter()
+            .filter_map(|id| {
+                id.strip_prefix(&prefix_with_dot)
+                    .and_then(|suffix| suffix.split('.').next())
+                    .and_then(|num_str| num_str.parse::<u32>().ok())
+            })
+            .max()
+            .unwrap_or(0);
+        Ok(max_child.saturating_add(1))
+    }
+
+    // ========================================================================
+    // Ready and blocked work
+    // ========================================================================
+
+    /// Open, unblocked issues in `sort` order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read or does not support a
+    /// filter.
+    fn get_ready_issues(&self, filters: &ReadyFilters, sort: ReadySortPolicy)
+    -> Result<Vec<Issue>>;
+
+    /// The first ready issue not in `exclude`, ignoring `filters.limit`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn next_ready_issue(
+        &self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+        exclude: &HashSet<String>,
+    ) -> Result<Option<Issue>> {
+        let filters = ReadyFilters {
+            limit: None,
+            ..filters.clone()
+        };
+        Ok(self
+            .get_ready_issues(&filters, sort)?
+            .into_iter()
+            .find(|issue| !exclude.contains(&issue.id)))
+    }
+
+    /// Assign up to `count` unassigned ready issues (skipping `exclude`) to
+    /// `actor` and move them to `in_progress`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read or written.
+    fn claim_ready_issues(
+        &mut self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+        count: usize,
+        exclude: &HashSet<String>,
+        actor: &str,
+    ) -> Result<Vec<Issue>> {
+        let filters = ReadyFilters {
+            assignee: None,
+            unassigned: true,
+            limit: None,
+            ..filters.clone()
+        };
+        let candidates: Vec<Issue> = self
+            .get_ready_issues(&filters, sort)?
+            .into_iter()
+            .filter(|issue| !exclude.contains(&issue.id))
+            .take(count)
+            .collect();
+        let update = IssueUpdate {
+            status: Some(Status::InProgress),
+            assignee: Some(Some(actor.to_string())),
+            ..IssueUpdate::default()
+        };
+        candidates
+            .iter()
+            .map(|issue| self.update_issue(&issue.id, &update, actor))
+            .collect()
+    }
+
+    /// Open issues that are blocked, each with what blocks it
+    /// (`id:status` or `id:parent-blocked`), by priority.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_blocked_issues(&self) -> Result<Vec<(Issue, Vec<String>)>>;
+
+    /// IDs of every blocked issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_blocked_ids(&self) -> Result<HashSet<String>> {
+        Ok(self
+            .get_blocked_issues()?
+            .into_iter()
+            .map(|(issue, _)| issue.id)
+            .collect())
+    }
+
+    /// IDs of the open issues blocked through `blocks` dependencies alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_blocked_by_blocks_deps_only(&self) -> Result<HashSet<String>> {
+        let is_open = |id: &str| -> Result<bool> {
+            Ok(self
+                .get_issue(id)?
+                .is_some_and(|issue| !matches!(issue.status, Status::Closed | Status::Tombstone)))
+        };
+        let mut ids = HashSet::new();
+        for (issue_id, deps) in self.get_all_dependency_records()? {
+            for dep in deps {
+                if dep.dep_type == DependencyType::Blocks
+                    && is_open(&dep.depends_on_id)?
+                    && is_open(&issue_id)?
+                {
+                    ids.insert(issue_id.clone());
+                }
+            }
+        }
+        Ok(ids)
+    }
+
+    /// Whether an issue is blocked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn is_blocked(&self, issue_id: &str) -> Result<bool> {
+        Ok(!self.get_blockers(issue_id)?.is_empty())
+    }
+
+    /// IDs of the issues blocking `issue_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_blockers(&self, issue_id: &str) -> Result<Vec<String>>;
+
+    /// Recompute stored blocked state, if the backend keeps any; returns the
+    /// number of blocked issues recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn rebuild_blocked_cache(&mut self, force_rebuild: bool) -> Result<usize>;
+
+    /// Wake times of `waits-for` dependencies, by `(issue_id, depends_on_id)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_waits_for_wake_times(&self) -> Result<HashMap<(String, String), DateTime<Utc>>> {
+        let mut wake_times = HashMap::new();
+        for dep in self.get_all_dependency_records()?.into_values().flatten() {
+            if dep.dep_type != DependencyType::WaitsFor {
+                continue;
+            }
+            if let Some(WaitUntil(until)) = WaitUntil::from_metadata(dep.metadata.as_deref()) {
+                wake_times.insert((dep.issue_id, dep.depends_on_id), until);
+            }
+        }
+        Ok(wake_times)
+    }
+
+    /// Whether each `external:` dependency is satisfied, looking the
+    /// capabilities up in the projects `external_db_paths` names.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if local dependencies cannot be read.
+    fn resolve_external_dependency_statuses(
+        &self,
+        external_db_paths: &HashMap<String, PathBuf>,
+        blocking_only: bool,
+    ) -> Result<HashMap<String, bool>>;
+
+    /// Blockers caused by unsatisfied external dependencies, including those
+    /// inherited through parent-child links.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if dependencies cannot be read.
+    fn external_blockers(
+        &self,
+        external_statuses: &HashMap<String, bool>,
+    ) -> Result<HashMap<String, Vec<String>>> {
+        let mut direct = Vec::new();
+        let mut parent_edges = Vec::new();
+        for dep in self.get_all_dependency_records()?.into_values().flatten() {
+            if dep.dep_type == DependencyType::ParentChild {
+                parent_edges.push((dep.issue_id.clone(), dep.depends_on_id.clone()));
+            }
+            if dep.dep_type.is_blocking() && dep.depends_on_id.starts_with("external:") {
+                direct.push((
+                    dep.issue_id,
+                    dep.depends_on_id,
+                    dep.dep_type.as_str().to_string(),
+                    dep.metadata,
+                ));
+            }
+        }
+        Ok(external_blockers_from(
+            direct,
+            &parent_edges,
+            external_statuses,
+        ))
+    }
+
+    // ========================================================================
+    // Dependencies
+    // ========================================================================
+
+    /// Add a dependency; returns `false` if it already existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either issue is missing or the edge would create a
+    /// cycle.
+    fn add_dependency(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &str,
+        actor: &str,
+    ) -> Result<bool> {
+        self.add_dependency_with_metadata(issue_id, depends_on_id, dep_type, None, actor)
+    }
+
+    /// [`Self::add_dependency`] with the dependency's metadata JSON
+    /// (condition, wake time).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either issue is missing or the edge would create a
+    /// cycle.
+    fn add_dependency_with_metadata(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &str,
+        metadata: Option<&str>,
+        actor: &str,
+    ) -> Result<bool>;
+
+    /// Mark `issue_id` a duplicate of `of_id` and close it if it is open;
+    /// returns whether the dependency was added and whether it was closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either issue is missing.
+    fn add_duplicate_and_close(
+        &mut self,
+        issue_id: &str,
+        of_id: &str,
+        actor: &str,
+    ) -> Result<(bool, bool)> {
+        let issue = self
+            .get_issue(issue_id)?
+            .ok_or_else(|| BeadsError::IssueNotFound {
+                id: issue_id.to_string(),
+            })?;
+        let added =
+            self.add_dependency(issue_id, of_id, DependencyType::Duplicates.as_str(), actor)?;
+        let close = !issue.status.is_terminal();
+        if close {
+            let update = IssueUpdate {
+                status: Some(Status::Closed),
+                closed_at: Some(Some(Utc::now())),
+                close_reason: Some(Some(format!("duplicate of {of_id}"))),
+                closed_by_session: Some(self.session_id().map(str::to_string)),
+                ..IssueUpdate::default()
+            };
+            self.update_issue(issue_id, &update, actor)?;
+        }
+        Ok((added, close))
+    }
+
+    /// Apply adds and removals in order; returns whether each changed
+    /// anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DependencyCycle` if a blocking add would close a cycle.
+    fn apply_dependency_changes(
+        &mut self,
+        changes: &[DependencyChange],
+        actor: &str,
+    ) -> Result<Vec<bool>> {
+        let mut results = Vec::with_capacity(changes.len());
+        for change in changes {
+            let changed = match change {
+                DependencyChange::Add {
+                    issue_id,
+                    depends_on_id,
+                    dep_type,
+                } => {
+                    let blocking = dep_type
+                        .parse::<DependencyType>()
+                        .is_ok_and(|t| t.is_blocking());
+                    if blocking && self.would_create_cycle(issue_id, depends_on_id, true)? {
+                        return Err(BeadsError::DependencyCycle {
+                            path: format!(
+                                "Adding dependency {issue_id} -> {depends_on_id} would create a cycle"
+                            ),
+                        });
+                    }
+                    self.add_dependency(issue_id, depends_on_id, dep_type, actor)?
+                }
+                DependencyChange::Remove {
+                    issue_id,
+                    depends_on_id,
+                } => self.remove_dependency(issue_id, depends_on_id, actor)?,
+            };
+            results.push(changed);
+        }
+        Ok(results)
+    }
+
+    /// Remove a dependency; returns `false` if there was none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn remove_dependency(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        actor: &str,
+    ) -> Result<bool>;
+
+    /// Remove every dependency to and from `issue_id`; returns how many.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn remove_all_dependencies(&mut self, issue_id: &str, actor: &str) -> Result<usize> {
+        let mut removed = 0;
+        for depends_on_id in self.get_dependencies(issue_id)? {
+            if self.remove_dependency(issue_id, &depends_on_id, actor)? {
+                removed += 1;
+            }
+        }
+        for dependent_id in self.get_dependents(issue_id)? {
+            if self.remove_dependency(&dependent_id, issue_id, actor)? {
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// The parent of `issue_id` (its `parent-child` dependency), if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_parent_id(&self, issue_id: &str) -> Result<Option<String>> {
+        Ok(self
+            .get_dependencies_full(issue_id)?
+            .into_iter()
+            .find(|dep| dep.dep_type == DependencyType::ParentChild)
+            .map(|dep| dep.depends_on_id))
+    }
+
+    /// Detach `issue_id` from its parent; returns `false` if it had none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn remove_parent(&mut self, issue_id: &str, actor: &str) -> Result<bool> {
+        self.get_parent_id(issue_id)?
+            .map_or(Ok(false), |parent_id| {
+                self.remove_dependency(issue_id, &parent_id, actor)
+            })
+    }
+
+    /// IDs `issue_id` depends on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_dependencies(&self, issue_id: &str) -> Result<Vec<String>>;
+
+    /// IDs that depend on `issue_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_dependents(&self, issue_id: &str) -> Result<Vec<String>>;
+
+    /// The dependency records of `issue_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_dependencies_full(&self, issue_id: &str) -> Result<Vec<Dependency>>;
+
+    /// Every dependency record, by `issue_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_all_dependency_records(&self) -> Result<HashMap<String, Vec<Dependency>>>;
+
+    /// The issues `issue_id` depends on, with the dependency type and
+    /// condition, by priority.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_dependencies_with_metadata(
+        &self,
+        issue_id: &str,
+    ) -> Result<Vec<IssueWithDependencyMetadata>> {
+        let mut related = Vec::new();
+        for dep in self.get_dependencies_full(issue_id)? {
+            related.push(related_issue(self, &dep.depends_on_id, &dep)?);
+        }
+        related.sort_by_key(|issue| issue.priority);
+        Ok(related)
+    }
+
+    /// The issues that depend on `issue_id`, with the dependency type and
+    /// condition, by priority.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_dependents_with_metadata(
+        &self,
+        issue_id: &str,
+    ) -> Result<Vec<IssueWithDependencyMetadata>> {
+        let mut related = Vec::new();
+        for dependent_id in self.get_dependents(issue_id)? {
+            let edge = self
+                .get_dependencies_full(&dependent_id)?
+                .into_iter()
+                .find(|dep| dep.depends_on_id == issue_id);
+            if let Some(dep) = edge {
+                related.push(related_issue(self, &dependent_id, &dep)?);
+            }
+        }
+        related.sort_by_key(|issue| issue.priority);
+        Ok(related)
+    }
+
+    /// Open issues below `parent_id` through parent-child links, deepest
+    /// first, so closing them in order closes children before parents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_open_descendants(&self, parent_id: &str) -> Result<Vec<String>>;
+
+    /// Open issues that `issue_id` blocks, directly or through blocking and
+    /// parent-child links, with their shallowest depth (up to `max_depth`,
+    /// default 50), by depth.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_transitive_dependents(
+        &self,
+        issue_id: &str,
+        max_depth: Option<usize>,
+    ) -> Result<Vec<(String, usize)>> {
+        let max_depth = max_depth.unwrap_or(50);
+        let mut depths: HashMap<String, usize> = HashMap::new();
+        let mut frontier = vec![issue_id.to_string()];
+        let mut depth = 0;
+        while !frontier.is_empty() && depth < max_depth {
+            depth += 1;
+            let mut next = Vec::new();
+            for id in &frontier {
+                for dependent_id in self.get_dependents(id)? {
+                    if dependent_id == issue_id || depths.contains_key(&dependent_id) {
+                        continue;
+                    }
+                    let Some(dep) = self
+                        .get_dependencies_full(&dependent_id)?
+                        .into_iter()
+                        .find(|dep| dep.depends_on_id == *id)
+                    else {
+                        continue;
+                    };
+                    // Only the first hop must be a blocking edge proper
+                    let follows = match dep.dep_type {
+                        DependencyType::ParentChild => depth > 1,
+                        ref dep_type => dep_type.is_blocking(),
+                    };
+                    let open = self.get_issue(&dependent_id)?.is_some_and(|issue| {
+                        !matches!(issue.status, Status::Closed | Status::Tombstone)
+                    });
+                    if follows && open {
+                        depths.insert(dependent_id.clone(), depth);
+                        next.push(dependent_id);
+                    }
+                }
+            }
+            frontier = next;
+        }
+        let mut impacted: Vec<(String, usize)> = depths.into_iter().collect();
+        impacted.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(impacted)
+    }
+
+    /// Whether adding `issue_id -> depends_on_id` would close a cycle
+    /// (following only blocking types with `blocking_only`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn would_create_cycle(
+        &self,
+        issue_id: &str,
+        depends_on_id: &str,
+        blocking_only: bool,
+    ) -> Result<bool>;
+
+    /// Every dependency cycle, as strongly connected components (following
+    /// only blocking types with `blocking_only`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn find_dependency_cycles(&self, blocking_only: bool) -> Result<Vec<DependencyCycle>> {
+        let rows = self
+            .get_all_dependency_records()?
+            .into_values()
+            .flatten()
+            .map(|dep| {
+                let dep_type = dep.dep_type.as_str().to_string();
+                (dep.issue_id, dep.depends_on_id, dep_type)
+            })
+            .collect();
+        Ok(dependency_cycles(rows, blocking_only))
+    }
+
+    /// Number of dependencies of each of `issue_ids` that has any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn count_dependencies_for_issues(
+        &self,
+        issue_ids: &[String],
+    ) -> Result<HashMap<String, usize>> {
+        let mut counts = HashMap::new();
+        for id in issue_ids {
+            let count = self.get_dependencies(id)?.len();
+            if count > 0 {
+                counts.insert(id.clone(), count);
+            }
+        }
+        Ok(counts)
+    }
+
+    /// Number of dependents of each of `issue_ids` that has any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn count_dependents_for_issues(&self, issue_ids: &[String]) -> Result<HashMap<String, usize>> {
+        let mut counts = HashMap::new();
+        for id in issue_ids {
+            let count = self.get_dependents(id)?.len();
+            if count > 0 {
+                counts.insert(id.clone(), count);
+            }
+        }
+        Ok(counts)
+    }
+
+    // ========================================================================
+    // Labels
+    // ========================================================================
+
+    /// Add a label; returns `false` if the issue already had it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist.
+    fn add_label(&mut self, issue_id: &str, label: &str, actor: &str) -> Result<bool>;
+
+    /// Remove a label; returns `false` if the issue did not have it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist.
+    fn remove_label(&mut self, issue_id: &str, label: &str, actor: &str) -> Result<bool>;
+
+    /// Replace an issue's labels with `labels`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist.
+    fn set_labels(&mut self, issue_id: &str, labels: &[String], actor: &str) -> Result<()> {
+        for label in self.get_labels(issue_id)? {
+            if !labels.contains(&label) {
+                self.remove_label(issue_id, &label, actor)?;
+            }
+        }
+        for label in labels {
+            self.add_label(issue_id, label, actor)?;
+        }
+        Ok(())
+    }
+
+    /// Remove every label from an issue; returns how many.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn remove_all_labels(&mut self, issue_id: &str, actor: &str) -> Result<usize> {
+        let labels = self.get_labels(issue_id)?;
+        for label in &labels {
+            self.remove_label(issue_id, label, actor)?;
+        }
+        Ok(labels.len())
+    }
+
+    /// Labels on an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_labels(&self, issue_id: &str) -> Result<Vec<String>>;
+
+    /// Labels of each of `issue_ids` that has any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_labels_for_issues(&self, issue_ids: &[String]) -> Result<HashMap<String, Vec<String>>> {
+        let mut labels = HashMap::new();
+        for id in issue_ids {
+            let issue_labels = self.get_labels(id)?;
+            if !issue_labels.is_empty() {
+                labels.insert(id.clone(), issue_labels);
+            }
+        }
+        Ok(labels)
+    }
+
+    /// Every label in use with the number of issues carrying it, by label.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_unique_labels_with_counts(&self) -> Result<Vec<(String, i64)>>;
+
+    /// Replace each of `sources` with `target` on every issue carrying one;
+    /// returns the number of issues changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn merge_labels(&mut self, sources: &[&str], target: &str, actor: &str) -> Result<usize> {
+        let sources: Vec<&str> = sources
+            .iter()
+            .copied()
+            .filter(|source| *source != target)
+            .collect();
+        if sources.is_empty() {
+            return Ok(0);
+        }
+        let mut count = 0;
+        for id in self.get_all_ids()? {
+            let removed: Vec<String> = self
+                .get_labels(&id)?
+                .into_iter()
+                .filter(|label| sources.contains(&label.as_str()))
+                .collect();
+            if removed.is_empty() {
+                continue;
+            }
+            count += 1;
+            for label in &removed {
+                self.remove_label(&id, label, actor)?;
+            }
+            self.add_label(&id, target, actor)?;
+        }
+        Ok(count)
+    }
+
+    /// Rename a label on every issue; returns the number of issues changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn rename_label(&mut self, old_name: &str, new_name: &str, actor: &str) -> Result<usize> {
+        self.merge_labels(&[old_name], new_name, actor)
+    }
+
+    // ========================================================================
+    // Comments
+    // ========================================================================
+
+    /// Add a comment and return it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist.
+    fn add_comment(&mut self, issue_id: &str, author: &str, text: &str) -> Result<Comment>;
+
+    /// Replace a live comment's text and return it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no live comment with this ID.
+    fn edit_comment(&mut self, comment_id: i64, text: &str, actor: &str) -> Result<Comment>;
+
+    /// Soft-delete a comment (kept as a tombstone so the deletion syncs) and
+    /// return it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no live comment with this ID.
+    fn delete_comment(&mut self, comment_id: i64, actor: &str) -> Result<Comment>;
+
+    /// Comments on an issue, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_comments(&self, issue_id: &str) -> Result<Vec<Comment>>;
+
+    /// A comment by ID, deleted or not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_comment(&self, comment_id: i64) -> Result<Option<Comment>> {
+        Ok(self
+            .get_all_comments()?
+            .into_values()
+            .flatten()
+            .find(|comment| comment.id == comment_id))
+    }
+
+    /// Every comment, by issue ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_all_comments(&self) -> Result<HashMap<String, Vec<Comment>>>;
+
+    /// Live comments that `@`-mention `name`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_comments_mentioning(&self, name: &str) -> Result<Vec<Comment>> {
+        let name = name.to_lowercase();
+        let mut comments: Vec<Comment> = self
+            .get_all_comments()?
+            .into_values()
+            .flatten()
+            .filter(|comment| !comment.deleted && parse_mentions(&comment.body).contains(&name))
+            .collect();
+        comments.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+        Ok(comments)
+    }
+
+    // ========================================================================
+    // Code refs, reactions, watchers and custom fields
+    // ========================================================================
+
+    /// Code references on an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_code_refs(&self, issue_id: &str) -> Result<Vec<CodeRef>> {
+        Ok(self
+            .get_issue(issue_id)?
+            .map(|issue| issue.code_refs)
+            .unwrap_or_default())
+    }
+
+    /// Attach a code reference; returns `false` if it was already there.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist.
+    fn add_code_ref(&mut self, issue_id: &str, code_ref: &CodeRef, actor: &str) -> Result<bool>;
+
+    /// Detach a code reference; returns `false` if it was not there.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn remove_code_ref(&mut self, issue_id: &str, code_ref: &CodeRef, actor: &str) -> Result<bool>;
+
+    /// React to an issue, or to one of its comments; returns `false` if
+    /// `actor` had already reacted so.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue or comment does not exist.
+    fn add_reaction(
+        &mut self,
+        issue_id: &str,
+        comment_id: Option<i64>,
+        reaction: &str,
+        actor: &str,
+    ) -> Result<bool>;
+
+    /// Withdraw a reaction; returns `false` if there was none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn remove_reaction(
+        &mut self,
+        issue_id: &str,
+        comment_id: Option<i64>,
+        reaction: &str,
+        actor: &str,
+    ) -> Result<bool>;
+
+    /// Start watching an issue; returns `false` if already watching.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist.
+    fn add_watcher(&mut self, issue_id: &str, watcher: &str, actor: &str) -> Result<bool>;
+
+    /// Stop watching an issue; returns `false` if not watching.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn remove_watcher(&mut self, issue_id: &str, watcher: &str, actor: &str) -> Result<bool>;
+
+    /// Who watches an issue, by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_watchers(&self, issue_id: &str) -> Result<Vec<String>> {
+        let mut watchers = self
+            .get_issue(issue_id)?
+            .map(|issue| issue.watchers)
+            .unwrap_or_default();
+        watchers.sort();
+        Ok(watchers)
+    }
+
+    /// Issues `watcher` watches, with when they started, by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_watched_issues(&self, watcher: &str) -> Result<Vec<(String, DateTime<Utc>)>>;
+
+    /// Set (or, with `None`, clear) a custom field; returns `false` if the
+    /// value did not change.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist.
+    fn set_field(
+        &mut self,
+        issue_id: &str,
+        name: &str,
+        value: Option<&str>,
+        actor: &str,
+    ) -> Result<bool>;
+
+    // ========================================================================
+    // Events
+    // ========================================================================
+
+    /// Events for an issue, newest first (`limit` 0 = all).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_events(&self, issue_id: &str, limit: usize) -> Result<Vec<Event>>;
+
+    /// Events for every issue, newest first (`limit` 0 = all).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_all_events(&self, limit: usize) -> Result<Vec<Event>>;
+
+    /// Events with an ID above `after_id`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_events_after(&self, after_id: i64) -> Result<Vec<Event>>;
+
+    /// Largest event ID so far (0 if none).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn max_event_id(&self) -> Result<i64> {
+        Ok(self
+            .get_all_events(0)?
+            .iter()
+            .map(|event| event.id)
+            .max()
+            .unwrap_or(0))
+    }
+
+    /// Events recorded in one session, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_session_events(&self, session_id: &str) -> Result<Vec<Event>> {
+        let mut events: Vec<Event> = self
+            .get_all_events(0)?
+            .into_iter()
+            .filter(|event| event.session_id.as_deref() == Some(session_id))
+            .collect();
+        events.reverse();
+        Ok(events)
+    }
+
+    // ========================================================================
+    // Config and metadata
+    // ========================================================================
+
+    /// A config value stored with the issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_config(&self, key: &str) -> Result<Option<String>>;
+
+    /// Every config value stored with the issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_all_config(&self) -> Result<HashMap<String, String>>;
+
+    /// Store a config value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn set_config(&mut self, key: &str, value: &str) -> Result<()>;
+
+    /// Remove a config value; returns `false` if it was not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn delete_config(&mut self, key: &str) -> Result<bool>;
+
+    /// An internal bookkeeping value (read markers, import hashes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be read.
+    fn get_metadata(&self, key: &str) -> Result<Option<String>>;
+
+    /// Store an internal bookkeeping value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be written.
+    fn set_metadata(&mut self, key: &str, value: &str) -> Result<()>;
+}
+
+/// `other_id` as the far end of `dep`, for the `*_with_metadata` listings.
+fn related_issue<S: Storage + ?Sized>(
+    storage: &S,
+    other_id: &str,
+    dep: &Dependency,
+) -> Result<IssueWithDependencyMetadata> {
+    let (title, status, priority) = storage.get_issue(other_id)?.map_or_else(
+        || (String::new(), Status::default(), Priority::MEDIUM),
+        |issue| (issue.title, issue.status, issue.priority),
+    );
+    Ok(IssueWithDependencyMetadata {
+        id: other_id.to_string(),
+        title,
+        status,
+        priority,
+        dep_type: dep.dep_type.as_str().to_string(),
+        condition: DependencyCondition::from_metadata(dep.metadata.as_deref())
+            .map(|condition| condition.to_string()),
+    })
+}
+
+/// The storage a command runs on: the database, or with `--no-db` the JSONL
+/// file loaded into memory.
+///
+/// Derefs to [`Storage`], so commands use it as `&dyn Storage`. Database
+/// upkeep that has no JSONL-only meaning goes through [`Self::sqlite`].
+#[derive(Debug)]
+pub enum Backend {
+    Sqlite(SqliteStorage),
+    Memory(Box<MemoryStorage>),
+}
+
+impl Backend {
+    /// The database, for operations only it supports.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming `operation` under `--no-db`.
+    pub fn sqlite(&self, operation: &str) -> Result<&SqliteStorage> {
+        match self {
+            Self::Sqlite(storage) => Ok(storage),
+            Self::Memory(_) => Err(needs_database(operation)),
+        }
+    }
+
+    /// Mutable [`Self::sqlite`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming `operation` under `--no-db`.
+    pub fn sqlite_mut(&mut self, operation: &str) -> Result<&mut SqliteStorage> {
+        match self {
+            Self::Sqlite(storage) => Ok(storage),
+            Self::Memory(_) => Err(needs_database(operation)),
+        }
+    }
+
+    /// The database, or `None` under `--no-db`.
+    #[must_use]
+    pub const fn as_sqlite(&self) -> Option<&SqliteStorage> {
+        match self {
+            Self::Sqlite(storage) => Some(storage),
+            Self::Memory(_) => None,
+        }
+    }
+}
+
+impl Deref for Backend {
+    type Target = dyn Storage;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Sqlite(storage) => storage,
+            Self::Memory(storage) => &**storage,
+        }
+    }
+}
+
+impl DerefMut for Backend {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Sqlite(storage) => storage,
+            Self::Memory(storage) => &mut **storage,
+        }
+    }
+}
+
+fn needs_database(operation: &str) -> BeadsError {
+    BeadsError::validation(
+        "no-db",
+        format!("{operation} needs the database and is not available with --no-db"),
+    )
+}
+
+impl Storage for SqliteStorage {
+    fn is_read_only(&self) -> bool {
+        Self::is_read_only(self)
+    }
+
+    fn session_id(&self) -> Option<&str> {
+        Self::session_id(self)
+    }
+
+    fn create_issue(&mut self, issue: &Issue, actor: &str) -> Result<()> {
+        Self::create_issue(self, issue, actor)
+    }
+
+    fn update_issue(&mut self, id: &str, update: &IssueUpdate, actor: &str) -> Result<Issue> {
+        Self::update_issue(self, id, update, actor)
+    }
+
+    fn get_issue(&self, id: &str) -> Result<Option<Issue>> {
+        Self::get_issue(self, id)
+    }
+
+    fn get_issues_by_ids(&self, ids: &[String]) -> Result<Vec<Issue>> {
+        Self::get_issues_by_ids(self, ids)
+    }
+
+    fn get_issue_for_export(&self, id: &str) -> Result<Option<Issue>> {
+        Self::get_issue_for_export(self, id)
+    }
+
+    fn get_issue_details(
+        &self,
+        id: &str,
+        include_comments: bool,
+        include_events: bool,
+        event_limit: usize,
+    ) -> Result<Option<IssueDetails>> {
+        Self::get_issue_details(self, id, include_comments, include_events, event_limit)
+    }
+
+    fn find_by_external_ref(&self, external_ref: &str) -> Result<Option<Issue>> {
+        Self::find_by_external_ref(self, external_ref)
+    }
+
+    fn reopen_issue(&mut self, id: &str, reason: Option<&str>, actor: &str) -> Result<Issue> {
+        Self::reopen_issue(self, id, reason, actor)
+    }
+
+    fn restore_issue(&mut self, id: &str, actor: &str, reason: Option<&str>) -> Result<Issue> {
+        Self::restore_issue(self, id, actor, reason)
+    }
+
+    fn is_tombstone(&self, id: &str) -> Result<bool> {
+        Self::is_tombstone(self, id)
+    }
+
+    fn delete_issue(&mut self, id: &str, actor: &str, reason: &str, force: bool) -> Result<Issue> {
+        check_dependents(self, id, force)?;
+        Self::remove_all_dependencies(self, id, actor)?;
+        Self::delete_issue(self, id, actor, reason, None)
+    }
+
+    fn purge_issue(&mut self, id: &str, actor: &str, force: bool) -> Result<()> {
+        check_dependents(self, id, force)?;
+        Self::purge_issue(self, id, actor)
+    }
+
+    fn delete_issues(
+        &mut self,
+        ids: &[String],
+        actor: &str,
+        reason: &str,
+        hard: bool,
+    ) -> Result<usize> {
+        Self::delete_issues(self, ids, actor, reason, hard)
+    }
+
+    fn rename_issue_ids(
+        &mut self,
+        renames: &[(String, String)],
+        actor: &str,
+        comment: &str,
+    ) -> Result<()> {
+        Self::rename_issue_ids(self, renames, actor, comment)
+    }
+
+    fn list_issues(&self, filters: &ListFilters) -> Result<Vec<Issue>> {
+        Self::list_issues(self, filters)
+    }
+
+    fn list_issues_with_counts(&self, filters: &ListFilters) -> Result<Vec<IssueWithCounts>> {
+        Self::list_issues_with_counts(self, filters)
+    }
+
+    fn stream_issues(
+        &self,
+        filters: &ListFilters,
+        batch_size: usize,
+        f: &mut dyn FnMut(Vec<Issue>) -> Result<bool>,
+    ) -> Result<()> {
+        Self::stream_issues(self, filters, batch_size, f)
+    }
+
+    fn search_issues_with(&self, query: &SearchQuery, filters: &ListFilters) -> Result<Vec<Issue>> {
+        Self::search_issues_with(self, query, filters)
+    }
+
+    fn stream_search_issues(
+        &self,
+        query: &SearchQuery,
+        filters: &ListFilters,
+        batch_size: usize,
+        f: &mut dyn FnMut(Vec<Issue>) -> Result<bool>,
+    ) -> Result<()> {
+        Self::stream_search_issues(self, query, filters, batch_size, f)
+    }
+
+    fn get_all_ids(&self) -> Result<Vec<String>> {
+        Self::get_all_ids(self)
+    }
+
+    fn count_issues(&self) -> Result<usize> {
+        Self::count_issues(self)
+    }
+
+    fn count_issues_by_status(&self) -> Result<BTreeMap<String, usize>> {
+        Self::count_issues_by_status(self)
+    }
+
+    fn get_id_aliases(&self) -> Result<HashMap<String, String>> {
+        Self::get_id_aliases(self)
+    }
+
+    fn id_exists(&self, id: &str) -> Result<bool> {
+        Self::id_exists(self, id)
+    }
+
+    fn find_ids_by_hash(&self, hash_suffix: &str) -> Result<Vec<String>> {
+        Self::find_ids_by_hash(self, hash_suffix)
+    }
+
+    fn next_child_number(&self, parent_id: &str) -> Result<u32> {
+        Self::next_child_number(self, parent_id)
+    }
+
+    fn get_ready_issues(
+        &self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+    ) -> Result<Vec<Issue>> {
+        Self::get_ready_issues(self, filters, sort)
+    }
+
+    fn next_ready_issue(
+        &self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+        exclude: &HashSet<String>,
+    ) -> Result<Option<Issue>> {
+        Self::next_ready_issue(self, filters, sort, exclude)
+    }
+
+    fn claim_ready_issues(
+        &mut self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+        count: usize,
+        exclude: &HashSet<String>,
+        actor: &str,
+    ) -> Result<Vec<Issue>> {
+        Self::claim_ready_issues(self, filters, sort, count, exclude, actor)
+    }
+
+    fn get_blocked_issues(&self) -> Result<Vec<(Issue, Vec<String>)>> {
+        Self::get_blocked_issues(self)
+    }
+
+    fn get_blocked_ids(&self) -> Result<HashSet<String>> {
+        Self::get_blocked_ids(self)
+    }
+
+    fn get_blocked_by_blocks_deps_only(&self) -> Result<HashSet<String>> {
+        Self::get_blocked_by_blocks_deps_only(self)
+    }
+
+    fn is_blocked(&self, issue_id: &str) -> Result<bool> {
+        Self::is_blocked(self, issue_id)
+    }
+
+    fn get_blockers(&self, issue_id: &str) -> Result<Vec<String>> {
+        Self::get_blockers(self, issue_id)
+    }
+
+    fn rebuild_blocked_cache(&mut self, force_rebuild: bool) -> Result<usize> {
+        Self::rebuild_blocked_cache(self, force_rebuild)
+    }
+
+    fn get_waits_for_wake_times(&self) -> Result<HashMap<(String, String), DateTime<Utc>>> {
+        Self::get_waits_for_wake_times(self)
+    }
+
+    fn resolve_external_dependency_statuses(
+        &self,
+        external_db_paths: &HashMap<String, PathBuf>,
+        blocking_only: bool,
+    ) -> Result<HashMap<String, bool>> {
+        Self::resolve_external_dependency_statuses(self, external_db_paths, blocking_only)
+    }
+
+    fn external_blockers(
+        &self,
+        external_statuses: &HashMap<String, bool>,
+    ) -> Result<HashMap<String, Vec<String>>> {
+        Self::external_blockers(self, external_statuses)
+    }
+
+    fn add_dependency(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &str,
+        actor: &str,
+    ) -> Result<bool> {
+        Self::add_dependency(self, issue_id, depends_on_id, dep_type, actor)
+    }
+
+    fn add_dependency_with_metadata(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &str,
+        metadata: Option<&str>,
+        actor: &str,
+    ) -> Result<bool> {
+        Self::add_dependency_with_metadata(self, issue_id, depends_on_id, dep_type, metadata, actor)
+    }
+
+    fn add_duplicate_and_close(
+        &mut self,
+        issue_id: &str,
+        of_id: &str,
+        actor: &str,
+    ) -> Result<(bool, bool)> {
+        Self::add_duplicate_and_close(self, issue_id, of_id, actor)
+    }
+
+    fn apply_dependency_changes(
+        &mut self,
+        changes: &[DependencyChange],
+        actor: &str,
+    ) -> Result<Vec<bool>> {
+        Self::apply_dependency_changes(self, changes, actor)
+    }
+
+    fn remove_dependency(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        actor: &str,
+    ) -> Result<bool> {
+        Self::remove_dependency(self, issue_id, depends_on_id, actor)
+    }
+
+    fn remove_all_dependencies(&mut self, issue_id: &str, actor: &str) -> Result<usize> {
+        Self::remove_all_dependencies(self, issue_id, actor)
+    }
+
+    fn get_parent_id(&self, issue_id: &str) -> Result<Option<String>> {
+        Self::get_parent_id(self, issue_id)
+    }
+
+    fn remove_parent(&mut self, issue_id: &str, actor: &str) -> Result<bool> {
+        Self::remove_parent(self, issue_id, actor)
+    }
+
+    fn get_dependencies(&self, issue_id: &str) -> Result<Vec<String>> {
+        Self::get_dependencies(self, issue_id)
+    }
+
+    fn get_dependents(&self, issue_id: &str) -> Result<Vec<String>> {
+        Self::get_dependents(self, issue_id)
+    }
+
+    fn get_dependencies_full(&self, issue_id: &str) -> Result<Vec<Dependency>> {
+        Self::get_dependencies_full(self, issue_id)
+    }
+
+    fn get_all_dependency_records(&self) -> Result<HashMap<String, Vec<Dependency>>> {
+        Self::get_all_dependency_records(self)
+    }
+
+    fn get_dependencies_with_metadata(
+        &self,
+        issue_id: &str,
+    ) -> Result<Vec<IssueWithDependencyMetadata>> {
+        Self::get_dependencies_with_metadata(self, issue_id)
+    }
+
+    fn get_dependents_with_metadata(
+        &self,
+        issue_id: &str,
+    ) -> Result<Vec<IssueWithDependencyMetadata>> {
+        Self::get_dependents_with_metadata(self, issue_id)
+    }
+
+    fn get_open_descendants(&self, parent_id: &str) -> Result<Vec<String>> {
+        Self::get_open_descendants(self, parent_id)
+    }
+
+    fn get_transitive_dependents(
+        &self,
+        issue_id: &str,
+        max_depth: Option<usize>,
+    ) -> Result<Vec<(String, usize)>> {
+        Self::get_transitive_dependents(self, issue_id, max_depth)
+    }
+
+    fn would_create_cycle(
+        &self,
+        issue_id: &str,
+        depends_on_id: &str,
+        blocking_only: bool,
+    ) -> Result<bool> {
+        Self::would_create_cycle(self, issue_id, depends_on_id, blocking_only)
+    }
+
+    fn find_dependency_cycles(&self, blocking_only: bool) -> Result<Vec<DependencyCycle>> {
+        Self::find_dependency_cycles(self, blocking_only)
+    }
+
+    fn count_dependencies_for_issues(
+        &self,
+        issue_ids: &[String],
+    ) -> Result<HashMap<String, usize>> {
+        Self::count_dependencies_for_issues(self, issue_ids)
+    }
+
+    fn count_dependents_for_issues(&self, issue_ids: &[String]) -> Result<HashMap<String, usize>> {
+        Self::count_dependents_for_issues(self, issue_ids)
+    }
+
+    fn add_label(&mut self, issue_id: &str, label: &str, actor: &str) -> Result<bool> {
+        Self::add_label(self, issue_id, label, actor)
+    }
+
+    fn remove_label(&mut self, issue_id: &str, label: &str, actor: &str) -> Result<bool> {
+        Self::remove_label(self, issue_id, label, actor)
+    }
+
+    fn set_labels(&mut self, issue_id: &str, labels: &[String], actor: &str) -> Result<()> {
+        Self::set_labels(self, issue_id, labels, actor)
+    }
+
+    fn remove_all_labels(&mut self, issue_id: &str, actor: &str) -> Result<usize> {
+        Self::remove_all_labels(self, issue_id, actor)
+    }
+
+    fn get_labels(&self, issue_id: &str) -> Result<Vec<String>> {
+        Self::get_labels(self, issue_id)
+    }
+
+    fn get_labels_for_issues(&self, issue_ids: &[String]) -> Result<HashMap<String, Vec<String>>> {
+        Self::get_labels_for_issues(self, issue_ids)
+    }
+
+    fn get_unique_labels_with_counts(&self) -> Result<Vec<(String, i64)>> {
+        Self::get_unique_labels_with_counts(self)
+    }
+
+    fn merge_labels(&mut self, sources: &[&str], target: &str, actor: &str) -> Result<usize> {
+        Self::merge_labels(self, sources, target, actor)
+    }
+
+    fn rename_label(&mut self, old_name: &str, new_name: &str, actor: &str) -> Result<usize> {
+        Self::rename_label(self, old_name, new_name, actor)
+    }
+
+    fn add_comment(&mut self, issue_id: &str, author: &str, text: &str) -> Result<Comment> {
+        Self::add_comment(self, issue_id, author, text)
+    }
+
+    fn edit_comment(&mut self, comment_id: i64, text: &str, actor: &str) -> Result<Comment> {
+        Self::edit_comment(self, comment_id, text, actor)
+    }
+
+    fn delete_comment(&mut self, comment_id: i64, actor: &str) -> Result<Comment> {
+        Self::delete_comment(self, comment_id, actor)
+    }
+
+    fn get_comments(&self, issue_id: &str) -> Result<Vec<Comment>> {
+        Self::get_comments(self, issue_id)
+    }
+
+    fn get_comment(&self, comment_id: i64) -> Result<Option<Comment>> {
+        Self::get_comment(self, comment_id)
+    }
+
+    fn get_all_comments(&self) -> Result<HashMap<String, Vec<Comment>>> {
+        Self::get_all_comments(self)
+    }
+
+    fn get_comments_mentioning(&self, name: &str) -> Result<Vec<Comment>> {
+        Self::get_comments_mentioning(self, name)
+    }
+
+    fn get_code_refs(&self, issue_id: &str) -> Result<Vec<CodeRef>> {
+        Self::get_code_refs(self, issue_id)
+    }
+
+    fn add_code_ref(&mut self, issue_id: &str, code_ref: &CodeRef, actor: &str) -> Result<bool> {
+        Self::add_code_ref(self, issue_id, code_ref, actor)
+    }
+
+    fn remove_code_ref(&mut self, issue_id: &str, code_ref: &CodeRef, actor: &str) -> Result<bool> {
+        Self::remove_code_ref(self, issue_id, code_ref, actor)
+    }
+
+    fn add_reaction(
+        &mut self,
+        issue_id: &str,
+        comment_id: Option<i64>,
+        reaction: &str,
+        actor: &str,
+    ) -> Result<bool> {
+        Self::add_reaction(self, issue_id, comment_id, reaction, actor)
+    }
+
+    fn remove_reaction(
+        &mut self,
+        issue_id: &str,
+        comment_id: Option<i64>,
+        reaction: &str,
+        actor: &str,
+    ) -> Result<bool> {
+        Self::remove_reaction(self, issue_id, comment_id, reaction, actor)
+    }
+
+    fn add_watcher(&mut self, issue_id: &str, watcher: &str, actor: &str) -> Result<bool> {
+        Self::add_watcher(self, issue_id, watcher, actor)
+    }
+
+    fn remove_watcher(&mut self, issue_id: &str, watcher: &str, actor: &str) -> Result<bool> {
+        Self::remove_watcher(self, issue_id, watcher, actor)
+    }
+
+    fn get_watchers(&self, issue_id: &str) -> Result<Vec<String>> {
+        Self::get_watchers(self, issue_id)
+    }
+
+    fn get_watched_issues(&self, watcher: &str) -> Result<Vec<(String, DateTime<Utc>)>> {
+        Self::get_watched_issues(self, watcher)
+    }
+
+    fn set_field(
+        &mut self,
+        issue_id: &str,
+        name: &str,
+        value: Option<&str>,
+        actor: &str,
+    ) -> Result<bool> {
+        Self::set_field(self, issue_id, name, value, actor)
+    }
+
+    fn get_events(&self, issue_id: &str, limit: usize) -> Result<Vec<Event>> {
+        Self::get_events(self, issue_id, limit)
+    }
+
+    fn get_all_events(&self, limit: usize) -> Result<Vec<Event>> {
+        Self::get_all_events(self, limit)
+    }
+
+    fn get_events_after(&self, after_id: i64) -> Result<Vec<Event>> {
+        Self::get_events_after(self, after_id)
+    }
+
+    fn max_event_id(&self) -> Result<i64> {
+        Self::max_event_id(self)
+    }
+
+    fn get_session_events(&self, session_id: &str) -> Result<Vec<Event>> {
+        Self::get_session_events(self, session_id)
+    }
+
+    fn get_config(&self, key: &str) -> Result<Option<String>> {
+        Self::get_config(self, key)
+    }
+
+    fn get_all_config(&self) -> Result<HashMap<String, String>> {
+        Self::get_all_config(self)
+    }
+
+    fn set_config(&mut self, key: &str, value: &str) -> Result<()> {
+        Self::set_config(self, key, value)
+    }
+
+    fn delete_config(&mut self, key: &str) -> Result<bool> {
+        Self::delete_config(self, key)
+    }
+
+    fn get_metadata(&self, key: &str) -> Result<Option<String>> {
+        Self::get_metadata(self, key)
+    }
+
+    fn set_metadata(&mut self, key: &str, value: &str) -> Result<()> {
+        Self::set_metadata(self, key, value)
+    }
+}
+
+/// `HasDependents` if other issues depend on `id`, unless `force`.
+fn check_dependents(storage: &SqliteStorage, id: &str, force: bool) -> Result<()> {
+    let count = storage.get_dependents(id)?.len();
+    if force || count == 0 {
+        return Ok(());
+    }
+    Err(BeadsError::HasDependents {
+        id: id.to_string(),
+        count,
+    })
+}
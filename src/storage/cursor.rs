@@ -0,0 +1,339 @@
+//! Opaque pagination cursors for list and search queries.
+//!
+//! A cursor records the sort keys of the last issue on a page. The next page
+//! is fetched with a keyset predicate (`WHERE (keys) > (cursor)`) rather than
+//! an offset, so pages stay stable while issues are created concurrently.
+//!
+//! Cursors are hex-encoded JSON and should be treated as opaque by callers.
+//!
+//! Timestamp columns are ordered and compared through `julianday()`, so stored
+//! values written as `...Z`, `...+00:00` or with a different fractional
+//! precision sort and match the same as the RFC 3339 keys held in the cursor.
+
+use std::fmt::Write as _;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+
+/// Column participating in a list ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OrderColumn {
+    Pinned,
+    Priority,
+    CreatedAt,
+    UpdatedAt,
+    Title,
+    Id,
+}
+
+impl OrderColumn {
+    /// SQL expression used in ORDER BY and keyset comparisons.
+    const fn sql(self) -> &'static str {
+        match self {
+            Self::Pinned => "COALESCE(pinned, 0)",
+            Self::Priority => "priority",
+            Self::CreatedAt => "julianday(created_at)",
+            Self::UpdatedAt => "julianday(updated_at)",
+            Self::Title => "title COLLATE NOCASE",
+            Self::Id => "id",
+        }
+    }
+
+    /// Placeholder for a cursor key, normalized the same way as [`Self::sql`].
+    const fn placeholder(self) -> &'static str {
+        match self {
+            Self::CreatedAt | Self::UpdatedAt => "julianday(?)",
+            Self::Pinned | Self::Priority | Self::Title | Self::Id => "?",
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Pinned => "pinned",
+            Self::Priority => "priority",
+            Self::CreatedAt => "created_at",
+            Self::UpdatedAt => "updated_at",
+            Self::Title => "title",
+            Self::Id => "id",
+        }
+    }
+
+    fn value(self, issue: &Issue) -> String {
+        match self {
+            Self::Pinned => i32::from(issue.pinned).to_string(),
+            Self::Priority => issue.priority.0.to_string(),
+            Self::CreatedAt => issue.created_at.to_rfc3339(),
+            Self::UpdatedAt => issue.updated_at.to_rfc3339(),
+            Self::Title => issue.title.clone(),
+            Self::Id => issue.id.clone(),
+        }
+    }
+}
+
+/// Effective ORDER BY for list queries: `(column, ascending)` pairs.
+///
+/// Mirrors the `--sort`/`--reverse` semantics of `br list`. Pinned issues
+/// always come first, regardless of the sort key or direction.
+pub(crate) fn order_columns(sort: Option<&str>, reverse: bool) -> Vec<(OrderColumn, bool)> {
+    let mut columns = vec![(OrderColumn::Pinned, false)];
+    columns.extend(match sort {
+        Some("priority") => vec![
+            (OrderColumn::Priority, !reverse),
+            (OrderColumn::CreatedAt, reverse),
+        ],
+        Some("created_at" | "created") => vec![(OrderColumn::CreatedAt, reverse)],
+        Some("updated_at" | "updated") => vec![(OrderColumn::UpdatedAt, reverse)],
+        Some("title") => vec![(OrderColumn::Title, !reverse)],
+        Some(_) => vec![
+            (OrderColumn::Priority, true),
+            (OrderColumn::CreatedAt, false),
+        ],
+        None => vec![
+            (OrderColumn::Priority, !reverse),
+            (OrderColumn::CreatedAt, reverse),
+        ],
+    });
+    columns
+}
+
+/// Render an ORDER BY clause (without the keyword).
+///
+/// When `paginate` is set, `id` is appended as a tiebreaker so the order is total.
+pub(crate) fn order_by_clause(columns: &[(OrderColumn, bool)], paginate: bool) -> String {
+    let mut clause = String::new();
+    for (i, (column, ascending)) in columns.iter().enumerate() {
+        if i > 0 {
+            clause.push_str(", ");
+        }
+        let _ = write!(
+            clause,
+            "{} {}",
+            column.sql(),
+            if *ascending { "ASC" } else { "DESC" }
+        );
+    }
+    if paginate {
+        clause.push_str(", id ASC");
+    }
+    clause
+}
+
+/// Position after the last issue of a page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListCursor {
+    /// Ordering the cursor was produced for (e.g. `priority:asc,created_at:desc,id:asc`).
+    #[serde(rename = "o")]
+    order: String,
+    /// Sort key values of the last issue, one per ordering column.
+    #[serde(rename = "k")]
+    keys: Vec<String>,
+}
+
+impl ListCursor {
+    /// Build the cursor pointing just past `issue` for the given ordering.
+    #[must_use]
+    pub fn after_issue(issue: &Issue, sort: Option<&str>, reverse: bool) -> Self {
+        let columns = paginated_columns(sort, reverse);
+        Self {
+            order: order_fingerprint(&columns),
+            keys: columns
+                .iter()
+                .map(|(column, _)| column.value(issue))
+                .collect(),
+        }
+    }
+
+    /// Encode as an opaque string.
+    #[must_use]
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_string(self).unwrap_or_default();
+        let mut out = String::with_capacity(json.len() * 2);
+        for byte in json.bytes() {
+            let _ = write!(out, "{byte:02x}");
+        }
+        out
+    }
+
+    /// Decode a cursor produced by [`Self::encode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the cursor is malformed.
+    pub fn decode(encoded: &str) -> Result<Self> {
+        let invalid = || BeadsError::validation("after", "invalid pagination cursor");
+        let encoded = encoded.trim();
+        if encoded.is_empty() || encoded.len() % 2 != 0 || !encoded.is_ascii() {
+            return Err(invalid());
+        }
+        let bytes = (0..encoded.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&encoded[i..i + 2], 16))
+            .collect::<std::result::Result<Vec<u8>, _>>()
+            .map_err(|_| invalid())?;
+        serde_json::from_slice(&bytes).map_err(|_| invalid())
+    }
+
+    /// Append a keyset predicate selecting rows after this cursor.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the cursor was produced for a different ordering.
+    pub(crate) fn push_predicate(
+        &self,
+        sort: Option<&str>,
+        reverse: bool,
+        sql: &mut String,
+        params: &mut Vec<Box<dyn rusqlite::ToSql>>,
+    ) -> Result<()> {
+        let columns = paginated_columns(sort, reverse);
+        if self.order != order_fingerprint(&columns) || self.keys.len() != columns.len() {
+            return Err(BeadsError::validation(
+                "after",
+                "cursor was produced for a different sort order",
+            ));
+        }
+
+        // (c0 > v0) OR (c0 = v0 AND c1 > v1) OR ... with > / < per direction
+        sql.push_str(" AND (");
+        for (i, &(column, ascending)) in columns.iter().enumerate() {
+            if i > 0 {
+                sql.push_str(" OR ");
+            }
+            sql.push('(');
+            for (prefix, _) in &columns[..i] {
+                let _ = write!(sql, "{} = {} AND ", prefix.sql(), prefix.placeholder());
+            }
+            let _ = write!(
+                sql,
+                "{} {} {}",
+                column.sql(),
+                if ascending { ">" } else { "<" },
+                column.placeholder()
+            );
+            sql.push(')');
+
+            for ((key_column, _), key) in columns[..=i].iter().zip(&self.keys) {
+                params.push(bind_key(*key_column, key)?);
+            }
+        }
+        sql.push(')');
+        Ok(())
+    }
+}
+
+fn paginated_columns(sort: Option<&str>, reverse: bool) -> Vec<(OrderColumn, bool)> {
+    let mut columns = order_columns(sort, reverse);
+    columns.push((OrderColumn::Id, true));
+    columns
+}
+
+fn order_fingerprint(columns: &[(OrderColumn, bool)]) -> String {
+    columns
+        .iter()
+        .map(|(column, ascending)| {
+            format!(
+                "{}:{}",
+                column.name(),
+                if *ascending { "asc" } else { "desc" }
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn bind_key(column: OrderColumn, value: &str) -> Result<Box<dyn rusqlite::ToSql>> {
+    if matches!(column, OrderColumn::Pinned | OrderColumn::Priority) {
+        let number: i32 = value
+            .parse()
+            .map_err(|_| BeadsError::validation("after", "invalid pagination cursor"))?;
+        Ok(Box::new(number))
+    } else {
+        Ok(Box::new(value.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trips() {
+        let issue = Issue {
+            id: "bd-abc".to_string(),
+            title: "Hello".to_string(),
+            ..Issue::default()
+        };
+        let cursor = ListCursor::after_issue(&issue, Some("title"), false);
+        let decoded = ListCursor::decode(&cursor.encode()).unwrap();
+        assert_eq!(decoded, cursor);
+        assert_eq!(
+            decoded.keys,
+            vec!["0".to_string(), "Hello".to_string(), "bd-abc".to_string()]
+        );
+    }
+
+    #[test]
+    fn decode_rejects_garbage() {
+        assert!(ListCursor::decode("").is_err());
+        assert!(ListCursor::decode("abc").is_err());
+        assert!(ListCursor::decode("zz").is_err());
+        assert!(ListCursor::decode("7b7d").is_err());
+    }
+
+    #[test]
+    fn predicate_rejects_mismatched_order() {
+        let cursor = ListCursor::after_issue(&Issue::default(), Some("title"), false);
+        let mut sql = String::new();
+        let mut params = Vec::new();
+        assert!(
+            cursor
+                .push_predicate(Some("priority"), false, &mut sql, &mut params)
+                .is_err()
+        );
+        assert!(
+            cursor
+                .push_predicate(Some("title"), false, &mut sql, &mut params)
+                .is_ok()
+        );
+        assert_eq!(params.len(), 6);
+    }
+
+    #[test]
+    fn timestamp_keys_match_other_stored_forms() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE issues (id TEXT, pinned INTEGER, priority INTEGER, created_at TEXT);
+             INSERT INTO issues VALUES
+                ('bd-a', 0, 2, '2024-01-01T00:00:00Z'),
+                ('bd-b', 0, 2, '2024-01-01T00:00:00.000+00:00'),
+                ('bd-c', 0, 2, '2024-01-02T00:00:00Z');",
+        )
+        .unwrap();
+
+        let last = Issue {
+            id: "bd-a".to_string(),
+            priority: crate::model::Priority(2),
+            created_at: "2024-01-01T00:00:00Z".parse().unwrap(),
+            ..Issue::default()
+        };
+        let cursor = ListCursor::after_issue(&last, Some("created_at"), true);
+        let mut sql = "SELECT id FROM issues WHERE 1 = 1".to_string();
+        let mut params = Vec::new();
+        cursor
+            .push_predicate(Some("created_at"), true, &mut sql, &mut params)
+            .unwrap();
+        let order = order_columns(Some("created_at"), true);
+        let _ = write!(sql, " ORDER BY {}", order_by_clause(&order, true));
+
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        let mut stmt = conn.prepare(&sql).unwrap();
+        let ids: Vec<String> = stmt
+            .query_map(params_refs.as_slice(), |row| row.get(0))
+            .unwrap()
+            .collect::<std::result::Result<_, _>>()
+            .unwrap();
+        assert_eq!(ids, vec!["bd-b".to_string(), "bd-c".to_string()]);
+    }
+}
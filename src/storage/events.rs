@@ -26,6 +26,7 @@ CREATE TABLE IF NOT EXISTS events (
     new_value TEXT,
     comment TEXT,
     created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    session_id TEXT DEFAULT '',
     FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
 );
 
@@ -355,7 +356,8 @@ pub fn insert_restored_event(
 pub fn get_events(conn: &Connection, issue_id: &str, limit: usize) -> Result<Vec<Event>> {
     let query = if limit > 0 {
         r"
-            SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at
+            SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at,
+                   session_id
             FROM events
             WHERE issue_id = ?1
             ORDER BY created_at DESC, id DESC
@@ -363,7 +365,8 @@ pub fn get_events(conn: &Connection, issue_id: &str, limit: usize) -> Result<Vec
             "
     } else {
         r"
-            SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at
+            SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at,
+                   session_id
             FROM events
             WHERE issue_id = ?1
             ORDER BY created_at DESC, id DESC
@@ -391,6 +394,9 @@ fn event_from_row(row: &rusqlite::Row) -> rusqlite::Result<Event> {
     let new_value: Option<String> = row.get(5)?;
     let comment: Option<String> = row.get(6)?;
     let created_at_str: String = row.get(7)?;
+    let session_id: Option<String> = row
+        .get::<_, Option<String>>(8)?
+        .filter(|value| !value.is_empty());
 
     // Parse event type
     let event_type = parse_event_type(&event_type_str);
@@ -407,6 +413,7 @@ fn event_from_row(row: &rusqlite::Row) -> rusqlite::Result<Event> {
         new_value,
         comment,
         created_at,
+        session_id,
     })
 }
 
@@ -432,14 +439,16 @@ fn parse_event_timestamp(value: &str) -> DateTime<Utc> {
 pub fn get_all_events(conn: &Connection, limit: usize) -> Result<Vec<Event>> {
     let query = if limit > 0 {
         r"
-            SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at
+            SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at,
+                   session_id
             FROM events
             ORDER BY created_at DESC, id DESC
             LIMIT ?1
             "
     } else {
         r"
-            SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at
+            SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at,
+                   session_id
             FROM events
             ORDER BY created_at DESC, id DESC
             "
@@ -457,6 +466,61 @@ pub fn get_all_events(conn: &Connection, limit: usize) -> Result<Vec<Event>> {
     Ok(events)
 }
 
+/// Get events with an ID above `after_id`, oldest first.
+///
+/// Event IDs only grow, so the largest ID seen before a command marks where
+/// that command's events begin.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn get_events_after(conn: &Connection, after_id: i64) -> Result<Vec<Event>> {
+    let mut stmt = conn.prepare(
+        r"
+            SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at,
+                   session_id
+            FROM events
+            WHERE id > ?1
+            ORDER BY id ASC
+            ",
+    )?;
+    let events = stmt
+        .query_map(params![after_id], event_from_row)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(events)
+}
+
+/// Get the events recorded in one session, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn get_session_events(conn: &Connection, session_id: &str) -> Result<Vec<Event>> {
+    let mut stmt = conn.prepare(
+        r"
+            SELECT id, issue_id, event_type, actor, old_value, new_value, comment, created_at,
+                   session_id
+            FROM events
+            WHERE session_id = ?1
+            ORDER BY created_at ASC, id ASC
+            ",
+    )?;
+    let events = stmt
+        .query_map(params![session_id], event_from_row)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(events)
+}
+
+/// Largest event ID, or 0 if there are no events.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn max_event_id(conn: &Connection) -> Result<i64> {
+    let id: Option<i64> = conn.query_row("SELECT MAX(id) FROM events", [], |row| row.get(0))?;
+    Ok(id.unwrap_or(0))
+}
+
 /// Get event count for an issue.
 ///
 /// # Errors
@@ -700,6 +764,26 @@ mod tests {
         assert_eq!(count, 5);
     }
 
+    #[test]
+    fn test_get_events_after() {
+        let conn = setup_test_db();
+        assert_eq!(max_event_id(&conn).expect("max id"), 0);
+
+        let tx = conn.unchecked_transaction().expect("Failed to start tx");
+        insert_created_event(&tx, "test-001", "alice").expect("Failed to insert event");
+        tx.commit().expect("Failed to commit");
+        let mark = max_event_id(&conn).expect("max id");
+
+        let tx = conn.unchecked_transaction().expect("Failed to start tx");
+        insert_commented_event(&tx, "test-001", "bob", "First").expect("Failed to insert event");
+        insert_closed_event(&tx, "test-001", "bob", Some("done")).expect("Failed to insert event");
+        tx.commit().expect("Failed to commit");
+
+        let events = get_events_after(&conn, mark).expect("Failed to get events");
+        let types: Vec<EventType> = events.into_iter().map(|e| e.event_type).collect();
+        assert_eq!(types, vec![EventType::Commented, EventType::Closed]);
+    }
+
     #[test]
     fn test_deleted_and_restored_events() {
         let conn = setup_test_db();
@@ -0,0 +1,1174 @@
+//! [`Storage`] over `beads_lib::InMemoryStore`.
+//!
+//! This is the `--no-db` backend: the JSONL file is loaded into memory, the
+//! command runs against it, and the caller saves it back. The library has its
+//! own copies of the model types with the same JSON form, so values cross the
+//! boundary through `serde_json`.
+//!
+//! Filters and writes that need a database (code-ref and dependency filters,
+//! cursors, pinning, code refs, reactions, watchers, custom fields, renames)
+//! are rejected rather than silently ignored. Data that only the database
+//! keeps (ID aliases, backlinks) comes back empty, and config and metadata
+//! set through this backend last for the process only. Dependencies must
+//! point at issues in the file, and the library refuses any cycle, not just
+//! blocking ones.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+
+use crate::error::{BeadsError, Result, ValidationError};
+use crate::format::{IssueDetails, IssueWithCounts, IssueWithDependencyMetadata};
+use crate::model::{
+    CodeRef, Comment, Dependency, DependencyCondition, Event, Issue, Priority, Status,
+};
+use crate::storage::backend::Storage;
+use crate::storage::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy};
+use beads_lib::InMemoryStore;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// In-memory storage backend, typically loaded from a JSONL file.
+pub struct MemoryStorage {
+    store: InMemoryStore,
+    /// Session recorded as `closed_by_session` on issues closed through
+    /// this handle.
+    session_id: Option<String>,
+    read_only: bool,
+    config: HashMap<String, String>,
+    metadata: HashMap<String, String>,
+}
+
+impl fmt::Debug for MemoryStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryStorage")
+            .field("issues", &self.store.len())
+            .field("session_id", &self.session_id)
+            .field("read_only", &self.read_only)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MemoryStorage {
+    #[must_use]
+    pub fn new(store: InMemoryStore) -> Self {
+        Self {
+            store,
+            session_id: None,
+            read_only: false,
+            config: HashMap::new(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn store(&self) -> &InMemoryStore {
+        &self.store
+    }
+
+    pub const fn store_mut(&mut self) -> &mut InMemoryStore {
+        &mut self.store
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> InMemoryStore {
+        self.store
+    }
+
+    /// Set the session ID recorded on issues closed by later mutations.
+    pub fn set_session_id(&mut self, session_id: Option<String>) {
+        self.session_id = session_id;
+    }
+
+    /// Refuse all further mutations through this handle.
+    pub const fn set_read_only(&mut self) {
+        self.read_only = true;
+    }
+
+    /// Whether any issue changed since the last [`Self::save_to`].
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.store.is_dirty()
+    }
+
+    /// Write every issue to `path` (temp file, then rename) and mark the
+    /// store clean.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an issue cannot be serialized or the file cannot
+    /// be written.
+    pub fn save_to(&mut self, path: &Path) -> Result<()> {
+        self.store.save_to(path)?;
+        self.store.clear_dirty();
+        Ok(())
+    }
+
+    /// Refuse `operation` on a read-only handle with [`BeadsError::ReadOnly`].
+    fn ensure_writable(&self, operation: &str) -> Result<()> {
+        if self.read_only {
+            return Err(BeadsError::ReadOnly {
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// `other_id` as the far end of `dep`, for issue details.
+    fn related(
+        &self,
+        other_id: &str,
+        dep: &beads_lib::Dependency,
+    ) -> Result<IssueWithDependencyMetadata> {
+        let (title, status, priority) = self.get_issue(other_id)?.map_or_else(
+            || (String::new(), Status::default(), Priority::MEDIUM),
+            |issue| (issue.title, issue.status, issue.priority),
+        );
+        Ok(IssueWithDependencyMetadata {
+            id: other_id.to_string(),
+            title,
+            status,
+            priority,
+            dep_type: dep.dep_type.as_str().to_string(),
+            condition: DependencyCondition::from_metadata(dep.metadata.as_deref())
+                .map(|condition| condition.to_string()),
+        })
+    }
+
+    /// Every comment on `issue_id`, deleted ones included, oldest first.
+    fn all_comments_on(&self, issue_id: &str) -> Vec<beads_lib::Comment> {
+        let mut comments: Vec<beads_lib::Comment> = self
+            .store
+            .get_all_comments()
+            .into_iter()
+            .filter(|(id, _)| id == issue_id)
+            .flat_map(|(_, comments)| comments)
+            .collect();
+        comments.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+        comments
+    }
+
+    /// The live comment with `comment_id`, wherever it is.
+    fn live_comment(&self, comment_id: i64) -> Result<beads_lib::Comment> {
+        let comment = self
+            .store
+            .get_all_comments()
+            .into_iter()
+            .flat_map(|(_, comments)| comments)
+            .find(|comment| comment.id == comment_id)
+            .ok_or_else(|| {
+                BeadsError::validation("comment", format!("comment {comment_id} not found"))
+            })?;
+        if comment.deleted {
+            return Err(BeadsError::validation(
+                "comment",
+                format!("comment {comment_id} is deleted"),
+            ));
+        }
+        Ok(comment)
+    }
+
+    fn comment(&self, issue_id: &str, comment_id: i64) -> Result<Comment> {
+        let comment = self
+            .all_comments_on(issue_id)
+            .into_iter()
+            .find(|comment| comment.id == comment_id)
+            .ok_or_else(|| {
+                BeadsError::validation("comment", format!("comment {comment_id} not found"))
+            })?;
+        convert(&comment)
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
+    }
+
+    fn create_issue(&mut self, issue: &Issue, actor: &str) -> Result<()> {
+        self.ensure_writable("create_issue")?;
+        self.store.create_issue(&convert(issue)?, actor)?;
+        Ok(())
+    }
+
+    fn update_issue(&mut self, id: &str, update: &IssueUpdate, actor: &str) -> Result<Issue> {
+        self.ensure_writable("update_issue")?;
+        if update.pinned.is_some() {
+            return Err(unsupported("pinned", "pinning"));
+        }
+        if update.expect_unassigned {
+            let current = self.store.get_issue(id)?.assignee.as_deref().map(str::trim);
+            let claim_actor = update.claim_actor.as_deref().unwrap_or("");
+            match current.filter(|assignee| !assignee.is_empty()) {
+                None => {}
+                Some(current) if !update.claim_exclusive && current == claim_actor => {}
+                Some(current) => {
+                    return Err(BeadsError::validation(
+                        "claim",
+                        format!("issue {id} already assigned to {current}"),
+                    ));
+                }
+            }
+        }
+        let updated = self
+            .store
+            .update_issue(id, &to_lib_update(update)?, actor)?;
+        convert(&updated)
+    }
+
+    fn get_issue(&self, id: &str) -> Result<Option<Issue>> {
+        match self.store.get_issue(id) {
+            Ok(issue) => Ok(Some(convert(issue)?)),
+            Err(beads_lib::BeadsError::IssueNotFound { .. }) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn get_issue_details(
+        &self,
+        id: &str,
+        include_comments: bool,
+        include_events: bool,
+        event_limit: usize,
+    ) -> Result<Option<IssueDetails>> {
+        let Some(issue) = self.get_issue(id)? else {
+            return Ok(None);
+        };
+        let mut dependencies = self
+            .store
+            .get_dependencies(id)
+            .into_iter()
+            .map(|dep| self.related(&dep.depends_on_id, dep))
+            .collect::<Result<Vec<_>>>()?;
+        dependencies.sort_by_key(|dep| dep.priority);
+        let mut dependents = self
+            .store
+            .get_dependents(id)
+            .into_iter()
+            .map(|dep| self.related(&dep.issue_id, dep))
+            .collect::<Result<Vec<_>>>()?;
+        dependents.sort_by_key(|dep| dep.priority);
+        let parent = self
+            .store
+            .get_dependencies(id)
+            .into_iter()
+            .find(|dep| dep.dep_type.as_str() == "parent-child")
+            .map(|dep| dep.depends_on_id.clone());
+        let comments = if include_comments {
+            self.get_comments(id)?
+                .into_iter()
+                .filter(|comment| !comment.deleted)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let events = if include_events {
+            self.get_events(id, event_limit)?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Some(IssueDetails {
+            labels: self.get_labels(id)?,
+            issue,
+            dependencies,
+            dependents,
+            referenced_by: Vec::new(),
+            comments,
+            reactions: Vec::new(),
+            events,
+            parent,
+        }))
+    }
+
+    fn find_by_external_ref(&self, external_ref: &str) -> Result<Option<Issue>> {
+        self.store
+            .get_all_issues_for_export()
+            .iter()
+            .find(|issue| issue.external_ref.as_deref() == Some(external_ref))
+            .map(convert)
+            .transpose()
+    }
+
+    fn reopen_issue(&mut self, id: &str, _reason: Option<&str>, actor: &str) -> Result<Issue> {
+        self.ensure_writable("reopen_issue")?;
+        let status = &self.store.get_issue(id)?.status;
+        if !status.is_terminal() {
+            return Err(BeadsError::validation(
+                "id",
+                format!("{id} is not closed (status: {})", status.as_str()),
+            ));
+        }
+        let update = beads_lib::IssueUpdate {
+            status: Some(beads_lib::Status::Open),
+            closed_at: Some(None),
+            close_reason: Some(None),
+            closed_by_session: Some(None),
+            deleted_at: Some(None),
+            deleted_by: Some(None),
+            delete_reason: Some(None),
+            ..beads_lib::IssueUpdate::default()
+        };
+        convert(&self.store.update_issue(id, &update, actor)?)
+    }
+
+    fn restore_issue(&mut self, id: &str, actor: &str, _reason: Option<&str>) -> Result<Issue> {
+        self.ensure_writable("restore_issue")?;
+        let issue = self.store.get_issue(id)?;
+        if issue.status != beads_lib::Status::Tombstone {
+            return Err(BeadsError::validation(
+                "id",
+                format!("{id} is not deleted (status: {})", issue.status.as_str()),
+            ));
+        }
+        let was_closed = issue.closed_at.is_some() && issue.close_reason.is_some();
+        let update = beads_lib::IssueUpdate {
+            status: Some(if was_closed {
+                beads_lib::Status::Closed
+            } else {
+                beads_lib::Status::Open
+            }),
+            deleted_at: Some(None),
+            deleted_by: Some(None),
+            delete_reason: Some(None),
+            ..beads_lib::IssueUpdate::default()
+        };
+        convert(&self.store.update_issue(id, &update, actor)?)
+    }
+
+    fn delete_issue(&mut self, id: &str, actor: &str, reason: &str, force: bool) -> Result<Issue> {
+        self.ensure_writable("delete_issue")?;
+        let deleted = self.store.delete_issue(id, actor, reason, force)?;
+        convert(&deleted)
+    }
+
+    fn purge_issue(&mut self, id: &str, actor: &str, force: bool) -> Result<()> {
+        self.ensure_writable("purge_issue")?;
+        self.store.purge_issue(id, actor, force)?;
+        Ok(())
+    }
+
+    fn rename_issue_ids(
+        &mut self,
+        _renames: &[(String, String)],
+        _actor: &str,
+        _comment: &str,
+    ) -> Result<()> {
+        Err(unsupported("id", "renaming issues"))
+    }
+
+    fn list_issues(&self, filters: &ListFilters) -> Result<Vec<Issue>> {
+        self.store
+            .list_issues(&to_lib_filters(filters)?)
+            .into_iter()
+            .map(convert)
+            .collect()
+    }
+
+    fn list_issues_with_counts(&self, filters: &ListFilters) -> Result<Vec<IssueWithCounts>> {
+        self.store
+            .list_issues_with_counts(&to_lib_filters(filters)?)
+            .into_iter()
+            .map(|(issue, dependency_count, dependent_count)| {
+                Ok(IssueWithCounts {
+                    issue: convert(issue)?,
+                    dependency_count,
+                    dependent_count,
+                })
+            })
+            .collect()
+    }
+
+    fn get_all_ids(&self) -> Result<Vec<String>> {
+        let mut ids = self.store.get_all_ids();
+        ids.sort();
+        Ok(ids)
+    }
+
+    fn count_issues(&self) -> Result<usize> {
+        Ok(self.store.len())
+    }
+
+    fn get_id_aliases(&self) -> Result<HashMap<String, String>> {
+        Ok(HashMap::new())
+    }
+
+    fn id_exists(&self, id: &str) -> Result<bool> {
+        Ok(self.store.id_exists(id))
+    }
+
+    fn find_ids_by_hash(&self, hash_suffix: &str) -> Result<Vec<String>> {
+        let needle = format!("-{hash_suffix}");
+        let mut ids: Vec<String> = self
+            .store
+            .get_all_ids()
+            .into_iter()
+            .filter(|id| id.contains(&needle))
+            .collect();
+        ids.sort();
+        Ok(ids)
+    }
+
+    fn get_ready_issues(
+        &self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+    ) -> Result<Vec<Issue>> {
+        if filters.depends_on.is_some() || filters.blocks.is_some() || filters.dep_type.is_some() {
+            return Err(unsupported("dep_type", "filtering by dependencies"));
+        }
+        let lib_filters = beads_lib::ReadyFilters {
+            assignee: filters.assignee.clone(),
+            unassigned: filters.unassigned,
+            labels_and: filters.labels_and.clone(),
+            labels_or: filters.labels_or.clone(),
+            types: filters.types.as_ref().map(convert).transpose()?,
+            priorities: filters.priorities.as_ref().map(convert).transpose()?,
+            include_deferred: filters.include_deferred,
+            // Applied below, after the filters the library lacks
+            limit: None,
+            parent: filters.parent.clone(),
+            recursive: filters.recursive,
+        };
+        let lib_sort = match sort {
+            ReadySortPolicy::Hybrid | ReadySortPolicy::Fit => beads_lib::ReadySortPolicy::Hybrid,
+            ReadySortPolicy::Priority | ReadySortPolicy::Aging => {
+                beads_lib::ReadySortPolicy::Priority
+            }
+            ReadySortPolicy::Oldest => beads_lib::ReadySortPolicy::Oldest,
+        };
+        let mut issues = self
+            .store
+            .get_ready_issues(&lib_filters, lib_sort)
+            .into_iter()
+            .map(convert)
+            .collect::<Result<Vec<Issue>>>()?;
+        if let Some(max_minutes) = filters.max_minutes {
+            issues.retain(|issue| issue.estimated_minutes.is_some_and(|m| m <= max_minutes));
+        }
+        if sort == ReadySortPolicy::Aging {
+            issues.sort_by_key(|issue| (issue.priority, issue.updated_at));
+        }
+        if let Some(limit) = filters.limit {
+            issues.truncate(limit);
+        }
+        Ok(issues)
+    }
+
+    fn get_blocked_issues(&self) -> Result<Vec<(Issue, Vec<String>)>> {
+        let mut blocked = Vec::new();
+        for issue in self.store.get_blocked_issues() {
+            if !matches!(
+                issue.status,
+                beads_lib::Status::Open | beads_lib::Status::InProgress
+            ) {
+                continue;
+            }
+            let blockers: Vec<String> = self
+                .store
+                .get_blockers(&issue.id)
+                .into_iter()
+                .map(|blocker| format!("{}:{}", blocker.id, blocker.status.as_str()))
+                .collect();
+            blocked.push((convert::<_, Issue>(issue)?, blockers));
+        }
+        blocked.sort_by(|(a, _), (b, _)| {
+            a.priority
+                .cmp(&b.priority)
+                .then(a.created_at.cmp(&b.created_at))
+        });
+        Ok(blocked)
+    }
+
+    fn get_blockers(&self, issue_id: &str) -> Result<Vec<String>> {
+        Ok(self
+            .store
+            .get_blockers(issue_id)
+            .into_iter()
+            .map(|blocker| blocker.id.clone())
+            .collect())
+    }
+
+    fn rebuild_blocked_cache(&mut self, _force_rebuild: bool) -> Result<usize> {
+        // Blocked state is computed on every query; there is nothing to keep
+        Ok(self.store.get_blocked_issues().len())
+    }
+
+    fn resolve_external_dependency_statuses(
+        &self,
+        _external_db_paths: &HashMap<String, PathBuf>,
+        _blocking_only: bool,
+    ) -> Result<HashMap<String, bool>> {
+        // The library only accepts dependencies on issues in the file
+        Ok(HashMap::new())
+    }
+
+    fn add_dependency_with_metadata(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &str,
+        metadata: Option<&str>,
+        actor: &str,
+    ) -> Result<bool> {
+        self.ensure_writable("add_dependency")?;
+        if self.store.dependency_exists(issue_id, depends_on_id) {
+            return Ok(false);
+        }
+        self.store.add_dependency(
+            issue_id,
+            depends_on_id,
+            dep_type.parse()?,
+            actor,
+            metadata.map(str::to_string),
+        )?;
+        Ok(true)
+    }
+
+    fn remove_dependency(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        actor: &str,
+    ) -> Result<bool> {
+        self.ensure_writable("remove_dependency")?;
+        match self.store.remove_dependency(issue_id, depends_on_id, actor) {
+            Ok(()) => Ok(true),
+            Err(beads_lib::BeadsError::NothingToDo { .. }) => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn get_dependencies(&self, issue_id: &str) -> Result<Vec<String>> {
+        Ok(self
+            .store
+            .get_dependencies(issue_id)
+            .into_iter()
+            .map(|dep| dep.depends_on_id.clone())
+            .collect())
+    }
+
+    fn get_dependents(&self, issue_id: &str) -> Result<Vec<String>> {
+        Ok(self
+            .store
+            .get_dependents(issue_id)
+            .into_iter()
+            .map(|dep| dep.issue_id.clone())
+            .collect())
+    }
+
+    fn get_dependencies_full(&self, issue_id: &str) -> Result<Vec<Dependency>> {
+        self.store
+            .get_dependencies(issue_id)
+            .into_iter()
+            .map(convert)
+            .collect()
+    }
+
+    fn get_all_dependency_records(&self) -> Result<HashMap<String, Vec<Dependency>>> {
+        let mut records: HashMap<String, Vec<Dependency>> = HashMap::new();
+        for dep in self.store.get_all_dependency_records() {
+            records
+                .entry(dep.issue_id.clone())
+                .or_default()
+                .push(convert(&dep)?);
+        }
+        Ok(records)
+    }
+
+    fn get_open_descendants(&self, parent_id: &str) -> Result<Vec<String>> {
+        // Breadth first, so each issue keeps its shallowest depth (as in SQL)
+        let mut depths: HashMap<String, usize> = HashMap::new();
+        let mut frontier = vec![parent_id.to_string()];
+        let mut depth = 0;
+        while !frontier.is_empty() && depth < 64 {
+            depth += 1;
+            let mut next = Vec::new();
+            for id in &frontier {
+                for dep in self.store.get_dependents(id) {
+                    if dep.dep_type.as_str() == "parent-child"
+                        && dep.issue_id != parent_id
+                        && !depths.contains_key(&dep.issue_id)
+                    {
+                        depths.insert(dep.issue_id.clone(), depth);
+                        next.push(dep.issue_id.clone());
+                    }
+                }
+            }
+            frontier = next;
+        }
+
+        let mut open = Vec::new();
+        for (id, depth) in depths {
+            if self
+                .get_issue(&id)?
+                .is_some_and(|issue| !matches!(issue.status, Status::Closed | Status::Tombstone))
+            {
+                open.push((depth, id));
+            }
+        }
+        open.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        Ok(open.into_iter().map(|(_, id)| id).collect())
+    }
+
+    fn would_create_cycle(
+        &self,
+        issue_id: &str,
+        depends_on_id: &str,
+        blocking_only: bool,
+    ) -> Result<bool> {
+        let mut seen = HashSet::new();
+        let mut stack = vec![depends_on_id.to_string()];
+        while let Some(current) = stack.pop() {
+            if current == issue_id {
+                return Ok(true);
+            }
+            if !seen.insert(current.clone()) {
+                continue;
+            }
+            for dep in self.store.get_dependencies(&current) {
+                if !blocking_only || dep.dep_type.is_blocking() {
+                    stack.push(dep.depends_on_id.clone());
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    fn add_label(&mut self, issue_id: &str, label: &str, actor: &str) -> Result<bool> {
+        self.ensure_writable("add_label")?;
+        let existed = self.store.get_labels(issue_id).contains(&label);
+        self.store.add_label(issue_id, label, actor)?;
+        Ok(!existed)
+    }
+
+    fn remove_label(&mut self, issue_id: &str, label: &str, actor: &str) -> Result<bool> {
+        self.ensure_writable("remove_label")?;
+        let existed = self.store.get_labels(issue_id).contains(&label);
+        self.store.remove_label(issue_id, label, actor)?;
+        Ok(existed)
+    }
+
+    fn get_labels(&self, issue_id: &str) -> Result<Vec<String>> {
+        let mut labels: Vec<String> = self
+            .store
+            .get_labels(issue_id)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        labels.sort();
+        Ok(labels)
+    }
+
+    fn get_unique_labels_with_counts(&self) -> Result<Vec<(String, i64)>> {
+        let mut counts: Vec<(String, i64)> = self
+            .store
+            .get_unique_labels_with_counts()
+            .into_iter()
+            .map(|(label, count)| (label, i64::try_from(count).unwrap_or(i64::MAX)))
+            .collect();
+        counts.sort();
+        Ok(counts)
+    }
+
+    fn add_comment(&mut self, issue_id: &str, author: &str, text: &str) -> Result<Comment> {
+        self.ensure_writable("add_comment")?;
+        convert(&self.store.add_comment(issue_id, author, text)?)
+    }
+
+    fn edit_comment(&mut self, comment_id: i64, text: &str, actor: &str) -> Result<Comment> {
+        self.ensure_writable("edit_comment")?;
+        let issue_id = self.live_comment(comment_id)?.issue_id;
+        self.store
+            .update_comment(&issue_id, comment_id, text, actor)?;
+        self.comment(&issue_id, comment_id)
+    }
+
+    fn delete_comment(&mut self, comment_id: i64, actor: &str) -> Result<Comment> {
+        self.ensure_writable("delete_comment")?;
+        let issue_id = self.live_comment(comment_id)?.issue_id;
+        self.store.delete_comment(&issue_id, comment_id, actor)?;
+        self.comment(&issue_id, comment_id)
+    }
+
+    fn get_comments(&self, issue_id: &str) -> Result<Vec<Comment>> {
+        self.all_comments_on(issue_id).iter().map(convert).collect()
+    }
+
+    fn get_all_comments(&self) -> Result<HashMap<String, Vec<Comment>>> {
+        self.store
+            .get_all_comments()
+            .into_iter()
+            .map(|(id, comments)| Ok((id, convert(&comments)?)))
+            .collect()
+    }
+
+    fn add_code_ref(&mut self, _issue_id: &str, _code_ref: &CodeRef, _actor: &str) -> Result<bool> {
+        Err(unsupported("path", "linking code"))
+    }
+
+    fn remove_code_ref(
+        &mut self,
+        _issue_id: &str,
+        _code_ref: &CodeRef,
+        _actor: &str,
+    ) -> Result<bool> {
+        Err(unsupported("path", "linking code"))
+    }
+
+    fn add_reaction(
+        &mut self,
+        _issue_id: &str,
+        _comment_id: Option<i64>,
+        _reaction: &str,
+        _actor: &str,
+    ) -> Result<bool> {
+        Err(unsupported("reaction", "reacting"))
+    }
+
+    fn remove_reaction(
+        &mut self,
+        _issue_id: &str,
+        _comment_id: Option<i64>,
+        _reaction: &str,
+        _actor: &str,
+    ) -> Result<bool> {
+        Err(unsupported("reaction", "reacting"))
+    }
+
+    fn add_watcher(&mut self, _issue_id: &str, _watcher: &str, _actor: &str) -> Result<bool> {
+        Err(unsupported("watcher", "watching issues"))
+    }
+
+    fn remove_watcher(&mut self, _issue_id: &str, _watcher: &str, _actor: &str) -> Result<bool> {
+        Err(unsupported("watcher", "watching issues"))
+    }
+
+    fn get_watched_issues(&self, watcher: &str) -> Result<Vec<(String, DateTime<Utc>)>> {
+        // The file keeps who watches, not since when; the issue's creation
+        // time stands in
+        let mut issues: Vec<(String, DateTime<Utc>)> = self
+            .store
+            .get_all_issues_for_export()
+            .into_iter()
+            .filter(|issue| issue.watchers.iter().any(|name| name == watcher))
+            .map(|issue| (issue.id, issue.created_at))
+            .collect();
+        issues.sort();
+        Ok(issues)
+    }
+
+    fn set_field(
+        &mut self,
+        _issue_id: &str,
+        _name: &str,
+        _value: Option<&str>,
+        _actor: &str,
+    ) -> Result<bool> {
+        Err(unsupported("set", "custom fields"))
+    }
+
+    fn get_events(&self, issue_id: &str, limit: usize) -> Result<Vec<Event>> {
+        let limit = if limit == 0 { usize::MAX } else { limit };
+        self.store
+            .get_events(issue_id)
+            .into_iter()
+            .rev()
+            .take(limit)
+            .map(convert)
+            .collect()
+    }
+
+    fn get_all_events(&self, limit: usize) -> Result<Vec<Event>> {
+        let limit = if limit == 0 { usize::MAX } else { limit };
+        self.store
+            .get_all_events()
+            .iter()
+            .rev()
+            .take(limit)
+            .map(convert)
+            .collect()
+    }
+
+    fn get_events_after(&self, after_id: i64) -> Result<Vec<Event>> {
+        self.store
+            .get_all_events()
+            .iter()
+            .filter(|event| event.id > after_id)
+            .map(convert)
+            .collect()
+    }
+
+    fn get_config(&self, key: &str) -> Result<Option<String>> {
+        Ok(self.config.get(key).cloned())
+    }
+
+    fn get_all_config(&self) -> Result<HashMap<String, String>> {
+        Ok(self.config.clone())
+    }
+
+    fn set_config(&mut self, key: &str, value: &str) -> Result<()> {
+        self.ensure_writable("set_config")?;
+        self.config.insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn delete_config(&mut self, key: &str) -> Result<bool> {
+        self.ensure_writable("delete_config")?;
+        Ok(self.config.remove(key).is_some())
+    }
+
+    fn get_metadata(&self, key: &str) -> Result<Option<String>> {
+        Ok(self.metadata.get(key).cloned())
+    }
+
+    fn set_metadata(&mut self, key: &str, value: &str) -> Result<()> {
+        self.ensure_writable("set_metadata")?;
+        self.metadata.insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+}
+
+/// Convert between this crate's types and their `beads_lib` twins.
+fn convert<T: Serialize, U: DeserializeOwned>(value: &T) -> Result<U> {
+    Ok(serde_json::from_value(serde_json::to_value(value)?)?)
+}
+
+fn unsupported(field: &str, what: &str) -> BeadsError {
+    BeadsError::validation(field, format!("{what} is not supported without a database"))
+}
+
+fn to_lib_filters(filters: &ListFilters) -> Result<beads_lib::ListFilters> {
+    if filters.touches.is_some() {
+        return Err(unsupported("touches", "filtering by code refs"));
+    }
+    if filters.depends_on.is_some() || filters.blocks.is_some() || filters.dep_type.is_some() {
+        return Err(unsupported("dep_type", "filtering by dependencies"));
+    }
+    if filters.after.is_some() {
+        return Err(unsupported("after", "cursor pagination"));
+    }
+    if !filters.field_filters.is_empty() {
+        return Err(unsupported("where", "filtering by custom fields"));
+    }
+    Ok(beads_lib::ListFilters {
+        statuses: filters.statuses.as_ref().map(convert).transpose()?,
+        types: filters.types.as_ref().map(convert).transpose()?,
+        priorities: filters.priorities.as_ref().map(convert).transpose()?,
+        assignee: filters.assignee.clone(),
+        unassigned: filters.unassigned,
+        include_closed: filters.include_closed,
+        include_deferred: filters.include_deferred,
+        include_templates: filters.include_templates,
+        title_contains: filters.title_contains.clone(),
+        limit: filters.limit,
+        sort: filters.sort.clone(),
+        reverse: filters.reverse,
+        labels: filters.labels.clone(),
+        labels_or: filters.labels_or.clone(),
+        updated_before: filters.updated_before,
+        updated_after: filters.updated_after,
+    })
+}
+
+fn to_lib_update(update: &IssueUpdate) -> Result<beads_lib::IssueUpdate> {
+    Ok(beads_lib::IssueUpdate {
+        title: update.title.clone(),
+        description: update.description.clone(),
+        design: update.design.clone(),
+        acceptance_criteria: update.acceptance_criteria.clone(),
+        notes: update.notes.clone(),
+        status: update.status.as_ref().map(convert).transpose()?,
+        priority: update.priority.as_ref().map(convert).transpose()?,
+        issue_type: update.issue_type.as_ref().map(convert).transpose()?,
+        assignee: update.assignee.clone(),
+        owner: update.owner.clone(),
+        estimated_minutes: update.estimated_minutes,
+        due_at: update.due_at,
+        defer_until: update.defer_until,
+        external_ref: update.external_ref.clone(),
+        closed_at: update.closed_at,
+        close_reason: update.close_reason.clone(),
+        closed_by_session: update.closed_by_session.clone(),
+        deleted_at: update.deleted_at,
+        deleted_by: update.deleted_by.clone(),
+        delete_reason: update.delete_reason.clone(),
+    })
+}
+
+impl From<beads_lib::BeadsError> for BeadsError {
+    fn from(err: beads_lib::BeadsError) -> Self {
+        use beads_lib::BeadsError as Lib;
+        match err {
+            Lib::IssueNotFound { id } => Self::IssueNotFound { id },
+            Lib::CommentNotFound {
+                issue_id,
+                comment_id,
+            } => Self::CommentNotFound {
+                issue_id,
+                comment_id,
+            },
+            Lib::IdCollision { id } => Self::IdCollision { id },
+            Lib::AmbiguousId { partial, matches } => Self::AmbiguousId { partial, matches },
+            Lib::InvalidId { id } => Self::InvalidId { id },
+            Lib::Validation { field, reason } => Self::Validation { field, reason },
+            Lib::ValidationErrors { errors } => Self::ValidationErrors {
+                errors: errors
+                    .into_iter()
+                    .map(|error| ValidationError {
+                        field: error.field,
+                        message: error.message,
+                    })
+                    .collect(),
+            },
+            Lib::InvalidStatus { status } => Self::InvalidStatus { status },
+            Lib::InvalidType { issue_type } => Self::InvalidType { issue_type },
+            Lib::InvalidPriority { priority } => Self::InvalidPriority { priority },
+            Lib::JsonlParse { line, reason } => Self::JsonlParse { line, reason },
+            Lib::PrefixMismatch { expected, found } => Self::PrefixMismatch { expected, found },
+            Lib::DependencyCycle { path } => Self::DependencyCycle { path },
+            Lib::HasDependents { id, count } => Self::HasDependents { id, count },
+            Lib::SelfDependency { id } => Self::SelfDependency { id },
+            Lib::DependencyNotFound { id } => Self::DependencyNotFound { id },
+            Lib::DuplicateDependency { from, to } => Self::DuplicateDependency { from, to },
+            Lib::Io(err) => Self::Io(err),
+            Lib::Json(err) => Self::Json(err),
+            Lib::NothingToDo { reason } => Self::NothingToDo { reason },
+            Lib::Config(message) => Self::Config(message),
+            other => Self::Other(other.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::SqliteStorage;
+
+    fn issue(id: &str, title: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            ..Issue::default()
+        }
+    }
+
+    /// The same operations give the same answers on every backend.
+    fn exercise(storage: &mut dyn Storage) {
+        storage
+            .create_issue(&issue("bd-a1", "Parent"), "tester")
+            .unwrap();
+        storage
+            .create_issue(&issue("bd-b2", "Child"), "tester")
+            .unwrap();
+        assert!(storage.id_exists("bd-a1").unwrap());
+        assert_eq!(storage.count_issues().unwrap(), 2);
+        assert_eq!(storage.find_ids_by_hash("b2").unwrap(), vec!["bd-b2"]);
+        assert!(storage.get_issue("bd-zz").unwrap().is_none());
+
+        assert!(storage.add_label("bd-a1", "ops", "tester").unwrap());
+        assert!(!storage.add_label("bd-a1", "ops", "tester").unwrap());
+        assert_eq!(storage.get_labels("bd-a1").unwrap(), vec!["ops"]);
+        assert_eq!(
+            storage.get_unique_labels_with_counts().unwrap(),
+            vec![("ops".to_string(), 1)]
+        );
+        assert!(!storage.remove_label("bd-b2", "ops", "tester").unwrap());
+
+        assert!(
+            storage
+                .add_dependency("bd-b2", "bd-a1", "blocks", "tester")
+                .unwrap()
+        );
+        assert_eq!(storage.get_dependencies("bd-b2").unwrap(), vec!["bd-a1"]);
+        assert_eq!(storage.get_dependents("bd-a1").unwrap(), vec!["bd-b2"]);
+        assert_eq!(storage.get_all_ids().unwrap(), vec!["bd-a1", "bd-b2"]);
+        assert!(storage.would_create_cycle("bd-a1", "bd-b2", true).unwrap());
+        assert_eq!(storage.get_blockers("bd-b2").unwrap(), vec!["bd-a1"]);
+        let blocked: Vec<(String, Vec<String>)> = storage
+            .get_blocked_issues()
+            .unwrap()
+            .into_iter()
+            .map(|(issue, blockers)| (issue.id, blockers))
+            .collect();
+        assert_eq!(
+            blocked,
+            vec![("bd-b2".to_string(), vec!["bd-a1:open".to_string()])]
+        );
+        let ready: Vec<String> = storage
+            .get_ready_issues(&ReadyFilters::default(), ReadySortPolicy::default())
+            .unwrap()
+            .into_iter()
+            .map(|issue| issue.id)
+            .collect();
+        assert_eq!(ready, vec!["bd-a1"]);
+        let impacted = storage.get_transitive_dependents("bd-a1", None).unwrap();
+        assert_eq!(impacted, vec![("bd-b2".to_string(), 1)]);
+        let details = storage
+            .get_issue_details("bd-b2", true, false, 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(details.dependencies.len(), 1);
+        assert_eq!(details.dependencies[0].id, "bd-a1");
+        assert_eq!(details.dependencies[0].title, "Parent");
+        assert_eq!(details.dependencies[0].dep_type, "blocks");
+        assert!(
+            storage
+                .get_issue_details("bd-zz", true, false, 0)
+                .unwrap()
+                .is_none()
+        );
+        let mut counts: Vec<(String, usize, usize)> = storage
+            .list_issues_with_counts(&ListFilters::default())
+            .unwrap()
+            .into_iter()
+            .map(|row| (row.issue.id, row.dependency_count, row.dependent_count))
+            .collect();
+        counts.sort();
+        assert_eq!(
+            counts,
+            vec![("bd-a1".to_string(), 0, 1), ("bd-b2".to_string(), 1, 0)]
+        );
+        assert!(
+            storage
+                .remove_dependency("bd-b2", "bd-a1", "tester")
+                .unwrap()
+        );
+        assert!(
+            !storage
+                .remove_dependency("bd-b2", "bd-a1", "tester")
+                .unwrap()
+        );
+
+        storage
+            .add_dependency("bd-b2", "bd-a1", "parent-child", "tester")
+            .unwrap();
+        assert_eq!(
+            storage.get_open_descendants("bd-a1").unwrap(),
+            vec!["bd-b2"]
+        );
+        assert!(storage.get_open_descendants("bd-b2").unwrap().is_empty());
+        assert_eq!(
+            storage.get_parent_id("bd-b2").unwrap().as_deref(),
+            Some("bd-a1")
+        );
+        assert!(storage.remove_parent("bd-b2", "tester").unwrap());
+        assert!(!storage.remove_parent("bd-b2", "tester").unwrap());
+
+        exercise_writes(storage);
+    }
+
+    /// Comments, updates, reopening and deletion, continuing from [`exercise`].
+    fn exercise_writes(storage: &mut dyn Storage) {
+        let comment = storage
+            .add_comment("bd-a1", "tester", "Looks good, @Alice")
+            .unwrap();
+        let comments = storage.get_comments("bd-a1").unwrap();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].id, comment.id);
+        assert_eq!(comments[0].body, "Looks good, @Alice");
+        assert_eq!(storage.get_comments_mentioning("alice").unwrap().len(), 1);
+        let edited = storage
+            .edit_comment(comment.id, "Looks fine", "tester")
+            .unwrap();
+        assert_eq!(edited.body, "Looks fine");
+        assert!(
+            storage
+                .delete_comment(comment.id, "tester")
+                .unwrap()
+                .deleted
+        );
+        assert!(storage.delete_comment(comment.id, "tester").is_err());
+
+        assert_eq!(
+            storage.merge_labels(&["ops"], "infra", "tester").unwrap(),
+            1
+        );
+        assert_eq!(storage.get_labels("bd-a1").unwrap(), vec!["infra"]);
+
+        let update = IssueUpdate {
+            status: Some(Status::InProgress),
+            ..IssueUpdate::default()
+        };
+        let updated = storage.update_issue("bd-b2", &update, "tester").unwrap();
+        assert_eq!(updated.status, Status::InProgress);
+        let listed = storage
+            .list_issues(&ListFilters {
+                statuses: Some(vec![Status::InProgress]),
+                ..ListFilters::default()
+            })
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, "bd-b2");
+        assert!(!storage.get_events("bd-b2", 1).unwrap().is_empty());
+
+        assert!(storage.reopen_issue("bd-b2", None, "tester").is_err());
+        let close = IssueUpdate {
+            status: Some(Status::Closed),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue("bd-a1", &close, "tester").unwrap();
+        let reopened = storage
+            .reopen_issue("bd-a1", Some("not done"), "tester")
+            .unwrap();
+        assert_eq!(reopened.status, Status::Open);
+        assert!(reopened.closed_at.is_none());
+
+        storage
+            .add_dependency("bd-b2", "bd-a1", "blocks", "tester")
+            .unwrap();
+        assert!(matches!(
+            storage.delete_issue("bd-a1", "tester", "cleanup", false),
+            Err(BeadsError::HasDependents { count: 1, .. })
+        ));
+        let deleted = storage
+            .delete_issue("bd-b2", "tester", "cleanup", false)
+            .unwrap();
+        assert_eq!(deleted.status, Status::Tombstone);
+        assert_eq!(deleted.deleted_by.as_deref(), Some("tester"));
+        assert!(storage.is_tombstone("bd-b2").unwrap());
+        let listed = storage.list_issues(&ListFilters::default()).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, "bd-a1");
+        let restored = storage.restore_issue("bd-b2", "tester", None).unwrap();
+        assert_eq!(restored.status, Status::Open);
+        storage
+            .delete_issue("bd-b2", "tester", "cleanup", false)
+            .unwrap();
+        storage.purge_issue("bd-b2", "tester", false).unwrap();
+        assert!(storage.get_issue("bd-b2").unwrap().is_none());
+        assert!(!storage.id_exists("bd-b2").unwrap());
+    }
+
+    #[test]
+    fn test_backends_agree() {
+        exercise(&mut SqliteStorage::open_memory().unwrap());
+        exercise(&mut MemoryStorage::new(InMemoryStore::new()));
+    }
+
+    #[test]
+    fn test_memory_rejects_database_only_filters() {
+        let storage = MemoryStorage::new(InMemoryStore::new());
+        let filters = ListFilters {
+            touches: Some("src/".to_string()),
+            ..ListFilters::default()
+        };
+        assert!(storage.list_issues(&filters).is_err());
+    }
+
+    #[test]
+    fn test_memory_read_only_refuses_writes() {
+        let mut storage = MemoryStorage::new(InMemoryStore::new());
+        storage.set_read_only();
+        assert!(matches!(
+            storage.create_issue(&issue("bd-a1", "Task"), "tester"),
+            Err(BeadsError::ReadOnly { .. })
+        ));
+    }
+}
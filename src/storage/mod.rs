@@ -1,19 +1,40 @@
 //! `SQLite` storage layer for `beads_rust`.
 //!
 //! This module provides the persistence layer using `SQLite` with:
-//! - WAL mode for concurrent reads
+//! - WAL mode for concurrent reads, applied with the other connection
+//!   PRAGMAs on every open (see [`pragmas`])
+//! - Prepared statement caching keyed by SQL text
 //! - Transaction discipline for atomic writes
 //! - Dirty tracking for JSONL export
 //! - Blocked cache for ready/blocked queries
 //!
 //! # Submodules
 //!
+//! - [`backend`] - The [`Storage`] trait shared by every backend
+//! - [`cursor`] - Opaque keyset pagination cursors
 //! - [`events`] - Audit event storage (insertion, retrieval)
+//! - [`memory`] - [`Storage`] over the JSONL file held in memory (`--no-db`)
+//! - [`plan`] - Change plans recorded by `--plan` and replayed by `br apply`
 //! - [`schema`] - Database schema definitions
+//! - [`search`] - Field-scoped text and regex search queries
 //! - [`sqlite`] - Main `SQLite` storage implementation
 
+pub mod backend;
+pub mod cursor;
 pub mod events;
+pub mod memory;
+pub mod plan;
 pub mod schema;
+pub mod search;
 pub mod sqlite;
 
-pub use sqlite::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, SqliteStorage};
+pub use backend::{Backend, Storage};
+pub use cursor::ListCursor;
+pub use memory::MemoryStorage;
+pub use plan::{ChangePlan, PlannedChange};
+pub use schema::{ConnectionPragmas, pragmas};
+pub use search::{SearchField, SearchQuery, TextMatcher};
+pub use sqlite::{
+    CycleEdge, DependencyChange, DependencyCycle, FieldFilter, FieldOp, IssueUpdate, ListFilters,
+    ReadyFilters, ReadySortPolicy, SqliteStorage,
+};
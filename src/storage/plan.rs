@@ -0,0 +1,529 @@
+//! Change plans.
+//!
+//! A [`ChangePlan`] is the difference between two states of a database,
+//! issue by issue: the issues a command would create, and for every issue it
+//! would change, the old and new value of each changed field (labels,
+//! dependencies, comments and code references count as fields). `br --plan`
+//! records one by running a command against a copy of the database; `br
+//! apply` replays it through the normal mutation paths, so events, dirty
+//! tracking and the blocked cache stay correct.
+//!
+//! Before writing anything, `apply` checks that every issue it updates still
+//! has the recorded old values and that no issue it creates exists yet. The
+//! checks and every write run in one immediate transaction, so a plan is
+//! applied completely or not at all.
+//! Ephemeral issues are not exported and so not planned.
+
+use crate::error::{BeadsError, Result};
+use crate::model::{Comment, Issue, Reaction, Status};
+use crate::storage::{IssueUpdate, SqliteStorage};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Version of the plan format written by this `br`.
+pub const PLAN_VERSION: u32 = 1;
+
+/// Fields left out of plans: they change as a side effect of every write.
+const DERIVED_FIELDS: [&str; 2] = ["updated_at", "original_type"];
+
+/// Planned changes, in the order they are applied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangePlan {
+    pub version: u32,
+    /// The command line the plan was recorded from.
+    pub command: String,
+    pub created_at: DateTime<Utc>,
+    pub operations: Vec<PlannedChange>,
+}
+
+/// One planned change to one issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PlannedChange {
+    /// Create an issue, with its labels, dependencies and comments.
+    Create { id: String, issue: Box<Issue> },
+    /// Change fields of an existing issue; `before` and `after` hold the
+    /// changed fields only (`null` for unset).
+    Update {
+        id: String,
+        before: Map<String, Value>,
+        after: Map<String, Value>,
+    },
+}
+
+impl PlannedChange {
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Create { id, .. } | Self::Update { id, .. } => id,
+        }
+    }
+}
+
+/// Issues created and updated by [`ChangePlan::apply`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ApplyReport {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+}
+
+impl ChangePlan {
+    /// The changes that turn `before` into `after`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either database cannot be read.
+    pub fn diff(before: &SqliteStorage, after: &SqliteStorage, command: &str) -> Result<Self> {
+        let old = snapshot(before)?;
+        let mut operations = Vec::new();
+        for (id, new_fields) in snapshot(after)? {
+            let Some(old_fields) = old.get(&id) else {
+                let issue = serde_json::from_value(Value::Object(new_fields))?;
+                operations.push(PlannedChange::Create {
+                    id,
+                    issue: Box::new(issue),
+                });
+                continue;
+            };
+            let keys: BTreeSet<&String> = old_fields
+                .keys()
+                .chain(new_fields.keys())
+                .filter(|key| !DERIVED_FIELDS.contains(&key.as_str()))
+                .collect();
+            let mut before = Map::new();
+            let mut after = Map::new();
+            for key in keys {
+                let old_value = old_fields.get(key).unwrap_or(&Value::Null);
+                let new_value = new_fields.get(key).unwrap_or(&Value::Null);
+                if old_value != new_value {
+                    before.insert(key.clone(), old_value.clone());
+                    after.insert(key.clone(), new_value.clone());
+                }
+            }
+            if !after.is_empty() {
+                operations.push(PlannedChange::Update { id, before, after });
+            }
+        }
+        Ok(Self {
+            version: PLAN_VERSION,
+            command: command.to_string(),
+            created_at: Utc::now(),
+            operations,
+        })
+    }
+
+    /// Apply the plan to `storage` in one transaction.
+    ///
+    /// Unless `force` is set, every operation is checked against the current
+    /// state first. Nothing is written if any check or change fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the plan has an unknown version, an
+    /// issue changed since the plan was recorded, or a change cannot be
+    /// applied; otherwise any storage error.
+    pub fn apply(
+        &self,
+        storage: &mut SqliteStorage,
+        actor: &str,
+        force: bool,
+    ) -> Result<ApplyReport> {
+        if self.version != PLAN_VERSION {
+            return Err(BeadsError::validation(
+                "plan",
+                format!(
+                    "unsupported plan version {} (this br writes version {PLAN_VERSION})",
+                    self.version
+                ),
+            ));
+        }
+        storage.transaction(|storage| self.apply_operations(storage, actor, force))
+    }
+
+    fn apply_operations(
+        &self,
+        storage: &mut SqliteStorage,
+        actor: &str,
+        force: bool,
+    ) -> Result<ApplyReport> {
+        if !force {
+            for operation in &self.operations {
+                check(storage, operation)?;
+            }
+        }
+
+        // Create every new issue before adding dependencies, which may point
+        // at issues later in the plan
+        let mut report = ApplyReport::default();
+        for operation in &self.operations {
+            if let PlannedChange::Create { issue, .. } = operation {
+                let bare = Issue {
+                    dependencies: Vec::new(),
+                    ..(**issue).clone()
+                };
+                storage.create_issue(&bare, actor)?;
+                report.created.push(issue.id.clone());
+            }
+        }
+        for operation in &self.operations {
+            match operation {
+                PlannedChange::Create { issue, .. } => {
+                    for dep in &issue.dependencies {
+                        storage.add_dependency(
+                            &issue.id,
+                            &dep.depends_on_id,
+                            dep.dep_type.as_str(),
+                            actor,
+                        )?;
+                    }
+                }
+                PlannedChange::Update { id, after, .. } => {
+                    apply_update(storage, id, after, actor)?;
+                    report.updated.push(id.clone());
+                }
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// Every exported issue with its relations, as JSON objects keyed by ID.
+fn snapshot(storage: &SqliteStorage) -> Result<BTreeMap<String, Map<String, Value>>> {
+    let mut issues = BTreeMap::new();
+    for issue in storage.get_all_issues_for_export()? {
+        if let Some(fields) = issue_fields(storage, &issue.id)? {
+            issues.insert(issue.id, fields);
+        }
+    }
+    Ok(issues)
+}
+
+fn issue_fields(storage: &SqliteStorage, id: &str) -> Result<Option<Map<String, Value>>> {
+    let Some(mut issue) = storage.get_issue_for_export(id)? else {
+        return Ok(None);
+    };
+    issue.code_refs = storage.get_code_refs(id)?;
+    issue.watchers = storage.get_watchers(id)?;
+    issue.reactions = storage.get_reactions(id)?;
+    issue.fields = storage.get_fields(id)?;
+    match serde_json::to_value(&issue)? {
+        Value::Object(fields) => Ok(Some(fields)),
+        _ => Ok(None),
+    }
+}
+
+/// Fail if `operation` no longer fits the current state.
+fn check(storage: &SqliteStorage, operation: &PlannedChange) -> Result<()> {
+    match operation {
+        PlannedChange::Create { id, .. } => {
+            if storage.id_exists(id)? {
+                return Err(BeadsError::IdCollision { id: id.clone() });
+            }
+        }
+        PlannedChange::Update { id, before, .. } => {
+            let current = issue_fields(storage, id)?
+                .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+            for (key, expected) in before {
+                let found = current.get(key).unwrap_or(&Value::Null);
+                if found != expected {
+                    return Err(BeadsError::validation(
+                        "plan",
+                        format!(
+                            "{id}: '{key}' changed since the plan was recorded (expected {expected}, found {found})"
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Bring issue `id` to the `after` values, field by field.
+#[allow(clippy::too_many_lines)]
+fn apply_update(
+    storage: &mut SqliteStorage,
+    id: &str,
+    after: &Map<String, Value>,
+    actor: &str,
+) -> Result<()> {
+    let mut merged =
+        issue_fields(storage, id)?.ok_or_else(|| BeadsError::IssueNotFound { id: id.into() })?;
+    for (key, value) in after {
+        if value.is_null() {
+            merged.remove(key);
+        } else {
+            merged.insert(key.clone(), value.clone());
+        }
+    }
+    let target: Issue = serde_json::from_value(Value::Object(merged))?;
+    let tombstone = target.status == Status::Tombstone;
+
+    let mut update = IssueUpdate::default();
+    for key in after.keys() {
+        match key.as_str() {
+            "title" => update.title = Some(target.title.clone()),
+            "description" => update.description = Some(target.description.clone()),
+            "design" => update.design = Some(target.design.clone()),
+            "acceptance_criteria" => {
+                update.acceptance_criteria = Some(target.acceptance_criteria.clone());
+            }
+            "notes" => update.notes = Some(target.notes.clone()),
+            "status" if !tombstone => update.status = Some(target.status.clone()),
+            "priority" => update.priority = Some(target.priority),
+            "issue_type" => update.issue_type = Some(target.issue_type.clone()),
+            "assignee" => update.assignee = Some(target.assignee.clone()),
+            "owner" => update.owner = Some(target.owner.clone()),
+            "estimated_minutes" => update.estimated_minutes = Some(target.estimated_minutes),
+            "due_at" => update.due_at = Some(target.due_at),
+            "defer_until" => update.defer_until = Some(target.defer_until),
+            "external_ref" => update.external_ref = Some(target.external_ref.clone()),
+            "closed_at" => update.closed_at = Some(target.closed_at),
+            "close_reason" => update.close_reason = Some(target.close_reason.clone()),
+            "closed_by_session" => {
+                update.closed_by_session = Some(target.closed_by_session.clone());
+            }
+            "pinned" => update.pinned = Some(target.pinned),
+            // Set together by `delete_issue` below
+            "status" | "deleted_at" | "deleted_by" | "delete_reason" if tombstone => {}
+            "deleted_at" => update.deleted_at = Some(target.deleted_at),
+            "deleted_by" => update.deleted_by = Some(target.deleted_by.clone()),
+            "delete_reason" => update.delete_reason = Some(target.delete_reason.clone()),
+            "labels" | "dependencies" | "comments" | "code_refs" | "watchers" | "reactions"
+            | "fields" => {}
+            other => {
+                return Err(BeadsError::validation(
+                    "plan",
+                    format!("{id}: changes to '{other}' cannot be applied"),
+                ));
+            }
+        }
+    }
+    if !update.is_empty() {
+        storage.update_issue(id, &update, actor)?;
+    }
+    if tombstone && after.contains_key("status") {
+        storage.delete_issue(
+            id,
+            target.deleted_by.as_deref().unwrap_or(actor),
+            target.delete_reason.as_deref().unwrap_or_default(),
+            target.deleted_at,
+        )?;
+    }
+
+    if after.contains_key("labels") {
+        let current: BTreeSet<String> = storage.get_labels(id)?.into_iter().collect();
+        let wanted: BTreeSet<String> = target.labels.iter().cloned().collect();
+        for label in current.difference(&wanted) {
+            storage.remove_label(id, label, actor)?;
+        }
+        for label in wanted.difference(&current) {
+            storage.add_label(id, label, actor)?;
+        }
+    }
+    if after.contains_key("dependencies") {
+        let key = |on: &str, kind: &str| (on.to_string(), kind.to_string());
+        let current: BTreeSet<(String, String)> = storage
+            .get_dependencies_full(id)?
+            .iter()
+            .map(|dep| key(&dep.depends_on_id, dep.dep_type.as_str()))
+            .collect();
+        let wanted: BTreeSet<(String, String)> = target
+            .dependencies
+            .iter()
+            .map(|dep| key(&dep.depends_on_id, dep.dep_type.as_str()))
+            .collect();
+        for (on, _) in current.difference(&wanted) {
+            storage.remove_dependency(id, on, actor)?;
+        }
+        for (on, kind) in wanted.difference(&current) {
+            storage.add_dependency(id, on, kind, actor)?;
+        }
+    }
+    if after.contains_key("comments") {
+        apply_comments(storage, id, &target.comments, actor)?;
+    }
+    if after.contains_key("code_refs") {
+        let current = storage.get_code_refs(id)?;
+        for code_ref in current.iter().filter(|r| !target.code_refs.contains(r)) {
+            storage.remove_code_ref(id, code_ref, actor)?;
+        }
+        for code_ref in target.code_refs.iter().filter(|r| !current.contains(r)) {
+            storage.add_code_ref(id, code_ref, actor)?;
+        }
+    }
+    if after.contains_key("watchers") {
+        let current = storage.get_watchers(id)?;
+        for watcher in current.iter().filter(|w| !target.watchers.contains(w)) {
+            storage.remove_watcher(id, watcher, actor)?;
+        }
+        for watcher in target.watchers.iter().filter(|w| !current.contains(w)) {
+            storage.add_watcher(id, watcher, actor)?;
+        }
+    }
+    if after.contains_key("reactions") {
+        let key = |r: &Reaction| (r.comment_id, r.reaction.clone(), r.actor.clone());
+        let current = storage.get_reactions(id)?;
+        let wanted: Vec<_> = target.reactions.iter().map(key).collect();
+        let existing: Vec<_> = current.iter().map(key).collect();
+        for (comment_id, reaction, who) in existing.iter().filter(|k| !wanted.contains(k)) {
+            storage.remove_reaction(id, *comment_id, reaction, who)?;
+        }
+        for (comment_id, reaction, who) in wanted.iter().filter(|k| !existing.contains(k)) {
+            storage.add_reaction(id, *comment_id, reaction, who)?;
+        }
+    }
+    if after.contains_key("fields") {
+        let current = storage.get_fields(id)?;
+        for name in current
+            .keys()
+            .filter(|name| !target.fields.contains_key(*name))
+        {
+            storage.set_field(id, name, None, actor)?;
+        }
+        for (name, value) in &target.fields {
+            storage.set_field(id, name, Some(value), actor)?;
+        }
+    }
+    Ok(())
+}
+
+/// Add, edit and delete comments so the issue's comments match `wanted`.
+///
+/// Comments are matched by ID; a wanted comment with an unknown ID is new.
+fn apply_comments(
+    storage: &mut SqliteStorage,
+    id: &str,
+    wanted: &[Comment],
+    actor: &str,
+) -> Result<()> {
+    let current: HashMap<i64, Comment> = storage
+        .get_comments(id)?
+        .into_iter()
+        .map(|comment| (comment.id, comment))
+        .collect();
+    for comment in wanted {
+        match current.get(&comment.id) {
+            None => {
+                storage.add_comment(id, &comment.author, &comment.body)?;
+            }
+            Some(existing) if comment.deleted && !existing.deleted => {
+                storage.delete_comment(comment.id, actor)?;
+            }
+            Some(existing) if !comment.deleted && existing.body != comment.body => {
+                storage.edit_comment(comment.id, &comment.body, actor)?;
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn issue(id: &str, title: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn test_plan_records_and_replays_changes() {
+        let temp = TempDir::new().unwrap();
+        let db_path = temp.path().join("beads.db");
+        let copy_path = temp.path().join("copy.db");
+
+        let mut storage = SqliteStorage::open(&db_path).unwrap();
+        storage
+            .create_issue(&issue("bd-1", "First"), "tester")
+            .unwrap();
+        storage.add_comment("bd-1", "tester", "note").unwrap();
+        storage.snapshot_to(&copy_path).unwrap();
+
+        let mut copy = SqliteStorage::open(&copy_path).unwrap();
+        let update = IssueUpdate {
+            status: Some(Status::InProgress),
+            assignee: Some(Some("alice".to_string())),
+            ..IssueUpdate::default()
+        };
+        copy.update_issue("bd-1", &update, "tester").unwrap();
+        copy.add_label("bd-1", "ops", "tester").unwrap();
+        copy.add_comment("bd-1", "tester", "started").unwrap();
+        copy.create_issue(&issue("bd-2", "Second"), "tester")
+            .unwrap();
+        copy.add_dependency("bd-2", "bd-1", "blocks", "tester")
+            .unwrap();
+
+        let plan = ChangePlan::diff(&storage, &copy, "br test").unwrap();
+        let ids: Vec<&str> = plan.operations.iter().map(PlannedChange::id).collect();
+        assert_eq!(ids, vec!["bd-1", "bd-2"]);
+        let PlannedChange::Update { before, after, .. } = &plan.operations[0] else {
+            panic!("expected an update");
+        };
+        assert_eq!(before["status"], "open");
+        assert_eq!(after["status"], "in_progress");
+        assert_eq!(before["assignee"], Value::Null);
+        assert!(!after.contains_key("updated_at"));
+
+        // Plans survive a round trip through JSON
+        let json = serde_json::to_string(&plan).unwrap();
+        let plan: ChangePlan = serde_json::from_str(&json).unwrap();
+
+        let report = plan.apply(&mut storage, "tester", false).unwrap();
+        assert_eq!(report.created, vec!["bd-2"]);
+        assert_eq!(report.updated, vec!["bd-1"]);
+        let applied = storage.get_issue_for_export("bd-1").unwrap().unwrap();
+        assert_eq!(applied.status, Status::InProgress);
+        assert_eq!(applied.assignee.as_deref(), Some("alice"));
+        assert_eq!(applied.labels, vec!["ops"]);
+        assert_eq!(applied.comments.len(), 2);
+        assert_eq!(storage.get_dependencies("bd-2").unwrap(), vec!["bd-1"]);
+
+        // A second apply finds bd-1 no longer matches and writes nothing
+        let err = plan.apply(&mut storage, "tester", false).unwrap_err();
+        assert!(matches!(err, BeadsError::Validation { .. }), "{err:?}");
+    }
+
+    #[test]
+    fn test_plan_failing_late_step_commits_nothing() {
+        let temp = TempDir::new().unwrap();
+        let mut storage = SqliteStorage::open(&temp.path().join("beads.db")).unwrap();
+        storage
+            .create_issue(&issue("bd-1", "First"), "tester")
+            .unwrap();
+
+        let retitle = |id: &str| PlannedChange::Update {
+            id: id.to_string(),
+            before: Map::new(),
+            after: Map::from_iter([("title".to_string(), Value::from("Renamed"))]),
+        };
+        let plan = ChangePlan {
+            version: PLAN_VERSION,
+            command: "br test".to_string(),
+            created_at: Utc::now(),
+            operations: vec![
+                PlannedChange::Create {
+                    id: "bd-2".to_string(),
+                    issue: Box::new(issue("bd-2", "Second")),
+                },
+                retitle("bd-1"),
+                retitle("bd-missing"),
+            ],
+        };
+
+        // Forced, so the missing issue is only found by the last step
+        let err = plan.apply(&mut storage, "tester", true).unwrap_err();
+        assert!(matches!(err, BeadsError::IssueNotFound { .. }), "{err:?}");
+        assert!(!storage.id_exists("bd-2").unwrap());
+        let first = storage.get_issue_for_export("bd-1").unwrap().unwrap();
+        assert_eq!(first.title, "First");
+        assert_eq!(storage.get_dirty_issue_count().unwrap(), 1);
+    }
+}
@@ -1,8 +1,121 @@
 //! Database schema definitions and migration logic.
+//!
+//! The base schema is idempotent DDL, applied whenever a database is older
+//! than [`CURRENT_SCHEMA_VERSION`]. On top of it, [`MIGRATIONS`] lists every
+//! schema version with an optional one-off script; each applied version is
+//! recorded in the `schema_migrations` table and the database's
+//! `PRAGMA user_version` is set to the newest one.
 
-use rusqlite::{Connection, Result};
+use rusqlite::{Connection, Result, params};
+use std::collections::HashSet;
 
-pub const CURRENT_SCHEMA_VERSION: i32 = 1;
+/// Bump when columns are added so existing databases re-run `apply_schema`.
+///
+/// - v2: `events.session_id`
+/// - v3: `comments.updated_at`, `comments.deleted`
+/// - v4: `reactions` table
+/// - v5: `code_refs` table
+/// - v6: `idx_issues_ready` no longer excludes pinned issues
+/// - v7: `id_aliases` table
+/// - v8: `schema_migrations` table
+/// - v9: `mentions` table
+/// - v10: `watchers` table
+/// - v11: `issue_fields` table
+/// - v12: `issue_references` table
+/// - v13: `issues.dependency_count`, `issues.dependent_count` and their triggers
+pub const CURRENT_SCHEMA_VERSION: i32 = 13;
+
+/// One schema version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Migration {
+    pub version: i32,
+    pub name: &'static str,
+    /// Run once, after the base schema, when a database first reaches this
+    /// version. Empty when the base schema already covers the change.
+    pub sql: &'static str,
+}
+
+/// Every schema version in order; the last one is [`CURRENT_SCHEMA_VERSION`].
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "base schema",
+        sql: "",
+    },
+    Migration {
+        version: 2,
+        name: "events.session_id",
+        sql: "",
+    },
+    Migration {
+        version: 3,
+        name: "comment edits and soft deletes",
+        sql: "",
+    },
+    Migration {
+        version: 4,
+        name: "reactions table",
+        sql: "",
+    },
+    Migration {
+        version: 5,
+        name: "code_refs table",
+        sql: "",
+    },
+    Migration {
+        version: 6,
+        name: "ready index includes pinned issues",
+        sql: "",
+    },
+    Migration {
+        version: 7,
+        name: "id_aliases table",
+        sql: "",
+    },
+    Migration {
+        version: 8,
+        name: "schema_migrations table",
+        sql: "",
+    },
+    Migration {
+        version: 9,
+        name: "mentions table",
+        sql: "",
+    },
+    Migration {
+        version: 10,
+        name: "watchers table",
+        sql: "",
+    },
+    Migration {
+        version: 11,
+        name: "issue_fields table",
+        sql: "",
+    },
+    Migration {
+        version: 12,
+        name: "issue_references table",
+        sql: "",
+    },
+    Migration {
+        version: 13,
+        name: "cached dependency counts",
+        sql: REBUILD_DEPENDENCY_COUNTS_SQL,
+    },
+];
+
+/// Recompute `issues.dependency_count` and `issues.dependent_count` from the
+/// dependencies table, touching only issues whose counts are wrong.
+///
+/// The triggers in [`SCHEMA_SQL`] keep the counts current; this backfills
+/// them on upgrade and repairs drift (`br migrate --rebuild-counts`).
+pub const REBUILD_DEPENDENCY_COUNTS_SQL: &str = r"
+    UPDATE issues SET
+        dependency_count = (SELECT COUNT(*) FROM dependencies WHERE issue_id = issues.id),
+        dependent_count = (SELECT COUNT(*) FROM dependencies WHERE depends_on_id = issues.id)
+    WHERE dependency_count IS NOT (SELECT COUNT(*) FROM dependencies WHERE issue_id = issues.id)
+       OR dependent_count IS NOT (SELECT COUNT(*) FROM dependencies WHERE depends_on_id = issues.id)
+";
 
 /// The complete SQL schema for the beads database.
 /// Schema matches classic bd (Go) for interoperability.
@@ -47,6 +160,9 @@ pub const SCHEMA_SQL: &str = r"
         ephemeral INTEGER DEFAULT 0,
         pinned INTEGER DEFAULT 0,
         is_template INTEGER DEFAULT 0,
+        -- Cached counts, maintained by the dependency triggers below
+        dependency_count INTEGER NOT NULL DEFAULT 0,
+        dependent_count INTEGER NOT NULL DEFAULT 0,
         -- Closed-at invariant: closed issues MUST have closed_at timestamp
         CHECK (
             (status = 'closed' AND closed_at IS NOT NULL) OR
@@ -82,7 +198,6 @@ pub const SCHEMA_SQL: &str = r"
         ON issues(status, priority, created_at)
         WHERE status IN ('open', 'in_progress')
         AND ephemeral = 0
-        AND pinned = 0
         AND (is_template = 0 OR is_template IS NULL);
 
     -- Dependencies
@@ -108,6 +223,36 @@ pub const SCHEMA_SQL: &str = r"
         ON dependencies(depends_on_id, issue_id)
         WHERE type IN ('blocks', 'parent-child', 'conditional-blocks', 'waits-for');
 
+    -- Keep issues.dependency_count / dependent_count in step with the
+    -- dependencies table. Targets need not exist (external refs), so an issue
+    -- created after edges that point at it counts them on insert. Rewritten
+    -- edges (ID renames) recount both ends, since the issue row may have been
+    -- renamed before or after its edges.
+    CREATE TRIGGER IF NOT EXISTS trg_dependencies_count_insert
+    AFTER INSERT ON dependencies BEGIN
+        UPDATE issues SET dependency_count = dependency_count + 1 WHERE id = NEW.issue_id;
+        UPDATE issues SET dependent_count = dependent_count + 1 WHERE id = NEW.depends_on_id;
+    END;
+    CREATE TRIGGER IF NOT EXISTS trg_dependencies_count_delete
+    AFTER DELETE ON dependencies BEGIN
+        UPDATE issues SET dependency_count = dependency_count - 1 WHERE id = OLD.issue_id;
+        UPDATE issues SET dependent_count = dependent_count - 1 WHERE id = OLD.depends_on_id;
+    END;
+    CREATE TRIGGER IF NOT EXISTS trg_dependencies_count_update
+    AFTER UPDATE OF issue_id, depends_on_id ON dependencies BEGIN
+        UPDATE issues SET
+            dependency_count = (SELECT COUNT(*) FROM dependencies WHERE issue_id = issues.id),
+            dependent_count = (SELECT COUNT(*) FROM dependencies WHERE depends_on_id = issues.id)
+        WHERE id IN (OLD.issue_id, OLD.depends_on_id, NEW.issue_id, NEW.depends_on_id);
+    END;
+    CREATE TRIGGER IF NOT EXISTS trg_issues_count_insert
+    AFTER INSERT ON issues BEGIN
+        UPDATE issues SET
+            dependency_count = (SELECT COUNT(*) FROM dependencies WHERE issue_id = NEW.id),
+            dependent_count = (SELECT COUNT(*) FROM dependencies WHERE depends_on_id = NEW.id)
+        WHERE id = NEW.id;
+    END;
+
     -- Labels
     CREATE TABLE IF NOT EXISTS labels (
         issue_id TEXT NOT NULL,
@@ -125,11 +270,76 @@ pub const SCHEMA_SQL: &str = r"
         author TEXT NOT NULL,
         text TEXT NOT NULL,
         created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at DATETIME,
+        deleted INTEGER NOT NULL DEFAULT 0,
         FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
     );
     CREATE INDEX IF NOT EXISTS idx_comments_issue ON comments(issue_id);
     CREATE INDEX IF NOT EXISTS idx_comments_created_at ON comments(created_at);
 
+    -- Mentions (lowercased @name in comment text; derived, not exported)
+    CREATE TABLE IF NOT EXISTS mentions (
+        comment_id INTEGER NOT NULL,
+        issue_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        PRIMARY KEY (comment_id, name),
+        FOREIGN KEY (comment_id) REFERENCES comments(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_mentions_name ON mentions(name);
+
+    -- Issue IDs written in an issue's text (comment_id 0) or its comments;
+    -- derived, not exported. target_id need not exist (yet).
+    CREATE TABLE IF NOT EXISTS issue_references (
+        issue_id TEXT NOT NULL,
+        comment_id INTEGER NOT NULL DEFAULT 0,
+        target_id TEXT NOT NULL,
+        PRIMARY KEY (issue_id, comment_id, target_id),
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_issue_references_target ON issue_references(target_id);
+
+    -- Reactions (comment_id 0 = the issue itself, so UNIQUE sees no NULLs)
+    CREATE TABLE IF NOT EXISTS reactions (
+        issue_id TEXT NOT NULL,
+        comment_id INTEGER NOT NULL DEFAULT 0,
+        reaction TEXT NOT NULL,
+        actor TEXT NOT NULL,
+        created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (issue_id, comment_id, reaction, actor),
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+
+    -- Source code locations (line 0 / commit '' = unset, so the PK sees no NULLs)
+    CREATE TABLE IF NOT EXISTS code_refs (
+        issue_id TEXT NOT NULL,
+        path TEXT NOT NULL,
+        line INTEGER NOT NULL DEFAULT 0,
+        commit_ref TEXT NOT NULL DEFAULT '',
+        PRIMARY KEY (issue_id, path, line, commit_ref),
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_code_refs_path ON code_refs(path);
+
+    -- Watchers (actors notified of events on an issue; names match in any case)
+    CREATE TABLE IF NOT EXISTS watchers (
+        issue_id TEXT NOT NULL,
+        actor TEXT NOT NULL COLLATE NOCASE,
+        created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (issue_id, actor),
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_watchers_actor ON watchers(actor);
+
+    -- Custom fields declared in config (values in canonical text form)
+    CREATE TABLE IF NOT EXISTS issue_fields (
+        issue_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        value TEXT NOT NULL,
+        PRIMARY KEY (issue_id, name),
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_issue_fields_name_value ON issue_fields(name, value);
+
     -- Events (Audit)
     CREATE TABLE IF NOT EXISTS events (
         id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -140,6 +350,7 @@ pub const SCHEMA_SQL: &str = r"
         new_value TEXT,
         comment TEXT,
         created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        session_id TEXT DEFAULT '',
         FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
     );
     CREATE INDEX IF NOT EXISTS idx_events_issue ON events(issue_id);
@@ -191,6 +402,22 @@ pub const SCHEMA_SQL: &str = r"
         last_child INTEGER NOT NULL DEFAULT 0,
         FOREIGN KEY (parent_id) REFERENCES issues(id) ON DELETE CASCADE
     );
+
+    -- ID Aliases (old IDs of reparented or renamed issues stay resolvable)
+    CREATE TABLE IF NOT EXISTS id_aliases (
+        alias TEXT PRIMARY KEY,
+        issue_id TEXT NOT NULL,
+        created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_id_aliases_issue ON id_aliases(issue_id);
+
+    -- Schema Migrations (one row per applied schema version)
+    CREATE TABLE IF NOT EXISTS schema_migrations (
+        version INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        applied_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
 ";
 
 /// Apply the schema to the database.
@@ -211,9 +438,129 @@ pub fn apply_schema(conn: &Connection) -> Result<()> {
 
     // Run migrations for existing databases
     run_migrations(conn)?;
+    run_versioned_migrations(conn)?;
+
+    apply_connection_pragmas(conn)?;
+
+    // Mark schema as applied so future opens can skip DDL/migration work.
+    conn.pragma_update(None, "user_version", CURRENT_SCHEMA_VERSION)?;
+
+    Ok(())
+}
+
+/// Run and record every migration not yet in `schema_migrations`, each in
+/// its own transaction.
+fn run_versioned_migrations(conn: &Connection) -> Result<()> {
+    let applied: HashSet<i32> = applied_migrations(conn)?
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect();
+    for migration in MIGRATIONS {
+        if applied.contains(&migration.version) {
+            continue;
+        }
+        let tx = conn.unchecked_transaction()?;
+        if !migration.sql.is_empty() {
+            tx.execute_batch(migration.sql)?;
+        }
+        tx.execute(
+            "INSERT INTO schema_migrations (version, name, applied_at) VALUES (?, ?, ?)",
+            params![
+                migration.version,
+                migration.name,
+                chrono::Utc::now().to_rfc3339()
+            ],
+        )?;
+        tx.commit()?;
+    }
+    Ok(())
+}
+
+/// A migration recorded in `schema_migrations`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedMigration {
+    pub version: i32,
+    pub name: String,
+    pub applied_at: String,
+}
+
+/// Schema state of a database, read without changing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaState {
+    /// `PRAGMA user_version` of the database.
+    pub version: i32,
+    /// Recorded migrations, oldest first (empty before v8).
+    pub applied: Vec<AppliedMigration>,
+}
+
+impl SchemaState {
+    /// Migrations the next open will bring the database up to.
+    #[must_use]
+    pub fn pending(&self) -> Vec<Migration> {
+        MIGRATIONS
+            .iter()
+            .filter(|migration| migration.version > self.version)
+            .copied()
+            .collect()
+    }
+
+    /// Whether the database was written by a newer `br` than this one.
+    #[must_use]
+    pub const fn is_newer(&self) -> bool {
+        self.version > CURRENT_SCHEMA_VERSION
+    }
+}
+
+/// Migrations recorded in `schema_migrations`, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if the table exists but cannot be read.
+pub fn applied_migrations(conn: &Connection) -> Result<Vec<AppliedMigration>> {
+    if !table_exists(conn, "schema_migrations") {
+        return Ok(Vec::new());
+    }
+    let mut stmt =
+        conn.prepare("SELECT version, name, applied_at FROM schema_migrations ORDER BY version")?;
+    let applied = stmt
+        .query_map([], |row| {
+            Ok(AppliedMigration {
+                version: row.get(0)?,
+                name: row.get(1)?,
+                applied_at: row.get(2)?,
+            })
+        })?
+        .collect::<Result<Vec<_>>>()?;
+    Ok(applied)
+}
 
-    // Set journal mode to WAL for concurrency
-    conn.pragma_update(None, "journal_mode", "WAL")?;
+/// Read the schema version and migration history of `conn`.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be read.
+pub fn schema_state(conn: &Connection) -> Result<SchemaState> {
+    Ok(SchemaState {
+        version: conn.query_row("PRAGMA user_version", [], |row| row.get(0))?,
+        applied: applied_migrations(conn)?,
+    })
+}
+
+/// Apply the per-connection PRAGMAs.
+///
+/// Unlike the schema these settings do not persist in the database file
+/// (except `journal_mode`), so they are applied on every open.
+///
+/// # Errors
+///
+/// Returns an error if a pragma cannot be set.
+pub fn apply_connection_pragmas(conn: &Connection) -> Result<()> {
+    // Set journal mode to WAL for concurrency. Switching modes needs an
+    // exclusive lock, so skip it when the file is already in WAL mode.
+    let journal_mode: String = conn.query_row("PRAGMA journal_mode", [], |row| row.get(0))?;
+    if !journal_mode.eq_ignore_ascii_case("wal") {
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+    }
 
     // Enable foreign keys
     conn.pragma_update(None, "foreign_keys", "ON")?;
@@ -225,12 +572,46 @@ pub fn apply_schema(conn: &Connection) -> Result<()> {
     conn.pragma_update(None, "temp_store", "MEMORY")?;
     // 8MB page cache (default is ~2MB), improves read-heavy workloads
     conn.pragma_update(None, "cache_size", "-8000")?;
-    // Mark schema as applied so future opens can skip DDL/migration work.
-    conn.pragma_update(None, "user_version", CURRENT_SCHEMA_VERSION)?;
 
     Ok(())
 }
 
+/// Connection settings as reported by `SQLite`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionPragmas {
+    /// `wal` for file databases, `memory` for in-memory ones
+    pub journal_mode: String,
+    /// 0 = OFF, 1 = NORMAL, 2 = FULL, 3 = EXTRA
+    pub synchronous: i64,
+    pub foreign_keys: bool,
+    pub busy_timeout_ms: i64,
+    /// 0 = DEFAULT, 1 = FILE, 2 = MEMORY
+    pub temp_store: i64,
+    /// Negative values are KiB, positive values are pages
+    pub cache_size: i64,
+}
+
+/// Read back the connection PRAGMAs, so tests can check what an open applied.
+///
+/// # Errors
+///
+/// Returns an error if a pragma cannot be queried.
+pub fn pragmas(conn: &Connection) -> Result<ConnectionPragmas> {
+    let read = |name: &str| -> Result<i64> {
+        conn.query_row(&format!("PRAGMA {name}"), [], |row| row.get(0))
+    };
+    Ok(ConnectionPragmas {
+        journal_mode: conn
+            .query_row("PRAGMA journal_mode", [], |row| row.get::<_, String>(0))?
+            .to_lowercase(),
+        synchronous: read("synchronous")?,
+        foreign_keys: read("foreign_keys")? == 1,
+        busy_timeout_ms: read("busy_timeout")?,
+        temp_store: read("temp_store")?,
+        cache_size: read("cache_size")?,
+    })
+}
+
 fn table_exists(conn: &Connection, table: &str) -> bool {
     conn.prepare("SELECT 1 FROM sqlite_master WHERE type='table' AND name=?")
         .and_then(|mut stmt| stmt.exists([table]))
@@ -282,6 +663,8 @@ const ISSUE_COLUMNS: &[(&str, &str)] = &[
     ("ephemeral", "INTEGER DEFAULT 0"),
     ("pinned", "INTEGER DEFAULT 0"),
     ("is_template", "INTEGER DEFAULT 0"),
+    ("dependency_count", "INTEGER NOT NULL DEFAULT 0"),
+    ("dependent_count", "INTEGER NOT NULL DEFAULT 0"),
 ];
 
 const DEPENDENCY_COLUMNS: &[(&str, &str)] = &[
@@ -296,6 +679,8 @@ const COMMENT_COLUMNS: &[(&str, &str)] = &[
     ("author", "TEXT NOT NULL DEFAULT ''"),
     ("text", "TEXT NOT NULL DEFAULT ''"),
     ("created_at", "DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP"),
+    ("updated_at", "DATETIME"),
+    ("deleted", "INTEGER NOT NULL DEFAULT 0"),
 ];
 
 const EVENT_COLUMNS: &[(&str, &str)] = &[
@@ -305,6 +690,7 @@ const EVENT_COLUMNS: &[(&str, &str)] = &[
     ("new_value", "TEXT"),
     ("comment", "TEXT"),
     ("created_at", "DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP"),
+    ("session_id", "TEXT DEFAULT ''"),
 ];
 
 fn ensure_columns(conn: &Connection, table: &str, columns: &[(&str, &str)]) -> Result<()> {
@@ -450,7 +836,6 @@ fn run_migrations(conn: &Connection) -> Result<()> {
             ON issues(status, priority, created_at)
             WHERE status IN ('open', 'in_progress')
             AND ephemeral = 0
-            AND pinned = 0
             AND (is_template = 0 OR is_template IS NULL);
 
     ",
@@ -513,6 +898,101 @@ fn run_migrations(conn: &Connection) -> Result<()> {
         )?;
     }
 
+    // Migration: index mentions in comments written before the mentions table
+    backfill_mentions(conn)?;
+
+    // Migration: index issue references written before the references table
+    backfill_references(conn)?;
+
+    Ok(())
+}
+
+/// Index the mentions of every comment that has none indexed yet.
+fn backfill_mentions(conn: &Connection) -> Result<()> {
+    let comments: Vec<(i64, String, String)> = conn
+        .prepare(
+            "SELECT id, issue_id, text FROM comments
+             WHERE text LIKE '%@%'
+               AND id NOT IN (SELECT comment_id FROM mentions)",
+        )?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<Result<_>>()?;
+    for (comment_id, issue_id, text) in comments {
+        index_mentions(conn, comment_id, &issue_id, &text)?;
+    }
+    Ok(())
+}
+
+/// Index the issue references of every issue and comment, once: only while
+/// nothing is indexed yet.
+fn backfill_references(conn: &Connection) -> Result<()> {
+    let indexed: bool = conn
+        .prepare("SELECT 1 FROM issue_references LIMIT 1")?
+        .exists([])?;
+    if indexed {
+        return Ok(());
+    }
+    let texts: Vec<(String, i64, String)> = conn
+        .prepare(
+            "SELECT id, 0, COALESCE(title, '') || char(10) || COALESCE(description, '')
+                    || char(10) || COALESCE(design, '') || char(10)
+                    || COALESCE(acceptance_criteria, '') || char(10) || COALESCE(notes, '')
+             FROM issues
+             UNION ALL
+             SELECT issue_id, id, text FROM comments WHERE issue_id IN (SELECT id FROM issues)",
+        )?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<Result<_>>()?;
+    for (issue_id, comment_id, text) in texts {
+        index_references(conn, &issue_id, comment_id, &text)?;
+    }
+    Ok(())
+}
+
+/// Replace the indexed issue references of an issue's own text
+/// (`comment_id` 0) or of one of its comments. The issue's own ID is skipped.
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn index_references(
+    conn: &Connection,
+    issue_id: &str,
+    comment_id: i64,
+    text: &str,
+) -> Result<()> {
+    conn.prepare_cached("DELETE FROM issue_references WHERE issue_id = ? AND comment_id = ?")?
+        .execute(params![issue_id, comment_id])?;
+    let mut insert = conn.prepare_cached(
+        "INSERT OR IGNORE INTO issue_references (issue_id, comment_id, target_id) VALUES (?, ?, ?)",
+    )?;
+    for target_id in crate::util::parse_issue_references(text) {
+        if target_id != issue_id {
+            insert.execute(params![issue_id, comment_id, target_id])?;
+        }
+    }
+    Ok(())
+}
+
+/// Replace the indexed `@name` mentions of one comment.
+///
+/// # Errors
+///
+/// Returns an error if the database update fails.
+pub fn index_mentions(
+    conn: &Connection,
+    comment_id: i64,
+    issue_id: &str,
+    text: &str,
+) -> Result<()> {
+    conn.prepare_cached("DELETE FROM mentions WHERE comment_id = ?")?
+        .execute([comment_id])?;
+    let mut insert = conn.prepare_cached(
+        "INSERT OR IGNORE INTO mentions (comment_id, issue_id, name) VALUES (?, ?, ?)",
+    )?;
+    for name in crate::util::parse_mentions(text) {
+        insert.execute(params![comment_id, issue_id, name])?;
+    }
     Ok(())
 }
 
@@ -1037,6 +1517,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_versioned_migrations_are_recorded() {
+        assert_eq!(
+            MIGRATIONS.last().map(|migration| migration.version),
+            Some(CURRENT_SCHEMA_VERSION)
+        );
+
+        let conn = Connection::open_in_memory().unwrap();
+        apply_schema(&conn).unwrap();
+        let state = schema_state(&conn).unwrap();
+        assert_eq!(state.version, CURRENT_SCHEMA_VERSION);
+        let versions: Vec<i32> = state.applied.iter().map(|m| m.version).collect();
+        let expected: Vec<i32> = MIGRATIONS.iter().map(|m| m.version).collect();
+        assert_eq!(versions, expected);
+        assert!(state.pending().is_empty());
+        assert!(!state.is_newer());
+
+        // A database from before migrations were recorded: v8 onwards are pending, and
+        // applying the schema records every version exactly once.
+        conn.execute_batch("DROP TABLE schema_migrations; PRAGMA user_version = 7;")
+            .unwrap();
+        let state = schema_state(&conn).unwrap();
+        assert!(state.applied.is_empty());
+        assert_eq!(
+            state
+                .pending()
+                .iter()
+                .map(|m| m.version)
+                .collect::<Vec<_>>(),
+            vec![8, 9, 10, 11, 12, 13]
+        );
+        apply_schema(&conn).unwrap();
+        apply_schema(&conn).unwrap();
+        assert_eq!(schema_state(&conn).unwrap().applied.len(), MIGRATIONS.len());
+    }
+
     /// Migration: add missing dependency type column for older schemas.
     #[test]
     fn test_migration_adds_missing_dependency_type() {
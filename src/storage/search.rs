@@ -0,0 +1,242 @@
+//! Text search queries.
+//!
+//! A [`SearchQuery`] says what to look for and where: which issue fields, and
+//! whether the text is a substring or a regular expression, case-sensitive or
+//! not. Substring queries are evaluated in SQL; regex queries select
+//! candidates with the other filters and are matched here with
+//! [`SearchQuery::matcher`].
+
+use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+use regex::{Regex, RegexBuilder};
+use std::fmt;
+use std::str::FromStr;
+
+/// An issue field a search can be scoped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchField {
+    Id,
+    Title,
+    Description,
+    Design,
+    AcceptanceCriteria,
+    Notes,
+    Comments,
+}
+
+impl SearchField {
+    /// Fields searched when none are given.
+    pub const DEFAULT: [Self; 3] = [Self::Title, Self::Description, Self::Id];
+
+    /// Every searchable field.
+    pub const ALL: [Self; 7] = [
+        Self::Id,
+        Self::Title,
+        Self::Description,
+        Self::Design,
+        Self::AcceptanceCriteria,
+        Self::Notes,
+        Self::Comments,
+    ];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Title => "title",
+            Self::Description => "description",
+            Self::Design => "design",
+            Self::AcceptanceCriteria => "acceptance_criteria",
+            Self::Notes => "notes",
+            Self::Comments => "comments",
+        }
+    }
+
+    /// The `issues` column holding this field (`None` for comments).
+    #[must_use]
+    pub const fn column(self) -> Option<&'static str> {
+        match self {
+            Self::Comments => None,
+            other => Some(other.as_str()),
+        }
+    }
+
+    /// The field's text on `issue` (`None` for comments, which are stored
+    /// separately, and for unset fields).
+    #[must_use]
+    pub fn text(self, issue: &Issue) -> Option<&str> {
+        match self {
+            Self::Id => Some(&issue.id),
+            Self::Title => Some(&issue.title),
+            Self::Description => issue.description.as_deref(),
+            Self::Design => issue.design.as_deref(),
+            Self::AcceptanceCriteria => issue.acceptance_criteria.as_deref(),
+            Self::Notes => issue.notes.as_deref(),
+            Self::Comments => None,
+        }
+    }
+}
+
+impl fmt::Display for SearchField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SearchField {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().replace('-', "_").as_str() {
+            "id" => Ok(Self::Id),
+            "title" => Ok(Self::Title),
+            "description" | "desc" => Ok(Self::Description),
+            "design" => Ok(Self::Design),
+            "acceptance_criteria" | "acceptance" => Ok(Self::AcceptanceCriteria),
+            "notes" => Ok(Self::Notes),
+            "comments" | "comment" => Ok(Self::Comments),
+            other => Err(BeadsError::validation(
+                "in",
+                format!(
+                    "unknown search field '{other}' (expected one of: {})",
+                    Self::ALL.map(Self::as_str).join(", ")
+                ),
+            )),
+        }
+    }
+}
+
+/// What to search for, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub text: String,
+    /// Fields to search; an issue matches if any of them does.
+    pub fields: Vec<SearchField>,
+    /// Treat `text` as a regular expression.
+    pub regex: bool,
+    pub case_sensitive: bool,
+}
+
+impl SearchQuery {
+    /// Case-insensitive substring search over the default fields.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            fields: SearchField::DEFAULT.to_vec(),
+            regex: false,
+            case_sensitive: false,
+        }
+    }
+
+    /// Compile the query into a matcher.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a regex query does not compile.
+    pub fn matcher(&self) -> Result<TextMatcher> {
+        if self.regex {
+            let regex = RegexBuilder::new(&self.text)
+                .case_insensitive(!self.case_sensitive)
+                .build()
+                .map_err(|err| BeadsError::validation("query", format!("invalid regex: {err}")))?;
+            Ok(TextMatcher::Regex(regex))
+        } else if self.case_sensitive {
+            Ok(TextMatcher::Exact(self.text.clone()))
+        } else {
+            Ok(TextMatcher::Folded(self.text.to_lowercase()))
+        }
+    }
+}
+
+/// A compiled [`SearchQuery`].
+#[derive(Debug, Clone)]
+pub enum TextMatcher {
+    Regex(Regex),
+    /// Case-sensitive substring.
+    Exact(String),
+    /// Case-insensitive substring (the needle is already lowercased).
+    Folded(String),
+}
+
+impl TextMatcher {
+    /// Byte range of the first match in `haystack`.
+    #[must_use]
+    pub fn find(&self, haystack: &str) -> Option<(usize, usize)> {
+        match self {
+            Self::Regex(regex) => regex.find(haystack).map(|m| (m.start(), m.end())),
+            Self::Exact(needle) => haystack
+                .find(needle.as_str())
+                .map(|start| (start, start + needle.len())),
+            Self::Folded(needle) => find_folded(haystack, needle),
+        }
+    }
+
+    #[must_use]
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.find(haystack).is_some()
+    }
+}
+
+/// Case-insensitive substring search returning byte offsets into `haystack`.
+fn find_folded(haystack: &str, needle: &str) -> Option<(usize, usize)> {
+    if needle.is_empty() {
+        return Some((0, 0));
+    }
+    let starts = haystack.char_indices().map(|(i, _)| i);
+    for start in starts {
+        let mut folded = String::new();
+        for (offset, ch) in haystack[start..].char_indices() {
+            folded.extend(ch.to_lowercase());
+            if folded.len() >= needle.len() {
+                if folded == needle {
+                    return Some((start, start + offset + ch.len_utf8()));
+                }
+                break;
+            }
+            if !needle.starts_with(folded.as_str()) {
+                break;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_field_parse() {
+        assert_eq!("notes".parse::<SearchField>().unwrap(), SearchField::Notes);
+        assert_eq!(
+            "acceptance-criteria".parse::<SearchField>().unwrap(),
+            SearchField::AcceptanceCriteria
+        );
+        assert!("labels".parse::<SearchField>().is_err());
+    }
+
+    #[test]
+    fn test_matcher_modes() {
+        let mut query = SearchQuery::new("Auth");
+        assert_eq!(
+            query.matcher().unwrap().find("fix oauth flow"),
+            Some((5, 9))
+        );
+
+        query.case_sensitive = true;
+        assert!(!query.matcher().unwrap().is_match("fix oauth flow"));
+        assert!(query.matcher().unwrap().is_match("Auth flow"));
+
+        query = SearchQuery {
+            regex: true,
+            ..SearchQuery::new(r"time ?out")
+        };
+        assert_eq!(
+            query.matcher().unwrap().find("A Timeout here"),
+            Some((2, 9))
+        );
+        query.text = "(".to_string();
+        assert!(query.matcher().is_err());
+    }
+}
@@ -1,13 +1,22 @@
 //! `SQLite` storage implementation.
 
 use crate::error::{BeadsError, Result};
-use crate::format::{IssueDetails, IssueWithDependencyMetadata};
-use crate::model::{Comment, DependencyType, Event, EventType, Issue, IssueType, Priority, Status};
+use crate::format::{IssueBacklink, IssueDetails, IssueWithCounts, IssueWithDependencyMetadata};
+use crate::model::{
+    CodeRef, Comment, DependencyCondition, DependencyType, Event, EventType, Issue, IssueType,
+    MILESTONE_TYPE, Priority, Reaction, Status, WaitUntil,
+};
+use crate::storage::backend::Storage;
+use crate::storage::cursor::{ListCursor, order_by_clause, order_columns};
 use crate::storage::events::get_events;
-use crate::storage::schema::{CURRENT_SCHEMA_VERSION, apply_schema};
+use crate::storage::schema::{
+    CURRENT_SCHEMA_VERSION, ConnectionPragmas, REBUILD_DEPENDENCY_COUNTS_SQL, SchemaState,
+    apply_connection_pragmas, apply_schema, index_mentions, index_references, schema_state,
+};
+use crate::storage::search::{SearchField, SearchQuery, TextMatcher};
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use rusqlite::{Connection, OpenFlags, OptionalExtension, Transaction};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
@@ -17,12 +26,21 @@ use tracing::warn;
 #[derive(Debug)]
 pub struct SqliteStorage {
     conn: Connection,
+    /// Session recorded on events written through this handle.
+    session_id: Option<String>,
+    /// Schema version of a database written by a newer `br`. Such a database
+    /// is opened read-only so this binary cannot corrupt it.
+    newer_schema: Option<i32>,
+    /// Read-only mode: every mutation fails with [`BeadsError::ReadOnly`]
+    /// before touching the database.
+    read_only: bool,
 }
 
 /// Context for a mutation operation, tracking side effects.
 pub struct MutationContext {
     pub op_name: String,
     pub actor: String,
+    pub session_id: Option<String>,
     pub events: Vec<Event>,
     pub dirty_ids: HashSet<String>,
     pub invalidate_blocked_cache: bool,
@@ -34,6 +52,7 @@ impl MutationContext {
         Self {
             op_name: op_name.to_string(),
             actor: actor.to_string(),
+            session_id: None,
             events: Vec::new(),
             dirty_ids: HashSet::new(),
             invalidate_blocked_cache: false,
@@ -50,6 +69,7 @@ impl MutationContext {
             new_value: None,
             comment: details,
             created_at: Utc::now(),
+            session_id: self.session_id.clone(),
         });
     }
 
@@ -71,6 +91,7 @@ impl MutationContext {
             new_value,
             comment,
             created_at: Utc::now(),
+            session_id: self.session_id.clone(),
         });
     }
 
@@ -83,6 +104,18 @@ impl MutationContext {
     }
 }
 
+/// Busy timeout used when no lock timeout is configured.
+pub const DEFAULT_BUSY_TIMEOUT_MS: u64 = 30_000;
+
+/// Prepared statements kept per connection, keyed by SQL text. Ready and
+/// list queries are built per filter set, so this is sized well above
+/// rusqlite's default of 16.
+const STATEMENT_CACHE_CAPACITY: usize = 128;
+
+/// Metadata key holding the next `waits-for` wake time, after which the
+/// blocked cache is out of date.
+const BLOCKED_CACHE_WAKE_KEY: &str = "blocked_cache_next_wake";
+
 impl SqliteStorage {
     /// Open a new connection to the database at the given path.
     ///
@@ -95,21 +128,142 @@ impl SqliteStorage {
 
     /// Open a new connection with an optional busy timeout (ms).
     ///
+    /// Without a timeout, [`DEFAULT_BUSY_TIMEOUT_MS`] is used. WAL mode,
+    /// `synchronous=NORMAL` and the other connection PRAGMAs are applied on
+    /// every open. An older database is migrated to [`CURRENT_SCHEMA_VERSION`];
+    /// a newer one is left as is and opened read-only, and every mutation
+    /// fails with [`BeadsError::SchemaMismatch`].
+    ///
     /// # Errors
     ///
     /// Returns an error if the connection cannot be established or schema application fails.
     pub fn open_with_timeout(path: &Path, lock_timeout_ms: Option<u64>) -> Result<Self> {
         let conn = Connection::open(path)?;
-        if let Some(timeout) = lock_timeout_ms {
-            conn.busy_timeout(Duration::from_millis(timeout))?;
-        }
+        conn.busy_timeout(Duration::from_millis(
+            lock_timeout_ms.unwrap_or(DEFAULT_BUSY_TIMEOUT_MS),
+        ))?;
+        conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
         let user_version: i32 = conn
             .query_row("PRAGMA user_version", [], |row| row.get(0))
             .unwrap_or(0);
+        let mut newer_schema = None;
         if user_version < CURRENT_SCHEMA_VERSION {
             apply_schema(&conn)?;
+        } else {
+            apply_connection_pragmas(&conn)?;
+            if user_version > CURRENT_SCHEMA_VERSION {
+                warn!(
+                    found = user_version,
+                    supported = CURRENT_SCHEMA_VERSION,
+                    "database schema is newer than this br; opening read-only"
+                );
+                conn.pragma_update(None, "query_only", "ON")?;
+                newer_schema = Some(user_version);
+            }
+        }
+        Ok(Self {
+            conn,
+            session_id: None,
+            newer_schema,
+            read_only: false,
+        })
+    }
+
+    /// Open the database at `path` with `SQLITE_OPEN_READ_ONLY`.
+    ///
+    /// The schema is neither created nor migrated, and every mutation fails
+    /// with [`BeadsError::ReadOnly`] before reaching `SQLite`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database does not exist or cannot be opened.
+    pub fn open_read_only(path: &Path, lock_timeout_ms: Option<u64>) -> Result<Self> {
+        if !path.exists() {
+            return Err(BeadsError::DatabaseNotFound {
+                path: path.to_path_buf(),
+            });
+        }
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        conn.busy_timeout(Duration::from_millis(
+            lock_timeout_ms.unwrap_or(DEFAULT_BUSY_TIMEOUT_MS),
+        ))?;
+        conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+        let user_version: i32 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap_or(0);
+        if user_version < CURRENT_SCHEMA_VERSION {
+            warn!(
+                found = user_version,
+                supported = CURRENT_SCHEMA_VERSION,
+                "database schema is older than this br and cannot be migrated read-only"
+            );
+        }
+        Ok(Self {
+            conn,
+            session_id: None,
+            newer_schema: (user_version > CURRENT_SCHEMA_VERSION).then_some(user_version),
+            read_only: true,
+        })
+    }
+
+    /// Refuse all further mutations through this handle (see
+    /// [`SqliteStorage::open_read_only`]).
+    pub const fn set_read_only(&mut self) {
+        self.read_only = true;
+    }
+
+    /// Whether mutations through this handle are refused.
+    #[must_use]
+    pub const fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Refuse `operation` on a read-only handle with [`BeadsError::ReadOnly`]
+    /// instead of letting SQLite fail the write.
+    fn ensure_writable(&self, operation: &str) -> Result<()> {
+        if self.read_only {
+            return Err(BeadsError::ReadOnly {
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Write a consistent copy of the database to `path` (`VACUUM INTO`).
+    ///
+    /// Works on read-only handles; `path` must not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the copy cannot be written.
+    pub fn snapshot_to(&self, path: &Path) -> Result<()> {
+        self.conn
+            .execute("VACUUM INTO ?1", [path.to_string_lossy()])?;
+        Ok(())
+    }
+
+    /// Read the schema state of the database at `path` without migrating it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database does not exist or cannot be read.
+    pub fn inspect_schema(path: &Path) -> Result<SchemaState> {
+        if !path.exists() {
+            return Err(BeadsError::DatabaseNotFound {
+                path: path.to_path_buf(),
+            });
         }
-        Ok(Self { conn })
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        Ok(schema_state(&conn)?)
+    }
+
+    /// Schema state of this database.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema tables cannot be read.
+    pub fn schema_state(&self) -> Result<SchemaState> {
+        Ok(schema_state(&self.conn)?)
     }
 
     /// Open an in-memory database for testing.
@@ -119,8 +273,34 @@ impl SqliteStorage {
     /// Returns an error if the connection cannot be established.
     pub fn open_memory() -> Result<Self> {
         let conn = Connection::open_in_memory()?;
+        conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
         apply_schema(&conn)?;
-        Ok(Self { conn })
+        Ok(Self {
+            conn,
+            session_id: None,
+            newer_schema: None,
+            read_only: false,
+        })
+    }
+
+    /// Set the session ID recorded on events written by subsequent mutations.
+    pub fn set_session_id(&mut self, session_id: Option<String>) {
+        self.session_id = session_id;
+    }
+
+    /// Current connection PRAGMAs (journal mode, synchronous, busy timeout, ...).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pragma cannot be queried.
+    pub fn pragmas(&self) -> Result<ConnectionPragmas> {
+        Ok(crate::storage::schema::pragmas(&self.conn)?)
+    }
+
+    /// Session ID recorded on events, if any.
+    #[must_use]
+    pub fn session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
     }
 
     /// Get audit events for a specific issue.
@@ -141,28 +321,77 @@ impl SqliteStorage {
         crate::storage::events::get_all_events(&self.conn, limit)
     }
 
+    /// Get events recorded after event `after_id`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_events_after(&self, after_id: i64) -> Result<Vec<Event>> {
+        crate::storage::events::get_events_after(&self.conn, after_id)
+    }
+
+    /// Get the events recorded in one session, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_session_events(&self, session_id: &str) -> Result<Vec<Event>> {
+        crate::storage::events::get_session_events(&self.conn, session_id)
+    }
+
+    /// Largest event ID so far (0 if none).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn max_event_id(&self) -> Result<i64> {
+        crate::storage::events::max_event_id(&self.conn)
+    }
+
     /// Execute a mutation with the 4-step transaction protocol.
     ///
+    /// Inside [`Self::transaction`] the mutation joins the enclosing
+    /// transaction instead of starting its own.
+    ///
     /// # Errors
     ///
     /// Returns an error if any step fails (e.g. database error, logic error).
     /// The transaction is rolled back on error.
     pub fn mutate<F, R>(&mut self, op: &str, actor: &str, f: F) -> Result<R>
     where
-        F: FnOnce(&Transaction, &mut MutationContext) -> Result<R>,
+        F: FnOnce(&Connection, &mut MutationContext) -> Result<R>,
     {
-        let tx = self
-            .conn
-            .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+        self.ensure_writable(op)?;
+        if let Some(found) = self.newer_schema {
+            return Err(BeadsError::SchemaMismatch {
+                expected: CURRENT_SCHEMA_VERSION,
+                found,
+            });
+        }
+        let own_tx = if self.conn.is_autocommit() {
+            Some(Transaction::new_unchecked(
+                &self.conn,
+                rusqlite::TransactionBehavior::Immediate,
+            )?)
+        } else {
+            None
+        };
+        let tx = &self.conn;
         let mut ctx = MutationContext::new(op, actor);
+        ctx.session_id.clone_from(&self.session_id);
+
+        let result = f(tx, &mut ctx)?;
 
-        let result = f(&tx, &mut ctx)?;
+        // Conditional dependencies depend on their blocker's labels, type and
+        // priority, so a change to a conditional blocker re-evaluates them
+        let needs_cache_rebuild =
+            ctx.invalidate_blocked_cache || Self::has_conditional_dependents(tx, &ctx.dirty_ids)?;
 
         // Write events
         for event in ctx.events {
             tx.execute(
-                "INSERT INTO events (issue_id, event_type, actor, old_value, new_value, comment, created_at)
-                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+                "INSERT INTO events (issue_id, event_type, actor, old_value, new_value, comment, created_at, session_id)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
                 rusqlite::params![
                     event.issue_id,
                     event.event_type.as_str(),
@@ -170,7 +399,8 @@ impl SqliteStorage {
                     event.old_value,
                     event.new_value,
                     event.comment,
-                    event.created_at.to_rfc3339()
+                    event.created_at.to_rfc3339(),
+                    event.session_id.unwrap_or_default()
                 ],
             )?;
         }
@@ -183,19 +413,55 @@ impl SqliteStorage {
             )?;
         }
 
-        // Track if we need to rebuild cache
-        let needs_cache_rebuild = ctx.invalidate_blocked_cache;
-
         // Rebuild blocked cache inside the transaction if needed
         if needs_cache_rebuild {
-            Self::rebuild_blocked_cache_impl(&tx)?;
+            Self::rebuild_blocked_cache_impl(tx)?;
         }
 
-        tx.commit()?;
+        if let Some(own_tx) = own_tx {
+            own_tx.commit()?;
+        }
 
         Ok(result)
     }
 
+    /// Run several mutations inside one immediate transaction: either all of
+    /// them are committed or, if `f` fails, none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction cannot be started, `f` fails, or
+    /// the commit fails. The transaction is rolled back on error.
+    pub fn transaction<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut Self) -> Result<R>,
+    {
+        self.ensure_writable("transaction")?;
+        self.conn.execute_batch("BEGIN IMMEDIATE")?;
+        let result = f(self).and_then(|value| {
+            self.conn.execute_batch("COMMIT")?;
+            Ok(value)
+        });
+        if result.is_err() && !self.conn.is_autocommit() {
+            let _ = self.conn.execute_batch("ROLLBACK");
+        }
+        result
+    }
+
+    /// Whether any of `ids` is the target of a `conditional-blocks` dependency.
+    fn has_conditional_dependents(conn: &Connection, ids: &HashSet<String>) -> Result<bool> {
+        let mut stmt = conn.prepare_cached(
+            "SELECT EXISTS(SELECT 1 FROM dependencies
+             WHERE depends_on_id = ? AND type = 'conditional-blocks')",
+        )?;
+        for id in ids {
+            if stmt.query_row([id], |row| row.get::<_, bool>(0))? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     /// Create a new issue.
     ///
     /// # Errors
@@ -263,6 +529,7 @@ impl SqliteStorage {
                     i32::from(issue.is_template),
                 ],
             )?;
+            index_references(tx, &issue.id, 0, &reference_text(issue))?;
 
             // Insert Labels
             for label in &issue.labels {
@@ -292,14 +559,16 @@ impl SqliteStorage {
                 }
 
                 tx.execute(
-                    "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by)
-                     VALUES (?, ?, ?, ?, ?)",
+                    "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by, metadata, thread_id)
+                     VALUES (?, ?, ?, ?, ?, ?, ?)",
                     rusqlite::params![
                         issue.id,
                         dep.depends_on_id,
                         dep.dep_type.as_str(),
                         dep.created_at.to_rfc3339(),
-                        dep.created_by.as_deref().unwrap_or(actor)
+                        dep.created_by.as_deref().unwrap_or(actor),
+                        dep.metadata.as_deref().unwrap_or("{}"),
+                        dep.thread_id.as_deref().unwrap_or("")
                     ],
                 )?;
 
@@ -317,14 +586,20 @@ impl SqliteStorage {
             // Insert Comments
             for comment in &issue.comments {
                 tx.execute(
-                    "INSERT INTO comments (issue_id, author, text, created_at) VALUES (?, ?, ?, ?)",
+                    "INSERT INTO comments (issue_id, author, text, created_at, updated_at, deleted)
+                     VALUES (?, ?, ?, ?, ?, ?)",
                     rusqlite::params![
                         issue.id,
                         comment.author,
                         comment.body,
-                        comment.created_at.to_rfc3339()
+                        comment.created_at.to_rfc3339(),
+                        comment.updated_at.map(|at| at.to_rfc3339()),
+                        comment.deleted
                     ],
                 )?;
+                let comment_id = tx.last_insert_rowid();
+                index_mentions(tx, comment_id, &issue.id, &comment.body)?;
+                index_references(tx, &issue.id, comment_id, &comment.body)?;
                 ctx.record_event(
                     EventType::Commented,
                     &issue.id,
@@ -332,6 +607,26 @@ impl SqliteStorage {
                 );
             }
 
+            // Insert Code Refs
+            for code_ref in &issue.code_refs {
+                insert_code_ref(tx, &issue.id, code_ref)?;
+            }
+
+            // Insert Watchers
+            for watcher in &issue.watchers {
+                insert_watcher(tx, &issue.id, watcher)?;
+            }
+
+            // Insert Reactions
+            for reaction in &issue.reactions {
+                insert_reaction(tx, &issue.id, reaction)?;
+            }
+
+            // Insert Custom Fields
+            for (name, value) in &issue.fields {
+                insert_field(tx, &issue.id, name, value)?;
+            }
+
             ctx.record_event(
                 EventType::Created,
                 &issue.id,
@@ -599,6 +894,21 @@ impl SqliteStorage {
                 );
             }
 
+            if let Some(pinned) = updates.pinned {
+                let old_pinned = issue.pinned;
+                issue.pinned = pinned;
+                add_update("pinned", Box::new(i32::from(pinned)));
+                if pinned != old_pinned {
+                    ctx.record_field_change(
+                        EventType::Updated,
+                        id,
+                        Some(old_pinned.to_string()),
+                        Some(pinned.to_string()),
+                        Some(if pinned { "Pinned" } else { "Unpinned" }.to_string()),
+                    );
+                }
+            }
+
             // Date fields
             if let Some(ref val) = updates.due_at {
                 issue.due_at = *val;
@@ -629,6 +939,15 @@ impl SqliteStorage {
             let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
             tx.execute(&sql, params_refs.as_slice())?;
 
+            if updates.title.is_some()
+                || updates.description.is_some()
+                || updates.design.is_some()
+                || updates.acceptance_criteria.is_some()
+                || updates.notes.is_some()
+            {
+                index_references(tx, id, 0, &reference_text(&issue))?;
+            }
+
             ctx.mark_dirty(id);
 
             Ok(())
@@ -693,127 +1012,557 @@ impl SqliteStorage {
             .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })
     }
 
-    /// Get an issue by ID.
+    /// Delete several issues in one transaction.
+    ///
+    /// Every dependency link touching them is removed first, then each one
+    /// becomes a tombstone (or, with `hard`, is removed outright). Either all
+    /// of them go or none do. Returns the number of links removed.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    #[tracing::instrument(skip(self))]
-    pub fn get_issue(&self, id: &str) -> Result<Option<Issue>> {
-        let start = Instant::now();
-        let sql = r"
-            SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
-                   status, priority, issue_type, assignee, owner, estimated_minutes,
-                   created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
-                   due_at, defer_until, external_ref, source_system, source_repo,
-                   deleted_at, deleted_by, delete_reason, original_type,
-                   compaction_level, compacted_at, compacted_at_commit, original_size,
-                   sender, ephemeral, pinned, is_template
-            FROM issues WHERE id = ?
-        ";
-
-        let mut stmt = self.conn.prepare_cached(sql)?;
-        let result = stmt.query_row([id], |row| self.issue_from_row(row));
+    /// Returns an error if any issue doesn't exist or the delete fails.
+    pub fn delete_issues(
+        &mut self,
+        ids: &[String],
+        actor: &str,
+        reason: &str,
+        hard: bool,
+    ) -> Result<usize> {
+        for id in ids {
+            if !self.id_exists(id)? {
+                return Err(BeadsError::IssueNotFound { id: id.clone() });
+            }
+        }
+        let doomed: HashSet<&str> = ids.iter().map(String::as_str).collect();
 
-        let elapsed = start.elapsed();
-        match result {
-            Ok(issue) => {
-                tracing::debug!(
-                    operation = "get_issue",
-                    duration_ms = elapsed.as_millis(),
-                    found = true,
-                    "DB query completed"
-                );
-                Ok(Some(issue))
+        self.mutate("delete_issues", actor, |tx, ctx| {
+            let now = Utc::now().to_rfc3339();
+            let mut removed = 0;
+            let mut touched: BTreeSet<String> = BTreeSet::new();
+            for id in ids {
+                let neighbours: Vec<String> = tx
+                    .prepare(
+                        "SELECT issue_id FROM dependencies WHERE depends_on_id = ?1
+                         UNION
+                         SELECT d.depends_on_id FROM dependencies d
+                         JOIN issues i ON i.id = d.depends_on_id
+                         WHERE d.issue_id = ?1",
+                    )?
+                    .query_map([id], |row| row.get(0))?
+                    .collect::<rusqlite::Result<_>>()?;
+                touched.extend(neighbours);
+                removed += tx.execute(
+                    "DELETE FROM dependencies WHERE issue_id = ?1 OR depends_on_id = ?1",
+                    [id],
+                )?;
             }
-            Err(rusqlite::Error::QueryReturnedNoRows) => {
-                tracing::debug!(
-                    operation = "get_issue",
-                    duration_ms = elapsed.as_millis(),
-                    found = false,
-                    "DB query completed"
+
+            for id in ids {
+                if hard {
+                    tx.execute("DELETE FROM issues WHERE id = ?", [id])?;
+                    continue;
+                }
+                tx.execute(
+                    "UPDATE issues SET
+                        status = 'tombstone',
+                        deleted_at = ?1,
+                        deleted_by = ?2,
+                        delete_reason = ?3,
+                        original_type = issue_type,
+                        updated_at = ?1
+                     WHERE id = ?4",
+                    rusqlite::params![now, actor, reason, id],
+                )?;
+                ctx.record_event(
+                    EventType::Deleted,
+                    id,
+                    Some(format!("Deleted issue: {reason}")),
                 );
-                Ok(None)
+                ctx.mark_dirty(id);
             }
-            Err(e) => {
-                tracing::warn!(operation = "get_issue", duration_ms = elapsed.as_millis(), error = %e, "DB query failed");
-                Err(e.into())
+
+            for id in touched.iter().filter(|id| !doomed.contains(id.as_str())) {
+                tx.execute(
+                    "UPDATE issues SET updated_at = ? WHERE id = ?",
+                    rusqlite::params![now, id],
+                )?;
+                ctx.mark_dirty(id);
             }
-        }
+            ctx.invalidate_cache();
+
+            Ok(removed)
+        })
     }
 
-    /// Get multiple issues by ID.
+    /// Remove an issue outright, leaving no tombstone.
+    ///
+    /// Its labels, comments, events and other rows go with it; dependencies
+    /// on it are dropped and their issues marked dirty. Other clones only
+    /// learn of the removal from a JSONL exported afterwards, and bring the
+    /// issue back if they still have it.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    #[tracing::instrument(skip(self), fields(count = ids.len()))]
-    pub fn get_issues_by_ids(&self, ids: &[String]) -> Result<Vec<Issue>> {
-        let start = Instant::now();
-        const SQLITE_VAR_LIMIT: usize = 900;
-
-        if ids.is_empty() {
-            return Ok(Vec::new());
+    /// Returns an error if the issue doesn't exist or the delete fails.
+    pub fn purge_issue(&mut self, id: &str, actor: &str) -> Result<()> {
+        if !self.id_exists(id)? {
+            return Err(BeadsError::IssueNotFound { id: id.to_string() });
         }
 
-        let mut issues = Vec::new();
-
-        for chunk in ids.chunks(SQLITE_VAR_LIMIT) {
-            let placeholders: Vec<&str> = chunk.iter().map(|_| "?").collect();
-            let sql = format!(
-                r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
-                         status, priority, issue_type, assignee, owner, estimated_minutes,
-                         created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
-                         due_at, defer_until, external_ref, source_system, source_repo,
-                         deleted_at, deleted_by, delete_reason, original_type,
-                         compaction_level, compacted_at, compacted_at_commit, original_size,
-                         sender, ephemeral, pinned, is_template
-                  FROM issues WHERE id IN ({})",
-                placeholders.join(",")
-            );
-
-            let params: Vec<&dyn rusqlite::ToSql> =
-                chunk.iter().map(|s| s as &dyn rusqlite::ToSql).collect();
+        self.mutate("purge_issue", actor, |tx, ctx| {
+            let dependents: Vec<String> = tx
+                .prepare("SELECT DISTINCT issue_id FROM dependencies WHERE depends_on_id = ?")?
+                .query_map([id], |row| row.get(0))?
+                .collect::<rusqlite::Result<_>>()?;
+            tx.execute("DELETE FROM dependencies WHERE depends_on_id = ?", [id])?;
+            tx.execute("DELETE FROM issues WHERE id = ?", [id])?;
 
-            let mut stmt = self.conn.prepare(&sql)?;
-            let chunk_issues = stmt
-                .query_map(params.as_slice(), |row| self.issue_from_row(row))?
-                .collect::<std::result::Result<Vec<_>, _>>()?;
-            issues.extend(chunk_issues);
-        }
+            for dependent in dependents.iter().filter(|dependent| *dependent != id) {
+                ctx.mark_dirty(dependent);
+            }
+            ctx.invalidate_cache();
 
-        tracing::debug!(
-            operation = "get_issues_by_ids",
-            duration_ms = start.elapsed().as_millis(),
-            result_count = issues.len(),
-            "DB query completed"
-        );
-        Ok(issues)
+            Ok(())
+        })
     }
 
-    /// List issues with optional filters.
+    /// Restore a tombstoned issue.
+    ///
+    /// Clears the deletion fields and records a `Restored` event. The issue
+    /// comes back as `closed` if it had been closed (it still carries a close
+    /// reason), otherwise as `open`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    #[allow(clippy::too_many_lines)]
-    #[tracing::instrument(skip(self, filters))]
-    pub fn list_issues(&self, filters: &ListFilters) -> Result<Vec<Issue>> {
-        let start = Instant::now();
-        let mut sql = String::from(
-            r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
-                     status, priority, issue_type, assignee, owner, estimated_minutes,
-                     created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
-                     due_at, defer_until, external_ref, source_system, source_repo,
-                     deleted_at, deleted_by, delete_reason, original_type,
-                     compaction_level, compacted_at, compacted_at_commit, original_size,
-                     sender, ephemeral, pinned, is_template
-            FROM issues WHERE 1=1",
-        );
+    /// Returns an error if the issue doesn't exist, is not a tombstone, or the
+    /// database update fails.
+    pub fn restore_issue(&mut self, id: &str, actor: &str, reason: Option<&str>) -> Result<Issue> {
+        let mut issue = self
+            .get_issue(id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+        if issue.status != Status::Tombstone {
+            return Err(BeadsError::validation(
+                "id",
+                format!("{id} is not deleted (status: {})", issue.status.as_str()),
+            ));
+        }
 
-        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        let was_closed = issue.closed_at.is_some() && issue.close_reason.is_some();
+        issue.status = if was_closed {
+            Status::Closed
+        } else {
+            Status::Open
+        };
+        if !was_closed {
+            issue.closed_at = None;
+        }
+        issue.deleted_at = None;
+        issue.deleted_by = None;
+        issue.delete_reason = None;
+        issue.original_type = None;
+        issue.updated_at = Utc::now();
+        issue.content_hash = Some(issue.compute_content_hash());
 
-        if let Some(ref statuses) = filters.statuses {
+        self.mutate("restore_issue", actor, |tx, ctx| {
+            tx.execute(
+                "UPDATE issues SET
+                    status = ?,
+                    closed_at = ?,
+                    deleted_at = NULL,
+                    deleted_by = '',
+                    delete_reason = '',
+                    original_type = '',
+                    updated_at = ?,
+                    content_hash = ?
+                 WHERE id = ?",
+                rusqlite::params![
+                    issue.status.as_str(),
+                    issue.closed_at.map(|t| t.to_rfc3339()),
+                    issue.updated_at.to_rfc3339(),
+                    issue.content_hash,
+                    id
+                ],
+            )?;
+
+            ctx.record_event(
+                EventType::Restored,
+                id,
+                Some(reason.map_or_else(
+                    || "Restored issue".to_string(),
+                    |reason| format!("Restored issue: {reason}"),
+                )),
+            );
+            ctx.mark_dirty(id);
+            ctx.invalidate_cache();
+
+            Ok(())
+        })?;
+
+        self.get_issue(id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })
+    }
+
+    /// Reopen a closed or deleted issue.
+    ///
+    /// Sets the status back to `open`, clears the close and deletion fields,
+    /// and records a `StatusChanged` and a `Reopened` event, the latter with
+    /// `reason` as its comment. The blocked cache is rebuilt, so issues that
+    /// depend on this one are blocked again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue doesn't exist, is not closed or deleted,
+    /// or the database update fails.
+    pub fn reopen_issue(&mut self, id: &str, reason: Option<&str>, actor: &str) -> Result<Issue> {
+        let mut issue = self
+            .get_issue(id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+        if !issue.status.is_terminal() {
+            return Err(BeadsError::validation(
+                "id",
+                format!("{id} is not closed (status: {})", issue.status.as_str()),
+            ));
+        }
+
+        let old_status = issue.status.as_str().to_string();
+        issue.status = Status::Open;
+        issue.closed_at = None;
+        issue.close_reason = None;
+        issue.closed_by_session = None;
+        issue.deleted_at = None;
+        issue.deleted_by = None;
+        issue.delete_reason = None;
+        issue.updated_at = Utc::now();
+        issue.content_hash = Some(issue.compute_content_hash());
+
+        self.mutate("reopen_issue", actor, |tx, ctx| {
+            tx.execute(
+                "UPDATE issues SET
+                    status = ?,
+                    closed_at = NULL,
+                    close_reason = '',
+                    closed_by_session = '',
+                    deleted_at = NULL,
+                    deleted_by = '',
+                    delete_reason = '',
+                    updated_at = ?,
+                    content_hash = ?
+                 WHERE id = ?",
+                rusqlite::params![
+                    issue.status.as_str(),
+                    issue.updated_at.to_rfc3339(),
+                    issue.content_hash,
+                    id
+                ],
+            )?;
+
+            ctx.record_field_change(
+                EventType::StatusChanged,
+                id,
+                Some(old_status),
+                Some(issue.status.as_str().to_string()),
+                None,
+            );
+            ctx.record_event(EventType::Reopened, id, reason.map(str::to_string));
+            ctx.mark_dirty(id);
+            ctx.invalidate_cache();
+
+            Ok(())
+        })?;
+
+        self.get_issue(id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })
+    }
+
+    /// Change issue IDs, rewriting every row that references them.
+    ///
+    /// The `(old, new)` pairs are applied in order inside one transaction.
+    /// Each old ID is recorded in `id_aliases` so it keeps resolving to the
+    /// issue, and aliases that pointed at the old ID follow it to the new one.
+    /// Issues whose dependencies pointed at a renamed ID are marked dirty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an old ID doesn't exist, a new ID is already taken,
+    /// or the database update fails.
+    pub fn rename_issue_ids(
+        &mut self,
+        renames: &[(String, String)],
+        actor: &str,
+        comment: &str,
+    ) -> Result<()> {
+        for (old, new) in renames {
+            if !self.id_exists(old)? {
+                return Err(BeadsError::IssueNotFound { id: old.clone() });
+            }
+            if self.id_exists(new)? {
+                return Err(BeadsError::validation(
+                    "id",
+                    format!("cannot rename {old}: {new} already exists"),
+                ));
+            }
+        }
+
+        self.mutate("rename_issue_ids", actor, |tx, ctx| {
+            // Referencing rows are rewritten one table at a time, so foreign
+            // keys are only checked once everything points at the new ID.
+            tx.execute_batch("PRAGMA defer_foreign_keys = ON")?;
+            let now = Utc::now().to_rfc3339();
+
+            for (old, new) in renames {
+                let dependents: Vec<String> = tx
+                    .prepare("SELECT DISTINCT issue_id FROM dependencies WHERE depends_on_id = ?")?
+                    .query_map([old], |row| row.get(0))?
+                    .collect::<std::result::Result<_, _>>()?;
+
+                tx.execute(
+                    "UPDATE issues SET id = ?, updated_at = ? WHERE id = ?",
+                    rusqlite::params![new, now, old],
+                )?;
+                for (table, column) in ISSUE_ID_REFERENCES {
+                    tx.execute(
+                        &format!("UPDATE {table} SET {column} = ? WHERE {column} = ?"),
+                        rusqlite::params![new, old],
+                    )?;
+                }
+                tx.execute("DELETE FROM dirty_issues WHERE issue_id = ?", [old])?;
+                tx.execute("DELETE FROM export_hashes WHERE issue_id = ?", [old])?;
+                tx.execute("DELETE FROM blocked_issues_cache WHERE issue_id = ?", [old])?;
+
+                tx.execute("DELETE FROM id_aliases WHERE alias = ?", [new])?;
+                tx.execute(
+                    "UPDATE id_aliases SET issue_id = ? WHERE issue_id = ?",
+                    rusqlite::params![new, old],
+                )?;
+                tx.execute(
+                    "INSERT OR REPLACE INTO id_aliases (alias, issue_id, created_at) VALUES (?, ?, ?)",
+                    rusqlite::params![old, new, now],
+                )?;
+
+                ctx.record_field_change(
+                    EventType::Updated,
+                    new,
+                    Some(old.clone()),
+                    Some(new.clone()),
+                    Some(comment.to_string()),
+                );
+                ctx.mark_dirty(new);
+                for dependent in &dependents {
+                    ctx.mark_dirty(dependent);
+                }
+            }
+
+            // Dependents renamed later in the batch are no longer under their old ID.
+            for (old, _) in renames {
+                ctx.dirty_ids.remove(old);
+            }
+            ctx.invalidate_cache();
+            Ok(())
+        })
+    }
+
+    /// Get an issue by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(skip(self))]
+    pub fn get_issue(&self, id: &str) -> Result<Option<Issue>> {
+        let start = Instant::now();
+        let sql = r"
+            SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
+                   status, priority, issue_type, assignee, owner, estimated_minutes,
+                   created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
+                   due_at, defer_until, external_ref, source_system, source_repo,
+                   deleted_at, deleted_by, delete_reason, original_type,
+                   compaction_level, compacted_at, compacted_at_commit, original_size,
+                   sender, ephemeral, pinned, is_template
+            FROM issues WHERE id = ?
+        ";
+
+        let mut stmt = self.conn.prepare_cached(sql)?;
+        let result = stmt.query_row([id], Self::issue_from_row);
+
+        let elapsed = start.elapsed();
+        match result {
+            Ok(issue) => {
+                tracing::debug!(
+                    operation = "get_issue",
+                    duration_ms = elapsed.as_millis(),
+                    found = true,
+                    "DB query completed"
+                );
+                Ok(Some(issue))
+            }
+            Err(rusqlite::Error::QueryReturnedNoRows) => {
+                tracing::debug!(
+                    operation = "get_issue",
+                    duration_ms = elapsed.as_millis(),
+                    found = false,
+                    "DB query completed"
+                );
+                Ok(None)
+            }
+            Err(e) => {
+                tracing::warn!(operation = "get_issue", duration_ms = elapsed.as_millis(), error = %e, "DB query failed");
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Get multiple issues by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(skip(self), fields(count = ids.len()))]
+    pub fn get_issues_by_ids(&self, ids: &[String]) -> Result<Vec<Issue>> {
+        let start = Instant::now();
+        const SQLITE_VAR_LIMIT: usize = 900;
+
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut issues = Vec::new();
+
+        for chunk in ids.chunks(SQLITE_VAR_LIMIT) {
+            let placeholders: Vec<&str> = chunk.iter().map(|_| "?").collect();
+            let sql = format!(
+                r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
+                         status, priority, issue_type, assignee, owner, estimated_minutes,
+                         created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
+                         due_at, defer_until, external_ref, source_system, source_repo,
+                         deleted_at, deleted_by, delete_reason, original_type,
+                         compaction_level, compacted_at, compacted_at_commit, original_size,
+                         sender, ephemeral, pinned, is_template
+                  FROM issues WHERE id IN ({})",
+                placeholders.join(",")
+            );
+
+            let params: Vec<&dyn rusqlite::ToSql> =
+                chunk.iter().map(|s| s as &dyn rusqlite::ToSql).collect();
+
+            let mut stmt = self.conn.prepare(&sql)?;
+            let chunk_issues = stmt
+                .query_map(params.as_slice(), Self::issue_from_row)?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            issues.extend(chunk_issues);
+        }
+
+        tracing::debug!(
+            operation = "get_issues_by_ids",
+            duration_ms = start.elapsed().as_millis(),
+            result_count = issues.len(),
+            "DB query completed"
+        );
+        Ok(issues)
+    }
+
+    /// List issues with optional filters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(skip(self, filters))]
+    pub fn list_issues(&self, filters: &ListFilters) -> Result<Vec<Issue>> {
+        let start = Instant::now();
+        let (sql, params) = Self::list_issues_sql(filters, false)?;
+
+        let mut stmt = self.conn.prepare_cached(&sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        let issues = stmt
+            .query_map(params_refs.as_slice(), Self::issue_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        tracing::debug!(
+            operation = "list_issues",
+            duration_ms = start.elapsed().as_millis(),
+            result_count = issues.len(),
+            "DB query completed"
+        );
+        Ok(issues)
+    }
+
+    /// List issues with optional filters, each with its dependency and
+    /// dependent counts.
+    ///
+    /// The counts are read from the trigger-maintained `dependency_count` and
+    /// `dependent_count` columns, so listing does no aggregation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(skip(self, filters))]
+    pub fn list_issues_with_counts(&self, filters: &ListFilters) -> Result<Vec<IssueWithCounts>> {
+        let start = Instant::now();
+        let (sql, params) = Self::list_issues_sql(filters, true)?;
+
+        let mut stmt = self.conn.prepare_cached(&sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        let rows = stmt
+            .query_map(params_refs.as_slice(), |row| {
+                // The counts follow the 36 issue columns
+                Ok(IssueWithCounts {
+                    issue: Self::issue_from_row(row)?,
+                    dependency_count: usize::try_from(row.get::<_, i64>(36)?).unwrap_or(0),
+                    dependent_count: usize::try_from(row.get::<_, i64>(37)?).unwrap_or(0),
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        tracing::debug!(
+            operation = "list_issues_with_counts",
+            duration_ms = start.elapsed().as_millis(),
+            result_count = rows.len(),
+            "DB query completed"
+        );
+        Ok(rows)
+    }
+
+    /// Stream issues matching `filters` in batches of up to `batch_size`.
+    ///
+    /// Rows are read incrementally from the database, so memory stays bounded by
+    /// the batch size regardless of how many issues match. `f` returns `false`
+    /// to stop early.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails or `f` returns an error.
+    pub fn stream_issues<F>(&self, filters: &ListFilters, batch_size: usize, f: F) -> Result<()>
+    where
+        F: FnMut(Vec<Issue>) -> Result<bool>,
+    {
+        let (sql, params) = Self::list_issues_sql(filters, false)?;
+        self.stream_query(&sql, &params, batch_size, f)
+    }
+
+    /// Build the SQL (and bound parameters) for [`Self::list_issues`].
+    ///
+    /// With `with_counts`, each row also carries the issue's cached
+    /// dependency and dependent counts.
+    #[allow(clippy::too_many_lines)]
+    fn list_issues_sql(
+        filters: &ListFilters,
+        with_counts: bool,
+    ) -> Result<(String, Vec<Box<dyn rusqlite::ToSql>>)> {
+        let mut sql = String::from(
+            r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
+                     status, priority, issue_type, assignee, owner, estimated_minutes,
+                     created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
+                     due_at, defer_until, external_ref, source_system, source_repo,
+                     deleted_at, deleted_by, delete_reason, original_type,
+                     compaction_level, compacted_at, compacted_at_commit, original_size,
+                     sender, ephemeral, pinned, is_template",
+        );
+        if with_counts {
+            sql.push_str(", dependency_count, dependent_count");
+        }
+        sql.push_str(" FROM issues WHERE 1=1");
+
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+
+        if let Some(ref statuses) = filters.statuses {
             if !statuses.is_empty() {
                 let placeholders: Vec<String> = statuses.iter().map(|_| "?".to_string()).collect();
                 let _ = write!(sql, " AND status IN ({}) ", placeholders.join(","));
@@ -886,6 +1635,24 @@ impl SqliteStorage {
             }
         }
 
+        if let Some(ref touches) = filters.touches {
+            let path = touches.trim_end_matches('/');
+            sql.push_str(
+                " AND id IN (SELECT issue_id FROM code_refs WHERE path = ? OR path LIKE ? ESCAPE '\\')",
+            );
+            params.push(Box::new(path.to_string()));
+            params.push(Box::new(format!("{}/%", escape_like_pattern(path))));
+        }
+
+        push_dependency_filters(
+            &mut sql,
+            &mut params,
+            filters.depends_on.as_deref(),
+            filters.blocks.as_deref(),
+            filters.dep_type.as_deref(),
+        );
+        push_field_filters(&mut sql, &mut params, &filters.field_filters);
+
         if let Some(ref title_contains) = filters.title_contains {
             sql.push_str(" AND title LIKE ? ESCAPE '\\'");
             let escaped = escape_like_pattern(title_contains);
@@ -902,42 +1669,21 @@ impl SqliteStorage {
             params.push(Box::new(ts.to_rfc3339()));
         }
 
-        // Apply custom sort if provided
-        if let Some(ref sort_field) = filters.sort {
-            let order = if filters.reverse { "DESC" } else { "ASC" };
-            // Simple validation to prevent injection (though params should handle it,
-            // column names can't be parameterized)
-            match sort_field.as_str() {
-                "priority" => {
-                    let secondary_order = if filters.reverse { "ASC" } else { "DESC" };
-                    let _ = write!(
-                        sql,
-                        " ORDER BY priority {order}, created_at {secondary_order}"
-                    );
-                }
-                "created_at" | "created" => {
-                    let order = if filters.reverse { "ASC" } else { "DESC" };
-                    let _ = write!(sql, " ORDER BY created_at {order}");
-                }
-                "updated_at" | "updated" => {
-                    let order = if filters.reverse { "ASC" } else { "DESC" };
-                    let _ = write!(sql, " ORDER BY updated_at {order}");
-                }
-                "title" => {
-                    // Case-insensitive sort for title
-                    let _ = write!(sql, " ORDER BY title COLLATE NOCASE {order}");
-                }
-                _ => {
-                    // Default fallback
-                    sql.push_str(" ORDER BY priority ASC, created_at DESC");
-                }
-            }
-        } else if filters.reverse {
-            sql.push_str(" ORDER BY priority DESC, created_at ASC");
-        } else {
-            sql.push_str(" ORDER BY priority ASC, created_at DESC");
+        if let Some(ref cursor) = filters.after {
+            cursor.push_predicate(
+                filters.sort.as_deref(),
+                filters.reverse,
+                &mut sql,
+                &mut params,
+            )?;
         }
 
+        // Column names can't be parameterized; order_columns maps the sort key
+        // onto a fixed set of columns (unknown keys fall back to the default).
+        let order = order_columns(filters.sort.as_deref(), filters.reverse);
+        let paginate = filters.paginate || filters.after.is_some();
+        let _ = write!(sql, " ORDER BY {}", order_by_clause(&order, paginate));
+
         if let Some(limit) = filters.limit {
             if limit > 0 {
                 sql.push_str(" LIMIT ?");
@@ -945,14 +1691,88 @@ impl SqliteStorage {
             }
         }
 
+        Ok((sql, params))
+    }
+
+    /// Run an issue-row query, handing rows to `f` in batches of up to `batch_size`.
+    fn stream_query<F>(
+        &self,
+        sql: &str,
+        params: &[Box<dyn rusqlite::ToSql>],
+        batch_size: usize,
+        mut f: F,
+    ) -> Result<()>
+    where
+        F: FnMut(Vec<Issue>) -> Result<bool>,
+    {
+        let batch_size = batch_size.max(1);
+        let mut stmt = self.conn.prepare(sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        let mut rows = stmt.query_map(params_refs.as_slice(), Self::issue_from_row)?;
+
+        loop {
+            let mut batch = Vec::with_capacity(batch_size);
+            for row in rows.by_ref().take(batch_size) {
+                batch.push(row?);
+            }
+            if batch.is_empty() {
+                return Ok(());
+            }
+            let exhausted = batch.len() < batch_size;
+            if !f(batch)? || exhausted {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Search issues by query with optional filters.
+    ///
+    /// Case-insensitive substring match on title, description and ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn search_issues(&self, query: &str, filters: &ListFilters) -> Result<Vec<Issue>> {
+        self.search_issues_with(&SearchQuery::new(query.trim()), filters)
+    }
+
+    /// Search issues with a field-scoped, optionally regex or case-sensitive
+    /// query.
+    ///
+    /// Substring queries are matched in SQL. Regex queries select candidates
+    /// with the other filters and are matched in Rust, with `filters.limit`
+    /// applied afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the regex is invalid or the database query fails.
+    #[tracing::instrument(skip(self, filters))]
+    pub fn search_issues_with(
+        &self,
+        query: &SearchQuery,
+        filters: &ListFilters,
+    ) -> Result<Vec<Issue>> {
+        let start = Instant::now();
+        if query.text.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        let matcher = query.regex.then(|| query.matcher()).transpose()?;
+        let (sql, params) = Self::search_issues_sql(query, filters)?;
+
         let mut stmt = self.conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
-        let issues = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
+        let mut issues = stmt
+            .query_map(params_refs.as_slice(), Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
+        if let Some(matcher) = matcher {
+            issues = self.retain_search_matches(issues, &query.fields, &matcher)?;
+            if let Some(limit) = filters.limit.filter(|&limit| limit > 0) {
+                issues.truncate(limit);
+            }
+        }
 
         tracing::debug!(
-            operation = "list_issues",
+            operation = "search_issues",
             duration_ms = start.elapsed().as_millis(),
             result_count = issues.len(),
             "DB query completed"
@@ -960,20 +1780,63 @@ impl SqliteStorage {
         Ok(issues)
     }
 
-    /// Search issues by query with optional filters.
+    /// Stream search results in batches of up to `batch_size`.
+    ///
+    /// See [`Self::stream_issues`]; an empty query yields no batches. With a
+    /// regex query, batches hold only the matching rows of each page read.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    #[allow(clippy::too_many_lines)]
-    #[tracing::instrument(skip(self, filters))]
-    pub fn search_issues(&self, query: &str, filters: &ListFilters) -> Result<Vec<Issue>> {
-        let start = Instant::now();
-        let trimmed = query.trim();
-        if trimmed.is_empty() {
-            return Ok(Vec::new());
+    /// Returns an error if the regex is invalid, the database query fails or
+    /// `f` returns an error.
+    pub fn stream_search_issues<F>(
+        &self,
+        query: &SearchQuery,
+        filters: &ListFilters,
+        batch_size: usize,
+        mut f: F,
+    ) -> Result<()>
+    where
+        F: FnMut(Vec<Issue>) -> Result<bool>,
+    {
+        if query.text.trim().is_empty() {
+            return Ok(());
+        }
+        let (sql, params) = Self::search_issues_sql(query, filters)?;
+        if !query.regex {
+            return self.stream_query(&sql, &params, batch_size, f);
+        }
+        let matcher = query.matcher()?;
+        self.stream_query(&sql, &params, batch_size, |batch| {
+            let batch = self.retain_search_matches(batch, &query.fields, &matcher)?;
+            if batch.is_empty() { Ok(true) } else { f(batch) }
+        })
+    }
+
+    fn retain_search_matches(
+        &self,
+        issues: Vec<Issue>,
+        fields: &[SearchField],
+        matcher: &TextMatcher,
+    ) -> Result<Vec<Issue>> {
+        let mut kept = Vec::with_capacity(issues.len());
+        for issue in issues {
+            if Storage::find_search_match(self, &issue, fields, matcher)?.is_some() {
+                kept.push(issue);
+            }
         }
+        Ok(kept)
+    }
 
+    /// Build the SQL (and bound parameters) for [`Self::search_issues_with`].
+    ///
+    /// Regex queries get no text predicate and no `LIMIT`: the caller matches
+    /// and truncates the rows.
+    #[allow(clippy::too_many_lines)]
+    fn search_issues_sql(
+        query: &SearchQuery,
+        filters: &ListFilters,
+    ) -> Result<(String, Vec<Box<dyn rusqlite::ToSql>>)> {
         let mut sql = String::from(
             r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
                      status, priority, issue_type, assignee, owner, estimated_minutes,
@@ -988,14 +1851,41 @@ impl SqliteStorage {
 
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
 
-        sql.push_str(
-            " AND (title LIKE ? ESCAPE '\\' OR description LIKE ? ESCAPE '\\' OR id LIKE ? ESCAPE '\\')",
-        );
-        let escaped = escape_like_pattern(trimmed);
-        let pattern = format!("%{escaped}%");
-        params.push(Box::new(pattern.clone()));
-        params.push(Box::new(pattern.clone()));
-        params.push(Box::new(pattern));
+        if !query.regex {
+            let text = query.text.trim();
+            // instr() is case-sensitive; LIKE folds ASCII case
+            let (pattern, predicate): (String, fn(&str) -> String) = if query.case_sensitive {
+                (text.to_string(), |column| format!("instr({column}, ?) > 0"))
+            } else {
+                (format!("%{}%", escape_like_pattern(text)), |column| {
+                    format!("{column} LIKE ? ESCAPE '\\'")
+                })
+            };
+            let fields = if query.fields.is_empty() {
+                &SearchField::DEFAULT[..]
+            } else {
+                &query.fields
+            };
+            let clauses: Vec<String> = fields
+                .iter()
+                .map(|field| {
+                    field.column().map_or_else(
+                        || {
+                            format!(
+                                "EXISTS (SELECT 1 FROM comments WHERE comments.issue_id = issues.id \
+                                 AND comments.deleted = 0 AND {})",
+                                predicate("comments.text")
+                            )
+                        },
+                        predicate,
+                    )
+                })
+                .collect();
+            let _ = write!(sql, " AND ({})", clauses.join(" OR "));
+            for _ in &clauses {
+                params.push(Box::new(pattern.clone()));
+            }
+        }
 
         if let Some(ref statuses) = filters.statuses {
             if !statuses.is_empty() {
@@ -1066,54 +1956,203 @@ impl SqliteStorage {
             }
         }
 
+        if let Some(ref touches) = filters.touches {
+            let path = touches.trim_end_matches('/');
+            sql.push_str(
+                " AND id IN (SELECT issue_id FROM code_refs WHERE path = ? OR path LIKE ? ESCAPE '\\')",
+            );
+            params.push(Box::new(path.to_string()));
+            params.push(Box::new(format!("{}/%", escape_like_pattern(path))));
+        }
+
+        push_dependency_filters(
+            &mut sql,
+            &mut params,
+            filters.depends_on.as_deref(),
+            filters.blocks.as_deref(),
+            filters.dep_type.as_deref(),
+        );
+        push_field_filters(&mut sql, &mut params, &filters.field_filters);
+
         if let Some(ref title_contains) = filters.title_contains {
             sql.push_str(" AND title LIKE ? ESCAPE '\\'");
             let escaped = escape_like_pattern(title_contains);
             params.push(Box::new(format!("%{escaped}%")));
         }
 
-        sql.push_str(" ORDER BY priority ASC, created_at DESC");
+        // Search always uses the default ordering (callers re-sort client-side)
+        if let Some(ref cursor) = filters.after {
+            cursor.push_predicate(None, false, &mut sql, &mut params)?;
+        }
+        let order = order_columns(None, false);
+        let paginate = filters.paginate || filters.after.is_some();
+        let _ = write!(sql, " ORDER BY {}", order_by_clause(&order, paginate));
 
         if let Some(limit) = filters.limit {
-            if limit > 0 {
+            if limit > 0 && !query.regex {
                 sql.push_str(" LIMIT ?");
                 params.push(Box::new(limit));
             }
         }
 
-        let mut stmt = self.conn.prepare(&sql)?;
-        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
-        let issues = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
-            .collect::<std::result::Result<Vec<_>, _>>()?;
-
-        tracing::debug!(
-            operation = "search_issues",
-            duration_ms = start.elapsed().as_millis(),
-            result_count = issues.len(),
-            "DB query completed"
-        );
-        Ok(issues)
+        Ok((sql, params))
     }
 
-    /// Get ready issues (unblocked, not deferred, not pinned, not ephemeral).
+    /// Get ready issues (unblocked, not deferred, not ephemeral).
     ///
     /// Ready definition:
     /// 1. Status is `open` OR `in_progress`
     /// 2. NOT in `blocked_issues_cache`
     /// 3. `defer_until` is NULL or <= now (unless `include_deferred`)
-    /// 4. `pinned = 0` (not pinned)
-    /// 5. `ephemeral = 0` AND ID does not contain `-wisp-`
+    /// 4. `ephemeral = 0` AND ID does not contain `-wisp-`
+    /// 5. Not a template or a milestone
+    ///
+    /// Pinned issues sort first under every policy.
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    #[allow(clippy::too_many_lines)]
     pub fn get_ready_issues(
         &self,
         filters: &ReadyFilters,
         sort: ReadySortPolicy,
     ) -> Result<Vec<Issue>> {
+        let (sql, params) = Self::ready_issues_sql(filters, sort);
+        let mut stmt = self.conn.prepare_cached(&sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        let issues: Vec<Issue> = stmt
+            .query_map(params_refs.as_slice(), Self::issue_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(issues)
+    }
+
+    /// Atomically claim the top ready issue(s) for `actor`.
+    ///
+    /// Candidates are selected with the same rules as [`Self::get_ready_issues`]
+    /// (restricted to unassigned issues) inside a single IMMEDIATE transaction,
+    /// so concurrent claimers can never receive the same issue. Each claimed
+    /// issue is assigned to `actor` and moved to `in_progress`.
+    ///
+    /// IDs in `exclude` (e.g. issues with unresolved external blockers) are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query or update fails.
+    pub fn claim_ready_issues(
+        &mut self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+        count: usize,
+        exclude: &HashSet<String>,
+        actor: &str,
+    ) -> Result<Vec<Issue>> {
+        let filters = ReadyFilters {
+            assignee: None,
+            unassigned: true,
+            limit: None,
+            ..filters.clone()
+        };
+        let (sql, params) = Self::ready_issues_sql(&filters, sort);
+
+        self.mutate("claim_ready_issues", actor, |tx, ctx| {
+            let candidates: Vec<Issue> = {
+                let mut stmt = tx.prepare_cached(&sql)?;
+                let params_refs: Vec<&dyn rusqlite::ToSql> =
+                    params.iter().map(AsRef::as_ref).collect();
+                stmt.query_map(params_refs.as_slice(), Self::issue_from_row)?
+                    .collect::<std::result::Result<Vec<_>, _>>()?
+            };
+
+            let now = Utc::now();
+            let mut claimed = Vec::new();
+            for mut issue in candidates
+                .into_iter()
+                .filter(|issue| !exclude.contains(&issue.id))
+                .take(count)
+            {
+                let old_status = issue.status.as_str().to_string();
+                issue.status = Status::InProgress;
+                issue.assignee = Some(actor.to_string());
+                issue.updated_at = now;
+                issue.content_hash = Some(issue.compute_content_hash());
+
+                tx.execute(
+                    "UPDATE issues SET status = ?, assignee = ?, updated_at = ?, content_hash = ?
+                     WHERE id = ?",
+                    rusqlite::params![
+                        Status::InProgress.as_str(),
+                        actor,
+                        now.to_rfc3339(),
+                        issue.content_hash,
+                        issue.id
+                    ],
+                )?;
+
+                ctx.record_field_change(
+                    EventType::StatusChanged,
+                    &issue.id,
+                    Some(old_status),
+                    Some(Status::InProgress.as_str().to_string()),
+                    None,
+                );
+                ctx.record_field_change(
+                    EventType::AssigneeChanged,
+                    &issue.id,
+                    None,
+                    Some(actor.to_string()),
+                    None,
+                );
+                ctx.mark_dirty(&issue.id);
+                claimed.push(issue);
+            }
+
+            if !claimed.is_empty() {
+                ctx.invalidate_cache();
+            }
+
+            Ok(claimed)
+        })
+    }
+
+    /// Return the single top ready issue, or `None` if nothing is ready.
+    ///
+    /// Uses the same selection rules as [`Self::get_ready_issues`], but steps
+    /// through the sorted rows and stops at the first one not in `exclude`
+    /// instead of loading every ready issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn next_ready_issue(
+        &self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+        exclude: &HashSet<String>,
+    ) -> Result<Option<Issue>> {
+        let filters = ReadyFilters {
+            limit: None,
+            ..filters.clone()
+        };
+        let (sql, params) = Self::ready_issues_sql(&filters, sort);
+        let mut stmt = self.conn.prepare_cached(&sql)?;
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        let mut rows = stmt.query_map(params_refs.as_slice(), Self::issue_from_row)?;
+
+        rows.find_map(|row| match row {
+            Ok(issue) if exclude.contains(&issue.id) => None,
+            other => Some(other),
+        })
+        .transpose()
+        .map_err(Into::into)
+    }
+
+    /// Build the SQL (and bound parameters) selecting ready issues.
+    #[allow(clippy::too_many_lines)]
+    fn ready_issues_sql(
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+    ) -> (String, Vec<Box<dyn rusqlite::ToSql>>) {
         let mut sql = String::from(
             r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
                      status, priority, issue_type, assignee, owner, estimated_minutes,
@@ -1145,16 +2184,17 @@ impl SqliteStorage {
             sql.push_str(" AND (defer_until IS NULL OR datetime(defer_until) <= datetime('now'))");
         }
 
-        // Ready condition 4: not pinned
-        sql.push_str(" AND (pinned = 0 OR pinned IS NULL)");
-
-        // Ready condition 5: not ephemeral and not wisp
+        // Ready condition 4: not ephemeral and not wisp
         sql.push_str(" AND (ephemeral = 0 OR ephemeral IS NULL)");
         sql.push_str(" AND id NOT LIKE '%-wisp-%'");
 
         // Exclude templates
         sql.push_str(" AND (is_template = 0 OR is_template IS NULL)");
 
+        // Milestones group work rather than being work
+        sql.push_str(" AND issue_type != ?");
+        params.push(Box::new(MILESTONE_TYPE.to_string()));
+
         // Filter by types
         if let Some(ref types) = filters.types {
             if !types.is_empty() {
@@ -1241,17 +2281,35 @@ impl SqliteStorage {
             params.push(Box::new(parent_id.clone()));
         }
 
-        // Sorting
+        push_dependency_filters(
+            &mut sql,
+            &mut params,
+            filters.depends_on.as_deref(),
+            filters.blocks.as_deref(),
+            filters.dep_type.as_deref(),
+        );
+
+        // Filter by estimated effort
+        if let Some(max_minutes) = filters.max_minutes {
+            sql.push_str(" AND estimated_minutes IS NOT NULL AND estimated_minutes <= ?");
+            params.push(Box::new(max_minutes));
+        }
+
+        // Sorting (pinned first)
+        sql.push_str(" ORDER BY COALESCE(pinned, 0) DESC, ");
         match sort {
-            ReadySortPolicy::Hybrid => {
+            ReadySortPolicy::Hybrid | ReadySortPolicy::Fit => {
                 // P0/P1 first by `created_at` ASC, then others by `created_at` ASC
-                sql.push_str(" ORDER BY CASE WHEN priority <= 1 THEN 0 ELSE 1 END, created_at ASC");
+                sql.push_str("CASE WHEN priority <= 1 THEN 0 ELSE 1 END, created_at ASC");
             }
             ReadySortPolicy::Priority => {
-                sql.push_str(" ORDER BY priority ASC, created_at ASC");
+                sql.push_str("priority ASC, created_at ASC");
             }
             ReadySortPolicy::Oldest => {
-                sql.push_str(" ORDER BY created_at ASC");
+                sql.push_str("created_at ASC");
+            }
+            ReadySortPolicy::Aging => {
+                sql.push_str("priority ASC, updated_at ASC");
             }
         }
 
@@ -1264,13 +2322,7 @@ impl SqliteStorage {
             }
         }
 
-        let mut stmt = self.conn.prepare(&sql)?;
-        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
-        let issues: Vec<Issue> = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
-            .collect::<std::result::Result<Vec<_>, _>>()?;
-
-        Ok(issues)
+        (sql, params)
     }
 
     /// Get IDs of blocked issues from cache.
@@ -1382,12 +2434,108 @@ impl SqliteStorage {
         if !force_rebuild {
             return Ok(0);
         }
+        self.ensure_writable("rebuild_blocked_cache")?;
         let tx = self.conn.transaction()?;
         let count = Self::rebuild_blocked_cache_impl(&tx)?;
         tx.commit()?;
         Ok(count)
     }
 
+    /// Rebuild the blocked cache if a `waits-for` wake time recorded by the
+    /// last rebuild has passed. Returns whether it was rebuilt.
+    ///
+    /// Does nothing on a read-only handle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata cannot be read or the rebuild fails.
+    pub fn refresh_blocked_cache_if_due(&mut self) -> Result<bool> {
+        if self.read_only || self.newer_schema.is_some() {
+            return Ok(false);
+        }
+        let due = self
+            .get_metadata(BLOCKED_CACHE_WAKE_KEY)?
+            .and_then(|wake| DateTime::parse_from_rfc3339(&wake).ok())
+            .is_some_and(|wake| wake.with_timezone(&Utc) <= Utc::now());
+        if due {
+            self.rebuild_blocked_cache(true)?;
+        }
+        Ok(due)
+    }
+
+    /// Issues blocked by a direct dependency, each with its
+    /// `blocker:status` references, and the earliest wake time of a
+    /// `waits-for` edge that still blocks.
+    fn direct_blockers(
+        conn: &Connection,
+    ) -> Result<(HashMap<String, Vec<String>>, Option<WaitUntil>)> {
+        let now = Utc::now();
+        let mut next_wake: Option<WaitUntil> = None;
+        let mut blocked_issues_map: HashMap<String, Vec<String>> = HashMap::new();
+        let mut labels_stmt = conn.prepare("SELECT label FROM labels WHERE issue_id = ?")?;
+        let mut stmt = conn.prepare(
+            r"SELECT DISTINCT d.issue_id, d.depends_on_id, COALESCE(i.status, 'unknown'),
+                     d.type, d.metadata, i.issue_type, i.priority
+              FROM dependencies d
+              LEFT JOIN issues i ON d.depends_on_id = i.id
+              WHERE d.type IN ('blocks', 'conditional-blocks', 'waits-for')
+                AND (
+                  -- The blocker is in a blocking state (anything not terminal)
+                  i.status NOT IN ('closed', 'tombstone')
+                  -- Or it's a missing local dependency (orphan)
+                  -- External dependencies are resolved at runtime in the CLI
+                  OR (i.id IS NULL AND d.depends_on_id NOT LIKE 'external:%')
+                )",
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            let issue_id: String = row.get(0)?;
+            let blocker_id: String = row.get(1)?;
+            let status: String = row.get(2)?;
+            let dep_type: String = row.get(3)?;
+            let metadata: Option<String> = row.get(4)?;
+            if dep_type == "waits-for" {
+                if let Some(until) = WaitUntil::from_metadata(metadata.as_deref()) {
+                    if until.has_passed(now) {
+                        return Ok(None);
+                    }
+                    next_wake = Some(next_wake.map_or(until, |wake| wake.min(until)));
+                }
+            }
+            let condition = if dep_type == "conditional-blocks" {
+                DependencyCondition::from_metadata(metadata.as_deref())
+            } else {
+                None
+            };
+            // Orphaned blockers (no issue row) block regardless
+            let blocks = match (condition, row.get::<_, Option<String>>(5)?) {
+                (Some(condition), Some(issue_type)) => {
+                    let labels = if matches!(condition, DependencyCondition::Label(_)) {
+                        labels_stmt
+                            .query_map([&blocker_id], |label| label.get(0))?
+                            .collect::<rusqlite::Result<Vec<String>>>()?
+                    } else {
+                        Vec::new()
+                    };
+                    let priority = row.get::<_, Option<i32>>(6)?.unwrap_or(2);
+                    condition.matches(&issue_type, priority, &labels)
+                }
+                _ => true,
+            };
+            Ok(blocks.then(|| (issue_id, format!("{blocker_id}:{status}"))))
+        })?;
+
+        for row in rows {
+            if let Some((issue_id, blocker_ref)) = row? {
+                blocked_issues_map
+                    .entry(issue_id)
+                    .or_default()
+                    .push(blocker_ref);
+            }
+        }
+        Ok((blocked_issues_map, next_wake))
+    }
+
     fn rebuild_blocked_cache_impl(conn: &Connection) -> Result<usize> {
         const MAX_DEPTH: i32 = 50;
 
@@ -1401,35 +2549,21 @@ impl SqliteStorage {
         // its parent epic is open. However, if the parent is blocked by something else,
         // that blocking propagates to children (handled in the transitive section below).
         //
-        // For conditional-blocks, we also need to check if the blocker closed with failure
-        // but for simplicity in this initial implementation, we treat it like blocks.
-        let mut blocked_issues_map: std::collections::HashMap<String, Vec<String>> =
-            std::collections::HashMap::new();
-        {
-            let mut stmt = conn.prepare(
-                r"SELECT DISTINCT d.issue_id, d.depends_on_id || ':' || COALESCE(i.status, 'unknown')
-                  FROM dependencies d
-                  LEFT JOIN issues i ON d.depends_on_id = i.id
-                  WHERE d.type IN ('blocks', 'conditional-blocks', 'waits-for')
-                    AND (
-                      -- The blocker is in a blocking state (anything not terminal)
-                      i.status NOT IN ('closed', 'tombstone')
-                      -- Or it's a missing local dependency (orphan)
-                      -- External dependencies are resolved at runtime in the CLI
-                      OR (i.id IS NULL AND d.depends_on_id NOT LIKE 'external:%')
-                    )",
-            )?;
-
-            let rows = stmt.query_map([], |row| {
-                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-            })?;
-
-            for row in rows {
-                let (issue_id, blocker_ref) = row?;
-                blocked_issues_map
-                    .entry(issue_id)
-                    .or_default()
-                    .push(blocker_ref);
+        // A conditional-blocks dependency with a condition in its metadata only
+        // blocks while the condition holds on the blocker; without one it is
+        // treated like blocks. A waits-for dependency stops blocking once the
+        // wake time in its metadata has passed; the next wake time is kept so
+        // the cache can be rebuilt when it comes due.
+        let (blocked_issues_map, next_wake) = Self::direct_blockers(conn)?;
+        match next_wake {
+            Some(WaitUntil(wake)) => {
+                Self::set_metadata_in_tx(conn, BLOCKED_CACHE_WAKE_KEY, &wake.to_rfc3339())?;
+            }
+            None => {
+                conn.execute(
+                    "DELETE FROM metadata WHERE key = ?",
+                    [BLOCKED_CACHE_WAKE_KEY],
+                )?;
             }
         }
 
@@ -1515,13 +2649,40 @@ impl SqliteStorage {
         Ok(count)
     }
 
+    /// Wake times of `waits-for` dependencies, keyed by
+    /// `(issue_id, depends_on_id)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_waits_for_wake_times(&self) -> Result<HashMap<(String, String), DateTime<Utc>>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, depends_on_id, metadata FROM dependencies WHERE type = 'waits-for'",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+            ))
+        })?;
+        let mut wake_times = HashMap::new();
+        for row in rows {
+            let (issue_id, depends_on_id, metadata) = row?;
+            if let Some(WaitUntil(until)) = WaitUntil::from_metadata(metadata.as_deref()) {
+                wake_times.insert((issue_id, depends_on_id), until);
+            }
+        }
+        Ok(wake_times)
+    }
+
     /// Get issues that are blocked, along with what's blocking them.
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
     pub fn get_blocked_issues(&self) -> Result<Vec<(Issue, Vec<String>)>> {
-        let mut stmt = self.conn.prepare(
+        let mut stmt = self.conn.prepare_cached(
             r"SELECT i.id, i.content_hash, i.title, i.description, i.design, i.acceptance_criteria, i.notes,
                      i.status, i.priority, i.issue_type, i.assignee, i.owner, i.estimated_minutes,
                      i.created_at, i.created_by, i.updated_at, i.closed_at, i.close_reason, i.closed_by_session,
@@ -1538,7 +2699,7 @@ impl SqliteStorage {
 
         let results = stmt
             .query_map([], |row| {
-                let issue = self.issue_from_row(row)?;
+                let issue = Self::issue_from_row(row)?;
                 let blockers_json: String = row.get(36)?;
                 Ok((issue, blockers_json))
             })?
@@ -1635,78 +2796,38 @@ impl SqliteStorage {
         &self,
         external_statuses: &HashMap<String, bool>,
     ) -> Result<HashMap<String, Vec<String>>> {
-        let mut blockers: HashMap<String, Vec<String>> = HashMap::new();
-
         // Direct external blockers (blocking dependency types only).
         let mut stmt = self.conn.prepare(
-            "SELECT issue_id, depends_on_id
+            "SELECT issue_id, depends_on_id, type, metadata
              FROM dependencies
              WHERE depends_on_id LIKE 'external:%'
                AND type IN ('blocks', 'parent-child', 'conditional-blocks', 'waits-for')",
         )?;
+        let direct = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
 
-        let rows = stmt.query_map([], |row| {
-            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-        })?;
-
-        for row in rows {
-            let (issue_id, depends_on_id) = row?;
-            let satisfied = external_statuses
-                .get(&depends_on_id)
-                .copied()
-                .unwrap_or(false);
-            if !satisfied {
-                blockers
-                    .entry(issue_id)
-                    .or_default()
-                    .push(format!("{depends_on_id}:blocked"));
-            }
-        }
-
-        // Propagate external blocking through parent-child relationships.
         let mut parent_stmt = self.conn.prepare(
             "SELECT issue_id, depends_on_id FROM dependencies WHERE type = 'parent-child'",
         )?;
-        let edges = parent_stmt
+        let parent_edges = parent_stmt
             .query_map([], |row| {
                 Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
-        if !edges.is_empty() && !blockers.is_empty() {
-            let mut children_by_parent: HashMap<String, Vec<String>> = HashMap::new();
-            for (child, parent) in &edges {
-                children_by_parent
-                    .entry(parent.clone())
-                    .or_default()
-                    .push(child.clone());
-            }
-
-            let mut queue: Vec<String> = blockers.keys().cloned().collect();
-            let mut seen: HashSet<String> = HashSet::new();
-
-            while let Some(parent_id) = queue.pop() {
-                if !seen.insert(parent_id.clone()) {
-                    continue;
-                }
-                if let Some(children) = children_by_parent.get(&parent_id) {
-                    for child in children {
-                        let entry = blockers.entry(child.clone()).or_default();
-                        let marker = format!("{parent_id}:parent-blocked");
-                        if entry.contains(&marker) {
-                            continue;
-                        }
-                        entry.push(marker);
-                        queue.push(child.clone());
-                    }
-                }
-            }
-        }
-
-        Ok(blockers
-            .into_iter()
-            .map(|(k, v)| (k, v.into_iter().collect()))
-            .collect())
+        Ok(external_blockers_from(
+            direct,
+            &parent_edges,
+            external_statuses,
+        ))
     }
 
     fn list_external_dependency_ids(&self, blocking_only: bool) -> Result<HashSet<String>> {
@@ -1775,6 +2896,37 @@ impl SqliteStorage {
         Ok(usize::try_from(count).unwrap_or(0))
     }
 
+    /// Count issues per status (tombstones included).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn count_issues_by_status(&self) -> Result<BTreeMap<String, usize>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT status, count(*) FROM issues GROUP BY status")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })?;
+        let mut counts = BTreeMap::new();
+        for row in rows {
+            let (status, count) = row?;
+            counts.insert(status, usize::try_from(count).unwrap_or(0));
+        }
+        Ok(counts)
+    }
+
+    /// Schema version recorded in the database (`PRAGMA user_version`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pragma cannot be read.
+    pub fn schema_version(&self) -> Result<i32> {
+        Ok(self
+            .conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))?)
+    }
+
     /// Get all issue IDs in the database.
     ///
     /// # Errors
@@ -1824,6 +2976,35 @@ impl SqliteStorage {
         Ok(counts)
     }
 
+    /// IDs of the open (non-terminal) descendants of `parent_id` through
+    /// parent-child dependencies, deepest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_open_descendants(&self, parent_id: &str) -> Result<Vec<String>> {
+        // UNION (not UNION ALL) so a parent-child cycle terminates
+        let mut stmt = self.conn.prepare(
+            "WITH RECURSIVE descendants(id, depth) AS (
+                SELECT issue_id, 1 FROM dependencies
+                WHERE depends_on_id = ?1 AND type = 'parent-child'
+                UNION
+                SELECT d.issue_id, desc.depth + 1 FROM dependencies d
+                INNER JOIN descendants desc ON d.depends_on_id = desc.id
+                WHERE d.type = 'parent-child' AND desc.depth < 64
+             )
+             SELECT i.id FROM issues i
+             INNER JOIN descendants desc ON desc.id = i.id
+             WHERE i.id != ?1 AND i.status NOT IN ('closed', 'tombstone')
+             GROUP BY i.id
+             ORDER BY MIN(desc.depth) DESC, i.id",
+        )?;
+        let ids = stmt
+            .query_map([parent_id], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(ids)
+    }
+
     /// Add a dependency between issues.
     ///
     /// # Errors
@@ -1835,6 +3016,23 @@ impl SqliteStorage {
         depends_on_id: &str,
         dep_type: &str,
         actor: &str,
+    ) -> Result<bool> {
+        self.add_dependency_with_metadata(issue_id, depends_on_id, dep_type, None, actor)
+    }
+
+    /// Add a dependency between issues with JSON `metadata` (such as the
+    /// condition of a `conditional-blocks` dependency).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn add_dependency_with_metadata(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &str,
+        metadata: Option<&str>,
+        actor: &str,
     ) -> Result<bool> {
         // Check for cycles if this is a blocking dependency
         if let Ok(dt) = dep_type.parse::<DependencyType>() {
@@ -1859,14 +3057,15 @@ impl SqliteStorage {
             }
 
             tx.execute(
-                "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by)
-                 VALUES (?, ?, ?, ?, ?)",
+                "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by, metadata)
+                 VALUES (?, ?, ?, ?, ?, ?)",
                 rusqlite::params![
                     issue_id,
                     depends_on_id,
                     dep_type,
                     Utc::now().to_rfc3339(),
-                    actor
+                    actor,
+                    metadata.unwrap_or("{}")
                 ],
             )?;
 
@@ -1888,6 +3087,103 @@ impl SqliteStorage {
         })
     }
 
+    /// Add a `duplicates` dependency from `issue_id` to `of_id` and close
+    /// `issue_id` with the reason "duplicate of `of_id`", in one transaction.
+    ///
+    /// Returns whether the dependency was added and whether the issue was
+    /// closed; an issue that is already closed is left as it is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `issue_id` doesn't exist or the database update
+    /// fails.
+    pub fn add_duplicate_and_close(
+        &mut self,
+        issue_id: &str,
+        of_id: &str,
+        actor: &str,
+    ) -> Result<(bool, bool)> {
+        let mut issue = self
+            .get_issue(issue_id)?
+            .ok_or_else(|| BeadsError::IssueNotFound {
+                id: issue_id.to_string(),
+            })?;
+        let close = !issue.status.is_terminal();
+        let old_status = issue.status.as_str().to_string();
+        let close_reason = format!("duplicate of {of_id}");
+        let now = Utc::now();
+        if close {
+            issue.status = Status::Closed;
+            issue.closed_at = Some(now);
+            issue.close_reason = Some(close_reason.clone());
+            issue.closed_by_session = self.session_id.clone();
+            issue.updated_at = now;
+            issue.content_hash = Some(issue.compute_content_hash());
+        }
+        let dep_type = DependencyType::Duplicates.as_str();
+
+        self.mutate("add_duplicate_and_close", actor, |tx, ctx| {
+            let exists: i64 = tx.query_row(
+                "SELECT count(*) FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
+                rusqlite::params![issue_id, of_id],
+                |row| row.get(0),
+            )?;
+            let added = exists == 0;
+            if added {
+                tx.execute(
+                    "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by)
+                     VALUES (?, ?, ?, ?, ?)",
+                    rusqlite::params![issue_id, of_id, dep_type, now.to_rfc3339(), actor],
+                )?;
+                tx.execute(
+                    "UPDATE issues SET updated_at = ? WHERE id = ?",
+                    rusqlite::params![now.to_rfc3339(), issue_id],
+                )?;
+                ctx.record_event(
+                    EventType::DependencyAdded,
+                    issue_id,
+                    Some(format!("Added dependency on {of_id} ({dep_type})")),
+                );
+            }
+
+            if close {
+                tx.execute(
+                    "UPDATE issues SET
+                        status = ?,
+                        closed_at = ?,
+                        close_reason = ?,
+                        closed_by_session = ?,
+                        updated_at = ?,
+                        content_hash = ?
+                     WHERE id = ?",
+                    rusqlite::params![
+                        issue.status.as_str(),
+                        now.to_rfc3339(),
+                        close_reason,
+                        issue.closed_by_session.as_deref().unwrap_or(""),
+                        now.to_rfc3339(),
+                        issue.content_hash,
+                        issue_id
+                    ],
+                )?;
+                ctx.record_field_change(
+                    EventType::StatusChanged,
+                    issue_id,
+                    Some(old_status),
+                    Some(issue.status.as_str().to_string()),
+                    None,
+                );
+                ctx.record_event(EventType::Closed, issue_id, Some(close_reason.clone()));
+            }
+
+            if added || close {
+                ctx.mark_dirty(issue_id);
+                ctx.invalidate_cache();
+            }
+            Ok((added, close))
+        })
+    }
+
     /// Remove a dependency link.
     ///
     /// # Errors
@@ -1925,6 +3221,97 @@ impl SqliteStorage {
         })
     }
 
+    /// Apply a batch of dependency additions and removals in one transaction.
+    ///
+    /// Changes are applied in order, so a blocking addition that closes a
+    /// cycle with an earlier one in the batch is caught. On any error nothing
+    /// is applied. Returns, per change, whether it changed anything (false
+    /// for an addition that already exists or a removal that matched nothing).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an addition would create a blocking cycle or the
+    /// database update fails.
+    pub fn apply_dependency_changes(
+        &mut self,
+        changes: &[DependencyChange],
+        actor: &str,
+    ) -> Result<Vec<bool>> {
+        self.mutate("apply_dependency_changes", actor, |tx, ctx| {
+            let now = Utc::now().to_rfc3339();
+            let mut results = Vec::with_capacity(changes.len());
+            for change in changes {
+                let changed = match change {
+                    DependencyChange::Add {
+                        issue_id,
+                        depends_on_id,
+                        dep_type,
+                    } => {
+                        let exists: i64 = tx.query_row(
+                            "SELECT count(*) FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
+                            rusqlite::params![issue_id, depends_on_id],
+                            |row| row.get(0),
+                        )?;
+                        if exists > 0 {
+                            false
+                        } else {
+                            let blocking = dep_type
+                                .parse::<DependencyType>()
+                                .is_ok_and(|t| t.is_blocking());
+                            if blocking && Self::check_cycle(tx, issue_id, depends_on_id, true)? {
+                                return Err(BeadsError::DependencyCycle {
+                                    path: format!(
+                                        "Adding dependency {issue_id} -> {depends_on_id} would create a cycle"
+                                    ),
+                                });
+                            }
+                            tx.execute(
+                                "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by)
+                                 VALUES (?, ?, ?, ?, ?)",
+                                rusqlite::params![issue_id, depends_on_id, dep_type, now, actor],
+                            )?;
+                            ctx.record_event(
+                                EventType::DependencyAdded,
+                                issue_id,
+                                Some(format!("Added dependency on {depends_on_id} ({dep_type})")),
+                            );
+                            true
+                        }
+                    }
+                    DependencyChange::Remove {
+                        issue_id,
+                        depends_on_id,
+                    } => {
+                        let rows = tx.execute(
+                            "DELETE FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
+                            rusqlite::params![issue_id, depends_on_id],
+                        )?;
+                        if rows > 0 {
+                            ctx.record_event(
+                                EventType::DependencyRemoved,
+                                issue_id,
+                                Some(format!("Removed dependency on {depends_on_id}")),
+                            );
+                        }
+                        rows > 0
+                    }
+                };
+
+                if changed {
+                    let issue_id = change.issue_id();
+                    tx.execute(
+                        "UPDATE issues SET updated_at = ? WHERE id = ?",
+                        rusqlite::params![now, issue_id],
+                    )?;
+                    ctx.mark_dirty(issue_id);
+                    ctx.invalidate_cache();
+                }
+                results.push(changed);
+            }
+            Ok(results)
+        })
+    }
+
     /// Remove all dependencies for an issue.
     ///
     /// # Errors
@@ -2237,161 +3624,789 @@ impl SqliteStorage {
         Ok(map)
     }
 
-    /// Get all labels for all issues as a map of issue_id -> labels.
+    /// Get all labels for all issues as a map of issue_id -> labels.
+    ///
+    /// Used for export and sync operations that need complete label state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_all_labels(&self) -> Result<HashMap<String, Vec<String>>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT issue_id, label FROM labels ORDER BY issue_id, label")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+
+        let mut map: HashMap<String, Vec<String>> = HashMap::new();
+        for row in rows {
+            let (issue_id, label) = row?;
+            map.entry(issue_id).or_default().push(label);
+        }
+        Ok(map)
+    }
+
+    /// Get all unique labels with their issue counts.
+    ///
+    /// Returns a vector of (label, count) pairs sorted alphabetically by label.
+    /// Excludes labels on tombstoned (deleted) issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_unique_labels_with_counts(&self) -> Result<Vec<(String, i64)>> {
+        let mut stmt = self.conn.prepare_cached(
+            r"SELECT l.label, COUNT(*) as count
+              FROM labels l
+              JOIN issues i ON l.issue_id = i.id
+              WHERE i.status != 'tombstone'
+              GROUP BY l.label
+              ORDER BY l.label",
+        )?;
+        let results = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(results)
+    }
+
+    /// Rename a label across all issues.
+    ///
+    /// Returns the number of issues affected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn rename_label(&mut self, old_name: &str, new_name: &str, actor: &str) -> Result<usize> {
+        self.relabel("rename_label", &[old_name], new_name, actor, |label| {
+            format!("Renamed label {label} to {new_name}")
+        })
+    }
+
+    /// Merge several labels into one across all issues.
+    ///
+    /// Every issue carrying any of `sources` ends up with `target` instead.
+    /// Sources equal to `target` are ignored. Returns the number of issues
+    /// affected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn merge_labels(&mut self, sources: &[&str], target: &str, actor: &str) -> Result<usize> {
+        self.relabel("merge_labels", sources, target, actor, |label| {
+            format!("Merged label {label} into {target}")
+        })
+    }
+
+    /// Replace `sources` with `target` on every issue in one transaction,
+    /// recording a `LabelRemoved` event per source and a `LabelAdded` event
+    /// where the issue did not already have `target`.
+    fn relabel(
+        &mut self,
+        op: &str,
+        sources: &[&str],
+        target: &str,
+        actor: &str,
+        describe: impl Fn(&str) -> String,
+    ) -> Result<usize> {
+        let sources: Vec<&str> = sources
+            .iter()
+            .copied()
+            .filter(|source| *source != target)
+            .collect();
+        if sources.is_empty() {
+            return Ok(0);
+        }
+
+        self.mutate(op, actor, |tx, ctx| {
+            // Labels each affected issue currently has, restricted to sources + target
+            let mut affected: BTreeMap<String, Vec<String>> = BTreeMap::new();
+            {
+                let mut stmt = tx.prepare("SELECT issue_id, label FROM labels WHERE label = ?")?;
+                for label in sources.iter().copied().chain(std::iter::once(target)) {
+                    let rows = stmt
+                        .query_map([label], |row| row.get::<_, String>(0))?
+                        .collect::<std::result::Result<Vec<_>, _>>()?;
+                    for issue_id in rows {
+                        affected
+                            .entry(issue_id)
+                            .or_default()
+                            .push(label.to_string());
+                    }
+                }
+            }
+
+            let now = Utc::now().to_rfc3339();
+            let mut count = 0;
+            for (issue_id, labels) in &affected {
+                let removed: Vec<&String> = labels.iter().filter(|l| *l != target).collect();
+                if removed.is_empty() {
+                    continue;
+                }
+                count += 1;
+
+                for label in &removed {
+                    tx.execute(
+                        "DELETE FROM labels WHERE issue_id = ? AND label = ?",
+                        rusqlite::params![issue_id, label],
+                    )?;
+                    ctx.record_event(EventType::LabelRemoved, issue_id, Some(describe(label)));
+                }
+                if !labels.iter().any(|l| l == target) {
+                    tx.execute(
+                        "INSERT INTO labels (issue_id, label) VALUES (?, ?)",
+                        rusqlite::params![issue_id, target],
+                    )?;
+                    ctx.record_event(
+                        EventType::LabelAdded,
+                        issue_id,
+                        Some(format!("Added label {target}")),
+                    );
+                }
+
+                tx.execute(
+                    "UPDATE issues SET updated_at = ? WHERE id = ?",
+                    rusqlite::params![now, issue_id],
+                )?;
+                ctx.mark_dirty(issue_id);
+            }
+
+            Ok(count)
+        })
+    }
+
+    /// Get comments for an issue, including soft-deleted tombstones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_comments(&self, issue_id: &str) -> Result<Vec<Comment>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, issue_id, author, text, created_at, updated_at, deleted
+             FROM comments
+             WHERE issue_id = ?
+             ORDER BY created_at ASC",
+        )?;
+
+        let comments = stmt
+            .query_map([issue_id], comment_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(comments)
+    }
+
+    /// Get the live comments that mention `@name` (case-insensitive), oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_comments_mentioning(&self, name: &str) -> Result<Vec<Comment>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT c.id, c.issue_id, c.author, c.text, c.created_at, c.updated_at, c.deleted
+             FROM comments c
+             JOIN mentions m ON m.comment_id = c.id
+             WHERE m.name = ? AND c.deleted = 0
+             ORDER BY c.created_at ASC, c.id ASC",
+        )?;
+
+        let comments = stmt
+            .query_map([name.to_lowercase()], comment_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(comments)
+    }
+
+    /// Add a comment to an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn add_comment(&mut self, issue_id: &str, author: &str, text: &str) -> Result<Comment> {
+        self.mutate("add_comment", author, |tx, ctx| {
+            let comment_id = insert_comment_row(tx, issue_id, author, text)?;
+
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+
+            ctx.record_event(EventType::Commented, issue_id, Some(text.to_string()));
+            ctx.mark_dirty(issue_id);
+
+            fetch_comment(tx, comment_id)
+        })
+    }
+
+    /// Get a single comment by ID, including soft-deleted ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_comment(&self, comment_id: i64) -> Result<Option<Comment>> {
+        self.conn
+            .query_row(
+                "SELECT id, issue_id, author, text, created_at, updated_at, deleted
+                 FROM comments WHERE id = ?",
+                [comment_id],
+                comment_from_row,
+            )
+            .optional()
+            .map_err(BeadsError::from)
+    }
+
+    /// Replace the text of a comment and stamp its `updated_at`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the comment does not exist, is deleted, or the
+    /// database update fails.
+    pub fn edit_comment(&mut self, comment_id: i64, text: &str, actor: &str) -> Result<Comment> {
+        self.mutate("edit_comment", actor, |tx, ctx| {
+            let comment = fetch_live_comment(tx, comment_id)?;
+            let now = Utc::now().to_rfc3339();
+
+            tx.execute(
+                "UPDATE comments SET text = ?, updated_at = ? WHERE id = ?",
+                rusqlite::params![text, now, comment_id],
+            )?;
+            index_mentions(tx, comment_id, &comment.issue_id, text)?;
+            index_references(tx, &comment.issue_id, comment_id, text)?;
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![now, comment.issue_id],
+            )?;
+
+            ctx.record_event(
+                EventType::Updated,
+                &comment.issue_id,
+                Some(format!("Edited comment {comment_id}")),
+            );
+            ctx.mark_dirty(&comment.issue_id);
+
+            fetch_comment(tx, comment_id)
+        })
+    }
+
+    /// Soft-delete a comment, keeping the row as a tombstone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the comment does not exist, is already deleted,
+    /// or the database update fails.
+    pub fn delete_comment(&mut self, comment_id: i64, actor: &str) -> Result<Comment> {
+        self.mutate("delete_comment", actor, |tx, ctx| {
+            let comment = fetch_live_comment(tx, comment_id)?;
+            let now = Utc::now().to_rfc3339();
+
+            tx.execute(
+                "UPDATE comments SET deleted = 1, updated_at = ? WHERE id = ?",
+                rusqlite::params![now, comment_id],
+            )?;
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![now, comment.issue_id],
+            )?;
+
+            ctx.record_event(
+                EventType::Updated,
+                &comment.issue_id,
+                Some(format!("Deleted comment {comment_id}")),
+            );
+            ctx.mark_dirty(&comment.issue_id);
+
+            fetch_comment(tx, comment_id)
+        })
+    }
+
+    /// Add a reaction to an issue, or to one of its comments.
+    ///
+    /// Returns `false` if the actor already left the same reaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue or comment does not exist or the insert fails.
+    pub fn add_reaction(
+        &mut self,
+        issue_id: &str,
+        comment_id: Option<i64>,
+        reaction: &str,
+        actor: &str,
+    ) -> Result<bool> {
+        self.check_reaction_target(issue_id, comment_id)?;
+        let target = reaction_target(comment_id);
+        self.mutate("add_reaction", actor, |tx, ctx| {
+            let new = Reaction {
+                issue_id: issue_id.to_string(),
+                comment_id,
+                reaction: reaction.to_string(),
+                actor: actor.to_string(),
+                created_at: Utc::now(),
+            };
+            if insert_reaction(tx, issue_id, &new)? == 0 {
+                return Ok(false);
+            }
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+            ctx.record_event(
+                EventType::Updated,
+                issue_id,
+                Some(format!("Reacted {reaction}{target}")),
+            );
+            ctx.mark_dirty(issue_id);
+            Ok(true)
+        })
+    }
+
+    /// Remove the actor's reaction from an issue or comment.
+    ///
+    /// Returns `false` if there was no such reaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails.
+    pub fn remove_reaction(
+        &mut self,
+        issue_id: &str,
+        comment_id: Option<i64>,
+        reaction: &str,
+        actor: &str,
+    ) -> Result<bool> {
+        let target = reaction_target(comment_id);
+        self.mutate("remove_reaction", actor, |tx, ctx| {
+            let removed = tx.execute(
+                "DELETE FROM reactions
+                 WHERE issue_id = ? AND comment_id = ? AND reaction = ? AND actor = ?",
+                rusqlite::params![issue_id, comment_id.unwrap_or(0), reaction, actor],
+            )?;
+            if removed > 0 {
+                tx.execute(
+                    "UPDATE issues SET updated_at = ? WHERE id = ?",
+                    rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+                )?;
+                ctx.record_event(
+                    EventType::Updated,
+                    issue_id,
+                    Some(format!("Removed reaction {reaction}{target}")),
+                );
+                ctx.mark_dirty(issue_id);
+            }
+            Ok(removed > 0)
+        })
+    }
+
+    /// Get all reactions on an issue and its comments, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_reactions(&self, issue_id: &str) -> Result<Vec<Reaction>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, comment_id, reaction, actor, created_at
+             FROM reactions
+             WHERE issue_id = ?
+             ORDER BY created_at ASC, reaction, actor",
+        )?;
+        let reactions = stmt
+            .query_map([issue_id], |row| {
+                Ok(Reaction {
+                    issue_id: row.get(0)?,
+                    comment_id: Some(row.get::<_, i64>(1)?).filter(|id| *id != 0),
+                    reaction: row.get(2)?,
+                    actor: row.get(3)?,
+                    created_at: parse_datetime(&row.get::<_, String>(4)?),
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(reactions)
+    }
+
+    /// Get all reactions for all issues as a map of `issue_id` -> reactions.
+    ///
+    /// Used for export and sync operations that need complete state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_all_reactions(&self) -> Result<HashMap<String, Vec<Reaction>>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT issue_id, comment_id, reaction, actor, created_at
+             FROM reactions
+             ORDER BY issue_id, created_at ASC, reaction, actor",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(Reaction {
+                issue_id: row.get(0)?,
+                comment_id: Some(row.get::<_, i64>(1)?).filter(|id| *id != 0),
+                reaction: row.get(2)?,
+                actor: row.get(3)?,
+                created_at: parse_datetime(&row.get::<_, String>(4)?),
+            })
+        })?;
+
+        let mut map: HashMap<String, Vec<Reaction>> = HashMap::new();
+        for row in rows {
+            let reaction = row?;
+            map.entry(reaction.issue_id.clone())
+                .or_default()
+                .push(reaction);
+        }
+        Ok(map)
+    }
+
+    fn check_reaction_target(&self, issue_id: &str, comment_id: Option<i64>) -> Result<()> {
+        if !self.id_exists(issue_id)? {
+            return Err(BeadsError::IssueNotFound {
+                id: issue_id.to_string(),
+            });
+        }
+        if let Some(comment_id) = comment_id {
+            let on_issue = self
+                .get_comment(comment_id)?
+                .is_some_and(|comment| comment.issue_id == issue_id && !comment.deleted);
+            if !on_issue {
+                return Err(BeadsError::validation(
+                    "comment",
+                    format!("comment {comment_id} not found on {issue_id}"),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Link a source code location to an issue.
+    ///
+    /// Returns `false` if the issue already has this exact reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist or the database update fails.
+    pub fn add_code_ref(
+        &mut self,
+        issue_id: &str,
+        code_ref: &CodeRef,
+        actor: &str,
+    ) -> Result<bool> {
+        if !self.id_exists(issue_id)? {
+            return Err(BeadsError::IssueNotFound {
+                id: issue_id.to_string(),
+            });
+        }
+        self.mutate("add_code_ref", actor, |tx, ctx| {
+            if insert_code_ref(tx, issue_id, code_ref)? == 0 {
+                return Ok(false);
+            }
+
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+            ctx.record_event(
+                EventType::Updated,
+                issue_id,
+                Some(format!("Linked {code_ref}")),
+            );
+            ctx.mark_dirty(issue_id);
+
+            Ok(true)
+        })
+    }
+
+    /// Unlink a source code location from an issue.
+    ///
+    /// Returns `false` if the issue had no such reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn remove_code_ref(
+        &mut self,
+        issue_id: &str,
+        code_ref: &CodeRef,
+        actor: &str,
+    ) -> Result<bool> {
+        self.mutate("remove_code_ref", actor, |tx, ctx| {
+            let rows = tx.execute(
+                "DELETE FROM code_refs
+                 WHERE issue_id = ? AND path = ? AND line = ? AND commit_ref = ?",
+                rusqlite::params![
+                    issue_id,
+                    code_ref.path,
+                    code_ref.line.unwrap_or(0),
+                    code_ref.commit.as_deref().unwrap_or("")
+                ],
+            )?;
+
+            if rows > 0 {
+                tx.execute(
+                    "UPDATE issues SET updated_at = ? WHERE id = ?",
+                    rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+                )?;
+                ctx.record_event(
+                    EventType::Updated,
+                    issue_id,
+                    Some(format!("Unlinked {code_ref}")),
+                );
+                ctx.mark_dirty(issue_id);
+            }
+
+            Ok(rows > 0)
+        })
+    }
+
+    /// Get the source code locations linked to an issue, sorted by path and line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_code_refs(&self, issue_id: &str) -> Result<Vec<CodeRef>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT path, line, commit_ref FROM code_refs
+             WHERE issue_id = ?
+             ORDER BY path, line, commit_ref",
+        )?;
+        let code_refs = stmt
+            .query_map([issue_id], code_ref_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(code_refs)
+    }
+
+    /// Get all code refs for all issues as a map of `issue_id` -> refs.
+    ///
+    /// Used for export and sync operations that need complete state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_all_code_refs(&self) -> Result<HashMap<String, Vec<CodeRef>>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT path, line, commit_ref, issue_id FROM code_refs
+             ORDER BY issue_id, path, line, commit_ref",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(3)?, code_ref_from_row(row)?))
+        })?;
+
+        let mut map: HashMap<String, Vec<CodeRef>> = HashMap::new();
+        for row in rows {
+            let (issue_id, code_ref) = row?;
+            map.entry(issue_id).or_default().push(code_ref);
+        }
+        Ok(map)
+    }
+
+    /// Register an actor as a watcher of an issue.
+    ///
+    /// Returns `false` if the actor already watches it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue does not exist or the database update fails.
+    pub fn add_watcher(&mut self, issue_id: &str, watcher: &str, actor: &str) -> Result<bool> {
+        if !self.id_exists(issue_id)? {
+            return Err(BeadsError::IssueNotFound {
+                id: issue_id.to_string(),
+            });
+        }
+        self.mutate("add_watcher", actor, |tx, ctx| {
+            if insert_watcher(tx, issue_id, watcher)? == 0 {
+                return Ok(false);
+            }
+            // Watching is not a change to the issue, so no event; the bump
+            // lets the new watcher win when clones are merged.
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+            ctx.mark_dirty(issue_id);
+            Ok(true)
+        })
+    }
+
+    /// Stop an actor watching an issue.
+    ///
+    /// Returns `false` if the actor was not watching it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn remove_watcher(&mut self, issue_id: &str, watcher: &str, actor: &str) -> Result<bool> {
+        self.mutate("remove_watcher", actor, |tx, ctx| {
+            let rows = tx.execute(
+                "DELETE FROM watchers WHERE issue_id = ? AND actor = ?",
+                rusqlite::params![issue_id, watcher],
+            )?;
+            if rows > 0 {
+                tx.execute(
+                    "UPDATE issues SET updated_at = ? WHERE id = ?",
+                    rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+                )?;
+                ctx.mark_dirty(issue_id);
+            }
+            Ok(rows > 0)
+        })
+    }
+
+    /// Get the watchers of an issue, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_watchers(&self, issue_id: &str) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT actor FROM watchers WHERE issue_id = ? ORDER BY actor")?;
+        let watchers = stmt
+            .query_map([issue_id], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+        Ok(watchers)
+    }
+
+    /// Get all watchers for all issues as a map of `issue_id` -> actors.
     ///
-    /// Used for export and sync operations that need complete label state.
+    /// Used for export and sync operations that need complete state.
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    pub fn get_all_labels(&self) -> Result<HashMap<String, Vec<String>>> {
+    pub fn get_all_watchers(&self) -> Result<HashMap<String, Vec<String>>> {
         let mut stmt = self
             .conn
-            .prepare_cached("SELECT issue_id, label FROM labels ORDER BY issue_id, label")?;
+            .prepare_cached("SELECT issue_id, actor FROM watchers ORDER BY issue_id, actor")?;
         let rows = stmt.query_map([], |row| {
             Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
         })?;
 
         let mut map: HashMap<String, Vec<String>> = HashMap::new();
         for row in rows {
-            let (issue_id, label) = row?;
-            map.entry(issue_id).or_default().push(label);
+            let (issue_id, watcher) = row?;
+            map.entry(issue_id).or_default().push(watcher);
         }
         Ok(map)
     }
 
-    /// Get all unique labels with their issue counts.
-    ///
-    /// Returns a vector of (label, count) pairs sorted alphabetically by label.
-    /// Excludes labels on tombstoned (deleted) issues.
+    /// Get the issues an actor watches (case-insensitive), sorted by ID, with
+    /// when the actor started watching each.
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    pub fn get_unique_labels_with_counts(&self) -> Result<Vec<(String, i64)>> {
+    pub fn get_watched_issues(&self, watcher: &str) -> Result<Vec<(String, DateTime<Utc>)>> {
         let mut stmt = self.conn.prepare_cached(
-            r"SELECT l.label, COUNT(*) as count
-              FROM labels l
-              JOIN issues i ON l.issue_id = i.id
-              WHERE i.status != 'tombstone'
-              GROUP BY l.label
-              ORDER BY l.label",
+            "SELECT issue_id, created_at FROM watchers WHERE actor = ? ORDER BY issue_id",
         )?;
-        let results = stmt
-            .query_map([], |row| {
-                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        let watched = stmt
+            .query_map([watcher], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    parse_datetime(&row.get::<_, String>(1)?),
+                ))
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
-        Ok(results)
+        Ok(watched)
     }
 
-    /// Rename a label across all issues.
+    /// Set a custom field on an issue, or clear it with `None`.
     ///
-    /// Returns the number of issues affected.
+    /// The value must already be in canonical form for the field's type.
+    /// Returns `false` if the field already had that value.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database update fails.
-    pub fn rename_label(&mut self, old_name: &str, new_name: &str, actor: &str) -> Result<usize> {
-        self.mutate("rename_label", actor, |tx, ctx| {
-            // Get all issue IDs that have the old label
-            let mut stmt = tx.prepare("SELECT issue_id FROM labels WHERE label = ?")?;
-            let issue_ids: Vec<String> = stmt
-                .query_map([old_name], |row| row.get(0))?
-                .collect::<std::result::Result<Vec<_>, _>>()?;
-            drop(stmt);
-
-            // Check if any issues already have the new label (would cause duplicates)
-            let mut check_stmt =
-                tx.prepare("SELECT issue_id FROM labels WHERE label = ? AND issue_id IN (SELECT issue_id FROM labels WHERE label = ?)")?;
-            let conflicts: Vec<String> = check_stmt
-                .query_map(rusqlite::params![new_name, old_name], |row| row.get(0))?
-                .collect::<std::result::Result<Vec<_>, _>>()?;
-            drop(check_stmt);
-
-            // For issues that already have the new label, just remove the old one
-            for conflict_id in &conflicts {
-                tx.execute(
-                    "DELETE FROM labels WHERE issue_id = ? AND label = ?",
-                    rusqlite::params![conflict_id, old_name],
-                )?;
-                ctx.mark_dirty(conflict_id);
+    /// Returns an error if the issue does not exist or the database update fails.
+    pub fn set_field(
+        &mut self,
+        issue_id: &str,
+        name: &str,
+        value: Option<&str>,
+        actor: &str,
+    ) -> Result<bool> {
+        if !self.id_exists(issue_id)? {
+            return Err(BeadsError::IssueNotFound {
+                id: issue_id.to_string(),
+            });
+        }
+        self.mutate("set_field", actor, |tx, ctx| {
+            let old: Option<String> = tx
+                .query_row(
+                    "SELECT value FROM issue_fields WHERE issue_id = ? AND name = ?",
+                    rusqlite::params![issue_id, name],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            if old.as_deref() == value {
+                return Ok(false);
             }
-
-            // Rename the label for issues that don't have conflicts
-            let renamed = tx.execute(
-                "UPDATE labels SET label = ? WHERE label = ?",
-                rusqlite::params![new_name, old_name],
-            )?;
-
-            // Mark all affected issues as dirty and record events
-            let now = Utc::now().to_rfc3339();
-            for issue_id in &issue_ids {
-                ctx.record_event(
-                    EventType::LabelRemoved,
-                    issue_id,
-                    Some(format!("Renamed label {old_name} to {new_name}")),
-                );
-                ctx.mark_dirty(issue_id);
-
-                // Update timestamp
-                tx.execute(
-                    "UPDATE issues SET updated_at = ? WHERE id = ?",
-                    rusqlite::params![now, issue_id],
-                )?;
+            match value {
+                Some(value) => {
+                    insert_field(tx, issue_id, name, value)?;
+                }
+                None => {
+                    tx.execute(
+                        "DELETE FROM issue_fields WHERE issue_id = ? AND name = ?",
+                        rusqlite::params![issue_id, name],
+                    )?;
+                }
             }
-
-            Ok(renamed + conflicts.len())
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+            ctx.record_field_change(
+                EventType::Updated,
+                issue_id,
+                old,
+                value.map(str::to_string),
+                Some(format!("Field {name} changed")),
+            );
+            ctx.mark_dirty(issue_id);
+            Ok(true)
         })
     }
 
-    /// Get comments for an issue.
+    /// Get the custom fields of an issue by name.
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    pub fn get_comments(&self, issue_id: &str) -> Result<Vec<Comment>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, issue_id, author, text, created_at
-             FROM comments
-             WHERE issue_id = ?
-             ORDER BY created_at ASC",
-        )?;
-
-        let comments = stmt
-            .query_map([issue_id], |row| {
-                Ok(Comment {
-                    id: row.get(0)?,
-                    issue_id: row.get(1)?,
-                    author: row.get(2)?,
-                    body: row.get(3)?,
-                    created_at: parse_datetime(&row.get::<_, String>(4)?),
-                })
-            })?
-            .collect::<std::result::Result<Vec<_>, _>>()?;
-
-        Ok(comments)
+    pub fn get_fields(&self, issue_id: &str) -> Result<BTreeMap<String, String>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT name, value FROM issue_fields WHERE issue_id = ?")?;
+        let fields = stmt
+            .query_map([issue_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<std::result::Result<BTreeMap<String, String>, _>>()?;
+        Ok(fields)
     }
 
-    /// Add a comment to an issue.
+    /// Get all custom fields for all issues as a map of `issue_id` -> fields.
+    ///
+    /// Used for export and sync operations that need complete state.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database update fails.
-    pub fn add_comment(&mut self, issue_id: &str, author: &str, text: &str) -> Result<Comment> {
-        self.mutate("add_comment", author, |tx, ctx| {
-            let comment_id = insert_comment_row(tx, issue_id, author, text)?;
-
-            tx.execute(
-                "UPDATE issues SET updated_at = ? WHERE id = ?",
-                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
-            )?;
-
-            ctx.record_event(EventType::Commented, issue_id, Some(text.to_string()));
-            ctx.mark_dirty(issue_id);
+    /// Returns an error if the database query fails.
+    pub fn get_all_fields(&self) -> Result<HashMap<String, BTreeMap<String, String>>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT issue_id, name, value FROM issue_fields")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
 
-            fetch_comment(tx, comment_id)
-        })
+        let mut map: HashMap<String, BTreeMap<String, String>> = HashMap::new();
+        for row in rows {
+            let (issue_id, name, value) = row?;
+            map.entry(issue_id).or_default().insert(name, value);
+        }
+        Ok(map)
     }
 
     /// Get dependencies with metadata.
@@ -2404,7 +4419,7 @@ impl SqliteStorage {
         issue_id: &str,
     ) -> Result<Vec<IssueWithDependencyMetadata>> {
         let mut stmt = self.conn.prepare_cached(
-            "SELECT d.depends_on_id, i.title, i.status, i.priority, d.type
+            "SELECT d.depends_on_id, i.title, i.status, i.priority, d.type, d.metadata
              FROM dependencies d
              LEFT JOIN issues i ON d.depends_on_id = i.id
              WHERE d.issue_id = ?
@@ -2421,6 +4436,10 @@ impl SqliteStorage {
                     dep_type: row
                         .get::<_, Option<String>>(4)?
                         .unwrap_or_else(|| "blocks".to_string()),
+                    condition: DependencyCondition::from_metadata(
+                        row.get::<_, Option<String>>(5)?.as_deref(),
+                    )
+                    .map(|condition| condition.to_string()),
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -2438,7 +4457,7 @@ impl SqliteStorage {
         issue_id: &str,
     ) -> Result<Vec<IssueWithDependencyMetadata>> {
         let mut stmt = self.conn.prepare_cached(
-            "SELECT d.issue_id, i.title, i.status, i.priority, d.type
+            "SELECT d.issue_id, i.title, i.status, i.priority, d.type, d.metadata
              FROM dependencies d
              LEFT JOIN issues i ON d.issue_id = i.id
              WHERE d.depends_on_id = ?
@@ -2455,6 +4474,10 @@ impl SqliteStorage {
                     dep_type: row
                         .get::<_, Option<String>>(4)?
                         .unwrap_or_else(|| "blocks".to_string()),
+                    condition: DependencyCondition::from_metadata(
+                        row.get::<_, Option<String>>(5)?.as_deref(),
+                    )
+                    .map(|condition| condition.to_string()),
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -2462,6 +4485,37 @@ impl SqliteStorage {
         Ok(deps)
     }
 
+    /// Get the issues whose text or live comments mention `issue_id`, or one
+    /// of its old IDs, oldest first. Tombstones are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_referencing_issues(&self, issue_id: &str) -> Result<Vec<IssueBacklink>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT DISTINCT i.id, i.title, i.status, i.created_at
+             FROM issue_references r
+             JOIN issues i ON i.id = r.issue_id
+             LEFT JOIN comments c ON c.id = r.comment_id
+             WHERE (r.target_id = ?1
+                    OR r.target_id IN (SELECT alias FROM id_aliases WHERE issue_id = ?1))
+               AND r.issue_id != ?1
+               AND (r.comment_id = 0 OR c.deleted = 0)
+               AND i.status != 'tombstone'
+             ORDER BY i.created_at, i.id",
+        )?;
+        let backlinks = stmt
+            .query_map([issue_id], |row| {
+                Ok(IssueBacklink {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    status: parse_status(row.get::<_, Option<String>>(2)?.as_deref()),
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(backlinks)
+    }
+
     /// Get parent issue ID.
     ///
     /// # Errors
@@ -2496,6 +4550,56 @@ impl SqliteStorage {
         Ok(ids)
     }
 
+    /// Get the open issues transitively blocked by this one, with the
+    /// shortest number of dependency hops to each, nearest first.
+    ///
+    /// Mirrors the blocked cache: the first hop follows blocking edges other
+    /// than parent-child, later hops also follow parent-child since children
+    /// inherit their parent's blocked state. Traversal stops at closed issues
+    /// and after `max_depth` hops (50 if `None`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_transitive_dependents(
+        &self,
+        issue_id: &str,
+        max_depth: Option<usize>,
+    ) -> Result<Vec<(String, usize)>> {
+        let max_depth = i64::try_from(max_depth.unwrap_or(50)).unwrap_or(i64::MAX);
+        let mut stmt = self.conn.prepare_cached(
+            "WITH RECURSIVE impacted(id, depth) AS (
+                SELECT d.issue_id, 1
+                FROM dependencies d
+                JOIN issues i ON i.id = d.issue_id
+                WHERE d.depends_on_id = ?1
+                  AND d.type IN ('blocks', 'conditional-blocks', 'waits-for')
+                  AND i.status NOT IN ('closed', 'tombstone')
+                UNION
+                SELECT d.issue_id, imp.depth + 1
+                FROM dependencies d
+                JOIN impacted imp ON d.depends_on_id = imp.id
+                JOIN issues i ON i.id = d.issue_id
+                WHERE imp.depth < ?2
+                  AND d.type IN ('blocks', 'parent-child', 'conditional-blocks', 'waits-for')
+                  AND i.status NOT IN ('closed', 'tombstone')
+            )
+            SELECT id, MIN(depth) FROM impacted
+            WHERE id != ?1
+            GROUP BY id
+            ORDER BY MIN(depth), id",
+        )?;
+        let rows = stmt
+            .query_map(rusqlite::params![issue_id, max_depth], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(rows
+            .into_iter()
+            .map(|(id, depth)| (id, usize::try_from(depth).unwrap_or(0)))
+            .collect())
+    }
+
     /// Get IDs of issues that this one depends on.
     ///
     /// # Errors
@@ -2543,6 +4647,32 @@ impl SqliteStorage {
         Ok(count as usize)
     }
 
+    /// Recompute every issue's cached dependency and dependent counts.
+    /// Returns how many issues had drifted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn rebuild_dependency_counts(&mut self) -> Result<usize> {
+        self.ensure_writable("rebuild_dependency_counts")?;
+        Ok(self.conn.execute(REBUILD_DEPENDENCY_COUNTS_SQL, [])?)
+    }
+
+    /// Get all ID aliases as `alias -> issue_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_id_aliases(&self) -> Result<HashMap<String, String>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT alias, issue_id FROM id_aliases")?;
+        let aliases: HashMap<String, String> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<std::result::Result<HashMap<_, _>, _>>()?;
+        Ok(aliases)
+    }
+
     /// Find the next available child number for a parent issue.
     ///
     /// Looks for existing issues with IDs like `{parent_id}.N` and returns the next
@@ -2703,6 +4833,7 @@ impl SqliteStorage {
     ///
     /// Returns an error if the database update fails.
     pub fn set_config(&mut self, key: &str, value: &str) -> Result<()> {
+        self.ensure_writable("set_config")?;
         self.conn.execute(
             "INSERT INTO config (key, value) VALUES (?, ?)
              ON CONFLICT(key) DO UPDATE SET value = excluded.value",
@@ -2719,6 +4850,7 @@ impl SqliteStorage {
     ///
     /// Returns an error if the database delete fails.
     pub fn delete_config(&mut self, key: &str) -> Result<bool> {
+        self.ensure_writable("delete_config")?;
         let deleted = self
             .conn
             .execute("DELETE FROM config WHERE key = ?", rusqlite::params![key])?;
@@ -2752,7 +4884,7 @@ impl SqliteStorage {
 
         let mut stmt = self.conn.prepare_cached(sql)?;
         let issues = stmt
-            .query_map([], |row| self.issue_from_row(row))?
+            .query_map([], Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(issues)
@@ -2813,20 +4945,12 @@ impl SqliteStorage {
     /// Returns an error if the database query fails.
     pub fn get_all_comments(&self) -> Result<HashMap<String, Vec<Comment>>> {
         let mut stmt = self.conn.prepare_cached(
-            "SELECT id, issue_id, author, text, created_at
+            "SELECT id, issue_id, author, text, created_at, updated_at, deleted
              FROM comments
              ORDER BY issue_id, created_at ASC",
         )?;
 
-        let rows = stmt.query_map([], |row| {
-            Ok(Comment {
-                id: row.get(0)?,
-                issue_id: row.get(1)?,
-                author: row.get(2)?,
-                body: row.get(3)?,
-                created_at: parse_datetime(&row.get::<_, String>(4)?),
-            })
-        })?;
+        let rows = stmt.query_map([], comment_from_row)?;
 
         let mut map: HashMap<String, Vec<Comment>> = HashMap::new();
         for row in rows {
@@ -2975,47 +5099,6 @@ impl SqliteStorage {
         Ok(count)
     }
 
-    /// Get issues that need to be exported (dirty issues whose content hash differs from stored export hash).
-    ///
-    /// This enables incremental export by filtering out issues that haven't actually changed
-    /// since the last export, even if they were marked dirty.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the database query fails.
-    pub fn get_issues_needing_export(&self, dirty_ids: &[String]) -> Result<Vec<String>> {
-        if dirty_ids.is_empty() {
-            return Ok(vec![]);
-        }
-
-        // Build a query that finds dirty issues where:
-        // 1. The issue has no export hash (never exported), OR
-        // 2. The issue's current content hash differs from the stored export hash
-        let placeholders: Vec<&str> = dirty_ids.iter().map(|_| "?").collect();
-        let sql = format!(
-            "SELECT i.id FROM issues i
-             WHERE i.id IN ({})
-               AND i.deleted_at IS NULL
-               AND (
-                 NOT EXISTS (SELECT 1 FROM export_hashes e WHERE e.issue_id = i.id)
-                 OR i.content_hash != (SELECT e.content_hash FROM export_hashes e WHERE e.issue_id = i.id)
-               )
-             ORDER BY i.id",
-            placeholders.join(",")
-        );
-
-        let params: Vec<&dyn rusqlite::ToSql> = dirty_ids
-            .iter()
-            .map(|s| s as &dyn rusqlite::ToSql)
-            .collect();
-
-        let mut stmt = self.conn.prepare(&sql)?;
-        let ids = stmt
-            .query_map(params.as_slice(), |row| row.get(0))?
-            .collect::<std::result::Result<Vec<String>, _>>()?;
-        Ok(ids)
-    }
-
     /// Get a metadata value by key.
     ///
     /// # Errors
@@ -3040,6 +5123,7 @@ impl SqliteStorage {
     ///
     /// Returns an error if the database update fails.
     pub fn set_metadata(&mut self, key: &str, value: &str) -> Result<()> {
+        self.ensure_writable("set_metadata")?;
         self.conn.execute(
             "INSERT OR REPLACE INTO metadata (key, value) VALUES (?, ?)",
             [key, value],
@@ -3053,6 +5137,7 @@ impl SqliteStorage {
     ///
     /// Returns an error if the database update fails.
     pub fn delete_metadata(&mut self, key: &str) -> Result<bool> {
+        self.ensure_writable("delete_metadata")?;
         let count = self
             .conn
             .execute("DELETE FROM metadata WHERE key = ?", [key])?;
@@ -3073,6 +5158,9 @@ impl SqliteStorage {
 
     /// Get full issue details.
     ///
+    /// The parent comes from the parent-child edge, falling back to the
+    /// hierarchical ID (`bd-abc.1` -> `bd-abc`) when that issue exists.
+    ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
@@ -3083,15 +5171,22 @@ impl SqliteStorage {
         include_events: bool,
         event_limit: usize,
     ) -> Result<Option<IssueDetails>> {
-        let Some(issue) = self.get_issue(id)? else {
+        let Some(mut issue) = self.get_issue(id)? else {
             return Ok(None);
         };
+        issue.code_refs = self.get_code_refs(id)?;
+        issue.watchers = self.get_watchers(id)?;
+        issue.fields = self.get_fields(id)?;
 
         let labels = self.get_labels(id)?;
         let dependencies = self.get_dependencies_with_metadata(id)?;
         let dependents = self.get_dependents_with_metadata(id)?;
+        let referenced_by = self.get_referencing_issues(id)?;
         let comments = if include_comments {
             self.get_comments(id)?
+                .into_iter()
+                .filter(|comment| !comment.deleted)
+                .collect()
         } else {
             vec![]
         };
@@ -3100,14 +5195,27 @@ impl SqliteStorage {
         } else {
             vec![]
         };
-        let parent = self.get_parent_id(id)?;
+        let mut parent = self.get_parent_id(id)?;
+        if parent.is_none() {
+            if let Some(candidate) = crate::util::id::parse_id(id)
+                .ok()
+                .and_then(|parsed| parsed.parent())
+            {
+                if self.id_exists(&candidate)? {
+                    parent = Some(candidate);
+                }
+            }
+        }
+        let reactions = self.get_reactions(id)?;
 
         Ok(Some(IssueDetails {
             issue,
             labels,
             dependencies,
             dependents,
+            referenced_by,
             comments,
+            reactions,
             events,
             parent,
         }))
@@ -3121,8 +5229,7 @@ impl SqliteStorage {
         s.filter(|v| !v.is_empty())
     }
 
-    #[allow(clippy::unused_self)]
-    fn issue_from_row(&self, row: &rusqlite::Row) -> rusqlite::Result<Issue> {
+    fn issue_from_row(row: &rusqlite::Row) -> rusqlite::Result<Issue> {
         Ok(Issue {
             id: row.get(0)?,
             content_hash: row.get::<_, Option<String>>(1)?,
@@ -3178,6 +5285,10 @@ impl SqliteStorage {
             labels: vec![],       // Loaded separately if needed
             dependencies: vec![], // Loaded separately if needed
             comments: vec![],     // Loaded separately if needed
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         })
     }
 
@@ -3227,10 +5338,113 @@ pub struct ListFilters {
     pub labels: Option<Vec<String>>,
     /// Filter by labels (OR logic)
     pub labels_or: Option<Vec<String>>,
+    /// Filter to issues with a code ref in this file or directory
+    pub touches: Option<String>,
+    /// Filter to issues that depend on this issue ID
+    pub depends_on: Option<String>,
+    /// Filter to issues that block this issue ID
+    pub blocks: Option<String>,
+    /// Filter to issues with a dependency of this type (also restricts
+    /// `depends_on` and `blocks` to it)
+    pub dep_type: Option<String>,
     /// Filter by `updated_at` <= timestamp
     pub updated_before: Option<DateTime<Utc>>,
     /// Filter by `updated_at` >= timestamp
     pub updated_after: Option<DateTime<Utc>>,
+    /// Resume after this cursor (keyset pagination)
+    pub after: Option<ListCursor>,
+    /// Add an `id` tiebreaker so pages are deterministic
+    pub paginate: bool,
+    /// Filter by custom field values (all must match)
+    pub field_filters: Vec<FieldFilter>,
+}
+
+/// Comparison in a custom field filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl FieldOp {
+    /// Operators in the order they are tried when parsing, longest first.
+    const PARSE_ORDER: [Self; 6] = [Self::Ne, Self::Ge, Self::Le, Self::Eq, Self::Gt, Self::Lt];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+
+    /// Whether the operator compares order rather than equality.
+    #[must_use]
+    pub const fn is_ordered(self) -> bool {
+        matches!(self, Self::Lt | Self::Le | Self::Gt | Self::Ge)
+    }
+}
+
+/// A custom field filter such as `sprint>=12`.
+///
+/// An empty value with `=` matches issues where the field is unset, and with
+/// `!=` issues where it is set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldFilter {
+    pub name: String,
+    pub op: FieldOp,
+    pub value: String,
+}
+
+impl FieldFilter {
+    /// Parse `<name><op><value>`, where op is one of `=`, `!=`, `<`, `<=`,
+    /// `>`, `>=`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no operator or no field name.
+    pub fn parse(expr: &str) -> Result<Self> {
+        let (start, op) = expr
+            .find(['=', '!', '<', '>'])
+            .and_then(|start| {
+                FieldOp::PARSE_ORDER
+                    .into_iter()
+                    .find(|op| expr[start..].starts_with(op.as_str()))
+                    .map(|op| (start, op))
+            })
+            .ok_or_else(|| {
+                BeadsError::validation(
+                    "where",
+                    format!("expected FIELD=VALUE (or !=, <, <=, >, >=), got '{expr}'"),
+                )
+            })?;
+        let name = expr[..start].trim();
+        if name.is_empty() {
+            return Err(BeadsError::validation(
+                "where",
+                format!("missing field name in '{expr}'"),
+            ));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            op,
+            value: expr[start + op.as_str().len()..].trim().to_string(),
+        })
+    }
+}
+
+impl std::fmt::Display for FieldFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}", self.name, self.op.as_str(), self.value)
+    }
 }
 
 /// Fields to update on an issue.
@@ -3256,6 +5470,7 @@ pub struct IssueUpdate {
     pub deleted_at: Option<Option<DateTime<Utc>>>,
     pub deleted_by: Option<Option<String>>,
     pub delete_reason: Option<Option<String>>,
+    pub pinned: Option<bool>,
     /// If true, do not rebuild the blocked cache after update.
     /// Caller is responsible for rebuilding cache if needed.
     pub skip_cache_rebuild: bool,
@@ -3291,10 +5506,165 @@ impl IssueUpdate {
             && self.deleted_at.is_none()
             && self.deleted_by.is_none()
             && self.delete_reason.is_none()
+            && self.pinned.is_none()
             && !self.expect_unassigned
     }
 }
 
+/// One change in [`SqliteStorage::apply_dependency_changes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyChange {
+    Add {
+        issue_id: String,
+        depends_on_id: String,
+        dep_type: String,
+    },
+    Remove {
+        issue_id: String,
+        depends_on_id: String,
+    },
+}
+
+impl DependencyChange {
+    /// The issue whose dependency is changed.
+    #[must_use]
+    pub fn issue_id(&self) -> &str {
+        match self {
+            Self::Add { issue_id, .. } | Self::Remove { issue_id, .. } => issue_id,
+        }
+    }
+}
+
+/// A dependency cycle: a set of issues that all (transitively) depend on
+/// each other.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DependencyCycle {
+    /// Issue IDs in the cycle, sorted
+    pub members: Vec<String>,
+    /// One loop through the cycle, starting and ending at the first member
+    pub path: Vec<String>,
+    /// Every dependency between members; removing enough of these breaks
+    /// the cycle
+    pub edges: Vec<CycleEdge>,
+}
+
+/// A dependency edge inside a [`DependencyCycle`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CycleEdge {
+    pub issue_id: String,
+    pub depends_on_id: String,
+    #[serde(rename = "type")]
+    pub dep_type: String,
+}
+
+/// The dependency cycles among `rows` (`issue_id`, `depends_on_id`, type),
+/// as [`SqliteStorage::find_dependency_cycles`] reports them.
+pub(crate) fn dependency_cycles(
+    rows: Vec<(String, String, String)>,
+    blocking_only: bool,
+) -> Vec<DependencyCycle> {
+    let mut graph: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut edge_types: HashMap<(String, String), String> = HashMap::new();
+    for (from, to, dep_type) in rows {
+        if blocking_only
+            && !dep_type
+                .parse::<DependencyType>()
+                .is_ok_and(|t| t.is_blocking())
+        {
+            continue;
+        }
+        graph.entry(from.clone()).or_default().push(to.clone());
+        edge_types.insert((from, to), dep_type);
+    }
+    for targets in graph.values_mut() {
+        targets.sort();
+    }
+
+    crate::util::graph::cyclic_components(&graph)
+        .into_iter()
+        .map(|members| {
+            let path = crate::util::graph::shortest_cycle(&graph, &members, &members[0]);
+            let mut edges = Vec::new();
+            for from in &members {
+                for to in graph.get(from).into_iter().flatten() {
+                    if members.binary_search(to).is_ok() {
+                        edges.push(CycleEdge {
+                            dep_type: edge_types[&(from.clone(), to.clone())].clone(),
+                            issue_id: from.clone(),
+                            depends_on_id: to.clone(),
+                        });
+                    }
+                }
+            }
+            DependencyCycle {
+                members,
+                path,
+                edges,
+            }
+        })
+        .collect()
+}
+
+/// Blockers from unsatisfied external dependencies, as
+/// [`SqliteStorage::external_blockers`] reports them.
+///
+/// `direct` holds the blocking edges (`issue_id`, `depends_on_id`, type,
+/// metadata) onto `external:` IDs; `parent_edges` holds every
+/// (child, parent) pair, through which the blocking propagates.
+pub(crate) fn external_blockers_from(
+    direct: Vec<(String, String, String, Option<String>)>,
+    parent_edges: &[(String, String)],
+    external_statuses: &HashMap<String, bool>,
+) -> HashMap<String, Vec<String>> {
+    let mut blockers: HashMap<String, Vec<String>> = HashMap::new();
+    let now = Utc::now();
+    for (issue_id, depends_on_id, dep_type, metadata) in direct {
+        // A waits-for edge whose wake time has passed no longer blocks
+        let woken = dep_type == "waits-for"
+            && WaitUntil::from_metadata(metadata.as_deref())
+                .is_some_and(|until| until.has_passed(now));
+        let satisfied = woken
+            || external_statuses
+                .get(&depends_on_id)
+                .copied()
+                .unwrap_or(false);
+        if !satisfied {
+            blockers
+                .entry(issue_id)
+                .or_default()
+                .push(format!("{depends_on_id}:blocked"));
+        }
+    }
+
+    if parent_edges.is_empty() || blockers.is_empty() {
+        return blockers;
+    }
+    let mut children_by_parent: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (child, parent) in parent_edges {
+        children_by_parent.entry(parent).or_default().push(child);
+    }
+
+    let mut queue: Vec<String> = blockers.keys().cloned().collect();
+    let mut seen: HashSet<String> = HashSet::new();
+    while let Some(parent_id) = queue.pop() {
+        if !seen.insert(parent_id.clone()) {
+            continue;
+        }
+        if let Some(children) = children_by_parent.get(parent_id.as_str()) {
+            for &child in children {
+                let entry = blockers.entry(child.to_string()).or_default();
+                let marker = format!("{parent_id}:parent-blocked");
+                if entry.contains(&marker) {
+                    continue;
+                }
+                entry.push(marker);
+                queue.push(child.to_string());
+            }
+        }
+    }
+    blockers
+}
+
 /// Filter options for ready issues.
 #[derive(Debug, Clone, Default)]
 pub struct ReadyFilters {
@@ -3310,6 +5680,16 @@ pub struct ReadyFilters {
     pub parent: Option<String>,
     /// Include all descendants (grandchildren, etc.) not just direct children.
     pub recursive: bool,
+    /// Filter to issues that depend on this issue ID
+    pub depends_on: Option<String>,
+    /// Filter to issues that block this issue ID
+    pub blocks: Option<String>,
+    /// Filter to issues with a dependency of this type (also restricts
+    /// `depends_on` and `blocks` to it)
+    pub dep_type: Option<String>,
+    /// Only issues estimated to take at most this many minutes (issues
+    /// without an estimate are excluded)
+    pub max_minutes: Option<i32>,
 }
 
 /// Sort policy for ready issues.
@@ -3322,6 +5702,13 @@ pub enum ReadySortPolicy {
     Priority,
     /// Sort by `created_at` ASC only
     Oldest,
+    /// Fill a time budget: issues are fetched in hybrid order, and callers
+    /// move the ones that fit the remaining budget to the front
+    /// (see `max_minutes`)
+    Fit,
+    /// Sort by priority ASC, then least recently updated first; `br ready`
+    /// refines this with the configured aging boost
+    Aging,
 }
 
 fn parse_status(s: Option<&str>) -> Status {
@@ -3411,6 +5798,111 @@ fn parse_datetime(s: &str) -> DateTime<Utc> {
     Utc::now()
 }
 
+/// Columns other than `issues.id` that hold an issue ID and follow a rename.
+const ISSUE_ID_REFERENCES: &[(&str, &str)] = &[
+    ("dependencies", "issue_id"),
+    ("dependencies", "depends_on_id"),
+    ("labels", "issue_id"),
+    ("comments", "issue_id"),
+    ("mentions", "issue_id"),
+    ("reactions", "issue_id"),
+    ("code_refs", "issue_id"),
+    ("watchers", "issue_id"),
+    ("issue_fields", "issue_id"),
+    ("issue_references", "issue_id"),
+    ("events", "issue_id"),
+    ("child_counters", "parent_id"),
+];
+
+/// Append the dependency filters shared by list, search and ready queries:
+/// issues that depend on `depends_on`, issues that block `blocks` (any
+/// blocking type), and issues with a dependency of `dep_type`. With
+/// `dep_type`, the other two only follow edges of that type.
+fn push_dependency_filters(
+    sql: &mut String,
+    params: &mut Vec<Box<dyn rusqlite::ToSql>>,
+    depends_on: Option<&str>,
+    blocks: Option<&str>,
+    dep_type: Option<&str>,
+) {
+    let type_clause = if dep_type.is_some() {
+        " AND type = ?"
+    } else {
+        ""
+    };
+    if let Some(target) = depends_on {
+        let _ = write!(
+            sql,
+            " AND id IN (SELECT issue_id FROM dependencies WHERE depends_on_id = ?{type_clause})"
+        );
+        params.push(Box::new(target.to_string()));
+        if let Some(dep_type) = dep_type {
+            params.push(Box::new(dep_type.to_string()));
+        }
+    }
+    if let Some(target) = blocks {
+        let type_clause = if dep_type.is_some() {
+            type_clause
+        } else {
+            " AND type IN ('blocks', 'parent-child', 'conditional-blocks', 'waits-for')"
+        };
+        let _ = write!(
+            sql,
+            " AND id IN (SELECT depends_on_id FROM dependencies WHERE issue_id = ?{type_clause})"
+        );
+        params.push(Box::new(target.to_string()));
+        if let Some(dep_type) = dep_type {
+            params.push(Box::new(dep_type.to_string()));
+        }
+    }
+    if let (None, None, Some(dep_type)) = (depends_on, blocks, dep_type) {
+        sql.push_str(" AND id IN (SELECT issue_id FROM dependencies WHERE type = ?)");
+        params.push(Box::new(dep_type.to_string()));
+    }
+}
+
+/// Append custom field filters. Values are stored in canonical form, so
+/// equality is a text match; ordered comparisons are numeric for integer
+/// values and textual otherwise (ISO dates sort as text).
+fn push_field_filters(
+    sql: &mut String,
+    params: &mut Vec<Box<dyn rusqlite::ToSql>>,
+    filters: &[FieldFilter],
+) {
+    for filter in filters {
+        params.push(Box::new(filter.name.clone()));
+        match (filter.op, filter.value.is_empty()) {
+            (FieldOp::Eq, true) => {
+                sql.push_str(" AND id NOT IN (SELECT issue_id FROM issue_fields WHERE name = ?)");
+                continue;
+            }
+            (FieldOp::Ne, true) => {
+                sql.push_str(" AND id IN (SELECT issue_id FROM issue_fields WHERE name = ?)");
+                continue;
+            }
+            (FieldOp::Ne, false) => sql.push_str(
+                " AND id NOT IN (SELECT issue_id FROM issue_fields WHERE name = ? AND value = ?)",
+            ),
+            (op, _) => {
+                let column = if op.is_ordered() && filter.value.parse::<i64>().is_ok() {
+                    "CAST(value AS INTEGER)"
+                } else {
+                    "value"
+                };
+                let _ = write!(
+                    sql,
+                    " AND id IN (SELECT issue_id FROM issue_fields WHERE name = ? AND {column} {} ?)",
+                    op.as_str()
+                );
+            }
+        }
+        match filter.value.parse::<i64>() {
+            Ok(number) if filter.op.is_ordered() => params.push(Box::new(number)),
+            _ => params.push(Box::new(filter.value.clone())),
+        }
+    }
+}
+
 /// Escape special LIKE pattern characters (%, _, \) for literal matching.
 ///
 /// Use with `LIKE ? ESCAPE '\\'` in SQL queries.
@@ -3559,85 +6051,45 @@ impl SqliteStorage {
 
     /// Detect all cycles in the dependency graph.
     ///
-    /// Returns a list of cycles, where each cycle is a vector of issue IDs.
-    /// Uses an iterative DFS to avoid stack overflow on deep graphs.
+    /// Returns one closed path (`a -> ... -> a`) per cycle, following every
+    /// dependency type. See [`Self::find_dependency_cycles`] for the members
+    /// and edge types.
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
     pub fn detect_all_cycles(&self) -> Result<Vec<Vec<String>>> {
-        use std::collections::{HashMap, HashSet};
+        Ok(self
+            .find_dependency_cycles(false)?
+            .into_iter()
+            .map(|cycle| cycle.path)
+            .collect())
+    }
 
-        // Get all dependencies
-        let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+    /// Find every dependency cycle, as the strongly connected components of
+    /// the dependency graph. Each component is reported once, however many
+    /// distinct loops run through it.
+    ///
+    /// With `blocking_only`, only blocking dependency types are followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn find_dependency_cycles(&self, blocking_only: bool) -> Result<Vec<DependencyCycle>> {
         let mut stmt = self
             .conn
-            .prepare_cached("SELECT issue_id, depends_on_id FROM dependencies")?;
-
-        let edges = stmt.query_map([], |row| {
-            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-        })?;
-
-        for edge in edges {
-            let (from, to) = edge?;
-            graph.entry(from).or_default().push(to);
-        }
-
-        let mut cycles = Vec::new();
-        let mut visited = HashSet::new();
-        let mut rec_stack = HashSet::new();
-        let mut path = Vec::new();
-
-        // Stack stores (node_id, neighbor_index)
-        let mut stack: Vec<(String, usize)> = Vec::new();
-
-        // Sort keys for deterministic output
-        let mut keys: Vec<_> = graph.keys().cloned().collect();
-        keys.sort();
-
-        for node in keys {
-            if visited.contains(&node) {
-                continue;
-            }
-
-            stack.push((node.clone(), 0));
-            visited.insert(node.clone());
-            rec_stack.insert(node.clone());
-            path.push(node.clone());
-
-            while let Some((u, idx)) = stack.last_mut() {
-                let neighbors = graph.get(u);
-
-                if let Some(neighbors) = neighbors {
-                    if *idx < neighbors.len() {
-                        let v = &neighbors[*idx];
-                        *idx += 1;
-
-                        if rec_stack.contains(v) {
-                            // Found a cycle: reconstruct it from the current path
-                            if let Some(start_pos) = path.iter().position(|x| x == v) {
-                                let mut cycle = path[start_pos..].to_vec();
-                                cycle.push(v.clone()); // Close the loop
-                                cycles.push(cycle);
-                            }
-                        } else if !visited.contains(v) {
-                            visited.insert(v.clone());
-                            rec_stack.insert(v.clone());
-                            path.push(v.clone());
-                            stack.push((v.clone(), 0));
-                        }
-                        continue;
-                    }
-                }
-
-                // Finished processing all neighbors of u
-                rec_stack.remove(u);
-                path.pop();
-                stack.pop();
-            }
-        }
+            .prepare_cached("SELECT issue_id, depends_on_id, type FROM dependencies")?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
 
-        Ok(cycles)
+        Ok(dependency_cycles(rows, blocking_only))
     }
 
     // ===== Import Helper Methods =====
@@ -3658,7 +6110,7 @@ impl SqliteStorage {
                      pinned, is_template
                FROM issues WHERE external_ref = ?",
             [external_ref],
-            |row| self.issue_from_row(row),
+            Self::issue_from_row,
         );
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -3683,7 +6135,7 @@ impl SqliteStorage {
                      pinned, is_template
                FROM issues WHERE content_hash = ?",
             [content_hash],
-            |row| self.issue_from_row(row),
+            Self::issue_from_row,
         );
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -3710,6 +6162,74 @@ impl SqliteStorage {
         }
     }
 
+    /// Run a bulk import inside one immediate transaction.
+    ///
+    /// Foreign keys are checked at commit instead of per row. When the
+    /// database has no issues yet, secondary indexes on `issues`, `labels` and
+    /// `dependencies` are dropped for the duration and rebuilt once before
+    /// commit, which is much cheaper than updating them row by row. The
+    /// transaction is rolled back if `f` fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `f` fails, an index cannot be rebuilt (e.g. duplicate
+    /// external refs), or the commit fails.
+    pub fn bulk_import<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut Self) -> Result<R>,
+    {
+        self.ensure_writable("bulk_import")?;
+        self.conn
+            .execute_batch("BEGIN IMMEDIATE; PRAGMA defer_foreign_keys = ON;")?;
+        let result = self.deferring_import_indexes(f).and_then(|value| {
+            self.conn.execute_batch("COMMIT")?;
+            Ok(value)
+        });
+        if result.is_err() && !self.conn.is_autocommit() {
+            let _ = self.conn.execute_batch("ROLLBACK");
+        }
+        result
+    }
+
+    fn deferring_import_indexes<F, R>(&mut self, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut Self) -> Result<R>,
+    {
+        let empty: bool =
+            self.conn
+                .query_row("SELECT NOT EXISTS (SELECT 1 FROM issues)", [], |row| {
+                    row.get(0)
+                })?;
+        // Auto-indexes backing primary keys have no SQL and are kept, so the
+        // per-issue relation deletes stay indexed. The `depends_on_id` index is
+        // kept too: `trg_issues_count_insert` counts dependents on every issue
+        // insert, which is a full scan of `dependencies` without it.
+        let deferred: Vec<(String, String)> = if empty {
+            self.conn
+                .prepare(
+                    "SELECT name, sql FROM sqlite_master
+                     WHERE type = 'index' AND sql IS NOT NULL
+                       AND tbl_name IN ('issues', 'labels', 'dependencies')
+                       AND name != 'idx_dependencies_depends_on'",
+                )?
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<std::result::Result<_, _>>()?
+        } else {
+            Vec::new()
+        };
+        for (name, _) in &deferred {
+            self.conn
+                .execute_batch(&format!("DROP INDEX \"{}\"", name.replace('"', "\"\"")))?;
+        }
+
+        let value = f(self)?;
+
+        for (_, sql) in &deferred {
+            self.conn.execute_batch(sql)?;
+        }
+        Ok(value)
+    }
+
     /// Upsert an issue (create or update) for import operations.
     ///
     /// Uses INSERT OR REPLACE to atomically handle both cases.
@@ -3730,7 +6250,7 @@ impl SqliteStorage {
         let deleted_at_str = issue.deleted_at.map(|dt| dt.to_rfc3339());
         let compacted_at_str = issue.compacted_at.map(|dt| dt.to_rfc3339());
 
-        let rows = self.conn.execute(
+        let mut stmt = self.conn.prepare_cached(
             r"INSERT OR REPLACE INTO issues (
                 id, content_hash, title, description, design, acceptance_criteria, notes,
                 status, priority, issue_type, assignee, owner, estimated_minutes,
@@ -3742,45 +6262,46 @@ impl SqliteStorage {
             ) VALUES (
                 ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
             )",
-            rusqlite::params![
-                issue.id,
-                issue.content_hash,
-                issue.title,
-                issue.description,
-                issue.design,
-                issue.acceptance_criteria,
-                issue.notes,
-                status_str,
-                issue.priority.0,
-                issue_type_str,
-                issue.assignee,
-                issue.owner,
-                issue.estimated_minutes,
-                created_at_str,
-                issue.created_by,
-                updated_at_str,
-                closed_at_str,
-                issue.close_reason,
-                issue.closed_by_session,
-                due_at_str,
-                defer_until_str,
-                issue.external_ref,
-                issue.source_system,
-                issue.source_repo.as_deref().unwrap_or("."),
-                deleted_at_str,
-                issue.deleted_by,
-                issue.delete_reason,
-                issue.original_type,
-                issue.compaction_level.unwrap_or(0),
-                compacted_at_str,
-                issue.compacted_at_commit,
-                issue.original_size.unwrap_or(0),
-                issue.sender,
-                issue.ephemeral,
-                issue.pinned,
-                issue.is_template,
-            ],
         )?;
+        let rows = stmt.execute(rusqlite::params![
+            issue.id,
+            issue.content_hash,
+            issue.title,
+            issue.description,
+            issue.design,
+            issue.acceptance_criteria,
+            issue.notes,
+            status_str,
+            issue.priority.0,
+            issue_type_str,
+            issue.assignee,
+            issue.owner,
+            issue.estimated_minutes,
+            created_at_str,
+            issue.created_by,
+            updated_at_str,
+            closed_at_str,
+            issue.close_reason,
+            issue.closed_by_session,
+            due_at_str,
+            defer_until_str,
+            issue.external_ref,
+            issue.source_system,
+            issue.source_repo.as_deref().unwrap_or("."),
+            deleted_at_str,
+            issue.deleted_by,
+            issue.delete_reason,
+            issue.original_type,
+            issue.compaction_level.unwrap_or(0),
+            compacted_at_str,
+            issue.compacted_at_commit,
+            issue.original_size.unwrap_or(0),
+            issue.sender,
+            issue.ephemeral,
+            issue.pinned,
+            issue.is_template,
+        ])?;
+        index_references(&self.conn, &issue.id, 0, &reference_text(issue))?;
 
         Ok(rows > 0)
     }
@@ -3793,16 +6314,89 @@ impl SqliteStorage {
     pub fn sync_labels_for_import(&mut self, issue_id: &str, labels: &[String]) -> Result<()> {
         // Remove existing labels
         self.conn
-            .execute("DELETE FROM labels WHERE issue_id = ?", [issue_id])?;
+            .prepare_cached("DELETE FROM labels WHERE issue_id = ?")?
+            .execute([issue_id])?;
 
         // Add new labels
+        let mut stmt = self
+            .conn
+            .prepare_cached("INSERT INTO labels (issue_id, label) VALUES (?, ?)")?;
         for label in labels {
-            self.conn.execute(
-                "INSERT INTO labels (issue_id, label) VALUES (?, ?)",
-                rusqlite::params![issue_id, label],
-            )?;
+            stmt.execute(rusqlite::params![issue_id, label])?;
+        }
+
+        Ok(())
+    }
+
+    /// Sync code refs for an issue (remove existing, add new).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn sync_code_refs_for_import(
+        &mut self,
+        issue_id: &str,
+        code_refs: &[CodeRef],
+    ) -> Result<()> {
+        self.conn
+            .prepare_cached("DELETE FROM code_refs WHERE issue_id = ?")?
+            .execute([issue_id])?;
+        for code_ref in code_refs {
+            insert_code_ref(&self.conn, issue_id, code_ref)?;
+        }
+        Ok(())
+    }
+
+    /// Sync watchers for an issue (remove existing, add new).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn sync_watchers_for_import(&mut self, issue_id: &str, watchers: &[String]) -> Result<()> {
+        self.conn
+            .prepare_cached("DELETE FROM watchers WHERE issue_id = ?")?
+            .execute([issue_id])?;
+        for watcher in watchers {
+            insert_watcher(&self.conn, issue_id, watcher)?;
+        }
+        Ok(())
+    }
+
+    /// Sync reactions for an issue (remove existing, add new).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn sync_reactions_for_import(
+        &mut self,
+        issue_id: &str,
+        reactions: &[Reaction],
+    ) -> Result<()> {
+        self.conn
+            .prepare_cached("DELETE FROM reactions WHERE issue_id = ?")?
+            .execute([issue_id])?;
+        for reaction in reactions {
+            insert_reaction(&self.conn, issue_id, reaction)?;
         }
+        Ok(())
+    }
 
+    /// Sync custom fields for an issue (remove existing, add new).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn sync_fields_for_import(
+        &mut self,
+        issue_id: &str,
+        fields: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        self.conn
+            .prepare_cached("DELETE FROM issue_fields WHERE issue_id = ?")?
+            .execute([issue_id])?;
+        for (name, value) in fields {
+            insert_field(&self.conn, issue_id, name, value)?;
+        }
         Ok(())
     }
 
@@ -3818,23 +6412,24 @@ impl SqliteStorage {
     ) -> Result<()> {
         // Remove existing dependencies where this issue is the dependent
         self.conn
-            .execute("DELETE FROM dependencies WHERE issue_id = ?", [issue_id])?;
+            .prepare_cached("DELETE FROM dependencies WHERE issue_id = ?")?
+            .execute([issue_id])?;
 
         // Add new dependencies
+        let mut stmt = self.conn.prepare_cached(
+            "INSERT OR IGNORE INTO dependencies (issue_id, depends_on_id, type, created_at, created_by, metadata, thread_id)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )?;
         for dep in dependencies {
-            self.conn.execute(
-                "INSERT OR IGNORE INTO dependencies (issue_id, depends_on_id, type, created_at, created_by, metadata, thread_id)
-                 VALUES (?, ?, ?, ?, ?, ?, ?)",
-                rusqlite::params![
-                    issue_id,
-                    dep.depends_on_id,
-                    dep.dep_type.as_str(),
-                    dep.created_at.to_rfc3339(),
-                    dep.created_by.as_deref().unwrap_or("import"),
-                    dep.metadata.as_deref().unwrap_or("{}"),
-                    dep.thread_id.as_deref().unwrap_or(""),
-                ],
-            )?;
+            stmt.execute(rusqlite::params![
+                issue_id,
+                dep.depends_on_id,
+                dep.dep_type.as_str(),
+                dep.created_at.to_rfc3339(),
+                dep.created_by.as_deref().unwrap_or("import"),
+                dep.metadata.as_deref().unwrap_or("{}"),
+                dep.thread_id.as_deref().unwrap_or(""),
+            ])?;
         }
 
         Ok(())
@@ -3852,20 +6447,33 @@ impl SqliteStorage {
     ) -> Result<()> {
         // Remove existing comments
         self.conn
-            .execute("DELETE FROM comments WHERE issue_id = ?", [issue_id])?;
+            .prepare_cached("DELETE FROM mentions WHERE issue_id = ?")?
+            .execute([issue_id])?;
+        self.conn
+            .prepare_cached("DELETE FROM issue_references WHERE issue_id = ? AND comment_id != 0")?
+            .execute([issue_id])?;
+        self.conn
+            .prepare_cached("DELETE FROM comments WHERE issue_id = ?")?
+            .execute([issue_id])?;
 
         // Add new comments
+        let mut stmt = self.conn.prepare_cached(
+            "INSERT OR REPLACE INTO comments (id, issue_id, author, text, created_at, updated_at, deleted)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )?;
         for comment in comments {
-            self.conn.execute(
-                "INSERT OR REPLACE INTO comments (id, issue_id, author, text, created_at) VALUES (?, ?, ?, ?, ?)",
-                rusqlite::params![
-                    comment.id,
-                    issue_id,
-                    comment.author,
-                    comment.body,
-                    comment.created_at.to_rfc3339()
-                ],
-            )?;
+            stmt.execute(rusqlite::params![
+                comment.id,
+                issue_id,
+                comment.author,
+                comment.body,
+                comment.created_at.to_rfc3339(),
+                comment.updated_at.map(|at| at.to_rfc3339()),
+                comment.deleted
+            ])?;
+            let comment_id = self.conn.last_insert_rowid();
+            index_mentions(&self.conn, comment_id, issue_id, &comment.body)?;
+            index_references(&self.conn, issue_id, comment_id, &comment.body)?;
         }
 
         Ok(())
@@ -3895,37 +6503,147 @@ impl crate::validation::DependencyStore for SqliteStorage {
     }
 }
 
-fn insert_comment_row(
-    tx: &Transaction<'_>,
-    issue_id: &str,
-    author: &str,
-    text: &str,
-) -> Result<i64> {
+fn insert_comment_row(tx: &Connection, issue_id: &str, author: &str, text: &str) -> Result<i64> {
     tx.execute(
         "INSERT INTO comments (issue_id, author, text, created_at)
          VALUES (?, ?, ?, CURRENT_TIMESTAMP)",
         rusqlite::params![issue_id, author, text],
     )?;
-    Ok(tx.last_insert_rowid())
+    let comment_id = tx.last_insert_rowid();
+    index_mentions(tx, comment_id, issue_id, text)?;
+    index_references(tx, issue_id, comment_id, text)?;
+    Ok(comment_id)
+}
+
+/// The text of an issue's own fields that is scanned for issue references.
+fn reference_text(issue: &Issue) -> String {
+    [
+        Some(issue.title.as_str()),
+        issue.description.as_deref(),
+        issue.design.as_deref(),
+        issue.acceptance_criteria.as_deref(),
+        issue.notes.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Insert a code ref, ignoring duplicates. Returns the number of rows inserted.
+fn insert_code_ref(conn: &Connection, issue_id: &str, code_ref: &CodeRef) -> Result<usize> {
+    let mut stmt = conn.prepare_cached(
+        "INSERT OR IGNORE INTO code_refs (issue_id, path, line, commit_ref) VALUES (?, ?, ?, ?)",
+    )?;
+    let inserted = stmt.execute(rusqlite::params![
+        issue_id,
+        code_ref.path,
+        code_ref.line.unwrap_or(0),
+        code_ref.commit.as_deref().unwrap_or("")
+    ])?;
+    Ok(inserted)
+}
+
+/// Insert a watcher, ignoring duplicates. Returns the number of rows inserted.
+fn insert_reaction(conn: &Connection, issue_id: &str, reaction: &Reaction) -> Result<usize> {
+    let inserted = conn
+        .prepare_cached(
+            "INSERT OR IGNORE INTO reactions (issue_id, comment_id, reaction, actor, created_at)
+             VALUES (?, ?, ?, ?, ?)",
+        )?
+        .execute(rusqlite::params![
+            issue_id,
+            reaction.comment_id.unwrap_or(0),
+            reaction.reaction,
+            reaction.actor,
+            reaction.created_at.to_rfc3339()
+        ])?;
+    Ok(inserted)
 }
 
-fn fetch_comment(tx: &Transaction<'_>, comment_id: i64) -> Result<Comment> {
+/// Event suffix naming the comment a reaction is on, if any.
+fn reaction_target(comment_id: Option<i64>) -> String {
+    comment_id.map_or_else(String::new, |id| format!(" on comment {id}"))
+}
+
+fn insert_watcher(conn: &Connection, issue_id: &str, watcher: &str) -> Result<usize> {
+    let inserted = conn
+        .prepare_cached(
+            "INSERT OR IGNORE INTO watchers (issue_id, actor, created_at) VALUES (?, ?, ?)",
+        )?
+        .execute(rusqlite::params![
+            issue_id,
+            watcher,
+            Utc::now().to_rfc3339()
+        ])?;
+    Ok(inserted)
+}
+
+/// Insert or replace a custom field value.
+fn insert_field(conn: &Connection, issue_id: &str, name: &str, value: &str) -> Result<()> {
+    conn.prepare_cached(
+        "INSERT OR REPLACE INTO issue_fields (issue_id, name, value) VALUES (?, ?, ?)",
+    )?
+    .execute(rusqlite::params![issue_id, name, value])?;
+    Ok(())
+}
+
+/// Map a `path, line, commit_ref` row, where 0 and '' mean unset.
+fn code_ref_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<CodeRef> {
+    Ok(CodeRef {
+        path: row.get(0)?,
+        line: Some(row.get::<_, u32>(1)?).filter(|line| *line != 0),
+        commit: Some(row.get::<_, String>(2)?).filter(|commit| !commit.is_empty()),
+    })
+}
+
+fn comment_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Comment> {
+    Ok(Comment {
+        id: row.get(0)?,
+        issue_id: row.get(1)?,
+        author: row.get(2)?,
+        body: row.get(3)?,
+        created_at: parse_datetime(&row.get::<_, String>(4)?),
+        updated_at: row
+            .get::<_, Option<String>>(5)?
+            .as_deref()
+            .map(parse_datetime),
+        deleted: row.get(6)?,
+    })
+}
+
+fn fetch_comment(tx: &Connection, comment_id: i64) -> Result<Comment> {
     tx.query_row(
-        "SELECT id, issue_id, author, text, created_at FROM comments WHERE id = ?",
+        "SELECT id, issue_id, author, text, created_at, updated_at, deleted
+         FROM comments WHERE id = ?",
         rusqlite::params![comment_id],
-        |row| {
-            Ok(Comment {
-                id: row.get(0)?,
-                issue_id: row.get(1)?,
-                author: row.get(2)?,
-                body: row.get(3)?,
-                created_at: parse_datetime(&row.get::<_, String>(4)?),
-            })
-        },
+        comment_from_row,
     )
     .map_err(BeadsError::from)
 }
 
+/// Fetch a comment for modification, rejecting missing and deleted comments.
+fn fetch_live_comment(tx: &Connection, comment_id: i64) -> Result<Comment> {
+    let comment = tx
+        .query_row(
+            "SELECT id, issue_id, author, text, created_at, updated_at, deleted
+             FROM comments WHERE id = ?",
+            rusqlite::params![comment_id],
+            comment_from_row,
+        )
+        .optional()?
+        .ok_or_else(|| {
+            BeadsError::validation("comment", format!("comment {comment_id} not found"))
+        })?;
+    if comment.deleted {
+        return Err(BeadsError::validation(
+            "comment",
+            format!("comment {comment_id} is deleted"),
+        ));
+    }
+    Ok(comment)
+}
+
 #[cfg(test)]
 impl SqliteStorage {
     /// Execute raw SQL for tests.
@@ -3997,6 +6715,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -4049,6 +6771,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         };
 
         storage.create_issue(&issue, "tester").unwrap();
@@ -4204,6 +6930,151 @@ mod tests {
         assert!(is_tombstone);
     }
 
+    #[test]
+    fn test_purge_issue_removes_row_and_edges() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        for id in ["bd-p1", "bd-p2"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-p2", "bd-p1", "blocks", "tester")
+            .unwrap();
+        storage.add_label("bd-p1", "ops", "tester").unwrap();
+        storage.clear_all_dirty_flags().unwrap();
+
+        storage.purge_issue("bd-p1", "tester").unwrap();
+        assert!(storage.get_issue("bd-p1").unwrap().is_none());
+        assert!(storage.get_dependencies("bd-p2").unwrap().is_empty());
+        assert!(!storage.is_blocked("bd-p2").unwrap());
+        assert_eq!(storage.get_dirty_issue_ids().unwrap(), vec!["bd-p2"]);
+        assert!(storage.get_unique_labels_with_counts().unwrap().is_empty());
+        assert!(matches!(
+            storage.purge_issue("bd-p1", "tester"),
+            Err(BeadsError::IssueNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_delete_issues_is_all_or_nothing() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        for id in ["bd-d1", "bd-d2", "bd-d3"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-d2", "bd-d1", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-d3", "bd-d2", "blocks", "tester")
+            .unwrap();
+
+        let ids = vec!["bd-d1".to_string(), "bd-nope".to_string()];
+        assert!(
+            storage
+                .delete_issues(&ids, "tester", "gone", false)
+                .is_err()
+        );
+        assert_eq!(storage.get_dependents("bd-d1").unwrap(), vec!["bd-d2"]);
+
+        storage.clear_all_dirty_flags().unwrap();
+        let ids = vec!["bd-d1".to_string(), "bd-d2".to_string()];
+        let removed = storage
+            .delete_issues(&ids, "tester", "gone", false)
+            .unwrap();
+        assert_eq!(removed, 2);
+        for id in &ids {
+            let issue = storage.get_issue(id).unwrap().unwrap();
+            assert_eq!(issue.status, Status::Tombstone);
+            assert_eq!(issue.original_type.as_deref(), Some("task"));
+            assert_eq!(issue.delete_reason.as_deref(), Some("gone"));
+        }
+        assert!(!storage.is_blocked("bd-d3").unwrap());
+        let mut dirty = storage.get_dirty_issue_ids().unwrap();
+        dirty.sort();
+        assert_eq!(dirty, vec!["bd-d1", "bd-d2", "bd-d3"]);
+    }
+
+    #[test]
+    fn test_restore_issue_clears_tombstone() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+
+        let issue = make_issue("bd-r1", "Restore me", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        assert!(storage.restore_issue("bd-r1", "tester", None).is_err());
+
+        storage
+            .delete_issue("bd-r1", "tester", "oops", None)
+            .unwrap();
+        let restored = storage
+            .restore_issue("bd-r1", "tester", Some("mistake"))
+            .unwrap();
+        assert_eq!(restored.status, Status::Open);
+        assert!(restored.deleted_at.is_none());
+        assert!(restored.deleted_by.is_none());
+        assert!(restored.delete_reason.is_none());
+        assert!(restored.original_type.is_none());
+        assert!(!storage.is_tombstone("bd-r1").unwrap());
+
+        let events = storage.get_events("bd-r1", 10).unwrap();
+        assert_eq!(events[0].event_type, EventType::Restored);
+    }
+
+    #[test]
+    fn test_rename_issue_ids_rewrites_references_and_records_alias() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+
+        for (id, title) in [("bd-p", "Parent"), ("bd-p.1", "Child"), ("bd-x", "Other")] {
+            let issue = make_issue(id, title, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-p.1", "bd-p", "parent-child", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-x", "bd-p.1", "blocks", "tester")
+            .unwrap();
+        storage.add_label("bd-p.1", "ui", "tester").unwrap();
+        storage.clear_all_dirty_issues().unwrap();
+
+        let renames = vec![("bd-p.1".to_string(), "bd-q".to_string())];
+        storage
+            .rename_issue_ids(&renames, "tester", "Promoted bd-p.1 to top level")
+            .unwrap();
+
+        assert!(!storage.id_exists("bd-p.1").unwrap());
+        assert_eq!(storage.get_labels("bd-q").unwrap(), vec!["ui".to_string()]);
+        assert_eq!(
+            storage.get_parent_id("bd-q").unwrap().as_deref(),
+            Some("bd-p")
+        );
+        assert_eq!(
+            storage.get_dependencies("bd-x").unwrap(),
+            vec!["bd-q".to_string()]
+        );
+        assert_eq!(
+            storage
+                .get_id_aliases()
+                .unwrap()
+                .get("bd-p.1")
+                .map(String::as_str),
+            Some("bd-q")
+        );
+
+        let mut dirty = storage.get_dirty_issue_ids().unwrap();
+        dirty.sort();
+        assert_eq!(dirty, vec!["bd-q".to_string(), "bd-x".to_string()]);
+        assert!(
+            storage
+                .rename_issue_ids(&renames, "tester", "again")
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_get_blocked_issues_lists_blockers() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4246,6 +7117,58 @@ mod tests {
         assert_eq!(labels, vec!["backend".to_string()]);
     }
 
+    #[test]
+    fn test_merge_labels_records_events_and_dedupes() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 1, 0, 0, 0).unwrap();
+
+        for id in ["bd-m1", "bd-m2", "bd-m3"] {
+            let issue = make_issue(id, "Merge me", Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage.add_label("bd-m1", "bug", "tester").unwrap();
+        storage.add_label("bd-m1", "defect", "tester").unwrap();
+        storage.add_label("bd-m2", "defect", "tester").unwrap();
+        storage.add_label("bd-m2", "kind-bug", "tester").unwrap();
+        storage.add_label("bd-m3", "kind-bug", "tester").unwrap();
+        storage
+            .clear_dirty_issues(&[
+                "bd-m1".to_string(),
+                "bd-m2".to_string(),
+                "bd-m3".to_string(),
+            ])
+            .unwrap();
+
+        let affected = storage
+            .merge_labels(&["bug", "defect"], "kind-bug", "tester")
+            .unwrap();
+        assert_eq!(affected, 2);
+        assert_eq!(storage.get_labels("bd-m1").unwrap(), vec!["kind-bug"]);
+        assert_eq!(storage.get_labels("bd-m2").unwrap(), vec!["kind-bug"]);
+
+        let dirty = storage.get_dirty_issue_ids().unwrap();
+        assert!(dirty.contains(&"bd-m1".to_string()));
+        assert!(!dirty.contains(&"bd-m3".to_string()));
+
+        let events = storage.get_events("bd-m1", 10).unwrap();
+        let removed = events
+            .iter()
+            .filter(|e| e.event_type == EventType::LabelRemoved)
+            .count();
+        assert_eq!(removed, 2);
+        // bd-m2 already had the target label, so only bd-m1 gains it
+        assert_eq!(
+            storage.get_events("bd-m2", 10).unwrap()[0].event_type,
+            EventType::LabelRemoved
+        );
+
+        assert_eq!(
+            storage.rename_label("kind-bug", "bug", "tester").unwrap(),
+            3
+        );
+        assert_eq!(storage.get_labels("bd-m3").unwrap(), vec!["bug"]);
+    }
+
     #[test]
     fn test_add_dependency_and_remove() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4302,6 +7225,84 @@ mod tests {
         assert!(creates_cycle);
     }
 
+    #[test]
+    fn test_get_transitive_dependents_follows_blocking_chain() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 3, 0, 0, 0).unwrap();
+        for (id, status) in [
+            ("bd-im1", Status::Open),
+            ("bd-im2", Status::Open),
+            ("bd-im3", Status::Open),
+            ("bd-im4", Status::Closed),
+            ("bd-im5", Status::Open),
+        ] {
+            let issue = make_issue(id, id, status, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        // im2 and im4 wait on im1 and im3 waits on im2. The related edge is
+        // not followed, and im5 hangs off closed im4.
+        for (from, to, dep_type) in [
+            ("bd-im2", "bd-im1", "blocks"),
+            ("bd-im4", "bd-im1", "blocks"),
+            ("bd-im3", "bd-im2", "blocks"),
+            ("bd-im1", "bd-im3", "related"),
+            ("bd-im5", "bd-im4", "parent-child"),
+        ] {
+            storage
+                .add_dependency(from, to, dep_type, "tester")
+                .unwrap();
+        }
+
+        let impacted = storage.get_transitive_dependents("bd-im1", None).unwrap();
+        assert_eq!(
+            impacted,
+            vec![("bd-im2".to_string(), 1), ("bd-im3".to_string(), 2)]
+        );
+        let direct = storage
+            .get_transitive_dependents("bd-im1", Some(1))
+            .unwrap();
+        assert_eq!(direct, vec![("bd-im2".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_apply_dependency_changes_is_all_or_nothing() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 3, 0, 0, 0).unwrap();
+        for id in ["bd-ap1", "bd-ap2", "bd-ap3"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        let add = |from: &str, to: &str| DependencyChange::Add {
+            issue_id: from.to_string(),
+            depends_on_id: to.to_string(),
+            dep_type: "blocks".to_string(),
+        };
+
+        let applied = storage
+            .apply_dependency_changes(
+                &[add("bd-ap1", "bd-ap2"), add("bd-ap2", "bd-ap3")],
+                "tester",
+            )
+            .unwrap();
+        assert_eq!(applied, vec![true, true]);
+
+        // The removal succeeds but the cycle fails, so neither is kept
+        let err = storage.apply_dependency_changes(
+            &[
+                DependencyChange::Remove {
+                    issue_id: "bd-ap1".to_string(),
+                    depends_on_id: "bd-ap2".to_string(),
+                },
+                add("bd-ap1", "bd-ap3"),
+                add("bd-ap3", "bd-ap1"),
+            ],
+            "tester",
+        );
+        assert!(matches!(err, Err(BeadsError::DependencyCycle { .. })));
+        assert_eq!(storage.get_dependencies("bd-ap1").unwrap(), vec!["bd-ap2"]);
+        assert!(storage.get_dependencies("bd-ap3").unwrap().is_empty());
+    }
+
     #[test]
     fn test_get_comments_orders_by_created_at() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4347,6 +7348,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         };
         storage.create_issue(&issue, "tester").unwrap();
 
@@ -4416,83 +7421,652 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
+        };
+        storage.create_issue(&issue, "tester").unwrap();
+
+        let comment = storage
+            .add_comment("bd-c2", "alice", "Hello there")
+            .unwrap();
+        assert_eq!(comment.issue_id, "bd-c2");
+        assert_eq!(comment.author, "alice");
+        assert_eq!(comment.body, "Hello there");
+        assert!(comment.id > 0);
+
+        let comments = storage.get_comments("bd-c2").unwrap();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0], comment);
+    }
+
+    #[test]
+    fn test_add_comment_marks_dirty() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+
+        let issue = Issue {
+            id: "bd-c3".to_string(),
+            content_hash: None,
+            title: "Comment issue".to_string(),
+            description: None,
+            design: None,
+            acceptance_criteria: None,
+            notes: None,
+            status: Status::Open,
+            priority: Priority::MEDIUM,
+            issue_type: IssueType::Task,
+            assignee: None,
+            owner: None,
+            estimated_minutes: None,
+            created_at: t1,
+            created_by: None,
+            updated_at: t1,
+            closed_at: None,
+            close_reason: None,
+            closed_by_session: None,
+            defer_until: None,
+            due_at: None,
+            external_ref: None,
+            source_system: None,
+            source_repo: None,
+            deleted_at: None,
+            deleted_by: None,
+            delete_reason: None,
+            original_type: None,
+            compaction_level: None,
+            compacted_at: None,
+            compacted_at_commit: None,
+            original_size: None,
+            sender: None,
+            ephemeral: false,
+            pinned: false,
+            is_template: false,
+            labels: vec![],
+            dependencies: vec![],
+            comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
+        };
+        storage.create_issue(&issue, "tester").unwrap();
+
+        storage
+            .add_comment("bd-c3", "alice", "Dirty comment")
+            .unwrap();
+
+        let dirty_count: i64 = storage
+            .conn
+            .query_row(
+                "SELECT count(*) FROM dirty_issues WHERE issue_id = ?",
+                ["bd-c3"],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(dirty_count, 1);
+    }
+
+    #[test]
+    fn test_edit_and_soft_delete_comment() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 5, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-c4", "Comment issue", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+
+        let comment = storage.add_comment("bd-c4", "alice", "Typo").unwrap();
+        assert!(comment.updated_at.is_none());
+
+        let edited = storage.edit_comment(comment.id, "Fixed", "alice").unwrap();
+        assert_eq!(edited.body, "Fixed");
+        assert!(edited.updated_at.is_some());
+
+        let deleted = storage.delete_comment(comment.id, "alice").unwrap();
+        assert!(deleted.deleted);
+        assert!(storage.edit_comment(comment.id, "Again", "alice").is_err());
+        assert!(storage.delete_comment(comment.id, "alice").is_err());
+
+        // Tombstone stays in storage (for export) but not in issue details
+        assert_eq!(storage.get_comments("bd-c4").unwrap().len(), 1);
+        let details = storage
+            .get_issue_details("bd-c4", true, false, 0)
+            .unwrap()
+            .unwrap();
+        assert!(details.comments.is_empty());
+    }
+
+    #[test]
+    fn test_comment_mentions_follow_edits_and_deletes() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 5, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-c5", "Mention issue", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+
+        let comment = storage
+            .add_comment("bd-c5", "alice", "@Bob can you review?")
+            .unwrap();
+        let mentioned = storage.get_comments_mentioning("bob").unwrap();
+        assert_eq!(mentioned.len(), 1);
+        assert_eq!(mentioned[0].id, comment.id);
+
+        storage
+            .edit_comment(comment.id, "@carol can you review?", "alice")
+            .unwrap();
+        assert!(storage.get_comments_mentioning("BOB").unwrap().is_empty());
+        assert_eq!(storage.get_comments_mentioning("carol").unwrap().len(), 1);
+
+        storage.delete_comment(comment.id, "alice").unwrap();
+        assert!(storage.get_comments_mentioning("carol").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_comment_mentions_follow_renames() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 5, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-m1", "Mention issue", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        storage
+            .add_comment("bd-m1", "alice", "@bob can you review?")
+            .unwrap();
+
+        storage
+            .rename_issue_ids(
+                &[("bd-m1".to_string(), "bd-m9".to_string())],
+                "tester",
+                "rename",
+            )
+            .unwrap();
+        let indexed: Vec<String> = storage
+            .conn
+            .prepare("SELECT issue_id FROM mentions WHERE name = 'bob'")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<std::result::Result<_, _>>()
+            .unwrap();
+        assert_eq!(indexed, vec!["bd-m9".to_string()]);
+        let mentioned = storage.get_comments_mentioning("bob").unwrap();
+        assert_eq!(mentioned.len(), 1);
+        assert_eq!(mentioned[0].issue_id, "bd-m9");
+    }
+
+    #[test]
+    fn test_issue_references_index_text_and_comments() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 5, 0, 0, 0).unwrap();
+        let target = make_issue("bd-r1", "Target", Status::Open, 2, None, t1, None);
+        storage.create_issue(&target, "tester").unwrap();
+        let mut source = make_issue("bd-r2", "Source", Status::Open, 2, None, t1, None);
+        source.description = Some("Same cause as bd-r1 and bd-r2 itself".to_string());
+        storage.create_issue(&source, "tester").unwrap();
+        let commenter = make_issue("bd-r3", "Commenter", Status::Open, 2, None, t1, None);
+        storage.create_issue(&commenter, "tester").unwrap();
+
+        let comment = storage
+            .add_comment("bd-r3", "alice", "See also bd-r1")
+            .unwrap();
+        let backlinks = |storage: &SqliteStorage, id: &str| -> Vec<String> {
+            storage
+                .get_referencing_issues(id)
+                .unwrap()
+                .into_iter()
+                .map(|backlink| backlink.id)
+                .collect()
+        };
+        assert_eq!(backlinks(&storage, "bd-r1"), vec!["bd-r2", "bd-r3"]);
+        assert!(backlinks(&storage, "bd-r2").is_empty(), "self reference");
+
+        let updates = IssueUpdate {
+            description: Some(Some("Unrelated after all".to_string())),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue("bd-r2", &updates, "tester").unwrap();
+        storage.delete_comment(comment.id, "alice").unwrap();
+        assert!(backlinks(&storage, "bd-r1").is_empty());
+
+        // References to an old ID follow the issue to its new one
+        storage
+            .add_comment("bd-r3", "alice", "Still about bd-r1")
+            .unwrap();
+        storage
+            .rename_issue_ids(
+                &[("bd-r1".to_string(), "bd-r9".to_string())],
+                "tester",
+                "rename",
+            )
+            .unwrap();
+        assert_eq!(backlinks(&storage, "bd-r9"), vec!["bd-r3"]);
+    }
+
+    #[test]
+    fn test_reopen_issue_reblocks_dependents() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 5, 0, 0, 0).unwrap();
+        let blocker = make_issue("bd-o1", "Blocker", Status::Open, 2, None, t1, None);
+        storage.create_issue(&blocker, "tester").unwrap();
+        let dependent = make_issue("bd-o2", "Dependent", Status::Open, 2, None, t1, None);
+        storage.create_issue(&dependent, "tester").unwrap();
+        storage
+            .add_dependency("bd-o2", "bd-o1", "blocks", "tester")
+            .unwrap();
+
+        let close = IssueUpdate {
+            status: Some(Status::Closed),
+            closed_at: Some(Some(t1)),
+            close_reason: Some(Some("done".to_string())),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue("bd-o1", &close, "tester").unwrap();
+        assert!(!storage.get_blocked_ids().unwrap().contains("bd-o2"));
+
+        let reopened = storage
+            .reopen_issue("bd-o1", Some("regressed"), "tester")
+            .unwrap();
+        assert_eq!(reopened.status, Status::Open);
+        assert!(reopened.closed_at.is_none());
+        assert!(reopened.close_reason.is_none());
+        assert!(storage.get_blocked_ids().unwrap().contains("bd-o2"));
+        let events = storage.get_events("bd-o1", 10).unwrap();
+        assert!(events.iter().any(|event| {
+            event.event_type == EventType::Reopened && event.comment.as_deref() == Some("regressed")
+        }));
+
+        assert!(storage.reopen_issue("bd-o1", None, "tester").is_err());
+    }
+
+    #[test]
+    fn test_add_duplicate_and_close() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 5, 0, 0, 0).unwrap();
+        for id in ["bd-d1", "bd-d2", "bd-d3"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+
+        assert_eq!(
+            storage
+                .add_duplicate_and_close("bd-d1", "bd-d2", "tester")
+                .unwrap(),
+            (true, true)
+        );
+        let closed = storage.get_issue("bd-d1").unwrap().unwrap();
+        assert_eq!(closed.status, Status::Closed);
+        assert!(closed.closed_at.is_some());
+        assert_eq!(closed.close_reason.as_deref(), Some("duplicate of bd-d2"));
+        assert_eq!(closed.content_hash, Some(closed.compute_content_hash()));
+        assert_eq!(storage.get_dependencies("bd-d1").unwrap(), vec!["bd-d2"]);
+        let events = storage.get_events("bd-d1", 10).unwrap();
+        assert!(
+            events
+                .iter()
+                .any(|event| event.event_type == EventType::Closed)
+        );
+
+        // Already closed: linked, not closed again
+        assert_eq!(
+            storage
+                .add_duplicate_and_close("bd-d1", "bd-d3", "tester")
+                .unwrap(),
+            (true, false)
+        );
+        let closed = storage.get_issue("bd-d1").unwrap().unwrap();
+        assert_eq!(closed.close_reason.as_deref(), Some("duplicate of bd-d2"));
+    }
+
+    #[test]
+    fn test_conditional_blocks_follows_condition() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 5, 0, 0, 0).unwrap();
+        for id in ["bd-c1", "bd-c2", "bd-c3"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        let condition = DependencyCondition::Label("release-blocker".to_string());
+        storage
+            .add_dependency_with_metadata(
+                "bd-c2",
+                "bd-c1",
+                "conditional-blocks",
+                Some(&condition.to_metadata()),
+                "tester",
+            )
+            .unwrap();
+        // Without a condition it blocks like `blocks`
+        storage
+            .add_dependency("bd-c3", "bd-c1", "conditional-blocks", "tester")
+            .unwrap();
+
+        assert!(!storage.is_blocked("bd-c2").unwrap());
+        assert!(storage.is_blocked("bd-c3").unwrap());
+
+        // Labelling the blocker re-evaluates the condition
+        storage
+            .add_label("bd-c1", "release-blocker", "tester")
+            .unwrap();
+        assert!(storage.is_blocked("bd-c2").unwrap());
+        assert_eq!(storage.get_blockers("bd-c2").unwrap(), vec!["bd-c1"]);
+        storage
+            .remove_label("bd-c1", "release-blocker", "tester")
+            .unwrap();
+        assert!(!storage.is_blocked("bd-c2").unwrap());
+
+        let deps = storage.get_dependencies_with_metadata("bd-c2").unwrap();
+        assert_eq!(deps[0].condition.as_deref(), Some("label:release-blocker"));
+    }
+
+    #[test]
+    fn test_waits_for_until_refreshes_when_due() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 5, 0, 0, 0).unwrap();
+        for id in ["bd-w1", "bd-w2"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        let until = WaitUntil(Utc::now() + chrono::Duration::days(1));
+        storage
+            .add_dependency_with_metadata(
+                "bd-w2",
+                "bd-w1",
+                "waits-for",
+                Some(&until.to_metadata()),
+                "tester",
+            )
+            .unwrap();
+        assert!(storage.is_blocked("bd-w2").unwrap());
+        assert!(!storage.refresh_blocked_cache_if_due().unwrap());
+
+        // Let the wake time pass without a mutation
+        let passed = WaitUntil(Utc::now() - chrono::Duration::minutes(1));
+        storage
+            .conn
+            .execute(
+                "UPDATE dependencies SET metadata = ? WHERE issue_id = 'bd-w2'",
+                [passed.to_metadata()],
+            )
+            .unwrap();
+        storage
+            .set_metadata(BLOCKED_CACHE_WAKE_KEY, &passed.0.to_rfc3339())
+            .unwrap();
+
+        assert!(storage.is_blocked("bd-w2").unwrap());
+        assert!(storage.refresh_blocked_cache_if_due().unwrap());
+        assert!(!storage.is_blocked("bd-w2").unwrap());
+        assert_eq!(storage.get_metadata(BLOCKED_CACHE_WAKE_KEY).unwrap(), None);
+        assert_eq!(
+            storage.get_waits_for_wake_times().unwrap()
+                [&("bd-w2".to_string(), "bd-w1".to_string())],
+            passed.0
+        );
+    }
+
+    #[test]
+    fn test_get_open_descendants_deepest_first() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 5, 0, 0, 0).unwrap();
+        for (id, status) in [
+            ("bd-e", Status::Open),
+            ("bd-e.1", Status::Open),
+            ("bd-e.2", Status::Closed),
+            ("bd-e.1.1", Status::InProgress),
+        ] {
+            let issue = make_issue(id, id, status, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        for (child, parent) in [
+            ("bd-e.1", "bd-e"),
+            ("bd-e.2", "bd-e"),
+            ("bd-e.1.1", "bd-e.1"),
+        ] {
+            storage
+                .add_dependency(child, parent, "parent-child", "tester")
+                .unwrap();
+        }
+
+        assert_eq!(
+            storage.get_open_descendants("bd-e").unwrap(),
+            vec!["bd-e.1.1", "bd-e.1"]
+        );
+        assert!(storage.get_open_descendants("bd-e.1.1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_reactions_on_issue_and_comment() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 6, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-r1", "React here", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        let comment = storage.add_comment("bd-r1", "alice", "LGTM?").unwrap();
+
+        assert!(storage.add_reaction("bd-r1", None, ":+1:", "bob").unwrap());
+        assert!(!storage.add_reaction("bd-r1", None, ":+1:", "bob").unwrap());
+        assert!(
+            storage
+                .add_reaction("bd-r1", Some(comment.id), ":+1:", "bob")
+                .unwrap()
+        );
+        assert!(
+            storage
+                .add_reaction("bd-r1", Some(999), ":+1:", "bob")
+                .is_err()
+        );
+        assert!(
+            storage
+                .add_reaction("bd-missing", None, ":+1:", "bob")
+                .is_err()
+        );
+
+        let reactions = storage.get_reactions("bd-r1").unwrap();
+        assert_eq!(reactions.len(), 2);
+        assert!(reactions.iter().any(|r| r.comment_id == Some(comment.id)));
+
+        assert!(
+            storage
+                .remove_reaction("bd-r1", None, ":+1:", "bob")
+                .unwrap()
+        );
+        assert!(
+            !storage
+                .remove_reaction("bd-r1", None, ":+1:", "bob")
+                .unwrap()
+        );
+        let details = storage
+            .get_issue_details("bd-r1", true, false, 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(details.reactions.len(), 1);
+        assert_eq!(details.reactions[0].comment_id, Some(comment.id));
+        assert!(
+            storage
+                .get_dirty_issue_ids()
+                .unwrap()
+                .contains(&"bd-r1".to_string())
+        );
+
+        storage.set_read_only();
+        assert!(matches!(
+            storage.add_reaction("bd-r1", None, ":eyes:", "bob"),
+            Err(BeadsError::ReadOnly { .. })
+        ));
+    }
+
+    #[test]
+    fn test_pinned_issues_sort_first_in_list_and_ready() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 6, 0, 0, 0).unwrap();
+        let urgent = make_issue("bd-p0", "Urgent", Status::Open, 0, None, t1, None);
+        let backlog = make_issue("bd-p4", "Backlog", Status::Open, 4, None, t1, None);
+        storage.create_issue(&urgent, "tester").unwrap();
+        storage.create_issue(&backlog, "tester").unwrap();
+
+        let update = IssueUpdate {
+            pinned: Some(true),
+            ..IssueUpdate::default()
+        };
+        assert!(
+            storage
+                .update_issue("bd-p4", &update, "tester")
+                .unwrap()
+                .pinned
+        );
+
+        let listed = storage.list_issues(&ListFilters::default()).unwrap();
+        assert_eq!(listed[0].id, "bd-p4");
+        let ready = storage
+            .get_ready_issues(&ReadyFilters::default(), ReadySortPolicy::Priority)
+            .unwrap();
+        assert_eq!(ready[0].id, "bd-p4");
+        assert_eq!(ready.len(), 2);
+    }
+
+    #[test]
+    fn test_code_refs_link_and_touches_filter() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 6, 0, 0, 0).unwrap();
+        for id in ["bd-c1", "bd-c2"] {
+            let issue = make_issue(id, "Code", Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        let line: CodeRef = "src/storage/sqlite.rs:42".parse().unwrap();
+        let pinned: CodeRef = "src/storage_old.rs@abc123".parse().unwrap();
+
+        assert!(storage.add_code_ref("bd-c1", &line, "tester").unwrap());
+        assert!(!storage.add_code_ref("bd-c1", &line, "tester").unwrap());
+        assert!(storage.add_code_ref("bd-c2", &pinned, "tester").unwrap());
+        assert!(storage.add_code_ref("bd-missing", &line, "tester").is_err());
+
+        let touching = |path: &str| -> Vec<String> {
+            let filters = ListFilters {
+                touches: Some(path.to_string()),
+                ..ListFilters::default()
+            };
+            let mut ids: Vec<String> = storage
+                .list_issues(&filters)
+                .unwrap()
+                .into_iter()
+                .map(|issue| issue.id)
+                .collect();
+            ids.sort();
+            ids
         };
+        assert_eq!(touching("src/storage/sqlite.rs"), vec!["bd-c1"]);
+        assert_eq!(touching("src/storage/"), vec!["bd-c1"]);
+        assert_eq!(touching("src"), vec!["bd-c1", "bd-c2"]);
+        assert!(touching("src/stor").is_empty());
+
+        let details = storage
+            .get_issue_details("bd-c1", false, false, 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(details.issue.code_refs, vec![line.clone()]);
+        assert!(storage.get_all_code_refs().unwrap().contains_key("bd-c2"));
+
+        assert!(storage.remove_code_ref("bd-c1", &line, "tester").unwrap());
+        assert!(storage.get_code_refs("bd-c1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_watchers_are_case_insensitive_and_loaded_with_details() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 6, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-w1", "Watched", Status::Open, 2, None, t1, None);
         storage.create_issue(&issue, "tester").unwrap();
 
-        let comment = storage
-            .add_comment("bd-c2", "alice", "Hello there")
+        assert!(storage.add_watcher("bd-w1", "alice", "alice").unwrap());
+        assert!(!storage.add_watcher("bd-w1", "Alice", "Alice").unwrap());
+        assert!(storage.add_watcher("bd-w1", "bob", "bob").unwrap());
+        assert!(storage.add_watcher("bd-missing", "bob", "bob").is_err());
+
+        let watched = storage.get_watched_issues("ALICE").unwrap();
+        assert_eq!(watched.len(), 1);
+        assert_eq!(watched[0].0, "bd-w1");
+        let details = storage
+            .get_issue_details("bd-w1", false, false, 0)
+            .unwrap()
             .unwrap();
-        assert_eq!(comment.issue_id, "bd-c2");
-        assert_eq!(comment.author, "alice");
-        assert_eq!(comment.body, "Hello there");
-        assert!(comment.id > 0);
+        assert_eq!(details.issue.watchers, vec!["alice", "bob"]);
 
-        let comments = storage.get_comments("bd-c2").unwrap();
-        assert_eq!(comments.len(), 1);
-        assert_eq!(comments[0], comment);
+        assert!(storage.remove_watcher("bd-w1", "ALICE", "alice").unwrap());
+        assert_eq!(storage.get_watchers("bd-w1").unwrap(), vec!["bob"]);
+        assert!(storage.get_watched_issues("alice").unwrap().is_empty());
     }
 
     #[test]
-    fn test_add_comment_marks_dirty() {
+    fn test_custom_fields_set_and_filter() {
         let mut storage = SqliteStorage::open_memory().unwrap();
-        let t1 = Utc.with_ymd_and_hms(2025, 7, 4, 0, 0, 0).unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 7, 6, 0, 0, 0).unwrap();
+        for (id, sprint) in [("bd-f1", Some("9")), ("bd-f2", Some("12")), ("bd-f3", None)] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+            if let Some(sprint) = sprint {
+                assert!(
+                    storage
+                        .set_field(id, "sprint", Some(sprint), "tester")
+                        .unwrap()
+                );
+            }
+        }
+        assert!(
+            !storage
+                .set_field("bd-f1", "sprint", Some("9"), "tester")
+                .unwrap()
+        );
+        assert!(
+            storage
+                .set_field("bd-missing", "sprint", Some("1"), "tester")
+                .is_err()
+        );
 
-        let issue = Issue {
-            id: "bd-c3".to_string(),
-            content_hash: None,
-            title: "Comment issue".to_string(),
-            description: None,
-            design: None,
-            acceptance_criteria: None,
-            notes: None,
-            status: Status::Open,
-            priority: Priority::MEDIUM,
-            issue_type: IssueType::Task,
-            assignee: None,
-            owner: None,
-            estimated_minutes: None,
-            created_at: t1,
-            created_by: None,
-            updated_at: t1,
-            closed_at: None,
-            close_reason: None,
-            closed_by_session: None,
-            defer_until: None,
-            due_at: None,
-            external_ref: None,
-            source_system: None,
-            source_repo: None,
-            deleted_at: None,
-            deleted_by: None,
-            delete_reason: None,
-            original_type: None,
-            compaction_level: None,
-            compacted_at: None,
-            compacted_at_commit: None,
-            original_size: None,
-            sender: None,
-            ephemeral: false,
-            pinned: false,
-            is_template: false,
-            labels: vec![],
-            dependencies: vec![],
-            comments: vec![],
+        let ids = |expr: &str| -> Vec<String> {
+            let mut ids: Vec<String> = storage
+                .list_issues(&ListFilters {
+                    field_filters: vec![FieldFilter::parse(expr).unwrap()],
+                    ..ListFilters::default()
+                })
+                .unwrap()
+                .into_iter()
+                .map(|issue| issue.id)
+                .collect();
+            ids.sort();
+            ids
         };
-        storage.create_issue(&issue, "tester").unwrap();
+        // Numeric, not textual: "9" < "12"
+        assert_eq!(ids("sprint>=10"), vec!["bd-f2"]);
+        assert_eq!(ids("sprint<10"), vec!["bd-f1"]);
+        assert_eq!(ids("sprint=12"), vec!["bd-f2"]);
+        assert_eq!(ids("sprint!=12"), vec!["bd-f1", "bd-f3"]);
+        assert_eq!(ids("sprint="), vec!["bd-f3"]);
+        assert_eq!(ids("sprint!="), vec!["bd-f1", "bd-f2"]);
 
-        storage
-            .add_comment("bd-c3", "alice", "Dirty comment")
+        assert!(
+            storage
+                .set_field("bd-f2", "sprint", None, "tester")
+                .unwrap()
+        );
+        let details = storage
+            .get_issue_details("bd-f1", false, false, 0)
+            .unwrap()
             .unwrap();
+        assert_eq!(
+            details.issue.fields.get("sprint").map(String::as_str),
+            Some("9")
+        );
+        assert!(storage.get_fields("bd-f2").unwrap().is_empty());
+    }
 
-        let dirty_count: i64 = storage
-            .conn
-            .query_row(
-                "SELECT count(*) FROM dirty_issues WHERE issue_id = ?",
-                ["bd-c3"],
-                |row| row.get(0),
-            )
-            .unwrap();
-        assert_eq!(dirty_count, 1);
+    #[test]
+    fn test_field_filter_parse() {
+        let filter = FieldFilter::parse(" sprint >= 12 ").unwrap();
+        assert_eq!(filter.name, "sprint");
+        assert_eq!(filter.op, FieldOp::Ge);
+        assert_eq!(filter.value, "12");
+        assert_eq!(FieldFilter::parse("team!=web").unwrap().op, FieldOp::Ne);
+        assert_eq!(
+            FieldFilter::parse("due<2026-05-01").unwrap().to_string(),
+            "due<2026-05-01"
+        );
+        assert!(FieldFilter::parse("sprint").is_err());
+        assert!(FieldFilter::parse("=12").is_err());
     }
 
     #[test]
@@ -4526,6 +8100,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_events_record_session_id() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc::now();
+        let plain = make_issue("bd-s1", "No session", Status::Open, 2, None, t1, None);
+        storage.create_issue(&plain, "tester").unwrap();
+
+        storage.set_session_id(Some("run-42".to_string()));
+        assert_eq!(storage.session_id(), Some("run-42"));
+        let tracked = make_issue("bd-s2", "With session", Status::Open, 2, None, t1, None);
+        storage.create_issue(&tracked, "tester").unwrap();
+
+        let events = storage.get_events("bd-s1", 0).unwrap();
+        assert!(events.iter().all(|e| e.session_id.is_none()));
+        let events = storage.get_events("bd-s2", 0).unwrap();
+        assert!(!events.is_empty());
+        assert!(
+            events
+                .iter()
+                .all(|e| e.session_id.as_deref() == Some("run-42"))
+        );
+    }
+
     #[test]
     fn test_blocked_cache_invalidation() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4649,6 +8246,91 @@ mod tests {
         assert!(!deleted_again, "Should return false when key doesn't exist");
     }
 
+    #[test]
+    fn test_newer_schema_opens_read_only() {
+        let temp = TempDir::new().unwrap();
+        let db_path = temp.path().join("beads.db");
+        let issue = |id: &str| Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            ..Issue::default()
+        };
+        {
+            let mut storage = SqliteStorage::open(&db_path).unwrap();
+            storage.create_issue(&issue("bd-1"), "tester").unwrap();
+            storage
+                .conn
+                .pragma_update(None, "user_version", CURRENT_SCHEMA_VERSION + 1)
+                .unwrap();
+        }
+
+        let state = SqliteStorage::inspect_schema(&db_path).unwrap();
+        assert!(state.is_newer());
+
+        let mut storage = SqliteStorage::open(&db_path).unwrap();
+        assert_eq!(
+            storage.schema_version().unwrap(),
+            CURRENT_SCHEMA_VERSION + 1,
+            "a newer schema must not be downgraded"
+        );
+        assert!(storage.get_issue("bd-1").unwrap().is_some());
+        let err = storage.create_issue(&issue("bd-2"), "tester").unwrap_err();
+        assert!(
+            matches!(err, BeadsError::SchemaMismatch { found, .. } if found == CURRENT_SCHEMA_VERSION + 1),
+            "{err:?}"
+        );
+        assert!(storage.set_config("key", "value").is_err());
+    }
+
+    #[test]
+    fn test_read_only_refuses_mutations() {
+        let temp = TempDir::new().unwrap();
+        let db_path = temp.path().join("beads.db");
+        let issue = |id: &str| Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            ..Issue::default()
+        };
+        assert!(matches!(
+            SqliteStorage::open_read_only(&db_path, None),
+            Err(BeadsError::DatabaseNotFound { .. })
+        ));
+        SqliteStorage::open(&db_path)
+            .unwrap()
+            .create_issue(&issue("bd-1"), "tester")
+            .unwrap();
+
+        let mut storage = SqliteStorage::open_read_only(&db_path, None).unwrap();
+        assert!(storage.is_read_only());
+        assert!(storage.get_issue("bd-1").unwrap().is_some());
+        let err = storage.create_issue(&issue("bd-2"), "tester").unwrap_err();
+        assert!(
+            matches!(&err, BeadsError::ReadOnly { operation } if operation == "create_issue"),
+            "{err:?}"
+        );
+        let refused: [(&str, Result<()>); 7] = [
+            ("set_config", storage.set_config("key", "value")),
+            ("delete_config", storage.delete_config("key").map(drop)),
+            ("set_metadata", storage.set_metadata("key", "value")),
+            ("delete_metadata", storage.delete_metadata("key").map(drop)),
+            (
+                "rebuild_dependency_counts",
+                storage.rebuild_dependency_counts().map(drop),
+            ),
+            (
+                "rebuild_blocked_cache",
+                storage.rebuild_blocked_cache(true).map(drop),
+            ),
+            ("bulk_import", storage.bulk_import(|_| Ok(()))),
+        ];
+        for (op, result) in refused {
+            assert!(
+                matches!(&result, Err(BeadsError::ReadOnly { operation }) if operation == op),
+                "{op}: {result:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_open_creates_database() {
         let temp = TempDir::new().unwrap();
@@ -4702,6 +8384,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_open_applies_connection_pragmas_on_every_open() {
+        let temp = TempDir::new().unwrap();
+        let db_path = temp.path().join("pragmas.db");
+
+        let fresh = SqliteStorage::open(&db_path).unwrap().pragmas().unwrap();
+        assert_eq!(fresh.journal_mode, "wal");
+        assert_eq!(fresh.synchronous, 1, "synchronous should be NORMAL");
+        assert!(fresh.foreign_keys);
+        assert_eq!(fresh.temp_store, 2);
+        assert_eq!(fresh.cache_size, -8000);
+        assert_eq!(
+            fresh.busy_timeout_ms,
+            i64::try_from(DEFAULT_BUSY_TIMEOUT_MS).unwrap()
+        );
+
+        // Schema is current now, so this open skips apply_schema
+        let reopened = SqliteStorage::open_with_timeout(&db_path, Some(250))
+            .unwrap()
+            .pragmas()
+            .unwrap();
+        assert_eq!(reopened.busy_timeout_ms, 250);
+        assert_eq!(
+            ConnectionPragmas {
+                busy_timeout_ms: fresh.busy_timeout_ms,
+                ..reopened
+            },
+            fresh
+        );
+    }
+
+    #[test]
+    fn test_bulk_import_rebuilds_deferred_indexes_and_rolls_back_on_error() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let index_names = |storage: &SqliteStorage| -> Vec<String> {
+            storage
+                .conn
+                .prepare("SELECT name FROM sqlite_master WHERE type = 'index' ORDER BY name")
+                .unwrap()
+                .query_map([], |row| row.get(0))
+                .unwrap()
+                .collect::<std::result::Result<_, _>>()
+                .unwrap()
+        };
+        let before = index_names(&storage);
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+
+        storage
+            .bulk_import(|storage| {
+                let kept = index_names(storage);
+                assert!(kept.contains(&"idx_dependencies_depends_on".to_string()));
+                assert!(!kept.contains(&"idx_dependencies_type".to_string()));
+                let issue = make_issue("bd-bulk-1", "Bulk", Status::Open, 2, None, t1, None);
+                storage.upsert_issue_for_import(&issue)?;
+                storage.sync_labels_for_import("bd-bulk-1", &["ops".to_string()])?;
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(index_names(&storage), before);
+        assert!(storage.get_issue("bd-bulk-1").unwrap().is_some());
+
+        let failed: Result<()> = storage.bulk_import(|storage| {
+            let issue = make_issue("bd-bulk-2", "Rolled back", Status::Open, 2, None, t1, None);
+            storage.upsert_issue_for_import(&issue)?;
+            Err(BeadsError::validation("test", "abort"))
+        });
+        assert!(failed.is_err());
+        assert!(storage.get_issue("bd-bulk-2").unwrap().is_none());
+        assert!(storage.conn.is_autocommit(), "transaction should be closed");
+    }
+
     #[test]
     fn test_create_duplicate_id_fails() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4842,6 +8595,188 @@ mod tests {
         assert_eq!(ids, vec!["bd-c", "bd-a", "bd-b"]);
     }
 
+    #[test]
+    fn test_list_issues_with_counts_matches_list_and_batch_counts() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 8, 1, 0, 0, 0).unwrap();
+        for (id, priority) in [("bd-a", 0), ("bd-b", 1), ("bd-c", 2), ("bd-d", 3)] {
+            let issue = make_issue(id, id, Status::Open, priority, Some("alice"), t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-b", "bd-a", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-c", "bd-a", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-c", "bd-b", "related", "tester")
+            .unwrap();
+
+        let filters = ListFilters {
+            assignee: Some("alice".to_string()),
+            limit: Some(3),
+            ..ListFilters::default()
+        };
+        let rows = storage.list_issues_with_counts(&filters).unwrap();
+        let ids: Vec<String> = rows.iter().map(|row| row.issue.id.clone()).collect();
+        let listed: Vec<String> = storage
+            .list_issues(&filters)
+            .unwrap()
+            .into_iter()
+            .map(|i| i.id)
+            .collect();
+        assert_eq!(ids, listed);
+
+        let dependencies = storage.count_dependencies_for_issues(&ids).unwrap();
+        let dependents = storage.count_dependents_for_issues(&ids).unwrap();
+        for row in &rows {
+            let id = &row.issue.id;
+            assert_eq!(row.dependency_count, *dependencies.get(id).unwrap_or(&0));
+            assert_eq!(row.dependent_count, *dependents.get(id).unwrap_or(&0));
+        }
+        let counts: Vec<(usize, usize)> = rows
+            .iter()
+            .map(|row| (row.dependency_count, row.dependent_count))
+            .collect();
+        assert_eq!(counts, vec![(0, 2), (1, 1), (2, 0)]);
+    }
+
+    #[test]
+    fn test_dependency_counts_follow_renames_deletes_and_rebuild() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 8, 1, 0, 0, 0).unwrap();
+        for id in ["bd-a", "bd-b", "bd-c"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-b", "bd-a", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-c", "bd-a", "blocks", "tester")
+            .unwrap();
+        storage
+            .rename_issue_ids(&[("bd-a".to_string(), "bd-z".to_string())], "tester", "")
+            .unwrap();
+        storage.remove_dependency("bd-c", "bd-z", "tester").unwrap();
+
+        let counts = |storage: &SqliteStorage| -> Vec<(String, usize, usize)> {
+            storage
+                .list_issues_with_counts(&ListFilters::default())
+                .unwrap()
+                .into_iter()
+                .map(|row| (row.issue.id, row.dependency_count, row.dependent_count))
+                .collect()
+        };
+        let expected = vec![
+            ("bd-b".to_string(), 1, 0),
+            ("bd-c".to_string(), 0, 0),
+            ("bd-z".to_string(), 0, 1),
+        ];
+        assert_eq!(counts(&storage), expected);
+        assert_eq!(storage.rebuild_dependency_counts().unwrap(), 0);
+
+        storage
+            .conn
+            .execute(
+                "UPDATE issues SET dependent_count = 5 WHERE id = 'bd-z'",
+                [],
+            )
+            .unwrap();
+        assert_eq!(storage.rebuild_dependency_counts().unwrap(), 1);
+        assert_eq!(counts(&storage), expected);
+    }
+
+    #[test]
+    fn test_stream_issues_batches_match_list_order() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 8, 1, 0, 0, 0).unwrap();
+        for n in 0..5 {
+            let issue = make_issue(
+                &format!("bd-s{n}"),
+                "Streamed",
+                Status::Open,
+                n % 3,
+                None,
+                t1,
+                None,
+            );
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+
+        let filters = ListFilters::default();
+        let expected: Vec<String> = storage
+            .list_issues(&filters)
+            .unwrap()
+            .into_iter()
+            .map(|i| i.id)
+            .collect();
+
+        let mut batch_sizes = Vec::new();
+        let mut streamed = Vec::new();
+        storage
+            .stream_issues(&filters, 2, |batch| {
+                batch_sizes.push(batch.len());
+                streamed.extend(batch.into_iter().map(|i| i.id));
+                Ok(true)
+            })
+            .unwrap();
+        assert_eq!(batch_sizes, vec![2, 2, 1]);
+        assert_eq!(streamed, expected);
+
+        // Returning false stops after the first batch
+        let mut calls = 0;
+        storage
+            .stream_search_issues(&SearchQuery::new("Streamed"), &filters, 2, |_| {
+                calls += 1;
+                Ok(false)
+            })
+            .unwrap();
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_list_issues_cursor_pages_through_ties() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 8, 1, 0, 0, 0).unwrap();
+        // Identical sort keys: only the id tiebreaker orders these
+        for id in ["bd-c", "bd-a", "bd-e", "bd-b", "bd-d"] {
+            let issue = make_issue(id, "Same", Status::Open, 2, None, t1, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+
+        let mut seen = Vec::new();
+        let mut after = None;
+        loop {
+            let filters = ListFilters {
+                limit: Some(2),
+                paginate: true,
+                after: after.take(),
+                ..ListFilters::default()
+            };
+            let page = storage.list_issues(&filters).unwrap();
+            seen.extend(page.iter().map(|i| i.id.clone()));
+            if page.len() < 2 {
+                break;
+            }
+            after = Some(ListCursor::after_issue(page.last().unwrap(), None, false));
+        }
+        assert_eq!(seen, vec!["bd-a", "bd-b", "bd-c", "bd-d", "bd-e"]);
+
+        // A cursor for a different ordering is rejected
+        let filters = ListFilters {
+            sort: Some("title".to_string()),
+            after: Some(ListCursor::after_issue(
+                &make_issue("bd-a", "Same", Status::Open, 2, None, t1, None),
+                None,
+                false,
+            )),
+            ..ListFilters::default()
+        };
+        assert!(storage.list_issues(&filters).is_err());
+    }
+
     #[test]
     fn test_search_issues_full_text() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -5054,6 +8989,86 @@ mod tests {
         assert_eq!(res.len(), 2);
     }
 
+    #[test]
+    fn test_claim_ready_issues_takes_top_unassigned() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap();
+
+        let taken = make_issue("bd-1", "Taken", Status::Open, 0, Some("bob"), t1, None);
+        let high = make_issue("bd-2", "High", Status::Open, 1, None, t2, None);
+        let low = make_issue("bd-3", "Low", Status::Open, 3, None, t1, None);
+        let skipped = make_issue("bd-4", "Skipped", Status::Open, 0, None, t1, None);
+        for issue in [&taken, &high, &low, &skipped] {
+            storage.create_issue(issue, "tester").unwrap();
+        }
+
+        let exclude: HashSet<String> = std::iter::once("bd-4".to_string()).collect();
+        let claimed = storage
+            .claim_ready_issues(
+                &ReadyFilters::default(),
+                ReadySortPolicy::Priority,
+                1,
+                &exclude,
+                "alice",
+            )
+            .unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].id, "bd-2");
+
+        let stored = storage.get_issue("bd-2").unwrap().unwrap();
+        assert_eq!(stored.status, Status::InProgress);
+        assert_eq!(stored.assignee.as_deref(), Some("alice"));
+
+        // A second claim never returns an already-claimed issue
+        let claimed = storage
+            .claim_ready_issues(
+                &ReadyFilters::default(),
+                ReadySortPolicy::Priority,
+                5,
+                &exclude,
+                "carol",
+            )
+            .unwrap();
+        let ids: Vec<&str> = claimed.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["bd-3"]);
+    }
+
+    #[test]
+    fn test_next_ready_issue_skips_blocked_and_excluded() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        let blocked = make_issue("bd-1", "Blocked", Status::Open, 0, None, t1, None);
+        let blocker = make_issue("bd-2", "Blocker", Status::Open, 2, None, t1, None);
+        let excluded = make_issue("bd-3", "Excluded", Status::Open, 1, None, t1, None);
+        for issue in [&blocked, &blocker, &excluded] {
+            storage.create_issue(issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-1", "bd-2", "blocks", "tester")
+            .unwrap();
+
+        let exclude: HashSet<String> = std::iter::once("bd-3".to_string()).collect();
+        let next = storage
+            .next_ready_issue(
+                &ReadyFilters::default(),
+                ReadySortPolicy::Priority,
+                &exclude,
+            )
+            .unwrap();
+        assert_eq!(next.map(|issue| issue.id).as_deref(), Some("bd-2"));
+
+        let filters = ReadyFilters {
+            priorities: Some(vec![Priority(0)]),
+            ..ReadyFilters::default()
+        };
+        let none = storage
+            .next_ready_issue(&filters, ReadySortPolicy::Priority, &exclude)
+            .unwrap();
+        assert!(none.is_none());
+    }
+
     #[test]
     fn test_get_ready_issues_filters_by_parent() {
         let mut storage = SqliteStorage::open_memory().unwrap();
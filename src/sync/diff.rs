@@ -0,0 +1,199 @@
+//! Field-level diff between two sets of issues.
+//!
+//! Either side can be a JSONL file, a history snapshot, or the issues the
+//! database would export right now. Issues are matched by ID and compared by
+//! [`export_hash`] first, so unchanged issues never pay for a field-by-field
+//! comparison.
+
+use crate::model::{Issue, Status};
+use crate::sync::export_hash;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Fields that change on every touch without changing the issue.
+const IGNORED_FIELDS: [&str; 2] = ["updated_at", "content_hash"];
+
+/// One field that differs between two versions of an issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    /// Previous value (`null` when the field was absent).
+    pub old: Value,
+    /// New value (`null` when the field was removed).
+    pub new: Value,
+}
+
+/// An issue that differs between the two sides.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueDelta {
+    pub id: String,
+    pub title: String,
+    /// Changed fields, sorted by name (empty for created and deleted issues).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<FieldChange>,
+}
+
+/// Difference between two sets of issues, each list sorted by ID.
+///
+/// An issue that moved to closed is reported under `closed` rather than
+/// `modified`; one that was removed or tombstoned is reported under `deleted`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SnapshotDiff {
+    pub created: Vec<IssueDelta>,
+    pub closed: Vec<IssueDelta>,
+    pub modified: Vec<IssueDelta>,
+    pub deleted: Vec<IssueDelta>,
+    /// Issues present on both sides with identical content.
+    pub unchanged: usize,
+}
+
+impl SnapshotDiff {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty()
+            && self.closed.is_empty()
+            && self.modified.is_empty()
+            && self.deleted.is_empty()
+    }
+}
+
+/// Compare `before` and `after` by issue ID.
+#[must_use]
+pub fn diff_snapshots(before: &[Issue], after: &[Issue]) -> SnapshotDiff {
+    let mut after: BTreeMap<&str, &Issue> = after
+        .iter()
+        .map(|issue| (issue.id.as_str(), issue))
+        .collect();
+    let before: BTreeMap<&str, &Issue> = before
+        .iter()
+        .map(|issue| (issue.id.as_str(), issue))
+        .collect();
+    let mut diff = SnapshotDiff::default();
+
+    for (id, old) in before {
+        let Some(new) = after.remove(id) else {
+            diff.deleted.push(delta(old, Vec::new()));
+            continue;
+        };
+        if export_hash(old) == export_hash(new) {
+            diff.unchanged += 1;
+            continue;
+        }
+        let changes = field_changes(old, new);
+        if changes.is_empty() {
+            diff.unchanged += 1;
+        } else if new.status == Status::Tombstone && old.status != Status::Tombstone {
+            diff.deleted.push(delta(new, changes));
+        } else if new.status == Status::Closed && old.status != Status::Closed {
+            diff.closed.push(delta(new, changes));
+        } else {
+            diff.modified.push(delta(new, changes));
+        }
+    }
+    diff.created = after
+        .into_values()
+        .map(|issue| delta(issue, Vec::new()))
+        .collect();
+
+    diff
+}
+
+/// Every exported field that differs between `old` and `new`, sorted by name.
+///
+/// `updated_at` and `content_hash` are ignored, as in [`export_hash`].
+#[must_use]
+pub fn field_changes(old: &Issue, new: &Issue) -> Vec<FieldChange> {
+    let old = to_fields(old);
+    let new = to_fields(new);
+    let mut changes: Vec<FieldChange> = old
+        .keys()
+        .chain(new.keys().filter(|key| !old.contains_key(*key)))
+        .filter(|key| !IGNORED_FIELDS.contains(&key.as_str()))
+        .filter_map(|key| {
+            let before = old.get(key).cloned().unwrap_or(Value::Null);
+            let after = new.get(key).cloned().unwrap_or(Value::Null);
+            (before != after).then(|| FieldChange {
+                field: key.clone(),
+                old: before,
+                new: after,
+            })
+        })
+        .collect();
+    changes.sort_by(|a, b| a.field.cmp(&b.field));
+    changes
+}
+
+fn to_fields(issue: &Issue) -> serde_json::Map<String, Value> {
+    match serde_json::to_value(issue) {
+        Ok(Value::Object(fields)) => fields,
+        _ => serde_json::Map::new(),
+    }
+}
+
+fn delta(issue: &Issue, changes: Vec<FieldChange>) -> IssueDelta {
+    IssueDelta {
+        id: issue.id.clone(),
+        title: issue.title.clone(),
+        changes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Priority;
+    use chrono::{TimeZone, Utc};
+
+    fn issue(id: &str, title: &str) -> Issue {
+        let at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            created_at: at,
+            updated_at: at,
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_classifies_created_closed_modified_deleted() {
+        let before = vec![
+            issue("bd-1", "Unchanged"),
+            issue("bd-2", "To close"),
+            issue("bd-3", "To edit"),
+            issue("bd-4", "To remove"),
+        ];
+        let mut after = vec![issue("bd-1", "Unchanged"), issue("bd-5", "New")];
+        let mut closed = issue("bd-2", "To close");
+        closed.status = Status::Closed;
+        let mut edited = issue("bd-3", "Edited");
+        edited.priority = Priority(0);
+        edited.labels = vec!["urgent".to_string()];
+        // A touch alone is not a change
+        after[0].updated_at = Utc::now();
+        after.extend([closed, edited]);
+
+        let diff = diff_snapshots(&before, &after);
+        assert_eq!(diff.unchanged, 1);
+        assert_eq!(diff.created.len(), 1);
+        assert_eq!(diff.created[0].id, "bd-5");
+        assert_eq!(diff.deleted.len(), 1);
+        assert_eq!(diff.deleted[0].id, "bd-4");
+        assert_eq!(diff.closed.len(), 1);
+        assert_eq!(diff.closed[0].changes[0].field, "status");
+        assert_eq!(diff.closed[0].changes[0].new, Value::from("closed"));
+
+        assert_eq!(diff.modified.len(), 1);
+        let fields: Vec<&str> = diff.modified[0]
+            .changes
+            .iter()
+            .map(|change| change.field.as_str())
+            .collect();
+        assert_eq!(fields, vec!["labels", "priority", "title"]);
+        assert_eq!(diff.modified[0].changes[0].old, Value::Null);
+        assert_eq!(diff.modified[0].title, "Edited");
+        assert!(!diff.is_empty());
+        assert!(diff_snapshots(&before, &before).is_empty());
+    }
+}
@@ -4,9 +4,15 @@
 //! - Creating timestamped backups of `issues.jsonl` before export
 //! - Rotating backups based on count and age
 //! - Listing and restoring backups
+//! - Resolving and diffing snapshots at the issue level
 
 use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+use crate::sync::diff;
+use crate::sync::shard::Shard;
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fs::{self, File};
 use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
@@ -35,6 +41,8 @@ pub struct BackupEntry {
     pub path: PathBuf,
     pub timestamp: DateTime<Utc>,
     pub size: u64,
+    /// Stem of the file that was backed up (`issues`, `issues.open`, ...).
+    pub stem: String,
 }
 
 /// Backup the JSONL file before export.
@@ -197,6 +205,7 @@ pub fn list_backups(history_dir: &Path, filter_prefix: Option<&str>) -> Result<V
 
         let timestamp = Utc.from_utc_datetime(&dt);
         backups.push(BackupEntry {
+            stem: parts[..parts.len() - 2].join("."),
             path,
             timestamp,
             size: metadata.len(),
@@ -209,6 +218,164 @@ pub fn list_backups(history_dir: &Path, filter_prefix: Option<&str>) -> Result<V
     Ok(backups)
 }
 
+/// Parse a snapshot timestamp.
+///
+/// Accepts the form used in backup filenames (`20250101_120000`), RFC 3339 as
+/// printed by `br history list --json`, and `YYYY-MM-DD[T ]HH:MM:SS`.
+#[must_use]
+pub fn parse_snapshot_timestamp(spec: &str) -> Option<DateTime<Utc>> {
+    let spec = spec.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(spec) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    ["%Y%m%d_%H%M%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(spec, format).ok())
+        .map(|dt| Utc.from_utc_datetime(&dt))
+}
+
+/// The backup files that make up the snapshot named by `spec`.
+///
+/// `spec` is a backup filename or a timestamp (see
+/// [`parse_snapshot_timestamp`]). A sharded export is backed up file by file
+/// and an unchanged shard is not copied again, so for a timestamp each file
+/// of the layout backed up at that time contributes its newest backup taken
+/// at or before it.
+///
+/// # Errors
+///
+/// Returns an error if no backup matches or the directory cannot be read.
+pub fn find_snapshot(history_dir: &Path, spec: &str) -> Result<Vec<BackupEntry>> {
+    let not_found = || BeadsError::Config(format!("Backup file not found: {spec}"));
+    let backups = list_backups(history_dir, None)?;
+
+    if let Some(entry) = backups
+        .iter()
+        .find(|entry| entry.path.file_name().is_some_and(|name| name == spec))
+    {
+        return Ok(vec![entry.clone()]);
+    }
+
+    let at = parse_snapshot_timestamp(spec).ok_or_else(not_found)?;
+    let mut stems: Vec<String> = Vec::new();
+    for entry in backups.iter().filter(|entry| entry.timestamp == at) {
+        let base = Shard::ALL
+            .iter()
+            .find_map(|shard| entry.stem.strip_suffix(&format!(".{}", shard.as_str())));
+        match base {
+            Some(base) => stems.extend(
+                Shard::ALL
+                    .iter()
+                    .map(|shard| format!("{base}.{}", shard.as_str())),
+            ),
+            None => stems.push(entry.stem.clone()),
+        }
+    }
+    if stems.is_empty() {
+        return Err(not_found());
+    }
+    stems.sort();
+    stems.dedup();
+
+    // Backups are sorted newest first
+    Ok(stems
+        .iter()
+        .filter_map(|stem| {
+            backups
+                .iter()
+                .find(|entry| entry.stem == *stem && entry.timestamp <= at)
+                .cloned()
+        })
+        .collect())
+}
+
+/// Read every issue in a snapshot.
+///
+/// # Errors
+///
+/// Returns an error if a backup file cannot be read or is not valid JSONL.
+pub fn read_snapshot(entries: &[BackupEntry]) -> Result<Vec<Issue>> {
+    let mut issues = Vec::new();
+    for entry in entries {
+        issues.extend(crate::sync::read_issues_from_jsonl(&entry.path)?);
+    }
+    Ok(issues)
+}
+
+/// An issue that differs between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueChange {
+    pub id: String,
+    pub title: String,
+    /// JSONL fields that differ (empty for added and removed issues).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+/// Issue-level difference between two snapshots, each list sorted by ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct IssueDiff {
+    pub added: Vec<IssueChange>,
+    pub removed: Vec<IssueChange>,
+    pub changed: Vec<IssueChange>,
+}
+
+impl IssueDiff {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compare two sets of issues by ID.
+///
+/// Fields are compared with [`diff::field_changes`], so a touched but
+/// unchanged issue does not show up as changed.
+#[must_use]
+pub fn diff_issues(before: &[Issue], after: &[Issue]) -> IssueDiff {
+    let index = |issues: &[Issue]| -> BTreeMap<String, Issue> {
+        issues
+            .iter()
+            .map(|issue| (issue.id.clone(), issue.clone()))
+            .collect()
+    };
+    let before = index(before);
+    let mut after = index(after);
+    let mut diff = IssueDiff::default();
+
+    for (id, old) in before {
+        let Some(new) = after.remove(&id) else {
+            diff.removed.push(IssueChange {
+                id,
+                title: old.title,
+                fields: Vec::new(),
+            });
+            continue;
+        };
+        let fields: Vec<String> = diff::field_changes(&old, &new)
+            .into_iter()
+            .map(|change| change.field)
+            .collect();
+        if !fields.is_empty() {
+            diff.changed.push(IssueChange {
+                id,
+                title: new.title,
+                fields,
+            });
+        }
+    }
+    diff.added = after
+        .into_iter()
+        .map(|(id, issue)| IssueChange {
+            id,
+            title: issue.title,
+            fields: Vec::new(),
+        })
+        .collect();
+
+    diff
+}
+
 fn get_latest_backup(history_dir: &Path, filter_stem: Option<&str>) -> Result<Option<BackupEntry>> {
     // If filtering by stem, ensure we match "{stem}." to avoid prefix collisions
     // e.g. "issues" shouldn't match "issues_archive"
@@ -409,6 +576,74 @@ mod tests {
         assert!(backups[1].path.to_string_lossy().contains("20230101"));
     }
 
+    #[test]
+    fn test_find_snapshot_resolves_sharded_timestamp() {
+        let temp = TempDir::new().unwrap();
+        let history_dir = temp.path();
+
+        // The closed shard was unchanged at 12:00, so only the open shard was copied
+        for name in [
+            "issues.open.20230101_100000.jsonl",
+            "issues.closed.20230101_100000.jsonl",
+            "issues.open.20230101_120000.jsonl",
+            "issues.20221231_090000.jsonl",
+        ] {
+            File::create(history_dir.join(name)).unwrap();
+        }
+
+        let names = |entries: Vec<BackupEntry>| -> Vec<String> {
+            entries
+                .iter()
+                .map(|e| e.path.file_name().unwrap().to_string_lossy().to_string())
+                .collect()
+        };
+        assert_eq!(
+            names(find_snapshot(history_dir, "20230101_120000").unwrap()),
+            vec![
+                "issues.closed.20230101_100000.jsonl",
+                "issues.open.20230101_120000.jsonl"
+            ]
+        );
+        assert_eq!(
+            names(find_snapshot(history_dir, "2022-12-31T09:00:00Z").unwrap()),
+            vec!["issues.20221231_090000.jsonl"]
+        );
+        assert_eq!(
+            names(find_snapshot(history_dir, "issues.open.20230101_100000.jsonl").unwrap()),
+            vec!["issues.open.20230101_100000.jsonl"]
+        );
+        assert!(find_snapshot(history_dir, "20990101_000000").is_err());
+    }
+
+    #[test]
+    fn test_diff_issues_reports_added_removed_changed() {
+        let created = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let issue = |id: &str, title: &str| Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            created_at: created,
+            updated_at: created,
+            ..Issue::default()
+        };
+        let before = vec![
+            issue("bd-1", "Keep"),
+            issue("bd-2", "Old"),
+            issue("bd-3", "Gone"),
+        ];
+        let mut touched = issue("bd-1", "Keep");
+        touched.updated_at = before[0].updated_at + chrono::Duration::hours(1);
+        let after = vec![touched, issue("bd-2", "New"), issue("bd-4", "Added")];
+
+        let diff = diff_issues(&before, &after);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].id, "bd-4");
+        assert_eq!(diff.removed[0].id, "bd-3");
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].id, "bd-2");
+        assert_eq!(diff.changed[0].fields, vec!["title".to_string()]);
+        assert!(diff_issues(&after, &after).is_empty());
+    }
+
     #[test]
     fn test_prune_backups() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,174 @@
+//! Cross-process lock files.
+//!
+//! Concurrent `br` runs would otherwise interleave: one run's auto-flush can
+//! export while another imports, and two `--no-db` writers each rewrite the
+//! JSONL file from their own copy. Mutating commands and sync therefore hold
+//! the workspace lock (`.beads/.lock`) for the whole run.
+//!
+//! A lock is an OS advisory lock (`flock` on Unix, `LockFileEx` on Windows)
+//! on an open handle to the lock file. The OS releases it when the handle
+//! closes, including when the process crashes or is killed, so a lock can
+//! never outlive its holder. The file itself stays in place; it records the
+//! holder's PID and actor for diagnostics only, and is emptied on release.
+//! A run that finds the lock taken retries until its lock timeout runs out,
+//! then fails with [`BeadsError::Locked`] naming the holder.
+
+use crate::error::{BeadsError, Result};
+use fs4::{FileExt, TryLockError};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// File name of the workspace lock inside `.beads/`.
+pub const WORKSPACE_LOCK_FILE: &str = ".lock";
+
+/// Delay between attempts to take a held lock.
+const RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Who holds a lock, as recorded in the lock file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LockHolder {
+    pub pid: Option<u32>,
+    pub actor: Option<String>,
+}
+
+impl LockHolder {
+    /// Read the holder recorded in `path` (empty if unreadable, as it is on
+    /// Windows while the lock is held).
+    #[must_use]
+    pub fn read(path: &Path) -> Self {
+        let contents = fs::read_to_string(path).unwrap_or_default();
+        let mut lines = contents.lines().map(str::trim);
+        Self {
+            pid: lines.next().and_then(|line| line.parse().ok()),
+            actor: lines
+                .next()
+                .filter(|line| !line.is_empty())
+                .map(str::to_string),
+        }
+    }
+}
+
+/// Held lock; released on drop or when the process exits.
+#[derive(Debug)]
+pub struct LockFile {
+    path: PathBuf,
+    file: File,
+}
+
+impl LockFile {
+    /// Take the workspace lock in `beads_dir`.
+    ///
+    /// # Errors
+    ///
+    /// See [`LockFile::acquire`].
+    pub fn workspace(beads_dir: &Path, actor: &str, timeout: Duration) -> Result<Self> {
+        Self::acquire(&beads_dir.join(WORKSPACE_LOCK_FILE), actor, timeout)
+    }
+
+    /// Lock the file at `path` (creating it if needed), waiting up to
+    /// `timeout` for another holder to release it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Locked` if the lock is still held after `timeout`, or an I/O
+    /// error if the lock file cannot be opened or locked.
+    pub fn acquire(path: &Path, actor: &str, timeout: Duration) -> Result<Self> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let deadline = Instant::now() + timeout;
+        loop {
+            // Called through the trait: `File::try_lock` is newer than our MSRV
+            match FileExt::try_lock(&file) {
+                Ok(()) => break,
+                Err(TryLockError::WouldBlock) => {
+                    if Instant::now() >= deadline {
+                        let holder = LockHolder::read(path);
+                        return Err(BeadsError::Locked {
+                            path: path.to_path_buf(),
+                            pid: holder.pid,
+                            actor: holder.actor,
+                        });
+                    }
+                    thread::sleep(RETRY_INTERVAL);
+                }
+                Err(TryLockError::Error(err)) => return Err(err.into()),
+            }
+        }
+
+        // Whatever a crashed holder left behind is replaced
+        file.set_len(0)?;
+        let _ = writeln!(file, "{}\n{actor}", std::process::id());
+        let _ = file.flush();
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+        })
+    }
+
+    /// Path of the lock file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for LockFile {
+    fn drop(&mut self) {
+        // The file is kept: removing it would let a waiter that already
+        // opened it lock a different inode than the next run creates
+        let _ = self.file.set_len(0);
+        let _ = FileExt::unlock(&self.file);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_lock_is_exclusive_until_dropped() {
+        let temp = TempDir::new().unwrap();
+
+        let lock = LockFile::workspace(temp.path(), "alice", Duration::ZERO).unwrap();
+        assert_eq!(lock.path(), temp.path().join(WORKSPACE_LOCK_FILE));
+        assert!(lock.path().exists());
+        match LockFile::workspace(temp.path(), "bob", Duration::from_millis(60)) {
+            Err(BeadsError::Locked { path, pid, actor }) => {
+                assert_eq!(path, lock.path());
+                // Windows denies reads of a locked file, so the holder is unknown there
+                if cfg!(unix) {
+                    assert_eq!(pid, Some(std::process::id()));
+                    assert_eq!(actor.as_deref(), Some("alice"));
+                }
+            }
+            other => panic!("expected Locked, got {other:?}"),
+        }
+
+        drop(lock);
+        let path = temp.path().join(WORKSPACE_LOCK_FILE);
+        assert_eq!(LockHolder::read(&path), LockHolder::default());
+        assert!(LockFile::workspace(temp.path(), "bob", Duration::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_leftover_lock_file_does_not_block() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(WORKSPACE_LOCK_FILE);
+        // What a killed run leaves behind: the file, but no OS lock
+        fs::write(&path, format!("{}\nghost\n", std::process::id())).unwrap();
+
+        let lock = LockFile::workspace(temp.path(), "alice", Duration::ZERO).unwrap();
+        assert_eq!(
+            LockHolder::read(lock.path()).actor.as_deref(),
+            Some("alice")
+        );
+    }
+}
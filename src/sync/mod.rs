@@ -6,9 +6,13 @@
 //! - Dirty tracking for incremental exports
 //! - Collision detection during imports
 //! - Path validation and allowlist enforcement
+//! - Optional status-sharded layout (`issues.open.jsonl` / `issues.closed.jsonl`)
 
+pub mod diff;
 pub mod history;
+pub mod lock;
 pub mod path;
+pub mod shard;
 
 pub use path::{
     ALLOWED_EXACT_NAMES, ALLOWED_EXTENSIONS, PathValidation, is_sync_path_allowed,
@@ -20,11 +24,12 @@ use crate::error::{BeadsError, Result};
 use crate::model::Issue;
 use crate::storage::SqliteStorage;
 use crate::sync::history::HistoryConfig;
+use crate::sync::shard::{Shard, ShardStrategy};
 use crate::util::progress::{create_progress_bar, create_spinner};
 use crate::validation::IssueValidator;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::collections::{HashSet, hash_map::RandomState};
+use std::collections::{HashMap, HashSet, hash_map::RandomState};
 use std::fmt::Write as FmtWrite;
 use std::fs::{self, File};
 use std::io::{BufRead, BufReader, BufWriter, Write};
@@ -52,6 +57,11 @@ pub struct ExportConfig {
     pub show_progress: bool,
     /// Configuration for history backups.
     pub history: HistoryConfig,
+    /// How the export is split across JSONL files.
+    pub shard: ShardStrategy,
+    /// IDs removed on purpose (`delete --hard`): the JSONL may still list
+    /// them without the database counting as stale.
+    pub purged_ids: Vec<String>,
 }
 
 /// Export error handling policy.
@@ -105,6 +115,10 @@ pub enum ExportEntityType {
     Dependency,
     Label,
     Comment,
+    CodeRef,
+    Watcher,
+    Reaction,
+    Field,
 }
 
 /// Export error record.
@@ -303,6 +317,44 @@ pub struct ImportResult {
     pub tombstone_skipped: usize,
     /// Conflict markers detected (if any).
     pub conflict_markers: Vec<ConflictMarker>,
+    /// Issues given a new ID because another issue already had theirs.
+    ///
+    /// IDs are hashes, so two branches can derive the same ID for different
+    /// issues. Records sharing an ID but not a `created_at` are different
+    /// issues: the oldest keeps the ID and each newer one is re-hashed.
+    pub id_remaps: Vec<IdRemap>,
+}
+
+/// An issue moved to a new ID by import-time collision resolution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IdRemap {
+    /// The contested ID, still held by the oldest issue.
+    pub old_id: String,
+    /// The newer issue's new ID.
+    pub new_id: String,
+    /// Title of the remapped issue.
+    pub title: String,
+    /// Whether the remapped issue was the one already in the database.
+    pub existing: bool,
+}
+
+/// Stage of a running import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportStage {
+    /// Collision detection and ID resolution.
+    Scan,
+    /// Writing issues and their relations.
+    Write,
+}
+
+/// Progress of a running import, reported once per issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportProgress {
+    pub stage: ImportStage,
+    /// Issues handled so far in this stage.
+    pub done: usize,
+    /// Issues this stage will handle.
+    pub total: usize,
 }
 
 // ============================================================================
@@ -572,7 +624,7 @@ pub fn preflight_export(
             tracing::debug!(issue_count = count, "Database access check: PASS");
 
             // Check 4: Empty database safety (would overwrite non-empty JSONL)
-            if count == 0 && !config.force && output_path.exists() {
+            if count == 0 && !config.force && shard::jsonl_exists(output_path) {
                 match count_issues_in_jsonl(output_path) {
                     Ok(jsonl_count) if jsonl_count > 0 => {
                         result.add(PreflightCheck::fail(
@@ -614,13 +666,15 @@ pub fn preflight_export(
             }
 
             // Check 5: Stale database safety (would lose issues from JSONL)
-            if count > 0 && !config.force && output_path.exists() {
+            if count > 0 && !config.force && shard::jsonl_exists(output_path) {
                 match get_issue_ids_from_jsonl(output_path) {
                     Ok(jsonl_ids) if !jsonl_ids.is_empty() => {
-                        let db_ids: HashSet<String> = storage
+                        let mut db_ids: HashSet<String> = storage
                             .get_all_issues_for_export()
                             .map(|issues| issues.into_iter().map(|i| i.id).collect())
                             .unwrap_or_default();
+                        // Old IDs of renamed issues are accounted for by the new ID.
+                        db_ids.extend(storage.get_id_aliases().unwrap_or_default().into_keys());
                         let missing: Vec<_> = jsonl_ids.difference(&db_ids).take(5).collect();
                         if missing.is_empty() {
                             result.add(PreflightCheck::pass(
@@ -791,8 +845,11 @@ pub fn preflight_import(
     }
 
     // Check 3: Input file exists and is readable
-    if input_path.exists() {
-        match File::open(input_path) {
+    if shard::jsonl_exists(input_path) {
+        match shard::jsonl_sources(input_path)
+            .iter()
+            .try_for_each(|source| File::open(source).map(drop))
+        {
             Ok(_) => {
                 result.add(PreflightCheck::pass(
                     "file_readable",
@@ -1044,25 +1101,27 @@ const CONFLICT_START: &str = "<<<<<<<";
 const CONFLICT_SEPARATOR: &str = "=======";
 const CONFLICT_END: &str = ">>>>>>>";
 
-/// Scan a file for merge conflict markers.
+/// Scan a file (or every file of a sharded export) for merge conflict markers.
 ///
 /// # Errors
 ///
 /// Returns an error if the file cannot be read.
 pub fn scan_conflict_markers(path: &Path) -> Result<Vec<ConflictMarker>> {
-    let file = File::open(path)?;
-    let reader = BufReader::with_capacity(2 * 1024 * 1024, file);
     let mut markers = Vec::new();
 
-    for (line_num, line) in reader.lines().enumerate() {
-        let line = line?;
-        if let Some((marker_type, branch)) = detect_conflict_marker(&line) {
-            markers.push(ConflictMarker {
-                path: path.to_path_buf(),
-                line: line_num + 1,
-                marker_type,
-                branch,
-            });
+    for source in shard::jsonl_sources(path) {
+        let file = File::open(&source)?;
+        let reader = BufReader::with_capacity(2 * 1024 * 1024, file);
+        for (line_num, line) in reader.lines().enumerate() {
+            let line = line?;
+            if let Some((marker_type, branch)) = detect_conflict_marker(&line) {
+                markers.push(ConflictMarker {
+                    path: source.clone(),
+                    line: line_num + 1,
+                    marker_type,
+                    branch,
+                });
+            }
         }
     }
 
@@ -1122,19 +1181,30 @@ struct PartialId {
 
 /// Analyze JSONL to get line count and unique issue IDs efficiently.
 ///
+/// Reads every file of a sharded export (see [`shard::jsonl_sources`]).
+///
 /// # Errors
 ///
 /// Returns an error if the file cannot be read or contains invalid JSON.
 pub fn analyze_jsonl(path: &Path) -> Result<(usize, HashSet<String>)> {
+    let mut count = 0;
+    let mut ids = HashSet::new();
+    for source in shard::jsonl_sources(path) {
+        count += analyze_jsonl_file(&source, &mut ids)?;
+    }
+    Ok((count, ids))
+}
+
+/// Count the issue lines in a single JSONL file, collecting IDs into `ids`.
+fn analyze_jsonl_file(path: &Path, ids: &mut HashSet<String>) -> Result<usize> {
     let file = match File::open(path) {
         Ok(f) => f,
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok((0, HashSet::new())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
         Err(e) => return Err(BeadsError::Io(e)),
     };
 
     let mut reader = BufReader::new(file);
     let mut count = 0;
-    let mut ids = HashSet::new();
     let mut line_buf = String::new();
     let mut line_num = 0;
 
@@ -1158,7 +1228,7 @@ pub fn analyze_jsonl(path: &Path) -> Result<(usize, HashSet<String>)> {
         count += 1;
     }
 
-    Ok((count, ids))
+    Ok(count)
 }
 
 /// Count issues in an existing JSONL file.
@@ -1179,6 +1249,91 @@ pub fn get_issue_ids_from_jsonl(path: &Path) -> Result<HashSet<String>> {
     Ok(analyze_jsonl(path)?.1)
 }
 
+/// Attach dependencies, labels, comments, code refs, watchers, reactions and
+/// custom fields to issues being exported.
+///
+/// Relations are loaded with one batch query each; a failed query is routed
+/// through the export error policy and leaves that relation empty.
+fn populate_export_relations(
+    storage: &SqliteStorage,
+    issues: &mut [Issue],
+    ctx: &mut ExportContext,
+) -> Result<()> {
+    let all_deps = load_relation(
+        ctx,
+        ExportEntityType::Dependency,
+        storage.get_all_dependency_records(),
+    )?;
+    let all_labels = load_relation(ctx, ExportEntityType::Label, storage.get_all_labels())?;
+    let all_comments = load_relation(ctx, ExportEntityType::Comment, storage.get_all_comments())?;
+    let all_code_refs = load_relation(ctx, ExportEntityType::CodeRef, storage.get_all_code_refs())?;
+    let all_watchers = load_relation(ctx, ExportEntityType::Watcher, storage.get_all_watchers())?;
+    let all_reactions =
+        load_relation(ctx, ExportEntityType::Reaction, storage.get_all_reactions())?;
+    let all_fields = load_relation(ctx, ExportEntityType::Field, storage.get_all_fields())?;
+
+    for issue in issues.iter_mut() {
+        if let Some(deps) = all_deps.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.dependencies = deps.clone();
+        } else {
+            issue.dependencies.clear();
+        }
+        if let Some(labels) = all_labels.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.labels = labels.clone();
+        } else {
+            issue.labels.clear();
+        }
+        // Normalize labels for consistent round-trip hashing (matches import behavior)
+        if !issue.labels.is_empty() {
+            issue.labels.sort();
+            issue.labels.dedup();
+        }
+        if let Some(comments) = all_comments.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.comments = comments.clone();
+        } else {
+            issue.comments.clear();
+        }
+        if let Some(code_refs) = all_code_refs.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.code_refs = code_refs.clone();
+        } else {
+            issue.code_refs.clear();
+        }
+        if let Some(watchers) = all_watchers.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.watchers = watchers.clone();
+        } else {
+            issue.watchers.clear();
+        }
+        if let Some(reactions) = all_reactions.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.reactions = reactions.clone();
+        } else {
+            issue.reactions.clear();
+        }
+        if let Some(fields) = all_fields.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.fields = fields.clone();
+        } else {
+            issue.fields.clear();
+        }
+    }
+
+    Ok(())
+}
+
+/// Take the result of a batch relation query, routing a failure through the
+/// export error policy.
+fn load_relation<T>(
+    ctx: &mut ExportContext,
+    entity_type: ExportEntityType,
+    loaded: Result<HashMap<String, T>>,
+) -> Result<Option<HashMap<String, T>>> {
+    match loaded {
+        Ok(map) => Ok(Some(map)),
+        Err(err) => {
+            ctx.handle_error(ExportError::new(entity_type, "all", err.to_string()))?;
+            Ok(None)
+        }
+    }
+}
+
 /// Export issues from `SQLite` to JSONL format.
 ///
 /// This implements the classic beads export semantics:
@@ -1243,19 +1398,23 @@ pub fn export_to_jsonl_with_policy(
             output_path.to_path_buf()
         };
         if output_abs.starts_with(beads_dir) {
-            history::backup_before_export(beads_dir, &config.history, &output_abs)?;
+            for source in shard::jsonl_sources(&output_abs) {
+                history::backup_before_export(beads_dir, &config.history, &source)?;
+            }
         }
     }
 
     // Get all issues for export (sorted by ID, excludes ephemerals/wisps)
     let mut issues = storage.get_all_issues_for_export()?;
 
-    // Safety checks
-    if !config.force && output_path.exists() {
+    // Safety checks (against every file of the current layout)
+    if !config.force && shard::jsonl_exists(output_path) {
         let (jsonl_count, jsonl_ids) = analyze_jsonl(output_path)?;
+        let only_purged = !config.purged_ids.is_empty()
+            && jsonl_ids.iter().all(|id| config.purged_ids.contains(id));
 
         // Check 1: prevent exporting empty database over non-empty JSONL
-        if issues.is_empty() && jsonl_count > 0 {
+        if issues.is_empty() && jsonl_count > 0 && !only_purged {
             return Err(BeadsError::Config(format!(
                 "Refusing to export empty database over non-empty JSONL file.\n\
                  Database has 0 issues, JSONL has {jsonl_count} lines.\n\
@@ -1266,7 +1425,10 @@ pub fn export_to_jsonl_with_policy(
 
         // Check 2: prevent exporting stale database that would lose issues
         if !jsonl_ids.is_empty() {
-            let db_ids: HashSet<String> = issues.iter().map(|i| i.id.clone()).collect();
+            let mut db_ids: HashSet<String> = issues.iter().map(|i| i.id.clone()).collect();
+            // Old IDs of renamed issues are accounted for by the new ID.
+            db_ids.extend(storage.get_id_aliases()?.into_keys());
+            db_ids.extend(config.purged_ids.iter().cloned());
             let missing: Vec<_> = jsonl_ids.difference(&db_ids).collect();
 
             if !missing.is_empty() {
@@ -1309,62 +1471,7 @@ pub fn export_to_jsonl_with_policy(
     );
 
     // Populate dependencies and labels for all issues (batch queries to avoid N+1)
-    let all_deps = match storage.get_all_dependency_records() {
-        Ok(map) => Some(map),
-        Err(err) => {
-            ctx.handle_error(ExportError::new(
-                ExportEntityType::Dependency,
-                "all",
-                err.to_string(),
-            ))?;
-            None
-        }
-    };
-    let all_labels = match storage.get_all_labels() {
-        Ok(map) => Some(map),
-        Err(err) => {
-            ctx.handle_error(ExportError::new(
-                ExportEntityType::Label,
-                "all",
-                err.to_string(),
-            ))?;
-            None
-        }
-    };
-    let all_comments = match storage.get_all_comments() {
-        Ok(map) => Some(map),
-        Err(err) => {
-            ctx.handle_error(ExportError::new(
-                ExportEntityType::Comment,
-                "all",
-                err.to_string(),
-            ))?;
-            None
-        }
-    };
-
-    for issue in &mut issues {
-        if let Some(deps) = all_deps.as_ref().and_then(|map| map.get(&issue.id)) {
-            issue.dependencies = deps.clone();
-        } else {
-            issue.dependencies.clear();
-        }
-        if let Some(labels) = all_labels.as_ref().and_then(|map| map.get(&issue.id)) {
-            issue.labels = labels.clone();
-        } else {
-            issue.labels.clear();
-        }
-        // Normalize labels for consistent round-trip hashing (matches import behavior)
-        if !issue.labels.is_empty() {
-            issue.labels.sort();
-            issue.labels.dedup();
-        }
-        if let Some(comments) = all_comments.as_ref().and_then(|map| map.get(&issue.id)) {
-            issue.comments = comments.clone();
-        } else {
-            issue.comments.clear();
-        }
-    }
+    populate_export_relations(storage, &mut issues, &mut ctx)?;
 
     // Write to temp file for atomic rename
     let parent_dir = output_path.parent().ok_or_else(|| {
@@ -1374,43 +1481,70 @@ pub fn export_to_jsonl_with_policy(
     // Ensure parent directory exists
     fs::create_dir_all(parent_dir)?;
 
-    let temp_path = output_path.with_extension("jsonl.tmp");
-
-    // Validate temp file path (PC-4: temp files must be in same directory as target)
-    if let Some(ref beads_dir) = config.beads_dir {
-        validate_temp_file_path(
-            &temp_path,
-            output_path,
-            beads_dir,
-            config.allow_external_jsonl,
-        )?;
-        tracing::debug!(
-            temp_path = %temp_path.display(),
-            target_path = %output_path.display(),
-            "Temp file path validated"
-        );
-    }
-
-    let temp_file = File::create(&temp_path)?;
-    let mut writer = BufWriter::new(temp_file);
+    // One output file per target: the configured path, or one per status shard
+    let targets: Vec<(PathBuf, Option<Shard>)> = match config.shard {
+        ShardStrategy::None => vec![(output_path.to_path_buf(), None)],
+        ShardStrategy::Status => Shard::ALL
+            .iter()
+            .map(|&shard| (shard.path(output_path), Some(shard)))
+            .collect(),
+    };
 
-    // Write JSONL and compute hash
+    // Write JSONL and compute hash (shards are hashed in write order)
     let mut hasher = Sha256::new();
     let mut exported_ids = Vec::new();
     let mut skipped_tombstone_ids = Vec::new();
     let mut issue_hashes = Vec::new();
-
-    for issue in &issues {
-        // Skip expired tombstones
-        if issue.is_expired_tombstone(config.retention_days) {
-            skipped_tombstone_ids.push(issue.id.clone());
-            progress.inc(1);
-            continue;
+    let mut written: Vec<(PathBuf, &Path)> = Vec::with_capacity(targets.len());
+
+    for (target_path, shard) in &targets {
+        let shard = *shard;
+        let temp_path = target_path.with_extension("jsonl.tmp");
+
+        // Validate temp file path (PC-4: temp files must be in same directory as target)
+        if let Some(ref beads_dir) = config.beads_dir {
+            validate_temp_file_path(
+                &temp_path,
+                target_path,
+                beads_dir,
+                config.allow_external_jsonl,
+            )?;
+            tracing::debug!(
+                temp_path = %temp_path.display(),
+                target_path = %target_path.display(),
+                "Temp file path validated"
+            );
         }
 
-        let json = match serde_json::to_string(issue) {
-            Ok(json) => json,
-            Err(err) => {
+        let temp_file = File::create(&temp_path)?;
+        let mut writer = BufWriter::new(temp_file);
+        let mut target_count = 0;
+
+        for issue in issues
+            .iter()
+            .filter(|issue| shard.is_none_or(|shard| Shard::of(issue) == shard))
+        {
+            // Skip expired tombstones
+            if issue.is_expired_tombstone(config.retention_days) {
+                skipped_tombstone_ids.push(issue.id.clone());
+                progress.inc(1);
+                continue;
+            }
+
+            let json = match serde_json::to_string(issue) {
+                Ok(json) => json,
+                Err(err) => {
+                    ctx.handle_error(ExportError::new(
+                        ExportEntityType::Issue,
+                        issue.id.clone(),
+                        err.to_string(),
+                    ))?;
+                    progress.inc(1);
+                    continue;
+                }
+            };
+
+            if let Err(err) = writeln!(writer, "{json}") {
                 ctx.handle_error(ExportError::new(
                     ExportEntityType::Issue,
                     issue.id.clone(),
@@ -1419,81 +1553,85 @@ pub fn export_to_jsonl_with_policy(
                 progress.inc(1);
                 continue;
             }
-        };
 
-        if let Err(err) = writeln!(writer, "{json}") {
-            ctx.handle_error(ExportError::new(
-                ExportEntityType::Issue,
-                issue.id.clone(),
-                err.to_string(),
-            ))?;
+            hasher.update(json.as_bytes());
+            hasher.update(b"\n");
+
+            exported_ids.push(issue.id.clone());
+            issue_hashes.push((issue.id.clone(), export_hash(issue)));
+            target_count += 1;
+            report.issues_exported += 1;
+            report.dependencies_exported += issue.dependencies.len();
+            report.labels_exported += issue.labels.len();
+            report.comments_exported += issue.comments.len();
             progress.inc(1);
-            continue;
         }
 
-        hasher.update(json.as_bytes());
-        hasher.update(b"\n");
+        // Flush and sync
+        writer.flush()?;
+        writer
+            .into_inner()
+            .map_err(|e| BeadsError::Io(e.into_error()))?
+            .sync_all()?;
+
+        if let Some(ref beads_dir) = config.beads_dir {
+            require_safe_sync_overwrite_path(
+                &temp_path,
+                beads_dir,
+                config.allow_external_jsonl,
+                "rename temp file",
+            )?;
+            require_safe_sync_overwrite_path(
+                target_path,
+                beads_dir,
+                config.allow_external_jsonl,
+                "overwrite JSONL output",
+            )?;
+        }
 
-        exported_ids.push(issue.id.clone());
-        issue_hashes.push((
-            issue.id.clone(),
-            issue
-                .content_hash
-                .clone()
-                .unwrap_or_else(|| crate::util::content_hash(issue)),
-        ));
-        report.issues_exported += 1;
-        report.dependencies_exported += issue.dependencies.len();
-        report.labels_exported += issue.labels.len();
-        report.comments_exported += issue.comments.len();
-        progress.inc(1);
+        // Verify export integrity BEFORE the atomic rename so a count mismatch
+        // never overwrites the existing JSONL with a corrupted file.
+        let actual_count = analyze_jsonl_file(&temp_path, &mut HashSet::new())?;
+        if actual_count != target_count {
+            let _ = fs::remove_file(&temp_path);
+            for (written_temp, _) in &written {
+                let _ = fs::remove_file(written_temp);
+            }
+            return Err(BeadsError::Config(format!(
+                "Export verification failed: expected {} issues, JSONL has {} lines",
+                target_count, actual_count
+            )));
+        }
+
+        written.push((temp_path, target_path.as_path()));
     }
 
     progress.finish_with_message("Export complete");
 
-    // Flush and sync
-    writer.flush()?;
-    writer
-        .into_inner()
-        .map_err(|e| BeadsError::Io(e.into_error()))?
-        .sync_all()?;
+    // Atomic renames (only after every file passed verification)
+    for (temp_path, target_path) in written {
+        fs::rename(&temp_path, target_path)?;
 
-    if let Some(ref beads_dir) = config.beads_dir {
-        require_safe_sync_overwrite_path(
-            &temp_path,
-            beads_dir,
-            config.allow_external_jsonl,
-            "rename temp file",
-        )?;
-        require_safe_sync_overwrite_path(
-            output_path,
-            beads_dir,
-            config.allow_external_jsonl,
-            "overwrite JSONL output",
-        )?;
-    }
-
-    // Verify export integrity BEFORE the atomic rename so a count mismatch
-    // never overwrites the existing JSONL with a corrupted file.
-    let actual_count = count_issues_in_jsonl(&temp_path)?;
-    if actual_count != exported_ids.len() {
-        let _ = fs::remove_file(&temp_path);
-        return Err(BeadsError::Config(format!(
-            "Export verification failed: expected {} issues, JSONL has {} lines",
-            exported_ids.len(),
-            actual_count
-        )));
-    }
-
-    // Atomic rename (only after verification passes)
-    fs::rename(&temp_path, output_path)?;
-
-    // Set file permissions (0600)
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        let perms = std::fs::Permissions::from_mode(0o600);
-        let _ = fs::set_permissions(output_path, perms);
+        // Set file permissions (0600)
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let perms = std::fs::Permissions::from_mode(0o600);
+            let _ = fs::set_permissions(target_path, perms);
+        }
+    }
+
+    // Drop files from the other layout so readers never merge stale data
+    for stale_path in shard::superseded_paths(output_path, config.shard) {
+        if let Some(ref beads_dir) = config.beads_dir {
+            require_safe_sync_overwrite_path(
+                &stale_path,
+                beads_dir,
+                config.allow_external_jsonl,
+                "remove superseded JSONL",
+            )?;
+        }
+        fs::remove_file(&stale_path)?;
     }
 
     // Compute final hash
@@ -1540,57 +1678,7 @@ pub fn export_to_writer_with_policy<W: Write>(
     // Populate dependencies and labels
     let mut ctx = ExportContext::new(policy);
     let mut report = ExportReport::new(policy);
-    let all_deps = match storage.get_all_dependency_records() {
-        Ok(map) => Some(map),
-        Err(err) => {
-            ctx.handle_error(ExportError::new(
-                ExportEntityType::Dependency,
-                "all",
-                err.to_string(),
-            ))?;
-            None
-        }
-    };
-    let all_labels = match storage.get_all_labels() {
-        Ok(map) => Some(map),
-        Err(err) => {
-            ctx.handle_error(ExportError::new(
-                ExportEntityType::Label,
-                "all",
-                err.to_string(),
-            ))?;
-            None
-        }
-    };
-    let all_comments = match storage.get_all_comments() {
-        Ok(map) => Some(map),
-        Err(err) => {
-            ctx.handle_error(ExportError::new(
-                ExportEntityType::Comment,
-                "all",
-                err.to_string(),
-            ))?;
-            None
-        }
-    };
-
-    for issue in &mut issues {
-        if let Some(deps) = all_deps.as_ref().and_then(|map| map.get(&issue.id)) {
-            issue.dependencies = deps.clone();
-        } else {
-            issue.dependencies.clear();
-        }
-        if let Some(labels) = all_labels.as_ref().and_then(|map| map.get(&issue.id)) {
-            issue.labels = labels.clone();
-        } else {
-            issue.labels.clear();
-        }
-        if let Some(comments) = all_comments.as_ref().and_then(|map| map.get(&issue.id)) {
-            issue.comments = comments.clone();
-        } else {
-            issue.comments.clear();
-        }
-    }
+    populate_export_relations(storage, &mut issues, &mut ctx)?;
 
     let mut hasher = Sha256::new();
     let mut exported_ids = Vec::new();
@@ -1621,13 +1709,7 @@ pub fn export_to_writer_with_policy<W: Write>(
         hasher.update(b"\n");
 
         exported_ids.push(issue.id.clone());
-        issue_hashes.push((
-            issue.id.clone(),
-            issue
-                .content_hash
-                .clone()
-                .unwrap_or_else(|| crate::util::content_hash(issue)),
-        ));
+        issue_hashes.push((issue.id.clone(), export_hash(issue)));
         report.issues_exported += 1;
         report.dependencies_exported += issue.dependencies.len();
         report.labels_exported += issue.labels.len();
@@ -1676,10 +1758,14 @@ pub fn compute_staleness(storage: &SqliteStorage, jsonl_path: &Path) -> Result<S
     let dirty_count = storage.get_dirty_issue_count()?;
     let last_import_time = storage.get_metadata(METADATA_LAST_IMPORT_TIME)?;
     let jsonl_content_hash = storage.get_metadata(METADATA_JSONL_CONTENT_HASH)?;
-    let jsonl_exists = jsonl_path.exists();
+    let jsonl_exists = shard::jsonl_exists(jsonl_path);
 
     let (jsonl_newer, db_newer) = if jsonl_exists {
-        let jsonl_mtime = fs::symlink_metadata(jsonl_path)?.modified()?;
+        // Newest mtime across the base file and any shards
+        let mut jsonl_mtime = std::time::SystemTime::UNIX_EPOCH;
+        for source in shard::jsonl_sources(jsonl_path) {
+            jsonl_mtime = jsonl_mtime.max(fs::symlink_metadata(source)?.modified()?);
+        }
 
         // JSONL is newer if it was modified after last import
         // If metadata is missing or invalid, assume JSONL is newer (safe default)
@@ -1787,6 +1873,15 @@ pub fn auto_import_if_stale(
         );
     }
 
+    for remap in &result.id_remaps {
+        tracing::warn!(
+            old_id = %remap.old_id,
+            new_id = %remap.new_id,
+            title = %remap.title,
+            "Auto-import moved an issue off a colliding ID"
+        );
+    }
+
     Ok(AutoImportResult {
         attempted: true,
         imported_count: result.imported_count,
@@ -1794,6 +1889,80 @@ pub fn auto_import_if_stale(
     })
 }
 
+/// Hash of an issue's exported JSONL record, ignoring `updated_at` and the
+/// derived `content_hash` field.
+///
+/// This is what `export_hashes` records. Unlike [`crate::util::content_hash`]
+/// it also covers labels, dependencies, comments and every other exported
+/// field, so it changes exactly when re-exporting would change the record.
+#[must_use]
+pub fn export_hash(issue: &Issue) -> String {
+    let mut value = serde_json::to_value(issue).unwrap_or_default();
+    if let Some(fields) = value.as_object_mut() {
+        fields.remove("updated_at");
+        fields.remove("content_hash");
+    }
+    format!("{:x}", Sha256::digest(value.to_string().as_bytes()))
+}
+
+/// The issues an export would write right now, with relations populated.
+///
+/// # Errors
+///
+/// Returns an error if the database queries fail.
+pub fn issues_for_export(storage: &SqliteStorage) -> Result<Vec<Issue>> {
+    let mut issues = storage.get_all_issues_for_export()?;
+    populate_export_relations(
+        storage,
+        &mut issues,
+        &mut ExportContext::new(ExportErrorPolicy::Strict),
+    )?;
+    Ok(issues)
+}
+
+/// Dirty issues whose export record differs from the one last exported.
+///
+/// A dirty issue that is no longer exportable (hard-deleted, or now
+/// ephemeral) still needs an export if it was exported before, so the JSONL
+/// drops it. An empty result means a flush would rewrite identical content.
+///
+/// # Errors
+///
+/// Returns an error if the database queries fail.
+pub fn dirty_issues_needing_export(
+    storage: &SqliteStorage,
+    dirty_ids: &[String],
+) -> Result<Vec<String>> {
+    let dirty: HashSet<&str> = dirty_ids.iter().map(String::as_str).collect();
+    let mut issues: Vec<Issue> = storage
+        .get_all_issues_for_export()?
+        .into_iter()
+        .filter(|issue| dirty.contains(issue.id.as_str()))
+        .collect();
+    populate_export_relations(
+        storage,
+        &mut issues,
+        &mut ExportContext::new(ExportErrorPolicy::Strict),
+    )?;
+
+    let exportable: HashSet<&str> = issues.iter().map(|issue| issue.id.as_str()).collect();
+    let mut needing = Vec::new();
+    for issue in &issues {
+        let stored = storage.get_export_hash(&issue.id)?.map(|(hash, _)| hash);
+        if stored.as_deref() != Some(export_hash(issue).as_str()) {
+            needing.push(issue.id.clone());
+        }
+    }
+    for id in dirty_ids {
+        if !exportable.contains(id.as_str()) && storage.get_export_hash(id)?.is_some() {
+            needing.push(id.clone());
+        }
+    }
+    needing.sort();
+    needing.dedup();
+    Ok(needing)
+}
+
 /// Finalize an export by updating metadata, clearing dirty flags, and recording export hashes.
 ///
 /// This should be called after a successful export to the default JSONL path.
@@ -1865,6 +2034,20 @@ pub struct AutoFlushResult {
 /// Returns an error if the export fails.
 #[tracing::instrument(skip(storage))]
 pub fn auto_flush(storage: &mut SqliteStorage, beads_dir: &Path) -> Result<AutoFlushResult> {
+    auto_flush_with_shard(storage, beads_dir, ShardStrategy::None)
+}
+
+/// Auto-flush using the `sync.shard` layout for the written JSONL.
+///
+/// # Errors
+///
+/// Same as [`auto_flush`].
+#[tracing::instrument(skip(storage))]
+pub fn auto_flush_with_shard(
+    storage: &mut SqliteStorage,
+    beads_dir: &Path,
+    shard: ShardStrategy,
+) -> Result<AutoFlushResult> {
     // Check for dirty issues first
     let dirty_count = storage.get_dirty_issue_count()?;
     if dirty_count == 0 {
@@ -1881,6 +2064,7 @@ pub fn auto_flush(storage: &mut SqliteStorage, beads_dir: &Path) -> Result<AutoF
     let export_config = ExportConfig {
         force: false,
         beads_dir: Some(beads_dir.to_path_buf()),
+        shard,
         ..Default::default()
     };
 
@@ -1903,28 +2087,37 @@ pub fn auto_flush(storage: &mut SqliteStorage, beads_dir: &Path) -> Result<AutoF
     })
 }
 
-/// Read all issues from a JSONL file.
+/// Read all issues from a JSONL file (or every file of a sharded export).
 ///
 /// # Errors
 ///
 /// Returns an error if the file cannot be read or contains invalid JSON.
 pub fn read_issues_from_jsonl(path: &Path) -> Result<Vec<Issue>> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
     let mut issues = Vec::new();
+    for source in shard::jsonl_sources(path) {
+        read_issues_into(&source, BufReader::new(File::open(&source)?), &mut issues)?;
+    }
+    Ok(issues)
+}
 
+/// Parse every non-blank line of one JSONL file into `issues`.
+fn read_issues_into<R: BufRead>(path: &Path, reader: R, issues: &mut Vec<Issue>) -> Result<()> {
     for (line_num, line) in reader.lines().enumerate() {
         let line = line?;
         if line.trim().is_empty() {
             continue;
         }
         let issue: Issue = serde_json::from_str(&line).map_err(|e| {
-            BeadsError::Config(format!("Invalid JSON at line {}: {}", line_num + 1, e))
+            BeadsError::Config(format!(
+                "Invalid JSON at line {} of {}: {}",
+                line_num + 1,
+                path.display(),
+                e
+            ))
         })?;
         issues.push(issue);
     }
-
-    Ok(issues)
+    Ok(())
 }
 
 // ===== 4-Phase Collision Detection =====
@@ -2100,6 +2293,139 @@ fn normalize_issue(issue: &mut Issue) {
     }
 }
 
+/// Give distinct issues that share an ID their own IDs.
+///
+/// Claimants of an ID are the database row plus every incoming record with
+/// that ID, grouped by `created_at` (millisecond precision): one group is one
+/// issue. The oldest group keeps the ID. Each newer group is re-hashed once;
+/// incoming records move with their own dependency and comment rows, and a
+/// database issue is renamed with every reference to it. References from
+/// other incoming issues are ambiguous and keep pointing at the oldest.
+///
+/// Tombstones and ephemerals never take part, so tombstone protection still
+/// applies to a deleted issue's ID.
+fn resolve_id_collisions(
+    storage: &mut SqliteStorage,
+    issues: &mut [Issue],
+) -> Result<Vec<IdRemap>> {
+    use crate::util::id::{IdConfig, IdGenerator, parse_id};
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+
+    let claims =
+        |issue: &Issue| !issue.ephemeral && issue.status != crate::model::Status::Tombstone;
+
+    let mut by_id: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (idx, issue) in issues.iter().enumerate() {
+        if claims(issue) {
+            by_id.entry(issue.id.clone()).or_default().push(idx);
+        }
+    }
+
+    let mut taken: HashSet<String> = issues.iter().map(|i| i.id.clone()).collect();
+    let mut remaps = Vec::new();
+    let mut db_renames = Vec::new();
+
+    for (id, indices) in by_id {
+        let existing = storage.get_issue(&id)?.filter(|issue| claims(issue));
+
+        // created_at (ms) -> (representative, incoming indices, is database row)
+        let mut groups: BTreeMap<i64, (Issue, Vec<usize>, bool)> = BTreeMap::new();
+        if let Some(existing) = existing {
+            groups.insert(
+                existing.created_at.timestamp_millis(),
+                (existing, Vec::new(), true),
+            );
+        }
+        for idx in indices {
+            groups
+                .entry(issues[idx].created_at.timestamp_millis())
+                .or_insert_with(|| (issues[idx].clone(), Vec::new(), false))
+                .1
+                .push(idx);
+        }
+        if groups.len() < 2 {
+            continue;
+        }
+
+        let Ok(parsed) = parse_id(&id) else {
+            tracing::warn!(id = %id, "Distinct issues share an unparseable ID; not remapping");
+            continue;
+        };
+
+        for (representative, members, in_db) in groups.into_values().skip(1) {
+            let lookup_error = RefCell::new(None);
+            let is_taken = |candidate: &str| {
+                taken.contains(candidate)
+                    || storage.id_exists(candidate).unwrap_or_else(|err| {
+                        lookup_error.borrow_mut().get_or_insert(err);
+                        true
+                    })
+            };
+            let new_id =
+                if let Some((parent, _)) = id.rsplit_once('.').filter(|_| !parsed.is_root()) {
+                    // Child IDs stay under their parent, taking the next free number.
+                    let last = parsed.child_path.last().copied().unwrap_or(0);
+                    (last + 1..)
+                        .map(|n| format!("{parent}.{n}"))
+                        .find(|candidate| !is_taken(candidate))
+                        .unwrap_or_else(|| unreachable!("child numbers are unbounded"))
+                } else {
+                    let hash_length = parsed.hash.len();
+                    let generator = IdGenerator::new(IdConfig {
+                        prefix: parsed.prefix.clone(),
+                        min_hash_length: hash_length,
+                        max_hash_length: hash_length.max(IdConfig::default().max_hash_length),
+                        ..IdConfig::default()
+                    });
+                    generator.generate(
+                        &representative.title,
+                        representative.description.as_deref(),
+                        representative.created_by.as_deref(),
+                        representative.created_at,
+                        0,
+                        is_taken,
+                    )
+                };
+            if let Some(err) = lookup_error.into_inner() {
+                return Err(err);
+            }
+
+            tracing::info!(old_id = %id, new_id = %new_id, existing = in_db, "Remapping colliding issue ID");
+            for idx in members {
+                let issue = &mut issues[idx];
+                issue.id.clone_from(&new_id);
+                for dep in &mut issue.dependencies {
+                    if dep.issue_id == id {
+                        dep.issue_id.clone_from(&new_id);
+                    }
+                }
+                for comment in &mut issue.comments {
+                    if comment.issue_id == id {
+                        comment.issue_id.clone_from(&new_id);
+                    }
+                }
+            }
+            if in_db {
+                db_renames.push((id.clone(), new_id.clone()));
+            }
+            taken.insert(new_id.clone());
+            remaps.push(IdRemap {
+                old_id: id.clone(),
+                new_id,
+                title: representative.title,
+                existing: in_db,
+            });
+        }
+    }
+
+    if !db_renames.is_empty() {
+        storage.rename_issue_ids(&db_renames, "import", "ID collision resolved on import")?;
+    }
+
+    Ok(remaps)
+}
+
 /// Import issues from a JSONL file.
 ///
 /// Implements classic bd import semantics:
@@ -2108,13 +2434,16 @@ fn normalize_issue(issue: &mut Issue) {
 /// 2. Parse JSONL with 2MB buffer
 /// 3. Normalize issues (recompute `content_hash`, set defaults)
 /// 4. Prefix validation (optional)
-/// 5. 4-phase collision detection
-/// 6. Tombstone protection
-/// 7. Orphan handling
-/// 8. Create/update issues
-/// 9. Sync deps/labels/comments
-/// 10. Refresh blocked cache
-/// 11. Update metadata
+/// 5. ID collision resolution (see [`ImportResult::id_remaps`])
+/// 6. 4-phase collision detection
+/// 7. Tombstone protection
+/// 8. Orphan handling
+/// 9. Create/update issues
+/// 10. Sync deps/labels/comments
+/// 11. Refresh blocked cache
+/// 12. Update metadata
+///
+/// Steps 5-10 run as one bulk transaction (see [`SqliteStorage::bulk_import`]).
 ///
 /// # Errors
 ///
@@ -2124,7 +2453,6 @@ fn normalize_issue(issue: &mut Issue) {
 /// - File cannot be read
 /// - Prefix validation fails
 /// - Database operations fail
-#[allow(clippy::too_many_lines)]
 #[tracing::instrument(skip(storage, config), fields(input = %input_path.display()))]
 pub fn import_from_jsonl(
     storage: &mut SqliteStorage,
@@ -2132,6 +2460,26 @@ pub fn import_from_jsonl(
     config: &ImportConfig,
     expected_prefix: Option<&str>,
 ) -> Result<ImportResult> {
+    import_from_jsonl_with_progress(storage, input_path, config, expected_prefix, |_| {})
+}
+
+/// Import issues from a JSONL file, calling `on_progress` after each issue
+/// is scanned and after each issue is written.
+///
+/// # Errors
+///
+/// Same as [`import_from_jsonl`].
+#[allow(clippy::too_many_lines)]
+pub fn import_from_jsonl_with_progress<F>(
+    storage: &mut SqliteStorage,
+    input_path: &Path,
+    config: &ImportConfig,
+    expected_prefix: Option<&str>,
+    mut on_progress: F,
+) -> Result<ImportResult>
+where
+    F: FnMut(ImportProgress),
+{
     use crate::util::content_hash;
 
     // Step 0: Path validation (PC-1, PC-2, PC-3, NGI-3) - BEFORE any file operations
@@ -2145,24 +2493,16 @@ pub fn import_from_jsonl(
         );
     }
 
-    // Step 1: Conflict marker scan
+    // Step 1: Conflict marker scan (covers every shard of a sharded export)
     ensure_no_conflict_markers(input_path)?;
 
     // Step 2: Parse JSONL with 2MB buffer
     let spinner = create_spinner("Reading JSONL", config.show_progress);
-    let file = File::open(input_path)?;
-    let reader = BufReader::with_capacity(2 * 1024 * 1024, file);
     let mut issues = Vec::new();
-
-    for (line_num, line) in reader.lines().enumerate() {
-        let line = line?;
-        if line.trim().is_empty() {
-            continue;
-        }
-        let issue: Issue = serde_json::from_str(&line).map_err(|e| {
-            BeadsError::Config(format!("Invalid JSON at line {}: {}", line_num + 1, e))
-        })?;
-        issues.push(issue);
+    for source in shard::jsonl_sources(input_path) {
+        let file = File::open(&source)?;
+        let reader = BufReader::with_capacity(2 * 1024 * 1024, file);
+        read_issues_into(&source, reader, &mut issues)?;
     }
     spinner.finish_with_message("Read JSONL");
 
@@ -2286,122 +2626,148 @@ pub fn import_from_jsonl(
         }
     }
 
-    // Clear export hashes before importing new data.
-    storage.clear_all_export_hashes()?;
-
-    // Phase 1: Scan and Resolve IDs
-    let mut seen_external_refs: HashSet<String> = HashSet::new();
-    let mut renames: std::collections::HashMap<String, String> = std::collections::HashMap::new();
-    let mut import_ops = Vec::new();
-    let mut new_export_hashes = Vec::new();
-
-    let progress =
-        create_progress_bar(issues.len() as u64, "Scanning issues", config.show_progress);
+    // Steps 5-10 share one transaction, so a failure after collision
+    // renames leaves the database untouched.
+    storage.bulk_import(|storage| {
+        // Step 5: Distinct issues that ended up with the same ID (e.g. created
+        // on two branches) each get their own before collision detection.
+        result.id_remaps = resolve_id_collisions(storage, &mut issues)?;
+
+        // Clear export hashes before importing new data.
+        storage.clear_all_export_hashes()?;
+
+        // Phase 1: Scan and Resolve IDs
+        let mut seen_external_refs: HashSet<String> = HashSet::new();
+        let mut renames: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        let mut import_ops = Vec::new();
+        let mut new_export_hashes = Vec::new();
+
+        let progress =
+            create_progress_bar(issues.len() as u64, "Scanning issues", config.show_progress);
+        let scan_total = issues.len();
+
+        for (scanned, issue) in issues.iter().enumerate() {
+            on_progress(ImportProgress {
+                stage: ImportStage::Scan,
+                done: scanned + 1,
+                total: scan_total,
+            });
 
-    for issue in &issues {
-        // Skip ephemerals during import (they shouldn't be in JSONL anyway)
-        if issue.ephemeral {
-            result.skipped_count += 1;
-            progress.inc(1);
-            continue;
-        }
+            // Skip ephemerals during import (they shouldn't be in JSONL anyway)
+            if issue.ephemeral {
+                result.skipped_count += 1;
+                progress.inc(1);
+                continue;
+            }
 
-        let mut effective_issue = issue.clone();
-
-        // Handle external ref duplicates before collision detection.
-        // Collision detection requires unique external_refs, so duplicates must
-        // be cleared. We preserve the original value in the notes field and warn
-        // the user instead of losing it silently.
-        if let Some(ref ext_ref) = issue.external_ref {
-            if seen_external_refs.contains(ext_ref) {
-                if config.clear_duplicate_external_refs {
-                    tracing::warn!(
-                        id = %issue.id,
-                        external_ref = %ext_ref,
-                        "Duplicate external_ref cleared; original preserved in notes"
-                    );
-                    let note = format!("Duplicate external_ref cleared during import: {ext_ref}");
-                    effective_issue.notes = Some(match effective_issue.notes {
-                        Some(existing) => format!("{existing}\n{note}"),
-                        None => note,
-                    });
-                    effective_issue.external_ref = None;
-                    effective_issue.content_hash = Some(content_hash(&effective_issue));
+            let mut effective_issue = issue.clone();
+
+            // Handle external ref duplicates before collision detection.
+            // Collision detection requires unique external_refs, so duplicates must
+            // be cleared. We preserve the original value in the notes field and warn
+            // the user instead of losing it silently.
+            if let Some(ref ext_ref) = issue.external_ref {
+                if seen_external_refs.contains(ext_ref) {
+                    if config.clear_duplicate_external_refs {
+                        tracing::warn!(
+                            id = %issue.id,
+                            external_ref = %ext_ref,
+                            "Duplicate external_ref cleared; original preserved in notes"
+                        );
+                        let note =
+                            format!("Duplicate external_ref cleared during import: {ext_ref}");
+                        effective_issue.notes = Some(match effective_issue.notes {
+                            Some(existing) => format!("{existing}\n{note}"),
+                            None => note,
+                        });
+                        effective_issue.external_ref = None;
+                        effective_issue.content_hash = Some(content_hash(&effective_issue));
+                    } else {
+                        progress.inc(1);
+                        return Err(BeadsError::Config(format!(
+                            "Duplicate external_ref: {ext_ref}"
+                        )));
+                    }
                 } else {
-                    progress.inc(1);
-                    return Err(BeadsError::Config(format!(
-                        "Duplicate external_ref: {ext_ref}"
-                    )));
+                    seen_external_refs.insert(ext_ref.clone());
                 }
-            } else {
-                seen_external_refs.insert(ext_ref.clone());
             }
-        }
 
-        // Compute content hash for collision detection
-        let computed_hash = content_hash(&effective_issue);
+            // Compute content hash for collision detection
+            let computed_hash = content_hash(&effective_issue);
 
-        // Detect collision
-        let collision = detect_collision(&effective_issue, storage, &computed_hash)?;
+            // Detect collision
+            let collision = detect_collision(&effective_issue, storage, &computed_hash)?;
 
-        // Determine action
-        let action = determine_action(&collision, &effective_issue, storage, config.force_upsert)?;
+            // Determine action
+            let action =
+                determine_action(&collision, &effective_issue, storage, config.force_upsert)?;
 
-        // Determine target ID and record mapping
-        let target_id = match &collision {
-            CollisionResult::Match { existing_id, .. } => existing_id.clone(),
-            CollisionResult::NewIssue => effective_issue.id.clone(),
-        };
-
-        if target_id != effective_issue.id {
-            renames.insert(effective_issue.id.clone(), target_id.clone());
-        }
-
-        // Collect hash for export_hashes table
-        new_export_hashes.push((target_id, computed_hash));
+            // Determine target ID and record mapping
+            let target_id = match &collision {
+                CollisionResult::Match { existing_id, .. } => existing_id.clone(),
+                CollisionResult::NewIssue => effective_issue.id.clone(),
+            };
 
-        import_ops.push((effective_issue, action));
-        progress.inc(1);
-    }
-    progress.finish_with_message("Scan complete");
-
-    // Phase 2: Remap Dependencies
-    if !renames.is_empty() {
-        for (issue, _) in &mut import_ops {
-            // Update issue ID if it was remapped (e.g. collision with existing issue)
-            if let Some(new_id) = renames.get(&issue.id) {
-                issue.id = new_id.clone();
+            if target_id != effective_issue.id {
+                renames.insert(effective_issue.id.clone(), target_id.clone());
             }
 
-            // Remap dependencies to point to the resolved IDs
-            for dep in &mut issue.dependencies {
-                if let Some(new_target) = renames.get(&dep.depends_on_id) {
-                    dep.depends_on_id = new_target.clone();
+            // Collect hash for export_hashes table
+            new_export_hashes.push((target_id, export_hash(&effective_issue)));
+
+            import_ops.push((effective_issue, action));
+            progress.inc(1);
+        }
+        progress.finish_with_message("Scan complete");
+
+        // Phase 2: Remap Dependencies
+        if !renames.is_empty() {
+            for (issue, _) in &mut import_ops {
+                // Update issue ID if it was remapped (e.g. collision with existing issue)
+                if let Some(new_id) = renames.get(&issue.id) {
+                    issue.id = new_id.clone();
                 }
-                if let Some(new_source) = renames.get(&dep.issue_id) {
-                    dep.issue_id = new_source.clone();
+
+                // Remap dependencies to point to the resolved IDs
+                for dep in &mut issue.dependencies {
+                    if let Some(new_target) = renames.get(&dep.depends_on_id) {
+                        dep.depends_on_id = new_target.clone();
+                    }
+                    if let Some(new_source) = renames.get(&dep.issue_id) {
+                        dep.issue_id = new_source.clone();
+                    }
                 }
             }
         }
-    }
 
-    // Phase 3: Execute Actions
-    let progress = create_progress_bar(
-        import_ops.len() as u64,
-        "Importing issues",
-        config.show_progress,
-    );
+        // Phase 3: Execute Actions
+        let progress = create_progress_bar(
+            import_ops.len() as u64,
+            "Importing issues",
+            config.show_progress,
+        );
 
-    for (issue, action) in import_ops {
-        process_import_action(storage, &action, &issue, &mut result)?;
-        progress.inc(1);
-    }
-    progress.finish_with_message("Import complete");
+        let write_total = import_ops.len();
 
-    // Restore export hashes for imported issues
-    if !new_export_hashes.is_empty() {
-        storage.set_export_hashes(&new_export_hashes)?;
-    }
+        for (written, (issue, action)) in import_ops.into_iter().enumerate() {
+            process_import_action(storage, &action, &issue, &mut result)?;
+            progress.inc(1);
+            on_progress(ImportProgress {
+                stage: ImportStage::Write,
+                done: written + 1,
+                total: write_total,
+            });
+        }
+
+        // Restore export hashes for imported issues
+        if !new_export_hashes.is_empty() {
+            storage.set_export_hashes(&new_export_hashes)?;
+        }
+        progress.finish_with_message("Import complete");
+        Ok(())
+    })?;
 
     // Step 10: Refresh blocked cache
     storage.rebuild_blocked_cache(true)?;
@@ -2453,7 +2819,8 @@ fn process_import_action(
     Ok(())
 }
 
-/// Sync labels, dependencies, and comments for an imported issue.
+/// Sync labels, dependencies, comments, code refs, watchers, reactions, and
+/// custom fields for an imported issue.
 fn sync_issue_relations(storage: &mut SqliteStorage, issue: &Issue) -> Result<()> {
     // Sync labels
     storage.sync_labels_for_import(&issue.id, &issue.labels)?;
@@ -2464,29 +2831,46 @@ fn sync_issue_relations(storage: &mut SqliteStorage, issue: &Issue) -> Result<()
     // Sync comments
     storage.sync_comments_for_import(&issue.id, &issue.comments)?;
 
+    // Sync code refs
+    storage.sync_code_refs_for_import(&issue.id, &issue.code_refs)?;
+
+    // Sync watchers
+    storage.sync_watchers_for_import(&issue.id, &issue.watchers)?;
+
+    // Sync reactions
+    storage.sync_reactions_for_import(&issue.id, &issue.reactions)?;
+
+    // Sync custom fields
+    storage.sync_fields_for_import(&issue.id, &issue.fields)?;
+
     Ok(())
 }
 
 /// Finalize an import by computing the content hash of the imported file.
 ///
+/// For a sharded export the shards are hashed in write order, matching the
+/// hash recorded by the export.
+///
 /// # Errors
 ///
 /// Returns an error if the file cannot be read.
 pub fn compute_jsonl_hash(path: &Path) -> Result<String> {
-    let file = File::open(path)?;
-    let mut reader = BufReader::new(file);
     let mut hasher = Sha256::new();
     let mut line_buf = String::new();
-    loop {
-        line_buf.clear();
-        let bytes = reader.read_line(&mut line_buf)?;
-        if bytes == 0 {
-            break;
-        }
+    for source in shard::jsonl_sources(path) {
+        let file = File::open(source)?;
+        let mut reader = BufReader::new(file);
+        loop {
+            line_buf.clear();
+            let bytes = reader.read_line(&mut line_buf)?;
+            if bytes == 0 {
+                break;
+            }
 
-        let trimmed = line_buf.trim_end_matches(['\n', '\r']);
-        hasher.update(trimmed.as_bytes());
-        hasher.update(b"\n");
+            let trimmed = line_buf.trim_end_matches(['\n', '\r']);
+            hasher.update(trimmed.as_bytes());
+            hasher.update(b"\n");
+        }
     }
 
     Ok(format!("{:x}", hasher.finalize()))
@@ -2909,9 +3293,10 @@ pub fn load_base_snapshot(jsonl_dir: &Path) -> Result<std::collections::HashMap<
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::{Issue, IssueType, Priority, Status};
+    use crate::model::{Comment, Issue, IssueType, Priority, Status};
     use chrono::Utc;
     use indicatif::{ProgressBar, ProgressStyle};
+    use std::collections::BTreeMap;
     use std::io::{self, Write};
     use tempfile::TempDir;
 
@@ -2956,6 +3341,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -3001,6 +3390,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -3129,6 +3522,104 @@ mod tests {
         assert_eq!(read_back[1].id, "bd-002");
     }
 
+    #[test]
+    fn test_export_import_roundtrips_reactions() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("issues.jsonl");
+
+        storage
+            .create_issue(&make_test_issue("bd-001", "Reacted"), "test")
+            .unwrap();
+        let comment = storage.add_comment("bd-001", "alice", "Ready").unwrap();
+        storage.add_reaction("bd-001", None, ":+1:", "bob").unwrap();
+        storage
+            .add_reaction("bd-001", Some(comment.id), ":eyes:", "carol")
+            .unwrap();
+        export_to_jsonl(&storage, &output_path, &ExportConfig::default()).unwrap();
+
+        let mut fresh = SqliteStorage::open_memory().unwrap();
+        import_from_jsonl(
+            &mut fresh,
+            &output_path,
+            &ImportConfig::default(),
+            Some("bd-"),
+        )
+        .unwrap();
+        let reactions = fresh.get_reactions("bd-001").unwrap();
+        assert_eq!(reactions.len(), 2);
+        assert!(
+            reactions
+                .iter()
+                .any(|r| r.comment_id == Some(comment.id) && r.actor == "carol")
+        );
+    }
+
+    #[test]
+    fn test_export_status_shards_roundtrip() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("issues.jsonl");
+        let open_path = Shard::Open.path(&output_path);
+        let closed_path = Shard::Closed.path(&output_path);
+
+        let open = make_test_issue("bd-001", "Open issue");
+        let mut closed = make_test_issue("bd-002", "Closed issue");
+        closed.status = Status::Closed;
+        closed.closed_at = Some(Utc::now());
+        storage.create_issue(&open, "test").unwrap();
+        storage.create_issue(&closed, "test").unwrap();
+
+        // A stale single-file export is replaced by the shards
+        fs::write(&output_path, "").unwrap();
+        let config = ExportConfig {
+            shard: ShardStrategy::Status,
+            ..Default::default()
+        };
+        let result = export_to_jsonl(&storage, &output_path, &config).unwrap();
+
+        assert_eq!(result.exported_count, 2);
+        assert!(!output_path.exists());
+        assert_eq!(
+            shard::jsonl_sources(&output_path),
+            vec![open_path.clone(), closed_path.clone()]
+        );
+        let ids = |path: &Path| -> Vec<String> {
+            let mut issues = Vec::new();
+            read_issues_into(path, BufReader::new(File::open(path).unwrap()), &mut issues).unwrap();
+            issues.into_iter().map(|issue| issue.id).collect()
+        };
+        assert_eq!(ids(&open_path), vec!["bd-001"]);
+        assert_eq!(ids(&closed_path), vec!["bd-002"]);
+        assert_eq!(
+            compute_jsonl_hash(&output_path).unwrap(),
+            result.content_hash
+        );
+        assert!(shard::check_consistency(&output_path).unwrap().is_empty());
+
+        // Import reads every shard
+        let mut fresh = SqliteStorage::open_memory().unwrap();
+        let imported = import_from_jsonl(
+            &mut fresh,
+            &output_path,
+            &ImportConfig::default(),
+            Some("bd-"),
+        )
+        .unwrap();
+        assert_eq!(imported.imported_count, 2);
+        assert_eq!(
+            fresh.get_issue("bd-002").unwrap().unwrap().status,
+            Status::Closed
+        );
+
+        // Switching back to a single file removes the shards
+        export_to_jsonl(&storage, &output_path, &ExportConfig::default()).unwrap();
+        assert!(output_path.exists());
+        assert!(!open_path.exists());
+        assert!(!closed_path.exists());
+        assert_eq!(read_issues_from_jsonl(&output_path).unwrap().len(), 2);
+    }
+
     #[test]
     fn test_safety_guard_empty_over_nonempty() {
         let storage = SqliteStorage::open_memory().unwrap();
@@ -3339,6 +3830,66 @@ mod tests {
         assert!(!issue.content_hash.as_ref().unwrap().is_empty());
     }
 
+    #[test]
+    fn test_import_with_progress_reports_each_stage() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("issues.jsonl");
+
+        let blocker = make_test_issue("test-001", "Blocker");
+        let mut blocked = make_test_issue("test-002", "Blocked");
+        blocked.labels = vec!["backend".to_string()];
+        blocked.dependencies = vec![crate::model::Dependency {
+            issue_id: "test-002".to_string(),
+            depends_on_id: "test-001".to_string(),
+            dep_type: crate::model::DependencyType::Blocks,
+            created_at: Utc::now(),
+            created_by: None,
+            metadata: None,
+            thread_id: None,
+        }];
+        let lines = [&blocker, &blocked]
+            .iter()
+            .map(|issue| serde_json::to_string(issue).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        fs::write(&path, format!("{lines}\n")).unwrap();
+
+        let mut events = Vec::new();
+        let result = import_from_jsonl_with_progress(
+            &mut storage,
+            &path,
+            &ImportConfig::default(),
+            Some("test-"),
+            |progress| events.push(progress),
+        )
+        .unwrap();
+
+        assert_eq!(result.imported_count, 2);
+        let stages: Vec<_> = events.iter().map(|p| (p.stage, p.done, p.total)).collect();
+        assert_eq!(
+            stages,
+            vec![
+                (ImportStage::Scan, 1, 2),
+                (ImportStage::Scan, 2, 2),
+                (ImportStage::Write, 1, 2),
+                (ImportStage::Write, 2, 2),
+            ]
+        );
+        assert_eq!(storage.get_labels("test-002").unwrap(), vec!["backend"]);
+        assert_eq!(
+            storage.get_dependencies("test-002").unwrap(),
+            vec!["test-001"]
+        );
+        let blocked_ids: Vec<String> = storage
+            .get_blocked_issues()
+            .unwrap()
+            .into_iter()
+            .map(|(issue, _)| issue.id)
+            .collect();
+        assert_eq!(blocked_ids, vec!["test-002"]);
+    }
+
     #[test]
     fn test_import_collision_by_id_updates_newer() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -3430,6 +3981,118 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_import_remaps_distinct_issues_sharing_an_id() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("issues.jsonl");
+
+        // Created locally first; a different issue with the same ID arrives later
+        let local = make_issue_at("bd-abc", "Local", fixed_time(100));
+        storage.create_issue(&local, "test").unwrap();
+        storage
+            .add_comment("bd-abc", "test", "local comment")
+            .unwrap();
+
+        let mut remote = make_issue_at("bd-abc", "Remote", fixed_time(300));
+        remote.comments.push(Comment {
+            id: 1,
+            issue_id: "bd-abc".to_string(),
+            author: "remote".to_string(),
+            body: "remote comment".to_string(),
+            created_at: fixed_time(300),
+            updated_at: None,
+            deleted: false,
+        });
+        // The local issue as exported on this branch, merged into the same file
+        let mut exported_local = local.clone();
+        exported_local.title = "Local (edited)".to_string();
+        exported_local.updated_at = fixed_time(400);
+        let lines = [&remote, &exported_local]
+            .iter()
+            .map(|issue| serde_json::to_string(issue).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        fs::write(&path, format!("{lines}\n")).unwrap();
+
+        let result =
+            import_from_jsonl(&mut storage, &path, &ImportConfig::default(), Some("bd-")).unwrap();
+
+        assert_eq!(result.id_remaps.len(), 1);
+        let remap = &result.id_remaps[0];
+        assert_eq!(remap.old_id, "bd-abc");
+        assert_eq!(remap.title, "Remote");
+        assert!(!remap.existing);
+        assert_ne!(remap.new_id, "bd-abc");
+
+        let kept = storage.get_issue("bd-abc").unwrap().unwrap();
+        assert_eq!(kept.title, "Local (edited)");
+        let moved = storage.get_issue(&remap.new_id).unwrap().unwrap();
+        assert_eq!(moved.title, "Remote");
+        let comments = storage.get_comments(&remap.new_id).unwrap();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].body, "remote comment");
+    }
+
+    #[test]
+    fn test_import_renames_newer_existing_issue_on_id_collision() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("issues.jsonl");
+
+        let local = make_issue_at("bd-abc", "Local", fixed_time(500));
+        storage.create_issue(&local, "test").unwrap();
+        let remote = make_issue_at("bd-abc", "Remote", fixed_time(300));
+        fs::write(
+            &path,
+            format!("{}\n", serde_json::to_string(&remote).unwrap()),
+        )
+        .unwrap();
+
+        let result =
+            import_from_jsonl(&mut storage, &path, &ImportConfig::default(), Some("bd-")).unwrap();
+
+        assert_eq!(result.id_remaps.len(), 1);
+        let remap = &result.id_remaps[0];
+        assert!(remap.existing);
+        assert_eq!(remap.title, "Local");
+        assert_eq!(
+            storage.get_issue("bd-abc").unwrap().unwrap().title,
+            "Remote"
+        );
+        assert_eq!(
+            storage.get_issue(&remap.new_id).unwrap().unwrap().title,
+            "Local"
+        );
+    }
+
+    #[test]
+    fn test_import_failure_rolls_back_collision_rename() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("issues.jsonl");
+
+        let local = make_issue_at("bd-abc", "Local", fixed_time(500));
+        storage.create_issue(&local, "test").unwrap();
+        let remote = make_issue_at("bd-abc", "Remote", fixed_time(300));
+        let mut first = make_issue_at("bd-def", "First", fixed_time(100));
+        first.external_ref = Some("JIRA-1".to_string());
+        let mut second = make_issue_at("bd-ghi", "Second", fixed_time(100));
+        second.external_ref = Some("JIRA-1".to_string());
+        let lines = [remote, first, second]
+            .iter()
+            .map(|issue| serde_json::to_string(issue).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        fs::write(&path, format!("{lines}\n")).unwrap();
+
+        let result = import_from_jsonl(&mut storage, &path, &ImportConfig::default(), Some("bd-"));
+        assert!(result.is_err(), "duplicate external_ref should fail");
+
+        assert_eq!(storage.get_issue("bd-abc").unwrap().unwrap().title, "Local");
+        assert_eq!(storage.count_issues().unwrap(), 1);
+    }
+
     #[test]
     fn test_import_tombstone_protection() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -3834,6 +4497,45 @@ mod tests {
         assert_eq!(ids, vec!["bd-a", "bd-m", "bd-z"]);
     }
 
+    #[test]
+    fn test_dirty_issues_needing_export_ignores_reverted_edits() {
+        use crate::storage::IssueUpdate;
+
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("issues.jsonl");
+
+        storage
+            .create_issue(&make_test_issue("bd-1", "Issue"), "test")
+            .unwrap();
+        let result = export_to_jsonl(&storage, &output_path, &ExportConfig::default()).unwrap();
+        finalize_export(&mut storage, &result, Some(&result.issue_hashes)).unwrap();
+
+        // Edit and revert: dirty, but the exported record is unchanged
+        for title in ["Changed", "Issue"] {
+            let update = IssueUpdate {
+                title: Some(title.to_string()),
+                ..Default::default()
+            };
+            storage.update_issue("bd-1", &update, "test").unwrap();
+        }
+        let dirty = storage.get_dirty_issue_ids().unwrap();
+        assert_eq!(dirty, vec!["bd-1"]);
+        assert!(
+            dirty_issues_needing_export(&storage, &dirty)
+                .unwrap()
+                .is_empty()
+        );
+
+        // Relations are part of the export record
+        storage.add_label("bd-1", "backend", "test").unwrap();
+        let dirty = storage.get_dirty_issue_ids().unwrap();
+        assert_eq!(
+            dirty_issues_needing_export(&storage, &dirty).unwrap(),
+            vec!["bd-1"]
+        );
+    }
+
     #[test]
     fn test_finalize_export_updates_metadata_and_clears_dirty() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4587,6 +5289,10 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            code_refs: vec![],
+            watchers: vec![],
+            reactions: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
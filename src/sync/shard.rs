@@ -0,0 +1,321 @@
+//! Sharded JSONL layout for exports.
+//!
+//! By default the whole project is exported to a single `issues.jsonl`. With
+//! `sync.shard: status` the export is split by status instead:
+//!
+//! | File | Contents |
+//! |------|----------|
+//! | `issues.open.jsonl` | Every issue that is not closed or tombstoned |
+//! | `issues.closed.jsonl` | Closed issues and tombstones |
+//!
+//! Shard paths are derived from the configured JSONL path, so a custom
+//! `BEADS_JSONL=foo.jsonl` shards into `foo.open.jsonl` and `foo.closed.jsonl`.
+//! Readers never need to know which layout is in use: [`jsonl_sources`]
+//! returns every file that currently makes up the export.
+
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, Status};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// How exports are split across JSONL files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShardStrategy {
+    /// Single `issues.jsonl` file.
+    #[default]
+    None,
+    /// `issues.open.jsonl` + `issues.closed.jsonl`.
+    Status,
+}
+
+impl ShardStrategy {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Status => "status",
+        }
+    }
+}
+
+impl std::fmt::Display for ShardStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ShardStrategy {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "none" | "off" => Ok(Self::None),
+            "status" => Ok(Self::Status),
+            other => Err(BeadsError::Config(format!(
+                "Invalid sync.shard value '{other}' (expected 'none' or 'status')"
+            ))),
+        }
+    }
+}
+
+/// A single shard file in the status layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shard {
+    Open,
+    Closed,
+}
+
+impl Shard {
+    /// All shards, in the order they are written and hashed.
+    pub const ALL: [Self; 2] = [Self::Open, Self::Closed];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Closed => "closed",
+        }
+    }
+
+    /// The shard an issue belongs to.
+    #[must_use]
+    pub const fn of(issue: &Issue) -> Self {
+        Self::for_status(&issue.status)
+    }
+
+    /// The shard for issues with `status`.
+    #[must_use]
+    pub const fn for_status(status: &Status) -> Self {
+        match status {
+            Status::Closed | Status::Tombstone => Self::Closed,
+            _ => Self::Open,
+        }
+    }
+
+    /// Path of this shard next to `base` (`issues.jsonl` -> `issues.open.jsonl`).
+    #[must_use]
+    pub fn path(self, base: &Path) -> PathBuf {
+        base.with_extension(format!("{}.jsonl", self.as_str()))
+    }
+}
+
+/// Every JSONL file that currently makes up the export at `base`.
+///
+/// Order is base file first, then the shards in [`Shard::ALL`] order, which is
+/// also the order exports hash them. Only existing files are returned; when
+/// none exist the base path is returned alone so callers report the usual
+/// "file not found" error against the configured path.
+#[must_use]
+pub fn jsonl_sources(base: &Path) -> Vec<PathBuf> {
+    let sources: Vec<PathBuf> = std::iter::once(base.to_path_buf())
+        .chain(Shard::ALL.iter().map(|shard| shard.path(base)))
+        .filter(|path| path.is_file())
+        .collect();
+    if sources.is_empty() {
+        vec![base.to_path_buf()]
+    } else {
+        sources
+    }
+}
+
+/// Whether any file of the export at `base` exists (base file or a shard).
+#[must_use]
+pub fn jsonl_exists(base: &Path) -> bool {
+    base.exists() || Shard::ALL.iter().any(|shard| shard.path(base).exists())
+}
+
+/// Files that an export with `strategy` does not write and should remove.
+#[must_use]
+pub fn superseded_paths(base: &Path, strategy: ShardStrategy) -> Vec<PathBuf> {
+    match strategy {
+        ShardStrategy::None => Shard::ALL.iter().map(|shard| shard.path(base)).collect(),
+        ShardStrategy::Status => vec![base.to_path_buf()],
+    }
+    .into_iter()
+    .filter(|path| path.exists())
+    .collect()
+}
+
+/// A problem found by [`check_consistency`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShardProblem {
+    /// The unsharded file and at least one shard exist side by side.
+    MixedLayout { files: Vec<PathBuf> },
+    /// An issue appears in more than one file.
+    DuplicateId { id: String, files: Vec<PathBuf> },
+    /// An issue sits in the wrong shard for its status.
+    Misplaced {
+        id: String,
+        file: PathBuf,
+        expected: PathBuf,
+    },
+}
+
+impl std::fmt::Display for ShardProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |files: &[PathBuf]| {
+            files
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            Self::MixedLayout { files } => {
+                write!(
+                    f,
+                    "both sharded and unsharded JSONL present: {}",
+                    join(files)
+                )
+            }
+            Self::DuplicateId { id, files } => {
+                write!(f, "{id} appears in multiple files: {}", join(files))
+            }
+            Self::Misplaced { id, file, expected } => write!(
+                f,
+                "{id} is in {} but belongs in {}",
+                file.display(),
+                expected.display()
+            ),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct PartialIssue {
+    id: String,
+    #[serde(default)]
+    status: Status,
+}
+
+/// Check that the files making up the export at `base` agree with each other.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read or contains invalid JSON.
+pub fn check_consistency(base: &Path) -> Result<Vec<ShardProblem>> {
+    let sources = jsonl_sources(base);
+    let mut problems = Vec::new();
+    if base.is_file() && sources.len() > 1 {
+        problems.push(ShardProblem::MixedLayout {
+            files: sources.clone(),
+        });
+    }
+
+    let mut seen: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    for path in sources.iter().filter(|path| path.is_file()) {
+        let shard = Shard::ALL.into_iter().find(|s| s.path(base) == *path);
+        let reader = BufReader::new(File::open(path)?);
+        for (line_num, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let partial: PartialIssue = serde_json::from_str(&line).map_err(|e| {
+                BeadsError::Config(format!(
+                    "Invalid JSON at line {} of {}: {e}",
+                    line_num + 1,
+                    path.display()
+                ))
+            })?;
+            if let Some(shard) = shard {
+                let expected = Shard::for_status(&partial.status);
+                if expected != shard {
+                    problems.push(ShardProblem::Misplaced {
+                        id: partial.id.clone(),
+                        file: path.clone(),
+                        expected: expected.path(base),
+                    });
+                }
+            }
+            seen.entry(partial.id).or_default().push(path.clone());
+        }
+    }
+
+    let mut duplicates: Vec<_> = seen
+        .into_iter()
+        .filter(|(_, files)| files.len() > 1)
+        .collect();
+    duplicates.sort_by(|a, b| a.0.cmp(&b.0));
+    problems.extend(
+        duplicates
+            .into_iter()
+            .map(|(id, files)| ShardProblem::DuplicateId { id, files }),
+    );
+
+    Ok(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn line(id: &str, status: &str) -> String {
+        format!("{{\"id\":\"{id}\",\"title\":\"t\",\"status\":\"{status}\"}}\n")
+    }
+
+    #[test]
+    fn test_shard_paths_and_sources() {
+        let temp = TempDir::new().unwrap();
+        let base = temp.path().join("issues.jsonl");
+        assert_eq!(
+            Shard::Open.path(&base),
+            temp.path().join("issues.open.jsonl")
+        );
+        assert_eq!(jsonl_sources(&base), vec![base.clone()]);
+        assert!(!jsonl_exists(&base));
+
+        std::fs::write(Shard::Closed.path(&base), line("bd-2", "closed")).unwrap();
+        std::fs::write(Shard::Open.path(&base), line("bd-1", "open")).unwrap();
+        assert!(jsonl_exists(&base));
+        assert_eq!(
+            jsonl_sources(&base),
+            vec![Shard::Open.path(&base), Shard::Closed.path(&base)]
+        );
+        assert!(check_consistency(&base).unwrap().is_empty());
+        assert_eq!(
+            superseded_paths(&base, ShardStrategy::None),
+            vec![Shard::Open.path(&base), Shard::Closed.path(&base)]
+        );
+        assert!(superseded_paths(&base, ShardStrategy::Status).is_empty());
+    }
+
+    #[test]
+    fn test_check_consistency_reports_problems() {
+        let temp = TempDir::new().unwrap();
+        let base = temp.path().join("issues.jsonl");
+        std::fs::write(&base, line("bd-1", "open")).unwrap();
+        std::fs::write(
+            Shard::Open.path(&base),
+            line("bd-1", "open") + &line("bd-3", "closed"),
+        )
+        .unwrap();
+
+        let problems = check_consistency(&base).unwrap();
+        assert_eq!(problems.len(), 3);
+        assert!(matches!(problems[0], ShardProblem::MixedLayout { .. }));
+        assert!(
+            matches!(&problems[1], ShardProblem::Misplaced { id, expected, .. }
+                if id == "bd-3" && *expected == Shard::Closed.path(&base))
+        );
+        assert!(matches!(&problems[2], ShardProblem::DuplicateId { id, .. } if id == "bd-1"));
+    }
+
+    #[test]
+    fn test_strategy_parse() {
+        assert_eq!(
+            "status".parse::<ShardStrategy>().unwrap(),
+            ShardStrategy::Status
+        );
+        assert_eq!(
+            "None".parse::<ShardStrategy>().unwrap(),
+            ShardStrategy::None
+        );
+        assert!("label".parse::<ShardStrategy>().is_err());
+    }
+}
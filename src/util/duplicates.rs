@@ -0,0 +1,148 @@
+//! Duplicate detection for new issues.
+//!
+//! `br create` compares a new issue against existing open issues before
+//! writing it. An issue is a suspected duplicate if its content hash matches
+//! exactly, or if the normalized tokens of its title (or title plus
+//! description) overlap enough by Jaccard similarity.
+
+use crate::model::Issue;
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+/// Similarity at or above which an issue is reported as a duplicate.
+pub const DEFAULT_SIMILARITY_THRESHOLD: f64 = 0.6;
+
+/// Words too common to say anything about what an issue is about.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "is", "it", "of", "on",
+    "or", "that", "the", "this", "to", "when", "with",
+];
+
+/// An existing issue that looks like the one being created.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DuplicateCandidate {
+    pub id: String,
+    pub title: String,
+    /// Token similarity in 0.0..=1.0
+    pub similarity: f64,
+    /// True if the content hash matches exactly
+    pub exact: bool,
+}
+
+/// Lowercased alphanumeric tokens of `text`, without stop words.
+#[must_use]
+pub fn tokenize(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .filter(|token| !STOP_WORDS.contains(&token.as_str()))
+        .collect()
+}
+
+/// Jaccard similarity of two token sets (0.0 when both are empty).
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn similarity(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Find the issues in `existing` that `issue` likely duplicates, most
+/// similar first. Exact content-hash matches always qualify.
+#[must_use]
+pub fn find_duplicates(
+    issue: &Issue,
+    existing: &[Issue],
+    threshold: f64,
+) -> Vec<DuplicateCandidate> {
+    let hash = issue
+        .content_hash
+        .clone()
+        .unwrap_or_else(|| issue.compute_content_hash());
+    let title_tokens = tokenize(&issue.title);
+    let all_tokens = full_tokens(issue);
+
+    let mut candidates: Vec<DuplicateCandidate> = existing
+        .iter()
+        .filter(|other| other.id != issue.id)
+        .filter_map(|other| {
+            let exact = other.content_hash.as_deref() == Some(hash.as_str());
+            let score = similarity(&title_tokens, &tokenize(&other.title))
+                .max(similarity(&all_tokens, &full_tokens(other)));
+            (exact || score >= threshold).then(|| DuplicateCandidate {
+                id: other.id.clone(),
+                title: other.title.clone(),
+                similarity: if exact { 1.0 } else { score },
+                exact,
+            })
+        })
+        .collect();
+    candidates.sort_by(|a, b| {
+        b.exact
+            .cmp(&a.exact)
+            .then_with(|| b.similarity.total_cmp(&a.similarity))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    candidates
+}
+
+fn full_tokens(issue: &Issue) -> BTreeSet<String> {
+    let mut tokens = tokenize(&issue.title);
+    if let Some(description) = &issue.description {
+        tokens.extend(tokenize(description));
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(id: &str, title: &str, description: Option<&str>) -> Issue {
+        let mut issue = Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            description: description.map(str::to_string),
+            ..Issue::default()
+        };
+        issue.content_hash = Some(issue.compute_content_hash());
+        issue
+    }
+
+    #[test]
+    fn test_tokenize_normalizes_and_drops_stop_words() {
+        let tokens = tokenize("Fix the Login-Page crash!");
+        let expected: BTreeSet<String> = ["fix", "login", "page", "crash"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_find_duplicates_by_similarity_and_hash() {
+        let existing = vec![
+            issue("bd-1", "Login page crashes on Safari", None),
+            issue("bd-2", "Add dark mode", None),
+            issue("bd-3", "Fix flaky CI", Some("Retries time out")),
+        ];
+
+        let new = issue("bd-new", "Safari: login page crashes", None);
+        let found = find_duplicates(&new, &existing, DEFAULT_SIMILARITY_THRESHOLD);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, "bd-1");
+        assert!(!found[0].exact);
+
+        let copy = issue("bd-new", "Fix flaky CI", Some("Retries time out"));
+        let found = find_duplicates(&copy, &existing, 1.1);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, "bd-3");
+        assert!(found[0].exact);
+
+        let unrelated = issue("bd-new", "Write release notes", None);
+        assert!(find_duplicates(&unrelated, &existing, DEFAULT_SIMILARITY_THRESHOLD).is_empty());
+    }
+}
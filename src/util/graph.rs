@@ -0,0 +1,157 @@
+//! Graph algorithms over dependency edges.
+//!
+//! Graphs are adjacency lists keyed by issue ID (`issue -> depends_on`).
+//! Both functions are iterative so deep dependency chains cannot overflow
+//! the stack, and ordered so results are deterministic.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+/// Strongly connected components that contain a cycle (Tarjan's algorithm).
+///
+/// These are the components with more than one node, plus single nodes with
+/// a self-loop. Members of each component are sorted, and components are
+/// ordered by their first member.
+#[must_use]
+pub fn cyclic_components(graph: &BTreeMap<String, Vec<String>>) -> Vec<Vec<String>> {
+    let mut index_of: HashMap<&str, usize> = HashMap::new();
+    let mut lowlink: HashMap<&str, usize> = HashMap::new();
+    let mut on_stack: BTreeSet<&str> = BTreeSet::new();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+
+    for root in graph.keys() {
+        if index_of.contains_key(root.as_str()) {
+            continue;
+        }
+        // (node, next neighbor to visit)
+        let mut work: Vec<(&str, usize)> = vec![(root.as_str(), 0)];
+        while let Some(&mut (node, ref mut next)) = work.last_mut() {
+            if *next == 0 && !index_of.contains_key(node) {
+                index_of.insert(node, next_index);
+                lowlink.insert(node, next_index);
+                next_index += 1;
+                stack.push(node);
+                on_stack.insert(node);
+            }
+
+            let neighbors = graph.get(node).map_or(&[][..], Vec::as_slice);
+            if let Some(neighbor) = neighbors.get(*next) {
+                *next += 1;
+                let neighbor = neighbor.as_str();
+                if !index_of.contains_key(neighbor) {
+                    work.push((neighbor, 0));
+                } else if on_stack.contains(neighbor) {
+                    let low = lowlink[node].min(index_of[neighbor]);
+                    lowlink.insert(node, low);
+                }
+                continue;
+            }
+
+            // All neighbors done: pop the component if `node` is its root
+            work.pop();
+            if let Some(&(parent, _)) = work.last() {
+                let low = lowlink[parent].min(lowlink[node]);
+                lowlink.insert(parent, low);
+            }
+            if lowlink[node] == index_of[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack.remove(member);
+                    component.push(member.to_string());
+                    if member == node {
+                        break;
+                    }
+                }
+                let self_loop = neighbors.iter().any(|n| n == node);
+                if component.len() > 1 || self_loop {
+                    component.sort();
+                    components.push(component);
+                }
+            }
+        }
+    }
+
+    components.sort();
+    components
+}
+
+/// The shortest cycle through `start` that stays inside `members`, as a
+/// closed path (`start ... start`). Empty if there is none.
+#[must_use]
+pub fn shortest_cycle(
+    graph: &BTreeMap<String, Vec<String>>,
+    members: &[String],
+    start: &str,
+) -> Vec<String> {
+    let allowed: BTreeSet<&str> = members.iter().map(String::as_str).collect();
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    let mut queue: VecDeque<&str> = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        for neighbor in graph.get(node).into_iter().flatten() {
+            let neighbor = neighbor.as_str();
+            if neighbor == start {
+                let mut path = vec![start.to_string()];
+                let mut current = node;
+                while current != start {
+                    path.push(current.to_string());
+                    current = previous[current];
+                }
+                path.push(start.to_string());
+                path.reverse();
+                return path;
+            }
+            if allowed.contains(neighbor) && !previous.contains_key(neighbor) {
+                previous.insert(neighbor, node);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(&str, &str)]) -> BTreeMap<String, Vec<String>> {
+        let mut graph: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (from, to) in edges {
+            graph
+                .entry((*from).to_string())
+                .or_default()
+                .push((*to).to_string());
+        }
+        graph
+    }
+
+    #[test]
+    fn test_cyclic_components_finds_each_cycle_once() {
+        // a <-> b, c -> d -> e -> c, f -> f, and acyclic g -> a
+        let graph = graph(&[
+            ("a", "b"),
+            ("b", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "c"),
+            ("f", "f"),
+            ("g", "a"),
+        ]);
+        assert_eq!(
+            cyclic_components(&graph),
+            vec![vec!["a", "b"], vec!["c", "d", "e"], vec!["f"]]
+        );
+    }
+
+    #[test]
+    fn test_shortest_cycle_stays_in_component() {
+        let graph = graph(&[("a", "b"), ("b", "c"), ("c", "a"), ("b", "a")]);
+        let members = cyclic_components(&graph).remove(0);
+        assert_eq!(shortest_cycle(&graph, &members, "a"), vec!["a", "b", "a"]);
+        assert_eq!(
+            shortest_cycle(&graph, &members, "c"),
+            vec!["c", "a", "b", "c"]
+        );
+    }
+}
@@ -17,6 +17,10 @@ pub struct IdConfig {
     pub max_hash_length: usize,
     /// Maximum collision probability before increasing length.
     pub max_collision_prob: f64,
+    /// Extra entropy mixed into every seed so that two machines (or
+    /// sessions) creating the same issue at the same instant still get
+    /// different IDs. `None` keeps the classic bd seed.
+    pub salt: Option<String>,
 }
 
 impl Default for IdConfig {
@@ -26,6 +30,7 @@ impl Default for IdConfig {
             min_hash_length: 3,
             max_hash_length: 8,
             max_collision_prob: 0.25,
+            salt: None,
         }
     }
 }
@@ -102,7 +107,7 @@ impl IdGenerator {
         nonce: u32,
         hash_length: usize,
     ) -> String {
-        let seed = generate_id_seed(title, description, creator, created_at, nonce);
+        let seed = self.seed(title, description, creator, created_at, nonce);
         let hash_str = compute_id_hash(&seed, hash_length);
         format!("{}-{hash_str}", self.config.prefix)
     }
@@ -142,7 +147,7 @@ impl IdGenerator {
                 // Try increasing nonces until we find a free one
                 let mut nonce = 0;
                 loop {
-                    let seed = generate_id_seed(title, description, creator, created_at, nonce);
+                    let seed = self.seed(title, description, creator, created_at, nonce);
                     let hash_str = compute_id_hash(&seed, 12);
                     let id = format!("{}-{hash_str}", self.config.prefix);
 
@@ -171,6 +176,22 @@ impl IdGenerator {
             }
         }
     }
+
+    /// Seed for one candidate: the classic bd seed, plus `|salt` when set.
+    fn seed(
+        &self,
+        title: &str,
+        description: Option<&str>,
+        creator: Option<&str>,
+        created_at: DateTime<Utc>,
+        nonce: u32,
+    ) -> String {
+        let seed = generate_id_seed(title, description, creator, created_at, nonce);
+        match &self.config.salt {
+            Some(salt) => format!("{seed}|{salt}"),
+            None => seed,
+        }
+    }
 }
 
 /// Generate the seed string for ID generation.
@@ -247,6 +268,26 @@ pub fn child_id(parent_id: &str, child_number: u32) -> String {
     format!("{parent_id}.{child_number}")
 }
 
+/// `(old, new)` pairs for renaming `id` to `new_id` along with every ID nested
+/// under it (`id.1`, `id.1.2`, ...), parents first.
+#[must_use]
+pub fn nested_id_renames(all_ids: &[String], id: &str, new_id: &str) -> Vec<(String, String)> {
+    let nested_prefix = format!("{id}.");
+    let mut renames = vec![(id.to_string(), new_id.to_string())];
+    let mut nested: Vec<&String> = all_ids
+        .iter()
+        .filter(|other| other.starts_with(&nested_prefix))
+        .collect();
+    nested.sort_by_key(|other| other.matches('.').count());
+    renames.extend(nested.into_iter().map(|other| {
+        (
+            other.clone(),
+            format!("{new_id}.{}", &other[nested_prefix.len()..]),
+        )
+    }));
+    renames
+}
+
 fn is_numeric_segment(segment: &str) -> bool {
     !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit())
 }
@@ -336,6 +377,7 @@ pub fn generate_id(
 // ============================================================================
 
 use crate::error::{BeadsError, Result};
+use std::collections::HashMap;
 
 /// Parsed components of an issue ID.
 ///
@@ -500,6 +542,9 @@ pub fn is_valid_id_format(id: &str) -> bool {
 // ID Resolution
 // ============================================================================
 
+/// Argument that stands for the last-touched issue (`br dep add - bd-x`).
+pub const LAST_TOUCHED_ID: &str = "-";
+
 /// Configuration for ID resolution.
 #[derive(Debug, Clone)]
 pub struct ResolverConfig {
@@ -509,6 +554,10 @@ pub struct ResolverConfig {
     pub allowed_prefixes: Vec<String>,
     /// Whether to allow substring matching on hash portion.
     pub allow_substring_match: bool,
+    /// Old IDs of reparented or renamed issues, mapped to their current ID.
+    pub aliases: HashMap<String, String>,
+    /// Issue that [`LAST_TOUCHED_ID`] resolves to, if any.
+    pub last_touched: Option<String>,
 }
 
 impl Default for ResolverConfig {
@@ -517,6 +566,8 @@ impl Default for ResolverConfig {
             default_prefix: "bd".to_string(),
             allowed_prefixes: Vec::new(),
             allow_substring_match: true,
+            aliases: HashMap::new(),
+            last_touched: None,
         }
     }
 }
@@ -530,6 +581,21 @@ impl ResolverConfig {
             ..Default::default()
         }
     }
+
+    /// Follow the given `alias -> issue_id` map when resolving.
+    #[must_use]
+    pub fn with_aliases(mut self, aliases: HashMap<String, String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Resolve [`LAST_TOUCHED_ID`] to `id` (ignored when empty).
+    #[must_use]
+    pub fn with_last_touched(mut self, id: impl Into<String>) -> Self {
+        let id = id.into();
+        self.last_touched = (!id.is_empty()).then_some(id);
+        self
+    }
 }
 
 /// Resolved ID result from the resolution process.
@@ -552,15 +618,19 @@ pub enum MatchType {
     PrefixNormalized,
     /// Matched via substring on hash portion.
     Substring,
+    /// Matched an old ID recorded in the alias map.
+    Alias,
 }
 
 /// ID resolver that resolves partial IDs to full IDs.
 ///
 /// Resolution order:
+/// 0. [`LAST_TOUCHED_ID`] is replaced by the configured last-touched issue
 /// 1. Exact ID match
 /// 2. Normalize: if missing prefix, prepend `default_prefix-` and retry
-/// 3. Substring match on hash portion across all prefixes
-/// 4. Ambiguity => error with candidate list
+/// 3. Alias lookup: an old ID resolves to the issue's current ID
+/// 4. Substring match on hash portion across all prefixes
+/// 5. Ambiguity => error with candidate list
 #[derive(Debug, Clone)]
 pub struct IdResolver {
     config: ResolverConfig,
@@ -624,6 +694,14 @@ impl IdResolver {
             return Err(BeadsError::InvalidId { id: String::new() });
         }
 
+        let input = if input == LAST_TOUCHED_ID {
+            self.config.last_touched.as_deref().ok_or_else(|| {
+                BeadsError::validation("id", "'-' given but there is no last-touched issue")
+            })?
+        } else {
+            input
+        };
+
         // Normalize input to lowercase
         let normalized = normalize_id(input);
 
@@ -637,7 +715,9 @@ impl IdResolver {
         }
 
         // Step 2: If no dash (missing prefix), prepend default prefix and retry
-        if !normalized.contains('-') {
+        let with_prefix = if normalized.contains('-') {
+            None
+        } else {
             let with_prefix = format!("{}-{}", self.config.default_prefix, normalized);
             if exists_fn(&with_prefix) {
                 return Ok(ResolvedId {
@@ -646,9 +726,24 @@ impl IdResolver {
                     original_input: input.to_string(),
                 });
             }
+            Some(with_prefix)
+        };
+
+        // Step 3: Old ID of a reparented or renamed issue
+        let alias_target = self.config.aliases.get(&normalized).or_else(|| {
+            with_prefix
+                .as_ref()
+                .and_then(|id| self.config.aliases.get(id))
+        });
+        if let Some(target) = alias_target.filter(|target| exists_fn(target)) {
+            return Ok(ResolvedId {
+                id: target.clone(),
+                match_type: MatchType::Alias,
+                original_input: input.to_string(),
+            });
         }
 
-        // Step 3: Substring match on hash portion
+        // Step 4: Substring match on hash portion
         if self.config.allow_substring_match {
             // Extract the potential hash portion (after dash, or entire input if no dash)
             let hash_pattern = split_prefix_remainder(&normalized)
@@ -679,7 +774,7 @@ impl IdResolver {
             }
         }
 
-        // Step 4: No match found
+        // Step 5: No match found
         Err(BeadsError::IssueNotFound {
             id: input.to_string(),
         })
@@ -795,6 +890,59 @@ mod tests {
         assert_eq!(result.match_type, MatchType::PrefixNormalized);
     }
 
+    #[test]
+    fn test_nested_id_renames() {
+        let all_ids = vec![
+            "bd-abc".to_string(),
+            "bd-abc.1".to_string(),
+            "bd-abc.1.1".to_string(),
+            "bd-abc.1.2".to_string(),
+            "bd-abc.10".to_string(),
+            "bd-xyz".to_string(),
+        ];
+
+        let renames = nested_id_renames(&all_ids, "bd-abc.1", "bd-xyz.3");
+        assert_eq!(
+            renames,
+            vec![
+                ("bd-abc.1".to_string(), "bd-xyz.3".to_string()),
+                ("bd-abc.1.1".to_string(), "bd-xyz.3.1".to_string()),
+                ("bd-abc.1.2".to_string(), "bd-xyz.3.2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_alias() {
+        let aliases = HashMap::from([("bd-old1".to_string(), "bd-abc123.1".to_string())]);
+        let resolver = IdResolver::new(ResolverConfig::default().with_aliases(aliases));
+
+        let result = resolver
+            .resolve("bd-old1", exists_in_mock, substring_in_mock)
+            .unwrap();
+        assert_eq!(result.id, "bd-abc123.1");
+        assert_eq!(result.match_type, MatchType::Alias);
+
+        let result = resolver
+            .resolve("old1", exists_in_mock, substring_in_mock)
+            .unwrap();
+        assert_eq!(result.id, "bd-abc123.1");
+    }
+
+    #[test]
+    fn test_resolve_last_touched_placeholder() {
+        let resolver = IdResolver::new(ResolverConfig::default().with_last_touched("abc123"));
+        let result = resolver
+            .resolve(LAST_TOUCHED_ID, exists_in_mock, substring_in_mock)
+            .unwrap();
+        assert_eq!(result.id, "bd-abc123");
+
+        let err = IdResolver::new(ResolverConfig::default().with_last_touched(""))
+            .resolve("-", exists_in_mock, substring_in_mock)
+            .unwrap_err();
+        assert!(err.to_string().contains("last-touched"));
+    }
+
     #[test]
     fn test_resolve_substring_match() {
         let resolver = IdResolver::with_defaults();
@@ -928,6 +1076,27 @@ mod tests {
         assert!(seed.ends_with("|0"));
     }
 
+    #[test]
+    fn test_salt_changes_generated_id() {
+        let now = Utc::now();
+        let plain = IdGenerator::with_defaults();
+        let salted = |salt: &str| {
+            IdGenerator::new(IdConfig {
+                salt: Some(salt.to_string()),
+                ..IdConfig::default()
+            })
+        };
+
+        let a = salted("host-a").generate_candidate("Same", None, None, now, 0, 8);
+        let b = salted("host-b").generate_candidate("Same", None, None, now, 0, 8);
+        assert_ne!(a, b);
+        assert_eq!(
+            a,
+            salted("host-a").generate_candidate("Same", None, None, now, 0, 8)
+        );
+        assert_ne!(a, plain.generate_candidate("Same", None, None, now, 0, 8));
+    }
+
     #[test]
     fn test_parse_id_root() {
         let parsed = parse_id("bd-abc123").unwrap();
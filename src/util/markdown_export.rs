@@ -0,0 +1,125 @@
+//! Markdown export in the dialect read by [`markdown_import`].
+//!
+//! Used by `br show --format md` and `br export --format md`. Each issue is an
+//! H2 title followed by an ID comment and H3 sections, so an exported file can
+//! be edited in bulk and fed back through `br create --file <path> --update`,
+//! which updates the issues named in the ID comments instead of creating new
+//! ones.
+//!
+//! Empty fields are omitted. Text containing its own `##`/`###` headings will
+//! not round-trip cleanly, since those lines start new issues or sections.
+//!
+//! [`markdown_import`]: crate::util::markdown_import
+
+use crate::model::Issue;
+use std::io::{self, Write};
+
+/// Write issues as markdown. `labels` and `dependencies` are written if the
+/// issues have them populated.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+pub fn write_issues<W: Write>(writer: &mut W, issues: &[Issue]) -> io::Result<()> {
+    for issue in issues {
+        writer.write_all(render_issue(issue).as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Render one issue as markdown, ending with a blank line.
+#[must_use]
+pub fn render_issue(issue: &Issue) -> String {
+    let mut out = format!("## {}\n<!-- br:id {} -->\n", issue.title, issue.id);
+
+    push_section(&mut out, "Status", Some(issue.status.as_str()));
+    push_section(&mut out, "Priority", Some(&issue.priority.0.to_string()));
+    push_section(&mut out, "Type", Some(issue.issue_type.as_str()));
+    push_section(&mut out, "Assignee", issue.assignee.as_deref());
+    if !issue.labels.is_empty() {
+        push_section(&mut out, "Labels", Some(&issue.labels.join(", ")));
+    }
+    push_section(&mut out, "External Ref", issue.external_ref.as_deref());
+    push_section(&mut out, "Description", issue.description.as_deref());
+    push_section(&mut out, "Design", issue.design.as_deref());
+    push_section(
+        &mut out,
+        "Acceptance Criteria",
+        issue.acceptance_criteria.as_deref(),
+    );
+    push_section(&mut out, "Notes", issue.notes.as_deref());
+    if !issue.dependencies.is_empty() {
+        let deps: Vec<String> = issue
+            .dependencies
+            .iter()
+            .map(|dep| format!("{}:{}", dep.dep_type.as_str(), dep.depends_on_id))
+            .collect();
+        push_section(&mut out, "Dependencies", Some(&deps.join(", ")));
+    }
+
+    out.push('\n');
+    out
+}
+
+fn push_section(out: &mut String, heading: &str, content: Option<&str>) {
+    let Some(content) = content.map(str::trim).filter(|c| !c.is_empty()) else {
+        return;
+    };
+    out.push_str("\n### ");
+    out.push_str(heading);
+    out.push('\n');
+    out.push_str(content);
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Dependency, DependencyType, IssueType, Priority};
+    use crate::util::markdown_import::parse_markdown_content;
+    use chrono::Utc;
+
+    #[test]
+    fn test_render_issue_round_trips_through_importer() {
+        let issue = Issue {
+            id: "bd-abc".to_string(),
+            title: "Fix login".to_string(),
+            priority: Priority::HIGH,
+            issue_type: IssueType::Bug,
+            assignee: Some("alice".to_string()),
+            description: Some("Login fails\non Safari".to_string()),
+            notes: Some("See logs".to_string()),
+            external_ref: Some("gh-12".to_string()),
+            labels: vec!["auth".to_string(), "web".to_string()],
+            dependencies: vec![Dependency {
+                issue_id: "bd-abc".to_string(),
+                depends_on_id: "bd-def".to_string(),
+                dep_type: DependencyType::Blocks,
+                created_at: Utc::now(),
+                created_by: None,
+                metadata: None,
+                thread_id: None,
+            }],
+            ..Issue::default()
+        };
+
+        let parsed = parse_markdown_content(&render_issue(&issue)).unwrap();
+        assert_eq!(parsed.len(), 1);
+        let parsed = &parsed[0];
+        assert_eq!(parsed.id.as_deref(), Some("bd-abc"));
+        assert_eq!(parsed.title, "Fix login");
+        assert_eq!(parsed.status.as_deref(), Some("open"));
+        assert_eq!(parsed.priority.as_deref(), Some("1"));
+        assert_eq!(parsed.issue_type.as_deref(), Some("bug"));
+        assert_eq!(parsed.assignee.as_deref(), Some("alice"));
+        assert_eq!(parsed.labels, vec!["auth", "web"]);
+        assert_eq!(parsed.external_ref.as_deref(), Some("gh-12"));
+        assert_eq!(
+            parsed.description.as_deref(),
+            Some("Login fails\non Safari")
+        );
+        assert_eq!(parsed.notes.as_deref(), Some("See logs"));
+        assert_eq!(parsed.dependencies, vec!["blocks:bd-def"]);
+        assert!(parsed.design.is_none());
+    }
+}
@@ -7,9 +7,14 @@
 //! - Each issue starts with an H2 line: `## Issue Title`
 //! - Per-issue sections are H3 lines: `### Section Name`
 //! - Recognized sections (case-insensitive):
-//!   - Priority, Type, Description, Design, Acceptance Criteria (alias Acceptance),
-//!     Assignee, Labels, Dependencies (alias Deps)
+//!   - Status, Priority, Type, Description, Design, Acceptance Criteria (alias
+//!     Acceptance), Notes, Assignee, Labels, External Ref, Dependencies (alias Deps)
 //! - Unknown sections are ignored
+//! - A `<!-- br:id bd-abc -->` line names the existing issue the heading came
+//!   from (written by the markdown export; used by `--update`)
+//! - A dependency target of `#N` refers to the Nth issue (1-based) in the same
+//!   file, e.g. `blocks:#1`; it is resolved to that issue's generated ID once
+//!   every issue has been created
 //!
 //! # Known Quirk (matches bd behavior)
 //!
@@ -25,8 +30,12 @@ use std::str::FromStr;
 /// A parsed issue from the markdown file.
 #[derive(Debug, Default, Clone)]
 pub struct ParsedIssue {
+    /// ID from a `<!-- br:id ... -->` comment.
+    pub id: Option<String>,
     /// Issue title from the H2 header.
     pub title: String,
+    /// Status string (e.g., "open", "`in_progress`").
+    pub status: Option<String>,
     /// Priority string (e.g., "0", "P1", "2").
     pub priority: Option<String>,
     /// Issue type (e.g., "task", "bug", "feature").
@@ -37,10 +46,14 @@ pub struct ParsedIssue {
     pub design: Option<String>,
     /// Acceptance criteria content.
     pub acceptance_criteria: Option<String>,
+    /// Notes section content.
+    pub notes: Option<String>,
     /// Assignee name.
     pub assignee: Option<String>,
     /// Labels list.
     pub labels: Vec<String>,
+    /// External reference.
+    pub external_ref: Option<String>,
     /// Dependencies list (format: "type:id" or "id").
     pub dependencies: Vec<String>,
 }
@@ -50,13 +63,16 @@ pub struct ParsedIssue {
 enum Section {
     /// Before any H3, capturing implicit description
     BeforeH3,
+    Status,
     Priority,
     Type,
     Description,
     Design,
     AcceptanceCriteria,
+    Notes,
     Assignee,
     Labels,
+    ExternalRef,
     Dependencies,
     Unknown,
 }
@@ -65,13 +81,16 @@ impl Section {
     fn from_header(header: &str) -> Self {
         let normalized = header.trim().to_lowercase();
         match normalized.as_str() {
+            "status" => Self::Status,
             "priority" => Self::Priority,
             "type" => Self::Type,
             "description" => Self::Description,
             "design" => Self::Design,
             "acceptance criteria" | "acceptance" => Self::AcceptanceCriteria,
+            "notes" => Self::Notes,
             "assignee" => Self::Assignee,
             "labels" => Self::Labels,
+            "external ref" | "external_ref" | "external reference" => Self::ExternalRef,
             "dependencies" | "deps" => Self::Dependencies,
             _ => Self::Unknown,
         }
@@ -180,6 +199,14 @@ pub fn parse_markdown_content(content: &str) -> Result<Vec<ParsedIssue>> {
             continue;
         }
 
+        // ID comment written by the markdown export
+        if let Some(id) = parse_id_comment(line) {
+            if let Some(ref mut issue) = current_issue {
+                issue.id = Some(id.to_string());
+            }
+            continue;
+        }
+
         // Collect content for current section
         if current_issue.is_some() {
             // Handle the quirk: before H3, only capture first non-empty line as description
@@ -225,6 +252,9 @@ fn apply_section_to_issue(issue: &mut ParsedIssue, section: Section, lines: &[St
                 issue.description = Some(content);
             }
         }
+        Section::Status => {
+            issue.status = Some(content);
+        }
         Section::Priority => {
             issue.priority = Some(content);
         }
@@ -240,9 +270,15 @@ fn apply_section_to_issue(issue: &mut ParsedIssue, section: Section, lines: &[St
         Section::AcceptanceCriteria => {
             issue.acceptance_criteria = Some(content);
         }
+        Section::Notes => {
+            issue.notes = Some(content);
+        }
         Section::Assignee => {
             issue.assignee = Some(content);
         }
+        Section::ExternalRef => {
+            issue.external_ref = Some(content);
+        }
         Section::Labels => {
             issue.labels = split_list_content(&content);
         }
@@ -255,6 +291,18 @@ fn apply_section_to_issue(issue: &mut ParsedIssue, section: Section, lines: &[St
     }
 }
 
+/// Extract the ID from a `<!-- br:id bd-abc -->` line.
+fn parse_id_comment(line: &str) -> Option<&str> {
+    let id = line
+        .trim()
+        .strip_prefix("<!--")?
+        .strip_suffix("-->")?
+        .trim()
+        .strip_prefix("br:id")?
+        .trim();
+    (!id.is_empty()).then_some(id)
+}
+
 /// Split content on commas or whitespace for labels/deps.
 fn split_list_content(content: &str) -> Vec<String> {
     // First try splitting on commas
@@ -309,6 +357,19 @@ pub fn parse_dependency(dep_str: &str) -> (String, String, bool) {
     }
 }
 
+/// Parse a `#N` dependency target referring to the Nth issue (1-based) in
+/// the same file.
+///
+/// Returns the 0-based issue index, or None if `dep_id` is not a placeholder.
+#[must_use]
+pub fn parse_placeholder(dep_id: &str) -> Option<usize> {
+    dep_id
+        .strip_prefix('#')?
+        .parse::<usize>()
+        .ok()?
+        .checked_sub(1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -473,6 +534,26 @@ This is the actual description.
         assert!(!valid);
     }
 
+    #[test]
+    fn test_parse_placeholder() {
+        assert_eq!(parse_placeholder("#1"), Some(0));
+        assert_eq!(parse_placeholder("#12"), Some(11));
+        assert_eq!(parse_placeholder("#0"), None);
+        assert_eq!(parse_placeholder("bd-123"), None);
+        assert_eq!(parse_placeholder("#abc"), None);
+
+        let content = r"## First
+## Second
+### Dependencies
+blocks:#1
+";
+        let issues = parse_markdown_content(content).unwrap();
+        let (dep_type, dep_id, valid) = parse_dependency(&issues[1].dependencies[0]);
+        assert!(valid);
+        assert_eq!(dep_type, "blocks");
+        assert_eq!(parse_placeholder(&dep_id), Some(0));
+    }
+
     #[test]
     fn test_parse_markdown_file_rejects_parent_dir() {
         let err = parse_markdown_file(Path::new("../issues.md")).unwrap_err();
@@ -0,0 +1,62 @@
+//! `@name` mentions in comment text.
+
+/// Names mentioned as `@name` in `text`, lowercased, in order of first use.
+///
+/// A mention starts at an `@` that is not preceded by a word character (so
+/// e-mail addresses are skipped) and runs over letters, digits, `_`, `-` and
+/// `.`; a trailing `.` or `-` is punctuation, not part of the name.
+#[must_use]
+pub fn parse_mentions(text: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut previous: Option<char> = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let after_word = previous.is_some_and(is_name_char);
+        previous = Some(c);
+        if c != '@' || after_word {
+            continue;
+        }
+        let start = index + 1;
+        let mut end = start;
+        while let Some(&(next_index, next)) = chars.peek() {
+            if !is_name_char(next) {
+                break;
+            }
+            end = next_index + next.len_utf8();
+            previous = Some(next);
+            chars.next();
+        }
+        let name = text[start..end].trim_end_matches(['.', '-']).to_lowercase();
+        if !name.is_empty() && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mentions() {
+        assert_eq!(
+            parse_mentions("@Alice can you check this with @bob.smith? cc @alice."),
+            vec!["alice", "bob.smith"]
+        );
+        assert_eq!(
+            parse_mentions("(@dev-team) and @agent_7,"),
+            vec!["dev-team", "agent_7"]
+        );
+    }
+
+    #[test]
+    fn test_parse_mentions_skips_emails_and_bare_at() {
+        assert!(parse_mentions("mail ops@example.com or reply @ noon").is_empty());
+        assert!(parse_mentions("").is_empty());
+    }
+}
@@ -5,21 +5,35 @@
 //! - Time parsing and formatting (RFC3339)
 //! - Path handling (.beads discovery)
 //! - ID generation (base36 adaptive)
+//! - Duplicate detection (token similarity)
+//! - Dependency cycle detection (strongly connected components)
 //! - Last-touched tracking
+//! - Current session tracking (`br session start`)
+//! - `@name` mention parsing
+//! - Issue ID references in free text
 //! - Progress indicators (for long-running operations)
+//! - `{{placeholder}}` template substitution
 
+pub mod duplicates;
+pub mod graph;
 mod hash;
 pub mod id;
+pub mod markdown_export;
 pub mod markdown_import;
+pub mod mentions;
 pub mod progress;
+pub mod references;
+pub mod template;
 pub mod time;
 
 pub use hash::{ContentHashable, content_hash, content_hash_from_parts};
 pub use id::{
     IdConfig, IdGenerator, IdResolver, MatchType, ParsedId, ResolvedId, ResolverConfig, child_id,
-    find_matching_ids, generate_id, id_depth, is_child_id, is_valid_id_format, normalize_id,
-    parse_id, resolve_id, validate_prefix,
+    find_matching_ids, generate_id, id_depth, is_child_id, is_valid_id_format, nested_id_renames,
+    normalize_id, parse_id, resolve_id, validate_prefix,
 };
+pub use mentions::parse_mentions;
+pub use references::parse_issue_references;
 
 use std::env;
 use std::fs::{self, OpenOptions};
@@ -27,6 +41,7 @@ use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 
 const LAST_TOUCHED_FILE: &str = "last-touched";
+const CURRENT_SESSION_FILE: &str = "current-session";
 
 /// Environment variable for overriding the cache directory location.
 ///
@@ -126,6 +141,39 @@ pub fn clear_last_touched(beads_dir: &Path) {
     let _ = fs::remove_file(path);
 }
 
+/// Build the path to the `current-session` file written by `br session start`.
+#[must_use]
+pub fn current_session_path(beads_dir: &Path) -> PathBuf {
+    resolve_cache_dir(beads_dir).join(CURRENT_SESSION_FILE)
+}
+
+/// Record `id` as the session stamped onto events until it is cleared.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn set_current_session(beads_dir: &Path, id: &str) -> std::io::Result<()> {
+    let path = current_session_path(beads_dir);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, format!("{id}\n"))
+}
+
+/// Read the session started with `br session start`, if any.
+#[must_use]
+pub fn get_current_session(beads_dir: &Path) -> Option<String> {
+    let contents = fs::read_to_string(current_session_path(beads_dir)).ok()?;
+    let id = contents.lines().next().unwrap_or("").trim();
+    (!id.is_empty()).then(|| id.to_string())
+}
+
+/// Clear the current session. Returns whether one was set.
+#[must_use]
+pub fn clear_current_session(beads_dir: &Path) -> bool {
+    fs::remove_file(current_session_path(beads_dir)).is_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,6 +194,20 @@ mod tests {
         assert_eq!(get_last_touched_id(&beads_dir), "");
     }
 
+    #[test]
+    fn test_set_get_clear_current_session() {
+        let temp = TempDir::new().expect("temp dir");
+        let beads_dir = temp.path().join(".beads");
+        fs::create_dir(&beads_dir).expect("create .beads");
+
+        assert_eq!(get_current_session(&beads_dir), None);
+        set_current_session(&beads_dir, "review-7").expect("set session");
+        assert_eq!(get_current_session(&beads_dir).as_deref(), Some("review-7"));
+        assert!(clear_current_session(&beads_dir));
+        assert!(!clear_current_session(&beads_dir));
+        assert_eq!(get_current_session(&beads_dir), None);
+    }
+
     #[cfg(unix)]
     #[test]
     fn test_last_touched_permissions() {
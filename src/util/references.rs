@@ -0,0 +1,69 @@
+//! Issue IDs referenced in free text (`see bd-xyz`).
+
+use super::id::is_valid_id_format;
+
+/// Issue IDs written in `text`, in order of first use.
+///
+/// A reference is a word shaped like an issue ID (`prefix-hash`, optionally
+/// with `.N` child segments, and may be written `#bd-xyz`) that is not part
+/// of a longer word, path or `@mention`; a trailing `.` or `-` is
+/// punctuation. Whether the ID exists is
+/// left to the caller.
+#[must_use]
+pub fn parse_issue_references(text: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    let mut previous: Option<char> = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let attached = previous.is_some_and(|p| is_id_char(p) || matches!(p, '@' | '/'));
+        previous = Some(c);
+        if !is_id_char(c) {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some(&(next_index, next)) = chars.peek() {
+            if !is_id_char(next) {
+                break;
+            }
+            end = next_index + next.len_utf8();
+            previous = Some(next);
+            chars.next();
+        }
+        if attached {
+            continue;
+        }
+        let word = text[start..end].trim_end_matches(['.', '-']);
+        if word.contains('-') && is_valid_id_format(word) && !ids.iter().any(|id| id == word) {
+            ids.push(word.to_string());
+        }
+    }
+    ids
+}
+
+fn is_id_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_issue_references() {
+        assert_eq!(
+            parse_issue_references("Same root cause as bd-a1b2 (see bd-x9.2). Also bd-a1b2."),
+            vec!["bd-a1b2", "bd-x9.2"]
+        );
+        assert_eq!(
+            parse_issue_references("Blocked on my-proj-k3z,#bd-q7"),
+            vec!["my-proj-k3z", "bd-q7"]
+        );
+    }
+
+    #[test]
+    fn test_parse_issue_references_skips_non_ids() {
+        assert!(parse_issue_references("@dev-team edit src/bd-abc.rs and bd-Abc").is_empty());
+        assert!(parse_issue_references("v1.2 and bd- or -bd").is_empty());
+        assert!(parse_issue_references("").is_empty());
+    }
+}
@@ -0,0 +1,74 @@
+//! Minimal `{{placeholder}}` substitution for user-supplied templates.
+//!
+//! Supports handlebars-like `{{ name }}` placeholders (surrounding whitespace
+//! inside the braces is ignored). There are no conditionals, loops, or
+//! escaping rules; `{{` without a closing `}}` is copied through verbatim.
+
+use std::collections::BTreeMap;
+
+use crate::error::{BeadsError, Result};
+
+/// Substitute `{{name}}` placeholders in `template` with values from `vars`.
+///
+/// # Errors
+///
+/// Returns a validation error naming the first placeholder not present in `vars`.
+pub fn render_template(template: &str, vars: &BTreeMap<&str, String>) -> Result<String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after_open = &rest[start + 2..];
+        let Some(end) = after_open.find("}}") else {
+            out.push_str(&rest[start..]);
+            return Ok(out);
+        };
+
+        let name = after_open[..end].trim();
+        let value = vars.get(name).ok_or_else(|| {
+            BeadsError::validation(
+                "template",
+                format!(
+                    "unknown placeholder '{{{{{name}}}}}' (available: {})",
+                    vars.keys().copied().collect::<Vec<_>>().join(", ")
+                ),
+            )
+        })?;
+        out.push_str(value);
+        rest = &after_open[end + 2..];
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> BTreeMap<&'static str, String> {
+        BTreeMap::from([
+            ("date", "2026-01-02".to_string()),
+            ("count", "3".to_string()),
+        ])
+    }
+
+    #[test]
+    fn substitutes_placeholders_with_optional_whitespace() {
+        let rendered = render_template("On {{date}}: {{ count }} open", &vars()).unwrap();
+        assert_eq!(rendered, "On 2026-01-02: 3 open");
+    }
+
+    #[test]
+    fn unterminated_placeholder_is_copied_verbatim() {
+        let rendered = render_template("{{count}} then {{date", &vars()).unwrap();
+        assert_eq!(rendered, "3 then {{date");
+    }
+
+    #[test]
+    fn unknown_placeholder_is_an_error() {
+        let err = render_template("{{nope}}", &vars()).unwrap_err();
+        assert!(err.to_string().contains("nope"));
+    }
+}
@@ -150,11 +150,87 @@ pub fn parse_relative_time(s: &str) -> Option<DateTime<Utc>> {
     }
 }
 
+/// Parse an effort duration into minutes.
+///
+/// Supports plain minutes (`90`) and `h`/`m` units, alone or combined:
+/// `45m`, `2h`, `1h30m`.
+///
+/// # Errors
+///
+/// Returns an error if the duration is empty, has an unknown unit, or
+/// overflows.
+pub fn parse_duration_minutes(s: &str, field_name: &str) -> Result<i32> {
+    let s = s.trim().to_lowercase();
+    let invalid =
+        || BeadsError::validation(field_name, "invalid duration (try: 90, 45m, 2h, 1h30m)");
+
+    if let Ok(minutes) = s.parse::<i32>() {
+        return if minutes >= 0 {
+            Ok(minutes)
+        } else {
+            Err(invalid())
+        };
+    }
+
+    let mut total: i32 = 0;
+    let mut amount = String::new();
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            amount.push(c);
+            continue;
+        }
+        let value: i32 = amount.parse().map_err(|_| invalid())?;
+        let factor = match c {
+            'h' => 60,
+            'm' => 1,
+            _ => return Err(invalid()),
+        };
+        total = value
+            .checked_mul(factor)
+            .and_then(|minutes| total.checked_add(minutes))
+            .ok_or_else(invalid)?;
+        amount.clear();
+    }
+    if !amount.is_empty() || s.is_empty() {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+/// Parse the start of a look-back window, as taken by `--since` options.
+///
+/// A bare span (`30m`, `24h`, `7d`, `4w`) counts back from now; anything
+/// else is read by [`parse_flexible_timestamp`].
+///
+/// # Errors
+///
+/// Returns an error if the value is neither a span nor a valid time.
+pub fn parse_since(s: &str, field_name: &str) -> Result<DateTime<Utc>> {
+    let s = s.trim();
+    let span = s
+        .starts_with(|c: char| c.is_ascii_digit())
+        .then(|| parse_relative_time(&format!("-{s}")))
+        .flatten();
+    match span {
+        Some(start) => Ok(start),
+        None => parse_flexible_timestamp(s, field_name),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::Datelike;
 
+    #[test]
+    fn test_parse_since_spans_count_back() {
+        let result = parse_since("7d", "since").unwrap();
+        let expected = Utc::now() - Duration::days(7);
+        assert!((result - expected).num_seconds().abs() < 5);
+        assert_eq!(parse_since("2025-06-20", "since").unwrap().year(), 2025);
+        assert!(parse_since("7x", "since").is_err());
+    }
+
     #[test]
     fn test_parse_flexible_rfc3339() {
         let result = parse_flexible_timestamp("2025-01-15T12:00:00Z", "test").unwrap();
@@ -198,4 +274,16 @@ mod tests {
         assert!(parse_relative_time("invalid").is_none());
         assert!(parse_relative_time("2025-01-15").is_none());
     }
+
+    #[test]
+    fn test_parse_duration_minutes() {
+        assert_eq!(parse_duration_minutes("90", "within").unwrap(), 90);
+        assert_eq!(parse_duration_minutes("45m", "within").unwrap(), 45);
+        assert_eq!(parse_duration_minutes("2h", "within").unwrap(), 120);
+        assert_eq!(parse_duration_minutes("1h30m", "within").unwrap(), 90);
+        assert!(parse_duration_minutes("", "within").is_err());
+        assert!(parse_duration_minutes("1d", "within").is_err());
+        assert!(parse_duration_minutes("1h30", "within").is_err());
+        assert!(parse_duration_minutes("-5", "within").is_err());
+    }
 }
@@ -299,6 +299,133 @@ pub fn is_valid_id_format(id: &str) -> bool {
     true
 }
 
+// =============================================================================
+// POLICY VALIDATION
+// =============================================================================
+
+/// Fields a policy rule can require.
+pub const POLICY_FIELDS: &[&str] = &[
+    "description",
+    "design",
+    "acceptance_criteria",
+    "notes",
+    "assignee",
+    "owner",
+    "estimate",
+    "due",
+    "labels",
+];
+
+/// When a policy rule is enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PolicyTrigger {
+    /// On every update or close of a matching issue.
+    Always,
+    /// Only when a matching issue is closed.
+    OnClose,
+}
+
+/// A configured rule: issues matching `selector` must have `fields` set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyRule {
+    pub trigger: PolicyTrigger,
+    /// Issue type (`bug`), priority (`p0`-`p4`), or `all`.
+    pub selector: String,
+    pub fields: Vec<String>,
+}
+
+impl PolicyRule {
+    /// Whether the rule's selector matches an issue.
+    #[must_use]
+    pub fn applies_to(&self, issue: &Issue) -> bool {
+        let selector = self.selector.as_str();
+        if selector == "all" || selector == "*" {
+            return true;
+        }
+        selector
+            .strip_prefix('p')
+            .and_then(|n| n.parse::<i32>().ok())
+            .map_or_else(
+                || issue.issue_type.as_str().eq_ignore_ascii_case(selector),
+                |priority| issue.priority.0 == priority,
+            )
+    }
+
+    fn describe(&self) -> String {
+        let scope = if self.selector == "all" || self.selector == "*" {
+            "all".to_string()
+        } else {
+            self.selector
+                .strip_prefix('p')
+                .filter(|n| n.parse::<i32>().is_ok())
+                .map_or_else(|| self.selector.clone(), |n| format!("P{n}"))
+        };
+        match self.trigger {
+            PolicyTrigger::Always => format!("required for {scope} issues"),
+            PolicyTrigger::OnClose => format!("required before closing {scope} issues"),
+        }
+    }
+}
+
+/// Checks issues against configured required-field policies.
+pub struct PolicyValidator;
+
+impl PolicyValidator {
+    /// Validate an issue against policy rules.
+    ///
+    /// `closing` selects whether on-close rules apply in addition to the
+    /// always-on rules. The issue's `labels` must be populated for `labels`
+    /// requirements to be checked accurately.
+    ///
+    /// # Errors
+    ///
+    /// Returns one `ValidationError` per missing field.
+    pub fn validate(
+        issue: &Issue,
+        rules: &[PolicyRule],
+        closing: bool,
+    ) -> Result<(), Vec<ValidationError>> {
+        let mut errors: Vec<ValidationError> = Vec::new();
+
+        for rule in rules {
+            if (rule.trigger == PolicyTrigger::OnClose && !closing) || !rule.applies_to(issue) {
+                continue;
+            }
+            for field in &rule.fields {
+                if policy_field_is_set(issue, field) || errors.iter().any(|e| e.field == *field) {
+                    continue;
+                }
+                errors.push(ValidationError::new(field.as_str(), rule.describe()));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn policy_field_is_set(issue: &Issue, field: &str) -> bool {
+    match field {
+        "description" => has_text(issue.description.as_deref()),
+        "design" => has_text(issue.design.as_deref()),
+        "acceptance_criteria" => has_text(issue.acceptance_criteria.as_deref()),
+        "notes" => has_text(issue.notes.as_deref()),
+        "assignee" => has_text(issue.assignee.as_deref()),
+        "owner" => has_text(issue.owner.as_deref()),
+        "estimate" => issue.estimated_minutes.is_some(),
+        "due" => issue.due_at.is_some(),
+        "labels" => !issue.labels.is_empty(),
+        _ => true,
+    }
+}
+
+fn has_text(value: Option<&str>) -> bool {
+    value.is_some_and(|v| !v.trim().is_empty())
+}
+
 // =============================================================================
 // SYNC SAFETY VALIDATION
 // =============================================================================
@@ -422,6 +549,7 @@ mod tests {
     use super::*;
     use crate::model::{DependencyType, IssueType, Status};
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     fn base_issue() -> Issue {
         Issue {
@@ -464,6 +592,10 @@ mod tests {
             labels: Vec::new(),
             dependencies: Vec::new(),
             comments: Vec::new(),
+            code_refs: Vec::new(),
+            watchers: Vec::new(),
+            reactions: Vec::new(),
+            fields: BTreeMap::new(),
         }
     }
 
@@ -528,6 +660,8 @@ mod tests {
             author: "tester".to_string(),
             body: " ".to_string(),
             created_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            updated_at: None,
+            deleted: false,
         };
 
         let errors = CommentValidator::validate(&comment).unwrap_err();
@@ -724,6 +858,39 @@ mod tests {
         assert!(is_valid_id_format("bd-abc123456789"));
     }
 
+    #[test]
+    fn policy_validator_reports_missing_fields() {
+        let rules = vec![
+            PolicyRule {
+                trigger: PolicyTrigger::OnClose,
+                selector: "bug".to_string(),
+                fields: vec!["acceptance_criteria".to_string()],
+            },
+            PolicyRule {
+                trigger: PolicyTrigger::Always,
+                selector: "p0".to_string(),
+                fields: vec!["assignee".to_string()],
+            },
+        ];
+        let mut issue = base_issue();
+        issue.issue_type = IssueType::Bug;
+        issue.priority = Priority::CRITICAL;
+
+        let errors = PolicyValidator::validate(&issue, &rules, false).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "assignee");
+        assert_eq!(errors[0].message, "required for P0 issues");
+
+        issue.assignee = Some("alice".to_string());
+        assert!(PolicyValidator::validate(&issue, &rules, false).is_ok());
+        let errors = PolicyValidator::validate(&issue, &rules, true).unwrap_err();
+        assert_eq!(errors[0].field, "acceptance_criteria");
+        assert_eq!(errors[0].message, "required before closing bug issues");
+
+        issue.acceptance_criteria = Some("Crash no longer reproduces".to_string());
+        assert!(PolicyValidator::validate(&issue, &rules, true).is_ok());
+    }
+
     // =========================================================================
     // SYNC SAFETY VALIDATOR TESTS
     // =========================================================================
@@ -1,4 +1,5 @@
 use assert_cmd::Command;
+use serde_json::Value;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::PathBuf;
@@ -145,3 +146,23 @@ pub fn extract_json_payload(stdout: &str) -> String {
     }
     stdout.trim().to_string()
 }
+
+/// Parse the JSON payload of a `--json` run.
+pub fn json_output(stdout: &str) -> Value {
+    serde_json::from_str(&extract_json_payload(stdout)).expect("json output")
+}
+
+/// Run `br <args> --json`, assert it succeeded, and return the printed `id`.
+pub fn created_id(workspace: &BrWorkspace, args: &[&str]) -> String {
+    let label = args.first().copied().unwrap_or("br");
+    let created = run_br(workspace, args.iter().copied().chain(["--json"]), label);
+    assert!(
+        created.status.success(),
+        "{label} failed: {}",
+        created.stderr
+    );
+    json_output(&created.stdout)["id"]
+        .as_str()
+        .expect("created id")
+        .to_string()
+}
@@ -2,6 +2,7 @@
 
 use beads_rust::model::{Dependency, DependencyType, Issue, IssueType, Priority, Status};
 use chrono::{Duration, TimeZone, Utc};
+use std::collections::BTreeMap;
 
 /// Base time for test fixtures - set in the past to allow tests to manipulate
 /// `updated_at` without violating the `created_at` <= `updated_at` constraint.
@@ -52,6 +53,10 @@ pub fn issue(title: &str) -> Issue {
         is_template: false,
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
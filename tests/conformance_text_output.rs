@@ -291,7 +291,7 @@ fn conformance_text_show() {
     let bd_id = extract_id_from_create(&bd_create.stdout);
 
     // Run show command
-    let br_show = workspace.run_br(["show", &br_id], "show");
+    let br_show = workspace.run_br(["show", &br_id, "--absolute"], "show");
     let bd_show = workspace.run_bd(["show", &bd_id], "show");
 
     assert!(br_show.success, "br show failed: {}", br_show.stderr);
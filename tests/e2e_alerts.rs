@@ -0,0 +1,85 @@
+//! E2E tests for alert hooks.
+
+#![cfg(unix)]
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, run_br};
+use std::fs;
+
+#[test]
+fn e2e_alerts_command_runs_for_unblocked_and_overdue_issues() {
+    let _log = common::test_log("e2e_alerts_command_runs_for_unblocked_and_overdue_issues");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    // HOME is the workspace root, so this is the user config
+    let user_config = workspace.root.join(".config").join("beads");
+    fs::create_dir_all(&user_config).expect("user config dir");
+    fs::write(
+        user_config.join("config.yaml"),
+        "alerts:\n  command: 'echo \"$BR_ALERT $BR_ISSUE_ID\" >> alerts.log'\n",
+    )
+    .expect("write config");
+
+    let blocker = created_id(&workspace, &["create", "Blocker"]);
+    let waiting = created_id(&workspace, &["create", "Waiting"]);
+    let dep = run_br(&workspace, ["dep", "add", &waiting, &blocker], "dep");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    // The first check only records what it sees
+    let ready = run_br(&workspace, ["ready"], "ready");
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    let log_path = workspace.root.join("alerts.log");
+    assert!(!log_path.exists());
+
+    let close = run_br(&workspace, ["close", &blocker], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let late = created_id(
+        &workspace,
+        &["create", "Late", "--due", "2020-01-01T00:00:00Z"],
+    );
+    let ready = run_br(&workspace, ["ready"], "ready");
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    let log = fs::read_to_string(&log_path).expect("alerts log");
+    assert!(log.contains(&format!("unblocked {waiting}")), "log: {log}");
+    assert!(log.contains(&format!("overdue {late}")), "log: {log}");
+
+    // Already reported, so a later sync stays quiet
+    let sync = run_br(&workspace, ["sync", "--flush-only"], "sync");
+    assert!(sync.status.success(), "sync failed: {}", sync.stderr);
+    assert_eq!(fs::read_to_string(&log_path).expect("alerts log"), log);
+}
+
+#[test]
+fn e2e_alerts_in_project_config_are_rejected() {
+    let _log = common::test_log("e2e_alerts_in_project_config_are_rejected");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    fs::write(
+        workspace.root.join(".beads").join("config.yaml"),
+        "alerts:\n  command: 'touch pwned'\n",
+    )
+    .expect("write config");
+
+    let blocker = created_id(&workspace, &["create", "Blocker"]);
+    let waiting = created_id(&workspace, &["create", "Waiting"]);
+    let dep = run_br(&workspace, ["dep", "add", &waiting, &blocker], "dep");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+    let ready = run_br(&workspace, ["ready"], "ready");
+    let close = run_br(&workspace, ["close", &blocker], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let ready_again = run_br(&workspace, ["ready"], "ready_again");
+
+    for run in [&ready, &ready_again] {
+        assert!(run.status.success(), "ready failed: {}", run.stderr);
+        assert!(
+            run.stderr
+                .contains("alerts.command cannot be set in .beads/config.yaml"),
+            "stderr: {}",
+            run.stderr
+        );
+    }
+    assert!(!workspace.root.join("pwned").exists());
+}
@@ -5,6 +5,7 @@ use chrono::Utc;
 use common::cli::{BrWorkspace, extract_json_payload, run_br};
 use common::wait_for_next_second;
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::fs;
 
 fn parse_created_id(stdout: &str) -> String {
@@ -62,6 +63,10 @@ fn make_issue(id: &str, title: &str, now: chrono::DateTime<Utc>) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
@@ -375,6 +380,52 @@ fn e2e_no_db_mixed_prefix_error() {
     );
 }
 
+#[test]
+fn e2e_no_db_never_creates_database() {
+    let workspace = BrWorkspace::new();
+    let beads_dir = workspace.root.join(".beads");
+    fs::create_dir_all(&beads_dir).expect("create .beads");
+    fs::write(beads_dir.join("config.yaml"), "issue_prefix: bd\n").expect("write config");
+    let jsonl_path = beads_dir.join("issues.jsonl");
+    fs::write(&jsonl_path, "").expect("write jsonl");
+
+    let create = run_br(
+        &workspace,
+        ["--no-db", "create", "Only in JSONL"],
+        "create_no_db_only",
+    );
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let id = parse_created_id(&create.stdout);
+
+    let close = run_br(&workspace, ["--no-db", "close", &id], "close_no_db_only");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let list = run_br(
+        &workspace,
+        ["--no-db", "list", "--all", "--json"],
+        "list_no_db_only",
+    );
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let list_json: Vec<Value> =
+        serde_json::from_str(&extract_json_payload(&list.stdout)).expect("list json");
+    assert!(
+        list_json
+            .iter()
+            .any(|item| item["id"] == id.as_str() && item["status"] == "closed"),
+        "closed issue missing from no-db list"
+    );
+
+    let contents = fs::read_to_string(&jsonl_path).expect("read jsonl");
+    assert!(contents.contains("Only in JSONL"), "JSONL missing issue");
+    let db_files: Vec<_> = fs::read_dir(&beads_dir)
+        .expect("read .beads")
+        .filter_map(Result::ok)
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| name.contains(".db"))
+        .collect();
+    assert!(db_files.is_empty(), "--no-db created {db_files:?}");
+}
+
 #[test]
 fn e2e_sync_manifest() {
     let workspace = BrWorkspace::new();
@@ -1,8 +1,9 @@
 //! Atomic claim guard tests — verifies TOCTOU-safe claiming via IMMEDIATE transactions.
 
 use beads_rust::model::{Priority, Status};
-use beads_rust::storage::{IssueUpdate, SqliteStorage};
+use beads_rust::storage::{IssueUpdate, ReadyFilters, ReadySortPolicy, SqliteStorage};
 use chrono::{TimeZone, Utc};
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 use std::sync::{Arc, Barrier};
 use std::thread;
@@ -50,6 +51,10 @@ fn seed_issue(storage: &mut SqliteStorage, id: &str, assignee: Option<&str>) {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     };
     storage.create_issue(&issue, "seed").unwrap();
 }
@@ -290,3 +295,51 @@ fn test_non_claim_update_skips_guard() {
     let result = storage.update_issue("test-nc", &update, "alice");
     assert!(result.is_ok(), "Non-claim update should not check assignee");
 }
+
+#[test]
+fn test_concurrent_claim_ready_never_shares_issue() {
+    let tmp = tempfile::NamedTempFile::new().unwrap();
+    let db_path = tmp.path().to_str().unwrap();
+
+    {
+        let mut storage = SqliteStorage::open(Path::new(db_path)).unwrap();
+        seed_issue(&mut storage, "ready-1", None);
+        seed_issue(&mut storage, "ready-2", None);
+    }
+
+    let barrier = Arc::new(Barrier::new(2));
+    let path = db_path.to_string();
+
+    let handles: Vec<_> = ["alice", "bob"]
+        .iter()
+        .map(|actor| {
+            let barrier = Arc::clone(&barrier);
+            let path = path.clone();
+            let actor = actor.to_string();
+            thread::spawn(move || {
+                let mut storage = SqliteStorage::open(Path::new(&path)).unwrap();
+                barrier.wait();
+                storage.claim_ready_issues(
+                    &ReadyFilters::default(),
+                    ReadySortPolicy::Oldest,
+                    1,
+                    &HashSet::new(),
+                    &actor,
+                )
+            })
+        })
+        .collect();
+
+    let mut claimed_ids: Vec<String> = handles
+        .into_iter()
+        .flat_map(|h| h.join().unwrap().unwrap())
+        .map(|issue| issue.id)
+        .collect();
+    claimed_ids.sort();
+
+    assert_eq!(
+        claimed_ids,
+        vec!["ready-1".to_string(), "ready-2".to_string()],
+        "Each agent should claim a distinct issue"
+    );
+}
@@ -0,0 +1,87 @@
+//! E2E tests for closing parents: `close --cascade` and `close.children`.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, json_output, run_br};
+use std::fs;
+
+fn status(workspace: &BrWorkspace, id: &str) -> String {
+    let show = run_br(workspace, ["show", id, "--json"], "show");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    json_output(&show.stdout)[0]["status"]
+        .as_str()
+        .expect("status")
+        .to_string()
+}
+
+#[test]
+fn e2e_close_cascade_closes_descendants() {
+    let _log = common::test_log("e2e_close_cascade_closes_descendants");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let epic = created_id(&workspace, &["create", "Epic", "-t", "epic"]);
+    let child = created_id(&workspace, &["create", "Child", "--parent", &epic]);
+    let grandchild = created_id(&workspace, &["create", "Grandchild", "--parent", &child]);
+
+    let close = run_br(
+        &workspace,
+        ["close", &epic, "--cascade", "-r", "shipped", "--json"],
+        "close_cascade",
+    );
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let closed = json_output(&close.stdout);
+    let closed = closed.as_array().expect("array");
+    let ids: Vec<&str> = closed
+        .iter()
+        .map(|issue| issue["id"].as_str().expect("id"))
+        .collect();
+    assert_eq!(
+        ids,
+        vec![grandchild.as_str(), child.as_str(), epic.as_str()]
+    );
+    assert_eq!(
+        closed[0]["close_reason"],
+        format!("shipped (closed with {epic})").as_str()
+    );
+    assert_eq!(closed[2]["close_reason"], "shipped");
+    assert_eq!(status(&workspace, &grandchild), "closed");
+}
+
+#[test]
+fn e2e_close_children_block_and_warn() {
+    let _log = common::test_log("e2e_close_children_block_and_warn");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let epic = created_id(&workspace, &["create", "Epic", "-t", "epic"]);
+    let child = created_id(&workspace, &["create", "Child", "--parent", &epic]);
+
+    // Default (warn): the parent closes and the open child is reported
+    let close = run_br(&workspace, ["close", &epic], "close_warn");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let output = format!("{}{}", close.stdout, close.stderr);
+    assert!(output.contains("open children"), "{output}");
+    assert!(output.contains(&child), "{output}");
+    assert_eq!(status(&workspace, &child), "open");
+
+    let reopen = run_br(&workspace, ["reopen", &epic], "reopen");
+    assert!(reopen.status.success(), "reopen failed: {}", reopen.stderr);
+
+    let config_path = workspace.root.join(".beads").join("config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str("\nclose:\n  children: block\n");
+    fs::write(&config_path, config).expect("write config");
+
+    let close = run_br(&workspace, ["close", &epic], "close_block");
+    assert!(!close.status.success(), "parent closed with open children");
+    assert_eq!(status(&workspace, &epic), "open");
+
+    let close = run_br(&workspace, ["close", &epic, "--force"], "close_force");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    assert_eq!(status(&workspace, &epic), "closed");
+}
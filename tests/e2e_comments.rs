@@ -6,6 +6,7 @@
 //! - JSON output validation
 //! - Error cases (non-existent issues, empty comments)
 //! - Edge cases (special characters, long comments, closed issues)
+//! - `br inbox` for @mentions and assignments
 
 mod common;
 
@@ -254,6 +255,54 @@ fn e2e_comments_add_nonexistent_issue() {
     );
 }
 
+/// Editing or deleting a missing or deleted comment is "not found" (exit 3)
+#[test]
+fn e2e_comments_edit_missing_comment_not_found() {
+    let _log = common::test_log("e2e_comments_edit_missing_comment_not_found");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let create = run_br(&workspace, ["create", "Comment target"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let id = parse_created_id(&create.stdout);
+
+    let add = run_br(
+        &workspace,
+        ["comments", "add", &id, "Soon gone", "--json"],
+        "add_comment",
+    );
+    assert!(add.status.success(), "add comment failed: {}", add.stderr);
+    let comment: Value = serde_json::from_str(&extract_json_payload(&add.stdout)).expect("json");
+    let comment_id = comment["id"].as_i64().expect("comment id").to_string();
+
+    let edit = run_br(
+        &workspace,
+        ["comments", "edit", &id, "999", "New text", "--json"],
+        "edit_missing",
+    );
+    assert_eq!(edit.status.code(), Some(3), "stderr: {}", edit.stderr);
+    assert!(
+        edit.stdout.contains("COMMENT_NOT_FOUND") || edit.stderr.contains("COMMENT_NOT_FOUND"),
+        "expected COMMENT_NOT_FOUND: {} {}",
+        edit.stdout,
+        edit.stderr
+    );
+
+    let delete = run_br(
+        &workspace,
+        ["comments", "delete", &id, &comment_id],
+        "delete",
+    );
+    assert!(delete.status.success(), "delete failed: {}", delete.stderr);
+    let again = run_br(
+        &workspace,
+        ["comments", "delete", &id, &comment_id],
+        "delete_again",
+    );
+    assert_eq!(again.status.code(), Some(3), "stderr: {}", again.stderr);
+}
+
 /// Test 6: Add empty comment → error or rejection
 #[test]
 fn e2e_comments_add_empty() {
@@ -610,3 +659,156 @@ fn e2e_comments_sync_roundtrip() {
     assert!(texts.contains(&"First sync comment"));
     assert!(texts.contains(&"Second sync comment"));
 }
+
+#[test]
+fn e2e_inbox_mentions_and_assignments() {
+    let _log = common::test_log("e2e_inbox_mentions_and_assignments");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let create = run_br(
+        &workspace,
+        ["--actor", "bob", "create", "Needs review"],
+        "create",
+    );
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let id = parse_created_id(&create.stdout);
+    let mention = run_br(
+        &workspace,
+        [
+            "--actor",
+            "bob",
+            "comments",
+            "add",
+            &id,
+            "@Alice can you review?",
+        ],
+        "mention",
+    );
+    assert!(
+        mention.status.success(),
+        "comment failed: {}",
+        mention.stderr
+    );
+    let assign = run_br(
+        &workspace,
+        ["--actor", "carol", "update", &id, "--assignee", "alice"],
+        "assign",
+    );
+    assert!(assign.status.success(), "assign failed: {}", assign.stderr);
+
+    let inbox = run_br(&workspace, ["--actor", "alice", "inbox", "--json"], "inbox");
+    assert!(inbox.status.success(), "inbox failed: {}", inbox.stderr);
+    let payload: Value = serde_json::from_str(&extract_json_payload(&inbox.stdout)).expect("json");
+    assert_eq!(payload["unread"], 2);
+    let kinds: Vec<&str> = payload["items"]
+        .as_array()
+        .expect("items")
+        .iter()
+        .filter_map(|item| item["kind"].as_str())
+        .collect();
+    assert!(
+        kinds.contains(&"mention") && kinds.contains(&"assigned"),
+        "{kinds:?}"
+    );
+
+    // Listing marked everything read
+    let again = run_br(
+        &workspace,
+        ["--actor", "alice", "inbox", "--json"],
+        "inbox_again",
+    );
+    let payload: Value = serde_json::from_str(&extract_json_payload(&again.stdout)).expect("json");
+    assert_eq!(payload["unread"], 0);
+    assert!(payload["items"].as_array().expect("items").is_empty());
+
+    let all = run_br(
+        &workspace,
+        ["--actor", "alice", "inbox", "--all", "--json"],
+        "inbox_all",
+    );
+    let payload: Value = serde_json::from_str(&extract_json_payload(&all.stdout)).expect("json");
+    assert_eq!(payload["items"].as_array().map(Vec::len), Some(2));
+
+    // Bob's own comment does not land in his inbox
+    let bob = run_br(
+        &workspace,
+        ["--actor", "bob", "inbox", "--json"],
+        "inbox_bob",
+    );
+    let payload: Value = serde_json::from_str(&extract_json_payload(&bob.stdout)).expect("json");
+    assert_eq!(payload["unread"], 0);
+}
+
+#[test]
+fn e2e_watch_feeds_inbox_and_exports_watchers() {
+    let _log = common::test_log("e2e_watch_feeds_inbox_and_exports_watchers");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let create = run_br(&workspace, ["create", "Watched work"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let id = parse_created_id(&create.stdout);
+
+    let watch = run_br(&workspace, ["--actor", "alice", "watch", &id], "watch");
+    assert!(watch.status.success(), "watch failed: {}", watch.stderr);
+    let again = run_br(
+        &workspace,
+        ["--actor", "Alice", "watch", &id, "--json"],
+        "watch_again",
+    );
+    let payload: Value = serde_json::from_str(&extract_json_payload(&again.stdout)).expect("json");
+    assert_eq!(payload[0]["action"], "unchanged");
+
+    let update = run_br(
+        &workspace,
+        ["--actor", "bob", "update", &id, "--status", "in_progress"],
+        "update",
+    );
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+
+    let inbox = run_br(&workspace, ["--actor", "alice", "inbox", "--json"], "inbox");
+    let payload: Value = serde_json::from_str(&extract_json_payload(&inbox.stdout)).expect("json");
+    let items = payload["items"].as_array().expect("items");
+    assert!(
+        items.iter().any(|item| item["kind"] == "watched"
+            && item["by"] == "bob"
+            && item["event"] == "status_changed"),
+        "{items:?}"
+    );
+
+    let list = run_br(
+        &workspace,
+        ["--actor", "alice", "watch", "--json"],
+        "watch_list",
+    );
+    let payload: Value = serde_json::from_str(&extract_json_payload(&list.stdout)).expect("json");
+    assert_eq!(payload[0]["id"], id.as_str());
+
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let jsonl = std::fs::read_to_string(workspace.root.join(".beads").join("issues.jsonl"))
+        .expect("read jsonl");
+    assert!(jsonl.contains(r#""watchers":["alice"]"#), "{jsonl}");
+
+    let unwatch = run_br(
+        &workspace,
+        ["--actor", "alice", "watch", &id, "--remove"],
+        "unwatch",
+    );
+    assert!(
+        unwatch.status.success(),
+        "unwatch failed: {}",
+        unwatch.stderr
+    );
+    let list = run_br(
+        &workspace,
+        ["--actor", "alice", "watch", "--json"],
+        "watch_list_empty",
+    );
+    let payload: Value = serde_json::from_str(&extract_json_payload(&list.stdout)).expect("json");
+    assert!(payload.as_array().expect("array").is_empty());
+}
@@ -674,3 +674,50 @@ fn e2e_completions_all_shells_file_output() {
     }
     info!("e2e_completions_all_shells_file_output: done");
 }
+
+#[test]
+fn e2e_complete_ids_helper_lists_workspace_candidates() {
+    common::init_test_logging();
+    info!("e2e_complete_ids_helper_lists_workspace_candidates: start");
+    let workspace = BrWorkspace::new();
+    init_workspace(&workspace);
+
+    let create = run_br(
+        &workspace,
+        ["create", "Completable", "--labels", "frontend"],
+        "create_completable",
+    );
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+
+    let ids = run_br(&workspace, ["__complete-ids", "bd-"], "complete_ids");
+    assert!(ids.status.success(), "helper failed: {}", ids.stderr);
+    let lines: Vec<&str> = ids.stdout.lines().collect();
+    assert_eq!(lines.len(), 1, "one issue expected: {}", ids.stdout);
+    assert!(lines[0].starts_with("bd-"));
+
+    let described = run_br(
+        &workspace,
+        ["__complete-ids", "--describe"],
+        "complete_ids_describe",
+    );
+    assert!(described.stdout.contains("\topen | Completable"));
+
+    let labels = run_br(
+        &workspace,
+        ["__complete-ids", "front", "--kind", "labels"],
+        "complete_labels",
+    );
+    assert_eq!(labels.stdout.trim(), "frontend");
+
+    let statuses = run_br(
+        &workspace,
+        ["__complete-ids", "in", "--kind", "statuses"],
+        "complete_statuses",
+    );
+    assert_eq!(statuses.stdout.trim(), "in_progress");
+
+    // Hidden from help
+    let help = run_br(&workspace, ["--help"], "help");
+    assert!(!help.stdout.contains("__complete-ids"));
+    info!("e2e_complete_ids_helper_lists_workspace_candidates: done");
+}
@@ -0,0 +1,73 @@
+//! E2E tests for `conditional-blocks` dependencies with a `--condition`.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, json_output, run_br};
+
+fn is_ready(workspace: &BrWorkspace, id: &str) -> bool {
+    let ready = run_br(workspace, ["ready", "--json"], "ready");
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    json_output(&ready.stdout)
+        .as_array()
+        .expect("array")
+        .iter()
+        .any(|issue| issue["id"] == id)
+}
+
+#[test]
+fn e2e_conditional_blocks_only_while_condition_holds() {
+    let _log = common::test_log("e2e_conditional_blocks_only_while_condition_holds");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let blocker = created_id(&workspace, &["create", "Flaky upload"]);
+    let release = created_id(&workspace, &["create", "Cut release"]);
+
+    let bad = run_br(
+        &workspace,
+        ["dep", "add", &release, &blocker, "--condition", "label:x"],
+        "dep_add_not_conditional",
+    );
+    assert!(!bad.status.success(), "condition accepted on a blocks dep");
+
+    let add = run_br(
+        &workspace,
+        [
+            "dep",
+            "add",
+            &release,
+            &blocker,
+            "--type",
+            "conditional-blocks",
+            "--condition",
+            "label:release-blocker",
+        ],
+        "dep_add",
+    );
+    assert!(add.status.success(), "dep add failed: {}", add.stderr);
+    assert!(is_ready(&workspace, &release));
+
+    let label = run_br(
+        &workspace,
+        ["label", "add", &blocker, "release-blocker"],
+        "label_add",
+    );
+    assert!(label.status.success(), "label failed: {}", label.stderr);
+    assert!(!is_ready(&workspace, &release));
+
+    let list = run_br(&workspace, ["dep", "list", &release, "--json"], "dep_list");
+    assert!(list.status.success(), "dep list failed: {}", list.stderr);
+    assert_eq!(
+        json_output(&list.stdout)[0]["condition"],
+        "label:release-blocker"
+    );
+    let list = run_br(&workspace, ["dep", "list", &release], "dep_list_text");
+    assert!(
+        list.stdout
+            .contains("conditional-blocks if label:release-blocker"),
+        "{}",
+        list.stdout
+    );
+}
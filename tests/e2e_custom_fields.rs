@@ -0,0 +1,93 @@
+//! E2E tests for custom fields: `update --field`, `list --where`, export.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, json_output, run_br};
+use std::fs;
+
+fn listed_ids(workspace: &BrWorkspace, filter: &str) -> Vec<String> {
+    let list = run_br(workspace, ["list", "--where", filter, "--json"], "list");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let mut ids: Vec<String> = json_output(&list.stdout)
+        .as_array()
+        .expect("array")
+        .iter()
+        .map(|issue| issue["id"].as_str().expect("id").to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn e2e_custom_fields_set_filter_and_export() {
+    let _log = common::test_log("e2e_custom_fields_set_filter_and_export");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let config_path = workspace.root.join(".beads").join("config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str("\nfields:\n  sprint: int\n  team: enum(core, web)\n");
+    fs::write(&config_path, config).expect("write config");
+
+    let early = created_id(&workspace, &["create", "Early work"]);
+    let late = created_id(&workspace, &["create", "Late work"]);
+    let unplanned = created_id(&workspace, &["create", "Unplanned work"]);
+
+    let set = run_br(
+        &workspace,
+        [
+            "update",
+            &early,
+            "--field",
+            "sprint=9",
+            "--field",
+            "team=Core",
+        ],
+        "update_early",
+    );
+    assert!(set.status.success(), "update failed: {}", set.stderr);
+    let set = run_br(
+        &workspace,
+        ["update", &late, "--field", "sprint=12"],
+        "update_late",
+    );
+    assert!(set.status.success(), "update failed: {}", set.stderr);
+
+    // Values are checked against the declared type
+    let bad = run_br(
+        &workspace,
+        ["update", &late, "--field", "sprint=soon"],
+        "update_bad_int",
+    );
+    assert!(!bad.status.success(), "non-integer sprint was accepted");
+    let bad = run_br(
+        &workspace,
+        ["update", &late, "--field", "points=3"],
+        "update_undeclared",
+    );
+    assert!(!bad.status.success(), "undeclared field was accepted");
+
+    // Integers compare numerically
+    assert_eq!(listed_ids(&workspace, "sprint>=10"), vec![late]);
+    assert_eq!(listed_ids(&workspace, "team=core"), vec![early.clone()]);
+    assert_eq!(listed_ids(&workspace, "sprint="), vec![unplanned]);
+    let bad = run_br(&workspace, ["list", "--where", "team>core"], "list_bad_op");
+    assert!(
+        !bad.status.success(),
+        "ordered filter on an enum was accepted"
+    );
+
+    let show = run_br(&workspace, ["show", &early, "--json"], "show");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    assert_eq!(json_output(&show.stdout)[0]["fields"]["team"], "core");
+
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let jsonl =
+        fs::read_to_string(workspace.root.join(".beads").join("issues.jsonl")).expect("read jsonl");
+    assert!(
+        jsonl.contains(r#""fields":{"sprint":"9","team":"core"}"#),
+        "{jsonl}"
+    );
+}
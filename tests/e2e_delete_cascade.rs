@@ -0,0 +1,93 @@
+//! E2E tests for the `br delete` dependents preview and `--cascade`.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, json_output, run_br};
+use serde_json::Value;
+
+fn ids(value: &Value) -> Vec<String> {
+    let mut ids: Vec<String> = value
+        .as_array()
+        .expect("array")
+        .iter()
+        .map(|id| id.as_str().expect("id").to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn e2e_delete_previews_subtree_and_cascades() {
+    let _log = common::test_log("e2e_delete_previews_subtree_and_cascades");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let root = created_id(&workspace, &["create", "Drop the legacy API"]);
+    let child = created_id(&workspace, &["create", "Migrate clients"]);
+    let grandchild = created_id(&workspace, &["create", "Remove client shims"]);
+    let other = created_id(&workspace, &["create", "Unrelated work"]);
+    for (issue, depends_on) in [(&child, &root), (&grandchild, &child)] {
+        let add = run_br(&workspace, ["dep", "add", issue, depends_on], "dep_add");
+        assert!(add.status.success(), "dep add failed: {}", add.stderr);
+    }
+
+    let preview = run_br(&workspace, ["delete", &root, "--json"], "preview");
+    assert!(
+        preview.status.success(),
+        "preview failed: {}",
+        preview.stderr
+    );
+    let plan = json_output(&preview.stdout);
+    assert_eq!(plan["preview"], true);
+    assert_eq!(ids(&plan["dependents"]), vec![child.clone()]);
+    let mut subtree = vec![child.clone(), grandchild.clone()];
+    subtree.sort();
+    assert_eq!(ids(&plan["cascade"]), subtree);
+    assert_eq!(plan["edges"].as_array().expect("edges").len(), 1);
+    assert_eq!(plan["edges"][0]["issue_id"], child.as_str());
+
+    let text = run_br(&workspace, ["delete", &root], "preview_text");
+    assert!(
+        text.stdout
+            .contains(&format!("{child} depends on {root} (blocks)")),
+        "{}",
+        text.stdout
+    );
+
+    let dry = run_br(
+        &workspace,
+        ["delete", &root, "--cascade", "--dry-run", "--json"],
+        "dry_run",
+    );
+    assert!(dry.status.success(), "dry run failed: {}", dry.stderr);
+    assert_eq!(
+        json_output(&dry.stdout)["edges"]
+            .as_array()
+            .expect("edges")
+            .len(),
+        2
+    );
+
+    let delete = run_br(
+        &workspace,
+        ["delete", &root, "--cascade", "--json"],
+        "delete_cascade",
+    );
+    assert!(delete.status.success(), "delete failed: {}", delete.stderr);
+    let result = json_output(&delete.stdout);
+    assert_eq!(result["deleted_count"], 3);
+    assert_eq!(result["dependencies_removed"], 2);
+
+    let list = run_br(&workspace, ["list", "--json"], "list");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let remaining = json_output(&list.stdout);
+    let listed: Vec<&str> = remaining
+        .as_array()
+        .expect("array")
+        .iter()
+        .map(|issue| issue["id"].as_str().expect("id"))
+        .collect();
+    assert_eq!(listed, vec![other.as_str()]);
+}
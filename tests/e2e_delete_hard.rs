@@ -0,0 +1,66 @@
+//! E2E tests for `br delete`: tombstones by default, removal with `--hard`.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, json_output, run_br};
+use std::fs;
+
+fn jsonl_line(workspace: &BrWorkspace, id: &str) -> Option<String> {
+    let flush = run_br(workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    fs::read_to_string(workspace.root.join(".beads").join("issues.jsonl"))
+        .expect("read jsonl")
+        .lines()
+        .find(|line| line.contains(&format!(r#""id":"{id}""#)))
+        .map(str::to_string)
+}
+
+#[test]
+fn e2e_delete_tombstones_and_hard_delete_removes() {
+    let _log = common::test_log("e2e_delete_tombstones_and_hard_delete_removes");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let soft = created_id(&workspace, &["create", "Old idea"]);
+    let leaked = created_id(&workspace, &["create", "Pasted a token by mistake"]);
+    let kept = created_id(&workspace, &["create", "Real work"]);
+    assert!(jsonl_line(&workspace, &leaked).is_some());
+
+    let delete = run_br(
+        &workspace,
+        ["delete", &soft, "--reason", "obsolete"],
+        "delete",
+    );
+    assert!(delete.status.success(), "delete failed: {}", delete.stderr);
+    let line = jsonl_line(&workspace, &soft).expect("tombstone exported");
+    assert!(line.contains(r#""status":"tombstone""#), "{line}");
+    assert!(line.contains(r#""delete_reason":"obsolete""#), "{line}");
+
+    let delete = run_br(
+        &workspace,
+        ["delete", &leaked, "--hard", "--json"],
+        "delete_hard",
+    );
+    assert!(delete.status.success(), "delete failed: {}", delete.stderr);
+    assert_eq!(json_output(&delete.stdout)["hard"], true);
+    assert!(jsonl_line(&workspace, &leaked).is_none());
+    let show = run_br(&workspace, ["show", &leaked], "show_purged");
+    assert!(!show.status.success(), "purged issue still shown");
+
+    let list = run_br(&workspace, ["list", "--json"], "list");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let ids: Vec<String> = json_output(&list.stdout)
+        .as_array()
+        .expect("array")
+        .iter()
+        .map(|issue| issue["id"].as_str().expect("id").to_string())
+        .collect();
+    assert_eq!(ids, vec![kept.clone()]);
+
+    // Later exports still work
+    let update = run_br(&workspace, ["update", &kept, "-p", "1"], "update");
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+    assert!(jsonl_line(&workspace, &kept).is_some());
+}
@@ -0,0 +1,79 @@
+//! End-to-end tests for `br diff`.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, json_output, run_br};
+use serde_json::Value;
+
+fn diff_json<const N: usize>(workspace: &BrWorkspace, args: [&str; N], label: &str) -> Value {
+    let out = run_br(workspace, args, label);
+    assert!(out.status.success(), "diff failed: {}", out.stderr);
+    json_output(&out.stdout)
+}
+
+#[test]
+fn e2e_diff_reports_created_closed_modified() {
+    let _log = common::test_log("e2e_diff_reports_created_closed_modified");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init", "--prefix", "df"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    created_id(&workspace, &["create", "Keep me", "--no-auto-flush"]);
+    let to_close = created_id(&workspace, &["create", "Close me", "--no-auto-flush"]);
+    let to_edit = created_id(&workspace, &["create", "Edit me", "--no-auto-flush"]);
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let before = workspace.root.join("before.jsonl");
+    std::fs::copy(workspace.root.join(".beads").join("issues.jsonl"), &before).unwrap();
+
+    let close = run_br(&workspace, ["--no-auto-flush", "close", &to_close], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let update = run_br(
+        &workspace,
+        ["--no-auto-flush", "update", &to_edit, "--priority", "0"],
+        "update",
+    );
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+    let created = created_id(&workspace, &["create", "Brand new", "--no-auto-flush"]);
+
+    // Bare diff: last export vs working database
+    let json = diff_json(&workspace, ["diff", "--json"], "diff_db");
+    assert_eq!(json["to"], "database");
+    assert_eq!(json["identical"], false);
+    assert_eq!(json["created"][0]["id"], created.as_str());
+    assert_eq!(json["closed"][0]["id"], to_close.as_str());
+    assert_eq!(json["modified"][0]["id"], to_edit.as_str());
+    let changes = json["modified"][0]["changes"].as_array().unwrap();
+    assert_eq!(changes.len(), 1, "only priority changed: {changes:?}");
+    assert_eq!(changes[0]["field"], "priority");
+    assert_eq!(changes[0]["new"], 0);
+    assert_eq!(json["unchanged"], 1);
+
+    let text = run_br(&workspace, ["diff"], "diff_text");
+    assert!(text.status.success(), "diff failed: {}", text.stderr);
+    assert!(text.stdout.contains("Created (1):"), "{}", text.stdout);
+    assert!(text.stdout.contains("priority: 2 → 0"), "{}", text.stdout);
+
+    // After a flush the export matches the database
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush_again");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let json = diff_json(&workspace, ["diff", "--json"], "diff_clean");
+    assert_eq!(json["identical"], true);
+
+    // Two files
+    let before_str = before.to_string_lossy().to_string();
+    let current = workspace.root.join(".beads").join("issues.jsonl");
+    let current_str = current.to_string_lossy().to_string();
+    let json = diff_json(
+        &workspace,
+        ["diff", &before_str, &current_str, "--json"],
+        "diff_files",
+    );
+    assert_eq!(json["created"].as_array().map(Vec::len), Some(1));
+    assert_eq!(json["closed"].as_array().map(Vec::len), Some(1));
+    assert_eq!(json["modified"].as_array().map(Vec::len), Some(1));
+
+    let missing = run_br(&workspace, ["diff", "nope.jsonl"], "diff_missing");
+    assert!(!missing.status.success());
+    assert!(missing.stderr.contains("not found"), "{}", missing.stderr);
+}
@@ -512,14 +512,15 @@ fn e2e_lint_skips_types_without_required_sections() {
 
 // === Structured JSON Error Output Tests ===
 
-/// Parse structured error JSON from stderr.
-/// This handles the case where log lines may precede the JSON output.
+/// Parse structured error JSON from command output.
+/// With `--json` the envelope is written to stdout; this also handles the case
+/// where log lines precede it.
 ///
 /// Tracing may emit lines like `DEBUG span{key=value}: message` before the error
-/// envelope; the first `{` in stderr is not necessarily the start of JSON, so we
-/// try each `{` offset until one parses as an object with an `"error"` field.
-fn parse_error_json(stderr: &str) -> Option<Value> {
-    let trimmed = stderr.trim();
+/// envelope; the first `{` is not necessarily the start of JSON, so we try each
+/// `{` offset until one parses as an object with an `"error"` field.
+fn parse_error_json(output: &str) -> Option<Value> {
+    let trimmed = output.trim();
     if let Ok(json) = serde_json::from_str::<Value>(trimmed) {
         if json.get("error").is_some() {
             return Some(json);
@@ -527,9 +528,9 @@ fn parse_error_json(stderr: &str) -> Option<Value> {
     }
 
     let mut search_start = 0;
-    while let Some(rel) = stderr[search_start..].find('{') {
+    while let Some(rel) = output[search_start..].find('{') {
         let start = search_start + rel;
-        let tail = &stderr[start..];
+        let tail = &output[start..];
         if let Ok(json) = serde_json::from_str::<Value>(tail) {
             if json.get("error").is_some() {
                 return Some(json);
@@ -563,9 +564,9 @@ fn e2e_structured_error_not_initialized() {
     // Don't init - test NOT_INITIALIZED error
     let result = run_br(&workspace, ["list", "--json"], "list_not_init_json");
     assert!(!result.status.success());
-    assert_eq!(result.status.code(), Some(2), "exit code should be 2");
+    assert_eq!(result.status.code(), Some(5), "exit code should be 5");
 
-    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    let json = parse_error_json(&result.stdout).expect("should be valid JSON");
     assert!(verify_error_structure(&json), "missing required fields");
 
     let error = &json["error"];
@@ -590,14 +591,31 @@ fn e2e_structured_error_issue_not_found() {
     assert!(!result.status.success());
     assert_eq!(result.status.code(), Some(3), "exit code should be 3");
 
-    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    let json = parse_error_json(&result.stdout).expect("should be valid JSON");
     assert!(verify_error_structure(&json), "missing required fields");
 
     let error = &json["error"];
     assert_eq!(error["code"], "ISSUE_NOT_FOUND");
+    assert_eq!(error["id"], "bd-nonexistent");
     assert!(!error["retryable"].as_bool().unwrap());
     assert!(error["context"]["searched_id"].is_string());
     assert!(error["hint"].as_str().unwrap().contains("br list"));
+    // The plain message still goes to stderr for logs
+    assert!(result.stderr.contains("Issue not found"));
+}
+
+#[test]
+fn e2e_structured_error_robot_flag_writes_stdout() {
+    let _log = common::test_log("e2e_structured_error_robot_flag_writes_stdout");
+    let workspace = BrWorkspace::new();
+
+    let result = run_br(&workspace, ["ready", "--robot"], "ready_not_init_robot");
+    assert!(!result.status.success());
+    assert_eq!(result.status.code(), Some(5), "exit code should be 5");
+
+    let json = parse_error_json(&result.stdout).expect("envelope on stdout");
+    assert!(verify_error_structure(&json), "missing required fields");
+    assert_eq!(json["error"]["code"], "NOT_INITIALIZED");
 }
 
 #[test]
@@ -618,9 +636,9 @@ fn e2e_structured_error_invalid_status() {
         "update_status_done_json",
     );
     assert!(!result.status.success());
-    assert_eq!(result.status.code(), Some(4), "exit code should be 4");
+    assert_eq!(result.status.code(), Some(2), "exit code should be 2");
 
-    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    let json = parse_error_json(&result.stdout).expect("should be valid JSON");
     assert!(verify_error_structure(&json), "missing required fields");
 
     let error = &json["error"];
@@ -660,9 +678,9 @@ fn e2e_structured_error_cycle_detected() {
         "dep_cycle_json",
     );
     assert!(!result.status.success());
-    assert_eq!(result.status.code(), Some(5), "exit code should be 5");
+    assert_eq!(result.status.code(), Some(4), "exit code should be 4");
 
-    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    let json = parse_error_json(&result.stdout).expect("should be valid JSON");
     assert!(verify_error_structure(&json), "missing required fields");
 
     let error = &json["error"];
@@ -689,9 +707,9 @@ fn e2e_structured_error_self_dependency() {
         "dep_self_json",
     );
     assert!(!result.status.success());
-    assert_eq!(result.status.code(), Some(5), "exit code should be 5");
+    assert_eq!(result.status.code(), Some(2), "exit code should be 2");
 
-    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    let json = parse_error_json(&result.stdout).expect("should be valid JSON");
     assert!(verify_error_structure(&json), "missing required fields");
 
     let error = &json["error"];
@@ -748,9 +766,9 @@ fn e2e_structured_error_ambiguous_id() {
         "show_ambiguous_json",
     );
     assert!(!result.status.success());
-    assert_eq!(result.status.code(), Some(3), "exit code should be 3");
+    assert_eq!(result.status.code(), Some(4), "exit code should be 4");
 
-    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    let json = parse_error_json(&result.stdout).expect("should be valid JSON");
     assert!(verify_error_structure(&json), "missing required fields");
 
     let error = &json["error"];
@@ -778,15 +796,15 @@ fn e2e_structured_error_jsonl_parse() {
         "import_bad_json",
     );
     assert!(!result.status.success());
-    // JSONL parse errors should be exit code 6 (sync errors) or 7 (config)
+    // JSONL parse errors should be exit code 7 (sync errors) or 8 (config)
     let exit_code = result.status.code().unwrap_or(0);
     assert!(
-        exit_code == 6 || exit_code == 7,
+        exit_code == 7 || exit_code == 8,
         "unexpected exit code: {exit_code}"
     );
 
     // The error output should be valid JSON
-    let json = parse_error_json(&result.stderr);
+    let json = parse_error_json(&result.stdout);
     if let Some(json) = json {
         assert!(verify_error_structure(&json), "missing required fields");
     }
@@ -868,9 +886,9 @@ fn e2e_structured_error_invalid_priority() {
         "create_invalid_priority_json",
     );
     assert!(!result.status.success());
-    assert_eq!(result.status.code(), Some(4), "exit code should be 4");
+    assert_eq!(result.status.code(), Some(2), "exit code should be 2");
 
-    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    let json = parse_error_json(&result.stdout).expect("should be valid JSON");
     assert!(verify_error_structure(&json), "missing required fields");
 
     let error = &json["error"];
@@ -937,7 +955,7 @@ fn e2e_error_text_vs_json_parity() {
     );
 
     // JSON mode should produce valid structured error
-    let json = parse_error_json(&json_result.stderr).expect("JSON mode should produce valid JSON");
+    let json = parse_error_json(&json_result.stdout).expect("JSON mode should produce valid JSON");
     assert!(
         verify_error_structure(&json),
         "JSON error should have required fields"
@@ -962,7 +980,7 @@ fn e2e_error_multiple_errors_same_exit_code() {
     assert!(create.status.success());
     let id = parse_created_id(&create.stdout);
 
-    // Validation errors should return exit code 4
+    // Validation errors should return exit code 2
     // Note: invalid type is NOT tested here because custom types are allowed
     let invalid_status = run_br(
         &workspace,
@@ -977,13 +995,13 @@ fn e2e_error_multiple_errors_same_exit_code() {
 
     assert_eq!(
         invalid_status.status.code(),
-        Some(4),
-        "invalid status should be exit 4"
+        Some(2),
+        "invalid status should be exit 2"
     );
     assert_eq!(
         invalid_priority.status.code(),
-        Some(4),
-        "invalid priority should be exit 4"
+        Some(2),
+        "invalid priority should be exit 2"
     );
 }
 
@@ -992,18 +1010,18 @@ fn e2e_error_exit_code_categories() {
     let _log = common::test_log("e2e_error_exit_code_categories");
     let workspace = BrWorkspace::new();
 
-    // Exit code 2: Database/initialization errors
+    // Exit code 5: Workspace not initialized
     let not_init = run_br(&workspace, ["list", "--json"], "not_init");
     assert_eq!(
         not_init.status.code(),
-        Some(2),
-        "NOT_INITIALIZED should be exit 2"
+        Some(5),
+        "NOT_INITIALIZED should be exit 5"
     );
 
     let init = run_br(&workspace, ["init"], "init");
     assert!(init.status.success());
 
-    // Exit code 3: Issue errors
+    // Exit code 3: Not found
     let not_found = run_br(&workspace, ["show", "bd-missing", "--json"], "not_found");
     assert_eq!(
         not_found.status.code(),
@@ -1011,9 +1029,16 @@ fn e2e_error_exit_code_categories() {
         "ISSUE_NOT_FOUND should be exit 3"
     );
 
-    // Exit code 4: Validation errors (already tested above)
+    // Exit code 2: Validation errors, including command-line usage errors
+    let usage = run_br(&workspace, ["list", "--no-such-flag"], "usage_error");
+    assert_eq!(
+        usage.status.code(),
+        Some(2),
+        "usage errors should be exit 2"
+    );
+    let help = run_br(&workspace, ["list", "--help"], "help");
+    assert_eq!(help.status.code(), Some(0), "--help should exit 0");
 
-    // Exit code 5: Dependency errors
     let create = run_br(&workspace, ["create", "Self dep"], "create_self");
     assert!(create.status.success());
     let id = parse_created_id(&create.stdout);
@@ -1021,8 +1046,26 @@ fn e2e_error_exit_code_categories() {
     let self_dep = run_br(&workspace, ["dep", "add", &id, &id, "--json"], "self_dep");
     assert_eq!(
         self_dep.status.code(),
-        Some(5),
-        "SELF_DEPENDENCY should be exit 5"
+        Some(2),
+        "SELF_DEPENDENCY should be exit 2"
+    );
+
+    // Exit code 4: Conflicts with existing state
+    let other = run_br(&workspace, ["create", "Other"], "create_other");
+    assert!(other.status.success());
+    let other_id = parse_created_id(&other.stdout);
+    let dep = run_br(&workspace, ["dep", "add", &id, &other_id], "dep_add");
+    assert!(dep.status.success());
+
+    let cycle = run_br(
+        &workspace,
+        ["dep", "add", &other_id, &id, "--json"],
+        "dep_cycle",
+    );
+    assert_eq!(
+        cycle.status.code(),
+        Some(4),
+        "CYCLE_DETECTED should be exit 4"
     );
 }
 
@@ -1047,9 +1090,9 @@ fn e2e_structured_error_label_validation() {
         "update_bad_label_json",
     );
     assert!(!result.status.success());
-    assert_eq!(result.status.code(), Some(4), "exit code should be 4");
+    assert_eq!(result.status.code(), Some(2), "exit code should be 2");
 
-    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    let json = parse_error_json(&result.stdout).expect("should be valid JSON");
     assert!(verify_error_structure(&json), "missing required fields");
 
     let error = &json["error"];
@@ -1082,9 +1125,9 @@ fn e2e_structured_error_label_too_long() {
         "update_long_label_json",
     );
     assert!(!result.status.success());
-    assert_eq!(result.status.code(), Some(4), "exit code should be 4");
+    assert_eq!(result.status.code(), Some(2), "exit code should be 2");
 
-    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    let json = parse_error_json(&result.stdout).expect("should be valid JSON");
     assert!(verify_error_structure(&json), "missing required fields");
 
     let error = &json["error"];
@@ -1117,7 +1160,7 @@ fn e2e_structured_error_dependency_target_not_found() {
         "exit code should be 3 (issue not found)"
     );
 
-    let json = parse_error_json(&result.stderr).expect("should be valid JSON");
+    let json = parse_error_json(&result.stdout).expect("should be valid JSON");
     assert!(verify_error_structure(&json), "missing required fields");
 
     let error = &json["error"];
@@ -1240,7 +1283,7 @@ fn e2e_validation_error_empty_label() {
         "update_empty_label_json",
     );
     assert!(!result.status.success());
-    assert_eq!(result.status.code(), Some(4), "exit code should be 4");
+    assert_eq!(result.status.code(), Some(2), "exit code should be 2");
 }
 
 #[test]
@@ -1328,7 +1371,7 @@ fn e2e_error_text_json_parity_validation() {
     );
 
     // JSON mode should produce valid structured error
-    let json = parse_error_json(&json_result.stderr).expect("JSON mode should produce valid JSON");
+    let json = parse_error_json(&json_result.stdout).expect("JSON mode should produce valid JSON");
     assert!(
         verify_error_structure(&json),
         "JSON error should have required fields"
@@ -0,0 +1,172 @@
+//! End-to-end tests for `br export` (CSV/TSV).
+
+mod common;
+
+use common::cli::{BrWorkspace, run_br};
+
+#[test]
+fn e2e_export_csv_and_tsv_columns() {
+    let _log = common::test_log("e2e_export_csv_and_tsv_columns");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init", "--prefix", "ex"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let create = run_br(&workspace, ["q", "Fix login, then deploy"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let id = create.stdout.trim().to_string();
+    let label = run_br(
+        &workspace,
+        [
+            "update",
+            &id,
+            "--add-label",
+            "backend",
+            "--add-label",
+            "urgent",
+        ],
+        "add_labels",
+    );
+    assert!(label.status.success(), "update failed: {}", label.stderr);
+
+    let csv = run_br(
+        &workspace,
+        ["export", "--columns", "id,title,labels"],
+        "export_csv",
+    );
+    assert!(csv.status.success(), "export failed: {}", csv.stderr);
+    let lines: Vec<&str> = csv.stdout.lines().collect();
+    assert_eq!(lines[0], "id,title,labels");
+    assert_eq!(
+        lines[1],
+        format!("{id},\"Fix login, then deploy\",backend;urgent")
+    );
+
+    let tsv_path = workspace.root.join("issues.tsv");
+    let tsv_path_str = tsv_path.to_string_lossy().to_string();
+    let tsv = run_br(
+        &workspace,
+        [
+            "export",
+            "--format",
+            "tsv",
+            "--columns",
+            "id,status",
+            "--no-header",
+            "-o",
+            &tsv_path_str,
+        ],
+        "export_tsv",
+    );
+    assert!(tsv.status.success(), "export tsv failed: {}", tsv.stderr);
+    let contents = std::fs::read_to_string(&tsv_path).expect("read tsv");
+    assert_eq!(contents, format!("{id}\topen\n"));
+
+    let bad = run_br(
+        &workspace,
+        ["export", "--columns", "id,bogus"],
+        "export_bad_column",
+    );
+    assert!(!bad.status.success(), "unknown column should be rejected");
+}
+
+#[test]
+fn e2e_export_ics_due_dates() {
+    let _log = common::test_log("e2e_export_ics_due_dates");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init", "--prefix", "ex"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let dated = run_br(
+        &workspace,
+        [
+            "create",
+            "Release v2",
+            "--due",
+            "2030-06-01T12:00:00Z",
+            "--silent",
+        ],
+        "create_dated",
+    );
+    assert!(dated.status.success(), "create failed: {}", dated.stderr);
+    let dated_id = dated.stdout.trim().to_string();
+    let undated = run_br(&workspace, ["q", "No deadline"], "create_undated");
+    assert!(
+        undated.status.success(),
+        "create failed: {}",
+        undated.stderr
+    );
+    let undated_id = undated.stdout.trim().to_string();
+
+    let ics = run_br(&workspace, ["export", "--format", "ics"], "export_ics");
+    assert!(ics.status.success(), "export ics failed: {}", ics.stderr);
+    assert!(ics.stdout.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.stdout.trim_end().ends_with("END:VCALENDAR"));
+    assert!(ics.stdout.contains(&format!("UID:{dated_id}-due@beads")));
+    assert!(ics.stdout.contains("DTSTART:20300601T120000Z"));
+    assert!(ics.stdout.contains("SUMMARY:[open] Due: Release v2"));
+    assert!(!ics.stdout.contains(&undated_id));
+
+    let with_columns = run_br(
+        &workspace,
+        ["export", "--format", "ics", "--columns", "id,title"],
+        "export_ics_columns",
+    );
+    assert_eq!(
+        with_columns.status.code(),
+        Some(2),
+        "--columns with ics should be rejected: {}",
+        with_columns.stderr
+    );
+}
+
+#[test]
+fn e2e_export_html_dashboard() {
+    let _log = common::test_log("e2e_export_html_dashboard");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init", "--prefix", "ex"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let blocker = run_br(&workspace, ["q", "Lay <foundations>"], "create_blocker");
+    assert!(
+        blocker.status.success(),
+        "create failed: {}",
+        blocker.stderr
+    );
+    let blocker_id = blocker.stdout.trim().to_string();
+    let waiting = run_br(&workspace, ["q", "Build walls"], "create_waiting");
+    assert!(
+        waiting.status.success(),
+        "create failed: {}",
+        waiting.stderr
+    );
+    let waiting_id = waiting.stdout.trim().to_string();
+    let dep = run_br(
+        &workspace,
+        ["dep", "add", &waiting_id, &blocker_id],
+        "dep_add",
+    );
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    let html_path = workspace.root.join("index.html");
+    let html_arg = html_path.to_string_lossy().to_string();
+    let export = run_br(
+        &workspace,
+        ["export", "--format", "html", "-o", &html_arg],
+        "export_html",
+    );
+    assert!(
+        export.status.success(),
+        "export html failed: {}",
+        export.stderr
+    );
+    let page = std::fs::read_to_string(&html_path).expect("read html");
+    assert!(page.starts_with("<!DOCTYPE html>"));
+    assert!(page.contains("Lay &lt;foundations&gt;"));
+    assert!(page.contains("<h2 id=\"ready\">Ready (1)</h2>"));
+    assert!(page.contains("<h2 id=\"blocked\">Blocked (1)</h2>"));
+    assert!(page.contains("<line class=\"edge\""));
+    assert!(
+        !page.contains("<link") && !page.contains(" src="),
+        "page must not load external assets"
+    );
+}
@@ -5,9 +5,11 @@
 
 mod common;
 
-use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use beads_rust::sync::lock::{LockFile, LockHolder};
+use common::cli::{BrWorkspace, created_id, extract_json_payload, run_br, run_br_with_env};
 use serde_json::Value;
 use std::fs;
+use std::time::Duration;
 
 // ============================================================================
 // --json flag tests
@@ -239,6 +241,42 @@ fn e2e_no_color_flag() {
     );
 }
 
+#[test]
+fn e2e_color_flag_overrides_no_color_env() {
+    let _log = common::test_log("e2e_color_flag_overrides_no_color_env");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let create = run_br(&workspace, ["create", "Color test"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+
+    // NO_COLOR is set by the harness and stdout is piped; always wins anyway
+    let list = run_br(
+        &workspace,
+        ["list", "--color", "always"],
+        "list_color_always",
+    );
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    assert!(
+        list.stdout.contains("\x1b["),
+        "--color always should emit ANSI codes: {}",
+        list.stdout
+    );
+
+    let list = run_br(&workspace, ["list", "--color", "never"], "list_color_never");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    assert!(!list.stdout.contains("\x1b["));
+
+    let bad = run_br(
+        &workspace,
+        ["list", "--color", "sometimes"],
+        "list_color_bad",
+    );
+    assert!(!bad.status.success(), "invalid --color value accepted");
+}
+
 #[test]
 fn e2e_no_color_env_var() {
     let _log = common::test_log("e2e_no_color_env_var");
@@ -360,6 +398,343 @@ fn e2e_no_db_flag_ready() {
     let _json: Vec<Value> = serde_json::from_str(&payload).expect("valid JSON");
 }
 
+#[test]
+fn e2e_no_db_label_and_comment_write_jsonl() {
+    let _log = common::test_log("e2e_no_db_label_and_comment_write_jsonl");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let create = run_br(
+        &workspace,
+        ["create", "No-DB write test", "--json"],
+        "create",
+    );
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let payload = extract_json_payload(&create.stdout);
+    let created: Value = serde_json::from_str(&payload).expect("valid JSON");
+    let id = created["id"].as_str().expect("id").to_string();
+
+    let sync = run_br(&workspace, ["sync", "--flush-only"], "sync_flush");
+    assert!(sync.status.success(), "sync flush failed: {}", sync.stderr);
+
+    // Writes go straight to the JSONL file
+    let add = run_br(
+        &workspace,
+        ["--no-db", "label", "add", &id, "ops"],
+        "label_add",
+    );
+    assert!(add.status.success(), "label add failed: {}", add.stderr);
+    let jsonl_path = workspace.root.join(".beads").join("issues.jsonl");
+    let jsonl = fs::read_to_string(&jsonl_path).expect("read jsonl");
+    assert!(jsonl.contains("\"ops\""), "label not saved: {jsonl}");
+    assert_eq!(
+        LockHolder::read(&workspace.root.join(".beads").join(".lock")),
+        LockHolder::default(),
+        "workspace lock should be released"
+    );
+
+    let comment = run_br(
+        &workspace,
+        ["--no-db", "comments", "add", &id, "from a read-only mount"],
+        "comment_add",
+    );
+    assert!(
+        comment.status.success(),
+        "comment failed: {}",
+        comment.stderr
+    );
+    let jsonl = fs::read_to_string(&jsonl_path).expect("read jsonl");
+    assert!(
+        jsonl.contains("from a read-only mount"),
+        "comment not saved"
+    );
+
+    let list = run_br(
+        &workspace,
+        ["--no-db", "label", "list", &id, "--json"],
+        "label_list",
+    );
+    assert!(list.status.success(), "label list failed: {}", list.stderr);
+    let payload = extract_json_payload(&list.stdout);
+    let labels: Vec<String> = serde_json::from_str(&payload).expect("valid JSON");
+    assert_eq!(labels, vec!["ops".to_string()]);
+}
+
+// ============================================================================
+// --read-only flag tests
+// ============================================================================
+
+#[test]
+fn e2e_read_only_turns_writes_into_dry_runs() {
+    let _log = common::test_log("e2e_read_only_turns_writes_into_dry_runs");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let create = run_br(&workspace, ["create", "Existing", "--json"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let payload = extract_json_payload(&create.stdout);
+    let created: Value = serde_json::from_str(&payload).expect("valid JSON");
+    let id = created["id"].as_str().expect("id").to_string();
+
+    let dry_create = run_br(
+        &workspace,
+        ["--read-only", "--json", "create", "Not written"],
+        "create_read_only",
+    );
+    assert!(
+        dry_create.status.success(),
+        "read-only create failed: {}",
+        dry_create.stderr
+    );
+    let payload = extract_json_payload(&dry_create.stdout);
+    let json: Value = serde_json::from_str(&payload).expect("valid JSON");
+    assert_eq!(json["dry_run"], true);
+    assert_eq!(json["operation"], "create_issue");
+
+    let dry_update = run_br_with_env(
+        &workspace,
+        ["update", &id, "--status", "in_progress"],
+        [("BEADS_READONLY", "1")],
+        "update_read_only_env",
+    );
+    assert!(
+        dry_update.status.success(),
+        "read-only update failed: {}",
+        dry_update.stderr
+    );
+    assert!(dry_update.stdout.contains("dry run"));
+
+    // Invalid input is still rejected
+    let invalid = run_br(
+        &workspace,
+        ["--read-only", "update", &id, "--priority", "9"],
+        "update_read_only_invalid",
+    );
+    assert!(!invalid.status.success(), "invalid update should fail");
+
+    let sync = run_br(
+        &workspace,
+        ["--read-only", "--json", "sync", "--flush-only"],
+        "sync_read_only",
+    );
+    assert_eq!(sync.status.code(), Some(6));
+    let payload = extract_json_payload(&sync.stdout);
+    let json: Value = serde_json::from_str(&payload).expect("valid JSON");
+    assert_eq!(json["error"]["code"], "READ_ONLY");
+
+    let list = run_br(&workspace, ["list", "--json"], "list");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let payload = extract_json_payload(&list.stdout);
+    let issues: Vec<Value> = serde_json::from_str(&payload).expect("valid JSON");
+    assert_eq!(issues.len(), 1, "read-only create must not write");
+    assert_eq!(
+        issues[0]["status"], "open",
+        "read-only update must not write"
+    );
+}
+
+/// Run a command under `--read-only` and check it is refused before writing.
+fn assert_read_only_refused(workspace: &BrWorkspace, args: &[&str], label: &str) {
+    let mut full = vec!["--read-only", "--json"];
+    full.extend_from_slice(args);
+    let run = run_br(workspace, full, label);
+    assert_eq!(run.status.code(), Some(6), "{label}: {}", run.stderr);
+    let payload = extract_json_payload(&run.stdout);
+    let json: Value = serde_json::from_str(&payload).expect("valid JSON");
+    assert_eq!(json["error"]["code"], "READ_ONLY", "{label}");
+}
+
+fn init_read_only_workspace() -> BrWorkspace {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    workspace
+}
+
+#[test]
+fn e2e_read_only_refuses_history_restore() {
+    let _log = common::test_log("e2e_read_only_refuses_history_restore");
+    let workspace = init_read_only_workspace();
+    let create = run_br(&workspace, ["create", "Kept"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let before = fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).unwrap();
+
+    assert_read_only_refused(
+        &workspace,
+        &[
+            "history",
+            "restore",
+            "issues.20990101_120000.jsonl",
+            "--force",
+        ],
+        "history_restore_read_only",
+    );
+    let after = fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).unwrap();
+    assert_eq!(before, after, "read-only restore must not touch the JSONL");
+}
+
+#[test]
+fn e2e_read_only_refuses_label_describe() {
+    let _log = common::test_log("e2e_read_only_refuses_label_describe");
+    let workspace = init_read_only_workspace();
+    assert_read_only_refused(
+        &workspace,
+        &["label", "describe", "ops", "--label-color", "red"],
+        "label_describe_read_only",
+    );
+
+    // Showing a definition is still allowed
+    let show = run_br(
+        &workspace,
+        ["--read-only", "label", "describe", "--json"],
+        "label_describe_show",
+    );
+    assert!(show.status.success(), "describe failed: {}", show.stderr);
+    assert!(
+        !show.stdout.contains("red"),
+        "definition must not be written"
+    );
+}
+
+#[test]
+fn e2e_read_only_refuses_session_start() {
+    let _log = common::test_log("e2e_read_only_refuses_session_start");
+    let workspace = init_read_only_workspace();
+    assert_read_only_refused(&workspace, &["session", "start"], "session_start_read_only");
+    assert_read_only_refused(&workspace, &["session", "end"], "session_end_read_only");
+}
+
+#[test]
+fn e2e_read_only_inbox_only_peeks() {
+    let _log = common::test_log("e2e_read_only_inbox_only_peeks");
+    let workspace = init_read_only_workspace();
+    let id = created_id(&workspace, &["create", "Review"]);
+    let assign = run_br(
+        &workspace,
+        ["--actor", "bob", "update", &id, "--assignee", "alice"],
+        "assign",
+    );
+    assert!(assign.status.success(), "assign failed: {}", assign.stderr);
+
+    // Listing would move the read mark, so it stops before showing anything
+    let inbox = run_br(
+        &workspace,
+        ["--read-only", "--json", "--actor", "alice", "inbox"],
+        "inbox_read_only",
+    );
+    assert!(inbox.status.success(), "inbox failed: {}", inbox.stderr);
+    let json: Value = serde_json::from_str(&extract_json_payload(&inbox.stdout)).expect("json");
+    assert_eq!(json["dry_run"], true);
+    assert_eq!(json["operation"], "inbox read mark");
+    assert!(json.get("items").is_none(), "{json}");
+
+    let peek = run_br(
+        &workspace,
+        [
+            "--read-only",
+            "--json",
+            "--actor",
+            "alice",
+            "inbox",
+            "--peek",
+        ],
+        "inbox_read_only_peek",
+    );
+    assert!(peek.status.success(), "peek failed: {}", peek.stderr);
+    let json: Value = serde_json::from_str(&extract_json_payload(&peek.stdout)).expect("json");
+    assert_eq!(json["unread"], 1, "read-only inbox must not move the mark");
+}
+
+#[test]
+fn e2e_read_only_refuses_remote_add() {
+    let _log = common::test_log("e2e_read_only_refuses_remote_add");
+    let workspace = init_read_only_workspace();
+    assert_read_only_refused(
+        &workspace,
+        &["remote", "add", "other", "../other"],
+        "remote_add_read_only",
+    );
+
+    let list = run_br(&workspace, ["remote", "list"], "remote_list");
+    assert!(list.status.success(), "remote list failed: {}", list.stderr);
+    assert!(!list.stdout.contains("other"), "remote must not be added");
+}
+
+#[test]
+fn e2e_read_only_refuses_config_set() {
+    let _log = common::test_log("e2e_read_only_refuses_config_set");
+    let workspace = init_read_only_workspace();
+    let before = fs::read_to_string(workspace.root.join(".beads/config.yaml")).unwrap_or_default();
+    assert_read_only_refused(
+        &workspace,
+        &["config", "set", "issue_prefix=ro"],
+        "config_set_read_only",
+    );
+    let after = fs::read_to_string(workspace.root.join(".beads/config.yaml")).unwrap_or_default();
+    assert_eq!(before, after, "read-only config set must not write");
+}
+
+#[test]
+fn e2e_plan_records_changes_and_apply_replays_them() {
+    let _log = common::test_log("e2e_plan_records_changes_and_apply_replays_them");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let create = run_br(&workspace, ["create", "Planned", "--json"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let payload = extract_json_payload(&create.stdout);
+    let created: Value = serde_json::from_str(&payload).expect("valid JSON");
+    let id = created["id"].as_str().expect("id").to_string();
+
+    let plan = run_br(
+        &workspace,
+        ["--plan", "update", &id, "--status", "in_progress"],
+        "plan_update",
+    );
+    assert!(plan.status.success(), "plan failed: {}", plan.stderr);
+    let payload = extract_json_payload(&plan.stdout);
+    let json: Value = serde_json::from_str(&payload).expect("valid JSON");
+    assert_eq!(json["operations"][0]["op"], "update");
+    assert_eq!(json["operations"][0]["before"]["status"], "open");
+    assert_eq!(json["operations"][0]["after"]["status"], "in_progress");
+
+    let show = run_br(&workspace, ["show", &id, "--json"], "show_after_plan");
+    assert!(show.stdout.contains("\"open\""), "--plan must not write");
+
+    let plan_file = workspace.root.join("plan.json");
+    std::fs::write(&plan_file, &payload).expect("write plan");
+    let plan_path = plan_file.to_string_lossy().to_string();
+    let apply = run_br(&workspace, ["apply", &plan_path], "apply");
+    assert!(apply.status.success(), "apply failed: {}", apply.stderr);
+    let show = run_br(&workspace, ["show", &id, "--json"], "show_after_apply");
+    assert!(show.stdout.contains("\"in_progress\""), "apply must write");
+
+    // The issue no longer matches the plan's recorded old values
+    let again = run_br(&workspace, ["apply", &plan_path], "apply_again");
+    assert!(!again.status.success(), "stale plan should be refused");
+
+    let read = run_br(&workspace, ["--plan", "list"], "plan_read_command");
+    assert!(!read.status.success(), "--plan needs a writing command");
+
+    // Label definitions live outside the database, beyond the snapshot
+    let describe = run_br(
+        &workspace,
+        ["--plan", "label", "describe", "ops", "--label-color", "red"],
+        "plan_label_describe",
+    );
+    assert_eq!(describe.status.code(), Some(2), "{}", describe.stderr);
+    let show = run_br(
+        &workspace,
+        ["label", "describe", "--json"],
+        "label_describe",
+    );
+    assert!(!show.stdout.contains("red"), "--plan must not write files");
+}
+
 // ============================================================================
 // --allow-stale flag tests
 // ============================================================================
@@ -507,6 +882,58 @@ fn e2e_lock_timeout_flag() {
     );
 }
 
+#[test]
+fn e2e_workspace_lock_blocks_concurrent_writers() {
+    let _log = common::test_log("e2e_workspace_lock_blocks_concurrent_writers");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    // Stand in for another run holding the lock
+    let beads_dir = workspace.root.join(".beads");
+    let lock_path = beads_dir.join(".lock");
+    let held = LockFile::workspace(&beads_dir, "other-agent", Duration::ZERO).expect("take lock");
+
+    let blocked = run_br(
+        &workspace,
+        ["--json", "--lock-timeout", "100", "create", "Blocked"],
+        "create_locked",
+    );
+    assert!(!blocked.status.success(), "create should wait and fail");
+    assert_eq!(blocked.status.code(), Some(6));
+    let payload = extract_json_payload(&blocked.stdout);
+    let json: Value = serde_json::from_str(&payload).expect("valid JSON");
+    assert_eq!(json["error"]["code"], "DATABASE_LOCKED");
+    // Windows denies reads of a locked file, so the holder is unknown there
+    if cfg!(unix) {
+        assert_eq!(json["error"]["context"]["actor"], "other-agent");
+    }
+    assert_eq!(json["error"]["retryable"], true);
+
+    // Reads do not take the lock
+    let list = run_br(&workspace, ["list", "--json"], "list_while_locked");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+
+    drop(held);
+    let create = run_br(&workspace, ["create", "Unblocked"], "create_unlocked");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    assert_eq!(
+        LockHolder::read(&lock_path),
+        LockHolder::default(),
+        "lock should be released after the run"
+    );
+
+    // A lock file left by a killed run holds no OS lock and blocks nothing
+    fs::write(&lock_path, "999999\ncrashed-agent\n").expect("write leftover lock");
+    let create = run_br(
+        &workspace,
+        ["--lock-timeout", "100", "create", "After crash"],
+        "create_after_crash",
+    );
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+}
+
 // ============================================================================
 // --quiet flag tests
 // ============================================================================
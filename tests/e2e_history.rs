@@ -2,8 +2,9 @@
 //!
 //! Tests cover:
 //! - history list: List available backup snapshots
-//! - history diff: Show diff between current and a backup
-//! - history restore: Restore a backup to issues.jsonl
+//! - history show: List the issues in a snapshot
+//! - history diff: Issue-level diff between current and a backup
+//! - history restore: Restore a backup to issues.jsonl and re-import it
 //! - history prune: Prune old backups
 //! - Error handling: Before init, missing files, restore without force
 //! - Edge cases: Many backups, backup deduplication
@@ -786,7 +787,69 @@ fn e2e_history_restore_json_output() {
         "backup field should match"
     );
     assert_eq!(json["restored"], true, "restored should be true");
-    assert!(json.get("next_step").is_some(), "should have next_step");
+    assert_eq!(json["dry_run"], false, "dry_run should be false");
+    assert!(
+        json["imported"].as_u64().is_some(),
+        "restore should report the re-imported count"
+    );
+}
+
+#[test]
+fn e2e_history_diff_and_dry_run_restore_report_issue_changes() {
+    let _log = common::test_log("e2e_history_diff_and_dry_run_restore_report_issue_changes");
+    let workspace = setup_workspace_with_jsonl();
+
+    // The backup holds only "Initial issue"; the current JSONL adds one more
+    create_issue(&workspace, "Added after backup", "create_added");
+    sync_flush(&workspace);
+
+    let backups = list_backup_files(&workspace);
+    assert!(!backups.is_empty(), "should have backup");
+    let backup_file = &backups[0];
+
+    let show = run_br(
+        &workspace,
+        ["--json", "history", "show", backup_file],
+        "history_show_json",
+    );
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    let json: serde_json::Value = serde_json::from_str(&show.stdout).expect("valid JSON");
+    assert_eq!(json["count"], 1);
+    assert_eq!(json["issues"][0]["title"], "Initial issue");
+
+    let diff = run_br(
+        &workspace,
+        ["--json", "history", "diff", backup_file],
+        "history_diff_json",
+    );
+    assert!(diff.status.success(), "diff failed: {}", diff.stderr);
+    let json: serde_json::Value = serde_json::from_str(&diff.stdout).expect("valid JSON");
+    assert_eq!(json["identical"], false);
+    assert_eq!(json["added"].as_array().map(Vec::len), Some(1));
+    assert_eq!(json["added"][0]["title"], "Added after backup");
+    assert_eq!(json["removed"].as_array().map(Vec::len), Some(0));
+
+    let jsonl_path = workspace.root.join(".beads").join("issues.jsonl");
+    let before = fs::read(&jsonl_path).expect("read issues.jsonl");
+    let restore = run_br(
+        &workspace,
+        ["--json", "history", "restore", backup_file, "--dry-run"],
+        "history_restore_dry_run",
+    );
+    assert!(
+        restore.status.success(),
+        "dry run failed: {}",
+        restore.stderr
+    );
+    let json: serde_json::Value = serde_json::from_str(&restore.stdout).expect("valid JSON");
+    assert_eq!(json["dry_run"], true);
+    assert_eq!(json["restored"], false);
+    assert_eq!(json["removed"][0]["title"], "Added after backup");
+    assert_eq!(
+        fs::read(&jsonl_path).expect("read issues.jsonl"),
+        before,
+        "dry run must not write"
+    );
 }
 
 #[test]
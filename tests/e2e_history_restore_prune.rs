@@ -223,7 +223,8 @@ fn e2e_history_restore_json_output() {
     assert_eq!(json["backup"], backup_file.as_str());
     assert_eq!(json["restored"], true);
     assert!(json["target"].as_str().is_some());
-    assert!(json["next_step"].as_str().is_some());
+    assert_eq!(json["dry_run"], false);
+    assert!(json["imported"].as_u64().is_some());
 }
 
 // =============================================================================
@@ -231,8 +232,8 @@ fn e2e_history_restore_json_output() {
 // =============================================================================
 
 #[test]
-fn e2e_history_restore_corrupt_backup_succeeds_copy() {
-    let _log = common::test_log("e2e_history_restore_corrupt_backup_succeeds_copy");
+fn e2e_history_restore_corrupt_backup_fails_without_writing() {
+    let _log = common::test_log("e2e_history_restore_corrupt_backup_fails_without_writing");
     let workspace = setup_workspace_with_jsonl();
 
     // Create issue to trigger backup
@@ -252,26 +253,26 @@ fn e2e_history_restore_corrupt_backup_succeeds_copy() {
     fs::write(&backup_path, "{ this is not valid json }\n{ also broken }")
         .expect("write corrupt backup");
 
-    // Restore should succeed (it just copies the file)
-    // The corruption would be detected on import, not restore
+    let before = read_file_bytes(&workspace, ".beads/issues.jsonl");
+
+    // Restore re-imports the snapshot, so it is validated before anything is written
     let restore = run_br(
         &workspace,
         ["history", "restore", backup_file, "--force"],
         "history_restore_corrupt",
     );
     assert!(
-        restore.status.success(),
-        "restore should succeed (it's just a copy): {}",
-        restore.stderr
+        !restore.status.success(),
+        "restore should reject a corrupt snapshot"
     );
-
-    // The restored issues.jsonl should contain the corrupt content
-    let restored_content = fs::read_to_string(workspace.root.join(".beads").join("issues.jsonl"))
-        .expect("read restored");
     assert!(
-        restored_content.contains("this is not valid json"),
-        "restored file should contain the corrupt content"
+        restore.stderr.contains("not valid JSONL"),
+        "error should explain the snapshot is corrupt: {}",
+        restore.stderr
     );
+
+    // The current issues.jsonl is untouched
+    assert_eq!(read_file_bytes(&workspace, ".beads/issues.jsonl"), before);
 }
 
 #[test]
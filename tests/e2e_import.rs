@@ -0,0 +1,158 @@
+//! End-to-end tests for `br import` (tracker CSV exports).
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+#[test]
+fn e2e_import_jira_csv_updates_on_reimport() {
+    let _log = common::test_log("e2e_import_jira_csv_updates_on_reimport");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init", "--prefix", "im"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let csv_path = workspace.root.join("jira.csv");
+    let csv_path_str = csv_path.to_string_lossy().to_string();
+    std::fs::write(
+        &csv_path,
+        "Summary,Issue key,Issue Type,Status,Priority,Labels,Labels\n\
+         \"Fix login, again\",PROJ-1,Bug,To Do,High,auth,web\n\
+         Write docs,PROJ-2,Story,In Progress,Lowest,,\n",
+    )
+    .expect("write csv");
+
+    let first = run_br(
+        &workspace,
+        ["import", "--from", "jira-csv", &csv_path_str, "--json"],
+        "import_first",
+    );
+    assert!(first.status.success(), "import failed: {}", first.stderr);
+    let summary: Value = serde_json::from_str(&extract_json_payload(&first.stdout)).unwrap();
+    assert_eq!(summary["created"].as_array().unwrap().len(), 2);
+    let id = summary["created"][0]["id"].as_str().unwrap().to_string();
+
+    let show = run_br(&workspace, ["show", &id, "--json"], "show");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    let show_json: Vec<Value> = serde_json::from_str(&extract_json_payload(&show.stdout)).unwrap();
+    let issue = &show_json[0];
+    assert_eq!(issue["title"], "Fix login, again");
+    assert_eq!(issue["issue_type"], "bug");
+    assert_eq!(issue["priority"], 1);
+    assert_eq!(issue["external_ref"], "PROJ-1");
+    assert_eq!(issue["source_system"], "jira");
+
+    std::fs::write(
+        &csv_path,
+        "Summary,Issue key,Issue Type,Status,Priority,Labels,Labels\n\
+         \"Fix login, again\",PROJ-1,Bug,Done,High,auth,web\n\
+         Write docs,PROJ-2,Story,In Progress,Lowest,,\n",
+    )
+    .expect("rewrite csv");
+
+    let second = run_br(
+        &workspace,
+        ["import", "--from", "jira-csv", &csv_path_str, "--json"],
+        "import_second",
+    );
+    assert!(
+        second.status.success(),
+        "reimport failed: {}",
+        second.stderr
+    );
+    let summary: Value = serde_json::from_str(&extract_json_payload(&second.stdout)).unwrap();
+    assert_eq!(summary["created"].as_array().unwrap().len(), 0);
+    assert_eq!(summary["updated"][0]["id"], id.as_str());
+    assert_eq!(summary["unchanged"], 1);
+
+    let list = run_br(&workspace, ["list", "--all", "--json"], "list");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let issues: Value = serde_json::from_str(&extract_json_payload(&list.stdout)).unwrap();
+    let issues = issues.as_array().unwrap();
+    assert_eq!(issues.len(), 2);
+    let closed = issues.iter().find(|i| i["id"] == id.as_str()).unwrap();
+    assert_eq!(closed["status"], "closed");
+}
+
+#[test]
+fn e2e_import_skips_keys_owned_by_another_source() {
+    let _log = common::test_log("e2e_import_skips_keys_owned_by_another_source");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init", "--prefix", "im"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let jira_path = workspace.root.join("jira.csv");
+    std::fs::write(&jira_path, "Summary,Issue key\nFrom Jira,ENG-1\n").expect("write csv");
+    let jira = run_br(
+        &workspace,
+        [
+            "import",
+            "--from",
+            "jira-csv",
+            &jira_path.to_string_lossy().to_string(),
+            "--json",
+        ],
+        "import_jira",
+    );
+    assert!(jira.status.success(), "import failed: {}", jira.stderr);
+
+    let linear_path = workspace.root.join("linear.csv");
+    std::fs::write(&linear_path, "ID,Title\nENG-1,From Linear\nENG-2,Fresh\n").expect("write csv");
+    let linear = run_br(
+        &workspace,
+        [
+            "import",
+            "--from",
+            "linear-csv",
+            &linear_path.to_string_lossy().to_string(),
+            "--json",
+        ],
+        "import_linear",
+    );
+    assert!(linear.status.success(), "import failed: {}", linear.stderr);
+    let summary: Value = serde_json::from_str(&extract_json_payload(&linear.stdout)).unwrap();
+    assert_eq!(summary["created"].as_array().unwrap().len(), 1);
+    assert_eq!(summary["skipped"][0]["row"], 2);
+    assert!(
+        summary["skipped"][0]["reason"]
+            .as_str()
+            .unwrap()
+            .contains("ENG-1")
+    );
+}
+
+#[test]
+fn e2e_import_dry_run_tracks_repeated_keys() {
+    let _log = common::test_log("e2e_import_dry_run_tracks_repeated_keys");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init", "--prefix", "im"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let csv_path = workspace.root.join("github.csv");
+    std::fs::write(
+        &csv_path,
+        "URL,Title,State\n\
+         https://example.com/1,First,Open\n\
+         https://example.com/1,First,Open\n\
+         https://example.com/1,First,Closed\n",
+    )
+    .expect("write csv");
+    let dry = run_br(
+        &workspace,
+        [
+            "import",
+            "--from",
+            "github-csv",
+            &csv_path.to_string_lossy().to_string(),
+            "--dry-run",
+            "--json",
+        ],
+        "import_dry_run",
+    );
+    assert!(dry.status.success(), "dry run failed: {}", dry.stderr);
+    let summary: Value = serde_json::from_str(&extract_json_payload(&dry.stdout)).unwrap();
+    assert_eq!(summary["created"].as_array().unwrap().len(), 1);
+    assert_eq!(summary["unchanged"], 1);
+    assert_eq!(summary["updated"].as_array().unwrap().len(), 1);
+    assert_eq!(summary["updated"][0]["id"], summary["created"][0]["id"]);
+}
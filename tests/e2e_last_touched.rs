@@ -0,0 +1,46 @@
+//! End-to-end tests for the last-touched issue and the `-` ID placeholder.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+#[test]
+fn e2e_dash_resolves_to_last_touched_issue() {
+    let _log = common::test_log("e2e_dash_resolves_to_last_touched_issue");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init", "--prefix", "lt"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    // No issue touched yet
+    let none = run_br(&workspace, ["show", "-"], "show_none");
+    assert!(!none.status.success());
+    assert!(none.stderr.contains("last-touched"), "{}", none.stderr);
+
+    let blocker = run_br(&workspace, ["q", "Blocker"], "q_blocker");
+    assert!(blocker.status.success(), "q failed: {}", blocker.stderr);
+    let blocker = blocker.stdout.trim().to_string();
+    let task = run_br(&workspace, ["q", "Task"], "q_task");
+    assert!(task.status.success(), "q failed: {}", task.stderr);
+    let task = task.stdout.trim().to_string();
+
+    // `-` is the issue just created
+    let dep = run_br(&workspace, ["dep", "add", "-", &blocker], "dep_add");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+    let update = run_br(&workspace, ["update", "-", "--priority", "0"], "update");
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+
+    let show = run_br(&workspace, ["show", "-", "--json"], "show_task");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    let json: Value = serde_json::from_str(&extract_json_payload(&show.stdout)).unwrap();
+    assert_eq!(json[0]["id"], task.as_str());
+    assert_eq!(json[0]["priority"], 0);
+    assert_eq!(json[0]["dependencies"][0]["id"], blocker.as_str());
+
+    // Showing an issue makes it the last-touched one
+    let show = run_br(&workspace, ["show", &blocker], "show_blocker");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    let close = run_br(&workspace, ["close", "--json"], "close_bare");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    assert!(close.stdout.contains(&blocker), "{}", close.stdout);
+}
@@ -0,0 +1,89 @@
+//! E2E tests for `br list --group-by`.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, json_output, run_br};
+
+#[test]
+fn e2e_list_group_by_text_and_json() {
+    let _log = common::test_log("e2e_list_group_by_text_and_json");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let api = created_id(
+        &workspace,
+        &["create", "API work", "--labels", "api,backend"],
+    );
+    let ui = created_id(&workspace, &["create", "UI work", "--labels", "ui"]);
+    let bare = created_id(&workspace, &["create", "Unlabelled", "--assignee", "alice"]);
+
+    let list = run_br(
+        &workspace,
+        ["list", "--group-by", "label", "--json"],
+        "group_label_json",
+    );
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let payload = json_output(&list.stdout);
+    assert_eq!(payload["total"], 3);
+    let groups: Vec<(String, Vec<String>)> = payload["groups"]
+        .as_array()
+        .expect("groups")
+        .iter()
+        .map(|group| {
+            assert_eq!(
+                group["count"],
+                group["issues"].as_array().expect("issues").len()
+            );
+            let ids = group["issues"]
+                .as_array()
+                .expect("issues")
+                .iter()
+                .map(|issue| issue["id"].as_str().expect("id").to_string())
+                .collect();
+            (group["group"].as_str().expect("group").to_string(), ids)
+        })
+        .collect();
+    assert_eq!(
+        groups,
+        vec![
+            ("(no labels)".to_string(), vec![bare.clone()]),
+            ("api".to_string(), vec![api.clone()]),
+            ("backend".to_string(), vec![api.clone()]),
+            ("ui".to_string(), vec![ui.clone()]),
+        ]
+    );
+
+    let list = run_br(
+        &workspace,
+        ["list", "--group-by", "assignee"],
+        "group_assignee_text",
+    );
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let lines: Vec<&str> = list.stdout.lines().collect();
+    let unassigned = lines
+        .iter()
+        .position(|line| *line == "(unassigned) (2)")
+        .expect("unassigned header");
+    let alice = lines
+        .iter()
+        .position(|line| *line == "alice (1)")
+        .expect("alice header");
+    assert!(lines[alice + 1].contains(&bare), "{lines:?}");
+    assert!(
+        lines[unassigned + 1..alice]
+            .iter()
+            .filter(|line| line.contains(&api) || line.contains(&ui))
+            .count()
+            == 2,
+        "{lines:?}"
+    );
+
+    let csv = run_br(
+        &workspace,
+        ["list", "--group-by", "status", "--format", "csv"],
+        "group_csv",
+    );
+    assert!(!csv.status.success(), "csv grouping accepted");
+}
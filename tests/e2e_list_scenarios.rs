@@ -1000,3 +1000,197 @@ fn list_issue_with_special_chars_in_title() {
         "CSV should escape special characters"
     );
 }
+
+// =============================================================================
+// Streaming Output Tests
+// =============================================================================
+
+#[test]
+fn list_stream_emits_json_lines() {
+    let (workspace, _ids) = setup_diverse_workspace();
+
+    let list = run_br(
+        &workspace,
+        ["list", "--all", "--limit", "0", "--json"],
+        "list_all_json",
+    );
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let expected: Vec<Value> =
+        serde_json::from_str(&extract_json_payload(&list.stdout)).expect("parse json");
+
+    let stream = run_br(
+        &workspace,
+        ["list", "--all", "--limit", "0", "--stream"],
+        "list_stream",
+    );
+    assert!(
+        stream.status.success(),
+        "list --stream failed: {}",
+        stream.stderr
+    );
+    let rows: Vec<Value> = stream
+        .stdout
+        .lines()
+        .filter(|line| line.starts_with('{'))
+        .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+        .collect();
+    assert_eq!(rows.len(), expected.len());
+    for (row, expected) in rows.iter().zip(&expected) {
+        assert_eq!(row["id"], expected["id"]);
+        assert_eq!(row["dependency_count"], expected["dependency_count"]);
+    }
+
+    let limited = run_br(
+        &workspace,
+        ["list", "--all", "--limit", "2", "--stream"],
+        "list_stream_limit",
+    );
+    assert!(limited.status.success());
+    assert_eq!(
+        limited
+            .stdout
+            .lines()
+            .filter(|line| line.starts_with('{'))
+            .count(),
+        2
+    );
+
+    let search = run_br(&workspace, ["search", "bug", "--stream"], "search_stream");
+    assert!(
+        search.status.success(),
+        "search --stream failed: {}",
+        search.stderr
+    );
+    assert!(
+        search
+            .stdout
+            .lines()
+            .filter(|line| line.starts_with('{'))
+            .all(|line| serde_json::from_str::<Value>(line).is_ok())
+    );
+
+    let sorted = run_br(
+        &workspace,
+        ["search", "bug", "--stream", "--sort", "title"],
+        "search_stream_sort",
+    );
+    assert!(
+        !sorted.status.success(),
+        "--stream with --sort should be rejected for search"
+    );
+}
+
+// =============================================================================
+// Pagination Tests
+// =============================================================================
+
+#[test]
+fn list_paginate_follows_cursors() {
+    let (workspace, _ids) = setup_diverse_workspace();
+
+    let full = run_br(
+        &workspace,
+        ["list", "--all", "--limit", "0", "--json"],
+        "list_all_json",
+    );
+    assert!(full.status.success(), "list failed: {}", full.stderr);
+    let full: Vec<Value> =
+        serde_json::from_str(&extract_json_payload(&full.stdout)).expect("parse json");
+    assert!(full.len() > 2, "need more than one page of issues");
+
+    let mut seen = Vec::new();
+    let mut after: Option<String> = None;
+    for page_num in 0..full.len() {
+        let mut args = vec!["list", "--all", "--limit", "2", "--paginate", "--json"];
+        if let Some(cursor) = after.as_deref() {
+            args.extend(["--after", cursor]);
+        }
+        let page = run_br(&workspace, args, &format!("list_page_{page_num}"));
+        assert!(page.status.success(), "page failed: {}", page.stderr);
+        let page: Value =
+            serde_json::from_str(&extract_json_payload(&page.stdout)).expect("parse json");
+        for issue in page["issues"].as_array().expect("issues array") {
+            seen.push(issue["id"].as_str().unwrap().to_string());
+        }
+        match page["next_cursor"].as_str() {
+            Some(cursor) => after = Some(cursor.to_string()),
+            None => break,
+        }
+    }
+
+    let mut expected: Vec<String> = full
+        .iter()
+        .map(|issue| issue["id"].as_str().unwrap().to_string())
+        .collect();
+    let mut sorted_seen = seen.clone();
+    sorted_seen.sort();
+    sorted_seen.dedup();
+    expected.sort();
+    assert_eq!(sorted_seen, expected);
+    assert_eq!(seen.len(), expected.len(), "no issue appears twice");
+
+    let bad = run_br(
+        &workspace,
+        ["list", "--after", "not-a-cursor", "--json"],
+        "list_bad_cursor",
+    );
+    assert!(!bad.status.success(), "invalid cursor should be rejected");
+}
+
+#[test]
+fn list_dependency_filters_resolve_partial_ids() {
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let create = run_br(&workspace, ["create", "Blocked task"], "create_blocked");
+    let blocked = parse_created_id(&create.stdout);
+    let create = run_br(&workspace, ["create", "Blocker task"], "create_blocker");
+    let blocker = parse_created_id(&create.stdout);
+    let dep = run_br(&workspace, ["dep", "add", &blocked, &blocker], "dep_add");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    // The hash alone, without the prefix
+    let partial = |id: &str| id.split_once('-').expect("prefixed id").1.to_string();
+    let ids = |stdout: &str| -> Vec<String> {
+        let issues: Vec<Value> =
+            serde_json::from_str(&extract_json_payload(stdout)).expect("parse json");
+        issues
+            .iter()
+            .map(|issue| issue["id"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let depends_on = partial(&blocker);
+    let list = run_br(
+        &workspace,
+        ["list", "--depends-on", &depends_on, "--json"],
+        "list_depends_on",
+    );
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    assert_eq!(ids(&list.stdout), vec![blocked.clone()]);
+
+    let blocks = partial(&blocked);
+    let search = run_br(
+        &workspace,
+        ["search", "task", "--blocks", &blocks, "--json"],
+        "search_blocks",
+    );
+    assert!(search.status.success(), "search failed: {}", search.stderr);
+    assert_eq!(ids(&search.stdout), vec![blocker.clone()]);
+
+    let ready = run_br(
+        &workspace,
+        ["ready", "--blocks", &blocks, "--json"],
+        "ready_blocks",
+    );
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    assert_eq!(ids(&ready.stdout), vec![blocker]);
+
+    let missing = run_br(
+        &workspace,
+        ["list", "--depends-on", "zzzzzz", "--json"],
+        "list_depends_on_missing",
+    );
+    assert_eq!(missing.status.code(), Some(3), "{}", missing.stderr);
+}
@@ -0,0 +1,123 @@
+//! E2E tests for milestones: create, assign, `list --milestone`, stats, export.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, json_output, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn ids(value: &Value) -> Vec<String> {
+    let mut ids: Vec<String> = value
+        .as_array()
+        .expect("array")
+        .iter()
+        .map(|issue| issue["id"].as_str().expect("id").to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn e2e_milestone_assign_progress_and_export() {
+    let _log = common::test_log("e2e_milestone_assign_progress_and_export");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let sprint = created_id(
+        &workspace,
+        &["milestone", "create", "Sprint 12", "--due", "2025-07-01"],
+    );
+    let next = created_id(&workspace, &["milestone", "create", "Sprint 13"]);
+    let done = created_id(&workspace, &["create", "Done work"]);
+    let open = created_id(&workspace, &["create", "Open work"]);
+    let outside = created_id(&workspace, &["create", "Unplanned"]);
+
+    let add = run_br(
+        &workspace,
+        ["milestone", "add", &next, &done, &open],
+        "milestone_add",
+    );
+    assert!(add.status.success(), "add failed: {}", add.stderr);
+    // Adding to another milestone moves the issues
+    let add = run_br(
+        &workspace,
+        ["milestone", "add", &sprint, &done, &open, "--json"],
+        "milestone_move",
+    );
+    assert!(add.status.success(), "move failed: {}", add.stderr);
+    assert_eq!(json_output(&add.stdout)[0]["previous"], next.as_str());
+    let bad = run_br(
+        &workspace,
+        ["milestone", "add", &outside, &open],
+        "milestone_add_to_task",
+    );
+    assert!(!bad.status.success(), "assigned to a non-milestone");
+
+    let close = run_br(&workspace, ["close", &done], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    // A partial ID works, like anywhere else an issue is named
+    let partial = sprint.split_once('-').expect("prefixed id").1;
+    let list = run_br(
+        &workspace,
+        ["list", "--milestone", partial, "--all", "--json"],
+        "list_milestone",
+    );
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    let mut expected = vec![done.clone(), open.clone()];
+    expected.sort();
+    assert_eq!(ids(&json_output(&list.stdout)), expected);
+    let not_milestone = run_br(
+        &workspace,
+        ["list", "--milestone", &outside, "--json"],
+        "list_milestone_task",
+    );
+    assert_eq!(
+        not_milestone.status.code(),
+        Some(2),
+        "{}",
+        not_milestone.stderr
+    );
+    let missing = run_br(
+        &workspace,
+        ["list", "--milestone", "zzzzzz", "--json"],
+        "list_milestone_missing",
+    );
+    assert_eq!(missing.status.code(), Some(3), "{}", missing.stderr);
+
+    let stats = run_br(&workspace, ["stats", "--no-activity", "--json"], "stats");
+    assert!(stats.status.success(), "stats failed: {}", stats.stderr);
+    let milestones = json_output(&stats.stdout)["milestones"].clone();
+    assert_eq!(milestones[0]["id"], sprint.as_str());
+    assert_eq!(milestones[0]["total"], 2);
+    assert_eq!(milestones[0]["closed"], 1);
+    assert_eq!(milestones[1]["id"], next.as_str());
+    assert_eq!(milestones[1]["total"], 0);
+
+    let ready = run_br(&workspace, ["ready", "--json"], "ready");
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    let ready_ids = ids(&json_output(&ready.stdout));
+    assert!(!ready_ids.contains(&sprint), "milestone listed as ready");
+    assert!(ready_ids.contains(&open));
+
+    let remove = run_br(&workspace, ["milestone", "remove", &open], "remove");
+    assert!(remove.status.success(), "remove failed: {}", remove.stderr);
+
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let jsonl =
+        fs::read_to_string(workspace.root.join(".beads").join("issues.jsonl")).expect("read jsonl");
+    let done_line = jsonl
+        .lines()
+        .find(|line| line.contains(&format!(r#""id":"{done}""#)))
+        .expect("done issue exported");
+    assert!(done_line.contains(r#""type":"milestone""#), "{done_line}");
+    assert!(done_line.contains(&sprint), "{done_line}");
+    let open_line = jsonl
+        .lines()
+        .find(|line| line.contains(&format!(r#""id":"{open}""#)))
+        .expect("open issue exported");
+    assert!(!open_line.contains(&sprint), "{open_line}");
+}
@@ -0,0 +1,95 @@
+//! E2E tests for the `msg` command: send, inbox, reply and threading.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+fn json(stdout: &str) -> Value {
+    serde_json::from_str(&extract_json_payload(stdout)).expect("json output")
+}
+
+#[test]
+fn e2e_msg_send_inbox_reply() {
+    let _log = common::test_log("e2e_msg_send_inbox_reply");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let send = run_br(
+        &workspace,
+        [
+            "--actor",
+            "lead",
+            "msg",
+            "send",
+            "--to",
+            "worker",
+            "Please take the parser task\nIt is blocking the release.",
+            "--json",
+        ],
+        "send",
+    );
+    assert!(send.status.success(), "send failed: {}", send.stderr);
+    let first_id = json(&send.stdout)["id"].as_str().expect("id").to_string();
+
+    let inbox = run_br(
+        &workspace,
+        ["msg", "inbox", "--for", "worker", "--json"],
+        "inbox_worker",
+    );
+    assert!(inbox.status.success(), "inbox failed: {}", inbox.stderr);
+    let messages = json(&inbox.stdout);
+    assert_eq!(messages.as_array().map(Vec::len), Some(1));
+    assert_eq!(messages[0]["from"], "lead");
+    assert_eq!(messages[0]["subject"], "Please take the parser task");
+    assert_eq!(messages[0]["thread_id"], first_id.as_str());
+
+    let reply = run_br(
+        &workspace,
+        [
+            "--actor", "worker", "msg", "reply", &first_id, "On it", "--json",
+        ],
+        "reply",
+    );
+    assert!(reply.status.success(), "reply failed: {}", reply.stderr);
+    let reply = json(&reply.stdout);
+    assert_eq!(reply["to"], "lead");
+    assert_eq!(reply["thread_id"], first_id.as_str());
+
+    let lead_inbox = run_br(
+        &workspace,
+        ["--actor", "lead", "msg", "inbox", "--json"],
+        "inbox_lead",
+    );
+    let messages = json(&lead_inbox.stdout);
+    assert_eq!(messages[0]["from"], "worker");
+    assert_eq!(messages[0]["in_reply_to"], first_id.as_str());
+    assert_eq!(messages[0]["thread_id"], first_id.as_str());
+
+    // Messages are ephemeral: not ready work and never exported
+    let ready = run_br(&workspace, ["ready", "--json"], "ready");
+    assert!(!ready.stdout.contains(&first_id), "{}", ready.stdout);
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let jsonl = std::fs::read_to_string(workspace.root.join(".beads").join("issues.jsonl"))
+        .expect("read jsonl");
+    assert!(!jsonl.contains(&first_id));
+
+    let task = run_br(
+        &workspace,
+        ["create", "Plain task", "--json"],
+        "create_task",
+    );
+    let task_id = json(&task.stdout)["id"]
+        .as_str()
+        .expect("task id")
+        .to_string();
+    let bad = run_br(
+        &workspace,
+        ["msg", "reply", &task_id, "Hello"],
+        "reply_to_task",
+    );
+    assert!(!bad.status.success(), "replying to a task should fail");
+}
@@ -398,6 +398,212 @@ fn e2e_stats_command() {
     assert!(has_priority, "missing priority breakdown");
 }
 
+#[test]
+fn e2e_stats_burndown() {
+    let _log = common::test_log("e2e_stats_burndown");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "burndown_init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let done = run_br(&workspace, ["create", "Done soon"], "burndown_create_done");
+    assert!(done.status.success(), "create failed: {}", done.stderr);
+    let done_id = parse_created_id(&done.stdout);
+    let open = run_br(&workspace, ["create", "Still open"], "burndown_create_open");
+    assert!(open.status.success(), "create failed: {}", open.stderr);
+    let close = run_br(&workspace, ["close", &done_id], "burndown_close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let json = run_br(
+        &workspace,
+        ["stats", "--burndown", "--since", "7d", "--json"],
+        "burndown_json",
+    );
+    assert!(json.status.success(), "burndown failed: {}", json.stderr);
+    let payload = extract_json_payload(&json.stdout);
+    let burndown: Value = serde_json::from_str(&payload).expect("burndown json");
+    let days = burndown["days"].as_array().expect("days");
+    assert_eq!(days.len(), 8);
+    assert_eq!(burndown["total_created"], 2);
+    assert_eq!(burndown["total_closed"], 1);
+    let today = days.last().expect("today");
+    assert_eq!(today["remaining"], 1);
+    assert_eq!(today["done"], 1);
+
+    let text = run_br(&workspace, ["stats", "--burndown"], "burndown_text");
+    assert!(
+        text.status.success(),
+        "burndown text failed: {}",
+        text.stderr
+    );
+    assert!(text.stdout.contains("Closed/day"), "{}", text.stdout);
+
+    let orphan = run_br(
+        &workspace,
+        ["stats", "--since", "7d"],
+        "burndown_since_alone",
+    );
+    assert!(!orphan.status.success(), "--since requires --burndown");
+}
+
+#[test]
+fn e2e_activity_feed() {
+    let _log = common::test_log("e2e_activity_feed");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "activity_init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let create = run_br(&workspace, ["create", "Watched issue"], "activity_create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let id = parse_created_id(&create.stdout);
+    let comment = run_br(
+        &workspace,
+        [
+            "--actor",
+            "reviewer",
+            "comments",
+            "add",
+            &id,
+            "Halfway there",
+        ],
+        "activity_comment",
+    );
+    assert!(
+        comment.status.success(),
+        "comment failed: {}",
+        comment.stderr
+    );
+    let close = run_br(&workspace, ["close", &id], "activity_close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let json = run_br(&workspace, ["activity", "--json"], "activity_json");
+    assert!(json.status.success(), "activity failed: {}", json.stderr);
+    let payload = extract_json_payload(&json.stdout);
+    let items: Vec<Value> = serde_json::from_str(&payload).expect("activity json");
+    let types: Vec<&str> = items.iter().filter_map(|i| i["type"].as_str()).collect();
+    assert_eq!(types.first(), Some(&"closed"), "{types:?}");
+    assert_eq!(types.last(), Some(&"created"), "{types:?}");
+    assert_eq!(
+        types.iter().filter(|t| **t == "commented").count(),
+        1,
+        "comment listed once: {types:?}"
+    );
+
+    let filtered = run_br(
+        &workspace,
+        ["activity", "--type", "commented", "--since", "1h", "--json"],
+        "activity_filtered",
+    );
+    assert!(
+        filtered.status.success(),
+        "filter failed: {}",
+        filtered.stderr
+    );
+    let items: Vec<Value> =
+        serde_json::from_str(&extract_json_payload(&filtered.stdout)).expect("json");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["comment"], "Halfway there");
+
+    let by_actor = run_br(
+        &workspace,
+        ["activity", "--actor", "reviewer", "--json"],
+        "activity_actor",
+    );
+    assert!(
+        by_actor.status.success(),
+        "actor filter failed: {}",
+        by_actor.stderr
+    );
+    let items: Vec<Value> =
+        serde_json::from_str(&extract_json_payload(&by_actor.stdout)).expect("json");
+    assert_eq!(items.len(), 1, "{items:?}");
+    assert_eq!(items[0]["actor"], "reviewer");
+
+    let text = run_br(&workspace, ["activity"], "activity_text");
+    assert!(
+        text.status.success(),
+        "activity text failed: {}",
+        text.stderr
+    );
+    assert!(text.stdout.contains("Watched issue"), "{}", text.stdout);
+
+    let bad = run_br(
+        &workspace,
+        ["activity", "--type", "exploded"],
+        "activity_bad_type",
+    );
+    assert!(!bad.status.success(), "unknown type should fail");
+}
+
+#[test]
+fn e2e_session_start_show_end() {
+    let _log = common::test_log("e2e_session_start_show_end");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "session_init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let before = run_br(
+        &workspace,
+        ["create", "Before session"],
+        "session_create_before",
+    );
+    assert!(before.status.success(), "create failed: {}", before.stderr);
+    let before_id = parse_created_id(&before.stdout);
+
+    let start = run_br(&workspace, ["session", "start", "run-1"], "session_start");
+    assert!(start.status.success(), "start failed: {}", start.stderr);
+    let again = run_br(&workspace, ["session", "start"], "session_start_again");
+    assert!(!again.status.success(), "second start should fail");
+
+    let create = run_br(&workspace, ["create", "During session"], "session_create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let during_id = parse_created_id(&create.stdout);
+    let close = run_br(&workspace, ["close", &before_id], "session_close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let show = run_br(
+        &workspace,
+        ["show", &before_id, "--json"],
+        "session_show_issue",
+    );
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    let issues: Value = serde_json::from_str(&extract_json_payload(&show.stdout)).expect("json");
+    assert_eq!(issues[0]["closed_by_session"], "run-1");
+
+    let end = run_br(&workspace, ["session", "end"], "session_end");
+    assert!(end.status.success(), "end failed: {}", end.stderr);
+    let after = run_br(
+        &workspace,
+        ["create", "After session"],
+        "session_create_after",
+    );
+    assert!(after.status.success(), "create failed: {}", after.stderr);
+
+    let summary = run_br(
+        &workspace,
+        ["session", "show", "run-1", "--json"],
+        "session_show",
+    );
+    assert!(
+        summary.status.success(),
+        "session show failed: {}",
+        summary.stderr
+    );
+    let summary: Value =
+        serde_json::from_str(&extract_json_payload(&summary.stdout)).expect("summary json");
+    assert_eq!(summary["active"], false);
+    assert_eq!(summary["created"][0]["id"], during_id.as_str());
+    assert_eq!(summary["created"].as_array().map(Vec::len), Some(1));
+    assert_eq!(summary["closed"][0]["id"], before_id.as_str());
+
+    let list = run_br(&workspace, ["session", "list"], "session_list");
+    assert!(
+        list.status.success(),
+        "session list failed: {}",
+        list.stderr
+    );
+    assert!(list.stdout.contains("run-1"), "{}", list.stdout);
+}
+
 /// E2E tests for config command - list, get, path.
 #[test]
 fn e2e_config_command() {
@@ -430,10 +636,10 @@ fn e2e_config_command() {
 
     // Test config get subcommand - use json key which is a startup setting
     let config_get = run_br(&workspace, ["config", "get", "json"], "config_get");
-    // Config get for existing key should either succeed or return "not found" (exit 1)
+    // Config get for existing key should either succeed or return "not found" (exit 7)
     // We just verify it doesn't crash with an unexpected error
     assert!(
-        config_get.status.code() == Some(0) || config_get.status.code() == Some(1),
+        config_get.status.code() == Some(0) || config_get.status.code() == Some(8),
         "config get returned unexpected exit code: {:?}",
         config_get.status.code()
     );
@@ -941,3 +1147,65 @@ fn e2e_saved_queries_run_with_overrides() {
             .collect::<Vec<_>>()
     );
 }
+
+#[test]
+fn e2e_forecast_epic_from_close_history() {
+    let _log = common::test_log("e2e_forecast_epic_from_close_history");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "forecast_init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let epic = run_br(
+        &workspace,
+        ["create", "Launch", "-t", "epic"],
+        "forecast_create_epic",
+    );
+    assert!(epic.status.success(), "epic failed: {}", epic.stderr);
+    let epic_id = parse_created_id(&epic.stdout);
+    for title in ["Child one", "Child two"] {
+        let child = run_br(
+            &workspace,
+            ["create", title, "--parent", &epic_id],
+            "forecast_create_child",
+        );
+        assert!(child.status.success(), "child failed: {}", child.stderr);
+    }
+    for title in ["Done one", "Done two"] {
+        let done = run_br(&workspace, ["create", title], "forecast_create_done");
+        assert!(done.status.success(), "create failed: {}", done.stderr);
+        let id = parse_created_id(&done.stdout);
+        let close = run_br(&workspace, ["close", &id], "forecast_close");
+        assert!(close.status.success(), "close failed: {}", close.stderr);
+    }
+
+    let forecast = run_br(
+        &workspace,
+        ["forecast", &epic_id, "--seed", "7", "--json"],
+        "forecast_json",
+    );
+    assert!(
+        forecast.status.success(),
+        "forecast failed: {}",
+        forecast.stderr
+    );
+    let payload = extract_json_payload(&forecast.stdout);
+    let output: Value = serde_json::from_str(&payload).expect("forecast json");
+    assert_eq!(output["scope"], epic_id.as_str());
+    assert_eq!(output["remaining"], 2);
+    assert_eq!(output["closed_in_history"], 2);
+    let confidence = output["confidence"].as_array().expect("confidence");
+    let percentiles: Vec<u64> = confidence
+        .iter()
+        .filter_map(|point| point["percentile"].as_u64())
+        .collect();
+    assert_eq!(percentiles, vec![50, 85, 95]);
+    assert!(confidence[0]["days"].as_u64() <= confidence[2]["days"].as_u64());
+
+    let text = run_br(&workspace, ["forecast"], "forecast_text");
+    assert!(
+        text.status.success(),
+        "forecast text failed: {}",
+        text.stderr
+    );
+    assert!(text.stdout.contains("Forecast for the open backlog"));
+}
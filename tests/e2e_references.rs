@@ -0,0 +1,66 @@
+//! E2E tests for "referenced by" backlinks in `br show`.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, json_output, run_br};
+
+fn backlinks(workspace: &BrWorkspace, id: &str) -> Vec<String> {
+    let show = run_br(workspace, ["show", id, "--json"], "show");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    json_output(&show.stdout)[0]["referenced_by"]
+        .as_array()
+        .map(|links| {
+            links
+                .iter()
+                .map(|link| link["id"].as_str().expect("id").to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn e2e_show_lists_issues_that_mention_it() {
+    let _log = common::test_log("e2e_show_lists_issues_that_mention_it");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let target = created_id(&workspace, &["create", "Flaky login"]);
+    let described = created_id(
+        &workspace,
+        &[
+            "create",
+            "Session timeout",
+            "--description",
+            &format!("Probably the same root cause as {target}."),
+        ],
+    );
+    let commented = created_id(&workspace, &["create", "Retry logic"]);
+    let comment = run_br(
+        &workspace,
+        [
+            "comments",
+            "add",
+            &commented,
+            &format!("see also #{target}"),
+        ],
+        "comment",
+    );
+    assert!(
+        comment.status.success(),
+        "comment failed: {}",
+        comment.stderr
+    );
+
+    assert_eq!(
+        backlinks(&workspace, &target),
+        vec![described.clone(), commented]
+    );
+    assert!(backlinks(&workspace, &described).is_empty());
+
+    let show = run_br(&workspace, ["show", &target], "show_text");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    assert!(show.stdout.contains("Referenced by"), "{}", show.stdout);
+    assert!(show.stdout.contains(&described), "{}", show.stdout);
+}
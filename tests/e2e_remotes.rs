@@ -0,0 +1,124 @@
+//! End-to-end tests for remote workspace federation.
+//!
+//! Tests cover:
+//! - `br remote add/list/remove` registry management
+//! - `list --remotes` / `ready --remotes` aggregation with repo-qualified IDs
+//! - `show remote:id` lookups in a registered remote
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+fn init_with_issue(workspace: &BrWorkspace, prefix: &str, title: &str) -> String {
+    let init = run_br(workspace, ["init", "--prefix", prefix], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let create = run_br(workspace, ["q", title], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    create.stdout.trim().to_string()
+}
+
+#[test]
+fn e2e_remote_add_list_remove() {
+    let _log = common::test_log("e2e_remote_add_list_remove");
+    let local = BrWorkspace::new();
+    let other = BrWorkspace::new();
+    init_with_issue(&local, "loc", "Local issue");
+    init_with_issue(&other, "oth", "Other issue");
+
+    let other_path = other.root.to_string_lossy().to_string();
+    let add = run_br(
+        &local,
+        ["remote", "add", "other", &other_path],
+        "remote_add",
+    );
+    assert!(add.status.success(), "remote add failed: {}", add.stderr);
+
+    let dup = run_br(
+        &local,
+        ["remote", "add", "other", &other_path],
+        "remote_add_dup",
+    );
+    assert!(!dup.status.success(), "duplicate remote should fail");
+
+    let list = run_br(&local, ["remote", "list", "--json"], "remote_list");
+    assert!(list.status.success(), "remote list failed: {}", list.stderr);
+    let remotes: Value = serde_json::from_str(&extract_json_payload(&list.stdout)).unwrap();
+    let remotes = remotes.as_array().expect("array");
+    assert_eq!(remotes.len(), 1);
+    assert_eq!(remotes[0]["name"], "other");
+    assert_eq!(remotes[0]["available"], true);
+
+    let remove = run_br(&local, ["remote", "remove", "other"], "remote_remove");
+    assert!(
+        remove.status.success(),
+        "remote remove failed: {}",
+        remove.stderr
+    );
+
+    let missing = run_br(
+        &local,
+        ["remote", "remove", "other"],
+        "remote_remove_missing",
+    );
+    assert!(
+        !missing.status.success(),
+        "removing unknown remote should fail"
+    );
+}
+
+#[test]
+fn e2e_remote_federated_list_ready_show() {
+    let _log = common::test_log("e2e_remote_federated_list_ready_show");
+    let local = BrWorkspace::new();
+    let other = BrWorkspace::new();
+    let local_id = init_with_issue(&local, "loc", "Local issue");
+    let other_id = init_with_issue(&other, "oth", "Other issue");
+    let qualified = format!("other:{other_id}");
+
+    let other_path = other.root.to_string_lossy().to_string();
+    let add = run_br(
+        &local,
+        ["remote", "add", "other", &other_path],
+        "remote_add",
+    );
+    assert!(add.status.success(), "remote add failed: {}", add.stderr);
+
+    // Without --remotes only local issues are listed
+    let list = run_br(&local, ["list", "--json"], "list_local");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    assert!(list.stdout.contains(&local_id));
+    assert!(!list.stdout.contains(&qualified));
+
+    let list = run_br(&local, ["list", "--remotes", "--json"], "list_remotes");
+    assert!(
+        list.status.success(),
+        "list --remotes failed: {}",
+        list.stderr
+    );
+    let issues: Value = serde_json::from_str(&extract_json_payload(&list.stdout)).unwrap();
+    let ids: Vec<&str> = issues
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|issue| issue["id"].as_str())
+        .collect();
+    assert!(ids.contains(&local_id.as_str()), "ids: {ids:?}");
+    assert!(ids.contains(&qualified.as_str()), "ids: {ids:?}");
+
+    let ready = run_br(&local, ["ready", "--remotes", "--json"], "ready_remotes");
+    assert!(
+        ready.status.success(),
+        "ready --remotes failed: {}",
+        ready.stderr
+    );
+    assert!(ready.stdout.contains(&qualified));
+
+    let show = run_br(&local, ["show", &qualified, "--json"], "show_remote");
+    assert!(show.status.success(), "show remote failed: {}", show.stderr);
+    let details: Value = serde_json::from_str(&extract_json_payload(&show.stdout)).unwrap();
+    assert_eq!(details[0]["id"], qualified.as_str());
+    assert_eq!(details[0]["title"], "Other issue");
+    assert_eq!(details[0]["source_repo"], "other");
+}
@@ -0,0 +1,55 @@
+//! End-to-end tests for `br report` Markdown generation.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+#[test]
+fn e2e_report_default_and_template() {
+    let _log = common::test_log("e2e_report_default_and_template");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init", "--prefix", "rp"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let open = run_br(&workspace, ["q", "Still open", "-p", "1"], "create_open");
+    assert!(open.status.success(), "create failed: {}", open.stderr);
+    let open_id = open.stdout.trim().to_string();
+    let done = run_br(&workspace, ["q", "Already done"], "create_done");
+    assert!(done.status.success(), "create failed: {}", done.stderr);
+    let done_id = done.stdout.trim().to_string();
+    let close = run_br(&workspace, ["close", &done_id], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let report = run_br(&workspace, ["report"], "report");
+    assert!(report.status.success(), "report failed: {}", report.stderr);
+    assert!(report.stdout.contains("# Status Report"));
+    assert!(report.stdout.contains("### P1 (1)"));
+    assert!(report.stdout.contains(&format!("`{open_id}` Still open")));
+    assert!(report.stdout.contains(&format!("`{done_id}` Already done")));
+
+    let template = workspace.root.join("report.tmpl");
+    std::fs::write(
+        &template,
+        "Open: {{open_count}}, closed: {{closed_count}}\n",
+    )
+    .unwrap();
+    let template_str = template.to_string_lossy().to_string();
+    let custom = run_br(
+        &workspace,
+        ["report", "--template", &template_str, "--json"],
+        "report_template",
+    );
+    assert!(custom.status.success(), "report failed: {}", custom.stderr);
+    let json: Value = serde_json::from_str(&extract_json_payload(&custom.stdout)).unwrap();
+    assert_eq!(json["markdown"], "Open: 1, closed: 1\n");
+    assert_eq!(json["open_count"], 1);
+
+    std::fs::write(&template, "{{bogus}}").unwrap();
+    let bad = run_br(
+        &workspace,
+        ["report", "--template", &template_str],
+        "report_bad_template",
+    );
+    assert!(!bad.status.success(), "unknown placeholder should fail");
+}
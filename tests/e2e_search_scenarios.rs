@@ -583,3 +583,145 @@ fn search_combined_multiple_filters() {
         assert_eq!(issue["issue_type"], "bug");
     }
 }
+
+// =============================================================================
+// FIELD-SCOPED AND REGEX SEARCH TESTS
+// =============================================================================
+
+#[test]
+fn search_in_fields_regex_and_case() {
+    let _log = common::test_log("search_in_fields_regex_and_case");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let create = |title: &str, label: &str| {
+        let out = run_br(&workspace, ["create", title], label);
+        assert!(out.status.success(), "create failed: {}", out.stderr);
+        parse_created_id(&out.stdout)
+    };
+    let cache = create("Cache layer", "create_cache");
+    let login = create("Timeout on login", "create_login");
+    let update = run_br(
+        &workspace,
+        ["update", &cache, "--notes", "Saw a Timeout in prod"],
+        "notes",
+    );
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+    let comment = run_br(
+        &workspace,
+        ["comments", "add", &login, "retry after backoff"],
+        "comment",
+    );
+    assert!(
+        comment.status.success(),
+        "comment failed: {}",
+        comment.stderr
+    );
+    let label = run_br(
+        &workspace,
+        ["update", &login, "--add-label", "backend"],
+        "label",
+    );
+    assert!(label.status.success(), "label failed: {}", label.stderr);
+
+    let search_ids = |args: &[&str], label: &str| -> Vec<String> {
+        let mut full = vec!["search"];
+        full.extend_from_slice(args);
+        full.push("--json");
+        let out = run_br(&workspace, full, label);
+        assert!(out.status.success(), "search failed: {}", out.stderr);
+        let json: Vec<Value> =
+            serde_json::from_str(&extract_json_payload(&out.stdout)).expect("parse json");
+        let mut ids: Vec<String> = json
+            .iter()
+            .map(|issue| issue["id"].as_str().unwrap().to_string())
+            .collect();
+        ids.sort();
+        ids
+    };
+    let mut both = vec![cache.clone(), login.clone()];
+    both.sort();
+
+    // Notes are not searched by default
+    assert_eq!(
+        search_ids(&["backoff"], "default_fields"),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        search_ids(&["timeout", "--in", "notes"], "in_notes"),
+        vec![cache.clone()]
+    );
+    assert_eq!(
+        search_ids(&["backoff", "--in", "comments"], "in_comments"),
+        vec![login.clone()]
+    );
+    assert_eq!(
+        search_ids(&["time?out", "--regex", "--in", "title,notes"], "regex"),
+        both
+    );
+    assert_eq!(
+        search_ids(
+            &["timeout", "--in", "title,notes", "--case-sensitive"],
+            "case_sensitive"
+        ),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        search_ids(
+            &[
+                "^Time",
+                "--regex",
+                "--case-sensitive",
+                "--in",
+                "title,notes",
+                "--label",
+                "backend"
+            ],
+            "regex_label"
+        ),
+        vec![login.clone()]
+    );
+
+    // Results say where they matched
+    let out = run_br(
+        &workspace,
+        ["search", "timeout", "--in", "title,notes", "--json"],
+        "match_json",
+    );
+    assert!(out.status.success(), "search failed: {}", out.stderr);
+    let json: Vec<Value> =
+        serde_json::from_str(&extract_json_payload(&out.stdout)).expect("parse json");
+    let hit = |id: &str| json.iter().find(|issue| issue["id"] == id).unwrap();
+    assert_eq!(hit(&login)["match_field"], "title");
+    assert_eq!(hit(&login)["snippet"], "Timeout on login");
+    assert_eq!(hit(&cache)["match_field"], "notes");
+    assert_eq!(hit(&cache)["snippet"], "Saw a Timeout in prod");
+
+    let text = run_br(
+        &workspace,
+        ["search", "backoff", "--in", "comments"],
+        "match_text",
+    );
+    assert!(text.status.success(), "search failed: {}", text.stderr);
+    assert!(
+        text.stdout.contains("comments: retry after **backoff**"),
+        "{}",
+        text.stdout
+    );
+
+    let bad_field = run_br(&workspace, ["search", "x", "--in", "labels"], "bad_field");
+    assert!(!bad_field.status.success());
+    assert!(
+        bad_field.stderr.contains("unknown search field"),
+        "{}",
+        bad_field.stderr
+    );
+    let bad_regex = run_br(&workspace, ["search", "(", "--regex"], "bad_regex");
+    assert!(!bad_regex.status.success());
+    assert!(
+        bad_regex.stderr.contains("invalid regex"),
+        "{}",
+        bad_regex.stderr
+    );
+}
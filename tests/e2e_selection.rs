@@ -0,0 +1,99 @@
+//! End-to-end tests for bulk selectors (`a..b`, `id.*`, `@filter:`).
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+fn create<const N: usize>(workspace: &BrWorkspace, args: [&str; N], label: &str) -> String {
+    let out = run_br(workspace, args, label);
+    assert!(out.status.success(), "create failed: {}", out.stderr);
+    out.stdout.trim().to_string()
+}
+
+fn statuses(workspace: &BrWorkspace) -> Vec<(String, String)> {
+    let out = run_br(workspace, ["list", "--all", "--json"], "list_all");
+    assert!(out.status.success(), "list failed: {}", out.stderr);
+    let json: Value = serde_json::from_str(&extract_json_payload(&out.stdout)).unwrap();
+    let mut rows: Vec<(String, String)> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| {
+            (
+                issue["id"].as_str().unwrap().to_string(),
+                issue["status"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    rows.sort();
+    rows
+}
+
+#[test]
+fn e2e_bulk_selectors_expand_for_close_and_label() {
+    let _log = common::test_log("e2e_bulk_selectors_expand_for_close_and_label");
+    let workspace = BrWorkspace::new();
+    let init = run_br(&workspace, ["init", "--prefix", "sel"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let epic = create(&workspace, ["create", "Epic", "--silent"], "epic");
+    let first = create(
+        &workspace,
+        ["create", "One", "--parent", &epic, "--silent"],
+        "child1",
+    );
+    let second = create(
+        &workspace,
+        ["create", "Two", "--parent", &epic, "--silent"],
+        "child2",
+    );
+    let third = create(
+        &workspace,
+        ["create", "Three", "--parent", &epic, "--silent"],
+        "child3",
+    );
+    let other = create(&workspace, ["create", "Other", "--silent"], "other");
+
+    // Label every child through a glob
+    let glob = format!("{epic}.*");
+    let label = run_br(&workspace, ["label", "add", &glob, "-l", "tmp"], "label");
+    assert!(label.status.success(), "label failed: {}", label.stderr);
+
+    // Close a range of children
+    let range = format!("{first}..{second}");
+    let close = run_br(&workspace, ["close", &range], "close_range");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let rows = statuses(&workspace);
+    let status_of = |id: &str| rows.iter().find(|(row, _)| row == id).unwrap().1.clone();
+    assert_eq!(status_of(&first), "closed");
+    assert_eq!(status_of(&second), "closed");
+    assert_eq!(status_of(&third), "open");
+    assert_eq!(status_of(&epic), "open");
+
+    // A filter selects the remaining open labelled child only
+    let update = run_br(
+        &workspace,
+        [
+            "update",
+            "@filter:\"label=tmp status=open\"",
+            "--priority",
+            "0",
+        ],
+        "update_filter",
+    );
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+    let show = run_br(&workspace, ["show", &third, "--json"], "show_third");
+    let json: Value = serde_json::from_str(&extract_json_payload(&show.stdout)).unwrap();
+    assert_eq!(json[0]["priority"], 0);
+    let show = run_br(&workspace, ["show", &other, "--json"], "show_other");
+    let json: Value = serde_json::from_str(&extract_json_payload(&show.stdout)).unwrap();
+    assert_eq!(json[0]["priority"], 2);
+
+    let none = run_br(&workspace, ["close", "@filter:label=missing"], "close_none");
+    assert!(!none.status.success());
+    assert!(none.stderr.contains("matched no issues"), "{}", none.stderr);
+    let bad = run_br(&workspace, ["close", "@filter:colour=red"], "close_bad");
+    assert!(!bad.status.success());
+    assert!(bad.stderr.contains("unknown key"), "{}", bad.stderr);
+}
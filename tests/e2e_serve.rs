@@ -0,0 +1,278 @@
+//! E2E tests for the `serve` HTTP API.
+
+#![cfg(feature = "serve")]
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+const TOKEN: &str = "test-token";
+
+/// A running `br serve`, killed on drop.
+struct ServeProcess {
+    child: Child,
+    addr: String,
+}
+
+impl ServeProcess {
+    fn start(workspace: &BrWorkspace) -> Self {
+        let mut child = Command::new(assert_cmd::cargo::cargo_bin!("br"))
+            .args(["serve", "--port", "0", "--token", TOKEN, "--json"])
+            .current_dir(&workspace.root)
+            .env("NO_COLOR", "1")
+            .env("HOME", &workspace.root)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("spawn br serve");
+        let mut line = String::new();
+        BufReader::new(child.stdout.as_mut().expect("stdout"))
+            .read_line(&mut line)
+            .expect("read startup line");
+        let startup: Value = serde_json::from_str(&line).expect("startup JSON");
+        let addr = startup["url"]
+            .as_str()
+            .expect("url")
+            .trim_start_matches("http://")
+            .to_string();
+        Self { child, addr }
+    }
+
+    /// Send a request and return the status code and JSON body.
+    fn request(&self, method: &str, path: &str, token: &str, body: Option<&str>) -> (u16, Value) {
+        let mut stream = TcpStream::connect(&self.addr).expect("connect");
+        let body = body.unwrap_or("");
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer {token}\r\n\
+             Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .expect("write request");
+        let mut response = String::new();
+        stream.read_to_string(&mut response).expect("read response");
+        let (head, body) = response.split_once("\r\n\r\n").expect("response head");
+        let status = head
+            .split_whitespace()
+            .nth(1)
+            .and_then(|code| code.parse().ok())
+            .expect("status code");
+        (status, serde_json::from_str(body).expect("JSON body"))
+    }
+}
+
+impl Drop for ServeProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn e2e_serve_reads_and_writes_issues() {
+    let _log = common::test_log("e2e_serve_reads_and_writes_issues");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let create = run_br(&workspace, ["create", "Blocker", "--json"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let payload = extract_json_payload(&create.stdout);
+    let created: Value = serde_json::from_str(&payload).expect("valid JSON");
+    let blocker = created["id"].as_str().expect("id").to_string();
+
+    let server = ServeProcess::start(&workspace);
+
+    let (status, body) = server.request("GET", "/issues", "wrong", None);
+    assert_eq!(status, 401);
+    assert_eq!(body["error"]["code"], "UNAUTHORIZED");
+
+    let (status, body) = server.request(
+        "POST",
+        "/issues",
+        TOKEN,
+        Some(r#"{"title": "From the API", "priority": 1}"#),
+    );
+    assert_eq!(status, 201, "create: {body}");
+    let id = body["id"].as_str().expect("id").to_string();
+
+    let (status, _) = server.request(
+        "POST",
+        &format!("/issues/{id}/dependencies"),
+        TOKEN,
+        Some(&format!(r#"{{"depends_on": "{blocker}"}}"#)),
+    );
+    assert_eq!(status, 201);
+    let (_, ready) = server.request("GET", "/ready", TOKEN, None);
+    let ready_ids: Vec<&str> = ready
+        .as_array()
+        .expect("ready list")
+        .iter()
+        .filter_map(|issue| issue["id"].as_str())
+        .collect();
+    assert_eq!(ready_ids, vec![blocker.as_str()]);
+
+    let (status, body) = server.request(
+        "PATCH",
+        &format!("/issues/{id}"),
+        TOKEN,
+        Some(r#"{"status": "in_progress", "assignee": "api"}"#),
+    );
+    assert_eq!(status, 200, "update: {body}");
+    assert_eq!(body["status"], "in_progress");
+
+    let (status, _) = server.request(
+        "POST",
+        &format!("/issues/{id}/comments"),
+        TOKEN,
+        Some(r#"{"text": "Posted over HTTP"}"#),
+    );
+    assert_eq!(status, 201);
+    let (_, comments) = server.request("GET", &format!("/issues/{id}/comments"), TOKEN, None);
+    assert_eq!(comments[0]["text"], "Posted over HTTP");
+
+    let (status, body) = server.request("GET", "/issues/bd-nope", TOKEN, None);
+    assert_eq!(status, 404);
+    assert_eq!(body["error"]["code"], "ISSUE_NOT_FOUND");
+    let (status, _) = server.request("PATCH", &format!("/issues/{id}"), TOKEN, Some("{}"));
+    assert_eq!(status, 400);
+
+    // Writes are flushed to JSONL like a CLI run
+    let jsonl =
+        std::fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).expect("read JSONL");
+    assert!(jsonl.contains("Posted over HTTP"));
+    assert!(jsonl.contains("From the API"));
+}
+
+#[test]
+fn e2e_serve_patch_enforces_close_policies() {
+    let _log = common::test_log("e2e_serve_patch_enforces_close_policies");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = std::fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str("\npolicies:\n  on_close:\n    all: acceptance_criteria\n");
+    std::fs::write(&config_path, config).expect("write config");
+    let create = run_br(&workspace, ["create", "Needs criteria", "--json"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let payload = extract_json_payload(&create.stdout);
+    let created: Value = serde_json::from_str(&payload).expect("valid JSON");
+    let id = created["id"].as_str().expect("id").to_string();
+
+    let server = ServeProcess::start(&workspace);
+
+    let (status, body) = server.request(
+        "PATCH",
+        &format!("/issues/{id}"),
+        TOKEN,
+        Some(r#"{"status": "closed"}"#),
+    );
+    assert_eq!(status, 400, "policy-violating close: {body}");
+    assert_eq!(body["error"]["code"], "VALIDATION_FAILED");
+    let (_, issue) = server.request("GET", &format!("/issues/{id}"), TOKEN, None);
+    assert_eq!(issue["status"], "open", "rejected PATCH must not write");
+
+    let (status, body) = server.request(
+        "PATCH",
+        &format!("/issues/{id}"),
+        TOKEN,
+        Some(r#"{"status": "closed", "acceptance_criteria": "Works"}"#),
+    );
+    assert_eq!(status, 200, "close: {body}");
+    assert_eq!(body["status"], "closed");
+    assert_eq!(body["close_reason"], "done");
+}
+
+#[test]
+fn e2e_serve_dependencies_and_close_follow_cli_rules() {
+    let _log = common::test_log("e2e_serve_dependencies_and_close_follow_cli_rules");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = std::fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str("\nauto_close_duplicates: true\n");
+    std::fs::write(&config_path, config).expect("write config");
+    let mut ids = Vec::new();
+    for title in ["Blocker", "Blocked", "Original", "Copy"] {
+        let create = run_br(&workspace, ["create", title, "--json"], "create");
+        assert!(create.status.success(), "create failed: {}", create.stderr);
+        let payload = extract_json_payload(&create.stdout);
+        let created: Value = serde_json::from_str(&payload).expect("valid JSON");
+        ids.push(created["id"].as_str().expect("id").to_string());
+    }
+    let [blocker, blocked, original, copy] = &ids[..] else {
+        unreachable!()
+    };
+
+    let server = ServeProcess::start(&workspace);
+
+    let (status, body) = server.request(
+        "POST",
+        &format!("/issues/{blocked}/dependencies"),
+        TOKEN,
+        Some(&format!(
+            r#"{{"depends_on": "{blocker}", "type": "parent_child"}}"#
+        )),
+    );
+    assert_eq!(status, 400, "unknown type: {body}");
+
+    let (status, _) = server.request(
+        "POST",
+        &format!("/issues/{blocked}/dependencies"),
+        TOKEN,
+        Some(&format!(r#"{{"depends_on": "{blocker}"}}"#)),
+    );
+    assert_eq!(status, 201);
+    let (status, body) = server.request(
+        "PATCH",
+        &format!("/issues/{blocked}"),
+        TOKEN,
+        Some(r#"{"status": "closed"}"#),
+    );
+    assert_eq!(status, 409, "close of a blocked issue: {body}");
+    let (_, issue) = server.request("GET", &format!("/issues/{blocked}"), TOKEN, None);
+    assert_eq!(issue["status"], "open", "refused close must not write");
+
+    let (status, body) = server.request(
+        "POST",
+        &format!("/issues/{copy}/dependencies"),
+        TOKEN,
+        Some(&format!(
+            r#"{{"depends_on": "{original}", "type": "duplicates"}}"#
+        )),
+    );
+    assert_eq!(status, 201, "duplicate: {body}");
+    assert_eq!(body["closed"], true);
+    let (_, issue) = server.request("GET", &format!("/issues/{copy}"), TOKEN, None);
+    assert_eq!(issue["status"], "closed");
+}
+
+#[test]
+fn e2e_serve_stalled_client_does_not_block_others() {
+    let _log = common::test_log("e2e_serve_stalled_client_does_not_block_others");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let server = ServeProcess::start(&workspace);
+
+    // Connects but never sends its request
+    let stalled = TcpStream::connect(&server.addr).expect("connect");
+    let started = Instant::now();
+    let (status, _) = server.request("GET", "/issues", TOKEN, None);
+    assert_eq!(status, 200);
+    assert!(
+        started.elapsed() < Duration::from_secs(5),
+        "request waited for the stalled connection"
+    );
+    drop(stalled);
+}
@@ -0,0 +1,94 @@
+//! E2E tests for `--format table` and `--columns`.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, run_br};
+
+fn table(workspace: &BrWorkspace, args: &[&str], label: &str) -> Vec<String> {
+    let output = run_br(workspace, args, label);
+    assert!(output.status.success(), "{label} failed: {}", output.stderr);
+    output.stdout.lines().map(str::to_string).collect()
+}
+
+#[test]
+fn e2e_table_format_columns_and_width() {
+    let _log = common::test_log("e2e_table_format_columns_and_width");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let blocker = created_id(
+        &workspace,
+        &["create", "Set up the database schema for reporting"],
+    );
+    let blocked = created_id(&workspace, &["create", "Build the weekly report"]);
+    let dep = run_br(&workspace, ["dep", "add", &blocked, &blocker], "dep_add");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+    let label = run_br(&workspace, ["label", "add", &blocked, "reports"], "label");
+    assert!(label.status.success(), "label add failed: {}", label.stderr);
+
+    let lines = table(&workspace, &["list", "--format", "table"], "list_table");
+    assert_eq!(lines.len(), 3, "{lines:?}");
+    assert_eq!(
+        lines[0].split_whitespace().collect::<Vec<_>>(),
+        ["ID", "STATUS", "PRIORITY", "ASSIGNEE", "AGE", "TITLE"]
+    );
+    // Titles start in the same column on every row
+    let title_at = lines[0].find("TITLE").expect("title header");
+    assert!(
+        lines[1..]
+            .iter()
+            .all(|line| line[..title_at].ends_with("  "))
+    );
+
+    let lines = table(
+        &workspace,
+        &["list", "--format", "table", "--columns", "id,labels"],
+        "list_columns",
+    );
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.starts_with(&blocked) && line.ends_with("reports")),
+        "{lines:?}"
+    );
+
+    let unknown = run_br(
+        &workspace,
+        ["list", "--format", "table", "--columns", "id,nope"],
+        "list_unknown_column",
+    );
+    assert!(!unknown.status.success(), "unknown column accepted");
+    assert!(unknown.stderr.contains("nope"), "{}", unknown.stderr);
+
+    let set = run_br(
+        &workspace,
+        ["config", "set", "table.max_width=40"],
+        "config_set",
+    );
+    assert!(set.status.success(), "config set failed: {}", set.stderr);
+    let lines = table(&workspace, &["list", "--format", "table"], "list_narrow");
+    assert!(
+        lines.iter().all(|line| line.chars().count() <= 40),
+        "{lines:?}"
+    );
+    assert!(lines.iter().any(|line| line.ends_with("...")), "{lines:?}");
+
+    let lines = table(
+        &workspace,
+        &["blocked", "--format", "table", "--columns", "id,blocked_by"],
+        "blocked_table",
+    );
+    assert_eq!(lines.len(), 2, "{lines:?}");
+    assert!(lines[1].starts_with(&blocked), "{lines:?}");
+    assert!(lines[1].ends_with(&blocker), "{lines:?}");
+
+    let lines = table(
+        &workspace,
+        &["ready", "--format", "table", "--columns", "id,title"],
+        "ready_table",
+    );
+    assert_eq!(lines.len(), 2, "{lines:?}");
+    assert!(lines[1].starts_with(&blocker), "{lines:?}");
+}
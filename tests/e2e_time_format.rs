@@ -0,0 +1,66 @@
+//! E2E tests for relative timestamps, `--absolute` and `display.*_format`.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+fn json(stdout: &str) -> Value {
+    serde_json::from_str(&extract_json_payload(stdout)).expect("json output")
+}
+
+fn show(workspace: &BrWorkspace, args: &[&str], label: &str) -> String {
+    let output = run_br(workspace, args, label);
+    assert!(output.status.success(), "{label} failed: {}", output.stderr);
+    output.stdout
+}
+
+#[test]
+fn e2e_show_relative_and_absolute_times() {
+    let _log = common::test_log("e2e_show_relative_and_absolute_times");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let created = run_br(
+        &workspace,
+        ["create", "Ship it", "--due", "2030-01-15", "--json"],
+        "create",
+    );
+    assert!(
+        created.status.success(),
+        "create failed: {}",
+        created.stderr
+    );
+    let id = json(&created.stdout)["id"]
+        .as_str()
+        .expect("id")
+        .to_string();
+
+    let stdout = show(&workspace, &["show", &id], "show_relative");
+    assert!(
+        stdout.contains("Created: just now · Updated: just now"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("Due: in "), "{stdout}");
+
+    let stdout = show(&workspace, &["show", &id, "--absolute"], "show_absolute");
+    assert!(stdout.contains("Due: 2030-01-15"), "{stdout}");
+    assert!(!stdout.contains("just now"), "{stdout}");
+
+    let set = run_br(
+        &workspace,
+        ["config", "set", "display.date_format=%d %b %Y"],
+        "config_date_format",
+    );
+    assert!(set.status.success(), "config set failed: {}", set.stderr);
+    let set = run_br(
+        &workspace,
+        ["config", "set", "display.time=absolute"],
+        "config_time",
+    );
+    assert!(set.status.success(), "config set failed: {}", set.stderr);
+    let stdout = show(&workspace, &["show", &id], "show_configured");
+    assert!(stdout.contains("Due: 15 Jan 2030"), "{stdout}");
+}
@@ -0,0 +1,95 @@
+//! E2E tests for `waits-for` dependencies with an `--until` wake time.
+
+mod common;
+
+use common::cli::{BrWorkspace, created_id, json_output, run_br};
+
+fn is_ready(workspace: &BrWorkspace, id: &str) -> bool {
+    let ready = run_br(workspace, ["ready", "--json"], "ready");
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    json_output(&ready.stdout)
+        .as_array()
+        .expect("array")
+        .iter()
+        .any(|issue| issue["id"] == id)
+}
+
+#[test]
+fn e2e_waits_for_until_blocks_until_wake_time() {
+    let _log = common::test_log("e2e_waits_for_until_blocks_until_wake_time");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let vendor = created_id(&workspace, &["create", "Vendor ships SDK 2.0"]);
+    let upgrade = created_id(&workspace, &["create", "Upgrade to SDK 2.0"]);
+    let fallback = created_id(&workspace, &["create", "Ship the fallback"]);
+
+    let bad = run_br(
+        &workspace,
+        ["dep", "add", &upgrade, &vendor, "--until", "+2d"],
+        "dep_add_not_waits_for",
+    );
+    assert!(!bad.status.success(), "--until accepted on a blocks dep");
+
+    let add = run_br(
+        &workspace,
+        [
+            "dep",
+            "add",
+            &upgrade,
+            &vendor,
+            "--type",
+            "waits-for",
+            "--until",
+            "+2d",
+        ],
+        "dep_add_pending",
+    );
+    assert!(add.status.success(), "dep add failed: {}", add.stderr);
+    assert!(!is_ready(&workspace, &upgrade));
+
+    // A wake time in the past no longer blocks
+    let add = run_br(
+        &workspace,
+        [
+            "dep",
+            "add",
+            &fallback,
+            &vendor,
+            "--type",
+            "waits-for",
+            "--until",
+            "2020-01-01T00:00:00Z",
+        ],
+        "dep_add_passed",
+    );
+    assert!(add.status.success(), "dep add failed: {}", add.stderr);
+    assert!(is_ready(&workspace, &fallback));
+
+    let blocked = run_br(&workspace, ["blocked", "--json"], "blocked_json");
+    assert!(
+        blocked.status.success(),
+        "blocked failed: {}",
+        blocked.stderr
+    );
+    let blocked = json_output(&blocked.stdout);
+    let entry = blocked
+        .as_array()
+        .expect("array")
+        .iter()
+        .find(|issue| issue["id"] == upgrade.as_str())
+        .expect("upgrade is blocked");
+    assert!(entry["blockers"][0]["waits_until"].is_string(), "{entry}");
+
+    let blocked = run_br(&workspace, ["blocked"], "blocked_text");
+    assert!(
+        blocked
+            .stdout
+            .contains(&format!("Waits for {vendor} until")),
+        "{}",
+        blocked.stdout
+    );
+    assert!(blocked.stdout.contains("(in 1d 23h)"), "{}", blocked.stdout);
+}
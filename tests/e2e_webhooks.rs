@@ -0,0 +1,155 @@
+//! E2E tests for webhook notifications.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Write `yaml` as the user config (HOME is the workspace root).
+fn write_user_config(workspace: &BrWorkspace, yaml: &str) {
+    let dir = workspace.root.join(".config").join("beads");
+    fs::create_dir_all(&dir).expect("user config dir");
+    fs::write(dir.join("config.yaml"), yaml).expect("write config");
+}
+
+/// Accept webhook POSTs, answer 200, and pass each JSON body on.
+fn spawn_receiver() -> (String, mpsc::Receiver<Value>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind receiver");
+    let url = format!("http://{}/beads", listener.local_addr().expect("addr"));
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("read header");
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(value) = line.strip_prefix("Content-Length: ") {
+                    length = value.parse().expect("length");
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).expect("read body");
+            let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n");
+            let _ = sender.send(serde_json::from_slice(&body).expect("JSON body"));
+        }
+    });
+    (url, receiver)
+}
+
+#[test]
+fn e2e_webhooks_post_created_and_closed_events() {
+    let _log = common::test_log("e2e_webhooks_post_created_and_closed_events");
+    let workspace = BrWorkspace::new();
+    let (url, receiver) = spawn_receiver();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    write_user_config(
+        &workspace,
+        &format!("webhooks:\n  urls: [\"{url}\"]\n  events: [created, closed]\n"),
+    );
+
+    let create = run_br(&workspace, ["create", "Hooked", "--json"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let payload = extract_json_payload(&create.stdout);
+    let created: Value = serde_json::from_str(&payload).expect("valid JSON");
+    let id = created["id"].as_str().expect("id").to_string();
+
+    let event = receiver
+        .recv_timeout(Duration::from_secs(10))
+        .expect("created webhook");
+    assert_eq!(event["event"]["event_type"], "created");
+    assert_eq!(event["issue"]["id"], id.as_str());
+
+    // Comments are not in webhooks.events, so only the close is sent
+    let comment = run_br(&workspace, ["comments", "add", &id, "Noted"], "comment");
+    assert!(
+        comment.status.success(),
+        "comment failed: {}",
+        comment.stderr
+    );
+    let watch = run_br(&workspace, ["--actor", "lead", "watch", &id], "watch");
+    assert!(watch.status.success(), "watch failed: {}", watch.stderr);
+    let close = run_br(&workspace, ["close", &id], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let event = receiver
+        .recv_timeout(Duration::from_secs(10))
+        .expect("closed webhook");
+    assert_eq!(event["event"]["event_type"], "closed");
+    assert_eq!(event["issue"]["status"], "closed");
+    assert_eq!(event["watchers"], serde_json::json!(["lead"]));
+    assert!(receiver.try_recv().is_err(), "unexpected extra webhook");
+}
+
+#[test]
+fn e2e_webhooks_dead_letter_undeliverable_events() {
+    let _log = common::test_log("e2e_webhooks_dead_letter_undeliverable_events");
+    let workspace = BrWorkspace::new();
+    // Bind then drop, so nothing is listening on the port
+    let port = TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("free port")
+        .port();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    write_user_config(
+        &workspace,
+        &format!("webhooks:\n  urls: http://127.0.0.1:{port}/\n  retries: 0\n"),
+    );
+
+    let create = run_br(&workspace, ["create", "Unheard"], "create");
+    assert!(
+        create.status.success(),
+        "create must not fail: {}",
+        create.stderr
+    );
+    assert!(create.stderr.contains("webhook deliveries failed"));
+
+    let dead = fs::read_to_string(workspace.root.join(".beads").join("webhooks-dead.jsonl"))
+        .expect("dead-letter file");
+    let entry: Value = serde_json::from_str(dead.lines().next().expect("entry")).expect("JSON");
+    assert_eq!(entry["attempts"], 1);
+    assert_eq!(entry["payload"]["event"]["event_type"], "created");
+}
+
+#[test]
+fn e2e_webhooks_in_project_config_are_rejected() {
+    let _log = common::test_log("e2e_webhooks_in_project_config_are_rejected");
+    let workspace = BrWorkspace::new();
+    let (url, receiver) = spawn_receiver();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    fs::write(
+        workspace.root.join(".beads").join("config.yaml"),
+        format!("webhooks:\n  urls: [\"{url}\"]\n"),
+    )
+    .expect("write config");
+
+    let create = run_br(&workspace, ["create", "Private"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    assert!(
+        create
+            .stderr
+            .contains("webhooks.urls cannot be set in .beads/config.yaml"),
+        "stderr: {}",
+        create.stderr
+    );
+    assert!(
+        receiver.recv_timeout(Duration::from_millis(500)).is_err(),
+        "issue data was sent"
+    );
+}
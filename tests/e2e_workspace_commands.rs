@@ -610,3 +610,96 @@ fn e2e_workspace_paths_consistent() {
         );
     }
 }
+
+#[test]
+fn e2e_info_workspace_report() {
+    let _log = common::test_log("e2e_info_workspace_report");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init", "--prefix", "inf"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let create = run_br(&workspace, ["create", "Report me"], "create");
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    std::fs::write(
+        workspace.root.join(".beads").join("config.yaml"),
+        "default_priority: 1\n",
+    )
+    .unwrap();
+
+    let info = run_br(&workspace, ["info", "--json"], "info_json");
+    assert!(info.status.success(), "info failed: {}", info.stderr);
+    let json: Value = serde_json::from_str(&extract_json_payload(&info.stdout)).unwrap();
+    assert_eq!(json["prefix"], "inf");
+    assert_eq!(json["issue_counts"]["open"], 1);
+    assert!(json["schema_version"].as_i64().unwrap() > 0);
+    assert!(json["jsonl_modified_at"].is_string(), "{json}");
+    assert!(json["last_export_time"].is_string(), "{json}");
+    assert!(
+        json["config_origins"]["default_priority"]
+            .as_str()
+            .unwrap()
+            .ends_with("config.yaml"),
+        "{json}"
+    );
+
+    let text = run_br(&workspace, ["info"], "info_text");
+    assert!(text.status.success(), "info failed: {}", text.stderr);
+    assert!(text.stdout.contains("Issue prefix: inf"), "{}", text.stdout);
+    assert!(
+        text.stdout.contains("Issue count: 1 (open 1)"),
+        "{}",
+        text.stdout
+    );
+    assert!(text.stdout.contains("Config origins:"), "{}", text.stdout);
+}
+
+#[test]
+fn e2e_migrate_status_and_run() {
+    let _log = common::test_log("e2e_migrate_status_and_run");
+    let workspace = BrWorkspace::new();
+
+    let missing = run_br(&workspace, ["migrate", "--status"], "migrate_uninitialized");
+    assert!(!missing.status.success());
+
+    let init = run_br(&workspace, ["init", "--prefix", "mig"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let status = run_br(
+        &workspace,
+        ["migrate", "--status", "--json"],
+        "migrate_status",
+    );
+    assert!(status.status.success(), "migrate failed: {}", status.stderr);
+    let json: Value = serde_json::from_str(&extract_json_payload(&status.stdout)).unwrap();
+    let supported = json["supported_version"].as_i64().unwrap();
+    assert_eq!(json["database_version"].as_i64(), Some(supported));
+    assert_eq!(
+        json["applied"].as_array().map(Vec::len),
+        usize::try_from(supported).ok()
+    );
+    assert_eq!(json["applied"][0]["name"], "base schema");
+    assert_eq!(json["pending"].as_array().map(Vec::len), Some(0));
+
+    let text = run_br(&workspace, ["migrate"], "migrate_run");
+    assert!(text.status.success(), "migrate failed: {}", text.stderr);
+    assert!(text.stdout.contains("up to date"), "{}", text.stdout);
+
+    let text = run_br(&workspace, ["migrate", "--status"], "migrate_status_text");
+    assert!(text.status.success(), "migrate failed: {}", text.stderr);
+    assert!(text.stdout.contains("Pending: none"), "{}", text.stdout);
+
+    let rebuild = run_br(
+        &workspace,
+        ["migrate", "--rebuild-counts", "--json"],
+        "migrate_rebuild_counts",
+    );
+    assert!(
+        rebuild.status.success(),
+        "migrate failed: {}",
+        rebuild.stderr
+    );
+    let json: Value = serde_json::from_str(&extract_json_payload(&rebuild.stdout)).unwrap();
+    assert_eq!(json["rebuilt_counts"], 0);
+}
@@ -177,3 +177,87 @@ invalid-type:bd-123
         "expected warning for invalid dependency type"
     );
 }
+
+#[test]
+fn test_markdown_import_resolves_placeholder_dependencies() {
+    let workspace = BrWorkspace::new();
+
+    let output = run_br(&workspace, ["init"], "init_placeholder");
+    assert!(output.status.success(), "init failed");
+
+    let md_path = workspace.root.join("issues.md");
+    let content = r"## Design schema
+### Dependencies
+blocks:#2
+
+## Write migration
+";
+    fs::write(&md_path, content).expect("write md");
+
+    let output = run_br(
+        &workspace,
+        ["create", "--file", "issues.md", "--json"],
+        "create_placeholder",
+    );
+    assert!(output.status.success(), "create --file failed");
+
+    let payload = extract_json_payload(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&payload).expect("json parse");
+    let array = json.as_array().expect("json array");
+    let second_id = array[1]["id"].as_str().expect("second id");
+    let deps = array[0]["dependencies"].as_array().expect("dependencies");
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0]["depends_on_id"], second_id);
+}
+
+#[test]
+fn test_markdown_export_round_trips_with_update() {
+    let workspace = BrWorkspace::new();
+
+    let output = run_br(&workspace, ["init"], "init_round_trip");
+    assert!(output.status.success(), "init failed");
+
+    let output = run_br(
+        &workspace,
+        ["create", "Fix login", "--priority", "2", "--json"],
+        "create_round_trip",
+    );
+    assert!(output.status.success(), "create failed");
+    let payload = extract_json_payload(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&payload).expect("json parse");
+    let id = json["id"].as_str().expect("id").to_string();
+
+    let output = run_br(
+        &workspace,
+        ["export", "--format", "md", "--output", "issues.md"],
+        "export_md",
+    );
+    assert!(output.status.success(), "export --format md failed");
+
+    let md_path = workspace.root.join("issues.md");
+    let exported = fs::read_to_string(&md_path).expect("read md");
+    assert!(exported.contains(&format!("<!-- br:id {id} -->")));
+    let edited = exported.replace("### Priority\n2", "### Priority\n0")
+        + "\n### Notes\nReproduced on Safari\n";
+    fs::write(&md_path, edited).expect("write md");
+
+    let output = run_br(
+        &workspace,
+        ["create", "--file", "issues.md", "--update", "--json"],
+        "import_update",
+    );
+    assert!(output.status.success(), "create --file --update failed");
+    let payload = extract_json_payload(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&payload).expect("json parse");
+    let array = json.as_array().expect("json array");
+    assert_eq!(array.len(), 1);
+    assert_eq!(array[0]["id"], id.as_str());
+    assert_eq!(array[0]["priority"], 0);
+    assert_eq!(array[0]["notes"], "Reproduced on Safari");
+
+    let output = run_br(&workspace, ["count", "--json"], "count_after_update");
+    assert!(output.status.success(), "count failed");
+    let payload = extract_json_payload(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&payload).expect("json parse");
+    assert_eq!(json["count"], 1);
+}
@@ -8,7 +8,7 @@
 
 use chrono::Utc;
 use proptest::prelude::*;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use tracing::info;
 
 use beads_rust::model::{Issue, IssueType, Priority, Status};
@@ -64,6 +64,10 @@ fn make_issue(title: &str, description: Option<&str>) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
@@ -8,6 +8,7 @@
 
 use chrono::{TimeZone, Utc};
 use proptest::prelude::*;
+use std::collections::BTreeMap;
 use tracing::info;
 
 use beads_rust::model::{Issue, IssueType, Priority, Status};
@@ -64,6 +65,10 @@ fn make_valid_issue(title: &str) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
@@ -1,6 +1,7 @@
 use beads_rust::model::{Issue, IssueType, Priority, Status};
 use beads_rust::storage::SqliteStorage;
 use chrono::Utc;
+use std::collections::BTreeMap;
 
 fn make_issue(id: &str, title: &str) -> Issue {
     Issue {
@@ -43,6 +44,10 @@ fn make_issue(id: &str, title: &str) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
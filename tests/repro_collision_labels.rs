@@ -2,6 +2,7 @@ use beads_rust::model::{Issue, IssueType, Priority, Status};
 use beads_rust::storage::SqliteStorage;
 use beads_rust::sync::{ImportConfig, import_from_jsonl};
 use chrono::Utc;
+use std::collections::BTreeMap;
 use std::fs;
 use tempfile::TempDir;
 
@@ -47,6 +48,10 @@ fn make_issue(id: &str, title: &str) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
@@ -1,6 +1,7 @@
 use beads_rust::model::{Issue, IssueType, Priority, Status};
 use beads_rust::storage::SqliteStorage;
 use chrono::Utc;
+use std::collections::BTreeMap;
 
 fn create_issue(id: &str, title: &str, issue_type: IssueType) -> Issue {
     Issue {
@@ -43,6 +44,10 @@ fn create_issue(id: &str, title: &str, issue_type: IssueType) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
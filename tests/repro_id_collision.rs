@@ -8,6 +8,7 @@ fn test_id_generator_fallback_collision() {
         min_hash_length: 3,
         max_hash_length: 3, // Force max length quickly
         max_collision_prob: 0.0,
+        salt: None,
     };
     let generator = IdGenerator::new(config);
 
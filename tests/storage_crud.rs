@@ -10,6 +10,7 @@ use beads_rust::model::{DependencyType, EventType, Issue, IssueType, Priority, S
 use beads_rust::storage::{IssueUpdate, SqliteStorage};
 use chrono::{Duration, Utc};
 use common::{fixtures, test_db, test_db_with_dir};
+use std::collections::BTreeMap;
 
 // ============================================================================
 // CREATE ISSUE TESTS
@@ -63,6 +64,10 @@ fn create_issue_all_fields_populated() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
         // Other optional fields
         content_hash: None,
         closed_at: None,
@@ -443,6 +448,10 @@ fn update_issue_clear_optional_fields() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     };
 
     storage.create_issue(&issue, "tester").unwrap();
@@ -702,6 +711,10 @@ fn upsert_issue_stores_all_fields() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
         content_hash: Some("abc123".to_string()),
         closed_at: None,
         close_reason: None,
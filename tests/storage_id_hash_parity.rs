@@ -10,6 +10,7 @@
 //! to verify compatibility.
 
 use chrono::{TimeZone, Utc};
+use std::collections::BTreeMap;
 
 use beads_rust::model::{Issue, IssueType, Priority, Status};
 use beads_rust::util::id::{
@@ -589,6 +590,10 @@ fn content_hash_trait_implementation() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        code_refs: vec![],
+        watchers: vec![],
+        reactions: vec![],
+        fields: BTreeMap::new(),
     };
 
     let hash_trait = issue.content_hash();
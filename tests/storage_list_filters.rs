@@ -9,6 +9,7 @@
 //! - Limit filter
 //! - Include closed filter
 //! - Include templates filter
+//! - Dependency filters (depends-on, blocks, dependency type)
 //! - Combined filter tests
 #![allow(clippy::similar_names)]
 
@@ -827,6 +828,78 @@ fn combined_multiple_statuses_types_priorities() {
     assert_eq!(results[0].title, "multi-combo-1");
 }
 
+// ============================================================================
+// DEPENDENCY FILTER TESTS
+// ============================================================================
+
+#[test]
+fn filter_by_dependency_relationships() {
+    let mut storage = test_db();
+
+    let epic = IssueBuilder::new("Epic").with_id("dep-epic").build();
+    let api = IssueBuilder::new("API").with_id("dep-api").build();
+    let ui = IssueBuilder::new("UI").with_id("dep-ui").build();
+    let docs = IssueBuilder::new("Docs").with_id("dep-docs").build();
+    for issue in [&epic, &api, &ui, &docs] {
+        storage.create_issue(issue, "tester").unwrap();
+    }
+    // ui waits on api; api and ui are children of the epic; docs relates to ui
+    storage
+        .add_dependency("dep-ui", "dep-api", "blocks", "tester")
+        .unwrap();
+    storage
+        .add_dependency("dep-api", "dep-epic", "parent-child", "tester")
+        .unwrap();
+    storage
+        .add_dependency("dep-ui", "dep-epic", "parent-child", "tester")
+        .unwrap();
+    storage
+        .add_dependency("dep-docs", "dep-ui", "related", "tester")
+        .unwrap();
+
+    let ids = |filters: ListFilters| {
+        let mut ids: Vec<String> = storage
+            .list_issues(&filters)
+            .unwrap()
+            .into_iter()
+            .map(|issue| issue.id)
+            .collect();
+        ids.sort();
+        ids
+    };
+
+    assert_eq!(
+        ids(ListFilters {
+            depends_on: Some("dep-epic".to_string()),
+            ..Default::default()
+        }),
+        vec!["dep-api", "dep-ui"]
+    );
+    // Blockers of ui: api (blocks) and the epic (parent-child), not docs
+    assert_eq!(
+        ids(ListFilters {
+            blocks: Some("dep-ui".to_string()),
+            ..Default::default()
+        }),
+        vec!["dep-api", "dep-epic"]
+    );
+    assert_eq!(
+        ids(ListFilters {
+            blocks: Some("dep-ui".to_string()),
+            dep_type: Some("blocks".to_string()),
+            ..Default::default()
+        }),
+        vec!["dep-api"]
+    );
+    assert_eq!(
+        ids(ListFilters {
+            dep_type: Some("related".to_string()),
+            ..Default::default()
+        }),
+        vec!["dep-docs"]
+    );
+}
+
 // ============================================================================
 // SORT ORDER TESTS
 // ============================================================================
@@ -1,7 +1,7 @@
 //! Storage unit tests for ready issues functionality.
 //!
 //! Tests: `get_ready_issues` with various filters (assignee, unassigned, types,
-//! priorities, `labels_and`, `labels_or`, `include_deferred`, limit) and sort policies
+//! priorities, `labels_and`, `labels_or`, `include_deferred`, limit, `max_minutes`) and sort policies
 //! (Hybrid, Priority, Oldest). Real `SQLite`, no mocks.
 
 mod common;
@@ -360,6 +360,36 @@ fn ready_filter_limit_greater_than_total() {
     assert_eq!(ids.len(), 2);
 }
 
+// ============================================================================
+// ESTIMATE FILTER TESTS
+// ============================================================================
+
+#[test]
+fn ready_filter_max_minutes() {
+    let mut storage = test_db();
+
+    let mut quick = fixtures::issue("Quick fix");
+    quick.estimated_minutes = Some(30);
+    let mut exact = fixtures::issue("Exactly an hour");
+    exact.estimated_minutes = Some(60);
+    let mut long = fixtures::issue("Long refactor");
+    long.estimated_minutes = Some(240);
+    let unestimated = fixtures::issue("No estimate");
+    for issue in [&quick, &exact, &long, &unestimated] {
+        storage.create_issue(issue, "tester").unwrap();
+    }
+
+    let filters = ReadyFilters {
+        max_minutes: Some(60),
+        ..Default::default()
+    };
+
+    let ids = ready_ids(&storage, &filters, ReadySortPolicy::Oldest);
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&quick.id));
+    assert!(ids.contains(&exact.id));
+}
+
 // ============================================================================
 // SORT POLICY TESTS
 // ============================================================================